@@ -0,0 +1,510 @@
+//! Distributed Benchmark Orchestrator
+//!
+//! Spawns publisher and subscriber workers as separate processes, locally or
+//! on another host over SSH, coordinates them through a control topic and
+//! aggregates their results into one report.
+//!
+//! Usage:
+//!   bench_orchestrator [options]
+//!     --publishers N        local publisher processes (default 1)
+//!     --subscribers N       local subscriber processes (default 1)
+//!     --remote HOST         SSH destination for remote workers (user@host)
+//!     --remote-exe PATH     bench_orchestrator path on the remote host
+//!                           (default: same path as locally)
+//!     --remote-subscribers N  subscribers on the remote host (default 1 with --remote)
+//!     --remote-publishers N   publishers on the remote host (default 0)
+//!     --data TOPIC          data topic endpoint (default bench.data)
+//!     --control TOPIC       control topic endpoint (default bench.control,
+//!                           bench.control@* with --remote)
+//!     --rate HZ             publish rate per publisher, 0 = unthrottled (default 1000)
+//!     --duration SECS       measurement duration (default 10)
+//!     --output FILE         write the aggregated JSON report
+//!
+//! Multi-host runs need a network endpoint for the data topic, e.g.
+//! `--data bench.data@192.168.1.5` or `--data bench.data@*`.
+//!
+//! Worker mode (spawned by the coordinator):
+//!   bench_orchestrator worker --id N --role pub|sub --data TOPIC --control TOPIC
+//!                             --rate HZ --timeout SECS
+
+use colored::*;
+use horus::prelude::Hub;
+use horus_benchmarks::orchestrator::{
+    aggregate, decimate, now_ns, remote_command_line, AggregateReport, BenchSample, ControlMessage,
+    RunConfig, SequenceTracker, WorkerReport, WorkerRole, WorkerSpec, MAX_REPORTED_LATENCIES,
+};
+use std::env;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// Interval for re-sending control messages and polling topics
+const CONTROL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// How long subscribers keep draining after Stop
+const DRAIN_GRACE: Duration = Duration::from_millis(500);
+
+struct Options {
+    config: RunConfig,
+    publishers: u32,
+    subscribers: u32,
+    remote: Option<String>,
+    remote_exe: Option<String>,
+    remote_publishers: u32,
+    remote_subscribers: Option<u32>,
+    duration: Duration,
+    output: Option<String>,
+    control_set: bool,
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = if args.first().map(String::as_str) == Some("worker") {
+        run_worker(&args[1..])
+    } else {
+        run_coordinator(&args)
+    };
+
+    if let Err(e) = result {
+        eprintln!("{} {}", "Error:".bright_red().bold(), e);
+        std::process::exit(1);
+    }
+}
+
+/// Value following a flag
+fn flag_value<'a>(args: &'a [String], i: &mut usize) -> Result<&'a str, String> {
+    let flag = &args[*i];
+    *i += 1;
+    args.get(*i)
+        .map(String::as_str)
+        .ok_or_else(|| format!("missing value for {}", flag))
+}
+
+fn parse_num<T: std::str::FromStr>(value: &str, flag: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value '{}' for {}", value, flag))
+}
+
+fn parse_options(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        config: RunConfig::default(),
+        publishers: 1,
+        subscribers: 1,
+        remote: None,
+        remote_exe: None,
+        remote_publishers: 0,
+        remote_subscribers: None,
+        duration: Duration::from_secs(10),
+        output: None,
+        control_set: false,
+    };
+
+    let mut i = 0;
+    while i < args.len() {
+        let flag = args[i].clone();
+        let value = flag_value(args, &mut i)?;
+        match flag.as_str() {
+            "--publishers" => options.publishers = parse_num(value, &flag)?,
+            "--subscribers" => options.subscribers = parse_num(value, &flag)?,
+            "--remote" => options.remote = Some(value.to_string()),
+            "--remote-exe" => options.remote_exe = Some(value.to_string()),
+            "--remote-publishers" => options.remote_publishers = parse_num(value, &flag)?,
+            "--remote-subscribers" => options.remote_subscribers = Some(parse_num(value, &flag)?),
+            "--data" => options.config.data_topic = value.to_string(),
+            "--control" => {
+                options.config.control_topic = value.to_string();
+                options.control_set = true;
+            }
+            "--rate" => options.config.rate_hz = parse_num(value, &flag)?,
+            "--duration" => options.duration = Duration::from_secs(parse_num(value, &flag)?),
+            "--output" => options.output = Some(value.to_string()),
+            other => return Err(format!("unknown option '{}'", other)),
+        }
+        i += 1;
+    }
+
+    if options.remote.is_some() && !options.control_set {
+        // Shared memory does not reach the other host
+        options.config.control_topic = format!("{}@*", options.config.control_topic);
+    }
+    // Workers must outlive the run itself
+    options.config.timeout = options.duration + Duration::from_secs(60);
+    Ok(options)
+}
+
+fn worker_specs(options: &Options) -> Vec<WorkerSpec> {
+    let mut specs = Vec::new();
+    let mut add = |role, host: Option<&String>, count: u32| {
+        for _ in 0..count {
+            specs.push(WorkerSpec {
+                id: specs.len() as u32,
+                role,
+                host: host.cloned(),
+            });
+        }
+    };
+
+    // Subscribers first so they are listening before publishing starts
+    add(WorkerRole::Subscriber, None, options.subscribers);
+    if let Some(remote) = &options.remote {
+        add(
+            WorkerRole::Subscriber,
+            Some(remote),
+            options.remote_subscribers.unwrap_or(1),
+        );
+        add(
+            WorkerRole::Publisher,
+            Some(remote),
+            options.remote_publishers,
+        );
+    }
+    add(WorkerRole::Publisher, None, options.publishers);
+    specs
+}
+
+fn spawn_worker(options: &Options, spec: &WorkerSpec) -> Result<Child, String> {
+    let exe = env::current_exe().map_err(|e| format!("cannot locate executable: {}", e))?;
+    let args = options.config.worker_args(spec);
+
+    let mut cmd = match &spec.host {
+        None => {
+            let mut cmd = Command::new(&exe);
+            cmd.args(&args);
+            cmd
+        }
+        Some(host) => {
+            let remote_exe = options
+                .remote_exe
+                .clone()
+                .unwrap_or_else(|| exe.to_string_lossy().into_owned());
+            let mut cmd = Command::new("ssh");
+            cmd.args(["-o", "BatchMode=yes", host])
+                .arg(remote_command_line(&remote_exe, &args));
+            cmd
+        }
+    };
+
+    cmd.stdout(Stdio::piped())
+        .stderr(Stdio::inherit())
+        .spawn()
+        .map_err(|e| format!("failed to spawn worker {}: {}", spec.id, e))
+}
+
+fn run_coordinator(args: &[String]) -> Result<(), String> {
+    let options = parse_options(args)?;
+    let specs = worker_specs(&options);
+    if !specs.iter().any(|s| s.role == WorkerRole::Publisher)
+        || !specs.iter().any(|s| s.role == WorkerRole::Subscriber)
+    {
+        return Err("need at least one publisher and one subscriber".to_string());
+    }
+
+    println!("\n{}", "═".repeat(80).bright_cyan().bold());
+    println!("{}", "  HORUS DISTRIBUTED BENCHMARK".bright_cyan().bold());
+    println!("{}", "═".repeat(80).bright_cyan().bold());
+    println!("  Data topic:    {}", options.config.data_topic);
+    println!("  Control topic: {}", options.config.control_topic);
+    println!(
+        "  Rate:          {}",
+        match options.config.rate_hz {
+            0 => "unthrottled".to_string(),
+            hz => format!("{} Hz per publisher", hz),
+        }
+    );
+    println!("  Duration:      {}s", options.duration.as_secs());
+
+    // Open the control topic before any worker can announce itself
+    let control = Hub::<ControlMessage>::new(&options.config.control_topic)
+        .map_err(|e| format!("failed to open control topic: {}", e))?;
+
+    let mut children = Vec::new();
+    for spec in &specs {
+        println!(
+            "  {} worker {} ({}) on {}",
+            "Spawning".bright_yellow(),
+            spec.id,
+            spec.role.as_str(),
+            spec.host.as_deref().unwrap_or("localhost")
+        );
+        match spawn_worker(&options, spec) {
+            Ok(child) => children.push(child),
+            Err(e) => {
+                kill_all(&mut children);
+                return Err(e);
+            }
+        }
+    }
+
+    // Collect report lines in the background so pipes never fill up
+    let readers: Vec<_> = children
+        .iter_mut()
+        .map(|child| {
+            let stdout = child.stdout.take().expect("worker stdout is piped");
+            std::thread::spawn(move || {
+                BufReader::new(stdout)
+                    .lines()
+                    .map_while(Result::ok)
+                    .find_map(|line| WorkerReport::from_line(&line))
+            })
+        })
+        .collect();
+
+    // Wait until every worker is ready
+    let run_id = now_ns();
+    let mut ready = vec![false; specs.len()];
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while ready.iter().any(|r| !r) {
+        if Instant::now() > deadline {
+            kill_all(&mut children);
+            let missing: Vec<String> = ready
+                .iter()
+                .enumerate()
+                .filter(|(_, r)| !**r)
+                .map(|(id, _)| id.to_string())
+                .collect();
+            return Err(format!("workers not ready: {}", missing.join(", ")));
+        }
+        while let Some(msg) = control.recv(&mut None) {
+            if let ControlMessage::Ready { worker } = msg {
+                if let Some(flag) = ready.get_mut(worker as usize) {
+                    *flag = true;
+                }
+            }
+        }
+        std::thread::sleep(CONTROL_INTERVAL);
+    }
+    println!("\n  {} all {} workers ready", "[OK]".green(), specs.len());
+
+    // Run
+    println!("  {} run {}", "Starting".bright_green(), run_id);
+    let started = Instant::now();
+    while started.elapsed() < options.duration {
+        let _ = control.send(ControlMessage::Start { run_id }, &mut None);
+        std::thread::sleep(CONTROL_INTERVAL);
+    }
+
+    // Stop and wait for the workers to report
+    println!("  {} run {}", "Stopping".bright_yellow(), run_id);
+    let deadline = Instant::now() + options.config.timeout;
+    while children
+        .iter_mut()
+        .any(|c| matches!(c.try_wait(), Ok(None)))
+    {
+        if Instant::now() > deadline {
+            kill_all(&mut children);
+            break;
+        }
+        let _ = control.send(ControlMessage::Stop { run_id }, &mut None);
+        std::thread::sleep(CONTROL_INTERVAL);
+    }
+
+    let mut reports = Vec::new();
+    for (spec, reader) in specs.iter().zip(readers) {
+        match reader.join().ok().flatten() {
+            Some(report) => reports.push(report),
+            None => eprintln!(
+                "{} worker {} did not report",
+                "WARNING:".bright_yellow(),
+                spec.id
+            ),
+        }
+    }
+
+    let report = aggregate(run_id, reports);
+    print_report(&report);
+
+    if let Some(path) = &options.output {
+        let json = serde_json::to_string_pretty(&report).map_err(|e| e.to_string())?;
+        std::fs::write(path, json).map_err(|e| format!("failed to write {}: {}", path, e))?;
+        println!("\n  Report written to {}", path);
+    }
+    Ok(())
+}
+
+fn kill_all(children: &mut [Child]) {
+    for child in children {
+        let _ = child.kill();
+        let _ = child.wait();
+    }
+}
+
+fn print_report(report: &AggregateReport) {
+    println!("\n{}", "Results:".bright_yellow());
+    println!("  Hosts:       {}", report.hosts.join(", "));
+    println!(
+        "  Workers:     {} publishers, {} subscribers",
+        report.publishers, report.subscribers
+    );
+    println!("  Sent:        {}", report.sent);
+    println!("  Received:    {}", report.received);
+    println!(
+        "  Lost:        {} ({:.3}%)",
+        report.lost, report.loss_percent
+    );
+    println!("  Throughput:  {:.0} msg/s", report.throughput);
+
+    println!(
+        "\n  {:<8} {:<12} {:<20} {:>10} {:>10} {:>8}",
+        "Worker", "Role", "Host", "Sent", "Received", "Lost"
+    );
+    for w in &report.workers {
+        println!(
+            "  {:<8} {:<12} {:<20} {:>10} {:>10} {:>8}",
+            w.worker,
+            w.role.as_str(),
+            w.hostname,
+            w.sent,
+            w.received,
+            w.lost
+        );
+    }
+
+    match &report.latency {
+        Some(stats) => println!("{}", stats.format_table()),
+        None => println!("\n  No latency samples collected"),
+    }
+}
+
+// ============================================================================
+// Worker
+// ============================================================================
+
+fn run_worker(args: &[String]) -> Result<(), String> {
+    let mut id = None;
+    let mut role = None;
+    let mut config = RunConfig::default();
+
+    let mut i = 0;
+    while i < args.len() {
+        let flag = args[i].clone();
+        let value = flag_value(args, &mut i)?;
+        match flag.as_str() {
+            "--id" => id = Some(parse_num::<u32>(value, &flag)?),
+            "--role" => role = Some(value.parse::<WorkerRole>()?),
+            "--data" => config.data_topic = value.to_string(),
+            "--control" => config.control_topic = value.to_string(),
+            "--rate" => config.rate_hz = parse_num(value, &flag)?,
+            "--timeout" => config.timeout = Duration::from_secs(parse_num(value, &flag)?),
+            other => return Err(format!("unknown worker option '{}'", other)),
+        }
+        i += 1;
+    }
+    let id = id.ok_or("worker needs --id")?;
+    let role = role.ok_or("worker needs --role")?;
+
+    let control = Hub::<ControlMessage>::new(&config.control_topic)
+        .map_err(|e| format!("worker {}: control topic: {}", id, e))?;
+    let data = Hub::<BenchSample>::new(&config.data_topic)
+        .map_err(|e| format!("worker {}: data topic: {}", id, e))?;
+
+    // Announce until the run starts
+    let deadline = Instant::now() + config.timeout;
+    let mut last_announce = Instant::now() - CONTROL_INTERVAL;
+    let run_id = loop {
+        if Instant::now() > deadline {
+            return Err(format!("worker {}: run never started", id));
+        }
+        if last_announce.elapsed() >= CONTROL_INTERVAL {
+            let _ = control.send(ControlMessage::Ready { worker: id }, &mut None);
+            last_announce = Instant::now();
+        }
+        if let Some(ControlMessage::Start { run_id }) = control.recv(&mut None) {
+            break run_id;
+        }
+        // Drop anything published before the run
+        while data.recv(&mut None).is_some() {}
+        std::thread::sleep(Duration::from_millis(1));
+    };
+
+    let stopped = |control: &Hub<ControlMessage>| {
+        std::iter::from_fn(|| control.recv(&mut None))
+            .any(|msg| msg == ControlMessage::Stop { run_id })
+    };
+
+    let started = Instant::now();
+    let mut report = WorkerReport {
+        worker: id,
+        role,
+        hostname: hostname(),
+        run_id,
+        sent: 0,
+        received: 0,
+        lost: 0,
+        duration: Duration::ZERO,
+        latencies_ns: Vec::new(),
+    };
+
+    match role {
+        WorkerRole::Publisher => {
+            let period = match config.rate_hz {
+                0 => Duration::ZERO,
+                hz => Duration::from_secs_f64(1.0 / hz as f64),
+            };
+            let mut next = Instant::now();
+            let mut last_check = Instant::now();
+            loop {
+                if data
+                    .send(BenchSample::new(id, report.sent), &mut None)
+                    .is_ok()
+                {
+                    report.sent += 1;
+                }
+                if last_check.elapsed() >= Duration::from_millis(10) {
+                    if stopped(&control) || Instant::now() > deadline {
+                        break;
+                    }
+                    last_check = Instant::now();
+                }
+                if !period.is_zero() {
+                    next += period;
+                    if let Some(wait) = next.checked_duration_since(Instant::now()) {
+                        std::thread::sleep(wait);
+                    }
+                }
+            }
+        }
+        WorkerRole::Subscriber => {
+            let mut tracker = SequenceTracker::default();
+            let mut stop_at: Option<Instant> = None;
+            let mut last_check = Instant::now();
+            loop {
+                match data.recv(&mut None) {
+                    Some(sample) => {
+                        report
+                            .latencies_ns
+                            .push(now_ns().saturating_sub(sample.sent_ns));
+                        tracker.record(sample.publisher, sample.sequence);
+                    }
+                    None => std::hint::spin_loop(),
+                }
+                if last_check.elapsed() >= Duration::from_millis(10) {
+                    if stop_at.is_none() && (stopped(&control) || Instant::now() > deadline) {
+                        stop_at = Some(Instant::now() + DRAIN_GRACE);
+                    }
+                    if stop_at.is_some_and(|at| Instant::now() > at) {
+                        break;
+                    }
+                    last_check = Instant::now();
+                }
+            }
+            report.received = tracker.received();
+            report.lost = tracker.lost();
+            report.latencies_ns = decimate(report.latencies_ns, MAX_REPORTED_LATENCIES);
+        }
+    }
+
+    report.duration = started.elapsed();
+    println!("{}", report.to_line());
+    Ok(())
+}
+
+fn hostname() -> String {
+    std::fs::read_to_string("/etc/hostname")
+        .map(|s| s.trim().to_string())
+        .ok()
+        .filter(|s| !s.is_empty())
+        .or_else(|| env::var("HOSTNAME").ok())
+        .unwrap_or_else(|| "localhost".to_string())
+}
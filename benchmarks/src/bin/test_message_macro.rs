@@ -28,6 +28,23 @@ message! {
     }
 }
 
+// Test enum-style message
+message! {
+    enum Mode {
+        Idle,
+        Teleop,
+        Auto = 5,
+    }
+}
+
+// Test enum and optional fields in a message
+message! {
+    DriveStatus {
+        mode: Mode,
+        target_speed: Optional<f32>,
+    }
+}
+
 fn main() {
     println!("Testing message! macro...\n");
 
@@ -66,7 +83,23 @@ fn main() {
     assert_eq!(status.x, 1.5);
     assert_eq!(status.battery, 85);
 
-    // Test 6: Hub compatibility (most important!)
+    // Test 6: Enum messages convert to and from u8
+    assert_eq!(Mode::default(), Mode::Idle);
+    assert_eq!(Mode::Teleop.as_u8(), 1);
+    assert_eq!(Mode::from_u8(5), Some(Mode::Auto));
+    assert_eq!(Mode::try_from(2u8), Err(2));
+    assert_eq!(u8::from(Mode::Auto), 5);
+    println!(" Mode enum round-trips through u8");
+
+    let drive = DriveStatus {
+        mode: Mode::Teleop,
+        target_speed: Optional::some(0.5),
+    };
+    assert_eq!(drive.target_speed.value(), Some(0.5));
+    assert_eq!(std::mem::size_of::<DriveStatus>(), 12);
+    println!(" Created DriveStatus with enum and optional fields");
+
+    // Test 7: Hub compatibility (most important!)
     println!("\nTesting Hub compatibility...");
 
     let hub_pos = Hub::<Position>::new("test/position").expect("Failed to create Hub<Position>");
@@ -76,7 +109,7 @@ fn main() {
         Hub::<RobotStatus>::new("test/status").expect("Failed to create Hub<RobotStatus>");
     println!(" Created Hub<RobotStatus>");
 
-    // Test 7: Send without logging (ctx = None)
+    // Test 8: Send without logging (ctx = None)
     hub_pos
         .send(Position(3.0, 4.0), &mut None)
         .expect("Failed to send Position");
@@ -95,7 +128,7 @@ fn main() {
         .expect("Failed to send RobotStatus");
     println!(" Sent RobotStatus message (no logging)");
 
-    // Test 8: Send with logging (ctx = Some)
+    // Test 9: Send with logging (ctx = Some)
     let mut ctx_info = NodeInfo::new("test_node".to_string(), true);
     let mut ctx = Some(&mut ctx_info);
     hub_pos
@@ -103,7 +136,7 @@ fn main() {
         .expect("Failed to send with logging");
     println!(" Sent Position message WITH logging");
 
-    // Test 9: Receive messages
+    // Test 10: Receive messages
     if let Some(received) = hub_pos.recv(&mut None) {
         println!(" Received Position: ({}, {})", received.0, received.1);
     }
@@ -112,6 +145,7 @@ fn main() {
     println!("\nSummary:");
     println!("  - Tuple-style messages work: ");
     println!("  - Struct-style messages work: ");
+    println!("  - Enum and optional fields work: ");
     println!("  - LogSummary auto-implemented: ");
     println!("  - Hub<T> compatibility: ");
     println!("  - Send/recv operations: ");
@@ -13,6 +13,7 @@
 #![allow(unused_variables)]
 #![allow(unused_mut)]
 
+pub mod orchestrator;
 pub mod visualization;
 
 use serde::{Deserialize, Serialize};
@@ -0,0 +1,429 @@
+//! Multi-Process Benchmark Orchestration
+//!
+//! Building blocks for the `bench_orchestrator` binary, which runs publisher
+//! and subscriber workers as separate processes (locally or over SSH on other
+//! hosts), drives them through a shared control topic and aggregates their
+//! results into a single report.
+//!
+//! ## Protocol
+//!
+//! 1. The coordinator opens the control topic and spawns the workers.
+//! 2. Each worker announces itself with [`ControlMessage::Ready`] until it
+//!    sees [`ControlMessage::Start`] for the run.
+//! 3. Publishers send [`BenchSample`]s on the data topic until
+//!    [`ControlMessage::Stop`]; subscribers keep draining for a short grace
+//!    period afterwards.
+//! 4. Every worker prints one [`WorkerReport`] line on stdout, which the
+//!    coordinator collects (also through SSH) and merges with [`aggregate`].
+//!
+//! Control messages are re-sent until acknowledged by the next phase, so
+//! lossy network backends (e.g. multicast) can carry the control topic.
+//!
+//! One-way latencies are computed from wall-clock timestamps; across hosts
+//! they are only meaningful with synchronized clocks (PTP or chrony).
+
+use crate::BenchmarkResult;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Default control topic shared by the coordinator and all workers
+pub const DEFAULT_CONTROL_TOPIC: &str = "bench.control";
+
+/// Default data topic carrying benchmark samples
+pub const DEFAULT_DATA_TOPIC: &str = "bench.data";
+
+/// Prefix of the stdout line carrying a worker's JSON report
+pub const REPORT_PREFIX: &str = "HORUS_BENCH_REPORT ";
+
+/// Payload bytes carried by every sample
+pub const SAMPLE_PAYLOAD_BYTES: usize = 256;
+
+/// Maximum latency samples a subscriber reports (longer runs are decimated)
+pub const MAX_REPORTED_LATENCIES: usize = 100_000;
+
+/// Messages on the control topic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ControlMessage {
+    /// A worker is connected and waiting for the run to start
+    Ready { worker: u32 },
+    /// Start the run
+    Start { run_id: u64 },
+    /// Stop publishing and report
+    Stop { run_id: u64 },
+}
+
+impl horus::core::LogSummary for ControlMessage {
+    fn log_summary(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+/// Message sent on the data topic
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BenchSample {
+    pub publisher: u32,
+    pub sequence: u64,
+    /// Wall-clock send time (ns since the Unix epoch)
+    pub sent_ns: u64,
+    #[serde(with = "serde_arrays")]
+    pub payload: [u8; SAMPLE_PAYLOAD_BYTES],
+}
+
+impl BenchSample {
+    pub fn new(publisher: u32, sequence: u64) -> Self {
+        Self {
+            publisher,
+            sequence,
+            sent_ns: now_ns(),
+            payload: [(sequence & 0xFF) as u8; SAMPLE_PAYLOAD_BYTES],
+        }
+    }
+}
+
+impl horus::core::LogSummary for BenchSample {
+    fn log_summary(&self) -> String {
+        format!("BenchSample(pub:{}, seq:{})", self.publisher, self.sequence)
+    }
+}
+
+/// Wall-clock time in nanoseconds since the Unix epoch
+pub fn now_ns() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+/// What a worker process does
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkerRole {
+    Publisher,
+    Subscriber,
+}
+
+impl WorkerRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkerRole::Publisher => "publisher",
+            WorkerRole::Subscriber => "subscriber",
+        }
+    }
+}
+
+impl std::str::FromStr for WorkerRole {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, String> {
+        match s {
+            "pub" | "publisher" => Ok(WorkerRole::Publisher),
+            "sub" | "subscriber" => Ok(WorkerRole::Subscriber),
+            other => Err(format!("unknown worker role '{}'", other)),
+        }
+    }
+}
+
+/// Where and how a worker runs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkerSpec {
+    pub id: u32,
+    pub role: WorkerRole,
+    /// SSH destination (`user@host`), or `None` to run locally
+    pub host: Option<String>,
+}
+
+/// Benchmark parameters passed to every worker
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunConfig {
+    pub data_topic: String,
+    pub control_topic: String,
+    /// Publish rate per publisher (0 = as fast as possible)
+    pub rate_hz: u32,
+    /// Longest a worker waits for the run to start and stop
+    pub timeout: Duration,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        Self {
+            data_topic: DEFAULT_DATA_TOPIC.to_string(),
+            control_topic: DEFAULT_CONTROL_TOPIC.to_string(),
+            rate_hz: 1000,
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RunConfig {
+    /// Command-line arguments that start `spec` as a worker
+    pub fn worker_args(&self, spec: &WorkerSpec) -> Vec<String> {
+        vec![
+            "worker".to_string(),
+            "--id".to_string(),
+            spec.id.to_string(),
+            "--role".to_string(),
+            spec.role.as_str().to_string(),
+            "--data".to_string(),
+            self.data_topic.clone(),
+            "--control".to_string(),
+            self.control_topic.clone(),
+            "--rate".to_string(),
+            self.rate_hz.to_string(),
+            "--timeout".to_string(),
+            self.timeout.as_secs().to_string(),
+        ]
+    }
+}
+
+/// Quote an argument for a POSIX shell (SSH runs remote commands via a shell)
+pub fn shell_quote(arg: &str) -> String {
+    let safe = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:@=,".contains(c));
+    if safe {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+/// Remote command line running `program` with `args` over SSH
+pub fn remote_command_line(program: &str, args: &[String]) -> String {
+    std::iter::once(program)
+        .chain(args.iter().map(String::as_str))
+        .map(shell_quote)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Result of one worker, printed as a single JSON line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerReport {
+    pub worker: u32,
+    pub role: WorkerRole,
+    pub hostname: String,
+    pub run_id: u64,
+    /// Samples published (publishers)
+    pub sent: u64,
+    /// Samples received (subscribers)
+    pub received: u64,
+    /// Samples missing from the received sequences (subscribers)
+    pub lost: u64,
+    /// Time between start and stop as seen by the worker
+    pub duration: Duration,
+    /// One-way latencies in ns (subscribers, possibly decimated)
+    pub latencies_ns: Vec<u64>,
+}
+
+impl WorkerReport {
+    /// Render as a report line for stdout
+    pub fn to_line(&self) -> String {
+        format!(
+            "{}{}",
+            REPORT_PREFIX,
+            serde_json::to_string(self).unwrap_or_default()
+        )
+    }
+
+    /// Parse a line printed by `to_line`; other output is ignored
+    pub fn from_line(line: &str) -> Option<Self> {
+        let json = line.trim().strip_prefix(REPORT_PREFIX)?;
+        serde_json::from_str(json).ok()
+    }
+}
+
+/// Tracks the sequences received from each publisher
+#[derive(Debug, Default)]
+pub struct SequenceTracker {
+    /// publisher -> (first sequence, highest sequence, count)
+    publishers: HashMap<u32, (u64, u64, u64)>,
+}
+
+impl SequenceTracker {
+    pub fn record(&mut self, publisher: u32, sequence: u64) {
+        let entry = self
+            .publishers
+            .entry(publisher)
+            .or_insert((sequence, sequence, 0));
+        entry.0 = entry.0.min(sequence);
+        entry.1 = entry.1.max(sequence);
+        entry.2 += 1;
+    }
+
+    pub fn received(&self) -> u64 {
+        self.publishers.values().map(|&(_, _, count)| count).sum()
+    }
+
+    /// Gaps between the first and highest sequence seen per publisher
+    pub fn lost(&self) -> u64 {
+        self.publishers
+            .values()
+            .map(|&(first, last, count)| (last - first + 1).saturating_sub(count))
+            .sum()
+    }
+}
+
+/// Keep at most `max` evenly spaced samples
+pub fn decimate(samples: Vec<u64>, max: usize) -> Vec<u64> {
+    if samples.len() <= max || max == 0 {
+        return samples;
+    }
+    let step = samples.len().div_ceil(max);
+    samples.into_iter().step_by(step).collect()
+}
+
+/// Combined results of a run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregateReport {
+    pub run_id: u64,
+    pub publishers: usize,
+    pub subscribers: usize,
+    pub hosts: Vec<String>,
+    pub sent: u64,
+    pub received: u64,
+    pub lost: u64,
+    /// Lost samples as a percentage of expected deliveries
+    pub loss_percent: f64,
+    /// Received samples per second, over all subscribers
+    pub throughput: f64,
+    /// Latency distribution over all subscribers (`None` without samples)
+    pub latency: Option<crate::Statistics>,
+    pub workers: Vec<WorkerReport>,
+}
+
+/// Merge worker reports into one report
+pub fn aggregate(run_id: u64, workers: Vec<WorkerReport>) -> AggregateReport {
+    let count = |role| workers.iter().filter(|w| w.role == role).count();
+    let publishers = count(WorkerRole::Publisher);
+    let subscribers = count(WorkerRole::Subscriber);
+
+    let mut hosts: Vec<String> = workers.iter().map(|w| w.hostname.clone()).collect();
+    hosts.sort();
+    hosts.dedup();
+
+    let sent: u64 = workers.iter().map(|w| w.sent).sum();
+    let received: u64 = workers.iter().map(|w| w.received).sum();
+    let lost: u64 = workers.iter().map(|w| w.lost).sum();
+    let loss_percent = if received + lost > 0 {
+        lost as f64 * 100.0 / (received + lost) as f64
+    } else {
+        0.0
+    };
+
+    let subscriber_secs: f64 = workers
+        .iter()
+        .filter(|w| w.role == WorkerRole::Subscriber)
+        .map(|w| w.duration.as_secs_f64())
+        .fold(0.0, f64::max);
+    let throughput = if subscriber_secs > 0.0 {
+        received as f64 / subscriber_secs
+    } else {
+        0.0
+    };
+
+    let latencies: Vec<Duration> = workers
+        .iter()
+        .flat_map(|w| w.latencies_ns.iter().copied())
+        .map(Duration::from_nanos)
+        .collect();
+    let latency = (!latencies.is_empty()).then(|| {
+        BenchmarkResult {
+            name: "orchestrated".to_string(),
+            framework: "HORUS".to_string(),
+            message_size: std::mem::size_of::<BenchSample>(),
+            iterations: latencies.len(),
+            total_duration: Duration::from_secs_f64(subscriber_secs),
+            latencies,
+            throughput,
+            cpu_usage: 0.0,
+            memory_usage: 0,
+        }
+        .statistics()
+    });
+
+    AggregateReport {
+        run_id,
+        publishers,
+        subscribers,
+        hosts,
+        sent,
+        received,
+        lost,
+        loss_percent,
+        throughput,
+        latency,
+        workers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(worker: u32, role: WorkerRole, received: u64, latencies: Vec<u64>) -> WorkerReport {
+        WorkerReport {
+            worker,
+            role,
+            hostname: format!("host{}", worker % 2),
+            run_id: 7,
+            sent: if role == WorkerRole::Publisher {
+                100
+            } else {
+                0
+            },
+            received,
+            lost: if role == WorkerRole::Subscriber {
+                100 - received
+            } else {
+                0
+            },
+            duration: Duration::from_secs(2),
+            latencies_ns: latencies,
+        }
+    }
+
+    #[test]
+    fn test_report_line_roundtrip_and_aggregate() {
+        let sub = report(1, WorkerRole::Subscriber, 90, vec![1_000, 2_000, 3_000]);
+        let line = sub.to_line();
+        let parsed = WorkerReport::from_line(&line).unwrap();
+        assert_eq!(parsed.received, 90);
+        assert!(WorkerReport::from_line("Producer: Hub created").is_none());
+
+        let report = aggregate(7, vec![report(0, WorkerRole::Publisher, 0, vec![]), parsed]);
+        assert_eq!((report.publishers, report.subscribers), (1, 1));
+        assert_eq!(report.hosts, vec!["host0", "host1"]);
+        assert_eq!((report.sent, report.received, report.lost), (100, 90, 10));
+        assert!((report.loss_percent - 10.0).abs() < 1e-9);
+        assert!((report.throughput - 45.0).abs() < 1e-9);
+        assert_eq!(report.latency.unwrap().median, Duration::from_nanos(2_000));
+    }
+
+    #[test]
+    fn test_sequence_tracking_and_quoting() {
+        let mut tracker = SequenceTracker::default();
+        for seq in [0, 1, 2, 5, 6] {
+            tracker.record(1, seq);
+        }
+        tracker.record(2, 10);
+        assert_eq!(tracker.received(), 6);
+        assert_eq!(tracker.lost(), 2);
+
+        assert_eq!(decimate((0..10).collect(), 5), vec![0, 2, 4, 6, 8]);
+
+        let config = RunConfig {
+            data_topic: "bench data@*".to_string(),
+            ..RunConfig::default()
+        };
+        let spec = WorkerSpec {
+            id: 3,
+            role: WorkerRole::Subscriber,
+            host: Some("robot@10.0.0.2".to_string()),
+        };
+        let line = remote_command_line("/opt/horus/bench_orchestrator", &config.worker_args(&spec));
+        assert!(line.starts_with("/opt/horus/bench_orchestrator worker --id 3 --role subscriber"));
+        assert!(line.contains("--data 'bench data@*'"));
+        assert_eq!(shell_quote("it's"), r"'it'\''s'");
+    }
+}
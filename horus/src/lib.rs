@@ -52,12 +52,15 @@ pub mod prelude {
     // Core Node Types
     // ============================================
     pub use horus_core::core::node::NodeConfig;
-    pub use horus_core::core::{LogSummary, Node, NodeInfo, NodeInfoExt, NodeState};
+    pub use horus_core::core::{
+        BoundedVec, LogSummary, MessageFingerprint, Node, NodeInfo, NodeInfoExt, NodeState,
+        Optional, TraceId, Traced,
+    };
 
     // ============================================
     // Communication (IPC)
     // ============================================
-    pub use horus_core::communication::{Hub, Link};
+    pub use horus_core::communication::{Hub, Link, Topic};
 
     // ============================================
     // Scheduling
@@ -108,7 +111,7 @@ pub mod prelude {
     // ============================================
     pub use horus_core::scheduling::{
         NodeRecorder, NodeRecording, NodeReplayer, NodeTickSnapshot, RecordingConfig,
-        RecordingManager, SchedulerRecording,
+        RecordingManager, RecordingReader, SchedulerRecording,
     };
 
     // ============================================
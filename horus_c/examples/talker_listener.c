@@ -0,0 +1,63 @@
+/*
+ * Talker/listener over a HORUS topic.
+ *
+ *   cargo build --release -p horus_c
+ *   cc examples/talker_listener.c -Iinclude -L../target/release -lhorus_c \
+ *      -o talker_listener
+ *   LD_LIBRARY_PATH=../target/release ./talker_listener
+ */
+#include <stdio.h>
+#include <string.h>
+
+#include "horus.h"
+
+typedef struct {
+    horus_hub *hub;
+    unsigned count;
+} talker_state;
+
+static void talker_tick(horus_node_ctx *ctx, void *user_data) {
+    talker_state *state = user_data;
+    char message[64];
+    int len = snprintf(message, sizeof message, "hello %u", state->count++);
+    if (horus_hub_send(state->hub, message, (size_t)len, ctx) != HORUS_OK) {
+        horus_log_warning(ctx, horus_last_error());
+    }
+}
+
+static void listener_tick(horus_node_ctx *ctx, void *user_data) {
+    horus_hub *hub = user_data;
+    char buffer[256];
+    size_t len = 0;
+    while (horus_hub_recv(hub, buffer, sizeof buffer - 1, &len, ctx) == HORUS_OK) {
+        buffer[len] = '\0';
+        horus_log_info(ctx, buffer);
+    }
+}
+
+int main(void) {
+    talker_state talker = {horus_hub_create("chatter"), 0};
+    horus_hub *listener_hub = horus_hub_create("chatter");
+    if (!talker.hub || !listener_hub) {
+        fprintf(stderr, "hub: %s\n", horus_last_error());
+        return 1;
+    }
+
+    horus_scheduler *scheduler = horus_scheduler_create();
+    horus_node *talker_node = horus_node_create("talker", talker_tick, &talker);
+    horus_node *listener_node = horus_node_create("listener", listener_tick, listener_hub);
+    horus_node_set_rate(talker_node, 10.0);
+
+    horus_scheduler_add(scheduler, talker_node, 0, true);
+    horus_scheduler_add(scheduler, listener_node, 1, true);
+
+    int status = horus_scheduler_run_for(scheduler, 3.0);
+    if (status != HORUS_OK) {
+        fprintf(stderr, "scheduler: %s\n", horus_last_error());
+    }
+
+    horus_scheduler_destroy(scheduler);
+    horus_hub_destroy(listener_hub);
+    horus_hub_destroy(talker.hub);
+    return status == HORUS_OK ? 0 : 1;
+}
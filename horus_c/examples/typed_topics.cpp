@@ -0,0 +1,50 @@
+/*
+ * Typed topics and C++ nodes.
+ *
+ *   cargo build --release -p horus_c
+ *   c++ -std=c++17 examples/typed_topics.cpp -Iinclude -L../target/release -lhorus_c \
+ *       -o typed_topics
+ *   LD_LIBRARY_PATH=../target/release ./typed_topics
+ */
+#include <chrono>
+#include <string>
+
+#include "horus.hpp"
+
+struct Pose {
+    double x;
+    double y;
+    double theta;
+};
+HORUS_MESSAGE(Pose, "Pose{x:f64,y:f64,theta:f64}");
+
+class Odometry : public horus::Node {
+  public:
+    Odometry() : horus::Node("odometry"), pose_("robot_pose") {}
+
+    void tick(horus::NodeContext &ctx) override {
+        pose_.send(current_, &ctx);
+        current_.x += 0.1;
+    }
+
+    double rate_hz() const override { return 20.0; }
+
+  private:
+    horus::Topic<Pose> pose_;
+    Pose current_{0.0, 0.0, 0.0};
+};
+
+int main() {
+    horus::Topic<Pose> poses("robot_pose");
+
+    horus::Scheduler scheduler;
+    scheduler.add(std::make_unique<Odometry>(), 0, true);
+    scheduler.add("pose_logger", [&](horus::NodeContext &ctx) {
+        while (auto pose = poses.recv(&ctx)) {
+            ctx.log_info("x=" + std::to_string(pose->x) + " y=" + std::to_string(pose->y));
+        }
+    }, 1);
+
+    scheduler.run_for(std::chrono::seconds(3));
+    return 0;
+}
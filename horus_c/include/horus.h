@@ -0,0 +1,117 @@
+/*
+ * HORUS C API
+ *
+ * Publish and subscribe to HORUS topics and run nodes in a HORUS scheduler
+ * from C and C++. Link against libhorus_c.so or libhorus_c.a.
+ *
+ * Conventions:
+ *   - Functions returning int return HORUS_OK or a negative HORUS_ERR_* code;
+ *     constructors return NULL on failure.
+ *   - horus_last_error() describes the last failure on the calling thread.
+ *   - Objects are freed with their *_destroy function. A node passed to
+ *     horus_scheduler_add() belongs to the scheduler and must not be destroyed.
+ *   - Message payloads are opaque bytes (at most horus_max_message_size()).
+ *     Python nodes on the same topic expect MessagePack-encoded payloads.
+ */
+#ifndef HORUS_H
+#define HORUS_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Status codes */
+#define HORUS_OK 0
+#define HORUS_ERR_INVALID_ARG -1      /* NULL or invalid argument */
+#define HORUS_ERR_NO_MESSAGE -2       /* nothing waiting on the hub */
+#define HORUS_ERR_BUFFER_TOO_SMALL -3 /* message kept for the next recv */
+#define HORUS_ERR_TOO_LARGE -4        /* payload over the size limit */
+#define HORUS_ERR_SEND_FAILED -5      /* buffer full or disconnected */
+#define HORUS_ERR_RUNTIME -6          /* scheduler or node failure */
+#define HORUS_ERR_INTERNAL -7         /* panic inside the library */
+
+typedef struct horus_hub horus_hub;
+typedef struct horus_node horus_node;
+typedef struct horus_scheduler horus_scheduler;
+/* Node context passed to callbacks; valid only during the callback */
+typedef struct horus_node_ctx horus_node_ctx;
+
+/* Called on every tick */
+typedef void (*horus_tick_fn)(horus_node_ctx *ctx, void *user_data);
+/* Init/shutdown callback; return 0 on success */
+typedef int (*horus_lifecycle_fn)(horus_node_ctx *ctx, void *user_data);
+
+/* ---- Library ---- */
+
+/* Last error on this thread, or NULL. Valid until the next failing call. */
+const char *horus_last_error(void);
+/* Library version, e.g. "0.1.7" */
+const char *horus_version(void);
+/* Largest message payload in bytes */
+size_t horus_max_message_size(void);
+
+/* ---- Hubs ---- */
+
+/* Create a hub on topic ("name" or "name@endpoint") with the default capacity */
+horus_hub *horus_hub_create(const char *topic);
+/* Create a hub holding up to capacity messages */
+horus_hub *horus_hub_create_with_capacity(const char *topic, size_t capacity);
+void horus_hub_destroy(horus_hub *hub);
+
+/* Publish len bytes. ctx is the callback context (for logging) or NULL. */
+int horus_hub_send(horus_hub *hub, const void *data, size_t len, horus_node_ctx *ctx);
+
+/*
+ * Receive the next message into buffer. On HORUS_OK, *len is the message size.
+ * Returns HORUS_ERR_NO_MESSAGE if nothing is waiting. If the buffer is too
+ * small, *len is set to the required size, HORUS_ERR_BUFFER_TOO_SMALL is
+ * returned and the message is kept for the next call. len may be NULL.
+ */
+int horus_hub_recv(horus_hub *hub, void *buffer, size_t capacity, size_t *len,
+                   horus_node_ctx *ctx);
+
+/* Topic name, valid as long as the hub */
+const char *horus_hub_topic(const horus_hub *hub);
+
+/* ---- Nodes ---- */
+
+/* Create a node calling tick(ctx, user_data) on every tick */
+horus_node *horus_node_create(const char *name, horus_tick_fn tick, void *user_data);
+/* Callback run once before the first tick */
+int horus_node_set_init(horus_node *node, horus_lifecycle_fn init);
+/* Callback run once when the scheduler stops */
+int horus_node_set_shutdown(horus_node *node, horus_lifecycle_fn shutdown);
+/* Tick rate in Hz (0 = the scheduler's rate) */
+int horus_node_set_rate(horus_node *node, double rate_hz);
+/* Free a node that was not added to a scheduler */
+void horus_node_destroy(horus_node *node);
+
+/* Log from a callback */
+int horus_log_info(horus_node_ctx *ctx, const char *message);
+int horus_log_warning(horus_node_ctx *ctx, const char *message);
+int horus_log_error(horus_node_ctx *ctx, const char *message);
+
+/* ---- Scheduler ---- */
+
+horus_scheduler *horus_scheduler_create(void);
+/* Add a node (ownership is taken). Lower priorities run first (0 = highest). */
+int horus_scheduler_add(horus_scheduler *scheduler, horus_node *node, uint32_t priority,
+                        bool logging);
+/* Run until Ctrl+C or horus_scheduler_stop() */
+int horus_scheduler_run(horus_scheduler *scheduler);
+/* Run for the given number of seconds, then shut the nodes down */
+int horus_scheduler_run_for(horus_scheduler *scheduler, double seconds);
+/* Ask a running scheduler to stop; safe from callbacks and other threads */
+int horus_scheduler_stop(horus_scheduler *scheduler);
+/* Free a scheduler and its nodes */
+void horus_scheduler_destroy(horus_scheduler *scheduler);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* HORUS_H */
@@ -0,0 +1,422 @@
+/*
+ * HORUS C++ API
+ *
+ * Header-only C++17 wrapper over horus.h: RAII hubs, typed topics checked by
+ * message fingerprint, and nodes run by the HORUS scheduler. Link against
+ * libhorus_c like a C program.
+ *
+ *   struct Pose { double x, y; };
+ *   HORUS_MESSAGE(Pose, "Pose{x:f64,y:f64}");
+ *
+ *   horus::Topic<Pose> pose("pose");
+ *   pose.send({1.0, 2.0});
+ *   if (auto p = pose.recv()) { ... }
+ *
+ * Errors from the C API are thrown as horus::Error. Exceptions thrown inside
+ * node callbacks are caught at the boundary and logged; they never unwind
+ * into the library.
+ */
+#ifndef HORUS_HPP
+#define HORUS_HPP
+
+#include <chrono>
+#include <cstdint>
+#include <cstring>
+#include <functional>
+#include <memory>
+#include <optional>
+#include <stdexcept>
+#include <string>
+#include <string_view>
+#include <type_traits>
+#include <utility>
+#include <vector>
+
+#include "horus.h"
+
+namespace horus {
+
+/* ---- Errors ---- */
+
+/* A failed C API call; code() is the HORUS_ERR_* status */
+class Error : public std::runtime_error {
+  public:
+    Error(int code, const std::string &message) : std::runtime_error(message), code_(code) {}
+
+    int code() const noexcept { return code_; }
+
+  private:
+    int code_;
+};
+
+/* A typed topic received a message of another type or layout */
+class TypeMismatch : public Error {
+  public:
+    explicit TypeMismatch(const std::string &message) : Error(HORUS_ERR_INVALID_ARG, message) {}
+};
+
+namespace detail {
+
+inline std::string last_error(const char *fallback) {
+    const char *message = horus_last_error();
+    return message ? message : fallback;
+}
+
+inline void check(int status, const char *what) {
+    if (status != HORUS_OK) {
+        throw Error(status, std::string(what) + ": " + last_error("unknown error"));
+    }
+}
+
+template <typename T> T *check_ptr(T *ptr, const char *what) {
+    if (!ptr) {
+        throw Error(HORUS_ERR_RUNTIME, std::string(what) + ": " + last_error("unknown error"));
+    }
+    return ptr;
+}
+
+struct HubDeleter {
+    void operator()(horus_hub *hub) const noexcept { horus_hub_destroy(hub); }
+};
+
+struct SchedulerDeleter {
+    void operator()(horus_scheduler *scheduler) const noexcept {
+        horus_scheduler_destroy(scheduler);
+    }
+};
+
+} // namespace detail
+
+inline std::string version() { return horus_version(); }
+
+inline std::size_t max_message_size() { return horus_max_message_size(); }
+
+/* ---- Message fingerprints ---- */
+
+/*
+ * 64-bit FNV-1a hash of a schema string, the same function as Rust's
+ * horus::core::schema_fingerprint. A schema written exactly like the
+ * SCHEMA of a #[derive(HorusMessage)] type gets the same fingerprint.
+ */
+constexpr std::uint64_t schema_fingerprint(std::string_view schema) noexcept {
+    std::uint64_t hash = 0xcbf29ce484222325ull;
+    for (char c : schema) {
+        hash ^= static_cast<unsigned char>(c);
+        hash *= 0x100000001b3ull;
+    }
+    return hash;
+}
+
+/*
+ * Message types usable with Topic<T>. Specialize with HORUS_MESSAGE; T must
+ * be trivially copyable, since it is sent as its raw bytes.
+ */
+template <typename T> struct MessageTraits;
+
+#define HORUS_MESSAGE(Type, Schema)                                                       \
+    template <> struct horus::MessageTraits<Type> {                                       \
+        static_assert(std::is_trivially_copyable_v<Type>,                                  \
+                      #Type " must be trivially copyable to be sent as a HORUS message"); \
+        static constexpr std::string_view schema = Schema;                                 \
+        static constexpr std::uint64_t fingerprint = horus::schema_fingerprint(Schema);    \
+    }
+
+/* ---- Node context ---- */
+
+/* Context passed to node callbacks; only valid during the callback */
+class NodeContext {
+  public:
+    explicit NodeContext(horus_node_ctx *ctx) noexcept : ctx_(ctx) {}
+
+    void log_info(const std::string &message) const { horus_log_info(ctx_, message.c_str()); }
+    void log_warning(const std::string &message) const {
+        horus_log_warning(ctx_, message.c_str());
+    }
+    void log_error(const std::string &message) const { horus_log_error(ctx_, message.c_str()); }
+
+    horus_node_ctx *get() const noexcept { return ctx_; }
+
+  private:
+    horus_node_ctx *ctx_;
+};
+
+namespace detail {
+
+inline horus_node_ctx *raw(const NodeContext *ctx) noexcept { return ctx ? ctx->get() : nullptr; }
+
+} // namespace detail
+
+/* ---- Hubs ---- */
+
+/* Untyped hub carrying byte payloads; move-only, destroyed with its owner */
+class Hub {
+  public:
+    explicit Hub(const std::string &topic)
+        : hub_(detail::check_ptr(horus_hub_create(topic.c_str()), "horus_hub_create")) {}
+
+    Hub(const std::string &topic, std::size_t capacity)
+        : hub_(detail::check_ptr(horus_hub_create_with_capacity(topic.c_str(), capacity),
+                                 "horus_hub_create_with_capacity")) {}
+
+    /* Publish len bytes; false if the hub could not deliver the message */
+    bool send(const void *data, std::size_t len, const NodeContext *ctx = nullptr) {
+        int status = horus_hub_send(hub_.get(), data, len, detail::raw(ctx));
+        if (status == HORUS_ERR_SEND_FAILED) {
+            return false;
+        }
+        detail::check(status, "horus_hub_send");
+        return true;
+    }
+
+    bool send(const std::vector<std::uint8_t> &data, const NodeContext *ctx = nullptr) {
+        return send(data.data(), data.size(), ctx);
+    }
+
+    /* Receive the next message into data; false if nothing is waiting */
+    bool recv(std::vector<std::uint8_t> &data, const NodeContext *ctx = nullptr) {
+        if (data.capacity() == 0) {
+            data.reserve(max_message_size());
+        }
+        data.resize(data.capacity());
+        std::size_t len = 0;
+        int status = horus_hub_recv(hub_.get(), data.data(), data.size(), &len, detail::raw(ctx));
+        if (status == HORUS_ERR_BUFFER_TOO_SMALL) {
+            data.resize(len);
+            status = horus_hub_recv(hub_.get(), data.data(), data.size(), &len, detail::raw(ctx));
+        }
+        if (status == HORUS_ERR_NO_MESSAGE) {
+            data.clear();
+            return false;
+        }
+        detail::check(status, "horus_hub_recv");
+        data.resize(len);
+        return true;
+    }
+
+    std::optional<std::vector<std::uint8_t>> recv(const NodeContext *ctx = nullptr) {
+        std::vector<std::uint8_t> data;
+        if (!recv(data, ctx)) {
+            return std::nullopt;
+        }
+        return data;
+    }
+
+    std::string topic() const { return horus_hub_topic(hub_.get()); }
+
+    horus_hub *get() const noexcept { return hub_.get(); }
+
+  private:
+    std::unique_ptr<horus_hub, detail::HubDeleter> hub_;
+};
+
+/*
+ * Hub carrying one message type. Each payload is the type's 8-byte
+ * fingerprint (little-endian) followed by the raw bytes of T, so a process
+ * built with a different declaration of T is rejected with TypeMismatch
+ * instead of being misread.
+ */
+template <typename T> class Topic {
+  public:
+    using Traits = MessageTraits<T>;
+
+    static constexpr std::size_t header_size = sizeof(std::uint64_t);
+    static constexpr std::size_t message_size = header_size + sizeof(T);
+
+    explicit Topic(const std::string &topic) : hub_(topic) { check_size(); }
+
+    Topic(const std::string &topic, std::size_t capacity) : hub_(topic, capacity) {
+        check_size();
+    }
+
+    bool send(const T &message, const NodeContext *ctx = nullptr) {
+        unsigned char buffer[message_size];
+        write_fingerprint(buffer);
+        std::memcpy(buffer + header_size, &message, sizeof(T));
+        return hub_.send(buffer, sizeof buffer, ctx);
+    }
+
+    std::optional<T> recv(const NodeContext *ctx = nullptr) {
+        if (!hub_.recv(buffer_, ctx)) {
+            return std::nullopt;
+        }
+        if (buffer_.size() != message_size || read_fingerprint(buffer_.data()) != Traits::fingerprint) {
+            throw TypeMismatch("message on '" + hub_.topic() + "' is not a " +
+                               std::string(Traits::schema));
+        }
+        T message;
+        std::memcpy(&message, buffer_.data() + header_size, sizeof(T));
+        return message;
+    }
+
+    std::string topic() const { return hub_.topic(); }
+
+    Hub &hub() noexcept { return hub_; }
+
+  private:
+    void check_size() const {
+        if (message_size > max_message_size()) {
+            throw Error(HORUS_ERR_TOO_LARGE,
+                        std::string(Traits::schema) + " is larger than the message size limit");
+        }
+    }
+
+    static void write_fingerprint(unsigned char *out) noexcept {
+        for (std::size_t i = 0; i < header_size; ++i) {
+            out[i] = static_cast<unsigned char>(Traits::fingerprint >> (8 * i));
+        }
+    }
+
+    static std::uint64_t read_fingerprint(const unsigned char *in) noexcept {
+        std::uint64_t fingerprint = 0;
+        for (std::size_t i = 0; i < header_size; ++i) {
+            fingerprint |= static_cast<std::uint64_t>(in[i]) << (8 * i);
+        }
+        return fingerprint;
+    }
+
+    Hub hub_;
+    std::vector<std::uint8_t> buffer_;
+};
+
+/* ---- Nodes ---- */
+
+/*
+ * Base class for C++ nodes. Override tick() and optionally init(),
+ * shutdown() and rate_hz(). A node must outlive the scheduler running it.
+ */
+class Node {
+  public:
+    explicit Node(std::string name) : name_(std::move(name)) {}
+    virtual ~Node() = default;
+
+    Node(const Node &) = delete;
+    Node &operator=(const Node &) = delete;
+
+    virtual void init(NodeContext &) {}
+    virtual void tick(NodeContext &ctx) = 0;
+    virtual void shutdown(NodeContext &) {}
+    /* Tick rate in Hz, 0 for the scheduler's rate */
+    virtual double rate_hz() const { return 0.0; }
+
+    const std::string &name() const noexcept { return name_; }
+
+  private:
+    std::string name_;
+};
+
+/* Node built from a tick function */
+class FunctionNode : public Node {
+  public:
+    FunctionNode(std::string name, std::function<void(NodeContext &)> tick, double rate_hz = 0.0)
+        : Node(std::move(name)), tick_(std::move(tick)), rate_hz_(rate_hz) {}
+
+    void tick(NodeContext &ctx) override { tick_(ctx); }
+    double rate_hz() const override { return rate_hz_; }
+
+  private:
+    std::function<void(NodeContext &)> tick_;
+    double rate_hz_;
+};
+
+namespace detail {
+
+inline void report(horus_node_ctx *ctx, const Node &node, const char *stage, const char *what) {
+    std::string message = node.name() + ": exception in " + stage + ": " + what;
+    horus_log_error(ctx, message.c_str());
+}
+
+/* Trampolines from the C callbacks to Node; exceptions stop here */
+inline void tick_trampoline(horus_node_ctx *ctx, void *user_data) {
+    Node &node = *static_cast<Node *>(user_data);
+    NodeContext context(ctx);
+    try {
+        node.tick(context);
+    } catch (const std::exception &e) {
+        report(ctx, node, "tick", e.what());
+    } catch (...) {
+        report(ctx, node, "tick", "unknown exception");
+    }
+}
+
+template <void (Node::*Stage)(NodeContext &)>
+int lifecycle_trampoline(horus_node_ctx *ctx, void *user_data) {
+    Node &node = *static_cast<Node *>(user_data);
+    NodeContext context(ctx);
+    const char *stage = Stage == &Node::init ? "init" : "shutdown";
+    try {
+        (node.*Stage)(context);
+        return HORUS_OK;
+    } catch (const std::exception &e) {
+        report(ctx, node, stage, e.what());
+    } catch (...) {
+        report(ctx, node, stage, "unknown exception");
+    }
+    return HORUS_ERR_RUNTIME;
+}
+
+} // namespace detail
+
+/* ---- Scheduler ---- */
+
+/*
+ * Runs C++ nodes in the HORUS scheduler. Nodes added by reference must
+ * outlive the scheduler; nodes added as unique_ptr are owned by it.
+ */
+class Scheduler {
+  public:
+    Scheduler()
+        : scheduler_(detail::check_ptr(horus_scheduler_create(), "horus_scheduler_create")) {}
+
+    /* Add a node; lower priorities run first (0 = highest) */
+    Scheduler &add(Node &node, std::uint32_t priority = 0, bool logging = false) {
+        horus_node *raw = detail::check_ptr(
+            horus_node_create(node.name().c_str(), &detail::tick_trampoline, &node),
+            "horus_node_create");
+        horus_node_set_init(raw, &detail::lifecycle_trampoline<&Node::init>);
+        horus_node_set_shutdown(raw, &detail::lifecycle_trampoline<&Node::shutdown>);
+        int status = horus_node_set_rate(raw, node.rate_hz());
+        if (status != HORUS_OK) {
+            horus_node_destroy(raw);
+            detail::check(status, "horus_node_set_rate");
+        }
+        detail::check(horus_scheduler_add(scheduler_.get(), raw, priority, logging),
+                      "horus_scheduler_add");
+        return *this;
+    }
+
+    Scheduler &add(std::unique_ptr<Node> node, std::uint32_t priority = 0, bool logging = false) {
+        add(*node, priority, logging);
+        owned_.push_back(std::move(node));
+        return *this;
+    }
+
+    /* Add a node built from a tick function */
+    Scheduler &add(std::string name, std::function<void(NodeContext &)> tick,
+                   std::uint32_t priority = 0, double rate_hz = 0.0, bool logging = false) {
+        return add(std::make_unique<FunctionNode>(std::move(name), std::move(tick), rate_hz),
+                   priority, logging);
+    }
+
+    /* Run until Ctrl+C or stop() */
+    void run() { detail::check(horus_scheduler_run(scheduler_.get()), "horus_scheduler_run"); }
+
+    /* Run for a duration, then shut the nodes down */
+    template <typename Rep, typename Period> void run_for(std::chrono::duration<Rep, Period> duration) {
+        double seconds = std::chrono::duration<double>(duration).count();
+        detail::check(horus_scheduler_run_for(scheduler_.get(), seconds), "horus_scheduler_run_for");
+    }
+
+    /* Ask a running scheduler to stop; safe from nodes and other threads */
+    void stop() noexcept { horus_scheduler_stop(scheduler_.get()); }
+
+    horus_scheduler *get() const noexcept { return scheduler_.get(); }
+
+  private:
+    /* Declared before scheduler_ so the nodes outlive the C scheduler calling them */
+    std::vector<std::unique_ptr<Node>> owned_;
+    std::unique_ptr<horus_scheduler, detail::SchedulerDeleter> scheduler_;
+};
+
+} // namespace horus
+
+#endif /* HORUS_HPP */
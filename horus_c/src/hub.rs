@@ -0,0 +1,275 @@
+//! Hubs: publish and subscribe to topics with byte payloads
+
+use crate::{
+    fail, guard, str_arg, HORUS_ERR_BUFFER_TOO_SMALL, HORUS_ERR_INVALID_ARG, HORUS_ERR_NO_MESSAGE,
+    HORUS_ERR_RUNTIME, HORUS_ERR_SEND_FAILED, HORUS_ERR_TOO_LARGE, HORUS_MAX_MESSAGE_SIZE,
+    HORUS_OK,
+};
+use horus_core::communication::Hub;
+use horus_core::core::NodeInfo;
+use horus_library::messages::GenericMessage;
+use std::ffi::{c_char, c_void, CString};
+
+/// `horus_hub` in the C header
+pub struct HorusHub {
+    hub: Hub<GenericMessage>,
+    /// NUL-terminated topic name for `horus_hub_topic`
+    topic: CString,
+    /// Message received into a buffer that was too small
+    pending: Option<Vec<u8>>,
+}
+
+/// Create a hub on `topic` (`"name"` or `"name@endpoint"`) with the default capacity
+///
+/// # Safety
+///
+/// `topic` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn horus_hub_create(topic: *const c_char) -> *mut HorusHub {
+    horus_hub_create_with_capacity(topic, 1024)
+}
+
+/// Create a hub on `topic` holding up to `capacity` messages
+///
+/// # Safety
+///
+/// `topic` must be a NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn horus_hub_create_with_capacity(
+    topic: *const c_char,
+    capacity: usize,
+) -> *mut HorusHub {
+    guard(std::ptr::null_mut(), || {
+        let Ok(topic) = str_arg(topic, "topic") else {
+            return std::ptr::null_mut();
+        };
+        match Hub::<GenericMessage>::new_with_capacity(topic, capacity.max(1)) {
+            Ok(hub) => Box::into_raw(Box::new(HorusHub {
+                topic: CString::new(hub.get_topic_name()).unwrap_or_default(),
+                hub,
+                pending: None,
+            })),
+            Err(e) => {
+                fail(
+                    HORUS_ERR_RUNTIME,
+                    format!("failed to create hub '{}': {}", topic, e),
+                );
+                std::ptr::null_mut()
+            }
+        }
+    })
+}
+
+/// Free a hub
+///
+/// # Safety
+///
+/// `hub` must be NULL or come from `horus_hub_create*`, and not be used afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn horus_hub_destroy(hub: *mut HorusHub) {
+    if !hub.is_null() {
+        guard((), || drop(Box::from_raw(hub)));
+    }
+}
+
+/// Publish `len` bytes from `data`
+///
+/// `ctx` is the node context passed to tick callbacks (for logging), or NULL.
+///
+/// # Safety
+///
+/// `hub` must be a live hub, `data` must point to `len` readable bytes and
+/// `ctx` must be NULL or the context of the running callback.
+#[no_mangle]
+pub unsafe extern "C" fn horus_hub_send(
+    hub: *mut HorusHub,
+    data: *const c_void,
+    len: usize,
+    ctx: *mut NodeInfo,
+) -> i32 {
+    guard(crate::HORUS_ERR_INTERNAL, || {
+        let Some(hub) = hub.as_mut() else {
+            return fail(HORUS_ERR_INVALID_ARG, "hub is NULL");
+        };
+        if data.is_null() && len > 0 {
+            return fail(HORUS_ERR_INVALID_ARG, "data is NULL");
+        }
+        if len > HORUS_MAX_MESSAGE_SIZE {
+            return fail(
+                HORUS_ERR_TOO_LARGE,
+                format!(
+                    "message of {} bytes exceeds the {} byte limit",
+                    len, HORUS_MAX_MESSAGE_SIZE
+                ),
+            );
+        }
+        let bytes = if len == 0 {
+            Vec::new()
+        } else {
+            std::slice::from_raw_parts(data as *const u8, len).to_vec()
+        };
+        let message = match GenericMessage::new(bytes) {
+            Ok(message) => message,
+            Err(e) => return fail(HORUS_ERR_TOO_LARGE, e),
+        };
+        match hub.hub.send(message, &mut ctx.as_mut()) {
+            Ok(()) => HORUS_OK,
+            Err(_) => fail(
+                HORUS_ERR_SEND_FAILED,
+                format!("failed to send on '{}'", hub.hub.get_topic_name()),
+            ),
+        }
+    })
+}
+
+/// Receive the next message into `buffer` (`capacity` bytes)
+///
+/// Returns `HORUS_OK` and sets `*len` to the message size, or
+/// `HORUS_ERR_NO_MESSAGE` if nothing is waiting. If the buffer is too small,
+/// `*len` is set to the required size, `HORUS_ERR_BUFFER_TOO_SMALL` is
+/// returned and the message is kept for the next call.
+///
+/// # Safety
+///
+/// `hub` must be a live hub, `buffer` must point to `capacity` writable bytes,
+/// `len` must be NULL or writable and `ctx` must be NULL or the context of the
+/// running callback.
+#[no_mangle]
+pub unsafe extern "C" fn horus_hub_recv(
+    hub: *mut HorusHub,
+    buffer: *mut c_void,
+    capacity: usize,
+    len: *mut usize,
+    ctx: *mut NodeInfo,
+) -> i32 {
+    guard(crate::HORUS_ERR_INTERNAL, || {
+        let Some(hub) = hub.as_mut() else {
+            return fail(HORUS_ERR_INVALID_ARG, "hub is NULL");
+        };
+        let message = match hub.pending.take() {
+            Some(message) => message,
+            None => match hub.hub.recv(&mut ctx.as_mut()) {
+                Some(message) => message.data(),
+                None => return HORUS_ERR_NO_MESSAGE,
+            },
+        };
+        if let Some(len) = len.as_mut() {
+            *len = message.len();
+        }
+        if message.len() > capacity || (buffer.is_null() && !message.is_empty()) {
+            let required = message.len();
+            hub.pending = Some(message);
+            return fail(
+                HORUS_ERR_BUFFER_TOO_SMALL,
+                format!(
+                    "message of {} bytes does not fit the {} byte buffer",
+                    required, capacity
+                ),
+            );
+        }
+        if !message.is_empty() {
+            std::ptr::copy_nonoverlapping(message.as_ptr(), buffer as *mut u8, message.len());
+        }
+        HORUS_OK
+    })
+}
+
+/// Topic name of a hub, valid as long as the hub
+///
+/// # Safety
+///
+/// `hub` must be NULL or a live hub.
+#[no_mangle]
+pub unsafe extern "C" fn horus_hub_topic(hub: *const HorusHub) -> *const c_char {
+    match hub.as_ref() {
+        Some(hub) => hub.topic.as_ptr(),
+        None => {
+            fail(HORUS_ERR_INVALID_ARG, "hub is NULL");
+            std::ptr::null()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_send_recv_roundtrip() {
+        let topic = CString::new(format!("horus_c_test_{}", std::process::id())).unwrap();
+        unsafe {
+            let publisher = horus_hub_create(topic.as_ptr());
+            let subscriber = horus_hub_create(topic.as_ptr());
+            assert!(!publisher.is_null() && !subscriber.is_null());
+
+            let mut buffer = [0u8; 16];
+            let mut len = 0usize;
+            assert_eq!(
+                horus_hub_recv(
+                    subscriber,
+                    buffer.as_mut_ptr() as *mut c_void,
+                    buffer.len(),
+                    &mut len,
+                    std::ptr::null_mut()
+                ),
+                HORUS_ERR_NO_MESSAGE
+            );
+
+            let payload = b"hello from C";
+            assert_eq!(
+                horus_hub_send(
+                    publisher,
+                    payload.as_ptr() as *const c_void,
+                    payload.len(),
+                    std::ptr::null_mut()
+                ),
+                HORUS_OK
+            );
+
+            // Too small: the message is kept and its size reported
+            let mut small = [0u8; 4];
+            assert_eq!(
+                horus_hub_recv(
+                    subscriber,
+                    small.as_mut_ptr() as *mut c_void,
+                    small.len(),
+                    &mut len,
+                    std::ptr::null_mut()
+                ),
+                HORUS_ERR_BUFFER_TOO_SMALL
+            );
+            assert_eq!(len, payload.len());
+
+            assert_eq!(
+                horus_hub_recv(
+                    subscriber,
+                    buffer.as_mut_ptr() as *mut c_void,
+                    buffer.len(),
+                    &mut len,
+                    std::ptr::null_mut()
+                ),
+                HORUS_OK
+            );
+            assert_eq!(&buffer[..len], payload);
+
+            horus_hub_destroy(publisher);
+            horus_hub_destroy(subscriber);
+        }
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        unsafe {
+            assert!(horus_hub_create(std::ptr::null()).is_null());
+            assert_eq!(
+                horus_hub_send(
+                    std::ptr::null_mut(),
+                    std::ptr::null(),
+                    0,
+                    std::ptr::null_mut()
+                ),
+                HORUS_ERR_INVALID_ARG
+            );
+        }
+    }
+}
@@ -0,0 +1,139 @@
+//! # HORUS C API
+//!
+//! FFI layer that lets C and C++ code (and any language with a C FFI) publish
+//! and subscribe to HORUS topics and run nodes in a HORUS scheduler. The
+//! declarations are in `include/horus.h`; link against `libhorus_c.so` or
+//! `libhorus_c.a`. C++ code can use the header-only wrapper in
+//! `include/horus.hpp` instead.
+//!
+//! Messages are opaque byte buffers of up to [`HORUS_MAX_MESSAGE_SIZE`]
+//! bytes, carried on the same generic hubs the Python bindings use for
+//! untyped topics. To exchange data with Python nodes, encode the payload as
+//! MessagePack.
+//!
+//! ## Conventions
+//!
+//! - Functions returning `int` return [`HORUS_OK`] or a negative `HORUS_ERR_*`
+//!   code; constructors return `NULL` on failure.
+//! - [`horus_last_error`] describes the last failure on the calling thread.
+//! - Objects are freed with their `*_destroy` function. A node handed to
+//!   `horus_scheduler_add` belongs to the scheduler and must not be destroyed.
+//! - Panics never cross the boundary; they are reported as `HORUS_ERR_INTERNAL`.
+
+use std::cell::RefCell;
+use std::ffi::{c_char, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+mod hub;
+mod node;
+
+pub use hub::*;
+pub use node::*;
+
+/// Success
+pub const HORUS_OK: i32 = 0;
+/// A pointer or string argument was NULL or invalid
+pub const HORUS_ERR_INVALID_ARG: i32 = -1;
+/// No message is waiting on the hub
+pub const HORUS_ERR_NO_MESSAGE: i32 = -2;
+/// The receive buffer is too small; the message is kept for the next call
+pub const HORUS_ERR_BUFFER_TOO_SMALL: i32 = -3;
+/// The message exceeds `HORUS_MAX_MESSAGE_SIZE`
+pub const HORUS_ERR_TOO_LARGE: i32 = -4;
+/// The hub could not deliver the message (buffer full or disconnected)
+pub const HORUS_ERR_SEND_FAILED: i32 = -5;
+/// The scheduler or a node failed
+pub const HORUS_ERR_RUNTIME: i32 = -6;
+/// Internal error (a panic inside the library)
+pub const HORUS_ERR_INTERNAL: i32 = -7;
+
+/// Largest message payload in bytes
+pub const HORUS_MAX_MESSAGE_SIZE: usize = horus_library::messages::MAX_GENERIC_PAYLOAD;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+/// Record the error message returned by `horus_last_error`
+pub(crate) fn set_error(message: impl Into<String>) {
+    let message = CString::new(message.into().replace('\0', " ")).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Record `message` and return `code`
+pub(crate) fn fail(code: i32, message: impl Into<String>) -> i32 {
+    set_error(message);
+    code
+}
+
+/// Run an FFI entry point, turning panics into `on_panic`
+pub(crate) fn guard<T>(on_panic: T, body: impl FnOnce() -> T) -> T {
+    catch_unwind(AssertUnwindSafe(body)).unwrap_or_else(|_| {
+        set_error("internal error: panic inside the HORUS C API");
+        on_panic
+    })
+}
+
+/// Borrow a C string argument
+///
+/// # Safety
+///
+/// `ptr` must be NULL or point to a NUL-terminated string.
+pub(crate) unsafe fn str_arg<'a>(ptr: *const c_char, what: &str) -> Result<&'a str, i32> {
+    if ptr.is_null() {
+        return Err(fail(HORUS_ERR_INVALID_ARG, format!("{} is NULL", what)));
+    }
+    CStr::from_ptr(ptr).to_str().map_err(|_| {
+        fail(
+            HORUS_ERR_INVALID_ARG,
+            format!("{} is not valid UTF-8", what),
+        )
+    })
+}
+
+/// Description of the last error on this thread, or NULL if there was none
+///
+/// The string stays valid until the next failing call on the same thread.
+#[no_mangle]
+pub extern "C" fn horus_last_error() -> *const c_char {
+    LAST_ERROR.with(|last| {
+        last.borrow()
+            .as_ref()
+            .map_or(std::ptr::null(), |message| message.as_ptr())
+    })
+}
+
+/// Version of the HORUS C API library
+#[no_mangle]
+pub extern "C" fn horus_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr() as *const c_char
+}
+
+/// Largest message payload in bytes
+#[no_mangle]
+pub extern "C" fn horus_max_message_size() -> usize {
+    HORUS_MAX_MESSAGE_SIZE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_last_error() {
+        set_error("first");
+        let message = unsafe { CStr::from_ptr(horus_last_error()) };
+        assert_eq!(message.to_str().unwrap(), "first");
+
+        assert_eq!(guard(HORUS_OK, || panic!("boom")), HORUS_OK);
+        let message = unsafe { CStr::from_ptr(horus_last_error()) };
+        assert!(message.to_str().unwrap().contains("panic"));
+    }
+
+    #[test]
+    fn test_version() {
+        let version = unsafe { CStr::from_ptr(horus_version()) };
+        assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
+        assert_eq!(horus_max_message_size(), 4096);
+    }
+}
@@ -0,0 +1,395 @@
+//! Nodes driven by C callbacks, and the scheduler running them
+
+use crate::{
+    fail, guard, str_arg, HORUS_ERR_INTERNAL, HORUS_ERR_INVALID_ARG, HORUS_ERR_RUNTIME, HORUS_OK,
+};
+use horus_core::core::{Node, NodeInfo};
+use horus_core::error::{HorusError, HorusResult};
+use horus_core::scheduling::{Scheduler, SchedulerStopHandle};
+use std::ffi::{c_char, c_void};
+use std::time::Duration;
+
+/// `horus_tick_fn`: called on every tick with the node context and user data
+pub type HorusTickFn = Option<unsafe extern "C" fn(ctx: *mut NodeInfo, user_data: *mut c_void)>;
+
+/// `horus_lifecycle_fn`: init/shutdown callback, returns 0 on success
+pub type HorusLifecycleFn =
+    Option<unsafe extern "C" fn(ctx: *mut NodeInfo, user_data: *mut c_void) -> i32>;
+
+/// `horus_node` in the C header
+pub struct HorusNode {
+    name: &'static str,
+    tick: HorusTickFn,
+    init: HorusLifecycleFn,
+    shutdown: HorusLifecycleFn,
+    rate_hz: Option<f64>,
+    user_data: *mut c_void,
+}
+
+// The scheduler calls a node from one thread at a time; the C side owns
+// `user_data` and is responsible for what it points to
+unsafe impl Send for HorusNode {}
+
+impl Node for HorusNode {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> HorusResult<()> {
+        let Some(init) = self.init else {
+            return Ok(());
+        };
+        match unsafe { init(ctx, self.user_data) } {
+            0 => Ok(()),
+            code => Err(HorusError::InitializationFailed(format!(
+                "init callback of '{}' returned {}",
+                self.name, code
+            ))),
+        }
+    }
+
+    fn tick(&mut self, ctx: Option<&mut NodeInfo>) {
+        if let Some(tick) = self.tick {
+            let ctx = ctx.map_or(std::ptr::null_mut(), |ctx| ctx as *mut NodeInfo);
+            unsafe { tick(ctx, self.user_data) }
+        }
+    }
+
+    fn shutdown(&mut self, ctx: &mut NodeInfo) -> HorusResult<()> {
+        let Some(shutdown) = self.shutdown else {
+            return Ok(());
+        };
+        match unsafe { shutdown(ctx, self.user_data) } {
+            0 => Ok(()),
+            code => Err(HorusError::Internal(format!(
+                "shutdown callback of '{}' returned {}",
+                self.name, code
+            ))),
+        }
+    }
+
+    fn rate_hz(&self) -> Option<f64> {
+        self.rate_hz
+    }
+}
+
+/// Create a node named `name` that calls `tick(ctx, user_data)` on every tick
+///
+/// # Safety
+///
+/// `name` must be a NUL-terminated string. `user_data` is passed through
+/// untouched and must stay valid while the node exists.
+#[no_mangle]
+pub unsafe extern "C" fn horus_node_create(
+    name: *const c_char,
+    tick: HorusTickFn,
+    user_data: *mut c_void,
+) -> *mut HorusNode {
+    guard(std::ptr::null_mut(), || {
+        let Ok(name) = str_arg(name, "name") else {
+            return std::ptr::null_mut();
+        };
+        if tick.is_none() {
+            fail(HORUS_ERR_INVALID_ARG, "tick callback is NULL");
+            return std::ptr::null_mut();
+        }
+        Box::into_raw(Box::new(HorusNode {
+            // Node::name() is &'static; one small allocation per node
+            name: Box::leak(name.to_string().into_boxed_str()),
+            tick,
+            init: None,
+            shutdown: None,
+            rate_hz: None,
+            user_data,
+        }))
+    })
+}
+
+/// Set the callback run once before the first tick (0 = success)
+///
+/// # Safety
+///
+/// `node` must be a node not yet added to a scheduler.
+#[no_mangle]
+pub unsafe extern "C" fn horus_node_set_init(node: *mut HorusNode, init: HorusLifecycleFn) -> i32 {
+    match node.as_mut() {
+        Some(node) => {
+            node.init = init;
+            HORUS_OK
+        }
+        None => fail(HORUS_ERR_INVALID_ARG, "node is NULL"),
+    }
+}
+
+/// Set the callback run once when the scheduler stops (0 = success)
+///
+/// # Safety
+///
+/// `node` must be a node not yet added to a scheduler.
+#[no_mangle]
+pub unsafe extern "C" fn horus_node_set_shutdown(
+    node: *mut HorusNode,
+    shutdown: HorusLifecycleFn,
+) -> i32 {
+    match node.as_mut() {
+        Some(node) => {
+            node.shutdown = shutdown;
+            HORUS_OK
+        }
+        None => fail(HORUS_ERR_INVALID_ARG, "node is NULL"),
+    }
+}
+
+/// Set the node's tick rate in Hz (0 = the scheduler's rate)
+///
+/// # Safety
+///
+/// `node` must be a node not yet added to a scheduler.
+#[no_mangle]
+pub unsafe extern "C" fn horus_node_set_rate(node: *mut HorusNode, rate_hz: f64) -> i32 {
+    let Some(node) = node.as_mut() else {
+        return fail(HORUS_ERR_INVALID_ARG, "node is NULL");
+    };
+    if !rate_hz.is_finite() || rate_hz < 0.0 {
+        return fail(HORUS_ERR_INVALID_ARG, format!("invalid rate {}", rate_hz));
+    }
+    node.rate_hz = (rate_hz > 0.0).then_some(rate_hz);
+    HORUS_OK
+}
+
+/// Free a node that was not added to a scheduler
+///
+/// # Safety
+///
+/// `node` must be NULL or come from `horus_node_create` and not have been
+/// passed to `horus_scheduler_add`.
+#[no_mangle]
+pub unsafe extern "C" fn horus_node_destroy(node: *mut HorusNode) {
+    if !node.is_null() {
+        guard((), || drop(Box::from_raw(node)));
+    }
+}
+
+unsafe fn log(ctx: *mut NodeInfo, message: *const c_char, write: fn(&mut NodeInfo, &str)) -> i32 {
+    guard(HORUS_ERR_INTERNAL, || {
+        let Some(ctx) = ctx.as_mut() else {
+            return fail(HORUS_ERR_INVALID_ARG, "ctx is NULL");
+        };
+        match str_arg(message, "message") {
+            Ok(message) => {
+                write(ctx, message);
+                HORUS_OK
+            }
+            Err(code) => code,
+        }
+    })
+}
+
+/// Log an info message from a callback
+///
+/// # Safety
+///
+/// `ctx` must be the context of the running callback and `message` a
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn horus_log_info(ctx: *mut NodeInfo, message: *const c_char) -> i32 {
+    log(ctx, message, |ctx, message| ctx.log_info(message))
+}
+
+/// Log a warning from a callback
+///
+/// # Safety
+///
+/// `ctx` must be the context of the running callback and `message` a
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn horus_log_warning(ctx: *mut NodeInfo, message: *const c_char) -> i32 {
+    log(ctx, message, |ctx, message| ctx.log_warning(message))
+}
+
+/// Log an error from a callback
+///
+/// # Safety
+///
+/// `ctx` must be the context of the running callback and `message` a
+/// NUL-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn horus_log_error(ctx: *mut NodeInfo, message: *const c_char) -> i32 {
+    log(ctx, message, |ctx, message| ctx.log_error(message))
+}
+
+/// `horus_scheduler` in the C header
+pub struct HorusScheduler {
+    scheduler: Scheduler,
+    stop: SchedulerStopHandle,
+}
+
+/// Create a scheduler
+#[no_mangle]
+pub extern "C" fn horus_scheduler_create() -> *mut HorusScheduler {
+    guard(std::ptr::null_mut(), || {
+        let scheduler = Scheduler::new();
+        let stop = scheduler.stop_handle();
+        Box::into_raw(Box::new(HorusScheduler { scheduler, stop }))
+    })
+}
+
+/// Add a node; the scheduler takes ownership of it
+///
+/// Lower priorities run first (0 = highest).
+///
+/// # Safety
+///
+/// `scheduler` must be a live scheduler that is not running, and `node` must
+/// come from `horus_node_create` and not be used by the caller afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn horus_scheduler_add(
+    scheduler: *mut HorusScheduler,
+    node: *mut HorusNode,
+    priority: u32,
+    logging: bool,
+) -> i32 {
+    guard(HORUS_ERR_INTERNAL, || {
+        let Some(scheduler) = scheduler.as_mut() else {
+            return fail(HORUS_ERR_INVALID_ARG, "scheduler is NULL");
+        };
+        if node.is_null() {
+            return fail(HORUS_ERR_INVALID_ARG, "node is NULL");
+        }
+        scheduler
+            .scheduler
+            .add(Box::from_raw(node), priority, Some(logging));
+        HORUS_OK
+    })
+}
+
+unsafe fn run(scheduler: *mut HorusScheduler, duration: Option<Duration>) -> i32 {
+    guard(HORUS_ERR_INTERNAL, || {
+        if scheduler.is_null() {
+            return fail(HORUS_ERR_INVALID_ARG, "scheduler is NULL");
+        }
+        // Borrow only the scheduler field so `horus_scheduler_stop` can read
+        // the stop handle from another thread meanwhile
+        let inner = &mut *std::ptr::addr_of_mut!((*scheduler).scheduler);
+        let result = match duration {
+            Some(duration) => inner.run_for(duration),
+            None => inner.run(),
+        };
+        match result {
+            Ok(()) => HORUS_OK,
+            Err(e) => fail(HORUS_ERR_RUNTIME, e.to_string()),
+        }
+    })
+}
+
+/// Run the nodes until Ctrl+C or `horus_scheduler_stop`
+///
+/// # Safety
+///
+/// `scheduler` must be a live scheduler.
+#[no_mangle]
+pub unsafe extern "C" fn horus_scheduler_run(scheduler: *mut HorusScheduler) -> i32 {
+    run(scheduler, None)
+}
+
+/// Run the nodes for `seconds`, then shut them down
+///
+/// # Safety
+///
+/// `scheduler` must be a live scheduler.
+#[no_mangle]
+pub unsafe extern "C" fn horus_scheduler_run_for(
+    scheduler: *mut HorusScheduler,
+    seconds: f64,
+) -> i32 {
+    if !seconds.is_finite() || seconds < 0.0 {
+        return fail(
+            HORUS_ERR_INVALID_ARG,
+            format!("invalid duration {}", seconds),
+        );
+    }
+    run(scheduler, Some(Duration::from_secs_f64(seconds)))
+}
+
+/// Ask a running scheduler to stop; safe to call from callbacks and other threads
+///
+/// # Safety
+///
+/// `scheduler` must be a live scheduler.
+#[no_mangle]
+pub unsafe extern "C" fn horus_scheduler_stop(scheduler: *mut HorusScheduler) -> i32 {
+    if scheduler.is_null() {
+        return fail(HORUS_ERR_INVALID_ARG, "scheduler is NULL");
+    }
+    (*std::ptr::addr_of!((*scheduler).stop)).stop();
+    HORUS_OK
+}
+
+/// Free a scheduler and the nodes added to it
+///
+/// # Safety
+///
+/// `scheduler` must be NULL or a scheduler that is not running.
+#[no_mangle]
+pub unsafe extern "C" fn horus_scheduler_destroy(scheduler: *mut HorusScheduler) {
+    if !scheduler.is_null() {
+        guard((), || drop(Box::from_raw(scheduler)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    unsafe extern "C" fn count_tick(_ctx: *mut NodeInfo, user_data: *mut c_void) {
+        (*(user_data as *const AtomicUsize)).fetch_add(1, Ordering::SeqCst);
+    }
+
+    unsafe extern "C" fn failing_init(_ctx: *mut NodeInfo, _user_data: *mut c_void) -> i32 {
+        -1
+    }
+
+    #[test]
+    fn test_node_callbacks() {
+        let ticks = AtomicUsize::new(0);
+        let name = CString::new("c_counter").unwrap();
+        unsafe {
+            let node = horus_node_create(
+                name.as_ptr(),
+                Some(count_tick),
+                &ticks as *const AtomicUsize as *mut c_void,
+            );
+            assert!(!node.is_null());
+            assert_eq!(horus_node_set_rate(node, 50.0), HORUS_OK);
+            assert_eq!(horus_node_set_rate(node, -1.0), HORUS_ERR_INVALID_ARG);
+
+            let node = &mut *node;
+            assert_eq!(node.name(), "c_counter");
+            assert_eq!(node.rate_hz(), Some(50.0));
+            node.tick(None);
+            node.tick(None);
+            assert_eq!(ticks.load(Ordering::SeqCst), 2);
+
+            let mut info = NodeInfo::new("c_counter".to_string(), false);
+            assert!(node.init(&mut info).is_ok());
+            horus_node_set_init(node, Some(failing_init));
+            assert!(node.init(&mut info).is_err());
+
+            horus_node_destroy(node);
+        }
+    }
+
+    #[test]
+    fn test_create_without_tick_fails() {
+        let name = CString::new("no_tick").unwrap();
+        unsafe {
+            assert!(horus_node_create(name.as_ptr(), None, std::ptr::null_mut()).is_null());
+            assert_eq!(
+                horus_scheduler_add(std::ptr::null_mut(), std::ptr::null_mut(), 0, false),
+                HORUS_ERR_INVALID_ARG
+            );
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! Subscriber-side message adapters
+//!
+//! `Hub::filtered` skips messages that don't match a predicate and
+//! `Hub::map` / `Hub::map_into` convert them, so nodes don't need filtering
+//! loops in `tick()`:
+//!
+//! ```rust,ignore
+//! let confident = Hub::<Detection>::new("detections")?.filtered(|d| d.confidence > 0.5);
+//! let poses = Hub::<Odometry>::new("odom")?.map_into::<Pose2D>();
+//! ```
+//!
+//! Adapters run inside `recv()` before delivery. Predicates are stored with
+//! the Hub rather than wrapped around it, so the transport can apply them
+//! itself without an API change.
+
+use crate::communication::hub::Hub;
+use crate::core::node::NodeInfo;
+use std::sync::Arc;
+
+/// Predicate attached to a Hub by `Hub::filtered`
+pub type MessageFilter<T> = Arc<dyn Fn(&T) -> bool + Send + Sync>;
+
+/// Conversion of a `MappedHub`, `None` skips the message
+type MessageMap<T, U> = Arc<dyn Fn(T) -> Option<U> + Send + Sync>;
+
+/// Hub whose received messages are converted to another type
+///
+/// Created by `Hub::map` and `Hub::map_into`. Further `filtered`, `map` and
+/// `map_into` calls apply to the converted messages.
+pub struct MappedHub<T, U> {
+    hub: Hub<T>,
+    map: MessageMap<T, U>,
+}
+
+impl<T, U> MappedHub<T, U>
+where
+    T: Send
+        + Sync
+        + 'static
+        + Clone
+        + std::fmt::Debug
+        + serde::Serialize
+        + serde::de::DeserializeOwned,
+    U: 'static,
+{
+    pub(crate) fn new(hub: Hub<T>, map: MessageMap<T, U>) -> Self {
+        Self { hub, map }
+    }
+
+    /// Receive the next message that passes all filters, converted
+    pub fn recv(&self, ctx: &mut Option<&mut NodeInfo>) -> Option<U>
+    where
+        T: crate::core::LogSummary,
+    {
+        loop {
+            let msg = self.hub.recv(ctx)?;
+            if let Some(mapped) = (self.map)(msg) {
+                return Some(mapped);
+            }
+            self.hub.record_filtered();
+        }
+    }
+
+    /// Only deliver converted messages matching `predicate`
+    pub fn filtered<F>(self, predicate: F) -> Self
+    where
+        F: Fn(&U) -> bool + Send + Sync + 'static,
+    {
+        let map = self.map;
+        Self {
+            hub: self.hub,
+            map: Arc::new(move |msg: T| map(msg).filter(|mapped| predicate(mapped))),
+        }
+    }
+
+    /// Convert the converted messages again with `f`
+    pub fn map<V, F>(self, f: F) -> MappedHub<T, V>
+    where
+        V: 'static,
+        F: Fn(U) -> V + Send + Sync + 'static,
+    {
+        let map = self.map;
+        MappedHub {
+            hub: self.hub,
+            map: Arc::new(move |msg: T| map(msg).map(&f)),
+        }
+    }
+
+    /// Convert the converted messages again through `From`
+    pub fn map_into<V>(self) -> MappedHub<T, V>
+    where
+        V: From<U> + 'static,
+    {
+        self.map(V::from)
+    }
+
+    /// The underlying Hub
+    pub fn hub(&self) -> &Hub<T> {
+        &self.hub
+    }
+
+    /// Get the topic name of the underlying Hub
+    pub fn get_topic_name(&self) -> &str {
+        self.hub.get_topic_name()
+    }
+}
+
+impl<T: Clone, U> Clone for MappedHub<T, U> {
+    fn clone(&self) -> Self {
+        Self {
+            hub: self.hub.clone(),
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<T, U> std::fmt::Debug for MappedHub<T, U> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MappedHub")
+            .field("hub", &self.hub)
+            .field("output", &std::any::type_name::<U>())
+            .finish()
+    }
+}
+
+impl<T, U> crate::communication::traits::Subscriber<U> for MappedHub<T, U>
+where
+    T: Send
+        + Sync
+        + Clone
+        + std::fmt::Debug
+        + serde::Serialize
+        + serde::de::DeserializeOwned
+        + crate::core::LogSummary
+        + 'static,
+    U: 'static,
+{
+    fn recv(&self) -> Option<U> {
+        MappedHub::recv(self, &mut None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Detection {
+        label: String,
+        confidence: f32,
+    }
+
+    impl crate::core::LogSummary for Detection {
+        fn log_summary(&self) -> String {
+            format!("{} ({:.2})", self.label, self.confidence)
+        }
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Label(String);
+
+    impl From<Detection> for Label {
+        fn from(detection: Detection) -> Self {
+            Label(detection.label)
+        }
+    }
+
+    fn detection(label: &str, confidence: f32) -> Detection {
+        Detection {
+            label: label.to_string(),
+            confidence,
+        }
+    }
+
+    #[test]
+    fn test_hub_filtered() {
+        let publisher: Hub<Detection> = Hub::new("test_adapter_filtered").unwrap();
+        let subscriber = Hub::<Detection>::new("test_adapter_filtered")
+            .unwrap()
+            .filtered(|d| d.confidence > 0.5)
+            .filtered(|d| d.label != "cat");
+
+        publisher.send(detection("dog", 0.2), &mut None).unwrap();
+        assert_eq!(subscriber.recv(&mut None), None);
+
+        publisher.send(detection("cat", 0.9), &mut None).unwrap();
+        assert_eq!(subscriber.recv(&mut None), None);
+
+        publisher.send(detection("dog", 0.9), &mut None).unwrap();
+        assert_eq!(subscriber.recv(&mut None), Some(detection("dog", 0.9)));
+
+        assert_eq!(subscriber.get_metrics().messages_filtered, 2);
+    }
+
+    #[test]
+    fn test_hub_map_into() {
+        let publisher: Hub<Detection> = Hub::new("test_adapter_map_into").unwrap();
+        let labels = Hub::<Detection>::new("test_adapter_map_into")
+            .unwrap()
+            .filtered(|d| d.confidence > 0.5)
+            .map_into::<Label>()
+            .filtered(|label| !label.0.is_empty());
+        let lengths = labels.clone().map(|label| label.0.len());
+
+        publisher.send(detection("", 0.9), &mut None).unwrap();
+        assert_eq!(labels.recv(&mut None), None);
+
+        publisher.send(detection("person", 0.8), &mut None).unwrap();
+        assert_eq!(labels.recv(&mut None), Some(Label("person".to_string())));
+
+        publisher
+            .send(detection("bicycle", 0.7), &mut None)
+            .unwrap();
+        assert_eq!(lengths.recv(&mut None), Some(7));
+        assert_eq!(lengths.get_topic_name(), "test_adapter_map_into");
+    }
+}
@@ -2,6 +2,7 @@
 ///
 /// Allows Hub creation from TOML/YAML config files instead of hardcoded strings.
 /// Supports auto-detection of file format and multiple search paths.
+use crate::communication::validation::ValidationRuleSpec;
 use crate::error::{HorusError, HorusResult};
 use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
@@ -160,20 +161,81 @@ pub struct HubConfig {
     #[serde(default)]
     pub tls_key: Option<String>,
 
+    /// CA certificate (PEM) the router certificate must chain to; with
+    /// `tls_cert` and `tls_key` the hub also authenticates itself (mutual TLS)
+    #[serde(default)]
+    pub tls_ca: Option<String>,
+
+    /// Name the router certificate is checked against (default: router address)
+    #[serde(default)]
+    pub tls_server_name: Option<String>,
+
+    /// File holding a pre-shared key shared by both ends (hex, at least 16
+    /// bytes); UDP and router traffic is then encrypted and authenticated
+    #[serde(default)]
+    pub psk_file: Option<String>,
+
     /// Zenoh-specific configuration (when transport = "zenoh")
     #[serde(default)]
     pub zenoh: Option<ZenohHubConfig>,
 
+    /// Validation rules applied to received messages
+    ///
+    /// ```yaml
+    /// validation:
+    ///   - type: range
+    ///     field: linear.x
+    ///     min: -2.0
+    ///     max: 2.0
+    ///     action: clamp
+    ///   - type: monotonic
+    ///     field: timestamp
+    ///     action: drop
+    /// ```
+    #[serde(default)]
+    pub validation: Vec<ValidationRuleSpec>,
+
+    /// Keep the last message for subscribers that join later
+    #[serde(default)]
+    pub latched: bool,
+
+    /// Back the shared memory with 2 MiB huge pages, falling back to regular
+    /// pages when none are available
+    #[serde(default)]
+    pub huge_pages: bool,
+
     /// Additional options
     #[serde(flatten)]
     pub options: std::collections::HashMap<String, serde_yaml::Value>,
 }
 
+/// Publish policy of a topic (see [`super::publish_policy`])
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TopicPublishPolicy {
+    /// Designated publishers: node name -> hex SHA-256 of its publish key
+    ///
+    /// Hubs created without one of these keys can only subscribe.
+    #[serde(default)]
+    pub publishers: std::collections::BTreeMap<String, String>,
+}
+
 /// Full configuration file with multiple hub definitions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HorusConfig {
     /// Map of hub name -> hub config
+    #[serde(default)]
     pub hubs: std::collections::HashMap<String, HubConfig>,
+
+    /// Map of topic name or `*` pattern -> designated publishers
+    ///
+    /// ```yaml
+    /// publish_policy:
+    ///   motors.cmd_vel:
+    ///     publishers:
+    ///       safety_controller: 9c56cc51b374c3ba189210d5b6d4bf57790d351c96c47c02190ecf1e430635ab
+    /// ```
+    #[serde(default)]
+    pub publish_policy: std::collections::HashMap<String, TopicPublishPolicy>,
 }
 
 impl HubConfig {
@@ -404,7 +466,13 @@ mod tests {
             tls: None,
             tls_cert: None,
             tls_key: None,
+            tls_ca: None,
+            tls_server_name: None,
+            psk_file: None,
             zenoh: None,
+            validation: Vec::new(),
+            latched: false,
+            huge_pages: false,
             options: std::collections::HashMap::new(),
         };
 
@@ -422,7 +490,13 @@ mod tests {
             tls: None,
             tls_cert: None,
             tls_key: None,
+            tls_ca: None,
+            tls_server_name: None,
+            psk_file: None,
             zenoh: None,
+            validation: Vec::new(),
+            latched: false,
+            huge_pages: false,
             options: std::collections::HashMap::new(),
         };
 
@@ -440,7 +514,13 @@ mod tests {
             tls: None,
             tls_cert: None,
             tls_key: None,
+            tls_ca: None,
+            tls_server_name: None,
+            psk_file: None,
             zenoh: None,
+            validation: Vec::new(),
+            latched: false,
+            huge_pages: false,
             options: std::collections::HashMap::new(),
         };
 
@@ -458,7 +538,13 @@ mod tests {
             tls: None,
             tls_cert: None,
             tls_key: None,
+            tls_ca: None,
+            tls_server_name: None,
+            psk_file: None,
             zenoh: None,
+            validation: Vec::new(),
+            latched: false,
+            huge_pages: false,
             options: std::collections::HashMap::new(),
         };
 
@@ -476,6 +562,9 @@ mod tests {
             tls: None,
             tls_cert: None,
             tls_key: None,
+            tls_ca: None,
+            tls_server_name: None,
+            psk_file: None,
             zenoh: Some(ZenohHubConfig {
                 ros2_mode: true,
                 ros2_domain_id: 0,
@@ -488,6 +577,9 @@ mod tests {
                 priority: None,
                 express: false,
             }),
+            validation: Vec::new(),
+            latched: false,
+            huge_pages: false,
             options: std::collections::HashMap::new(),
         };
 
@@ -505,6 +597,9 @@ mod tests {
             tls: None,
             tls_cert: None,
             tls_key: None,
+            tls_ca: None,
+            tls_server_name: None,
+            psk_file: None,
             zenoh: Some(ZenohHubConfig {
                 ros2_mode: false,
                 ros2_domain_id: 0,
@@ -517,6 +612,9 @@ mod tests {
                 priority: Some(7),
                 express: false,
             }),
+            validation: Vec::new(),
+            latched: false,
+            huge_pages: false,
             options: std::collections::HashMap::new(),
         };
 
@@ -1,4 +1,12 @@
-use crate::communication::network::{parse_endpoint, Endpoint, NetworkBackend};
+use crate::communication::adapter::{MappedHub, MessageFilter};
+use crate::communication::mirror::{mirror_topic_name, MirrorRelay};
+use crate::communication::network::{
+    apply_bridge, parse_endpoint, Endpoint, NetworkBackend, TransportSecurity,
+};
+use crate::communication::publish_policy::{self, PublisherPolicy};
+use crate::communication::topic::TopicName;
+use crate::communication::validation::{TopicValidator, ValidationOutcome, ValidationStats};
+use crate::communication::window::TopicWindow;
 use crate::core::node::NodeInfo;
 use crate::error::HorusResult;
 use crate::memory::shm_topic::ShmTopic;
@@ -23,7 +31,10 @@ pub struct AtomicHubMetrics {
     pub messages_received: std::sync::atomic::AtomicU64,
     pub send_failures: std::sync::atomic::AtomicU64,
     pub recv_failures: std::sync::atomic::AtomicU64,
-    _padding: [u8; 32], // Pad to cache line boundary
+    pub validation_violations: std::sync::atomic::AtomicU64,
+    pub validation_drops: std::sync::atomic::AtomicU64,
+    pub messages_filtered: std::sync::atomic::AtomicU64,
+    _padding: [u8; 8], // Pad to cache line boundary
 }
 
 impl Default for AtomicHubMetrics {
@@ -33,7 +44,10 @@ impl Default for AtomicHubMetrics {
             messages_received: std::sync::atomic::AtomicU64::new(0),
             send_failures: std::sync::atomic::AtomicU64::new(0),
             recv_failures: std::sync::atomic::AtomicU64::new(0),
-            _padding: [0; 32],
+            validation_violations: std::sync::atomic::AtomicU64::new(0),
+            validation_drops: std::sync::atomic::AtomicU64::new(0),
+            messages_filtered: std::sync::atomic::AtomicU64::new(0),
+            _padding: [0; 8],
         }
     }
 }
@@ -54,6 +68,15 @@ impl AtomicHubMetrics {
             recv_failures: self
                 .recv_failures
                 .load(std::sync::atomic::Ordering::Relaxed),
+            validation_violations: self
+                .validation_violations
+                .load(std::sync::atomic::Ordering::Relaxed),
+            validation_drops: self
+                .validation_drops
+                .load(std::sync::atomic::Ordering::Relaxed),
+            messages_filtered: self
+                .messages_filtered
+                .load(std::sync::atomic::Ordering::Relaxed),
             last_activity: None, // Eliminated to remove Instant::now() overhead
         }
     }
@@ -66,6 +89,12 @@ pub struct HubMetrics {
     pub messages_received: u64,
     pub send_failures: u64,
     pub recv_failures: u64,
+    /// Messages that violated at least one validation rule
+    pub validation_violations: u64,
+    /// Messages dropped by validation
+    pub validation_drops: u64,
+    /// Messages skipped by `filtered` and filtering adapters
+    pub messages_filtered: u64,
     pub last_activity: Option<Instant>,
 }
 
@@ -78,7 +107,11 @@ pub struct Hub<T> {
     topic_name: String,
     state: std::sync::atomic::AtomicU8, // Lock-free state using atomic u8
     metrics: Arc<AtomicHubMetrics>,     // Lock-free atomic metrics
-    _padding: [u8; 14],                 // Pad to prevent false sharing
+    validator: Option<Arc<parking_lot::Mutex<TopicValidator>>>, // Optional subscriber-side validation
+    mirror_relay: Option<Arc<MirrorRelay>>, // Keeps the relay of a `topic@5hz` mirror running
+    filter: Option<MessageFilter<T>>,       // Subscriber-side filter from `filtered()`
+    publish_denied: bool, // No designated publish key for this topic (see `publish_policy`)
+    _padding: [u8; 6],    // Pad to prevent false sharing
 }
 
 // Manual Clone implementation since AtomicU8 doesn't implement Clone
@@ -96,7 +129,11 @@ where
                 self.state.load(std::sync::atomic::Ordering::Relaxed),
             ),
             metrics: self.metrics.clone(),
-            _padding: [0; 14],
+            validator: self.validator.clone(),
+            mirror_relay: self.mirror_relay.clone(),
+            filter: self.filter.clone(),
+            publish_denied: self.publish_denied,
+            _padding: [0; 6],
         }
     }
 }
@@ -148,8 +185,11 @@ impl<
     > Hub<T>
 {
     /// Create a new Hub
-    pub fn new(topic_name: &str) -> HorusResult<Self> {
-        Self::new_with_capacity(topic_name, 1024)
+    ///
+    /// Takes a topic or endpoint string, or a typed [`Topic`](super::Topic)
+    /// constant from `topics!`, which must be for the hub's message type.
+    pub fn new(topic: impl TopicName<T>) -> HorusResult<Self> {
+        Self::new_with_capacity(topic, 1024)
     }
 
     /// Create a Hub from configuration file
@@ -200,11 +240,30 @@ impl<
         // Get hub config
         let hub_config = config.get_hub(hub_name)?;
 
+        Self::from_hub_config(hub_config)
+    }
+
+    /// Create a Hub from a parsed hub config, attaching any validation rules
+    fn from_hub_config(hub_config: &crate::communication::config::HubConfig) -> HorusResult<Self> {
         // Get endpoint string
         let endpoint_str = hub_config.get_endpoint();
 
-        // Create hub with the endpoint
-        Self::new(&endpoint_str)
+        // Create hub with the endpoint and its encryption settings
+        let security = TransportSecurity::from_hub_config(hub_config)?;
+        let mut hub = Self::create(
+            endpoint_str.as_str(),
+            1024,
+            hub_config.huge_pages,
+            Some(&security),
+        )?;
+        if hub_config.latched {
+            hub = hub.latched();
+        }
+        if hub_config.validation.is_empty() {
+            Ok(hub)
+        } else {
+            Ok(hub.with_validator(TopicValidator::from_rules(hub_config.validation.clone())))
+        }
     }
 
     /// Create a Hub from a specific config file path
@@ -224,11 +283,7 @@ impl<
         // Get hub config
         let hub_config = config.get_hub(hub_name)?;
 
-        // Get endpoint string
-        let endpoint_str = hub_config.get_endpoint();
-
-        // Create hub with the endpoint
-        Self::new(&endpoint_str)
+        Self::from_hub_config(hub_config)
     }
 
     /// Create a new Hub with custom capacity
@@ -239,16 +294,49 @@ impl<
     /// - `"topic@192.168.1.5"` → Direct network (future: UDP)
     /// - `"topic@192.168.1.5:9000"` → Direct network with custom port
     /// - `"topic@*"` → Multicast discovery (future)
+    /// - `"topic@5hz"` → Local mirror of `topic` decimated to 5 Hz (see [`super::mirror`])
     ///
     /// Note: Network endpoints require T: serde::Serialize + serde::de::DeserializeOwned
-    pub fn new_with_capacity(topic_name: &str, capacity: usize) -> HorusResult<Self> {
-        // Parse endpoint
-        let endpoint = parse_endpoint(topic_name)?;
+    pub fn new_with_capacity(topic: impl TopicName<T>, capacity: usize) -> HorusResult<Self> {
+        Self::create(topic, capacity, false, None)
+    }
+
+    /// Create a Hub whose shared memory is backed by 2 MiB huge pages
+    ///
+    /// For large messages (map updates, camera frames) where TLB misses show
+    /// up in latency. Needs a hugetlbfs mount with free pages (see
+    /// [`crate::memory::huge_pages`]); otherwise regular shared memory is
+    /// used, check [`uses_huge_pages`](Self::uses_huge_pages).
+    pub fn new_with_huge_pages(topic: impl TopicName<T>, capacity: usize) -> HorusResult<Self> {
+        Self::create(topic, capacity, true, None)
+    }
+
+    /// `security` of network endpoints defaults to [`TransportSecurity::from_env`]
+    fn create(
+        topic: impl TopicName<T>,
+        capacity: usize,
+        huge_pages: bool,
+        security: Option<&TransportSecurity>,
+    ) -> HorusResult<Self> {
+        // Prefix relative topics with the process namespace (see `namespace`)
+        let topic_name = &super::namespace::resolve_endpoint(topic.topic_name());
+
+        // Designated publishers from the config, matched on the topic part
+        let configured =
+            publish_policy::policy_for(topic_name.split('@').next().unwrap_or(topic_name))?;
+
+        // Parse endpoint, routing topics bridged by `horus launch` between hosts
+        let endpoint = apply_bridge(parse_endpoint(topic_name)?);
 
         match endpoint {
             Endpoint::Local { topic } => {
                 // Fast path: local shared memory only
-                let shm_topic = Arc::new(ShmTopic::new(&topic, capacity)?);
+                let shm_topic = Arc::new(if huge_pages {
+                    ShmTopic::new_with_huge_pages(&topic, capacity)?
+                } else {
+                    ShmTopic::new(&topic, capacity)?
+                });
+                let publish_denied = publish_denied(topic_name, &shm_topic, configured)?;
 
                 Ok(Hub {
                     shm_topic: Some(shm_topic),
@@ -257,13 +345,48 @@ impl<
                     topic_name: topic_name.to_string(),
                     state: std::sync::atomic::AtomicU8::new(ConnectionState::Connected.into_u8()),
                     metrics: Arc::new(AtomicHubMetrics::default()),
-                    _padding: [0; 14],
+                    validator: None,
+                    mirror_relay: None,
+                    filter: None,
+                    publish_denied,
+                    _padding: [0; 6],
+                })
+            }
+
+            Endpoint::Mirror { topic, rate_hz } => {
+                // Subscribe to the mirror topic and make sure a relay feeds it
+                let shm_topic = Arc::new(ShmTopic::new(
+                    &mirror_topic_name(&topic, rate_hz),
+                    capacity,
+                )?);
+                let relay = MirrorRelay::acquire::<T>(&topic, rate_hz, capacity)?;
+
+                Ok(Hub {
+                    shm_topic: Some(shm_topic),
+                    network: None,
+                    is_network: false,
+                    topic_name: topic_name.to_string(),
+                    state: std::sync::atomic::AtomicU8::new(ConnectionState::Connected.into_u8()),
+                    metrics: Arc::new(AtomicHubMetrics::default()),
+                    validator: None,
+                    mirror_relay: Some(relay),
+                    filter: None,
+                    publish_denied: false,
+                    _padding: [0; 6],
                 })
             }
 
             // Network endpoints - no shared memory allocated (avoids wasting resources)
             network_endpoint => {
-                let network_backend = NetworkBackend::new(network_endpoint)?;
+                let network_backend = match security {
+                    Some(security) => {
+                        NetworkBackend::new_with_security(network_endpoint, security)?
+                    }
+                    None => NetworkBackend::new_with_security(
+                        network_endpoint,
+                        &TransportSecurity::from_env()?,
+                    )?,
+                };
 
                 Ok(Hub {
                     shm_topic: None, // Network-only: no local shared memory needed
@@ -272,7 +395,11 @@ impl<
                     topic_name: topic_name.to_string(),
                     state: std::sync::atomic::AtomicU8::new(ConnectionState::Connected.into_u8()),
                     metrics: Arc::new(AtomicHubMetrics::default()),
-                    _padding: [0; 14],
+                    validator: None,
+                    mirror_relay: None,
+                    filter: None,
+                    publish_denied: configured.is_some_and(|p| !publish_policy::may_publish(&p)),
+                    _padding: [0; 6],
                 })
             }
         }
@@ -285,11 +412,27 @@ impl<
     /// Supports both local shared memory and network backends transparently
     ///
     /// Note: Network endpoints require T: serde::Serialize
+    ///
+    /// On a topic with designated publishers, sends are rejected unless the
+    /// hub was created with a designated publish key (see `publish_policy`).
     #[inline(always)]
     pub fn send(&self, msg: T, ctx: &mut Option<&mut NodeInfo>) -> Result<(), T>
     where
         T: crate::core::LogSummary,
     {
+        // Topics with designated publishers reject hubs without their key
+        if self.publish_denied {
+            let node = ctx
+                .as_deref()
+                .map(|ctx| ctx.name().to_string())
+                .or_else(publish_policy::identity);
+            publish_policy::report_violation(&self.topic_name, node.as_deref());
+            self.metrics
+                .send_failures
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            return Err(msg);
+        }
+
         // Network path (if network backend is present)
         if self.is_network {
             if let Some(ref network_mutex) = self.network {
@@ -401,13 +544,204 @@ impl<
             }
         }
     }
+    /// Validate received messages against declarative rules
+    ///
+    /// Validation runs at the subscriber boundary inside `recv()` (and
+    /// `on_message` callbacks): violating messages are delivered with a
+    /// warning, dropped, or clamped depending on each rule's action. Clones of
+    /// this Hub share the validator and its statistics.
+    pub fn with_validator(mut self, validator: TopicValidator) -> Self {
+        self.validator = Some(Arc::new(parking_lot::Mutex::new(validator)));
+        self
+    }
+
+    /// Keep the last message for subscribers that join later
+    ///
+    /// For configuration-like topics published once (map metadata, robot
+    /// description): the topic is marked latched in shared memory, so a Hub
+    /// created later for it, in any process, receives the last message on
+    /// its first `recv()`. Either the publisher or the subscriber can set it.
+    /// The value lives as long as some process has the topic open.
+    ///
+    /// Network endpoints are not latched.
+    pub fn latched(self) -> Self {
+        if let Some(shm_topic) = &self.shm_topic {
+            shm_topic.set_latched();
+        }
+        self
+    }
+
+    /// Whether the topic is latched (see [`latched`](Self::latched))
+    pub fn is_latched(&self) -> bool {
+        self.shm_topic
+            .as_ref()
+            .is_some_and(|shm_topic| shm_topic.is_latched())
+    }
+
+    /// Whether the shared memory of the topic is backed by huge pages
+    pub fn uses_huge_pages(&self) -> bool {
+        self.shm_topic
+            .as_ref()
+            .is_some_and(|shm_topic| shm_topic.uses_huge_pages())
+    }
+
+    /// Get validation statistics (None if no validator is attached)
+    pub fn get_validation_stats(&self) -> Option<ValidationStats> {
+        self.validator
+            .as_ref()
+            .map(|validator| validator.lock().stats().clone())
+    }
+
+    /// Only deliver messages matching `predicate`
+    ///
+    /// Messages that don't match are skipped inside `recv()` (and
+    /// `on_message` callbacks) after validation, so `tick()` only sees the
+    /// ones it cares about. Calling `filtered` again adds another condition.
+    /// Clones of this Hub share the filter.
+    ///
+    /// ```rust,ignore
+    /// let detections = Hub::<Detection>::new("detections")?.filtered(|d| d.confidence > 0.5);
+    /// ```
+    pub fn filtered<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&T) -> bool + Send + Sync + 'static,
+    {
+        let filter: MessageFilter<T> = match self.filter.take() {
+            Some(previous) => Arc::new(move |msg: &T| previous(msg) && predicate(msg)),
+            None => Arc::new(predicate),
+        };
+        self.filter = Some(filter);
+        self
+    }
+
+    /// Convert received messages with `f`
+    ///
+    /// Returns a subscriber-side adapter: `recv()` on it returns the converted
+    /// messages. Filters and validators attached to this Hub run first.
+    pub fn map<U, F>(self, f: F) -> MappedHub<T, U>
+    where
+        U: 'static,
+        F: Fn(T) -> U + Send + Sync + 'static,
+    {
+        MappedHub::new(self, Arc::new(move |msg| Some(f(msg))))
+    }
+
+    /// Convert received messages into another type through its `From` impl
+    ///
+    /// ```rust,ignore
+    /// let poses = Hub::<Odometry>::new("odom")?.map_into::<Pose2D>();
+    /// ```
+    pub fn map_into<U>(self) -> MappedHub<T, U>
+    where
+        U: From<T> + 'static,
+    {
+        self.map(U::from)
+    }
+
+    /// Keep the messages received during the last `span`
+    ///
+    /// Returns a `TopicWindow` with time queries, interpolation and
+    /// smoothing helpers; call `update()` on it each tick.
+    ///
+    /// ```rust,ignore
+    /// let mut odom = Hub::<Odometry>::new("odom")?.window(Duration::from_secs(2));
+    /// ```
+    pub fn window(self, span: std::time::Duration) -> TopicWindow<T>
+    where
+        T: crate::core::LogSummary,
+    {
+        TopicWindow::new(self, span)
+    }
+
+    /// Count a message skipped by a filter
+    pub(crate) fn record_filtered(&self) {
+        self.metrics
+            .messages_filtered
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    }
+
     /// Receive a message from the topic
     ///
-    /// Supports both local shared memory and network backends transparently
+    /// Supports both local shared memory and network backends transparently.
+    /// If a validator or filter is attached, messages they drop are skipped.
     ///
     /// Note: Network endpoints require T: serde::de::DeserializeOwned
     #[inline(always)]
     pub fn recv(&self, ctx: &mut Option<&mut NodeInfo>) -> Option<T>
+    where
+        T: crate::core::LogSummary,
+    {
+        let Some(filter) = &self.filter else {
+            return self.recv_validated(ctx);
+        };
+
+        loop {
+            let msg = self.recv_validated(ctx)?;
+            if filter(&msg) {
+                return Some(msg);
+            }
+            self.record_filtered();
+        }
+    }
+
+    /// Receive with validation but without filtering
+    #[inline(always)]
+    fn recv_validated(&self, ctx: &mut Option<&mut NodeInfo>) -> Option<T>
+    where
+        T: crate::core::LogSummary,
+    {
+        let Some(validator) = &self.validator else {
+            return self.recv_raw(ctx);
+        };
+
+        loop {
+            let msg = self.recv_raw(ctx)?;
+            let (outcome, violated) = {
+                let mut validator = validator.lock();
+                let before = validator.stats().total_violations();
+                let outcome = validator.validate(msg);
+                (outcome, validator.stats().total_violations() > before)
+            };
+            match outcome {
+                ValidationOutcome::Deliver(msg, warnings) => {
+                    if violated {
+                        self.metrics
+                            .validation_violations
+                            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    }
+                    if !warnings.is_empty() {
+                        if let Some(ctx) = ctx.as_deref_mut() {
+                            for warning in &warnings {
+                                ctx.log_warning(&format!(
+                                    "Validation on '{}': {}",
+                                    self.topic_name, warning
+                                ));
+                            }
+                        }
+                    }
+                    return Some(msg);
+                }
+                ValidationOutcome::Dropped(reason) => {
+                    self.metrics
+                        .validation_violations
+                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    self.metrics
+                        .validation_drops
+                        .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+                    if let Some(ctx) = ctx.as_deref_mut() {
+                        ctx.log_warning(&format!(
+                            "Dropped message on '{}': {}",
+                            self.topic_name, reason
+                        ));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Receive without validation
+    #[inline(always)]
+    fn recv_raw(&self, ctx: &mut Option<&mut NodeInfo>) -> Option<T>
     where
         T: crate::core::LogSummary,
     {
@@ -502,6 +836,127 @@ impl<
     pub fn get_topic_name(&self) -> &str {
         &self.topic_name
     }
+
+    /// Register a callback invoked for each message on this topic
+    ///
+    /// The callback is attached to the node owning `ctx` (call this from
+    /// `Node::init`). The scheduler dispatches it between ticks, handing at most
+    /// a bounded batch of messages to each callback per scheduler cycle, so
+    /// callbacks never run inside `tick()` and cannot starve other nodes.
+    ///
+    /// The callback shares this Hub's read position: messages it consumes are
+    /// not returned by `recv()` on the same Hub. Only local (shared memory)
+    /// topics are supported.
+    ///
+    /// ```rust,ignore
+    /// fn init(&mut self, ctx: &mut NodeInfo) -> HorusResult<()> {
+    ///     let estop = self.estop_flag.clone();
+    ///     self.estop_sub.on_message(ctx, move |msg: EmergencyStop, ctx| {
+    ///         estop.store(msg.engaged, Ordering::SeqCst);
+    ///         ctx.log_warning("E-stop received");
+    ///     })
+    /// }
+    /// ```
+    pub fn on_message<F>(&self, ctx: &mut NodeInfo, callback: F) -> HorusResult<()>
+    where
+        T: crate::core::LogSummary,
+        F: FnMut(T, &mut NodeInfo) + Send + 'static,
+    {
+        if self.is_network {
+            return Err(crate::error::HorusError::Unsupported(format!(
+                "Message callbacks are not supported on network topic '{}'",
+                self.topic_name
+            )));
+        }
+
+        ctx.register_subscriber(&self.topic_name, std::any::type_name::<T>());
+        ctx.add_message_callback(Box::new(HubCallback {
+            hub: self.clone(),
+            callback,
+        }));
+        Ok(())
+    }
+}
+
+/// Type-erased message callback dispatched by the scheduler between ticks
+pub trait MessageCallback: Send {
+    /// Topic the callback is subscribed to
+    fn topic_name(&self) -> &str;
+
+    /// Deliver up to `max_messages` pending messages, returning how many were delivered
+    fn dispatch(&mut self, ctx: &mut NodeInfo, max_messages: usize) -> usize;
+}
+
+/// Whether this process may not publish on a shared memory topic
+///
+/// Tags already in the segment header win; otherwise the configured
+/// ones are written there for the processes joining later. A config
+/// that disagrees with the segment is an error.
+fn publish_denied<T>(
+    topic_name: &str,
+    shm_topic: &ShmTopic<T>,
+    configured: Option<PublisherPolicy>,
+) -> HorusResult<bool> {
+    let publishers = match configured {
+        Some(policy) => {
+            let mut in_force = shm_topic.set_publishers(policy.tags())?;
+            in_force.sort_unstable();
+            if in_force != policy.tags() {
+                return Err(crate::error::HorusError::Config(format!(
+                    "Publish policy of '{}' differs from the one the topic was created with",
+                    topic_name
+                )));
+            }
+            policy
+        }
+        None => match shm_topic.publishers() {
+            Some(tags) => PublisherPolicy::from_tags(tags),
+            None => return Ok(false),
+        },
+    };
+    let denied = !publish_policy::may_publish(&publishers);
+    if denied {
+        log::info!(
+            "No designated publish key for '{}': the hub is read-only",
+            topic_name
+        );
+    }
+    Ok(denied)
+}
+
+/// Callback registered through `Hub::on_message`
+struct HubCallback<T, F> {
+    hub: Hub<T>,
+    callback: F,
+}
+
+impl<T, F> MessageCallback for HubCallback<T, F>
+where
+    T: Send
+        + Sync
+        + 'static
+        + Clone
+        + std::fmt::Debug
+        + serde::Serialize
+        + serde::de::DeserializeOwned
+        + crate::core::LogSummary,
+    F: FnMut(T, &mut NodeInfo) + Send + 'static,
+{
+    fn topic_name(&self) -> &str {
+        &self.hub.topic_name
+    }
+
+    fn dispatch(&mut self, ctx: &mut NodeInfo, max_messages: usize) -> usize {
+        let mut delivered = 0;
+        while delivered < max_messages {
+            let Some(msg) = self.hub.recv(&mut Some(&mut *ctx)) else {
+                break;
+            };
+            (self.callback)(msg, ctx);
+            delivered += 1;
+        }
+        delivered
+    }
 }
 
 #[cfg(test)]
@@ -610,6 +1065,30 @@ mod tests {
         assert_eq!(received.unwrap(), SimpleValue(42.0));
     }
 
+    #[test]
+    fn test_hub_mirror_relays_latest_message() {
+        let source: Hub<SimpleValue> = Hub::new("test_hub_mirror_src").unwrap();
+        for i in 0..100 {
+            source.send(SimpleValue(i as f64), &mut None).unwrap();
+        }
+
+        // Only the latest message is copied, at the mirror rate
+        let mirror: Hub<SimpleValue> = Hub::new("test_hub_mirror_src@50hz").unwrap();
+        assert_eq!(mirror.get_topic_name(), "test_hub_mirror_src@50hz");
+        let deadline = Instant::now() + std::time::Duration::from_secs(2);
+        let received = loop {
+            if let Some(value) = mirror.recv(&mut None) {
+                break Some(value);
+            }
+            if Instant::now() > deadline {
+                break None;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        };
+        assert_eq!(received, Some(SimpleValue(99.0)));
+        assert!(mirror.recv(&mut None).is_none());
+    }
+
     #[test]
     fn test_hub_send_recv_complex_message() {
         let hub: Hub<TestMessage> = Hub::new("test_send_recv_complex").unwrap();
@@ -625,6 +1104,116 @@ mod tests {
         assert_eq!(received, msg);
     }
 
+    #[test]
+    fn test_hub_on_message_bounded_dispatch() {
+        let hub: Hub<SimpleValue> = Hub::new("test_hub_on_message").unwrap();
+        let mut ctx = NodeInfo::new("callback_node".to_string(), false);
+
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = received.clone();
+        hub.on_message(&mut ctx, move |msg: SimpleValue, _ctx| {
+            sink.lock().unwrap().push(msg.0);
+        })
+        .unwrap();
+        assert_eq!(ctx.message_callback_count(), 1);
+
+        for i in 0..5 {
+            hub.send(SimpleValue(i as f64), &mut None).unwrap();
+        }
+
+        // At most 3 messages per dispatch, remainder on the next one
+        assert_eq!(ctx.dispatch_message_callbacks(3), 3);
+        assert_eq!(ctx.dispatch_message_callbacks(3), 2);
+        assert_eq!(ctx.dispatch_message_callbacks(3), 0);
+        assert_eq!(*received.lock().unwrap(), vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_hub_on_message_after_restart_delivers_once() {
+        let hub: Hub<SimpleValue> = Hub::new("test_hub_on_message_restart").unwrap();
+        let mut ctx = NodeInfo::new("restart_node".to_string(), false);
+
+        let received = Arc::new(std::sync::Mutex::new(Vec::new()));
+        // Registration as a node's init() would do it, run again on restart
+        let register = |ctx: &mut NodeInfo| {
+            let sink = received.clone();
+            hub.on_message(ctx, move |msg: SimpleValue, _ctx| {
+                sink.lock().unwrap().push(msg.0);
+            })
+            .unwrap();
+        };
+        register(&mut ctx);
+        ctx.reset_for_restart();
+        register(&mut ctx);
+        assert_eq!(ctx.message_callback_count(), 1);
+
+        for i in 0..4 {
+            hub.send(SimpleValue(i as f64), &mut None).unwrap();
+        }
+        assert_eq!(ctx.dispatch_message_callbacks(10), 4);
+        assert_eq!(*received.lock().unwrap(), vec![0.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_hub_validation() {
+        use crate::communication::validation::{TopicValidator, ValidationAction};
+
+        let hub: Hub<SimpleValue> = Hub::new("test_hub_validation").unwrap().with_validator(
+            TopicValidator::new().finite(ValidationAction::Drop).range(
+                "",
+                0.0,
+                10.0,
+                ValidationAction::Clamp,
+            ),
+        );
+
+        hub.send(SimpleValue(42.0), &mut None).unwrap();
+        assert_eq!(hub.recv(&mut None), Some(SimpleValue(10.0)));
+
+        hub.send(SimpleValue(f64::NAN), &mut None).unwrap();
+        assert!(hub.recv(&mut None).is_none());
+
+        hub.send(SimpleValue(5.0), &mut None).unwrap();
+        assert_eq!(hub.recv(&mut None), Some(SimpleValue(5.0)));
+
+        let metrics = hub.get_metrics();
+        assert_eq!(metrics.validation_violations, 2);
+        assert_eq!(metrics.validation_drops, 1);
+
+        let stats = hub.get_validation_stats().unwrap();
+        assert_eq!(stats.checked, 3);
+        assert_eq!(stats.clamped, 1);
+        assert_eq!(stats.dropped, 1);
+    }
+
+    #[test]
+    fn test_hub_latched_late_subscriber() {
+        let publisher: Hub<SimpleValue> = Hub::new("test_hub_latched").unwrap().latched();
+        assert!(publisher.is_latched());
+        publisher.send(SimpleValue(1.0), &mut None).unwrap();
+        publisher.send(SimpleValue(2.0), &mut None).unwrap();
+
+        // Joins after the last publish and still gets it, once
+        let late: Hub<SimpleValue> = Hub::new("test_hub_latched").unwrap();
+        assert!(late.is_latched());
+        assert_eq!(late.recv(&mut None), Some(SimpleValue(2.0)));
+        assert_eq!(late.recv(&mut None), None);
+
+        publisher.send(SimpleValue(3.0), &mut None).unwrap();
+        assert_eq!(late.recv(&mut None), Some(SimpleValue(3.0)));
+
+        // Without latching, late subscribers only see new messages
+        let plain: Hub<SimpleValue> = Hub::new("test_hub_not_latched").unwrap();
+        plain.send(SimpleValue(1.0), &mut None).unwrap();
+        let late: Hub<SimpleValue> = Hub::new("test_hub_not_latched").unwrap();
+        assert!(!late.is_latched());
+        assert_eq!(late.recv(&mut None), None);
+
+        // Latching on the subscriber side delivers the last message too
+        let late: Hub<SimpleValue> = Hub::new("test_hub_not_latched").unwrap().latched();
+        assert_eq!(late.recv(&mut None), Some(SimpleValue(1.0)));
+    }
+
     #[test]
     fn test_hub_recv_empty() {
         let hub: Hub<SimpleValue> = Hub::new("test_recv_empty").unwrap();
@@ -873,6 +1462,61 @@ mod tests {
         assert!(received.data.iter().all(|&b| b == 42));
     }
 
+    #[test]
+    fn test_hub_designated_publishers() {
+        use crate::communication::config::TopicPublishPolicy;
+
+        let mut config = std::collections::HashMap::new();
+        config.insert(
+            "test_hub_perm.*".to_string(),
+            TopicPublishPolicy {
+                publishers: [(
+                    "arm_driver".to_string(),
+                    publish_policy::key_hash("arm-driver-key"),
+                )]
+                .into_iter()
+                .collect(),
+            },
+        );
+        publish_policy::set_publish_policy(config);
+        let _reset = publish_policy::ResetPublishPolicy;
+
+        // Without the key the hub is read-only, whatever the node name
+        let reader: Hub<SimpleValue> = Hub::new("test_hub_perm.joint").unwrap();
+        let mut driver = NodeInfo::new("arm_driver".to_string(), false);
+        assert!(reader
+            .send(SimpleValue(1.0), &mut Some(&mut driver))
+            .is_err());
+        assert_eq!(reader.get_metrics().send_failures, 1);
+
+        publish_policy::set_publish_key("arm-driver-key");
+        let hub: Hub<SimpleValue> = Hub::new("test_hub_perm.joint").unwrap();
+        assert!(hub.send(SimpleValue(2.0), &mut None).is_ok());
+        assert_eq!(reader.recv(&mut None), Some(SimpleValue(2.0)));
+
+        // The tags live in the segment: joining without config is still restricted
+        publish_policy::set_publish_policy(std::collections::HashMap::new());
+        publish_policy::set_publish_key("guessed-key");
+        let late: Hub<SimpleValue> = Hub::new("test_hub_perm.joint").unwrap();
+        assert!(late.send(SimpleValue(3.0), &mut None).is_err());
+
+        // A config naming other keys cannot reopen the topic
+        let mut other = std::collections::HashMap::new();
+        other.insert(
+            "test_hub_perm.joint".to_string(),
+            TopicPublishPolicy {
+                publishers: [(
+                    "intruder".to_string(),
+                    publish_policy::key_hash("guessed-key"),
+                )]
+                .into_iter()
+                .collect(),
+            },
+        );
+        publish_policy::set_publish_policy(other);
+        assert!(Hub::<SimpleValue>::new("test_hub_perm.joint").is_err());
+    }
+
     #[test]
     fn test_hub_with_empty_string_topic() {
         // Empty topic name should still work (or fail gracefully)
@@ -75,6 +75,8 @@ pub struct LinkMetrics {
     pub messages_received: u64,
     pub send_failures: u64,
     pub recv_failures: u64,
+    /// Shared memory is backed by hugetlbfs huge pages
+    pub huge_pages: bool,
 }
 
 /// Lock-free atomic metrics for Link monitoring (stored in local memory)
@@ -393,7 +395,13 @@ where
 {
     /// Create a Unix socket producer with connect() for fast send()
     pub fn new_producer(topic: &str, consumer_path: &str) -> HorusResult<Self> {
-        let socket_path = format!("/tmp/horus_link_{}_{}.sock", topic, std::process::id());
+        let socket_path = format!(
+            "{}/{}link_{}_{}.sock",
+            crate::memory::platform::link_socket_dir().display(),
+            crate::memory::platform::shm_object_prefix(),
+            topic,
+            std::process::id()
+        );
 
         // Remove if exists
         let _ = std::fs::remove_file(&socket_path);
@@ -422,7 +430,12 @@ where
 
     /// Create a Unix socket consumer
     pub fn new_consumer(topic: &str) -> HorusResult<Self> {
-        let socket_path = format!("/tmp/horus_link_{}_consumer.sock", topic);
+        let socket_path = format!(
+            "{}/{}link_{}_consumer.sock",
+            crate::memory::platform::link_socket_dir().display(),
+            crate::memory::platform::shm_object_prefix(),
+            topic
+        );
 
         // Remove if exists
         let _ = std::fs::remove_file(&socket_path);
@@ -680,7 +693,7 @@ where
     /// output.send(42.0, None)?;
     /// ```
     pub fn producer(topic: &str) -> HorusResult<Self> {
-        Self::with_role(topic, LinkRole::Producer)
+        Self::with_role(topic, LinkRole::Producer, false)
     }
 
     /// Create a Link as a consumer (receiver)
@@ -704,7 +717,24 @@ where
     /// }
     /// ```
     pub fn consumer(topic: &str) -> HorusResult<Self> {
-        Self::with_role(topic, LinkRole::Consumer)
+        Self::with_role(topic, LinkRole::Consumer, false)
+    }
+
+    /// Create a Link producer whose shared memory uses 2 MiB huge pages
+    ///
+    /// For large messages such as camera frames. Falls back to regular pages
+    /// when no hugetlbfs mount has free pages, or when the consumer already
+    /// created the link without them; `get_metrics().huge_pages` reports
+    /// which one was used. Consumers join a huge page link automatically.
+    pub fn producer_with_huge_pages(topic: &str) -> HorusResult<Self> {
+        Self::with_role(topic, LinkRole::Producer, true)
+    }
+
+    /// Create a Link consumer whose shared memory uses 2 MiB huge pages
+    ///
+    /// See [`producer_with_huge_pages`](Self::producer_with_huge_pages).
+    pub fn consumer_with_huge_pages(topic: &str) -> HorusResult<Self> {
+        Self::with_role(topic, LinkRole::Consumer, true)
     }
 
     /// Create a Link as a producer (alias for `producer`)
@@ -793,7 +823,7 @@ where
         let endpoint_str = link_config.get_endpoint();
 
         // Create producer with the endpoint
-        Self::producer(&endpoint_str)
+        Self::with_role(&endpoint_str, LinkRole::Producer, link_config.huge_pages)
     }
 
     /// Create a Link producer from a specific config file path
@@ -822,7 +852,7 @@ where
         let endpoint_str = link_config.get_endpoint();
 
         // Create producer with the endpoint
-        Self::producer(&endpoint_str)
+        Self::with_role(&endpoint_str, LinkRole::Producer, link_config.huge_pages)
     }
 
     /// Create a Link consumer from configuration file
@@ -862,7 +892,7 @@ where
         let endpoint_str = link_config.get_endpoint();
 
         // Create consumer with the endpoint
-        Self::consumer(&endpoint_str)
+        Self::with_role(&endpoint_str, LinkRole::Consumer, link_config.huge_pages)
     }
 
     /// Create a Link consumer from a specific config file path
@@ -891,19 +921,22 @@ where
         let endpoint_str = link_config.get_endpoint();
 
         // Create consumer with the endpoint
-        Self::consumer(&endpoint_str)
+        Self::with_role(&endpoint_str, LinkRole::Consumer, link_config.huge_pages)
     }
 
     // ====== INTERNAL IMPLEMENTATION ======
 
     /// Internal method to create Link with explicit role
-    fn with_role(topic: &str, role: LinkRole) -> HorusResult<Self> {
+    fn with_role(topic: &str, role: LinkRole, huge_pages: bool) -> HorusResult<Self> {
         let element_size = mem::size_of::<T>();
 
         if element_size == 0 {
             return Err("Cannot create Link for zero-sized types".into());
         }
 
+        // Prefix relative topics with the process namespace (see `namespace`)
+        let topic = &super::namespace::resolve_endpoint(topic);
+
         // Parse endpoint: check if it's network (contains '@')
         if topic.contains('@') {
             // Network endpoint
@@ -911,7 +944,7 @@ where
         }
 
         // Local shared memory
-        Self::create_local_link(topic, role)
+        Self::create_local_link(topic, role, huge_pages)
     }
 
     /// Create a network-based Link with smart transport selection
@@ -1043,7 +1076,12 @@ where
             #[cfg(unix)]
             {
                 // For localhost, prefer Unix sockets - ~1-2µs latency
-                let consumer_path = format!("/tmp/horus_link_{}_consumer.sock", topic);
+                let consumer_path = format!(
+                    "{}/{}link_{}_consumer.sock",
+                    crate::memory::platform::link_socket_dir().display(),
+                    crate::memory::platform::shm_object_prefix(),
+                    topic
+                );
                 let backend = match role {
                     LinkRole::Producer => {
                         UnixSocketLinkBackend::new_producer(topic, &consumer_path)?
@@ -1084,7 +1122,7 @@ where
     }
 
     /// Create a local shared memory Link
-    fn create_local_link(topic: &str, role: LinkRole) -> HorusResult<Self> {
+    fn create_local_link(topic: &str, role: LinkRole, huge_pages: bool) -> HorusResult<Self> {
         let element_size = mem::size_of::<T>();
         let element_align = mem::align_of::<T>();
         let header_size = mem::size_of::<LinkHeader>();
@@ -1094,7 +1132,11 @@ where
         let total_size = aligned_header_size + element_size;
 
         let link_name = format!("links/{}", topic);
-        let shm_region = Arc::new(ShmRegion::new(&link_name, total_size)?);
+        let shm_region = Arc::new(if huge_pages {
+            ShmRegion::new_with_huge_pages(&link_name, total_size)?
+        } else {
+            ShmRegion::new(&link_name, total_size)?
+        });
 
         // Use role names for logging
         let (producer_node, consumer_node) = match role {
@@ -1483,6 +1525,10 @@ where
             messages_received: self.metrics.messages_received.load(Ordering::Relaxed),
             send_failures: self.metrics.send_failures.load(Ordering::Relaxed),
             recv_failures: self.metrics.recv_failures.load(Ordering::Relaxed),
+            huge_pages: self
+                .shm_region
+                .as_ref()
+                .is_some_and(|region| region.uses_huge_pages()),
         }
     }
 }
@@ -1934,6 +1980,7 @@ mod tests {
             messages_received: 5,
             send_failures: 1,
             recv_failures: 2,
+            huge_pages: false,
         };
         let cloned = metrics.clone();
         assert_eq!(cloned.messages_sent, 10);
@@ -0,0 +1,206 @@
+//! Decimated mirror topics
+//!
+//! Subscribing to `topic@5hz` gives a copy of `topic` limited to 5 messages
+//! per second, for visualization and bridges that have no use for the full
+//! rate of a kilohertz control topic:
+//!
+//! ```rust,no_run
+//! use horus_core::communication::Hub;
+//! let imu: Hub<f64> = Hub::new("imu.raw@5hz").unwrap();
+//! ```
+//!
+//! The mirror is an ordinary shared memory topic named `topic@5hz`. A relay
+//! thread, started with the first mirror subscription in a process, polls
+//! the source topic at the mirror rate and copies only its latest message,
+//! so the cost does not depend on the source rate. When several processes
+//! subscribe to the same mirror, one of them relays and the others stand by,
+//! taking over if it exits.
+
+use crate::error::HorusResult;
+use crate::memory::platform::shm_base_dir;
+use crate::memory::shm_topic::ShmTopic;
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock, Weak};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Longest sleep of the relay thread, so it stops promptly at low rates
+const MAX_SLEEP: Duration = Duration::from_millis(50);
+
+/// Shared memory topic name of the mirror of `topic` at `rate_hz`
+///
+/// The rate is normalized (`5.0` and `5` both give `topic@5hz`), so every
+/// process agrees on the name.
+pub fn mirror_topic_name(topic: &str, rate_hz: f64) -> String {
+    format!("{}@{}hz", topic, rate_hz)
+}
+
+/// Relay thread copying a source topic into its mirror
+///
+/// Shared by all mirror hubs of the same topic and rate in a process; the
+/// thread stops when the last of them is dropped.
+pub struct MirrorRelay {
+    name: String,
+    running: Arc<AtomicBool>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl MirrorRelay {
+    /// Get the relay for `topic` at `rate_hz`, starting it if needed
+    pub fn acquire<T>(topic: &str, rate_hz: f64, capacity: usize) -> HorusResult<Arc<Self>>
+    where
+        T: Clone + Send + 'static,
+    {
+        static RELAYS: OnceLock<parking_lot::Mutex<HashMap<String, Weak<MirrorRelay>>>> =
+            OnceLock::new();
+
+        let name = mirror_topic_name(topic, rate_hz);
+        let mut relays = RELAYS.get_or_init(Default::default).lock();
+        if let Some(relay) = relays.get(&name).and_then(Weak::upgrade) {
+            return Ok(relay);
+        }
+        relays.retain(|_, relay| relay.strong_count() > 0);
+
+        let mirror = ShmTopic::<T>::new(&name, capacity)?;
+        let running = Arc::new(AtomicBool::new(true));
+        let source = topic.to_string();
+        let period = Duration::from_secs_f64(1.0 / rate_hz);
+        let lock_path = relay_lock_path(&name);
+        let thread = std::thread::Builder::new()
+            .name(format!("horus-mirror-{}", name))
+            .spawn({
+                let running = Arc::clone(&running);
+                move || run_relay(&source, &mirror, period, &lock_path, &running)
+            })?;
+
+        let relay = Arc::new(MirrorRelay {
+            name: name.clone(),
+            running,
+            thread: Some(thread),
+        });
+        relays.insert(name, Arc::downgrade(&relay));
+        Ok(relay)
+    }
+
+    /// Name of the mirror topic
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Drop for MirrorRelay {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+impl std::fmt::Debug for MirrorRelay {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MirrorRelay")
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+fn run_relay<T: Clone>(
+    source_name: &str,
+    mirror: &ShmTopic<T>,
+    period: Duration,
+    lock_path: &Path,
+    running: &AtomicBool,
+) {
+    let mut lock: Option<File> = None;
+    let mut source: Option<ShmTopic<T>> = None;
+    let mut last_sequence = None;
+    let mut next_run = Instant::now();
+
+    while running.load(Ordering::Relaxed) {
+        let now = Instant::now();
+        if now < next_run {
+            std::thread::sleep((next_run - now).min(MAX_SLEEP));
+            continue;
+        }
+        next_run = if now - next_run < period {
+            next_run + period
+        } else {
+            now + period
+        };
+
+        // Only the process holding the lock relays; the others stand by
+        if lock.is_none() {
+            lock = try_lock_relay(lock_path);
+            if lock.is_none() {
+                continue;
+            }
+        }
+
+        // The source may be created after the mirror is subscribed
+        if source.is_none() {
+            source = ShmTopic::open(source_name).ok();
+        }
+        let Some(source) = &source else {
+            continue;
+        };
+
+        let sequence = source.sequence();
+        if last_sequence == Some(sequence) {
+            continue;
+        }
+        if let Some(sample) = source.read_latest() {
+            let _ = mirror.loan_and_write(sample.get_ref().clone());
+            last_sequence = Some(sequence);
+        }
+    }
+}
+
+fn relay_lock_path(name: &str) -> PathBuf {
+    shm_base_dir()
+        .join("mirrors")
+        .join(format!("{}.lock", name.replace('/', "_")))
+}
+
+/// Take the cross-process relay lock for a mirror, released when the file
+/// is closed or the process exits
+#[cfg(unix)]
+fn try_lock_relay(path: &Path) -> Option<File> {
+    use std::os::unix::io::AsRawFd;
+
+    std::fs::create_dir_all(path.parent()?).ok()?;
+    let file = std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)
+        .ok()?;
+    let locked = unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) } == 0;
+    locked.then_some(file)
+}
+
+/// Without `flock` every subscribing process relays
+#[cfg(not(unix))]
+fn try_lock_relay(path: &Path) -> Option<File> {
+    std::fs::create_dir_all(path.parent()?).ok()?;
+    std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(path)
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mirror_topic_name_normalizes_rate() {
+        assert_eq!(mirror_topic_name("imu.raw", 5.0), "imu.raw@5hz");
+        assert_eq!(mirror_topic_name("imu.raw", 2.5), "imu.raw@2.5hz");
+    }
+}
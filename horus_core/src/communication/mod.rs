@@ -4,6 +4,12 @@
 //!
 //! - **Hub**: MPMC publisher-subscriber pattern (167-6994 ns/msg)
 //! - **Link**: SPSC point-to-point channels (85-167 ns/msg, ultra-low latency)
+//! - **Mirror topics**: `topic@5hz` decimated copies for monitors and bridges
+//! - **Adapters**: `hub.filtered(..)` and `hub.map_into::<U>()` applied before delivery
+//! - **SyncSubscriber**: messages of 2-4 topics grouped by timestamp
+//! - **Windows**: `hub.window(span)` keeps the last seconds of a topic
+//! - **Namespaces**: `robot1/scan` instead of `scan` for multi-robot deployments
+//! - **Publish policy**: only holders of a designated publish key can publish to actuator topics
 //!
 //! ## Usage Patterns
 //!
@@ -27,19 +33,34 @@
 //! }
 //! ```
 
+pub mod adapter;
 pub mod config;
 pub mod hub;
 pub mod link;
+pub mod mirror;
+pub mod namespace;
 pub mod network;
 pub mod pod;
+pub mod publish_policy;
+pub mod sync;
+pub mod topic;
 pub mod traits;
+pub mod validation;
+pub mod window;
 
 // Re-export commonly used types for convenience
-pub use config::{HorusConfig, HubConfig};
-pub use hub::Hub;
+pub use adapter::{MappedHub, MessageFilter};
+pub use config::{HorusConfig, HubConfig, TopicPublishPolicy};
+pub use hub::{Hub, MessageCallback};
 pub use link::{ConnectionState, Link, LinkMetrics, LinkRole};
 pub use pod::{PodLink, PodMessage};
+pub use sync::{SyncInput, SyncSubscriber};
+pub use topic::{Topic, TopicInfo, TopicName};
 pub use traits::{Channel, Publisher, Subscriber};
+pub use validation::{
+    TopicValidator, ValidationAction, ValidationRule, ValidationRuleSpec, ValidationStats,
+};
+pub use window::TopicWindow;
 
 use crate::communication::traits::{Publisher as PublisherTrait, Subscriber as SubscriberTrait};
 
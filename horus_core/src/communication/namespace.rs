@@ -0,0 +1,192 @@
+//! Topic namespaces for multi-robot deployments
+//!
+//! A namespace prefixes every relative topic name of a process, so two robot
+//! stacks sharing a host or network use `robot1/scan` and `robot2/scan`
+//! instead of colliding on `scan`. Node code keeps using plain names.
+//!
+//! The namespace of a process is, in order of precedence:
+//! 1. set with [`set_namespace`] (or `Scheduler::with_namespace`)
+//! 2. [`NAMESPACE_ENV`], followed by [`NODE_NAMESPACE_ENV`] (both set by
+//!    `horus run --namespace` and `horus launch`)
+//!
+//! Names starting with `/` are absolute and never prefixed: `/clock` is the
+//! shared `clock` topic in every namespace.
+//!
+//! ```rust,ignore
+//! use horus_core::communication::namespace;
+//!
+//! namespace::set_namespace("robot1")?;
+//! let scan: Hub<LaserScan> = Hub::new("scan")?;     // robot1/scan
+//! let clock: Hub<Clock> = Hub::new("/clock")?;      // clock
+//! ```
+
+use crate::error::{HorusError, HorusResult};
+use std::sync::RwLock;
+
+/// Namespace of the process (`robot1`, `fleet/robot1`)
+pub const NAMESPACE_ENV: &str = "HORUS_NAMESPACE";
+
+/// Namespace of a single node inside [`NAMESPACE_ENV`] (set by `horus launch`)
+pub const NODE_NAMESPACE_ENV: &str = "HORUS_NODE_NAMESPACE";
+
+/// Separator between namespace segments and the topic name
+pub const NAMESPACE_SEPARATOR: char = '/';
+
+/// Namespace set in code; `Some("")` disables the environment namespace
+static NAMESPACE: RwLock<Option<String>> = RwLock::new(None);
+
+/// Set the namespace of this process, overriding the environment
+///
+/// Applies to topics created afterwards. An empty namespace disables
+/// namespacing even if [`NAMESPACE_ENV`] is set.
+pub fn set_namespace(namespace: &str) -> HorusResult<()> {
+    let namespace = normalize(namespace);
+    validate_namespace(&namespace)?;
+    *NAMESPACE.write().unwrap_or_else(|e| e.into_inner()) = Some(namespace);
+    Ok(())
+}
+
+/// Forget the namespace set with [`set_namespace`] and use the environment again
+pub fn clear_namespace() {
+    *NAMESPACE.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Namespace of this process, if any
+pub fn current_namespace() -> Option<String> {
+    let explicit = NAMESPACE.read().unwrap_or_else(|e| e.into_inner()).clone();
+    let namespace = match explicit {
+        Some(namespace) => namespace,
+        None => {
+            let global = std::env::var(NAMESPACE_ENV).unwrap_or_default();
+            let node = std::env::var(NODE_NAMESPACE_ENV).unwrap_or_default();
+            join(&normalize(&global), &normalize(&node))
+        }
+    };
+    if namespace.is_empty() {
+        None
+    } else {
+        Some(namespace)
+    }
+}
+
+/// Check that a namespace only uses `[A-Za-z0-9_-]` segments separated by `/`
+pub fn validate_namespace(namespace: &str) -> HorusResult<()> {
+    let namespace = normalize(namespace);
+    if namespace.is_empty() {
+        return Ok(());
+    }
+    for segment in namespace.split(NAMESPACE_SEPARATOR) {
+        if segment.is_empty() {
+            return Err(HorusError::Config(format!(
+                "Invalid namespace '{}': empty segment",
+                namespace
+            )));
+        }
+        if !segment
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            return Err(HorusError::Config(format!(
+                "Invalid namespace '{}': segments may only contain letters, digits, '_' and '-'",
+                namespace
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Full name of `name` in `namespace`
+///
+/// Relative names are prefixed (`robot1` + `scan` -> `robot1/scan`); absolute
+/// names lose their leading `/` and are not prefixed. Without a namespace the
+/// name is returned unchanged.
+pub fn apply_namespace(namespace: Option<&str>, name: &str) -> String {
+    let namespace = namespace.map(normalize).unwrap_or_default();
+    if namespace.is_empty() {
+        return name.to_string();
+    }
+    match name.strip_prefix(NAMESPACE_SEPARATOR) {
+        Some(absolute) => absolute.to_string(),
+        None => join(&namespace, name),
+    }
+}
+
+/// Full name of a topic in the current namespace
+pub fn resolve_topic(name: &str) -> String {
+    apply_namespace(current_namespace().as_deref(), name)
+}
+
+/// Namespace the topic part of an endpoint (`scan@router` -> `robot1/scan@router`)
+pub fn apply_namespace_to_endpoint(namespace: Option<&str>, endpoint: &str) -> String {
+    match endpoint.split_once('@') {
+        Some((topic, location)) => {
+            format!("{}@{}", apply_namespace(namespace, topic), location)
+        }
+        None => apply_namespace(namespace, endpoint),
+    }
+}
+
+/// Endpoint with its topic in the current namespace
+pub fn resolve_endpoint(endpoint: &str) -> String {
+    apply_namespace_to_endpoint(current_namespace().as_deref(), endpoint)
+}
+
+fn normalize(namespace: &str) -> String {
+    namespace
+        .trim()
+        .trim_matches(NAMESPACE_SEPARATOR)
+        .to_string()
+}
+
+fn join(namespace: &str, name: &str) -> String {
+    match (namespace.is_empty(), name.is_empty()) {
+        (true, _) => name.to_string(),
+        (_, true) => namespace.to_string(),
+        _ => format!("{}{}{}", namespace, NAMESPACE_SEPARATOR, name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_namespace() {
+        assert_eq!(apply_namespace(Some("robot1"), "scan"), "robot1/scan");
+        assert_eq!(
+            apply_namespace(Some("/fleet/robot1/"), "sensors.imu"),
+            "fleet/robot1/sensors.imu"
+        );
+        assert_eq!(apply_namespace(Some("robot1"), "/clock"), "clock");
+        assert_eq!(apply_namespace(None, "scan"), "scan");
+        assert_eq!(apply_namespace(Some(""), "/scan"), "/scan");
+    }
+
+    #[test]
+    fn test_validate_namespace() {
+        assert!(validate_namespace("robot1").is_ok());
+        assert!(validate_namespace("fleet/robot_2-a").is_ok());
+        assert!(validate_namespace("").is_ok());
+        assert!(validate_namespace("robot 1").is_err());
+        assert!(validate_namespace("fleet//robot1").is_err());
+        assert!(validate_namespace("robot@1").is_err());
+    }
+
+    #[test]
+    fn test_apply_namespace_to_endpoint() {
+        let ns = Some("robot7");
+        assert_eq!(
+            apply_namespace_to_endpoint(ns, "odom@router"),
+            "robot7/odom@router"
+        );
+        assert_eq!(apply_namespace_to_endpoint(ns, "/map@5hz"), "map@5hz");
+        assert_eq!(apply_namespace_to_endpoint(ns, "cmd_vel"), "robot7/cmd_vel");
+        assert_eq!(
+            apply_namespace_to_endpoint(None, "odom@router"),
+            "odom@router"
+        );
+
+        // Invalid namespaces are rejected before changing the process namespace
+        assert!(set_namespace("bad name").is_err());
+    }
+}
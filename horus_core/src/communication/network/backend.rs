@@ -1,5 +1,6 @@
 use super::endpoint::Endpoint;
 use super::router::RouterBackend;
+use super::security::{SecureChannel, TransportSecurity};
 use super::smart_copy::{CopyStrategy, SmartCopyConfig, SmartCopySender};
 use super::smart_transport::{NetworkLocation, TransportSelector, TransportType};
 use super::udp_direct::UdpDirectBackend;
@@ -102,6 +103,10 @@ where
                 "Local endpoint should use shared memory, not network backend".to_string(),
             )),
 
+            Endpoint::Mirror { .. } => Err(crate::error::HorusError::Communication(
+                "Mirror endpoint should use shared memory, not network backend".to_string(),
+            )),
+
             Endpoint::Localhost { topic, .. } => {
                 // For localhost, use smart transport selection
                 let localhost_addr: SocketAddr = "127.0.0.1:0".parse().unwrap();
@@ -166,6 +171,51 @@ where
         }
     }
 
+    /// Create a network backend with encryption and authentication
+    ///
+    /// A pre-shared key is supported on direct UDP and router endpoints,
+    /// TLS on router endpoints. Localhost endpoints never leave the machine
+    /// and stay unencrypted.
+    pub fn new_with_security(
+        endpoint: Endpoint,
+        security: &TransportSecurity,
+    ) -> HorusResult<Self> {
+        match (endpoint, security) {
+            (endpoint, TransportSecurity::None) => Self::new(endpoint),
+
+            (endpoint @ Endpoint::Localhost { .. }, _) => Self::new(endpoint),
+
+            (Endpoint::Router { topic, host, port }, security) => {
+                let host = host.unwrap_or_else(|| "127.0.0.1".parse().unwrap());
+                let router_backend =
+                    RouterBackend::new_with_security(&topic, host, port.unwrap_or(7777), security)?;
+                Ok(NetworkBackend::Router(router_backend))
+            }
+
+            // Sealed datagrams go through the standard UDP backend
+            (Endpoint::Direct { topic, host, port }, TransportSecurity::Psk(psk)) => {
+                let channel = Arc::new(SecureChannel::new(psk, &topic));
+                let udp_backend =
+                    UdpDirectBackend::new_with_channel(&topic, host, port, Some(channel))?;
+                Ok(NetworkBackend::UdpDirect(udp_backend))
+            }
+
+            (endpoint, TransportSecurity::Tls(_)) => {
+                Err(crate::error::HorusError::Communication(format!(
+                    "TLS is only supported on router endpoints (topic@router), not {:?}; use a pre-shared key for direct endpoints",
+                    endpoint
+                )))
+            }
+
+            (endpoint, TransportSecurity::Psk(_)) => {
+                Err(crate::error::HorusError::Communication(format!(
+                    "Pre-shared keys are only supported on direct and router endpoints, not {:?}",
+                    endpoint
+                )))
+            }
+        }
+    }
+
     /// Create backend for a specific address using smart transport selection
     fn create_for_address(topic: &str, addr: SocketAddr, is_localhost: bool) -> HorusResult<Self> {
         let selector = TransportSelector::new();
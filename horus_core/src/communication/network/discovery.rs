@@ -2,8 +2,12 @@
 ///
 /// Implements automatic peer discovery using UDP multicast on 239.255.72.85:9871
 /// Target: <1ms discovery for 10 peers
+///
+/// Each `HORUS_DOMAIN_ID` uses its own discovery port (9871 + domain), so
+/// systems in different domains never see each other's announcements.
 use crate::communication::network::protocol::{HorusPacket, MessageType};
 use crate::error::HorusResult;
+use crate::memory::platform::domain_id;
 use log::{error, warn};
 use std::collections::HashMap;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr, UdpSocket};
@@ -14,6 +18,17 @@ const MULTICAST_ADDR: &str = "239.255.72.85"; // HORUS multicast group
 const MULTICAST_PORT: u16 = 9871;
 const DISCOVERY_TIMEOUT: Duration = Duration::from_millis(500);
 const PEER_TIMEOUT: Duration = Duration::from_secs(30); // Remove peers not seen in 30s
+const DOMAIN_PORT_SPAN: u32 = 50_000; // Keeps every domain port below 65535
+
+/// Discovery port of a domain (None is the default domain)
+///
+/// Sockets bound to different ports don't receive each other's multicast
+/// traffic, even within the same group. Domain IDs that are a multiple of
+/// 50000 apart share a port.
+fn discovery_port(domain: Option<u32>) -> u16 {
+    let offset = domain.map(|id| id % DOMAIN_PORT_SPAN).unwrap_or(0);
+    (MULTICAST_PORT as u32 + offset) as u16
+}
 
 /// Peer information discovered via multicast
 #[derive(Debug, Clone)]
@@ -33,9 +48,15 @@ pub struct DiscoveryService {
 }
 
 impl DiscoveryService {
-    /// Create a new discovery service
+    /// Create a new discovery service for this process's domain
     pub fn new() -> HorusResult<Self> {
-        let socket = UdpSocket::bind(("0.0.0.0", MULTICAST_PORT))
+        Self::with_domain(domain_id())
+    }
+
+    /// Create a discovery service for an explicit domain (None is the default)
+    pub fn with_domain(domain: Option<u32>) -> HorusResult<Self> {
+        let port = discovery_port(domain);
+        let socket = UdpSocket::bind(("0.0.0.0", port))
             .map_err(|e| format!("Failed to bind discovery socket: {}", e))?;
 
         socket
@@ -54,7 +75,7 @@ impl DiscoveryService {
             .join_multicast_v4(&multicast_ip, &Ipv4Addr::UNSPECIFIED)
             .map_err(|e| format!("Failed to join multicast group: {}", e))?;
 
-        let multicast_addr = SocketAddr::new(IpAddr::V4(multicast_ip), MULTICAST_PORT);
+        let multicast_addr = SocketAddr::new(IpAddr::V4(multicast_ip), port);
 
         let service = Self {
             socket,
@@ -245,6 +266,29 @@ mod tests {
     // These tests require exclusive network access to port 9871
     // Run with: cargo test -- --ignored --test-threads=1
 
+    #[test]
+    fn test_discovery_port_per_domain() {
+        assert_eq!(discovery_port(None), MULTICAST_PORT);
+        assert_eq!(discovery_port(Some(7)), MULTICAST_PORT + 7);
+        assert_ne!(discovery_port(Some(1)), discovery_port(Some(2)));
+        assert_eq!(
+            discovery_port(Some(DOMAIN_PORT_SPAN + 3)),
+            MULTICAST_PORT + 3
+        );
+        assert!(discovery_port(Some(u32::MAX)) > MULTICAST_PORT);
+    }
+
+    #[test]
+    #[ignore = "requires exclusive network access to multicast ports 9872-9873"]
+    fn test_domains_do_not_discover_each_other() {
+        let domain_a = DiscoveryService::with_domain(Some(1)).unwrap();
+        let domain_b = DiscoveryService::with_domain(Some(2)).unwrap();
+        assert_ne!(domain_a.multicast_addr, domain_b.multicast_addr);
+
+        domain_a.announce("domain_topic", 9870).unwrap();
+        assert!(domain_b.discover("domain_topic").unwrap().is_empty());
+    }
+
     #[test]
     #[ignore = "requires exclusive network access to multicast port 9871"]
     fn test_discovery_service_creation() {
@@ -1,4 +1,4 @@
-use std::net::IpAddr;
+use std::net::{IpAddr, SocketAddr};
 
 /// Endpoint types for HORUS network communication
 #[derive(Debug, Clone, PartialEq)]
@@ -26,6 +26,10 @@ pub enum Endpoint {
         port: Option<u16>,
     },
 
+    /// Decimated local mirror: "topic@5hz"
+    /// Served from shared memory by a relay that samples `topic` at `rate_hz`
+    Mirror { topic: String, rate_hz: f64 },
+
     /// Zenoh transport: "topic@zenoh" or "topic@zenoh/ros2"
     /// Supports multi-robot mesh, cloud connectivity, and ROS2 interop
     Zenoh {
@@ -44,6 +48,55 @@ pub const DEFAULT_PORT: u16 = 9870;
 pub const MULTICAST_ADDR: &str = "239.255.72.85";
 pub const MULTICAST_PORT: u16 = 9871;
 
+/// Router address (`ip:port`) for topics bridged between hosts
+pub const BRIDGE_ROUTER_ENV: &str = "HORUS_BRIDGE_ROUTER";
+
+/// Comma-separated topics bridged between hosts through the router
+pub const BRIDGE_TOPICS_ENV: &str = "HORUS_BRIDGE_TOPICS";
+
+/// Route a local endpoint through the router if its topic is bridged
+///
+/// `horus launch` sets [`BRIDGE_ROUTER_ENV`] and [`BRIDGE_TOPICS_ENV`] for the
+/// nodes of a launch spread over several hosts: topics used on more than one
+/// host go through the router, everything else stays in shared memory.
+///
+/// Bridged topic names are resolved in the process namespace, like the
+/// endpoint's own topic.
+pub fn apply_bridge(endpoint: Endpoint) -> Endpoint {
+    let router = std::env::var(BRIDGE_ROUTER_ENV).ok();
+    let topics = std::env::var(BRIDGE_TOPICS_ENV).ok().map(|topics| {
+        topics
+            .split(',')
+            .map(|topic| crate::communication::namespace::resolve_topic(topic.trim()))
+            .collect::<Vec<_>>()
+            .join(",")
+    });
+    bridge_endpoint(endpoint, router.as_deref(), topics.as_deref())
+}
+
+fn bridge_endpoint(endpoint: Endpoint, router: Option<&str>, topics: Option<&str>) -> Endpoint {
+    let Endpoint::Local { topic } = endpoint else {
+        return endpoint;
+    };
+    let (Some(router), Some(topics)) = (router, topics) else {
+        return Endpoint::Local { topic };
+    };
+    let Ok(router) = router.trim().parse::<SocketAddr>() else {
+        log::warn!("Ignoring invalid {} '{}'", BRIDGE_ROUTER_ENV, router);
+        return Endpoint::Local { topic };
+    };
+
+    if topics.split(',').any(|bridged| bridged.trim() == topic) {
+        Endpoint::Router {
+            topic,
+            host: Some(router.ip()),
+            port: Some(router.port()),
+        }
+    } else {
+        Endpoint::Local { topic }
+    }
+}
+
 /// Parse endpoint string into Endpoint enum
 ///
 /// # Format:
@@ -52,6 +105,7 @@ pub const MULTICAST_PORT: u16 = 9871;
 /// - `"topic@192.168.1.5"` → Direct network (default port 9870)
 /// - `"topic@192.168.1.5:9000"` → Direct network (custom port)
 /// - `"topic@*"` → Multicast discovery
+/// - `"topic@5hz"` → Local mirror of `topic` decimated to 5 Hz
 pub fn parse_endpoint(input: &str) -> Result<Endpoint, String> {
     // Split on '@'
     if !input.contains('@') {
@@ -80,6 +134,17 @@ pub fn parse_endpoint(input: &str) -> Result<Endpoint, String> {
         return Ok(Endpoint::Multicast { topic });
     }
 
+    // Check for decimated mirror: "5hz" or "0.5hz"
+    if let Some(rate_str) = location.strip_suffix("hz") {
+        let rate_hz = rate_str
+            .parse::<f64>()
+            .map_err(|e| format!("Invalid mirror rate '{}': {}", location, e))?;
+        if !(rate_hz.is_finite() && rate_hz > 0.0) {
+            return Err(format!("Mirror rate must be positive, got '{}'", location));
+        }
+        return Ok(Endpoint::Mirror { topic, rate_hz });
+    }
+
     // Check for router
     if location == "router" {
         return Ok(Endpoint::Router {
@@ -444,4 +509,56 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_parse_mirror() {
+        let ep = parse_endpoint("imu.raw@5hz").unwrap();
+        assert_eq!(
+            ep,
+            Endpoint::Mirror {
+                topic: "imu.raw".to_string(),
+                rate_hz: 5.0
+            }
+        );
+
+        let ep = parse_endpoint("imu.raw@0.5hz").unwrap();
+        assert_eq!(
+            ep,
+            Endpoint::Mirror {
+                topic: "imu.raw".to_string(),
+                rate_hz: 0.5
+            }
+        );
+
+        assert!(parse_endpoint("imu.raw@0hz").is_err());
+        assert!(parse_endpoint("imu.raw@fasthz").is_err());
+    }
+
+    #[test]
+    fn test_bridge_endpoint() {
+        let local = |topic: &str| Endpoint::Local {
+            topic: topic.to_string(),
+        };
+        let router = Some("192.168.1.10:7777");
+        let topics = Some("scan, cmd_vel");
+
+        assert_eq!(
+            bridge_endpoint(local("scan"), router, topics),
+            Endpoint::Router {
+                topic: "scan".to_string(),
+                host: Some("192.168.1.10".parse().unwrap()),
+                port: Some(7777)
+            }
+        );
+        assert_eq!(bridge_endpoint(local("imu"), router, topics), local("imu"));
+        assert_eq!(bridge_endpoint(local("scan"), None, topics), local("scan"));
+        assert_eq!(
+            bridge_endpoint(local("scan"), Some("not-an-address"), topics),
+            local("scan")
+        );
+
+        // Explicit endpoints are left alone
+        let mirror = parse_endpoint("scan@5hz").unwrap();
+        assert_eq!(bridge_endpoint(mirror.clone(), router, topics), mirror);
+    }
 }
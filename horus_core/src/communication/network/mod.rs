@@ -31,6 +31,7 @@ pub mod protocol;
 pub mod queryable;
 pub mod reconnect;
 pub mod router;
+pub mod security;
 pub mod udp_direct;
 pub mod udp_multicast;
 
@@ -80,11 +81,15 @@ pub mod zenoh_ros2_params;
 pub use backend::NetworkBackend;
 pub use direct::{DirectBackend, DirectRole};
 pub use discovery::{DiscoveryService, PeerInfo};
-pub use endpoint::{parse_endpoint, Endpoint, DEFAULT_PORT, MULTICAST_ADDR, MULTICAST_PORT};
+pub use endpoint::{
+    apply_bridge, parse_endpoint, Endpoint, BRIDGE_ROUTER_ENV, BRIDGE_TOPICS_ENV, DEFAULT_PORT,
+    MULTICAST_ADDR, MULTICAST_PORT,
+};
 pub use fragmentation::{Fragment, FragmentManager};
 pub use protocol::{HorusPacket, MessageType};
 pub use reconnect::{ConnectionHealth, ReconnectContext, ReconnectStrategy};
 pub use router::RouterBackend;
+pub use security::{PreSharedKey, SecureChannel, TlsClientSettings, TransportSecurity};
 pub use udp_direct::UdpDirectBackend;
 pub use udp_multicast::UdpMulticastBackend;
 
@@ -107,7 +112,7 @@ pub use queryable::{
 pub use unix_socket::UnixSocketBackend;
 
 #[cfg(feature = "tls")]
-pub use tls::{TlsCertConfig, TlsStream};
+pub use tls::{create_connector, TlsCertConfig, TlsStream};
 
 // Network v2 re-exports
 pub use batch_udp::{
@@ -9,13 +9,14 @@ use crate::communication::network::fragmentation::{Fragment, FragmentManager};
 /// - Batched operations
 /// - Zero-copy where possible
 use crate::communication::network::protocol::{HorusPacket, MessageType};
+use crate::communication::network::security::{open_payload, SecureChannel, TransportSecurity};
 use crate::error::HorusResult;
 use crossbeam::channel::{bounded, Receiver, Sender};
 use crossbeam::queue::SegQueue;
 use log::{error, warn};
 use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
-use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
 use tokio::net::TcpStream;
 use tokio::runtime::Handle;
 
@@ -69,6 +70,17 @@ impl BufferPool {
     }
 }
 
+/// TLS setup of a router connection
+#[cfg(feature = "tls")]
+struct RouterTls {
+    connector: tokio_rustls::TlsConnector,
+    server_name: rustls::pki_types::ServerName<'static>,
+}
+
+/// TLS needs the `tls` feature
+#[cfg(not(feature = "tls"))]
+enum RouterTls {}
+
 /// High-performance router client backend
 pub struct RouterBackend<T> {
     topic_name: String,
@@ -77,7 +89,8 @@ pub struct RouterBackend<T> {
     recv_rx: Receiver<T>,              // Lock-free recv queue
     sequence: parking_lot::Mutex<u32>, // Faster mutex
     fragment_manager: Arc<FragmentManager>,
-    buffer_pool: Arc<BufferPool>, // Zero-allocation buffer pool
+    buffer_pool: Arc<BufferPool>,        // Zero-allocation buffer pool
+    channel: Option<Arc<SecureChannel>>, // Seals payloads end to end (PSK)
     _phantom: std::marker::PhantomData<T>,
 }
 
@@ -92,6 +105,84 @@ where
 
     /// Create with custom router address
     pub fn new_with_addr(topic: &str, host: IpAddr, port: u16) -> HorusResult<Self> {
+        Self::connect(topic, host, port, None, None)
+    }
+
+    /// Create with custom router address and transport security
+    ///
+    /// A pre-shared key seals payloads end to end (the router only relays
+    /// them); TLS encrypts the connection to the router.
+    pub fn new_with_security(
+        topic: &str,
+        host: IpAddr,
+        port: u16,
+        security: &TransportSecurity,
+    ) -> HorusResult<Self> {
+        match security {
+            TransportSecurity::None => Self::connect(topic, host, port, None, None),
+            TransportSecurity::Psk(psk) => Self::connect(
+                topic,
+                host,
+                port,
+                Some(Arc::new(SecureChannel::new(psk, topic))),
+                None,
+            ),
+            #[cfg(feature = "tls")]
+            TransportSecurity::Tls(settings) => {
+                let ca = settings.ca.as_ref().ok_or_else(|| {
+                    crate::error::HorusError::config(
+                        "tls_ca is required to verify the router certificate",
+                    )
+                })?;
+                let connector = crate::communication::network::tls::create_connector(
+                    &ca.to_string_lossy(),
+                    settings
+                        .cert
+                        .as_ref()
+                        .map(|p| p.to_string_lossy())
+                        .as_deref(),
+                    settings
+                        .key
+                        .as_ref()
+                        .map(|p| p.to_string_lossy())
+                        .as_deref(),
+                )?;
+                let server_name = match &settings.server_name {
+                    Some(name) => {
+                        rustls::pki_types::ServerName::try_from(name.clone()).map_err(|e| {
+                            crate::error::HorusError::config(format!(
+                                "Invalid TLS server name '{}': {}",
+                                name, e
+                            ))
+                        })?
+                    }
+                    None => rustls::pki_types::ServerName::from(host),
+                };
+                Self::connect(
+                    topic,
+                    host,
+                    port,
+                    None,
+                    Some(RouterTls {
+                        connector,
+                        server_name,
+                    }),
+                )
+            }
+            #[cfg(not(feature = "tls"))]
+            TransportSecurity::Tls(_) => Err(crate::error::HorusError::config(
+                "TLS router connections need horus_core built with the 'tls' feature",
+            )),
+        }
+    }
+
+    fn connect(
+        topic: &str,
+        host: IpAddr,
+        port: u16,
+        channel: Option<Arc<SecureChannel>>,
+        tls: Option<RouterTls>,
+    ) -> HorusResult<Self> {
         // Get or create tokio runtime
         let runtime_handle = Handle::try_current().unwrap_or_else(|_| {
             // If no runtime exists, create one (shouldn't happen in practice)
@@ -112,6 +203,7 @@ where
             sequence: parking_lot::Mutex::new(0),
             fragment_manager: Arc::new(FragmentManager::default()),
             buffer_pool: Arc::new(BufferPool::new()),
+            channel: channel.clone(),
             _phantom: std::marker::PhantomData,
         };
 
@@ -125,6 +217,8 @@ where
                 send_rx,
                 recv_tx,
                 buffer_pool_clone,
+                channel,
+                tls,
             )
             .await
             {
@@ -142,9 +236,11 @@ where
         send_rx: Receiver<Vec<u8>>,
         recv_tx: Sender<T>,
         buffer_pool: Arc<BufferPool>,
+        channel: Option<Arc<SecureChannel>>,
+        tls: Option<RouterTls>,
     ) -> HorusResult<()> {
         // Connect with TCP_NODELAY for low latency
-        let mut stream = TcpStream::connect(router_addr)
+        let stream = TcpStream::connect(router_addr)
             .await
             .map_err(|e| format!("Failed to connect to router at {}: {}", router_addr, e))?;
 
@@ -152,6 +248,36 @@ where
             .set_nodelay(true)
             .map_err(|e| format!("Failed to set TCP_NODELAY: {}", e))?;
 
+        match tls {
+            #[cfg(feature = "tls")]
+            Some(tls) => {
+                let stream = tls
+                    .connector
+                    .connect(tls.server_name, stream)
+                    .await
+                    .map_err(|e| {
+                        format!("TLS handshake with router at {} failed: {}", router_addr, e)
+                    })?;
+                Self::run_session(stream, topic, send_rx, recv_tx, buffer_pool, channel).await
+            }
+            #[cfg(not(feature = "tls"))]
+            Some(tls) => match tls {},
+            None => Self::run_session(stream, topic, send_rx, recv_tx, buffer_pool, channel).await,
+        }
+    }
+
+    /// Subscribe and exchange packets over an established connection
+    async fn run_session<S>(
+        mut stream: S,
+        topic: String,
+        send_rx: Receiver<Vec<u8>>,
+        recv_tx: Sender<T>,
+        buffer_pool: Arc<BufferPool>,
+        channel: Option<Arc<SecureChannel>>,
+    ) -> HorusResult<()>
+    where
+        S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    {
         // Send subscribe message
         let subscribe_packet = HorusPacket::new_router_subscribe(topic.clone());
         let mut buffer = Vec::with_capacity(1024);
@@ -168,7 +294,7 @@ where
             .map_err(|e| format!("Failed to send subscribe: {}", e))?;
 
         // Split stream for concurrent read/write
-        let (mut read_half, mut write_half) = stream.into_split();
+        let (mut read_half, mut write_half) = tokio::io::split(stream);
 
         // Spawn write task - returns buffers to pool after sending
         let write_task = tokio::spawn(async move {
@@ -220,14 +346,19 @@ where
                 match packet.msg_type {
                     MessageType::RouterPublish => {
                         // Fast path: direct deserialize
-                        if let Ok(msg) = bincode::deserialize::<T>(&packet.payload) {
-                            let _ = recv_tx.try_send(msg); // Non-blocking
+                        if let Some(payload) = open_payload(&channel, packet.payload) {
+                            if let Ok(msg) = bincode::deserialize::<T>(&payload) {
+                                let _ = recv_tx.try_send(msg); // Non-blocking
+                            }
                         }
                     }
                     MessageType::Fragment => {
                         // Fragment reassembly
                         if let Ok(fragment) = Fragment::decode(&packet.payload) {
-                            if let Some(complete_data) = fragment_manager.reassemble(fragment) {
+                            if let Some(complete_data) = fragment_manager
+                                .reassemble(fragment)
+                                .and_then(|data| open_payload(&channel, data))
+                            {
                                 if let Ok(msg) = bincode::deserialize::<T>(&complete_data) {
                                     let _ = recv_tx.try_send(msg);
                                 }
@@ -246,7 +377,11 @@ where
     /// Send a message (optimized with zero-allocation buffer pooling)
     pub fn send(&self, msg: &T) -> HorusResult<()> {
         // Serialize payload
-        let payload = bincode::serialize(msg).map_err(|e| format!("Serialization error: {}", e))?;
+        let mut payload =
+            bincode::serialize(msg).map_err(|e| format!("Serialization error: {}", e))?;
+        if let Some(ref channel) = self.channel {
+            payload = channel.seal(&payload)?;
+        }
 
         // Fragment if needed
         let fragments = self.fragment_manager.fragment(&payload);
@@ -303,6 +438,7 @@ impl<T> std::fmt::Debug for RouterBackend<T> {
         f.debug_struct("RouterBackend")
             .field("topic_name", &self.topic_name)
             .field("router_addr", &self.router_addr)
+            .field("encrypted", &self.channel.is_some())
             .finish()
     }
 }
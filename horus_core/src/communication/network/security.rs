@@ -0,0 +1,475 @@
+//! Encryption and authentication of network endpoints
+//!
+//! Network hubs send plaintext by default. Two protections can be configured
+//! per hub in `horus.yaml`:
+//!
+//! ```yaml
+//! hubs:
+//!   cmd_vel:
+//!     name: cmd_vel
+//!     endpoint: cmd_vel@192.168.1.20:9000
+//!     psk_file: ~/.horus/teleop.key      # UDP: pre-shared key
+//!   camera:
+//!     name: camera
+//!     endpoint: camera@router
+//!     tls: true                          # router: (mutual) TLS
+//!     tls_ca: /etc/horus/ca.pem
+//!     tls_cert: /etc/horus/robot.pem
+//!     tls_key: /etc/horus/robot.key
+//! ```
+//!
+//! With a pre-shared key every message is sealed with XChaCha20-Poly1305
+//! under a key derived from the PSK and the topic name. Messages that were
+//! not sealed with the same key, were modified, or were already received are
+//! dropped, so only holders of the key can publish. Received counters are
+//! kept in memory only; across a receiver restart replays are bounded by the
+//! send time sealed into every frame, which must be within
+//! [`MAX_CLOCK_SKEW`] of the receiver clock. Hosts sharing a key therefore
+//! need synchronized clocks (NTP), and a frame captured less than that long
+//! before a restart can be replayed once. Sealing is end to end:
+//! a router relays the ciphertext without being able to read it. Topic names
+//! and packet sizes remain visible.
+//!
+//! With TLS the router connection is encrypted, the router certificate is
+//! checked against `tls_ca`, and the hub presents `tls_cert` to routers
+//! started with `--tls-client-ca`. Needs the `tls` feature.
+//!
+//! Hubs created without a config entry use the key from [`PSK_ENV`] or
+//! [`PSK_FILE_ENV`] if set.
+
+use crate::communication::config::HubConfig;
+use crate::error::{HorusError, HorusResult};
+use chacha20poly1305::aead::rand_core::RngCore;
+use chacha20poly1305::aead::{Aead, KeyInit, OsRng, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Pre-shared key in hex, used by network hubs without their own setting
+pub const PSK_ENV: &str = "HORUS_NETWORK_PSK";
+
+/// File holding the pre-shared key, used when [`PSK_ENV`] is unset
+pub const PSK_FILE_ENV: &str = "HORUS_NETWORK_PSK_FILE";
+
+/// Shortest accepted pre-shared key
+const MIN_PSK_LEN: usize = 16;
+
+/// Frame magic and version: `H`, `S`, version
+const FRAME_MAGIC: [u8; 3] = [b'H', b'S', 2];
+
+/// Magic, sender id, counter and send time
+const FRAME_HEADER_LEN: usize = 3 + 8 + 8 + 8;
+
+/// Poly1305 tag
+const TAG_LEN: usize = 16;
+
+/// Senders whose replay windows are kept per channel
+const MAX_SENDERS: usize = 1024;
+
+/// Largest difference between the send time of a frame and the receiver
+/// clock; older (or newer) frames are dropped
+pub const MAX_CLOCK_SKEW: Duration = Duration::from_secs(30);
+
+/// Security of a network endpoint
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum TransportSecurity {
+    /// Plaintext
+    #[default]
+    None,
+    /// Messages sealed with a pre-shared key
+    Psk(PreSharedKey),
+    /// TLS connection to the router
+    Tls(TlsClientSettings),
+}
+
+impl TransportSecurity {
+    /// Security configured for a hub, falling back to [`Self::from_env`]
+    pub fn from_hub_config(config: &HubConfig) -> HorusResult<Self> {
+        if config.tls == Some(true) {
+            return Ok(Self::Tls(TlsClientSettings {
+                ca: config.tls_ca.as_deref().map(expand_home),
+                cert: config.tls_cert.as_deref().map(expand_home),
+                key: config.tls_key.as_deref().map(expand_home),
+                server_name: config.tls_server_name.clone(),
+            }));
+        }
+        if let Some(ref path) = config.psk_file {
+            return Ok(Self::Psk(PreSharedKey::from_file(expand_home(path))?));
+        }
+        Self::from_env()
+    }
+
+    /// Pre-shared key from [`PSK_ENV`] or [`PSK_FILE_ENV`], if set
+    pub fn from_env() -> HorusResult<Self> {
+        if let Ok(hex) = std::env::var(PSK_ENV) {
+            if !hex.trim().is_empty() {
+                return Ok(Self::Psk(PreSharedKey::from_hex(&hex)?));
+            }
+        }
+        match std::env::var(PSK_FILE_ENV) {
+            Ok(path) if !path.is_empty() => {
+                Ok(Self::Psk(PreSharedKey::from_file(expand_home(&path))?))
+            }
+            _ => Ok(Self::None),
+        }
+    }
+
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+}
+
+/// Secret shared by both ends of an endpoint
+#[derive(Clone, PartialEq, Eq)]
+pub struct PreSharedKey(Vec<u8>);
+
+impl PreSharedKey {
+    /// Key from raw bytes
+    pub fn new(key: &[u8]) -> HorusResult<Self> {
+        if key.len() < MIN_PSK_LEN {
+            return Err(HorusError::config(format!(
+                "Pre-shared key is {} bytes, at least {} are required",
+                key.len(),
+                MIN_PSK_LEN
+            )));
+        }
+        Ok(Self(key.to_vec()))
+    }
+
+    /// Key from a hex string (`openssl rand -hex 32`)
+    pub fn from_hex(hex: &str) -> HorusResult<Self> {
+        let hex = hex.trim();
+        if !hex.len().is_multiple_of(2) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(HorusError::config("Pre-shared key is not a hex string"));
+        }
+        let bytes: Vec<u8> = (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap_or_default())
+            .collect();
+        Self::new(&bytes)
+    }
+
+    /// Key from a file holding it in hex
+    pub fn from_file(path: impl AsRef<Path>) -> HorusResult<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            HorusError::config(format!(
+                "Failed to read pre-shared key {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Self::from_hex(&contents)
+            .map_err(|e| HorusError::config(format!("{}: {}", path.display(), e)))
+    }
+}
+
+impl std::fmt::Debug for PreSharedKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "PreSharedKey(<{} bytes>)", self.0.len())
+    }
+}
+
+/// TLS settings of a router connection
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TlsClientSettings {
+    /// CA certificate the router certificate must chain to
+    pub ca: Option<PathBuf>,
+    /// Client certificate presented to the router
+    pub cert: Option<PathBuf>,
+    /// Private key of the client certificate
+    pub key: Option<PathBuf>,
+    /// Name checked against the router certificate (default: router address)
+    pub server_name: Option<String>,
+}
+
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+/// Seals and opens the messages of one topic under a pre-shared key
+///
+/// Frames are `[magic][sender id][counter][send time][ciphertext + tag]`.
+/// Each channel sends under a random sender id with an increasing counter,
+/// which form the nonce; receivers track a replay window per sender and
+/// drop frames sent more than [`MAX_CLOCK_SKEW`] away from their clock.
+pub struct SecureChannel {
+    cipher: XChaCha20Poly1305,
+    sender_id: u64,
+    counter: AtomicU64,
+    windows: Mutex<HashMap<u64, ReplayWindow>>,
+}
+
+impl SecureChannel {
+    /// Channel for `topic`; both ends must use the same key and topic
+    pub fn new(psk: &PreSharedKey, topic: &str) -> Self {
+        let mut key = [0u8; 32];
+        hkdf::Hkdf::<sha2::Sha256>::new(Some(b"horus-psk-v1"), &psk.0)
+            .expand(topic.as_bytes(), &mut key)
+            .expect("32 bytes is a valid HKDF-SHA256 output length");
+        Self {
+            cipher: XChaCha20Poly1305::new(&key.into()),
+            sender_id: OsRng.next_u64(),
+            counter: AtomicU64::new(0),
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Encrypt and authenticate a message
+    pub fn seal(&self, plaintext: &[u8]) -> HorusResult<Vec<u8>> {
+        self.seal_at(plaintext, unix_millis())
+    }
+
+    fn seal_at(&self, plaintext: &[u8], sent_ms: u64) -> HorusResult<Vec<u8>> {
+        let counter = self.counter.fetch_add(1, Ordering::Relaxed);
+        let mut frame = Vec::with_capacity(FRAME_HEADER_LEN + plaintext.len() + TAG_LEN);
+        frame.extend_from_slice(&FRAME_MAGIC);
+        frame.extend_from_slice(&self.sender_id.to_le_bytes());
+        frame.extend_from_slice(&counter.to_le_bytes());
+        frame.extend_from_slice(&sent_ms.to_le_bytes());
+        let ciphertext = self
+            .cipher
+            .encrypt(
+                &nonce(self.sender_id, counter),
+                Payload {
+                    msg: plaintext,
+                    aad: &frame,
+                },
+            )
+            .map_err(|_| HorusError::Communication("Failed to encrypt message".to_string()))?;
+        frame.extend_from_slice(&ciphertext);
+        Ok(frame)
+    }
+
+    /// Decrypt a sealed message
+    ///
+    /// None if it was not sealed under this key and topic, was modified, was
+    /// already received, or was sent more than [`MAX_CLOCK_SKEW`] ago.
+    pub fn open(&self, frame: &[u8]) -> Option<Vec<u8>> {
+        if frame.len() < FRAME_HEADER_LEN + TAG_LEN || frame[..3] != FRAME_MAGIC {
+            return None;
+        }
+        let (header, ciphertext) = frame.split_at(FRAME_HEADER_LEN);
+        let sender_id = u64::from_le_bytes(header[3..11].try_into().ok()?);
+        let counter = u64::from_le_bytes(header[11..19].try_into().ok()?);
+        let sent_ms = u64::from_le_bytes(header[19..27].try_into().ok()?);
+        if unix_millis().abs_diff(sent_ms) > MAX_CLOCK_SKEW.as_millis() as u64 {
+            return None;
+        }
+
+        let mut windows = self.windows.lock().unwrap_or_else(|e| e.into_inner());
+        if windows
+            .get(&sender_id)
+            .is_some_and(|window| !window.is_fresh(counter))
+        {
+            return None;
+        }
+        let plaintext = self
+            .cipher
+            .decrypt(
+                &nonce(sender_id, counter),
+                Payload {
+                    msg: ciphertext,
+                    aad: header,
+                },
+            )
+            .ok()?;
+
+        // Only authenticated senders get a window
+        if !windows.contains_key(&sender_id) && windows.len() >= MAX_SENDERS {
+            if let Some(oldest) = windows
+                .iter()
+                .min_by_key(|(_, window)| window.last_seen)
+                .map(|(id, _)| *id)
+            {
+                windows.remove(&oldest);
+            }
+        }
+        windows
+            .entry(sender_id)
+            .or_insert_with(ReplayWindow::new)
+            .accept(counter);
+        Some(plaintext)
+    }
+}
+
+impl std::fmt::Debug for SecureChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecureChannel")
+            .field("sender_id", &self.sender_id)
+            .finish()
+    }
+}
+
+/// Open a received payload, passing it through when there is no channel
+pub(crate) fn open_payload(
+    channel: &Option<Arc<SecureChannel>>,
+    payload: Vec<u8>,
+) -> Option<Vec<u8>> {
+    match channel {
+        Some(channel) => {
+            let opened = channel.open(&payload);
+            if opened.is_none() {
+                log::debug!("Dropped unauthenticated network message");
+            }
+            opened
+        }
+        None => Some(payload),
+    }
+}
+
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+fn nonce(sender_id: u64, counter: u64) -> XNonce {
+    let mut nonce = [0u8; 24];
+    nonce[..8].copy_from_slice(&sender_id.to_le_bytes());
+    nonce[8..16].copy_from_slice(&counter.to_le_bytes());
+    nonce.into()
+}
+
+/// Counters already received from a sender: the highest and the 64 below it
+#[derive(Debug)]
+struct ReplayWindow {
+    highest: u64,
+    seen: u64,
+    last_seen: Instant,
+}
+
+impl ReplayWindow {
+    fn new() -> Self {
+        Self {
+            highest: 0,
+            seen: 0,
+            last_seen: Instant::now(),
+        }
+    }
+
+    fn is_fresh(&self, counter: u64) -> bool {
+        if self.seen == 0 || counter > self.highest {
+            return true;
+        }
+        let age = self.highest - counter;
+        age < 64 && self.seen & (1 << age) == 0
+    }
+
+    fn accept(&mut self, counter: u64) {
+        if self.seen == 0 {
+            self.highest = counter;
+            self.seen = 1;
+        } else if counter > self.highest {
+            let shift = counter - self.highest;
+            let shifted = if shift < 64 { self.seen << shift } else { 0 };
+            self.seen = shifted | 1;
+            self.highest = counter;
+        } else {
+            self.seen |= 1 << (self.highest - counter);
+        }
+        self.last_seen = Instant::now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn psk() -> PreSharedKey {
+        PreSharedKey::from_hex("000102030405060708090a0b0c0d0e0f").unwrap()
+    }
+
+    #[test]
+    fn test_seal_open() {
+        let sender = SecureChannel::new(&psk(), "cmd_vel");
+        let receiver = SecureChannel::new(&psk(), "cmd_vel");
+
+        let frame = sender.seal(b"forward").unwrap();
+        assert!(!frame.windows(7).any(|w| w == b"forward"));
+        assert_eq!(receiver.open(&frame).unwrap(), b"forward");
+    }
+
+    #[test]
+    fn test_rejects_tampering_and_other_keys() {
+        let sender = SecureChannel::new(&psk(), "cmd_vel");
+        let frame = sender.seal(b"forward").unwrap();
+
+        let mut tampered = frame.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(SecureChannel::new(&psk(), "cmd_vel")
+            .open(&tampered)
+            .is_none());
+
+        let other = PreSharedKey::new(&[7u8; 32]).unwrap();
+        assert!(SecureChannel::new(&other, "cmd_vel").open(&frame).is_none());
+        assert!(SecureChannel::new(&psk(), "odom").open(&frame).is_none());
+        assert!(SecureChannel::new(&psk(), "cmd_vel")
+            .open(b"plain")
+            .is_none());
+    }
+
+    #[test]
+    fn test_rejects_replays() {
+        let sender = SecureChannel::new(&psk(), "cmd_vel");
+        let receiver = SecureChannel::new(&psk(), "cmd_vel");
+
+        let first = sender.seal(b"1").unwrap();
+        let second = sender.seal(b"2").unwrap();
+        assert!(receiver.open(&second).is_some());
+        // Reordered but not yet seen
+        assert!(receiver.open(&first).is_some());
+        assert!(receiver.open(&first).is_none());
+        assert!(receiver.open(&second).is_none());
+    }
+
+    #[test]
+    fn test_rejects_stale_frames_after_restart() {
+        let sender = SecureChannel::new(&psk(), "cmd_vel");
+        let captured = sender
+            .seal_at(b"1", unix_millis() - 2 * MAX_CLOCK_SKEW.as_millis() as u64)
+            .unwrap();
+
+        // A restarted receiver has no replay window for the sender
+        let restarted = SecureChannel::new(&psk(), "cmd_vel");
+        assert!(restarted.open(&captured).is_none());
+        let future = sender
+            .seal_at(b"2", unix_millis() + 2 * MAX_CLOCK_SKEW.as_millis() as u64)
+            .unwrap();
+        assert!(restarted.open(&future).is_none());
+        assert!(restarted.open(&sender.seal(b"3").unwrap()).is_some());
+
+        // The send time is authenticated
+        let mut rewritten = sender.seal(b"4").unwrap();
+        rewritten[19] ^= 1;
+        assert!(restarted.open(&rewritten).is_none());
+    }
+
+    #[test]
+    fn test_replay_window() {
+        let mut window = ReplayWindow::new();
+        window.accept(100);
+        assert!(window.is_fresh(101));
+        assert!(window.is_fresh(99));
+        assert!(!window.is_fresh(100));
+        window.accept(200);
+        assert!(!window.is_fresh(100));
+        assert!(window.is_fresh(199));
+        assert!(!window.is_fresh(136));
+    }
+
+    #[test]
+    fn test_pre_shared_key() {
+        assert!(PreSharedKey::from_hex("abcd").is_err());
+        assert!(PreSharedKey::from_hex("zz0102030405060708090a0b0c0d0e0f").is_err());
+        assert!(PreSharedKey::from_hex(" 000102030405060708090a0b0c0d0e0f\n").is_ok());
+        assert!(!format!("{:?}", psk()).contains("0a0b"));
+    }
+}
@@ -15,9 +15,9 @@ use rcgen::{CertificateParams, DistinguishedName};
 #[cfg(feature = "tls")]
 use rustls::pki_types::{CertificateDer, PrivateKeyDer};
 #[cfg(feature = "tls")]
-use rustls::ServerConfig;
+use rustls::{ClientConfig, RootCertStore, ServerConfig};
 #[cfg(feature = "tls")]
-use tokio_rustls::TlsAcceptor;
+use tokio_rustls::{TlsAcceptor, TlsConnector};
 
 // Re-export TlsStream for use by other modules
 #[cfg(feature = "tls")]
@@ -37,6 +37,8 @@ pub struct TlsCertConfig {
     pub organization: String,
     /// Common name for generated certificates
     pub common_name: String,
+    /// CA certificate client certificates must chain to (mutual TLS)
+    pub client_ca_path: Option<String>,
 }
 
 #[cfg(feature = "tls")]
@@ -48,6 +50,7 @@ impl Default for TlsCertConfig {
             auto_generate: true,
             organization: "HORUS Robotics".to_string(),
             common_name: "horus-router".to_string(),
+            client_ca_path: None,
         }
     }
 }
@@ -85,6 +88,12 @@ impl TlsCertConfig {
         self
     }
 
+    /// Require clients to present a certificate signed by this CA
+    pub fn with_client_ca(mut self, ca_path: impl Into<String>) -> Self {
+        self.client_ca_path = Some(ca_path.into());
+        self
+    }
+
     /// Load or generate TLS certificate and private key
     pub fn load_or_generate(
         &self,
@@ -110,29 +119,7 @@ impl TlsCertConfig {
         cert_path: &str,
         key_path: &str,
     ) -> HorusResult<(Vec<CertificateDer<'static>>, PrivateKeyDer<'static>)> {
-        // Load certificate
-        let cert_file = std::fs::File::open(cert_path)
-            .map_err(|e| HorusError::config(format!("Failed to open certificate file: {}", e)))?;
-        let mut cert_reader = std::io::BufReader::new(cert_file);
-        let certs = rustls_pemfile::certs(&mut cert_reader)
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| HorusError::config(format!("Failed to parse certificate: {}", e)))?;
-
-        if certs.is_empty() {
-            return Err(HorusError::config(
-                "No certificates found in certificate file",
-            ));
-        }
-
-        // Load private key
-        let key_file = std::fs::File::open(key_path)
-            .map_err(|e| HorusError::config(format!("Failed to open private key file: {}", e)))?;
-        let mut key_reader = std::io::BufReader::new(key_file);
-        let key = rustls_pemfile::private_key(&mut key_reader)
-            .map_err(|e| HorusError::config(format!("Failed to parse private key: {}", e)))?
-            .ok_or_else(|| HorusError::config("No private key found in key file"))?;
-
-        Ok((certs, key))
+        Ok((load_certs(cert_path)?, load_private_key(key_path)?))
     }
 
     /// Generate a self-signed certificate
@@ -221,9 +208,20 @@ impl TlsCertConfig {
     pub fn create_acceptor(&self) -> HorusResult<TlsAcceptor> {
         let (certs, key) = self.load_or_generate()?;
 
-        // Create server config
-        let mut config = ServerConfig::builder()
-            .with_no_client_auth()
+        // Create server config, verifying client certificates if a CA is set
+        let builder = ServerConfig::builder();
+        let builder = match &self.client_ca_path {
+            Some(ca_path) => {
+                let verifier = rustls::server::WebPkiClientVerifier::builder(Arc::new(
+                    load_root_store(ca_path)?,
+                ))
+                .build()
+                .map_err(|e| HorusError::config(format!("Invalid client CA: {}", e)))?;
+                builder.with_client_cert_verifier(verifier)
+            }
+            None => builder.with_no_client_auth(),
+        };
+        let mut config = builder
             .with_single_cert(certs, key)
             .map_err(|e| HorusError::config(format!("Failed to create TLS config: {}", e)))?;
 
@@ -234,6 +232,69 @@ impl TlsCertConfig {
     }
 }
 
+#[cfg(feature = "tls")]
+/// Create a TLS connector for client use
+///
+/// The server certificate must chain to `ca_path`. With `cert_path` and
+/// `key_path` the client authenticates itself to servers that require it.
+pub fn create_connector(
+    ca_path: &str,
+    cert_path: Option<&str>,
+    key_path: Option<&str>,
+) -> HorusResult<TlsConnector> {
+    let builder = ClientConfig::builder().with_root_certificates(load_root_store(ca_path)?);
+    let config = match (cert_path, key_path) {
+        (Some(cert_path), Some(key_path)) => builder
+            .with_client_auth_cert(load_certs(cert_path)?, load_private_key(key_path)?)
+            .map_err(|e| HorusError::config(format!("Invalid client certificate: {}", e)))?,
+        (None, None) => builder.with_no_client_auth(),
+        _ => {
+            return Err(HorusError::config(
+                "A client certificate needs both a certificate and a private key",
+            ))
+        }
+    };
+    Ok(TlsConnector::from(Arc::new(config)))
+}
+
+#[cfg(feature = "tls")]
+fn load_certs(cert_path: &str) -> HorusResult<Vec<CertificateDer<'static>>> {
+    let cert_file = std::fs::File::open(cert_path)
+        .map_err(|e| HorusError::config(format!("Failed to open certificate file: {}", e)))?;
+    let mut cert_reader = std::io::BufReader::new(cert_file);
+    let certs = rustls_pemfile::certs(&mut cert_reader)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| HorusError::config(format!("Failed to parse certificate: {}", e)))?;
+
+    if certs.is_empty() {
+        return Err(HorusError::config(
+            "No certificates found in certificate file",
+        ));
+    }
+    Ok(certs)
+}
+
+#[cfg(feature = "tls")]
+fn load_private_key(key_path: &str) -> HorusResult<PrivateKeyDer<'static>> {
+    let key_file = std::fs::File::open(key_path)
+        .map_err(|e| HorusError::config(format!("Failed to open private key file: {}", e)))?;
+    let mut key_reader = std::io::BufReader::new(key_file);
+    rustls_pemfile::private_key(&mut key_reader)
+        .map_err(|e| HorusError::config(format!("Failed to parse private key: {}", e)))?
+        .ok_or_else(|| HorusError::config("No private key found in key file"))
+}
+
+#[cfg(feature = "tls")]
+fn load_root_store(ca_path: &str) -> HorusResult<RootCertStore> {
+    let mut roots = RootCertStore::empty();
+    for cert in load_certs(ca_path)? {
+        roots
+            .add(cert)
+            .map_err(|e| HorusError::config(format!("Invalid CA certificate: {}", e)))?;
+    }
+    Ok(roots)
+}
+
 #[cfg(feature = "tls")]
 /// Save certificate and key to PEM files
 pub fn save_cert_to_files<P: AsRef<Path>>(
@@ -274,6 +335,13 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_missing_ca() {
+        let config = TlsCertConfig::default().with_client_ca("/nonexistent/ca.pem");
+        assert!(config.create_acceptor().is_err());
+        assert!(create_connector("/nonexistent/ca.pem", None, None).is_err());
+    }
+
     #[test]
     fn test_custom_config() {
         let config = TlsCertConfig::new()
@@ -4,6 +4,7 @@ use crate::communication::network::fragmentation::{Fragment, FragmentManager};
 /// Provides <50μs latency for LAN communication using direct UDP sockets.
 /// No discovery overhead - you specify the target host directly.
 use crate::communication::network::protocol::{HorusPacket, MessageType};
+use crate::communication::network::security::{open_payload, SecureChannel};
 use crate::error::HorusResult;
 use std::collections::VecDeque;
 use std::net::{IpAddr, SocketAddr, UdpSocket};
@@ -20,6 +21,7 @@ pub struct UdpDirectBackend<T> {
     sequence: Arc<Mutex<u32>>,
     recv_queue: Arc<Mutex<VecDeque<T>>>,
     fragment_manager: Arc<FragmentManager>,
+    channel: Option<Arc<SecureChannel>>,
     _phantom: std::marker::PhantomData<T>,
 }
 
@@ -29,6 +31,18 @@ where
 {
     /// Create a new UDP direct backend
     pub fn new(topic: &str, host: IpAddr, port: u16) -> HorusResult<Self> {
+        Self::new_with_channel(topic, host, port, None)
+    }
+
+    /// Create a backend whose messages are sealed with `channel`
+    ///
+    /// Received messages that don't open under the channel are dropped.
+    pub fn new_with_channel(
+        topic: &str,
+        host: IpAddr,
+        port: u16,
+        channel: Option<Arc<SecureChannel>>,
+    ) -> HorusResult<Self> {
         // Bind to any available port
         let socket = UdpSocket::bind("0.0.0.0:0")
             .map_err(|e| format!("Failed to bind UDP socket: {}", e))?;
@@ -49,6 +63,7 @@ where
             sequence: Arc::new(Mutex::new(0)),
             recv_queue: Arc::new(Mutex::new(VecDeque::with_capacity(RECV_QUEUE_SIZE))),
             fragment_manager: Arc::new(FragmentManager::default()),
+            channel,
             _phantom: std::marker::PhantomData,
         };
 
@@ -63,6 +78,7 @@ where
         let recv_queue = Arc::clone(&self.recv_queue);
         let topic_name = self.topic_name.clone();
         let fragment_manager = Arc::clone(&self.fragment_manager);
+        let channel = self.channel.clone();
 
         std::thread::spawn(move || {
             let mut buffer = vec![0u8; UDP_BUFFER_SIZE];
@@ -81,8 +97,12 @@ where
                                 // Handle different message types
                                 match packet.msg_type {
                                     MessageType::Data => {
+                                        let Some(payload) = open_payload(&channel, packet.payload)
+                                        else {
+                                            continue;
+                                        };
                                         // Deserialize payload
-                                        match bincode::deserialize::<T>(&packet.payload) {
+                                        match bincode::deserialize::<T>(&payload) {
                                             Ok(msg) => {
                                                 let mut queue = recv_queue.lock().unwrap();
                                                 if queue.len() < RECV_QUEUE_SIZE {
@@ -103,8 +123,9 @@ where
                                         match Fragment::decode(&packet.payload) {
                                             Ok(fragment) => {
                                                 // Try to reassemble
-                                                if let Some(complete_data) =
-                                                    fragment_manager.reassemble(fragment)
+                                                if let Some(complete_data) = fragment_manager
+                                                    .reassemble(fragment)
+                                                    .and_then(|data| open_payload(&channel, data))
                                                 {
                                                     // Deserialize complete message
                                                     match bincode::deserialize::<T>(&complete_data)
@@ -157,7 +178,11 @@ where
     /// Send a message over UDP
     pub fn send(&self, msg: &T) -> HorusResult<()> {
         // Serialize payload
-        let payload = bincode::serialize(msg).map_err(|e| format!("Serialization error: {}", e))?;
+        let mut payload =
+            bincode::serialize(msg).map_err(|e| format!("Serialization error: {}", e))?;
+        if let Some(ref channel) = self.channel {
+            payload = channel.seal(&payload)?;
+        }
 
         // Fragment the payload if needed
         let fragments = self.fragment_manager.fragment(&payload);
@@ -211,6 +236,7 @@ impl<T> std::fmt::Debug for UdpDirectBackend<T> {
         f.debug_struct("UdpDirectBackend")
             .field("topic_name", &self.topic_name)
             .field("remote_addr", &self.remote_addr)
+            .field("encrypted", &self.channel.is_some())
             .finish()
     }
 }
@@ -258,6 +284,34 @@ mod tests {
         assert_eq!(decoded.data, 42);
     }
 
+    #[test]
+    fn test_udp_direct_sealed() {
+        use crate::communication::network::security::PreSharedKey;
+
+        let listener_socket = UdpSocket::bind("127.0.0.1:19872").unwrap();
+        let psk = PreSharedKey::new(&[3u8; 32]).unwrap();
+        let backend = UdpDirectBackend::<TestMessage>::new_with_channel(
+            "test_sealed",
+            "127.0.0.1".parse().unwrap(),
+            19872,
+            Some(Arc::new(SecureChannel::new(&psk, "test_sealed"))),
+        )
+        .unwrap();
+        backend.send(&TestMessage { data: 42 }).unwrap();
+
+        let mut buffer = vec![0u8; UDP_BUFFER_SIZE];
+        let (size, _) = listener_socket.recv_from(&mut buffer).unwrap();
+        let packet = HorusPacket::decode(&buffer[..size]).unwrap();
+        assert_eq!(&packet.payload[..2], b"HS");
+
+        let receiver = SecureChannel::new(&psk, "test_sealed");
+        let opened = receiver.open(&packet.payload).unwrap();
+        assert_eq!(
+            bincode::deserialize::<TestMessage>(&opened).unwrap(),
+            TestMessage { data: 42 }
+        );
+    }
+
     #[test]
     fn test_fragmentation_large_message() {
         use serde::{Deserialize, Serialize};
@@ -12,6 +12,11 @@ use std::sync::{Arc, Mutex};
 const UNIX_SOCKET_DIR: &str = "/tmp/horus_sockets";
 const BUFFER_SIZE: usize = 65536; // 64KB buffer
 
+/// Socket directory of the current `HORUS_DOMAIN_ID` domain
+fn socket_dir() -> PathBuf {
+    crate::memory::platform::with_domain(PathBuf::from(UNIX_SOCKET_DIR))
+}
+
 /// Unix socket backend for high-performance localhost communication
 pub struct UnixSocketBackend<T> {
     topic_name: String,
@@ -28,10 +33,11 @@ where
     /// Create a new publisher (binds to socket, waits for subscriber)
     pub fn new_publisher(topic: &str) -> HorusResult<Self> {
         // Create socket directory
-        std::fs::create_dir_all(UNIX_SOCKET_DIR)
+        let dir = socket_dir();
+        std::fs::create_dir_all(&dir)
             .map_err(|e| format!("Failed to create socket directory: {}", e))?;
 
-        let socket_path = PathBuf::from(UNIX_SOCKET_DIR).join(format!("horus_{}.sock", topic));
+        let socket_path = dir.join(format!("horus_{}.sock", topic));
 
         // Remove existing socket if present
         let _ = std::fs::remove_file(&socket_path);
@@ -76,7 +82,7 @@ where
 
     /// Create a new subscriber (connects to existing publisher)
     pub fn new_subscriber(topic: &str) -> HorusResult<Self> {
-        let socket_path = PathBuf::from(UNIX_SOCKET_DIR).join(format!("horus_{}.sock", topic));
+        let socket_path = socket_dir().join(format!("horus_{}.sock", topic));
 
         // Connect to publisher
         let stream = UnixStream::connect(&socket_path)
@@ -0,0 +1,368 @@
+//! Designated publishers for safety-critical topics
+//!
+//! Topics listed under `publish_policy:` in the config file only accept
+//! messages from their designated publishers; any node can still subscribe
+//! to them. Each designated publisher holds a secret publish key and the
+//! config lists the SHA-256 of every key (hex, as printed by
+//! `printf %s "$KEY" | sha256sum`):
+//!
+//! ```yaml
+//! publish_policy:
+//!   motors.cmd_vel:
+//!     publishers:
+//!       safety_controller: 9c56cc51b374c3ba189210d5b6d4bf57790d351c96c47c02190ecf1e430635ab
+//!   "arm.*":
+//!     publishers:
+//!       arm_driver: 3a6eb0790f39ac87c94f3856b2dd2c5d110e6811602261a9a923d3bb23adc8b7
+//! ```
+//!
+//! A process presents its key in [`PUBLISH_KEY_ENV`] (or [`set_publish_key`]).
+//!
+//! The check runs in `Hub::new`. The process creating a restricted topic
+//! writes the key fingerprints (first 64 bits of the key hashes) into the
+//! segment header, and every process opening the topic is checked against
+//! them, with or without the config file; a config whose policy differs from
+//! the one in the segment is refused. A hub opened without a designated key
+//! is read-only: its sends are rejected, counted as send failures and
+//! recorded in the audit log ([`POLICY_LOG_ENV`], by default
+//! `publish_policy.log` in the shared memory directory).
+//!
+//! The key binds the HORUS API. A process that maps the shared memory itself
+//! bypasses it, so untrusted code must run as a user without access to the
+//! shared memory directory.
+
+use crate::communication::config::TopicPublishPolicy;
+use crate::error::{HorusError, HorusResult};
+use crate::memory::shm_topic::MAX_TOPIC_PUBLISHERS;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+
+/// Secret publish key of this process
+pub const PUBLISH_KEY_ENV: &str = "HORUS_PUBLISH_KEY";
+
+/// Name of the node running in this process, for the audit log (set by `horus launch`)
+pub const IDENTITY_ENV: &str = "HORUS_NODE_NAME";
+
+/// Path of the publish policy log
+pub const POLICY_LOG_ENV: &str = "HORUS_POLICY_LOG";
+
+/// Violations of the same publisher on the same topic between log entries
+const REPORT_EVERY: u64 = 1000;
+
+/// Key fingerprints of the designated publishers, as stored in the segment header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PublisherPolicy {
+    tags: Vec<u64>,
+}
+
+impl PublisherPolicy {
+    /// Policy from the configured key hashes (node name -> hex SHA-256)
+    pub fn from_key_hashes<'a>(
+        key_hashes: impl IntoIterator<Item = (&'a String, &'a String)>,
+    ) -> HorusResult<Self> {
+        let mut tags = Vec::new();
+        for (node, hash) in key_hashes {
+            let digest = parse_key_hash(hash).ok_or_else(|| {
+                HorusError::Config(format!(
+                    "Publish key hash of '{}' must be 64 hex characters (SHA-256)",
+                    node
+                ))
+            })?;
+            tags.push(digest_tag(&digest));
+        }
+        tags.sort_unstable();
+        tags.dedup();
+        Ok(Self { tags })
+    }
+
+    /// Policy read back from a segment header
+    pub fn from_tags(tags: Vec<u64>) -> Self {
+        Self { tags }
+    }
+
+    pub fn tags(&self) -> &[u64] {
+        &self.tags
+    }
+
+    /// Whether `key` is the publish key of a designated publisher
+    pub fn accepts(&self, key: Option<&str>) -> bool {
+        key.is_some_and(|key| {
+            self.tags
+                .contains(&digest_tag(&Sha256::digest(key.as_bytes()).into()))
+        })
+    }
+}
+
+/// Hex SHA-256 of a publish key, as listed in the config
+pub fn key_hash(key: &str) -> String {
+    format!("{:x}", Sha256::digest(key.as_bytes()))
+}
+
+fn parse_key_hash(hash: &str) -> Option<[u8; 32]> {
+    let hash = hash.trim();
+    if hash.len() != 64 || !hash.is_ascii() {
+        return None;
+    }
+    let mut digest = [0u8; 32];
+    for (byte, pair) in digest.iter_mut().zip(hash.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok()?;
+    }
+    Some(digest)
+}
+
+/// Fingerprint of a key hash stored in the segment header (never 0)
+fn digest_tag(digest: &[u8; 32]) -> u64 {
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(&digest[..8]);
+    u64::from_be_bytes(prefix).max(1)
+}
+
+/// Publish key set in code, overriding [`PUBLISH_KEY_ENV`]
+static PUBLISH_KEY: RwLock<Option<String>> = RwLock::new(None);
+
+/// Set the publish key presented by hubs created afterwards
+pub fn set_publish_key(key: &str) {
+    *PUBLISH_KEY.write().unwrap_or_else(|e| e.into_inner()) = Some(key.to_string());
+}
+
+/// Publish key of this process, if any
+fn publish_key() -> Option<String> {
+    static ENV_KEY: OnceLock<Option<String>> = OnceLock::new();
+    let explicit = PUBLISH_KEY
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone();
+    explicit.or_else(|| {
+        ENV_KEY
+            .get_or_init(|| {
+                std::env::var(PUBLISH_KEY_ENV)
+                    .ok()
+                    .filter(|k| !k.is_empty())
+            })
+            .clone()
+    })
+}
+
+/// Whether this process holds the key of a designated publisher
+pub fn may_publish(publishers: &PublisherPolicy) -> bool {
+    publishers.accepts(publish_key().as_deref())
+}
+
+/// Identity set in code, overriding [`IDENTITY_ENV`]
+static IDENTITY: RwLock<Option<String>> = RwLock::new(None);
+
+/// Set the node identity recorded for sends without a `NodeInfo` context
+pub fn set_identity(node: &str) {
+    *IDENTITY.write().unwrap_or_else(|e| e.into_inner()) = Some(node.to_string());
+}
+
+/// Node identity of this process, if any
+pub fn identity() -> Option<String> {
+    static ENV_IDENTITY: OnceLock<Option<String>> = OnceLock::new();
+    let explicit = IDENTITY.read().unwrap_or_else(|e| e.into_inner()).clone();
+    explicit.or_else(|| {
+        ENV_IDENTITY
+            .get_or_init(|| std::env::var(IDENTITY_ENV).ok().filter(|n| !n.is_empty()))
+            .clone()
+    })
+}
+
+/// Policy in force in this process, loaded from the config on first use
+static POLICY: RwLock<Option<Arc<HashMap<String, TopicPublishPolicy>>>> = RwLock::new(None);
+
+/// Replace the policy loaded from the config file
+///
+/// Applies to topics created afterwards.
+pub fn set_publish_policy(policy: HashMap<String, TopicPublishPolicy>) {
+    *POLICY.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(policy));
+}
+
+/// Drop guard restoring the policy of the config file and the publish key,
+/// for tests that call [`set_publish_policy`] or [`set_publish_key`]
+#[cfg(test)]
+pub(crate) struct ResetPublishPolicy;
+
+#[cfg(test)]
+impl Drop for ResetPublishPolicy {
+    fn drop(&mut self) {
+        *POLICY.write().unwrap_or_else(|e| e.into_inner()) = None;
+        *PUBLISH_KEY.write().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+}
+
+fn policy() -> Arc<HashMap<String, TopicPublishPolicy>> {
+    if let Some(policy) = POLICY.read().unwrap_or_else(|e| e.into_inner()).as_ref() {
+        return policy.clone();
+    }
+    let loaded = match crate::communication::config::HorusConfig::find_and_load() {
+        Ok(config) => config.publish_policy,
+        Err(_) => HashMap::new(),
+    };
+    POLICY
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .get_or_insert_with(|| Arc::new(loaded))
+        .clone()
+}
+
+/// Policy configured for `topic`, None if anyone may publish
+///
+/// Exact entries win over patterns; `*` matches any run of characters.
+pub fn policy_for(topic: &str) -> HorusResult<Option<PublisherPolicy>> {
+    let policy = policy();
+    let entry = policy.get(topic).map(|p| (topic, p)).or_else(|| {
+        policy
+            .iter()
+            .filter(|(pattern, _)| pattern.contains('*') && glob_match(pattern, topic))
+            .max_by_key(|(pattern, _)| pattern.len())
+            .map(|(pattern, p)| (pattern.as_str(), p))
+    });
+    let Some((pattern, topic_policy)) = entry else {
+        return Ok(None);
+    };
+    if topic_policy.publishers.len() > MAX_TOPIC_PUBLISHERS {
+        return Err(HorusError::Config(format!(
+            "Publish policy of '{}' lists {} publishers, at most {} are supported",
+            pattern,
+            topic_policy.publishers.len(),
+            MAX_TOPIC_PUBLISHERS
+        )));
+    }
+    PublisherPolicy::from_key_hashes(&topic_policy.publishers).map(Some)
+}
+
+fn glob_match(pattern: &str, text: &str) -> bool {
+    match pattern.split_once('*') {
+        None => pattern == text,
+        Some((prefix, rest)) => {
+            let Some(text) = text.strip_prefix(prefix) else {
+                return false;
+            };
+            (0..=text.len())
+                .filter(|&i| text.is_char_boundary(i))
+                .any(|i| glob_match(rest, &text[i..]))
+        }
+    }
+}
+
+/// Record a violating publisher in the policy log
+///
+/// The first violation of a publisher on a topic is recorded, then one
+/// entry every [`REPORT_EVERY`] violations.
+pub fn report_violation(topic: &str, publisher: Option<&str>) {
+    static REJECTIONS: Mutex<Option<HashMap<(String, String), u64>>> = Mutex::new(None);
+
+    let publisher = publisher.unwrap_or("<unknown>");
+    let count = {
+        let mut rejections = REJECTIONS.lock().unwrap_or_else(|e| e.into_inner());
+        let count = rejections
+            .get_or_insert_with(HashMap::new)
+            .entry((topic.to_string(), publisher.to_string()))
+            .or_insert(0);
+        *count += 1;
+        *count
+    };
+    if count != 1 && count % REPORT_EVERY != 0 {
+        return;
+    }
+
+    let message = format!(
+        "Rejected publish to '{}' by '{}' (no designated publish key, {} rejected)",
+        topic, publisher, count
+    );
+    log::warn!("{}", message);
+
+    use crate::core::log_buffer::{publish_log, LogEntry, LogType};
+    use chrono::Local;
+    publish_log(LogEntry {
+        timestamp: Local::now().format("%H:%M:%S%.3f").to_string(),
+        tick_number: 0,
+        node_name: publisher.to_string(),
+        log_type: LogType::Error,
+        topic: Some(topic.to_string()),
+        message: message.clone(),
+        tick_us: 0,
+        ipc_ns: 0,
+        trace_id: None,
+    });
+
+    let path = policy_log_path();
+    if let Some(parent) = path.parent() {
+        let _ = std::fs::create_dir_all(parent);
+    }
+    let line = format!(
+        "{} pid={} {}\n",
+        Local::now().to_rfc3339(),
+        std::process::id(),
+        message
+    );
+    if let Err(e) = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .and_then(|mut file| file.write_all(line.as_bytes()))
+    {
+        log::warn!("Failed to write policy log {}: {}", path.display(), e);
+    }
+}
+
+/// Log of publish policy violations
+pub fn policy_log_path() -> PathBuf {
+    match std::env::var(POLICY_LOG_ENV) {
+        Ok(path) if !path.is_empty() => PathBuf::from(path),
+        _ => crate::memory::shm_base_dir().join("publish_policy.log"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_publisher_policy_keys() {
+        let hashes: HashMap<String, String> = [
+            ("safety_controller".to_string(), key_hash("safety-secret")),
+            ("teleop".to_string(), key_hash("teleop-secret")),
+            ("teleop_backup".to_string(), key_hash("teleop-secret")),
+        ]
+        .into_iter()
+        .collect();
+        let policy = PublisherPolicy::from_key_hashes(&hashes).unwrap();
+        assert_eq!(policy.tags().len(), 2);
+        assert!(policy.accepts(Some("teleop-secret")));
+        assert!(!policy.accepts(Some("guess")));
+        assert!(!policy.accepts(None));
+
+        let read_back = PublisherPolicy::from_tags(policy.tags().to_vec());
+        assert!(read_back.accepts(Some("safety-secret")));
+
+        // `sha256sum` output of the key, upper or lower case
+        assert_eq!(
+            key_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        let upper: HashMap<String, String> = [("node".to_string(), key_hash("abc").to_uppercase())]
+            .into_iter()
+            .collect();
+        assert!(PublisherPolicy::from_key_hashes(&upper)
+            .unwrap()
+            .accepts(Some("abc")));
+
+        let invalid: HashMap<String, String> = [("node".to_string(), "abc".to_string())]
+            .into_iter()
+            .collect();
+        assert!(PublisherPolicy::from_key_hashes(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("arm.*", "arm.joint1"));
+        assert!(glob_match("*/motors.cmd_vel", "robot1/motors.cmd_vel"));
+        assert!(glob_match("motors.cmd_vel", "motors.cmd_vel"));
+        assert!(!glob_match("arm.*", "base.cmd_vel"));
+        assert!(!glob_match("motors", "motors.cmd_vel"));
+    }
+}
@@ -0,0 +1,335 @@
+//! Time-synchronized subscription to several topics
+//!
+//! `SyncSubscriber` groups messages from 2 to 4 Hubs whose timestamps lie
+//! within a tolerance of each other (the ApproximateTime policy of ROS
+//! `message_filters`) and delivers them as one tuple:
+//!
+//! ```rust,ignore
+//! let mut sync = SyncSubscriber::new(
+//!     (
+//!         SyncInput::new(Hub::<Image>::new("camera")?, |img| img.timestamp),
+//!         SyncInput::new(Hub::<LaserScan>::new("scan")?, |scan| scan.timestamp),
+//!     ),
+//!     Duration::from_millis(20),
+//! );
+//!
+//! // in tick()
+//! while let Some((image, scan)) = sync.recv(&mut ctx) {
+//!     fuse(&image, &scan);
+//! }
+//! ```
+//!
+//! Timestamps are in nanoseconds and must not decrease on a topic. Each
+//! topic keeps at most `queue_size` unmatched messages; messages that can no
+//! longer be part of a set are dropped and counted in [`SyncSubscriber::dropped`].
+
+use crate::communication::hub::Hub;
+use crate::core::node::NodeInfo;
+use crate::core::LogSummary;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::time::Duration;
+
+/// Default number of unmatched messages kept per topic
+pub const DEFAULT_SYNC_QUEUE_SIZE: usize = 10;
+
+/// Extracts the timestamp (nanoseconds) of a message
+pub(crate) type StampFn<T> = Box<dyn Fn(&T) -> u64 + Send + Sync>;
+
+/// One topic of a `SyncSubscriber`
+pub struct SyncInput<T> {
+    hub: Hub<T>,
+    stamp: StampFn<T>,
+    queue: VecDeque<(u64, T)>,
+}
+
+impl<T> SyncInput<T>
+where
+    T: Send
+        + Sync
+        + 'static
+        + Clone
+        + Debug
+        + serde::Serialize
+        + serde::de::DeserializeOwned
+        + LogSummary,
+{
+    /// Synchronize `hub` on the timestamp returned by `stamp`
+    pub fn new<F>(hub: Hub<T>, stamp: F) -> Self
+    where
+        F: Fn(&T) -> u64 + Send + Sync + 'static,
+    {
+        Self {
+            hub,
+            stamp: Box::new(stamp),
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Move received messages into the queue, returns how many overflowed
+    fn poll(&mut self, ctx: &mut Option<&mut NodeInfo>, queue_size: usize) -> u64 {
+        let mut dropped = 0;
+        while let Some(msg) = self.hub.recv(ctx) {
+            self.queue.push_back(((self.stamp)(&msg), msg));
+            if self.queue.len() > queue_size {
+                self.queue.pop_front();
+                dropped += 1;
+            }
+        }
+        dropped
+    }
+
+    fn front(&self) -> Option<u64> {
+        self.queue.front().map(|(stamp, _)| *stamp)
+    }
+
+    /// Drop messages superseded by a later one that is still not after `pivot`
+    fn skip_to(&mut self, pivot: u64) -> u64 {
+        let mut dropped = 0;
+        while self.queue.len() > 1 && self.queue[1].0 <= pivot {
+            self.queue.pop_front();
+            dropped += 1;
+        }
+        dropped
+    }
+
+    fn drop_front(&mut self) {
+        self.queue.pop_front();
+    }
+
+    fn pop(&mut self) -> T {
+        self.queue
+            .pop_front()
+            .map(|(_, msg)| msg)
+            .expect("sync queue checked non-empty")
+    }
+
+    /// The subscribed Hub
+    pub fn hub(&self) -> &Hub<T> {
+        &self.hub
+    }
+}
+
+impl<T> Debug for SyncInput<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SyncInput")
+            .field("hub", &self.hub)
+            .field("queued", &self.queue.len())
+            .finish()
+    }
+}
+
+/// Tuples of 2 to 4 `SyncInput`s
+pub trait SyncInputs {
+    /// Tuple of messages delivered together
+    type Output;
+
+    #[doc(hidden)]
+    fn poll(&mut self, ctx: &mut Option<&mut NodeInfo>, queue_size: usize) -> u64;
+
+    /// Front timestamp of every queue, None if one is empty
+    #[doc(hidden)]
+    fn fronts(&self) -> Option<Vec<u64>>;
+
+    #[doc(hidden)]
+    fn skip_to(&mut self, pivot: u64) -> u64;
+
+    #[doc(hidden)]
+    fn drop_front(&mut self, index: usize);
+
+    #[doc(hidden)]
+    fn pop(&mut self) -> Self::Output;
+}
+
+macro_rules! impl_sync_inputs {
+    ($($T:ident $i:tt),+) => {
+        impl<$($T),+> SyncInputs for ($(SyncInput<$T>,)+)
+        where
+            $($T: Send
+                + Sync
+                + 'static
+                + Clone
+                + Debug
+                + serde::Serialize
+                + serde::de::DeserializeOwned
+                + LogSummary,)+
+        {
+            type Output = ($($T,)+);
+
+            fn poll(&mut self, ctx: &mut Option<&mut NodeInfo>, queue_size: usize) -> u64 {
+                0 $(+ self.$i.poll(ctx, queue_size))+
+            }
+
+            fn fronts(&self) -> Option<Vec<u64>> {
+                Some(vec![$(self.$i.front()?),+])
+            }
+
+            fn skip_to(&mut self, pivot: u64) -> u64 {
+                0 $(+ self.$i.skip_to(pivot))+
+            }
+
+            fn drop_front(&mut self, index: usize) {
+                match index {
+                    $($i => self.$i.drop_front(),)+
+                    _ => {}
+                }
+            }
+
+            fn pop(&mut self) -> Self::Output {
+                ($(self.$i.pop(),)+)
+            }
+        }
+    };
+}
+
+impl_sync_inputs!(A 0, B 1);
+impl_sync_inputs!(A 0, B 1, C 2);
+impl_sync_inputs!(A 0, B 1, C 2, D 3);
+
+/// Delivers messages of several topics whose timestamps are within a tolerance
+///
+/// See the [module documentation](self).
+#[derive(Debug)]
+pub struct SyncSubscriber<I> {
+    inputs: I,
+    tolerance_ns: u64,
+    queue_size: usize,
+    dropped: u64,
+}
+
+impl<I: SyncInputs> SyncSubscriber<I> {
+    /// Synchronize `inputs`, a tuple of 2 to 4 `SyncInput`s
+    pub fn new(inputs: I, tolerance: Duration) -> Self {
+        Self {
+            inputs,
+            tolerance_ns: tolerance.as_nanos().min(u64::MAX as u128) as u64,
+            queue_size: DEFAULT_SYNC_QUEUE_SIZE,
+            dropped: 0,
+        }
+    }
+
+    /// Keep at most `size` unmatched messages per topic
+    pub fn with_queue_size(mut self, size: usize) -> Self {
+        self.queue_size = size.max(1);
+        self
+    }
+
+    /// Receive the next synchronized set of messages
+    pub fn recv(&mut self, ctx: &mut Option<&mut NodeInfo>) -> Option<I::Output> {
+        self.dropped += self.inputs.poll(ctx, self.queue_size);
+
+        loop {
+            let fronts = self.inputs.fronts()?;
+            let pivot = *fronts.iter().max()?;
+
+            // Prefer the message of each topic closest to the newest front
+            self.dropped += self.inputs.skip_to(pivot);
+            let fronts = self.inputs.fronts()?;
+            let (oldest, &min) = fronts.iter().enumerate().min_by_key(|&(_, s)| *s)?;
+
+            if pivot - min <= self.tolerance_ns {
+                return Some(self.inputs.pop());
+            }
+
+            // The oldest front is too old for every later message of the
+            // topic holding the pivot, so it can never be matched
+            self.inputs.drop_front(oldest);
+            self.dropped += 1;
+        }
+    }
+
+    /// Messages discarded without being part of a set
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Maximum timestamp difference within a set
+    pub fn tolerance(&self) -> Duration {
+        Duration::from_nanos(self.tolerance_ns)
+    }
+
+    /// The synchronized inputs
+    pub fn inputs(&self) -> &I {
+        &self.inputs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Stamped {
+        timestamp: u64,
+        value: u32,
+    }
+
+    impl LogSummary for Stamped {
+        fn log_summary(&self) -> String {
+            format!("{}@{}", self.value, self.timestamp)
+        }
+    }
+
+    fn stamped(timestamp: u64, value: u32) -> Stamped {
+        Stamped { timestamp, value }
+    }
+
+    fn input(topic: &str) -> SyncInput<Stamped> {
+        SyncInput::new(Hub::new(topic).unwrap(), |m: &Stamped| m.timestamp)
+    }
+
+    #[test]
+    fn test_sync_pairs_within_tolerance() {
+        let camera: Hub<Stamped> = Hub::new("test_sync_camera").unwrap();
+        let lidar: Hub<Stamped> = Hub::new("test_sync_lidar").unwrap();
+        let mut sync = SyncSubscriber::new(
+            (input("test_sync_camera"), input("test_sync_lidar")),
+            Duration::from_nanos(5),
+        );
+
+        camera.send(stamped(100, 1), &mut None).unwrap();
+        assert_eq!(sync.recv(&mut None), None);
+
+        // 120 is too far from 100: the camera frame is dropped
+        lidar.send(stamped(120, 1), &mut None).unwrap();
+        assert_eq!(sync.recv(&mut None), None);
+        assert_eq!(sync.dropped(), 1);
+
+        // Of 118 and 122, the one not after the pivot is preferred
+        camera.send(stamped(118, 2), &mut None).unwrap();
+        camera.send(stamped(122, 3), &mut None).unwrap();
+        assert_eq!(
+            sync.recv(&mut None),
+            Some((stamped(118, 2), stamped(120, 1)))
+        );
+        assert_eq!(sync.recv(&mut None), None);
+    }
+
+    #[test]
+    fn test_sync_three_topics() {
+        let a: Hub<Stamped> = Hub::new("test_sync3_a").unwrap();
+        let b: Hub<Stamped> = Hub::new("test_sync3_b").unwrap();
+        let c: Hub<Stamped> = Hub::new("test_sync3_c").unwrap();
+        let mut sync = SyncSubscriber::new(
+            (
+                input("test_sync3_a"),
+                input("test_sync3_b"),
+                input("test_sync3_c"),
+            ),
+            Duration::from_nanos(10),
+        )
+        .with_queue_size(2);
+
+        for (i, t) in [0u64, 100, 200].into_iter().enumerate() {
+            a.send(stamped(t, i as u32), &mut None).unwrap();
+        }
+        b.send(stamped(203, 0), &mut None).unwrap();
+        c.send(stamped(195, 0), &mut None).unwrap();
+
+        let (ma, mb, mc) = sync.recv(&mut None).unwrap();
+        assert_eq!((ma.timestamp, mb.timestamp, mc.timestamp), (200, 203, 195));
+        // 0 overflowed the queue of 2, 100 was superseded by 200
+        assert_eq!(sync.dropped(), 2);
+    }
+}
@@ -0,0 +1,119 @@
+//! Typed topic names
+//!
+//! The `topics!` macro declares a project's topics once, with their message
+//! types, as [`Topic`] constants:
+//!
+//! ```rust,ignore
+//! topics! {
+//!     /// Scans from the front lidar
+//!     "sensors.lidar": LaserScan,
+//!     "motors.cmd_vel": CmdVel,
+//! }
+//!
+//! let scans = Hub::new(topics::SENSORS_LIDAR)?; // Hub<LaserScan>
+//! ```
+//!
+//! A misspelled constant fails to compile, and so does using a constant with
+//! a hub of another message type. `horus check` validates string topics in
+//! Rust and Python code against the same registry.
+
+use std::marker::PhantomData;
+
+/// A topic name bound to its message type
+pub struct Topic<T> {
+    name: &'static str,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Topic<T> {
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            _marker: PhantomData,
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+// Manual impls so that `T` needs no bounds
+impl<T> Clone for Topic<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Topic<T> {}
+
+impl<T> std::fmt::Debug for Topic<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Topic")
+            .field("name", &self.name)
+            .field("type", &std::any::type_name::<T>())
+            .finish()
+    }
+}
+
+impl<T> std::fmt::Display for Topic<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+/// Registry entry generated by `topics!`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TopicInfo {
+    pub name: &'static str,
+    /// Message type as written in the registry
+    pub type_name: &'static str,
+}
+
+/// Topic arguments accepted by `Hub::new` for a `Hub<T>`
+///
+/// Implemented for strings, which may be any endpoint (`"topic@router"`), and
+/// for [`Topic<T>`] constants of the same message type.
+pub trait TopicName<T> {
+    fn topic_name(&self) -> &str;
+}
+
+impl<T> TopicName<T> for Topic<T> {
+    fn topic_name(&self) -> &str {
+        self.name
+    }
+}
+
+impl<T, S: AsRef<str> + ?Sized> TopicName<T> for &S {
+    fn topic_name(&self) -> &str {
+        (**self).as_ref()
+    }
+}
+
+impl<T> TopicName<T> for String {
+    fn topic_name(&self) -> &str {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn name_of<T>(topic: impl TopicName<T>) -> String {
+        topic.topic_name().to_string()
+    }
+
+    #[test]
+    fn test_topic_name_sources() {
+        const LIDAR: Topic<f32> = Topic::new("sensors.lidar");
+        let owned = String::from("motors.cmd_vel");
+
+        assert_eq!(name_of::<f32>(LIDAR), "sensors.lidar");
+        assert_eq!(LIDAR.to_string(), "sensors.lidar");
+        assert_eq!(name_of::<f32>("imu@router"), "imu@router");
+        assert_eq!(name_of::<f32>(&owned), "motors.cmd_vel");
+        assert_eq!(name_of::<f32>(&owned.as_str()), "motors.cmd_vel");
+        assert_eq!(name_of::<f32>(owned), "motors.cmd_vel");
+    }
+}
@@ -0,0 +1,751 @@
+//! Topic data validation at the subscriber boundary
+//!
+//! A [`TopicValidator`] holds declarative rules (field ranges, no NaN/Inf,
+//! monotonic fields such as timestamps) that are checked on every message a
+//! `Hub` receives. Each rule has an action: pass the message on with a warning,
+//! drop it, or clamp the offending field into range. Violations are counted
+//! per rule and exposed through `Hub::get_validation_stats()`.
+//!
+//! Fields are addressed by dotted path into the message's serde structure
+//! (`pose.x`, `ranges.3`); a newtype message like `struct Speed(f64)` is
+//! addressed by the empty path `""`.
+//!
+//! ```rust,ignore
+//! use horus_core::communication::{Hub, TopicValidator, ValidationAction};
+//!
+//! let imu: Hub<Imu> = Hub::new("imu")?.with_validator(
+//!     TopicValidator::new()
+//!         .finite(ValidationAction::Drop)
+//!         .range("linear_acceleration.2", -50.0, 50.0, ValidationAction::Clamp)
+//!         .monotonic("timestamp", ValidationAction::Drop),
+//! );
+//! ```
+
+use serde::ser::{self, Serialize};
+use serde::{Deserialize, Serialize as SerializeDerive};
+use std::collections::HashMap;
+use std::fmt;
+
+/// What to do with a message that violates a rule
+#[derive(Debug, Clone, Copy, PartialEq, Eq, SerializeDerive, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationAction {
+    /// Deliver the message unchanged and log a warning
+    Warn,
+    /// Discard the message
+    Drop,
+    /// Clamp the field into range (range rules only; other rules drop)
+    Clamp,
+}
+
+/// A single declarative validation rule
+#[derive(Debug, Clone, PartialEq, SerializeDerive, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ValidationRule {
+    /// Numeric field must lie within `[min, max]`
+    Range { field: String, min: f64, max: f64 },
+    /// Numeric fields must not be NaN or infinite (all fields if `field` is None)
+    Finite { field: Option<String> },
+    /// Numeric field must strictly increase from one message to the next
+    Monotonic { field: String },
+}
+
+impl fmt::Display for ValidationRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidationRule::Range { field, min, max } => {
+                write!(f, "range({}: {}..{})", field, min, max)
+            }
+            ValidationRule::Finite { field: Some(field) } => write!(f, "finite({})", field),
+            ValidationRule::Finite { field: None } => write!(f, "finite(*)"),
+            ValidationRule::Monotonic { field } => write!(f, "monotonic({})", field),
+        }
+    }
+}
+
+/// A rule together with the action taken when it is violated
+#[derive(Debug, Clone, PartialEq, SerializeDerive, Deserialize)]
+pub struct ValidationRuleSpec {
+    #[serde(flatten)]
+    pub rule: ValidationRule,
+    pub action: ValidationAction,
+}
+
+/// Violation counters of a validator
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationStats {
+    /// Messages checked
+    pub checked: u64,
+    /// Messages delivered despite a violation (`Warn`)
+    pub warned: u64,
+    /// Messages delivered with clamped fields
+    pub clamped: u64,
+    /// Messages discarded
+    pub dropped: u64,
+    /// Violations per rule (keyed by the rule's display form)
+    pub violations: HashMap<String, u64>,
+}
+
+impl ValidationStats {
+    /// Total number of rule violations
+    pub fn total_violations(&self) -> u64 {
+        self.violations.values().sum()
+    }
+}
+
+/// Result of validating one message
+#[derive(Debug)]
+pub enum ValidationOutcome<T> {
+    /// Deliver the message (possibly clamped); carries warnings to log
+    Deliver(T, Vec<String>),
+    /// Discard the message; carries the reason
+    Dropped(String),
+}
+
+/// Declarative per-topic message validator
+#[derive(Debug, Clone, Default)]
+pub struct TopicValidator {
+    rules: Vec<ValidationRuleSpec>,
+    /// Last value seen per monotonic field
+    last_values: HashMap<String, f64>,
+    stats: ValidationStats,
+}
+
+impl TopicValidator {
+    /// Create a validator without rules
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a validator from rule specs (e.g. loaded from YAML)
+    pub fn from_rules(rules: Vec<ValidationRuleSpec>) -> Self {
+        Self {
+            rules,
+            ..Default::default()
+        }
+    }
+
+    /// Add a rule
+    pub fn rule(mut self, rule: ValidationRule, action: ValidationAction) -> Self {
+        self.rules.push(ValidationRuleSpec { rule, action });
+        self
+    }
+
+    /// Require `field` to lie within `[min, max]`
+    pub fn range(self, field: &str, min: f64, max: f64, action: ValidationAction) -> Self {
+        self.rule(
+            ValidationRule::Range {
+                field: field.to_string(),
+                min,
+                max,
+            },
+            action,
+        )
+    }
+
+    /// Reject NaN and infinite values in any numeric field
+    pub fn finite(self, action: ValidationAction) -> Self {
+        self.rule(ValidationRule::Finite { field: None }, action)
+    }
+
+    /// Reject NaN and infinite values in `field` (and fields nested below it)
+    pub fn finite_field(self, field: &str, action: ValidationAction) -> Self {
+        self.rule(
+            ValidationRule::Finite {
+                field: Some(field.to_string()),
+            },
+            action,
+        )
+    }
+
+    /// Require `field` to strictly increase between messages
+    pub fn monotonic(self, field: &str, action: ValidationAction) -> Self {
+        self.rule(
+            ValidationRule::Monotonic {
+                field: field.to_string(),
+            },
+            action,
+        )
+    }
+
+    /// Configured rules
+    pub fn rules(&self) -> &[ValidationRuleSpec] {
+        &self.rules
+    }
+
+    /// Violation counters
+    pub fn stats(&self) -> &ValidationStats {
+        &self.stats
+    }
+
+    /// Check a message against all rules
+    pub fn validate<T>(&mut self, msg: T) -> ValidationOutcome<T>
+    where
+        T: Serialize + serde::de::DeserializeOwned,
+    {
+        self.stats.checked += 1;
+
+        let fields = match numeric_fields(&msg) {
+            Ok(fields) => fields,
+            // Messages that can't be inspected are passed through
+            Err(_) => return ValidationOutcome::Deliver(msg, Vec::new()),
+        };
+
+        let mut warnings = Vec::new();
+        let mut clamps: Vec<(String, f64)> = Vec::new();
+        let mut drop_reason = None;
+        let mut monotonic_updates: Vec<(String, f64)> = Vec::new();
+
+        for spec in &self.rules {
+            // (violation description, whether the field was clamped)
+            let violation = match &spec.rule {
+                ValidationRule::Range { field, min, max } => {
+                    match fields.iter().find(|(path, _)| path == field) {
+                        Some((_, value)) if value.is_nan() || *value < *min || *value > *max => {
+                            let clamp = spec.action == ValidationAction::Clamp && !value.is_nan();
+                            if clamp {
+                                clamps.push((field.clone(), value.clamp(*min, *max)));
+                            }
+                            Some((
+                                format!("{} = {} outside [{}, {}]", field, value, min, max),
+                                clamp,
+                            ))
+                        }
+                        _ => None,
+                    }
+                }
+                ValidationRule::Finite { field } => fields
+                    .iter()
+                    .filter(|(path, _)| field.as_ref().is_none_or(|f| is_under(path, f)))
+                    .find(|(_, value)| !value.is_finite())
+                    .map(|(path, value)| (format!("{} = {}", display_path(path), value), false)),
+                ValidationRule::Monotonic { field } => {
+                    match fields.iter().find(|(path, _)| path == field) {
+                        Some((_, value)) => match self.last_values.get(field) {
+                            Some(last) if *value <= *last => Some((
+                                format!("{} = {} not after previous {}", field, value, last),
+                                false,
+                            )),
+                            _ => {
+                                monotonic_updates.push((field.clone(), *value));
+                                None
+                            }
+                        },
+                        None => None,
+                    }
+                }
+            };
+
+            let Some((violation, clamped)) = violation else {
+                continue;
+            };
+
+            *self
+                .stats
+                .violations
+                .entry(spec.rule.to_string())
+                .or_insert(0) += 1;
+
+            match spec.action {
+                ValidationAction::Warn => warnings.push(format!("{}: {}", spec.rule, violation)),
+                ValidationAction::Clamp if clamped => {}
+                ValidationAction::Drop | ValidationAction::Clamp => {
+                    drop_reason.get_or_insert(format!("{}: {}", spec.rule, violation));
+                }
+            }
+        }
+
+        if let Some(reason) = drop_reason {
+            self.stats.dropped += 1;
+            return ValidationOutcome::Dropped(reason);
+        }
+
+        // Only accepted messages advance monotonic fields
+        self.last_values.extend(monotonic_updates);
+
+        if !warnings.is_empty() {
+            self.stats.warned += 1;
+        }
+
+        if clamps.is_empty() {
+            return ValidationOutcome::Deliver(msg, warnings);
+        }
+
+        match apply_clamps(&msg, &clamps) {
+            Some(clamped) => {
+                self.stats.clamped += 1;
+                ValidationOutcome::Deliver(clamped, warnings)
+            }
+            None => {
+                self.stats.dropped += 1;
+                ValidationOutcome::Dropped("clamping failed (message not representable)".into())
+            }
+        }
+    }
+}
+
+/// True if `path` is `field` or nested below it
+fn is_under(path: &str, field: &str) -> bool {
+    field.is_empty()
+        || path == field
+        || (path.starts_with(field) && path.as_bytes().get(field.len()) == Some(&b'.'))
+}
+
+fn display_path(path: &str) -> &str {
+    if path.is_empty() {
+        "<value>"
+    } else {
+        path
+    }
+}
+
+/// Rewrite clamped fields through a JSON round trip
+fn apply_clamps<T>(msg: &T, clamps: &[(String, f64)]) -> Option<T>
+where
+    T: Serialize + serde::de::DeserializeOwned,
+{
+    let mut value = serde_json::to_value(msg).ok()?;
+    for (field, clamped) in clamps {
+        let slot = if field.is_empty() {
+            &mut value
+        } else {
+            field.split('.').try_fold(&mut value, |v, key| match v {
+                serde_json::Value::Object(map) => map.get_mut(key),
+                serde_json::Value::Array(arr) => {
+                    key.parse::<usize>().ok().and_then(|i| arr.get_mut(i))
+                }
+                _ => None,
+            })?
+        };
+        // Keep integers integral so they deserialize back into integer fields
+        *slot = if slot.is_i64() || slot.is_u64() {
+            serde_json::json!(clamped.round() as i64)
+        } else {
+            serde_json::json!(clamped)
+        };
+    }
+    serde_json::from_value(value).ok()
+}
+
+// ============================================================================
+// Numeric field extraction
+// ============================================================================
+
+/// Collect all numeric leaves of a message as (dotted path, value)
+///
+/// Uses a dedicated serializer rather than serde_json so NaN and infinities
+/// are preserved (serde_json turns them into `null`).
+pub fn numeric_fields<T: Serialize + ?Sized>(msg: &T) -> Result<Vec<(String, f64)>, FieldError> {
+    let mut collector = FieldCollector {
+        path: Vec::new(),
+        fields: Vec::new(),
+    };
+    msg.serialize(&mut collector)?;
+    Ok(collector.fields)
+}
+
+/// Error raised while walking a message
+#[derive(Debug)]
+pub struct FieldError(String);
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for FieldError {}
+
+impl ser::Error for FieldError {
+    fn custom<M: fmt::Display>(msg: M) -> Self {
+        FieldError(msg.to_string())
+    }
+}
+
+struct FieldCollector {
+    path: Vec<String>,
+    fields: Vec<(String, f64)>,
+}
+
+impl FieldCollector {
+    fn push(&mut self, value: f64) {
+        self.fields.push((self.path.join("."), value));
+    }
+
+    fn nested<T: Serialize + ?Sized>(&mut self, key: String, value: &T) -> Result<(), FieldError> {
+        self.path.push(key);
+        let result = value.serialize(&mut *self);
+        self.path.pop();
+        result
+    }
+}
+
+/// Sequence/map state: next element index and pending map key
+struct Compound<'a> {
+    collector: &'a mut FieldCollector,
+    index: usize,
+    key: Option<String>,
+}
+
+impl<'a> Compound<'a> {
+    fn element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), FieldError> {
+        let key = self.index.to_string();
+        self.index += 1;
+        self.collector.nested(key, value)
+    }
+}
+
+macro_rules! collect_number {
+    ($($method:ident: $ty:ty),*) => {
+        $(
+            fn $method(self, v: $ty) -> Result<(), FieldError> {
+                self.push(v as f64);
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl<'a> ser::Serializer for &'a mut FieldCollector {
+    type Ok = ();
+    type Error = FieldError;
+    type SerializeSeq = Compound<'a>;
+    type SerializeTuple = Compound<'a>;
+    type SerializeTupleStruct = Compound<'a>;
+    type SerializeTupleVariant = Compound<'a>;
+    type SerializeMap = Compound<'a>;
+    type SerializeStruct = Compound<'a>;
+    type SerializeStructVariant = Compound<'a>;
+
+    collect_number!(
+        serialize_i8: i8, serialize_i16: i16, serialize_i32: i32, serialize_i64: i64,
+        serialize_u8: u8, serialize_u16: u16, serialize_u32: u32, serialize_u64: u64,
+        serialize_f32: f32, serialize_f64: f64
+    );
+
+    fn serialize_bool(self, _v: bool) -> Result<(), FieldError> {
+        Ok(())
+    }
+
+    fn serialize_char(self, _v: char) -> Result<(), FieldError> {
+        Ok(())
+    }
+
+    fn serialize_str(self, _v: &str) -> Result<(), FieldError> {
+        Ok(())
+    }
+
+    fn serialize_bytes(self, _v: &[u8]) -> Result<(), FieldError> {
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), FieldError> {
+        Ok(())
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<(), FieldError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), FieldError> {
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), FieldError> {
+        Ok(())
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        _variant: &'static str,
+    ) -> Result<(), FieldError> {
+        Ok(())
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), FieldError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), FieldError> {
+        self.nested(variant.to_string(), value)
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound<'a>, FieldError> {
+        Ok(Compound {
+            collector: self,
+            index: 0,
+            key: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Compound<'a>, FieldError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Compound<'a>, FieldError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Compound<'a>, FieldError> {
+        self.path.push(variant.to_string());
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'a>, FieldError> {
+        self.serialize_seq(None)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, FieldError> {
+        self.serialize_seq(None)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'a>, FieldError> {
+        self.path.push(variant.to_string());
+        self.serialize_seq(None)
+    }
+}
+
+impl ser::SerializeSeq for Compound<'_> {
+    type Ok = ();
+    type Error = FieldError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), FieldError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), FieldError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTuple for Compound<'_> {
+    type Ok = ();
+    type Error = FieldError;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), FieldError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), FieldError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleStruct for Compound<'_> {
+    type Ok = ();
+    type Error = FieldError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), FieldError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), FieldError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeTupleVariant for Compound<'_> {
+    type Ok = ();
+    type Error = FieldError;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), FieldError> {
+        self.element(value)
+    }
+
+    fn end(self) -> Result<(), FieldError> {
+        self.collector.path.pop();
+        Ok(())
+    }
+}
+
+impl ser::SerializeMap for Compound<'_> {
+    type Ok = ();
+    type Error = FieldError;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), FieldError> {
+        let key = match serde_json::to_value(key) {
+            Ok(serde_json::Value::String(s)) => s,
+            Ok(other) => other.to_string(),
+            Err(e) => return Err(FieldError(e.to_string())),
+        };
+        self.key = Some(key);
+        Ok(())
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), FieldError> {
+        let key = self.key.take().unwrap_or_default();
+        self.collector.nested(key, value)
+    }
+
+    fn end(self) -> Result<(), FieldError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStruct for Compound<'_> {
+    type Ok = ();
+    type Error = FieldError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), FieldError> {
+        self.collector.nested(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<(), FieldError> {
+        Ok(())
+    }
+}
+
+impl ser::SerializeStructVariant for Compound<'_> {
+    type Ok = ();
+    type Error = FieldError;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), FieldError> {
+        self.collector.nested(key.to_string(), value)
+    }
+
+    fn end(self) -> Result<(), FieldError> {
+        self.collector.path.pop();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, SerializeDerive, Deserialize)]
+    struct Pose {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, SerializeDerive, Deserialize)]
+    struct Reading {
+        timestamp: u64,
+        pose: Pose,
+        ranges: Vec<f32>,
+    }
+
+    fn reading(timestamp: u64, x: f64, range: f32) -> Reading {
+        Reading {
+            timestamp,
+            pose: Pose { x, y: 0.0 },
+            ranges: vec![1.0, range],
+        }
+    }
+
+    #[test]
+    fn test_numeric_fields_keep_nan() {
+        let fields = numeric_fields(&reading(5, f64::NAN, 2.0)).unwrap();
+        let paths: Vec<&str> = fields.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["timestamp", "pose.x", "pose.y", "ranges.0", "ranges.1"]
+        );
+        assert!(fields[1].1.is_nan());
+    }
+
+    #[test]
+    fn test_validator_actions() {
+        let mut validator = TopicValidator::new()
+            .finite(ValidationAction::Drop)
+            .range("pose.x", -1.0, 1.0, ValidationAction::Clamp)
+            .range("ranges.1", 0.0, 10.0, ValidationAction::Warn)
+            .monotonic("timestamp", ValidationAction::Drop);
+
+        // Clamped into range
+        match validator.validate(reading(1, 5.0, 2.0)) {
+            ValidationOutcome::Deliver(msg, warnings) => {
+                assert_eq!(msg.pose.x, 1.0);
+                assert!(warnings.is_empty());
+            }
+            other => panic!("expected delivery, got {:?}", other),
+        }
+
+        // Out of range with Warn: delivered unchanged
+        match validator.validate(reading(2, 0.0, 20.0)) {
+            ValidationOutcome::Deliver(msg, warnings) => {
+                assert_eq!(msg.ranges[1], 20.0);
+                assert_eq!(warnings.len(), 1);
+            }
+            other => panic!("expected delivery, got {:?}", other),
+        }
+
+        // NaN and stale timestamp are dropped
+        assert!(matches!(
+            validator.validate(reading(3, 0.0, f32::NAN)),
+            ValidationOutcome::Dropped(_)
+        ));
+        assert!(matches!(
+            validator.validate(reading(2, 0.0, 1.0)),
+            ValidationOutcome::Dropped(_)
+        ));
+
+        let stats = validator.stats();
+        assert_eq!(stats.checked, 4);
+        assert_eq!(stats.clamped, 1);
+        assert_eq!(stats.warned, 1);
+        assert_eq!(stats.dropped, 2);
+        assert_eq!(stats.violations["monotonic(timestamp)"], 1);
+    }
+
+    #[test]
+    fn test_rules_from_yaml() {
+        let rules: Vec<ValidationRuleSpec> = serde_yaml::from_str(
+            r#"
+- type: range
+  field: pose.x
+  min: -1.0
+  max: 1.0
+  action: clamp
+- type: finite
+  field: null
+  action: drop
+"#,
+        )
+        .unwrap();
+        let validator = TopicValidator::from_rules(rules);
+        assert_eq!(validator.rules().len(), 2);
+        assert_eq!(validator.rules()[0].action, ValidationAction::Clamp);
+    }
+}
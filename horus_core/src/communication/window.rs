@@ -0,0 +1,319 @@
+//! Recent history of a topic
+//!
+//! `Hub::window(span)` keeps the messages of the last `span` in time order,
+//! so nodes that smooth, differentiate or interpolate a signal don't keep
+//! their own buffers:
+//!
+//! ```rust,ignore
+//! let mut imu = Hub::<Imu>::new("imu")?
+//!     .window(Duration::from_secs(2))
+//!     .stamped_by(|m| m.timestamp);
+//!
+//! // in tick()
+//! imu.update(&mut ctx);
+//! let yaw_rate = imu.mean(|m| m.angular_velocity[2]);
+//! let accel = imu.rate(|m| m.linear_acceleration[0]);
+//! ```
+//!
+//! Messages are stamped with their receive time (nanoseconds since the UNIX
+//! epoch) unless `stamped_by` supplies the message's own timestamp. The
+//! window covers `span` back from the newest message, so it keeps the last
+//! samples when the topic goes quiet.
+
+use crate::communication::hub::Hub;
+use crate::communication::sync::StampFn;
+use crate::core::node::NodeInfo;
+use crate::core::LogSummary;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Messages of a topic received during the last `span`, oldest first
+pub struct TopicWindow<T> {
+    hub: Hub<T>,
+    span_ns: u64,
+    stamp: Option<StampFn<T>>,
+    samples: VecDeque<(u64, T)>,
+}
+
+impl<T> TopicWindow<T>
+where
+    T: Send
+        + Sync
+        + 'static
+        + Clone
+        + Debug
+        + serde::Serialize
+        + serde::de::DeserializeOwned
+        + LogSummary,
+{
+    pub(crate) fn new(hub: Hub<T>, span: Duration) -> Self {
+        Self {
+            hub,
+            span_ns: span.as_nanos().min(u64::MAX as u128) as u64,
+            stamp: None,
+            samples: VecDeque::new(),
+        }
+    }
+
+    /// Stamp messages with their own timestamp (nanoseconds) instead of the receive time
+    pub fn stamped_by<F>(mut self, stamp: F) -> Self
+    where
+        F: Fn(&T) -> u64 + Send + Sync + 'static,
+    {
+        self.stamp = Some(Box::new(stamp));
+        self
+    }
+
+    /// Receive pending messages and drop those older than the span, returns
+    /// the number received
+    pub fn update(&mut self, ctx: &mut Option<&mut NodeInfo>) -> usize {
+        let mut received = 0;
+        while let Some(msg) = self.hub.recv(ctx) {
+            let stamp = match &self.stamp {
+                Some(stamp) => stamp(&msg),
+                None => now_ns(),
+            };
+            self.insert(stamp, msg);
+            received += 1;
+        }
+        received
+    }
+
+    /// Add a message, keeping the window in time order
+    pub fn insert(&mut self, stamp: u64, msg: T) {
+        let at = self.samples.partition_point(|(s, _)| *s <= stamp);
+        self.samples.insert(at, (stamp, msg));
+
+        let newest = self.samples.back().map_or(stamp, |(s, _)| *s);
+        let cutoff = newest.saturating_sub(self.span_ns);
+        while self.samples.front().is_some_and(|(s, _)| *s < cutoff) {
+            self.samples.pop_front();
+        }
+    }
+
+    /// Number of messages in the window
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Configured time span
+    pub fn span(&self) -> Duration {
+        Duration::from_nanos(self.span_ns)
+    }
+
+    /// Time between the oldest and newest message
+    pub fn covered(&self) -> Duration {
+        match (self.samples.front(), self.samples.back()) {
+            (Some((first, _)), Some((last, _))) => Duration::from_nanos(last - first),
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// Messages with their stamps, oldest first
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (u64, &T)> + '_ {
+        self.samples.iter().map(|(s, msg)| (*s, msg))
+    }
+
+    /// Newest message
+    pub fn latest(&self) -> Option<(u64, &T)> {
+        self.samples.back().map(|(s, msg)| (*s, msg))
+    }
+
+    /// Oldest message
+    pub fn oldest(&self) -> Option<(u64, &T)> {
+        self.samples.front().map(|(s, msg)| (*s, msg))
+    }
+
+    /// Newest message stamped at or before `stamp`
+    pub fn at_or_before(&self, stamp: u64) -> Option<(u64, &T)> {
+        let at = self.samples.partition_point(|(s, _)| *s <= stamp);
+        at.checked_sub(1)
+            .map(|i| (self.samples[i].0, &self.samples[i].1))
+    }
+
+    /// Message stamped closest to `stamp`
+    pub fn nearest(&self, stamp: u64) -> Option<(u64, &T)> {
+        let at = self.samples.partition_point(|(s, _)| *s <= stamp);
+        let before = at.checked_sub(1).map(|i| &self.samples[i]);
+        let after = self.samples.get(at);
+        let (s, msg) = match (before, after) {
+            (Some(b), Some(a)) if a.0 - stamp < stamp - b.0 => a,
+            (Some(b), _) => b,
+            (None, a) => a?,
+        };
+        Some((*s, msg))
+    }
+
+    /// Messages stamped within `from..=to`
+    pub fn between(&self, from: u64, to: u64) -> impl Iterator<Item = (u64, &T)> + '_ {
+        let start = self.samples.partition_point(|(s, _)| *s < from);
+        self.samples
+            .range(start..)
+            .take_while(move |(s, _)| *s <= to)
+            .map(|(s, msg)| (*s, msg))
+    }
+
+    /// Linearly interpolate `value` at `stamp`
+    ///
+    /// None if `stamp` is outside the window.
+    pub fn interpolate<F>(&self, stamp: u64, value: F) -> Option<f64>
+    where
+        F: Fn(&T) -> f64,
+    {
+        self.interpolate_with(stamp, |a, b, t| {
+            let (a, b) = (value(a), value(b));
+            a + (b - a) * t
+        })
+    }
+
+    /// Combine the messages around `stamp` with `f(before, after, t)`, where
+    /// `t` in `0.0..=1.0` is the position of `stamp` between them
+    ///
+    /// None if `stamp` is outside the window.
+    pub fn interpolate_with<R, F>(&self, stamp: u64, f: F) -> Option<R>
+    where
+        F: FnOnce(&T, &T, f64) -> R,
+    {
+        let at = self.samples.partition_point(|(s, _)| *s < stamp);
+        let (s1, after) = self.samples.get(at)?;
+        if *s1 == stamp {
+            return Some(f(after, after, 0.0));
+        }
+        let (s0, before) = &self.samples[at.checked_sub(1)?];
+        let t = (stamp - s0) as f64 / (s1 - s0) as f64;
+        Some(f(before, after, t))
+    }
+
+    /// Mean of `value` over the window
+    pub fn mean<F>(&self, value: F) -> Option<f64>
+    where
+        F: Fn(&T) -> f64,
+    {
+        if self.samples.is_empty() {
+            return None;
+        }
+        let sum: f64 = self.samples.iter().map(|(_, msg)| value(msg)).sum();
+        Some(sum / self.samples.len() as f64)
+    }
+
+    /// Average rate of change of `value` per second over the window
+    ///
+    /// None with fewer than two messages or no elapsed time.
+    pub fn rate<F>(&self, value: F) -> Option<f64>
+    where
+        F: Fn(&T) -> f64,
+    {
+        let (s0, first) = self.samples.front()?;
+        let (s1, last) = self.samples.back()?;
+        if s1 <= s0 {
+            return None;
+        }
+        Some((value(last) - value(first)) / ((s1 - s0) as f64 / 1e9))
+    }
+
+    /// Remove all messages
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// The subscribed Hub
+    pub fn hub(&self) -> &Hub<T> {
+        &self.hub
+    }
+}
+
+impl<T> Debug for TopicWindow<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TopicWindow")
+            .field("hub", &self.hub)
+            .field("span", &Duration::from_nanos(self.span_ns))
+            .field("len", &self.samples.len())
+            .finish()
+    }
+}
+
+fn now_ns() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Reading {
+        timestamp: u64,
+        value: f64,
+    }
+
+    impl LogSummary for Reading {
+        fn log_summary(&self) -> String {
+            format!("{}@{}", self.value, self.timestamp)
+        }
+    }
+
+    const SECOND: u64 = 1_000_000_000;
+
+    #[test]
+    fn test_window_evicts_and_queries() {
+        let publisher: Hub<Reading> = Hub::new("test_window_queries").unwrap();
+        let mut window = Hub::<Reading>::new("test_window_queries")
+            .unwrap()
+            .window(Duration::from_secs(2))
+            .stamped_by(|r| r.timestamp);
+
+        for (t, value) in [(0, 0.0), (1, 10.0), (2, 20.0), (3, 25.0)] {
+            let reading = Reading {
+                timestamp: t * SECOND,
+                value,
+            };
+            publisher.send(reading, &mut None).unwrap();
+        }
+        assert_eq!(window.update(&mut None), 4);
+
+        // t=0 is more than 2s older than t=3
+        assert_eq!(window.len(), 3);
+        assert_eq!(window.covered(), Duration::from_secs(2));
+        assert_eq!(window.oldest().unwrap().1.value, 10.0);
+        assert_eq!(window.at_or_before(2 * SECOND + 1).unwrap().1.value, 20.0);
+        assert_eq!(
+            window.nearest(2 * SECOND + SECOND / 3).unwrap().1.value,
+            20.0
+        );
+        assert_eq!(window.between(2 * SECOND, 3 * SECOND).count(), 2);
+
+        assert_eq!(
+            window.interpolate(SECOND + SECOND / 2, |r| r.value),
+            Some(15.0)
+        );
+        assert_eq!(window.interpolate(3 * SECOND, |r| r.value), Some(25.0));
+        assert_eq!(window.interpolate(4 * SECOND, |r| r.value), None);
+        assert_eq!(window.mean(|r| r.value), Some(55.0 / 3.0));
+        assert_eq!(window.rate(|r| r.value), Some(7.5));
+    }
+
+    #[test]
+    fn test_window_out_of_order_insert() {
+        let mut window = Hub::<Reading>::new("test_window_order")
+            .unwrap()
+            .window(Duration::from_secs(1));
+        for t in [3, 1, 2] {
+            let reading = Reading {
+                timestamp: t,
+                value: t as f64,
+            };
+            window.insert(t, reading);
+        }
+        let stamps: Vec<u64> = window.iter().map(|(s, _)| s).collect();
+        assert_eq!(stamps, vec![1, 2, 3]);
+    }
+}
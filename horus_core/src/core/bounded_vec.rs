@@ -0,0 +1,306 @@
+//! Variable-length sequences with a fixed memory layout
+
+use bytemuck::{Pod, Zeroable};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// A sequence of up to `N` elements stored inline.
+///
+/// `Vec<T>` owns a heap pointer, so it cannot be part of a Pod message.
+/// `BoundedVec<T, N>` keeps the elements in a fixed `[T; N]` buffer behind an
+/// 8-byte header holding the length (a native-endian `u32` and 4 reserved
+/// bytes), which keeps it `Pod` and lets it be used in `zero_copy_message!`
+/// types:
+///
+/// ```rust,ignore
+/// zero_copy_message! {
+///     Detections {
+///         timestamp_ns: u64,
+///         objects: BoundedVec<Detection, 256>,
+///     }
+/// }
+///
+/// let mut msg = Detections::default();
+/// msg.objects.push(detection).unwrap();
+///
+/// // Only the header and the used elements are sent
+/// let bytes = msg.as_trimmed_bytes();
+/// ```
+///
+/// Unused slots are kept zeroed. Serializes as a sequence of the used elements.
+///
+/// The header is stored as bytes, so the struct is aligned like `T` and any
+/// capacity works, e.g. `BoundedVec<u8, 5>` is 13 bytes. Elements must have
+/// an alignment of at most 8; wider elements would leave padding after the
+/// header and fail to compile:
+///
+/// ```compile_fail,E0080
+/// use horus_core::core::BoundedVec;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C, align(16))]
+/// struct Wide([u8; 16]);
+/// unsafe impl bytemuck::Zeroable for Wide {}
+/// unsafe impl bytemuck::Pod for Wide {}
+///
+/// let _ = BoundedVec::<Wide, 4>::new();
+/// ```
+///
+/// ```compile_fail,E0080
+/// use horus_core::core::BoundedVec;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C, align(16))]
+/// struct Wide([u8; 16]);
+/// unsafe impl bytemuck::Zeroable for Wide {}
+/// unsafe impl bytemuck::Pod for Wide {}
+///
+/// let _: BoundedVec<Wide, 4> = bytemuck::Zeroable::zeroed();
+/// ```
+#[derive(Clone, Copy)]
+#[repr(C)]
+pub struct BoundedVec<T: Pod, const N: usize> {
+    len: [u8; 4],
+    _reserved: [u8; 4],
+    data: [T; N],
+}
+
+// SAFETY: the 8-byte header is made of byte arrays, so the struct is aligned
+// like `T`: `data` starts right after the header for any `T` with alignment
+// <= 8 and the struct size is a multiple of its alignment, leaving no padding
+// bytes. Wider elements are rejected by `LAYOUT_CHECK`, which `new()`,
+// `zeroed()` and `zero_copy_message!` evaluate. Every field is Pod.
+unsafe impl<T: Pod, const N: usize> Zeroable for BoundedVec<T, N> {
+    fn zeroed() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::LAYOUT_CHECK;
+        // SAFETY: all fields are Pod, for which all-zero bytes are valid
+        unsafe { std::mem::zeroed() }
+    }
+}
+unsafe impl<T: Pod, const N: usize> Pod for BoundedVec<T, N> {}
+
+impl<T: Pod, const N: usize> BoundedVec<T, N> {
+    /// Maximum number of elements
+    pub const CAPACITY: usize = N;
+
+    /// Size of the length header in bytes
+    pub const HEADER_SIZE: usize = 8;
+
+    /// Compile-time layout check, evaluated wherever the type is created
+    #[doc(hidden)]
+    pub const LAYOUT_CHECK: () = {
+        assert!(
+            std::mem::align_of::<T>() <= 8,
+            "BoundedVec elements must have alignment <= 8"
+        );
+        assert!(N <= u32::MAX as usize, "BoundedVec capacity exceeds u32");
+        assert!(
+            std::mem::size_of::<Self>() == Self::HEADER_SIZE + N * std::mem::size_of::<T>(),
+            "BoundedVec<T, N> must not contain padding"
+        );
+    };
+
+    /// An empty sequence
+    pub fn new() -> Self {
+        Zeroable::zeroed()
+    }
+
+    fn set_len(&mut self, len: usize) {
+        self.len = (len as u32).to_ne_bytes();
+    }
+
+    /// Copy up to `N` elements from a slice, dropping the rest
+    pub fn from_slice(items: &[T]) -> Self {
+        let mut result = Self::new();
+        let len = items.len().min(N);
+        result.data[..len].copy_from_slice(&items[..len]);
+        result.set_len(len);
+        result
+    }
+
+    /// Number of elements
+    pub fn len(&self) -> usize {
+        (u32::from_ne_bytes(self.len) as usize).min(N)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == N
+    }
+
+    /// Append an element, handing it back if the sequence is full
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        let len = self.len();
+        if len == N {
+            return Err(value);
+        }
+        self.data[len] = value;
+        self.set_len(len + 1);
+        Ok(())
+    }
+
+    /// Remove the last element
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.len().checked_sub(1)?;
+        let value = std::mem::replace(&mut self.data[len], T::zeroed());
+        self.set_len(len);
+        Some(value)
+    }
+
+    /// Remove all elements
+    pub fn clear(&mut self) {
+        let len = self.len();
+        self.data[..len].fill(T::zeroed());
+        self.set_len(0);
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data[..self.len()]
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.len();
+        &mut self.data[..len]
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Bytes taken by the header and the used elements
+    pub fn used_size(&self) -> usize {
+        Self::HEADER_SIZE + self.len() * std::mem::size_of::<T>()
+    }
+}
+
+impl<T: Pod, const N: usize> Default for BoundedVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Pod, const N: usize> std::ops::Deref for BoundedVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T: Pod, const N: usize> std::ops::DerefMut for BoundedVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Pod + std::fmt::Debug, const N: usize> std::fmt::Debug for BoundedVec<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Pod + PartialEq, const N: usize> PartialEq for BoundedVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Pod, const N: usize> TryFrom<&[T]> for BoundedVec<T, N> {
+    /// The number of elements given
+    type Error = usize;
+
+    fn try_from(items: &[T]) -> Result<Self, usize> {
+        if items.len() > N {
+            return Err(items.len());
+        }
+        Ok(Self::from_slice(items))
+    }
+}
+
+impl<'a, T: Pod, const N: usize> IntoIterator for &'a BoundedVec<T, N> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Pod + Serialize, const N: usize> Serialize for BoundedVec<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl<'de, T: Pod + Deserialize<'de>, const N: usize> Deserialize<'de> for BoundedVec<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let items = Vec::<T>::deserialize(deserializer)?;
+        Self::try_from(items.as_slice()).map_err(|len| {
+            D::Error::custom(format!(
+                "sequence of {} elements exceeds capacity {}",
+                len, N
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bounded_vec_layout_and_access() {
+        assert_eq!(std::mem::size_of::<BoundedVec<u8, 8>>(), 16);
+        assert_eq!(std::mem::size_of::<BoundedVec<f64, 4>>(), 40);
+        // Byte header: no trailing padding for odd capacities
+        assert_eq!(std::mem::size_of::<BoundedVec<u8, 5>>(), 13);
+        assert_eq!(std::mem::align_of::<BoundedVec<u8, 5>>(), 1);
+
+        let mut odd = BoundedVec::<u8, 5>::from_slice(&[1, 2, 3]);
+        odd.push(4).unwrap();
+        assert_eq!(odd.as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(bytemuck::bytes_of(&odd)[..4], 4u32.to_ne_bytes());
+
+        let mut items = BoundedVec::<f32, 3>::new();
+        assert!(items.is_empty());
+        assert_eq!(items.used_size(), 8);
+
+        items.push(1.0).unwrap();
+        items.push(2.0).unwrap();
+        items.push(3.0).unwrap();
+        assert!(items.is_full());
+        assert_eq!(items.push(4.0), Err(4.0));
+        assert_eq!(items.as_slice(), &[1.0, 2.0, 3.0]);
+        assert_eq!(items.used_size(), 20);
+
+        assert_eq!(items.pop(), Some(3.0));
+        assert_eq!(items.len(), 2);
+        assert_eq!(
+            BoundedVec::<f32, 3>::try_from(&[1.0, 2.0][..]).unwrap(),
+            items
+        );
+        assert_eq!(
+            BoundedVec::<f32, 3>::try_from(&[0.0; 4][..]).unwrap_err(),
+            4
+        );
+
+        items.clear();
+        assert!(bytemuck::bytes_of(&items).iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_bounded_vec_serializes_used_elements() {
+        let items = BoundedVec::<u16, 8>::from_slice(&[7, 8, 9]);
+        let json = serde_json::to_string(&items).unwrap();
+        assert_eq!(json, "[7,8,9]");
+        assert_eq!(
+            serde_json::from_str::<BoundedVec<u16, 8>>(&json).unwrap(),
+            items
+        );
+        assert!(serde_json::from_str::<BoundedVec<u16, 2>>(&json).is_err());
+    }
+}
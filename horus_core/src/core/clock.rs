@@ -0,0 +1,217 @@
+//! Framework time: wall clock or simulated time
+//!
+//! Simulators (sim2d, sim3d) publish their simulated time on the
+//! [`CLOCK_TOPIC`] hub. When `use_sim_time` is enabled, every timestamp the
+//! framework produces follows that clock instead of the system clock: node
+//! time (`NodeInfo::now`), scheduler rate limiting, watchdogs, HFrame
+//! timestamps and recordings. Code that runs against a simulator or a replay
+//! then behaves exactly as it does on a robot, only with time supplied by
+//! the simulation.
+//!
+//! `use_sim_time` is process-wide. It can be turned on with
+//! `HORUS_USE_SIM_TIME=1`, `Scheduler::with_sim_time()`,
+//! `TimeSyncSource::SimTime` in a `SchedulerConfig`, or [`set_use_sim_time`].
+//! Until the first clock message arrives simulated time is zero.
+//!
+//! ```rust,ignore
+//! // Simulator: publish sim time after every physics step
+//! let clock = ClockPublisher::new()?;
+//! clock.publish(sim_time);
+//!
+//! // Node: timestamp data with framework time
+//! let stamp_ns = horus_core::core::clock::now_ns();
+//! ```
+
+use crate::communication::Hub;
+use crate::core::LogSummary;
+use crate::error::HorusResult;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, AtomicU8, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Topic on which simulators publish their simulated time
+pub const CLOCK_TOPIC: &str = "clock";
+
+/// Environment variable enabling sim time for the whole process
+pub const USE_SIM_TIME_ENV: &str = "HORUS_USE_SIM_TIME";
+
+const UNSET: u8 = 0;
+const WALL: u8 = 1;
+const SIM: u8 = 2;
+
+static USE_SIM_TIME: AtomicU8 = AtomicU8::new(UNSET);
+static SIM_TIME_NS: AtomicU64 = AtomicU64::new(0);
+static PROCESS_START: OnceLock<Instant> = OnceLock::new();
+
+/// Simulated time published by a simulator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Clock {
+    /// Simulated time since the simulation started, in nanoseconds
+    pub sim_time_ns: u64,
+}
+
+impl Clock {
+    pub fn new(sim_time: Duration) -> Self {
+        Self {
+            sim_time_ns: sim_time.as_nanos() as u64,
+        }
+    }
+
+    pub fn sim_time(&self) -> Duration {
+        Duration::from_nanos(self.sim_time_ns)
+    }
+}
+
+impl LogSummary for Clock {
+    fn log_summary(&self) -> String {
+        format!("{:.3}s", self.sim_time_ns as f64 / 1e9)
+    }
+}
+
+/// Whether framework time follows the simulated clock
+///
+/// Defaults to `HORUS_USE_SIM_TIME` (`1`/`true`) until set explicitly.
+pub fn use_sim_time() -> bool {
+    match USE_SIM_TIME.load(Ordering::Acquire) {
+        UNSET => {
+            let enabled = std::env::var(USE_SIM_TIME_ENV)
+                .map(|v| matches!(v.to_ascii_lowercase().as_str(), "1" | "true" | "yes"))
+                .unwrap_or(false);
+            let _ = USE_SIM_TIME.compare_exchange(
+                UNSET,
+                if enabled { SIM } else { WALL },
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            );
+            USE_SIM_TIME.load(Ordering::Acquire) == SIM
+        }
+        state => state == SIM,
+    }
+}
+
+/// Switch framework time between the simulated and the system clock
+pub fn set_use_sim_time(enabled: bool) {
+    USE_SIM_TIME.store(if enabled { SIM } else { WALL }, Ordering::Release);
+}
+
+/// Latest simulated time received in this process
+pub fn sim_time() -> Duration {
+    Duration::from_nanos(SIM_TIME_NS.load(Ordering::Acquire))
+}
+
+/// Record the latest simulated time (done by the scheduler for clock
+/// messages and lockstep steps)
+pub fn set_sim_time(sim_time: Duration) {
+    SIM_TIME_NS.store(sim_time.as_nanos() as u64, Ordering::Release);
+}
+
+/// Current time as a timestamp: simulated time, or time since the Unix epoch
+pub fn now() -> Duration {
+    if use_sim_time() {
+        sim_time()
+    } else {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+}
+
+/// [`now`] in nanoseconds
+pub fn now_ns() -> u64 {
+    now().as_nanos() as u64
+}
+
+/// [`now`] in microseconds
+pub fn now_us() -> u64 {
+    now().as_micros() as u64
+}
+
+/// Monotonic time for measuring intervals (timeouts, watchdogs)
+///
+/// Simulated time, or time since the process started. Only differences
+/// between two readings are meaningful.
+pub fn monotonic() -> Duration {
+    if use_sim_time() {
+        sim_time()
+    } else {
+        PROCESS_START.get_or_init(Instant::now).elapsed()
+    }
+}
+
+/// Publishes simulated time on the clock topic (simulator side)
+pub struct ClockPublisher {
+    hub: Hub<Clock>,
+}
+
+impl ClockPublisher {
+    pub fn new() -> HorusResult<Self> {
+        Self::with_topic(CLOCK_TOPIC)
+    }
+
+    pub fn with_topic(topic: &str) -> HorusResult<Self> {
+        Ok(Self {
+            hub: Hub::new(topic)?,
+        })
+    }
+
+    /// Publish the simulated time reached after a step
+    pub fn publish(&self, sim_time: Duration) {
+        let _ = self.hub.send(Clock::new(sim_time), &mut None);
+    }
+}
+
+/// Follows the clock topic and updates the process-wide simulated time
+pub struct ClockSubscriber {
+    hub: Hub<Clock>,
+}
+
+impl ClockSubscriber {
+    pub fn new() -> HorusResult<Self> {
+        Self::with_topic(CLOCK_TOPIC)
+    }
+
+    pub fn with_topic(topic: &str) -> HorusResult<Self> {
+        Ok(Self {
+            hub: Hub::new(topic)?,
+        })
+    }
+
+    /// Apply the newest pending clock message, if any, and return its time
+    pub fn poll(&mut self) -> Option<Duration> {
+        let mut latest = None;
+        while let Some(clock) = self.hub.recv(&mut None) {
+            latest = Some(clock.sim_time());
+        }
+        if let Some(time) = latest {
+            set_sim_time(time);
+        }
+        latest
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clock_message_roundtrip() {
+        let clock = Clock::new(Duration::from_millis(2500));
+        assert_eq!(clock.sim_time_ns, 2_500_000_000);
+        assert_eq!(clock.sim_time(), Duration::from_millis(2500));
+        assert_eq!(clock.log_summary(), "2.500s");
+    }
+
+    #[test]
+    fn test_subscriber_takes_latest_clock() {
+        let topic = format!("test.clock.{}", std::process::id());
+        let mut subscriber = ClockSubscriber::with_topic(&topic).unwrap();
+        let publisher = ClockPublisher::with_topic(&topic).unwrap();
+        assert_eq!(subscriber.poll(), None);
+
+        publisher.publish(Duration::from_millis(10));
+        publisher.publish(Duration::from_millis(20));
+        assert_eq!(subscriber.poll(), Some(Duration::from_millis(20)));
+        assert_eq!(subscriber.poll(), None);
+    }
+}
@@ -0,0 +1,47 @@
+//! Message type fingerprints
+//!
+//! A fingerprint is a hash of a message type's declaration (its name and its
+//! fields with their types, as written), so two processes can check that
+//! they agree on a message layout before exchanging raw bytes.
+
+/// Message types with a schema fingerprint
+///
+/// Implemented by `#[derive(HorusMessage)]`.
+pub trait MessageFingerprint {
+    /// Canonical form of the type declaration, e.g. `Pose{x:f64,y:f64}`
+    const SCHEMA: &'static str;
+
+    /// Hash of [`SCHEMA`](Self::SCHEMA), see [`schema_fingerprint`]
+    const FINGERPRINT: u64;
+}
+
+/// 64-bit FNV-1a hash of a schema string, usable in constants
+pub const fn schema_fingerprint(schema: &str) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let bytes = schema.as_bytes();
+    let mut hash = OFFSET_BASIS;
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(PRIME);
+        i += 1;
+    }
+    hash
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_fingerprint_is_fnv1a() {
+        assert_eq!(schema_fingerprint(""), 0xcbf29ce484222325);
+        assert_eq!(schema_fingerprint("a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(
+            schema_fingerprint("Pose{x:f64,y:f64}"),
+            schema_fingerprint("Pose{x:f32,y:f32}")
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use crate::core::trace::TraceId;
 use crate::memory::platform::shm_logs_path;
 use log::error;
 use memmap2::MmapMut;
@@ -17,6 +18,9 @@ pub struct LogEntry {
     pub message: String,
     pub tick_us: u64,
     pub ipc_ns: u64,
+    /// Trace the node was working on (pub/sub entries)
+    #[serde(default)]
+    pub trace_id: Option<TraceId>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -201,6 +205,14 @@ impl SharedLogBuffer {
             .collect()
     }
 
+    /// Get logs recorded while working on a trace, oldest first
+    pub fn get_for_trace(&self, trace_id: TraceId) -> Vec<LogEntry> {
+        self.get_all()
+            .into_iter()
+            .filter(|e| e.trace_id == Some(trace_id))
+            .collect()
+    }
+
     pub fn clear(&self) {
         let mut mmap = self.mmap.lock().unwrap();
         mmap[0..8].fill(0); // Reset write index
@@ -6,6 +6,11 @@
 //! - **NodeContext**: Runtime context and utilities provided to nodes during execution
 //! - **Contracts**: Message schemas and validation for type-safe communication
 //! - **Data Fields**: Structured data types for robotics sensors and actuators
+//! - **Optional**: Optional message fields with a fixed memory layout
+//! - **BoundedVec**: Variable-length sequences with a fixed memory layout
+//! - **TraceId**: Causality IDs followed through derived messages
+//! - **MessageFingerprint**: Schema hashes for checking message layouts agree
+//! - **Clock**: Framework time, following a simulator's clock with `use_sim_time`
 //!
 //! ## Node Lifecycle
 //!
@@ -15,17 +20,27 @@
 //! 3. **Execution** - `tick()` is called repeatedly by the scheduler
 //! 4. **Shutdown** - `shutdown()` is called to clean up resources
 
+pub mod bounded_vec;
+pub mod clock;
+pub mod fingerprint;
 pub mod log_buffer;
 pub mod node;
 pub mod node_info_ext;
+pub mod optional;
 pub mod rt_node;
+pub mod trace;
 
+pub use bounded_vec::BoundedVec;
+pub use clock::{Clock, ClockPublisher, ClockSubscriber, CLOCK_TOPIC};
+pub use fingerprint::{schema_fingerprint, MessageFingerprint};
 pub use log_buffer::{LogEntry, LogType, SharedLogBuffer, GLOBAL_LOG_BUFFER};
 pub use node::{
     HealthStatus, LogSummary, NetworkStatus, Node, NodeConfig, NodeHeartbeat, NodeInfo,
     NodeMetrics, NodeState, TopicMetadata,
 };
 pub use node_info_ext::NodeInfoExt;
+pub use optional::Optional;
 pub use rt_node::{
     DeadlineMissPolicy, RTClass, RTNode, RTNodeWrapper, RTPriority, RTStats, WCETViolation,
 };
+pub use trace::{TraceId, Traced};
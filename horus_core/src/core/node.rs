@@ -1,7 +1,9 @@
+use crate::communication::MessageCallback;
+use crate::core::trace::{TraceId, Traced};
 use crate::memory::platform::shm_heartbeats_dir;
 use crate::params::RuntimeParams;
 use crate::terminal::is_raw_mode;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
@@ -113,6 +115,11 @@ pub struct NodeHeartbeat {
     pub error_count: u32,
     pub last_tick_timestamp: u64,
     pub heartbeat_timestamp: u64,
+    pub deadline_misses: u64,
+    pub jitter_p95_us: f64,
+    pub jitter_p99_us: f64,
+    pub tick_p95_us: f64,
+    pub worst_tick_us: f64,
 }
 
 impl NodeHeartbeat {
@@ -123,12 +130,16 @@ impl NodeHeartbeat {
             .unwrap()
             .as_secs();
 
-        // Determine health from metrics
-        let health = if metrics.errors_count > 10 {
+        // Determine health from the recent tick window so a node recovers
+        // once its problems stop
+        let health = if metrics.recent_errors > 10 {
             HealthStatus::Critical
-        } else if metrics.errors_count > 3 {
+        } else if metrics.recent_errors > 3 {
             HealthStatus::Error
-        } else if metrics.failed_ticks > 0 || metrics.avg_tick_duration_ms > 100.0 {
+        } else if metrics.recent_failed_ticks > 0
+            || metrics.recent_deadline_misses > 0
+            || metrics.tick_p95_us > 100_000.0
+        {
             HealthStatus::Warning
         } else {
             HealthStatus::Healthy
@@ -147,6 +158,11 @@ impl NodeHeartbeat {
             error_count: metrics.errors_count as u32,
             last_tick_timestamp: now,
             heartbeat_timestamp: now,
+            deadline_misses: metrics.deadline_misses,
+            jitter_p95_us: metrics.jitter_p95_us,
+            jitter_p99_us: metrics.jitter_p99_us,
+            tick_p95_us: metrics.tick_p95_us,
+            worst_tick_us: metrics.worst_tick_duration_us,
         }
     }
 
@@ -166,6 +182,11 @@ impl NodeHeartbeat {
             "error_count": self.error_count,
             "last_tick_timestamp": self.last_tick_timestamp,
             "heartbeat_timestamp": self.heartbeat_timestamp,
+            "deadline_misses": self.deadline_misses,
+            "jitter_p95_us": self.jitter_p95_us,
+            "jitter_p99_us": self.jitter_p99_us,
+            "tick_p95_us": self.tick_p95_us,
+            "worst_tick_us": self.worst_tick_us,
         });
 
         std::fs::write(&path, json.to_string())?;
@@ -211,6 +232,12 @@ impl NodeHeartbeat {
             error_count: json["error_count"].as_u64()? as u32,
             last_tick_timestamp: json["last_tick_timestamp"].as_u64()?,
             heartbeat_timestamp: json["heartbeat_timestamp"].as_u64()?,
+            // Timing statistics are optional (older heartbeats don't have them)
+            deadline_misses: json["deadline_misses"].as_u64().unwrap_or(0),
+            jitter_p95_us: json["jitter_p95_us"].as_f64().unwrap_or(0.0),
+            jitter_p99_us: json["jitter_p99_us"].as_f64().unwrap_or(0.0),
+            tick_p95_us: json["tick_p95_us"].as_f64().unwrap_or(0.0),
+            worst_tick_us: json["worst_tick_us"].as_f64().unwrap_or(0.0),
         })
     }
 
@@ -347,6 +374,22 @@ pub struct NodeMetrics {
     pub errors_count: u64,
     pub warnings_count: u64,
     pub uptime_seconds: f64,
+    /// Ticks that overran their deadline (explicit RT deadline or rate period)
+    pub deadline_misses: u64,
+    /// Deadline misses among the last `TIMING_WINDOW_SIZE` ticks
+    pub recent_deadline_misses: u64,
+    /// Failed ticks among the last `TIMING_WINDOW_SIZE` ticks
+    pub recent_failed_ticks: u64,
+    /// Errors logged during the last `TIMING_WINDOW_SIZE` ticks
+    pub recent_errors: u64,
+    /// 95th percentile tick start jitter over the sliding window (microseconds)
+    pub jitter_p95_us: f64,
+    /// 99th percentile tick start jitter over the sliding window (microseconds)
+    pub jitter_p99_us: f64,
+    /// 95th percentile tick duration over the sliding window (microseconds)
+    pub tick_p95_us: f64,
+    /// Worst-case tick duration over the sliding window (microseconds)
+    pub worst_tick_duration_us: f64,
 }
 
 impl NodeMetrics {
@@ -356,9 +399,163 @@ impl NodeMetrics {
         self.max_tick_duration_ms = 0.0;
         self.min_tick_duration_ms = 0.0;
         self.last_tick_duration_ms = 0.0;
+        self.jitter_p95_us = 0.0;
+        self.jitter_p99_us = 0.0;
+        self.tick_p95_us = 0.0;
+        self.worst_tick_duration_us = 0.0;
+        self.recent_deadline_misses = 0;
+        self.recent_failed_ticks = 0;
+        self.recent_errors = 0;
     }
 }
 
+/// Number of ticks kept for jitter and worst-case statistics
+pub const TIMING_WINDOW_SIZE: usize = 256;
+
+/// Ticks between percentile refreshes, keeping the sort out of every tick
+const TIMING_REFRESH_TICKS: u32 = 32;
+
+/// What went wrong during one tick
+#[derive(Debug, Clone, Copy, Default)]
+struct TickOutcome {
+    deadline_missed: bool,
+    failed: bool,
+    errors: u64,
+}
+
+/// Sliding window of recent tick timings
+///
+/// Jitter is the deviation of each tick-start interval from the mean interval
+/// in the window, so it is meaningful for both fixed-rate and free-running nodes.
+/// Per-tick outcomes are kept alongside so health reflects recent ticks only.
+#[derive(Debug, Clone, Default)]
+struct TimingWindow {
+    intervals_us: VecDeque<f64>,
+    durations_us: VecDeque<f64>,
+    outcomes: VecDeque<TickOutcome>,
+    last_start: Option<Instant>,
+    /// Sort buffer reused across percentile refreshes
+    scratch: Vec<f64>,
+    /// Ticks recorded since the percentiles were last refreshed
+    stale_ticks: u32,
+}
+
+impl TimingWindow {
+    fn record_start(&mut self, now: Instant) {
+        if let Some(last) = self.last_start {
+            push_bounded(
+                &mut self.intervals_us,
+                now.duration_since(last).as_secs_f64() * 1_000_000.0,
+            );
+        }
+        self.last_start = Some(now);
+    }
+
+    fn record_duration(&mut self, duration: Duration) {
+        push_bounded(&mut self.durations_us, duration.as_secs_f64() * 1_000_000.0);
+    }
+
+    /// Open the outcome of a new tick, retiring the oldest one from the counts
+    fn begin_outcome(&mut self, metrics: &mut NodeMetrics) {
+        if self.outcomes.len() == TIMING_WINDOW_SIZE {
+            if let Some(oldest) = self.outcomes.pop_front() {
+                metrics.recent_deadline_misses -= u64::from(oldest.deadline_missed);
+                metrics.recent_failed_ticks -= u64::from(oldest.failed);
+                metrics.recent_errors -= oldest.errors;
+            }
+        }
+        self.outcomes.push_back(TickOutcome::default());
+    }
+
+    /// Outcome of the current tick (anything before the first tick gets its own)
+    fn current_outcome(&mut self) -> &mut TickOutcome {
+        if self.outcomes.is_empty() {
+            self.outcomes.push_back(TickOutcome::default());
+        }
+        let last = self.outcomes.len() - 1;
+        &mut self.outcomes[last]
+    }
+
+    fn clear(&mut self) {
+        self.intervals_us.clear();
+        self.durations_us.clear();
+        self.outcomes.clear();
+        self.last_start = None;
+        self.stale_ticks = 0;
+    }
+
+    /// Update metrics after a tick
+    ///
+    /// A new worst-case duration shows up immediately; percentiles are
+    /// recomputed every `TIMING_REFRESH_TICKS` ticks.
+    fn apply_to(&mut self, metrics: &mut NodeMetrics) {
+        if let Some(&latest) = self.durations_us.back() {
+            metrics.worst_tick_duration_us = metrics.worst_tick_duration_us.max(latest);
+        }
+        self.stale_ticks += 1;
+        if self.stale_ticks >= TIMING_REFRESH_TICKS {
+            self.refresh(metrics);
+        }
+    }
+
+    /// Recompute all window statistics into metrics
+    fn refresh(&mut self, metrics: &mut NodeMetrics) {
+        self.stale_ticks = 0;
+        let (p95, p99, worst) = self.stats();
+        metrics.jitter_p95_us = p95;
+        metrics.jitter_p99_us = p99;
+        metrics.worst_tick_duration_us = worst;
+        metrics.tick_p95_us = self.duration_p95();
+    }
+
+    /// 95th percentile tick duration in microseconds
+    fn duration_p95(&mut self) -> f64 {
+        self.scratch.clear();
+        self.scratch.extend(self.durations_us.iter().copied());
+        self.scratch.sort_unstable_by(f64::total_cmp);
+        percentile(&self.scratch, 0.95)
+    }
+
+    /// (jitter p95, jitter p99, worst duration) in microseconds
+    fn stats(&mut self) -> (f64, f64, f64) {
+        let worst = self.durations_us.iter().copied().fold(0.0, f64::max);
+        if self.intervals_us.is_empty() {
+            return (0.0, 0.0, worst);
+        }
+
+        let mean = self.intervals_us.iter().sum::<f64>() / self.intervals_us.len() as f64;
+        self.scratch.clear();
+        self.scratch.extend(
+            self.intervals_us
+                .iter()
+                .map(|interval| (interval - mean).abs()),
+        );
+        self.scratch.sort_unstable_by(f64::total_cmp);
+
+        (
+            percentile(&self.scratch, 0.95),
+            percentile(&self.scratch, 0.99),
+            worst,
+        )
+    }
+}
+
+fn push_bounded(window: &mut VecDeque<f64>, value: f64) {
+    if window.len() == TIMING_WINDOW_SIZE {
+        window.pop_front();
+    }
+    window.push_back(value);
+}
+
+/// Nearest-rank percentile of an ascending slice
+fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = (p * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
 /// Configuration parameters for node behavior
 #[derive(Debug, Clone)]
 pub struct NodeConfig {
@@ -408,6 +605,7 @@ pub struct NodeInfo {
     creation_time: Instant,
     last_tick_time: Option<Instant>,
     tick_start_time: Option<Instant>,
+    timing_window: TimingWindow,
 
     // Lifecycle management
     restart_count: u32,
@@ -421,15 +619,22 @@ pub struct NodeInfo {
     // Runtime-discovered pub/sub (for monitor)
     registered_publishers: HashMap<String, String>, // topic_name -> type_name
     registered_subscribers: HashMap<String, String>, // topic_name -> type_name
+    topic_generation: u64,                          // bumped whenever a new topic is registered
 
     // Debugging
     custom_data: HashMap<String, String>,
+    current_trace: TraceId, // Trace of the message being processed this tick
 
     // Thread safety for metrics updates
     metrics_lock: Arc<Mutex<()>>,
 
     // Runtime parameters
     pub params: RuntimeParams,
+
+    // Message callbacks registered via Hub::on_message (dispatched by the scheduler).
+    // Callbacks only need to be Send; the Mutex keeps NodeInfo Sync and is
+    // reached through get_mut, so dispatch never locks.
+    message_callbacks: Mutex<Vec<Box<dyn MessageCallback>>>,
 }
 
 impl NodeInfo {
@@ -463,6 +668,7 @@ impl NodeInfo {
             creation_time: now,
             last_tick_time: None,
             tick_start_time: None,
+            timing_window: TimingWindow::default(),
             restart_count: 0,
             error_history: Vec::new(),
             warning_history: Vec::new(),
@@ -470,9 +676,12 @@ impl NodeInfo {
             subscribed_topics: HashMap::new(),
             registered_publishers: HashMap::new(),
             registered_subscribers: HashMap::new(),
+            topic_generation: 0,
             custom_data: HashMap::new(),
+            current_trace: TraceId::NONE,
             metrics_lock: Arc::new(Mutex::new(())),
             params: RuntimeParams::default(),
+            message_callbacks: Mutex::new(Vec::new()),
         }
     }
 
@@ -537,6 +746,9 @@ impl NodeInfo {
         self.state_change_time = Instant::now();
         self.last_tick_time = None;
         self.tick_start_time = None;
+        self.timing_window.clear();
+        // init() registers its Hub::on_message callbacks again
+        self.callbacks_mut().clear();
         // Keep metrics history but reset tick timing
         self.metrics.reset_timing();
     }
@@ -557,7 +769,11 @@ impl NodeInfo {
 
     // Tick Management
     pub fn start_tick(&mut self) {
-        self.tick_start_time = Some(Instant::now());
+        let now = Instant::now();
+        self.timing_window.record_start(now);
+        self.timing_window.begin_outcome(&mut self.metrics);
+        self.tick_start_time = Some(now);
+        self.current_trace = TraceId::NONE;
         if self.state == NodeState::Uninitialized {
             let _ = self.initialize();
         }
@@ -610,6 +826,10 @@ impl NodeInfo {
             self.metrics.avg_tick_duration_ms =
                 (total_duration + duration_ms) / self.metrics.successful_ticks as f64;
 
+            // Update sliding-window jitter and worst-case duration
+            self.timing_window.record_duration(duration);
+            self.timing_window.apply_to(&mut self.metrics);
+
             self.last_tick_time = Some(Instant::now());
             self.tick_start_time = None;
 
@@ -630,6 +850,8 @@ impl NodeInfo {
     /// Record node shutdown and write final heartbeat
     pub fn record_shutdown(&mut self) {
         self.transition_to_stopped();
+        // The final heartbeat carries up-to-date percentiles
+        self.timing_window.refresh(&mut self.metrics);
         self.write_heartbeat();
     }
 
@@ -644,11 +866,19 @@ impl NodeInfo {
                 self.metrics.total_ticks += 1;
             }
             self.metrics.failed_ticks += 1;
+            let outcome = self.timing_window.current_outcome();
+            if !outcome.failed {
+                outcome.failed = true;
+                self.metrics.recent_failed_ticks += 1;
+            }
 
             if let Some(start_time) = self.tick_start_time {
                 let duration = start_time.elapsed();
                 self.metrics.last_tick_duration_ms = duration.as_millis() as f64;
                 self.tick_start_time = None;
+
+                self.timing_window.record_duration(duration);
+                self.timing_window.apply_to(&mut self.metrics);
             }
         }
 
@@ -658,6 +888,26 @@ impl NodeInfo {
         self.write_heartbeat();
     }
 
+    /// Record that the current tick overran its deadline
+    ///
+    /// Called by the scheduler for RT nodes with an explicit deadline and for
+    /// rate-limited nodes whose tick took longer than their period.
+    pub fn record_deadline_miss(&mut self) {
+        {
+            let _guard = self
+                .metrics_lock
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            self.metrics.deadline_misses += 1;
+            let outcome = self.timing_window.current_outcome();
+            if !outcome.deadline_missed {
+                outcome.deadline_missed = true;
+                self.metrics.recent_deadline_misses += 1;
+            }
+        }
+        self.write_heartbeat();
+    }
+
     /// Get elapsed time since tick started in microseconds
     pub fn tick_elapsed_us(&self) -> u64 {
         if let Some(start_time) = self.tick_start_time {
@@ -672,16 +922,65 @@ impl NodeInfo {
 
     /// Register this node as a publisher to a topic (called automatically by Hub::send)
     pub fn register_publisher(&mut self, topic_name: &str, type_name: &str) {
-        self.registered_publishers
-            .entry(topic_name.to_string())
-            .or_insert_with(|| type_name.to_string());
+        if !self.registered_publishers.contains_key(topic_name) {
+            self.registered_publishers
+                .insert(topic_name.to_string(), type_name.to_string());
+            self.topic_generation += 1;
+        }
     }
 
     /// Register this node as a subscriber to a topic (called automatically by Hub::recv)
     pub fn register_subscriber(&mut self, topic_name: &str, type_name: &str) {
-        self.registered_subscribers
-            .entry(topic_name.to_string())
-            .or_insert_with(|| type_name.to_string());
+        if !self.registered_subscribers.contains_key(topic_name) {
+            self.registered_subscribers
+                .insert(topic_name.to_string(), type_name.to_string());
+            self.topic_generation += 1;
+        }
+    }
+
+    /// Counter that changes whenever this node registers a new topic
+    pub fn topic_generation(&self) -> u64 {
+        self.topic_generation
+    }
+
+    /// Attach a message callback to this node (called by Hub::on_message)
+    pub fn add_message_callback(&mut self, callback: Box<dyn MessageCallback>) {
+        self.callbacks_mut().push(callback);
+    }
+
+    /// Number of message callbacks registered for this node
+    pub fn message_callback_count(&self) -> usize {
+        self.message_callbacks
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .len()
+    }
+
+    fn callbacks_mut(&mut self) -> &mut Vec<Box<dyn MessageCallback>> {
+        self.message_callbacks
+            .get_mut()
+            .unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Run registered callbacks, delivering at most `max_per_callback` messages each
+    ///
+    /// Returns the total number of messages delivered.
+    pub fn dispatch_message_callbacks(&mut self, max_per_callback: usize) -> usize {
+        if self.callbacks_mut().is_empty() {
+            return 0;
+        }
+
+        // Take the callbacks out so each one can borrow this context mutably
+        let mut callbacks = std::mem::take(self.callbacks_mut());
+        let delivered: usize = callbacks
+            .iter_mut()
+            .map(|cb| cb.dispatch(self, max_per_callback))
+            .sum();
+
+        // Keep any callbacks registered from inside a callback
+        callbacks.append(self.callbacks_mut());
+        *self.callbacks_mut() = callbacks;
+        delivered
     }
 
     /// Get all registered publishers (topic_name -> type_name)
@@ -755,6 +1054,7 @@ impl NodeInfo {
             message: summary.to_string(),
             tick_us: current_tick_us,
             ipc_ns,
+            trace_id: self.current_trace.get(),
         });
 
         *self.published_topics.entry(topic.to_string()).or_insert(0) += 1;
@@ -800,6 +1100,7 @@ impl NodeInfo {
             message: summary.to_string(),
             tick_us: current_tick_us,
             ipc_ns,
+            trace_id: self.current_trace.get(),
         });
 
         *self.subscribed_topics.entry(topic.to_string()).or_insert(0) += 1;
@@ -838,6 +1139,7 @@ impl NodeInfo {
             message: message.to_string(),
             tick_us: current_tick_us,
             ipc_ns: 0,
+            trace_id: None,
         });
     }
 
@@ -872,6 +1174,7 @@ impl NodeInfo {
             message: message.to_string(),
             tick_us: current_tick_us,
             ipc_ns: 0,
+            trace_id: None,
         });
 
         self.warning_history
@@ -913,6 +1216,7 @@ impl NodeInfo {
             message: message.to_string(),
             tick_us: current_tick_us,
             ipc_ns: 0,
+            trace_id: None,
         });
 
         self.error_history
@@ -921,6 +1225,8 @@ impl NodeInfo {
             self.error_history.remove(0);
         }
         self.metrics.errors_count += 1;
+        self.timing_window.current_outcome().errors += 1;
+        self.metrics.recent_errors += 1;
     }
 
     pub fn log_debug(&mut self, message: &str) {
@@ -954,6 +1260,7 @@ impl NodeInfo {
             message: message.to_string(),
             tick_us: current_tick_us,
             ipc_ns: 0,
+            trace_id: None,
         });
     }
 
@@ -1014,6 +1321,13 @@ impl NodeInfo {
     pub fn time_in_current_state(&self) -> Duration {
         self.state_change_time.elapsed()
     }
+    /// Current time for timestamping data (simulated time with `use_sim_time`)
+    pub fn now(&self) -> Duration {
+        crate::core::clock::now()
+    }
+    pub fn now_ns(&self) -> u64 {
+        crate::core::clock::now_ns()
+    }
 
     // Setters
     pub fn set_priority(&mut self, priority: u32) {
@@ -1041,6 +1355,41 @@ impl NodeInfo {
     pub fn remove_custom_data(&mut self, key: &str) -> Option<String> {
         self.custom_data.remove(key)
     }
+
+    // Causality Tracing
+    // The current trace is reset at the start of every tick
+
+    /// Trace the node is currently working on (`TraceId::NONE` if none)
+    pub fn current_trace(&self) -> TraceId {
+        self.current_trace
+    }
+
+    /// Start a new trace for data entering the system (e.g. in a sensor driver)
+    pub fn begin_trace(&mut self) -> TraceId {
+        self.current_trace = TraceId::generate();
+        self.current_trace
+    }
+
+    /// Continue the trace of a received message
+    pub fn trace_from<M: Traced>(&mut self, msg: &M) -> TraceId {
+        self.current_trace = msg.trace_id();
+        self.current_trace
+    }
+
+    /// Set the current trace explicitly
+    pub fn set_trace(&mut self, trace_id: TraceId) {
+        self.current_trace = trace_id;
+    }
+
+    /// Stamp an outgoing message with the current trace, starting a new
+    /// trace if there is none yet
+    pub fn stamp<M: Traced>(&mut self, msg: &mut M) -> TraceId {
+        if self.current_trace.is_none() {
+            self.begin_trace();
+        }
+        msg.set_trace_id(self.current_trace);
+        self.current_trace
+    }
 }
 
 /// Topic metadata for monitoring and introspection
@@ -1412,6 +1761,7 @@ mod tests {
             errors_count: 2,
             warnings_count: 10,
             uptime_seconds: 3600.0,
+            ..NodeMetrics::default()
         };
         let cloned = metrics.clone();
         assert_eq!(cloned.total_ticks, 100);
@@ -1464,6 +1814,7 @@ mod tests {
             errors_count: 0,
             warnings_count: 0,
             uptime_seconds: 100.0,
+            ..NodeMetrics::default()
         };
         let heartbeat = NodeHeartbeat::from_metrics(NodeState::Running, &metrics);
         assert_eq!(heartbeat.health, HealthStatus::Healthy);
@@ -1475,7 +1826,8 @@ mod tests {
         let metrics = NodeMetrics {
             total_ticks: 100,
             successful_ticks: 95,
-            failed_ticks: 5, // > 0 failed ticks triggers warning
+            failed_ticks: 5,
+            recent_failed_ticks: 5, // > 0 recent failed ticks triggers warning
             avg_tick_duration_ms: 10.0,
             ..NodeMetrics::default()
         };
@@ -1487,7 +1839,8 @@ mod tests {
     fn test_node_heartbeat_from_metrics_error() {
         let metrics = NodeMetrics {
             total_ticks: 100,
-            errors_count: 5, // > 3 triggers error
+            errors_count: 5,
+            recent_errors: 5, // > 3 recent errors triggers error
             ..NodeMetrics::default()
         };
         let heartbeat = NodeHeartbeat::from_metrics(NodeState::Running, &metrics);
@@ -1498,13 +1851,33 @@ mod tests {
     fn test_node_heartbeat_from_metrics_critical() {
         let metrics = NodeMetrics {
             total_ticks: 100,
-            errors_count: 15, // > 10 triggers critical
+            errors_count: 15,
+            recent_errors: 15, // > 10 recent errors triggers critical
             ..NodeMetrics::default()
         };
         let heartbeat = NodeHeartbeat::from_metrics(NodeState::Running, &metrics);
         assert_eq!(heartbeat.health, HealthStatus::Critical);
     }
 
+    #[test]
+    fn test_node_heartbeat_from_metrics_deadline_miss_warning() {
+        let metrics = NodeMetrics {
+            total_ticks: 100,
+            deadline_misses: 1,
+            recent_deadline_misses: 1,
+            jitter_p99_us: 250.0,
+            tick_p95_us: 800.0,
+            worst_tick_duration_us: 1200.0,
+            ..NodeMetrics::default()
+        };
+        let heartbeat = NodeHeartbeat::from_metrics(NodeState::Running, &metrics);
+        assert_eq!(heartbeat.health, HealthStatus::Warning);
+        assert_eq!(heartbeat.deadline_misses, 1);
+        assert_eq!(heartbeat.jitter_p99_us, 250.0);
+        assert_eq!(heartbeat.tick_p95_us, 800.0);
+        assert_eq!(heartbeat.worst_tick_us, 1200.0);
+    }
+
     #[test]
     fn test_node_heartbeat_is_fresh() {
         let metrics = NodeMetrics::default();
@@ -1558,6 +1931,13 @@ mod tests {
         assert_eq!(info.state(), &NodeState::Uninitialized);
     }
 
+    #[test]
+    fn test_node_info_is_send_and_sync() {
+        // Held in Sync containers such as Bevy resources
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<NodeInfo>();
+    }
+
     #[test]
     fn test_node_info_node_id_format() {
         let info = NodeInfo::new("test_node".to_string(), true);
@@ -1605,6 +1985,154 @@ mod tests {
         assert_eq!(metrics.failed_ticks, 0);
     }
 
+    #[test]
+    fn test_node_info_deadline_misses() {
+        let mut info = NodeInfo::new("test_deadline_node".to_string(), false);
+        info.record_deadline_miss();
+        info.record_deadline_miss();
+        assert_eq!(info.metrics().deadline_misses, 2);
+    }
+
+    #[test]
+    fn test_node_info_deadline_miss_ages_out_of_health() {
+        let mut info = NodeInfo::new("test_deadline_window_node".to_string(), false);
+        info.start_tick();
+        info.record_tick();
+        info.record_deadline_miss();
+        info.record_deadline_miss();
+        assert_eq!(info.metrics().recent_deadline_misses, 1);
+        let heartbeat = NodeHeartbeat::from_metrics(NodeState::Running, info.metrics());
+        assert_eq!(heartbeat.health, HealthStatus::Warning);
+
+        for _ in 0..TIMING_WINDOW_SIZE {
+            info.start_tick();
+            info.record_tick();
+        }
+        assert_eq!(info.metrics().deadline_misses, 2);
+        assert_eq!(info.metrics().recent_deadline_misses, 0);
+        let heartbeat = NodeHeartbeat::from_metrics(NodeState::Running, info.metrics());
+        assert_eq!(heartbeat.health, HealthStatus::Healthy);
+    }
+
+    #[test]
+    fn test_node_info_errors_age_out_of_health() {
+        let mut info = NodeInfo::new("test_error_window_node".to_string(), false);
+        for _ in 0..5 {
+            info.start_tick();
+            info.record_tick_failure("sensor timeout".to_string());
+        }
+        assert_eq!(info.metrics().recent_failed_ticks, 5);
+        assert_eq!(info.metrics().recent_errors, 5);
+        let heartbeat = NodeHeartbeat::from_metrics(NodeState::Running, info.metrics());
+        assert_eq!(heartbeat.health, HealthStatus::Error);
+
+        for _ in 0..TIMING_WINDOW_SIZE {
+            info.start_tick();
+            info.record_tick();
+        }
+        assert_eq!(info.metrics().errors_count, 5);
+        assert_eq!(info.metrics().recent_errors, 0);
+        assert_eq!(info.metrics().recent_failed_ticks, 0);
+        let heartbeat = NodeHeartbeat::from_metrics(NodeState::Running, info.metrics());
+        assert_eq!(heartbeat.health, HealthStatus::Healthy);
+    }
+
+    #[test]
+    fn test_node_info_worst_tick_duration() {
+        let mut info = NodeInfo::new("test_worst_tick_node".to_string(), false);
+        for _ in 0..3 {
+            info.start_tick();
+            info.record_tick();
+        }
+        info.start_tick();
+        std::thread::sleep(Duration::from_millis(2));
+        info.record_tick();
+
+        let metrics = info.metrics();
+        assert!(metrics.worst_tick_duration_us >= 2000.0);
+        assert!(metrics.jitter_p99_us >= metrics.jitter_p95_us);
+    }
+
+    #[test]
+    fn test_node_info_trace_propagation() {
+        struct Scan(TraceId);
+        impl Traced for Scan {
+            fn trace_id(&self) -> TraceId {
+                self.0
+            }
+            fn set_trace_id(&mut self, trace_id: TraceId) {
+                self.0 = trace_id;
+            }
+        }
+
+        let mut info = NodeInfo::new("test_trace_node".to_string(), false);
+        info.start_tick();
+        let mut scan = Scan(TraceId::NONE);
+        let origin = info.stamp(&mut scan);
+        assert!(origin.is_some());
+        assert_eq!(scan.0, origin);
+
+        // Next tick in a downstream node: derived messages keep the origin
+        info.start_tick();
+        assert!(info.current_trace().is_none());
+        info.trace_from(&scan);
+        let mut stop = Scan(TraceId::NONE);
+        assert_eq!(info.stamp(&mut stop), origin);
+        assert_eq!(stop.0, origin);
+    }
+
+    #[test]
+    fn test_timing_window_stats() {
+        let mut window = TimingWindow::default();
+        // Perfectly periodic intervals have no jitter
+        window.intervals_us.extend([1000.0; 10]);
+        window.durations_us.extend([100.0, 400.0, 200.0]);
+        assert_eq!(window.stats(), (0.0, 0.0, 400.0));
+        assert_eq!(window.duration_p95(), 400.0);
+
+        // One late tick dominates the tail percentiles
+        window.intervals_us.clear();
+        window.intervals_us.extend([1000.0; 99]);
+        window.intervals_us.push_back(2000.0);
+        let (p95, p99, _) = window.stats();
+        assert!(p95 < 20.0);
+        assert!(p99 < 20.0);
+        window.intervals_us.push_back(2000.0);
+        let (_, p99, _) = window.stats();
+        assert!(p99 > 900.0);
+    }
+
+    #[test]
+    fn test_timing_window_is_bounded() {
+        let mut window = TimingWindow::default();
+        window.record_duration(Duration::from_millis(50));
+        for _ in 0..TIMING_WINDOW_SIZE {
+            window.record_duration(Duration::from_micros(10));
+        }
+        // The slow tick has slid out of the window
+        assert_eq!(window.durations_us.len(), TIMING_WINDOW_SIZE);
+        assert!(window.stats().2 < 100.0);
+        assert_eq!(window.duration_p95(), 10.0);
+    }
+
+    #[test]
+    fn test_timing_window_refreshes_percentiles_periodically() {
+        let mut window = TimingWindow::default();
+        let mut metrics = NodeMetrics::default();
+        for _ in 0..TIMING_REFRESH_TICKS - 1 {
+            window.record_duration(Duration::from_micros(100));
+            window.apply_to(&mut metrics);
+        }
+        // Worst case is current, percentiles wait for the refresh
+        assert_eq!(metrics.worst_tick_duration_us, 100.0);
+        assert_eq!(metrics.tick_p95_us, 0.0);
+
+        window.record_duration(Duration::from_micros(100));
+        window.apply_to(&mut metrics);
+        assert_eq!(metrics.tick_p95_us, 100.0);
+        assert_eq!(window.stale_ticks, 0);
+    }
+
     #[test]
     fn test_node_info_error_logging() {
         let mut info = NodeInfo::new("test_node".to_string(), true);
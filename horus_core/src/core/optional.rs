@@ -0,0 +1,157 @@
+//! Optional message fields with a fixed memory layout
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An optional message field with an explicit validity flag.
+///
+/// `Option<T>` has no guaranteed memory layout, so it cannot be shared
+/// through the in-place shared memory transport. `Optional<T>` is
+/// `#[repr(C)]`: a `bool` flag followed by the value, which is kept at
+/// `T::default()` while unset.
+///
+/// ```rust,ignore
+/// message! {
+///     GpsStatus {
+///         fix: FixType,
+///         altitude: Optional<f64>,
+///     }
+/// }
+///
+/// let status = GpsStatus { fix: FixType::Fix2d, altitude: Optional::none() };
+/// if let Some(altitude) = status.altitude.get() { /* ... */ }
+/// ```
+///
+/// Serializes as an `Option<T>`.
+#[derive(Debug, Clone, Copy, Default)]
+#[repr(C)]
+pub struct Optional<T> {
+    valid: bool,
+    value: T,
+}
+
+impl<T> Optional<T> {
+    /// A set field
+    pub const fn some(value: T) -> Self {
+        Self { valid: true, value }
+    }
+
+    /// Whether the field is set
+    pub const fn is_some(&self) -> bool {
+        self.valid
+    }
+
+    /// Whether the field is unset
+    pub const fn is_none(&self) -> bool {
+        !self.valid
+    }
+
+    /// Get the value if set
+    pub fn get(&self) -> Option<&T> {
+        self.valid.then_some(&self.value)
+    }
+
+    /// Get the value mutably if set
+    pub fn get_mut(&mut self) -> Option<&mut T> {
+        self.valid.then_some(&mut self.value)
+    }
+
+    /// Set the value
+    pub fn set(&mut self, value: T) {
+        self.value = value;
+        self.valid = true;
+    }
+
+    /// Convert into an `Option`
+    pub fn into_option(self) -> Option<T> {
+        self.valid.then_some(self.value)
+    }
+}
+
+impl<T: Default> Optional<T> {
+    /// An unset field
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Unset the field, returning the previous value
+    pub fn take(&mut self) -> Option<T> {
+        let valid = std::mem::replace(&mut self.valid, false);
+        let value = std::mem::take(&mut self.value);
+        valid.then_some(value)
+    }
+}
+
+impl<T: Copy> Optional<T> {
+    /// Get a copy of the value if set
+    pub fn value(&self) -> Option<T> {
+        self.valid.then_some(self.value)
+    }
+}
+
+impl<T: Default> From<Option<T>> for Optional<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => Self::some(value),
+            None => Self::none(),
+        }
+    }
+}
+
+impl<T> From<Optional<T>> for Option<T> {
+    fn from(value: Optional<T>) -> Self {
+        value.into_option()
+    }
+}
+
+/// Unset fields compare equal regardless of the stored value
+impl<T: PartialEq> PartialEq for Optional<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T: Serialize> Serialize for Optional<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de> + Default> Deserialize<'de> for Optional<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<T>::deserialize(deserializer).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_optional_layout_and_access() {
+        assert_eq!(std::mem::size_of::<Optional<f32>>(), 8);
+        assert_eq!(std::mem::size_of::<Optional<u8>>(), 2);
+
+        let mut field = Optional::<f32>::none();
+        assert!(field.is_none());
+        assert_eq!(field.get(), None);
+
+        field.set(1.5);
+        assert_eq!(field.value(), Some(1.5));
+        assert_eq!(Option::from(field), Some(1.5));
+
+        assert_eq!(field.take(), Some(1.5));
+        assert!(field.is_none());
+        assert_eq!(field, Optional::from(None));
+    }
+
+    #[test]
+    fn test_optional_serializes_as_option() {
+        let set = Optional::some(3u32);
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(json, "3");
+        assert_eq!(serde_json::from_str::<Optional<u32>>(&json).unwrap(), set);
+
+        let unset: Optional<u32> = serde_json::from_str("null").unwrap();
+        assert!(unset.is_none());
+    }
+}
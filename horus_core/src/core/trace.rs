@@ -0,0 +1,147 @@
+//! Causality trace IDs for messages
+//!
+//! A [`TraceId`] is assigned when data enters the system (typically in a
+//! sensor driver) and copied into every message derived from it, so a
+//! message can be followed back to its origin: "which lidar scan caused
+//! this emergency stop".
+//!
+//! ```rust,ignore
+//! message! {
+//!     LidarScan {
+//!         trace_id: TraceId,
+//!         ranges: [f32; 360],
+//!     }
+//! }
+//!
+//! // Sensor driver: the scan starts a new trace
+//! let mut scan = LidarScan { trace_id: TraceId::NONE, ranges };
+//! ctx.stamp(&mut scan);
+//! self.scan_pub.send(scan, &mut ctx);
+//!
+//! // Downstream node: derived messages continue the trace
+//! if let Some(scan) = self.scan_sub.recv(&mut ctx) {
+//!     ctx.trace_from(&scan);
+//!     let mut stop = EmergencyStop::new(true);
+//!     ctx.stamp(&mut stop);
+//!     self.estop_pub.send(stop, &mut ctx);
+//! }
+//! ```
+//!
+//! The trace of the current tick is attached to the node's pub/sub log
+//! entries, so [`SharedLogBuffer::get_for_trace`](crate::core::SharedLogBuffer::get_for_trace)
+//! lists every hop of a trace across nodes. `message!` implements [`Traced`]
+//! for messages with a `trace_id: TraceId` field.
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
+
+/// Causality ID shared by a message and everything derived from it
+///
+/// `#[repr(transparent)]` over `u64`, so it can be a field of shared memory
+/// and zero-copy messages. `TraceId::NONE` (0) means untraced.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[repr(transparent)]
+pub struct TraceId(pub u64);
+
+// SAFETY: transparent wrapper around u64
+unsafe impl bytemuck::Pod for TraceId {}
+unsafe impl bytemuck::Zeroable for TraceId {}
+
+/// Bits of a trace ID taken by the per-process counter
+const COUNTER_BITS: u32 = 40;
+
+impl TraceId {
+    /// No trace
+    pub const NONE: TraceId = TraceId(0);
+
+    /// Allocate a new, process-unique trace ID.
+    ///
+    /// The upper 24 bits identify the process (from its PID and start time),
+    /// the lower 40 bits count up, so IDs from different processes do not
+    /// collide in practice.
+    pub fn generate() -> Self {
+        static PREFIX: OnceLock<u64> = OnceLock::new();
+        static COUNTER: AtomicU64 = AtomicU64::new(1);
+
+        let prefix = *PREFIX.get_or_init(|| {
+            let nanos = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or(0);
+            // splitmix64 finalizer to spread PID and start time over the prefix
+            let mut x = nanos ^ ((std::process::id() as u64) << 32);
+            x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+            (x ^ (x >> 31)) >> COUNTER_BITS << COUNTER_BITS
+        });
+        let count = COUNTER.fetch_add(1, Ordering::Relaxed) & ((1 << COUNTER_BITS) - 1);
+        TraceId(prefix | count.max(1))
+    }
+
+    pub const fn is_none(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn is_some(&self) -> bool {
+        self.0 != 0
+    }
+
+    /// `None` for an untraced ID
+    pub const fn get(self) -> Option<TraceId> {
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self)
+        }
+    }
+}
+
+impl std::fmt::Display for TraceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+impl std::str::FromStr for TraceId {
+    type Err = std::num::ParseIntError;
+
+    /// Parse the hexadecimal form printed by `Display`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        u64::from_str_radix(s.trim_start_matches("0x"), 16).map(TraceId)
+    }
+}
+
+impl crate::core::LogSummary for TraceId {
+    fn log_summary(&self) -> String {
+        self.to_string()
+    }
+}
+
+/// Messages carrying a trace ID
+pub trait Traced {
+    fn trace_id(&self) -> TraceId;
+
+    fn set_trace_id(&mut self, trace_id: TraceId);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_ids_are_unique_and_parse() {
+        let a = TraceId::generate();
+        let b = TraceId::generate();
+        assert!(a.is_some() && b.is_some());
+        assert_ne!(a, b);
+        assert_eq!(a.0 >> COUNTER_BITS, b.0 >> COUNTER_BITS);
+
+        assert_eq!(a.to_string().parse::<TraceId>().unwrap(), a);
+        assert_eq!("0x2a".parse::<TraceId>().unwrap(), TraceId(42));
+        assert_eq!(TraceId::NONE.get(), None);
+        assert_eq!(std::mem::size_of::<TraceId>(), 8);
+    }
+}
@@ -43,10 +43,26 @@
 //! let tensor = pool.from_ipc_handle(handle, &[1080, 1920, 3], TensorDtype::F32)?;
 //! // Now tensor points to the SAME GPU memory as Process A
 //! ```
+//!
+//! # Hub transport
+//!
+//! A `CudaTensor` is published as a `HorusTensor` descriptor carrying the IPC
+//! handle; the subscriber resolves it to a device pointer in its own address
+//! space, so frames never go through host memory:
+//!
+//! ```rust,ignore
+//! // Publisher
+//! hub.send(tensor.to_horus_tensor(), &mut ctx)?;
+//!
+//! // Subscriber (pool opened with CudaTensorPool::open)
+//! if let Some(msg) = hub.recv(&mut ctx) {
+//!     let dev_ptr = pool.resolve(&msg)?;
+//! }
+//! ```
 
 use crate::error::{HorusError, HorusResult};
 use crate::memory::platform::shm_base_dir;
-use crate::memory::tensor_pool::{TensorDevice, TensorDtype, MAX_TENSOR_DIMS};
+use crate::memory::tensor_pool::{HorusTensor, TensorDevice, TensorDtype, MAX_TENSOR_DIMS};
 use memmap2::{MmapMut, MmapOptions};
 use std::collections::HashMap;
 use std::ffi::c_void;
@@ -116,7 +132,9 @@ struct CudaSlotHeader {
     generation: AtomicU32,
     /// Next free slot in free stack (for O(1) allocation)
     next_free: AtomicU32,
-    _padding: [u8; 14], // Align to 256 bytes total
+    /// Process that allocated the slot (device_ptr is only valid there)
+    owner_pid: AtomicU32,
+    _padding: [u8; 10], // Align to 256 bytes total
 }
 
 /// Configuration for CUDA tensor pool
@@ -342,6 +360,65 @@ impl CudaTensor {
     pub fn effective_offset(&self) -> u64 {
         self.offset
     }
+
+    /// Descriptor to publish on a Hub
+    ///
+    /// The device memory is not copied: receivers resolve the descriptor with
+    /// [`CudaTensorPool::resolve`], which opens the carried IPC handle.
+    pub fn to_horus_tensor(&self) -> HorusTensor {
+        let element_size = self.dtype.element_size() as u64;
+        let mut tensor = HorusTensor {
+            pool_id: self.pool_id,
+            slot_id: self.slot_id,
+            generation: self.generation,
+            offset: self.offset,
+            size: self.size,
+            dtype: self.dtype,
+            ndim: self.ndim,
+            device: self.device(),
+            shape: self.shape,
+            cuda_ipc_handle: self.ipc_handle,
+            ..Default::default()
+        };
+        for i in 0..self.ndim as usize {
+            tensor.strides[i] = self.strides[i] * element_size;
+        }
+        tensor
+    }
+
+    /// Rebuild a GPU tensor from a received descriptor
+    ///
+    /// None if the descriptor is for CPU memory or its strides are not whole
+    /// elements.
+    pub fn from_horus_tensor(tensor: &HorusTensor) -> Option<Self> {
+        let device_id = tensor.device.cuda_device_id()?;
+        let element_size = tensor.dtype.element_size() as u64;
+        let ndim = (tensor.ndim as usize).min(MAX_TENSOR_DIMS);
+
+        let mut strides = [0u64; MAX_TENSOR_DIMS];
+        for i in 0..ndim {
+            if tensor.strides[i] % element_size != 0 {
+                return None;
+            }
+            strides[i] = tensor.strides[i] / element_size;
+        }
+
+        Some(Self {
+            pool_id: tensor.pool_id,
+            slot_id: tensor.slot_id,
+            generation: tensor.generation,
+            device_id,
+            size: tensor.size,
+            numel: tensor.shape[..ndim].iter().product(),
+            offset: tensor.offset,
+            dtype: tensor.dtype,
+            ndim: ndim as u8,
+            _pad: [0; 6],
+            shape: tensor.shape,
+            strides,
+            ipc_handle: tensor.cuda_ipc_handle,
+        })
+    }
 }
 
 /// CUDA Tensor Pool for GPU memory with IPC support
@@ -353,10 +430,10 @@ pub struct CudaTensorPool {
     mmap: MmapMut,
     _file: File,
     is_owner: bool,
-    /// Local cache of opened IPC handles (slot_id -> device_ptr)
-    /// Only used by non-owner processes
+    /// Local cache of opened IPC handles (slot_id -> (generation, device_ptr))
+    /// Only used for slots allocated by another process
     #[allow(dead_code)]
-    opened_handles: Arc<Mutex<HashMap<u32, *mut c_void>>>,
+    opened_handles: Arc<Mutex<HashMap<u32, (u32, *mut c_void)>>>,
 }
 
 // Safety: Pool uses atomic operations and IPC handles are process-safe
@@ -541,6 +618,7 @@ impl CudaTensorPool {
         let slot = self.slot_mut(slot_id);
         slot.ipc_handle.copy_from_slice(&ipc_handle.reserved);
         slot.device_ptr.store(dev_ptr as u64, Ordering::Release);
+        slot.owner_pid.store(std::process::id(), Ordering::Release);
         slot.size = size;
         slot.numel = numel;
         slot.ndim = shape.len().min(MAX_TENSOR_DIMS) as u8;
@@ -744,7 +822,8 @@ impl CudaTensorPool {
             return std::ptr::null_mut();
         }
 
-        slot.device_ptr.load(Ordering::Acquire) as *mut c_void
+        self.slot_device_ptr(tensor.slot_id, tensor.generation)
+            .unwrap_or(std::ptr::null_mut())
     }
 
     /// Get device pointer for a tensor (includes offset for views/slices)
@@ -762,6 +841,35 @@ impl CudaTensorPool {
         }
     }
 
+    /// Device pointer of a tensor received as a `HorusTensor` (includes offset)
+    ///
+    /// Slots allocated by another process are opened through their IPC handle
+    /// once and cached until the slot is reused, so a subscriber receiving a
+    /// stream of frames maps each buffer a single time.
+    pub fn resolve(&self, tensor: &HorusTensor) -> HorusResult<*mut c_void> {
+        let cuda_tensor = CudaTensor::from_horus_tensor(tensor).ok_or_else(|| {
+            HorusError::Config(format!(
+                "Tensor on {:?} is not a CUDA tensor",
+                tensor.device
+            ))
+        })?;
+        if cuda_tensor.pool_id != self.pool_id {
+            return Err(HorusError::Config(format!(
+                "Tensor belongs to CUDA pool {}, not {}",
+                cuda_tensor.pool_id, self.pool_id
+            )));
+        }
+
+        let ptr = self.device_ptr(&cuda_tensor);
+        if ptr.is_null() {
+            return Err(HorusError::Memory(format!(
+                "CUDA tensor slot {} (generation {}) is no longer valid",
+                cuda_tensor.slot_id, cuda_tensor.generation
+            )));
+        }
+        Ok(ptr)
+    }
+
     /// Get pool statistics
     pub fn stats(&self) -> CudaPoolStats {
         let header = self.header();
@@ -790,6 +898,37 @@ impl CudaTensorPool {
 
     // === Private helpers ===
 
+    /// Base pointer of an allocated slot, valid in this process
+    #[cfg(feature = "cuda")]
+    fn slot_device_ptr(&self, slot_id: u32, generation: u32) -> HorusResult<*mut c_void> {
+        let slot = self.slot(slot_id);
+        if slot.owner_pid.load(Ordering::Acquire) == std::process::id() {
+            return Ok(slot.device_ptr.load(Ordering::Acquire) as *mut c_void);
+        }
+
+        let mut handles = self.opened_handles.lock().unwrap();
+        if let Some(&(opened_generation, ptr)) = handles.get(&slot_id) {
+            if opened_generation == generation {
+                return Ok(ptr);
+            }
+            // The slot was reallocated since it was opened
+            let _ = cuda_ffi::ipc_close_mem_handle(ptr);
+            handles.remove(&slot_id);
+        }
+
+        let mut handle = cuda_ffi::CudaIpcMemHandle::default();
+        handle.reserved.copy_from_slice(&slot.ipc_handle);
+        let ptr = cuda_ffi::ipc_open_mem_handle(handle)
+            .map_err(|e| HorusError::Memory(format!("Failed to open IPC handle: {}", e)))?;
+        handles.insert(slot_id, (generation, ptr));
+        Ok(ptr)
+    }
+
+    #[cfg(not(feature = "cuda"))]
+    fn slot_device_ptr(&self, slot_id: u32, _generation: u32) -> HorusResult<*mut c_void> {
+        Ok(self.slot(slot_id).device_ptr.load(Ordering::Acquire) as *mut c_void)
+    }
+
     fn header(&self) -> &CudaPoolHeader {
         unsafe { &*(self.mmap.as_ptr() as *const CudaPoolHeader) }
     }
@@ -905,7 +1044,7 @@ impl Drop for CudaTensorPool {
         #[cfg(feature = "cuda")]
         {
             let handles = self.opened_handles.lock().unwrap();
-            for (_, &ptr) in handles.iter() {
+            for &(_, ptr) in handles.values() {
                 let _ = cuda_ffi::ipc_close_mem_handle(ptr);
             }
         }
@@ -1594,6 +1733,31 @@ mod tests {
         assert!(flat.is_contiguous());
     }
 
+    #[test]
+    fn test_tensor_horus_descriptor_roundtrip() {
+        let mut tensor = create_test_tensor(&[4, 6], TensorDtype::F32);
+        tensor.device_id = 1;
+        tensor.ipc_handle[0] = 0xAB;
+        let sliced = tensor.slice_first_dim(1, 3).unwrap();
+
+        let desc = sliced.to_horus_tensor();
+        assert_eq!(desc.device, TensorDevice::Cuda1);
+        // HorusTensor strides are in bytes
+        assert_eq!(desc.strides[0], 6 * 4);
+        assert_eq!(desc.strides[1], 4);
+        assert_eq!(desc.offset, sliced.offset);
+        assert_eq!(desc.cuda_ipc_handle[0], 0xAB);
+
+        let back = CudaTensor::from_horus_tensor(&desc).unwrap();
+        assert_eq!(back.device_id, 1);
+        assert_eq!(back.strides[..2], sliced.strides[..2]);
+        assert_eq!(back.shape[..2], [2, 6]);
+        assert_eq!(back.numel, 12);
+
+        let cpu = HorusTensor::default();
+        assert!(CudaTensor::from_horus_tensor(&cpu).is_none());
+    }
+
     // ==========================================================================
     // P2PManager Tests
     // ==========================================================================
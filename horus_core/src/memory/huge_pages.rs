@@ -0,0 +1,181 @@
+//! Huge page backing for large shared memory segments
+//!
+//! Topics carrying map updates or camera frames touch megabytes per message,
+//! and with 4 KiB pages that costs thousands of TLB entries. Regions created
+//! with huge pages are placed on a hugetlbfs mount (2 MiB pages) instead of
+//! `/dev/shm`:
+//!
+//! ```bash
+//! sudo sysctl vm.nr_hugepages=512
+//! sudo mount -t hugetlbfs none /dev/hugepages   # mounted by systemd on most distros
+//! ```
+//!
+//! The mount is found through `/proc/mounts`, or set with `HORUS_HUGEPAGE_DIR`.
+//! When there is no mount or not enough free pages, the region falls back to
+//! regular shared memory with transparent huge pages requested; callers check
+//! `uses_huge_pages()` (also reported in `LinkMetrics`) to see what they got.
+
+use std::path::PathBuf;
+
+#[cfg(target_os = "linux")]
+use memmap2::{MmapMut, MmapOptions};
+#[cfg(target_os = "linux")]
+use std::fs::{File, OpenOptions};
+#[cfg(target_os = "linux")]
+use std::path::Path;
+
+/// Size of the huge pages requested from hugetlbfs (2 MiB)
+pub const HUGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
+
+/// Environment variable overriding the hugetlbfs directory
+pub const HUGEPAGE_DIR_ENV: &str = "HORUS_HUGEPAGE_DIR";
+
+/// Round `size` up to a whole number of huge pages
+pub fn round_up_to_huge_page(size: usize) -> usize {
+    size.div_ceil(HUGE_PAGE_SIZE).max(1) * HUGE_PAGE_SIZE
+}
+
+/// Directory holding HORUS huge page segments, None if no hugetlbfs is mounted
+///
+/// Partitioned by `HORUS_DOMAIN_ID` like the regular shared memory directory.
+pub fn hugetlbfs_dir() -> Option<PathBuf> {
+    hugetlbfs_root_dir().map(crate::memory::platform::with_domain)
+}
+
+fn hugetlbfs_root_dir() -> Option<PathBuf> {
+    if let Ok(dir) = std::env::var(HUGEPAGE_DIR_ENV) {
+        if !dir.is_empty() {
+            return Some(PathBuf::from(dir));
+        }
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let mounts = std::fs::read_to_string("/proc/mounts").ok()?;
+        find_hugetlbfs_mount(&mounts).map(|mount| mount.join("horus"))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Number of free huge pages reported by the kernel
+pub fn free_huge_pages() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+        meminfo
+            .lines()
+            .find_map(|line| line.strip_prefix("HugePages_Free:"))
+            .and_then(|value| value.trim().parse().ok())
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// First hugetlbfs mount with 2 MiB pages in the contents of `/proc/mounts`
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn find_hugetlbfs_mount(mounts: &str) -> Option<PathBuf> {
+    mounts.lines().find_map(|line| {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() < 4 || fields[2] != "hugetlbfs" {
+            return None;
+        }
+        // Mounts without a pagesize option use the default size (2 MiB on x86_64 and arm64)
+        let page_size = fields[3]
+            .split(',')
+            .find_map(|option| option.strip_prefix("pagesize="));
+        match page_size {
+            None | Some("2M") | Some("2048k") => Some(PathBuf::from(fields[1])),
+            _ => None,
+        }
+    })
+}
+
+/// Map `path` on hugetlbfs, creating it with at least `size` bytes
+///
+/// Returns the file, its mapping and whether this call created the file, or
+/// None when the huge pages can't be obtained. A file created here is removed
+/// again on failure.
+#[cfg(target_os = "linux")]
+pub(crate) fn map_huge_file(path: &Path, size: usize) -> Option<(File, MmapMut, bool)> {
+    let created = !path.exists();
+    if created {
+        std::fs::create_dir_all(path.parent()?).ok()?;
+    }
+
+    let result = (|| {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(created)
+            .open(path)
+            .ok()?;
+        let len = if created {
+            let len = round_up_to_huge_page(size);
+            file.set_len(len as u64).ok()?;
+            len
+        } else {
+            file.metadata().ok()?.len() as usize
+        };
+        if len < size {
+            return None;
+        }
+        // hugetlbfs reserves the pages at mmap time, so a shortage fails here
+        // rather than with SIGBUS on first access
+        let mmap = unsafe { MmapOptions::new().len(len).map_mut(&file).ok()? };
+        Some((file, mmap, created))
+    })();
+
+    if result.is_none() && created {
+        let _ = std::fs::remove_file(path);
+    }
+    result
+}
+
+/// Ask for transparent huge pages on a regular mapping (best effort)
+#[cfg(target_os = "linux")]
+pub(crate) fn advise_transparent_huge_pages(mmap: &MmapMut) {
+    unsafe {
+        libc::madvise(
+            mmap.as_ptr() as *mut libc::c_void,
+            mmap.len(),
+            libc::MADV_HUGEPAGE,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_up_to_huge_page() {
+        assert_eq!(round_up_to_huge_page(0), HUGE_PAGE_SIZE);
+        assert_eq!(round_up_to_huge_page(1), HUGE_PAGE_SIZE);
+        assert_eq!(round_up_to_huge_page(HUGE_PAGE_SIZE), HUGE_PAGE_SIZE);
+        assert_eq!(
+            round_up_to_huge_page(10 * 1024 * 1024 + 1),
+            6 * HUGE_PAGE_SIZE
+        );
+    }
+
+    #[test]
+    fn test_find_hugetlbfs_mount() {
+        let mounts = "\
+tmpfs /dev/shm tmpfs rw,nosuid,nodev 0 0
+hugetlbfs /mnt/huge1g hugetlbfs rw,relatime,pagesize=1024M 0 0
+hugetlbfs /dev/hugepages hugetlbfs rw,relatime,pagesize=2M 0 0
+";
+        assert_eq!(
+            find_hugetlbfs_mount(mounts),
+            Some(PathBuf::from("/dev/hugepages"))
+        );
+        assert_eq!(find_hugetlbfs_mount("tmpfs /dev/shm tmpfs rw 0 0\n"), None);
+    }
+}
@@ -4,6 +4,8 @@
 //!
 //! - **ShmRegion**: Cross-process memory regions using HORUS absolute paths
 //! - **ShmTopic**: Lock-free ring buffers in shared memory for high-performance messaging
+//! - **huge_pages**: Optional 2 MiB huge page backing for large-message segments
+//! - **stale**: Reclamation of regions left behind by crashed processes
 //!
 //! ## Performance Features
 //!
@@ -17,9 +19,11 @@
 //! All memory operations maintain Rust's safety guarantees through careful
 //! use of lifetime management and atomic operations.
 
+pub mod huge_pages;
 pub mod platform;
 pub mod shm_region;
 pub mod shm_topic;
+pub mod stale;
 pub mod tensor_handle;
 pub mod tensor_pool;
 
@@ -29,13 +33,15 @@ pub mod cuda_ffi;
 #[cfg(feature = "cuda")]
 pub mod cuda_pool;
 
+pub use huge_pages::{hugetlbfs_dir, HUGE_PAGE_SIZE};
 pub use platform::*;
 pub use shm_region::ShmRegion;
 pub use shm_topic::ShmTopic;
+pub use stale::{find_stale_segments, reclaim_stale_segments, StaleSegment};
 pub use tensor_handle::TensorHandle;
 pub use tensor_pool::{
-    HorusTensor, TensorDevice, TensorDtype, TensorPool, TensorPoolConfig, TensorPoolStats,
-    MAX_TENSOR_DIMS,
+    DefragmentReport, HorusTensor, TensorDevice, TensorDtype, TensorPool, TensorPoolConfig,
+    TensorPoolStats, MAX_TENSOR_DIMS,
 };
 
 // CUDA exports
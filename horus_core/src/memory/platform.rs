@@ -5,9 +5,73 @@
 // Windows: CreateFileMappingW (pagefile-backed - optimized for IPC) - NOT temp files!
 //
 // Note: macOS and Windows no longer use filesystem paths for shared memory.
-// The path functions below are kept for Linux and backward compatibility only.
+// On Windows each mapping still gets a small marker file (see ShmMarker) so
+// tools that scan the topics directory keep working.
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Environment variable selecting the shared memory domain
+///
+/// Independent HORUS systems on one machine (CI jobs, several developers)
+/// use different domain IDs so they don't see each other's topics, nodes or
+/// parameters. Unset or `0` is the default domain.
+pub const DOMAIN_ID_ENV: &str = "HORUS_DOMAIN_ID";
+
+/// Shared memory domain of this process, None for the default domain
+pub fn domain_id() -> Option<u32> {
+    let value = std::env::var(DOMAIN_ID_ENV).ok()?;
+    match parse_domain_id(&value) {
+        Ok(id) => id,
+        Err(()) => {
+            static WARNED: std::sync::Once = std::sync::Once::new();
+            WARNED.call_once(|| {
+                log::warn!(
+                    "Ignoring invalid {} '{}' (expected a non-negative integer)",
+                    DOMAIN_ID_ENV,
+                    value
+                );
+            });
+            None
+        }
+    }
+}
+
+fn parse_domain_id(value: &str) -> Result<Option<u32>, ()> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    match value.parse::<u32>() {
+        Ok(0) => Ok(None),
+        Ok(id) => Ok(Some(id)),
+        Err(_) => Err(()),
+    }
+}
+
+/// Partition a HORUS directory by domain (`horus` -> `horus_domain_7`)
+///
+/// The default domain keeps the unsuffixed path.
+pub fn with_domain(path: PathBuf) -> PathBuf {
+    domain_path(path, domain_id())
+}
+
+fn domain_path(path: PathBuf, domain: Option<u32>) -> PathBuf {
+    let (Some(id), Some(name)) = (domain, path.file_name()) else {
+        return path;
+    };
+    let name = format!("{}_domain_{}", name.to_string_lossy(), id);
+    path.with_file_name(name)
+}
+
+/// Prefix of kernel shared memory object names (macOS, Windows)
+///
+/// `horus_` in the default domain, `horus_d7_` in domain 7.
+pub fn shm_object_prefix() -> String {
+    match domain_id() {
+        Some(id) => format!("horus_d{}_", id),
+        None => "horus_".to_string(),
+    }
+}
 
 /// Get the base directory for HORUS shared memory
 ///
@@ -15,7 +79,15 @@ use std::path::PathBuf;
 /// - Linux: `/dev/shm/horus` (tmpfs for maximum performance)
 /// - macOS: `/tmp/horus` (no /dev/shm, but /tmp is still fast)
 /// - Windows: `%TEMP%\horus` (system temp directory)
+///
+/// With [`DOMAIN_ID_ENV`] set, each domain has its own base directory
+/// (`/dev/shm/horus_domain_7`).
 pub fn shm_base_dir() -> PathBuf {
+    with_domain(shm_root_dir())
+}
+
+/// Base directory of the default domain
+fn shm_root_dir() -> PathBuf {
     #[cfg(target_os = "linux")]
     {
         PathBuf::from("/dev/shm/horus")
@@ -77,8 +149,44 @@ pub fn shm_control_dir() -> PathBuf {
     shm_base_dir().join("control")
 }
 
+/// Get the directory recording which processes are attached to each region
+///
+/// Structure: `/dev/shm/horus/owners/horus_{topic}` (see [`ShmMarker`])
+pub fn shm_owners_dir() -> PathBuf {
+    shm_base_dir().join("owners")
+}
+
+/// Owner marker of the region `name`
+///
+/// On Windows the marker doubles as the region's entry in the topics
+/// directory; elsewhere it lives under [`shm_owners_dir`] so topic scans
+/// only see region files.
+pub fn owner_marker_path(name: &str) -> PathBuf {
+    #[cfg(target_os = "windows")]
+    {
+        shm_topics_dir().join(format!("horus_{}", name))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        shm_owners_dir().join(format!("horus_{}", name))
+    }
+}
+
+/// Directory holding the Unix sockets of local links
+///
+/// Producers bind `{dir}/{prefix}link_{topic}_{pid}.sock`, consumers
+/// `{dir}/{prefix}link_{topic}_consumer.sock`.
+pub fn link_socket_dir() -> PathBuf {
+    PathBuf::from("/tmp")
+}
+
 /// Get the logs shared memory path
 pub fn shm_logs_path() -> PathBuf {
+    with_domain(shm_logs_root_path())
+}
+
+fn shm_logs_root_path() -> PathBuf {
     // Logs are at the same level as horus dir, not inside it
     #[cfg(target_os = "linux")]
     {
@@ -102,24 +210,28 @@ pub fn shm_logs_path() -> PathBuf {
 }
 
 /// Check if a process with given PID is running
+///
+/// A process owned by another user still exists even though it can't be
+/// signalled, so `EPERM` counts as running.
 pub fn is_process_running(pid: u32) -> bool {
     #[cfg(unix)]
     {
         // On Unix, kill(pid, 0) checks if process exists without sending signal
-        unsafe { libc::kill(pid as i32, 0) == 0 }
+        let signalled = unsafe { libc::kill(pid as i32, 0) == 0 };
+        signalled || std::io::Error::last_os_error().raw_os_error() == Some(libc::EPERM)
     }
 
     #[cfg(windows)]
     {
         // On Windows, try to open the process using windows-sys
-        use windows_sys::Win32::Foundation::CloseHandle;
+        use windows_sys::Win32::Foundation::{CloseHandle, GetLastError, ERROR_ACCESS_DENIED};
         use windows_sys::Win32::System::Threading::{
             OpenProcess, PROCESS_QUERY_LIMITED_INFORMATION,
         };
         unsafe {
             let handle = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, 0, pid);
             if handle == 0 {
-                false
+                GetLastError() == ERROR_ACCESS_DENIED
             } else {
                 CloseHandle(handle);
                 true
@@ -134,6 +246,275 @@ pub fn is_process_running(pid: u32) -> bool {
     }
 }
 
+/// PID namespace of the current process (Linux), None where PIDs are global
+///
+/// Containers sharing `/dev/shm` with the host (`--ipc=host`) usually keep
+/// their own PID namespace, so a PID recorded by one of them means nothing
+/// to a process outside it.
+pub fn pid_namespace() -> Option<u64> {
+    #[cfg(target_os = "linux")]
+    {
+        static NAMESPACE: std::sync::OnceLock<Option<u64>> = std::sync::OnceLock::new();
+        *NAMESPACE.get_or_init(|| {
+            // The link reads `pid:[4026531836]`
+            let link = std::fs::read_link("/proc/self/ns/pid").ok()?;
+            let link = link.to_str()?;
+            link.strip_prefix("pid:[")?.strip_suffix(']')?.parse().ok()
+        })
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        None
+    }
+}
+
+/// Attachment record of a shared memory region
+///
+/// Holds the region size and the processes attached to it, which lets
+/// discovery, `horus clean` and `horus doctor` tell live regions from ones
+/// left behind by crashed processes. Windows file mappings live in the
+/// kernel object namespace, so there the marker sits at the path Linux uses
+/// for the region file; see [`owner_marker_path`].
+///
+/// Format: the size on the first line, then one PID per line, each followed
+/// by the PID namespace it belongs to where known (`4242 4026531836`).
+/// Entries of other PID namespaces can't be checked from here; they are kept
+/// in `foreign` verbatim and count as alive.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ShmMarker {
+    pub size: usize,
+    /// Attached processes of this PID namespace
+    pub pids: Vec<u32>,
+    /// Entries written by processes of other PID namespaces
+    pub foreign: Vec<String>,
+}
+
+impl ShmMarker {
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut lines = text.lines().map(str::trim).filter(|l| !l.is_empty());
+        let size = lines.next()?.parse().ok()?;
+        let own = pid_namespace();
+        let mut marker = Self {
+            size,
+            ..Self::default()
+        };
+        for line in lines {
+            let mut fields = line.split_whitespace();
+            let Some(Ok(pid)) = fields.next().map(str::parse::<u32>) else {
+                continue;
+            };
+            match fields.next().and_then(|ns| ns.parse::<u64>().ok()) {
+                Some(ns) if Some(ns) != own => marker.foreign.push(line.to_string()),
+                _ => marker.pids.push(pid),
+            }
+        }
+        Some(marker)
+    }
+
+    pub fn read(path: &Path) -> Option<Self> {
+        Self::parse(&std::fs::read_to_string(path).ok()?)
+    }
+
+    pub fn write(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_text())
+    }
+
+    fn to_text(&self) -> String {
+        let mut text = format!("{}\n", self.size);
+        let ns = pid_namespace();
+        for pid in &self.pids {
+            match ns {
+                Some(ns) => text.push_str(&format!("{} {}\n", pid, ns)),
+                None => text.push_str(&format!("{}\n", pid)),
+            }
+        }
+        for line in &self.foreign {
+            text.push_str(line);
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Attached processes of this PID namespace that are still running
+    pub fn live_pids(&self) -> Vec<u32> {
+        self.pids
+            .iter()
+            .copied()
+            .filter(|pid| is_process_running(*pid))
+            .collect()
+    }
+
+    /// Whether every recorded process has exited
+    ///
+    /// A marker with processes from another PID namespace is never stale.
+    pub fn is_stale(&self) -> bool {
+        self.foreign.is_empty() && self.live_pids().is_empty()
+    }
+
+    /// Record the current process as attached, dropping dead ones
+    pub fn attach(path: &Path, size: usize) -> std::io::Result<()> {
+        let pid = std::process::id();
+        Self::update(path, |marker| {
+            marker.size = marker.size.max(size);
+            marker.pids = marker.live_pids();
+            if !marker.pids.contains(&pid) {
+                marker.pids.push(pid);
+            }
+        })
+        .map(|_| ())
+    }
+
+    /// Remove the current process, deleting the marker once no live process remains
+    ///
+    /// Returns true if the marker was deleted, i.e. this was the last process.
+    pub fn detach(path: &Path) -> std::io::Result<bool> {
+        if !path.exists() {
+            return Ok(false);
+        }
+        let pid = std::process::id();
+        Self::update(path, |marker| {
+            marker.pids = marker
+                .live_pids()
+                .into_iter()
+                .filter(|p| *p != pid)
+                .collect();
+        })
+        .map(|kept| !kept)
+    }
+
+    /// Run `remove` and delete the marker if the marker is stale
+    ///
+    /// Staleness is checked while holding the same lock as [`Self::update`],
+    /// so a process that attaches concurrently either lands before the check
+    /// (and keeps the region) or after the removal (and starts a new one).
+    /// Returns whether the region was reclaimed; a missing marker is not stale.
+    pub fn remove_if_stale(
+        path: &Path,
+        remove: impl FnOnce() -> std::io::Result<()>,
+    ) -> std::io::Result<bool> {
+        if !path.exists() {
+            return Ok(false);
+        }
+        let mut stale = false;
+        let mut result = Ok(());
+        let kept = Self::update(path, |marker| {
+            // An empty marker was just created by `update` itself: the old
+            // one was removed while we waited for the lock
+            if !marker.pids.is_empty() && marker.is_stale() {
+                stale = true;
+                result = remove();
+                if result.is_ok() {
+                    marker.pids.clear();
+                }
+            }
+        })?;
+        result?;
+        Ok(stale && !kept)
+    }
+
+    /// Read-modify-write the marker, deleting it when no process is left
+    ///
+    /// The update holds an exclusive lock (`flock` on the marker on Unix,
+    /// [`lock_markers`] on Windows) so concurrent attaches don't lose each
+    /// other's PIDs and a detach can't delete a marker another process is
+    /// attaching to. Returns whether the marker was kept.
+    fn update(path: &Path, change: impl FnOnce(&mut Self)) -> std::io::Result<bool> {
+        use std::io::{Read, Seek, Write};
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        #[cfg(windows)]
+        let _lock = lock_markers()?;
+        let mut file = loop {
+            let file = std::fs::OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(false)
+                .open(path)?;
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::MetadataExt;
+                use std::os::unix::io::AsRawFd;
+
+                if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) } != 0 {
+                    return Err(std::io::Error::last_os_error());
+                }
+                // Another process may have deleted the marker while we waited
+                match std::fs::metadata(path) {
+                    Ok(meta) if meta.ino() == file.metadata()?.ino() => break file,
+                    _ => continue,
+                }
+            }
+            #[cfg(not(unix))]
+            break file;
+        };
+
+        let mut text = String::new();
+        file.read_to_string(&mut text)?;
+        let mut marker = Self::parse(&text).unwrap_or_default();
+        change(&mut marker);
+
+        if marker.pids.is_empty() && marker.foreign.is_empty() {
+            // Unlink while still holding the lock (Windows can't delete open files)
+            #[cfg(not(unix))]
+            drop(file);
+            std::fs::remove_file(path)?;
+            return Ok(false);
+        }
+
+        file.set_len(0)?;
+        file.rewind()?;
+        file.write_all(marker.to_text().as_bytes())?;
+        Ok(true)
+    }
+}
+
+/// Take the lock serializing marker updates across processes (Windows)
+///
+/// Windows can't delete a file another process has open, so markers can't be
+/// locked and unlinked the way `flock` allows. Every update instead takes a
+/// `LockFileEx` lock on one lock file in [`shm_owners_dir`], which is kept out
+/// of the topics directory where markers live. Dropping the file releases it.
+#[cfg(windows)]
+fn lock_markers() -> std::io::Result<std::fs::File> {
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Storage::FileSystem::{LockFileEx, LOCKFILE_EXCLUSIVE_LOCK};
+    use windows_sys::Win32::System::IO::OVERLAPPED;
+
+    let dir = shm_owners_dir();
+    std::fs::create_dir_all(&dir)?;
+    let file = std::fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(dir.join("markers.lock"))?;
+
+    // SAFETY: the handle is valid for the lifetime of `file` and the
+    // zeroed OVERLAPPED selects offset 0 for a blocking lock
+    let locked = unsafe {
+        let mut overlapped: OVERLAPPED = std::mem::zeroed();
+        LockFileEx(
+            file.as_raw_handle() as _,
+            LOCKFILE_EXCLUSIVE_LOCK,
+            0,
+            u32::MAX,
+            u32::MAX,
+            &mut overlapped,
+        )
+    };
+    if locked == 0 {
+        return Err(std::io::Error::last_os_error());
+    }
+    Ok(file)
+}
+
 /// Check if we're running on a platform with true RAM-backed shared memory
 ///
 /// All major platforms now use optimal shared memory:
@@ -193,4 +574,84 @@ mod tests {
         let params = shm_params_dir();
         assert!(params.starts_with(&base));
     }
+
+    #[test]
+    fn test_domain_paths() {
+        assert_eq!(parse_domain_id("7"), Ok(Some(7)));
+        assert_eq!(parse_domain_id(" 0 "), Ok(None));
+        assert_eq!(parse_domain_id(""), Ok(None));
+        assert!(parse_domain_id("robot1").is_err());
+        assert!(parse_domain_id("-3").is_err());
+
+        let base = PathBuf::from("/dev/shm/horus");
+        assert_eq!(
+            domain_path(base.clone(), Some(7)),
+            PathBuf::from("/dev/shm/horus_domain_7")
+        );
+        assert_eq!(domain_path(base.clone(), None), base);
+    }
+
+    #[test]
+    fn test_shm_marker_attach_detach() {
+        assert_eq!(
+            ShmMarker::parse("4096\n12\n\n34\n"),
+            Some(ShmMarker {
+                size: 4096,
+                pids: vec![12, 34],
+                foreign: Vec::new(),
+            })
+        );
+        assert_eq!(ShmMarker::parse(""), None);
+
+        let dir = std::env::temp_dir().join(format!("horus_marker_test_{}", std::process::id()));
+        let path = dir.join("horus_scan");
+        ShmMarker::attach(&path, 1024).unwrap();
+        ShmMarker::attach(&path, 512).unwrap();
+        let marker = ShmMarker::read(&path).unwrap();
+        assert_eq!(marker.size, 1024);
+        assert_eq!(marker.pids, vec![std::process::id()]);
+
+        assert!(ShmMarker::detach(&path).unwrap());
+        assert!(!path.exists());
+        assert!(!ShmMarker::detach(&path).unwrap());
+
+        let dead = ShmMarker {
+            size: 64,
+            pids: vec![999_999_999],
+            foreign: Vec::new(),
+        };
+        assert!(dead.is_stale());
+        let shared = ShmMarker {
+            foreign: vec!["999999999 1".to_string()],
+            ..dead
+        };
+        assert!(!shared.is_stale());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_shm_marker_concurrent_updates_keep_every_pid() {
+        let dir = std::env::temp_dir().join(format!("horus_marker_race_{}", std::process::id()));
+        let path = dir.join("horus_scan");
+        let threads: Vec<_> = (1..=16u32)
+            .map(|pid| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    ShmMarker::update(&path, |marker| {
+                        marker.size = 64;
+                        marker.pids.push(pid);
+                    })
+                    .unwrap();
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let mut pids = ShmMarker::read(&path).unwrap().pids;
+        pids.sort();
+        assert_eq!(pids, (1..=16).collect::<Vec<u32>>());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
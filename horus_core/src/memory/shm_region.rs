@@ -1,15 +1,23 @@
 // HORUS Shared Memory Region - Cross-platform optimized shared memory
 //
 // Each platform uses its optimal shared memory mechanism:
-// - Linux: /dev/shm files (tmpfs - RAM-backed, already optimal)
+// - Linux: /dev/shm files (tmpfs - RAM-backed, already optimal), or hugetlbfs
+//   files for regions created with huge pages
 // - macOS: shm_open() + mmap (POSIX shared memory, RAM-backed via Mach)
-// - Windows: CreateFileMappingW with INVALID_HANDLE_VALUE (pagefile-backed, optimized for IPC)
+// - Windows: CreateFileMappingW with INVALID_HANDLE_VALUE (pagefile-backed, optimized for IPC),
+//   plus a marker file per region for discovery and cleanup
 
 use crate::error::HorusResult;
 use std::path::PathBuf;
 
+#[cfg(target_os = "linux")]
+use crate::memory::huge_pages::{advise_transparent_huge_pages, hugetlbfs_dir, map_huge_file};
+#[cfg(any(target_os = "macos", target_os = "windows"))]
+use crate::memory::platform::shm_object_prefix;
 #[cfg(target_os = "linux")]
 use crate::memory::platform::shm_topics_dir;
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+use crate::memory::platform::{owner_marker_path, ShmMarker};
 #[cfg(target_os = "linux")]
 use memmap2::{MmapMut, MmapOptions};
 #[cfg(target_os = "linux")]
@@ -29,6 +37,10 @@ pub struct ShmRegion {
     _file: File,
     #[cfg(target_os = "linux")]
     path: PathBuf,
+    #[cfg(target_os = "linux")]
+    huge_pages: bool,
+    #[cfg(target_os = "linux")]
+    marker: PathBuf,
 
     #[cfg(target_os = "macos")]
     ptr: *mut u8,
@@ -41,6 +53,8 @@ pub struct ShmRegion {
     ptr: *mut u8,
     #[cfg(target_os = "windows")]
     handle: isize, // HANDLE
+    #[cfg(target_os = "windows")]
+    marker: PathBuf,
 
     size: usize,
     #[allow(dead_code)]
@@ -57,6 +71,18 @@ pub struct ShmRegion {
 impl ShmRegion {
     /// Create or open a shared memory region
     pub fn new(name: &str, size: usize) -> HorusResult<Self> {
+        Self::create(name, size, false)
+    }
+
+    /// Create or open a shared memory region backed by 2 MiB huge pages
+    ///
+    /// Falls back to a regular region if no hugetlbfs is mounted, it lacks
+    /// free pages, or the region already exists without huge pages.
+    pub fn new_with_huge_pages(name: &str, size: usize) -> HorusResult<Self> {
+        Self::create(name, size, true)
+    }
+
+    fn create(name: &str, size: usize, huge_pages: bool) -> HorusResult<Self> {
         // Use flat namespace - all topics in same directory (ROS-like simplicity)
         let horus_shm_dir = shm_topics_dir();
         std::fs::create_dir_all(&horus_shm_dir)?;
@@ -65,6 +91,24 @@ impl ShmRegion {
         // Names can also contain "/" for namespacing (e.g., "links/sensor_test")
         let path = horus_shm_dir.join(format!("horus_{}", name));
 
+        // A region whose processes all crashed has a stale header; start over
+        match crate::memory::stale::reclaim_if_stale(name) {
+            Ok(true) => log::info!("Reclaimed stale shared memory '{}'", name),
+            Ok(false) => {}
+            Err(e) => log::warn!("Failed to reclaim stale shared memory '{}': {}", name, e),
+        }
+
+        // Join a huge page region created by another process, or create one
+        if !path.exists() {
+            if let Some(huge_path) = huge_page_path(name) {
+                if huge_pages || huge_path.exists() {
+                    if let Some(region) = Self::map_huge(name, huge_path, size) {
+                        return Ok(region);
+                    }
+                }
+            }
+        }
+
         // Create parent directory if the name contains "/" (e.g., "links/sensor_test")
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
@@ -89,6 +133,9 @@ impl ShmRegion {
         };
 
         let mut mmap = unsafe { MmapOptions::new().len(size).map_mut(&file)? };
+        if huge_pages {
+            advise_transparent_huge_pages(&mmap);
+        }
 
         if is_owner {
             mmap.fill(0);
@@ -99,6 +146,27 @@ impl ShmRegion {
             size,
             path,
             _file: file,
+            huge_pages: false,
+            marker: attach_marker(name, size),
+            name: name.to_string(),
+            owner: is_owner,
+        })
+    }
+
+    fn map_huge(name: &str, path: PathBuf, size: usize) -> Option<Self> {
+        let (file, mut mmap, is_owner) = map_huge_file(&path, size)?;
+        if is_owner {
+            mmap.fill(0);
+        }
+        // Opening without a size maps the whole file
+        let size = if size == 0 { mmap.len() } else { size };
+        Some(Self {
+            mmap,
+            size,
+            path,
+            _file: file,
+            huge_pages: true,
+            marker: attach_marker(name, size),
             name: name.to_string(),
             owner: is_owner,
         })
@@ -112,6 +180,11 @@ impl ShmRegion {
         let path = horus_shm_dir.join(format!("horus_{}", name));
 
         if !path.exists() {
+            if let Some(huge_path) = huge_page_path(name).filter(|p| p.exists()) {
+                return Self::map_huge(name, huge_path, 0).ok_or_else(|| {
+                    format!("Failed to map huge page shared memory '{}'", name).into()
+                });
+            }
             return Err(format!("Shared memory '{}' does not exist", name).into());
         }
 
@@ -125,6 +198,8 @@ impl ShmRegion {
             size,
             path,
             _file: file,
+            huge_pages: false,
+            marker: attach_marker(name, size),
             name: name.to_string(),
             owner: false,
         })
@@ -146,19 +221,73 @@ impl ShmRegion {
         self.owner
     }
 
+    /// Whether the region is backed by hugetlbfs huge pages
+    pub fn uses_huge_pages(&self) -> bool {
+        self.huge_pages
+    }
+
     /// Force cleanup of the shared memory file (for use when last consumer exits)
     /// This bypasses the owner check and removes the file unconditionally
     pub fn force_cleanup(&self) {
         if self.path.exists() {
             let _ = std::fs::remove_file(&self.path);
         }
+        let _ = std::fs::remove_file(&self.marker);
+    }
+}
+
+/// Path of a topic region on hugetlbfs
+#[cfg(target_os = "linux")]
+fn huge_page_path(name: &str) -> Option<PathBuf> {
+    hugetlbfs_dir().map(|dir| dir.join("topics").join(format!("horus_{}", name)))
+}
+
+/// Regions mapped by this process, with their number of handles
+///
+/// The owner marker records processes, not handles, so the process only
+/// detaches once its last handle to a region is dropped.
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+static ATTACHED: std::sync::Mutex<std::collections::BTreeMap<PathBuf, usize>> =
+    std::sync::Mutex::new(std::collections::BTreeMap::new());
+
+/// Record the current process as attached to the region `name`
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+fn attach_marker(name: &str, size: usize) -> PathBuf {
+    let marker = owner_marker_path(name);
+    let mut attached = ATTACHED.lock().unwrap_or_else(|e| e.into_inner());
+    let handles = attached.entry(marker.clone()).or_insert(0);
+    // Re-attach on every new handle: the marker may have been reclaimed
+    if let Err(e) = ShmMarker::attach(&marker, size) {
+        log::warn!("Failed to record shared memory '{}': {}", name, e);
+    }
+    *handles += 1;
+    marker
+}
+
+/// Drop one handle, detaching the process with its last one
+///
+/// Returns true if no process is attached to the region anymore.
+#[cfg(any(target_os = "linux", target_os = "windows"))]
+fn detach_marker(marker: &std::path::Path) -> bool {
+    let mut attached = ATTACHED.lock().unwrap_or_else(|e| e.into_inner());
+    match attached.get_mut(marker) {
+        Some(handles) if *handles > 1 => {
+            *handles -= 1;
+            false
+        }
+        _ => {
+            attached.remove(marker);
+            ShmMarker::detach(marker).unwrap_or(false)
+        }
     }
 }
 
 #[cfg(target_os = "linux")]
 impl Drop for ShmRegion {
     fn drop(&mut self) {
-        if self.owner && self.path.exists() {
+        // The creator removes the region, or whoever detaches last
+        let last = detach_marker(&self.marker);
+        if (self.owner || last) && self.path.exists() {
             let _ = std::fs::remove_file(&self.path);
         }
     }
@@ -175,8 +304,9 @@ impl ShmRegion {
     pub fn new(name: &str, size: usize) -> HorusResult<Self> {
         use std::ffi::CString;
 
-        // Use flat namespace - all topics share same prefix (ROS-like simplicity)
-        let shm_name = format!("/horus_{}", name);
+        // Use flat namespace - all topics share same prefix (ROS-like simplicity),
+        // with the domain ID in the prefix to keep domains apart
+        let shm_name = format!("/{}{}", shm_object_prefix(), name);
         let c_name =
             CString::new(shm_name.clone()).map_err(|e| format!("Invalid shm name: {}", e))?;
 
@@ -264,7 +394,7 @@ impl ShmRegion {
         use std::ffi::CString;
 
         // Use flat namespace - all topics share same prefix
-        let shm_name = format!("/horus_{}", name);
+        let shm_name = format!("/{}{}", shm_object_prefix(), name);
         let c_name =
             CString::new(shm_name.clone()).map_err(|e| format!("Invalid shm name: {}", e))?;
 
@@ -367,14 +497,7 @@ impl ShmRegion {
             CreateFileMappingW, MapViewOfFile, FILE_MAP_ALL_ACCESS, PAGE_READWRITE,
         };
 
-        // Use flat namespace - all topics share same prefix (ROS-like simplicity)
-        let mapping_name = format!("Local\\horus_{}", name);
-
-        // Convert to wide string
-        let wide_name: Vec<u16> = mapping_name
-            .encode_utf16()
-            .chain(std::iter::once(0))
-            .collect();
+        let wide_name = windows_mapping_name(name);
 
         // Create or open file mapping (INVALID_HANDLE_VALUE = pagefile-backed)
         let handle = unsafe {
@@ -397,8 +520,8 @@ impl ShmRegion {
 
         let is_owner = unsafe { GetLastError() } != ERROR_ALREADY_EXISTS;
 
-        // Map view of file
-        let ptr = unsafe { MapViewOfFile(handle, FILE_MAP_ALL_ACCESS, 0, 0, size) };
+        // An existing mapping keeps its original size, which may be smaller
+        let ptr = unsafe { MapViewOfFile(handle, FILE_MAP_ALL_ACCESS, 0, 0, 0) };
 
         if ptr.is_null() {
             unsafe { CloseHandle(handle) };
@@ -407,6 +530,20 @@ impl ShmRegion {
             );
         }
 
+        let mapped = windows_view_size(ptr as *const std::ffi::c_void);
+        if mapped < size {
+            unsafe {
+                windows_sys::Win32::System::Memory::UnmapViewOfFile(ptr as *const std::ffi::c_void);
+                CloseHandle(handle);
+            }
+            return Err(format!(
+                "Shared memory '{}' exists with {} bytes, {} needed. \
+                 Stop the processes using it and retry.",
+                name, mapped, size
+            )
+            .into());
+        }
+
         // Initialize to zero if owner
         if is_owner {
             unsafe {
@@ -414,9 +551,12 @@ impl ShmRegion {
             }
         }
 
+        let marker = attach_marker(name, size);
+
         Ok(Self {
             ptr: ptr as *mut u8,
             handle,
+            marker,
             size,
             name: name.to_string(),
             owner: is_owner,
@@ -430,12 +570,7 @@ impl ShmRegion {
             MapViewOfFile, OpenFileMappingW, FILE_MAP_ALL_ACCESS,
         };
 
-        // Use flat namespace - all topics share same prefix
-        let mapping_name = format!("Local\\horus_{}", name);
-        let wide_name: Vec<u16> = mapping_name
-            .encode_utf16()
-            .chain(std::iter::once(0))
-            .collect();
+        let wide_name = windows_mapping_name(name);
 
         let handle = unsafe {
             OpenFileMappingW(
@@ -449,7 +584,7 @@ impl ShmRegion {
             return Err(format!("Shared memory '{}' does not exist", name).into());
         }
 
-        // Map view - we don't know size, map entire region
+        // Map view - map the entire region
         let ptr = unsafe {
             MapViewOfFile(
                 handle,
@@ -467,14 +602,18 @@ impl ShmRegion {
             );
         }
 
-        // Note: We can't easily get the size of an existing mapping on Windows
-        // without additional tracking. For now, use a reasonable default.
-        // In practice, the caller should know the expected size.
-        let size = 0; // Unknown - caller should track
+        // The view is rounded up to whole pages; the marker has the exact size
+        let mapped = windows_view_size(ptr as *const std::ffi::c_void);
+        let size = ShmMarker::read(&owner_marker_path(name))
+            .map(|m| m.size)
+            .filter(|&s| s > 0 && s <= mapped)
+            .unwrap_or(mapped);
+        let marker = attach_marker(name, size);
 
         Ok(Self {
             ptr: ptr as *mut u8,
             handle,
+            marker,
             size,
             name: name.to_string(),
             owner: false,
@@ -498,10 +637,41 @@ impl ShmRegion {
     }
 
     /// Force cleanup of the shared memory (for use when last consumer exits)
-    /// On Windows, cleanup is automatic when all handles are closed, so this is a no-op
+    ///
+    /// Windows destroys the mapping itself once every handle is closed, so
+    /// this only removes the marker file.
     pub fn force_cleanup(&self) {
-        // Windows automatically cleans up named file mappings when all handles are closed
-        // No explicit cleanup needed
+        let _ = std::fs::remove_file(&self.marker);
+    }
+}
+
+/// Kernel object name of a region (`\` is the namespace separator)
+#[cfg(target_os = "windows")]
+fn windows_mapping_name(name: &str) -> Vec<u16> {
+    // Use flat namespace - all topics share same prefix (ROS-like simplicity)
+    format!("Local\\{}{}", shm_object_prefix(), name.replace('\\', "_"))
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .collect()
+}
+
+/// Size of the mapped view starting at `ptr`
+#[cfg(target_os = "windows")]
+fn windows_view_size(ptr: *const std::ffi::c_void) -> usize {
+    use windows_sys::Win32::System::Memory::{VirtualQuery, MEMORY_BASIC_INFORMATION};
+
+    let mut info: MEMORY_BASIC_INFORMATION = unsafe { std::mem::zeroed() };
+    let written = unsafe {
+        VirtualQuery(
+            ptr,
+            &mut info,
+            std::mem::size_of::<MEMORY_BASIC_INFORMATION>(),
+        )
+    };
+    if written == 0 {
+        0
+    } else {
+        info.RegionSize
     }
 }
 
@@ -515,7 +685,9 @@ impl Drop for ShmRegion {
             UnmapViewOfFile(self.ptr as *const std::ffi::c_void);
             CloseHandle(self.handle);
         }
-        // Note: Windows automatically cleans up named file mappings when all handles are closed
+        // The mapping is destroyed with its last handle; the marker goes
+        // with the last attached process
+        detach_marker(&self.marker);
     }
 }
 
@@ -523,6 +695,20 @@ impl Drop for ShmRegion {
 unsafe impl Send for ShmRegion {}
 unsafe impl Sync for ShmRegion {}
 
+// Huge page backing is only implemented on Linux
+#[cfg(not(target_os = "linux"))]
+impl ShmRegion {
+    /// Create or open a regular shared memory region (huge pages need Linux)
+    pub fn new_with_huge_pages(name: &str, size: usize) -> HorusResult<Self> {
+        Self::new(name, size)
+    }
+
+    /// Whether the region is backed by hugetlbfs huge pages
+    pub fn uses_huge_pages(&self) -> bool {
+        false
+    }
+}
+
 // ============================================================================
 // Fallback for other platforms (BSD, etc.) - Use file-based approach
 // ============================================================================
@@ -538,7 +724,7 @@ use std::fs::{File, OpenOptions};
 impl ShmRegion {
     pub fn new(name: &str, size: usize) -> HorusResult<Self> {
         // Fallback to /tmp file-based approach
-        let horus_shm_dir = PathBuf::from("/tmp/horus/topics");
+        let horus_shm_dir = shm_topics_dir();
         std::fs::create_dir_all(&horus_shm_dir)?;
 
         // Topic names use dot notation (e.g., "motors.cmd_vel") - no conversion needed
@@ -575,7 +761,7 @@ impl ShmRegion {
 
     pub fn open(name: &str) -> HorusResult<Self> {
         // Topic names use dot notation - no conversion needed
-        let path = PathBuf::from("/tmp/horus/topics").join(format!("horus_{}", name));
+        let path = shm_topics_dir().join(format!("horus_{}", name));
         if !path.exists() {
             return Err(format!("Shared memory '{}' does not exist", name).into());
         }
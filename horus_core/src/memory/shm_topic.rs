@@ -13,9 +13,15 @@ const MAX_ELEMENT_SIZE: usize = 1_000_000; // Maximum size per element in bytes
 const MAX_TOTAL_SIZE: usize = 100_000_000; // Maximum total shared memory size (100MB)
 const MAX_CONSUMERS: usize = 16; // Maximum number of consumers per topic (MPMC support)
 
+/// Maximum number of designated publishers stored in a topic header
+pub const MAX_TOPIC_PUBLISHERS: usize = 7;
+
+// publisher_count while a process is writing the publisher tags
+const PUBLISHERS_WRITING: u64 = u64::MAX;
+
 // Magic number to indicate header is fully initialized (prevents race condition)
 // This value is written LAST by the owner with Release ordering
-const MAGIC_INITIALIZED: u64 = 0x484F5255535F4F4B; // "HORUS_OK" in ASCII hex
+const MAGIC_INITIALIZED: u64 = 0x484F5255535F5632; // "HORUS_V2" in ASCII hex (header with publishers)
 
 // Maximum time to wait for initialization (in spin iterations)
 const MAX_INIT_WAIT_ITERS: u32 = 1_000_000; // ~100ms on typical hardware
@@ -30,7 +36,9 @@ struct RingBufferHeader {
     element_size: AtomicUsize,
     consumer_count: AtomicUsize,
     sequence_number: AtomicUsize, // Global sequence counter
-    _padding: [u8; 8],            // Pad to 64-byte cache line boundary (1*8 + 6*8 + 8 = 64)
+    latched: AtomicU64, // Non-zero: new consumers start at the last message (1*8 + 6*8 + 8 = 64)
+    publisher_count: AtomicU64, // 0: anyone may publish, otherwise number of tags below
+    publishers: [AtomicU64; MAX_TOPIC_PUBLISHERS], // Publish key fingerprints (see communication::publish_policy)
 }
 
 /// Lock-free ring buffer in real shared memory using mmap with cache optimization
@@ -151,8 +159,32 @@ impl<T> ShmTopic<T> {
         power
     }
 
+    /// Where a new consumer starts reading: the current head, or the last
+    /// message if the topic is latched
+    fn start_position(header: &RingBufferHeader, head: usize) -> usize {
+        let capacity = header.capacity.load(Ordering::Acquire);
+        if header.latched.load(Ordering::Acquire) != 0
+            && header.sequence_number.load(Ordering::Acquire) > 0
+        {
+            (head + capacity - 1) & (capacity - 1)
+        } else {
+            head
+        }
+    }
+
     /// Create a new ring buffer in shared memory
     pub fn new(name: &str, capacity: usize) -> HorusResult<Self> {
+        Self::create(name, capacity, false)
+    }
+
+    /// Create a new ring buffer backed by 2 MiB huge pages when available
+    ///
+    /// Falls back to regular shared memory, see `uses_huge_pages()`.
+    pub fn new_with_huge_pages(name: &str, capacity: usize) -> HorusResult<Self> {
+        Self::create(name, capacity, true)
+    }
+
+    fn create(name: &str, capacity: usize, huge_pages: bool) -> HorusResult<Self> {
         // Safety validation: check capacity bounds
         if capacity < MIN_CAPACITY {
             return Err(format!(
@@ -214,7 +246,11 @@ impl<T> ShmTopic<T> {
         }
 
         // Create shared memory region
-        let region = Arc::new(ShmRegion::new(name, total_size)?);
+        let region = Arc::new(if huge_pages {
+            ShmRegion::new_with_huge_pages(name, total_size)?
+        } else {
+            ShmRegion::new(name, total_size)?
+        });
         let is_owner = region.is_owner();
 
         // Initialize header with safety checks
@@ -258,7 +294,10 @@ impl<T> ShmTopic<T> {
                     .sequence_number
                     .store(0, Ordering::Relaxed);
                 // MPMC OPTIMIZED: Consumer tails now tracked in local memory (not in header)
-                (*header.as_ptr())._padding = [0; 8]; // Initialize padding for cache alignment
+                (*header.as_ptr()).latched.store(0, Ordering::Relaxed);
+                (*header.as_ptr())
+                    .publisher_count
+                    .store(0, Ordering::Relaxed);
 
                 // CRITICAL: Write magic number LAST with Release ordering
                 // This ensures all previous writes are visible before magic is set
@@ -283,7 +322,7 @@ impl<T> ShmTopic<T> {
                     // Invalid magic - corrupted or incompatible version
                     return Err(format!(
                         "Topic '{}' has invalid magic number 0x{:X} (corrupted or incompatible version). \
-                         Please delete shared memory files in /dev/shm/horus/topics/ and restart.",
+                         Run `horus clean --shm` and restart.",
                         name, magic
                     )
                     .into());
@@ -293,7 +332,7 @@ impl<T> ShmTopic<T> {
                 if wait_iters > MAX_INIT_WAIT_ITERS {
                     return Err(format!(
                         "Topic '{}' initialization timeout: owner process may have crashed during setup. \
-                         Please delete shared memory files in /dev/shm/horus/topics/ and restart.",
+                         Run `horus clean --shm` and restart.",
                         name
                     )
                     .into());
@@ -308,7 +347,7 @@ impl<T> ShmTopic<T> {
             if !existing_capacity.is_power_of_two() {
                 return Err(format!(
                     "Topic '{}' has invalid capacity {} (corrupted shared memory). \
-                     Please delete shared memory files in /dev/shm/horus/topics/ and restart.",
+                     Run `horus clean --shm` and restart.",
                     name, existing_capacity
                 )
                 .into());
@@ -349,6 +388,7 @@ impl<T> ShmTopic<T> {
             ),
             tick_us: 0,
             ipc_ns: 0,
+            trace_id: None,
         });
 
         // Data starts after aligned header with comprehensive safety checks
@@ -400,6 +440,7 @@ impl<T> ShmTopic<T> {
 
             (id, current_head)
         };
+        let current_head = Self::start_position(unsafe { header.as_ref() }, current_head);
 
         Ok(ShmTopic {
             _region: region,
@@ -448,7 +489,7 @@ impl<T> ShmTopic<T> {
                 // Invalid magic - corrupted or incompatible version
                 return Err(format!(
                     "Topic '{}' has invalid magic number 0x{:X} (corrupted or incompatible version). \
-                     Please delete shared memory files in /dev/shm/horus/topics/ and restart.",
+                     Run `horus clean --shm` and restart.",
                     name, magic
                 )
                 .into());
@@ -458,7 +499,7 @@ impl<T> ShmTopic<T> {
             if wait_iters > MAX_INIT_WAIT_ITERS {
                 return Err(format!(
                     "Topic '{}' initialization timeout: owner process may have crashed during setup. \
-                     Please delete shared memory files in /dev/shm/horus/topics/ and restart.",
+                     Run `horus clean --shm` and restart.",
                     name
                 )
                 .into());
@@ -508,6 +549,7 @@ impl<T> ShmTopic<T> {
             message: format!("Opened existing topic (capacity: {})", capacity),
             tick_us: 0,
             ipc_ns: 0,
+            trace_id: None,
         });
 
         let element_align = mem::align_of::<T>();
@@ -572,6 +614,7 @@ impl<T> ShmTopic<T> {
 
             (id, head)
         };
+        let current_head = Self::start_position(unsafe { header.as_ref() }, current_head);
 
         Ok(ShmTopic {
             _region: region,
@@ -907,6 +950,104 @@ impl<T> ShmTopic<T> {
         }
     }
 
+    /// Number of messages published to this topic so far
+    ///
+    /// Changes whenever a message is published, so it can be polled to detect
+    /// new data without consuming it.
+    pub fn sequence(&self) -> usize {
+        let header = unsafe { self.header.as_ref() };
+        header.sequence_number.load(Ordering::Acquire)
+    }
+
+    /// Mark the topic as latched
+    ///
+    /// The flag lives in shared memory: consumers created afterwards, in any
+    /// process, receive the last published message first. If this consumer
+    /// has not received anything yet and a message exists, it is delivered
+    /// by the next `receive()` as well.
+    pub fn set_latched(&self) {
+        let header = unsafe { self.header.as_ref() };
+        header.latched.store(1, Ordering::Release);
+
+        let head = header.head.load(Ordering::Acquire);
+        if header.sequence_number.load(Ordering::Acquire) > 0 {
+            let last = (head + self.capacity - 1) & (self.capacity - 1);
+            let _ = self.consumer_tail.compare_exchange(
+                head,
+                last,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            );
+        }
+    }
+
+    /// Designate the publishers with the given tags
+    ///
+    /// Stored in shared memory so every process joining the topic is held to
+    /// the same publishers. Only the first call on a topic takes effect; the
+    /// tags in force afterwards are returned.
+    pub fn set_publishers(&self, tags: &[u64]) -> HorusResult<Vec<u64>> {
+        if tags.is_empty() || tags.len() > MAX_TOPIC_PUBLISHERS {
+            return Err(format!(
+                "A topic takes 1 to {} publisher tags, got {}",
+                MAX_TOPIC_PUBLISHERS,
+                tags.len()
+            )
+            .into());
+        }
+        let header = unsafe { self.header.as_ref() };
+        if header
+            .publisher_count
+            .compare_exchange(0, PUBLISHERS_WRITING, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            for (slot, tag) in header.publishers.iter().zip(tags) {
+                slot.store(*tag, Ordering::Relaxed);
+            }
+            header
+                .publisher_count
+                .store(tags.len() as u64, Ordering::Release);
+        }
+        Ok(self.publishers().unwrap_or_default())
+    }
+
+    /// Tags of the designated publishers, None if anyone may publish
+    pub fn publishers(&self) -> Option<Vec<u64>> {
+        let header = unsafe { self.header.as_ref() };
+        let mut wait_iters = 0u32;
+        let count = loop {
+            match header.publisher_count.load(Ordering::Acquire) {
+                PUBLISHERS_WRITING if wait_iters < MAX_INIT_WAIT_ITERS => {
+                    wait_iters += 1;
+                    std::hint::spin_loop();
+                }
+                // The writer died half-way: nobody may publish
+                PUBLISHERS_WRITING => return Some(Vec::new()),
+                count => break count as usize,
+            }
+        };
+        if count == 0 {
+            return None;
+        }
+        Some(
+            header.publishers[..count.min(MAX_TOPIC_PUBLISHERS)]
+                .iter()
+                .map(|slot| slot.load(Ordering::Relaxed))
+                .collect(),
+        )
+    }
+
+    /// Whether the ring buffer is backed by hugetlbfs huge pages
+    pub fn uses_huge_pages(&self) -> bool {
+        self._region.uses_huge_pages()
+    }
+
+    /// Whether the topic is latched (see [`set_latched`](Self::set_latched))
+    pub fn is_latched(&self) -> bool {
+        let header = unsafe { self.header.as_ref() };
+        header.latched.load(Ordering::Acquire) != 0
+    }
+
     /// Loan a slot and immediately write data (convenience method)
     /// This is equivalent to loan() followed by write(), but more convenient
     pub fn loan_and_write(&self, value: T) -> Result<(), T> {
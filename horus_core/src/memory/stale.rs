@@ -0,0 +1,248 @@
+//! Detection and reclamation of stale shared memory
+//!
+//! Every process attached to a region is recorded in its owner marker (see
+//! [`ShmMarker`]). A region whose marker only lists dead processes was left
+//! behind by a crash: its header may describe another message type or
+//! capacity, and a restarted publisher would silently join it. Such regions
+//! are reclaimed when a process next creates them (re-checked under the
+//! marker lock, see [`reclaim_if_stale`]), and by `horus doctor --fix`.
+//!
+//! PIDs only mean something inside their PID namespace, so regions shared
+//! with a container that has its own are never considered stale here.
+//!
+//! Region files without a marker (created by older HORUS versions) are
+//! reported as untracked and never removed automatically.
+
+use crate::memory::platform::{is_process_running, link_socket_dir, owner_marker_path, ShmMarker};
+#[cfg(not(target_os = "windows"))]
+use crate::memory::platform::{shm_owners_dir, shm_topics_dir};
+use std::path::{Path, PathBuf};
+
+/// A region whose attached processes have all exited
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaleSegment {
+    /// Region name as passed to `ShmRegion` (`motors.cmd_vel`, `links/scan`)
+    pub name: String,
+    /// Size recorded in the marker
+    pub size: usize,
+    /// Processes that were attached
+    pub pids: Vec<u32>,
+}
+
+/// Whether the region `name` was left behind by processes that have exited
+pub fn is_stale(name: &str) -> bool {
+    ShmMarker::read(&owner_marker_path(name)).is_some_and(|marker| marker.is_stale())
+}
+
+/// Remove the region `name` if the processes attached to it have all exited
+///
+/// Unlike [`is_stale`] followed by [`reclaim_segment`], the check and the
+/// removal happen under the marker lock, so a process that attached in the
+/// meantime keeps its region. Returns whether the region was reclaimed.
+pub fn reclaim_if_stale(name: &str) -> std::io::Result<bool> {
+    ShmMarker::remove_if_stale(&owner_marker_path(name), || {
+        for path in region_paths(name) {
+            remove_if_exists(&path)?;
+        }
+        Ok(())
+    })
+}
+
+/// Remove the region `name` and its owner marker unconditionally
+///
+/// Used by `horus doctor --fix`; processes creating regions go through
+/// [`reclaim_if_stale`].
+pub fn reclaim_segment(name: &str) -> std::io::Result<()> {
+    for path in region_paths(name) {
+        remove_if_exists(&path)?;
+    }
+    remove_if_exists(&owner_marker_path(name))
+}
+
+/// Regions of the current domain left behind by crashed processes
+pub fn find_stale_segments() -> Vec<StaleSegment> {
+    let mut stale = Vec::new();
+    let root = markers_dir();
+    for path in walk_files(&root) {
+        let Some(marker) = ShmMarker::read(&path) else {
+            continue;
+        };
+        if !marker.is_stale() {
+            continue;
+        }
+        if let Some(name) = region_name(&root, &path) {
+            stale.push(StaleSegment {
+                name,
+                size: marker.size,
+                pids: marker.pids,
+            });
+        }
+    }
+    stale.sort_by(|a, b| a.name.cmp(&b.name));
+    stale
+}
+
+/// Remove every stale region, returning the ones that were removed
+pub fn reclaim_stale_segments() -> Vec<StaleSegment> {
+    find_stale_segments()
+        .into_iter()
+        .filter(|segment| match reclaim_segment(&segment.name) {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!(
+                    "Failed to reclaim stale shared memory '{}': {}",
+                    segment.name,
+                    e
+                );
+                false
+            }
+        })
+        .collect()
+}
+
+/// Region files without an owner marker, whose state can't be determined
+pub fn find_untracked_segments() -> Vec<PathBuf> {
+    #[cfg(not(target_os = "windows"))]
+    {
+        let root = shm_topics_dir();
+        walk_files(&root)
+            .into_iter()
+            .filter(|path| {
+                region_name(&root, path).is_some_and(|name| !owner_marker_path(&name).exists())
+            })
+            .collect()
+    }
+
+    // Windows regions are only visible through their markers
+    #[cfg(target_os = "windows")]
+    {
+        Vec::new()
+    }
+}
+
+/// Link sockets (`horus_link_<topic>_<pid>.sock`) of exited producers
+pub fn find_stale_link_sockets() -> Vec<PathBuf> {
+    let prefix = format!("{}link_", crate::memory::platform::shm_object_prefix());
+    let Ok(entries) = std::fs::read_dir(link_socket_dir()) else {
+        return Vec::new();
+    };
+    let mut stale: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .and_then(|n| link_socket_pid(n, &prefix))
+                .is_some_and(|pid| !is_process_running(pid))
+        })
+        .collect();
+    stale.sort();
+    stale
+}
+
+/// PID of a producer socket name; consumer sockets carry no PID
+fn link_socket_pid(file_name: &str, prefix: &str) -> Option<u32> {
+    let rest = file_name.strip_prefix(prefix)?.strip_suffix(".sock")?;
+    let (_, pid) = rest.rsplit_once('_')?;
+    pid.parse().ok()
+}
+
+/// Files backing the region `name` (regular and huge page)
+fn region_paths(name: &str) -> Vec<PathBuf> {
+    #[cfg(target_os = "windows")]
+    {
+        // The mapping goes away with its last handle
+        let _ = name;
+        Vec::new()
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let file_name = format!("horus_{}", name);
+        let mut paths = vec![shm_topics_dir().join(&file_name)];
+        if let Some(dir) = crate::memory::huge_pages::hugetlbfs_dir() {
+            paths.push(dir.join("topics").join(&file_name));
+        }
+        paths
+    }
+}
+
+/// Directory holding the owner markers
+fn markers_dir() -> PathBuf {
+    #[cfg(target_os = "windows")]
+    {
+        crate::memory::platform::shm_topics_dir()
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        shm_owners_dir()
+    }
+}
+
+/// Region name of a file under `root` (`root/horus_links/scan` -> `links/scan`)
+fn region_name(root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(root).ok()?;
+    let relative = relative.to_string_lossy().replace('\\', "/");
+    relative.strip_prefix("horus_").map(str::to_string)
+}
+
+fn walk_files(dir: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return files;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        match entry.file_type() {
+            Ok(t) if t.is_dir() => files.extend(walk_files(&path)),
+            Ok(t) if t.is_file() => files.push(path),
+            _ => {}
+        }
+    }
+    files
+}
+
+fn remove_if_exists(path: &Path) -> std::io::Result<()> {
+    match std::fs::remove_file(path) {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_region_name() {
+        let root = Path::new("/dev/shm/horus/owners");
+        assert_eq!(
+            region_name(root, &root.join("horus_motors.cmd_vel")),
+            Some("motors.cmd_vel".to_string())
+        );
+        assert_eq!(
+            region_name(root, &root.join("horus_links").join("scan")),
+            Some("links/scan".to_string())
+        );
+        assert_eq!(region_name(root, &root.join("other")), None);
+    }
+
+    #[test]
+    fn test_link_socket_pid() {
+        let prefix = "horus_link_";
+        assert_eq!(
+            link_socket_pid("horus_link_odom_4242.sock", prefix),
+            Some(4242)
+        );
+        assert_eq!(
+            link_socket_pid("horus_link_cmd_vel_17.sock", prefix),
+            Some(17)
+        );
+        assert_eq!(
+            link_socket_pid("horus_link_odom_consumer.sock", prefix),
+            None
+        );
+        assert_eq!(link_socket_pid("horus_d3_link_odom_1.sock", prefix), None);
+    }
+}
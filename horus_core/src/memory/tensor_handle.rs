@@ -261,6 +261,7 @@ mod tests {
             pool_size: 1024 * 1024,
             max_slots: 16,
             slot_alignment: 64,
+            huge_pages: false,
         };
         Arc::new(TensorPool::new(pool_id, config).expect("Failed to create pool"))
     }
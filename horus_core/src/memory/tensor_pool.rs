@@ -9,24 +9,26 @@
 //! ┌────────────────────────────────────────────────────────────┐
 //! │                    TensorPool Layout                        │
 //! ├────────────────────────────────────────────────────────────┤
-//! │  PoolHeader (64 bytes)                                     │
+//! │  PoolHeader (592 bytes)                                    │
 //! │  ├── magic: u64                                            │
 //! │  ├── version: u32                                          │
 //! │  ├── pool_id: u32                                          │
 //! │  ├── pool_size: u64                                        │
 //! │  ├── max_slots: u32                                        │
 //! │  ├── slot_alignment: u32                                   │
-//! │  └── next_alloc_offset: AtomicU64                          │
+//! │  ├── next_alloc_offset: AtomicU64                          │
+//! │  ├── allocation counters (allocs, failures, live, peak)    │
+//! │  ├── empty_slots: AtomicU64 (stack of slots w/o a region)  │
+//! │  └── free_lists: [AtomicU64; 64] (free regions by size)    │
 //! ├────────────────────────────────────────────────────────────┤
-//! │  SlotHeaders[max_slots] (32 bytes each)                    │
+//! │  SlotHeaders[max_slots] (40 bytes each)                    │
 //! │  ├── refcount: AtomicU32                                   │
 //! │  ├── generation: AtomicU32                                 │
 //! │  ├── offset: u64                                           │
 //! │  ├── size: u64                                             │
-//! │  └── flags: AtomicU32                                      │
-//! ├────────────────────────────────────────────────────────────┤
-//! │  Free Stack (lock-free)                                    │
-//! │  └── free_stack_head: AtomicU64                            │
+//! │  ├── capacity: u64 (data region kept by the slot)          │
+//! │  ├── flags: AtomicU32                                      │
+//! │  └── next_free: AtomicU32 (link in its free stack)         │
 //! ├────────────────────────────────────────────────────────────┤
 //! │                                                            │
 //! │  Data Region (remaining space)                             │
@@ -55,28 +57,47 @@
 //!
 //! // Reference counting handles cleanup automatically
 //! ```
+//!
+//! # Memory reuse
+//!
+//! Data regions are carved from the front of the data area. A released slot
+//! keeps its region and is pushed on the free list of its size class
+//! (power of two), and later allocations pop a fitting region before carving
+//! a new one, so a pipeline allocating the same frame sizes every tick runs
+//! in constant space. A free region is never overwritten: when no slot is
+//! left to carve with, the allocation runs `defragment()` once and fails if
+//! that frees nothing. Mixed sizes still leave holes; `defragment()` merges
+//! adjacent free regions and returns the free tail, and `stats()` reports
+//! the fragmentation ratio and high-water marks used to size the pool.
 
 use crate::error::{HorusError, HorusResult};
+#[cfg(feature = "cuda")]
+use crate::memory::cuda_ffi::{self, CudaHostRegisterFlags};
+#[cfg(target_os = "linux")]
+use crate::memory::huge_pages::{advise_transparent_huge_pages, hugetlbfs_dir, map_huge_file};
 use crate::memory::platform::shm_base_dir;
 use memmap2::{MmapMut, MmapOptions};
 use std::fs::{File, OpenOptions};
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 
 /// Magic number for pool validation
 const POOL_MAGIC: u64 = 0x484F5255535F5450; // "HORUS_TP" in hex
 
 /// Current pool version
-const POOL_VERSION: u32 = 1;
+const POOL_VERSION: u32 = 3;
 
 /// Slot flags
 const SLOT_FREE: u32 = 0;
 const SLOT_ALLOCATED: u32 = 1;
 const SLOT_CUDA: u32 = 2;
 
-/// Invalid slot index (sentinel for free list)
+/// End of a free stack
 const INVALID_SLOT: u32 = u32::MAX;
 
+/// Free lists, one per power-of-two size class of the region capacity
+const SIZE_CLASSES: usize = 64;
+
 /// Pool header stored at the start of shared memory
 #[repr(C)]
 struct PoolHeader {
@@ -87,8 +108,18 @@ struct PoolHeader {
     max_slots: u32,
     slot_alignment: u32,
     next_alloc_offset: AtomicU64,
-    free_stack_head: AtomicU64,
-    _padding: [u8; 16],
+    /// Successful allocations since creation
+    total_allocs: AtomicU64,
+    /// Allocations that failed for lack of slots or memory
+    failed_allocs: AtomicU64,
+    /// Bytes held by live tensors
+    live_bytes: AtomicU64,
+    /// High-water mark of live_bytes
+    peak_live_bytes: AtomicU64,
+    /// Stack of free slots without a data region (tagged head)
+    empty_slots: AtomicU64,
+    /// Stacks of free slots keeping a region, by `size_class` (tagged heads)
+    free_lists: [AtomicU64; SIZE_CLASSES],
 }
 
 /// Slot metadata stored in shared memory
@@ -98,9 +129,10 @@ struct SlotHeader {
     generation: AtomicU32,
     offset: u64,
     size: u64,
+    /// Bytes of the data region at `offset` owned by the slot, kept while free
+    capacity: u64,
     flags: AtomicU32,
     next_free: AtomicU32,
-    _padding: [u8; 4],
 }
 
 /// Configuration for tensor pool
@@ -112,6 +144,9 @@ pub struct TensorPoolConfig {
     pub max_slots: usize,
     /// Memory alignment for tensor data (default: 64 bytes, cache-line)
     pub slot_alignment: usize,
+    /// Back the pool with 2 MiB huge pages, falling back to regular pages
+    /// when none are available (default: false)
+    pub huge_pages: bool,
 }
 
 impl Default for TensorPoolConfig {
@@ -120,6 +155,7 @@ impl Default for TensorPoolConfig {
             pool_size: 1024 * 1024 * 1024, // 1GB
             max_slots: 1024,
             slot_alignment: 64,
+            huge_pages: false,
         }
     }
 }
@@ -131,6 +167,7 @@ impl TensorPoolConfig {
             pool_size: 64 * 1024 * 1024, // 64MB
             max_slots: 256,
             slot_alignment: 64,
+            huge_pages: false,
         }
     }
 
@@ -140,6 +177,7 @@ impl TensorPoolConfig {
             pool_size: 4 * 1024 * 1024 * 1024, // 4GB
             max_slots: 4096,
             slot_alignment: 64,
+            huge_pages: false,
         }
     }
 }
@@ -155,6 +193,9 @@ pub struct TensorPool {
     mmap: MmapMut,
     _file: File,
     is_owner: bool,
+    huge_pages: bool,
+    /// Whether the mapping is registered with CUDA as pinned host memory
+    pinned: AtomicBool,
     #[allow(dead_code)]
     header_size: usize,
     slots_offset: usize,
@@ -178,22 +219,40 @@ impl TensorPool {
         let data_offset = Self::align_up(metadata_size, config.slot_alignment);
         let total_size = data_offset + config.pool_size;
 
-        // Try to open existing or create new
-        let (file, is_owner) = if shm_path.exists() {
-            let file = OpenOptions::new().read(true).write(true).open(&shm_path)?;
-            (file, false)
+        // Huge pages if requested or the pool was created with them, unless
+        // a regular pool already exists
+        let huge = if shm_path.exists() {
+            None
         } else {
-            let file = OpenOptions::new()
-                .read(true)
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .open(&shm_path)?;
-            file.set_len(total_size as u64)?;
-            (file, true)
+            Self::map_huge(pool_id, &config, total_size)
         };
 
-        let mmap = unsafe { MmapOptions::new().len(total_size).map_mut(&file)? };
+        let (shm_path, file, mmap, is_owner, huge_pages) = match huge {
+            Some((huge_path, file, mmap, is_owner)) => (huge_path, file, mmap, is_owner, true),
+            None => {
+                // Try to open existing or create new
+                let (file, is_owner) = if shm_path.exists() {
+                    let file = OpenOptions::new().read(true).write(true).open(&shm_path)?;
+                    (file, false)
+                } else {
+                    let file = OpenOptions::new()
+                        .read(true)
+                        .write(true)
+                        .create(true)
+                        .truncate(true)
+                        .open(&shm_path)?;
+                    file.set_len(total_size as u64)?;
+                    (file, true)
+                };
+
+                let mmap = unsafe { MmapOptions::new().len(total_size).map_mut(&file)? };
+                #[cfg(target_os = "linux")]
+                if config.huge_pages {
+                    advise_transparent_huge_pages(&mmap);
+                }
+                (shm_path, file, mmap, is_owner, false)
+            }
+        };
 
         let mut pool = Self {
             config: config.clone(),
@@ -202,6 +261,8 @@ impl TensorPool {
             mmap,
             _file: file,
             is_owner,
+            huge_pages,
+            pinned: AtomicBool::new(false),
             header_size,
             slots_offset: header_size,
             data_offset,
@@ -219,13 +280,22 @@ impl TensorPool {
     /// Open an existing tensor pool (fails if pool doesn't exist)
     pub fn open(pool_id: u32) -> HorusResult<Self> {
         let shm_dir = shm_base_dir().join("tensors");
-        let shm_path = shm_dir.join(format!("tensor_pool_{}", pool_id));
+        let mut shm_path = shm_dir.join(format!("tensor_pool_{}", pool_id));
+        let mut huge_pages = false;
 
         if !shm_path.exists() {
-            return Err(HorusError::Config(format!(
-                "Tensor pool {} does not exist",
-                pool_id
-            )));
+            match huge_pool_path(pool_id).filter(|path| path.exists()) {
+                Some(huge_path) => {
+                    shm_path = huge_path;
+                    huge_pages = true;
+                }
+                None => {
+                    return Err(HorusError::Config(format!(
+                        "Tensor pool {} does not exist",
+                        pool_id
+                    )));
+                }
+            }
         }
 
         let file = OpenOptions::new().read(true).write(true).open(&shm_path)?;
@@ -246,6 +316,7 @@ impl TensorPool {
             pool_size: header.pool_size as usize,
             max_slots: header.max_slots as usize,
             slot_alignment: header.slot_alignment as usize,
+            huge_pages,
         };
 
         let header_size = std::mem::size_of::<PoolHeader>();
@@ -260,12 +331,38 @@ impl TensorPool {
             mmap,
             _file: file,
             is_owner: false,
+            huge_pages,
+            pinned: AtomicBool::new(false),
             header_size,
             slots_offset: header_size,
             data_offset,
         })
     }
 
+    /// Map the pool on hugetlbfs, joining a huge page pool created by another process
+    #[cfg(target_os = "linux")]
+    fn map_huge(
+        pool_id: u32,
+        config: &TensorPoolConfig,
+        total_size: usize,
+    ) -> Option<(PathBuf, File, MmapMut, bool)> {
+        let huge_path = huge_pool_path(pool_id)?;
+        if !config.huge_pages && !huge_path.exists() {
+            return None;
+        }
+        let (file, mmap, is_owner) = map_huge_file(&huge_path, total_size)?;
+        Some((huge_path, file, mmap, is_owner))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    fn map_huge(
+        _pool_id: u32,
+        _config: &TensorPoolConfig,
+        _total_size: usize,
+    ) -> Option<(PathBuf, File, MmapMut, bool)> {
+        None
+    }
+
     /// Initialize a newly created pool
     fn initialize(&mut self) -> HorusResult<()> {
         // Zero the entire region
@@ -286,20 +383,28 @@ impl TensorPool {
         header.max_slots = max_slots;
         header.slot_alignment = slot_alignment;
         header.next_alloc_offset.store(0, Ordering::Release);
+        header.total_allocs.store(0, Ordering::Release);
+        header.failed_allocs.store(0, Ordering::Release);
+        header.live_bytes.store(0, Ordering::Release);
+        header.peak_live_bytes.store(0, Ordering::Release);
         header
-            .free_stack_head
+            .empty_slots
             .store(INVALID_SLOT as u64, Ordering::Release);
+        for list in &header.free_lists {
+            list.store(INVALID_SLOT as u64, Ordering::Release);
+        }
 
-        // Initialize all slots as free
+        // Initialize all slots as free, pushed so that slot 0 pops first
         let max_slots_usize = self.config.max_slots;
-        for i in 0..max_slots_usize {
+        for i in (0..max_slots_usize).rev() {
             let slot = self.slot_mut(i as u32);
             slot.refcount.store(0, Ordering::Release);
             slot.generation.store(0, Ordering::Release);
             slot.offset = 0;
             slot.size = 0;
+            slot.capacity = 0;
             slot.flags.store(SLOT_FREE, Ordering::Release);
-            slot.next_free.store(INVALID_SLOT, Ordering::Release);
+            self.push_free(&self.header().empty_slots, i as u32);
         }
 
         // Flush to ensure visibility
@@ -347,17 +452,27 @@ impl TensorPool {
         let element_size = dtype.element_size() as u64;
         let size = num_elements * element_size;
         let aligned_size = Self::align_up(size as usize, self.config.slot_alignment);
+        let header = self.header();
 
-        // Find a free slot
-        let slot_id = self.find_free_slot()?;
+        // Reuse a free region that fits, else carve a new one; when that
+        // fails, merging free regions may give back a slot or the tail
+        let slot_id = match self.claim_region(aligned_size) {
+            Ok(slot_id) => slot_id,
+            Err(_) if self.defragment() != DefragmentReport::default() => {
+                self.claim_region(aligned_size).inspect_err(|_| {
+                    header.failed_allocs.fetch_add(1, Ordering::Relaxed);
+                })?
+            }
+            Err(e) => {
+                header.failed_allocs.fetch_add(1, Ordering::Relaxed);
+                return Err(e);
+            }
+        };
         let slot = self.slot_mut(slot_id);
-
-        // Allocate from data region
-        let offset = self.allocate_data(aligned_size)?;
+        let offset = slot.offset;
 
         // Initialize slot
         let generation = slot.generation.fetch_add(1, Ordering::AcqRel) + 1;
-        slot.offset = offset as u64;
         slot.size = size;
         slot.refcount.store(1, Ordering::Release);
         slot.flags.store(
@@ -369,12 +484,16 @@ impl TensorPool {
             Ordering::Release,
         );
 
+        header.total_allocs.fetch_add(1, Ordering::Relaxed);
+        let live = header.live_bytes.fetch_add(size, Ordering::AcqRel) + size;
+        header.peak_live_bytes.fetch_max(live, Ordering::AcqRel);
+
         // Create tensor descriptor
         Ok(HorusTensor::new(
             self.pool_id,
             slot_id,
             generation,
-            offset as u64,
+            offset,
             shape,
             dtype,
             device,
@@ -416,7 +535,10 @@ impl TensorPool {
 
         let prev = slot.refcount.fetch_sub(1, Ordering::AcqRel);
         if prev == 1 {
-            // Last reference, return slot to free list
+            // Last reference, free the slot (it keeps its data region for reuse)
+            self.header()
+                .live_bytes
+                .fetch_sub(slot.size, Ordering::AcqRel);
             self.return_slot(tensor.slot_id);
         }
     }
@@ -485,17 +607,35 @@ impl TensorPool {
         let header = self.header();
         let mut allocated_slots = 0;
         let mut total_refcount = 0;
+        let mut free_regions = 0;
+        let mut free_region_bytes = 0;
+        let mut largest_free_region = 0;
 
         for i in 0..self.config.max_slots {
             let slot = self.slot(i as u32);
-            let flags = slot.flags.load(Ordering::Relaxed);
-            if flags != SLOT_FREE {
-                allocated_slots += 1;
-                total_refcount += slot.refcount.load(Ordering::Relaxed);
+            match slot.flags.load(Ordering::Relaxed) {
+                SLOT_ALLOCATED | SLOT_CUDA => {
+                    allocated_slots += 1;
+                    total_refcount += slot.refcount.load(Ordering::Relaxed);
+                }
+                _ if slot.capacity > 0 => {
+                    free_regions += 1;
+                    free_region_bytes += slot.capacity as usize;
+                    largest_free_region = largest_free_region.max(slot.capacity as usize);
+                }
+                _ => {}
             }
         }
 
         let used_bytes = header.next_alloc_offset.load(Ordering::Relaxed) as usize;
+        let tail_bytes = self.config.pool_size.saturating_sub(used_bytes);
+        let free_bytes = tail_bytes + free_region_bytes;
+        let largest_free_block = largest_free_region.max(tail_bytes);
+        let fragmentation = if free_bytes == 0 {
+            0.0
+        } else {
+            1.0 - largest_free_block as f64 / free_bytes as f64
+        };
 
         TensorPoolStats {
             pool_id: self.pool_id,
@@ -504,10 +644,140 @@ impl TensorPool {
             allocated_slots,
             total_refcount,
             used_bytes,
-            free_bytes: self.config.pool_size.saturating_sub(used_bytes),
+            free_bytes,
+            huge_pages: self.huge_pages,
+            total_allocs: header.total_allocs.load(Ordering::Relaxed),
+            failed_allocs: header.failed_allocs.load(Ordering::Relaxed),
+            live_bytes: header.live_bytes.load(Ordering::Relaxed) as usize,
+            peak_live_bytes: header.peak_live_bytes.load(Ordering::Relaxed) as usize,
+            free_regions,
+            largest_free_block,
+            fragmentation,
         }
     }
 
+    /// Merge adjacent free regions and give the free tail back to the pool
+    ///
+    /// Live tensors are never moved (their descriptors hold data offsets),
+    /// so only free space is rearranged. Safe to call while other processes
+    /// allocate; regions being rearranged are off their free lists meanwhile.
+    pub fn defragment(&self) -> DefragmentReport {
+        let header = self.header();
+
+        // Take every free region off its list so allocators leave it alone
+        let mut regions = Vec::new();
+        for list in &header.free_lists {
+            while let Some(i) = self.pop_free(list) {
+                regions.push(i);
+            }
+        }
+        regions.sort_by_key(|&i| self.slot(i).offset);
+
+        let mut report = DefragmentReport::default();
+
+        // Coalesce neighbours into the lowest region of each run
+        let mut run = None;
+        for &i in &regions {
+            let slot = self.slot_mut(i);
+            match run {
+                Some(first) => {
+                    let head = self.slot_mut(first);
+                    if head.offset + head.capacity == slot.offset {
+                        head.capacity += slot.capacity;
+                        slot.offset = 0;
+                        slot.capacity = 0;
+                        report.merged_regions += 1;
+                        continue;
+                    }
+                    run = Some(i);
+                }
+                None => run = Some(i),
+            }
+        }
+
+        // A free region ending at the allocation front can be handed back
+        if let Some(last) = run {
+            let slot = self.slot_mut(last);
+            let end = slot.offset + slot.capacity;
+            if header
+                .next_alloc_offset
+                .compare_exchange(end, slot.offset, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+            {
+                report.reclaimed_bytes = slot.capacity as usize;
+                slot.offset = 0;
+                slot.capacity = 0;
+            }
+        }
+
+        // Emptied slots go back to the empty stack, the rest by new size
+        for i in regions {
+            self.return_slot(i);
+        }
+        report
+    }
+
+    /// Whether the pool is backed by hugetlbfs huge pages
+    pub fn uses_huge_pages(&self) -> bool {
+        self.huge_pages
+    }
+
+    /// Page-lock the pool with CUDA so tensors copy to and from the GPU by DMA
+    ///
+    /// Registration is per process: the publisher and each GPU consumer pin
+    /// their own mapping. The memory is also mapped into the device address
+    /// space, see [`pinned_device_ptr`](Self::pinned_device_ptr).
+    #[cfg(feature = "cuda")]
+    pub fn pin(&self) -> HorusResult<()> {
+        if self.pinned.load(Ordering::Acquire) {
+            return Ok(());
+        }
+        cuda_ffi::host_register(
+            self.mmap.as_ptr() as *mut std::ffi::c_void,
+            self.mmap.len(),
+            CudaHostRegisterFlags::Mapped,
+        )
+        .map_err(|e| HorusError::Memory(format!("Failed to pin tensor pool: {}", e)))?;
+        self.pinned.store(true, Ordering::Release);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "cuda"))]
+    pub fn pin(&self) -> HorusResult<()> {
+        Err(HorusError::Config("CUDA feature not enabled".into()))
+    }
+
+    /// Whether the pool is registered as pinned host memory in this process
+    pub fn is_pinned(&self) -> bool {
+        self.pinned.load(Ordering::Acquire)
+    }
+
+    /// Device pointer through which kernels access a tensor of a pinned pool
+    /// directly, without a copy
+    #[cfg(feature = "cuda")]
+    pub fn pinned_device_ptr(&self, tensor: &HorusTensor) -> HorusResult<*mut std::ffi::c_void> {
+        if !self.is_pinned() {
+            return Err(HorusError::Config(format!(
+                "Tensor pool {} is not pinned",
+                self.pool_id
+            )));
+        }
+        let host_ptr = self.data_ptr(tensor);
+        if host_ptr.is_null() {
+            return Err(HorusError::Config(format!(
+                "Tensor belongs to pool {}, not {}",
+                tensor.pool_id, self.pool_id
+            )));
+        }
+        cuda_ffi::host_get_device_pointer(host_ptr as *mut std::ffi::c_void)
+            .map_err(|e| HorusError::Memory(format!("Failed to map pinned tensor: {}", e)))
+    }
+
+    #[cfg(not(feature = "cuda"))]
+    pub fn pinned_device_ptr(&self, _tensor: &HorusTensor) -> HorusResult<*mut std::ffi::c_void> {
+        Err(HorusError::Config("CUDA feature not enabled".into()))
+    }
+
     /// Get pool ID
     #[inline]
     pub fn pool_id(&self) -> u32 {
@@ -545,73 +815,101 @@ impl TensorPool {
         unsafe { &mut *(self.mmap.as_ptr().add(offset) as *mut SlotHeader) }
     }
 
-    fn find_free_slot(&self) -> HorusResult<u32> {
-        // Try to pop from free stack first
+    /// Claim a free slot owning a data region of at least `size` bytes
+    ///
+    /// Pops a fitting free region, else an empty slot to carve a new region
+    /// with. Free regions are never given up, so nothing leaks when slots
+    /// run out.
+    fn claim_region(&self, size: usize) -> HorusResult<u32> {
         let header = self.header();
+        let class = size_class(size as u64);
 
-        loop {
-            let head = header.free_stack_head.load(Ordering::Acquire);
-            if head != INVALID_SLOT as u64 {
-                let slot = self.slot(head as u32);
-                let next = slot.next_free.load(Ordering::Acquire);
-
-                if header
-                    .free_stack_head
-                    .compare_exchange_weak(head, next as u64, Ordering::AcqRel, Ordering::Relaxed)
-                    .is_ok()
-                {
-                    return Ok(head as u32);
-                }
-                continue;
+        // Regions of the request's own class may be too small, any above fits
+        if let Some(slot_id) = self.pop_free(&header.free_lists[class]) {
+            if self.slot(slot_id).capacity >= size as u64 {
+                return Ok(slot_id);
             }
-            break;
+            self.push_free(&header.free_lists[class], slot_id);
         }
-
-        // No free slots in stack, search linearly
-        for i in 0..self.config.max_slots {
-            let slot = self.slot(i as u32);
-            let flags = slot.flags.load(Ordering::Acquire);
-
-            if flags == SLOT_FREE {
-                // Try to claim this slot
-                if slot
-                    .flags
-                    .compare_exchange(
-                        SLOT_FREE,
-                        SLOT_ALLOCATED,
-                        Ordering::AcqRel,
-                        Ordering::Relaxed,
-                    )
-                    .is_ok()
-                {
-                    return Ok(i as u32);
-                }
+        for list in &header.free_lists[class + 1..] {
+            if let Some(slot_id) = self.pop_free(list) {
+                return Ok(slot_id);
             }
         }
 
-        Err(HorusError::Memory(
-            "No free tensor slots available".to_string(),
-        ))
+        let slot_id = self
+            .pop_free(&header.empty_slots)
+            .ok_or_else(|| HorusError::Memory("No free tensor slots available".to_string()))?;
+        match self.allocate_data(size) {
+            Ok(offset) => {
+                let slot = self.slot_mut(slot_id);
+                slot.offset = offset as u64;
+                slot.capacity = size as u64;
+                Ok(slot_id)
+            }
+            Err(e) => {
+                self.push_free(&header.empty_slots, slot_id);
+                Err(e)
+            }
+        }
     }
 
+    /// Mark a slot free and push it on the list for the region it keeps
     fn return_slot(&self, slot_id: u32) {
         let header = self.header();
-        let slot = self.slot_mut(slot_id);
-
-        // Mark as free
+        let slot = self.slot(slot_id);
         slot.flags.store(SLOT_FREE, Ordering::Release);
 
-        // Push to free stack
+        if slot.capacity == 0 {
+            self.push_free(&header.empty_slots, slot_id);
+        } else {
+            self.push_free(&header.free_lists[size_class(slot.capacity)], slot_id);
+        }
+    }
+
+    /// Push a slot on a free stack
+    ///
+    /// Heads hold the top slot in the low 32 bits and a tag bumped on every
+    /// update in the high 32 bits, so a pop racing a pop-push of the same
+    /// slot fails its compare-exchange (ABA).
+    fn push_free(&self, list: &AtomicU64, slot_id: u32) {
+        let slot = self.slot(slot_id);
         loop {
-            let head = header.free_stack_head.load(Ordering::Acquire);
+            let head = list.load(Ordering::Acquire);
             slot.next_free.store(head as u32, Ordering::Release);
+            if list
+                .compare_exchange_weak(
+                    head,
+                    next_tag(head) | slot_id as u64,
+                    Ordering::AcqRel,
+                    Ordering::Relaxed,
+                )
+                .is_ok()
+            {
+                return;
+            }
+        }
+    }
 
-            if header
-                .free_stack_head
-                .compare_exchange_weak(head, slot_id as u64, Ordering::AcqRel, Ordering::Relaxed)
+    /// Pop a slot off a free stack, taking ownership of it
+    fn pop_free(&self, list: &AtomicU64) -> Option<u32> {
+        loop {
+            let head = list.load(Ordering::Acquire);
+            let slot_id = head as u32;
+            if slot_id == INVALID_SLOT {
+                return None;
+            }
+            let next = self.slot(slot_id).next_free.load(Ordering::Acquire);
+            if list
+                .compare_exchange_weak(
+                    head,
+                    next_tag(head) | next as u64,
+                    Ordering::AcqRel,
+                    Ordering::Relaxed,
+                )
                 .is_ok()
             {
-                break;
+                return Some(slot_id);
             }
         }
     }
@@ -657,6 +955,37 @@ impl Drop for TensorPool {
     fn drop(&mut self) {
         // Don't delete the file - other processes may still be using it
         // The file can be cleaned up manually or by a cleanup routine
+
+        #[cfg(feature = "cuda")]
+        if self.pinned.load(Ordering::Acquire) {
+            let _ = cuda_ffi::host_unregister(self.mmap.as_ptr() as *mut std::ffi::c_void);
+        }
+    }
+}
+
+/// Free list of a region: the power of two at or below its capacity
+#[inline]
+fn size_class(capacity: u64) -> usize {
+    63u32.saturating_sub(capacity.leading_zeros()) as usize
+}
+
+/// Tag bits of the next value of a free stack head
+#[inline]
+fn next_tag(head: u64) -> u64 {
+    (((head >> 32) as u32).wrapping_add(1) as u64) << 32
+}
+
+/// Path of a tensor pool on hugetlbfs
+fn huge_pool_path(pool_id: u32) -> Option<PathBuf> {
+    #[cfg(target_os = "linux")]
+    {
+        hugetlbfs_dir().map(|dir| dir.join("tensors").join(format!("tensor_pool_{}", pool_id)))
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = pool_id;
+        None
     }
 }
 
@@ -672,8 +1001,34 @@ pub struct TensorPoolStats {
     pub max_slots: usize,
     pub allocated_slots: usize,
     pub total_refcount: u32,
+    /// Bytes carved from the data area so far (allocation front)
     pub used_bytes: usize,
+    /// Bytes available: free regions plus the uncarved tail
     pub free_bytes: usize,
+    pub huge_pages: bool,
+    /// Successful allocations since the pool was created
+    pub total_allocs: u64,
+    /// Allocations that failed for lack of slots or memory
+    pub failed_allocs: u64,
+    /// Bytes held by live tensors
+    pub live_bytes: usize,
+    /// High-water mark of `live_bytes`
+    pub peak_live_bytes: usize,
+    /// Free regions kept by released slots
+    pub free_regions: usize,
+    /// Largest allocation that can currently succeed (aligned bytes)
+    pub largest_free_block: usize,
+    /// 0.0 when all free memory is one block, approaching 1.0 as it splinters
+    pub fragmentation: f64,
+}
+
+/// Outcome of `TensorPool::defragment`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DefragmentReport {
+    /// Free regions merged into a neighbour
+    pub merged_regions: usize,
+    /// Bytes returned to the uncarved tail
+    pub reclaimed_bytes: usize,
 }
 
 // Re-export tensor types for public API
@@ -737,6 +1092,17 @@ mod messages_compat {
         pub const fn is_cuda(&self) -> bool {
             !matches!(self, TensorDevice::Cpu)
         }
+
+        #[inline]
+        pub const fn cuda_device_id(&self) -> Option<u32> {
+            match self {
+                TensorDevice::Cpu => None,
+                TensorDevice::Cuda0 => Some(0),
+                TensorDevice::Cuda1 => Some(1),
+                TensorDevice::Cuda2 => Some(2),
+                TensorDevice::Cuda3 => Some(3),
+            }
+        }
     }
 
     unsafe impl Pod for TensorDevice {}
@@ -894,6 +1260,7 @@ mod tests {
             pool_size: 1024 * 1024, // 1MB for testing
             max_slots: 16,
             slot_alignment: 64,
+            huge_pages: false,
         };
 
         let pool = TensorPool::new(9999, config).expect("Failed to create pool");
@@ -907,12 +1274,30 @@ mod tests {
         std::fs::remove_file(&pool.shm_path).ok();
     }
 
+    #[test]
+    #[cfg(not(feature = "cuda"))]
+    fn test_pin_requires_cuda() {
+        let config = TensorPoolConfig {
+            pool_size: 1024 * 1024,
+            max_slots: 16,
+            slot_alignment: 64,
+            huge_pages: false,
+        };
+
+        let pool = TensorPool::new(9990, config).expect("Failed to create pool");
+        assert!(pool.pin().is_err());
+        assert!(!pool.is_pinned());
+
+        std::fs::remove_file(&pool.shm_path).ok();
+    }
+
     #[test]
     fn test_alloc_and_release() {
         let config = TensorPoolConfig {
             pool_size: 1024 * 1024,
             max_slots: 16,
             slot_alignment: 64,
+            huge_pages: false,
         };
 
         let pool = TensorPool::new(9998, config).expect("Failed to create pool");
@@ -946,6 +1331,7 @@ mod tests {
             pool_size: 1024 * 1024,
             max_slots: 16,
             slot_alignment: 64,
+            huge_pages: false,
         };
 
         let pool = TensorPool::new(9997, config).expect("Failed to create pool");
@@ -969,4 +1355,150 @@ mod tests {
         pool.release(&tensor);
         std::fs::remove_file(&pool.shm_path).ok();
     }
+
+    #[test]
+    fn test_released_regions_are_reused() {
+        let config = TensorPoolConfig {
+            pool_size: 64 * 1024,
+            max_slots: 16,
+            slot_alignment: 64,
+            huge_pages: false,
+        };
+
+        let pool = TensorPool::new(9996, config).expect("Failed to create pool");
+
+        // Far more than the pool holds at once, in constant space
+        for _ in 0..100 {
+            let tensor = pool
+                .alloc(&[16 * 1024], TensorDtype::U8, TensorDevice::Cpu)
+                .expect("Failed to allocate tensor");
+            pool.release(&tensor);
+        }
+
+        let stats = pool.stats();
+        assert_eq!(stats.used_bytes, 16 * 1024);
+        assert_eq!(stats.total_allocs, 100);
+        assert_eq!(stats.failed_allocs, 0);
+        assert_eq!(stats.live_bytes, 0);
+        assert_eq!(stats.peak_live_bytes, 16 * 1024);
+
+        // The smallest fitting region is chosen
+        let big = pool
+            .alloc(&[32 * 1024], TensorDtype::U8, TensorDevice::Cpu)
+            .unwrap();
+        pool.release(&big);
+        let small = pool
+            .alloc(&[1024], TensorDtype::U8, TensorDevice::Cpu)
+            .unwrap();
+        assert_eq!(small.offset, 0);
+        pool.release(&small);
+
+        std::fs::remove_file(&pool.shm_path).ok();
+    }
+
+    #[test]
+    fn test_fragmentation_and_defragment() {
+        let config = TensorPoolConfig {
+            pool_size: 64 * 1024,
+            max_slots: 16,
+            slot_alignment: 64,
+            huge_pages: false,
+        };
+
+        let pool = TensorPool::new(9995, config).expect("Failed to create pool");
+
+        let tensors: Vec<HorusTensor> = (0..4)
+            .map(|_| {
+                pool.alloc(&[8 * 1024], TensorDtype::U8, TensorDevice::Cpu)
+                    .unwrap()
+            })
+            .collect();
+        // Free the 1st and 3rd: two 8 KiB holes beside a 32 KiB tail
+        pool.release(&tensors[0]);
+        pool.release(&tensors[2]);
+
+        let stats = pool.stats();
+        assert_eq!(stats.free_regions, 2);
+        assert_eq!(stats.free_bytes, 48 * 1024);
+        assert_eq!(stats.largest_free_block, 32 * 1024);
+        assert!((stats.fragmentation - 1.0 / 3.0).abs() < 1e-9);
+
+        // Nothing adjacent yet, and the front is held by a live tensor
+        assert_eq!(pool.defragment(), DefragmentReport::default());
+
+        pool.release(&tensors[3]);
+        let report = pool.defragment();
+        assert_eq!(report.merged_regions, 1);
+        assert_eq!(report.reclaimed_bytes, 16 * 1024);
+
+        let stats = pool.stats();
+        assert_eq!(stats.used_bytes, 16 * 1024);
+        assert_eq!(stats.free_regions, 1);
+        assert_eq!(stats.largest_free_block, 48 * 1024);
+
+        pool.release(&tensors[1]);
+        std::fs::remove_file(&pool.shm_path).ok();
+    }
+
+    #[test]
+    fn test_slot_exhaustion_defragments_instead_of_leaking() {
+        let config = TensorPoolConfig {
+            pool_size: 64 * 1024,
+            max_slots: 2,
+            slot_alignment: 64,
+            huge_pages: false,
+        };
+
+        let pool = TensorPool::new(9994, config).expect("Failed to create pool");
+
+        // Both slots keep a 1 KiB region; merging them frees a slot and the tail
+        let a = pool
+            .alloc(&[1024], TensorDtype::U8, TensorDevice::Cpu)
+            .unwrap();
+        let b = pool
+            .alloc(&[1024], TensorDtype::U8, TensorDevice::Cpu)
+            .unwrap();
+        pool.release(&a);
+        pool.release(&b);
+        let big = pool
+            .alloc(&[8 * 1024], TensorDtype::U8, TensorDevice::Cpu)
+            .unwrap();
+        assert_eq!(big.offset, 0);
+        assert_eq!(pool.stats().used_bytes, 8 * 1024);
+        pool.release(&big);
+
+        // A live neighbour blocks the merge: the alloc fails, the region stays
+        let a = pool
+            .alloc(&[1024], TensorDtype::U8, TensorDevice::Cpu)
+            .unwrap();
+        let b = pool
+            .alloc(&[1024], TensorDtype::U8, TensorDevice::Cpu)
+            .unwrap();
+        pool.release(&a);
+        assert!(pool
+            .alloc(&[16 * 1024], TensorDtype::U8, TensorDevice::Cpu)
+            .is_err());
+
+        let stats = pool.stats();
+        assert_eq!(stats.failed_allocs, 1);
+        assert_eq!(stats.free_regions, 1);
+        let again = pool
+            .alloc(&[1024], TensorDtype::U8, TensorDevice::Cpu)
+            .unwrap();
+        assert_eq!(again.offset, a.offset);
+
+        pool.release(&again);
+        pool.release(&b);
+        std::fs::remove_file(&pool.shm_path).ok();
+    }
+
+    #[test]
+    fn test_size_class() {
+        assert_eq!(size_class(0), 0);
+        assert_eq!(size_class(1), 0);
+        assert_eq!(size_class(64), 6);
+        assert_eq!(size_class(127), 6);
+        assert_eq!(size_class(128), 7);
+        assert_eq!(size_class(u64::MAX), 63);
+    }
 }
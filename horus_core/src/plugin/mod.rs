@@ -19,6 +19,7 @@
 //! - [`ProbeResult`]: Hardware detection results
 //! - [`PluginHealth`]: Plugin health monitoring
 //! - [`PluginLoader`]: Runtime plugin loading and management
+//! - [`PluginNodeLoader`]: Nodes loaded from `cdylib` plugins with a C ABI (see [`node_plugin`])
 //!
 //! ## Architecture
 //!
@@ -110,12 +111,17 @@
 
 pub mod driver_loader;
 pub mod loader;
+pub mod node_plugin;
 pub mod traits;
 pub mod types;
 
 // Re-export all public types for convenience
 pub use driver_loader::{DriverLoader, DriverLoaderConfig, DriverMode};
 pub use loader::{DiscoveredPlugin, PluginLoader};
+pub use node_plugin::{
+    NodePluginEntry, NodePluginEntryFn, NodePluginRegistrar, NodePluginVTable, PluginNode,
+    PluginNodeLoader, NODE_PLUGIN_ABI_VERSION, NODE_PLUGIN_ENTRY_SYMBOL,
+};
 pub use traits::{AutoDetectable, DriverPlugin, HotReloadable, PluginEntryFn, PLUGIN_ENTRY_SYMBOL};
 pub use types::{
     BackendHealth, BackendId, BackendInfo, PluginError, PluginFeature, PluginHealth, PluginId,
@@ -0,0 +1,681 @@
+//! Node plugins loaded from shared libraries
+//!
+//! A node plugin is a `cdylib` that exports one registration function with a
+//! C ABI. Only plain C types cross the library boundary (opaque instance
+//! pointers, `extern "C"` function tables and NUL-terminated strings), so a
+//! plugin keeps working with hosts built by a different compiler version and
+//! can be shipped without source, e.g. as a closed-source vendor node.
+//!
+//! # Writing a plugin
+//!
+//! ```rust,ignore
+//! // Cargo.toml: [lib] crate-type = ["cdylib"]
+//! use horus_core::export_node_plugin;
+//!
+//! export_node_plugin!(|params| {
+//!     let port = params["port"].as_str().unwrap_or("/dev/ttyUSB0");
+//!     Ok(vec![Box::new(VendorLidar::open(port)?) as Box<dyn Node>])
+//! });
+//! ```
+//!
+//! # Loading plugins
+//!
+//! List the libraries in `horus.yaml` (paths are relative to the file, then
+//! to the loader's search paths):
+//!
+//! ```yaml
+//! node_plugins:
+//!   - plugins/libvendor_lidar.so
+//!   - path: libplanner.so
+//!     priority: 5
+//!     params:
+//!       max_speed: 1.5
+//! ```
+//!
+//! With the `dynamic-plugins` feature, the scheduler adds their nodes when it
+//! starts running, reading the `horus.yaml` of its working directory. Another
+//! manifest can be loaded explicitly before running:
+//!
+//! ```rust,ignore
+//! let mut scheduler = Scheduler::new();
+//! scheduler.add(Box::new(Controller::new()?), 0, Some(true));
+//! PluginNodeLoader::new().load_into(&mut scheduler, "config/robot.yaml")?;
+//! scheduler.run()
+//! ```
+//!
+//! Plugin nodes tick with their own [`NodeInfo`], created inside the plugin,
+//! since the host's `NodeInfo` is a Rust type without a stable layout.
+
+use super::types::{PluginError, PluginResult};
+use crate::core::{Node, NodeInfo};
+
+use std::ffi::{c_char, c_void, CStr, CString};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+
+/// Version of the node plugin C ABI, bumped on any layout change
+pub const NODE_PLUGIN_ABI_VERSION: u32 = 1;
+
+/// Symbol name of the registration function exported by node plugins
+pub const NODE_PLUGIN_ENTRY_SYMBOL: &str = "horus_node_plugin_register";
+
+/// Registration function exported by a node plugin
+///
+/// Receives the host's registrar and returns the plugin's ABI version. A
+/// plugin registers its nodes only when both versions match.
+pub type NodePluginEntryFn = unsafe extern "C" fn(registrar: *const NodePluginRegistrar) -> u32;
+
+/// Function table of one plugin node
+///
+/// Every function receives the opaque `instance` pointer given to
+/// [`NodePluginRegistrar::register_node`]. `init` and `shutdown` return 0 on
+/// success.
+#[repr(C)]
+pub struct NodePluginVTable {
+    /// NUL-terminated node name, valid until `destroy`
+    pub name: unsafe extern "C" fn(instance: *mut c_void) -> *const c_char,
+    pub init: unsafe extern "C" fn(instance: *mut c_void) -> i32,
+    pub tick: unsafe extern "C" fn(instance: *mut c_void),
+    pub shutdown: unsafe extern "C" fn(instance: *mut c_void) -> i32,
+    /// Preferred tick rate in Hz, or 0 for the scheduler's rate
+    pub rate_hz: unsafe extern "C" fn(instance: *mut c_void) -> f64,
+    /// Free the instance; no other function is called afterwards
+    pub destroy: unsafe extern "C" fn(instance: *mut c_void),
+}
+
+/// Callbacks of the host handed to a plugin's registration function
+#[repr(C)]
+pub struct NodePluginRegistrar {
+    pub abi_version: u32,
+    /// Opaque host state passed back to the callbacks
+    pub context: *mut c_void,
+    /// Parameters of the plugin from `horus.yaml`, as a NUL-terminated JSON object
+    pub params_json: *const c_char,
+    /// Hand a node to the host, which takes ownership of `instance`
+    pub register_node: unsafe extern "C" fn(
+        context: *mut c_void,
+        instance: *mut c_void,
+        vtable: *const NodePluginVTable,
+    ),
+    /// Report why the plugin could not create its nodes
+    pub report_error: unsafe extern "C" fn(context: *mut c_void, message: *const c_char),
+}
+
+/// Export Rust nodes from a `cdylib` as a node plugin
+///
+/// Takes a factory receiving the plugin's parameters (`serde_json::Value`)
+/// and returning `HorusResult<Vec<Box<dyn Node>>>`.
+#[macro_export]
+macro_rules! export_node_plugin {
+    ($factory:expr) => {
+        /// Registration function of this HORUS node plugin
+        ///
+        /// # Safety
+        ///
+        /// Called by the HORUS plugin loader with a valid registrar.
+        #[no_mangle]
+        pub unsafe extern "C" fn horus_node_plugin_register(
+            registrar: *const $crate::plugin::NodePluginRegistrar,
+        ) -> u32 {
+            $crate::plugin::node_plugin::register_nodes(registrar, $factory)
+        }
+    };
+}
+
+/// Plugin side of [`export_node_plugin!`]: create the nodes and register them
+///
+/// # Safety
+///
+/// `registrar` must point to a valid registrar for the duration of the call.
+pub unsafe fn register_nodes<F>(registrar: *const NodePluginRegistrar, factory: F) -> u32
+where
+    F: FnOnce(&serde_json::Value) -> crate::error::HorusResult<Vec<Box<dyn Node>>>,
+{
+    let Some(registrar) = registrar.as_ref() else {
+        return NODE_PLUGIN_ABI_VERSION;
+    };
+    if registrar.abi_version != NODE_PLUGIN_ABI_VERSION {
+        return NODE_PLUGIN_ABI_VERSION;
+    }
+
+    let report = |message: String| {
+        let message = CString::new(message.replace('\0', " ")).unwrap_or_default();
+        (registrar.report_error)(registrar.context, message.as_ptr());
+    };
+
+    let params = if registrar.params_json.is_null() {
+        serde_json::Value::Null
+    } else {
+        let json = CStr::from_ptr(registrar.params_json).to_string_lossy();
+        serde_json::from_str(&json).unwrap_or(serde_json::Value::Null)
+    };
+
+    match catch_unwind(AssertUnwindSafe(|| factory(&params))) {
+        Ok(Ok(nodes)) => {
+            for node in nodes {
+                let exported = Box::new(ExportedNode::new(node));
+                (registrar.register_node)(
+                    registrar.context,
+                    Box::into_raw(exported) as *mut c_void,
+                    &EXPORTED_VTABLE,
+                );
+            }
+        }
+        Ok(Err(e)) => report(e.to_string()),
+        Err(_) => report("node factory panicked".to_string()),
+    }
+    NODE_PLUGIN_ABI_VERSION
+}
+
+/// A node inside a plugin, with the `NodeInfo` it ticks with
+struct ExportedNode {
+    node: Box<dyn Node>,
+    name: CString,
+    info: NodeInfo,
+}
+
+impl ExportedNode {
+    fn new(node: Box<dyn Node>) -> Self {
+        let name = node.name();
+        Self {
+            name: CString::new(name.replace('\0', "")).unwrap_or_default(),
+            info: NodeInfo::new(name.to_string(), false),
+            node,
+        }
+    }
+}
+
+static EXPORTED_VTABLE: NodePluginVTable = NodePluginVTable {
+    name: exported_name,
+    init: exported_init,
+    tick: exported_tick,
+    shutdown: exported_shutdown,
+    rate_hz: exported_rate_hz,
+    destroy: exported_destroy,
+};
+
+unsafe extern "C" fn exported_name(instance: *mut c_void) -> *const c_char {
+    (*(instance as *mut ExportedNode)).name.as_ptr()
+}
+
+unsafe extern "C" fn exported_init(instance: *mut c_void) -> i32 {
+    let exported = &mut *(instance as *mut ExportedNode);
+    match catch_unwind(AssertUnwindSafe(|| exported.node.init(&mut exported.info))) {
+        Ok(Ok(())) => 0,
+        Ok(Err(e)) => {
+            exported
+                .info
+                .log_error(&format!("Plugin node init failed: {}", e));
+            1
+        }
+        Err(_) => -1,
+    }
+}
+
+unsafe extern "C" fn exported_tick(instance: *mut c_void) {
+    let exported = &mut *(instance as *mut ExportedNode);
+    // A panic must not unwind into the host
+    let _ = catch_unwind(AssertUnwindSafe(|| {
+        exported.node.tick(Some(&mut exported.info))
+    }));
+}
+
+unsafe extern "C" fn exported_shutdown(instance: *mut c_void) -> i32 {
+    let exported = &mut *(instance as *mut ExportedNode);
+    match catch_unwind(AssertUnwindSafe(|| {
+        exported.node.shutdown(&mut exported.info)
+    })) {
+        Ok(Ok(())) => 0,
+        Ok(Err(_)) => 1,
+        Err(_) => -1,
+    }
+}
+
+unsafe extern "C" fn exported_rate_hz(instance: *mut c_void) -> f64 {
+    (*(instance as *mut ExportedNode))
+        .node
+        .rate_hz()
+        .unwrap_or(0.0)
+}
+
+unsafe extern "C" fn exported_destroy(instance: *mut c_void) {
+    drop(Box::from_raw(instance as *mut ExportedNode));
+}
+
+/// Host side of a plugin node, driven through its function table
+pub struct PluginNode {
+    instance: *mut c_void,
+    vtable: *const NodePluginVTable,
+    name: &'static str,
+    /// Keeps the shared library mapped while the node exists
+    #[cfg(feature = "dynamic-plugins")]
+    _library: std::sync::Arc<libloading::Library>,
+}
+
+// The plugin owns the instance exclusively and is only called through `&mut self`
+unsafe impl Send for PluginNode {}
+
+impl PluginNode {
+    /// Wrap a node registered by a plugin
+    ///
+    /// # Safety
+    ///
+    /// `instance` and `vtable` must come from a plugin's `register_node` call.
+    #[cfg(feature = "dynamic-plugins")]
+    unsafe fn new(
+        instance: *mut c_void,
+        vtable: *const NodePluginVTable,
+        library: std::sync::Arc<libloading::Library>,
+    ) -> Self {
+        let name = CStr::from_ptr(((*vtable).name)(instance))
+            .to_string_lossy()
+            .into_owned();
+        Self {
+            instance,
+            vtable,
+            // Node::name() is &'static; one small allocation per loaded node
+            name: Box::leak(name.into_boxed_str()),
+            _library: library,
+        }
+    }
+
+    fn vtable(&self) -> &NodePluginVTable {
+        // Safety: the vtable lives in the plugin, which outlives the node
+        unsafe { &*self.vtable }
+    }
+}
+
+impl Node for PluginNode {
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> crate::error::HorusResult<()> {
+        match unsafe { (self.vtable().init)(self.instance) } {
+            0 => {
+                ctx.log_info("Plugin node initialized");
+                Ok(())
+            }
+            code => Err(crate::error::HorusError::InitializationFailed(format!(
+                "Plugin node '{}' failed to initialize (code {})",
+                self.name, code
+            ))),
+        }
+    }
+
+    fn tick(&mut self, _ctx: Option<&mut NodeInfo>) {
+        unsafe { (self.vtable().tick)(self.instance) }
+    }
+
+    fn shutdown(&mut self, ctx: &mut NodeInfo) -> crate::error::HorusResult<()> {
+        match unsafe { (self.vtable().shutdown)(self.instance) } {
+            0 => Ok(()),
+            code => {
+                ctx.log_warning(&format!(
+                    "Plugin node '{}' failed to shut down cleanly (code {})",
+                    self.name, code
+                ));
+                Ok(())
+            }
+        }
+    }
+
+    fn rate_hz(&self) -> Option<f64> {
+        let rate = unsafe { (self.vtable().rate_hz)(self.instance) };
+        (rate > 0.0).then_some(rate)
+    }
+}
+
+impl Drop for PluginNode {
+    fn drop(&mut self) {
+        unsafe { (self.vtable().destroy)(self.instance) }
+    }
+}
+
+/// A node plugin entry of `horus.yaml`
+#[derive(Debug, Clone, PartialEq, serde::Deserialize)]
+#[serde(untagged)]
+pub enum NodePluginEntry {
+    /// `- plugins/libfoo.so`
+    Path(PathBuf),
+    /// `- path: plugins/libfoo.so` with priority and parameters
+    Detailed {
+        path: PathBuf,
+        #[serde(default)]
+        priority: Option<u32>,
+        #[serde(default)]
+        params: serde_json::Value,
+    },
+}
+
+impl NodePluginEntry {
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::Path(path) | Self::Detailed { path, .. } => path,
+        }
+    }
+
+    pub fn priority(&self) -> Option<u32> {
+        match self {
+            Self::Path(_) => None,
+            Self::Detailed { priority, .. } => *priority,
+        }
+    }
+
+    pub fn params(&self) -> serde_json::Value {
+        match self {
+            Self::Path(_) => serde_json::Value::Null,
+            Self::Detailed { params, .. } => params.clone(),
+        }
+    }
+}
+
+/// Read the `node_plugins:` list of a `horus.yaml`
+pub fn manifest_node_plugins(content: &str) -> PluginResult<Vec<NodePluginEntry>> {
+    #[derive(serde::Deserialize)]
+    struct Manifest {
+        #[serde(default)]
+        node_plugins: Vec<NodePluginEntry>,
+    }
+    serde_yaml::from_str::<Manifest>(content)
+        .map(|manifest| manifest.node_plugins)
+        .map_err(|e| PluginError::InvalidPlugin(format!("Invalid node_plugins section: {}", e)))
+}
+
+/// Loads node plugins from shared libraries
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let mut loader = PluginNodeLoader::new();
+/// loader.add_search_path("/opt/vendor/lib");
+/// for node in loader.load("libvendor_lidar.so", &serde_json::Value::Null)? {
+///     scheduler.add(node, 10, Some(true));
+/// }
+/// ```
+pub struct PluginNodeLoader {
+    search_paths: Vec<PathBuf>,
+}
+
+impl PluginNodeLoader {
+    pub fn new() -> Self {
+        Self {
+            search_paths: Vec::new(),
+        }
+    }
+
+    /// Add a directory searched for relative plugin paths
+    pub fn add_search_path(&mut self, path: impl Into<PathBuf>) {
+        let path = path.into();
+        if !self.search_paths.contains(&path) {
+            self.search_paths.push(path);
+        }
+    }
+
+    pub fn search_paths(&self) -> &[PathBuf] {
+        &self.search_paths
+    }
+
+    /// Resolve a plugin path against `base`, then the search paths
+    pub fn resolve(&self, path: &Path, base: Option<&Path>) -> PluginResult<PathBuf> {
+        if path.is_absolute() {
+            return if path.exists() {
+                Ok(path.to_path_buf())
+            } else {
+                Err(PluginError::NotFound(path.display().to_string()))
+            };
+        }
+        base.into_iter()
+            .chain(self.search_paths.iter().map(PathBuf::as_path))
+            .map(|dir| dir.join(path))
+            .find(|candidate| candidate.exists())
+            .ok_or_else(|| PluginError::NotFound(path.display().to_string()))
+    }
+
+    /// Load the plugins listed in a `horus.yaml` and add their nodes to the
+    /// scheduler. Returns the number of nodes added.
+    ///
+    /// The scheduler then skips loading its own `horus.yaml` on startup.
+    pub fn load_into(
+        &mut self,
+        scheduler: &mut crate::scheduling::Scheduler,
+        manifest: impl AsRef<Path>,
+    ) -> PluginResult<usize> {
+        let manifest = manifest.as_ref();
+        let content = std::fs::read_to_string(manifest)
+            .map_err(|e| PluginError::LoadError(format!("{}: {}", manifest.display(), e)))?;
+        let base = manifest.parent().filter(|dir| !dir.as_os_str().is_empty());
+
+        let mut added = 0;
+        for entry in manifest_node_plugins(&content)? {
+            let path = self.resolve(entry.path(), base)?;
+            for node in self.load(&path, &entry.params())? {
+                let priority = entry.priority().unwrap_or_else(|| node.priority());
+                scheduler.add(node, priority, Some(true));
+                added += 1;
+            }
+        }
+        scheduler.node_plugins_loaded = true;
+        Ok(added)
+    }
+
+    /// Load one plugin library and create its nodes
+    #[cfg(feature = "dynamic-plugins")]
+    pub fn load(
+        &self,
+        path: impl AsRef<Path>,
+        params: &serde_json::Value,
+    ) -> PluginResult<Vec<Box<dyn Node>>> {
+        use std::sync::Arc;
+
+        struct Registration {
+            nodes: Vec<(*mut c_void, *const NodePluginVTable)>,
+            error: Option<String>,
+        }
+
+        unsafe extern "C" fn register_node(
+            context: *mut c_void,
+            instance: *mut c_void,
+            vtable: *const NodePluginVTable,
+        ) {
+            (*(context as *mut Registration))
+                .nodes
+                .push((instance, vtable));
+        }
+
+        unsafe extern "C" fn report_error(context: *mut c_void, message: *const c_char) {
+            let message = if message.is_null() {
+                "unknown error".to_string()
+            } else {
+                CStr::from_ptr(message).to_string_lossy().into_owned()
+            };
+            (*(context as *mut Registration)).error = Some(message);
+        }
+
+        let path = path.as_ref();
+        let params = CString::new(params.to_string())
+            .map_err(|e| PluginError::CreationFailed(format!("Invalid params: {}", e)))?;
+        let mut registration = Registration {
+            nodes: Vec::new(),
+            error: None,
+        };
+
+        // Safety: loading a library runs its initializers and trusts that the
+        // entry symbol has the NodePluginEntryFn signature
+        unsafe {
+            let library = Arc::new(libloading::Library::new(path).map_err(|e| {
+                PluginError::LoadError(format!("Failed to load library {:?}: {}", path, e))
+            })?);
+            let entry: libloading::Symbol<NodePluginEntryFn> = library
+                .get(NODE_PLUGIN_ENTRY_SYMBOL.as_bytes())
+                .map_err(|e| {
+                    PluginError::InvalidPlugin(format!(
+                        "{:?} has no '{}' entry point: {}",
+                        path, NODE_PLUGIN_ENTRY_SYMBOL, e
+                    ))
+                })?;
+
+            let registrar = NodePluginRegistrar {
+                abi_version: NODE_PLUGIN_ABI_VERSION,
+                context: &mut registration as *mut Registration as *mut c_void,
+                params_json: params.as_ptr(),
+                register_node,
+                report_error,
+            };
+            let version = entry(&registrar);
+
+            let nodes: Vec<Box<dyn Node>> = registration
+                .nodes
+                .into_iter()
+                .map(|(instance, vtable)| {
+                    Box::new(PluginNode::new(instance, vtable, Arc::clone(&library)))
+                        as Box<dyn Node>
+                })
+                .collect();
+
+            if version != NODE_PLUGIN_ABI_VERSION {
+                return Err(PluginError::VersionMismatch {
+                    required: NODE_PLUGIN_ABI_VERSION.to_string(),
+                    found: version.to_string(),
+                });
+            }
+            if let Some(error) = registration.error {
+                return Err(PluginError::CreationFailed(format!(
+                    "{:?}: {}",
+                    path, error
+                )));
+            }
+            Ok(nodes)
+        }
+    }
+
+    /// Load one plugin library and create its nodes
+    #[cfg(not(feature = "dynamic-plugins"))]
+    pub fn load(
+        &self,
+        path: impl AsRef<Path>,
+        _params: &serde_json::Value,
+    ) -> PluginResult<Vec<Box<dyn Node>>> {
+        Err(PluginError::PlatformNotSupported(format!(
+            "Cannot load {:?}: horus_core was built without the 'dynamic-plugins' feature",
+            path.as_ref()
+        )))
+    }
+}
+
+impl Default for PluginNodeLoader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static TICKS: AtomicUsize = AtomicUsize::new(0);
+
+    struct Counter;
+
+    impl Node for Counter {
+        fn name(&self) -> &'static str {
+            "counter"
+        }
+
+        fn tick(&mut self, _ctx: Option<&mut NodeInfo>) {
+            TICKS.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn rate_hz(&self) -> Option<f64> {
+            Some(20.0)
+        }
+    }
+
+    #[test]
+    fn test_register_nodes_through_c_abi() {
+        let mut registered: Vec<(*mut c_void, *const NodePluginVTable)> = Vec::new();
+
+        unsafe extern "C" fn register_node(
+            context: *mut c_void,
+            instance: *mut c_void,
+            vtable: *const NodePluginVTable,
+        ) {
+            (*(context as *mut Vec<(*mut c_void, *const NodePluginVTable)>))
+                .push((instance, vtable));
+        }
+        unsafe extern "C" fn report_error(_context: *mut c_void, _message: *const c_char) {
+            panic!("unexpected plugin error");
+        }
+
+        let params = CString::new(r#"{"count": 1}"#).unwrap();
+        let registrar = NodePluginRegistrar {
+            abi_version: NODE_PLUGIN_ABI_VERSION,
+            context: &mut registered as *mut _ as *mut c_void,
+            params_json: params.as_ptr(),
+            register_node,
+            report_error,
+        };
+        let version = unsafe {
+            register_nodes(&registrar, |params| {
+                assert_eq!(params["count"], 1);
+                Ok(vec![Box::new(Counter) as Box<dyn Node>])
+            })
+        };
+        assert_eq!(version, NODE_PLUGIN_ABI_VERSION);
+        assert_eq!(registered.len(), 1);
+
+        let (instance, vtable) = registered[0];
+        unsafe {
+            let vtable = &*vtable;
+            assert_eq!(
+                CStr::from_ptr((vtable.name)(instance)).to_str().unwrap(),
+                "counter"
+            );
+            assert_eq!((vtable.rate_hz)(instance), 20.0);
+            assert_eq!((vtable.init)(instance), 0);
+            let before = TICKS.load(Ordering::SeqCst);
+            (vtable.tick)(instance);
+            assert_eq!(TICKS.load(Ordering::SeqCst), before + 1);
+            assert_eq!((vtable.shutdown)(instance), 0);
+            (vtable.destroy)(instance);
+        }
+    }
+
+    #[test]
+    fn test_abi_mismatch_registers_nothing() {
+        unsafe extern "C" fn register_node(
+            _context: *mut c_void,
+            _instance: *mut c_void,
+            _vtable: *const NodePluginVTable,
+        ) {
+            panic!("registered despite ABI mismatch");
+        }
+        unsafe extern "C" fn report_error(_context: *mut c_void, _message: *const c_char) {}
+
+        let registrar = NodePluginRegistrar {
+            abi_version: NODE_PLUGIN_ABI_VERSION + 1,
+            context: std::ptr::null_mut(),
+            params_json: std::ptr::null(),
+            register_node,
+            report_error,
+        };
+        let version =
+            unsafe { register_nodes(&registrar, |_| Ok(vec![Box::new(Counter) as Box<dyn Node>])) };
+        assert_eq!(version, NODE_PLUGIN_ABI_VERSION);
+    }
+
+    #[test]
+    fn test_manifest_node_plugins() {
+        let entries = manifest_node_plugins(
+            "name: robot\nnode_plugins:\n  - plugins/liba.so\n  - path: libb.so\n    priority: 5\n    params:\n      max_speed: 1.5\n",
+        )
+        .unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].path(), Path::new("plugins/liba.so"));
+        assert_eq!(entries[0].priority(), None);
+        assert_eq!(entries[1].priority(), Some(5));
+        assert_eq!(entries[1].params()["max_speed"], 1.5);
+        assert!(manifest_node_plugins("name: robot\n").unwrap().is_empty());
+    }
+}
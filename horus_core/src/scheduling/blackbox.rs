@@ -2,15 +2,26 @@
 //!
 //! Records all significant events in a circular buffer that persists
 //! across crashes for debugging and incident analysis.
-
-use log::info;
+//!
+//! With [`BlackBox::with_mmap_persistence`] every event is also written to a
+//! fixed-size ring in a memory-mapped file. Writes land in the page cache, so
+//! the last events survive a crash of the process and can be decoded later
+//! with [`BlackBox::read_persisted`] (`horus blackbox dump`).
+//!
+//! A ring file is locked by the process writing it. Schedulers use
+//! [`BlackBox::with_default_ring`], which keeps up to
+//! [`MAX_RINGS_PER_SCHEDULER`] rings per scheduler name and continues the
+//! first one no running process holds, so respawned processes reuse the
+//! same files instead of adding new ones.
+
+use log::{error, info};
+use memmap2::MmapMut;
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 use std::fs::{self, File, OpenOptions};
 use std::io::{BufWriter, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
-use std::time::{SystemTime, UNIX_EPOCH};
 
 /// Black box event types
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -60,6 +71,20 @@ pub enum BlackBoxEvent {
     JITCompilation { name: String, success: bool },
     /// Emergency stop
     EmergencyStop { reason: String },
+    /// Watchdog escalation step executed
+    WatchdogEscalation {
+        name: String,
+        step: usize,
+        action: String,
+        reason: String,
+    },
+    /// Node lifecycle change (crash, restart)
+    NodeStateChange {
+        name: String,
+        previous_state: String,
+        new_state: String,
+        restart_count: u8,
+    },
     /// Custom event
     Custom { category: String, message: String },
 }
@@ -75,6 +100,156 @@ pub struct BlackBoxRecord {
     pub event: BlackBoxEvent,
 }
 
+// Memory-mapped ring layout:
+// - header (64 bytes): magic u64, version u32, slot_size u32, slot_count u64, next_seq u64
+// - slots (RING_SLOT_SIZE each): seq u64 (0 = empty), len u32, JSON-encoded BlackBoxRecord
+const RING_MAGIC: u64 = u64::from_le_bytes(*b"HORUSBBX");
+const RING_VERSION: u32 = 1;
+const RING_HEADER_SIZE: usize = 64;
+const RING_SLOT_SIZE: usize = 1024;
+const RING_SLOT_HEADER_SIZE: usize = 12;
+const RING_MAX_PAYLOAD: usize = RING_SLOT_SIZE - RING_SLOT_HEADER_SIZE;
+
+/// Rings kept per scheduler name, i.e. processes with the same scheduler
+/// name that can record at the same time
+pub const MAX_RINGS_PER_SCHEDULER: usize = 4;
+
+/// Crash-safe ring of fixed-size slots in a memory-mapped file
+struct MmapRing {
+    mmap: MmapMut,
+    slot_count: u64,
+    next_seq: u64,
+    /// Holds the ring's lock until the ring is dropped
+    _file: File,
+}
+
+impl MmapRing {
+    /// Open an existing ring (continuing after its last event) or create a new one
+    ///
+    /// Fails with `WouldBlock` if another process is writing the ring.
+    fn open(path: &Path, capacity_bytes: usize) -> std::io::Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let slot_count = (capacity_bytes / RING_SLOT_SIZE).max(16) as u64;
+        let total_size = RING_HEADER_SIZE + slot_count as usize * RING_SLOT_SIZE;
+
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        lock_ring(&file)?;
+
+        // Keep the previous run's events if the geometry matches
+        let existing = file.metadata()?.len() == total_size as u64;
+        if !existing {
+            file.set_len(0)?;
+            file.set_len(total_size as u64)?;
+        }
+
+        let mut mmap = unsafe { MmapMut::map_mut(&file)? };
+
+        let valid = existing
+            && read_u64(&mmap, 0) == RING_MAGIC
+            && read_u32(&mmap, 8) == RING_VERSION
+            && read_u32(&mmap, 12) as usize == RING_SLOT_SIZE
+            && read_u64(&mmap, 16) == slot_count;
+
+        let next_seq = if valid {
+            read_u64(&mmap, 24)
+        } else {
+            mmap.fill(0);
+            mmap[0..8].copy_from_slice(&RING_MAGIC.to_le_bytes());
+            mmap[8..12].copy_from_slice(&RING_VERSION.to_le_bytes());
+            mmap[12..16].copy_from_slice(&(RING_SLOT_SIZE as u32).to_le_bytes());
+            mmap[16..24].copy_from_slice(&slot_count.to_le_bytes());
+            0
+        };
+
+        Ok(Self {
+            mmap,
+            slot_count,
+            next_seq,
+            _file: file,
+        })
+    }
+
+    /// Append a record, overwriting the oldest slot when full
+    fn push(&mut self, record: &BlackBoxRecord) {
+        let mut payload = match serde_json::to_vec(record) {
+            Ok(data) => data,
+            Err(e) => {
+                error!("[BLACKBOX] Failed to encode event: {}", e);
+                return;
+            }
+        };
+
+        if payload.len() > RING_MAX_PAYLOAD {
+            let placeholder = BlackBoxRecord {
+                timestamp_us: record.timestamp_us,
+                tick: record.tick,
+                event: BlackBoxEvent::Custom {
+                    category: "blackbox".to_string(),
+                    message: format!("Event too large for ring slot ({} bytes)", payload.len()),
+                },
+            };
+            payload = serde_json::to_vec(&placeholder).unwrap_or_default();
+        }
+
+        self.next_seq += 1;
+        let seq = self.next_seq;
+        let offset = RING_HEADER_SIZE + ((seq - 1) % self.slot_count) as usize * RING_SLOT_SIZE;
+        let data_start = offset + RING_SLOT_HEADER_SIZE;
+
+        // Invalidate the slot first so a crash mid-write never leaves a torn record
+        self.mmap[offset..offset + 8].copy_from_slice(&0u64.to_le_bytes());
+        self.mmap[data_start..data_start + payload.len()].copy_from_slice(&payload);
+        self.mmap[offset + 8..offset + 12].copy_from_slice(&(payload.len() as u32).to_le_bytes());
+        self.mmap[offset..offset + 8].copy_from_slice(&seq.to_le_bytes());
+        self.mmap[24..32].copy_from_slice(&seq.to_le_bytes());
+    }
+
+    /// Flush dirty pages to disk (needed to survive power loss, not process crashes)
+    fn flush(&self) -> std::io::Result<()> {
+        self.mmap.flush()
+    }
+}
+
+/// Take the ring's writer lock without waiting
+///
+/// The lock is released when the file is closed, including when the
+/// process crashes, so rings of dead processes are free to continue.
+fn lock_ring(file: &File) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::io::AsRawFd;
+
+        if unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) } != 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+    }
+
+    #[cfg(not(unix))]
+    let _ = file;
+
+    Ok(())
+}
+
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    let mut buf = [0u8; 8];
+    buf.copy_from_slice(&data[offset..offset + 8]);
+    u64::from_le_bytes(buf)
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    let mut buf = [0u8; 4];
+    buf.copy_from_slice(&data[offset..offset + 4]);
+    u32::from_le_bytes(buf)
+}
+
 /// Black box recorder with circular buffer
 pub struct BlackBox {
     /// Circular buffer of events
@@ -87,6 +262,8 @@ pub struct BlackBox {
     persist_path: Option<PathBuf>,
     /// Write-ahead log file (for crash recovery)
     wal_file: Option<BufWriter<File>>,
+    /// Memory-mapped event ring (survives process crashes)
+    mmap_ring: Option<MmapRing>,
     /// Whether recording is enabled
     enabled: bool,
 }
@@ -103,6 +280,7 @@ impl BlackBox {
             tick_counter: 0,
             persist_path: None,
             wal_file: None,
+            mmap_ring: None,
             enabled: max_size_mb > 0,
         }
     }
@@ -127,6 +305,114 @@ impl BlackBox {
         self
     }
 
+    /// Mirror events into a memory-mapped ring file of `capacity_mb` megabytes
+    ///
+    /// An existing ring with the same size is continued, so events from a run
+    /// that crashed stay available until they are overwritten.
+    pub fn with_mmap_persistence(mut self, path: PathBuf, capacity_mb: usize) -> Self {
+        match MmapRing::open(&path, capacity_mb.max(1) * 1024 * 1024) {
+            Ok(ring) => {
+                info!(
+                    "[BLACKBOX] Persisting events to {:?} ({} slots)",
+                    path, ring.slot_count
+                );
+                self.mmap_ring = Some(ring);
+            }
+            Err(e) => error!("[BLACKBOX] Failed to open ring file {:?}: {}", path, e),
+        }
+        self
+    }
+
+    /// Mirror events into the first free ring of `scheduler_name`
+    ///
+    /// Tries `default_ring_path(scheduler_name, 0)`, then the following
+    /// slots up to [`MAX_RINGS_PER_SCHEDULER`], skipping rings locked by
+    /// running processes (e.g. several schedulers with the default name
+    /// under `horus launch`). A restarted process continues the ring its
+    /// crashed predecessor wrote. If every ring is in use, events are only
+    /// kept in memory.
+    pub fn with_default_ring(self, scheduler_name: &str, capacity_mb: usize) -> Self {
+        self.with_ring_in(&blackbox_dir(), scheduler_name, capacity_mb)
+    }
+
+    fn with_ring_in(mut self, dir: &Path, scheduler_name: &str, capacity_mb: usize) -> Self {
+        for slot in 0..MAX_RINGS_PER_SCHEDULER {
+            let path = dir.join(ring_file_name(scheduler_name, slot));
+            match MmapRing::open(&path, capacity_mb.max(1) * 1024 * 1024) {
+                Ok(ring) => {
+                    info!(
+                        "[BLACKBOX] Persisting events to {:?} ({} slots)",
+                        path, ring.slot_count
+                    );
+                    self.mmap_ring = Some(ring);
+                    return self;
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => continue,
+                Err(e) => {
+                    error!("[BLACKBOX] Failed to open ring file {:?}: {}", path, e);
+                    return self;
+                }
+            }
+        }
+        error!(
+            "[BLACKBOX] All {} rings of '{}' are in use, events are not persisted",
+            MAX_RINGS_PER_SCHEDULER, scheduler_name
+        );
+        self
+    }
+
+    /// Ring file of `scheduler_name` in slot `slot`
+    ///
+    /// `~/.horus/blackbox/<scheduler_name>.bbx` for the first slot,
+    /// `<scheduler_name>.<slot>.bbx` for the others.
+    pub fn default_ring_path(scheduler_name: &str, slot: usize) -> PathBuf {
+        blackbox_dir().join(ring_file_name(scheduler_name, slot))
+    }
+
+    /// Decode all events from a ring file written by `with_mmap_persistence`
+    ///
+    /// Works on rings left behind by crashed processes. Records are returned
+    /// oldest first; empty or partially written slots are skipped.
+    pub fn read_persisted(path: &Path) -> std::io::Result<Vec<BlackBoxRecord>> {
+        let data = fs::read(path)?;
+        let invalid = |msg: &str| std::io::Error::new(std::io::ErrorKind::InvalidData, msg);
+
+        if data.len() < RING_HEADER_SIZE || read_u64(&data, 0) != RING_MAGIC {
+            return Err(invalid("not a black box ring file"));
+        }
+        if read_u32(&data, 8) != RING_VERSION {
+            return Err(invalid("unsupported black box ring version"));
+        }
+        let slot_size = read_u32(&data, 12) as usize;
+        let slot_count = usize::try_from(read_u64(&data, 16))
+            .map_err(|_| invalid("corrupt black box ring header"))?;
+        let ring_size = slot_count
+            .checked_mul(slot_size)
+            .and_then(|slots| slots.checked_add(RING_HEADER_SIZE))
+            .ok_or_else(|| invalid("corrupt black box ring header"))?;
+        if slot_size <= RING_SLOT_HEADER_SIZE || data.len() < ring_size {
+            return Err(invalid("truncated black box ring file"));
+        }
+
+        let mut records: Vec<(u64, BlackBoxRecord)> = (0..slot_count)
+            .filter_map(|i| {
+                let offset = RING_HEADER_SIZE + i * slot_size;
+                let seq = read_u64(&data, offset);
+                let len = read_u32(&data, offset + 8) as usize;
+                if seq == 0 || len > slot_size - RING_SLOT_HEADER_SIZE {
+                    return None;
+                }
+                let start = offset + RING_SLOT_HEADER_SIZE;
+                serde_json::from_slice(&data[start..start + len])
+                    .ok()
+                    .map(|record| (seq, record))
+            })
+            .collect();
+
+        records.sort_by_key(|(seq, _)| *seq);
+        Ok(records.into_iter().map(|(_, record)| record).collect())
+    }
+
     /// Record an event
     pub fn record(&mut self, event: BlackBoxEvent) {
         if !self.enabled {
@@ -134,10 +420,7 @@ impl BlackBox {
         }
 
         let record = BlackBoxRecord {
-            timestamp_us: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_micros() as u64,
+            timestamp_us: crate::core::clock::now_us(),
             tick: self.tick_counter,
             event,
         };
@@ -150,6 +433,10 @@ impl BlackBox {
             }
         }
 
+        if let Some(ref mut ring) = self.mmap_ring {
+            ring.push(&record);
+        }
+
         // Add to circular buffer
         if self.buffer.len() >= self.max_size {
             self.buffer.pop_front();
@@ -194,6 +481,8 @@ impl BlackBox {
                     BlackBoxEvent::LearningComplete { .. } => "LearningComplete",
                     BlackBoxEvent::JITCompilation { .. } => "JITCompilation",
                     BlackBoxEvent::EmergencyStop { .. } => "EmergencyStop",
+                    BlackBoxEvent::WatchdogEscalation { .. } => "WatchdogEscalation",
+                    BlackBoxEvent::NodeStateChange { .. } => "NodeStateChange",
                     BlackBoxEvent::Custom { .. } => "Custom",
                 };
                 type_name == event_type
@@ -213,6 +502,8 @@ impl BlackBox {
                         | BlackBoxEvent::DeadlineMiss { .. }
                         | BlackBoxEvent::WCETViolation { .. }
                         | BlackBoxEvent::EmergencyStop { .. }
+                        | BlackBoxEvent::WatchdogEscalation { .. }
+                        | BlackBoxEvent::NodeStateChange { .. }
                         | BlackBoxEvent::CircuitBreakerChange { .. }
                 )
             })
@@ -222,6 +513,9 @@ impl BlackBox {
 
     /// Save buffer to disk
     pub fn save(&self) -> std::io::Result<()> {
+        if let Some(ref ring) = self.mmap_ring {
+            ring.flush()?;
+        }
         if let Some(ref path) = self.persist_path {
             let file = File::create(path)?;
             let writer = BufWriter::new(file);
@@ -305,6 +599,32 @@ impl Default for BlackBox {
     }
 }
 
+/// Directory holding black box ring files (`~/.horus/blackbox`)
+pub fn blackbox_dir() -> PathBuf {
+    dirs::home_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join(".horus")
+        .join("blackbox")
+}
+
+/// File name of a scheduler's ring in `slot`, with the name made path-safe
+fn ring_file_name(scheduler_name: &str, slot: usize) -> String {
+    let name: String = scheduler_name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    match slot {
+        0 => format!("{}.bbx", name),
+        n => format!("{}.{}.bbx", name, n),
+    }
+}
+
 /// Thread-safe black box wrapper
 pub type SharedBlackBox = Arc<Mutex<BlackBox>>;
 
@@ -378,4 +698,97 @@ mod tests {
         let anomalies = bb.get_anomalies();
         assert_eq!(anomalies.len(), 2);
     }
+
+    #[test]
+    fn test_blackbox_mmap_ring_wraps_and_survives_reopen() {
+        let path =
+            std::env::temp_dir().join(format!("horus_blackbox_test_{}.bbx", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        {
+            let mut bb = BlackBox::new(1).with_mmap_persistence(path.clone(), 1);
+            let slots = bb.mmap_ring.as_ref().unwrap().slot_count;
+            for i in 0..slots + 5 {
+                bb.record(BlackBoxEvent::Custom {
+                    category: "test".to_string(),
+                    message: format!("event {}", i),
+                });
+            }
+            // Dropped without save(), like a crashed process
+        }
+
+        let records = BlackBox::read_persisted(&path).unwrap();
+        assert_eq!(records.len(), 1024);
+        match &records[0].event {
+            BlackBoxEvent::Custom { message, .. } => assert_eq!(message, "event 5"),
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        // Reopening continues after the last event
+        let mut bb = BlackBox::new(1).with_mmap_persistence(path.clone(), 1);
+        bb.record(BlackBoxEvent::EmergencyStop {
+            reason: "test".to_string(),
+        });
+        let records = BlackBox::read_persisted(&path).unwrap();
+        assert!(matches!(
+            records.last().unwrap().event,
+            BlackBoxEvent::EmergencyStop { .. }
+        ));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_default_ring_path() {
+        let path = BlackBox::default_ring_path("Default Scheduler", 0);
+        assert_eq!(path.parent().unwrap(), blackbox_dir());
+        assert_eq!(path.file_name().unwrap(), "Default_Scheduler.bbx");
+        assert_eq!(ring_file_name("../main", 2), "___main.2.bbx");
+    }
+
+    #[test]
+    fn test_default_rings_are_reused_and_bounded() {
+        let dir = std::env::temp_dir().join(format!("horus_blackbox_rings_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        // Concurrent writers each get their own ring, up to the limit
+        let boxes: Vec<BlackBox> = (0..MAX_RINGS_PER_SCHEDULER + 1)
+            .map(|_| BlackBox::new(1).with_ring_in(&dir, "main", 1))
+            .collect();
+        assert!(boxes[..MAX_RINGS_PER_SCHEDULER]
+            .iter()
+            .all(|bb| bb.mmap_ring.is_some()));
+        assert!(boxes[MAX_RINGS_PER_SCHEDULER].mmap_ring.is_none());
+        drop(boxes);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), MAX_RINGS_PER_SCHEDULER);
+
+        // A restarted process continues the first ring
+        let mut bb = BlackBox::new(1).with_ring_in(&dir, "main", 1);
+        bb.record(BlackBoxEvent::EmergencyStop {
+            reason: "restart".to_string(),
+        });
+        drop(bb);
+        let records = BlackBox::read_persisted(&dir.join("main.bbx")).unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), MAX_RINGS_PER_SCHEDULER);
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_read_persisted_rejects_corrupt_geometry() {
+        let path =
+            std::env::temp_dir().join(format!("horus_blackbox_corrupt_{}.bbx", std::process::id()));
+        let mut data = vec![0u8; RING_HEADER_SIZE];
+        data[0..8].copy_from_slice(&RING_MAGIC.to_le_bytes());
+        data[8..12].copy_from_slice(&RING_VERSION.to_le_bytes());
+        data[12..16].copy_from_slice(&(RING_SLOT_SIZE as u32).to_le_bytes());
+        data[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+        fs::write(&path, &data).unwrap();
+
+        let err = BlackBox::read_persisted(&path).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+
+        let _ = fs::remove_file(&path);
+    }
 }
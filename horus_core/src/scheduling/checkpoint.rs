@@ -7,7 +7,7 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::{self, File};
 use std::io::{BufReader, BufWriter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant, SystemTime};
 
 /// Checkpoint manager for periodic state persistence
@@ -69,6 +69,25 @@ pub struct CheckpointMetadata {
     pub uptime_secs: f64,
 }
 
+impl Checkpoint {
+    /// Write the checkpoint to `path` (bincode)
+    pub fn save_to(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let file = File::create(path)?;
+        let writer = BufWriter::new(file);
+        bincode::serialize_into(writer, self).map_err(std::io::Error::other)
+    }
+
+    /// Read a checkpoint written by `save_to`
+    pub fn load_from(path: &Path) -> std::io::Result<Self> {
+        let file = File::open(path)?;
+        let reader = BufReader::new(file);
+        bincode::deserialize_from(reader).map_err(std::io::Error::other)
+    }
+}
+
 impl CheckpointManager {
     /// Create a new checkpoint manager
     pub fn new(checkpoint_dir: PathBuf, interval_ms: u64) -> Self {
@@ -116,9 +135,7 @@ impl CheckpointManager {
         let filename = format!("checkpoint_{:08}.bin", checkpoint.id);
         let path = self.checkpoint_dir.join(&filename);
 
-        let file = File::create(&path)?;
-        let writer = BufWriter::new(file);
-        bincode::serialize_into(writer, checkpoint).map_err(std::io::Error::other)?;
+        checkpoint.save_to(&path)?;
 
         println!(
             "[CHECKPOINT] Saved checkpoint {} ({} nodes)",
@@ -160,10 +177,7 @@ impl CheckpointManager {
             return Ok(None);
         }
 
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
-        let checkpoint: Checkpoint =
-            bincode::deserialize_from(reader).map_err(std::io::Error::other)?;
+        let checkpoint = Checkpoint::load_from(path)?;
 
         println!(
             "[CHECKPOINT] Loaded checkpoint {} from {:?}",
@@ -1707,6 +1707,20 @@ impl CloudUploader {
         Ok(())
     }
 
+    /// Upload a small in-memory object (status documents, summaries)
+    ///
+    /// Stored at `<prefix>/<cloud_key>`. Returns the backend's object
+    /// location.
+    pub fn upload_bytes(&self, data: &[u8], cloud_key: &str) -> Result<String> {
+        let cloud_path = format!("{}/{}", self.config.prefix, cloud_key);
+        let temp_path =
+            std::env::temp_dir().join(format!("horus_upload_{}", uuid::Uuid::new_v4()));
+        std::fs::write(&temp_path, data)?;
+        let result = self.backend.upload_file(&temp_path, &cloud_path);
+        let _ = std::fs::remove_file(&temp_path);
+        result
+    }
+
     /// List recordings
     pub fn list_recordings(&self) -> Result<Vec<String>> {
         self.backend.list(&self.config.prefix)
@@ -2015,6 +2029,19 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_upload_bytes() -> Result<()> {
+        let temp_dir = env::temp_dir().join(format!("horus_cloud_test_{}", uuid::Uuid::new_v4()));
+        let uploader = CloudUploader::new(CloudConfig::local(&temp_dir, "fleet"))?;
+
+        uploader.upload_bytes(b"{\"seq\":1}", "rover-7/1.json")?;
+        let stored = std::fs::read(temp_dir.join("fleet/rover-7/1.json"))?;
+        assert_eq!(stored, b"{\"seq\":1}");
+
+        std::fs::remove_dir_all(&temp_dir)?;
+        Ok(())
+    }
+
     #[test]
     fn test_multipart_upload() -> Result<()> {
         let temp_dir =
@@ -58,6 +58,8 @@ pub enum TimeSyncSource {
     PTP,
     /// Custom external source
     External,
+    /// Simulated time from the simulator's clock topic (`use_sim_time`)
+    SimTime,
 }
 
 /// Fault tolerance configuration
@@ -134,6 +136,8 @@ pub struct MonitoringConfig {
     pub metrics_interval_ms: u64,
     /// Telemetry endpoint URL
     pub telemetry_endpoint: Option<String>,
+    /// Health probe endpoint for process supervisors (`http://ADDR:PORT`, `systemd` or a directory)
+    pub health_probe_endpoint: Option<String>,
     /// Enable black box recording
     pub black_box_enabled: bool,
     /// Black box buffer size in MB
@@ -444,6 +448,7 @@ impl SchedulerConfig {
                 tracing_enabled: false,
                 metrics_interval_ms: 1000,
                 telemetry_endpoint: None,
+                health_probe_endpoint: None,
                 black_box_enabled: false,
                 black_box_size_mb: 0,
             },
@@ -509,6 +514,7 @@ impl SchedulerConfig {
                 tracing_enabled: true,    // Full audit trail
                 metrics_interval_ms: 100,
                 telemetry_endpoint: None,
+                health_probe_endpoint: None,
                 black_box_enabled: true,
                 black_box_size_mb: 100,
             },
@@ -565,6 +571,7 @@ impl SchedulerConfig {
                 tracing_enabled: true,    // Full audit trail
                 metrics_interval_ms: 10,  // High-frequency monitoring
                 telemetry_endpoint: Some("local".to_string()),
+                health_probe_endpoint: None,
                 black_box_enabled: true, // Always record
                 black_box_size_mb: 1024, // Large buffer
             },
@@ -621,6 +628,7 @@ impl SchedulerConfig {
                 tracing_enabled: false,
                 metrics_interval_ms: 10000, // Minimal monitoring
                 telemetry_endpoint: None,
+                health_probe_endpoint: None,
                 black_box_enabled: false,
                 black_box_size_mb: 0,
             },
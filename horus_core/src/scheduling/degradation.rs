@@ -0,0 +1,532 @@
+//! Graceful degradation profiles
+//!
+//! A `DegradationPolicy` lists profiles from full capability to the most
+//! restricted mode. Each profile disables nodes (or named groups of nodes) and
+//! sets runtime parameters, and is entered when one of its triggers matches
+//! the aggregated system health:
+//!
+//! ```rust,ignore
+//! use horus_core::scheduling::{DegradationPolicy, DegradationProfile, DegradationTrigger};
+//!
+//! let policy = DegradationPolicy::new()
+//!     .group("autonomy", &["planner", "perception"])
+//!     .profile(DegradationProfile::new("full").set_param("max_speed", 1.0))
+//!     .profile(
+//!         DegradationProfile::new("reduced_speed")
+//!             .set_param("max_speed", 0.3)
+//!             .when(DegradationTrigger::SafetyState(SafetyState::Degraded))
+//!             .when(DegradationTrigger::NodeHealth {
+//!                 node: "perception".to_string(),
+//!                 health: HealthStatus::Warning,
+//!             }),
+//!     )
+//!     .profile(
+//!         DegradationProfile::new("teleop_only")
+//!             .disable("autonomy")
+//!             .when(DegradationTrigger::WatchdogExpired("planner".to_string())),
+//!     )
+//!     .profile(
+//!         DegradationProfile::new("safe_stop")
+//!             .disable("autonomy")
+//!             .disable("teleop")
+//!             .set_param("max_speed", 0.0)
+//!             .when(DegradationTrigger::SafetyState(SafetyState::SafeMode)),
+//!     );
+//!
+//! let scheduler = Scheduler::new().with_degradation_policy(policy);
+//! ```
+//!
+//! The most restricted profile whose trigger matches is entered immediately.
+//! Moving back to a less restricted profile waits until health has stayed
+//! good for the recovery delay, so a flapping sensor doesn't toggle the
+//! robot between modes. Parameters a profile does not set keep their value,
+//! so the first profile usually sets every parameter the others change.
+//!
+//! Node health comes from each node's recent tick window, so a node that
+//! stops failing recovers. The scheduler evaluates the policy every
+//! evaluation interval (the health probe's default cadence) rather than on
+//! every cycle.
+
+use super::safety_monitor::SafetyState;
+use crate::core::HealthStatus;
+use crate::error::{HorusError, HorusResult};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Default time health must stay good before moving to a less restricted profile
+pub const DEFAULT_RECOVERY_DELAY: Duration = Duration::from_secs(5);
+
+/// Default time between two evaluations by the scheduler
+pub const DEFAULT_EVALUATION_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Condition that makes a profile apply
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DegradationTrigger {
+    /// The safety monitor is in this state or a more severe one
+    SafetyState(SafetyState),
+    /// A node's health is this status or worse
+    NodeHealth { node: String, health: HealthStatus },
+    /// At least `count` nodes have this health status or worse
+    UnhealthyNodes { health: HealthStatus, count: usize },
+    /// The watchdog of a node has expired
+    WatchdogExpired(String),
+}
+
+impl DegradationTrigger {
+    /// Whether the trigger matches `health`
+    pub fn matches(&self, health: &SystemHealth) -> bool {
+        match self {
+            DegradationTrigger::SafetyState(state) => {
+                safety_rank(health.safety_state) >= safety_rank(*state)
+            }
+            DegradationTrigger::NodeHealth { node, health: min } => health
+                .node_health
+                .get(node)
+                .is_some_and(|status| health_rank(*status) >= health_rank(*min)),
+            DegradationTrigger::UnhealthyNodes { health: min, count } => {
+                let unhealthy = health
+                    .node_health
+                    .values()
+                    .filter(|status| health_rank(**status) >= health_rank(*min))
+                    .count();
+                unhealthy >= *count
+            }
+            DegradationTrigger::WatchdogExpired(node) => health.expired_watchdogs.contains(node),
+        }
+    }
+}
+
+impl std::fmt::Display for DegradationTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DegradationTrigger::SafetyState(state) => write!(f, "safety state {:?}", state),
+            DegradationTrigger::NodeHealth { node, health } => {
+                write!(f, "node '{}' health {}", node, health.as_str())
+            }
+            DegradationTrigger::UnhealthyNodes { health, count } => {
+                write!(f, "{} node(s) with health {}", count, health.as_str())
+            }
+            DegradationTrigger::WatchdogExpired(node) => {
+                write!(f, "watchdog of '{}' expired", node)
+            }
+        }
+    }
+}
+
+/// Severity order of safety states (Normal lowest)
+fn safety_rank(state: SafetyState) -> u8 {
+    match state {
+        SafetyState::Normal => 0,
+        SafetyState::Degraded => 1,
+        SafetyState::SafeMode => 2,
+        SafetyState::EmergencyStop => 3,
+    }
+}
+
+/// Severity order of health statuses; Unknown (no data yet) counts as healthy
+fn health_rank(status: HealthStatus) -> u8 {
+    match status {
+        HealthStatus::Unknown => 0,
+        status => status as u8,
+    }
+}
+
+/// One operating mode: nodes to disable and parameters to set
+#[derive(Debug, Clone)]
+pub struct DegradationProfile {
+    name: String,
+    disabled: Vec<String>,
+    params: Vec<(String, Value)>,
+    triggers: Vec<DegradationTrigger>,
+}
+
+impl DegradationProfile {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            disabled: Vec::new(),
+            params: Vec::new(),
+            triggers: Vec::new(),
+        }
+    }
+
+    /// Disable a node, or every node of a group defined on the policy
+    pub fn disable(mut self, node_or_group: &str) -> Self {
+        self.disabled.push(node_or_group.to_string());
+        self
+    }
+
+    /// Set a runtime parameter on every node while the profile is active
+    pub fn set_param<T: Serialize>(mut self, key: &str, value: T) -> Self {
+        let value = serde_json::to_value(value).unwrap_or(Value::Null);
+        self.params.push((key.to_string(), value));
+        self
+    }
+
+    /// Enter the profile when `trigger` matches
+    pub fn when(mut self, trigger: DegradationTrigger) -> Self {
+        self.triggers.push(trigger);
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Disabled node and group names as given
+    pub fn disabled(&self) -> &[String] {
+        &self.disabled
+    }
+
+    pub fn params(&self) -> &[(String, Value)] {
+        &self.params
+    }
+
+    pub fn triggers(&self) -> &[DegradationTrigger] {
+        &self.triggers
+    }
+}
+
+/// Aggregated health evaluated by a `DegradationPolicy`
+#[derive(Debug, Clone)]
+pub struct SystemHealth {
+    pub safety_state: SafetyState,
+    pub node_health: HashMap<String, HealthStatus>,
+    pub expired_watchdogs: Vec<String>,
+}
+
+impl Default for SystemHealth {
+    fn default() -> Self {
+        Self {
+            safety_state: SafetyState::Normal,
+            node_health: HashMap::new(),
+            expired_watchdogs: Vec::new(),
+        }
+    }
+}
+
+/// Change of the active profile
+#[derive(Debug, Clone, PartialEq)]
+pub struct DegradationTransition {
+    pub from: String,
+    pub to: String,
+    pub reason: String,
+    /// Nodes disabled by the new profile (groups resolved)
+    pub disabled_nodes: Vec<String>,
+    /// Parameters set by the new profile
+    pub params: Vec<(String, Value)>,
+}
+
+/// Ordered degradation profiles and the logic moving between them
+///
+/// See the [module documentation](self).
+#[derive(Debug, Clone)]
+pub struct DegradationPolicy {
+    profiles: Vec<DegradationProfile>,
+    groups: HashMap<String, Vec<String>>,
+    recovery_delay: Duration,
+    evaluation_interval: Duration,
+    last_evaluation: Option<Instant>,
+    current: usize,
+    forced: bool,
+    recovering_since: Option<Instant>,
+}
+
+impl Default for DegradationPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DegradationPolicy {
+    pub fn new() -> Self {
+        Self {
+            profiles: Vec::new(),
+            groups: HashMap::new(),
+            recovery_delay: DEFAULT_RECOVERY_DELAY,
+            evaluation_interval: DEFAULT_EVALUATION_INTERVAL,
+            last_evaluation: None,
+            current: 0,
+            forced: false,
+            recovering_since: None,
+        }
+    }
+
+    /// Name a group of nodes that profiles can disable together
+    pub fn group(mut self, name: &str, nodes: &[&str]) -> Self {
+        self.groups.insert(
+            name.to_string(),
+            nodes.iter().map(|n| n.to_string()).collect(),
+        );
+        self
+    }
+
+    /// Add the next, more restricted profile (the first one is the normal mode)
+    pub fn profile(mut self, profile: DegradationProfile) -> Self {
+        self.profiles.push(profile);
+        self
+    }
+
+    /// Time health must stay good before moving to a less restricted profile
+    pub fn with_recovery_delay(mut self, delay: Duration) -> Self {
+        self.recovery_delay = delay;
+        self
+    }
+
+    /// Time between two evaluations by the scheduler
+    pub fn with_evaluation_interval(mut self, interval: Duration) -> Self {
+        self.evaluation_interval = interval;
+        self
+    }
+
+    /// Whether the evaluation interval elapsed since the last `evaluate`
+    pub fn should_evaluate(&self, now: Instant) -> bool {
+        self.last_evaluation
+            .is_none_or(|last| now.duration_since(last) >= self.evaluation_interval)
+    }
+
+    pub fn profiles(&self) -> &[DegradationProfile] {
+        &self.profiles
+    }
+
+    /// Active profile, None if the policy has no profiles
+    pub fn current(&self) -> Option<&DegradationProfile> {
+        self.profiles.get(self.current)
+    }
+
+    /// Whether a profile was forced with `force`
+    pub fn is_forced(&self) -> bool {
+        self.forced
+    }
+
+    /// Nodes disabled by the profile at `index`, with groups resolved
+    pub fn disabled_nodes(&self, index: usize) -> Vec<String> {
+        let mut nodes = Vec::new();
+        if let Some(profile) = self.profiles.get(index) {
+            for name in &profile.disabled {
+                match self.groups.get(name) {
+                    Some(members) => nodes.extend(members.iter().cloned()),
+                    None => nodes.push(name.clone()),
+                }
+            }
+        }
+        nodes.sort();
+        nodes.dedup();
+        nodes
+    }
+
+    /// The profile that should be active for `health`, entering it now when
+    /// more restricted or after the recovery delay when less restricted
+    pub fn evaluate(
+        &mut self,
+        health: &SystemHealth,
+        now: Instant,
+    ) -> Option<DegradationTransition> {
+        self.last_evaluation = Some(now);
+        if self.forced || self.profiles.is_empty() {
+            return None;
+        }
+
+        let target = self
+            .profiles
+            .iter()
+            .enumerate()
+            .skip(1)
+            .rev()
+            .find_map(|(i, profile)| {
+                profile
+                    .triggers
+                    .iter()
+                    .find(|trigger| trigger.matches(health))
+                    .map(|trigger| (i, trigger.to_string()))
+            });
+
+        match target {
+            Some((index, reason)) if index > self.current => {
+                self.recovering_since = None;
+                Some(self.transition(index, reason))
+            }
+            Some((index, _)) if index == self.current => {
+                self.recovering_since = None;
+                None
+            }
+            target => {
+                if self.current == 0 {
+                    return None;
+                }
+                let index = target.map_or(0, |(index, _)| index);
+                let since = *self.recovering_since.get_or_insert(now);
+                if now.duration_since(since) < self.recovery_delay {
+                    return None;
+                }
+                self.recovering_since = None;
+                let reason = format!("recovered for {:?}", self.recovery_delay);
+                Some(self.transition(index, reason))
+            }
+        }
+    }
+
+    /// Enter a profile by name regardless of health until `release` is called
+    pub fn force(&mut self, name: &str) -> HorusResult<DegradationTransition> {
+        let index = self
+            .profiles
+            .iter()
+            .position(|p| p.name == name)
+            .ok_or_else(|| HorusError::Config(format!("Unknown degradation profile '{}'", name)))?;
+        self.forced = true;
+        self.recovering_since = None;
+        Ok(self.transition(index, "forced".to_string()))
+    }
+
+    /// Return to health-driven profile selection
+    pub fn release(&mut self) {
+        self.forced = false;
+    }
+
+    fn transition(&mut self, index: usize, reason: String) -> DegradationTransition {
+        let from = self.current().map(|p| p.name.clone()).unwrap_or_default();
+        self.current = index;
+        DegradationTransition {
+            from,
+            to: self.profiles[index].name.clone(),
+            reason,
+            disabled_nodes: self.disabled_nodes(index),
+            params: self.profiles[index].params.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> DegradationPolicy {
+        DegradationPolicy::new()
+            .group("autonomy", &["planner", "perception"])
+            .profile(DegradationProfile::new("full").set_param("max_speed", 1.0))
+            .profile(
+                DegradationProfile::new("reduced_speed")
+                    .set_param("max_speed", 0.3)
+                    .when(DegradationTrigger::UnhealthyNodes {
+                        health: HealthStatus::Warning,
+                        count: 2,
+                    }),
+            )
+            .profile(
+                DegradationProfile::new("teleop_only")
+                    .disable("autonomy")
+                    .when(DegradationTrigger::WatchdogExpired("planner".to_string())),
+            )
+            .profile(
+                DegradationProfile::new("safe_stop")
+                    .disable("autonomy")
+                    .disable("teleop")
+                    .set_param("max_speed", 0.0)
+                    .when(DegradationTrigger::SafetyState(SafetyState::SafeMode)),
+            )
+            .with_recovery_delay(Duration::from_secs(2))
+    }
+
+    #[test]
+    fn test_degradation_escalates_immediately() {
+        let mut policy = policy();
+        let now = Instant::now();
+        let mut health = SystemHealth::default();
+        assert_eq!(policy.evaluate(&health, now), None);
+
+        health
+            .node_health
+            .insert("camera".to_string(), HealthStatus::Warning);
+        health
+            .node_health
+            .insert("lidar".to_string(), HealthStatus::Error);
+        health
+            .node_health
+            .insert("imu".to_string(), HealthStatus::Unknown);
+        let transition = policy.evaluate(&health, now).unwrap();
+        assert_eq!(transition.from, "full");
+        assert_eq!(transition.to, "reduced_speed");
+        assert_eq!(
+            transition.params,
+            vec![("max_speed".to_string(), serde_json::json!(0.3))]
+        );
+
+        // The most restricted matching profile wins
+        health.safety_state = SafetyState::EmergencyStop;
+        health.expired_watchdogs.push("planner".to_string());
+        let transition = policy.evaluate(&health, now).unwrap();
+        assert_eq!(transition.to, "safe_stop");
+        assert_eq!(
+            transition.disabled_nodes,
+            vec!["perception", "planner", "teleop"]
+        );
+        assert_eq!(policy.evaluate(&health, now), None);
+    }
+
+    #[test]
+    fn test_degradation_recovers_after_delay() {
+        let mut policy = policy();
+        let start = Instant::now();
+        let mut health = SystemHealth {
+            expired_watchdogs: vec!["planner".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(policy.evaluate(&health, start).unwrap().to, "teleop_only");
+
+        health.expired_watchdogs.clear();
+        assert_eq!(policy.evaluate(&health, start), None);
+        assert_eq!(
+            policy.evaluate(&health, start + Duration::from_secs(1)),
+            None
+        );
+
+        // Trouble during the delay restarts it
+        health.expired_watchdogs.push("planner".to_string());
+        assert_eq!(
+            policy.evaluate(&health, start + Duration::from_millis(1500)),
+            None
+        );
+        health.expired_watchdogs.clear();
+        assert_eq!(
+            policy.evaluate(&health, start + Duration::from_secs(3)),
+            None
+        );
+
+        let transition = policy
+            .evaluate(&health, start + Duration::from_secs(5))
+            .unwrap();
+        assert_eq!(transition.from, "teleop_only");
+        assert_eq!(transition.to, "full");
+        assert!(transition.disabled_nodes.is_empty());
+    }
+
+    #[test]
+    fn test_degradation_evaluation_interval() {
+        let mut policy = policy().with_evaluation_interval(Duration::from_millis(500));
+        let start = Instant::now();
+        assert!(policy.should_evaluate(start));
+
+        policy.evaluate(&SystemHealth::default(), start);
+        assert!(!policy.should_evaluate(start + Duration::from_millis(100)));
+        assert!(policy.should_evaluate(start + Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn test_degradation_force_and_release() {
+        let mut policy = policy();
+        assert!(policy.force("missing").is_err());
+
+        let transition = policy.force("teleop_only").unwrap();
+        assert_eq!(transition.to, "teleop_only");
+        assert!(policy.is_forced());
+        assert_eq!(
+            policy.evaluate(&SystemHealth::default(), Instant::now()),
+            None
+        );
+
+        policy.release();
+        assert_eq!(policy.current().unwrap().name(), "teleop_only");
+    }
+}
@@ -278,10 +278,7 @@ impl ProcessRecorder {
             process_id: self.process_id.clone(),
             sequence: self.sequence.fetch_add(1, Ordering::Relaxed),
             vector_clock: self.clock.read().clone(),
-            timestamp_ns: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_nanos() as u64,
+            timestamp_ns: crate::core::clock::now_ns(),
             event_type,
             data: data.to_vec(),
         };
@@ -7,9 +7,12 @@
 //!
 //! Uses shared memory for IPC with minimal latency overhead.
 
-use crate::core::node::{Node, NodeInfo};
+use crate::core::node::{Node, NodeInfo, NodeState};
+use crate::core::LogSummary;
 use crate::error::{HorusError, HorusResult};
 use crate::memory::platform::shm_base_dir;
+use crate::scheduling::checkpoint::Checkpoint;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::{self, File, OpenOptions};
 use std::io::{Read, Seek, SeekFrom, Write};
@@ -36,6 +39,7 @@ pub enum IpcCommand {
     Tick = 2,
     Shutdown = 3,
     HealthCheck = 4,
+    Restart = 5,
 }
 
 impl From<u8> for IpcCommand {
@@ -45,6 +49,7 @@ impl From<u8> for IpcCommand {
             2 => IpcCommand::Tick,
             3 => IpcCommand::Shutdown,
             4 => IpcCommand::HealthCheck,
+            5 => IpcCommand::Restart,
             _ => IpcCommand::None,
         }
     }
@@ -108,7 +113,7 @@ impl IpcRegion {
         let dir = shm_base_dir().join("isolated");
         fs::create_dir_all(&dir)?;
 
-        let path = dir.join(format!("{}.ipc", node_name));
+        let path = dir.join(format!("{}.ipc", node_file_stem(node_name)));
 
         // Create and initialize the file
         let mut file = OpenOptions::new()
@@ -131,7 +136,7 @@ impl IpcRegion {
     pub fn open(node_name: &str) -> HorusResult<Self> {
         let path = shm_base_dir()
             .join("isolated")
-            .join(format!("{}.ipc", node_name));
+            .join(format!("{}.ipc", node_file_stem(node_name)));
 
         let file = OpenOptions::new()
             .read(true)
@@ -340,6 +345,114 @@ impl Drop for IpcRegion {
     }
 }
 
+// ============================================================================
+// Crash Recovery Helpers
+// ============================================================================
+
+/// File name stem of a node's IPC region and checkpoint
+///
+/// `_` is doubled and characters other than letters, digits and `-` become
+/// `_XX` (hex), so names with `/` or `..` stay inside the directory and
+/// distinct nodes never share a file.
+fn node_file_stem(node_name: &str) -> String {
+    let mut stem = String::with_capacity(node_name.len());
+    for byte in node_name.bytes() {
+        match byte {
+            b'_' => stem.push_str("__"),
+            b'-' => stem.push('-'),
+            _ if byte.is_ascii_alphanumeric() => stem.push(byte as char),
+            _ => stem.push_str(&format!("_{:02X}", byte)),
+        }
+    }
+    stem
+}
+
+/// Path of the per-node checkpoint file shared by executor and runner
+fn checkpoint_path(node_name: &str) -> PathBuf {
+    shm_base_dir()
+        .join("isolated")
+        .join(format!("{}.ckpt", node_file_stem(node_name)))
+}
+
+/// Save `Node::checkpoint_state()` next to the IPC region (no-op if unsupported)
+fn save_node_checkpoint(node: &dyn Node, node_name: &str) {
+    if !node.supports_checkpointing() {
+        return;
+    }
+    if let Some(state) = node.checkpoint_state() {
+        let path = checkpoint_path(node_name);
+        let tmp = path.with_extension("ckpt.tmp");
+        // Write then rename so a crash mid-write never leaves a torn checkpoint
+        if fs::write(&tmp, &state).is_ok() {
+            let _ = fs::rename(&tmp, &path);
+        }
+    }
+}
+
+/// Extract a readable message from a panic payload
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        format!("Node panicked: {}", s)
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        format!("Node panicked: {}", s)
+    } else {
+        "Node panicked with unknown error".to_string()
+    }
+}
+
+/// Re-initialize a crashed node, restoring its last checkpoint first if requested
+fn restart_node(
+    node: &mut dyn Node,
+    ctx: &mut NodeInfo,
+    node_name: &str,
+    restore: bool,
+) -> Result<(), String> {
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        ctx.reset_for_restart();
+
+        if restore && node.supports_checkpointing() {
+            if let Ok(state) = fs::read(checkpoint_path(node_name)) {
+                node.restore_state(&state)
+                    .map_err(|e| format!("Checkpoint restore failed: {}", e))?;
+            }
+        }
+
+        node.init(ctx)
+            .map_err(|e| format!("Re-initialization failed: {}", e))
+    }));
+
+    match result {
+        Ok(inner) => inner,
+        Err(payload) => Err(panic_message(payload)),
+    }
+}
+
+/// Node lifecycle change reported by the isolated executor
+///
+/// The scheduler publishes these on [`NODE_STATE_TOPIC`] and records them to
+/// the black box.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeStateEvent {
+    pub node_name: String,
+    pub previous_state: String,
+    pub new_state: String,
+    pub restart_count: u8,
+    /// Unix time in milliseconds
+    pub timestamp_ms: u64,
+}
+
+impl LogSummary for NodeStateEvent {
+    fn log_summary(&self) -> String {
+        format!(
+            "{}: {} -> {} (restarts: {})",
+            self.node_name, self.previous_state, self.new_state, self.restart_count
+        )
+    }
+}
+
+/// Topic on which the scheduler publishes `NodeStateEvent`s
+pub const NODE_STATE_TOPIC: &str = "horus.node_state";
+
 // ============================================================================
 // Isolated Node Handle
 // ============================================================================
@@ -350,6 +463,8 @@ pub struct IsolatedNodeHandle {
     pub node_name: String,
     /// Child process
     child: Option<Child>,
+    /// Runner thread (in-process mode only)
+    runner_thread: Option<JoinHandle<()>>,
     /// IPC region for communication
     ipc: IpcRegion,
     /// Current sequence number
@@ -364,6 +479,12 @@ pub struct IsolatedNodeHandle {
     last_error_count: u64,
     /// Time of last successful health check
     last_health_check: Instant,
+    /// Current lifecycle state
+    state: NodeState,
+    /// When the next restart attempt is due (set after a crash)
+    next_restart_at: Option<Instant>,
+    /// Reason of the last crash detected by `tick()` (panic, timeout, exit)
+    crash_reason: Option<String>,
 }
 
 /// Configuration for isolated nodes
@@ -371,8 +492,14 @@ pub struct IsolatedNodeHandle {
 pub struct IsolatedNodeConfig {
     /// Maximum number of restart attempts
     pub max_restarts: u8,
-    /// Delay between restart attempts
+    /// Delay before the first restart; doubles with every further restart
     pub restart_delay: Duration,
+    /// Upper bound for the exponential restart backoff
+    pub max_restart_delay: Duration,
+    /// Restore node state from its last checkpoint when restarting
+    pub restore_checkpoint: bool,
+    /// Save a node checkpoint every N successful ticks (0 = never)
+    pub checkpoint_interval_ticks: u64,
     /// Timeout for waiting on responses
     pub response_timeout: Duration,
     /// Heartbeat timeout (consider dead if no heartbeat for this long)
@@ -388,6 +515,9 @@ impl Default for IsolatedNodeConfig {
         Self {
             max_restarts: 3,
             restart_delay: Duration::from_millis(500),
+            max_restart_delay: Duration::from_secs(30),
+            restore_checkpoint: true,
+            checkpoint_interval_ticks: 100,
             response_timeout: Duration::from_millis(5000),
             heartbeat_timeout: Duration::from_secs(10),
             runner_binary: None,
@@ -404,6 +534,7 @@ impl IsolatedNodeHandle {
         Ok(Self {
             node_name,
             child: None,
+            runner_thread: None,
             ipc,
             sequence: 0,
             config,
@@ -411,6 +542,9 @@ impl IsolatedNodeHandle {
             last_tick_count: 0,
             last_error_count: 0,
             last_health_check: Instant::now(),
+            state: NodeState::Running,
+            next_restart_at: None,
+            crash_reason: None,
         })
     }
 
@@ -442,6 +576,20 @@ impl IsolatedNodeHandle {
             cmd.env(key, value);
         }
 
+        // Crash recovery settings for run_isolated_node()
+        cmd.env(
+            "HORUS_ISOLATED_CHECKPOINT_TICKS",
+            self.config.checkpoint_interval_ticks.to_string(),
+        );
+        cmd.env(
+            "HORUS_ISOLATED_RESTORE",
+            if self.config.restore_checkpoint {
+                "1"
+            } else {
+                "0"
+            },
+        );
+
         // Also pass session ID if set
         if let Ok(session_id) = std::env::var("HORUS_SESSION_ID") {
             cmd.env("HORUS_SESSION_ID", session_id);
@@ -489,11 +637,25 @@ impl IsolatedNodeHandle {
         )))
     }
 
+    /// Check whether the runner (process or thread) is still alive
+    fn runner_alive(&mut self) -> bool {
+        if let Some(ref thread) = self.runner_thread {
+            return !thread.is_finished();
+        }
+        match self.child {
+            Some(ref mut child) => matches!(child.try_wait(), Ok(None)),
+            None => false,
+        }
+    }
+
     /// Send a command and wait for response
     fn send_command(&mut self, cmd: IpcCommand) -> HorusResult<IpcStatus> {
         self.sequence += 1;
-        self.ipc.write_sequence(self.sequence)?;
+        // Mark busy and publish the command before the sequence number, so the
+        // runner never pairs a new sequence with a stale command or status
+        self.ipc.write_status(IpcStatus::Processing)?;
         self.ipc.write_command(cmd)?;
+        self.ipc.write_sequence(self.sequence)?;
         self.ipc.sync()?;
 
         let deadline = Instant::now() + self.config.response_timeout;
@@ -507,26 +669,19 @@ impl IsolatedNodeHandle {
                 return Ok(status);
             }
 
-            // Check if child process is still alive
-            if let Some(ref mut child) = self.child {
-                match child.try_wait() {
-                    Ok(Some(exit_status)) => {
-                        // Child exited
-                        return Err(HorusError::Node {
-                            node: self.node_name.clone(),
-                            message: format!("Process exited with status: {:?}", exit_status),
-                        });
-                    }
-                    Ok(None) => {
-                        // Still running, continue waiting
-                    }
-                    Err(e) => {
-                        return Err(HorusError::Node {
-                            node: self.node_name.clone(),
-                            message: format!("Failed to check process status: {}", e),
-                        });
+            // Check if the runner is still alive
+            if !self.runner_alive() {
+                let message = match self.child.as_mut().map(|c| c.try_wait()) {
+                    Some(Ok(Some(exit_status))) => {
+                        format!("Process exited with status: {:?}", exit_status)
                     }
-                }
+                    Some(Err(e)) => format!("Failed to check process status: {}", e),
+                    _ => "Runner exited".to_string(),
+                };
+                return Err(HorusError::Node {
+                    node: self.node_name.clone(),
+                    message,
+                });
             }
 
             thread::sleep(Duration::from_micros(100));
@@ -539,8 +694,16 @@ impl IsolatedNodeHandle {
     }
 
     /// Trigger a tick
+    ///
+    /// A panic, timeout or runner exit is recorded as a crash (see `take_crash`).
     pub fn tick(&mut self) -> HorusResult<Duration> {
-        let status = self.send_command(IpcCommand::Tick)?;
+        let status = match self.send_command(IpcCommand::Tick) {
+            Ok(status) => status,
+            Err(e) => {
+                self.crash_reason = Some(e.to_string());
+                return Err(e);
+            }
+        };
 
         match status {
             IpcStatus::Success => {
@@ -549,9 +712,12 @@ impl IsolatedNodeHandle {
                 self.last_health_check = Instant::now();
                 Ok(Duration::from_nanos(duration_ns))
             }
-            IpcStatus::Error => {
+            IpcStatus::Error | IpcStatus::Crashed => {
                 self.last_error_count = self.ipc.read_error_count()?;
                 let msg = self.ipc.read_error_message()?;
+                if status == IpcStatus::Crashed {
+                    self.crash_reason = Some(msg.clone());
+                }
                 Err(HorusError::Node {
                     node: self.node_name.clone(),
                     message: msg,
@@ -564,16 +730,15 @@ impl IsolatedNodeHandle {
         }
     }
 
+    /// Take the reason of the last crash detected by `tick()`
+    pub fn take_crash(&mut self) -> Option<String> {
+        self.crash_reason.take()
+    }
+
     /// Check if the node is healthy
     pub fn is_healthy(&mut self) -> bool {
-        // Check if child process is still running
-        if let Some(ref mut child) = self.child {
-            match child.try_wait() {
-                Ok(Some(_)) => return false, // Process exited
-                Ok(None) => {}               // Still running
-                Err(_) => return false,
-            }
-        } else {
+        // Check if the runner process/thread is still running
+        if !self.runner_alive() {
             return false;
         }
 
@@ -583,7 +748,6 @@ impl IsolatedNodeHandle {
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap()
                 .as_secs();
-
             if now.saturating_sub(last_hb) > self.config.heartbeat_timeout.as_secs() {
                 return false;
             }
@@ -592,7 +756,20 @@ impl IsolatedNodeHandle {
         true
     }
 
+    /// Delay before the next restart: `restart_delay * 2^restarts`, capped
+    pub fn restart_backoff(&self) -> Duration {
+        let factor = 1u32 << u32::from(self.restart_count.min(16));
+        self.config
+            .restart_delay
+            .saturating_mul(factor)
+            .min(self.config.max_restart_delay)
+    }
+
     /// Attempt to restart the node
+    ///
+    /// A live runner re-initializes the node in place (restoring its last
+    /// checkpoint if enabled). A dead child process is respawned first; a dead
+    /// in-process runner cannot be recovered.
     pub fn restart(&mut self, node_factory_name: &str) -> HorusResult<()> {
         if self.restart_count >= self.config.max_restarts {
             return Err(HorusError::Node {
@@ -604,24 +781,61 @@ impl IsolatedNodeHandle {
             });
         }
 
-        // Kill existing process if any
-        self.kill();
-
-        // Wait before restart
-        thread::sleep(self.config.restart_delay);
-
         self.restart_count += 1;
         self.ipc.write_restart_count(self.restart_count)?;
 
-        // Reset IPC state
-        self.ipc.write_status(IpcStatus::Idle)?;
-        self.ipc.write_command(IpcCommand::None)?;
-        self.ipc.sync()?;
+        if !self.runner_alive() {
+            if self.runner_thread.is_some() {
+                return Err(HorusError::Node {
+                    node: self.node_name.clone(),
+                    message: "In-process runner exited, node cannot be recreated".to_string(),
+                });
+            }
 
-        // Spawn new process
-        self.spawn_process(node_factory_name)?;
+            // Kill existing process if any
+            self.kill();
 
-        Ok(())
+            // Reset IPC state
+            self.sequence = 0;
+            self.ipc.write_sequence(0)?;
+            self.ipc.write_status(IpcStatus::Idle)?;
+            self.ipc.write_command(IpcCommand::None)?;
+            self.ipc.sync()?;
+
+            // Spawn new process
+            self.spawn_process(node_factory_name)?;
+        }
+
+        match self.send_command(IpcCommand::Restart)? {
+            IpcStatus::Success => {
+                self.ipc.update_heartbeat()?;
+                Ok(())
+            }
+            _ => Err(HorusError::Node {
+                node: self.node_name.clone(),
+                message: self.ipc.read_error_message()?,
+            }),
+        }
+    }
+
+    /// Record a state transition and return the corresponding event
+    fn transition(&mut self, new_state: NodeState) -> NodeStateEvent {
+        let previous = std::mem::replace(&mut self.state, new_state);
+        NodeStateEvent {
+            node_name: self.node_name.clone(),
+            previous_state: previous.to_string(),
+            new_state: self.state.to_string(),
+            restart_count: self.restart_count,
+            timestamp_ms: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u64,
+        }
+    }
+
+    /// Get the current lifecycle state
+    pub fn state(&self) -> &NodeState {
+        &self.state
     }
 
     /// Kill the child process
@@ -634,18 +848,22 @@ impl IsolatedNodeHandle {
 
     /// Shutdown the node gracefully
     pub fn shutdown(&mut self) -> HorusResult<()> {
-        if self.child.is_some() {
+        if self.runner_alive() {
             // Send shutdown command
             let _ = self.send_command(IpcCommand::Shutdown);
+        }
 
-            // Wait for process to exit
-            if let Some(mut child) = self.child.take() {
-                let _ = child.wait();
-            }
+        // Wait for process/thread to exit
+        if let Some(mut child) = self.child.take() {
+            let _ = child.wait();
+        }
+        if let Some(thread) = self.runner_thread.take() {
+            let _ = thread.join();
         }
 
-        // Clean up IPC file
+        // Clean up IPC and checkpoint files
         let _ = fs::remove_file(self.ipc.path());
+        let _ = fs::remove_file(checkpoint_path(&self.node_name));
 
         Ok(())
     }
@@ -694,12 +912,30 @@ pub struct IsolatedResult {
     pub restart_attempted: bool,
 }
 
+impl IsolatedResult {
+    fn failed(node_name: &str, error: String, restart_attempted: bool) -> Self {
+        Self {
+            node_name: node_name.to_string(),
+            duration: Duration::ZERO,
+            success: false,
+            error: Some(error),
+            restart_attempted,
+        }
+    }
+}
+
 /// Isolated executor for process-isolated node execution
 ///
 /// Runs high-failure-rate or untrusted nodes in separate processes
 /// for fault isolation. If a node crashes, only that process dies
 /// and can be automatically restarted.
 ///
+/// A crashed node (panic, hang or runner exit) is marked `Crashed` and
+/// restarted with exponential backoff (`restart_delay`, doubled per restart,
+/// capped at `max_restart_delay`) until `max_restarts` is reached. Nodes that
+/// support checkpointing are restored from their last checkpoint first. Every
+/// state change is queued as a `NodeStateEvent` (see `drain_state_events`).
+///
 /// # Example
 /// ```ignore
 /// use horus_core::scheduling::executors::isolated::{IsolatedExecutor, IsolatedNodeConfig};
@@ -726,6 +962,8 @@ pub struct IsolatedExecutor {
     watchdog_handle: Option<JoinHandle<()>>,
     /// Last tick count for progress tracking
     tick_count: AtomicU64,
+    /// State changes not yet collected by the scheduler
+    state_events: Vec<NodeStateEvent>,
 }
 
 impl IsolatedExecutor {
@@ -740,6 +978,7 @@ impl IsolatedExecutor {
             running,
             watchdog_handle: None,
             tick_count: AtomicU64::new(0),
+            state_events: Vec::new(),
         })
     }
 
@@ -777,8 +1016,14 @@ impl IsolatedExecutor {
         // Create handle with IPC region
         let mut handle = IsolatedNodeHandle::new(node_name.clone(), self.default_config.clone())?;
 
-        // Spawn the child process
+        // Spawn the child process and initialize the node
         handle.spawn_process(factory_name)?;
+        if handle.send_command(IpcCommand::Init)? != IpcStatus::Success {
+            return Err(HorusError::Node {
+                node: node_name,
+                message: handle.ipc.read_error_message()?,
+            });
+        }
 
         self.handles.insert(node_name.clone(), handle);
         self.factory_names
@@ -796,14 +1041,23 @@ impl IsolatedExecutor {
     ) -> HorusResult<()> {
         let node_name = node.name().to_string();
 
-        // Create IPC region for this node
-        let ipc = IpcRegion::create(&node_name)?;
+        // Create the handle first; it owns (and creates) the IPC region
+        let mut node_handle =
+            IsolatedNodeHandle::new(node_name.clone(), self.default_config.clone())?;
 
-        // Create the in-process isolated runner
-        let mut runner = InProcessIsolatedRunner::new(node, context, ipc, self.running.clone());
+        // Create the in-process isolated runner on the same region
+        let ipc = IpcRegion::open(&node_name)?;
+        let mut runner = InProcessIsolatedRunner::new(
+            node,
+            context,
+            ipc,
+            self.running.clone(),
+            self.default_config.checkpoint_interval_ticks,
+            self.default_config.restore_checkpoint,
+        );
 
         // Spawn the runner thread
-        let handle = thread::Builder::new()
+        let thread = thread::Builder::new()
             .name(format!("horus-isolated-{}", node_name))
             .spawn(move || {
                 runner.run();
@@ -815,20 +1069,14 @@ impl IsolatedExecutor {
                 ))
             })?;
 
-        // Create a handle that communicates via IPC
-        let node_handle = IsolatedNodeHandle::new(node_name.clone(), self.default_config.clone())?;
-
-        // Mark as "spawned" even though it's in-process
-        // We'll communicate via the IPC region
+        // Communicate with the thread via the IPC region, like a child process
+        node_handle.runner_thread = Some(thread);
+        node_handle.wait_for_ready()?;
 
         self.handles.insert(node_name.clone(), node_handle);
         self.factory_names
             .insert(node_name, factory_name.to_string());
 
-        // Store the thread handle (we could use this for join later)
-        // For now, we let it run detached
-        drop(handle);
-
         Ok(())
     }
 
@@ -868,50 +1116,65 @@ impl IsolatedExecutor {
         results
     }
 
-    /// Tick a single node
+    /// Tick a single node, restarting it first if a restart is due
     fn tick_node(&mut self, node_name: &str) -> IsolatedResult {
-        let factory_name = self.factory_names.get(node_name).cloned();
+        let factory_name = self
+            .factory_names
+            .get(node_name)
+            .cloned()
+            .unwrap_or_else(|| node_name.to_string());
 
         let handle = match self.handles.get_mut(node_name) {
             Some(h) => h,
-            None => {
-                return IsolatedResult {
-                    node_name: node_name.to_string(),
-                    duration: Duration::ZERO,
-                    success: false,
-                    error: Some("Node not found".to_string()),
-                    restart_attempted: false,
-                };
-            }
+            None => return IsolatedResult::failed(node_name, "Node not found".to_string(), false),
         };
 
-        // Check health first
-        if !handle.is_healthy() {
-            // Attempt restart
-            if let Some(ref factory) = factory_name {
-                match handle.restart(factory) {
-                    Ok(_) => {
-                        return IsolatedResult {
-                            node_name: node_name.to_string(),
-                            duration: Duration::ZERO,
-                            success: false,
-                            error: Some("Node was unhealthy, restarted".to_string()),
-                            restart_attempted: true,
-                        };
-                    }
-                    Err(e) => {
-                        return IsolatedResult {
-                            node_name: node_name.to_string(),
-                            duration: Duration::ZERO,
-                            success: false,
-                            error: Some(format!("Failed to restart: {}", e)),
-                            restart_attempted: true,
-                        };
-                    }
+        let mut restart_attempted = false;
+
+        // Crashed node: wait out the backoff, then restart
+        if let Some(restart_at) = handle.next_restart_at {
+            let now = Instant::now();
+            if now < restart_at {
+                return IsolatedResult::failed(
+                    node_name,
+                    format!("Restart pending in {:?}", restart_at - now),
+                    false,
+                );
+            }
+
+            handle.next_restart_at = None;
+            restart_attempted = true;
+            match handle.restart(&factory_name) {
+                Ok(()) => {
+                    println!(
+                        "[Isolated] Node '{}' restarted (attempt {}/{})",
+                        node_name, handle.restart_count, handle.config.max_restarts
+                    );
+                    self.state_events
+                        .push(handle.transition(NodeState::Running));
+                }
+                Err(e) => {
+                    let reason = format!("Restart failed: {}", e);
+                    return record_crash(handle, &mut self.state_events, reason, true);
                 }
             }
         }
 
+        // Crashed and out of restarts: leave the node down
+        if let NodeState::Crashed(ref reason) = handle.state {
+            return IsolatedResult::failed(
+                node_name,
+                format!("Node is down: {}", reason),
+                restart_attempted,
+            );
+        }
+
+        // Check health first
+        if !handle.is_healthy() {
+            let reason = "Runner not responding".to_string();
+            return record_crash(handle, &mut self.state_events, reason, restart_attempted);
+        }
+
         // Execute tick
         match handle.tick() {
             Ok(duration) => IsolatedResult {
@@ -919,25 +1182,54 @@ impl IsolatedExecutor {
                 duration,
                 success: true,
                 error: None,
-                restart_attempted: false,
+                restart_attempted,
             },
-            Err(e) => {
-                // Check if we should restart
-                let restart_attempted = if let Some(ref factory) = factory_name {
-                    handle.restart(factory).is_ok()
-                } else {
-                    false
-                };
+            Err(e) => match handle.take_crash() {
+                Some(reason) => {
+                    record_crash(handle, &mut self.state_events, reason, restart_attempted)
+                }
+                None => IsolatedResult::failed(node_name, e.to_string(), restart_attempted),
+            },
+        }
+    }
+
+    /// Take the node state changes recorded since the last call
+    pub fn drain_state_events(&mut self) -> Vec<NodeStateEvent> {
+        std::mem::take(&mut self.state_events)
+    }
+
+    /// Latest checkpointed state of each isolated node that supports checkpointing
+    pub fn checkpoint_states(&self) -> HashMap<String, Vec<u8>> {
+        self.handles
+            .keys()
+            .filter_map(|name| {
+                fs::read(checkpoint_path(name))
+                    .ok()
+                    .map(|state| (name.clone(), state))
+            })
+            .collect()
+    }
 
-                IsolatedResult {
-                    node_name: node_name.to_string(),
-                    duration: Duration::ZERO,
-                    success: false,
-                    error: Some(e.to_string()),
-                    restart_attempted,
+    /// Use the node states of a `Checkpoint` for subsequent crash restarts
+    ///
+    /// The scheduler hands over the checkpoint restored at replay start, so
+    /// a node moved here that crashes before its first own checkpoint comes
+    /// back with the replayed state. Returns the number of isolated nodes
+    /// that received a state.
+    pub fn restore_from_checkpoint(&mut self, checkpoint: &Checkpoint) -> usize {
+        let mut restored = 0;
+        for name in self.handles.keys() {
+            let state = checkpoint
+                .node_states
+                .get(name)
+                .and_then(|n| n.custom_state.as_ref());
+            if let Some(state) = state {
+                if fs::write(checkpoint_path(name), state).is_ok() {
+                    restored += 1;
                 }
             }
         }
+        restored
     }
 
     /// Get statistics for all nodes
@@ -952,14 +1244,14 @@ impl IsolatedExecutor {
 
     /// Shutdown all isolated nodes
     pub fn shutdown(&mut self) {
-        self.running.store(false, Ordering::SeqCst);
-
-        // Shutdown all nodes
+        // Shutdown all nodes while the runners are still listening
         for (_, handle) in self.handles.drain() {
             let mut handle = handle;
             let _ = handle.shutdown();
         }
 
+        self.running.store(false, Ordering::SeqCst);
+
         // Wait for watchdog
         if let Some(handle) = self.watchdog_handle.take() {
             let _ = handle.join();
@@ -969,6 +1261,32 @@ impl IsolatedExecutor {
     }
 }
 
+/// Mark a node crashed and schedule its restart (if any restarts are left)
+fn record_crash(
+    handle: &mut IsolatedNodeHandle,
+    events: &mut Vec<NodeStateEvent>,
+    reason: String,
+    restart_attempted: bool,
+) -> IsolatedResult {
+    events.push(handle.transition(NodeState::Crashed(reason.clone())));
+
+    if handle.restart_count < handle.config.max_restarts {
+        let delay = handle.restart_backoff();
+        handle.next_restart_at = Some(Instant::now() + delay);
+        eprintln!(
+            "[Isolated] Node '{}' crashed: {} (restart in {:?})",
+            handle.node_name, reason, delay
+        );
+    } else {
+        eprintln!(
+            "[Isolated] Node '{}' crashed: {} (max restarts reached, giving up)",
+            handle.node_name, reason
+        );
+    }
+
+    IsolatedResult::failed(&handle.node_name, reason, restart_attempted)
+}
+
 impl Default for IsolatedExecutor {
     fn default() -> Self {
         Self::new(IsolatedNodeConfig::default()).expect("Failed to create isolated executor")
@@ -996,6 +1314,8 @@ struct InProcessIsolatedRunner {
     running: Arc<AtomicBool>,
     tick_count: u64,
     error_count: u64,
+    checkpoint_interval_ticks: u64,
+    restore_checkpoint: bool,
 }
 
 impl InProcessIsolatedRunner {
@@ -1004,6 +1324,8 @@ impl InProcessIsolatedRunner {
         context: Option<NodeInfo>,
         ipc: IpcRegion,
         running: Arc<AtomicBool>,
+        checkpoint_interval_ticks: u64,
+        restore_checkpoint: bool,
     ) -> Self {
         Self {
             node,
@@ -1012,6 +1334,8 @@ impl InProcessIsolatedRunner {
             running,
             tick_count: 0,
             error_count: 0,
+            checkpoint_interval_ticks,
+            restore_checkpoint,
         }
     }
 
@@ -1046,11 +1370,15 @@ impl InProcessIsolatedRunner {
                     IpcCommand::HealthCheck => {
                         self.handle_health_check();
                     }
+                    IpcCommand::Restart => {
+                        self.handle_restart();
+                    }
                     IpcCommand::None => {}
                 }
 
-                // Update sequence to acknowledge command
-                let _ = self.ipc.write_sequence(sequence);
+                // The final status is the acknowledgement: writing the sequence
+                // back could overwrite the next command the executor publishes
+                // as soon as it sees that status
                 let _ = self.ipc.sync();
             }
 
@@ -1099,27 +1427,55 @@ impl InProcessIsolatedRunner {
         match result {
             Ok(_) => {
                 self.tick_count += 1;
+                if self.checkpoint_interval_ticks > 0
+                    && self
+                        .tick_count
+                        .is_multiple_of(self.checkpoint_interval_ticks)
+                {
+                    let node_name = self.node.name();
+                    save_node_checkpoint(self.node.as_ref(), node_name);
+                }
                 let _ = self.ipc.write_duration_ns(duration.as_nanos() as u64);
                 let _ = self.ipc.write_tick_count(self.tick_count);
                 let _ = self.ipc.write_status(IpcStatus::Success);
             }
             Err(panic_info) => {
                 self.error_count += 1;
-
-                let msg = if let Some(s) = panic_info.downcast_ref::<&str>() {
-                    format!("Node panicked: {}", s)
-                } else if let Some(s) = panic_info.downcast_ref::<String>() {
-                    format!("Node panicked: {}", s)
-                } else {
-                    "Node panicked with unknown error".to_string()
-                };
+                let msg = panic_message(panic_info);
 
                 if let Some(ref mut ctx) = self.context {
                     ctx.record_tick_failure(msg.clone());
+                    ctx.transition_to_crashed(msg.clone());
                 }
 
                 let _ = self.ipc.write_error_message(&msg);
                 let _ = self.ipc.write_error_count(self.error_count);
+                let _ = self.ipc.write_status(IpcStatus::Crashed);
+            }
+        }
+
+        let _ = self.ipc.update_heartbeat();
+    }
+
+    fn handle_restart(&mut self) {
+        let _ = self.ipc.write_status(IpcStatus::Processing);
+
+        let node_name = self.node.name();
+        let mut scratch;
+        let ctx = match self.context {
+            Some(ref mut ctx) => ctx,
+            None => {
+                scratch = NodeInfo::new(node_name.to_string(), false);
+                &mut scratch
+            }
+        };
+
+        match restart_node(self.node.as_mut(), ctx, node_name, self.restore_checkpoint) {
+            Ok(()) => {
+                let _ = self.ipc.write_status(IpcStatus::Success);
+            }
+            Err(msg) => {
+                let _ = self.ipc.write_error_message(&msg);
                 let _ = self.ipc.write_status(IpcStatus::Error);
             }
         }
@@ -1152,6 +1508,9 @@ impl InProcessIsolatedRunner {
 /// This function is intended to be called from the `horus-isolated-runner` binary.
 /// It reads IPC commands from shared memory and executes the node.
 ///
+/// Crash recovery settings are read from `HORUS_ISOLATED_CHECKPOINT_TICKS` and
+/// `HORUS_ISOLATED_RESTORE`, which the executor sets when spawning the runner.
+///
 /// # Arguments
 /// * `node` - The node to run
 /// * `ipc_path` - Path to the IPC shared memory file
@@ -1178,6 +1537,14 @@ pub fn run_isolated_node(mut node: Box<dyn Node>, ipc_path: &std::path::Path) ->
     // Create context
     let mut context = NodeInfo::new(node_name.clone(), true);
 
+    let checkpoint_interval_ticks: u64 = std::env::var("HORUS_ISOLATED_CHECKPOINT_TICKS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(0);
+    let restore_checkpoint = std::env::var("HORUS_ISOLATED_RESTORE")
+        .map(|v| v != "0")
+        .unwrap_or(false);
+
     // Signal ready
     ipc.write_pid(std::process::id())?;
     ipc.write_status(IpcStatus::Idle)?;
@@ -1234,6 +1601,11 @@ pub fn run_isolated_node(mut node: Box<dyn Node>, ipc_path: &std::path::Path) ->
                         Ok(_) => {
                             context.record_tick();
                             tick_count += 1;
+                            if checkpoint_interval_ticks > 0
+                                && tick_count.is_multiple_of(checkpoint_interval_ticks)
+                            {
+                                save_node_checkpoint(node.as_ref(), &node_name);
+                            }
                             ipc.write_duration_ns(duration.as_nanos() as u64)?;
                             ipc.write_tick_count(tick_count)?;
                             ipc.write_status(IpcStatus::Success)?;
@@ -1241,18 +1613,13 @@ pub fn run_isolated_node(mut node: Box<dyn Node>, ipc_path: &std::path::Path) ->
                         Err(panic_info) => {
                             error_count += 1;
 
-                            let msg = if let Some(s) = panic_info.downcast_ref::<&str>() {
-                                format!("Node panicked: {}", s)
-                            } else if let Some(s) = panic_info.downcast_ref::<String>() {
-                                format!("Node panicked: {}", s)
-                            } else {
-                                "Node panicked with unknown error".to_string()
-                            };
+                            let msg = panic_message(panic_info);
 
                             context.record_tick_failure(msg.clone());
+                            context.transition_to_crashed(msg.clone());
                             ipc.write_error_message(&msg)?;
                             ipc.write_error_count(error_count)?;
-                            ipc.write_status(IpcStatus::Error)?;
+                            ipc.write_status(IpcStatus::Crashed)?;
                         }
                     }
                 }
@@ -1268,11 +1635,24 @@ pub fn run_isolated_node(mut node: Box<dyn Node>, ipc_path: &std::path::Path) ->
                 IpcCommand::HealthCheck => {
                     ipc.write_status(IpcStatus::Success)?;
                 }
+                IpcCommand::Restart => {
+                    ipc.write_status(IpcStatus::Processing)?;
+
+                    match restart_node(node.as_mut(), &mut context, &node_name, restore_checkpoint)
+                    {
+                        Ok(()) => {
+                            ipc.write_status(IpcStatus::Success)?;
+                        }
+                        Err(msg) => {
+                            ipc.write_error_message(&msg)?;
+                            ipc.write_status(IpcStatus::Error)?;
+                        }
+                    }
+                }
                 IpcCommand::None => {}
             }
 
-            // Acknowledge command
-            ipc.write_sequence(sequence)?;
+            // The final status is the acknowledgement (see InProcessIsolatedRunner::run)
             ipc.update_heartbeat()?;
             ipc.sync()?;
         }
@@ -1319,6 +1699,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_node_file_stem_stays_in_directory() {
+        assert_eq!(node_file_stem("lidar-driver"), "lidar-driver");
+        assert_eq!(
+            node_file_stem("../../etc/passwd"),
+            "_2E_2E_2F_2E_2E_2Fetc_2Fpasswd"
+        );
+        assert_ne!(node_file_stem("a_2F"), node_file_stem("a/"));
+        assert_eq!(
+            checkpoint_path("robot/arm").parent(),
+            Some(shm_base_dir().join("isolated").as_path())
+        );
+    }
+
     #[test]
     fn test_ipc_region_create_and_read() {
         let mut ipc = IpcRegion::create("test_ipc").unwrap();
@@ -1387,6 +1781,168 @@ mod tests {
         executor.shutdown();
     }
 
+    /// Panics once on its third tick; checkpoints its tick counter
+    struct CrashOnceNode {
+        name: &'static str,
+        ticks: u32,
+        panic_pending: bool,
+    }
+
+    impl Node for CrashOnceNode {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn tick(&mut self, _ctx: Option<&mut NodeInfo>) {
+            if self.ticks == 2 && std::mem::take(&mut self.panic_pending) {
+                panic!("Intentional crash for restart test");
+            }
+            self.ticks += 1;
+        }
+
+        fn checkpoint_state(&self) -> Option<Vec<u8>> {
+            Some(self.ticks.to_le_bytes().to_vec())
+        }
+
+        fn restore_state(&mut self, data: &[u8]) -> HorusResult<()> {
+            let bytes: [u8; 4] = data
+                .try_into()
+                .map_err(|_| HorusError::Serialization("Invalid checkpoint size".into()))?;
+            self.ticks = u32::from_le_bytes(bytes);
+            Ok(())
+        }
+
+        fn supports_checkpointing(&self) -> bool {
+            true
+        }
+    }
+
+    /// Tick until the pending restart of the only node happens (5 s deadline)
+    fn tick_until_restarted(executor: &mut IsolatedExecutor) -> IsolatedResult {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            let result = executor.tick_all().remove(0);
+            if result.restart_attempted || Instant::now() > deadline {
+                return result;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    /// Checkpoint of `node` once it holds the tick counter `ticks` (5 s deadline)
+    fn wait_for_checkpoint(executor: &IsolatedExecutor, node: &str, ticks: u32) -> Option<Vec<u8>> {
+        let deadline = Instant::now() + Duration::from_secs(5);
+        loop {
+            let state = executor.checkpoint_states().remove(node);
+            if state.as_deref() == Some(&ticks.to_le_bytes()[..]) || Instant::now() > deadline {
+                return state;
+            }
+            thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn test_isolated_node_crash_restart() {
+        let config = IsolatedNodeConfig {
+            restart_delay: Duration::from_millis(200),
+            checkpoint_interval_ticks: 1,
+            ..Default::default()
+        };
+        let mut executor = IsolatedExecutor::new(config).unwrap();
+        let node = Box::new(CrashOnceNode {
+            name: "TestCrashOnceNode",
+            ticks: 0,
+            panic_pending: true,
+        });
+        executor.spawn_node(node, "CrashOnceNode", None).unwrap();
+
+        // Two good ticks, then the panic
+        for _ in 0..2 {
+            assert!(executor.tick_all()[0].success);
+        }
+        let crashed = &executor.tick_all()[0];
+        assert!(!crashed.success);
+        assert!(crashed
+            .error
+            .as_ref()
+            .unwrap()
+            .contains("Intentional crash"));
+
+        // Backoff pending: the node is not ticked
+        assert!(!executor.tick_all()[0].success);
+
+        let restarted = &tick_until_restarted(&mut executor);
+        assert!(restarted.success);
+        assert!(restarted.restart_attempted);
+
+        let events = executor.drain_state_events();
+        assert_eq!(events.len(), 2);
+        assert!(events[0].new_state.starts_with("Crashed"));
+        assert_eq!(events[1].new_state, "Running");
+        assert_eq!(events[1].restart_count, 1);
+
+        // Counter restored from the checkpoint taken before the crash
+        assert_eq!(
+            wait_for_checkpoint(&executor, "TestCrashOnceNode", 3),
+            Some(3u32.to_le_bytes().to_vec())
+        );
+
+        executor.shutdown();
+    }
+
+    #[test]
+    fn test_crash_restart_uses_restored_checkpoint() {
+        use crate::scheduling::checkpoint::{CheckpointMetadata, NodeCheckpoint};
+
+        let config = IsolatedNodeConfig {
+            restart_delay: Duration::from_millis(200),
+            checkpoint_interval_ticks: 1,
+            ..Default::default()
+        };
+        let mut executor = IsolatedExecutor::new(config).unwrap();
+        let node = Box::new(CrashOnceNode {
+            name: "TestRestoredCrashNode",
+            ticks: 0,
+            panic_pending: true,
+        });
+        executor.spawn_node(node, "CrashOnceNode", None).unwrap();
+        for _ in 0..3 {
+            executor.tick_all();
+        }
+
+        let checkpoint = Checkpoint {
+            id: 1,
+            timestamp: 0,
+            node_states: HashMap::from([(
+                "TestRestoredCrashNode".to_string(),
+                NodeCheckpoint {
+                    name: "TestRestoredCrashNode".to_string(),
+                    tick_count: 40,
+                    last_tick_us: 0,
+                    error_count: 0,
+                    custom_state: Some(40u32.to_le_bytes().to_vec()),
+                },
+            )]),
+            metadata: CheckpointMetadata {
+                scheduler_name: "test".to_string(),
+                total_ticks: 40,
+                learning_complete: true,
+                node_count: 1,
+                uptime_secs: 0.0,
+            },
+        };
+        assert_eq!(executor.restore_from_checkpoint(&checkpoint), 1);
+
+        let restarted = tick_until_restarted(&mut executor);
+        assert!(restarted.success, "{:?}", restarted.error);
+        assert_eq!(
+            wait_for_checkpoint(&executor, "TestRestoredCrashNode", 41),
+            Some(41u32.to_le_bytes().to_vec())
+        );
+
+        executor.shutdown();
+    }
+
     #[test]
     fn test_ipc_command_conversion() {
         assert_eq!(IpcCommand::from(0), IpcCommand::None);
@@ -1394,6 +1950,7 @@ mod tests {
         assert_eq!(IpcCommand::from(2), IpcCommand::Tick);
         assert_eq!(IpcCommand::from(3), IpcCommand::Shutdown);
         assert_eq!(IpcCommand::from(4), IpcCommand::HealthCheck);
+        assert_eq!(IpcCommand::from(5), IpcCommand::Restart);
         assert_eq!(IpcCommand::from(255), IpcCommand::None);
     }
 
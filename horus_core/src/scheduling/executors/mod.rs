@@ -12,5 +12,8 @@ pub mod isolated;
 
 pub use async_io::{AsyncIOExecutor, AsyncResult};
 pub use background::BackgroundExecutor;
-pub use isolated::{IsolatedExecutor, IsolatedNodeConfig, IsolatedNodeStats, IsolatedResult};
+pub use isolated::{
+    IsolatedExecutor, IsolatedNodeConfig, IsolatedNodeStats, IsolatedResult, NodeStateEvent,
+    NODE_STATE_TOPIC,
+};
 pub use parallel::ParallelExecutor;
@@ -0,0 +1,612 @@
+//! Liveness and readiness probes for process supervisors
+//!
+//! Lets systemd, Docker and Kubernetes restart an unhealthy HORUS process.
+//! The scheduler evaluates its safety monitor and node heartbeats every
+//! `update_interval` and publishes the result through one endpoint:
+//!
+//! - `http://ADDR:PORT`: `GET /livez` and `GET /readyz` answer `200` or `503`,
+//!   `GET /health` always answers `200`; all return the [`ProbeReport`] as JSON
+//! - `file://DIR` (or a path): `DIR/live` holds the Unix time of the last
+//!   update while live, `DIR/ready` exists while ready, `DIR/health.json` holds
+//!   the report
+//! - `systemd`: `sd_notify` protocol on `$NOTIFY_SOCKET`; `READY=1` once ready,
+//!   `WATCHDOG=1` on every live update (use with `Type=notify` and `WatchdogSec=`)
+//!
+//! A process is **live** while the scheduler loop keeps updating the probe, no
+//! emergency stop is active and no node is `Critical`. It is **ready** while it
+//! is live, all nodes initialized, the safety state is `Normal` or `Degraded`,
+//! no watchdog expired and no node is in `Error` health.
+//!
+//! ```rust,ignore
+//! let mut scheduler = Scheduler::new()
+//!     .with_safety_monitor(10)
+//!     .with_health_probe("http://0.0.0.0:8081");
+//! ```
+//!
+//! ```yaml
+//! # Kubernetes container spec
+//! livenessProbe:
+//!   httpGet: { path: /livez, port: 8081 }
+//! readinessProbe:
+//!   httpGet: { path: /readyz, port: 8081 }
+//! ```
+
+use super::degradation::SystemHealth;
+use super::safety_monitor::SafetyState;
+use crate::core::HealthStatus;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Health probe endpoint configuration
+#[derive(Debug, Clone, PartialEq)]
+pub enum HealthProbeEndpoint {
+    /// HTTP server on this address (e.g. "0.0.0.0:8081")
+    Http(String),
+    /// Status files in this directory
+    Directory(PathBuf),
+    /// systemd notification socket
+    Systemd,
+}
+
+impl HealthProbeEndpoint {
+    /// Parse endpoint from string (`http://ADDR:PORT`, `:PORT`, `systemd`, `file://DIR` or a path)
+    pub fn from_string(s: &str) -> Self {
+        if s == "systemd" {
+            HealthProbeEndpoint::Systemd
+        } else if let Some(addr) = s.strip_prefix("http://") {
+            HealthProbeEndpoint::Http(addr.trim_end_matches('/').to_string())
+        } else if let Some(port) = s.strip_prefix(':') {
+            HealthProbeEndpoint::Http(format!("0.0.0.0:{}", port))
+        } else {
+            HealthProbeEndpoint::Directory(PathBuf::from(s.trim_start_matches("file://")))
+        }
+    }
+}
+
+impl std::fmt::Display for HealthProbeEndpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HealthProbeEndpoint::Http(addr) => write!(f, "http://{}", addr),
+            HealthProbeEndpoint::Directory(dir) => write!(f, "file://{}", dir.display()),
+            HealthProbeEndpoint::Systemd => write!(f, "systemd"),
+        }
+    }
+}
+
+/// Result of a health evaluation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProbeReport {
+    pub live: bool,
+    pub ready: bool,
+    pub scheduler: String,
+    pub safety_state: String,
+    /// Health per node
+    pub nodes: BTreeMap<String, String>,
+    /// Why the process is not live or not ready
+    pub reasons: Vec<String>,
+    /// Unix time of the evaluation
+    pub timestamp: u64,
+}
+
+impl ProbeReport {
+    /// Report before the scheduler loop started
+    pub fn starting(scheduler: &str) -> Self {
+        Self {
+            live: true,
+            ready: false,
+            scheduler: scheduler.to_string(),
+            safety_state: format!("{:?}", SafetyState::Normal),
+            nodes: BTreeMap::new(),
+            reasons: vec!["Scheduler is starting".to_string()],
+            timestamp: unix_now(),
+        }
+    }
+
+    /// Evaluate liveness and readiness
+    ///
+    /// `failed_nodes` are nodes that failed to initialize or are in an error state.
+    pub fn evaluate(scheduler: &str, health: &SystemHealth, failed_nodes: &[String]) -> Self {
+        let mut live = true;
+        let mut ready = true;
+        let mut reasons = Vec::new();
+
+        match health.safety_state {
+            SafetyState::Normal | SafetyState::Degraded => {}
+            SafetyState::SafeMode => {
+                ready = false;
+                reasons.push("Safety monitor is in safe mode".to_string());
+            }
+            SafetyState::EmergencyStop => {
+                live = false;
+                ready = false;
+                reasons.push("Emergency stop is active".to_string());
+            }
+        }
+
+        for name in &health.expired_watchdogs {
+            ready = false;
+            reasons.push(format!("Watchdog expired for node '{}'", name));
+        }
+        for name in failed_nodes {
+            ready = false;
+            reasons.push(format!("Node '{}' failed", name));
+        }
+
+        let mut nodes = BTreeMap::new();
+        for (name, status) in &health.node_health {
+            match status {
+                HealthStatus::Critical => {
+                    live = false;
+                    ready = false;
+                    reasons.push(format!("Node '{}' is critical", name));
+                }
+                HealthStatus::Error => {
+                    ready = false;
+                    reasons.push(format!("Node '{}' has errors", name));
+                }
+                _ => {}
+            }
+            nodes.insert(name.clone(), status.as_str().to_string());
+        }
+        reasons.sort();
+
+        Self {
+            live,
+            ready,
+            scheduler: scheduler.to_string(),
+            safety_state: format!("{:?}", health.safety_state),
+            nodes,
+            reasons,
+            timestamp: unix_now(),
+        }
+    }
+
+    /// Report after the scheduler stopped
+    fn stopped(mut self) -> Self {
+        self.live = false;
+        self.ready = false;
+        self.reasons = vec!["Scheduler stopped".to_string()];
+        self.timestamp = unix_now();
+        self
+    }
+}
+
+/// Latest report and when the scheduler loop last published it
+struct ProbeState {
+    report: ProbeReport,
+    updated: Option<Instant>,
+}
+
+impl ProbeState {
+    /// Report as served, failing liveness if the loop stopped updating
+    fn current(&self, stall_timeout: Duration) -> ProbeReport {
+        let mut report = self.report.clone();
+        if let Some(updated) = self.updated {
+            if updated.elapsed() > stall_timeout {
+                report.live = false;
+                report.ready = false;
+                report.reasons.push(format!(
+                    "Scheduler loop stalled for {:.1}s",
+                    updated.elapsed().as_secs_f64()
+                ));
+            }
+        }
+        report
+    }
+}
+
+/// Publishes liveness and readiness through a [`HealthProbeEndpoint`]
+pub struct HealthProbe {
+    endpoint: HealthProbeEndpoint,
+    state: Arc<Mutex<ProbeState>>,
+    update_interval: Duration,
+    stall_timeout: Duration,
+    last_update: Option<Instant>,
+    stop: Arc<AtomicBool>,
+    local_addr: Option<SocketAddr>,
+    systemd_ready: bool,
+}
+
+impl HealthProbe {
+    /// Create a probe; nothing is published before `start`
+    pub fn new(endpoint: HealthProbeEndpoint, scheduler: &str) -> Self {
+        Self {
+            endpoint,
+            state: Arc::new(Mutex::new(ProbeState {
+                report: ProbeReport::starting(scheduler),
+                updated: None,
+            })),
+            update_interval: Duration::from_millis(500),
+            stall_timeout: Duration::from_secs(5),
+            last_update: None,
+            stop: Arc::new(AtomicBool::new(false)),
+            local_addr: None,
+            systemd_ready: false,
+        }
+    }
+
+    /// How often the scheduler evaluates health (default 500ms)
+    pub fn with_update_interval(mut self, interval: Duration) -> Self {
+        self.update_interval = interval;
+        self
+    }
+
+    /// Time without updates after which the process is no longer live (default 5s)
+    pub fn with_stall_timeout(mut self, timeout: Duration) -> Self {
+        self.stall_timeout = timeout;
+        self
+    }
+
+    pub fn endpoint(&self) -> &HealthProbeEndpoint {
+        &self.endpoint
+    }
+
+    /// Address of the HTTP server once started
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.local_addr
+    }
+
+    /// Start serving the probe (HTTP server thread or initial status files)
+    pub fn start(&mut self) -> std::io::Result<()> {
+        self.stop = Arc::new(AtomicBool::new(false));
+        match self.endpoint.clone() {
+            HealthProbeEndpoint::Http(addr) => {
+                let listener = TcpListener::bind(&addr)?;
+                listener.set_nonblocking(true)?;
+                self.local_addr = Some(listener.local_addr()?);
+
+                let state = self.state.clone();
+                let stop = self.stop.clone();
+                let stall_timeout = self.stall_timeout;
+                std::thread::Builder::new()
+                    .name("horus-health-probe".to_string())
+                    .spawn(move || serve_http(listener, state, stop, stall_timeout))?;
+            }
+            HealthProbeEndpoint::Directory(dir) => {
+                std::fs::create_dir_all(&dir)?;
+                let report = self.state.lock().unwrap().report.clone();
+                write_status_files(&dir, &report)?;
+            }
+            HealthProbeEndpoint::Systemd => {}
+        }
+        Ok(())
+    }
+
+    /// Whether the update interval elapsed
+    pub fn should_update(&self) -> bool {
+        self.last_update
+            .is_none_or(|last| last.elapsed() >= self.update_interval)
+    }
+
+    /// Publish a new report
+    pub fn update(&mut self, report: ProbeReport) {
+        self.last_update = Some(Instant::now());
+        self.publish(&report);
+        let mut state = self.state.lock().unwrap();
+        state.report = report;
+        state.updated = self.last_update;
+    }
+
+    /// Publish that the scheduler stopped and shut the HTTP server down
+    pub fn shutdown(&mut self) {
+        let report = {
+            let mut state = self.state.lock().unwrap();
+            state.report = state.report.clone().stopped();
+            state.updated = None;
+            state.report.clone()
+        };
+        self.publish(&report);
+        if self.endpoint == HealthProbeEndpoint::Systemd {
+            let _ = sd_notify("STOPPING=1");
+        }
+        self.stop.store(true, Ordering::SeqCst);
+    }
+
+    fn publish(&mut self, report: &ProbeReport) {
+        match self.endpoint {
+            HealthProbeEndpoint::Http(_) => {}
+            HealthProbeEndpoint::Directory(ref dir) => {
+                if let Err(e) = write_status_files(dir, report) {
+                    eprintln!("[HEALTH] Failed to write probe files: {}", e);
+                }
+            }
+            HealthProbeEndpoint::Systemd => {
+                if !report.live {
+                    // Missing watchdog pings make systemd restart the service
+                    return;
+                }
+                let mut message = format!("WATCHDOG=1\nSTATUS={}", status_line(report));
+                if report.ready && !self.systemd_ready {
+                    message.push_str("\nREADY=1");
+                    self.systemd_ready = true;
+                }
+                let _ = sd_notify(&message);
+            }
+        }
+    }
+}
+
+impl Drop for HealthProbe {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn status_line(report: &ProbeReport) -> String {
+    match (report.ready, report.reasons.first()) {
+        (true, _) | (false, None) => format!("{} nodes ready", report.nodes.len()),
+        (false, Some(reason)) => reason.clone(),
+    }
+}
+
+/// Serve probe requests until `stop` is set
+fn serve_http(
+    listener: TcpListener,
+    state: Arc<Mutex<ProbeState>>,
+    stop: Arc<AtomicBool>,
+    stall_timeout: Duration,
+) {
+    while !stop.load(Ordering::SeqCst) {
+        match listener.accept() {
+            Ok((stream, _)) => {
+                let report = state.lock().unwrap().current(stall_timeout);
+                let _ = handle_request(stream, &report);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(Duration::from_millis(20));
+            }
+            Err(_) => std::thread::sleep(Duration::from_millis(100)),
+        }
+    }
+}
+
+fn handle_request(mut stream: TcpStream, report: &ProbeReport) -> std::io::Result<()> {
+    stream.set_nonblocking(false)?;
+    stream.set_read_timeout(Some(Duration::from_secs(1)))?;
+
+    let head = read_request_head(&mut stream)?;
+    let request = String::from_utf8_lossy(&head);
+    let path = request
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().nth(1))
+        .unwrap_or("/");
+    let path = path.split('?').next().unwrap_or(path);
+
+    let ok = match path {
+        "/livez" => Some(report.live),
+        "/readyz" => Some(report.ready),
+        "/health" => Some(true),
+        _ => None,
+    };
+    let (status, body) = match ok {
+        Some(true) => ("200 OK", serde_json::to_string(report)?),
+        Some(false) => ("503 Service Unavailable", serde_json::to_string(report)?),
+        None => ("404 Not Found", r#"{"error":"not found"}"#.to_string()),
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+/// Largest request head read before answering
+const MAX_REQUEST_HEAD: usize = 8192;
+
+/// Read the request line and headers, up to the blank line
+///
+/// Answering before the whole head arrived and closing would reset the
+/// connection under a client still writing its request.
+fn read_request_head(stream: &mut TcpStream) -> std::io::Result<Vec<u8>> {
+    let mut head = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") && head.len() < MAX_REQUEST_HEAD {
+        let n = stream.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        head.extend_from_slice(&buf[..n]);
+    }
+    Ok(head)
+}
+
+/// Write `live`, `ready` and `health.json` into `dir`
+fn write_status_files(dir: &Path, report: &ProbeReport) -> std::io::Result<()> {
+    let json_path = dir.join("health.json");
+    let tmp_path = dir.join(".health.json.tmp");
+    std::fs::write(&tmp_path, serde_json::to_string_pretty(report)?)?;
+    std::fs::rename(&tmp_path, &json_path)?;
+
+    set_marker(&dir.join("live"), report.live, report.timestamp)?;
+    set_marker(&dir.join("ready"), report.ready, report.timestamp)
+}
+
+fn set_marker(path: &Path, present: bool, timestamp: u64) -> std::io::Result<()> {
+    if present {
+        std::fs::write(path, timestamp.to_string())
+    } else {
+        match std::fs::remove_file(path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Send a message to the systemd notification socket, if there is one
+#[cfg(target_os = "linux")]
+fn sd_notify(message: &str) -> std::io::Result<()> {
+    use std::os::linux::net::SocketAddrExt;
+    use std::os::unix::net::{SocketAddr as UnixAddr, UnixDatagram};
+
+    let Ok(socket_path) = std::env::var("NOTIFY_SOCKET") else {
+        return Ok(());
+    };
+    let addr = match socket_path.strip_prefix('@') {
+        Some(name) => UnixAddr::from_abstract_name(name.as_bytes())?,
+        None => UnixAddr::from_pathname(&socket_path)?,
+    };
+    let socket = UnixDatagram::unbound()?;
+    socket.send_to_addr(message.as_bytes(), &addr)?;
+    Ok(())
+}
+
+#[cfg(not(target_os = "linux"))]
+fn sd_notify(_message: &str) -> std::io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn health(safety_state: SafetyState, nodes: &[(&str, HealthStatus)]) -> SystemHealth {
+        SystemHealth {
+            safety_state,
+            node_health: nodes
+                .iter()
+                .map(|(name, status)| (name.to_string(), *status))
+                .collect::<HashMap<_, _>>(),
+            expired_watchdogs: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_endpoint_parsing() {
+        assert_eq!(
+            HealthProbeEndpoint::from_string("http://0.0.0.0:8081"),
+            HealthProbeEndpoint::Http("0.0.0.0:8081".to_string())
+        );
+        assert_eq!(
+            HealthProbeEndpoint::from_string(":8081"),
+            HealthProbeEndpoint::Http("0.0.0.0:8081".to_string())
+        );
+        assert_eq!(
+            HealthProbeEndpoint::from_string("systemd"),
+            HealthProbeEndpoint::Systemd
+        );
+        assert_eq!(
+            HealthProbeEndpoint::from_string("file:///run/horus"),
+            HealthProbeEndpoint::Directory(PathBuf::from("/run/horus"))
+        );
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let report = ProbeReport::evaluate(
+            "robot",
+            &health(SafetyState::Normal, &[("motor", HealthStatus::Healthy)]),
+            &[],
+        );
+        assert!(report.live && report.ready);
+
+        let mut degraded = health(SafetyState::Degraded, &[("camera", HealthStatus::Error)]);
+        degraded.expired_watchdogs.push("planner".to_string());
+        let report = ProbeReport::evaluate("robot", &degraded, &[]);
+        assert!(report.live);
+        assert!(!report.ready);
+        assert_eq!(report.reasons.len(), 2);
+
+        let report = ProbeReport::evaluate(
+            "robot",
+            &health(SafetyState::EmergencyStop, &[]),
+            &["lidar".to_string()],
+        );
+        assert!(!report.live && !report.ready);
+    }
+
+    #[test]
+    fn test_stalled_loop_is_not_live() {
+        let state = ProbeState {
+            report: ProbeReport::evaluate("robot", &health(SafetyState::Normal, &[]), &[]),
+            updated: Some(Instant::now() - Duration::from_secs(10)),
+        };
+        assert!(state.current(Duration::from_secs(60)).live);
+        let report = state.current(Duration::from_secs(5));
+        assert!(!report.live && !report.ready);
+    }
+
+    #[test]
+    fn test_http_probe() {
+        let mut probe = HealthProbe::new(
+            HealthProbeEndpoint::Http("127.0.0.1:0".to_string()),
+            "robot",
+        );
+        probe.start().unwrap();
+        let addr = probe.local_addr().unwrap();
+
+        let get = |path: &str| {
+            let mut stream = TcpStream::connect(addr).unwrap();
+            stream
+                .write_all(
+                    format!(
+                        "GET {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n",
+                        path
+                    )
+                    .as_bytes(),
+                )
+                .unwrap();
+            // Keep what arrived even if the server resets the connection after it
+            let mut response = Vec::new();
+            let _ = stream.read_to_end(&mut response);
+            String::from_utf8_lossy(&response).into_owned()
+        };
+
+        assert!(get("/livez").starts_with("HTTP/1.1 200"));
+        assert!(get("/readyz").starts_with("HTTP/1.1 503"));
+
+        probe.update(ProbeReport::evaluate(
+            "robot",
+            &health(SafetyState::Normal, &[]),
+            &[],
+        ));
+        assert!(get("/readyz").starts_with("HTTP/1.1 200"));
+        assert!(get("/nope").starts_with("HTTP/1.1 404"));
+
+        probe.shutdown();
+    }
+
+    #[test]
+    fn test_directory_probe() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut probe = HealthProbe::new(
+            HealthProbeEndpoint::Directory(dir.path().to_path_buf()),
+            "robot",
+        );
+        probe.start().unwrap();
+        assert!(dir.path().join("live").exists());
+        assert!(!dir.path().join("ready").exists());
+
+        probe.update(ProbeReport::evaluate(
+            "robot",
+            &health(SafetyState::Normal, &[]),
+            &[],
+        ));
+        assert!(dir.path().join("ready").exists());
+
+        probe.shutdown();
+        assert!(!dir.path().join("live").exists());
+        assert!(!dir.path().join("ready").exists());
+        let json = std::fs::read_to_string(dir.path().join("health.json")).unwrap();
+        let report: ProbeReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(report.reasons, vec!["Scheduler stopped".to_string()]);
+    }
+}
@@ -1,4 +1,5 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque};
 
 /// Represents a dependency graph for nodes based on pub/sub relationships
 #[derive(Debug, Clone)]
@@ -11,17 +12,40 @@ pub struct DependencyGraph {
     pub levels: Vec<Vec<String>>,
     /// Groups of nodes that can execute in parallel
     pub parallel_groups: Vec<Vec<String>>,
+    /// Single execution order with every producer before its consumers
+    pub order: Vec<String>,
+    /// Groups of nodes that form dependency cycles, in execution order
+    pub cycles: Vec<Vec<String>>,
 }
 
 impl DependencyGraph {
     /// Build dependency graph from node pub/sub metadata
     /// Uses Kahn's algorithm for topological sort to identify parallel execution groups
     pub fn from_nodes(nodes: &[(&str, Vec<String>, Vec<String>)]) -> Self {
+        let priorities = vec![0; nodes.len()];
+        Self::build(nodes, &priorities)
+    }
+
+    /// Build dependency graph with scheduler priorities (lower runs earlier)
+    ///
+    /// Priorities only order nodes that don't depend on each other in `order`.
+    pub fn from_prioritized_nodes(nodes: &[(&str, u32, Vec<String>, Vec<String>)]) -> Self {
+        let priorities: Vec<u32> = nodes.iter().map(|(_, priority, _, _)| *priority).collect();
+        let nodes: Vec<_> = nodes
+            .iter()
+            .map(|(name, _, pubs, subs)| (*name, pubs.clone(), subs.clone()))
+            .collect();
+        Self::build(&nodes, &priorities)
+    }
+
+    fn build(nodes: &[(&str, Vec<String>, Vec<String>)], priorities: &[u32]) -> Self {
         let mut graph = DependencyGraph {
             nodes: Vec::new(),
             edges: Vec::new(),
             levels: Vec::new(),
             parallel_groups: Vec::new(),
+            order: Vec::new(),
+            cycles: Vec::new(),
         };
 
         // Build node list
@@ -70,9 +94,90 @@ impl DependencyGraph {
         // Identify parallel groups (nodes with no dependencies between them)
         graph.find_parallel_groups();
 
+        // Total order for sequential execution, cycles collapsed
+        graph.compute_order(priorities);
+
         graph
     }
 
+    /// Compute a single execution order, priority breaking ties
+    ///
+    /// Cycles (e.g. a controller and a plant model feeding each other) cannot
+    /// be ordered. They are collapsed into strongly connected components,
+    /// ordered by priority internally, and recorded in `cycles`.
+    fn compute_order(&mut self, priorities: &[u32]) {
+        let n = self.nodes.len();
+        let index: HashMap<&str, usize> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (name.as_str(), i))
+            .collect();
+
+        // Deduplicated adjacency (a node may share several topics with another)
+        let mut adjacency: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); n];
+        for (from, to) in &self.edges {
+            adjacency[index[from.as_str()]].insert(index[to.as_str()]);
+        }
+
+        // Collapse cycles so the remaining graph is a DAG
+        let components = strongly_connected_components(&adjacency);
+        let mut component_of = vec![0usize; n];
+        for (cid, members) in components.iter().enumerate() {
+            for &m in members {
+                component_of[m] = cid;
+            }
+        }
+
+        let mut cycles = Vec::new();
+        let mut sorted_components: Vec<Vec<usize>> = Vec::with_capacity(components.len());
+        for members in &components {
+            let mut members = members.clone();
+            members.sort_by_key(|&m| (priorities[m], m));
+            if members.len() > 1 {
+                cycles.push(members.iter().map(|&m| self.nodes[m].clone()).collect());
+            }
+            sorted_components.push(members);
+        }
+
+        // Kahn's algorithm on the condensation, priority as tie-breaker
+        let c = sorted_components.len();
+        let mut comp_edges: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); c];
+        let mut in_degree = vec![0usize; c];
+        for (from, targets) in adjacency.iter().enumerate() {
+            for &to in targets {
+                let (cf, ct) = (component_of[from], component_of[to]);
+                if cf != ct && comp_edges[cf].insert(ct) {
+                    in_degree[ct] += 1;
+                }
+            }
+        }
+
+        let key = |cid: usize| {
+            let first = sorted_components[cid][0];
+            Reverse((priorities[first], first, cid))
+        };
+
+        let mut ready: BinaryHeap<Reverse<(u32, usize, usize)>> =
+            (0..c).filter(|&cid| in_degree[cid] == 0).map(key).collect();
+
+        let mut order = Vec::with_capacity(n);
+        while let Some(Reverse((_, _, cid))) = ready.pop() {
+            for &m in &sorted_components[cid] {
+                order.push(self.nodes[m].clone());
+            }
+            for &next in &comp_edges[cid] {
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    ready.push(key(next));
+                }
+            }
+        }
+
+        self.order = order;
+        self.cycles = cycles;
+    }
+
     /// Compute topological levels using Kahn's algorithm
     /// Nodes at the same level have no dependencies on each other
     fn compute_levels(&mut self) {
@@ -254,6 +359,68 @@ impl DependencyGraph {
     }
 }
 
+/// Tarjan's algorithm (iterative to avoid deep recursion on long pipelines)
+fn strongly_connected_components(adjacency: &[BTreeSet<usize>]) -> Vec<Vec<usize>> {
+    let n = adjacency.len();
+    let mut index = vec![usize::MAX; n];
+    let mut lowlink = vec![0usize; n];
+    let mut on_stack = vec![false; n];
+    let mut stack = Vec::new();
+    let mut components = Vec::new();
+    let mut next_index = 0;
+
+    for root in 0..n {
+        if index[root] != usize::MAX {
+            continue;
+        }
+
+        // (node, successors still to visit)
+        let mut work: Vec<(usize, Vec<usize>)> = Vec::new();
+        index[root] = next_index;
+        lowlink[root] = next_index;
+        next_index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        work.push((root, adjacency[root].iter().rev().copied().collect()));
+
+        while let Some((v, pending)) = work.last_mut() {
+            let v = *v;
+            if let Some(w) = pending.pop() {
+                if index[w] == usize::MAX {
+                    index[w] = next_index;
+                    lowlink[w] = next_index;
+                    next_index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    work.push((w, adjacency[w].iter().rev().copied().collect()));
+                } else if on_stack[w] {
+                    lowlink[v] = lowlink[v].min(index[w]);
+                }
+                continue;
+            }
+
+            work.pop();
+            if let Some((parent, _)) = work.last() {
+                lowlink[*parent] = lowlink[*parent].min(lowlink[v]);
+            }
+
+            if lowlink[v] == index[v] {
+                let mut component = Vec::new();
+                while let Some(w) = stack.pop() {
+                    on_stack[w] = false;
+                    component.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
+
 /// Statistics about dependency graph
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -264,3 +431,70 @@ pub struct GraphStats {
     pub num_parallel_groups: usize,
     pub max_parallel_nodes: usize,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node<'a>(
+        name: &'a str,
+        priority: u32,
+        pubs: &[&str],
+        subs: &[&str],
+    ) -> (&'a str, u32, Vec<String>, Vec<String>) {
+        (
+            name,
+            priority,
+            pubs.iter().map(|s| s.to_string()).collect(),
+            subs.iter().map(|s| s.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn test_consumer_runs_after_producer_despite_priority() {
+        let graph = DependencyGraph::from_prioritized_nodes(&[
+            node("controller", 0, &["cmd"], &["state"]),
+            node("estimator", 5, &["state"], &["imu"]),
+            node("imu", 10, &["imu"], &[]),
+        ]);
+        assert_eq!(graph.order, vec!["imu", "estimator", "controller"]);
+        assert!(graph.cycles.is_empty());
+        assert!(!graph.has_cycles());
+    }
+
+    #[test]
+    fn test_priority_breaks_ties() {
+        let graph = DependencyGraph::from_prioritized_nodes(&[
+            node("logger", 200, &[], &[]),
+            node("sensor_b", 2, &["b"], &[]),
+            node("sensor_a", 1, &["a"], &[]),
+            node("fusion", 50, &[], &["a", "b"]),
+        ]);
+        assert_eq!(
+            graph.order,
+            vec!["sensor_a", "sensor_b", "fusion", "logger"]
+        );
+    }
+
+    #[test]
+    fn test_cycle_detected_and_all_nodes_scheduled() {
+        let graph = DependencyGraph::from_prioritized_nodes(&[
+            node("source", 9, &["ref"], &[]),
+            node("plant", 2, &["y"], &["u"]),
+            node("controller", 1, &["u"], &["y", "ref"]),
+            node("sink", 0, &[], &["y"]),
+        ]);
+        assert!(graph.has_cycles());
+        assert_eq!(graph.cycles, vec![vec!["controller", "plant"]]);
+
+        // Every node still runs exactly once, source before the cycle, sink after
+        assert_eq!(graph.order, vec!["source", "controller", "plant", "sink"]);
+    }
+
+    #[test]
+    fn test_self_publish_is_not_a_cycle() {
+        let graph = DependencyGraph::from_prioritized_nodes(&[node("loopback", 0, &["t"], &["t"])]);
+        assert!(graph.cycles.is_empty());
+        assert_eq!(graph.order, vec!["loopback"]);
+    }
+}
@@ -0,0 +1,427 @@
+//! Lockstep co-simulation between the scheduler and a simulator
+//!
+//! In lockstep mode the simulator owns time. After every physics step it
+//! publishes a [`LockstepStep`] and blocks; the scheduler runs exactly one
+//! cycle for that step and answers with a [`LockstepAck`]. Controller ticks
+//! and physics steps therefore interleave identically on every run, no matter
+//! how fast or slow either process is, which makes control code testable
+//! in CI.
+//!
+//! ```text
+//! simulator                                scheduler
+//!   physics step, publish sensors
+//!   LockstepStep { seq, sim_time } ──────▶  one cycle: sensors in, commands out
+//!                                  ◀──────  LockstepAck { seq }
+//!   read commands, next physics step
+//! ```
+//!
+//! Both sides talk over shared-memory hubs on `{topic}.step` and
+//! `{topic}.ack`. Every message carries a session id picked by the simulator,
+//! so leftovers from an earlier run are never mistaken for the current one.
+//! Unacknowledged steps are re-sent, and the scheduler re-acknowledges steps
+//! it already ran, so either side may start first.
+//!
+//! ```rust,ignore
+//! // Scheduler process: tick once per simulator step, rates follow sim time
+//! let mut scheduler = Scheduler::new().with_lockstep(LockstepConfig::default())?;
+//! scheduler.add(Box::new(controller), 0, Some(true));
+//! scheduler.run_for(Duration::from_secs(10))?; // 10 s of simulated time
+//!
+//! // Simulator process: hand control to the scheduler after every step
+//! let mut driver = LockstepDriver::new(LockstepConfig::default())?;
+//! loop {
+//!     world.step(dt);
+//!     publish_sensors(&world);
+//!     driver.step(dt)?;
+//! }
+//! ```
+
+use crate::communication::Hub;
+use crate::core::LogSummary;
+use crate::error::{HorusError, HorusResult};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Default topic prefix for the step/ack hubs
+pub const DEFAULT_LOCKSTEP_TOPIC: &str = "horus.lockstep";
+
+/// How often a waiting side polls its hub
+const POLL_INTERVAL: Duration = Duration::from_micros(50);
+
+/// Lockstep settings, shared by the scheduler and the simulator
+#[derive(Debug, Clone)]
+pub struct LockstepConfig {
+    /// Topic prefix; steps go to `{topic}.step`, acks to `{topic}.ack`
+    pub topic: String,
+    /// How long either side waits for its peer once the handshake has
+    /// happened (`None` waits forever). Before the first exchange both sides
+    /// wait indefinitely, so start order does not matter.
+    pub timeout: Option<Duration>,
+    /// How often the simulator re-sends an unacknowledged step
+    pub resend_interval: Duration,
+}
+
+impl Default for LockstepConfig {
+    fn default() -> Self {
+        Self {
+            topic: DEFAULT_LOCKSTEP_TOPIC.to_string(),
+            timeout: Some(Duration::from_secs(10)),
+            resend_interval: Duration::from_millis(100),
+        }
+    }
+}
+
+impl LockstepConfig {
+    /// Use a different topic prefix (one per simulator/scheduler pair)
+    pub fn with_topic(mut self, topic: &str) -> Self {
+        self.topic = topic.to_string();
+        self
+    }
+
+    /// Set the peer timeout (`None` waits forever)
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn step_topic(&self) -> String {
+        format!("{}.step", self.topic)
+    }
+
+    fn ack_topic(&self) -> String {
+        format!("{}.ack", self.topic)
+    }
+}
+
+/// Simulator → scheduler: physics has advanced to `sim_time_ns`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockstepStep {
+    /// Simulator session, fixed for the lifetime of a [`LockstepDriver`]
+    pub session: u64,
+    /// Step number, starting at 1
+    pub seq: u64,
+    /// Simulated time after this step, in nanoseconds
+    pub sim_time_ns: u64,
+    /// Length of this step, in nanoseconds
+    pub dt_ns: u64,
+}
+
+impl LogSummary for LockstepStep {
+    fn log_summary(&self) -> String {
+        format!(
+            "step {} at {:.3}s (dt {:.3}ms)",
+            self.seq,
+            self.sim_time_ns as f64 / 1e9,
+            self.dt_ns as f64 / 1e6
+        )
+    }
+}
+
+/// Scheduler → simulator: the cycle for step `seq` has finished
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockstepAck {
+    pub session: u64,
+    pub seq: u64,
+}
+
+impl LogSummary for LockstepAck {
+    fn log_summary(&self) -> String {
+        format!("ack {}", self.seq)
+    }
+}
+
+/// Simulated time as seen by the scheduler
+///
+/// Cheap to clone; nodes can hold one to timestamp data with sim time.
+#[derive(Debug, Clone, Default)]
+pub struct SimClock(Arc<AtomicU64>);
+
+impl SimClock {
+    /// Current simulated time in nanoseconds
+    pub fn now_ns(&self) -> u64 {
+        self.0.load(Ordering::Acquire)
+    }
+
+    /// Current simulated time
+    pub fn now(&self) -> Duration {
+        Duration::from_nanos(self.now_ns())
+    }
+
+    fn set(&self, ns: u64) {
+        self.0.store(ns, Ordering::Release);
+    }
+}
+
+/// Outcome of waiting for the next step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockstepWait {
+    /// Run one cycle for this step, then [`LockstepClient::ack`]
+    Step(LockstepStep),
+    /// The caller asked to stop while waiting
+    Stopped,
+    /// The simulator went quiet for longer than the configured timeout
+    TimedOut,
+}
+
+/// Scheduler side of the lockstep protocol
+pub struct LockstepClient {
+    config: LockstepConfig,
+    step_hub: Hub<LockstepStep>,
+    ack_hub: Hub<LockstepAck>,
+    current: Option<LockstepStep>,
+    clock: SimClock,
+    epoch: Instant,
+}
+
+impl LockstepClient {
+    pub fn new(config: LockstepConfig) -> HorusResult<Self> {
+        Ok(Self {
+            step_hub: Hub::new(config.step_topic())?,
+            ack_hub: Hub::new(config.ack_topic())?,
+            config,
+            current: None,
+            clock: SimClock::default(),
+            epoch: Instant::now(),
+        })
+    }
+
+    /// Discard steps left on the hub by an earlier run
+    pub fn drain(&mut self) {
+        while self.step_hub.recv(&mut None).is_some() {}
+    }
+
+    /// Block until the simulator publishes a new step
+    ///
+    /// Re-sent copies of the step that already ran are acknowledged again
+    /// instead of being run twice. `keep_waiting` is polled so the caller can
+    /// stop on Ctrl+C.
+    pub fn wait_step(&mut self, keep_waiting: impl Fn() -> bool) -> LockstepWait {
+        let started = Instant::now();
+        loop {
+            while let Some(step) = self.step_hub.recv(&mut None) {
+                match self.current {
+                    Some(last) if last.session == step.session && step.seq <= last.seq => {
+                        if step.seq == last.seq {
+                            self.send_ack(&last);
+                        }
+                    }
+                    _ => {
+                        self.current = Some(step);
+                        self.clock.set(step.sim_time_ns);
+                        return LockstepWait::Step(step);
+                    }
+                }
+            }
+
+            if !keep_waiting() {
+                return LockstepWait::Stopped;
+            }
+            if let (Some(timeout), Some(_)) = (self.config.timeout, self.current) {
+                if started.elapsed() >= timeout {
+                    return LockstepWait::TimedOut;
+                }
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    /// Tell the simulator the cycle for the current step has finished
+    pub fn ack(&self) {
+        if let Some(step) = self.current {
+            self.send_ack(&step);
+        }
+    }
+
+    fn send_ack(&self, step: &LockstepStep) {
+        let ack = LockstepAck {
+            session: step.session,
+            seq: step.seq,
+        };
+        if self.ack_hub.send(ack, &mut None).is_err() {
+            eprintln!("[LOCKSTEP] Failed to acknowledge step {}", step.seq);
+        }
+    }
+
+    /// Handle to the simulated time of the current step
+    pub fn clock(&self) -> SimClock {
+        self.clock.clone()
+    }
+
+    /// Simulated time mapped onto `Instant`, for rate limiting
+    pub fn instant(&self) -> Instant {
+        self.epoch + self.clock.now()
+    }
+
+    /// Number of the step currently running (0 before the first step)
+    pub fn seq(&self) -> u64 {
+        self.current.map_or(0, |step| step.seq)
+    }
+}
+
+/// Simulator side of the lockstep protocol
+pub struct LockstepDriver {
+    config: LockstepConfig,
+    step_hub: Hub<LockstepStep>,
+    ack_hub: Hub<LockstepAck>,
+    session: u64,
+    seq: u64,
+    sim_time_ns: u64,
+    handshake_done: bool,
+}
+
+impl LockstepDriver {
+    pub fn new(config: LockstepConfig) -> HorusResult<Self> {
+        let driver = Self {
+            step_hub: Hub::new(config.step_topic())?,
+            ack_hub: Hub::new(config.ack_topic())?,
+            config,
+            session: new_session_id(),
+            seq: 0,
+            sim_time_ns: 0,
+            handshake_done: false,
+        };
+        // Acks from an earlier run carry another session and are ignored
+        // anyway; draining just avoids scanning them on the first step
+        while driver.ack_hub.recv(&mut None).is_some() {}
+        Ok(driver)
+    }
+
+    /// Advance simulated time by `dt` and wait for the scheduler's cycle
+    ///
+    /// Call once per simulation frame, after the physics step and after
+    /// sensor data for the new state has been published.
+    pub fn step(&mut self, dt: Duration) -> HorusResult<()> {
+        let dt_ns = dt.as_nanos() as u64;
+        self.seq += 1;
+        self.sim_time_ns += dt_ns;
+        let step = LockstepStep {
+            session: self.session,
+            seq: self.seq,
+            sim_time_ns: self.sim_time_ns,
+            dt_ns,
+        };
+
+        let started = Instant::now();
+        let mut last_sent = started;
+        self.publish(step)?;
+
+        loop {
+            while let Some(ack) = self.ack_hub.recv(&mut None) {
+                if ack.session == self.session && ack.seq == self.seq {
+                    self.handshake_done = true;
+                    return Ok(());
+                }
+            }
+
+            if let (Some(timeout), true) = (self.config.timeout, self.handshake_done) {
+                if started.elapsed() >= timeout {
+                    return Err(HorusError::Timeout(format!(
+                        "scheduler did not acknowledge lockstep step {} within {:?}",
+                        self.seq, timeout
+                    )));
+                }
+            }
+            if last_sent.elapsed() >= self.config.resend_interval {
+                self.publish(step)?;
+                last_sent = Instant::now();
+            }
+            std::thread::sleep(POLL_INTERVAL);
+        }
+    }
+
+    fn publish(&self, step: LockstepStep) -> HorusResult<()> {
+        self.step_hub.send(step, &mut None).map_err(|_| {
+            HorusError::Communication(format!(
+                "failed to publish lockstep step {} on '{}'",
+                step.seq,
+                self.config.step_topic()
+            ))
+        })
+    }
+
+    /// Simulated time after the last step
+    pub fn sim_time(&self) -> Duration {
+        Duration::from_nanos(self.sim_time_ns)
+    }
+
+    /// Number of steps taken so far
+    pub fn steps(&self) -> u64 {
+        self.seq
+    }
+}
+
+fn new_session_id() -> u64 {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_nanos() as u64);
+    nanos ^ ((std::process::id() as u64) << 32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config(name: &str) -> LockstepConfig {
+        LockstepConfig::default()
+            .with_topic(&format!("test.lockstep.{}.{}", name, std::process::id()))
+            .with_timeout(Some(Duration::from_secs(5)))
+    }
+
+    #[test]
+    fn test_sim_clock_is_shared() {
+        let clock = SimClock::default();
+        let handle = clock.clone();
+        clock.set(1_500_000_000);
+        assert_eq!(handle.now(), Duration::from_millis(1500));
+    }
+
+    #[test]
+    fn test_step_summary() {
+        let step = LockstepStep {
+            session: 1,
+            seq: 3,
+            sim_time_ns: 50_000_000,
+            dt_ns: 10_000_000,
+        };
+        assert_eq!(step.log_summary(), "step 3 at 0.050s (dt 10.000ms)");
+    }
+
+    #[test]
+    fn test_driver_and_client_stay_in_lockstep() {
+        let config = test_config("roundtrip");
+        let mut client = LockstepClient::new(config.clone()).unwrap();
+        client.drain();
+
+        let driver = std::thread::spawn(move || {
+            let mut driver = LockstepDriver::new(config).unwrap();
+            for _ in 0..5 {
+                driver.step(Duration::from_millis(10)).unwrap();
+            }
+            driver.sim_time()
+        });
+
+        let clock = client.clock();
+        for expected_seq in 1..=5u64 {
+            match client.wait_step(|| true) {
+                LockstepWait::Step(step) => {
+                    assert_eq!(step.seq, expected_seq);
+                    assert_eq!(clock.now(), Duration::from_millis(10 * expected_seq));
+                }
+                other => panic!("expected a step, got {:?}", other),
+            }
+            client.ack();
+        }
+
+        assert_eq!(driver.join().unwrap(), Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_wait_step_stops_when_asked() {
+        let mut client = LockstepClient::new(test_config("stop")).unwrap();
+        client.drain();
+        assert_eq!(client.wait_step(|| false), LockstepWait::Stopped);
+        assert_eq!(client.seq(), 0);
+    }
+}
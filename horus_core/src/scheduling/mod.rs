@@ -25,6 +25,7 @@
 //! - **200+**: Background priority (logging, diagnostics)
 
 pub mod config;
+pub mod degradation;
 pub mod safety_monitor;
 pub mod scheduler;
 
@@ -40,6 +41,7 @@ pub mod runtime;
 // Fault tolerance and monitoring
 pub mod blackbox;
 pub mod checkpoint;
+pub mod health_probe;
 pub mod redundancy;
 pub mod telemetry;
 
@@ -55,6 +57,9 @@ pub mod zero_copy_recording;
 // Deterministic execution
 pub mod deterministic;
 
+// Lockstep co-simulation with sim2d/sim3d
+pub mod lockstep;
+
 // Distributed multi-process/robot recording
 pub mod distributed_recording;
 
@@ -67,8 +72,14 @@ pub mod async_io {
 }
 
 pub use config::{ConfigValue, ExecutionMode, RecordingConfigYaml, RobotPreset, SchedulerConfig};
-pub use safety_monitor::{SafetyMonitor, SafetyState, SafetyStats, WCETEnforcer, Watchdog};
-pub use scheduler::{Scheduler, SchedulerNodeMetrics};
+pub use degradation::{
+    DegradationPolicy, DegradationProfile, DegradationTransition, DegradationTrigger, SystemHealth,
+};
+pub use safety_monitor::{
+    EscalationAction, EscalationEvent, EscalationPolicy, SafetyMonitor, SafetyState, SafetyStats,
+    WCETEnforcer, Watchdog,
+};
+pub use scheduler::{Scheduler, SchedulerNodeMetrics, SchedulerStopHandle};
 
 // Re-export runtime features
 pub use runtime::{
@@ -86,7 +97,10 @@ pub use telemetry::{TelemetryEndpoint, TelemetryManager};
 pub use record_replay::{
     compress_data,
     decompress_data,
+    diff_recording_files,
     diff_recordings,
+    find_session_checkpoint,
+    list_session_checkpoints,
     load_recording_compressed,
     save_recording_compressed,
     AutoRecordConfig,
@@ -100,11 +114,13 @@ pub use record_replay::{
     DebuggerState,
     NodeRecorder,
     NodeRecording,
+    NodeRecordingHeader,
     NodeReplayer,
     NodeTickSnapshot,
     RecordingConfig,
     RecordingDiff,
     RecordingManager,
+    RecordingReader,
     // Advanced debugging
     ReplayDebugger,
     ReplayMode,
@@ -124,7 +140,7 @@ pub use intelligence::{
 // Re-export executors
 pub use executors::{
     AsyncIOExecutor, AsyncResult, BackgroundExecutor, IsolatedExecutor, IsolatedNodeConfig,
-    IsolatedNodeStats, IsolatedResult, ParallelExecutor,
+    IsolatedNodeStats, IsolatedResult, NodeStateEvent, ParallelExecutor, NODE_STATE_TOPIC,
 };
 
 // Re-export fault tolerance
@@ -156,6 +172,12 @@ pub use deterministic::{
     ViolationSeverity,
 };
 
+// Re-export lockstep co-simulation
+pub use lockstep::{
+    LockstepAck, LockstepClient, LockstepConfig, LockstepDriver, LockstepStep, LockstepWait,
+    SimClock, DEFAULT_LOCKSTEP_TOPIC,
+};
+
 // Re-export distributed recording
 pub use distributed_recording::{
     DistributedError, DistributedEvent, DistributedEventType, FleetEvent, FleetEventRef,
@@ -6,12 +6,15 @@
 //! - Replay with tick-perfect determinism
 //! - Mix recordings from different runs
 //! - Time travel to specific ticks
+//! - Memory-mapped, lazily decoded reading of large recordings
 
+use memmap2::MmapOptions;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::{self, File};
-use std::io::{BufReader, BufWriter};
-use std::path::PathBuf;
+use std::io::{BufReader, BufWriter, Read, Seek, Write};
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
 /// Directory for storing recordings
@@ -20,6 +23,9 @@ const RECORDINGS_DIR: &str = ".horus/recordings";
 /// Recording file extension
 const RECORDING_EXT: &str = "horus";
 
+/// Subdirectory of a session holding scheduler checkpoints
+const CHECKPOINTS_SUBDIR: &str = "checkpoints";
+
 /// Maximum recording size (100MB per node by default)
 const MAX_RECORDING_SIZE: usize = 100 * 1024 * 1024;
 
@@ -112,6 +118,50 @@ impl RecordingConfig {
         self.session_dir()
             .join(format!("scheduler@{}.{}", scheduler_id, RECORDING_EXT))
     }
+
+    /// Get the path for a checkpoint taken at `tick`
+    pub fn checkpoint_path(&self, tick: u64) -> PathBuf {
+        session_checkpoint_path(&self.session_dir(), tick)
+    }
+}
+
+fn session_checkpoint_path(session_dir: &Path, tick: u64) -> PathBuf {
+    session_dir
+        .join(CHECKPOINTS_SUBDIR)
+        .join(format!("tick_{:012}.ckpt", tick))
+}
+
+/// List checkpoints stored in a recording session, sorted by tick
+pub fn list_session_checkpoints(session_dir: &Path) -> Vec<(u64, PathBuf)> {
+    let mut checkpoints: Vec<(u64, PathBuf)> = fs::read_dir(session_dir.join(CHECKPOINTS_SUBDIR))
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .filter_map(|e| {
+                    let path = e.path();
+                    let tick = path
+                        .file_name()?
+                        .to_str()?
+                        .strip_prefix("tick_")?
+                        .strip_suffix(".ckpt")?
+                        .parse::<u64>()
+                        .ok()?;
+                    Some((tick, path))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    checkpoints.sort_by_key(|(tick, _)| *tick);
+    checkpoints
+}
+
+/// Find the latest checkpoint in a session taken at or before `tick`
+pub fn find_session_checkpoint(session_dir: &Path, tick: u64) -> Option<(u64, PathBuf)> {
+    list_session_checkpoints(session_dir)
+        .into_iter()
+        .rev()
+        .find(|(checkpoint_tick, _)| *checkpoint_tick <= tick)
 }
 
 /// A snapshot of a node's state at a specific tick
@@ -135,10 +185,7 @@ impl NodeTickSnapshot {
     pub fn new(tick: u64) -> Self {
         Self {
             tick,
-            timestamp_us: SystemTime::now()
-                .duration_since(SystemTime::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_micros() as u64,
+            timestamp_us: crate::core::clock::now_us(),
             inputs: HashMap::new(),
             outputs: HashMap::new(),
             state: None,
@@ -219,7 +266,13 @@ impl NodeRecording {
 
     /// Get snapshot for a specific tick
     pub fn get_snapshot(&self, tick: u64) -> Option<&NodeTickSnapshot> {
-        self.snapshots.iter().find(|s| s.tick == tick)
+        // Snapshots are appended in tick order, so try a binary search first
+        // and only scan when the vector was built out of order.
+        let index = self.snapshots.partition_point(|s| s.tick < tick);
+        match self.snapshots.get(index) {
+            Some(snapshot) if snapshot.tick == tick => Some(snapshot),
+            _ => self.snapshots.iter().find(|s| s.tick == tick),
+        }
     }
 
     /// Get snapshots in a tick range
@@ -258,27 +311,426 @@ impl NodeRecording {
             .sum()
     }
 
-    /// Save to file
+    /// Get the recording metadata without the snapshots
+    pub fn header(&self) -> NodeRecordingHeader {
+        NodeRecordingHeader {
+            node_id: self.node_id.clone(),
+            node_name: self.node_name.clone(),
+            session_name: self.session_name.clone(),
+            started_at: self.started_at,
+            ended_at: self.ended_at,
+            first_tick: self.first_tick,
+            last_tick: self.last_tick,
+            config: self.config.clone(),
+        }
+    }
+
+    /// Build a recording from its metadata and snapshots
+    pub fn from_header(header: NodeRecordingHeader, snapshots: Vec<NodeTickSnapshot>) -> Self {
+        Self {
+            node_id: header.node_id,
+            node_name: header.node_name,
+            session_name: header.session_name,
+            started_at: header.started_at,
+            ended_at: header.ended_at,
+            first_tick: header.first_tick,
+            last_tick: header.last_tick,
+            snapshots,
+            config: header.config,
+        }
+    }
+
+    /// Save to file in the indexed format (see [`RecordingReader`])
     pub fn save(&self, path: &PathBuf) -> std::io::Result<()> {
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
 
         let file = File::create(path)?;
-        let writer = BufWriter::new(file);
+        write_indexed(self, BufWriter::new(file))
+    }
 
-        // Use bincode for efficient serialization
-        bincode::serialize_into(writer, self).map_err(|e| std::io::Error::other(e.to_string()))?;
+    /// Load from file, decoding every snapshot.
+    ///
+    /// Accepts both the indexed format and the older whole-file bincode
+    /// format. Use [`RecordingReader`] to avoid loading large recordings
+    /// into memory.
+    pub fn load(path: &PathBuf) -> std::io::Result<Self> {
+        let mut file = File::open(path)?;
+        if has_indexed_magic(&mut file)? {
+            return RecordingReader::open(path)?.to_recording();
+        }
+        file.rewind()?;
 
-        Ok(())
+        bincode::deserialize_from(BufReader::new(file)).map_err(bincode_error)
     }
+}
 
-    /// Load from file
-    pub fn load(path: &PathBuf) -> std::io::Result<Self> {
-        let file = File::open(path)?;
-        let reader = BufReader::new(file);
+// ============================================================================
+// Indexed recording format and memory-mapped reader
+// ============================================================================
+//
+// Layout written by `NodeRecording::save` (integers are little-endian):
+//
+//   magic "HORUSIDX" | version u32 | reserved u32
+//   snapshot blobs, one bincode-encoded `NodeTickSnapshot` each
+//   header: bincode-encoded `NodeRecordingHeader`
+//   index: count x (tick u64, offset u64, length u64), sorted by tick
+//   footer: header offset u64 | header length u64 | index offset u64 | count u64
+//
+// The header, index and footer come last so snapshots can be streamed out
+// without knowing how many there are.
+
+/// Magic bytes at the start of an indexed recording
+const INDEXED_MAGIC: &[u8; 8] = b"HORUSIDX";
+
+/// Indexed recording format version
+const INDEXED_VERSION: u32 = 1;
+
+/// Magic + version + reserved
+const INDEXED_PREAMBLE_LEN: usize = 16;
+
+/// Tick + offset + length
+const INDEX_ENTRY_LEN: usize = 24;
+
+/// Header offset + header length + index offset + count
+const INDEXED_FOOTER_LEN: usize = 32;
+
+fn bincode_error(e: bincode::Error) -> std::io::Error {
+    std::io::Error::other(e.to_string())
+}
 
-        bincode::deserialize_from(reader).map_err(|e| std::io::Error::other(e.to_string()))
+fn invalid_recording(msg: &str) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::InvalidData,
+        format!("Invalid recording: {}", msg),
+    )
+}
+
+fn read_u64_le(data: &[u8], offset: usize) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&data[offset..offset + 8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// Convert an (offset, length) pair from the file into a range that ends at
+/// or before `limit`
+fn checked_range(offset: u64, len: u64, limit: usize) -> std::io::Result<Range<usize>> {
+    let start = usize::try_from(offset).map_err(|_| invalid_recording("offset out of range"))?;
+    let len = usize::try_from(len).map_err(|_| invalid_recording("length out of range"))?;
+    match start.checked_add(len) {
+        Some(end) if end <= limit => Ok(start..end),
+        _ => Err(invalid_recording(
+            "section extends past the end of the file",
+        )),
+    }
+}
+
+/// Check for the indexed magic, leaving the file position after it.
+/// Files shorter than the magic are reported as not indexed.
+fn has_indexed_magic(file: &mut File) -> std::io::Result<bool> {
+    let mut magic = [0u8; 8];
+    match file.read_exact(&mut magic) {
+        Ok(()) => Ok(&magic == INDEXED_MAGIC),
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Write a recording in the indexed format
+fn write_indexed<W: Write>(recording: &NodeRecording, mut writer: W) -> std::io::Result<()> {
+    writer.write_all(INDEXED_MAGIC)?;
+    writer.write_all(&INDEXED_VERSION.to_le_bytes())?;
+    writer.write_all(&0u32.to_le_bytes())?;
+    let mut offset = INDEXED_PREAMBLE_LEN as u64;
+
+    let mut index = Vec::with_capacity(recording.snapshots.len());
+    for snapshot in &recording.snapshots {
+        let blob = bincode::serialize(snapshot).map_err(bincode_error)?;
+        writer.write_all(&blob)?;
+        index.push((snapshot.tick, offset, blob.len() as u64));
+        offset += blob.len() as u64;
+    }
+
+    let header = bincode::serialize(&recording.header()).map_err(bincode_error)?;
+    writer.write_all(&header)?;
+    let header_offset = offset;
+    offset += header.len() as u64;
+
+    // Stable sort keeps duplicate ticks in recording order
+    index.sort_by_key(|&(tick, _, _)| tick);
+    for (tick, blob_offset, len) in &index {
+        writer.write_all(&tick.to_le_bytes())?;
+        writer.write_all(&blob_offset.to_le_bytes())?;
+        writer.write_all(&len.to_le_bytes())?;
+    }
+
+    for value in [
+        header_offset,
+        header.len() as u64,
+        offset,
+        index.len() as u64,
+    ] {
+        writer.write_all(&value.to_le_bytes())?;
+    }
+    writer.flush()
+}
+
+/// Recording metadata without the snapshots
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NodeRecordingHeader {
+    /// Node ID (unique identifier)
+    pub node_id: String,
+    /// Node name
+    pub node_name: String,
+    /// Recording session name
+    pub session_name: String,
+    /// When recording started
+    pub started_at: u64,
+    /// When recording ended
+    pub ended_at: Option<u64>,
+    /// First tick recorded
+    pub first_tick: u64,
+    /// Last tick recorded
+    pub last_tick: u64,
+    /// Node configuration at recording time
+    pub config: Option<String>,
+}
+
+/// Bytes backing a [`RecordingReader`]
+enum RecordingBytes {
+    Mapped(memmap2::Mmap),
+    Owned(Vec<u8>),
+}
+
+impl std::ops::Deref for RecordingBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            RecordingBytes::Mapped(mmap) => mmap,
+            RecordingBytes::Owned(data) => data,
+        }
+    }
+}
+
+/// Memory-mapped reader for a saved node recording.
+///
+/// Opening a recording only decodes its header; each snapshot is decoded
+/// when it is accessed, so multi-gigabyte sessions can be replayed and
+/// diffed without reading them into memory. Tick lookups use the on-disk
+/// index: O(1) when ticks are evenly spaced (every tick, or every
+/// `interval` ticks), a binary search otherwise.
+///
+/// Files in the older whole-file bincode format, compressed or not, are
+/// still accepted but are decoded into memory when opened.
+pub struct RecordingReader {
+    data: RecordingBytes,
+    header: NodeRecordingHeader,
+    /// Byte offset of the first index entry
+    index_offset: usize,
+    /// Number of snapshots
+    count: usize,
+    /// Tick spacing when every snapshot is the same distance apart
+    stride: Option<u64>,
+}
+
+impl RecordingReader {
+    /// Open a recording file
+    pub fn open(path: &Path) -> std::io::Result<Self> {
+        let mut file = File::open(path)?;
+        if !has_indexed_magic(&mut file)? {
+            let recording = load_recording_compressed(&path.to_path_buf())?;
+            return Self::from_recording(&recording);
+        }
+
+        // SAFETY: the mapping is read-only and recordings are not modified
+        // after they are written. All offsets read from the file are
+        // bounds-checked before use.
+        let mmap = unsafe { MmapOptions::new().map(&file)? };
+        Self::parse(RecordingBytes::Mapped(mmap))
+    }
+
+    /// Create a reader over an in-memory recording
+    pub fn from_recording(recording: &NodeRecording) -> std::io::Result<Self> {
+        let mut data = Vec::new();
+        write_indexed(recording, &mut data)?;
+        Self::parse(RecordingBytes::Owned(data))
+    }
+
+    fn parse(data: RecordingBytes) -> std::io::Result<Self> {
+        if data.len() < INDEXED_PREAMBLE_LEN + INDEXED_FOOTER_LEN || &data[..8] != INDEXED_MAGIC {
+            return Err(invalid_recording("missing indexed recording header"));
+        }
+        let mut version = [0u8; 4];
+        version.copy_from_slice(&data[8..12]);
+        let version = u32::from_le_bytes(version);
+        if version != INDEXED_VERSION {
+            return Err(invalid_recording(&format!(
+                "unsupported format version {}",
+                version
+            )));
+        }
+
+        let footer = data.len() - INDEXED_FOOTER_LEN;
+        let header_range = checked_range(
+            read_u64_le(&data, footer),
+            read_u64_le(&data, footer + 8),
+            footer,
+        )?;
+        let count = usize::try_from(read_u64_le(&data, footer + 24))
+            .map_err(|_| invalid_recording("snapshot count out of range"))?;
+        let index_len = count
+            .checked_mul(INDEX_ENTRY_LEN)
+            .ok_or_else(|| invalid_recording("snapshot count out of range"))?;
+        let index_range = checked_range(read_u64_le(&data, footer + 16), index_len as u64, footer)?;
+
+        let header: NodeRecordingHeader =
+            bincode::deserialize(&data[header_range.clone()]).map_err(bincode_error)?;
+
+        let mut reader = Self {
+            data,
+            header,
+            index_offset: index_range.start,
+            count,
+            stride: None,
+        };
+
+        // Validate the index once so lookups can trust it
+        let mut stride = None;
+        let mut evenly_spaced = true;
+        let mut previous_tick = None;
+        for i in 0..count {
+            let (tick, offset, len) = reader.entry(i);
+            checked_range(offset, len, header_range.start)?;
+            if let Some(previous) = previous_tick {
+                if tick < previous {
+                    return Err(invalid_recording("index is not sorted by tick"));
+                }
+                let step = tick - previous;
+                match stride {
+                    None => stride = Some(step),
+                    Some(s) if s != step => evenly_spaced = false,
+                    Some(_) => {}
+                }
+            }
+            previous_tick = Some(tick);
+        }
+        reader.stride = stride.filter(|&s| evenly_spaced && s > 0);
+
+        Ok(reader)
+    }
+
+    /// Read index entry `i` as (tick, offset, length)
+    fn entry(&self, i: usize) -> (u64, u64, u64) {
+        let base = self.index_offset + i * INDEX_ENTRY_LEN;
+        (
+            read_u64_le(&self.data, base),
+            read_u64_le(&self.data, base + 8),
+            read_u64_le(&self.data, base + 16),
+        )
+    }
+
+    /// Get the recording metadata
+    pub fn header(&self) -> &NodeRecordingHeader {
+        &self.header
+    }
+
+    /// Get total number of snapshots
+    pub fn snapshot_count(&self) -> usize {
+        self.count
+    }
+
+    /// Get the size of the recording in bytes
+    pub fn size_bytes(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Get the tick of the snapshot at `index`
+    pub fn tick_at(&self, index: usize) -> Option<u64> {
+        (index < self.count).then(|| self.entry(index).0)
+    }
+
+    /// Position of the first snapshot at or after `tick`
+    fn lower_bound(&self, tick: u64) -> usize {
+        if self.count == 0 {
+            return 0;
+        }
+        let first = self.entry(0).0;
+        if tick <= first {
+            return 0;
+        }
+
+        if let Some(stride) = self.stride {
+            let position = (tick - first).div_ceil(stride);
+            return usize::try_from(position).map_or(self.count, |p| p.min(self.count));
+        }
+
+        let (mut low, mut high) = (0, self.count);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            if self.entry(mid).0 < tick {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+        low
+    }
+
+    /// Get the position of the snapshot for `tick`
+    pub fn position_of(&self, tick: u64) -> Option<usize> {
+        let position = self.lower_bound(tick);
+        (self.tick_at(position) == Some(tick)).then_some(position)
+    }
+
+    /// Get the position of the first snapshot at or after `tick`
+    pub fn position_at_or_after(&self, tick: u64) -> Option<usize> {
+        let position = self.lower_bound(tick);
+        (position < self.count).then_some(position)
+    }
+
+    /// Decode the snapshot at `index`
+    pub fn snapshot_at(&self, index: usize) -> std::io::Result<Option<NodeTickSnapshot>> {
+        if index >= self.count {
+            return Ok(None);
+        }
+        let (_, offset, len) = self.entry(index);
+        // Bounds were checked when the index was validated
+        let range = checked_range(offset, len, self.data.len())?;
+        bincode::deserialize(&self.data[range])
+            .map(Some)
+            .map_err(bincode_error)
+    }
+
+    /// Decode the snapshot for a specific tick
+    pub fn snapshot(&self, tick: u64) -> std::io::Result<Option<NodeTickSnapshot>> {
+        match self.position_of(tick) {
+            Some(index) => self.snapshot_at(index),
+            None => Ok(None),
+        }
+    }
+
+    /// Decode snapshots in a tick range, one at a time
+    pub fn snapshots_range(
+        &self,
+        start_tick: u64,
+        end_tick: u64,
+    ) -> impl Iterator<Item = std::io::Result<NodeTickSnapshot>> + '_ {
+        let start = self.lower_bound(start_tick);
+        let end = self.lower_bound(end_tick.saturating_add(1));
+        (start..end.max(start)).filter_map(move |i| self.snapshot_at(i).transpose())
+    }
+
+    /// Decode all snapshots, one at a time
+    pub fn snapshots(&self) -> impl Iterator<Item = std::io::Result<NodeTickSnapshot>> + '_ {
+        (0..self.count).filter_map(move |i| self.snapshot_at(i).transpose())
+    }
+
+    /// Decode the whole recording into memory
+    pub fn to_recording(&self) -> std::io::Result<NodeRecording> {
+        let snapshots = self.snapshots().collect::<std::io::Result<Vec<_>>>()?;
+        Ok(NodeRecording::from_header(self.header.clone(), snapshots))
     }
 }
 
@@ -459,33 +911,84 @@ impl NodeRecorder {
 /// Replayer for a node recording
 pub struct NodeReplayer {
     recording: NodeRecording,
+    /// Lazily decoded source when replaying from a file
+    reader: Option<RecordingReader>,
+    /// Decoded snapshot at `current_index` when replaying from `reader`
+    current: Option<NodeTickSnapshot>,
     current_index: usize,
     current_tick: u64,
 }
 
 impl NodeReplayer {
-    /// Load a recording from file
+    /// Load a recording from file.
+    ///
+    /// The file is memory-mapped and snapshots are decoded as the replay
+    /// reaches them.
     pub fn load(path: &PathBuf) -> std::io::Result<Self> {
-        let recording = NodeRecording::load(path)?;
-        Ok(Self {
-            recording,
-            current_index: 0,
-            current_tick: 0,
-        })
+        Ok(Self::from_reader(RecordingReader::open(path)?))
     }
 
     /// Load from a recording struct
     pub fn from_recording(recording: NodeRecording) -> Self {
         Self {
             recording,
+            reader: None,
+            current: None,
+            current_index: 0,
+            current_tick: 0,
+        }
+    }
+
+    /// Replay from a recording reader
+    pub fn from_reader(reader: RecordingReader) -> Self {
+        let mut replayer = Self {
+            recording: NodeRecording::from_header(reader.header().clone(), Vec::new()),
+            reader: Some(reader),
+            current: None,
             current_index: 0,
             current_tick: 0,
+        };
+        replayer.decode_current();
+        replayer
+    }
+
+    /// Decode the snapshot at `current_index` when replaying from a reader
+    fn decode_current(&mut self) {
+        if let Some(reader) = &self.reader {
+            self.current = match reader.snapshot_at(self.current_index) {
+                Ok(snapshot) => snapshot,
+                Err(e) => {
+                    eprintln!(
+                        "Failed to decode snapshot {} of '{}': {}",
+                        self.current_index, self.recording.node_name, e
+                    );
+                    None
+                }
+            };
+        }
+    }
+
+    fn tick_at(&self, index: usize) -> Option<u64> {
+        match &self.reader {
+            Some(reader) => reader.tick_at(index),
+            None => self.recording.snapshots.get(index).map(|s| s.tick),
+        }
+    }
+
+    /// Position of the first snapshot at or after `tick`
+    fn position_at_or_after(&self, tick: u64) -> Option<usize> {
+        match &self.reader {
+            Some(reader) => reader.position_at_or_after(tick),
+            None => self.recording.snapshots.iter().position(|s| s.tick >= tick),
         }
     }
 
     /// Get the snapshot for the current tick
     pub fn current_snapshot(&self) -> Option<&NodeTickSnapshot> {
-        self.recording.snapshots.get(self.current_index)
+        match &self.reader {
+            Some(_) => self.current.as_ref(),
+            None => self.recording.snapshots.get(self.current_index),
+        }
     }
 
     /// Get outputs for the current tick
@@ -500,11 +1003,12 @@ impl NodeReplayer {
 
     /// Advance to the next tick
     pub fn advance(&mut self) -> bool {
-        if self.current_index + 1 < self.recording.snapshots.len() {
+        if self.current_index + 1 < self.total_ticks() {
             self.current_index += 1;
-            if let Some(snapshot) = self.recording.snapshots.get(self.current_index) {
-                self.current_tick = snapshot.tick;
+            if let Some(tick) = self.tick_at(self.current_index) {
+                self.current_tick = tick;
             }
+            self.decode_current();
             true
         } else {
             false
@@ -513,32 +1017,51 @@ impl NodeReplayer {
 
     /// Jump to a specific tick
     pub fn seek(&mut self, tick: u64) -> bool {
-        for (i, snapshot) in self.recording.snapshots.iter().enumerate() {
-            if snapshot.tick >= tick {
-                self.current_index = i;
-                self.current_tick = snapshot.tick;
-                return true;
+        match self.position_at_or_after(tick) {
+            Some(index) => {
+                self.current_index = index;
+                self.current_tick = self.tick_at(index).unwrap_or(tick);
+                self.decode_current();
+                true
             }
+            None => false,
         }
-        false
+    }
+
+    /// Get the tick of the last snapshot before the current tick
+    pub fn previous_tick(&self) -> Option<u64> {
+        let index = self
+            .position_at_or_after(self.current_tick)
+            .unwrap_or_else(|| self.total_ticks());
+        index.checked_sub(1).and_then(|i| self.tick_at(i))
     }
 
     /// Reset to the beginning
     pub fn reset(&mut self) {
         self.current_index = 0;
         self.current_tick = self.recording.first_tick;
+        self.decode_current();
     }
 
     /// Check if replay is finished
     pub fn is_finished(&self) -> bool {
-        self.current_index >= self.recording.snapshots.len()
+        self.current_index >= self.total_ticks()
     }
 
-    /// Get the recording
+    /// Get the recording.
+    ///
+    /// When replaying from a file only the metadata is loaded and
+    /// `snapshots` is empty; use [`current_snapshot`](Self::current_snapshot)
+    /// or [`reader`](Self::reader) for snapshot data.
     pub fn recording(&self) -> &NodeRecording {
         &self.recording
     }
 
+    /// Get the reader when replaying from a file
+    pub fn reader(&self) -> Option<&RecordingReader> {
+        self.reader.as_ref()
+    }
+
     /// Get current tick number
     pub fn current_tick(&self) -> u64 {
         self.current_tick
@@ -546,7 +1069,10 @@ impl NodeReplayer {
 
     /// Get total ticks in recording
     pub fn total_ticks(&self) -> usize {
-        self.recording.snapshots.len()
+        match &self.reader {
+            Some(reader) => reader.snapshot_count(),
+            None => self.recording.snapshots.len(),
+        }
     }
 }
 
@@ -670,59 +1196,93 @@ pub fn diff_recordings(
     let end = recording1.last_tick.min(recording2.last_tick);
 
     for tick in start..=end {
-        let snap1 = recording1.get_snapshot(tick);
-        let snap2 = recording2.get_snapshot(tick);
-
-        match (snap1, snap2) {
-            (Some(s1), Some(s2)) => {
-                // Compare outputs
-                for (topic, data1) in &s1.outputs {
-                    if let Some(data2) = s2.outputs.get(topic) {
-                        if data1 != data2 {
-                            diffs.push(RecordingDiff::OutputDifference {
-                                tick,
-                                topic: topic.clone(),
-                                recording1_size: data1.len(),
-                                recording2_size: data2.len(),
-                            });
-                        }
-                    } else {
-                        diffs.push(RecordingDiff::MissingOutput {
-                            tick,
-                            topic: topic.clone(),
-                            in_recording: 1,
-                        });
-                    }
-                }
+        diff_tick(
+            tick,
+            recording1.get_snapshot(tick),
+            recording2.get_snapshot(tick),
+            &mut diffs,
+        );
+    }
+
+    diffs
+}
+
+/// Compare two recording files for differences.
+///
+/// Both files are memory-mapped and compared one tick at a time, so only
+/// two snapshots are held in memory at once.
+pub fn diff_recording_files(path1: &Path, path2: &Path) -> std::io::Result<Vec<RecordingDiff>> {
+    let reader1 = RecordingReader::open(path1)?;
+    let reader2 = RecordingReader::open(path2)?;
+    let mut diffs = Vec::new();
+
+    // Find common tick range
+    let start = reader1.header().first_tick.max(reader2.header().first_tick);
+    let end = reader1.header().last_tick.min(reader2.header().last_tick);
+
+    for tick in start..=end {
+        let snap1 = reader1.snapshot(tick)?;
+        let snap2 = reader2.snapshot(tick)?;
+        diff_tick(tick, snap1.as_ref(), snap2.as_ref(), &mut diffs);
+    }
+
+    Ok(diffs)
+}
 
-                // Check for outputs only in recording2
-                for topic in s2.outputs.keys() {
-                    if !s1.outputs.contains_key(topic) {
-                        diffs.push(RecordingDiff::MissingOutput {
+/// Compare the snapshots of one tick
+fn diff_tick(
+    tick: u64,
+    snap1: Option<&NodeTickSnapshot>,
+    snap2: Option<&NodeTickSnapshot>,
+    diffs: &mut Vec<RecordingDiff>,
+) {
+    match (snap1, snap2) {
+        (Some(s1), Some(s2)) => {
+            // Compare outputs
+            for (topic, data1) in &s1.outputs {
+                if let Some(data2) = s2.outputs.get(topic) {
+                    if data1 != data2 {
+                        diffs.push(RecordingDiff::OutputDifference {
                             tick,
                             topic: topic.clone(),
-                            in_recording: 2,
+                            recording1_size: data1.len(),
+                            recording2_size: data2.len(),
                         });
                     }
+                } else {
+                    diffs.push(RecordingDiff::MissingOutput {
+                        tick,
+                        topic: topic.clone(),
+                        in_recording: 1,
+                    });
                 }
             }
-            (Some(_), None) => {
-                diffs.push(RecordingDiff::MissingTick {
-                    tick,
-                    in_recording: 2,
-                });
-            }
-            (None, Some(_)) => {
-                diffs.push(RecordingDiff::MissingTick {
-                    tick,
-                    in_recording: 1,
-                });
+
+            // Check for outputs only in recording2
+            for topic in s2.outputs.keys() {
+                if !s1.outputs.contains_key(topic) {
+                    diffs.push(RecordingDiff::MissingOutput {
+                        tick,
+                        topic: topic.clone(),
+                        in_recording: 2,
+                    });
+                }
             }
-            (None, None) => {}
         }
+        (Some(_), None) => {
+            diffs.push(RecordingDiff::MissingTick {
+                tick,
+                in_recording: 2,
+            });
+        }
+        (None, Some(_)) => {
+            diffs.push(RecordingDiff::MissingTick {
+                tick,
+                in_recording: 1,
+            });
+        }
+        (None, None) => {}
     }
-
-    diffs
 }
 
 /// Difference between two recordings
@@ -815,7 +1375,6 @@ impl Node for ReplayNode {
 pub fn compress_data(data: &[u8], level: i32) -> std::io::Result<Vec<u8>> {
     use flate2::write::GzEncoder;
     use flate2::Compression;
-    use std::io::Write;
 
     let compression_level = Compression::new(level.clamp(0, 9) as u32);
     let mut encoder = GzEncoder::new(Vec::new(), compression_level);
@@ -826,7 +1385,6 @@ pub fn compress_data(data: &[u8], level: i32) -> std::io::Result<Vec<u8>> {
 /// Decompress gzip-compressed data.
 pub fn decompress_data(data: &[u8]) -> std::io::Result<Vec<u8>> {
     use flate2::read::GzDecoder;
-    use std::io::Read;
 
     let mut decoder = GzDecoder::new(data);
     let mut decompressed = Vec::new();
@@ -867,7 +1425,11 @@ pub fn load_recording_compressed(path: &PathBuf) -> std::io::Result<NodeRecordin
         data
     };
 
-    bincode::deserialize(&decompressed).map_err(|e| std::io::Error::other(e.to_string()))
+    if decompressed.starts_with(INDEXED_MAGIC) {
+        return RecordingReader::parse(RecordingBytes::Owned(decompressed))?.to_recording();
+    }
+
+    bincode::deserialize(&decompressed).map_err(bincode_error)
 }
 
 // ============================================================================
@@ -1364,21 +1926,8 @@ impl ReplayDebugger {
             });
         }
 
-        // Find the previous snapshot
-        let recording = self.replayer.recording();
-        let current_tick = self.replayer.current_tick();
-
         // Find the previous tick in the recording
-        let mut prev_tick = None;
-        for snapshot in &recording.snapshots {
-            if snapshot.tick < current_tick {
-                prev_tick = Some(snapshot.tick);
-            } else {
-                break;
-            }
-        }
-
-        if let Some(tick) = prev_tick {
+        if let Some(tick) = self.replayer.previous_tick() {
             self.replayer.seek(tick);
             self.evaluate_watches();
             self.emit_event(DebugEvent::PositionChanged {
@@ -1948,6 +2497,28 @@ mod tests {
     use super::*;
     use tempfile::tempdir;
 
+    #[test]
+    fn test_session_checkpoint_lookup() {
+        let dir = tempdir().unwrap();
+        let config = RecordingConfig {
+            session_name: "ckpt_session".to_string(),
+            base_dir: dir.path().to_path_buf(),
+            ..Default::default()
+        };
+
+        for tick in [1000u64, 2000, 3000] {
+            let path = config.checkpoint_path(tick);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(&path, b"ckpt").unwrap();
+        }
+
+        let session_dir = config.session_dir();
+        assert_eq!(list_session_checkpoints(&session_dir).len(), 3);
+        assert_eq!(find_session_checkpoint(&session_dir, 2500).unwrap().0, 2000);
+        assert_eq!(find_session_checkpoint(&session_dir, 3000).unwrap().0, 3000);
+        assert!(find_session_checkpoint(&session_dir, 999).is_none());
+    }
+
     #[test]
     fn test_node_recording() {
         let mut recording = NodeRecording::new("test_node", "abc123", "test_session");
@@ -1987,6 +2558,81 @@ mod tests {
         assert_eq!(loaded.snapshot_count(), 1);
     }
 
+    #[test]
+    fn test_recording_reader() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("indexed.horus");
+
+        // Every other tick, as recorded with an interval of 2
+        let mut recording = NodeRecording::new("test_node", "abc123", "test_session");
+        for tick in (10..30).step_by(2) {
+            recording.add_snapshot(
+                NodeTickSnapshot::new(tick).with_output("out", tick.to_le_bytes().to_vec()),
+            );
+        }
+        recording.save(&path).unwrap();
+
+        let reader = RecordingReader::open(&path).unwrap();
+        assert_eq!(reader.header().node_name, "test_node");
+        assert_eq!(reader.header().first_tick, 10);
+        assert_eq!(reader.snapshot_count(), 10);
+        assert_eq!(reader.stride, Some(2));
+
+        let snapshot = reader.snapshot(16).unwrap().unwrap();
+        assert_eq!(snapshot.outputs["out"], 16u64.to_le_bytes().to_vec());
+        assert!(reader.snapshot(17).unwrap().is_none());
+        assert!(reader.snapshot(8).unwrap().is_none());
+        assert_eq!(reader.position_at_or_after(17), Some(4));
+        assert_eq!(reader.position_at_or_after(29), None);
+
+        let ticks: Vec<u64> = reader
+            .snapshots_range(13, 19)
+            .map(|s| s.unwrap().tick)
+            .collect();
+        assert_eq!(ticks, vec![14, 16, 18]);
+
+        let loaded = NodeRecording::load(&path).unwrap();
+        assert_eq!(loaded.snapshot_count(), 10);
+        assert_eq!(loaded.last_tick, 28);
+
+        // Irregular spacing falls back to binary search
+        let mut irregular = NodeRecording::new("test_node", "abc123", "test_session");
+        for tick in [1, 2, 5, 9, 100] {
+            irregular.add_snapshot(NodeTickSnapshot::new(tick));
+        }
+        let reader = RecordingReader::from_recording(&irregular).unwrap();
+        assert_eq!(reader.stride, None);
+        assert_eq!(reader.position_of(9), Some(3));
+        assert_eq!(reader.position_of(10), None);
+        assert_eq!(reader.position_at_or_after(10), Some(4));
+    }
+
+    #[test]
+    fn test_recording_reader_legacy_format() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("legacy.horus");
+
+        let mut recording = NodeRecording::new("test_node", "abc123", "test_session");
+        recording.add_snapshot(NodeTickSnapshot::new(0).with_output("out", vec![1]));
+        recording.add_snapshot(NodeTickSnapshot::new(1).with_output("out", vec![2]));
+        fs::write(&path, bincode::serialize(&recording).unwrap()).unwrap();
+
+        let loaded = NodeRecording::load(&path).unwrap();
+        assert_eq!(loaded.snapshot_count(), 2);
+
+        let mut replayer = NodeReplayer::load(&path).unwrap();
+        assert_eq!(replayer.total_ticks(), 2);
+        assert_eq!(replayer.get_output("out").unwrap(), &vec![1]);
+        assert!(replayer.advance());
+        assert_eq!(replayer.get_output("out").unwrap(), &vec![2]);
+
+        let compressed = dir.path().join("compressed.horus");
+        recording.save(&path).unwrap();
+        save_recording_compressed(&recording, &compressed, true).unwrap();
+        let diffs = diff_recording_files(&path, &compressed).unwrap();
+        assert!(diffs.is_empty());
+    }
+
     #[test]
     fn test_node_recorder() {
         let dir = tempdir().unwrap();
@@ -2037,6 +2683,35 @@ mod tests {
         assert_eq!(replayer.current_tick(), 0);
     }
 
+    #[test]
+    fn test_node_replayer_from_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("replay.horus");
+
+        let mut recording = NodeRecording::new("test_node", "abc123", "test_session");
+        for tick in 0..5u8 {
+            recording
+                .add_snapshot(NodeTickSnapshot::new(tick as u64).with_output("motor", vec![tick]));
+        }
+        recording.save(&path).unwrap();
+
+        let mut replayer = NodeReplayer::load(&path).unwrap();
+        assert!(replayer.reader().is_some());
+        assert!(replayer.recording().snapshots.is_empty());
+        assert_eq!(replayer.total_ticks(), 5);
+        assert_eq!(replayer.get_output("motor").unwrap(), &vec![0]);
+
+        assert!(replayer.seek(3));
+        assert_eq!(replayer.current_tick(), 3);
+        assert_eq!(replayer.get_output("motor").unwrap(), &vec![3]);
+        assert_eq!(replayer.previous_tick(), Some(2));
+
+        assert!(replayer.advance());
+        assert!(!replayer.advance());
+        assert_eq!(replayer.get_output("motor").unwrap(), &vec![4]);
+        assert!(!replayer.seek(10));
+    }
+
     #[test]
     fn test_recording_diff() {
         let mut recording1 = NodeRecording::new("node", "1", "session");
@@ -1,9 +1,10 @@
 // Safety monitor for real-time critical systems
+use crate::core::clock;
 use parking_lot::Mutex;
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 /// Safety state of the system
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,8 +26,8 @@ pub struct Watchdog {
     node_name: String,
     /// Timeout duration
     timeout: Duration,
-    /// Last heartbeat time
-    last_heartbeat: Mutex<Instant>,
+    /// Last heartbeat time (framework monotonic time, simulated with `use_sim_time`)
+    last_heartbeat: Mutex<Duration>,
     /// Is watchdog expired?
     expired: AtomicBool,
 }
@@ -36,21 +37,21 @@ impl Watchdog {
         Self {
             node_name,
             timeout,
-            last_heartbeat: Mutex::new(Instant::now()),
+            last_heartbeat: Mutex::new(clock::monotonic()),
             expired: AtomicBool::new(false),
         }
     }
 
     /// Feed the watchdog (reset timer)
     pub fn feed(&self) {
-        *self.last_heartbeat.lock() = Instant::now();
+        *self.last_heartbeat.lock() = clock::monotonic();
         self.expired.store(false, Ordering::SeqCst);
     }
 
     /// Check if watchdog has expired
     pub fn check(&self) -> bool {
         let last = *self.last_heartbeat.lock();
-        let expired = last.elapsed() > self.timeout;
+        let expired = clock::monotonic().saturating_sub(last) > self.timeout;
         if expired {
             self.expired.store(true, Ordering::SeqCst);
         }
@@ -64,6 +65,96 @@ impl Watchdog {
     pub fn node_name(&self) -> &str {
         &self.node_name
     }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+/// Action taken when a watchdog stays expired
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EscalationAction {
+    /// Publish the expiry reason (as a `String`) on an emergency stop topic
+    PublishEStop { topic: String },
+    /// Re-initialize the node instance
+    RestartNode,
+    /// Trigger the safety monitor emergency stop (stops the scheduler)
+    EmergencyStop,
+    /// Terminate the process with the given exit code
+    ExitProcess { code: i32 },
+}
+
+impl std::fmt::Display for EscalationAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EscalationAction::PublishEStop { topic } => write!(f, "publish e-stop on '{}'", topic),
+            EscalationAction::RestartNode => write!(f, "restart node"),
+            EscalationAction::EmergencyStop => write!(f, "emergency stop"),
+            EscalationAction::ExitProcess { code } => write!(f, "exit process ({})", code),
+        }
+    }
+}
+
+/// Ordered escalation steps for an expired watchdog
+///
+/// The first step runs as soon as the watchdog expires. Each following step
+/// runs after another `step_interval` without the watchdog being fed, and the
+/// last step is never repeated. Feeding the watchdog resets the escalation.
+///
+/// ```rust,ignore
+/// let policy = EscalationPolicy::new()
+///     .then(EscalationAction::RestartNode)
+///     .then(EscalationAction::PublishEStop { topic: "estop".to_string() })
+///     .then(EscalationAction::ExitProcess { code: 3 });
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EscalationPolicy {
+    steps: Vec<EscalationAction>,
+    /// Delay between steps (None = the watchdog timeout)
+    step_interval: Option<Duration>,
+}
+
+impl EscalationPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append an action to the escalation sequence
+    pub fn then(mut self, action: EscalationAction) -> Self {
+        self.steps.push(action);
+        self
+    }
+
+    /// Set the delay between escalation steps
+    pub fn with_step_interval(mut self, interval: Duration) -> Self {
+        self.step_interval = Some(interval);
+        self
+    }
+
+    pub fn steps(&self) -> &[EscalationAction] {
+        &self.steps
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+/// An escalation step that is due and must be executed by the scheduler
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EscalationEvent {
+    pub node_name: String,
+    /// Index of the step in the policy (0 = first action)
+    pub step: usize,
+    pub action: EscalationAction,
+    pub reason: String,
+}
+
+/// Progress through a policy for one watchdog
+#[derive(Debug, Default)]
+struct EscalationProgress {
+    next_step: usize,
+    last_step_at: Option<Duration>,
 }
 
 /// WCET (Worst-Case Execution Time) enforcer
@@ -149,6 +240,10 @@ pub struct SafetyMonitor {
     deadline_misses: AtomicU64,
     /// Maximum allowed deadline misses before emergency
     max_deadline_misses: u64,
+    /// Escalation policies per watchdog
+    escalation_policies: HashMap<String, EscalationPolicy>,
+    /// Escalation progress per watchdog (reset when fed)
+    escalation_progress: Mutex<HashMap<String, EscalationProgress>>,
 }
 
 impl SafetyMonitor {
@@ -161,6 +256,8 @@ impl SafetyMonitor {
             critical_nodes: Vec::new(),
             deadline_misses: AtomicU64::new(0),
             max_deadline_misses,
+            escalation_policies: HashMap::new(),
+            escalation_progress: Mutex::new(HashMap::new()),
         }
     }
 
@@ -173,6 +270,28 @@ impl SafetyMonitor {
         );
     }
 
+    /// Add a watchdog for a non-critical node (expiry only runs its escalation policy)
+    pub fn add_watchdog(&mut self, node_name: String, timeout: Duration) {
+        self.watchdogs
+            .lock()
+            .entry(node_name.clone())
+            .or_insert_with(|| Watchdog::new(node_name, timeout));
+    }
+
+    /// Set the escalation policy for a watchdog
+    ///
+    /// A critical node with a policy no longer triggers an immediate emergency
+    /// stop on expiry; its policy decides what happens instead.
+    pub fn set_escalation_policy(&mut self, node_name: String, policy: EscalationPolicy) {
+        self.escalation_progress.lock().remove(&node_name);
+        self.escalation_policies.insert(node_name, policy);
+    }
+
+    /// Get the escalation policy for a watchdog
+    pub fn get_escalation_policy(&self, node_name: &str) -> Option<&EscalationPolicy> {
+        self.escalation_policies.get(node_name)
+    }
+
     /// Set WCET budget for a node
     pub fn set_wcet_budget(&mut self, node_name: String, budget: Duration) {
         self.wcet_enforcer.lock().set_budget(node_name, budget);
@@ -182,6 +301,9 @@ impl SafetyMonitor {
     pub fn feed_watchdog(&self, node_name: &str) {
         if let Some(watchdog) = self.watchdogs.lock().get(node_name) {
             watchdog.feed();
+            if !self.escalation_policies.is_empty() {
+                self.escalation_progress.lock().remove(node_name);
+            }
         }
     }
 
@@ -195,9 +317,12 @@ impl SafetyMonitor {
         }
 
         // If any critical node watchdog expired, trigger emergency stop
+        // (unless an escalation policy handles it)
         if !expired.is_empty() {
             for node in &expired {
-                if self.critical_nodes.contains(node) {
+                if self.critical_nodes.contains(node)
+                    && !self.escalation_policies.contains_key(node)
+                {
                     self.trigger_emergency_stop(format!("Critical node {} watchdog expired", node));
                     break;
                 }
@@ -207,6 +332,64 @@ impl SafetyMonitor {
         expired
     }
 
+    /// Advance escalation for expired watchdogs and return the steps now due
+    ///
+    /// `EmergencyStop` steps are applied here; all other actions are returned
+    /// for the caller (the scheduler) to execute and record.
+    pub fn check_escalations(&self) -> Vec<EscalationEvent> {
+        if self.escalation_policies.is_empty() {
+            return Vec::new();
+        }
+
+        let now = clock::monotonic();
+        let mut due = Vec::new();
+        {
+            let watchdogs = self.watchdogs.lock();
+            let mut progress = self.escalation_progress.lock();
+            for (name, policy) in &self.escalation_policies {
+                let Some(watchdog) = watchdogs.get(name) else {
+                    continue;
+                };
+                if !watchdog.is_expired() || policy.is_empty() {
+                    continue;
+                }
+
+                let state = progress.entry(name.clone()).or_default();
+                if state.next_step >= policy.steps.len() {
+                    continue;
+                }
+                let interval = policy.step_interval.unwrap_or(watchdog.timeout);
+                let ready = state
+                    .last_step_at
+                    .map(|t| now.saturating_sub(t) >= interval)
+                    .unwrap_or(true);
+                if !ready {
+                    continue;
+                }
+
+                due.push(EscalationEvent {
+                    node_name: name.clone(),
+                    step: state.next_step,
+                    action: policy.steps[state.next_step].clone(),
+                    reason: format!(
+                        "Node {} watchdog expired (no tick for {:?})",
+                        name, watchdog.timeout
+                    ),
+                });
+                state.next_step += 1;
+                state.last_step_at = Some(now);
+            }
+        }
+
+        for event in &due {
+            if event.action == EscalationAction::EmergencyStop {
+                self.trigger_emergency_stop(event.reason.clone());
+            }
+        }
+
+        due
+    }
+
     /// Check WCET budget for a node
     pub fn check_wcet(
         &self,
@@ -308,3 +491,45 @@ pub struct SafetyStats {
     pub deadline_misses: u64,
     pub watchdog_expirations: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escalation_policy_steps() {
+        let mut monitor = SafetyMonitor::new(u64::MAX);
+        monitor.add_critical_node("motor".to_string(), Duration::from_millis(1));
+        monitor.set_escalation_policy(
+            "motor".to_string(),
+            EscalationPolicy::new()
+                .then(EscalationAction::RestartNode)
+                .then(EscalationAction::EmergencyStop)
+                .with_step_interval(Duration::ZERO),
+        );
+
+        std::thread::sleep(Duration::from_millis(5));
+        assert_eq!(monitor.check_watchdogs(), vec!["motor".to_string()]);
+        // Policy replaces the immediate critical-node emergency stop
+        assert!(!monitor.is_emergency_stop());
+
+        let first = monitor.check_escalations();
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].step, 0);
+        assert_eq!(first[0].action, EscalationAction::RestartNode);
+
+        let second = monitor.check_escalations();
+        assert_eq!(second[0].action, EscalationAction::EmergencyStop);
+        assert!(monitor.is_emergency_stop());
+
+        // Last step is not repeated
+        assert!(monitor.check_escalations().is_empty());
+
+        // Feeding resets the escalation
+        monitor.reset_counters();
+        monitor.feed_watchdog("motor");
+        std::thread::sleep(Duration::from_millis(5));
+        monitor.check_watchdogs();
+        assert_eq!(monitor.check_escalations()[0].step, 0);
+    }
+}
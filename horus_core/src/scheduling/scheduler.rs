@@ -1,9 +1,10 @@
-use crate::core::{Node, NodeHeartbeat, NodeInfo};
+use crate::communication::Hub;
+use crate::core::{Node, NodeHeartbeat, NodeInfo, NodeState};
 use crate::error::HorusResult;
 use crate::memory::platform::{shm_control_dir, shm_heartbeats_dir};
 use crate::terminal::print_line;
 use colored::Colorize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -12,8 +13,8 @@ use std::time::{Duration, Instant};
 
 // Record/Replay imports
 use super::record_replay::{
-    NodeRecorder, NodeReplayer, RecordingConfig, RecordingManager, ReplayMode, ReplayNode,
-    SchedulerRecording,
+    find_session_checkpoint, NodeRecorder, NodeReplayer, RecordingConfig, RecordingManager,
+    ReplayMode, ReplayNode, SchedulerRecording,
 };
 
 // Global flag for SIGTERM handling
@@ -30,14 +31,15 @@ extern "C" fn sigterm_handler(_signum: libc::c_int) {
 }
 
 // Import intelligence modules
+use super::degradation::{DegradationPolicy, DegradationTransition, SystemHealth};
 use super::executors::{
     AsyncIOExecutor, AsyncResult, BackgroundExecutor, IsolatedExecutor, IsolatedNodeConfig,
-    ParallelExecutor,
+    NodeStateEvent, ParallelExecutor, NODE_STATE_TOPIC,
 };
 use super::fault_tolerance::CircuitBreaker;
 use super::intelligence::{DependencyGraph, ExecutionTier, RuntimeProfiler, TierClassifier};
 use super::jit::CompiledDataflow;
-use super::safety_monitor::SafetyMonitor;
+use super::safety_monitor::{EscalationAction, EscalationPolicy, SafetyMonitor, SafetyState};
 use tokio::sync::mpsc;
 
 /// Node control command for IPC-based lifecycle management
@@ -73,6 +75,32 @@ struct RegisteredNode {
     is_paused: bool,  // Node is temporarily paused
 }
 
+impl RegisteredNode {
+    /// Time budget for a single tick: the explicit deadline for RT nodes,
+    /// otherwise the period of a rate-limited node
+    fn tick_deadline(&self) -> Option<Duration> {
+        if self.is_rt_node && self.deadline.is_some() {
+            return self.deadline;
+        }
+        self.rate_hz
+            .filter(|rate| *rate > 0.0)
+            .map(|rate| Duration::from_secs_f64(1.0 / rate))
+    }
+}
+
+/// Stops a running scheduler, see [`Scheduler::stop_handle`]
+#[derive(Debug, Clone)]
+pub struct SchedulerStopHandle(Arc<Mutex<bool>>);
+
+impl SchedulerStopHandle {
+    /// Ask the scheduler to stop after the current tick
+    pub fn stop(&self) {
+        if let Ok(mut running) = self.0.lock() {
+            *running = false;
+        }
+    }
+}
+
 /// Performance metrics for a scheduler node
 ///
 /// Returned by `Scheduler::get_metrics()` to provide performance data
@@ -107,6 +135,16 @@ pub struct SchedulerNodeMetrics {
     pub warnings_count: u64,
     /// Node uptime in seconds
     pub uptime_seconds: f64,
+    /// Ticks that overran their deadline or rate period
+    pub deadline_misses: u64,
+    /// 95th percentile tick start jitter over the recent window (microseconds)
+    pub jitter_p95_us: f64,
+    /// 99th percentile tick start jitter over the recent window (microseconds)
+    pub jitter_p99_us: f64,
+    /// 95th percentile tick duration over the recent window (microseconds)
+    pub tick_p95_us: f64,
+    /// Worst tick duration over the recent window (microseconds)
+    pub worst_tick_duration_us: f64,
 }
 
 /// Central orchestrator: holds nodes, drives the tick loop.
@@ -138,6 +176,19 @@ pub struct Scheduler {
 
     // Safety monitor for real-time critical systems
     safety_monitor: Option<SafetyMonitor>,
+    // Watchdog escalation policies (timeout, policy), re-applied when the monitor is rebuilt
+    escalation_policies: HashMap<String, (Duration, EscalationPolicy)>,
+    // Publishers for PublishEStop escalation actions, keyed by topic
+    estop_publishers: HashMap<String, Hub<String>>,
+    // Publisher for isolated node crash/restart events (created on first event)
+    node_state_publisher: Option<Hub<NodeStateEvent>>,
+    // Degradation profiles driven by safety state and node health
+    degradation: Option<DegradationPolicy>,
+    // Nodes paused by the active degradation profile (manual pauses are left alone)
+    degradation_paused: HashSet<String>,
+
+    // Max messages delivered to each Hub::on_message callback per scheduler cycle
+    callback_batch_size: usize,
 
     // === New runtime features ===
     // Tick rate enforcement
@@ -152,6 +203,9 @@ pub struct Scheduler {
     // Telemetry
     telemetry: Option<super::telemetry::TelemetryManager>,
 
+    // Liveness/readiness probe for process supervisors
+    health_probe: Option<super::health_probe::HealthProbe>,
+
     // Redundancy manager
     redundancy: Option<super::redundancy::RedundancyManager>,
 
@@ -166,6 +220,18 @@ pub struct Scheduler {
     #[allow(dead_code)] // Reserved for future deterministic mode
     collected_subscribers: Vec<(String, String, String)>, // (node_name, topic, type)
 
+    // === Topic-dependency ordering ===
+    // Order nodes each tick so consumers run after producers
+    topic_ordering: bool,
+    // Current execution order (rebuilt when the topology generation changes)
+    topic_graph: Option<DependencyGraph>,
+    // Topology generation topic_graph was built for
+    topic_graph_generation: (u64, u64),
+    // Topology generation self.nodes was last sorted for
+    nodes_sorted_generation: Option<(u64, u64)>,
+    // Bumped whenever a node is added to or removed from this scheduler
+    node_generation: u64,
+
     // === Record/Replay System ===
     // Recording configuration (None = recording disabled)
     recording_config: Option<RecordingConfig>,
@@ -183,6 +249,23 @@ pub struct Scheduler {
     replay_stop_tick: Option<u64>,
     // Replay speed multiplier (1.0 = normal, 0.5 = half speed, 2.0 = double)
     replay_speed: f64,
+    // Session directory of the loaded recordings (for checkpoint lookup)
+    replay_session_dir: Option<PathBuf>,
+    // Checkpoint (and its tick) used to restore live nodes when replay starts
+    replay_checkpoint: Option<(u64, super::checkpoint::Checkpoint)>,
+    // Checkpoint restored at replay start, for crash restarts of isolated nodes
+    restored_checkpoint: Option<super::checkpoint::Checkpoint>,
+
+    // === Lockstep co-simulation ===
+    // Simulator-driven stepping (None = wall-clock tick period)
+    lockstep: Option<super::lockstep::LockstepClient>,
+    // Follows the clock topic while use_sim_time is on (created in run)
+    clock_source: Option<crate::core::clock::ClockSubscriber>,
+    // Instant that simulated time zero maps to for rate limiting
+    sim_epoch: Instant,
+
+    // Whether the node_plugins of horus.yaml were added (at most once)
+    pub(crate) node_plugins_loaded: bool,
 }
 
 impl Default for Scheduler {
@@ -233,12 +316,19 @@ impl Scheduler {
 
             // Safety monitor
             safety_monitor: None,
+            escalation_policies: HashMap::new(),
+            estop_publishers: HashMap::new(),
+            node_state_publisher: None,
+            degradation: None,
+            degradation_paused: HashSet::new(),
+            callback_batch_size: 64,
 
             // New runtime features (disabled by default)
             tick_period: Duration::from_micros(16667), // ~60Hz default
             checkpoint_manager: None,
             blackbox: None,
             telemetry: None,
+            health_probe: None,
             redundancy: None,
 
             // Deterministic topology tracking
@@ -246,6 +336,13 @@ impl Scheduler {
             collected_publishers: Vec::new(),
             collected_subscribers: Vec::new(),
 
+            // Topic-dependency ordering (disabled by default)
+            topic_ordering: false,
+            topic_graph: None,
+            topic_graph_generation: (0, 0),
+            nodes_sorted_generation: None,
+            node_generation: 0,
+
             // Record/Replay system (disabled by default)
             recording_config: None,
             scheduler_recording: None,
@@ -255,6 +352,13 @@ impl Scheduler {
             current_tick: 0,
             replay_stop_tick: None,
             replay_speed: 1.0,
+            replay_session_dir: None,
+            replay_checkpoint: None,
+            restored_checkpoint: None,
+            lockstep: None,
+            clock_source: None,
+            sim_epoch: Instant::now(),
+            node_plugins_loaded: false,
         }
     }
 
@@ -337,18 +441,441 @@ impl Scheduler {
         self
     }
 
+    /// Order node execution by topic dependencies instead of priority alone
+    ///
+    /// When enabled, the scheduler builds a graph from publisher/subscriber
+    /// relationships (declared via `get_publishers()`/`get_subscribers()` and
+    /// registered by `Hub` on first send/recv) and runs nodes in topological
+    /// order every tick, so consumers always see this tick's data from their
+    /// producers. Priorities only break ties between independent nodes.
+    ///
+    /// The order is rebuilt whenever a node registers a new topic. Nodes that
+    /// form a dependency cycle are grouped and ordered by priority; the cycle
+    /// is reported once and available via `topic_cycles()`.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use horus_core::Scheduler;
+    /// let scheduler = Scheduler::new()
+    ///     .enable_topic_ordering();  // Producers before consumers
+    /// ```
+    pub fn enable_topic_ordering(mut self) -> Self {
+        self.topic_ordering = true;
+        self
+    }
+
+    /// Check if topic-dependency ordering is enabled
+    pub fn is_topic_ordering_enabled(&self) -> bool {
+        self.topic_ordering
+    }
+
+    /// Get the node execution order used for the next tick
+    ///
+    /// With topic ordering enabled this is the topological order; otherwise
+    /// nodes are listed by priority.
+    pub fn execution_order(&mut self) -> Vec<String> {
+        if self.topic_ordering {
+            self.refresh_topic_order();
+            if let Some(ref graph) = self.topic_graph {
+                return graph.order.clone();
+            }
+        }
+        let mut nodes: Vec<(u32, String)> = self
+            .nodes
+            .iter()
+            .map(|r| (r.priority, r.node.name().to_string()))
+            .collect();
+        nodes.sort_by_key(|(priority, _)| *priority);
+        nodes.into_iter().map(|(_, name)| name).collect()
+    }
+
+    /// Get dependency cycles detected by topic ordering
+    ///
+    /// Each entry lists the nodes of one cycle in the order they are executed.
+    pub fn topic_cycles(&self) -> Vec<Vec<String>> {
+        self.topic_graph
+            .as_ref()
+            .map(|graph| graph.cycles.clone())
+            .unwrap_or_default()
+    }
+
     /// Enable safety monitor with maximum allowed deadline misses
     pub fn with_safety_monitor(mut self, max_deadline_misses: u64) -> Self {
-        self.safety_monitor = Some(SafetyMonitor::new(max_deadline_misses));
+        let mut monitor = SafetyMonitor::new(max_deadline_misses);
+        self.apply_escalation_policies(&mut monitor);
+        self.safety_monitor = Some(monitor);
+        self
+    }
+
+    /// Watch a node and escalate through `policy` when it stops ticking
+    ///
+    /// The watchdog expires when the node has not ticked for `watchdog_timeout`.
+    /// Steps then run in order (see `EscalationPolicy`) and each executed step
+    /// is recorded to the black box. Enables the safety monitor if needed.
+    ///
+    /// ```rust,ignore
+    /// use horus_core::scheduling::{EscalationAction, EscalationPolicy};
+    ///
+    /// scheduler.set_escalation_policy(
+    ///     "motor_driver",
+    ///     Duration::from_millis(100),
+    ///     EscalationPolicy::new()
+    ///         .then(EscalationAction::RestartNode)
+    ///         .then(EscalationAction::PublishEStop { topic: "estop".to_string() })
+    ///         .then(EscalationAction::ExitProcess { code: 3 }),
+    /// );
+    /// ```
+    pub fn set_escalation_policy(
+        &mut self,
+        node_name: &str,
+        watchdog_timeout: Duration,
+        policy: EscalationPolicy,
+    ) -> &mut Self {
+        self.escalation_policies
+            .insert(node_name.to_string(), (watchdog_timeout, policy.clone()));
+
+        let monitor = self
+            .safety_monitor
+            .get_or_insert_with(|| SafetyMonitor::new(u64::MAX));
+        monitor.add_watchdog(node_name.to_string(), watchdog_timeout);
+        monitor.set_escalation_policy(node_name.to_string(), policy);
+        self
+    }
+
+    /// Register stored escalation policies with a (new) safety monitor
+    fn apply_escalation_policies(&self, monitor: &mut SafetyMonitor) {
+        for (node_name, (timeout, policy)) in &self.escalation_policies {
+            monitor.add_watchdog(node_name.clone(), *timeout);
+            monitor.set_escalation_policy(node_name.clone(), policy.clone());
+        }
+    }
+
+    /// Execute watchdog escalation steps that are due
+    fn handle_watchdog_escalations(&mut self) {
+        let events = match self.safety_monitor {
+            Some(ref monitor) => monitor.check_escalations(),
+            None => return,
+        };
+
+        for event in events {
+            eprintln!(
+                "{}",
+                format!(
+                    "[WATCHDOG] {} - escalation step {}: {}",
+                    event.reason,
+                    event.step + 1,
+                    event.action
+                )
+                .red()
+            );
+
+            if let Some(ref mut bb) = self.blackbox {
+                bb.record(super::blackbox::BlackBoxEvent::WatchdogEscalation {
+                    name: event.node_name.clone(),
+                    step: event.step,
+                    action: event.action.to_string(),
+                    reason: event.reason.clone(),
+                });
+            }
+
+            match event.action {
+                EscalationAction::PublishEStop { ref topic } => {
+                    if !self.estop_publishers.contains_key(topic) {
+                        match Hub::new(topic) {
+                            Ok(hub) => {
+                                self.estop_publishers.insert(topic.clone(), hub);
+                            }
+                            Err(e) => {
+                                eprintln!(
+                                    "[WATCHDOG] Failed to open e-stop topic '{}': {}",
+                                    topic, e
+                                );
+                                continue;
+                            }
+                        }
+                    }
+                    if let Some(hub) = self.estop_publishers.get(topic) {
+                        let _ = hub.send(event.reason.clone(), &mut None);
+                    }
+                }
+                EscalationAction::RestartNode => {
+                    for registered in self.nodes.iter_mut() {
+                        if registered.node.name() == event.node_name {
+                            registered.is_stopped = false;
+                            registered.is_paused = false;
+                            registered.initialized = false;
+                            if let Some(ref mut ctx) = registered.context {
+                                ctx.reset_for_restart();
+                            }
+                            break;
+                        }
+                    }
+                }
+                // Already applied by the safety monitor
+                EscalationAction::EmergencyStop => {}
+                EscalationAction::ExitProcess { code } => {
+                    if let Some(ref mut bb) = self.blackbox {
+                        bb.record(super::blackbox::BlackBoxEvent::SchedulerStop {
+                            reason: format!("Watchdog escalation: {}", event.reason),
+                            total_ticks: self.current_tick,
+                        });
+                        if let Err(e) = bb.save() {
+                            eprintln!("[BLACKBOX] Failed to save: {}", e);
+                        }
+                    }
+                    std::process::exit(code);
+                }
+            }
+        }
+    }
+
+    /// Switch between degradation profiles as system health changes
+    ///
+    /// Each cycle the safety monitor state, expired watchdogs and node health
+    /// are evaluated against the policy. Entering a profile pauses the nodes
+    /// it disables, resumes the ones the previous profile disabled, sets its
+    /// parameters on every node and records the change to the black box.
+    /// See `DegradationPolicy`.
+    pub fn with_degradation_policy(mut self, policy: DegradationPolicy) -> Self {
+        self.degradation = Some(policy);
+        self
+    }
+
+    /// Name of the active degradation profile
+    pub fn degradation_profile(&self) -> Option<&str> {
+        self.degradation
+            .as_ref()
+            .and_then(|policy| policy.current())
+            .map(|profile| profile.name())
+    }
+
+    /// Enter a degradation profile regardless of health (e.g. operator request)
+    ///
+    /// Health-driven switching stays off until `release_degradation_profile`.
+    pub fn force_degradation_profile(&mut self, name: &str) -> HorusResult<&mut Self> {
+        let policy = self.degradation.as_mut().ok_or_else(|| {
+            crate::error::HorusError::Config("No degradation policy configured".to_string())
+        })?;
+        let transition = policy.force(name)?;
+        self.apply_degradation(transition);
+        Ok(self)
+    }
+
+    /// Return to health-driven degradation profile selection
+    pub fn release_degradation_profile(&mut self) -> &mut Self {
+        if let Some(ref mut policy) = self.degradation {
+            policy.release();
+        }
+        self
+    }
+
+    /// Aggregate safety state, expired watchdogs and node health
+    fn system_health(&self) -> SystemHealth {
+        let (safety_state, mut expired_watchdogs) = match self.safety_monitor {
+            Some(ref monitor) => (monitor.get_state(), monitor.check_watchdogs()),
+            None => (SafetyState::Normal, Vec::new()),
+        };
+        // Nodes paused by a profile stop feeding their watchdog; that must not
+        // keep the profile active forever
+        expired_watchdogs.retain(|name| !self.degradation_paused.contains(name));
+
+        let node_health = self
+            .nodes
+            .iter()
+            .filter(|r| !self.degradation_paused.contains(r.node.name()))
+            .filter_map(|r| {
+                let ctx = r.context.as_ref()?;
+                let heartbeat = NodeHeartbeat::from_metrics(ctx.state().clone(), ctx.metrics());
+                Some((r.node.name().to_string(), heartbeat.health))
+            })
+            .collect();
+
+        SystemHealth {
+            safety_state,
+            node_health,
+            expired_watchdogs,
+        }
+    }
+
+    /// Serve liveness and readiness probes for systemd, Docker or Kubernetes
+    ///
+    /// `endpoint` is `http://ADDR:PORT` (or `:PORT`), `systemd`, or a directory
+    /// for status files (see `health_probe`). Health is derived from the safety
+    /// monitor and node heartbeats; the process stops being live when the
+    /// scheduler loop stalls.
+    pub fn with_health_probe(mut self, endpoint: &str) -> Self {
+        let endpoint = super::health_probe::HealthProbeEndpoint::from_string(endpoint);
+        self.health_probe = Some(super::health_probe::HealthProbe::new(
+            endpoint,
+            &self.scheduler_name,
+        ));
         self
     }
 
+    /// Advance in lockstep with a simulator instead of the wall clock
+    ///
+    /// Each cycle waits for the simulator's next step (see `lockstep`), runs
+    /// every node once and acknowledges it; there is no sleep between cycles.
+    /// Per-node rates and `run_for` durations are measured in simulated time,
+    /// so a run is reproducible however fast either process executes.
+    pub fn with_lockstep(mut self, config: super::lockstep::LockstepConfig) -> HorusResult<Self> {
+        self.lockstep = Some(super::lockstep::LockstepClient::new(config)?);
+        Ok(self)
+    }
+
+    /// Whether the scheduler is stepped by a simulator
+    pub fn is_lockstep(&self) -> bool {
+        self.lockstep.is_some()
+    }
+
+    /// Simulated time of the current step, for nodes that timestamp data
+    /// (`None` unless running in lockstep)
+    pub fn sim_clock(&self) -> Option<super::lockstep::SimClock> {
+        self.lockstep.as_ref().map(|lockstep| lockstep.clock())
+    }
+
+    /// Follow the simulator's clock topic instead of the system clock
+    ///
+    /// Enables `use_sim_time` for the whole process (see `core::clock`):
+    /// node rates, `run_for` durations, watchdogs, `NodeInfo::now`, HFrame
+    /// timestamps and recordings all use the simulated time published by
+    /// sim2d/sim3d. Nodes with a rate limit wait until simulated time advances.
+    pub fn with_sim_time(self) -> Self {
+        crate::core::clock::set_use_sim_time(true);
+        self
+    }
+
+    /// Time used for rate limiting: simulated in lockstep or with
+    /// `use_sim_time`, wall clock otherwise
+    fn clock_now(&self) -> Instant {
+        match self.lockstep {
+            Some(ref lockstep) => lockstep.instant(),
+            None if crate::core::clock::use_sim_time() => {
+                self.sim_epoch + crate::core::clock::sim_time()
+            }
+            None => Instant::now(),
+        }
+    }
+
+    /// Publish liveness and readiness if the probe interval elapsed
+    fn update_health_probe(&mut self) {
+        if !self
+            .health_probe
+            .as_ref()
+            .is_some_and(|probe| probe.should_update())
+        {
+            return;
+        }
+        let health = self.system_health();
+        let failed_nodes: Vec<String> = self
+            .nodes
+            .iter()
+            .filter(|r| !self.degradation_paused.contains(r.node.name()))
+            .filter(|r| {
+                r.context.as_ref().is_some_and(|ctx| {
+                    matches!(ctx.state(), NodeState::Error(_) | NodeState::Crashed(_))
+                })
+            })
+            .map(|r| r.node.name().to_string())
+            .collect();
+        let report = super::health_probe::ProbeReport::evaluate(
+            &self.scheduler_name,
+            &health,
+            &failed_nodes,
+        );
+        if let Some(ref mut probe) = self.health_probe {
+            probe.update(report);
+        }
+    }
+
+    /// Evaluate the degradation policy and switch profile if needed
+    fn handle_degradation(&mut self) {
+        let now = Instant::now();
+        if !self
+            .degradation
+            .as_ref()
+            .is_some_and(|policy| policy.should_evaluate(now))
+        {
+            return;
+        }
+        let health = self.system_health();
+        let transition = match self.degradation {
+            Some(ref mut policy) => policy.evaluate(&health, now),
+            None => None,
+        };
+        if let Some(transition) = transition {
+            self.apply_degradation(transition);
+        }
+    }
+
+    /// Pause/resume nodes and set parameters for a new degradation profile
+    fn apply_degradation(&mut self, transition: DegradationTransition) {
+        eprintln!(
+            "{}",
+            format!(
+                "[DEGRADATION] {} -> {} ({})",
+                transition.from, transition.to, transition.reason
+            )
+            .yellow()
+        );
+
+        let disabled: HashSet<&str> = transition
+            .disabled_nodes
+            .iter()
+            .map(|n| n.as_str())
+            .collect();
+        for registered in self.nodes.iter_mut() {
+            let name = registered.node.name();
+            if disabled.contains(name) {
+                if !registered.is_paused {
+                    registered.is_paused = true;
+                    self.degradation_paused.insert(name.to_string());
+                }
+            } else if self.degradation_paused.remove(name) {
+                registered.is_paused = false;
+            }
+
+            if let Some(ref mut ctx) = registered.context {
+                for (key, value) in &transition.params {
+                    if let Err(e) = ctx.params.set(key, value) {
+                        eprintln!(
+                            "[DEGRADATION] Failed to set '{}' on node '{}': {}",
+                            key, name, e
+                        );
+                    }
+                }
+            }
+        }
+
+        if let Some(ref mut bb) = self.blackbox {
+            bb.record(super::blackbox::BlackBoxEvent::Custom {
+                category: "degradation".to_string(),
+                message: format!(
+                    "{} -> {} ({})",
+                    transition.from, transition.to, transition.reason
+                ),
+            });
+        }
+    }
+
     /// Set scheduler name (for debugging/logging)
     pub fn with_name(mut self, name: &str) -> Self {
         self.scheduler_name = name.to_string();
         self
     }
 
+    /// Run this robot stack in a namespace (`robot1`), prefixing its topics
+    ///
+    /// Sets the process namespace (see `communication::namespace`), so it
+    /// applies to hubs and links created afterwards; create the scheduler
+    /// before the nodes. Overrides `HORUS_NAMESPACE`.
+    pub fn with_namespace(self, namespace: &str) -> Self {
+        if let Err(e) = crate::communication::namespace::set_namespace(namespace) {
+            eprintln!("[SCHEDULER] {}", e);
+        }
+        self
+    }
+
     // ============================================================================
     // Record/Replay System
     // ============================================================================
@@ -357,6 +884,8 @@ impl Scheduler {
     ///
     /// When enabled, all node inputs/outputs are recorded to disk for later replay.
     /// Recordings are saved to `~/.horus/recordings/<session_name>/`.
+    /// When checkpointing is enabled, every checkpoint is also stored in the
+    /// session (`checkpoints/`), so replay can start from it.
     ///
     /// # Example
     /// ```no_run
@@ -457,6 +986,10 @@ impl Scheduler {
         let node_name = replayer.recording().node_name.clone();
         let node_id = replayer.recording().node_id.clone();
 
+        if self.replay_session_dir.is_none() {
+            self.replay_session_dir = recording_path.parent().map(|p| p.to_path_buf());
+        }
+
         println!(
             "{}",
             format!(
@@ -475,6 +1008,7 @@ impl Scheduler {
         self.replay_nodes.insert(node_name.clone(), replayer);
 
         // Add as a registered node with replay flag
+        self.node_generation += 1;
         self.nodes.push(RegisteredNode {
             node: Box::new(replay_node),
             priority,
@@ -553,6 +1087,12 @@ impl Scheduler {
 
     /// Set replay to start at a specific tick (time travel).
     ///
+    /// Replayed nodes jump straight to `tick`. Live nodes mixed into the replay
+    /// can't seek, so if the session holds a checkpoint at or before `tick`
+    /// their state is restored from it
+    /// when the scheduler starts, and the replay resumes at the checkpoint's
+    /// tick instead of tick 0.
+    ///
     /// # Example
     /// ```no_run
     /// use horus_core::Scheduler;
@@ -571,10 +1111,76 @@ impl Scheduler {
             replayer.seek(tick);
         }
 
+        // Remember the closest checkpoint for live nodes (applied in run)
+        self.replay_checkpoint = None;
+        if let Some(ref session_dir) = self.replay_session_dir {
+            if let Some((checkpoint_tick, path)) = find_session_checkpoint(session_dir, tick) {
+                match super::checkpoint::Checkpoint::load_from(&path) {
+                    Ok(checkpoint) => self.replay_checkpoint = Some((checkpoint_tick, checkpoint)),
+                    Err(e) => eprintln!(
+                        "[REPLAY] Failed to load checkpoint {}: {}",
+                        path.display(),
+                        e
+                    ),
+                }
+            }
+        }
+
         println!("{}", format!("[REPLAY] Starting at tick {}", tick).cyan());
         self
     }
 
+    /// Restore live nodes from the checkpoint selected by `start_at_tick`
+    ///
+    /// If any node was restored, replayed nodes are rewound to the checkpoint's
+    /// tick so live nodes see the same inputs they saw when recording. The
+    /// checkpoint is kept for nodes later moved to the isolated executor.
+    fn restore_replay_checkpoint(&mut self) {
+        let Some((checkpoint_tick, checkpoint)) = self.replay_checkpoint.take() else {
+            return;
+        };
+
+        let mut restored = 0;
+        for registered in self.nodes.iter_mut() {
+            if registered.is_replay_node || !registered.node.supports_checkpointing() {
+                continue;
+            }
+            let node_name = registered.node.name();
+            let state = checkpoint
+                .node_states
+                .get(node_name)
+                .and_then(|n| n.custom_state.as_ref());
+            if let Some(state) = state {
+                match registered.node.restore_state(state) {
+                    Ok(()) => restored += 1,
+                    Err(e) => eprintln!(
+                        "[REPLAY] Failed to restore '{}' from checkpoint: {}",
+                        node_name, e
+                    ),
+                }
+            }
+        }
+
+        if restored == 0 {
+            return;
+        }
+
+        self.current_tick = checkpoint_tick;
+        for replayer in self.replay_nodes.values_mut() {
+            replayer.seek(checkpoint_tick);
+        }
+
+        println!(
+            "{}",
+            format!(
+                "[REPLAY] Restored {} live node(s) from checkpoint at tick {}",
+                restored, checkpoint_tick
+            )
+            .cyan()
+        );
+        self.restored_checkpoint = Some(checkpoint);
+    }
+
     /// Set an override value for what-if testing during replay.
     ///
     /// # Example
@@ -1390,6 +1996,7 @@ impl Scheduler {
         // Get rate from node (can be overridden via set_node_rate)
         let node_rate = node.rate_hz();
 
+        self.node_generation += 1;
         self.nodes.push(RegisteredNode {
             node,
             priority,
@@ -1465,6 +2072,7 @@ impl Scheduler {
         // Get rate from node (can be overridden via set_node_rate)
         let node_rate = node.rate_hz();
 
+        self.node_generation += 1;
         self.nodes.push(RegisteredNode {
             node,
             priority,
@@ -1544,6 +2152,11 @@ impl Scheduler {
         }
     }
 
+    /// Handle that stops the scheduler from another thread while `run()` blocks
+    pub fn stop_handle(&self) -> SchedulerStopHandle {
+        SchedulerStopHandle(self.running.clone())
+    }
+
     /// Set per-node rate control (chainable)
     ///
     /// Allows individual nodes to run at different frequencies independent of the global scheduler rate.
@@ -1559,10 +2172,11 @@ impl Scheduler {
     ///     .set_node_rate("sensor", 100.0);  // Run sensor at 100Hz
     /// ```
     pub fn set_node_rate(&mut self, name: &str, rate_hz: f64) -> &mut Self {
+        let now = self.clock_now();
         for registered in self.nodes.iter_mut() {
             if registered.node.name() == name {
                 registered.rate_hz = Some(rate_hz);
-                registered.last_tick = Some(Instant::now());
+                registered.last_tick = Some(now);
                 println!("Set node '{}' rate to {:.1} Hz", name, rate_hz);
                 break;
             }
@@ -1570,6 +2184,33 @@ impl Scheduler {
         self
     }
 
+    /// Set the maximum number of messages each `Hub::on_message` callback
+    /// receives per scheduler cycle (chainable, minimum 1)
+    ///
+    /// Remaining messages stay queued for the next cycle, which bounds the time
+    /// spent in callbacks between ticks.
+    pub fn set_callback_batch_size(&mut self, max_messages: usize) -> &mut Self {
+        self.callback_batch_size = max_messages.max(1);
+        self
+    }
+
+    /// Dispatch pending messages to callbacks registered with `Hub::on_message`
+    fn dispatch_message_callbacks(&mut self, node_filter: Option<&[&str]>) {
+        let batch = self.callback_batch_size;
+        for registered in self.nodes.iter_mut() {
+            if !registered.initialized || registered.is_stopped || registered.is_paused {
+                continue;
+            }
+            let node_name = registered.node.name();
+            if !node_filter.is_none_or(|filter| filter.contains(&node_name)) {
+                continue;
+            }
+            if let Some(ref mut ctx) = registered.context {
+                ctx.dispatch_message_callbacks(batch);
+            }
+        }
+    }
+
     /// Main loop with automatic signal handling and cleanup
     pub fn run(&mut self) -> HorusResult<()> {
         self.run_with_filter(None, None)
@@ -1585,12 +2226,45 @@ impl Scheduler {
         self.run_with_filter(Some(node_names), Some(duration))
     }
 
+    /// Add the nodes of the plugins listed under `node_plugins:` in the
+    /// project's `horus.yaml`, unless they were already loaded
+    #[cfg(feature = "dynamic-plugins")]
+    fn load_node_plugins(&mut self) -> HorusResult<()> {
+        let manifest = self.working_dir.join("horus.yaml");
+        if self.node_plugins_loaded || !manifest.exists() {
+            return Ok(());
+        }
+        let added = crate::plugin::PluginNodeLoader::new()
+            .load_into(self, &manifest)
+            .map_err(|e| {
+                crate::error::HorusError::InitializationFailed(format!(
+                    "Failed to load node plugins from {}: {}",
+                    manifest.display(),
+                    e
+                ))
+            })?;
+        if added > 0 {
+            println!(
+                "[SCHEDULER] Loaded {} plugin node(s) from horus.yaml",
+                added
+            );
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "dynamic-plugins"))]
+    fn load_node_plugins(&mut self) -> HorusResult<()> {
+        Ok(())
+    }
+
     /// Internal method to run scheduler with optional node filtering and duration
     fn run_with_filter(
         &mut self,
         node_filter: Option<&[&str]>,
         duration: Option<Duration>,
     ) -> HorusResult<()> {
+        self.load_node_plugins()?;
+
         // Create tokio runtime for nodes that need async
         let rt = tokio::runtime::Runtime::new().map_err(|e| {
             crate::error::HorusError::Internal(format!("Failed to create tokio runtime: {}", e))
@@ -1627,6 +2301,14 @@ impl Scheduler {
                 libc::signal(libc::SIGTERM, sigterm_handler as libc::sighandler_t);
             }
 
+            // Report "live, not ready" while nodes initialize
+            if let Some(ref mut probe) = self.health_probe {
+                match probe.start() {
+                    Ok(()) => println!("[SCHEDULER] Health probe enabled ({})", probe.endpoint()),
+                    Err(e) => eprintln!("[HEALTH] Failed to start health probe: {}", e),
+                }
+            }
+
             // Initialize nodes
             for registered in self.nodes.iter_mut() {
                 let node_name = registered.node.name();
@@ -1651,6 +2333,9 @@ impl Scheduler {
                 }
             }
 
+            // Fast-forward replay: restore live nodes from a recorded checkpoint
+            self.restore_replay_checkpoint();
+
             // Suppress logging during learning phase for accurate profiling
             // (I/O from logging would skew execution time measurements)
             if !self.learning_complete {
@@ -1673,11 +2358,33 @@ impl Scheduler {
             // Build dependency graph from node pub/sub relationships
             self.build_dependency_graph();
 
+            // Ignore steps left over from an earlier co-simulation run
+            if let Some(ref mut lockstep) = self.lockstep {
+                lockstep.drain();
+                println!("[LOCKSTEP] Waiting for simulator steps");
+            } else if crate::core::clock::use_sim_time() && self.clock_source.is_none() {
+                match crate::core::clock::ClockSubscriber::new() {
+                    Ok(source) => {
+                        self.clock_source = Some(source);
+                        println!("[SIM TIME] Following '{}'", crate::core::clock::CLOCK_TOPIC);
+                    }
+                    Err(e) => eprintln!("[SIM TIME] Failed to subscribe to clock: {}", e),
+                }
+            }
+            let start_clock = self.clock_now();
+
             // Main tick loop
             while self.is_running() {
-                // Check if duration limit has been reached
+                // Apply the newest simulated time before anything reads the clock
+                if let Some(ref mut source) = self.clock_source {
+                    source.poll();
+                }
+
+                // Check if duration limit has been reached (simulated time in
+                // lockstep or with use_sim_time)
                 if let Some(max_duration) = duration {
-                    if start_time.elapsed() >= max_duration {
+                    let elapsed = self.clock_now().saturating_duration_since(start_clock);
+                    if elapsed >= max_duration {
                         println!("Scheduler reached time limit of {:?}", max_duration);
                         break;
                     }
@@ -1703,6 +2410,31 @@ impl Scheduler {
                     break;
                 }
 
+                // In lockstep, block until the simulator publishes its next step
+                if let Some(ref mut lockstep) = self.lockstep {
+                    let running = self.running.clone();
+                    let keep_waiting = move || {
+                        running.lock().map(|r| *r).unwrap_or(false)
+                            && !SIGTERM_RECEIVED.load(Ordering::SeqCst)
+                    };
+                    match lockstep.wait_step(keep_waiting) {
+                        super::lockstep::LockstepWait::Step(step) => {
+                            crate::core::clock::set_sim_time(Duration::from_nanos(
+                                step.sim_time_ns,
+                            ));
+                        }
+                        // Loop condition and SIGTERM check handle the shutdown
+                        super::lockstep::LockstepWait::Stopped => continue,
+                        super::lockstep::LockstepWait::TimedOut => {
+                            eprintln!(
+                                "{}",
+                                "[LOCKSTEP] Simulator stopped stepping - shutting down".red()
+                            );
+                            break;
+                        }
+                    }
+                }
+
                 // Process per-node control commands (stop, restart, pause, resume)
                 self.process_control_commands();
 
@@ -1820,6 +2552,9 @@ impl Scheduler {
                     self.profiler.tick();
                 }
 
+                // Deliver subscription callbacks between ticks (bounded per cycle)
+                self.dispatch_message_callbacks(node_filter);
+
                 // Check watchdogs and handle emergency stop for RT systems
                 if let Some(ref monitor) = self.safety_monitor {
                     // Check all watchdogs
@@ -1827,7 +2562,18 @@ impl Scheduler {
                     if !expired_watchdogs.is_empty() {
                         eprintln!(" Watchdog expired for nodes: {:?}", expired_watchdogs);
                     }
+                }
+
+                // Run escalation policies for expired watchdogs
+                self.handle_watchdog_escalations();
+
+                // Switch degradation profile on health changes
+                self.handle_degradation();
+
+                // Liveness/readiness for process supervisors
+                self.update_health_probe();
 
+                if let Some(ref monitor) = self.safety_monitor {
                     // Check if emergency stop was triggered
                     if monitor.is_emergency_stop() {
                         eprintln!(" Emergency stop activated - shutting down scheduler");
@@ -1967,40 +2713,83 @@ impl Scheduler {
                                     })
                                     .unwrap_or((0, 0, 0));
 
+                                let custom_state = if registered.node.supports_checkpointing() {
+                                    registered.node.checkpoint_state()
+                                } else {
+                                    None
+                                };
+
                                 let node_checkpoint = super::checkpoint::NodeCheckpoint {
                                     name: node_name.to_string(),
                                     tick_count,
                                     last_tick_us,
                                     error_count,
-                                    custom_state: None,
+                                    custom_state,
                                 };
                                 checkpoint
                                     .node_states
                                     .insert(node_name.to_string(), node_checkpoint);
                             }
 
+                            // Isolated nodes: latest state saved by their runners
+                            if let Some(ref executor) = self.isolated_executor {
+                                for (name, state) in executor.checkpoint_states() {
+                                    checkpoint.node_states.insert(
+                                        name.clone(),
+                                        super::checkpoint::NodeCheckpoint {
+                                            name,
+                                            tick_count: 0,
+                                            last_tick_us: 0,
+                                            error_count: 0,
+                                            custom_state: Some(state),
+                                        },
+                                    );
+                                }
+                            }
+
                             if let Err(e) = cm.save_checkpoint(&checkpoint) {
                                 eprintln!("[CHECKPOINT] Failed to save: {}", e);
                             }
+
+                            // Store in the recording session so replay can start here
+                            if let Some(ref config) = self.recording_config {
+                                let path = config.checkpoint_path(self.current_tick);
+                                if let Err(e) = checkpoint.save_to(&path) {
+                                    eprintln!(
+                                        "[CHECKPOINT] Failed to store in recording session: {}",
+                                        e
+                                    );
+                                }
+                            }
                         }
                     }
                 }
 
-                // Use pre-computed tick period (from config or default ~60Hz)
-                // Apply replay speed adjustment if in replay mode
-                let sleep_duration = if self.replay_mode.is_some() && self.replay_speed != 1.0 {
-                    Duration::from_nanos(
-                        (self.tick_period.as_nanos() as f64 / self.replay_speed) as u64,
-                    )
+                if let Some(ref lockstep) = self.lockstep {
+                    // The simulator takes its next physics step once acknowledged
+                    lockstep.ack();
                 } else {
-                    self.tick_period
-                };
-                tokio::time::sleep(sleep_duration).await;
+                    // Use pre-computed tick period (from config or default ~60Hz)
+                    // Apply replay speed adjustment if in replay mode
+                    let sleep_duration = if self.replay_mode.is_some() && self.replay_speed != 1.0 {
+                        Duration::from_nanos(
+                            (self.tick_period.as_nanos() as f64 / self.replay_speed) as u64,
+                        )
+                    } else {
+                        self.tick_period
+                    };
+                    tokio::time::sleep(sleep_duration).await;
+                }
 
                 // Increment tick counter for replay tracking
                 self.current_tick += 1;
             }
 
+            // Stop reporting ready before nodes shut down
+            if let Some(ref mut probe) = self.health_probe {
+                probe.shutdown();
+            }
+
             // Shutdown async I/O nodes first
             if let Some(ref mut executor) = self.async_io_executor {
                 executor.shutdown_all().await;
@@ -2140,6 +2929,11 @@ impl Scheduler {
                         errors_count: m.errors_count,
                         warnings_count: m.warnings_count,
                         uptime_seconds: m.uptime_seconds,
+                        deadline_misses: m.deadline_misses,
+                        jitter_p95_us: m.jitter_p95_us,
+                        jitter_p99_us: m.jitter_p99_us,
+                        tick_p95_us: m.tick_p95_us,
+                        worst_tick_duration_us: m.worst_tick_duration_us,
                     }
                 } else {
                     SchedulerNodeMetrics {
@@ -2541,10 +3335,119 @@ impl Scheduler {
         }
     }
 
+    /// Topology generation: (node additions/removals, topic registrations)
+    ///
+    /// Both parts only grow, so any change to the topology changes the pair.
+    fn topology_generation(&self) -> (u64, u64) {
+        let topics = self
+            .nodes
+            .iter()
+            .filter_map(|r| r.context.as_ref())
+            .map(|ctx| ctx.topic_generation())
+            .sum();
+        (self.node_generation, topics)
+    }
+
+    /// Rebuild the topic-dependency order if nodes or their topics changed
+    fn refresh_topic_order(&mut self) {
+        let generation = self.topology_generation();
+        if self.topic_graph.is_some() && generation == self.topic_graph_generation {
+            return;
+        }
+
+        let node_data: Vec<(&str, u32, Vec<String>, Vec<String>)> = self
+            .nodes
+            .iter()
+            .map(|r| {
+                let mut publishers: Vec<String> = r
+                    .node
+                    .get_publishers()
+                    .into_iter()
+                    .map(|p| p.topic_name)
+                    .collect();
+                let mut subscribers: Vec<String> = r
+                    .node
+                    .get_subscribers()
+                    .into_iter()
+                    .map(|s| s.topic_name)
+                    .collect();
+
+                // Topics registered by Hub at runtime
+                if let Some(ref ctx) = r.context {
+                    publishers.extend(
+                        ctx.get_registered_publishers()
+                            .into_iter()
+                            .map(|p| p.topic_name),
+                    );
+                    subscribers.extend(
+                        ctx.get_registered_subscribers()
+                            .into_iter()
+                            .map(|s| s.topic_name),
+                    );
+                }
+                publishers.sort();
+                publishers.dedup();
+                subscribers.sort();
+                subscribers.dedup();
+
+                (r.node.name(), r.priority, publishers, subscribers)
+            })
+            .collect();
+
+        let graph = DependencyGraph::from_prioritized_nodes(&node_data);
+        let previous_cycles = self.topic_cycles();
+        if graph.has_cycles() && graph.cycles != previous_cycles {
+            for cycle in &graph.cycles {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "WARNING: Topic dependency cycle: {} (ordered by priority)",
+                        cycle.join(" -> ")
+                    )
+                    .yellow()
+                );
+            }
+        }
+
+        self.topic_graph = Some(graph);
+        self.topic_graph_generation = generation;
+    }
+
+    /// Sort registered nodes into this tick's execution order
+    fn sort_nodes_for_tick(&mut self) {
+        if !self.topic_ordering {
+            self.nodes.sort_by_key(|r| r.priority);
+            return;
+        }
+
+        // Nodes only need re-sorting when the topology changed
+        let generation = self.topology_generation();
+        if self.nodes_sorted_generation == Some(generation) {
+            return;
+        }
+
+        self.refresh_topic_order();
+        self.nodes_sorted_generation = Some(generation);
+        if let Some(ref graph) = self.topic_graph {
+            let rank: HashMap<&str, usize> = graph
+                .order
+                .iter()
+                .enumerate()
+                .map(|(i, name)| (name.as_str(), i))
+                .collect();
+            self.nodes.sort_by_key(|r| {
+                (
+                    rank.get(r.node.name()).copied().unwrap_or(usize::MAX),
+                    r.priority,
+                )
+            });
+        }
+    }
+
     /// Execute nodes in learning mode (sequential with profiling)
     async fn execute_learning_mode(&mut self, node_filter: Option<&[&str]>) {
-        // Sort by priority
-        self.nodes.sort_by_key(|r| r.priority);
+        // Sort by priority (or by topic dependencies when enabled)
+        self.sort_nodes_for_tick();
 
         // We need to process nodes one at a time to avoid borrow checker issues
         let num_nodes = self.nodes.len();
@@ -2561,7 +3464,7 @@ impl Scheduler {
 
                 // Check rate limiting
                 let should_tick = if let Some(rate_hz) = registered.rate_hz {
-                    let current_time = Instant::now();
+                    let current_time = self.clock_now();
                     if let Some(last_tick) = registered.last_tick {
                         let elapsed_secs = (current_time - last_tick).as_secs_f64();
                         let period_secs = 1.0 / rate_hz;
@@ -2588,15 +3491,14 @@ impl Scheduler {
 
             // Update last tick time if rate limited
             if self.nodes[i].rate_hz.is_some() {
-                self.nodes[i].last_tick = Some(Instant::now());
+                let now = self.clock_now();
+                self.nodes[i].last_tick = Some(now);
             }
 
             if should_run && self.nodes[i].initialized {
-                // Feed watchdog for RT nodes
-                if self.nodes[i].is_rt_node {
-                    if let Some(ref monitor) = self.safety_monitor {
-                        monitor.feed_watchdog(node_name);
-                    }
+                // Feed watchdog (no-op for nodes without one)
+                if let Some(ref monitor) = self.safety_monitor {
+                    monitor.feed_watchdog(node_name);
                 }
 
                 let tick_start = Instant::now();
@@ -2638,11 +3540,14 @@ impl Scheduler {
                     }
                 }
 
-                // Check deadline for RT nodes
-                if self.nodes[i].is_rt_node {
-                    if let Some(deadline) = self.nodes[i].deadline {
-                        let elapsed = tick_start.elapsed();
-                        if elapsed > deadline {
+                // Check deadline (RT deadline or rate period)
+                if let Some(deadline) = self.nodes[i].tick_deadline() {
+                    let elapsed = tick_start.elapsed();
+                    if elapsed > deadline {
+                        if let Some(ref mut context) = self.nodes[i].context {
+                            context.record_deadline_miss();
+                        }
+                        if self.nodes[i].is_rt_node {
                             if let Some(ref monitor) = self.safety_monitor {
                                 monitor.record_deadline_miss(node_name);
                                 eprintln!(
@@ -2747,14 +3652,23 @@ impl Scheduler {
                 }
             }
         }
+        self.publish_node_state_events();
 
         // Execute nodes level by level (nodes in same level can run in parallel)
-        let levels = self
-            .dependency_graph
-            .as_ref()
-            .expect("Dependency graph should exist - checked above")
-            .levels
-            .clone();
+        let levels = if self.topic_ordering {
+            // Strict producer -> consumer order, one node per level
+            self.refresh_topic_order();
+            self.topic_graph
+                .as_ref()
+                .map(|graph| graph.order.iter().map(|n| vec![n.clone()]).collect())
+                .unwrap_or_default()
+        } else {
+            self.dependency_graph
+                .as_ref()
+                .expect("Dependency graph should exist - checked above")
+                .levels
+                .clone()
+        };
 
         for level in &levels {
             // Find indices of nodes in this level that should run
@@ -2773,7 +3687,7 @@ impl Scheduler {
 
                         // Check rate limiting
                         let should_tick = if let Some(rate_hz) = registered.rate_hz {
-                            let current_time = Instant::now();
+                            let current_time = self.clock_now();
                             if let Some(last_tick) = registered.last_tick {
                                 let elapsed_secs = (current_time - last_tick).as_secs_f64();
                                 let period_secs = 1.0 / rate_hz;
@@ -2830,19 +3744,18 @@ impl Scheduler {
 
         // Update rate limit timestamp
         if self.nodes[idx].rate_hz.is_some() {
-            self.nodes[idx].last_tick = Some(Instant::now());
+            let now = self.clock_now();
+            self.nodes[idx].last_tick = Some(now);
         }
 
         let node_name = self.nodes[idx].node.name();
         let is_rt_node = self.nodes[idx].is_rt_node;
         let wcet_budget = self.nodes[idx].wcet_budget;
-        let deadline = self.nodes[idx].deadline;
+        let deadline = self.nodes[idx].tick_deadline();
 
-        // Feed watchdog for RT nodes
-        if is_rt_node {
-            if let Some(ref monitor) = self.safety_monitor {
-                monitor.feed_watchdog(node_name);
-            }
+        // Feed watchdog (no-op for nodes without one)
+        if let Some(ref monitor) = self.safety_monitor {
+            monitor.feed_watchdog(node_name);
         }
 
         let tick_start = Instant::now();
@@ -2965,11 +3878,14 @@ impl Scheduler {
             }
         }
 
-        // Check deadline for RT nodes
-        if is_rt_node {
-            if let Some(deadline_duration) = deadline {
-                let elapsed = tick_start.elapsed();
-                if elapsed > deadline_duration {
+        // Check deadline (RT deadline or rate period)
+        if let Some(deadline_duration) = deadline {
+            let elapsed = tick_start.elapsed();
+            if elapsed > deadline_duration {
+                if let Some(ref mut context) = self.nodes[idx].context {
+                    context.record_deadline_miss();
+                }
+                if is_rt_node {
                     if let Some(ref monitor) = self.safety_monitor {
                         monitor.record_deadline_miss(node_name);
                         eprintln!(
@@ -3163,6 +4079,7 @@ impl Scheduler {
             for idx in nodes_to_move.into_iter().rev() {
                 // Remove from main scheduler
                 let registered = self.nodes.swap_remove(idx);
+                self.node_generation += 1;
                 let node_name = registered.node.name().to_string();
 
                 // Spawn in async executor
@@ -3224,6 +4141,7 @@ impl Scheduler {
             for idx in nodes_to_move.into_iter().rev() {
                 // Remove from main scheduler
                 let registered = self.nodes.swap_remove(idx);
+                self.node_generation += 1;
                 let node_name = registered.node.name().to_string();
 
                 // Spawn in background executor
@@ -3256,6 +4174,41 @@ impl Scheduler {
         }
     }
 
+    /// Publish isolated node state changes and record them to the black box
+    fn publish_node_state_events(&mut self) {
+        let events = match self.isolated_executor {
+            Some(ref mut executor) => executor.drain_state_events(),
+            None => return,
+        };
+        if events.is_empty() {
+            return;
+        }
+
+        if self.node_state_publisher.is_none() {
+            match Hub::new(NODE_STATE_TOPIC) {
+                Ok(hub) => self.node_state_publisher = Some(hub),
+                Err(e) => eprintln!(
+                    "[Isolated] Failed to open '{}' topic: {}",
+                    NODE_STATE_TOPIC, e
+                ),
+            }
+        }
+
+        for event in events {
+            if let Some(ref mut bb) = self.blackbox {
+                bb.record(super::blackbox::BlackBoxEvent::NodeStateChange {
+                    name: event.node_name.clone(),
+                    previous_state: event.previous_state.clone(),
+                    new_state: event.new_state.clone(),
+                    restart_count: event.restart_count,
+                });
+            }
+            if let Some(ref hub) = self.node_state_publisher {
+                let _ = hub.send(event, &mut None);
+            }
+        }
+    }
+
     /// Setup isolated executor and move high-failure-rate nodes to it
     fn setup_isolated_executor(&mut self) {
         // Create isolated executor with default config
@@ -3266,6 +4219,7 @@ impl Scheduler {
             heartbeat_timeout: std::time::Duration::from_secs(10),
             runner_binary: None, // Use in-process mode by default
             env_vars: std::collections::HashMap::new(),
+            ..Default::default()
         };
 
         let mut iso_executor = match IsolatedExecutor::new(config) {
@@ -3296,6 +4250,7 @@ impl Scheduler {
             for idx in nodes_to_move.into_iter().rev() {
                 // Remove from main scheduler
                 let registered = self.nodes.swap_remove(idx);
+                self.node_generation += 1;
                 let node_name = registered.node.name().to_string();
 
                 // Spawn in isolated executor
@@ -3313,6 +4268,12 @@ impl Scheduler {
                     iso_executor.node_count()
                 );
 
+                // Crash restarts resume from the replayed state until the
+                // nodes checkpoint themselves
+                if let Some(checkpoint) = self.restored_checkpoint.take() {
+                    iso_executor.restore_from_checkpoint(&checkpoint);
+                }
+
                 // Start the watchdog for health monitoring
                 iso_executor.start_watchdog();
             }
@@ -3409,11 +4370,18 @@ impl Scheduler {
                 }
             }
 
+            self.apply_escalation_policies(&mut monitor);
             self.safety_monitor = Some(monitor);
             println!("Safety monitor configured for RT nodes");
         }
 
         // Apply timing configuration
+        if matches!(
+            config.timing.time_sync_source,
+            super::config::TimeSyncSource::SimTime
+        ) {
+            crate::core::clock::set_use_sim_time(true);
+        }
         if config.timing.per_node_rates {
             // Per-node rate control already supported via set_node_rate()
         }
@@ -3506,7 +4474,12 @@ impl Scheduler {
 
         // 3. Black box flight recorder
         if config.monitoring.black_box_enabled && config.monitoring.black_box_size_mb > 0 {
-            let mut bb = super::blackbox::BlackBox::new(config.monitoring.black_box_size_mb);
+            // Mirror into a crash-safe ring file for `horus blackbox dump`
+            let mut bb = super::blackbox::BlackBox::new(config.monitoring.black_box_size_mb)
+                .with_default_ring(
+                    &self.scheduler_name,
+                    config.monitoring.black_box_size_mb.min(64),
+                );
             bb.record(super::blackbox::BlackBoxEvent::SchedulerStart {
                 name: self.scheduler_name.clone(),
                 node_count: self.nodes.len(),
@@ -3529,7 +4502,20 @@ impl Scheduler {
             println!("[SCHEDULER] Telemetry enabled (endpoint: {})", endpoint_str);
         }
 
-        // 5. Redundancy (TMR)
+        // 5. Health probe
+        if let Some(ref endpoint_str) = config.monitoring.health_probe_endpoint {
+            let endpoint = super::health_probe::HealthProbeEndpoint::from_string(endpoint_str);
+            self.health_probe = Some(super::health_probe::HealthProbe::new(
+                endpoint,
+                &self.scheduler_name,
+            ));
+            println!(
+                "[SCHEDULER] Health probe configured (endpoint: {})",
+                endpoint_str
+            );
+        }
+
+        // 6. Redundancy (TMR)
         if config.fault.redundancy_factor > 1 {
             // Default to majority voting strategy
             let strategy = super::redundancy::VotingStrategy::Majority;
@@ -3543,7 +4529,7 @@ impl Scheduler {
             );
         }
 
-        // 6. Real-time optimizations (Linux-specific)
+        // 7. Real-time optimizations (Linux-specific)
         #[cfg(target_os = "linux")]
         {
             // Memory locking
@@ -3581,7 +4567,7 @@ impl Scheduler {
             }
         }
 
-        // 7. Recording configuration for record/replay system
+        // 8. Recording configuration for record/replay system
         if let Some(ref recording_yaml) = config.recording {
             if recording_yaml.enabled {
                 // Generate session name if not provided
@@ -3907,6 +4893,59 @@ mod tests {
         assert!(errors.is_empty());
     }
 
+    #[test]
+    fn test_scheduler_topic_ordering() {
+        let mut scheduler = Scheduler::new().enable_topic_ordering();
+        // Consumer has the higher priority but must still run after its producer
+        scheduler.add(Box::new(SubscriberNode::new("consumer", "scan")), 0, None);
+        scheduler.add(Box::new(PublisherNode::new("producer", "scan")), 10, None);
+        scheduler.add(Box::new(CounterNode::new("idle")), 5, None);
+
+        assert!(scheduler.is_topic_ordering_enabled());
+        assert_eq!(
+            scheduler.execution_order(),
+            vec!["idle", "producer", "consumer"]
+        );
+        assert!(scheduler.topic_cycles().is_empty());
+    }
+
+    #[test]
+    fn test_scheduler_topic_order_follows_runtime_registration() {
+        let mut scheduler = Scheduler::new().enable_topic_ordering();
+        scheduler.add(Box::new(CounterNode::new("first")), 0, None);
+        scheduler.add(Box::new(CounterNode::new("second")), 1, None);
+        assert_eq!(scheduler.execution_order(), vec!["first", "second"]);
+        let generation = scheduler.topic_graph_generation;
+
+        // Nothing changed: the cached graph is kept
+        scheduler.execution_order();
+        assert_eq!(scheduler.topic_graph_generation, generation);
+
+        // Hubs register topics at runtime; "first" now consumes what "second" produces
+        scheduler.nodes[0]
+            .context
+            .as_mut()
+            .unwrap()
+            .register_subscriber("odom", "Odometry");
+        scheduler.nodes[1]
+            .context
+            .as_mut()
+            .unwrap()
+            .register_publisher("odom", "Odometry");
+
+        assert_eq!(scheduler.execution_order(), vec!["second", "first"]);
+        assert_ne!(scheduler.topic_graph_generation, generation);
+    }
+
+    #[test]
+    fn test_scheduler_priority_order_without_topic_ordering() {
+        let mut scheduler = Scheduler::new();
+        scheduler.add(Box::new(SubscriberNode::new("consumer", "scan")), 0, None);
+        scheduler.add(Box::new(PublisherNode::new("producer", "scan")), 10, None);
+
+        assert_eq!(scheduler.execution_order(), vec!["consumer", "producer"]);
+    }
+
     #[test]
     fn test_scheduler_topology_locked() {
         let mut scheduler = Scheduler::new();
@@ -4054,6 +5093,39 @@ mod tests {
         assert!(scheduler.is_running());
     }
 
+    #[test]
+    fn test_scheduler_degradation_pauses_profile_nodes() {
+        use crate::scheduling::degradation::{DegradationPolicy, DegradationProfile};
+
+        let policy = DegradationPolicy::new()
+            .group("autonomy", &["planner"])
+            .profile(DegradationProfile::new("full"))
+            .profile(
+                DegradationProfile::new("teleop_only")
+                    .disable("autonomy")
+                    .set_param("max_speed", 0.2),
+            );
+        let mut scheduler = Scheduler::new().with_degradation_policy(policy);
+        scheduler.add(Box::new(SubscriberNode::new("planner", "plan")), 0, None);
+        scheduler.add(Box::new(SubscriberNode::new("teleop", "joy")), 1, None);
+        scheduler.add(Box::new(SubscriberNode::new("paused", "x")), 2, None);
+        scheduler.nodes[2].is_paused = true;
+        assert_eq!(scheduler.degradation_profile(), Some("full"));
+
+        scheduler.force_degradation_profile("teleop_only").unwrap();
+        assert_eq!(scheduler.degradation_profile(), Some("teleop_only"));
+        assert!(scheduler.nodes[0].is_paused);
+        assert!(!scheduler.nodes[1].is_paused);
+        let ctx = scheduler.nodes[1].context.as_ref().unwrap();
+        assert_eq!(ctx.params.get_f64("max_speed", 1.0), 0.2);
+
+        scheduler.force_degradation_profile("full").unwrap();
+        assert!(!scheduler.nodes[0].is_paused);
+        // Paused by hand, not by the profile
+        assert!(scheduler.nodes[2].is_paused);
+        assert!(scheduler.force_degradation_profile("missing").is_err());
+    }
+
     // ============================================================================
     // Real-time Node Tests
     // ============================================================================
@@ -4095,6 +5167,70 @@ mod tests {
         assert!(counter.load(Ordering::SeqCst) > 0);
     }
 
+    /// Node whose tick always overruns a 1 kHz period
+    struct SlowNode;
+
+    impl Node for SlowNode {
+        fn name(&self) -> &'static str {
+            "slow_node"
+        }
+
+        fn tick(&mut self, _ctx: Option<&mut NodeInfo>) {
+            std::thread::sleep(Duration::from_millis(3));
+        }
+    }
+
+    #[test]
+    fn test_scheduler_records_rate_deadline_misses() {
+        let mut scheduler = Scheduler::new();
+        scheduler.add(Box::new(SlowNode), 0, None);
+        scheduler.set_node_rate("slow_node", 1000.0);
+
+        scheduler.run_for(Duration::from_millis(50)).unwrap();
+
+        let metrics = scheduler.get_metrics();
+        assert_eq!(metrics.len(), 1);
+        assert!(metrics[0].deadline_misses > 0);
+        assert!(metrics[0].worst_tick_duration_us >= 3000.0);
+    }
+
+    #[test]
+    fn test_scheduler_lockstep_ticks_once_per_step() {
+        use super::super::lockstep::{LockstepConfig, LockstepDriver};
+
+        let config = LockstepConfig::default()
+            .with_topic(&format!("test.scheduler_lockstep.{}", std::process::id()));
+        let mut scheduler = Scheduler::new().with_lockstep(config.clone()).unwrap();
+        let every_step = Arc::new(AtomicUsize::new(0));
+        let rated = Arc::new(AtomicUsize::new(0));
+        scheduler.add(
+            Box::new(CounterNode::with_counter("every_step", every_step.clone())),
+            0,
+            None,
+        );
+        scheduler.add(
+            Box::new(CounterNode::with_counter("rated", rated.clone())),
+            1,
+            None,
+        );
+        scheduler.set_node_rate("rated", 50.0);
+
+        let simulator = std::thread::spawn(move || {
+            let mut driver = LockstepDriver::new(config).unwrap();
+            for _ in 0..10 {
+                driver.step(Duration::from_millis(10)).unwrap();
+            }
+        });
+
+        // 100ms of simulated time at 10ms per step
+        scheduler.run_for(Duration::from_millis(100)).unwrap();
+        simulator.join().unwrap();
+
+        assert_eq!(every_step.load(Ordering::SeqCst), 10);
+        // 50 Hz in simulated time: every other step
+        assert_eq!(rated.load(Ordering::SeqCst), 5);
+    }
+
     // ============================================================================
     // Chainable API Tests
     // ============================================================================
@@ -373,7 +373,7 @@ impl ZeroCopyRecorder {
             entry_type,
             flags: 0,
             _padding: [0; 2],
-            timestamp_ns: chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0) as u64,
+            timestamp_ns: crate::core::clock::now_ns(),
             data_len: data.len() as u32,
             crc32: 0, // Could compute CRC32 if needed
         };
@@ -939,7 +939,7 @@ impl StreamingRecorder {
             entry_type,
             flags: 0,
             _padding: [0; 2],
-            timestamp_ns: chrono::Utc::now().timestamp_nanos_opt().unwrap_or(0) as u64,
+            timestamp_ns: crate::core::clock::now_ns(),
             data_len: data.len() as u32,
             crc32: 0,
         };
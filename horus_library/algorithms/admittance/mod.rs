@@ -0,0 +1,354 @@
+//! Cartesian Admittance Controller
+//!
+//! Force-compliant control law for manipulators: measured wrenches are turned
+//! into Cartesian offsets that are added to the commanded pose, so the arm
+//! "gives" under contact instead of fighting it.
+//!
+//! Each of the six axes (x, y, z, rx, ry, rz) behaves like an independent
+//! mass-spring-damper:
+//!
+//! ```text
+//! M * x'' + D * x' + K * x = F_measured - F_reference
+//! ```
+//!
+//! # Features
+//!
+//! - Per-axis virtual mass, damping and stiffness
+//! - Optional reference wrench (e.g. press down with 5 N during insertion)
+//! - Per-axis force deadband to ignore sensor noise
+//! - Per-axis offset limits
+//! - Safety force limits that freeze the controller when exceeded
+//!
+//! # Example
+//!
+//! ```rust
+//! use horus_library::algorithms::admittance::{Admittance, AdmittanceStatus};
+//!
+//! let mut admittance = Admittance::new();
+//! admittance.set_stiffness([500.0, 500.0, 200.0, 50.0, 50.0, 50.0]);
+//! admittance.set_damping([100.0, 100.0, 80.0, 10.0, 10.0, 10.0]);
+//!
+//! // 10 N push along z
+//! let status = admittance.update([0.0, 0.0, 10.0, 0.0, 0.0, 0.0], 0.002);
+//! assert_eq!(status, AdmittanceStatus::Ok);
+//! assert!(admittance.offset()[2] > 0.0);
+//! ```
+
+/// Number of Cartesian axes handled by the controller (3 linear + 3 angular)
+pub const AXES: usize = 6;
+
+/// Result of a single admittance update
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AdmittanceStatus {
+    /// Controller integrated normally
+    Ok,
+    /// Measured wrench exceeded the safety limit on the given axis (0..6)
+    ForceLimitExceeded { axis: usize, value: f64 },
+}
+
+/// Cartesian admittance controller
+pub struct Admittance {
+    mass: [f64; AXES],
+    damping: [f64; AXES],
+    stiffness: [f64; AXES],
+
+    force_reference: [f64; AXES],
+    force_deadband: [f64; AXES],
+    force_limits: [f64; AXES],
+    max_offset: [f64; AXES],
+
+    offset: [f64; AXES],
+    velocity: [f64; AXES],
+}
+
+impl Admittance {
+    /// Create a controller with moderate compliance on every axis
+    pub fn new() -> Self {
+        Self {
+            mass: [1.0, 1.0, 1.0, 0.1, 0.1, 0.1],
+            damping: [50.0, 50.0, 50.0, 5.0, 5.0, 5.0],
+            stiffness: [1000.0, 1000.0, 1000.0, 100.0, 100.0, 100.0],
+            force_reference: [0.0; AXES],
+            force_deadband: [0.0; AXES],
+            force_limits: [f64::INFINITY; AXES],
+            max_offset: [f64::INFINITY; AXES],
+            offset: [0.0; AXES],
+            velocity: [0.0; AXES],
+        }
+    }
+
+    /// Set virtual mass per axis (values <= 0 are clamped to a small positive mass)
+    pub fn set_mass(&mut self, mass: [f64; AXES]) {
+        self.mass = mass.map(|m| m.max(1e-6));
+    }
+
+    /// Set damping per axis
+    pub fn set_damping(&mut self, damping: [f64; AXES]) {
+        self.damping = damping.map(|d| d.max(0.0));
+    }
+
+    /// Set stiffness per axis (0 = pure damping, the offset is not pulled back)
+    pub fn set_stiffness(&mut self, stiffness: [f64; AXES]) {
+        self.stiffness = stiffness.map(|k| k.max(0.0));
+    }
+
+    /// Set the desired contact wrench the controller should regulate towards
+    pub fn set_force_reference(&mut self, reference: [f64; AXES]) {
+        self.force_reference = reference;
+    }
+
+    /// Set per-axis deadband below which wrench errors are ignored
+    pub fn set_force_deadband(&mut self, deadband: [f64; AXES]) {
+        self.force_deadband = deadband.map(f64::abs);
+    }
+
+    /// Set per-axis safety limits on the measured wrench
+    pub fn set_force_limits(&mut self, limits: [f64; AXES]) {
+        self.force_limits = limits.map(f64::abs);
+    }
+
+    /// Set per-axis limits on the Cartesian offset (meters / radians)
+    pub fn set_max_offset(&mut self, max_offset: [f64; AXES]) {
+        self.max_offset = max_offset.map(f64::abs);
+    }
+
+    /// Reset offset and velocity to zero
+    pub fn reset(&mut self) {
+        self.offset = [0.0; AXES];
+        self.velocity = [0.0; AXES];
+    }
+
+    /// Integrate the admittance dynamics for one time step
+    ///
+    /// # Arguments
+    /// * `wrench` - Measured wrench [fx, fy, fz, tx, ty, tz]
+    /// * `dt` - Time step (seconds)
+    ///
+    /// # Returns
+    /// `ForceLimitExceeded` if any axis is above its safety limit. In that
+    /// case the state is left untouched so the caller can hold position.
+    pub fn update(&mut self, wrench: [f64; AXES], dt: f64) -> AdmittanceStatus {
+        for (axis, &value) in wrench.iter().enumerate() {
+            if !value.is_finite() || value.abs() > self.force_limits[axis] {
+                return AdmittanceStatus::ForceLimitExceeded { axis, value };
+            }
+        }
+
+        if dt <= 0.0 || !dt.is_finite() {
+            return AdmittanceStatus::Ok;
+        }
+
+        for (axis, &measured) in wrench.iter().enumerate() {
+            let mut error = measured - self.force_reference[axis];
+            if error.abs() < self.force_deadband[axis] {
+                error = 0.0;
+            }
+
+            // Semi-implicit Euler keeps stiff settings stable at servo rates
+            let accel = (error
+                - self.damping[axis] * self.velocity[axis]
+                - self.stiffness[axis] * self.offset[axis])
+                / self.mass[axis];
+            self.velocity[axis] += accel * dt;
+
+            let limit = self.max_offset[axis];
+            let next = self.offset[axis] + self.velocity[axis] * dt;
+            if next.abs() > limit {
+                self.offset[axis] = next.clamp(-limit, limit);
+                self.velocity[axis] = 0.0;
+            } else {
+                self.offset[axis] = next;
+            }
+        }
+
+        AdmittanceStatus::Ok
+    }
+
+    /// Current Cartesian offset [x, y, z, rx, ry, rz]
+    pub fn offset(&self) -> [f64; AXES] {
+        self.offset
+    }
+
+    /// Current offset velocity
+    pub fn velocity(&self) -> [f64; AXES] {
+        self.velocity
+    }
+
+    /// Apply the current offset to a target pose
+    ///
+    /// # Arguments
+    /// * `translation` - Target position [x, y, z]
+    /// * `rotation` - Target orientation quaternion [x, y, z, w]
+    ///
+    /// # Returns
+    /// Compliant pose (translation, rotation). The angular offset is treated
+    /// as a rotation vector expressed in the base frame.
+    pub fn apply_offset(&self, translation: [f64; 3], rotation: [f64; 4]) -> ([f64; 3], [f64; 4]) {
+        let position = [
+            translation[0] + self.offset[0],
+            translation[1] + self.offset[1],
+            translation[2] + self.offset[2],
+        ];
+
+        let delta = rotation_vector_to_quaternion([self.offset[3], self.offset[4], self.offset[5]]);
+        let orientation = normalize(quaternion_multiply(delta, rotation));
+
+        (position, orientation)
+    }
+}
+
+impl Default for Admittance {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn rotation_vector_to_quaternion(rv: [f64; 3]) -> [f64; 4] {
+    let angle = (rv[0] * rv[0] + rv[1] * rv[1] + rv[2] * rv[2]).sqrt();
+    if angle < 1e-12 {
+        return [0.0, 0.0, 0.0, 1.0];
+    }
+    let half = angle / 2.0;
+    let s = half.sin() / angle;
+    [rv[0] * s, rv[1] * s, rv[2] * s, half.cos()]
+}
+
+fn quaternion_multiply(a: [f64; 4], b: [f64; 4]) -> [f64; 4] {
+    let [ax, ay, az, aw] = a;
+    let [bx, by, bz, bw] = b;
+    [
+        aw * bx + ax * bw + ay * bz - az * by,
+        aw * by - ax * bz + ay * bw + az * bx,
+        aw * bz + ax * by - ay * bx + az * bw,
+        aw * bw - ax * bx - ay * by - az * bz,
+    ]
+}
+
+fn normalize(q: [f64; 4]) -> [f64; 4] {
+    let norm = q.iter().map(|v| v * v).sum::<f64>().sqrt();
+    if norm > 0.0 {
+        q.map(|v| v / norm)
+    } else {
+        [0.0, 0.0, 0.0, 1.0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zero_force_no_motion() {
+        let mut admittance = Admittance::new();
+        for _ in 0..100 {
+            admittance.update([0.0; AXES], 0.01);
+        }
+        assert_eq!(admittance.offset(), [0.0; AXES]);
+    }
+
+    #[test]
+    fn test_steady_state_matches_stiffness() {
+        let mut admittance = Admittance::new();
+        admittance.set_stiffness([100.0; AXES]);
+        admittance.set_damping([40.0; AXES]);
+        admittance.set_mass([1.0; AXES]);
+
+        for _ in 0..5000 {
+            admittance.update([10.0, 0.0, 0.0, 0.0, 0.0, 0.0], 0.001);
+        }
+
+        // x = F / K = 10 / 100
+        assert!((admittance.offset()[0] - 0.1).abs() < 1e-3);
+        assert!(admittance.offset()[1].abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_force_reference_shifts_equilibrium() {
+        let mut admittance = Admittance::new();
+        admittance.set_stiffness([100.0; AXES]);
+        admittance.set_force_reference([0.0, 0.0, 5.0, 0.0, 0.0, 0.0]);
+
+        for _ in 0..5000 {
+            admittance.update([0.0, 0.0, 5.0, 0.0, 0.0, 0.0], 0.001);
+        }
+
+        assert!(admittance.offset()[2].abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_deadband_ignores_noise() {
+        let mut admittance = Admittance::new();
+        admittance.set_force_deadband([1.0; AXES]);
+
+        admittance.update([0.5, -0.5, 0.9, 0.0, 0.0, 0.0], 0.01);
+        assert_eq!(admittance.offset(), [0.0; AXES]);
+    }
+
+    #[test]
+    fn test_force_limit_freezes_state() {
+        let mut admittance = Admittance::new();
+        admittance.set_force_limits([20.0; AXES]);
+
+        admittance.update([10.0, 0.0, 0.0, 0.0, 0.0, 0.0], 0.01);
+        let before = admittance.offset();
+
+        let status = admittance.update([0.0, 0.0, 50.0, 0.0, 0.0, 0.0], 0.01);
+        assert_eq!(
+            status,
+            AdmittanceStatus::ForceLimitExceeded {
+                axis: 2,
+                value: 50.0
+            }
+        );
+        assert_eq!(admittance.offset(), before);
+    }
+
+    #[test]
+    fn test_nan_wrench_rejected() {
+        let mut admittance = Admittance::new();
+        let status = admittance.update([f64::NAN, 0.0, 0.0, 0.0, 0.0, 0.0], 0.01);
+        assert!(matches!(
+            status,
+            AdmittanceStatus::ForceLimitExceeded { axis: 0, .. }
+        ));
+    }
+
+    #[test]
+    fn test_max_offset_clamps() {
+        let mut admittance = Admittance::new();
+        admittance.set_stiffness([0.0; AXES]);
+        admittance.set_max_offset([0.01; AXES]);
+
+        for _ in 0..1000 {
+            admittance.update([10.0, 0.0, 0.0, 0.0, 0.0, 0.0], 0.01);
+        }
+
+        assert!((admittance.offset()[0] - 0.01).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_apply_offset() {
+        let mut admittance = Admittance::new();
+        admittance.set_stiffness([100.0; AXES]);
+        for _ in 0..5000 {
+            admittance.update([0.0, 10.0, 0.0, 0.0, 0.0, 1.0], 0.001);
+        }
+
+        let (position, orientation) =
+            admittance.apply_offset([1.0, 2.0, 3.0], [0.0, 0.0, 0.0, 1.0]);
+        assert!((position[1] - 2.1).abs() < 1e-3);
+
+        // Yaw offset of ~0.01 rad
+        let yaw = 2.0 * orientation[2].atan2(orientation[3]);
+        assert!((yaw - 0.01).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut admittance = Admittance::new();
+        admittance.update([10.0; AXES], 0.01);
+        admittance.reset();
+        assert_eq!(admittance.offset(), [0.0; AXES]);
+        assert_eq!(admittance.velocity(), [0.0; AXES]);
+    }
+}
@@ -0,0 +1,341 @@
+//! CiA-402 Drive State Machine
+//!
+//! Device profile state machine used by EtherCAT (CoE) and CANopen servo
+//! drives. The drive reports its state in the statusword (0x6041) and is
+//! moved between states with commands in the controlword (0x6040).
+//!
+//! # Features
+//!
+//! - Statusword decoding into the eight CiA-402 states
+//! - Controlword sequencing towards a requested target (enabled, disabled, quick stop)
+//! - Fault reset with a rising edge on controlword bit 7
+//! - Modes of operation (0x6060) including the cyclic synchronous modes
+//!
+//! # Example
+//!
+//! ```rust
+//! use horus_library::algorithms::cia402::{Cia402Drive, Cia402State, Cia402Target};
+//!
+//! let mut drive = Cia402Drive::new();
+//! drive.set_target(Cia402Target::Enabled);
+//!
+//! // Switch on disabled -> Shutdown
+//! assert_eq!(drive.update(0x0250), 0x0006);
+//! // Ready to switch on -> Switch on
+//! assert_eq!(drive.update(0x0231), 0x0007);
+//! // Switched on -> Enable operation
+//! assert_eq!(drive.update(0x0233), 0x000F);
+//! assert_eq!(drive.update(0x0237), 0x000F);
+//! assert_eq!(drive.state(), Cia402State::OperationEnabled);
+//! ```
+
+/// Controlword: disable voltage
+pub const CW_DISABLE_VOLTAGE: u16 = 0x0000;
+/// Controlword: quick stop
+pub const CW_QUICK_STOP: u16 = 0x0002;
+/// Controlword: shutdown (to ready to switch on)
+pub const CW_SHUTDOWN: u16 = 0x0006;
+/// Controlword: switch on (also disables operation)
+pub const CW_SWITCH_ON: u16 = 0x0007;
+/// Controlword: enable operation
+pub const CW_ENABLE_OPERATION: u16 = 0x000F;
+/// Controlword: fault reset (acts on the rising edge)
+pub const CW_FAULT_RESET: u16 = 0x0080;
+
+/// Statusword bit 7: warning
+pub const SW_WARNING: u16 = 1 << 7;
+/// Statusword bit 10: target reached
+pub const SW_TARGET_REACHED: u16 = 1 << 10;
+/// Statusword bit 13: following error (position modes)
+pub const SW_FOLLOWING_ERROR: u16 = 1 << 13;
+
+/// CiA-402 drive state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Cia402State {
+    #[default]
+    NotReadyToSwitchOn,
+    SwitchOnDisabled,
+    ReadyToSwitchOn,
+    SwitchedOn,
+    OperationEnabled,
+    QuickStopActive,
+    FaultReactionActive,
+    Fault,
+}
+
+impl Cia402State {
+    /// Decode the state from a statusword
+    pub fn from_statusword(statusword: u16) -> Self {
+        match statusword & 0x4F {
+            0x00 => return Self::NotReadyToSwitchOn,
+            0x40 => return Self::SwitchOnDisabled,
+            0x0F => return Self::FaultReactionActive,
+            0x08 => return Self::Fault,
+            _ => {}
+        }
+        match statusword & 0x6F {
+            0x21 => Self::ReadyToSwitchOn,
+            0x23 => Self::SwitchedOn,
+            0x27 => Self::OperationEnabled,
+            0x07 => Self::QuickStopActive,
+            // Reserved combinations are treated as not ready
+            _ => Self::NotReadyToSwitchOn,
+        }
+    }
+
+    /// Power is applied to the motor
+    pub fn is_enabled(&self) -> bool {
+        matches!(self, Self::OperationEnabled)
+    }
+
+    /// Drive is in fault or reacting to one
+    pub fn is_fault(&self) -> bool {
+        matches!(self, Self::Fault | Self::FaultReactionActive)
+    }
+}
+
+/// State the controller drives towards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Cia402Target {
+    /// Power stage off (ready to switch on)
+    #[default]
+    Disabled,
+    /// Operation enabled
+    Enabled,
+    /// Quick stop (decelerate, then hold or disable per 0x605A)
+    QuickStop,
+}
+
+/// Modes of operation (object 0x6060)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationMode {
+    ProfilePosition,
+    ProfileVelocity,
+    ProfileTorque,
+    Homing,
+    InterpolatedPosition,
+    CyclicSynchronousPosition,
+    CyclicSynchronousVelocity,
+    CyclicSynchronousTorque,
+}
+
+impl OperationMode {
+    /// Object 0x6060 value
+    pub fn code(&self) -> i8 {
+        match self {
+            Self::ProfilePosition => 1,
+            Self::ProfileVelocity => 3,
+            Self::ProfileTorque => 4,
+            Self::Homing => 6,
+            Self::InterpolatedPosition => 7,
+            Self::CyclicSynchronousPosition => 8,
+            Self::CyclicSynchronousVelocity => 9,
+            Self::CyclicSynchronousTorque => 10,
+        }
+    }
+
+    /// Mode from an 0x6060/0x6061 value
+    pub fn from_code(code: i8) -> Option<Self> {
+        match code {
+            1 => Some(Self::ProfilePosition),
+            3 => Some(Self::ProfileVelocity),
+            4 => Some(Self::ProfileTorque),
+            6 => Some(Self::Homing),
+            7 => Some(Self::InterpolatedPosition),
+            8 => Some(Self::CyclicSynchronousPosition),
+            9 => Some(Self::CyclicSynchronousVelocity),
+            10 => Some(Self::CyclicSynchronousTorque),
+            _ => None,
+        }
+    }
+
+    /// Cyclic synchronous modes take a new setpoint every cycle
+    pub fn is_cyclic(&self) -> bool {
+        matches!(
+            self,
+            Self::CyclicSynchronousPosition
+                | Self::CyclicSynchronousVelocity
+                | Self::CyclicSynchronousTorque
+        )
+    }
+}
+
+/// CiA-402 state machine controller
+///
+/// Call [`update`](Self::update) once per cycle with the received
+/// statusword and send the returned controlword.
+#[derive(Debug, Clone, Default)]
+pub struct Cia402Drive {
+    state: Cia402State,
+    target: Cia402Target,
+    controlword: u16,
+    fault_reset: bool,
+}
+
+impl Cia402Drive {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the state to drive towards
+    pub fn set_target(&mut self, target: Cia402Target) {
+        self.target = target;
+    }
+
+    pub fn target(&self) -> Cia402Target {
+        self.target
+    }
+
+    /// Last decoded state
+    pub fn state(&self) -> Cia402State {
+        self.state
+    }
+
+    /// Last controlword returned by [`update`](Self::update)
+    pub fn controlword(&self) -> u16 {
+        self.controlword
+    }
+
+    /// Request a fault reset; kept until the drive leaves the fault state
+    pub fn reset_fault(&mut self) {
+        self.fault_reset = true;
+    }
+
+    /// Decode the statusword and return the next controlword
+    pub fn update(&mut self, statusword: u16) -> u16 {
+        self.state = Cia402State::from_statusword(statusword);
+        if !self.state.is_fault() {
+            self.fault_reset = false;
+        }
+
+        self.controlword = match self.state {
+            Cia402State::NotReadyToSwitchOn | Cia402State::FaultReactionActive => {
+                CW_DISABLE_VOLTAGE
+            }
+            Cia402State::Fault => {
+                // Bit 7 acts on the rising edge: alternate until the fault clears
+                if self.fault_reset && self.controlword & CW_FAULT_RESET == 0 {
+                    CW_FAULT_RESET
+                } else {
+                    CW_DISABLE_VOLTAGE
+                }
+            }
+            state => match self.target {
+                Cia402Target::Disabled => match state {
+                    Cia402State::QuickStopActive => CW_DISABLE_VOLTAGE,
+                    _ => CW_SHUTDOWN,
+                },
+                Cia402Target::Enabled => match state {
+                    Cia402State::SwitchOnDisabled => CW_SHUTDOWN,
+                    Cia402State::ReadyToSwitchOn => CW_SWITCH_ON,
+                    Cia402State::SwitchedOn | Cia402State::OperationEnabled => CW_ENABLE_OPERATION,
+                    // Leave quick stop through switch on disabled
+                    _ => CW_DISABLE_VOLTAGE,
+                },
+                Cia402Target::QuickStop => match state {
+                    Cia402State::SwitchOnDisabled => CW_DISABLE_VOLTAGE,
+                    _ => CW_QUICK_STOP,
+                },
+            },
+        };
+        self.controlword
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_statusword_decoding() {
+        assert_eq!(
+            Cia402State::from_statusword(0x0000),
+            Cia402State::NotReadyToSwitchOn
+        );
+        assert_eq!(
+            Cia402State::from_statusword(0x0250),
+            Cia402State::SwitchOnDisabled
+        );
+        assert_eq!(
+            Cia402State::from_statusword(0x0231),
+            Cia402State::ReadyToSwitchOn
+        );
+        assert_eq!(
+            Cia402State::from_statusword(0x0233),
+            Cia402State::SwitchedOn
+        );
+        assert_eq!(
+            Cia402State::from_statusword(0x1637),
+            Cia402State::OperationEnabled
+        );
+        assert_eq!(
+            Cia402State::from_statusword(0x0217),
+            Cia402State::QuickStopActive
+        );
+        assert_eq!(
+            Cia402State::from_statusword(0x021F),
+            Cia402State::FaultReactionActive
+        );
+        assert_eq!(Cia402State::from_statusword(0x0218), Cia402State::Fault);
+        assert!(Cia402State::from_statusword(0x0218).is_fault());
+    }
+
+    #[test]
+    fn test_enable_sequence() {
+        let mut drive = Cia402Drive::new();
+        drive.set_target(Cia402Target::Enabled);
+
+        assert_eq!(drive.update(0x0000), CW_DISABLE_VOLTAGE);
+        assert_eq!(drive.update(0x0250), CW_SHUTDOWN);
+        assert_eq!(drive.update(0x0231), CW_SWITCH_ON);
+        assert_eq!(drive.update(0x0233), CW_ENABLE_OPERATION);
+        assert_eq!(drive.update(0x0237), CW_ENABLE_OPERATION);
+        assert!(drive.state().is_enabled());
+    }
+
+    #[test]
+    fn test_disable_and_quick_stop() {
+        let mut drive = Cia402Drive::new();
+        drive.set_target(Cia402Target::QuickStop);
+        assert_eq!(drive.update(0x0237), CW_QUICK_STOP);
+        assert_eq!(drive.update(0x0217), CW_QUICK_STOP);
+
+        // Re-enabling leaves quick stop through switch on disabled
+        drive.set_target(Cia402Target::Enabled);
+        assert_eq!(drive.update(0x0217), CW_DISABLE_VOLTAGE);
+        assert_eq!(drive.update(0x0250), CW_SHUTDOWN);
+
+        drive.set_target(Cia402Target::Disabled);
+        assert_eq!(drive.update(0x0237), CW_SHUTDOWN);
+        assert_eq!(drive.update(0x0231), CW_SHUTDOWN);
+    }
+
+    #[test]
+    fn test_fault_reset_edges() {
+        let mut drive = Cia402Drive::new();
+        drive.set_target(Cia402Target::Enabled);
+
+        // No reset requested: stay in fault
+        assert_eq!(drive.update(0x0218), CW_DISABLE_VOLTAGE);
+        assert_eq!(drive.update(0x0218), CW_DISABLE_VOLTAGE);
+
+        drive.reset_fault();
+        assert_eq!(drive.update(0x0218), CW_FAULT_RESET);
+        assert_eq!(drive.update(0x0218), CW_DISABLE_VOLTAGE);
+        assert_eq!(drive.update(0x0218), CW_FAULT_RESET);
+
+        // Fault cleared: request is consumed and the enable sequence resumes
+        assert_eq!(drive.update(0x0250), CW_SHUTDOWN);
+        assert_eq!(drive.update(0x0218), CW_DISABLE_VOLTAGE);
+    }
+
+    #[test]
+    fn test_operation_modes() {
+        for code in [1, 3, 4, 6, 7, 8, 9, 10] {
+            assert_eq!(OperationMode::from_code(code).unwrap().code(), code);
+        }
+        assert_eq!(OperationMode::from_code(2), None);
+        assert!(OperationMode::CyclicSynchronousPosition.is_cyclic());
+        assert!(!OperationMode::ProfilePosition.is_cyclic());
+    }
+}
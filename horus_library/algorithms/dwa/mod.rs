@@ -0,0 +1,573 @@
+//! Dynamic Window Approach Local Planner
+//!
+//! Reactive local planner for differential drive robots. Every control cycle
+//! it samples (linear, angular) velocity pairs reachable within the
+//! acceleration limits, forward-simulates each one for a short horizon and
+//! picks the trajectory that best follows the global path without bringing
+//! the robot footprint into contact with an obstacle.
+//!
+//! # Features
+//!
+//! - Velocity and acceleration limits (the "dynamic window")
+//! - Circular or polygonal robot footprints
+//! - Point obstacles, e.g. from a laser scan, checked along each trajectory
+//! - Weighted path, goal, clearance and speed costs
+//!
+//! # Example
+//!
+//! ```rust
+//! use horus_library::algorithms::dwa::{Dwa, Footprint};
+//!
+//! let mut dwa = Dwa::new();
+//! dwa.set_footprint(Footprint::Circle(0.2));
+//! dwa.set_path(vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)]);
+//! dwa.set_obstacles(vec![(1.0, 0.6)]);
+//!
+//! // Pose (x, y, theta), current velocity (v, w), control period
+//! let (v, w) = dwa
+//!     .compute_velocity((0.0, 0.0, 0.0), (0.2, 0.0), 0.1)
+//!     .expect("free trajectory");
+//! assert!(v > 0.0);
+//! assert!(w.abs() < 1.0);
+//! ```
+
+use std::f64::consts::PI;
+
+/// Cost added to trajectories the robot has to brake out of
+const COLLISION_COST: f64 = 1e3;
+
+/// Robot footprint used for collision checking
+#[derive(Debug, Clone, PartialEq)]
+pub enum Footprint {
+    /// Circle of the given radius around the robot origin
+    Circle(f64),
+    /// Convex or concave polygon in the robot frame (x forward, y left)
+    Polygon(Vec<(f64, f64)>),
+}
+
+impl Footprint {
+    /// Rectangle centred on the robot origin
+    pub fn rectangle(length: f64, width: f64) -> Self {
+        let (hl, hw) = (length / 2.0, width / 2.0);
+        Footprint::Polygon(vec![(hl, hw), (-hl, hw), (-hl, -hw), (hl, -hw)])
+    }
+
+    /// Distance from the robot origin to the farthest footprint point
+    pub fn radius(&self) -> f64 {
+        match self {
+            Footprint::Circle(r) => *r,
+            Footprint::Polygon(points) => {
+                points.iter().map(|&(x, y)| x.hypot(y)).fold(0.0, f64::max)
+            }
+        }
+    }
+
+    /// Distance between the footprint at `pose` and a world point
+    ///
+    /// Zero when the point is on or inside the footprint.
+    pub fn distance(&self, pose: (f64, f64, f64), point: (f64, f64)) -> f64 {
+        let (dx, dy) = (point.0 - pose.0, point.1 - pose.1);
+        match self {
+            Footprint::Circle(r) => (dx.hypot(dy) - r).max(0.0),
+            Footprint::Polygon(points) => {
+                // Point in the robot frame
+                let (s, c) = pose.2.sin_cos();
+                let p = (c * dx + s * dy, -s * dx + c * dy);
+                if points.len() < 3 {
+                    return p.0.hypot(p.1);
+                }
+                let mut inside = false;
+                let mut min = f64::INFINITY;
+                for i in 0..points.len() {
+                    let a = points[i];
+                    let b = points[(i + 1) % points.len()];
+                    if (a.1 > p.1) != (b.1 > p.1)
+                        && p.0 < (b.0 - a.0) * (p.1 - a.1) / (b.1 - a.1) + a.0
+                    {
+                        inside = !inside;
+                    }
+                    min = min.min(segment_distance(p, a, b));
+                }
+                if inside {
+                    0.0
+                } else {
+                    min
+                }
+            }
+        }
+    }
+}
+
+/// Planner limits, horizon and cost weights
+#[derive(Debug, Clone, PartialEq)]
+pub struct DwaConfig {
+    /// Maximum forward velocity (m/s)
+    pub max_linear: f64,
+    /// Minimum linear velocity (m/s), negative to allow reversing
+    pub min_linear: f64,
+    /// Maximum angular velocity magnitude (rad/s)
+    pub max_angular: f64,
+    /// Linear acceleration limit (m/s²)
+    pub max_linear_accel: f64,
+    /// Angular acceleration limit (rad/s²)
+    pub max_angular_accel: f64,
+    /// Linear velocity samples across the window
+    pub linear_samples: usize,
+    /// Angular velocity samples across the window
+    pub angular_samples: usize,
+    /// Forward simulation horizon (s)
+    pub sim_time: f64,
+    /// Forward simulation step (s)
+    pub sim_step: f64,
+    /// Extra clearance kept around the footprint (m)
+    pub safety_margin: f64,
+    /// Weight of the distance between trajectory end and the global path
+    pub path_weight: f64,
+    /// Weight of the distance between trajectory end and the path look-ahead point
+    pub goal_weight: f64,
+    /// Weight of the obstacle proximity cost
+    pub obstacle_weight: f64,
+    /// Clearance beyond which obstacles add no cost (m)
+    pub obstacle_range: f64,
+    /// Weight rewarding forward speed
+    pub speed_weight: f64,
+    /// Distance to the final waypoint at which the goal counts as reached (m)
+    pub goal_tolerance: f64,
+}
+
+impl Default for DwaConfig {
+    fn default() -> Self {
+        Self {
+            max_linear: 0.5,
+            min_linear: 0.0,
+            max_angular: 1.5,
+            max_linear_accel: 1.0,
+            max_angular_accel: 3.0,
+            linear_samples: 11,
+            angular_samples: 21,
+            sim_time: 1.5,
+            sim_step: 0.1,
+            safety_margin: 0.05,
+            path_weight: 1.0,
+            goal_weight: 2.0,
+            obstacle_weight: 0.5,
+            obstacle_range: 0.5,
+            speed_weight: 0.5,
+            goal_tolerance: 0.1,
+        }
+    }
+}
+
+/// Dynamic Window Approach local planner
+pub struct Dwa {
+    config: DwaConfig,
+    footprint: Footprint,
+    path: Vec<(f64, f64)>,
+    obstacles: Vec<(f64, f64)>,
+    trajectory: Vec<(f64, f64, f64)>,
+}
+
+impl Dwa {
+    /// Create a planner with default limits and a 0.2 m circular footprint
+    pub fn new() -> Self {
+        Self::with_config(DwaConfig::default())
+    }
+
+    /// Create a planner with the given configuration
+    pub fn with_config(config: DwaConfig) -> Self {
+        Self {
+            config,
+            footprint: Footprint::Circle(0.2),
+            path: Vec::new(),
+            obstacles: Vec::new(),
+            trajectory: Vec::new(),
+        }
+    }
+
+    /// Current configuration
+    pub fn config(&self) -> &DwaConfig {
+        &self.config
+    }
+
+    /// Mutable access to the configuration
+    pub fn config_mut(&mut self) -> &mut DwaConfig {
+        &mut self.config
+    }
+
+    /// Set the robot footprint
+    pub fn set_footprint(&mut self, footprint: Footprint) {
+        self.footprint = footprint;
+    }
+
+    /// Set velocity limits
+    pub fn set_velocity_limits(&mut self, min_linear: f64, max_linear: f64, max_angular: f64) {
+        self.config.min_linear = min_linear.min(max_linear);
+        self.config.max_linear = max_linear;
+        self.config.max_angular = max_angular.abs();
+    }
+
+    /// Set acceleration limits
+    pub fn set_acceleration_limits(&mut self, linear: f64, angular: f64) {
+        self.config.max_linear_accel = linear.abs();
+        self.config.max_angular_accel = angular.abs();
+    }
+
+    /// Set the global path to follow (world frame)
+    pub fn set_path(&mut self, path: Vec<(f64, f64)>) {
+        self.path = path;
+    }
+
+    /// Set obstacle points (world frame)
+    pub fn set_obstacles(&mut self, obstacles: Vec<(f64, f64)>) {
+        self.obstacles = obstacles;
+    }
+
+    /// Current global path
+    pub fn path(&self) -> &[(f64, f64)] {
+        &self.path
+    }
+
+    /// Trajectory chosen by the last `compute_velocity` call
+    pub fn trajectory(&self) -> &[(f64, f64, f64)] {
+        &self.trajectory
+    }
+
+    /// Check if the final waypoint is within the goal tolerance
+    pub fn is_goal_reached(&self, pose: (f64, f64, f64)) -> bool {
+        match self.path.last() {
+            Some(&(gx, gy)) => (gx - pose.0).hypot(gy - pose.1) < self.config.goal_tolerance,
+            None => true,
+        }
+    }
+
+    /// Pick the velocity command for the next control period
+    ///
+    /// `velocity` is the current (linear, angular) velocity and `dt` the
+    /// control period used to size the dynamic window. Returns (0, 0) once
+    /// the goal is reached and None when no sampled trajectory avoids an
+    /// obstacle or stops in front of it.
+    pub fn compute_velocity(
+        &mut self,
+        pose: (f64, f64, f64),
+        velocity: (f64, f64),
+        dt: f64,
+    ) -> Option<(f64, f64)> {
+        self.trajectory.clear();
+        if self.is_goal_reached(pose) {
+            return Some((0.0, 0.0));
+        }
+
+        let cfg = &self.config;
+        let dt = dt.max(1e-3);
+        let v_min = (velocity.0 - cfg.max_linear_accel * dt).max(cfg.min_linear);
+        let v_max = (velocity.0 + cfg.max_linear_accel * dt).min(cfg.max_linear);
+        let w_min = (velocity.1 - cfg.max_angular_accel * dt).max(-cfg.max_angular);
+        let w_max = (velocity.1 + cfg.max_angular_accel * dt).min(cfg.max_angular);
+        if v_min > v_max || w_min > w_max {
+            // Current velocity is outside the limits: brake as hard as allowed
+            let v = velocity.0.clamp(cfg.min_linear, cfg.max_linear);
+            let w = velocity.1.clamp(-cfg.max_angular, cfg.max_angular);
+            return Some((v, w));
+        }
+
+        // Only obstacles the footprint can reach within the horizon matter
+        let reach = cfg.max_linear.abs().max(cfg.min_linear.abs()) * cfg.sim_time
+            + self.footprint.radius()
+            + cfg.safety_margin
+            + 1.0;
+        let nearby: Vec<(f64, f64)> = self
+            .obstacles
+            .iter()
+            .copied()
+            .filter(|&(ox, oy)| (ox - pose.0).hypot(oy - pose.1) <= reach)
+            .collect();
+
+        let (closest, target) = self.path_targets(pose);
+
+        let mut best_cost = f64::INFINITY;
+        let mut best = None;
+        for v in samples(v_min, v_max, cfg.linear_samples) {
+            for w in samples(w_min, w_max, cfg.angular_samples) {
+                let trajectory = self.simulate(pose, v, w);
+                let (clearance, collision) = self.clearance(&trajectory, &nearby);
+                let mut cost = self.cost(&trajectory, v, clearance, closest, target);
+                if let Some(index) = collision {
+                    // Admissible only if the robot can stop before the last
+                    // free pose, and then only chosen when nothing is free
+                    let time = cfg.sim_time * (index - 1) as f64 / (trajectory.len() - 1) as f64;
+                    if v.abs() > cfg.max_linear_accel * time
+                        || w.abs() > cfg.max_angular_accel * time
+                    {
+                        continue;
+                    }
+                    cost += COLLISION_COST;
+                }
+                if cost < best_cost {
+                    best_cost = cost;
+                    best = Some(((v, w), trajectory));
+                }
+            }
+        }
+
+        best.map(|(command, trajectory)| {
+            self.trajectory = trajectory;
+            command
+        })
+    }
+
+    /// Forward simulate a constant (v, w) command from `pose`
+    fn simulate(&self, pose: (f64, f64, f64), v: f64, w: f64) -> Vec<(f64, f64, f64)> {
+        let steps = (self.config.sim_time / self.config.sim_step)
+            .ceil()
+            .max(1.0) as usize;
+        let step = self.config.sim_time / steps as f64;
+        let (mut x, mut y, mut theta) = pose;
+        let mut trajectory = Vec::with_capacity(steps + 1);
+        trajectory.push(pose);
+        for _ in 0..steps {
+            x += v * theta.cos() * step;
+            y += v * theta.sin() * step;
+            theta = normalize_angle(theta + w * step);
+            trajectory.push((x, y, theta));
+        }
+        trajectory
+    }
+
+    /// Smallest footprint clearance along a trajectory and the index of the
+    /// first pose within the safety margin of an obstacle
+    ///
+    /// The start pose is skipped: the robot is already there.
+    fn clearance(
+        &self,
+        trajectory: &[(f64, f64, f64)],
+        obstacles: &[(f64, f64)],
+    ) -> (f64, Option<usize>) {
+        let mut min = f64::INFINITY;
+        for (index, &pose) in trajectory.iter().enumerate().skip(1) {
+            for &obstacle in obstacles {
+                let distance = self.footprint.distance(pose, obstacle);
+                min = min.min(distance);
+                if distance <= self.config.safety_margin {
+                    return (min, Some(index));
+                }
+            }
+        }
+        (min, None)
+    }
+
+    fn cost(
+        &self,
+        trajectory: &[(f64, f64, f64)],
+        v: f64,
+        clearance: f64,
+        closest: usize,
+        target: (f64, f64),
+    ) -> f64 {
+        let cfg = &self.config;
+        let &(x, y, theta) = trajectory.last().unwrap();
+
+        let path_cost = self.path_distance((x, y), closest);
+        let heading = (target.1 - y).atan2(target.0 - x);
+        let goal_cost =
+            (target.0 - x).hypot(target.1 - y) + 0.5 * normalize_angle(heading - theta).abs() / PI;
+        let obstacle_cost = if cfg.obstacle_range > 0.0 {
+            (1.0 - (clearance - cfg.safety_margin) / cfg.obstacle_range).max(0.0)
+        } else {
+            0.0
+        };
+        let speed_cost = cfg.max_linear - v;
+
+        cfg.path_weight * path_cost
+            + cfg.goal_weight * goal_cost
+            + cfg.obstacle_weight * obstacle_cost
+            + cfg.speed_weight * speed_cost
+    }
+
+    /// Index of the path point closest to the robot and the look-ahead point
+    /// one horizon further along the path
+    fn path_targets(&self, pose: (f64, f64, f64)) -> (usize, (f64, f64)) {
+        if self.path.is_empty() {
+            return (0, (pose.0, pose.1));
+        }
+        let closest = self
+            .path
+            .iter()
+            .enumerate()
+            .map(|(i, &(px, py))| (i, (px - pose.0).hypot(py - pose.1)))
+            .fold(
+                (0, f64::INFINITY),
+                |best, cur| {
+                    if cur.1 < best.1 {
+                        cur
+                    } else {
+                        best
+                    }
+                },
+            )
+            .0;
+
+        let look_ahead = (self.config.max_linear * self.config.sim_time).max(0.3);
+        let mut travelled = 0.0;
+        for i in closest..self.path.len() - 1 {
+            let (a, b) = (self.path[i], self.path[i + 1]);
+            travelled += (b.0 - a.0).hypot(b.1 - a.1);
+            if travelled >= look_ahead {
+                return (closest, b);
+            }
+        }
+        (closest, *self.path.last().unwrap())
+    }
+
+    /// Distance from a point to the path, from the closest segment onwards
+    fn path_distance(&self, point: (f64, f64), closest: usize) -> f64 {
+        match self.path.len() {
+            0 => 0.0,
+            1 => (self.path[0].0 - point.0).hypot(self.path[0].1 - point.1),
+            len => (closest.saturating_sub(1)..len - 1)
+                .map(|i| segment_distance(point, self.path[i], self.path[i + 1]))
+                .fold(f64::INFINITY, f64::min),
+        }
+    }
+}
+
+impl Default for Dwa {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// `count` evenly spaced values from `min` to `max` inclusive
+fn samples(min: f64, max: f64, count: usize) -> impl Iterator<Item = f64> {
+    let count = count.max(1);
+    let step = if count > 1 {
+        (max - min) / (count - 1) as f64
+    } else {
+        0.0
+    };
+    (0..count).map(move |i| {
+        if count > 1 {
+            min + step * i as f64
+        } else {
+            (min + max) / 2.0
+        }
+    })
+}
+
+fn segment_distance(p: (f64, f64), a: (f64, f64), b: (f64, f64)) -> f64 {
+    let (abx, aby) = (b.0 - a.0, b.1 - a.1);
+    let len_sq = abx * abx + aby * aby;
+    let t = if len_sq > 0.0 {
+        (((p.0 - a.0) * abx + (p.1 - a.1) * aby) / len_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    (a.0 + t * abx - p.0).hypot(a.1 + t * aby - p.1)
+}
+
+fn normalize_angle(angle: f64) -> f64 {
+    let mut a = angle % (2.0 * PI);
+    if a > PI {
+        a -= 2.0 * PI;
+    } else if a < -PI {
+        a += 2.0 * PI;
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn straight_path() -> Vec<(f64, f64)> {
+        (0..=30).map(|i| (i as f64 * 0.1, 0.0)).collect()
+    }
+
+    #[test]
+    fn test_footprint_distance() {
+        let circle = Footprint::Circle(0.5);
+        assert!((circle.distance((0.0, 0.0, 0.0), (1.0, 0.0)) - 0.5).abs() < 1e-9);
+        assert_eq!(circle.distance((0.0, 0.0, 0.0), (0.2, 0.0)), 0.0);
+
+        let rect = Footprint::rectangle(1.0, 0.4);
+        assert!((rect.distance((0.0, 0.0, 0.0), (1.0, 0.0)) - 0.5).abs() < 1e-9);
+        // Rotated 90°, the long side points along y
+        assert_eq!(rect.distance((0.0, 0.0, PI / 2.0), (0.0, 0.4)), 0.0);
+        assert!((rect.distance((0.0, 0.0, PI / 2.0), (0.4, 0.0)) - 0.2).abs() < 1e-9);
+        assert!((rect.radius() - 0.5385).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_follows_straight_path() {
+        let mut dwa = Dwa::new();
+        dwa.set_path(straight_path());
+        let (v, w) = dwa
+            .compute_velocity((0.0, 0.0, 0.0), (0.5, 0.0), 0.1)
+            .unwrap();
+        assert!((v - 0.5).abs() < 1e-9);
+        assert!(w.abs() < 1e-9);
+        assert!(!dwa.trajectory().is_empty());
+    }
+
+    #[test]
+    fn test_respects_acceleration_limits() {
+        let mut dwa = Dwa::new();
+        dwa.set_acceleration_limits(0.5, 1.0);
+        dwa.set_path(straight_path());
+        let (v, w) = dwa
+            .compute_velocity((0.0, 0.0, 0.0), (0.0, 0.0), 0.1)
+            .unwrap();
+        assert!(v <= 0.05 + 1e-9);
+        assert!(w.abs() <= 0.1 + 1e-9);
+    }
+
+    #[test]
+    fn test_avoids_obstacle() {
+        // Obstacle reaching onto the left side of the path
+        let obstacles: Vec<(f64, f64)> = (0..5).map(|i| (1.0, 0.15 + i as f64 * 0.05)).collect();
+        let mut dwa = Dwa::new();
+        dwa.set_path(straight_path());
+        dwa.set_obstacles(obstacles.clone());
+
+        let mut pose = (0.0, 0.0, 0.0);
+        let mut velocity = (0.3, 0.0);
+        let mut min_y: f64 = 0.0;
+        for _ in 0..150 {
+            let command = dwa.compute_velocity(pose, velocity, 0.1).unwrap();
+            velocity = command;
+            pose.2 = normalize_angle(pose.2 + command.1 * 0.1);
+            pose.0 += command.0 * pose.2.cos() * 0.1;
+            pose.1 += command.0 * pose.2.sin() * 0.1;
+            min_y = min_y.min(pose.1);
+            for &obstacle in &obstacles {
+                assert!(dwa.footprint.distance(pose, obstacle) > 0.0);
+            }
+        }
+        // Swerved right, passed the obstacle and reached the goal
+        assert!(min_y < -0.05);
+        assert!(dwa.is_goal_reached(pose));
+    }
+
+    #[test]
+    fn test_blocked_and_goal() {
+        let mut dwa = Dwa::new();
+        dwa.set_path(straight_path());
+        // Boxed in while stopped: staying put is the only option
+        dwa.set_obstacles(vec![(0.1, 0.0), (-0.1, 0.0), (0.0, 0.1), (0.0, -0.1)]);
+        assert_eq!(
+            dwa.compute_velocity((0.0, 0.0, 0.0), (0.0, 0.0), 0.1),
+            Some((0.0, 0.0))
+        );
+
+        // Too fast to stop in front of a wall
+        dwa.set_obstacles((-10..=10).map(|i| (0.3, i as f64 * 0.05)).collect());
+        assert!(dwa
+            .compute_velocity((0.0, 0.0, 0.0), (0.5, 0.0), 0.1)
+            .is_none());
+
+        dwa.set_obstacles(Vec::new());
+        assert_eq!(
+            dwa.compute_velocity((3.0, 0.02, 0.0), (0.2, 0.0), 0.1),
+            Some((0.0, 0.0))
+        );
+    }
+}
@@ -47,6 +47,19 @@ pub struct EKF {
 }
 
 impl EKF {
+    /// State index of the x position
+    pub const X: usize = 0;
+    /// State index of the y position
+    pub const Y: usize = 1;
+    /// State index of the heading
+    pub const THETA: usize = 2;
+    /// State index of the x velocity
+    pub const VX: usize = 3;
+    /// State index of the y velocity
+    pub const VY: usize = 4;
+    /// State index of the angular velocity
+    pub const OMEGA: usize = 5;
+
     /// Create new EKF with default parameters
     pub fn new() -> Self {
         let mut ekf = Self {
@@ -227,6 +240,46 @@ impl EKF {
         }
     }
 
+    /// Update step with a direct measurement of some state variables
+    ///
+    /// `indices` selects the measured state entries (e.g. `[EKF::VX, EKF::OMEGA]`),
+    /// with one value and one measurement variance per index. Variables are
+    /// fused one after another, which is exact for independent measurement noise.
+    pub fn update_partial(&mut self, indices: &[usize], measurement: &[f64], variances: &[f64]) {
+        for ((&idx, &z), &r) in indices.iter().zip(measurement).zip(variances) {
+            if idx >= 6 || !z.is_finite() {
+                continue;
+            }
+
+            let mut innovation = z - self.state[idx];
+            if idx == Self::THETA {
+                innovation = normalize_angle(innovation);
+            }
+
+            // Innovation covariance: S = P[idx][idx] + R
+            let s = self.covariance[idx][idx] + r.max(1e-9);
+
+            // Kalman gain: K = P[:, idx] / S
+            let mut kalman_gain = [0.0; 6];
+            for i in 0..6 {
+                kalman_gain[i] = self.covariance[i][idx] / s;
+            }
+
+            for i in 0..6 {
+                self.state[i] += kalman_gain[i] * innovation;
+            }
+            self.state[2] = normalize_angle(self.state[2]);
+
+            // Covariance update: P = P - K * P[idx, :]
+            let row = self.covariance[idx];
+            for i in 0..6 {
+                for j in 0..6 {
+                    self.covariance[i][j] -= kalman_gain[i] * row[j];
+                }
+            }
+        }
+    }
+
     /// Reset EKF to initial state
     pub fn reset(&mut self) {
         self.state = [0.0; 6];
@@ -399,6 +452,25 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_partial_update() {
+        let mut ekf = EKF::new();
+        ekf.set_state([0.0, 0.0, 3.0, 0.0, 0.0, 0.0]);
+
+        // Heading measured across the ±π boundary, velocity very precisely
+        ekf.update_partial(&[EKF::THETA, EKF::VX], &[-3.0, 2.0], &[1.0, 1e-6]);
+
+        let (x, _y, theta) = ekf.get_pose();
+        let (vx, _vy, _omega) = ekf.get_velocity();
+        assert_eq!(x, 0.0); // Not measured, uncorrelated
+        assert!(!(-3.0..=3.0).contains(&theta)); // Moved the short way around
+        assert!((vx - 2.0).abs() < 1e-3);
+
+        let covariance = ekf.get_covariance();
+        assert!(covariance[EKF::VX][EKF::VX] < 1e-5);
+        assert_eq!(covariance[EKF::X][EKF::X], 1.0);
+    }
+
     #[test]
     fn test_rotation() {
         let mut ekf = EKF::new();
@@ -0,0 +1,506 @@
+//! Square Fiducial Pose Estimation
+//!
+//! Recovers the 6-DoF pose of a square marker (AprilTag, ArUco) of known
+//! size from its four corner pixels and the camera calibration. Detectors
+//! only find the corners; this turns them into a transform usable for
+//! docking, calibration and visual servoing.
+//!
+//! # Method
+//!
+//! 1. Corners are undistorted into normalized image coordinates
+//! 2. The plane-to-image homography gives an initial rotation and translation
+//! 3. Gauss-Newton iterations minimize the pixel reprojection error,
+//!    including lens distortion
+//!
+//! Corners are expected in detector order: top-left, top-right,
+//! bottom-right, bottom-left. The marker frame has its origin at the marker
+//! center, x to the right, y up and z out of the marker face, so a marker
+//! facing the camera upright has its z axis pointing back at the camera.
+//!
+//! # Example
+//!
+//! ```rust
+//! use horus_library::algorithms::fiducial;
+//! use horus_library::CameraInfo;
+//!
+//! let camera = CameraInfo::new(640, 480, 500.0, 500.0, 320.0, 240.0);
+//!
+//! // A 10cm marker, 1m in front of the camera
+//! let corners = [[295.0, 215.0], [345.0, 215.0], [345.0, 265.0], [295.0, 265.0]];
+//! let pose = fiducial::estimate_marker_pose(&corners, 0.1, &camera).unwrap();
+//!
+//! assert!((pose.translation[2] - 1.0).abs() < 1e-6);
+//! assert!(pose.reprojection_error < 1e-6);
+//! ```
+
+use crate::hframe;
+use crate::messages::geometry::Transform;
+use crate::CameraInfo;
+use horus_core::error::{HorusError, HorusResult};
+
+/// Pose of a marker in the camera optical frame
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarkerPose {
+    /// Rotation from the marker frame to the camera frame (row-major)
+    pub rotation: [[f64; 3]; 3],
+    /// Marker center in the camera frame (meters)
+    pub translation: [f64; 3],
+    /// RMS corner reprojection error (pixels)
+    pub reprojection_error: f64,
+}
+
+impl MarkerPose {
+    /// Rotation as quaternion [x, y, z, w]
+    pub fn quaternion(&self) -> [f64; 4] {
+        let r = &self.rotation;
+        hframe::Transform::from_matrix([
+            [r[0][0], r[0][1], r[0][2], 0.0],
+            [r[1][0], r[1][1], r[1][2], 0.0],
+            [r[2][0], r[2][1], r[2][2], 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ])
+        .rotation
+    }
+
+    /// Pose as a transform message
+    pub fn transform(&self) -> Transform {
+        Transform::new(self.translation, self.quaternion())
+    }
+
+    /// Transform a point from the marker frame into the camera frame
+    pub fn apply(&self, point: [f64; 3]) -> [f64; 3] {
+        let r = &self.rotation;
+        let t = &self.translation;
+        [
+            r[0][0] * point[0] + r[0][1] * point[1] + r[0][2] * point[2] + t[0],
+            r[1][0] * point[0] + r[1][1] * point[1] + r[1][2] * point[2] + t[1],
+            r[2][0] * point[0] + r[2][1] * point[1] + r[2][2] * point[2] + t[2],
+        ]
+    }
+}
+
+/// Corner positions in the marker frame, in detector order
+pub fn marker_corners(size: f64) -> [[f64; 3]; 4] {
+    let h = size / 2.0;
+    [[-h, h, 0.0], [h, h, 0.0], [h, -h, 0.0], [-h, -h, 0.0]]
+}
+
+/// Convert a raw pixel to undistorted normalized image coordinates (x/z, y/z)
+pub fn undistort_pixel(camera: &CameraInfo, u: f64, v: f64) -> (f64, f64) {
+    let k = &camera.camera_matrix;
+    let yd = (v - k[5]) / k[4];
+    let xd = (u - k[2] - k[1] * yd) / k[0];
+
+    // Fixed-point inversion of the distortion model
+    let (mut x, mut y) = (xd, yd);
+    for _ in 0..20 {
+        let (dx, dy) = camera.distort(x, y);
+        let (ex, ey) = (xd - dx, yd - dy);
+        x += ex;
+        y += ey;
+        if ex.abs() < 1e-12 && ey.abs() < 1e-12 {
+            break;
+        }
+    }
+    (x, y)
+}
+
+/// Estimate the pose of a square marker of side `size` (meters)
+///
+/// Fails if the calibration is missing, the size is not positive, or the
+/// corners do not form a valid quadrilateral.
+pub fn estimate_marker_pose(
+    corners: &[[f64; 2]; 4],
+    size: f64,
+    camera: &CameraInfo,
+) -> HorusResult<MarkerPose> {
+    let (fx, fy) = camera.focal_lengths();
+    if fx <= 0.0 || fy <= 0.0 {
+        return Err(HorusError::InvalidInput(
+            "Camera calibration has no focal length".to_string(),
+        ));
+    }
+    if !(size > 0.0 && size.is_finite()) {
+        return Err(HorusError::InvalidInput(format!(
+            "Marker size must be positive, got {}",
+            size
+        )));
+    }
+
+    let object = marker_corners(size);
+    let normalized: Vec<(f64, f64)> = corners
+        .iter()
+        .map(|c| undistort_pixel(camera, c[0], c[1]))
+        .collect();
+
+    let (rotation, translation) = initial_pose(&object, &normalized)
+        .ok_or_else(|| HorusError::InvalidInput("Marker corners are degenerate".to_string()))?;
+
+    let mut pose = MarkerPose {
+        rotation,
+        translation,
+        reprojection_error: f64::INFINITY,
+    };
+    refine(&mut pose, &object, corners, camera);
+    if !pose.reprojection_error.is_finite() {
+        return Err(HorusError::InvalidInput(
+            "Marker corners are not consistent with a visible square".to_string(),
+        ));
+    }
+    Ok(pose)
+}
+
+/// Pose from the homography between the marker plane and normalized coordinates
+fn initial_pose(
+    object: &[[f64; 3]; 4],
+    normalized: &[(f64, f64)],
+) -> Option<([[f64; 3]; 3], [f64; 3])> {
+    // h33 = 1, two equations per correspondence
+    let mut a = [[0.0; 8]; 8];
+    let mut b = [0.0; 8];
+    for (i, (p, &(x, y))) in object.iter().zip(normalized).enumerate() {
+        let (px, py) = (p[0], p[1]);
+        a[2 * i] = [px, py, 1.0, 0.0, 0.0, 0.0, -x * px, -x * py];
+        b[2 * i] = x;
+        a[2 * i + 1] = [0.0, 0.0, 0.0, px, py, 1.0, -y * px, -y * py];
+        b[2 * i + 1] = y;
+    }
+    let h = solve(a, b)?;
+
+    let h1 = [h[0], h[3], h[6]];
+    let h2 = [h[1], h[4], h[7]];
+    let h3 = [h[2], h[5], 1.0];
+
+    // h33 = 1 keeps the marker center in front of the camera
+    let scale = 2.0 / (norm(h1) + norm(h2));
+    if !scale.is_finite() {
+        return None;
+    }
+    let t = mul(h3, scale);
+    let r1 = normalize(h1)?;
+    let r2 = normalize(h2)?;
+
+    // Closest orthonormal pair, spreading the correction evenly over both axes
+    let sum = normalize(add(r1, r2))?;
+    let diff = normalize(sub(r1, r2))?;
+    let s = std::f64::consts::FRAC_1_SQRT_2;
+    let c1 = mul(add(sum, diff), s);
+    let c2 = mul(sub(sum, diff), s);
+    let c3 = cross(c1, c2);
+
+    let rotation = [
+        [c1[0], c2[0], c3[0]],
+        [c1[1], c2[1], c3[1]],
+        [c1[2], c2[2], c3[2]],
+    ];
+    Some((rotation, t))
+}
+
+/// Gauss-Newton refinement of the pixel reprojection error
+fn refine(
+    pose: &mut MarkerPose,
+    object: &[[f64; 3]; 4],
+    corners: &[[f64; 2]; 4],
+    camera: &CameraInfo,
+) {
+    const EPS: f64 = 1e-7;
+
+    let mut residual = match residuals(pose, object, corners, camera) {
+        Some(r) => r,
+        None => return,
+    };
+    pose.reprojection_error = rms(&residual);
+
+    for _ in 0..20 {
+        // Numeric Jacobian of the 8 residuals over (rotation vector, translation)
+        let mut jacobian = [[0.0; 6]; 8];
+        for k in 0..6 {
+            let mut delta = [0.0; 6];
+            delta[k] = EPS;
+            let perturbed = match residuals(&perturb(pose, &delta), object, corners, camera) {
+                Some(r) => r,
+                None => return,
+            };
+            for (row, (p, r)) in jacobian.iter_mut().zip(perturbed.iter().zip(&residual)) {
+                row[k] = (p - r) / EPS;
+            }
+        }
+
+        let mut jtj = [[0.0; 6]; 6];
+        let mut jtr = [0.0; 6];
+        for (row, r) in jacobian.iter().zip(&residual) {
+            for i in 0..6 {
+                jtr[i] -= row[i] * r;
+                for j in 0..6 {
+                    jtj[i][j] += row[i] * row[j];
+                }
+            }
+        }
+        for (i, row) in jtj.iter_mut().enumerate() {
+            row[i] *= 1.0 + 1e-9;
+        }
+
+        let step = match solve(jtj, jtr) {
+            Some(step) => step,
+            None => return,
+        };
+        let candidate = perturb(pose, &step);
+        let candidate_residual = match residuals(&candidate, object, corners, camera) {
+            Some(r) => r,
+            None => return,
+        };
+        let error = rms(&candidate_residual);
+        if error >= pose.reprojection_error {
+            return;
+        }
+
+        *pose = MarkerPose {
+            reprojection_error: error,
+            ..candidate
+        };
+        residual = candidate_residual;
+        if step.iter().all(|s| s.abs() < 1e-12) {
+            return;
+        }
+    }
+}
+
+/// Pixel residuals (projected - observed), None if a corner is behind the camera
+fn residuals(
+    pose: &MarkerPose,
+    object: &[[f64; 3]; 4],
+    corners: &[[f64; 2]; 4],
+    camera: &CameraInfo,
+) -> Option<[f64; 8]> {
+    let mut r = [0.0; 8];
+    for (i, (p, c)) in object.iter().zip(corners).enumerate() {
+        let (u, v) = camera.project_point(pose.apply(*p))?;
+        r[2 * i] = u - c[0];
+        r[2 * i + 1] = v - c[1];
+    }
+    Some(r)
+}
+
+fn rms(residual: &[f64; 8]) -> f64 {
+    (residual.iter().map(|r| r * r).sum::<f64>() / 4.0).sqrt()
+}
+
+/// Apply a rotation vector (left-multiplied) and translation increment
+fn perturb(pose: &MarkerPose, delta: &[f64; 6]) -> MarkerPose {
+    let dr = rodrigues([delta[0], delta[1], delta[2]]);
+    let mut rotation = [[0.0; 3]; 3];
+    for (i, row) in rotation.iter_mut().enumerate() {
+        for (j, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| dr[i][k] * pose.rotation[k][j]).sum();
+        }
+    }
+    MarkerPose {
+        rotation,
+        translation: [
+            pose.translation[0] + delta[3],
+            pose.translation[1] + delta[4],
+            pose.translation[2] + delta[5],
+        ],
+        reprojection_error: pose.reprojection_error,
+    }
+}
+
+/// Rotation matrix of a rotation vector
+fn rodrigues(w: [f64; 3]) -> [[f64; 3]; 3] {
+    let theta = norm(w);
+    if theta < 1e-15 {
+        return [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+    }
+    let [x, y, z] = mul(w, 1.0 / theta);
+    let (s, c) = theta.sin_cos();
+    let v = 1.0 - c;
+    [
+        [c + x * x * v, x * y * v - z * s, x * z * v + y * s],
+        [y * x * v + z * s, c + y * y * v, y * z * v - x * s],
+        [z * x * v - y * s, z * y * v + x * s, c + z * z * v],
+    ]
+}
+
+/// Solve a small dense linear system with partial pivoting
+fn solve<const N: usize>(mut a: [[f64; N]; N], mut b: [f64; N]) -> Option<[f64; N]> {
+    for col in 0..N {
+        let pivot = (col..N).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let pivot_row = a[col];
+        for row in col + 1..N {
+            let factor = a[row][col] / pivot_row[col];
+            for (value, p) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= factor * p;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = [0.0; N];
+    for row in (0..N).rev() {
+        let sum: f64 = (row + 1..N).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+fn add(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn mul(a: [f64; 3], s: f64) -> [f64; 3] {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+fn norm(a: [f64; 3]) -> f64 {
+    (a[0] * a[0] + a[1] * a[1] + a[2] * a[2]).sqrt()
+}
+
+fn normalize(a: [f64; 3]) -> Option<[f64; 3]> {
+    let n = norm(a);
+    (n > 1e-12).then(|| mul(a, 1.0 / n))
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DistortionModel;
+
+    fn camera() -> CameraInfo {
+        CameraInfo::new(640, 480, 500.0, 500.0, 320.0, 240.0)
+    }
+
+    /// Marker facing the camera, tilted by `tilt` about its x axis
+    fn true_pose(tilt: f64, translation: [f64; 3]) -> MarkerPose {
+        let (s, c) = tilt.sin_cos();
+        // Upright facing marker is diag(1, -1, -1), then tilted about x
+        MarkerPose {
+            rotation: [[1.0, 0.0, 0.0], [0.0, -c, s], [0.0, -s, -c]],
+            translation,
+            reprojection_error: 0.0,
+        }
+    }
+
+    fn project(pose: &MarkerPose, size: f64, camera: &CameraInfo) -> [[f64; 2]; 4] {
+        let mut corners = [[0.0; 2]; 4];
+        for (c, p) in corners.iter_mut().zip(marker_corners(size)) {
+            let (u, v) = camera.project_point(pose.apply(p)).unwrap();
+            *c = [u, v];
+        }
+        corners
+    }
+
+    fn rotation_error(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> f64 {
+        let mut max: f64 = 0.0;
+        for i in 0..3 {
+            for j in 0..3 {
+                max = max.max((a[i][j] - b[i][j]).abs());
+            }
+        }
+        max
+    }
+
+    #[test]
+    fn test_recovers_tilted_marker() {
+        let camera = camera();
+        let truth = true_pose(0.5, [0.2, -0.1, 1.5]);
+        let corners = project(&truth, 0.16, &camera);
+
+        let pose = estimate_marker_pose(&corners, 0.16, &camera).unwrap();
+        for k in 0..3 {
+            assert!((pose.translation[k] - truth.translation[k]).abs() < 1e-6);
+        }
+        assert!(rotation_error(&pose.rotation, &truth.rotation) < 1e-6);
+        assert!(pose.reprojection_error < 1e-6);
+
+        // Upright facing marker: 180 degrees about x
+        let facing = estimate_marker_pose(
+            &project(&true_pose(0.0, [0.0, 0.0, 1.0]), 0.1, &camera),
+            0.1,
+            &camera,
+        )
+        .unwrap();
+        let q = facing.quaternion();
+        assert!((q[0].abs() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_distorted_camera() {
+        let camera =
+            camera().with_distortion(DistortionModel::PlumbBob, &[-0.3, 0.1, 0.001, -0.002]);
+        let truth = true_pose(-0.3, [-0.3, 0.2, 1.2]);
+        let corners = project(&truth, 0.2, &camera);
+
+        let (x, y) = undistort_pixel(&camera, corners[0][0], corners[0][1]);
+        let p = truth.apply(marker_corners(0.2)[0]);
+        assert!((x - p[0] / p[2]).abs() < 1e-9 && (y - p[1] / p[2]).abs() < 1e-9);
+
+        let pose = estimate_marker_pose(&corners, 0.2, &camera).unwrap();
+        for k in 0..3 {
+            assert!((pose.translation[k] - truth.translation[k]).abs() < 1e-6);
+        }
+        assert!(rotation_error(&pose.rotation, &truth.rotation) < 1e-6);
+    }
+
+    #[test]
+    fn test_noisy_corners_are_refined() {
+        let camera = camera();
+        let truth = true_pose(0.4, [0.0, 0.0, 0.8]);
+        let mut corners = project(&truth, 0.1, &camera);
+        let noise = [[0.4, -0.3], [-0.2, 0.5], [0.3, 0.2], [-0.5, -0.1]];
+        for (c, n) in corners.iter_mut().zip(noise) {
+            c[0] += n[0];
+            c[1] += n[1];
+        }
+
+        let pose = estimate_marker_pose(&corners, 0.1, &camera).unwrap();
+        assert!(pose.reprojection_error < 0.5);
+        assert!((pose.translation[2] - 0.8).abs() < 0.02);
+        assert!(rotation_error(&pose.rotation, &truth.rotation) < 0.05);
+
+        // Still a proper rotation
+        let r = &pose.rotation;
+        let det = r[0][0] * (r[1][1] * r[2][2] - r[1][2] * r[2][1])
+            - r[0][1] * (r[1][0] * r[2][2] - r[1][2] * r[2][0])
+            + r[0][2] * (r[1][0] * r[2][1] - r[1][1] * r[2][0]);
+        assert!((det - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rejects_invalid_input() {
+        let camera = camera();
+        let line = [
+            [100.0, 100.0],
+            [200.0, 100.0],
+            [300.0, 100.0],
+            [400.0, 100.0],
+        ];
+        assert!(estimate_marker_pose(&line, 0.1, &camera).is_err());
+
+        let corners = [
+            [295.0, 215.0],
+            [345.0, 215.0],
+            [345.0, 265.0],
+            [295.0, 265.0],
+        ];
+        assert!(estimate_marker_pose(&corners, 0.0, &camera).is_err());
+        assert!(estimate_marker_pose(&corners, 0.1, &CameraInfo::default()).is_err());
+    }
+}
@@ -0,0 +1,483 @@
+//! Image Pixel Format Conversion and Resizing
+//!
+//! The conversions camera nodes need before inference, without pulling in
+//! OpenCV: RGB8/BGR8 swaps, grayscale, YUYV (YUV 4:2:2) unpacking, 2x
+//! downsampling, image pyramids, bilinear resizing and lens undistortion.
+//!
+//! # Features
+//!
+//! - Channel swaps and gray conversion use SSSE3 (x86_64, runtime detected) or NEON (aarch64)
+//! - In-place conversion of `Image` messages when the result fits the buffer
+//! - Slice-level kernels for zero-copy buffers (shared memory, driver frames)
+//! - Undistortion and stereo rectification from `CameraInfo` with precomputed maps
+//! - Integer fixed-point arithmetic, identical results on every target
+//!
+//! # Example
+//!
+//! ```rust
+//! use horus_library::algorithms::image_ops;
+//! use horus_library::messages::{Image, ImageEncoding};
+//!
+//! let mut image = Image::new(640, 480, ImageEncoding::Bgr8, vec![0; 640 * 480 * 3]);
+//!
+//! // BGR camera frame to RGB for the model, no allocation
+//! image_ops::convert_in_place(&mut image, ImageEncoding::Rgb8).unwrap();
+//!
+//! // Half resolution and a 3-level pyramid for feature tracking
+//! let half = image_ops::halve(&image).unwrap();
+//! assert_eq!((half.width, half.height), (320, 240));
+//! let levels = image_ops::pyramid(&image, 3).unwrap();
+//! assert_eq!(levels[2].width, 80);
+//! ```
+
+mod rectify;
+mod simd;
+
+pub use rectify::RectifyMap;
+
+use crate::messages::{Image, ImageEncoding};
+use horus_core::{HorusError, HorusResult};
+
+/// Bytes per pixel and gray weights of an 8-bit color encoding
+fn color_layout(encoding: ImageEncoding) -> Option<(usize, simd::GrayWeights)> {
+    match encoding {
+        ImageEncoding::Rgb8 => Some((3, simd::RGB_WEIGHTS)),
+        ImageEncoding::Bgr8 => Some((3, simd::BGR_WEIGHTS)),
+        ImageEncoding::Rgba8 => Some((4, simd::RGB_WEIGHTS)),
+        ImageEncoding::Bgra8 => Some((4, simd::BGR_WEIGHTS)),
+        _ => None,
+    }
+}
+
+/// Bytes per pixel of an encoding with one byte per channel
+fn byte_channels(encoding: ImageEncoding) -> Option<usize> {
+    match encoding {
+        ImageEncoding::Mono8 => Some(1),
+        _ => color_layout(encoding).map(|(channels, _)| channels),
+    }
+}
+
+/// Encoding with the red and blue channels swapped
+fn swapped(encoding: ImageEncoding) -> Option<ImageEncoding> {
+    match encoding {
+        ImageEncoding::Rgb8 => Some(ImageEncoding::Bgr8),
+        ImageEncoding::Bgr8 => Some(ImageEncoding::Rgb8),
+        ImageEncoding::Rgba8 => Some(ImageEncoding::Bgra8),
+        ImageEncoding::Bgra8 => Some(ImageEncoding::Rgba8),
+        _ => None,
+    }
+}
+
+fn unsupported(from: ImageEncoding, to: ImageEncoding) -> HorusError {
+    HorusError::Unsupported(format!(
+        "Image conversion from {:?} to {:?} is not supported",
+        from, to
+    ))
+}
+
+fn check_image(image: &Image) -> HorusResult<()> {
+    if !image.is_valid() {
+        return Err(HorusError::InvalidInput(format!(
+            "Invalid {}x{} {:?} image ({} bytes, step {})",
+            image.width,
+            image.height,
+            image.encoding,
+            image.data.len(),
+            image.step
+        )));
+    }
+    Ok(())
+}
+
+/// Image with the metadata of `like` and new pixels
+fn with_pixels(
+    like: &Image,
+    width: u32,
+    height: u32,
+    encoding: ImageEncoding,
+    data: Vec<u8>,
+) -> Image {
+    Image {
+        width,
+        height,
+        encoding,
+        step: width * encoding.bytes_per_pixel(),
+        data,
+        frame_id: like.frame_id,
+        timestamp: like.timestamp,
+    }
+}
+
+/// Swap the first and third channel of every pixel in `buf`
+///
+/// Converts RGB8 and BGR8 (`channels` = 3) or RGBA8 and BGRA8 (`channels` = 4)
+/// into each other. A trailing partial pixel is left untouched.
+pub fn swap_rb(buf: &mut [u8], channels: usize) {
+    assert!(
+        channels == 3 || channels == 4,
+        "swap_rb needs 3 or 4 channels"
+    );
+    let pixels = buf.len() / channels;
+    // SAFETY: the kernel touches pixels * channels <= buf.len() bytes
+    unsafe { simd::swap_rb(buf.as_mut_ptr(), pixels, channels) }
+}
+
+/// Write the BT.601 luma of the pixels in `src` to `dst`
+///
+/// Converts `min(src.len() / channels, dst.len())` pixels and returns that count.
+pub fn to_gray(src: &[u8], encoding: ImageEncoding, dst: &mut [u8]) -> HorusResult<usize> {
+    let (channels, weights) =
+        color_layout(encoding).ok_or_else(|| unsupported(encoding, ImageEncoding::Mono8))?;
+    let pixels = (src.len() / channels).min(dst.len());
+    // SAFETY: reads pixels * channels <= src.len() bytes, writes pixels <= dst.len()
+    unsafe { simd::gray(src.as_ptr(), dst.as_mut_ptr(), pixels, channels, weights) };
+    Ok(pixels)
+}
+
+/// Unpack YUYV (YUV 4:2:2, BT.601 limited range) into RGB8, or BGR8 with `bgr`
+///
+/// Converts `min(src.len() / 4, dst.len() / 6)` pixel pairs and returns the
+/// number of pixels written.
+pub fn yuyv_to_rgb(src: &[u8], dst: &mut [u8], bgr: bool) -> usize {
+    let (r, b) = if bgr { (2, 0) } else { (0, 2) };
+    let mut pixels = 0;
+    for (yuyv, out) in src.chunks_exact(4).zip(dst.chunks_exact_mut(6)) {
+        let d = yuyv[1] as i32 - 128;
+        let e = yuyv[3] as i32 - 128;
+        for (y, px) in [yuyv[0], yuyv[2]].into_iter().zip(out.chunks_exact_mut(3)) {
+            let c = 298 * (y as i32 - 16);
+            px[r] = ((c + 409 * e + 128) >> 8).clamp(0, 255) as u8;
+            px[1] = ((c - 100 * d - 208 * e + 128) >> 8).clamp(0, 255) as u8;
+            px[b] = ((c + 516 * d + 128) >> 8).clamp(0, 255) as u8;
+        }
+        pixels += 2;
+    }
+    pixels
+}
+
+/// Convert `image` to `to` without allocating where the result fits
+///
+/// Channel swaps (RGB8 ↔ BGR8, RGBA8 ↔ BGRA8) and conversions to Mono8
+/// reuse the buffer; rows are repacked without padding. Conversions that
+/// grow the image (Mono8 or YUYV to color) allocate a new buffer.
+pub fn convert_in_place(image: &mut Image, to: ImageEncoding) -> HorusResult<()> {
+    let from = image.encoding;
+    if from == to {
+        return Ok(());
+    }
+    check_image(image)?;
+
+    let width = image.width as usize;
+    let step = image.step as usize;
+
+    if swapped(from) == Some(to) {
+        let channels = from.bytes_per_pixel() as usize;
+        for row in image.data.chunks_mut(step).take(image.height as usize) {
+            swap_rb(&mut row[..width * channels], channels);
+        }
+        image.encoding = to;
+        return Ok(());
+    }
+
+    if to == ImageEncoding::Mono8 {
+        if let Some((channels, weights)) = color_layout(from) {
+            let ptr = image.data.as_mut_ptr();
+            for y in 0..image.height as usize {
+                // SAFETY: row y is read from y * step and written to
+                // y * width <= y * step, so only bytes already read are
+                // overwritten; both ranges lie within the validated buffer
+                unsafe {
+                    simd::gray(
+                        ptr.add(y * step),
+                        ptr.add(y * width),
+                        width,
+                        channels,
+                        weights,
+                    )
+                };
+            }
+            image.data.truncate(width * image.height as usize);
+            image.encoding = to;
+            image.step = image.width;
+            return Ok(());
+        }
+    }
+
+    *image = convert(image, to)?;
+    Ok(())
+}
+
+/// Convert `image` to `to` into a new image
+///
+/// Supports RGB8, BGR8, RGBA8 and BGRA8 among swapped pairs and to Mono8,
+/// Mono8 to RGB8/BGR8, and YUYV (`Yuv422`) to RGB8/BGR8.
+pub fn convert(image: &Image, to: ImageEncoding) -> HorusResult<Image> {
+    let from = image.encoding;
+    check_image(image)?;
+
+    let (width, height) = (image.width as usize, image.height as usize);
+    let step = image.step as usize;
+    let rows = image.data.chunks(step).take(height);
+
+    match (from, to) {
+        (ImageEncoding::Mono8, ImageEncoding::Rgb8 | ImageEncoding::Bgr8) => {
+            let mut data = Vec::with_capacity(width * height * 3);
+            for row in rows {
+                for &value in &row[..width] {
+                    data.extend_from_slice(&[value; 3]);
+                }
+            }
+            Ok(with_pixels(image, image.width, image.height, to, data))
+        }
+        (ImageEncoding::Yuv422, ImageEncoding::Rgb8 | ImageEncoding::Bgr8) => {
+            if width % 2 != 0 {
+                return Err(HorusError::InvalidInput(format!(
+                    "YUYV image width must be even, got {}",
+                    width
+                )));
+            }
+            let mut data = vec![0u8; width * height * 3];
+            for (row, out) in rows.zip(data.chunks_exact_mut(width * 3)) {
+                yuyv_to_rgb(&row[..width * 2], out, to == ImageEncoding::Bgr8);
+            }
+            Ok(with_pixels(image, image.width, image.height, to, data))
+        }
+        _ if from == to
+            || swapped(from) == Some(to)
+            || (to == ImageEncoding::Mono8 && color_layout(from).is_some()) =>
+        {
+            let mut copy = image.clone();
+            convert_in_place(&mut copy, to)?;
+            Ok(copy)
+        }
+        _ => Err(unsupported(from, to)),
+    }
+}
+
+/// Downsample by two with a 2x2 box filter
+///
+/// An odd last row or column is dropped. Works on Mono8, RGB8, BGR8, RGBA8
+/// and BGRA8.
+pub fn halve(image: &Image) -> HorusResult<Image> {
+    check_image(image)?;
+    let channels = byte_channels(image.encoding).ok_or_else(|| {
+        HorusError::Unsupported(format!("Cannot resize {:?} images", image.encoding))
+    })?;
+    if image.width < 2 || image.height < 2 {
+        return Err(HorusError::InvalidInput(format!(
+            "Cannot halve a {}x{} image",
+            image.width, image.height
+        )));
+    }
+
+    let (out_w, out_h) = (image.width as usize / 2, image.height as usize / 2);
+    let step = image.step as usize;
+    let row_bytes = out_w * channels;
+    let mut data = vec![0u8; row_bytes * out_h];
+
+    for (y, out) in data.chunks_exact_mut(row_bytes).enumerate() {
+        let top = &image.data[2 * y * step..];
+        let bottom = &image.data[(2 * y + 1) * step..];
+        for (x, px) in out.chunks_exact_mut(channels).enumerate() {
+            let left = 2 * x * channels;
+            let right = left + channels;
+            for c in 0..channels {
+                let sum = top[left + c] as u16
+                    + top[right + c] as u16
+                    + bottom[left + c] as u16
+                    + bottom[right + c] as u16;
+                px[c] = ((sum + 2) >> 2) as u8;
+            }
+        }
+    }
+
+    Ok(with_pixels(
+        image,
+        out_w as u32,
+        out_h as u32,
+        image.encoding,
+        data,
+    ))
+}
+
+/// Successively halved copies of `image`, largest first
+///
+/// `levels[0]` is half the input size. Stops early when a level would be
+/// smaller than 1x1.
+pub fn pyramid(image: &Image, levels: usize) -> HorusResult<Vec<Image>> {
+    let mut out: Vec<Image> = Vec::with_capacity(levels);
+    for _ in 0..levels {
+        let prev = out.last().unwrap_or(image);
+        if prev.width < 2 || prev.height < 2 {
+            break;
+        }
+        let next = halve(prev)?;
+        out.push(next);
+    }
+    Ok(out)
+}
+
+/// Source index and 8-bit weight of the right neighbour for each output coordinate
+fn bilinear_taps(src: usize, dst: usize) -> Vec<(usize, usize, u32)> {
+    (0..dst)
+        .map(|i| {
+            // Pixel centres: (i + 0.5) * src / dst - 0.5, in 1/256 pixel
+            let pos = ((2 * i + 1) * src * 256 / (2 * dst)).saturating_sub(128);
+            let i0 = (pos >> 8).min(src - 1);
+            let i1 = (i0 + 1).min(src - 1);
+            (i0, i1, (pos & 0xff) as u32)
+        })
+        .collect()
+}
+
+/// Resize to `width` x `height` with bilinear interpolation
+///
+/// Works on Mono8, RGB8, BGR8, RGBA8 and BGRA8.
+pub fn resize(image: &Image, width: u32, height: u32) -> HorusResult<Image> {
+    check_image(image)?;
+    let channels = byte_channels(image.encoding).ok_or_else(|| {
+        HorusError::Unsupported(format!("Cannot resize {:?} images", image.encoding))
+    })?;
+    if width == 0 || height == 0 {
+        return Err(HorusError::InvalidInput(format!(
+            "Cannot resize to {}x{}",
+            width, height
+        )));
+    }
+
+    let xs = bilinear_taps(image.width as usize, width as usize);
+    let ys = bilinear_taps(image.height as usize, height as usize);
+    let step = image.step as usize;
+    let row_bytes = width as usize * channels;
+    let mut data = vec![0u8; row_bytes * height as usize];
+
+    for (&(y0, y1, fy), out) in ys.iter().zip(data.chunks_exact_mut(row_bytes)) {
+        let top = &image.data[y0 * step..];
+        let bottom = &image.data[y1 * step..];
+        for (&(x0, x1, fx), px) in xs.iter().zip(out.chunks_exact_mut(channels)) {
+            let (a, b) = (x0 * channels, x1 * channels);
+            for c in 0..channels {
+                let t = top[a + c] as u32 * (256 - fx) + top[b + c] as u32 * fx;
+                let u = bottom[a + c] as u32 * (256 - fx) + bottom[b + c] as u32 * fx;
+                px[c] = ((t * (256 - fy) + u * fy + 32768) >> 16) as u8;
+            }
+        }
+    }
+
+    Ok(with_pixels(image, width, height, image.encoding, data))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pattern(len: usize) -> Vec<u8> {
+        (0..len).map(|i| (i * 37 % 251) as u8).collect()
+    }
+
+    #[test]
+    fn test_swap_rb_roundtrip() {
+        // Odd sizes exercise both the SIMD body and the scalar tail
+        let original = pattern(3 * 37);
+        let mut buf = original.clone();
+        swap_rb(&mut buf, 3);
+        for (px, orig) in buf.chunks(3).zip(original.chunks(3)) {
+            assert_eq!(px, [orig[2], orig[1], orig[0]]);
+        }
+        swap_rb(&mut buf, 3);
+        assert_eq!(buf, original);
+
+        let original = pattern(4 * 19);
+        let mut rgba = original.clone();
+        swap_rb(&mut rgba, 4);
+        for (px, orig) in rgba.chunks(4).zip(original.chunks(4)) {
+            assert_eq!(px, [orig[2], orig[1], orig[0], orig[3]]);
+        }
+    }
+
+    #[test]
+    fn test_gray_matches_scalar() {
+        let src = pattern(3 * 41);
+        let mut dst = vec![0u8; 41];
+        assert_eq!(to_gray(&src, ImageEncoding::Rgb8, &mut dst).unwrap(), 41);
+        for (px, gray) in src.chunks(3).zip(&dst) {
+            assert_eq!(
+                *gray,
+                simd::gray_pixel(px[0], px[1], px[2], simd::RGB_WEIGHTS)
+            );
+        }
+
+        let white = vec![255u8; 4 * 20];
+        let mut out = vec![0u8; 20];
+        to_gray(&white, ImageEncoding::Bgra8, &mut out).unwrap();
+        assert!(out.iter().all(|&v| v == 255));
+    }
+
+    #[test]
+    fn test_gray_in_place_with_row_padding() {
+        let data = pattern(18 * 3);
+        let mut image = Image::new(5, 3, ImageEncoding::Bgr8, data.clone());
+        image.step = 18;
+        convert_in_place(&mut image, ImageEncoding::Mono8).unwrap();
+        assert_eq!((image.step, image.data.len()), (5, 15));
+        for y in 0..3 {
+            for x in 0..5 {
+                let px = &data[y * 18 + x * 3..];
+                let expected = simd::gray_pixel(px[0], px[1], px[2], simd::BGR_WEIGHTS);
+                assert_eq!(image.data[y * 5 + x], expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_yuyv_to_rgb() {
+        // Black, white and mid gray with neutral chroma
+        let src = [16, 128, 235, 128, 126, 128, 126, 128];
+        let mut dst = [0u8; 12];
+        assert_eq!(yuyv_to_rgb(&src, &mut dst, false), 4);
+        assert_eq!(&dst[..3], &[0, 0, 0]);
+        assert_eq!(&dst[3..6], &[255, 255, 255]);
+        assert_eq!(dst[6], dst[7]);
+
+        let image = Image::new(2, 1, ImageEncoding::Yuv422, vec![81, 90, 81, 240]);
+        let rgb = convert(&image, ImageEncoding::Rgb8).unwrap();
+        // Pure red in BT.601: Y=81, U=90, V=240
+        assert!(rgb.data[0] > 250 && rgb.data[1] < 5 && rgb.data[2] < 5);
+    }
+
+    #[test]
+    fn test_halve_and_pyramid() {
+        let data = vec![
+            0, 10, 20, 30, //
+            40, 50, 60, 70, //
+            1, 1, 1, 1, //
+        ];
+        let image = Image::new(4, 3, ImageEncoding::Mono8, data);
+        let half = halve(&image).unwrap();
+        assert_eq!((half.width, half.height), (2, 1));
+        assert_eq!(half.data, vec![25, 45]);
+
+        let image = Image::new(16, 8, ImageEncoding::Rgb8, vec![100; 16 * 8 * 3]);
+        let levels = pyramid(&image, 5).unwrap();
+        let sizes: Vec<(u32, u32)> = levels.iter().map(|l| (l.width, l.height)).collect();
+        assert_eq!(sizes, vec![(8, 4), (4, 2), (2, 1)]);
+        assert!(levels[2].data.iter().all(|&v| v == 100));
+    }
+
+    #[test]
+    fn test_resize_bilinear() {
+        let image = Image::new(2, 1, ImageEncoding::Mono8, vec![0, 200]);
+        let wide = resize(&image, 4, 1).unwrap();
+        assert_eq!(wide.data, vec![0, 50, 150, 200]);
+
+        let same = resize(&wide, 4, 1).unwrap();
+        assert_eq!(same.data, wide.data);
+        assert!(resize(&image, 0, 1).is_err());
+    }
+
+    #[test]
+    fn test_unsupported_conversion() {
+        let image = Image::new(1, 1, ImageEncoding::Mono16, vec![0, 0]);
+        assert!(convert(&image, ImageEncoding::Rgb8).is_err());
+    }
+}
@@ -0,0 +1,229 @@
+//! Undistortion and rectification with precomputed maps
+//!
+//! The lens model is evaluated once per output pixel when the map is built;
+//! remapping a frame is then a bilinear lookup per pixel.
+
+use super::{byte_channels, check_image, with_pixels};
+use crate::messages::{CameraInfo, Image};
+use horus_core::{HorusError, HorusResult};
+
+/// Source pixel of one output pixel: top-left neighbour and 8-bit
+/// fractional offsets, `x == u16::MAX` when it falls outside the image
+#[derive(Debug, Clone, Copy)]
+struct Tap {
+    x: u16,
+    y: u16,
+    fx: u8,
+    fy: u8,
+}
+
+const OUTSIDE: Tap = Tap {
+    x: u16::MAX,
+    y: 0,
+    fx: 0,
+    fy: 0,
+};
+
+/// Precomputed pixel map from raw camera images to undistorted or rectified images
+///
+/// ```rust,ignore
+/// let map = RectifyMap::undistort(&camera_info)?;   // once, when CameraInfo arrives
+/// let undistorted = map.remap(&image)?;              // per frame
+/// ```
+#[derive(Debug, Clone)]
+pub struct RectifyMap {
+    width: u32,
+    height: u32,
+    taps: Vec<Tap>,
+}
+
+impl RectifyMap {
+    /// Map removing lens distortion, keeping the camera matrix K
+    pub fn undistort(info: &CameraInfo) -> HorusResult<Self> {
+        let identity = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        Self::build(info, &identity, &info.camera_matrix)
+    }
+
+    /// Map to the rectified image described by the rectification matrix R
+    /// and projection matrix P (stereo rectification)
+    ///
+    /// Falls back to K when P is unset.
+    pub fn rectify(info: &CameraInfo) -> HorusResult<Self> {
+        let p = &info.projection_matrix;
+        let new_k = if p[0] != 0.0 && p[5] != 0.0 {
+            [p[0], p[1], p[2], p[4], p[5], p[6], p[8], p[9], p[10]]
+        } else {
+            info.camera_matrix
+        };
+        Self::build(info, &info.rectification_matrix, &new_k)
+    }
+
+    fn build(info: &CameraInfo, r: &[f64; 9], new_k: &[f64; 9]) -> HorusResult<Self> {
+        let (width, height) = (info.width, info.height);
+        if width == 0 || height == 0 || width >= u16::MAX as u32 || height >= u16::MAX as u32 {
+            return Err(HorusError::InvalidInput(format!(
+                "Cannot build a rectification map for a {}x{} camera",
+                width, height
+            )));
+        }
+        let (fx, fy, cx, cy) = (new_k[0], new_k[4], new_k[2], new_k[5]);
+        if fx == 0.0 || fy == 0.0 {
+            return Err(HorusError::InvalidInput(
+                "Camera matrix has zero focal length".to_string(),
+            ));
+        }
+
+        let k = &info.camera_matrix;
+        let (max_x, max_y) = ((width - 1) as f64, (height - 1) as f64);
+        let mut taps = Vec::with_capacity(width as usize * height as usize);
+
+        for v in 0..height {
+            for u in 0..width {
+                // Ray of the output pixel, rotated back by R^T into the raw camera frame
+                let xr = (u as f64 - cx) / fx;
+                let yr = (v as f64 - cy) / fy;
+                let x = r[0] * xr + r[3] * yr + r[6];
+                let y = r[1] * xr + r[4] * yr + r[7];
+                let z = r[2] * xr + r[5] * yr + r[8];
+                if z <= 0.0 {
+                    taps.push(OUTSIDE);
+                    continue;
+                }
+
+                let (xd, yd) = info.distort(x / z, y / z);
+                let su = k[0] * xd + k[1] * yd + k[2];
+                let sv = k[4] * yd + k[5];
+                if !(0.0..=max_x).contains(&su) || !(0.0..=max_y).contains(&sv) {
+                    taps.push(OUTSIDE);
+                    continue;
+                }
+
+                // 8-bit fractions; rounding up to 256 moves to the next pixel
+                let (fu, fv) = ((su * 256.0).round() as u32, (sv * 256.0).round() as u32);
+                taps.push(Tap {
+                    x: (fu >> 8) as u16,
+                    y: (fv >> 8) as u16,
+                    fx: (fu & 0xff) as u8,
+                    fy: (fv & 0xff) as u8,
+                });
+            }
+        }
+
+        Ok(Self {
+            width,
+            height,
+            taps,
+        })
+    }
+
+    /// Image width the map was built for
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Image height the map was built for
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Raw image position sampled for output pixel (u, v), None if outside the raw image
+    pub fn source(&self, u: u32, v: u32) -> Option<(f64, f64)> {
+        if u >= self.width || v >= self.height {
+            return None;
+        }
+        let tap = self.taps[(v * self.width + u) as usize];
+        (tap.x != u16::MAX).then(|| {
+            (
+                tap.x as f64 + tap.fx as f64 / 256.0,
+                tap.y as f64 + tap.fy as f64 / 256.0,
+            )
+        })
+    }
+
+    /// Undistort or rectify `image`, filling pixels without a source with zero
+    ///
+    /// Works on Mono8, RGB8, BGR8, RGBA8 and BGRA8 images of the calibrated size.
+    pub fn remap(&self, image: &Image) -> HorusResult<Image> {
+        check_image(image)?;
+        let channels = byte_channels(image.encoding).ok_or_else(|| {
+            HorusError::Unsupported(format!("Cannot remap {:?} images", image.encoding))
+        })?;
+        if image.width != self.width || image.height != self.height {
+            return Err(HorusError::InvalidInput(format!(
+                "Image is {}x{} but the map was built for {}x{}",
+                image.width, image.height, self.width, self.height
+            )));
+        }
+
+        let step = image.step as usize;
+        let (max_x, max_y) = (self.width as usize - 1, self.height as usize - 1);
+        let row_bytes = self.width as usize * channels;
+        let mut data = vec![0u8; row_bytes * self.height as usize];
+
+        for (taps, out) in self
+            .taps
+            .chunks_exact(self.width as usize)
+            .zip(data.chunks_exact_mut(row_bytes))
+        {
+            for (tap, px) in taps.iter().zip(out.chunks_exact_mut(channels)) {
+                if tap.x == u16::MAX {
+                    continue;
+                }
+                let (x0, y0) = (tap.x as usize, tap.y as usize);
+                let (a, b) = (x0 * channels, (x0 + 1).min(max_x) * channels);
+                let top = &image.data[y0 * step..];
+                let bottom = &image.data[(y0 + 1).min(max_y) * step..];
+                let (fx, fy) = (tap.fx as u32, tap.fy as u32);
+                for c in 0..channels {
+                    let t = top[a + c] as u32 * (256 - fx) + top[b + c] as u32 * fx;
+                    let u = bottom[a + c] as u32 * (256 - fx) + bottom[b + c] as u32 * fx;
+                    px[c] = ((t * (256 - fy) + u * fy + 32768) >> 16) as u8;
+                }
+            }
+        }
+
+        Ok(with_pixels(
+            image,
+            self.width,
+            self.height,
+            image.encoding,
+            data,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::{DistortionModel, ImageEncoding};
+
+    #[test]
+    fn test_identity_without_distortion() {
+        let info = CameraInfo::new(8, 6, 10.0, 10.0, 4.0, 3.0);
+        let map = RectifyMap::undistort(&info).unwrap();
+        let data: Vec<u8> = (0..48).collect();
+        let image = Image::new(8, 6, ImageEncoding::Mono8, data.clone());
+        assert_eq!(map.remap(&image).unwrap().data, data);
+        assert_eq!(map.source(5, 2), Some((5.0, 2.0)));
+    }
+
+    #[test]
+    fn test_undistort_barrel() {
+        let info = CameraInfo::new(64, 48, 40.0, 40.0, 32.0, 24.0)
+            .with_distortion(DistortionModel::PlumbBob, &[-0.3, 0.0, 0.0, 0.0, 0.0]);
+        let map = RectifyMap::undistort(&info).unwrap();
+
+        // The centre stays put, edge pixels sample closer to the centre
+        assert_eq!(map.source(32, 24), Some((32.0, 24.0)));
+        let (u, v) = map.source(60, 24).unwrap();
+        assert!(u < 60.0 && u > 32.0);
+        assert_eq!(v, 24.0);
+
+        let image = Image::new(64, 48, ImageEncoding::Rgb8, vec![200; 64 * 48 * 3]);
+        let out = map.remap(&image).unwrap();
+        assert_eq!(out.data[(24 * 64 + 32) * 3], 200);
+
+        let small = Image::new(32, 24, ImageEncoding::Rgb8, vec![0; 32 * 24 * 3]);
+        assert!(map.remap(&small).is_err());
+    }
+}
@@ -0,0 +1,233 @@
+//! SIMD kernels for channel swaps and gray conversion
+//!
+//! Each kernel has an SSSE3 path (x86_64, detected at runtime) and a NEON
+//! path (aarch64). The vector paths return how many pixels they handled and
+//! the scalar loop finishes the rest, with the same fixed-point arithmetic so
+//! every path gives identical output.
+
+/// Fixed-point gray weights for the first three channels, summing to 128
+pub(super) type GrayWeights = [u8; 3];
+
+/// BT.601 luma weights (0.299, 0.587, 0.114) scaled by 128, for RGB order
+pub(super) const RGB_WEIGHTS: GrayWeights = [38, 75, 15];
+
+/// BT.601 luma weights for BGR order
+pub(super) const BGR_WEIGHTS: GrayWeights = [15, 75, 38];
+
+/// Gray value of one pixel
+#[inline]
+pub(super) fn gray_pixel(c0: u8, c1: u8, c2: u8, w: GrayWeights) -> u8 {
+    let sum = c0 as u16 * w[0] as u16 + c1 as u16 * w[1] as u16 + c2 as u16 * w[2] as u16;
+    ((sum + 64) >> 7) as u8
+}
+
+/// Swap the first and third channel of `pixels` pixels of `channels` (3 or 4) bytes
+///
+/// # Safety
+/// `ptr` must be valid for reads and writes of `pixels * channels` bytes.
+pub(super) unsafe fn swap_rb(ptr: *mut u8, pixels: usize, channels: usize) {
+    let done = swap_rb_simd(ptr, pixels, channels);
+    for i in done..pixels {
+        let p = ptr.add(i * channels);
+        std::ptr::swap(p, p.add(2));
+    }
+}
+
+/// Convert `pixels` pixels of `channels` (3 or 4) bytes to one gray byte each
+///
+/// `dst` may equal `src`: pixel `i` is written at byte `i`, which every
+/// kernel has already read by then.
+///
+/// # Safety
+/// `src` must be valid for reads of `pixels * channels` bytes and `dst` for
+/// writes of `pixels` bytes.
+pub(super) unsafe fn gray(
+    src: *const u8,
+    dst: *mut u8,
+    pixels: usize,
+    channels: usize,
+    weights: GrayWeights,
+) {
+    let done = gray_simd(src, dst, pixels, channels, weights);
+    for i in done..pixels {
+        let p = src.add(i * channels);
+        *dst.add(i) = gray_pixel(*p, *p.add(1), *p.add(2), weights);
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+unsafe fn swap_rb_simd(ptr: *mut u8, pixels: usize, channels: usize) -> usize {
+    if is_x86_feature_detected!("ssse3") {
+        x86::swap_rb(ptr, pixels, channels)
+    } else {
+        0
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+unsafe fn gray_simd(
+    src: *const u8,
+    dst: *mut u8,
+    pixels: usize,
+    channels: usize,
+    weights: GrayWeights,
+) -> usize {
+    if is_x86_feature_detected!("ssse3") {
+        x86::gray(src, dst, pixels, channels, weights)
+    } else {
+        0
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+unsafe fn swap_rb_simd(ptr: *mut u8, pixels: usize, channels: usize) -> usize {
+    neon::swap_rb(ptr, pixels, channels)
+}
+
+#[cfg(target_arch = "aarch64")]
+unsafe fn gray_simd(
+    src: *const u8,
+    dst: *mut u8,
+    pixels: usize,
+    channels: usize,
+    weights: GrayWeights,
+) -> usize {
+    neon::gray(src, dst, pixels, channels, weights)
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+unsafe fn swap_rb_simd(_ptr: *mut u8, _pixels: usize, _channels: usize) -> usize {
+    0
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+unsafe fn gray_simd(
+    _src: *const u8,
+    _dst: *mut u8,
+    _pixels: usize,
+    _channels: usize,
+    _weights: GrayWeights,
+) -> usize {
+    0
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use super::GrayWeights;
+    use std::arch::x86_64::*;
+
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn swap_rb(ptr: *mut u8, pixels: usize, channels: usize) -> usize {
+        let len = pixels * channels;
+        let mut i = 0;
+        if channels == 4 {
+            let mask = _mm_setr_epi8(2, 1, 0, 3, 6, 5, 4, 7, 10, 9, 8, 11, 14, 13, 12, 15);
+            while i + 16 <= len {
+                let v = _mm_loadu_si128(ptr.add(i) as *const __m128i);
+                _mm_storeu_si128(ptr.add(i) as *mut __m128i, _mm_shuffle_epi8(v, mask));
+                i += 16;
+            }
+            i / 4
+        } else {
+            // Five pixels per 16-byte load; byte 15 passes through unchanged
+            // and starts the next group
+            let mask = _mm_setr_epi8(2, 1, 0, 5, 4, 3, 8, 7, 6, 11, 10, 9, 14, 13, 12, 15);
+            while i + 16 <= len {
+                let v = _mm_loadu_si128(ptr.add(i) as *const __m128i);
+                _mm_storeu_si128(ptr.add(i) as *mut __m128i, _mm_shuffle_epi8(v, mask));
+                i += 15;
+            }
+            i / 3
+        }
+    }
+
+    #[target_feature(enable = "ssse3")]
+    pub(super) unsafe fn gray(
+        src: *const u8,
+        dst: *mut u8,
+        pixels: usize,
+        channels: usize,
+        w: GrayWeights,
+    ) -> usize {
+        let (w0, w1, w2) = (w[0] as i8, w[1] as i8, w[2] as i8);
+        let weights = _mm_setr_epi8(w0, w1, w2, 0, w0, w1, w2, 0, w0, w1, w2, 0, w0, w1, w2, 0);
+        // Spread four 3-byte pixels over 4-byte lanes (-1 zeroes the lane)
+        let expand = _mm_setr_epi8(0, 1, 2, -1, 3, 4, 5, -1, 6, 7, 8, -1, 9, 10, 11, -1);
+        let round = _mm_set1_epi16(64);
+        let len = pixels * channels;
+
+        // Eight pixels per iteration as two 16-byte loads of four pixels each
+        let mut n = 0;
+        while n + 8 <= pixels && (n + 4) * channels + 16 <= len {
+            let p = src.add(n * channels);
+            let mut a = _mm_loadu_si128(p as *const __m128i);
+            let mut b = _mm_loadu_si128(p.add(4 * channels) as *const __m128i);
+            if channels == 3 {
+                a = _mm_shuffle_epi8(a, expand);
+                b = _mm_shuffle_epi8(b, expand);
+            }
+            // Per pixel: (w0*c0 + w1*c1, w2*c2), then the pairs summed
+            let a = _mm_maddubs_epi16(a, weights);
+            let b = _mm_maddubs_epi16(b, weights);
+            let sum = _mm_hadd_epi16(a, b);
+            let gray = _mm_srli_epi16(_mm_add_epi16(sum, round), 7);
+            _mm_storel_epi64(dst.add(n) as *mut __m128i, _mm_packus_epi16(gray, gray));
+            n += 8;
+        }
+        n
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use super::GrayWeights;
+    use std::arch::aarch64::*;
+
+    pub(super) unsafe fn swap_rb(ptr: *mut u8, pixels: usize, channels: usize) -> usize {
+        let mut n = 0;
+        while n + 16 <= pixels {
+            let p = ptr.add(n * channels);
+            if channels == 4 {
+                let v = vld4q_u8(p);
+                vst4q_u8(p, uint8x16x4_t(v.2, v.1, v.0, v.3));
+            } else {
+                let v = vld3q_u8(p);
+                vst3q_u8(p, uint8x16x3_t(v.2, v.1, v.0));
+            }
+            n += 16;
+        }
+        n
+    }
+
+    pub(super) unsafe fn gray(
+        src: *const u8,
+        dst: *mut u8,
+        pixels: usize,
+        channels: usize,
+        w: GrayWeights,
+    ) -> usize {
+        let (w0, w1, w2) = (vdup_n_u8(w[0]), vdup_n_u8(w[1]), vdup_n_u8(w[2]));
+        let mut n = 0;
+        while n + 16 <= pixels {
+            let p = src.add(n * channels);
+            let (c0, c1, c2) = if channels == 4 {
+                let v = vld4q_u8(p);
+                (v.0, v.1, v.2)
+            } else {
+                let v = vld3q_u8(p);
+                (v.0, v.1, v.2)
+            };
+            let lo = vmull_u8(vget_low_u8(c0), w0);
+            let lo = vmlal_u8(lo, vget_low_u8(c1), w1);
+            let lo = vmlal_u8(lo, vget_low_u8(c2), w2);
+            let hi = vmull_u8(vget_high_u8(c0), w0);
+            let hi = vmlal_u8(hi, vget_high_u8(c1), w1);
+            let hi = vmlal_u8(hi, vget_high_u8(c2), w2);
+            // Rounding narrow: (x + 64) >> 7, as in the scalar path
+            let gray = vcombine_u8(vrshrn_n_u16::<7>(lo), vrshrn_n_u16::<7>(hi));
+            vst1q_u8(dst.add(n), gray);
+            n += 16;
+        }
+        n
+    }
+}
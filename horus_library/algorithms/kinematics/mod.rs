@@ -0,0 +1,868 @@
+//! Robot Kinematics from URDF
+//!
+//! Kinematic tree of a robot (links, joints, joint limits) built from the same
+//! URDF that sim3d loads, so controllers and simulation share one model.
+//!
+//! # Features
+//!
+//! - URDF parsing into a tree of links and joints
+//! - Revolute, continuous, prismatic and fixed joints with limits
+//! - Forward kinematics for every link
+//! - Geometric Jacobian of any link
+//! - Damped-least-squares inverse kinematics with joint limit clamping
+//!
+//! Joint positions are passed as one slice ordered like
+//! [`KinematicTree::joint_names`] (movable joints only).
+//!
+//! # Example
+//!
+//! ```rust
+//! use horus_library::algorithms::kinematics::{IkConfig, Joint, JointType, KinematicTree};
+//! use horus_library::hframe::Transform;
+//!
+//! // Planar two-link arm, 1 m links
+//! let z = [0.0, 0.0, 1.0];
+//! let link = Transform::from_translation([1.0, 0.0, 0.0]);
+//! let tree = KinematicTree::new(vec![
+//!     Joint::new("shoulder", JointType::Revolute, "base", "upper", Transform::identity(), z),
+//!     Joint::new("elbow", JointType::Revolute, "upper", "forearm", link, z),
+//!     Joint::new("wrist", JointType::Fixed, "forearm", "tool", link, z),
+//! ])
+//! .unwrap();
+//!
+//! let tool = tree.link_pose(&[0.0, std::f64::consts::FRAC_PI_2], "tool").unwrap();
+//! assert!((tool.translation[0] - 1.0).abs() < 1e-9);
+//! assert!((tool.translation[1] - 1.0).abs() < 1e-9);
+//!
+//! let mut config = IkConfig::default();
+//! config.orientation_weight = 0.0; // position only
+//! let target = Transform::from_translation([1.2, 0.8, 0.0]);
+//! let solution = tree.solve_ik("tool", &target, &[0.3, 0.3], &config).unwrap();
+//! assert!(solution.converged);
+//! ```
+
+use crate::hframe::{HFrame, HFrameResult, Transform};
+use horus_core::error::{HorusError, HorusResult};
+use std::collections::{HashMap, VecDeque};
+
+/// Joint type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JointType {
+    /// Rigid connection
+    Fixed,
+    /// Rotation about the axis within limits
+    Revolute,
+    /// Unlimited rotation about the axis
+    Continuous,
+    /// Translation along the axis within limits
+    Prismatic,
+}
+
+impl JointType {
+    /// Whether the joint has a position variable
+    pub fn is_movable(&self) -> bool {
+        !matches!(self, JointType::Fixed)
+    }
+}
+
+/// Joint position, velocity and effort limits
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct JointLimits {
+    /// Lower position limit (rad or m)
+    pub lower: f64,
+    /// Upper position limit (rad or m)
+    pub upper: f64,
+    /// Maximum velocity (rad/s or m/s)
+    pub velocity: f64,
+    /// Maximum effort (Nm or N)
+    pub effort: f64,
+}
+
+/// Joint connecting a parent link to a child link
+#[derive(Debug, Clone, PartialEq)]
+pub struct Joint {
+    /// Joint name
+    pub name: String,
+    /// Joint type
+    pub joint_type: JointType,
+    /// Parent link name
+    pub parent: String,
+    /// Child link name
+    pub child: String,
+    /// Child frame relative to the parent link at zero position
+    pub origin: Transform,
+    /// Unit motion axis in the joint frame
+    pub axis: [f64; 3],
+    /// Position limits (None for continuous and fixed joints)
+    pub limits: Option<JointLimits>,
+}
+
+impl Joint {
+    /// Create a joint without limits
+    pub fn new(
+        name: &str,
+        joint_type: JointType,
+        parent: &str,
+        child: &str,
+        origin: Transform,
+        axis: [f64; 3],
+    ) -> Self {
+        let norm = (axis[0] * axis[0] + axis[1] * axis[1] + axis[2] * axis[2]).sqrt();
+        let axis = if norm > 1e-12 {
+            [axis[0] / norm, axis[1] / norm, axis[2] / norm]
+        } else {
+            [1.0, 0.0, 0.0]
+        };
+        Self {
+            name: name.to_string(),
+            joint_type,
+            parent: parent.to_string(),
+            child: child.to_string(),
+            origin,
+            axis,
+            limits: None,
+        }
+    }
+
+    /// Set position limits (velocity and effort unlimited)
+    pub fn with_limits(mut self, lower: f64, upper: f64) -> Self {
+        self.limits = Some(JointLimits {
+            lower,
+            upper,
+            velocity: f64::INFINITY,
+            effort: f64::INFINITY,
+        });
+        self
+    }
+
+    /// Child frame relative to the parent link at position `q`
+    pub fn transform(&self, q: f64) -> Transform {
+        match self.joint_type {
+            JointType::Fixed => self.origin,
+            JointType::Revolute | JointType::Continuous => self
+                .origin
+                .compose(&Transform::from_axis_angle([0.0; 3], self.axis, q)),
+            JointType::Prismatic => self.origin.compose(&Transform::from_translation([
+                self.axis[0] * q,
+                self.axis[1] * q,
+                self.axis[2] * q,
+            ])),
+        }
+    }
+
+    /// Clamp a position to the joint limits
+    pub fn clamp(&self, q: f64) -> f64 {
+        match self.limits {
+            Some(limits) if self.joint_type != JointType::Continuous => {
+                q.clamp(limits.lower, limits.upper)
+            }
+            _ => q,
+        }
+    }
+}
+
+/// Inverse kinematics configuration
+#[derive(Debug, Clone)]
+pub struct IkConfig {
+    /// Maximum solver iterations
+    pub max_iterations: usize,
+    /// Damping factor (larger is more stable near singularities, slower)
+    pub damping: f64,
+    /// Position tolerance (m)
+    pub position_tolerance: f64,
+    /// Orientation tolerance (rad)
+    pub orientation_tolerance: f64,
+    /// Weight of the orientation error (0 solves for position only)
+    pub orientation_weight: f64,
+    /// Largest change of any joint per iteration (rad or m)
+    pub max_step: f64,
+}
+
+impl Default for IkConfig {
+    fn default() -> Self {
+        Self {
+            max_iterations: 200,
+            damping: 0.05,
+            position_tolerance: 1e-4,
+            orientation_tolerance: 1e-3,
+            orientation_weight: 1.0,
+            max_step: 0.2,
+        }
+    }
+}
+
+/// Inverse kinematics result
+#[derive(Debug, Clone)]
+pub struct IkSolution {
+    /// Joint positions (ordered like `KinematicTree::joint_names`)
+    pub positions: Vec<f64>,
+    /// Whether both tolerances were met
+    pub converged: bool,
+    /// Iterations used
+    pub iterations: usize,
+    /// Remaining position error (m)
+    pub position_error: f64,
+    /// Remaining orientation error (rad)
+    pub orientation_error: f64,
+}
+
+/// Kinematic tree of a robot
+#[derive(Debug, Clone)]
+pub struct KinematicTree {
+    links: Vec<String>,
+    root: usize,
+    joints: Vec<Joint>, // parents before children
+    link_index: HashMap<String, usize>,
+    parent_joint: Vec<Option<usize>>, // per link
+    joint_links: Vec<(usize, usize)>, // (parent, child) per joint
+    movable: Vec<usize>,              // joint indices with a position variable
+    variable: Vec<Option<usize>>,     // per joint, index into positions
+}
+
+impl KinematicTree {
+    /// Build a tree from joints
+    ///
+    /// Links are taken from the joints. There must be exactly one root link
+    /// and every other link must be the child of exactly one joint.
+    pub fn new(joints: Vec<Joint>) -> HorusResult<Self> {
+        if joints.is_empty() {
+            return Err(HorusError::InvalidInput(
+                "kinematic tree needs at least one joint".to_string(),
+            ));
+        }
+
+        let mut links: Vec<String> = Vec::new();
+        let mut link_index: HashMap<String, usize> = HashMap::new();
+        let mut edges = Vec::with_capacity(joints.len());
+        for joint in &joints {
+            let mut ends = [0; 2];
+            for (end, name) in ends.iter_mut().zip([&joint.parent, &joint.child]) {
+                *end = *link_index.entry(name.clone()).or_insert_with(|| {
+                    links.push(name.clone());
+                    links.len() - 1
+                });
+            }
+            edges.push((ends[0], ends[1]));
+        }
+
+        let mut parents = vec![0; links.len()];
+        for (j, &(_, child)) in edges.iter().enumerate() {
+            parents[child] += 1;
+            if parents[child] > 1 {
+                return Err(HorusError::InvalidInput(format!(
+                    "link '{}' is the child of more than one joint",
+                    joints[j].child
+                )));
+            }
+        }
+
+        let roots: Vec<usize> = (0..links.len()).filter(|&l| parents[l] == 0).collect();
+        if roots.len() != 1 {
+            return Err(HorusError::InvalidInput(format!(
+                "kinematic tree needs exactly one root link, found {}",
+                roots.len()
+            )));
+        }
+        let root = roots[0];
+
+        // Order joints parents-first by walking down from the root
+        let mut order = Vec::with_capacity(joints.len());
+        let mut queue = VecDeque::from([root]);
+        while let Some(link) = queue.pop_front() {
+            for (j, &(parent, child)) in edges.iter().enumerate() {
+                if parent == link {
+                    order.push(j);
+                    queue.push_back(child);
+                }
+            }
+        }
+        if order.len() != joints.len() {
+            return Err(HorusError::InvalidInput(
+                "kinematic tree contains a cycle".to_string(),
+            ));
+        }
+
+        let mut slots: Vec<Option<Joint>> = joints.into_iter().map(Some).collect();
+        let joints: Vec<Joint> = order.iter().filter_map(|&j| slots[j].take()).collect();
+        let joint_links: Vec<(usize, usize)> = order.iter().map(|&j| edges[j]).collect();
+
+        let mut parent_joint = vec![None; links.len()];
+        for (j, &(_, child)) in joint_links.iter().enumerate() {
+            parent_joint[child] = Some(j);
+        }
+
+        let movable: Vec<usize> = (0..joints.len())
+            .filter(|&j| joints[j].joint_type.is_movable())
+            .collect();
+        let mut variable = vec![None; joints.len()];
+        for (i, &j) in movable.iter().enumerate() {
+            variable[j] = Some(i);
+        }
+
+        Ok(Self {
+            links,
+            root,
+            joints,
+            link_index,
+            parent_joint,
+            joint_links,
+            movable,
+            variable,
+        })
+    }
+
+    /// Parse a URDF robot description
+    ///
+    /// Floating, planar and spherical joints are treated as fixed.
+    pub fn from_urdf_str(urdf: &str) -> HorusResult<Self> {
+        let robot =
+            urdf_rs::read_from_string(urdf).map_err(|e| HorusError::ParseError(e.to_string()))?;
+
+        let joints = robot
+            .joints
+            .iter()
+            .map(|joint| {
+                let joint_type = match joint.joint_type {
+                    urdf_rs::JointType::Revolute => JointType::Revolute,
+                    urdf_rs::JointType::Continuous => JointType::Continuous,
+                    urdf_rs::JointType::Prismatic => JointType::Prismatic,
+                    _ => JointType::Fixed,
+                };
+                let xyz = &joint.origin.xyz;
+                let rpy = &joint.origin.rpy;
+                let axis = &joint.axis.xyz;
+                let mut result = Joint::new(
+                    &joint.name,
+                    joint_type,
+                    &joint.parent.link,
+                    &joint.child.link,
+                    Transform::from_euler([xyz[0], xyz[1], xyz[2]], [rpy[0], rpy[1], rpy[2]]),
+                    [axis[0], axis[1], axis[2]],
+                );
+                if matches!(joint_type, JointType::Revolute | JointType::Prismatic) {
+                    result.limits = Some(JointLimits {
+                        lower: joint.limit.lower,
+                        upper: joint.limit.upper,
+                        velocity: joint.limit.velocity,
+                        effort: joint.limit.effort,
+                    });
+                }
+                result
+            })
+            .collect();
+
+        Self::new(joints)
+    }
+
+    /// Root link name
+    pub fn root_link(&self) -> &str {
+        &self.links[self.root]
+    }
+
+    /// All link names
+    pub fn links(&self) -> &[String] {
+        &self.links
+    }
+
+    /// All joints, parents before children
+    pub fn joints(&self) -> &[Joint] {
+        &self.joints
+    }
+
+    /// Look up a joint by name
+    pub fn joint(&self, name: &str) -> Option<&Joint> {
+        self.joints.iter().find(|j| j.name == name)
+    }
+
+    /// Names of the movable joints, in joint position order
+    pub fn joint_names(&self) -> Vec<&str> {
+        self.movable
+            .iter()
+            .map(|&j| self.joints[j].name.as_str())
+            .collect()
+    }
+
+    /// Number of movable joints
+    pub fn dof(&self) -> usize {
+        self.movable.len()
+    }
+
+    /// Clamp joint positions to their limits
+    pub fn clamp_positions(&self, positions: &mut [f64]) {
+        for (q, &j) in positions.iter_mut().zip(&self.movable) {
+            *q = self.joints[j].clamp(*q);
+        }
+    }
+
+    /// Whether all joint positions are within their limits
+    pub fn within_limits(&self, positions: &[f64]) -> bool {
+        positions
+            .iter()
+            .zip(&self.movable)
+            .all(|(&q, &j)| self.joints[j].clamp(q) == q)
+    }
+
+    /// Pose of every link relative to the root, indexed like [`links`](Self::links)
+    pub fn forward_kinematics(&self, positions: &[f64]) -> HorusResult<Vec<Transform>> {
+        self.check_positions(positions)?;
+
+        let mut poses = vec![Transform::identity(); self.links.len()];
+        for (j, joint) in self.joints.iter().enumerate() {
+            let (parent, child) = self.joint_links[j];
+            let q = self.variable[j].map_or(0.0, |i| positions[i]);
+            poses[child] = poses[parent].compose(&joint.transform(q));
+        }
+        Ok(poses)
+    }
+
+    /// Pose of one link relative to the root
+    pub fn link_pose(&self, positions: &[f64], link: &str) -> HorusResult<Transform> {
+        let index = self.require_link(link)?;
+        Ok(self.forward_kinematics(positions)?[index])
+    }
+
+    /// Geometric Jacobian of a link origin in the root frame
+    ///
+    /// One column per movable joint, each `[vx, vy, vz, wx, wy, wz]`. Joints
+    /// that do not move the link have zero columns.
+    pub fn jacobian(&self, positions: &[f64], link: &str) -> HorusResult<Vec<[f64; 6]>> {
+        let index = self.require_link(link)?;
+        let poses = self.forward_kinematics(positions)?;
+        Ok(self.jacobian_from_poses(&poses, positions, index))
+    }
+
+    /// Solve for joint positions that put `link` at `target` (relative to the root)
+    ///
+    /// Damped least squares starting from `seed`: each iteration solves
+    /// `dq = J^T (J J^T + λ² I)^-1 e` for the pose error `e`, limits the step
+    /// and clamps the result to the joint limits. The best solution found is
+    /// returned even if it did not converge.
+    pub fn solve_ik(
+        &self,
+        link: &str,
+        target: &Transform,
+        seed: &[f64],
+        config: &IkConfig,
+    ) -> HorusResult<IkSolution> {
+        let index = self.require_link(link)?;
+        self.check_positions(seed)?;
+
+        let mut positions = seed.to_vec();
+        self.clamp_positions(&mut positions);
+
+        let mut best = IkSolution {
+            positions: positions.clone(),
+            converged: false,
+            iterations: 0,
+            position_error: f64::INFINITY,
+            orientation_error: f64::INFINITY,
+        };
+        let lambda2 = config.damping * config.damping;
+
+        for iteration in 0..=config.max_iterations {
+            let poses = self.forward_kinematics(&positions)?;
+            let error = pose_error(&poses[index], target);
+            let position_error = norm3([error[0], error[1], error[2]]);
+            let orientation_error = norm3([error[3], error[4], error[5]]);
+
+            let converged = position_error <= config.position_tolerance
+                && (config.orientation_weight == 0.0
+                    || orientation_error <= config.orientation_tolerance);
+            let weighted_error = position_error + config.orientation_weight * orientation_error;
+            let best_error =
+                best.position_error + config.orientation_weight * best.orientation_error;
+            if converged || weighted_error < best_error {
+                best = IkSolution {
+                    positions: positions.clone(),
+                    converged,
+                    iterations: iteration,
+                    position_error,
+                    orientation_error,
+                };
+            }
+            if converged || iteration == config.max_iterations {
+                break;
+            }
+
+            // Weighted error and Jacobian rows
+            let mut e = error;
+            let mut jacobian = self.jacobian_from_poses(&poses, &positions, index);
+            for value in e.iter_mut().skip(3) {
+                *value *= config.orientation_weight;
+            }
+            for column in jacobian.iter_mut() {
+                for value in column.iter_mut().skip(3) {
+                    *value *= config.orientation_weight;
+                }
+            }
+
+            // A = J J^T + λ² I  (6x6), solve A y = e, dq = J^T y
+            let mut a = [[0.0; 6]; 6];
+            for (r, row) in a.iter_mut().enumerate() {
+                for (c, value) in row.iter_mut().enumerate() {
+                    *value = jacobian.iter().map(|col| col[r] * col[c]).sum();
+                }
+                row[r] += lambda2;
+            }
+            let Some(y) = solve6(a, e) else {
+                break;
+            };
+
+            let mut dq: Vec<f64> = jacobian
+                .iter()
+                .map(|col| (0..6).map(|k| col[k] * y[k]).sum())
+                .collect();
+            let largest = dq.iter().fold(0.0_f64, |m, d| m.max(d.abs()));
+            if largest > config.max_step {
+                let scale = config.max_step / largest;
+                dq.iter_mut().for_each(|d| *d *= scale);
+            }
+
+            for (q, d) in positions.iter_mut().zip(&dq) {
+                *q += d;
+            }
+            self.clamp_positions(&mut positions);
+        }
+
+        Ok(best)
+    }
+
+    /// Write the moving link frames for `positions` into an HFrame
+    ///
+    /// Every movable joint's child link must already be registered as a
+    /// dynamic frame (e.g. with `HFrame::load_description`).
+    pub fn update_hframe(
+        &self,
+        hframe: &HFrame,
+        positions: &[f64],
+        timestamp_ns: u64,
+    ) -> HFrameResult<()> {
+        for (&j, &q) in self.movable.iter().zip(positions) {
+            let joint = &self.joints[j];
+            hframe.update_transform(&joint.child, &joint.transform(q), timestamp_ns)?;
+        }
+        Ok(())
+    }
+
+    fn require_link(&self, link: &str) -> HorusResult<usize> {
+        self.link_index
+            .get(link)
+            .copied()
+            .ok_or_else(|| HorusError::NotFound(format!("link '{}'", link)))
+    }
+
+    fn check_positions(&self, positions: &[f64]) -> HorusResult<()> {
+        if positions.len() != self.movable.len() {
+            return Err(HorusError::InvalidInput(format!(
+                "expected {} joint positions, got {}",
+                self.movable.len(),
+                positions.len()
+            )));
+        }
+        Ok(())
+    }
+
+    fn jacobian_from_poses(
+        &self,
+        poses: &[Transform],
+        positions: &[f64],
+        link: usize,
+    ) -> Vec<[f64; 6]> {
+        let tip = poses[link].translation;
+        let mut jacobian = vec![[0.0; 6]; self.movable.len()];
+
+        // Walk from the link up to the root
+        let mut current = link;
+        while let Some(j) = self.parent_joint[current] {
+            let (parent, _) = self.joint_links[j];
+            if let Some(i) = self.variable[j] {
+                let joint = &self.joints[j];
+                // Joint frame: the axis does not move under its own motion
+                let frame = poses[parent].compose(&joint.transform(positions[i]));
+                let axis = frame.transform_vector(joint.axis);
+                jacobian[i] = match joint.joint_type {
+                    JointType::Prismatic => [axis[0], axis[1], axis[2], 0.0, 0.0, 0.0],
+                    _ => {
+                        let origin = frame.translation;
+                        let r = [tip[0] - origin[0], tip[1] - origin[1], tip[2] - origin[2]];
+                        let v = cross(axis, r);
+                        [v[0], v[1], v[2], axis[0], axis[1], axis[2]]
+                    }
+                };
+            }
+            current = parent;
+        }
+        jacobian
+    }
+}
+
+/// Pose error `[dx, dy, dz, rx, ry, rz]` from `current` to `target` (root frame)
+fn pose_error(current: &Transform, target: &Transform) -> [f64; 6] {
+    let dp = [
+        target.translation[0] - current.translation[0],
+        target.translation[1] - current.translation[1],
+        target.translation[2] - current.translation[2],
+    ];
+
+    // q_err = q_target * conj(q_current), as a rotation vector
+    let [x1, y1, z1, w1] = target.rotation;
+    let [x2, y2, z2, w2] = [
+        -current.rotation[0],
+        -current.rotation[1],
+        -current.rotation[2],
+        current.rotation[3],
+    ];
+    let mut q = [
+        w1 * x2 + x1 * w2 + y1 * z2 - z1 * y2,
+        w1 * y2 - x1 * z2 + y1 * w2 + z1 * x2,
+        w1 * z2 + x1 * y2 - y1 * x2 + z1 * w2,
+        w1 * w2 - x1 * x2 - y1 * y2 - z1 * z2,
+    ];
+    if q[3] < 0.0 {
+        q = [-q[0], -q[1], -q[2], -q[3]];
+    }
+    let s = norm3([q[0], q[1], q[2]]);
+    let scale = if s < 1e-12 {
+        2.0
+    } else {
+        2.0 * s.atan2(q[3]) / s
+    };
+
+    [
+        dp[0],
+        dp[1],
+        dp[2],
+        q[0] * scale,
+        q[1] * scale,
+        q[2] * scale,
+    ]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn norm3(v: [f64; 3]) -> f64 {
+    (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt()
+}
+
+/// Solve a 6x6 linear system with partial pivoting
+fn solve6(mut a: [[f64; 6]; 6], mut b: [f64; 6]) -> Option<[f64; 6]> {
+    for col in 0..6 {
+        let pivot = (col..6).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let pivot_row = a[col];
+        for row in col + 1..6 {
+            let factor = a[row][col] / pivot_row[col];
+            for (value, p) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= factor * p;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = [0.0; 6];
+    for row in (0..6).rev() {
+        let sum: f64 = (row + 1..6).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    const Z: [f64; 3] = [0.0, 0.0, 1.0];
+
+    fn planar_arm() -> KinematicTree {
+        KinematicTree::new(vec![
+            Joint::new(
+                "shoulder",
+                JointType::Revolute,
+                "base",
+                "upper_arm",
+                Transform::identity(),
+                Z,
+            )
+            .with_limits(-3.0, 3.0),
+            Joint::new(
+                "elbow",
+                JointType::Revolute,
+                "upper_arm",
+                "forearm",
+                Transform::from_translation([1.0, 0.0, 0.0]),
+                Z,
+            )
+            .with_limits(-3.0, 3.0),
+            Joint::new(
+                "tool_mount",
+                JointType::Fixed,
+                "forearm",
+                "tool",
+                Transform::from_translation([1.0, 0.0, 0.0]),
+                [0.0; 3],
+            ),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_tree_structure() {
+        let tree = planar_arm();
+        assert_eq!(tree.root_link(), "base");
+        assert_eq!(tree.dof(), 2);
+        assert_eq!(tree.joint_names(), vec!["shoulder", "elbow"]);
+        assert_eq!(tree.links().len(), 4);
+
+        let mut q = [5.0, -5.0];
+        assert!(!tree.within_limits(&q));
+        tree.clamp_positions(&mut q);
+        assert_eq!(q, [3.0, -3.0]);
+
+        // Two roots
+        let result = KinematicTree::new(vec![
+            Joint::new("a", JointType::Fixed, "base", "x", Transform::identity(), Z),
+            Joint::new(
+                "b",
+                JointType::Fixed,
+                "other",
+                "y",
+                Transform::identity(),
+                Z,
+            ),
+        ]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_forward_kinematics() {
+        let tree = planar_arm();
+
+        let tool = tree.link_pose(&[0.0, 0.0], "tool").unwrap();
+        assert!((tool.translation[0] - 2.0).abs() < 1e-9);
+
+        let tool = tree.link_pose(&[FRAC_PI_2, -FRAC_PI_2], "tool").unwrap();
+        assert!((tool.translation[0] - 1.0).abs() < 1e-9);
+        assert!((tool.translation[1] - 1.0).abs() < 1e-9);
+        assert!(tool.rotation_angle().abs() < 1e-9);
+
+        assert!(tree.link_pose(&[0.0], "tool").is_err());
+        assert!(tree.link_pose(&[0.0, 0.0], "gripper").is_err());
+    }
+
+    #[test]
+    fn test_prismatic_joint() {
+        let tree = KinematicTree::new(vec![Joint::new(
+            "lift",
+            JointType::Prismatic,
+            "base",
+            "carriage",
+            Transform::from_translation([0.0, 0.0, 0.1]),
+            Z,
+        )
+        .with_limits(0.0, 0.5)])
+        .unwrap();
+
+        let pose = tree.link_pose(&[0.3], "carriage").unwrap();
+        assert!((pose.translation[2] - 0.4).abs() < 1e-9);
+
+        let jacobian = tree.jacobian(&[0.3], "carriage").unwrap();
+        assert_eq!(jacobian[0], [0.0, 0.0, 1.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_jacobian_matches_finite_difference() {
+        let tree = planar_arm();
+        let q = [0.4, 0.7];
+        let jacobian = tree.jacobian(&q, "tool").unwrap();
+
+        let h = 1e-6;
+        for i in 0..2 {
+            let mut q2 = q;
+            q2[i] += h;
+            let p1 = tree.link_pose(&q, "tool").unwrap().translation;
+            let p2 = tree.link_pose(&q2, "tool").unwrap().translation;
+            for k in 0..3 {
+                assert!(((p2[k] - p1[k]) / h - jacobian[i][k]).abs() < 1e-4);
+            }
+            assert!((jacobian[i][5] - 1.0).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_urdf() {
+        let urdf = r#"
+<robot name="lift_arm">
+  <link name="base_link"/>
+  <link name="carriage"/>
+  <link name="arm"/>
+  <link name="camera"/>
+  <joint name="lift" type="prismatic">
+    <parent link="base_link"/>
+    <child link="carriage"/>
+    <origin xyz="0 0 0.2" rpy="0 0 0"/>
+    <axis xyz="0 0 1"/>
+    <limit lower="0" upper="0.5" effort="100" velocity="0.2"/>
+  </joint>
+  <joint name="swing" type="continuous">
+    <parent link="carriage"/>
+    <child link="arm"/>
+    <axis xyz="0 0 1"/>
+  </joint>
+  <joint name="camera_mount" type="fixed">
+    <parent link="arm"/>
+    <child link="camera"/>
+    <origin xyz="0.5 0 0" rpy="0 0 0"/>
+  </joint>
+</robot>
+"#;
+        let tree = KinematicTree::from_urdf_str(urdf).unwrap();
+        assert_eq!(tree.root_link(), "base_link");
+        assert_eq!(tree.joint_names(), vec!["lift", "swing"]);
+
+        let lift = tree.joint("lift").unwrap().limits.unwrap();
+        assert_eq!(lift.upper, 0.5);
+        assert_eq!(lift.velocity, 0.2);
+        assert!(tree.joint("swing").unwrap().limits.is_none());
+
+        let camera = tree.link_pose(&[0.1, FRAC_PI_2], "camera").unwrap();
+        assert!(camera.translation[0].abs() < 1e-9);
+        assert!((camera.translation[1] - 0.5).abs() < 1e-9);
+        assert!((camera.translation[2] - 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_inverse_kinematics() {
+        let tree = planar_arm();
+
+        // Reach a pose produced by forward kinematics, including orientation
+        let expected = [0.6, -1.1];
+        let target = tree.link_pose(&expected, "tool").unwrap();
+        let solution = tree
+            .solve_ik("tool", &target, &[0.1, -0.2], &IkConfig::default())
+            .unwrap();
+        assert!(solution.converged);
+        assert!((solution.positions[0] - expected[0]).abs() < 1e-3);
+        assert!((solution.positions[1] - expected[1]).abs() < 1e-3);
+
+        // Out of reach: best effort, not converged, within limits
+        let far = Transform::from_translation([5.0, 0.0, 0.0]);
+        let solution = tree
+            .solve_ik("tool", &far, &[0.1, 0.1], &IkConfig::default())
+            .unwrap();
+        assert!(!solution.converged);
+        assert!(solution.position_error > 2.9);
+        assert!(tree.within_limits(&solution.positions));
+    }
+}
@@ -16,31 +16,51 @@
 //! - **astar**: A* grid-based optimal pathfinding
 //! - **rrt**: Rapidly-exploring Random Tree sampling-based planning
 //! - **pure_pursuit**: Path tracking controller for mobile robots
+//! - **dwa**: Dynamic Window Approach local planner with footprint collision checks
 //!
 //! ## Localization & State Estimation
 //! - **ekf**: Extended Kalman Filter for 2D robot localization
 //! - **kalman_filter**: Linear Kalman Filter for 1D state estimation
 //! - **sensor_fusion**: Multi-sensor fusion with variance weighting
+//! - **soc_estimator**: Battery state of charge from coulomb counting and OCV curves
 //!
 //! ## Control
 //! - **pid**: PID feedback control with anti-windup
+//! - **admittance**: Cartesian admittance control for force-compliant manipulation
+//! - **cia402**: CiA-402 servo drive state machine and modes of operation
 //! - **differential_drive**: Differential drive kinematics and odometry
+//! - **kinematics**: URDF kinematic trees, forward kinematics, Jacobians and damped-least-squares IK
+//! - **trajectory**: Linear, cubic and quintic joint trajectory interpolation
 //!
 //! ## Mapping
 //! - **occupancy_grid**: 2D occupancy grid with ray tracing
+//! - **slam**: Log-odds grid SLAM with correlative scan matching
+//!
+//! ## Vision
+//! - **fiducial**: Square marker (AprilTag/ArUco) pose estimation from corner pixels
+//! - **image_ops**: SIMD pixel format conversion, downsampling and resizing for `Image`
 //!
 //! ## Safety & Collision Detection
 //! - **aabb**: Axis-Aligned Bounding Box collision detection
 //! - **safety_layer**: Multi-level safety monitoring and enforcement
 
 pub mod aabb;
+pub mod admittance;
 pub mod astar;
+pub mod cia402;
 pub mod differential_drive;
+pub mod dwa;
 pub mod ekf;
+pub mod fiducial;
+pub mod image_ops;
 pub mod kalman_filter;
+pub mod kinematics;
 pub mod occupancy_grid;
 pub mod pid;
 pub mod pure_pursuit;
 pub mod rrt;
 pub mod safety_layer;
 pub mod sensor_fusion;
+pub mod slam;
+pub mod soc_estimator;
+pub mod trajectory;
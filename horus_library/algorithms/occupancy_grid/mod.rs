@@ -157,7 +157,7 @@ impl OccupancyGrid {
 }
 
 /// Bresenham's line algorithm
-fn bresenham_line(x0: i32, y0: i32, x1: i32, y1: i32) -> Vec<(i32, i32)> {
+pub(crate) fn bresenham_line(x0: i32, y0: i32, x1: i32, y1: i32) -> Vec<(i32, i32)> {
     let mut cells = Vec::new();
 
     let dx = (x1 - x0).abs();
@@ -0,0 +1,503 @@
+//! 2D Scan-Matching SLAM
+//!
+//! Lightweight grid SLAM for planar robots with a laser scanner: each scan is
+//! aligned against the map built so far with a correlative scan matcher, and
+//! then ray traced into a log-odds occupancy grid at the corrected pose.
+//!
+//! # Features
+//!
+//! - Log-odds occupancy mapping with free-space ray tracing
+//! - Correlative matching over a configurable (x, y, theta) search window
+//! - Optional odometry prior, so matching only corrects the drift
+//! - Map updates only after the robot moved, to limit smearing
+//!
+//! There is no loop closure: drift that exceeds what matching can correct
+//! stays in the map. This is intended for small environments such as the
+//! sim2d worlds.
+//!
+//! # Example
+//!
+//! ```rust
+//! use horus_library::algorithms::slam::{GridSlam, SlamConfig};
+//!
+//! let mut slam = GridSlam::new(SlamConfig::default());
+//!
+//! // Wall 2m in front of the robot, as scan endpoints in the robot frame
+//! let scan: Vec<(f64, f64)> = (-10..=10).map(|i| (2.0, i as f64 * 0.1)).collect();
+//!
+//! let pose = slam.process(Some((0.0, 0.0, 0.0)), &scan);
+//! assert_eq!(pose, (0.0, 0.0, 0.0));
+//! assert_eq!(slam.scans_integrated(), 1);
+//!
+//! // The wall is mapped as occupied, the space before it as free
+//! assert!(slam.occupancy_at(2.0, 0.0) > 50);
+//! assert!((0..50).contains(&slam.occupancy_at(1.0, 0.0)));
+//! ```
+
+use super::occupancy_grid::bresenham_line;
+use std::f64::consts::PI;
+
+/// Grid SLAM parameters
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlamConfig {
+    /// Cell size (m)
+    pub resolution: f64,
+    /// Map width in cells
+    pub width: usize,
+    /// Map height in cells
+    pub height: usize,
+    /// World position of the map's lower left corner (m)
+    pub origin: (f64, f64),
+    /// Beams are truncated to this range and then only clear space (m)
+    pub max_range: f64,
+    /// Log-odds added to the cell a beam ends in
+    pub log_odds_hit: f32,
+    /// Log-odds added to cells a beam passes through
+    pub log_odds_miss: f32,
+    /// Bound on the accumulated log-odds, so cells can still change
+    pub log_odds_limit: f32,
+    /// Half-width of the translational search window (m)
+    pub search_linear: f64,
+    /// Half-width of the angular search window (rad)
+    pub search_angular: f64,
+    /// Angular search step (rad); the linear step is the resolution
+    pub angular_step: f64,
+    /// Matches scoring below this keep the odometry estimate (0.0 to 1.0)
+    pub min_match_score: f64,
+    /// Distance travelled before the next scan is added to the map (m)
+    pub min_travel_distance: f64,
+    /// Rotation before the next scan is added to the map (rad)
+    pub min_travel_angle: f64,
+}
+
+impl Default for SlamConfig {
+    fn default() -> Self {
+        Self {
+            resolution: 0.05,
+            width: 400,
+            height: 400,
+            origin: (-10.0, -10.0),
+            max_range: 12.0,
+            log_odds_hit: 0.9,
+            log_odds_miss: -0.4,
+            log_odds_limit: 5.0,
+            search_linear: 0.2,
+            search_angular: 0.2,
+            angular_step: 0.01,
+            min_match_score: 0.2,
+            min_travel_distance: 0.1,
+            min_travel_angle: 0.1,
+        }
+    }
+}
+
+/// Occupancy grid SLAM with correlative scan matching
+#[derive(Debug, Clone)]
+pub struct GridSlam {
+    config: SlamConfig,
+    log_odds: Vec<f32>,
+    observed: Vec<bool>,
+    likelihood: Vec<f32>, // Smoothed hit probability used for matching
+    pose: (f64, f64, f64),
+    last_odom: Option<(f64, f64, f64)>,
+    last_map_pose: Option<(f64, f64, f64)>,
+    scans_integrated: usize,
+    last_score: f64,
+}
+
+impl GridSlam {
+    /// Create an empty map with the robot at the origin
+    pub fn new(config: SlamConfig) -> Self {
+        let cells = config.width * config.height;
+        Self {
+            config,
+            log_odds: vec![0.0; cells],
+            observed: vec![false; cells],
+            likelihood: vec![0.0; cells],
+            pose: (0.0, 0.0, 0.0),
+            last_odom: None,
+            last_map_pose: None,
+            scans_integrated: 0,
+            last_score: 0.0,
+        }
+    }
+
+    /// Configuration
+    pub fn config(&self) -> &SlamConfig {
+        &self.config
+    }
+
+    /// Current pose estimate (x, y, theta) in the map frame
+    pub fn pose(&self) -> (f64, f64, f64) {
+        self.pose
+    }
+
+    /// Override the pose estimate (e.g. the robot's start pose before the first scan)
+    pub fn set_pose(&mut self, x: f64, y: f64, theta: f64) {
+        self.pose = (x, y, normalize_angle(theta));
+    }
+
+    /// Number of scans added to the map
+    pub fn scans_integrated(&self) -> usize {
+        self.scans_integrated
+    }
+
+    /// Score of the last scan match (fraction of points on mapped obstacles)
+    pub fn match_score(&self) -> f64 {
+        self.last_score
+    }
+
+    /// Clear the map and the pose estimate
+    pub fn reset(&mut self) {
+        *self = Self::new(self.config.clone());
+    }
+
+    /// Process a scan and return the corrected pose
+    ///
+    /// `odom` is the robot pose reported by odometry, used to predict the
+    /// motion since the previous scan (None to rely on matching alone).
+    /// `points` are the scan endpoints in the robot frame.
+    pub fn process(
+        &mut self,
+        odom: Option<(f64, f64, f64)>,
+        points: &[(f64, f64)],
+    ) -> (f64, f64, f64) {
+        // Predict with the odometry motion since the last scan
+        if let (Some(odom), Some(last)) = (odom, self.last_odom) {
+            let (dx, dy) = (odom.0 - last.0, odom.1 - last.1);
+            let (sin, cos) = last.2.sin_cos();
+            let local = (cos * dx + sin * dy, -sin * dx + cos * dy);
+            self.pose = compose(self.pose, (local.0, local.1, odom.2 - last.2));
+        }
+        if odom.is_some() {
+            self.last_odom = odom;
+        }
+
+        if points.is_empty() {
+            return self.pose;
+        }
+
+        if self.scans_integrated > 0 {
+            let (matched, score) = self.scan_match(self.pose, points);
+            self.last_score = score;
+            if score >= self.config.min_match_score {
+                self.pose = matched;
+            }
+        }
+
+        let moved = match self.last_map_pose {
+            None => true,
+            Some(last) => {
+                (self.pose.0 - last.0).hypot(self.pose.1 - last.1)
+                    >= self.config.min_travel_distance
+                    || normalize_angle(self.pose.2 - last.2).abs() >= self.config.min_travel_angle
+            }
+        };
+        if moved {
+            self.integrate(self.pose, points);
+        }
+        self.pose
+    }
+
+    /// Best pose for the scan within the search window around `guess`, with its score
+    pub fn scan_match(
+        &self,
+        guess: (f64, f64, f64),
+        points: &[(f64, f64)],
+    ) -> ((f64, f64, f64), f64) {
+        if points.is_empty() {
+            return (guess, 0.0);
+        }
+
+        let res = self.config.resolution;
+        let linear_steps = (self.config.search_linear / res).round() as i32;
+        let angular_steps = if self.config.angular_step > 0.0 {
+            (self.config.search_angular / self.config.angular_step).round() as i32
+        } else {
+            0
+        };
+
+        let mut best = (guess, f64::NEG_INFINITY, 0.0);
+        let mut rotated = Vec::with_capacity(points.len());
+        for a in -angular_steps..=angular_steps {
+            let theta = guess.2 + a as f64 * self.config.angular_step;
+            let (sin, cos) = theta.sin_cos();
+            rotated.clear();
+            rotated.extend(
+                points.iter().map(|&(px, py)| {
+                    (guess.0 + cos * px - sin * py, guess.1 + sin * px + cos * py)
+                }),
+            );
+
+            for ix in -linear_steps..=linear_steps {
+                for iy in -linear_steps..=linear_steps {
+                    let (dx, dy) = (ix as f64 * res, iy as f64 * res);
+                    let hits: f64 = rotated
+                        .iter()
+                        .map(|&(x, y)| self.likelihood_at(x + dx, y + dy) as f64)
+                        .sum();
+                    let score = hits / points.len() as f64;
+
+                    // Small pull towards the prediction to break ties
+                    let offset = (dx * dx + dy * dy) / self.config.search_linear.powi(2).max(1e-9)
+                        + (a as f64 / angular_steps.max(1) as f64).powi(2);
+                    let weighted = score - 0.01 * offset;
+                    if weighted > best.1 {
+                        best = (
+                            (guess.0 + dx, guess.1 + dy, normalize_angle(theta)),
+                            weighted,
+                            score,
+                        );
+                    }
+                }
+            }
+        }
+        (best.0, best.2)
+    }
+
+    /// Ray trace a scan taken at `pose` into the map
+    pub fn integrate(&mut self, pose: (f64, f64, f64), points: &[(f64, f64)]) {
+        let (sin, cos) = pose.2.sin_cos();
+        let start = self.world_to_cell(pose.0, pose.1);
+
+        for &(px, py) in points {
+            let range = px.hypot(py);
+            if !range.is_finite() || range <= 0.0 {
+                continue;
+            }
+            let (hit, scale) = if range > self.config.max_range {
+                (false, self.config.max_range / range)
+            } else {
+                (true, 1.0)
+            };
+            let (lx, ly) = (px * scale, py * scale);
+            let end =
+                self.world_to_cell(pose.0 + cos * lx - sin * ly, pose.1 + sin * lx + cos * ly);
+
+            let cells = bresenham_line(start.0, start.1, end.0, end.1);
+            let last = cells.len() - 1;
+            for (i, &(cx, cy)) in cells.iter().enumerate() {
+                let delta = if i == last && hit {
+                    self.config.log_odds_hit
+                } else {
+                    self.config.log_odds_miss
+                };
+                self.add_log_odds(cx, cy, delta);
+            }
+        }
+
+        self.update_likelihood();
+        self.last_map_pose = Some(pose);
+        self.scans_integrated += 1;
+    }
+
+    /// Occupancy of a cell (-1 = unknown, 0 = free, 100 = occupied), as in `OccupancyGrid`
+    pub fn occupancy(&self, cx: usize, cy: usize) -> i8 {
+        match self.index(cx as i32, cy as i32) {
+            Some(i) if self.observed[i] => (probability(self.log_odds[i]) * 100.0).round() as i8,
+            _ => -1,
+        }
+    }
+
+    /// Occupancy at a world position (-1 outside the map or unknown)
+    pub fn occupancy_at(&self, x: f64, y: f64) -> i8 {
+        let (cx, cy) = self.world_to_cell(x, y);
+        if cx < 0 || cy < 0 {
+            return -1;
+        }
+        self.occupancy(cx as usize, cy as usize)
+    }
+
+    /// Row-major occupancy of the whole map, starting at the lower left corner
+    pub fn occupancy_data(&self) -> Vec<i8> {
+        (0..self.config.height)
+            .flat_map(|cy| (0..self.config.width).map(move |cx| (cx, cy)))
+            .map(|(cx, cy)| self.occupancy(cx, cy))
+            .collect()
+    }
+
+    /// Cell containing a world position (may lie outside the map)
+    pub fn world_to_cell(&self, x: f64, y: f64) -> (i32, i32) {
+        (
+            ((x - self.config.origin.0) / self.config.resolution).floor() as i32,
+            ((y - self.config.origin.1) / self.config.resolution).floor() as i32,
+        )
+    }
+
+    fn index(&self, cx: i32, cy: i32) -> Option<usize> {
+        if cx >= 0
+            && cy >= 0
+            && (cx as usize) < self.config.width
+            && (cy as usize) < self.config.height
+        {
+            Some(cy as usize * self.config.width + cx as usize)
+        } else {
+            None
+        }
+    }
+
+    fn add_log_odds(&mut self, cx: i32, cy: i32, delta: f32) {
+        if let Some(i) = self.index(cx, cy) {
+            let limit = self.config.log_odds_limit;
+            self.log_odds[i] = (self.log_odds[i] + delta).clamp(-limit, limit);
+            self.observed[i] = true;
+        }
+    }
+
+    fn likelihood_at(&self, x: f64, y: f64) -> f32 {
+        let (cx, cy) = self.world_to_cell(x, y);
+        self.index(cx, cy).map_or(0.0, |i| self.likelihood[i])
+    }
+
+    /// Occupied cells score fully, their neighbours half, so near misses still count
+    fn update_likelihood(&mut self) {
+        let (w, h) = (self.config.width, self.config.height);
+        let occupied: Vec<f32> = self
+            .log_odds
+            .iter()
+            .map(|&l| if l > 0.0 { probability(l) as f32 } else { 0.0 })
+            .collect();
+
+        for cy in 0..h {
+            for cx in 0..w {
+                let mut neighbours = 0.0f32;
+                for ny in cy.saturating_sub(1)..(cy + 2).min(h) {
+                    for nx in cx.saturating_sub(1)..(cx + 2).min(w) {
+                        neighbours = neighbours.max(occupied[ny * w + nx]);
+                    }
+                }
+                let i = cy * w + cx;
+                self.likelihood[i] = occupied[i].max(0.5 * neighbours);
+            }
+        }
+    }
+}
+
+/// Apply a motion (dx, dy, dtheta) in the robot frame to a pose
+fn compose(pose: (f64, f64, f64), delta: (f64, f64, f64)) -> (f64, f64, f64) {
+    let (sin, cos) = pose.2.sin_cos();
+    (
+        pose.0 + cos * delta.0 - sin * delta.1,
+        pose.1 + sin * delta.0 + cos * delta.1,
+        normalize_angle(pose.2 + delta.2),
+    )
+}
+
+fn probability(log_odds: f32) -> f64 {
+    1.0 - 1.0 / (1.0 + (log_odds as f64).exp())
+}
+
+fn normalize_angle(angle: f64) -> f64 {
+    let mut a = angle;
+    while a > PI {
+        a -= 2.0 * PI;
+    }
+    while a < -PI {
+        a += 2.0 * PI;
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scan endpoints in the robot frame inside a square room of half-size 2.98m with a pillar
+    fn room_scan(pose: (f64, f64, f64)) -> Vec<(f64, f64)> {
+        (0..360)
+            .filter_map(|i| {
+                let beam = (i as f64).to_radians();
+                let (sin, cos) = (pose.2 + beam).sin_cos();
+                let mut range = f64::INFINITY;
+                // Walls at x = ±2.98 and y = ±2.98
+                for (d, wall) in [(cos, 2.98), (cos, -2.98), (sin, 2.98), (sin, -2.98)]
+                    .iter()
+                    .enumerate()
+                {
+                    let (dir, pos) = *wall;
+                    let origin = if d < 2 { pose.0 } else { pose.1 };
+                    if dir.abs() > 1e-9 {
+                        let t = (pos - origin) / dir;
+                        if t > 0.0 {
+                            range = range.min(t);
+                        }
+                    }
+                }
+                // Box pillar [1, 1.5] x [-2, -1] breaks the symmetry
+                let mut t = 0.0;
+                while t < range {
+                    let (x, y) = (pose.0 + cos * t, pose.1 + sin * t);
+                    if (1.0..=1.5).contains(&x) && (-2.0..=-1.0).contains(&y) {
+                        range = t;
+                        break;
+                    }
+                    t += 0.01;
+                }
+                range
+                    .is_finite()
+                    .then(|| (range * beam.cos(), range * beam.sin()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_builds_map() {
+        let mut slam = GridSlam::new(SlamConfig::default());
+        slam.process(None, &room_scan((0.0, 0.0, 0.0)));
+
+        assert!(slam.occupancy_at(2.97, 0.0) > 50);
+        assert!(slam.occupancy_at(0.0, -2.97) > 50);
+        assert!((0..50).contains(&slam.occupancy_at(1.5, 1.5)));
+        assert_eq!(slam.occupancy_at(5.0, 5.0), -1);
+
+        let data = slam.occupancy_data();
+        assert_eq!(data.len(), 400 * 400);
+        assert!(data.iter().any(|&v| (0..50).contains(&v)) && data.iter().any(|&v| v > 50));
+    }
+
+    #[test]
+    fn test_corrects_odometry_drift() {
+        let mut slam = GridSlam::new(SlamConfig::default());
+        slam.process(Some((0.0, 0.0, 0.0)), &room_scan((0.0, 0.0, 0.0)));
+
+        // Odometry overestimates the motion and misses some rotation
+        let truth = (0.3, 0.1, 0.08);
+        let odom = (0.42, 0.02, 0.0);
+        let pose = slam.process(Some(odom), &room_scan(truth));
+
+        assert!((pose.0 - truth.0).abs() < 0.06, "x = {}", pose.0);
+        assert!((pose.1 - truth.1).abs() < 0.06, "y = {}", pose.1);
+        assert!((pose.2 - truth.2).abs() < 0.03, "theta = {}", pose.2);
+        assert!(slam.match_score() > 0.5);
+        assert_eq!(slam.scans_integrated(), 2);
+    }
+
+    #[test]
+    fn test_keeps_prediction_without_match() {
+        let config = SlamConfig {
+            min_match_score: 0.99,
+            ..Default::default()
+        };
+        let mut slam = GridSlam::new(config);
+        slam.set_pose(1.0, 0.0, PI / 2.0);
+        slam.process(Some((5.0, 5.0, 0.0)), &room_scan((1.0, 0.0, PI / 2.0)));
+
+        // Robot-frame odometry motion is rotated into the map frame
+        let pose = slam.process(Some((5.5, 5.0, 0.0)), &[(20.0, 0.0)]);
+        assert!((pose.0 - 1.0).abs() < 1e-9);
+        assert!((pose.1 - 0.5).abs() < 1e-9);
+
+        // Small motions are not added to the map
+        slam.process(Some((5.52, 5.0, 0.0)), &room_scan((1.0, 0.52, PI / 2.0)));
+        assert_eq!(slam.scans_integrated(), 2);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut slam = GridSlam::new(SlamConfig::default());
+        slam.process(None, &room_scan((0.0, 0.0, 0.0)));
+        slam.reset();
+        assert_eq!(slam.scans_integrated(), 0);
+        assert_eq!(slam.occupancy_at(2.97, 0.0), -1);
+    }
+}
@@ -0,0 +1,410 @@
+//! Battery State of Charge Estimation
+//!
+//! Combines coulomb counting with open-circuit voltage (OCV) lookup.
+//! Integrating current is accurate over short periods but drifts and needs
+//! a known start; the OCV curve gives an absolute SOC but only when the
+//! battery has rested. The estimator uses both.
+//!
+//! # Features
+//!
+//! - Initial SOC from the OCV curve on the first sample
+//! - Coulomb counting with charge efficiency
+//! - Pulls towards the OCV estimate after the battery has rested
+//! - Optional internal resistance compensation for a slow correction under load
+//! - Built-in OCV curves for LiPo/Li-ion, LiFePO4, NiMH and lead-acid cells
+//!
+//! # Example
+//!
+//! ```rust
+//! use horus_library::algorithms::soc_estimator::{OcvCurve, SocEstimator};
+//!
+//! // 3S LiPo, 5 Ah
+//! let mut soc = SocEstimator::new(5.0, 3, OcvCurve::lipo());
+//!
+//! // First sample at rest: SOC from the resting voltage
+//! let initial = soc.update(11.4, 0.0, 0.0);
+//! assert!(initial > 20.0 && initial < 60.0);
+//!
+//! // Drawing 5 A for one minute removes 1/60 of the capacity
+//! for _ in 0..60 {
+//!     soc.update(11.2, -5.0, 1.0);
+//! }
+//! assert!((initial - soc.soc() - 100.0 / 60.0).abs() < 0.01);
+//! ```
+
+/// Open-circuit voltage per cell as a function of state of charge
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcvCurve {
+    /// (SOC in %, cell voltage in V), sorted by SOC
+    points: Vec<(f32, f32)>,
+}
+
+impl OcvCurve {
+    /// Create a curve from (SOC %, cell voltage) points
+    ///
+    /// Points are sorted by SOC; voltages must rise with SOC for the
+    /// inverse lookup to be meaningful.
+    pub fn new(points: &[(f32, f32)]) -> Self {
+        let mut points = points.to_vec();
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        Self { points }
+    }
+
+    /// Straight line between the empty and full cell voltages
+    pub fn linear(empty: f32, full: f32) -> Self {
+        Self::new(&[(0.0, empty), (100.0, full)])
+    }
+
+    /// Lithium polymer / lithium-ion cobalt cells (3.0-4.2 V)
+    pub fn lipo() -> Self {
+        Self::new(&[
+            (0.0, 3.27),
+            (5.0, 3.61),
+            (10.0, 3.69),
+            (20.0, 3.73),
+            (30.0, 3.77),
+            (40.0, 3.80),
+            (50.0, 3.84),
+            (60.0, 3.87),
+            (70.0, 3.93),
+            (80.0, 4.00),
+            (90.0, 4.08),
+            (100.0, 4.20),
+        ])
+    }
+
+    /// Lithium-ion NMC cells (18650, 21700)
+    pub fn li_ion() -> Self {
+        Self::new(&[
+            (0.0, 3.00),
+            (5.0, 3.30),
+            (10.0, 3.45),
+            (20.0, 3.55),
+            (30.0, 3.62),
+            (40.0, 3.68),
+            (50.0, 3.74),
+            (60.0, 3.81),
+            (70.0, 3.89),
+            (80.0, 3.97),
+            (90.0, 4.06),
+            (100.0, 4.20),
+        ])
+    }
+
+    /// Lithium iron phosphate cells (very flat between 20% and 90%)
+    pub fn lifepo4() -> Self {
+        Self::new(&[
+            (0.0, 2.50),
+            (5.0, 3.00),
+            (10.0, 3.20),
+            (20.0, 3.25),
+            (30.0, 3.28),
+            (50.0, 3.30),
+            (70.0, 3.32),
+            (90.0, 3.35),
+            (99.0, 3.40),
+            (100.0, 3.60),
+        ])
+    }
+
+    /// Nickel metal hydride cells
+    pub fn nimh() -> Self {
+        Self::new(&[
+            (0.0, 1.00),
+            (10.0, 1.15),
+            (20.0, 1.20),
+            (50.0, 1.24),
+            (80.0, 1.28),
+            (90.0, 1.32),
+            (100.0, 1.40),
+        ])
+    }
+
+    /// Lead-acid cells (2 V per cell)
+    pub fn lead_acid() -> Self {
+        Self::linear(1.95, 2.12)
+    }
+
+    /// Cell voltage at a SOC
+    pub fn voltage_at(&self, soc: f32) -> f32 {
+        interpolate(&self.points, soc, |p| p.0, |p| p.1)
+    }
+
+    /// SOC (0-100 %) at a resting cell voltage
+    pub fn soc_at(&self, cell_voltage: f32) -> f32 {
+        interpolate(&self.points, cell_voltage, |p| p.1, |p| p.0).clamp(0.0, 100.0)
+    }
+}
+
+/// Piecewise linear lookup, clamped to the end points
+fn interpolate(
+    points: &[(f32, f32)],
+    x: f32,
+    key: impl Fn(&(f32, f32)) -> f32,
+    value: impl Fn(&(f32, f32)) -> f32,
+) -> f32 {
+    let (first, last) = match (points.first(), points.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return 0.0,
+    };
+    if x <= key(first) {
+        return value(first);
+    }
+    for pair in points.windows(2) {
+        let (a, b) = (&pair[0], &pair[1]);
+        if x <= key(b) {
+            let span = key(b) - key(a);
+            if span <= 0.0 {
+                return value(b);
+            }
+            let t = (x - key(a)) / span;
+            return value(a) + t * (value(b) - value(a));
+        }
+    }
+    value(last)
+}
+
+/// Coulomb counting SOC estimator with OCV correction
+#[derive(Debug, Clone)]
+pub struct SocEstimator {
+    curve: OcvCurve,
+    capacity_ah: f32,
+    cells: u8,
+
+    // Tuning
+    internal_resistance: f32,
+    charge_efficiency: f32,
+    rest_current: f32,
+    rest_delay: f32,
+    rest_gain: f32,
+    load_gain: f32,
+
+    // State
+    soc: f32,
+    initialized: bool,
+    rest_time: f32,
+}
+
+impl SocEstimator {
+    /// Create an estimator for a pack of `cells` in series with `capacity_ah`
+    pub fn new(capacity_ah: f32, cells: u8, curve: OcvCurve) -> Self {
+        Self {
+            curve,
+            capacity_ah: capacity_ah.max(1e-3),
+            cells: cells.max(1),
+            internal_resistance: 0.0,
+            charge_efficiency: 0.99,
+            rest_current: 0.05,
+            rest_delay: 30.0,
+            rest_gain: 0.05,
+            load_gain: 0.002,
+            soc: 100.0,
+            initialized: false,
+            rest_time: 0.0,
+        }
+    }
+
+    /// Set the pack internal resistance in ohms
+    ///
+    /// With a known resistance the voltage under load is corrected to an
+    /// open-circuit voltage, and the SOC follows it slowly while driving.
+    pub fn set_internal_resistance(&mut self, ohms: f32) {
+        self.internal_resistance = ohms.max(0.0);
+    }
+
+    /// Set the fraction of charging current stored in the battery (0-1)
+    pub fn set_charge_efficiency(&mut self, efficiency: f32) {
+        self.charge_efficiency = efficiency.clamp(0.5, 1.0);
+    }
+
+    /// Set when the battery counts as resting: current below `current` (A)
+    /// for `delay` seconds
+    pub fn set_rest_detection(&mut self, current: f32, delay: f32) {
+        self.rest_current = current.max(0.0);
+        self.rest_delay = delay.max(0.0);
+    }
+
+    /// Set the OCV correction rates (fraction of the error per second)
+    /// while resting and, with a known internal resistance, under load
+    pub fn set_correction_gains(&mut self, rest: f32, load: f32) {
+        self.rest_gain = rest.clamp(0.0, 1.0);
+        self.load_gain = load.clamp(0.0, 1.0);
+    }
+
+    /// Set the pack capacity in amp-hours (keeps the SOC)
+    pub fn set_capacity(&mut self, capacity_ah: f32) {
+        self.capacity_ah = capacity_ah.max(1e-3);
+    }
+
+    /// Set the OCV curve and cell count
+    pub fn set_curve(&mut self, curve: OcvCurve, cells: u8) {
+        self.curve = curve;
+        self.cells = cells.max(1);
+    }
+
+    /// OCV curve in use
+    pub fn curve(&self) -> &OcvCurve {
+        &self.curve
+    }
+
+    /// Update with a measurement and return the SOC (0-100 %)
+    ///
+    /// `voltage` is the pack terminal voltage, `current` is positive while
+    /// charging and negative while discharging, `dt` is the time since the
+    /// previous update in seconds.
+    pub fn update(&mut self, voltage: f32, current: f32, dt: f32) -> f32 {
+        let ocv_soc = self.ocv_soc(voltage, current);
+        if !self.initialized {
+            self.soc = ocv_soc;
+            self.initialized = true;
+            self.rest_time = 0.0;
+            return self.soc;
+        }
+
+        let dt = dt.max(0.0);
+        let stored = if current > 0.0 {
+            current * self.charge_efficiency
+        } else {
+            current
+        };
+        self.soc += stored * dt / 3600.0 / self.capacity_ah * 100.0;
+
+        if current.abs() <= self.rest_current {
+            self.rest_time += dt;
+        } else {
+            self.rest_time = 0.0;
+        }
+
+        let gain = if self.rest_time >= self.rest_delay {
+            self.rest_gain
+        } else if self.internal_resistance > 0.0 {
+            self.load_gain
+        } else {
+            0.0
+        };
+        self.soc += (ocv_soc - self.soc) * (gain * dt).min(1.0);
+
+        self.soc = self.soc.clamp(0.0, 100.0);
+        self.soc
+    }
+
+    /// SOC from the OCV curve for a terminal voltage and current
+    pub fn ocv_soc(&self, voltage: f32, current: f32) -> f32 {
+        let open_circuit = voltage - current * self.internal_resistance;
+        self.curve.soc_at(open_circuit / self.cells as f32)
+    }
+
+    /// Current SOC estimate (0-100 %)
+    pub fn soc(&self) -> f32 {
+        self.soc
+    }
+
+    /// Remaining charge in amp-hours
+    pub fn charge_ah(&self) -> f32 {
+        self.soc / 100.0 * self.capacity_ah
+    }
+
+    /// Whether the first measurement has been taken
+    pub fn is_initialized(&self) -> bool {
+        self.initialized
+    }
+
+    /// Whether the battery is currently considered at rest
+    pub fn is_resting(&self) -> bool {
+        self.initialized && self.rest_time >= self.rest_delay
+    }
+
+    /// Set the SOC (e.g. 100 % after a full charge)
+    pub fn reset(&mut self, soc: f32) {
+        self.soc = soc.clamp(0.0, 100.0);
+        self.initialized = true;
+        self.rest_time = 0.0;
+    }
+
+    /// Forget the SOC and initialize from the next measurement's voltage
+    pub fn reinitialize(&mut self) {
+        self.initialized = false;
+        self.rest_time = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curve_lookup() {
+        let curve = OcvCurve::lipo();
+        assert!((curve.voltage_at(100.0) - 4.2).abs() < 1e-6);
+        assert!((curve.voltage_at(55.0) - 3.855).abs() < 1e-4);
+        assert!((curve.soc_at(3.855) - 55.0).abs() < 1e-3);
+
+        // Clamped outside the curve
+        assert_eq!(curve.soc_at(4.5), 100.0);
+        assert_eq!(curve.soc_at(2.0), 0.0);
+
+        let linear = OcvCurve::linear(10.0, 20.0);
+        assert!((linear.soc_at(12.5) - 25.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_initializes_from_voltage_and_counts_charge() {
+        let mut soc = SocEstimator::new(2.0, 3, OcvCurve::lipo());
+        assert!(!soc.is_initialized());
+        assert!((soc.update(3.0 * 3.84, 0.0, 0.0) - 50.0).abs() < 0.1);
+
+        // 2 A for 30 minutes drains half of a 2 Ah pack
+        for _ in 0..1800 {
+            soc.update(11.0, -2.0, 1.0);
+        }
+        assert!(soc.soc() < 0.1);
+
+        // Charging stores slightly less than the current delivered
+        soc.reset(0.0);
+        for _ in 0..600 {
+            soc.update(12.0, 2.0, 1.0);
+        }
+        let expected = 2.0 * 0.99 * 600.0 / 3600.0 / 2.0 * 100.0;
+        assert!((soc.soc() - expected).abs() < 0.01);
+        assert!((soc.charge_ah() - soc.soc() / 50.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rest_correction() {
+        let mut soc = SocEstimator::new(5.0, 1, OcvCurve::lipo());
+        soc.set_rest_detection(0.1, 10.0);
+        soc.reset(80.0);
+
+        // Resting at 50% OCV: no correction before the rest delay
+        for _ in 0..9 {
+            soc.update(3.84, 0.0, 1.0);
+        }
+        assert_eq!(soc.soc(), 80.0);
+        assert!(!soc.is_resting());
+
+        for _ in 0..200 {
+            soc.update(3.84, 0.0, 1.0);
+        }
+        assert!(soc.is_resting());
+        assert!((soc.soc() - 50.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_load_correction_with_internal_resistance() {
+        let mut soc = SocEstimator::new(5.0, 1, OcvCurve::lipo());
+        soc.set_correction_gains(0.05, 0.01);
+        soc.reset(70.0);
+
+        // Without resistance the sagging voltage under load is ignored
+        soc.update(3.74, -10.0, 1.0);
+        let counted = 70.0 - 10.0 / 3600.0 / 5.0 * 100.0;
+        assert!((soc.soc() - counted).abs() < 1e-4);
+
+        // 10 A through 10 mOhm: 3.74 V terminal is 3.84 V open circuit (50%)
+        soc.set_internal_resistance(0.01);
+        assert!((soc.ocv_soc(3.74, -10.0) - 50.0).abs() < 0.1);
+        let before = soc.soc();
+        soc.update(3.74, -10.0, 1.0);
+        assert!(soc.soc() < before - 0.1);
+    }
+}
@@ -0,0 +1,360 @@
+//! Joint Trajectory Interpolation
+//!
+//! Turns the sparse waypoints of a `JointTrajectory` into smooth joint
+//! positions, velocities and accelerations at any time, so an executor can
+//! command an arm at its control rate.
+//!
+//! # Interpolation Modes
+//!
+//! - **Linear**: straight lines between waypoints (velocity jumps at waypoints)
+//! - **Cubic**: continuous position and velocity (Hermite segments)
+//! - **Quintic**: continuous position, velocity and acceleration
+//!
+//! Waypoint velocities that are not given are chosen automatically: zero at
+//! the first and last waypoint and wherever the motion changes direction,
+//! otherwise the average of the neighbouring segment slopes. Missing
+//! accelerations are zero.
+//!
+//! # Example
+//!
+//! ```rust
+//! use horus_library::algorithms::trajectory::{Interpolation, JointSpline};
+//! use horus_library::JointTrajectoryPoint;
+//!
+//! let points = vec![
+//!     JointTrajectoryPoint::new(vec![0.0], 0.0),
+//!     JointTrajectoryPoint::new(vec![1.0], 2.0),
+//! ];
+//! let spline = JointSpline::new(&points, Interpolation::Cubic).unwrap();
+//!
+//! let mid = spline.sample(1.0);
+//! assert!((mid.positions[0] - 0.5).abs() < 1e-9);
+//! assert!(mid.velocities[0] > 0.0);
+//!
+//! // Starts and ends at rest
+//! assert_eq!(spline.sample(0.0).velocities[0], 0.0);
+//! assert_eq!(spline.sample(2.0).velocities[0], 0.0);
+//! ```
+
+use crate::JointTrajectoryPoint;
+use horus_core::error::{HorusError, HorusResult};
+
+/// Interpolation between waypoints
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Interpolation {
+    /// Piecewise linear positions
+    Linear,
+    /// Cubic Hermite segments (continuous velocity)
+    #[default]
+    Cubic,
+    /// Quintic segments (continuous acceleration)
+    Quintic,
+}
+
+/// Joint state sampled from a spline
+#[derive(Debug, Clone, PartialEq)]
+pub struct JointSample {
+    /// Positions (rad or m)
+    pub positions: Vec<f64>,
+    /// Velocities (rad/s or m/s)
+    pub velocities: Vec<f64>,
+    /// Accelerations (rad/s² or m/s²)
+    pub accelerations: Vec<f64>,
+}
+
+/// Piecewise polynomial through the waypoints of a joint trajectory
+#[derive(Debug, Clone)]
+pub struct JointSpline {
+    interpolation: Interpolation,
+    times: Vec<f64>,
+    positions: Vec<Vec<f64>>,
+    velocities: Vec<Vec<f64>>,
+    accelerations: Vec<Vec<f64>>,
+}
+
+impl JointSpline {
+    /// Build a spline through waypoints with increasing `time_from_start`
+    pub fn new(points: &[JointTrajectoryPoint], interpolation: Interpolation) -> HorusResult<Self> {
+        let joints = points
+            .first()
+            .map(|p| p.positions.len())
+            .ok_or_else(|| HorusError::InvalidInput("spline needs at least one point".into()))?;
+
+        for (i, point) in points.iter().enumerate() {
+            let sizes_ok = point.positions.len() == joints
+                && (point.velocities.is_empty() || point.velocities.len() == joints)
+                && (point.accelerations.is_empty() || point.accelerations.len() == joints);
+            if !sizes_ok {
+                return Err(HorusError::InvalidInput(format!(
+                    "point {} does not match {} joints",
+                    i, joints
+                )));
+            }
+            if i > 0 && point.time_from_start <= points[i - 1].time_from_start {
+                return Err(HorusError::InvalidInput(format!(
+                    "point {} is not after the previous point",
+                    i
+                )));
+            }
+        }
+
+        let times: Vec<f64> = points.iter().map(|p| p.time_from_start).collect();
+        let positions: Vec<Vec<f64>> = points.iter().map(|p| p.positions.clone()).collect();
+
+        let velocities = (0..points.len())
+            .map(|i| {
+                if !points[i].velocities.is_empty() {
+                    return points[i].velocities.clone();
+                }
+                if i == 0 || i + 1 == points.len() {
+                    return vec![0.0; joints];
+                }
+                (0..joints)
+                    .map(|j| {
+                        let before =
+                            (positions[i][j] - positions[i - 1][j]) / (times[i] - times[i - 1]);
+                        let after =
+                            (positions[i + 1][j] - positions[i][j]) / (times[i + 1] - times[i]);
+                        if before * after > 0.0 {
+                            (before + after) / 2.0
+                        } else {
+                            0.0
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let accelerations = points
+            .iter()
+            .map(|p| {
+                if p.accelerations.is_empty() {
+                    vec![0.0; joints]
+                } else {
+                    p.accelerations.clone()
+                }
+            })
+            .collect();
+
+        Ok(Self {
+            interpolation,
+            times,
+            positions,
+            velocities,
+            accelerations,
+        })
+    }
+
+    /// Interpolation mode
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Number of joints
+    pub fn joint_count(&self) -> usize {
+        self.positions[0].len()
+    }
+
+    /// Number of waypoints
+    pub fn point_count(&self) -> usize {
+        self.times.len()
+    }
+
+    /// Time of the last waypoint (s)
+    pub fn duration(&self) -> f64 {
+        *self.times.last().unwrap_or(&0.0)
+    }
+
+    /// Index of the next waypoint at time `t` (`point_count()` once finished)
+    pub fn next_point(&self, t: f64) -> usize {
+        self.times.partition_point(|&time| time <= t)
+    }
+
+    /// Sample positions, velocities and accelerations at time `t` (s)
+    ///
+    /// Before the first waypoint the first position is held, after the last
+    /// waypoint the last position is held, both at rest.
+    pub fn sample(&self, t: f64) -> JointSample {
+        let joints = self.joint_count();
+        let last = self.times.len() - 1;
+
+        if t <= self.times[0] || t >= self.times[last] {
+            let i = if t <= self.times[0] { 0 } else { last };
+            let moving = t == self.times[i];
+            return JointSample {
+                positions: self.positions[i].clone(),
+                velocities: if moving {
+                    self.velocities[i].clone()
+                } else {
+                    vec![0.0; joints]
+                },
+                accelerations: if moving && self.interpolation == Interpolation::Quintic {
+                    self.accelerations[i].clone()
+                } else {
+                    vec![0.0; joints]
+                },
+            };
+        }
+
+        let i = self.next_point(t) - 1;
+        let h = self.times[i + 1] - self.times[i];
+        let s = t - self.times[i];
+
+        let mut sample = JointSample {
+            positions: Vec::with_capacity(joints),
+            velocities: Vec::with_capacity(joints),
+            accelerations: Vec::with_capacity(joints),
+        };
+        for j in 0..joints {
+            let (p0, p1) = (self.positions[i][j], self.positions[i + 1][j]);
+            let (v0, v1) = (self.velocities[i][j], self.velocities[i + 1][j]);
+            let (a0, a1) = (self.accelerations[i][j], self.accelerations[i + 1][j]);
+
+            let c = match self.interpolation {
+                Interpolation::Linear => [p0, (p1 - p0) / h, 0.0, 0.0, 0.0, 0.0],
+                Interpolation::Cubic => [
+                    p0,
+                    v0,
+                    (3.0 * (p1 - p0) / h - 2.0 * v0 - v1) / h,
+                    (2.0 * (p0 - p1) / h + v0 + v1) / (h * h),
+                    0.0,
+                    0.0,
+                ],
+                Interpolation::Quintic => {
+                    let (h2, h3) = (h * h, h * h * h);
+                    [
+                        p0,
+                        v0,
+                        a0 / 2.0,
+                        (20.0 * (p1 - p0) - (8.0 * v1 + 12.0 * v0) * h - (3.0 * a0 - a1) * h2)
+                            / (2.0 * h3),
+                        (30.0 * (p0 - p1)
+                            + (14.0 * v1 + 16.0 * v0) * h
+                            + (3.0 * a0 - 2.0 * a1) * h2)
+                            / (2.0 * h3 * h),
+                        (12.0 * (p1 - p0) - 6.0 * (v1 + v0) * h - (a0 - a1) * h2) / (2.0 * h3 * h2),
+                    ]
+                }
+            };
+
+            sample
+                .positions
+                .push(c[0] + s * (c[1] + s * (c[2] + s * (c[3] + s * (c[4] + s * c[5])))));
+            sample.velocities.push(
+                c[1] + s * (2.0 * c[2] + s * (3.0 * c[3] + s * (4.0 * c[4] + s * 5.0 * c[5]))),
+            );
+            sample
+                .accelerations
+                .push(2.0 * c[2] + s * (6.0 * c[3] + s * (12.0 * c[4] + s * 20.0 * c[5])));
+        }
+        sample
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points() -> Vec<JointTrajectoryPoint> {
+        vec![
+            JointTrajectoryPoint::new(vec![0.0, 1.0], 0.0),
+            JointTrajectoryPoint::new(vec![1.0, 1.0], 1.0),
+            JointTrajectoryPoint::new(vec![3.0, 0.0], 2.0),
+        ]
+    }
+
+    #[test]
+    fn test_passes_through_waypoints() {
+        for mode in [
+            Interpolation::Linear,
+            Interpolation::Cubic,
+            Interpolation::Quintic,
+        ] {
+            let spline = JointSpline::new(&points(), mode).unwrap();
+            for point in points() {
+                let sample = spline.sample(point.time_from_start);
+                for (a, b) in sample.positions.iter().zip(&point.positions) {
+                    assert!(
+                        (a - b).abs() < 1e-9,
+                        "{:?} at {}",
+                        mode,
+                        point.time_from_start
+                    );
+                }
+            }
+            // Just inside the segments, too
+            let before = spline.sample(1.0 - 1e-7).positions[0];
+            let after = spline.sample(1.0 + 1e-7).positions[0];
+            assert!((before - 1.0).abs() < 1e-5 && (after - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_automatic_velocities() {
+        let spline = JointSpline::new(&points(), Interpolation::Cubic).unwrap();
+
+        // Joint 0 keeps moving forward through the middle point: average slope
+        let middle = spline.sample(1.0);
+        assert!((middle.velocities[0] - 1.5).abs() < 1e-9);
+        // Joint 1 stops there before moving the other way
+        assert_eq!(middle.velocities[1], 0.0);
+
+        // Velocity is continuous across the waypoint
+        let before = spline.sample(1.0 - 1e-6).velocities[0];
+        let after = spline.sample(1.0 + 1e-6).velocities[0];
+        assert!((before - after).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_quintic_boundary_conditions() {
+        let points = vec![
+            JointTrajectoryPoint::new(vec![0.0], 0.0),
+            JointTrajectoryPoint::new(vec![2.0], 1.0),
+        ];
+        let spline = JointSpline::new(&points, Interpolation::Quintic).unwrap();
+
+        let start = spline.sample(1e-9);
+        assert!(start.velocities[0].abs() < 1e-6);
+        assert!(start.accelerations[0].abs() < 1e-6);
+
+        let end = spline.sample(1.0 - 1e-9);
+        assert!(end.velocities[0].abs() < 1e-6);
+        assert!(end.accelerations[0].abs() < 1e-5);
+
+        // Symmetric profile peaks at the midpoint (15/8 * distance / time)
+        let mid = spline.sample(0.5);
+        assert!((mid.positions[0] - 1.0).abs() < 1e-9);
+        assert!((mid.velocities[0] - 3.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_holds_outside_time_range() {
+        let mut points = points();
+        for p in points.iter_mut() {
+            p.time_from_start += 1.0;
+        }
+        let spline = JointSpline::new(&points, Interpolation::Cubic).unwrap();
+
+        let early = spline.sample(0.5);
+        assert_eq!(early.positions, vec![0.0, 1.0]);
+        assert_eq!(early.velocities, vec![0.0, 0.0]);
+
+        let late = spline.sample(10.0);
+        assert_eq!(late.positions, vec![3.0, 0.0]);
+        assert_eq!(spline.next_point(10.0), 3);
+        assert_eq!(spline.next_point(1.5), 1);
+    }
+
+    #[test]
+    fn test_rejects_bad_points() {
+        assert!(JointSpline::new(&[], Interpolation::Cubic).is_err());
+
+        let mut bad = points();
+        bad[2].time_from_start = 1.0;
+        assert!(JointSpline::new(&bad, Interpolation::Cubic).is_err());
+
+        let mut bad = points();
+        bad[1].positions.pop();
+        assert!(JointSpline::new(&bad, Interpolation::Cubic).is_err());
+    }
+}
@@ -28,7 +28,7 @@ impl Default for SimulationBatteryConfig {
     fn default() -> Self {
         Self {
             battery: BatteryConfig::default(),
-            sample_rate: 1.0,       // 1 Hz
+            sample_rate: 10.0,      // 10 Hz, as the I2C power monitor driver
             discharge_current: 5.0, // 5A discharge
             initial_soc: 100.0,     // Start fully charged
         }
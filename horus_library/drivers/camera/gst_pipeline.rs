@@ -0,0 +1,419 @@
+//! GStreamer pipeline descriptions for the GStreamer camera driver
+//!
+//! Builds `gst-launch` style pipeline strings from a [`GStreamerCameraConfig`].
+//! Always compiled so configurations can be validated without GStreamer
+//! installed; the driver itself requires the `gstreamer-backend` feature.
+
+use horus_core::error::{HorusError, HorusResult};
+
+use crate::ImageEncoding;
+
+/// Name of the appsink element the driver pulls frames from
+pub const APPSINK_NAME: &str = "horus_sink";
+
+/// Video source of a GStreamer camera
+#[derive(Debug, Clone, PartialEq)]
+pub enum GstSource {
+    /// RTSP stream (IP cameras, NVRs)
+    Rtsp {
+        /// Stream URL, e.g. "rtsp://192.168.1.10:554/stream1"
+        url: String,
+        /// Jitter buffer latency in milliseconds
+        latency_ms: u32,
+        /// Force RTP over TCP (more robust over Wi-Fi, slightly higher latency)
+        tcp: bool,
+    },
+    /// MIPI CSI camera through the Jetson Argus stack (nvarguscamerasrc)
+    Csi {
+        /// Sensor index
+        sensor_id: u32,
+    },
+    /// USB (UVC) camera through V4L2
+    Usb {
+        /// Device path, e.g. "/dev/video0"
+        device: String,
+        /// Capture MJPEG instead of raw frames (needed for high resolutions on USB 2.0)
+        mjpeg: bool,
+    },
+    /// Custom pipeline producing raw or encoded video; the driver appends
+    /// conversion and the appsink
+    Custom(String),
+}
+
+/// Video codec of an RTSP stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VideoCodec {
+    #[default]
+    H264,
+    H265,
+}
+
+/// Decoder selection
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GstDecoder {
+    /// Let decodebin pick the highest ranked decoder (hardware when available)
+    #[default]
+    Auto,
+    /// libav software decoder
+    Software,
+    /// NVIDIA Jetson hardware decoder (nvv4l2decoder) and converter (nvvidconv)
+    Nvidia,
+    /// VA-API hardware decoder (Intel/AMD)
+    VaApi,
+}
+
+/// GStreamer camera configuration
+#[derive(Debug, Clone)]
+pub struct GStreamerCameraConfig {
+    /// Video source
+    pub source: GstSource,
+    /// Image width in pixels (0 = keep source width)
+    pub width: u32,
+    /// Image height in pixels (0 = keep source height)
+    pub height: u32,
+    /// Frame rate in Hz (sources that support it)
+    pub fps: f32,
+    /// Codec of RTSP streams
+    pub codec: VideoCodec,
+    /// Decoder for encoded streams
+    pub decoder: GstDecoder,
+    /// Output pixel format (Mono8, Mono16, Rgb8, Bgr8, Rgba8, Bgra8 or Yuv422)
+    pub encoding: ImageEncoding,
+    /// Frames buffered in the appsink; older frames are dropped when full
+    pub max_buffers: u32,
+    /// Timeout for one frame in milliseconds
+    pub timeout_ms: u64,
+}
+
+impl Default for GStreamerCameraConfig {
+    fn default() -> Self {
+        Self {
+            source: GstSource::Usb {
+                device: "/dev/video0".to_string(),
+                mjpeg: false,
+            },
+            width: 640,
+            height: 480,
+            fps: 30.0,
+            codec: VideoCodec::H264,
+            decoder: GstDecoder::Auto,
+            encoding: ImageEncoding::Rgb8,
+            max_buffers: 2,
+            timeout_ms: 1000,
+        }
+    }
+}
+
+impl GStreamerCameraConfig {
+    /// RTSP stream with TCP transport and 200 ms latency
+    pub fn rtsp(url: &str) -> Self {
+        Self {
+            source: GstSource::Rtsp {
+                url: url.to_string(),
+                latency_ms: 200,
+                tcp: true,
+            },
+            width: 0,
+            height: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Jetson CSI camera with hardware conversion
+    pub fn csi(sensor_id: u32, width: u32, height: u32, fps: f32) -> Self {
+        Self {
+            source: GstSource::Csi { sensor_id },
+            width,
+            height,
+            fps,
+            decoder: GstDecoder::Nvidia,
+            encoding: ImageEncoding::Rgba8,
+            ..Default::default()
+        }
+    }
+
+    /// USB camera capturing raw frames
+    pub fn usb(device: &str, width: u32, height: u32, fps: f32) -> Self {
+        Self {
+            source: GstSource::Usb {
+                device: device.to_string(),
+                mjpeg: false,
+            },
+            width,
+            height,
+            fps,
+            ..Default::default()
+        }
+    }
+
+    /// Select the decoder
+    pub fn with_decoder(mut self, decoder: GstDecoder) -> Self {
+        self.decoder = decoder;
+        self
+    }
+
+    /// Select the RTSP codec
+    pub fn with_codec(mut self, codec: VideoCodec) -> Self {
+        self.codec = codec;
+        self
+    }
+
+    /// Select the output pixel format
+    pub fn with_encoding(mut self, encoding: ImageEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Build the pipeline description
+    pub fn pipeline(&self) -> HorusResult<String> {
+        let format = gst_format(self.encoding)?;
+        let nvidia = self.decoder == GstDecoder::Nvidia;
+        let mut elements: Vec<String> = Vec::new();
+
+        // Source and decoding; `in_nvmm` tracks frames still in NVMM (GPU) memory
+        let in_nvmm = match &self.source {
+            GstSource::Rtsp {
+                url,
+                latency_ms,
+                tcp,
+            } => {
+                let mut src = format!("rtspsrc location=\"{}\" latency={}", url, latency_ms);
+                if *tcp {
+                    src.push_str(" protocols=tcp");
+                }
+                elements.push(src);
+                let (depay, parse) = match self.codec {
+                    VideoCodec::H264 => ("rtph264depay", "h264parse"),
+                    VideoCodec::H265 => ("rtph265depay", "h265parse"),
+                };
+                elements.push(depay.to_string());
+                elements.push(parse.to_string());
+                elements.push(self.decoder_element().to_string());
+                nvidia
+            }
+            GstSource::Csi { sensor_id } => {
+                if !nvidia {
+                    return Err(HorusError::config(
+                        "CSI cameras require the Nvidia decoder (nvarguscamerasrc)",
+                    ));
+                }
+                elements.push(format!("nvarguscamerasrc sensor-id={}", sensor_id));
+                elements.push(format!("video/x-raw(memory:NVMM){}", self.size_caps(true)));
+                true
+            }
+            GstSource::Usb { device, mjpeg } => {
+                elements.push(format!("v4l2src device={}", device));
+                if *mjpeg {
+                    elements.push(format!("image/jpeg{}", self.size_caps(true)));
+                    elements.push(
+                        match self.decoder {
+                            GstDecoder::Nvidia => "nvv4l2decoder mjpeg=1",
+                            GstDecoder::VaApi => "vaapijpegdec",
+                            GstDecoder::Auto => "decodebin",
+                            GstDecoder::Software => "jpegdec",
+                        }
+                        .to_string(),
+                    );
+                    nvidia
+                } else {
+                    elements.push(format!("video/x-raw{}", self.size_caps(true)));
+                    false
+                }
+            }
+            GstSource::Custom(description) => {
+                elements.push(description.trim().trim_end_matches('!').trim().to_string());
+                false
+            }
+        };
+
+        // Conversion to the output format, in hardware when possible
+        if in_nvmm {
+            // nvvidconv converts and scales on the VIC and copies to system memory;
+            // it only outputs a few packed formats
+            let nv_format = match self.encoding {
+                ImageEncoding::Mono8 => "GRAY8",
+                ImageEncoding::Bgra8 => "BGRx",
+                _ => "RGBA",
+            };
+            elements.push("nvvidconv".to_string());
+            elements.push(format!(
+                "video/x-raw,format={}{}",
+                nv_format,
+                self.size_caps(false)
+            ));
+            if nv_format != format && !(nv_format == "BGRx" && format == "BGRA") {
+                elements.push("videoconvert".to_string());
+                elements.push(format!("video/x-raw,format={}", format));
+            }
+        } else {
+            elements.push("videoconvert".to_string());
+            if self.width > 0 && self.height > 0 {
+                elements.push("videoscale".to_string());
+            }
+            elements.push(format!(
+                "video/x-raw,format={}{}",
+                format,
+                self.size_caps(false)
+            ));
+        }
+
+        elements.push(format!(
+            "appsink name={} max-buffers={} drop=true sync=false",
+            APPSINK_NAME,
+            self.max_buffers.max(1)
+        ));
+        Ok(elements.join(" ! "))
+    }
+
+    fn decoder_element(&self) -> &'static str {
+        match (self.decoder, self.codec) {
+            (GstDecoder::Auto, _) => "decodebin",
+            (GstDecoder::Software, VideoCodec::H264) => "avdec_h264",
+            (GstDecoder::Software, VideoCodec::H265) => "avdec_h265",
+            (GstDecoder::Nvidia, _) => "nvv4l2decoder",
+            (GstDecoder::VaApi, VideoCodec::H264) => "vaapih264dec",
+            (GstDecoder::VaApi, VideoCodec::H265) => "vaapih265dec",
+        }
+    }
+
+    /// Caps fields for width, height and (optionally) frame rate
+    fn size_caps(&self, with_rate: bool) -> String {
+        let mut caps = String::new();
+        if self.width > 0 && self.height > 0 {
+            caps.push_str(&format!(",width={},height={}", self.width, self.height));
+        }
+        if with_rate && self.fps > 0.0 {
+            // Fractional rates such as 29.97 are expressed in 1/1000 units
+            let millis = (self.fps as f64 * 1000.0).round() as u32;
+            let remainder = millis % 1000;
+            let rate = if remainder == 0 {
+                format!("{}/1", millis / 1000)
+            } else {
+                format!("{}/1000", millis)
+            };
+            caps.push_str(&format!(",framerate={}", rate));
+        }
+        caps
+    }
+}
+
+/// GStreamer video format name of an image encoding
+pub fn gst_format(encoding: ImageEncoding) -> HorusResult<&'static str> {
+    match encoding {
+        ImageEncoding::Mono8 => Ok("GRAY8"),
+        ImageEncoding::Mono16 => Ok("GRAY16_LE"),
+        ImageEncoding::Rgb8 => Ok("RGB"),
+        ImageEncoding::Bgr8 => Ok("BGR"),
+        ImageEncoding::Rgba8 => Ok("RGBA"),
+        ImageEncoding::Bgra8 => Ok("BGRA"),
+        ImageEncoding::Yuv422 => Ok("YUY2"),
+        other => Err(HorusError::config(format!(
+            "Image encoding {:?} is not supported by the GStreamer camera driver",
+            other
+        ))),
+    }
+}
+
+/// Image encoding of a GStreamer video format name
+#[cfg(any(feature = "gstreamer-backend", test))]
+pub fn encoding_from_gst_format(format: &str) -> Option<ImageEncoding> {
+    match format {
+        "GRAY8" => Some(ImageEncoding::Mono8),
+        "GRAY16_LE" => Some(ImageEncoding::Mono16),
+        "RGB" => Some(ImageEncoding::Rgb8),
+        "BGR" => Some(ImageEncoding::Bgr8),
+        "RGBA" | "RGBx" => Some(ImageEncoding::Rgba8),
+        "BGRA" | "BGRx" => Some(ImageEncoding::Bgra8),
+        "YUY2" => Some(ImageEncoding::Yuv422),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rtsp_hardware_decode_pipeline() {
+        let config = GStreamerCameraConfig::rtsp("rtsp://10.0.0.5/stream")
+            .with_codec(VideoCodec::H265)
+            .with_decoder(GstDecoder::Nvidia)
+            .with_encoding(ImageEncoding::Rgba8);
+        assert_eq!(
+            config.pipeline().unwrap(),
+            "rtspsrc location=\"rtsp://10.0.0.5/stream\" latency=200 protocols=tcp \
+             ! rtph265depay ! h265parse ! nvv4l2decoder ! nvvidconv ! video/x-raw,format=RGBA \
+             ! appsink name=horus_sink max-buffers=2 drop=true sync=false"
+        );
+
+        // RGB needs a CPU conversion after nvvidconv
+        let rgb = config
+            .with_encoding(ImageEncoding::Rgb8)
+            .pipeline()
+            .unwrap();
+        assert!(rgb.contains(
+            "nvvidconv ! video/x-raw,format=RGBA ! videoconvert ! video/x-raw,format=RGB ! appsink"
+        ));
+    }
+
+    #[test]
+    fn test_csi_and_usb_pipelines() {
+        let csi = GStreamerCameraConfig::csi(1, 1920, 1080, 30.0)
+            .pipeline()
+            .unwrap();
+        assert_eq!(
+            csi,
+            "nvarguscamerasrc sensor-id=1 \
+             ! video/x-raw(memory:NVMM),width=1920,height=1080,framerate=30/1 \
+             ! nvvidconv ! video/x-raw,format=RGBA,width=1920,height=1080 \
+             ! appsink name=horus_sink max-buffers=2 drop=true sync=false"
+        );
+        let no_nvidia =
+            GStreamerCameraConfig::csi(0, 1280, 720, 60.0).with_decoder(GstDecoder::Auto);
+        assert!(no_nvidia.pipeline().is_err());
+
+        let mut usb = GStreamerCameraConfig::usb("/dev/video2", 1280, 720, 29.97)
+            .with_decoder(GstDecoder::Software)
+            .with_encoding(ImageEncoding::Bgr8);
+        usb.source = GstSource::Usb {
+            device: "/dev/video2".to_string(),
+            mjpeg: true,
+        };
+        assert_eq!(
+            usb.pipeline().unwrap(),
+            "v4l2src device=/dev/video2 ! image/jpeg,width=1280,height=720,framerate=29970/1000 \
+             ! jpegdec ! videoconvert ! videoscale ! video/x-raw,format=BGR,width=1280,height=720 \
+             ! appsink name=horus_sink max-buffers=2 drop=true sync=false"
+        );
+    }
+
+    #[test]
+    fn test_formats() {
+        for encoding in [
+            ImageEncoding::Mono8,
+            ImageEncoding::Mono16,
+            ImageEncoding::Rgb8,
+            ImageEncoding::Bgr8,
+            ImageEncoding::Rgba8,
+            ImageEncoding::Bgra8,
+            ImageEncoding::Yuv422,
+        ] {
+            let format = gst_format(encoding).unwrap();
+            assert_eq!(encoding_from_gst_format(format), Some(encoding));
+        }
+        assert!(gst_format(ImageEncoding::Depth16).is_err());
+
+        let custom = GStreamerCameraConfig {
+            source: GstSource::Custom("videotestsrc pattern=ball !".to_string()),
+            width: 0,
+            height: 0,
+            encoding: ImageEncoding::Mono8,
+            ..Default::default()
+        };
+        assert_eq!(
+            custom.pipeline().unwrap(),
+            "videotestsrc pattern=ball ! videoconvert ! video/x-raw,format=GRAY8 \
+             ! appsink name=horus_sink max-buffers=2 drop=true sync=false"
+        );
+    }
+}
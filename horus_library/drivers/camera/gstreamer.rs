@@ -0,0 +1,274 @@
+//! GStreamer Camera driver
+//!
+//! Camera driver pulling frames from a GStreamer pipeline through an appsink.
+//! Supports RTSP, Jetson CSI and USB cameras with hardware H.264/H.265
+//! decoding (nvv4l2decoder on Jetson, VA-API on Intel/AMD).
+//! Requires the `gstreamer-backend` feature.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use gstreamer as gst;
+use gstreamer::prelude::*;
+use gstreamer_app as gst_app;
+use gstreamer_video as gst_video;
+
+use horus_core::driver::DriverStatus;
+use horus_core::error::{HorusError, HorusResult};
+
+use super::gst_pipeline::{encoding_from_gst_format, GStreamerCameraConfig, APPSINK_NAME};
+use crate::{Image, ImageEncoding};
+
+/// Decoded frame still owned by GStreamer
+///
+/// Holds the mapped buffer of the decoder/converter output, so the pixels
+/// can be used without copying. The buffer returns to its pool when the
+/// frame is dropped; keep at most `max_buffers` frames alive.
+pub struct GstFrame {
+    buffer: gst::MappedBuffer<gst::buffer::Readable>,
+    width: u32,
+    height: u32,
+    stride: u32,
+    encoding: ImageEncoding,
+    timestamp: u64,
+}
+
+impl GstFrame {
+    /// Pixel data (row-major, `stride` bytes per row)
+    pub fn data(&self) -> &[u8] {
+        let len = (self.stride * self.height) as usize;
+        &self.buffer.as_slice()[..len.min(self.buffer.size())]
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Bytes per row (may include padding)
+    pub fn stride(&self) -> u32 {
+        self.stride
+    }
+
+    pub fn encoding(&self) -> ImageEncoding {
+        self.encoding
+    }
+
+    /// Capture time in nanoseconds since epoch
+    pub fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Copy the frame into an Image message (one copy, row padding kept in `step`)
+    pub fn to_image(&self, frame_id: [u8; 32]) -> Image {
+        Image {
+            width: self.width,
+            height: self.height,
+            encoding: self.encoding,
+            step: self.stride,
+            data: self.data().to_vec(),
+            frame_id,
+            timestamp: self.timestamp,
+        }
+    }
+}
+
+/// GStreamer camera driver
+///
+/// Builds the pipeline from a [`GStreamerCameraConfig`] and pulls decoded
+/// frames from its appsink.
+pub struct GStreamerCameraDriver {
+    config: GStreamerCameraConfig,
+    status: DriverStatus,
+    pipeline: Option<gst::Pipeline>,
+    appsink: Option<gst_app::AppSink>,
+    frame_count: u64,
+}
+
+impl GStreamerCameraDriver {
+    /// Create a new GStreamer camera driver (USB camera /dev/video0)
+    pub fn new() -> HorusResult<Self> {
+        Self::with_config(GStreamerCameraConfig::default())
+    }
+
+    /// Create with custom configuration
+    pub fn with_config(config: GStreamerCameraConfig) -> HorusResult<Self> {
+        // Reject unsupported configurations before touching GStreamer
+        config.pipeline()?;
+        Ok(Self {
+            config,
+            status: DriverStatus::Uninitialized,
+            pipeline: None,
+            appsink: None,
+            frame_count: 0,
+        })
+    }
+
+    /// Get the configuration
+    pub fn config(&self) -> &GStreamerCameraConfig {
+        &self.config
+    }
+
+    /// Initialize the driver
+    pub fn init(&mut self) -> HorusResult<()> {
+        gst::init()
+            .map_err(|e| HorusError::driver(format!("Failed to initialize GStreamer: {}", e)))?;
+
+        let description = self.config.pipeline()?;
+        let pipeline = gst::parse::launch(&description)
+            .map_err(|e| {
+                HorusError::driver(format!(
+                    "Failed to create pipeline '{}': {}",
+                    description, e
+                ))
+            })?
+            .downcast::<gst::Pipeline>()
+            .map_err(|_| HorusError::driver("Pipeline description is not a pipeline"))?;
+
+        let appsink = pipeline
+            .by_name(APPSINK_NAME)
+            .and_then(|e| e.downcast::<gst_app::AppSink>().ok())
+            .ok_or_else(|| HorusError::driver("Pipeline has no appsink"))?;
+
+        pipeline.set_state(gst::State::Playing).map_err(|e| {
+            let _ = pipeline.set_state(gst::State::Null);
+            HorusError::driver(format!("Failed to start pipeline: {}", e))
+        })?;
+
+        self.pipeline = Some(pipeline);
+        self.appsink = Some(appsink);
+        self.frame_count = 0;
+        self.status = DriverStatus::Ready;
+        Ok(())
+    }
+
+    /// Shutdown the driver
+    pub fn shutdown(&mut self) -> HorusResult<()> {
+        self.appsink = None;
+        if let Some(pipeline) = self.pipeline.take() {
+            pipeline
+                .set_state(gst::State::Null)
+                .map_err(|e| HorusError::driver(format!("Failed to stop pipeline: {}", e)))?;
+        }
+        self.status = DriverStatus::Shutdown;
+        Ok(())
+    }
+
+    /// Check if driver is available
+    pub fn is_available(&self) -> bool {
+        self.appsink.is_some()
+    }
+
+    /// Get driver status
+    pub fn status(&self) -> DriverStatus {
+        self.status.clone()
+    }
+
+    /// Read the next frame without copying the pixels
+    pub fn read_frame(&mut self) -> HorusResult<GstFrame> {
+        let sample = self.pull_sample(self.config.timeout_ms)?;
+        let frame = frame_from_sample(&sample)?;
+        self.frame_count += 1;
+        self.status = DriverStatus::Running;
+        Ok(frame)
+    }
+
+    /// Read image data
+    pub fn read(&mut self) -> HorusResult<Image> {
+        let mut frame_id = [0u8; 32];
+        let id_bytes = b"gstreamer_camera";
+        frame_id[..id_bytes.len()].copy_from_slice(id_bytes);
+
+        Ok(self.read_frame()?.to_image(frame_id))
+    }
+
+    /// Check if data is available (reads block up to `timeout_ms` for the next frame)
+    pub fn has_data(&self) -> bool {
+        self.is_available() && !matches!(self.status, DriverStatus::Error(_))
+    }
+
+    /// Get sample rate
+    pub fn sample_rate(&self) -> Option<f32> {
+        Some(self.config.fps)
+    }
+
+    /// Frames read since init
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    fn pull_sample(&mut self, timeout_ms: u64) -> HorusResult<gst::Sample> {
+        let appsink = self
+            .appsink
+            .as_ref()
+            .ok_or_else(|| HorusError::driver("Camera not initialized"))?;
+
+        if let Some(sample) = appsink.try_pull_sample(gst::ClockTime::from_mseconds(timeout_ms)) {
+            return Ok(sample);
+        }
+
+        // No frame: report pipeline errors (stream lost, decoder failure) or end of stream
+        if let Some(bus) = self.pipeline.as_ref().and_then(|p| p.bus()) {
+            if let Some(msg) = bus.pop_filtered(&[gst::MessageType::Error, gst::MessageType::Eos]) {
+                let error = match msg.view() {
+                    gst::MessageView::Error(err) => format!(
+                        "GStreamer error from {}: {}",
+                        msg.src()
+                            .map(|s| s.path_string().to_string())
+                            .unwrap_or_default(),
+                        err.error()
+                    ),
+                    _ => "End of stream".to_string(),
+                };
+                self.status = DriverStatus::Error(error.clone());
+                return Err(HorusError::driver(error));
+            }
+        }
+        if appsink.is_eos() {
+            self.status = DriverStatus::Error("End of stream".to_string());
+            return Err(HorusError::driver("End of stream"));
+        }
+        Err(HorusError::driver(format!(
+            "No frame within {} ms",
+            timeout_ms
+        )))
+    }
+}
+
+/// Map the buffer of a sample and read its video layout from the caps
+fn frame_from_sample(sample: &gst::Sample) -> HorusResult<GstFrame> {
+    let caps = sample
+        .caps()
+        .ok_or_else(|| HorusError::driver("Sample without caps"))?;
+    let info = gst_video::VideoInfo::from_caps(caps)
+        .map_err(|e| HorusError::driver(format!("Invalid video caps: {}", e)))?;
+    let encoding = encoding_from_gst_format(&info.format().to_string())
+        .ok_or_else(|| HorusError::driver(format!("Unsupported video format {}", info.format())))?;
+    let buffer = sample
+        .buffer_owned()
+        .ok_or_else(|| HorusError::driver("Sample without buffer"))?
+        .into_mapped_buffer_readable()
+        .map_err(|_| HorusError::driver("Failed to map buffer"))?;
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64;
+
+    Ok(GstFrame {
+        buffer,
+        width: info.width(),
+        height: info.height(),
+        stride: info.stride()[0] as u32,
+        encoding,
+        timestamp,
+    })
+}
+
+impl Default for GStreamerCameraDriver {
+    fn default() -> Self {
+        Self::new().expect("Failed to create GStreamer camera driver")
+    }
+}
@@ -7,7 +7,10 @@
 //! - `SimulationCameraDriver` - Always available, generates synthetic images
 //! - `OpenCvCameraDriver` - OpenCV-based camera (requires `opencv-backend` feature)
 //! - `V4l2CameraDriver` - Video4Linux2 camera (requires `v4l2-backend` feature)
+//! - `GStreamerCameraDriver` - GStreamer pipeline (RTSP/CSI/USB) with hardware
+//!   decoding (requires `gstreamer-backend` feature)
 
+mod gst_pipeline;
 mod simulation;
 
 #[cfg(feature = "opencv-backend")]
@@ -16,7 +19,11 @@ mod opencv;
 #[cfg(feature = "v4l2-backend")]
 mod v4l2;
 
+#[cfg(feature = "gstreamer-backend")]
+mod gstreamer;
+
 // Re-exports
+pub use gst_pipeline::{GStreamerCameraConfig, GstDecoder, GstSource, VideoCodec};
 pub use simulation::SimulationCameraDriver;
 
 #[cfg(feature = "opencv-backend")]
@@ -25,6 +32,9 @@ pub use opencv::OpenCvCameraDriver;
 #[cfg(feature = "v4l2-backend")]
 pub use v4l2::V4l2CameraDriver;
 
+#[cfg(feature = "gstreamer-backend")]
+pub use gstreamer::{GStreamerCameraDriver, GstFrame};
+
 use horus_core::driver::DriverStatus;
 use horus_core::error::HorusResult;
 
@@ -42,6 +52,9 @@ pub enum CameraDriverBackend {
     /// Video4Linux2 backend
     #[cfg(feature = "v4l2-backend")]
     V4l2,
+    /// GStreamer backend
+    #[cfg(feature = "gstreamer-backend")]
+    GStreamer,
 }
 
 /// Type-erased camera driver for runtime backend selection
@@ -51,6 +64,8 @@ pub enum CameraDriver {
     OpenCv(OpenCvCameraDriver),
     #[cfg(feature = "v4l2-backend")]
     V4l2(V4l2CameraDriver),
+    #[cfg(feature = "gstreamer-backend")]
+    GStreamer(GStreamerCameraDriver),
 }
 
 impl CameraDriver {
@@ -62,9 +77,17 @@ impl CameraDriver {
             CameraDriverBackend::OpenCv => Ok(Self::OpenCv(OpenCvCameraDriver::new()?)),
             #[cfg(feature = "v4l2-backend")]
             CameraDriverBackend::V4l2 => Ok(Self::V4l2(V4l2CameraDriver::new()?)),
+            #[cfg(feature = "gstreamer-backend")]
+            CameraDriverBackend::GStreamer => Ok(Self::GStreamer(GStreamerCameraDriver::new()?)),
         }
     }
 
+    /// Create a GStreamer driver with the given pipeline configuration
+    #[cfg(feature = "gstreamer-backend")]
+    pub fn gstreamer(config: GStreamerCameraConfig) -> HorusResult<Self> {
+        Ok(Self::GStreamer(GStreamerCameraDriver::with_config(config)?))
+    }
+
     /// Create a simulation driver (always available)
     pub fn simulation() -> Self {
         Self::Simulation(SimulationCameraDriver::new())
@@ -81,6 +104,8 @@ impl CameraDriver {
             Self::OpenCv(d) => d.init(),
             #[cfg(feature = "v4l2-backend")]
             Self::V4l2(d) => d.init(),
+            #[cfg(feature = "gstreamer-backend")]
+            Self::GStreamer(d) => d.init(),
         }
     }
 
@@ -91,6 +116,8 @@ impl CameraDriver {
             Self::OpenCv(d) => d.shutdown(),
             #[cfg(feature = "v4l2-backend")]
             Self::V4l2(d) => d.shutdown(),
+            #[cfg(feature = "gstreamer-backend")]
+            Self::GStreamer(d) => d.shutdown(),
         }
     }
 
@@ -101,6 +128,8 @@ impl CameraDriver {
             Self::OpenCv(d) => d.is_available(),
             #[cfg(feature = "v4l2-backend")]
             Self::V4l2(d) => d.is_available(),
+            #[cfg(feature = "gstreamer-backend")]
+            Self::GStreamer(d) => d.is_available(),
         }
     }
 
@@ -111,6 +140,8 @@ impl CameraDriver {
             Self::OpenCv(d) => d.status(),
             #[cfg(feature = "v4l2-backend")]
             Self::V4l2(d) => d.status(),
+            #[cfg(feature = "gstreamer-backend")]
+            Self::GStreamer(d) => d.status(),
         }
     }
 
@@ -125,6 +156,8 @@ impl CameraDriver {
             Self::OpenCv(d) => d.read(),
             #[cfg(feature = "v4l2-backend")]
             Self::V4l2(d) => d.read(),
+            #[cfg(feature = "gstreamer-backend")]
+            Self::GStreamer(d) => d.read(),
         }
     }
 
@@ -135,6 +168,8 @@ impl CameraDriver {
             Self::OpenCv(d) => d.has_data(),
             #[cfg(feature = "v4l2-backend")]
             Self::V4l2(d) => d.has_data(),
+            #[cfg(feature = "gstreamer-backend")]
+            Self::GStreamer(d) => d.has_data(),
         }
     }
 
@@ -145,6 +180,8 @@ impl CameraDriver {
             Self::OpenCv(d) => d.sample_rate(),
             #[cfg(feature = "v4l2-backend")]
             Self::V4l2(d) => d.sample_rate(),
+            #[cfg(feature = "gstreamer-backend")]
+            Self::GStreamer(d) => d.sample_rate(),
         }
     }
 }
@@ -7,21 +7,65 @@
 //! - `SimulationDepthCameraDriver` - Always available, generates synthetic data
 //! - `RealSenseDriver` - Intel RealSense cameras (requires `realsense` feature)
 //! - `ZedDriver` - Stereolabs ZED cameras (requires `zed` feature)
+//!
+//! Every driver returns color and depth of the same capture in one
+//! [`DepthCameraFrame`], together with the intrinsics of both images.
 
+mod registration;
 mod simulation;
 
 #[cfg(feature = "realsense")]
 mod realsense;
+#[cfg(feature = "zed")]
+mod zed;
 
 // Re-exports
-pub use simulation::SimulationDepthCameraDriver;
+pub use registration::{scale_camera_info, DepthRegistration};
+pub use simulation::{SimulationDepthCameraConfig, SimulationDepthCameraDriver};
 
 #[cfg(feature = "realsense")]
 pub use realsense::RealSenseDriver;
+#[cfg(feature = "zed")]
+pub use zed::ZedDriver;
 
 use horus_core::driver::DriverStatus;
 use horus_core::error::HorusResult;
 
+use crate::CameraInfo;
+
+/// Depth camera configuration shared by the hardware backends
+#[derive(Debug, Clone, PartialEq)]
+pub struct DepthCameraConfig {
+    /// Device serial number (empty for the first camera found)
+    pub serial: String,
+    /// RGB resolution (width, height)
+    pub rgb_resolution: (u32, u32),
+    /// Depth resolution (width, height); stereo cameras use the RGB resolution
+    pub depth_resolution: (u32, u32),
+    /// Frame rate in Hz
+    pub fps: u32,
+    /// Register depth to the color camera (RGB resolution and intrinsics)
+    pub align_depth_to_color: bool,
+    /// Depth range in meters (used by cameras computing depth on the host)
+    pub depth_range: (f32, f32),
+    /// How long a read waits for the next frame in milliseconds
+    pub timeout_ms: u64,
+}
+
+impl Default for DepthCameraConfig {
+    fn default() -> Self {
+        Self {
+            serial: String::new(),
+            rgb_resolution: (640, 480),
+            depth_resolution: (640, 480),
+            fps: 30,
+            align_depth_to_color: true,
+            depth_range: (0.3, 10.0),
+            timeout_ms: 1000,
+        }
+    }
+}
+
 /// Composite output from a depth camera
 ///
 /// Contains both RGB and depth data from a single capture frame.
@@ -35,6 +79,14 @@ pub struct DepthCameraFrame {
     pub rgb_resolution: (u32, u32),
     /// Depth resolution (width, height)
     pub depth_resolution: (u32, u32),
+    /// Intrinsics of the RGB image
+    pub rgb_info: CameraInfo,
+    /// Intrinsics of the depth image (the RGB intrinsics when aligned)
+    pub depth_info: CameraInfo,
+    /// Meters per depth unit
+    pub depth_units: f32,
+    /// Depth is registered to the RGB image
+    pub aligned: bool,
     /// Timestamp in nanoseconds
     pub timestamp: u64,
 }
@@ -46,6 +98,10 @@ impl Default for DepthCameraFrame {
             depth_data: Vec::new(),
             rgb_resolution: (640, 480),
             depth_resolution: (640, 480),
+            rgb_info: CameraInfo::default(),
+            depth_info: CameraInfo::default(),
+            depth_units: 0.001,
+            aligned: false,
             timestamp: 0,
         }
     }
@@ -70,22 +126,31 @@ pub enum DepthCameraDriver {
     Simulation(SimulationDepthCameraDriver),
     #[cfg(feature = "realsense")]
     RealSense(RealSenseDriver),
+    #[cfg(feature = "zed")]
+    Zed(ZedDriver),
 }
 
 impl DepthCameraDriver {
     /// Create a new depth camera driver with the specified backend
     pub fn new(backend: DepthCameraDriverBackend) -> HorusResult<Self> {
+        Self::with_config(backend, DepthCameraConfig::default())
+    }
+
+    /// Create a depth camera driver with the specified backend and configuration
+    pub fn with_config(
+        backend: DepthCameraDriverBackend,
+        config: DepthCameraConfig,
+    ) -> HorusResult<Self> {
         match backend {
-            DepthCameraDriverBackend::Simulation => {
-                Ok(Self::Simulation(SimulationDepthCameraDriver::new()))
-            }
+            DepthCameraDriverBackend::Simulation => Ok(Self::Simulation(
+                SimulationDepthCameraDriver::with_camera_config(&config),
+            )),
             #[cfg(feature = "realsense")]
-            DepthCameraDriverBackend::RealSense => Ok(Self::RealSense(RealSenseDriver::new()?)),
-            #[cfg(feature = "zed")]
-            DepthCameraDriverBackend::Zed => {
-                // ZED support pending
-                Ok(Self::Simulation(SimulationDepthCameraDriver::new()))
+            DepthCameraDriverBackend::RealSense => {
+                Ok(Self::RealSense(RealSenseDriver::with_config(config)?))
             }
+            #[cfg(feature = "zed")]
+            DepthCameraDriverBackend::Zed => Ok(Self::Zed(ZedDriver::with_config(config)?)),
         }
     }
 
@@ -103,6 +168,8 @@ impl DepthCameraDriver {
             Self::Simulation(d) => d.init(),
             #[cfg(feature = "realsense")]
             Self::RealSense(d) => d.init(),
+            #[cfg(feature = "zed")]
+            Self::Zed(d) => d.init(),
         }
     }
 
@@ -111,6 +178,8 @@ impl DepthCameraDriver {
             Self::Simulation(d) => d.shutdown(),
             #[cfg(feature = "realsense")]
             Self::RealSense(d) => d.shutdown(),
+            #[cfg(feature = "zed")]
+            Self::Zed(d) => d.shutdown(),
         }
     }
 
@@ -119,6 +188,8 @@ impl DepthCameraDriver {
             Self::Simulation(d) => d.is_available(),
             #[cfg(feature = "realsense")]
             Self::RealSense(d) => d.is_available(),
+            #[cfg(feature = "zed")]
+            Self::Zed(d) => d.is_available(),
         }
     }
 
@@ -127,6 +198,8 @@ impl DepthCameraDriver {
             Self::Simulation(d) => d.status(),
             #[cfg(feature = "realsense")]
             Self::RealSense(d) => d.status(),
+            #[cfg(feature = "zed")]
+            Self::Zed(d) => d.status(),
         }
     }
 
@@ -134,11 +207,14 @@ impl DepthCameraDriver {
     // Sensor methods
     // ========================================================================
 
+    /// Read the next frame (color and depth of the same capture)
     pub fn read(&mut self) -> HorusResult<DepthCameraFrame> {
         match self {
             Self::Simulation(d) => d.read(),
             #[cfg(feature = "realsense")]
             Self::RealSense(d) => d.read(),
+            #[cfg(feature = "zed")]
+            Self::Zed(d) => d.read(),
         }
     }
 
@@ -147,6 +223,8 @@ impl DepthCameraDriver {
             Self::Simulation(d) => d.has_data(),
             #[cfg(feature = "realsense")]
             Self::RealSense(d) => d.has_data(),
+            #[cfg(feature = "zed")]
+            Self::Zed(d) => d.has_data(),
         }
     }
 
@@ -155,6 +233,8 @@ impl DepthCameraDriver {
             Self::Simulation(d) => d.sample_rate(),
             #[cfg(feature = "realsense")]
             Self::RealSense(d) => d.sample_rate(),
+            #[cfg(feature = "zed")]
+            Self::Zed(d) => d.sample_rate(),
         }
     }
 }
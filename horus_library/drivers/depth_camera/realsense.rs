@@ -1,80 +1,83 @@
 //! Intel RealSense Depth Camera driver
 //!
-//! Hardware driver for Intel RealSense D400 and L500 series cameras.
+//! Hardware driver for Intel RealSense D400 and L500 series cameras using
+//! librealsense2 (`realsense-rust`). Color and depth are taken from the same
+//! frameset; depth is registered to the color camera when
+//! `align_depth_to_color` is set.
+
+use std::collections::HashSet;
+use std::ffi::CString;
+use std::os::raw::c_void;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use horus_core::driver::DriverStatus;
 use horus_core::error::{HorusError, HorusResult};
 
-use super::DepthCameraFrame;
-
-#[cfg(feature = "realsense")]
 use realsense_rust::{
+    base::Rs2Intrinsics,
     config::Config,
     context::Context,
-    kind::{Rs2Format, Rs2StreamKind},
-    pipeline::{InactivePipeline, Pipeline},
+    frame::{ColorFrame, DepthFrame, ImageFrame},
+    kind::{Rs2CameraInfo, Rs2DistortionModel, Rs2Format, Rs2ProductLine, Rs2StreamKind},
+    pipeline::{ActivePipeline, InactivePipeline},
 };
 
-/// RealSense depth camera driver configuration
-#[derive(Debug, Clone)]
-pub struct RealSenseConfig {
-    /// Device serial number (empty for first available)
-    pub serial: String,
-    /// RGB resolution
-    pub rgb_resolution: (u32, u32),
-    /// Depth resolution
-    pub depth_resolution: (u32, u32),
-    /// Frame rate
-    pub frame_rate: u32,
-}
-
-impl Default for RealSenseConfig {
-    fn default() -> Self {
-        Self {
-            serial: String::new(),
-            rgb_resolution: (640, 480),
-            depth_resolution: (640, 480),
-            frame_rate: 30,
-        }
-    }
-}
+use super::{DepthCameraConfig, DepthCameraFrame, DepthRegistration};
+use crate::{CameraInfo, DistortionModel};
 
 /// Intel RealSense depth camera driver
 pub struct RealSenseDriver {
-    config: RealSenseConfig,
+    config: DepthCameraConfig,
     status: DriverStatus,
-    #[cfg(feature = "realsense")]
-    pipeline: Option<Pipeline>,
+    pipeline: Option<ActivePipeline>,
+    /// Intrinsics of the running streams, read when streaming starts
+    rgb_info: CameraInfo,
+    depth_info: CameraInfo,
+    registration: Option<DepthRegistration>,
+    frame_count: u64,
 }
 
 impl RealSenseDriver {
     /// Create a new RealSense driver with default configuration
     pub fn new() -> HorusResult<Self> {
-        Ok(Self {
-            config: RealSenseConfig::default(),
-            status: DriverStatus::Uninitialized,
-            #[cfg(feature = "realsense")]
-            pipeline: None,
-        })
+        Self::with_config(DepthCameraConfig::default())
     }
 
     /// Create a new RealSense driver with custom configuration
-    pub fn with_config(config: RealSenseConfig) -> HorusResult<Self> {
+    pub fn with_config(config: DepthCameraConfig) -> HorusResult<Self> {
         Ok(Self {
             config,
             status: DriverStatus::Uninitialized,
-            #[cfg(feature = "realsense")]
             pipeline: None,
+            rgb_info: CameraInfo::default(),
+            depth_info: CameraInfo::default(),
+            registration: None,
+            frame_count: 0,
         })
     }
 
+    /// Get the configuration
+    pub fn config(&self) -> &DepthCameraConfig {
+        &self.config
+    }
+
+    /// Frames read since init
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
     /// Get the current timestamp in nanoseconds
     fn now_nanos(&self) -> u64 {
-        std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
             .unwrap()
             .as_nanos() as u64
     }
+
+    fn fail(&mut self, error: String) -> HorusError {
+        self.status = DriverStatus::Error(error.clone());
+        HorusError::driver(error)
+    }
 }
 
 impl Default for RealSenseDriver {
@@ -88,77 +91,131 @@ impl Default for RealSenseDriver {
 // ========================================================================
 
 impl RealSenseDriver {
-    #[cfg(feature = "realsense")]
+    /// Open the camera and start the color and depth streams
+    ///
+    /// Fails when no (matching) camera is connected; call again to retry
+    /// after the camera is plugged in.
     pub fn init(&mut self) -> HorusResult<()> {
-        // Create context
-        let context = Context::new().map_err(|e| {
-            HorusError::driver(format!("Failed to create RealSense context: {:?}", e))
-        })?;
-
-        // Query devices
-        let devices = context
-            .query_devices(None)
-            .map_err(|e| HorusError::driver(format!("Failed to query devices: {:?}", e)))?;
-
-        if devices.len() == 0 {
-            return Err(HorusError::driver("No RealSense devices found"));
+        self.pipeline = None;
+
+        let context = Context::new()
+            .map_err(|e| self.fail(format!("Failed to create RealSense context: {:?}", e)))?;
+
+        let devices = context.query_devices(HashSet::from([Rs2ProductLine::Any]));
+        let serials: Vec<String> = devices
+            .iter()
+            .filter_map(|d| d.info(Rs2CameraInfo::SerialNumber))
+            .map(|s| s.to_string_lossy().into_owned())
+            .collect();
+        if serials.is_empty() {
+            return Err(self.fail("No RealSense devices found".to_string()));
+        }
+        if !self.config.serial.is_empty() && !serials.contains(&self.config.serial) {
+            return Err(self.fail(format!(
+                "RealSense {} not found (connected: {})",
+                self.config.serial,
+                serials.join(", ")
+            )));
         }
 
-        // Create pipeline
         let pipeline = InactivePipeline::try_from(&context)
-            .map_err(|e| HorusError::driver(format!("Failed to create pipeline: {:?}", e)))?;
+            .map_err(|e| self.fail(format!("Failed to create pipeline: {:?}", e)))?;
 
         // Configure streams
         let mut config = Config::new();
-
+        if !self.config.serial.is_empty() {
+            let serial = CString::new(self.config.serial.as_str())
+                .map_err(|_| HorusError::config("Invalid RealSense serial number"))?;
+            config
+                .enable_device_from_serial(&serial)
+                .map_err(|e| self.fail(format!("Failed to select device: {:?}", e)))?;
+        }
+        config
+            .disable_all_streams()
+            .map_err(|e| self.fail(format!("Failed to configure streams: {:?}", e)))?;
+        let (rgb_width, rgb_height) = self.config.rgb_resolution;
+        let (depth_width, depth_height) = self.config.depth_resolution;
         config
             .enable_stream(
                 Rs2StreamKind::Color,
                 None,
-                self.config.rgb_resolution.0 as usize,
-                self.config.rgb_resolution.1 as usize,
+                rgb_width as usize,
+                rgb_height as usize,
                 Rs2Format::Rgb8,
-                self.config.frame_rate as usize,
+                self.config.fps as usize,
             )
-            .ok();
-
+            .map_err(|e| self.fail(format!("Failed to enable color stream: {:?}", e)))?;
         config
             .enable_stream(
                 Rs2StreamKind::Depth,
                 None,
-                self.config.depth_resolution.0 as usize,
-                self.config.depth_resolution.1 as usize,
+                depth_width as usize,
+                depth_height as usize,
                 Rs2Format::Z16,
-                self.config.frame_rate as usize,
+                self.config.fps as usize,
             )
-            .ok();
+            .map_err(|e| self.fail(format!("Failed to enable depth stream: {:?}", e)))?;
 
         // Start pipeline
-        let active_pipeline = pipeline
-            .start(Some(config))
-            .map_err(|e| HorusError::driver(format!("Failed to start pipeline: {:?}", e)))?;
+        let pipeline = pipeline.start(Some(config)).map_err(|e| {
+            self.fail(format!(
+                "Failed to start RealSense pipeline ({}x{} color, {}x{} depth @ {} fps): {:?}",
+                rgb_width, rgb_height, depth_width, depth_height, self.config.fps, e
+            ))
+        })?;
 
-        self.pipeline = Some(active_pipeline);
+        // Intrinsics and extrinsics of the streams the device actually runs
+        let streams = pipeline.profile().streams();
+        let color = streams
+            .iter()
+            .find(|s| s.kind() == Rs2StreamKind::Color)
+            .ok_or_else(|| HorusError::driver("RealSense pipeline has no color stream"))?;
+        let depth = streams
+            .iter()
+            .find(|s| s.kind() == Rs2StreamKind::Depth)
+            .ok_or_else(|| HorusError::driver("RealSense pipeline has no depth stream"))?;
+        let color_intrinsics = color
+            .intrinsics()
+            .map_err(|e| HorusError::driver(format!("Failed to read color intrinsics: {:?}", e)))?;
+        let depth_intrinsics = depth
+            .intrinsics()
+            .map_err(|e| HorusError::driver(format!("Failed to read depth intrinsics: {:?}", e)))?;
+        self.rgb_info = camera_info(&color_intrinsics);
+        self.depth_info = camera_info(&depth_intrinsics);
+
+        self.registration = if self.config.align_depth_to_color {
+            let extrinsics = depth.extrinsics(color).map_err(|e| {
+                HorusError::driver(format!("Failed to read depth-to-color extrinsics: {:?}", e))
+            })?;
+            // librealsense stores the rotation column-major
+            let r = extrinsics.rotation();
+            let rotation = [r[0], r[3], r[6], r[1], r[4], r[7], r[2], r[5], r[8]].map(f64::from);
+            Some(DepthRegistration::new(
+                self.depth_info,
+                self.rgb_info,
+                rotation,
+                extrinsics.translation().map(f64::from),
+            ))
+        } else {
+            None
+        };
+
+        self.pipeline = Some(pipeline);
+        self.frame_count = 0;
         self.status = DriverStatus::Ready;
         Ok(())
     }
 
-    #[cfg(not(feature = "realsense"))]
-    pub fn init(&mut self) -> HorusResult<()> {
-        Err(HorusError::driver("RealSense feature not enabled"))
-    }
-
     pub fn shutdown(&mut self) -> HorusResult<()> {
-        #[cfg(feature = "realsense")]
-        {
-            self.pipeline = None;
+        if let Some(pipeline) = self.pipeline.take() {
+            pipeline.stop();
         }
         self.status = DriverStatus::Shutdown;
         Ok(())
     }
 
     pub fn is_available(&self) -> bool {
-        cfg!(feature = "realsense")
+        self.pipeline.is_some()
     }
 
     pub fn status(&self) -> DriverStatus {
@@ -169,57 +226,127 @@ impl RealSenseDriver {
     // Sensor methods
     // ========================================================================
 
-    #[cfg(feature = "realsense")]
+    /// Wait for the next frameset and return its color and depth images
+    ///
+    /// A camera that was unplugged returns an error and sets the status to
+    /// `Error`; shut the driver down and call `init` again to reconnect.
     pub fn read(&mut self) -> HorusResult<DepthCameraFrame> {
-        if self.status != DriverStatus::Ready && self.status != DriverStatus::Running {
-            return Err(HorusError::driver("Driver not initialized"));
-        }
-
-        let pipeline = self
-            .pipeline
-            .as_mut()
-            .ok_or_else(|| HorusError::driver("Pipeline not initialized"))?;
-
-        let frames = pipeline
-            .wait(Some(std::time::Duration::from_millis(1000)))
-            .map_err(|e| HorusError::driver(format!("Failed to get frames: {:?}", e)))?;
-
-        let mut rgb_data = Vec::new();
-        let mut depth_data = Vec::new();
-
-        if let Some(color_frame) = frames.color_frame() {
-            rgb_data = color_frame.get_data().to_vec();
-        }
-
-        if let Some(depth_frame) = frames.depth_frame() {
-            let data_bytes = depth_frame.get_data();
-            for chunk in data_bytes.chunks_exact(2) {
-                let value = u16::from_le_bytes([chunk[0], chunk[1]]);
-                depth_data.push(value);
-            }
-        }
-
+        let timeout = Duration::from_millis(self.config.timeout_ms);
+        let frames = match self.pipeline.as_mut() {
+            Some(pipeline) => pipeline.wait(Some(timeout)),
+            None => return Err(HorusError::driver("Driver not initialized")),
+        };
+        let frames = frames.map_err(|e| self.fail(format!("No RealSense frames: {:?}", e)))?;
+        let timestamp = self.now_nanos();
+
+        let color = frames
+            .frames_of_type::<ColorFrame>()
+            .into_iter()
+            .next()
+            .ok_or_else(|| HorusError::driver("Frameset without color frame"))?;
+        let depth = frames
+            .frames_of_type::<DepthFrame>()
+            .into_iter()
+            .next()
+            .ok_or_else(|| HorusError::driver("Frameset without depth frame"))?;
+
+        let rgb_resolution = (color.width() as u32, color.height() as u32);
+        let rgb_data = packed_rows(
+            frame_bytes(&color),
+            color.stride(),
+            color.width() * 3,
+            color.height(),
+        );
+        let depth_bytes = packed_rows(
+            frame_bytes(&depth),
+            depth.stride(),
+            depth.width() * 2,
+            depth.height(),
+        );
+        let depth_data: Vec<u16> = depth_bytes
+            .chunks_exact(2)
+            .map(|chunk| u16::from_le_bytes([chunk[0], chunk[1]]))
+            .collect();
+        let depth_units = depth.depth_units().unwrap_or(0.001);
+
+        let mut rgb_info = self.rgb_info;
+        rgb_info.timestamp = timestamp;
+        let (depth_data, depth_resolution, mut depth_info) = match &self.registration {
+            Some(registration) => (
+                registration.align(&depth_data, depth_units),
+                rgb_resolution,
+                rgb_info,
+            ),
+            None => (
+                depth_data,
+                (depth.width() as u32, depth.height() as u32),
+                self.depth_info,
+            ),
+        };
+        depth_info.timestamp = timestamp;
+
+        self.frame_count += 1;
         self.status = DriverStatus::Running;
 
         Ok(DepthCameraFrame {
             rgb_data,
             depth_data,
-            rgb_resolution: self.config.rgb_resolution,
-            depth_resolution: self.config.depth_resolution,
-            timestamp: self.now_nanos(),
+            rgb_resolution,
+            depth_resolution,
+            rgb_info,
+            depth_info,
+            depth_units,
+            aligned: self.registration.is_some(),
+            timestamp,
         })
     }
 
-    #[cfg(not(feature = "realsense"))]
-    pub fn read(&mut self) -> HorusResult<DepthCameraFrame> {
-        Err(HorusError::driver("RealSense feature not enabled"))
-    }
-
     pub fn has_data(&self) -> bool {
         matches!(self.status, DriverStatus::Ready | DriverStatus::Running)
     }
 
     pub fn sample_rate(&self) -> Option<f32> {
-        Some(self.config.frame_rate as f32)
+        Some(self.config.fps as f32)
+    }
+}
+
+/// Pixel data of a frame
+fn frame_bytes<K>(frame: &ImageFrame<K>) -> &[u8] {
+    // SAFETY: librealsense guarantees `get_data_size()` bytes behind the
+    // frame data, which stays valid while the frame is alive
+    unsafe {
+        let data: *const c_void = frame.get_data();
+        std::slice::from_raw_parts(data as *const u8, frame.get_data_size())
+    }
+}
+
+/// Copy the pixel rows of a frame, dropping row padding
+fn packed_rows(data: &[u8], stride: usize, row_bytes: usize, height: usize) -> Vec<u8> {
+    let mut packed = Vec::with_capacity(row_bytes * height);
+    for row in data.chunks(stride.max(row_bytes)).take(height) {
+        packed.extend_from_slice(&row[..row_bytes.min(row.len())]);
     }
+    packed
+}
+
+/// Convert librealsense intrinsics to a CameraInfo
+fn camera_info(intrinsics: &Rs2Intrinsics) -> CameraInfo {
+    let distortion = intrinsics.distortion();
+    let coefficients = distortion.coeffs.map(f64::from);
+    let model = match distortion.model {
+        Rs2DistortionModel::BrownConrady
+        | Rs2DistortionModel::BrownConradyModified
+        | Rs2DistortionModel::BrownConradyInverse => DistortionModel::PlumbBob,
+        Rs2DistortionModel::KannalaBrandt => DistortionModel::Equidistant,
+        _ => DistortionModel::None,
+    };
+    CameraInfo::new(
+        intrinsics.width() as u32,
+        intrinsics.height() as u32,
+        intrinsics.fx() as f64,
+        intrinsics.fy() as f64,
+        intrinsics.ppx() as f64,
+        intrinsics.ppy() as f64,
+    )
+    .with_distortion(model, &coefficients)
 }
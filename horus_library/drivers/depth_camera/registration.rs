@@ -0,0 +1,195 @@
+//! Depth-to-color registration
+//!
+//! Maps a depth image into the pixel grid of the color camera, so that depth
+//! pixel (u, v) and color pixel (u, v) see the same point. Used by depth
+//! cameras whose depth and color sensors are separate (RealSense); stereo
+//! cameras computing depth in the left image (ZED) are aligned natively.
+
+use crate::CameraInfo;
+
+/// Registers depth images into the color camera
+///
+/// Each depth pixel is back-projected with the depth intrinsics, moved into
+/// the color camera with the depth-to-color extrinsics and projected with
+/// the color intrinsics (including lens distortion). The pixel footprint is
+/// projected, not only its center, so upsampled depth has no holes. When
+/// several depth pixels land on the same color pixel the nearest one wins.
+/// Depth distortion is ignored (RealSense depth streams are undistorted).
+#[derive(Debug, Clone)]
+pub struct DepthRegistration {
+    depth: CameraInfo,
+    color: CameraInfo,
+    /// Depth-to-color rotation (3x3, row-major)
+    rotation: [f64; 9],
+    /// Depth-to-color translation in meters
+    translation: [f64; 3],
+}
+
+impl DepthRegistration {
+    pub fn new(
+        depth: CameraInfo,
+        color: CameraInfo,
+        rotation: [f64; 9],
+        translation: [f64; 3],
+    ) -> Self {
+        Self {
+            depth,
+            color,
+            rotation,
+            translation,
+        }
+    }
+
+    /// Intrinsics of the registered depth image (those of the color camera)
+    pub fn color_info(&self) -> &CameraInfo {
+        &self.color
+    }
+
+    /// Register a depth image (`depth_info` resolution, `depth_units` meters
+    /// per unit) into a depth image with the color resolution
+    ///
+    /// Values keep their units; color pixels no depth pixel maps to are 0.
+    pub fn align(&self, depth: &[u16], depth_units: f32) -> Vec<u16> {
+        let (width, height) = (self.depth.width as usize, self.depth.height as usize);
+        let (color_width, color_height) = (self.color.width as i64, self.color.height as i64);
+        let mut aligned = vec![0u16; (color_width * color_height) as usize];
+
+        for (v, row) in depth.chunks_exact(width).take(height).enumerate() {
+            for (u, &value) in row.iter().enumerate() {
+                if value == 0 {
+                    continue;
+                }
+                let z = value as f64 * depth_units as f64;
+
+                // Project the top-left and bottom-right corners of the pixel
+                let Some((x0, y0)) = self.project(u as f64 - 0.5, v as f64 - 0.5, z) else {
+                    continue;
+                };
+                let Some((x1, y1)) = self.project(u as f64 + 0.5, v as f64 + 0.5, z) else {
+                    continue;
+                };
+                // Color pixels whose centers lie inside the footprint
+                let (x0, x1) = (x0.min(x1).ceil().max(0.0), x0.max(x1).floor());
+                let (y0, y1) = (y0.min(y1).ceil().max(0.0), y0.max(y1).floor());
+                let x1 = (x1 as i64).min(color_width - 1);
+                let y1 = (y1 as i64).min(color_height - 1);
+                let (x0, y0) = (x0 as i64, y0 as i64);
+
+                for y in y0..=y1 {
+                    for x in x0..=x1 {
+                        let target = &mut aligned[(y * color_width + x) as usize];
+                        if *target == 0 || value < *target {
+                            *target = value;
+                        }
+                    }
+                }
+            }
+        }
+        aligned
+    }
+
+    /// Color pixel seen by depth pixel (u, v) at depth z
+    fn project(&self, u: f64, v: f64, z: f64) -> Option<(f64, f64)> {
+        let k = &self.depth.camera_matrix;
+        let point = [(u - k[2]) * z / k[0], (v - k[5]) * z / k[4], z];
+        let r = &self.rotation;
+        let t = &self.translation;
+        let point = [
+            r[0] * point[0] + r[1] * point[1] + r[2] * point[2] + t[0],
+            r[3] * point[0] + r[4] * point[1] + r[5] * point[2] + t[1],
+            r[6] * point[0] + r[7] * point[1] + r[8] * point[2] + t[2],
+        ];
+        self.color.project_point(point)
+    }
+}
+
+/// Intrinsics of a camera whose images are resized to `width` x `height`
+pub fn scale_camera_info(info: &CameraInfo, width: u32, height: u32) -> CameraInfo {
+    let mut scaled = *info;
+    if info.width == 0 || info.height == 0 {
+        return scaled;
+    }
+    let sx = width as f64 / info.width as f64;
+    let sy = height as f64 / info.height as f64;
+    scaled.width = width;
+    scaled.height = height;
+    // Pixel centers sit at +0.5, so the principal point scales about -0.5
+    for (matrix, row_len) in [
+        (&mut scaled.camera_matrix[..], 3),
+        (&mut scaled.projection_matrix[..], 4),
+    ] {
+        matrix[0] *= sx;
+        matrix[2] = (matrix[2] + 0.5) * sx - 0.5;
+        matrix[row_len + 1] *= sy;
+        matrix[row_len + 2] = (matrix[row_len + 2] + 0.5) * sy - 0.5;
+    }
+    // Stereo baseline term of the projection matrix (Tx = -fx * baseline)
+    scaled.projection_matrix[3] *= sx;
+    scaled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDENTITY: [f64; 9] = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+
+    fn info(width: u32, height: u32, f: f64) -> CameraInfo {
+        CameraInfo::new(
+            width,
+            height,
+            f,
+            f,
+            width as f64 / 2.0 - 0.5,
+            height as f64 / 2.0 - 0.5,
+        )
+    }
+
+    #[test]
+    fn test_identity_registration_keeps_image() {
+        let camera = info(8, 6, 10.0);
+        let registration = DepthRegistration::new(camera, camera, IDENTITY, [0.0; 3]);
+        let depth: Vec<u16> = (0..48).map(|i| 1000 + i).collect();
+        assert_eq!(registration.align(&depth, 0.001), depth);
+    }
+
+    #[test]
+    fn test_baseline_shifts_depth() {
+        // Color camera 2.5 cm to the left of the depth camera: at 1 m a
+        // focal length of 40 px shifts the image by one pixel
+        let camera = info(8, 6, 40.0);
+        let registration = DepthRegistration::new(camera, camera, IDENTITY, [0.025, 0.0, 0.0]);
+        let depth = vec![1000u16; 48];
+        let aligned = registration.align(&depth, 0.001);
+        for row in aligned.chunks(8) {
+            assert_eq!(row[0], 0);
+            assert!(row[1..].iter().all(|&d| d == 1000));
+        }
+
+        // At 0.5 m the shift is two pixels: the near pixel hides the far one
+        let mut depth = vec![1000u16; 48];
+        depth[0] = 500;
+        let aligned = registration.align(&depth, 0.001);
+        assert_eq!(aligned[1], 0);
+        assert_eq!(aligned[2], 500);
+    }
+
+    #[test]
+    fn test_upsampling_fills_color_pixels() {
+        let depth_camera = info(4, 3, 5.0);
+        let color_camera = scale_camera_info(&depth_camera, 8, 6);
+        assert_eq!(color_camera.focal_lengths(), (10.0, 10.0));
+        assert_eq!(color_camera.principal_point(), (3.5, 2.5));
+
+        let registration = DepthRegistration::new(depth_camera, color_camera, IDENTITY, [0.0; 3]);
+        let mut depth = vec![2000u16; 12];
+        depth[5] = 500;
+        let aligned = registration.align(&depth, 0.001);
+        assert!(aligned.iter().all(|&d| d > 0));
+        // Each depth pixel covers a 2x2 block
+        assert_eq!(aligned[2 * 8 + 2], 500);
+        assert_eq!(aligned[3 * 8 + 3], 500);
+        assert_eq!(aligned[2 * 8 + 4], 2000);
+        assert_eq!(aligned[0], 2000);
+    }
+}
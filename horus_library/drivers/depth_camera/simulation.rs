@@ -8,7 +8,8 @@ use std::time::{SystemTime, UNIX_EPOCH};
 use horus_core::driver::DriverStatus;
 use horus_core::error::HorusResult;
 
-use super::DepthCameraFrame;
+use super::{DepthCameraConfig, DepthCameraFrame};
+use crate::CameraInfo;
 
 /// Simulation depth camera driver configuration
 #[derive(Debug, Clone)]
@@ -72,6 +73,25 @@ impl SimulationDepthCameraDriver {
         }
     }
 
+    /// Create a simulation driver matching a hardware camera configuration
+    ///
+    /// Aligned depth is generated at the RGB resolution.
+    pub fn with_camera_config(config: &DepthCameraConfig) -> Self {
+        let depth_resolution = if config.align_depth_to_color {
+            config.rgb_resolution
+        } else {
+            config.depth_resolution
+        };
+        Self::with_config(SimulationDepthCameraConfig {
+            sample_rate: config.fps as f32,
+            rgb_resolution: config.rgb_resolution,
+            depth_resolution,
+            min_depth: config.depth_range.0,
+            max_depth: config.depth_range.1,
+            ..Default::default()
+        })
+    }
+
     /// Set RGB resolution
     pub fn set_rgb_resolution(&mut self, width: u32, height: u32) {
         self.config.rgb_resolution = (width, height);
@@ -90,6 +110,13 @@ impl SimulationDepthCameraDriver {
             .as_nanos() as u64
     }
 
+    /// Pinhole intrinsics of the simulated sensors (600 px focal length at 640x480)
+    fn camera_info(resolution: (u32, u32)) -> CameraInfo {
+        let (width, height) = resolution;
+        let f = 600.0 * width as f64 / 640.0;
+        CameraInfo::new(width, height, f, f, width as f64 / 2.0, height as f64 / 2.0)
+    }
+
     /// Generate synthetic RGB data
     fn generate_rgb_data(&self) -> Vec<u8> {
         let (width, height) = self.config.rgb_resolution;
@@ -186,12 +213,23 @@ impl SimulationDepthCameraDriver {
     fn generate_frame(&mut self) -> DepthCameraFrame {
         self.frame_count += 1;
 
+        let timestamp = self.now_nanos();
+        let mut rgb_info = Self::camera_info(self.config.rgb_resolution);
+        let mut depth_info = Self::camera_info(self.config.depth_resolution);
+        rgb_info.timestamp = timestamp;
+        depth_info.timestamp = timestamp;
+
         DepthCameraFrame {
             rgb_data: self.generate_rgb_data(),
             depth_data: self.generate_depth_data(),
             rgb_resolution: self.config.rgb_resolution,
             depth_resolution: self.config.depth_resolution,
-            timestamp: self.now_nanos(),
+            rgb_info,
+            depth_info,
+            depth_units: self.config.depth_units,
+            // Both images are rendered from the same viewpoint
+            aligned: self.config.rgb_resolution == self.config.depth_resolution,
+            timestamp,
         }
     }
 }
@@ -224,6 +262,9 @@ mod tests {
         assert!(!frame.depth_data.is_empty());
         assert_eq!(frame.rgb_resolution, (640, 480));
         assert_eq!(frame.depth_resolution, (640, 480));
+        assert_eq!(frame.rgb_info.width, 640);
+        assert_eq!(frame.depth_info.timestamp, frame.timestamp);
+        assert!(frame.aligned);
 
         driver.shutdown().unwrap();
         assert_eq!(driver.status(), DriverStatus::Shutdown);
@@ -0,0 +1,487 @@
+//! Stereolabs ZED Depth Camera driver
+//!
+//! Hardware driver for ZED, ZED Mini and ZED 2/2i USB cameras. Links the
+//! ZED SDK C wrapper (`libsl_zed_c`, ZED SDK 4.x), which needs an NVIDIA GPU
+//! with CUDA. Depth is computed by the SDK in the rectified left image, so
+//! it is always aligned to the RGB image.
+
+use std::os::raw::{c_char, c_int, c_uint, c_ulonglong, c_void};
+use std::ptr;
+use std::sync::atomic::{AtomicI32, Ordering};
+
+use horus_core::driver::DriverStatus;
+use horus_core::error::{HorusError, HorusResult};
+
+use super::{scale_camera_info, DepthCameraConfig, DepthCameraFrame};
+use crate::CameraInfo;
+
+// ZED SDK enum values (sl/c_api/types_c.h)
+const SL_ERROR_CODE_SUCCESS: c_int = 0;
+const SL_ERROR_CODE_CAMERA_NOT_DETECTED: c_int = 4;
+const SL_INPUT_TYPE_USB: c_int = 0;
+const SL_FLIP_MODE_OFF: c_int = 0;
+const SL_DEPTH_MODE_ULTRA: c_int = 3;
+const SL_UNIT_MILLIMETER: c_int = 0;
+const SL_COORDINATE_SYSTEM_IMAGE: c_int = 0;
+const SL_REFERENCE_FRAME_CAMERA: c_int = 1;
+const SL_VIEW_LEFT: c_int = 0;
+const SL_MEASURE_DEPTH: c_int = 1;
+const SL_MEM_CPU: c_int = 0;
+const SL_MAT_TYPE_F32_C1: c_int = 0;
+const SL_MAT_TYPE_U8_C4: c_int = 7;
+
+/// Capture modes of the USB cameras: (SL_RESOLUTION, width, height), smallest first
+const RESOLUTIONS: [(c_int, u32, u32); 4] = [
+    (7, 672, 376),   // VGA
+    (5, 1280, 720),  // HD720
+    (3, 1920, 1080), // HD1080
+    (2, 2208, 1242), // HD2K
+];
+
+#[repr(C)]
+struct SlInitParameters {
+    input_type: c_int,
+    resolution: c_int,
+    camera_fps: c_int,
+    camera_device_id: c_int,
+    camera_image_flip: c_int,
+    camera_disable_self_calib: bool,
+    enable_right_side_measure: bool,
+    svo_real_time_mode: bool,
+    depth_mode: c_int,
+    depth_stabilization: c_int,
+    depth_minimum_distance: f32,
+    depth_maximum_distance: f32,
+    coordinate_unit: c_int,
+    coordinate_system: c_int,
+    sdk_gpu_id: c_int,
+    sdk_verbose: c_int,
+    sensors_required: bool,
+    enable_image_enhancement: bool,
+    open_timeout_sec: f32,
+    async_grab_camera_recovery: bool,
+    grab_compute_capping_fps: f32,
+    enable_image_validity_check: bool,
+}
+
+#[repr(C)]
+struct SlRuntimeParameters {
+    reference_frame: c_int,
+    enable_depth: bool,
+    enable_fill_mode: bool,
+    confidence_threshold: c_int,
+    texture_confidence_threshold: c_int,
+    remove_saturated_areas: bool,
+}
+
+#[repr(C)]
+struct SlResolution {
+    width: i64,
+    height: i64,
+}
+
+#[repr(C)]
+struct SlCameraParameters {
+    fx: f32,
+    fy: f32,
+    cx: f32,
+    cy: f32,
+    disto: [f64; 12],
+    v_fov: f32,
+    h_fov: f32,
+    d_fov: f32,
+    /// Capture resolution the parameters refer to
+    image_size: SlResolution,
+    focal_length_metric: f32,
+}
+
+/// First field of `SL_CalibrationParameters` (right camera and stereo
+/// transform follow; only the left camera is read)
+#[repr(C)]
+struct SlCalibrationParameters {
+    left_cam: SlCameraParameters,
+}
+
+#[link(name = "sl_zed_c")]
+extern "C" {
+    fn sl_create_camera(camera_id: c_int) -> bool;
+    fn sl_open_camera(
+        camera_id: c_int,
+        init_parameters: *mut SlInitParameters,
+        serial_number: c_uint,
+        path_svo: *const c_char,
+        ip: *const c_char,
+        stream_port: c_int,
+        output_file: *const c_char,
+        opt_settings_path: *const c_char,
+        opencv_calib_path: *const c_char,
+    ) -> c_int;
+    fn sl_close_camera(camera_id: c_int);
+    fn sl_grab(camera_id: c_int, runtime: *mut SlRuntimeParameters) -> c_int;
+    fn sl_get_calibration_parameters(
+        camera_id: c_int,
+        raw_params: bool,
+    ) -> *mut SlCalibrationParameters;
+    fn sl_get_image_timestamp(camera_id: c_int) -> c_ulonglong;
+    fn sl_retrieve_image(
+        camera_id: c_int,
+        image_ptr: *mut c_void,
+        view: c_int,
+        mem: c_int,
+        width: c_int,
+        height: c_int,
+        cuda_stream: *mut c_void,
+    ) -> c_int;
+    fn sl_retrieve_measure(
+        camera_id: c_int,
+        measure_ptr: *mut c_void,
+        measure: c_int,
+        mem: c_int,
+        width: c_int,
+        height: c_int,
+        cuda_stream: *mut c_void,
+    ) -> c_int;
+    fn sl_mat_create_new(width: c_int, height: c_int, mat_type: c_int, mem: c_int) -> *mut c_void;
+    fn sl_mat_free(ptr: *mut c_void, mem: c_int);
+    fn sl_mat_get_ptr(ptr: *mut c_void, mem: c_int) -> *mut c_int;
+    fn sl_mat_get_step_bytes(ptr: *mut c_void, mem: c_int) -> c_int;
+}
+
+/// The C API addresses cameras by index
+static NEXT_CAMERA_ID: AtomicI32 = AtomicI32::new(0);
+
+/// CPU image owned by the ZED SDK
+struct ZedMat {
+    ptr: *mut c_void,
+    height: usize,
+}
+
+impl ZedMat {
+    fn new(width: u32, height: u32, mat_type: c_int) -> HorusResult<Self> {
+        // SAFETY: allocates a new matrix, freed in Drop
+        let ptr =
+            unsafe { sl_mat_create_new(width as c_int, height as c_int, mat_type, SL_MEM_CPU) };
+        if ptr.is_null() {
+            return Err(HorusError::driver("Failed to allocate ZED image"));
+        }
+        Ok(Self {
+            ptr,
+            height: height as usize,
+        })
+    }
+
+    /// Rows of the matrix (`step` bytes each, including padding)
+    fn rows(&self) -> (&[u8], usize) {
+        // SAFETY: the matrix owns `step * height` bytes of CPU memory
+        unsafe {
+            let step = sl_mat_get_step_bytes(self.ptr, SL_MEM_CPU) as usize;
+            let data = sl_mat_get_ptr(self.ptr, SL_MEM_CPU) as *const u8;
+            (std::slice::from_raw_parts(data, step * self.height), step)
+        }
+    }
+}
+
+impl Drop for ZedMat {
+    fn drop(&mut self) {
+        // SAFETY: `ptr` was allocated by sl_mat_create_new
+        unsafe { sl_mat_free(self.ptr, SL_MEM_CPU) }
+    }
+}
+
+/// Stereolabs ZED depth camera driver
+pub struct ZedDriver {
+    config: DepthCameraConfig,
+    status: DriverStatus,
+    camera_id: c_int,
+    open: bool,
+    /// Intrinsics of the rectified left image at the output resolution
+    info: CameraInfo,
+    image: Option<ZedMat>,
+    depth: Option<ZedMat>,
+    frame_count: u64,
+}
+
+// SAFETY: the SDK matrices are only accessed through `&mut self`
+unsafe impl Send for ZedDriver {}
+
+impl ZedDriver {
+    /// Create a new ZED driver with default configuration
+    pub fn new() -> HorusResult<Self> {
+        Self::with_config(DepthCameraConfig::default())
+    }
+
+    /// Create a new ZED driver with custom configuration
+    pub fn with_config(config: DepthCameraConfig) -> HorusResult<Self> {
+        if !config.serial.is_empty() && config.serial.parse::<u32>().is_err() {
+            return Err(HorusError::config(format!(
+                "Invalid ZED serial number '{}'",
+                config.serial
+            )));
+        }
+        Ok(Self {
+            config,
+            status: DriverStatus::Uninitialized,
+            camera_id: NEXT_CAMERA_ID.fetch_add(1, Ordering::Relaxed),
+            open: false,
+            info: CameraInfo::default(),
+            image: None,
+            depth: None,
+            frame_count: 0,
+        })
+    }
+
+    /// Get the configuration
+    pub fn config(&self) -> &DepthCameraConfig {
+        &self.config
+    }
+
+    /// Frames read since init
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    /// Smallest capture mode covering the requested RGB resolution
+    fn capture_mode(&self) -> c_int {
+        let (width, height) = self.config.rgb_resolution;
+        RESOLUTIONS
+            .iter()
+            .find(|&&(_, w, h)| w >= width && h >= height)
+            .map_or(RESOLUTIONS[RESOLUTIONS.len() - 1].0, |&(mode, _, _)| mode)
+    }
+
+    fn fail(&mut self, error: String) -> HorusError {
+        self.status = DriverStatus::Error(error.clone());
+        HorusError::driver(error)
+    }
+}
+
+impl Drop for ZedDriver {
+    fn drop(&mut self) {
+        let _ = self.shutdown();
+    }
+}
+
+// ========================================================================
+// Lifecycle methods
+// ========================================================================
+
+impl ZedDriver {
+    /// Open the camera
+    ///
+    /// Fails when no (matching) camera is connected; call again to retry
+    /// after the camera is plugged in.
+    pub fn init(&mut self) -> HorusResult<()> {
+        self.shutdown()?;
+
+        let mut init_parameters = SlInitParameters {
+            input_type: SL_INPUT_TYPE_USB,
+            resolution: self.capture_mode(),
+            camera_fps: self.config.fps as c_int,
+            camera_device_id: 0,
+            camera_image_flip: SL_FLIP_MODE_OFF,
+            camera_disable_self_calib: false,
+            enable_right_side_measure: false,
+            svo_real_time_mode: false,
+            depth_mode: SL_DEPTH_MODE_ULTRA,
+            depth_stabilization: 1,
+            depth_minimum_distance: self.config.depth_range.0 * 1000.0,
+            depth_maximum_distance: self.config.depth_range.1 * 1000.0,
+            coordinate_unit: SL_UNIT_MILLIMETER,
+            coordinate_system: SL_COORDINATE_SYSTEM_IMAGE,
+            sdk_gpu_id: -1,
+            sdk_verbose: 0,
+            sensors_required: false,
+            enable_image_enhancement: true,
+            open_timeout_sec: (self.config.timeout_ms as f32 / 1000.0).max(1.0),
+            async_grab_camera_recovery: false,
+            grab_compute_capping_fps: 0.0,
+            enable_image_validity_check: false,
+        };
+        let serial = self.config.serial.parse::<c_uint>().unwrap_or(0);
+
+        // SAFETY: the parameters outlive the call, unused paths are null
+        let code = unsafe {
+            sl_create_camera(self.camera_id);
+            sl_open_camera(
+                self.camera_id,
+                &mut init_parameters,
+                serial,
+                ptr::null(),
+                ptr::null(),
+                0,
+                ptr::null(),
+                ptr::null(),
+                ptr::null(),
+            )
+        };
+        if code != SL_ERROR_CODE_SUCCESS {
+            let error = if code == SL_ERROR_CODE_CAMERA_NOT_DETECTED {
+                "No ZED camera detected".to_string()
+            } else {
+                format!("Failed to open ZED camera (error code {})", code)
+            };
+            // SAFETY: closing a camera that failed to open is allowed
+            unsafe { sl_close_camera(self.camera_id) };
+            return Err(self.fail(error));
+        }
+        self.open = true;
+
+        // SAFETY: the camera is open; the SDK owns the returned struct
+        let calibration = unsafe { sl_get_calibration_parameters(self.camera_id, false) };
+        if calibration.is_null() {
+            return Err(self.fail("Failed to read ZED calibration".to_string()));
+        }
+        // SAFETY: checked for null above
+        let left = unsafe { &(*calibration).left_cam };
+        // Scale the rectified intrinsics from the capture to the output resolution
+        let native = CameraInfo::new(
+            left.image_size.width as u32,
+            left.image_size.height as u32,
+            left.fx as f64,
+            left.fy as f64,
+            left.cx as f64,
+            left.cy as f64,
+        );
+        let (width, height) = self.config.rgb_resolution;
+        self.info = scale_camera_info(&native, width, height);
+
+        self.image = Some(ZedMat::new(width, height, SL_MAT_TYPE_U8_C4)?);
+        self.depth = Some(ZedMat::new(width, height, SL_MAT_TYPE_F32_C1)?);
+        self.frame_count = 0;
+        self.status = DriverStatus::Ready;
+        Ok(())
+    }
+
+    pub fn shutdown(&mut self) -> HorusResult<()> {
+        self.image = None;
+        self.depth = None;
+        if self.open {
+            // SAFETY: the camera was opened by init
+            unsafe { sl_close_camera(self.camera_id) };
+            self.open = false;
+            self.status = DriverStatus::Shutdown;
+        }
+        Ok(())
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.open
+    }
+
+    pub fn status(&self) -> DriverStatus {
+        self.status.clone()
+    }
+
+    // ========================================================================
+    // Sensor methods
+    // ========================================================================
+
+    /// Grab the next frame and return the left image with its depth
+    ///
+    /// A camera that was unplugged returns an error and sets the status to
+    /// `Error`; shut the driver down and call `init` again to reconnect.
+    pub fn read(&mut self) -> HorusResult<DepthCameraFrame> {
+        if !self.open {
+            return Err(HorusError::driver("Driver not initialized"));
+        }
+        let (width, height) = self.config.rgb_resolution;
+
+        let mut runtime = SlRuntimeParameters {
+            reference_frame: SL_REFERENCE_FRAME_CAMERA,
+            enable_depth: true,
+            enable_fill_mode: false,
+            confidence_threshold: 95,
+            texture_confidence_threshold: 100,
+            remove_saturated_areas: true,
+        };
+        // SAFETY: the camera is open and the parameters outlive the call
+        let code = unsafe { sl_grab(self.camera_id, &mut runtime) };
+        if code != SL_ERROR_CODE_SUCCESS {
+            return Err(self.fail(format!("ZED grab failed (error code {})", code)));
+        }
+        // SAFETY: the camera is open
+        let timestamp = unsafe { sl_get_image_timestamp(self.camera_id) } as u64;
+
+        let (Some(image), Some(depth)) = (&self.image, &self.depth) else {
+            return Err(HorusError::driver("Driver not initialized"));
+        };
+        // SAFETY: the matrices were allocated with the requested size
+        let codes = unsafe {
+            [
+                sl_retrieve_image(
+                    self.camera_id,
+                    image.ptr,
+                    SL_VIEW_LEFT,
+                    SL_MEM_CPU,
+                    width as c_int,
+                    height as c_int,
+                    ptr::null_mut(),
+                ),
+                sl_retrieve_measure(
+                    self.camera_id,
+                    depth.ptr,
+                    SL_MEASURE_DEPTH,
+                    SL_MEM_CPU,
+                    width as c_int,
+                    height as c_int,
+                    ptr::null_mut(),
+                ),
+            ]
+        };
+        if let Some(code) = codes.iter().find(|&&c| c != SL_ERROR_CODE_SUCCESS) {
+            return Err(HorusError::driver(format!(
+                "Failed to retrieve ZED images (error code {})",
+                code
+            )));
+        }
+
+        // BGRA to RGB
+        let (width, height) = (width as usize, height as usize);
+        let (rows, step) = image.rows();
+        let mut rgb_data = Vec::with_capacity(width * height * 3);
+        for row in rows.chunks(step).take(height) {
+            for bgra in row[..width * 4].chunks_exact(4) {
+                rgb_data.extend_from_slice(&[bgra[2], bgra[1], bgra[0]]);
+            }
+        }
+
+        // Millimeters as f32 (NaN or infinite when unknown) to u16
+        let (rows, step) = depth.rows();
+        let mut depth_data = Vec::with_capacity(width * height);
+        for row in rows.chunks(step).take(height) {
+            for mm in row[..width * 4].chunks_exact(4) {
+                let mm = f32::from_ne_bytes([mm[0], mm[1], mm[2], mm[3]]);
+                depth_data.push(if mm.is_finite() && mm > 0.0 {
+                    mm.round().min(u16::MAX as f32) as u16
+                } else {
+                    0
+                });
+            }
+        }
+
+        let mut info = self.info;
+        info.timestamp = timestamp;
+        self.frame_count += 1;
+        self.status = DriverStatus::Running;
+
+        Ok(DepthCameraFrame {
+            rgb_data,
+            depth_data,
+            rgb_resolution: self.config.rgb_resolution,
+            depth_resolution: self.config.rgb_resolution,
+            rgb_info: info,
+            depth_info: info,
+            depth_units: 0.001,
+            aligned: true,
+            timestamp,
+        })
+    }
+
+    pub fn has_data(&self) -> bool {
+        matches!(self.status, DriverStatus::Ready | DriverStatus::Running)
+    }
+
+    pub fn sample_rate(&self) -> Option<f32> {
+        Some(self.config.fps as f32)
+    }
+}
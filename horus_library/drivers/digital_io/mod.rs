@@ -17,19 +17,11 @@ pub use simulation::SimulationDigitalIoDriver;
 #[cfg(feature = "gpio-hardware")]
 pub use gpio::GpioDigitalIoDriver;
 
+pub use super::gpio::PinMode;
+
 use horus_core::driver::DriverStatus;
 use horus_core::error::HorusResult;
 
-/// Digital I/O pin mode
-#[derive(Debug, Clone, Copy, PartialEq, Default)]
-pub enum PinMode {
-    #[default]
-    Input,
-    Output,
-    InputPullUp,
-    InputPullDown,
-}
-
 /// Digital I/O pin configuration
 #[derive(Debug, Clone)]
 pub struct DigitalIoPin {
@@ -6,7 +6,13 @@
 //!
 //! - `SimulationDynamixelDriver` - Always available, simulates Dynamixel behavior
 //! - `SerialDynamixelDriver` - Serial protocol (requires `serial-hardware` feature)
+//!
+//! Protocol 2.0 servos (X-series and newer) additionally support sync
+//! read/write of up to [`MAX_SYNC_IDS`] servos per transaction, bulk reads
+//! and the position, velocity and current operating modes. The packet codec
+//! is in [`protocol2`].
 
+pub mod protocol2;
 mod simulation;
 
 #[cfg(feature = "serial-hardware")]
@@ -17,9 +23,21 @@ pub use simulation::SimulationDynamixelDriver;
 #[cfg(feature = "serial-hardware")]
 pub use serial::SerialDynamixelDriver;
 
+use std::f64::consts::PI;
+
 use horus_core::driver::DriverStatus;
 use horus_core::error::HorusResult;
 
+/// Maximum number of servos in one sync read/write transaction
+pub const MAX_SYNC_IDS: usize = 32;
+
+/// Position units per revolution (X-series)
+const POSITION_UNITS_PER_REV: f64 = 4096.0;
+/// Raw position of the center (0 rad)
+const POSITION_CENTER: f64 = 2048.0;
+/// Velocity unit in RPM (X-series)
+const VELOCITY_UNIT_RPM: f64 = 0.229;
+
 /// Dynamixel servo command
 #[derive(Debug, Clone, Copy, Default)]
 pub struct DynamixelCommand {
@@ -71,6 +89,48 @@ pub enum DynamixelMode {
     ExtendedPosition,
     PWM,
     CurrentBasedPosition,
+    /// Torque control through the motor current (Protocol 2.0)
+    Current,
+}
+
+impl DynamixelMode {
+    /// Operating mode register value (address 11, Protocol 2.0)
+    pub fn operating_mode(&self) -> u8 {
+        match self {
+            Self::Current => 0,
+            Self::Velocity => 1,
+            Self::Position => 3,
+            Self::ExtendedPosition => 4,
+            Self::CurrentBasedPosition => 5,
+            Self::PWM => 16,
+        }
+    }
+
+    /// Goal register (address, size in bytes) written in this mode
+    pub fn goal_register(&self) -> (u16, u16) {
+        use protocol2::control_table::*;
+        match self {
+            Self::Position | Self::ExtendedPosition | Self::CurrentBasedPosition => {
+                (GOAL_POSITION, 4)
+            }
+            Self::Velocity => (GOAL_VELOCITY, 4),
+            Self::Current => (GOAL_CURRENT, 2),
+            Self::PWM => (GOAL_PWM, 2),
+        }
+    }
+}
+
+/// Present state of a servo read with a sync read
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DynamixelState {
+    /// Servo ID
+    pub id: u8,
+    /// Position in radians (0 = center)
+    pub position: f64,
+    /// Velocity in rad/s
+    pub velocity: f64,
+    /// Motor current in A
+    pub current: f64,
 }
 
 /// Dynamixel protocol version
@@ -92,6 +152,50 @@ pub struct DynamixelConfig {
     pub protocol: DynamixelProtocol,
     /// Servo IDs to manage
     pub servo_ids: Vec<u8>,
+    /// Current unit in mA (2.69 for XM/XH series, 1.0 for XC/XW series)
+    pub current_unit_ma: f64,
+    /// Status packet timeout in milliseconds
+    pub timeout_ms: u64,
+}
+
+impl DynamixelConfig {
+    /// Goal register value for a goal in SI units (rad, rad/s, A or PWM fraction)
+    pub fn goal_to_raw(&self, mode: DynamixelMode, goal: f64) -> i32 {
+        let raw = match mode {
+            DynamixelMode::Position
+            | DynamixelMode::ExtendedPosition
+            | DynamixelMode::CurrentBasedPosition => {
+                goal / (2.0 * PI) * POSITION_UNITS_PER_REV + POSITION_CENTER
+            }
+            DynamixelMode::Velocity => goal * 60.0 / (2.0 * PI) / VELOCITY_UNIT_RPM,
+            DynamixelMode::Current => goal * 1000.0 / self.current_unit_ma,
+            // Goal PWM is limited to +-885 (100%)
+            DynamixelMode::PWM => goal.clamp(-1.0, 1.0) * 885.0,
+        };
+        match mode {
+            DynamixelMode::Position => raw.round().clamp(0.0, POSITION_UNITS_PER_REV - 1.0) as i32,
+            DynamixelMode::Current | DynamixelMode::PWM => {
+                raw.round().clamp(i16::MIN as f64, i16::MAX as f64) as i32
+            }
+            _ => raw.round().clamp(i32::MIN as f64, i32::MAX as f64) as i32,
+        }
+    }
+
+    /// Decode present current (2 bytes), velocity (4) and position (4) from address 126
+    pub fn decode_state(&self, id: u8, data: &[u8]) -> Option<DynamixelState> {
+        if data.len() < 10 {
+            return None;
+        }
+        let current = i16::from_le_bytes([data[0], data[1]]);
+        let velocity = i32::from_le_bytes([data[2], data[3], data[4], data[5]]);
+        let position = i32::from_le_bytes([data[6], data[7], data[8], data[9]]);
+        Some(DynamixelState {
+            id,
+            position: (position as f64 - POSITION_CENTER) / POSITION_UNITS_PER_REV * 2.0 * PI,
+            velocity: velocity as f64 * VELOCITY_UNIT_RPM * 2.0 * PI / 60.0,
+            current: current as f64 * self.current_unit_ma / 1000.0,
+        })
+    }
 }
 
 impl Default for DynamixelConfig {
@@ -101,6 +205,8 @@ impl Default for DynamixelConfig {
             baud_rate: 1000000,
             protocol: DynamixelProtocol::V2,
             servo_ids: vec![1],
+            current_unit_ma: 2.69,
+            timeout_ms: 20,
         }
     }
 }
@@ -191,4 +297,78 @@ impl DynamixelDriver {
             Self::Serial(d) => d.stop(),
         }
     }
+
+    // ========================================================================
+    // Protocol 2.0 methods
+    // ========================================================================
+
+    /// Check whether a servo answers
+    pub fn ping(&mut self, servo_id: u8) -> HorusResult<bool> {
+        match self {
+            Self::Simulation(d) => d.ping(servo_id),
+            #[cfg(feature = "serial-hardware")]
+            Self::Serial(d) => d.ping(servo_id),
+        }
+    }
+
+    /// Change the operating mode (torque is disabled first, as the servo requires)
+    pub fn set_operating_mode(&mut self, servo_id: u8, mode: DynamixelMode) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.set_operating_mode(servo_id, mode),
+            #[cfg(feature = "serial-hardware")]
+            Self::Serial(d) => d.set_operating_mode(servo_id, mode),
+        }
+    }
+
+    /// Enable or disable torque of several servos with one sync write
+    pub fn set_torque_enabled(&mut self, servo_ids: &[u8], enable: bool) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.set_torque_enabled(servo_ids, enable),
+            #[cfg(feature = "serial-hardware")]
+            Self::Serial(d) => d.set_torque_enabled(servo_ids, enable),
+        }
+    }
+
+    /// Write goals of servos in the same mode with one sync write
+    ///
+    /// Goals are (ID, value) in rad, rad/s, A or PWM fraction depending on `mode`.
+    pub fn sync_write_goals(
+        &mut self,
+        mode: DynamixelMode,
+        goals: &[(u8, f64)],
+    ) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.sync_write_goals(mode, goals),
+            #[cfg(feature = "serial-hardware")]
+            Self::Serial(d) => d.sync_write_goals(mode, goals),
+        }
+    }
+
+    /// Read position, velocity and current of up to [`MAX_SYNC_IDS`] servos with one sync read
+    ///
+    /// Servos that do not answer are missing from the result.
+    pub fn sync_read_state(&mut self, servo_ids: &[u8]) -> HorusResult<Vec<DynamixelState>> {
+        match self {
+            Self::Simulation(d) => d.sync_read_state(servo_ids),
+            #[cfg(feature = "serial-hardware")]
+            Self::Serial(d) => d.sync_read_state(servo_ids),
+        }
+    }
+
+    /// Read a different register range from each servo: (ID, address, length)
+    pub fn bulk_read(&mut self, reads: &[(u8, u16, u16)]) -> HorusResult<Vec<(u8, Vec<u8>)>> {
+        match self {
+            Self::Simulation(d) => d.bulk_read(reads),
+            #[cfg(feature = "serial-hardware")]
+            Self::Serial(d) => d.bulk_read(reads),
+        }
+    }
+
+    pub fn config(&self) -> &DynamixelConfig {
+        match self {
+            Self::Simulation(d) => d.config(),
+            #[cfg(feature = "serial-hardware")]
+            Self::Serial(d) => d.config(),
+        }
+    }
 }
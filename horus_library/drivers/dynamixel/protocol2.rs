@@ -0,0 +1,317 @@
+//! Dynamixel Protocol 2.0 packet encoding and decoding
+//!
+//! Instruction packets:
+//!
+//! ```text
+//! FF FF FD 00 | ID | LEN_L LEN_H | INST | PARAM... | CRC_L CRC_H
+//! ```
+//!
+//! `LEN` counts the instruction, the (stuffed) parameters and the CRC.
+//! Status packets use instruction 0x55 followed by an error byte. Any
+//! `FF FF FD` sequence after the length field is stuffed with an extra
+//! `FD` so it cannot be mistaken for a header.
+
+use horus_core::error::{HorusError, HorusResult};
+
+/// Packet header
+pub const HEADER: [u8; 4] = [0xFF, 0xFF, 0xFD, 0x00];
+/// ID addressing all servos (no status packet is returned)
+pub const BROADCAST_ID: u8 = 0xFE;
+
+pub const INST_PING: u8 = 0x01;
+pub const INST_READ: u8 = 0x02;
+pub const INST_WRITE: u8 = 0x03;
+pub const INST_REBOOT: u8 = 0x08;
+pub const INST_STATUS: u8 = 0x55;
+pub const INST_SYNC_READ: u8 = 0x82;
+pub const INST_SYNC_WRITE: u8 = 0x83;
+pub const INST_BULK_READ: u8 = 0x92;
+
+/// X-series control table addresses (XL430, XC430, XM430, XM540, XH430, XH540)
+pub mod control_table {
+    pub const OPERATING_MODE: u16 = 11;
+    pub const TORQUE_ENABLE: u16 = 64;
+    pub const GOAL_PWM: u16 = 100;
+    pub const GOAL_CURRENT: u16 = 102;
+    pub const GOAL_VELOCITY: u16 = 104;
+    pub const GOAL_POSITION: u16 = 116;
+    pub const PRESENT_CURRENT: u16 = 126;
+    pub const PRESENT_VELOCITY: u16 = 128;
+    pub const PRESENT_POSITION: u16 = 132;
+    pub const PRESENT_INPUT_VOLTAGE: u16 = 144;
+    pub const PRESENT_TEMPERATURE: u16 = 146;
+}
+
+/// CRC-16 (polynomial 0x8005, initial value 0) over header to last parameter
+pub fn crc16(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x8005
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Insert an `FD` after every `FF FF FD` in the instruction and parameters
+fn stuff(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + 2);
+    for &byte in data {
+        out.push(byte);
+        let n = out.len();
+        if n >= 3 && out[n - 3..] == [0xFF, 0xFF, 0xFD] {
+            out.push(0xFD);
+        }
+    }
+    out
+}
+
+/// Remove the stuffing `FD` after every `FF FF FD`
+fn unstuff(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len());
+    let mut i = 0;
+    while i < data.len() {
+        out.push(data[i]);
+        let n = out.len();
+        if n >= 3 && out[n - 3..] == [0xFF, 0xFF, 0xFD] && data.get(i + 1) == Some(&0xFD) {
+            i += 1;
+        }
+        i += 1;
+    }
+    out
+}
+
+/// Build an instruction packet
+pub fn instruction_packet(id: u8, instruction: u8, params: &[u8]) -> Vec<u8> {
+    let mut body = Vec::with_capacity(params.len() + 1);
+    body.push(instruction);
+    body.extend_from_slice(params);
+    let body = stuff(&body);
+
+    let length = (body.len() + 2) as u16;
+    let mut packet = Vec::with_capacity(body.len() + 9);
+    packet.extend_from_slice(&HEADER);
+    packet.push(id);
+    packet.extend_from_slice(&length.to_le_bytes());
+    packet.extend_from_slice(&body);
+    let crc = crc16(&packet);
+    packet.extend_from_slice(&crc.to_le_bytes());
+    packet
+}
+
+pub fn ping_packet(id: u8) -> Vec<u8> {
+    instruction_packet(id, INST_PING, &[])
+}
+
+pub fn reboot_packet(id: u8) -> Vec<u8> {
+    instruction_packet(id, INST_REBOOT, &[])
+}
+
+pub fn read_packet(id: u8, address: u16, length: u16) -> Vec<u8> {
+    let mut params = Vec::with_capacity(4);
+    params.extend_from_slice(&address.to_le_bytes());
+    params.extend_from_slice(&length.to_le_bytes());
+    instruction_packet(id, INST_READ, &params)
+}
+
+pub fn write_packet(id: u8, address: u16, data: &[u8]) -> Vec<u8> {
+    let mut params = Vec::with_capacity(data.len() + 2);
+    params.extend_from_slice(&address.to_le_bytes());
+    params.extend_from_slice(data);
+    instruction_packet(id, INST_WRITE, &params)
+}
+
+/// Read the same address range from several servos (one status packet per ID, in order)
+pub fn sync_read_packet(address: u16, length: u16, ids: &[u8]) -> Vec<u8> {
+    let mut params = Vec::with_capacity(ids.len() + 4);
+    params.extend_from_slice(&address.to_le_bytes());
+    params.extend_from_slice(&length.to_le_bytes());
+    params.extend_from_slice(ids);
+    instruction_packet(BROADCAST_ID, INST_SYNC_READ, &params)
+}
+
+/// Write the same address range of several servos; every data slice must be `length` bytes
+pub fn sync_write_packet(address: u16, length: u16, data: &[(u8, &[u8])]) -> Vec<u8> {
+    let mut params = Vec::with_capacity(data.len() * (length as usize + 1) + 4);
+    params.extend_from_slice(&address.to_le_bytes());
+    params.extend_from_slice(&length.to_le_bytes());
+    for (id, bytes) in data {
+        params.push(*id);
+        params.extend_from_slice(bytes);
+    }
+    instruction_packet(BROADCAST_ID, INST_SYNC_WRITE, &params)
+}
+
+/// Read a different address range from each servo: (ID, address, length)
+pub fn bulk_read_packet(reads: &[(u8, u16, u16)]) -> Vec<u8> {
+    let mut params = Vec::with_capacity(reads.len() * 5);
+    for (id, address, length) in reads {
+        params.push(*id);
+        params.extend_from_slice(&address.to_le_bytes());
+        params.extend_from_slice(&length.to_le_bytes());
+    }
+    instruction_packet(BROADCAST_ID, INST_BULK_READ, &params)
+}
+
+/// Status packet returned by a servo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusPacket {
+    pub id: u8,
+    /// Error field: bit 7 = hardware alert, bits 0-6 = instruction error code
+    pub error: u8,
+    pub params: Vec<u8>,
+}
+
+impl StatusPacket {
+    /// Hardware error flagged (overload, overheating, voltage, ...; see 0x70)
+    pub fn hardware_alert(&self) -> bool {
+        self.error & 0x80 != 0
+    }
+
+    /// Instruction error code (0 = none)
+    pub fn error_code(&self) -> u8 {
+        self.error & 0x7F
+    }
+
+    pub fn error_description(&self) -> &'static str {
+        match self.error_code() {
+            0 => "none",
+            1 => "result fail",
+            2 => "instruction error",
+            3 => "CRC error",
+            4 => "data range error",
+            5 => "data length error",
+            6 => "data limit error",
+            7 => "access error",
+            _ => "unknown error",
+        }
+    }
+}
+
+/// Parse the first status packet in `buf`
+///
+/// Bytes before the header are skipped. Returns the packet and the number
+/// of bytes consumed, `None` if the packet is not complete yet, or an
+/// error if the CRC does not match.
+pub fn parse_status(buf: &[u8]) -> HorusResult<Option<(StatusPacket, usize)>> {
+    let Some(start) = buf.windows(4).position(|w| w == HEADER) else {
+        return Ok(None);
+    };
+    let packet = &buf[start..];
+    if packet.len() < 7 {
+        return Ok(None);
+    }
+    let length = u16::from_le_bytes([packet[5], packet[6]]) as usize;
+    let total = 7 + length;
+    if length < 4 {
+        return Err(HorusError::driver("Dynamixel status packet too short"));
+    }
+    if packet.len() < total {
+        return Ok(None);
+    }
+
+    let crc = u16::from_le_bytes([packet[total - 2], packet[total - 1]]);
+    if crc16(&packet[..total - 2]) != crc {
+        return Err(HorusError::driver("Dynamixel status packet CRC mismatch"));
+    }
+
+    let body = unstuff(&packet[7..total - 2]);
+    if body.len() < 2 || body[0] != INST_STATUS {
+        return Err(HorusError::driver("Not a Dynamixel status packet"));
+    }
+
+    Ok(Some((
+        StatusPacket {
+            id: packet[4],
+            error: body[1],
+            params: body[2..].to_vec(),
+        },
+        start + total,
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a status packet as a servo would
+    fn status(id: u8, error: u8, params: &[u8]) -> Vec<u8> {
+        let mut p = vec![error];
+        p.extend_from_slice(params);
+        instruction_packet(id, INST_STATUS, &p)
+    }
+
+    #[test]
+    fn test_ping_packet_matches_reference() {
+        // Reference packet from the Protocol 2.0 e-manual
+        assert_eq!(
+            ping_packet(1),
+            vec![0xFF, 0xFF, 0xFD, 0x00, 0x01, 0x03, 0x00, 0x01, 0x19, 0x4E]
+        );
+    }
+
+    #[test]
+    fn test_sync_packets_match_reference() {
+        // Sync read of present position from IDs 1 and 2
+        assert_eq!(
+            sync_read_packet(132, 4, &[1, 2]),
+            vec![
+                0xFF, 0xFF, 0xFD, 0x00, 0xFE, 0x09, 0x00, 0x82, 0x84, 0x00, 0x04, 0x00, 0x01, 0x02,
+                0xCE, 0xFA
+            ]
+        );
+        // Sync write of goal position 150 to ID 1 and 170 to ID 2
+        let packet = sync_write_packet(
+            116,
+            4,
+            &[
+                (1, &150u32.to_le_bytes()[..]),
+                (2, &170u32.to_le_bytes()[..]),
+            ],
+        );
+        assert_eq!(
+            packet,
+            vec![
+                0xFF, 0xFF, 0xFD, 0x00, 0xFE, 0x11, 0x00, 0x83, 0x74, 0x00, 0x04, 0x00, 0x01, 0x96,
+                0x00, 0x00, 0x00, 0x02, 0xAA, 0x00, 0x00, 0x00, 0x82, 0x87
+            ]
+        );
+    }
+
+    #[test]
+    fn test_status_round_trip_with_stuffing() {
+        let params = [0xFF, 0xFF, 0xFD, 0x01];
+        let mut buf = vec![0x00, 0x12]; // Line noise before the header
+        buf.extend(status(3, 0x80, &params));
+        // Stuffed parameters are one byte longer on the wire
+        assert_eq!(buf.len(), 2 + 11 + params.len() + 1);
+        buf.extend(status(4, 0, &[]));
+
+        let (first, used) = parse_status(&buf).unwrap().unwrap();
+        assert_eq!(first.id, 3);
+        assert!(first.hardware_alert());
+        assert_eq!(first.params, params);
+
+        let (second, _) = parse_status(&buf[used..]).unwrap().unwrap();
+        assert_eq!(second.id, 4);
+        assert_eq!(second.error_code(), 0);
+    }
+
+    #[test]
+    fn test_incomplete_and_corrupt_status() {
+        let packet = status(1, 0x04, &[1, 2]);
+        assert!(parse_status(&packet[..packet.len() - 1]).unwrap().is_none());
+
+        let (parsed, _) = parse_status(&packet).unwrap().unwrap();
+        assert_eq!(parsed.error_description(), "data range error");
+
+        let mut corrupt = packet.clone();
+        corrupt[9] ^= 0xFF;
+        assert!(parse_status(&corrupt).is_err());
+    }
+}
@@ -1,15 +1,19 @@
 //! Serial Dynamixel driver using serialport crate
 
-use std::io::{Read, Write};
+use std::io::{ErrorKind, Read, Write};
 use std::sync::Mutex;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
-use serialport::SerialPort;
+use serialport::{ClearBuffer, SerialPort};
 
 use horus_core::driver::DriverStatus;
 use horus_core::error::{HorusError, HorusResult};
 
-use super::{DynamixelCommand, DynamixelConfig, DynamixelMode, DynamixelProtocol};
+use super::protocol2::{self, control_table, StatusPacket};
+use super::{
+    DynamixelCommand, DynamixelConfig, DynamixelMode, DynamixelProtocol, DynamixelState,
+    MAX_SYNC_IDS,
+};
 
 /// Serial Dynamixel driver
 ///
@@ -41,22 +45,7 @@ impl SerialDynamixelDriver {
 
     /// Build a Dynamixel protocol 2.0 packet
     fn build_packet_v2(&self, id: u8, instruction: u8, params: &[u8]) -> Vec<u8> {
-        let length = (params.len() + 3) as u16;
-        let mut packet = vec![0xFF, 0xFF, 0xFD, 0x00, id];
-        packet.extend_from_slice(&length.to_le_bytes());
-        packet.push(instruction);
-        packet.extend_from_slice(params);
-        let crc = self.calculate_crc16(&packet);
-        packet.extend_from_slice(&crc.to_le_bytes());
-        packet
-    }
-
-    fn calculate_crc16(&self, data: &[u8]) -> u16 {
-        let mut crc: u16 = 0;
-        for &byte in data {
-            crc = crc.wrapping_add(byte as u16);
-        }
-        crc
+        protocol2::instruction_packet(id, instruction, params)
     }
 
     fn send_packet(&self, packet: &[u8]) -> HorusResult<()> {
@@ -76,6 +65,76 @@ impl SerialDynamixelDriver {
         Ok(())
     }
 
+    /// Send a Protocol 2.0 packet and collect `responses` status packets
+    ///
+    /// Servos that do not answer within the timeout are missing from the result.
+    fn transact(&self, packet: &[u8], responses: usize) -> HorusResult<Vec<StatusPacket>> {
+        let mut port_guard = self
+            .port
+            .lock()
+            .map_err(|_| HorusError::driver("Port lock poisoned"))?;
+        let port = port_guard
+            .as_mut()
+            .ok_or_else(|| HorusError::driver("Serial port not opened"))?;
+
+        let _ = port.clear(ClearBuffer::Input);
+        port.write_all(packet)
+            .map_err(|e| HorusError::driver(format!("Write failed: {}", e)))?;
+
+        let deadline = Instant::now() + Duration::from_millis(self.config.timeout_ms);
+        let mut buffer = Vec::new();
+        let mut chunk = [0u8; 256];
+        let mut packets = Vec::with_capacity(responses);
+        while packets.len() < responses && Instant::now() < deadline {
+            match port.read(&mut chunk) {
+                Ok(0) => break,
+                Ok(n) => buffer.extend_from_slice(&chunk[..n]),
+                Err(e) if e.kind() == ErrorKind::TimedOut => break,
+                Err(e) => return Err(HorusError::driver(format!("Read failed: {}", e))),
+            }
+            while let Some((status, used)) = protocol2::parse_status(&buffer)? {
+                buffer.drain(..used);
+                packets.push(status);
+            }
+        }
+        Ok(packets)
+    }
+
+    /// Write a register of one servo and check the status packet
+    fn write_register(&self, servo_id: u8, address: u16, data: &[u8]) -> HorusResult<()> {
+        let packet = protocol2::write_packet(servo_id, address, data);
+        let status = self.transact(&packet, 1)?;
+        match status.first() {
+            Some(status) if status.error_code() != 0 => Err(HorusError::driver(format!(
+                "Servo {} rejected write to {}: {}",
+                servo_id,
+                address,
+                status.error_description()
+            ))),
+            Some(_) => Ok(()),
+            None => Err(HorusError::driver(format!(
+                "Servo {} did not respond",
+                servo_id
+            ))),
+        }
+    }
+
+    fn require_v2(&self) -> HorusResult<()> {
+        if self.config.protocol != DynamixelProtocol::V2 {
+            return Err(HorusError::driver(
+                "Operation requires Dynamixel Protocol 2.0",
+            ));
+        }
+        let status = self
+            .status
+            .lock()
+            .map_err(|_| HorusError::driver("Lock poisoned"))?;
+        if !matches!(*status, DriverStatus::Ready | DriverStatus::Running) {
+            return Err(HorusError::driver("Driver not initialized"));
+        }
+        Ok(())
+    }
+
     fn write_goal_position(&self, servo_id: u8, position: u16) -> HorusResult<()> {
         let params = match self.config.protocol {
             DynamixelProtocol::V1 => vec![30, position as u8, (position >> 8) as u8],
@@ -121,7 +180,7 @@ impl SerialDynamixelDriver {
 
     pub fn init(&mut self) -> HorusResult<()> {
         let port = serialport::new(&self.config.port, self.config.baud_rate)
-            .timeout(Duration::from_millis(100))
+            .timeout(Duration::from_millis(self.config.timeout_ms.max(1)))
             .open()
             .map_err(|e| {
                 HorusError::driver(format!(
@@ -191,6 +250,18 @@ impl SerialDynamixelDriver {
 
         self.set_torque(cmd.servo_id, cmd.torque_enable)?;
 
+        if cmd.torque_enable && self.config.protocol == DynamixelProtocol::V2 {
+            // Command units are degrees (0-360 with 180 at center) and RPM
+            let goal = match cmd.mode {
+                DynamixelMode::Velocity => cmd.target / 60.0 * 2.0 * std::f64::consts::PI,
+                DynamixelMode::Current | DynamixelMode::PWM => cmd.target,
+                _ => (cmd.target - 180.0).to_radians(),
+            };
+            let (address, size) = cmd.mode.goal_register();
+            let raw = self.config.goal_to_raw(cmd.mode, goal).to_le_bytes();
+            return self.write_register(cmd.servo_id, address, &raw[..size as usize]);
+        }
+
         if cmd.torque_enable {
             match cmd.mode {
                 DynamixelMode::Position
@@ -199,7 +270,7 @@ impl SerialDynamixelDriver {
                     let raw_pos = ((cmd.target / 360.0) * 4095.0).clamp(0.0, 4095.0) as u16;
                     self.write_goal_position(cmd.servo_id, raw_pos)?;
                 }
-                DynamixelMode::Velocity | DynamixelMode::PWM => {
+                DynamixelMode::Velocity | DynamixelMode::PWM | DynamixelMode::Current => {
                     let raw_pos = ((cmd.target / 360.0) * 4095.0).clamp(0.0, 4095.0) as u16;
                     self.write_goal_position(cmd.servo_id, raw_pos)?;
                 }
@@ -216,4 +287,101 @@ impl SerialDynamixelDriver {
         }
         Ok(())
     }
+
+    // ========================================================================
+    // Protocol 2.0 methods
+    // ========================================================================
+
+    pub fn ping(&mut self, servo_id: u8) -> HorusResult<bool> {
+        self.require_v2()?;
+        let status = self.transact(&protocol2::ping_packet(servo_id), 1)?;
+        Ok(status.iter().any(|s| s.id == servo_id))
+    }
+
+    pub fn set_operating_mode(&mut self, servo_id: u8, mode: DynamixelMode) -> HorusResult<()> {
+        self.require_v2()?;
+        // The operating mode is in the EEPROM area, writable only with torque off
+        self.write_register(servo_id, control_table::TORQUE_ENABLE, &[0])?;
+        self.write_register(
+            servo_id,
+            control_table::OPERATING_MODE,
+            &[mode.operating_mode()],
+        )
+    }
+
+    pub fn set_torque_enabled(&mut self, servo_ids: &[u8], enable: bool) -> HorusResult<()> {
+        self.require_v2()?;
+        let value = [enable as u8];
+        let data: Vec<(u8, &[u8])> = servo_ids.iter().map(|&id| (id, &value[..])).collect();
+        let packet = protocol2::sync_write_packet(control_table::TORQUE_ENABLE, 1, &data);
+        // Broadcast packets are not answered
+        self.transact(&packet, 0)?;
+        Ok(())
+    }
+
+    pub fn sync_write_goals(
+        &mut self,
+        mode: DynamixelMode,
+        goals: &[(u8, f64)],
+    ) -> HorusResult<()> {
+        self.require_v2()?;
+        if goals.is_empty() {
+            return Ok(());
+        }
+        let (address, size) = mode.goal_register();
+        let raw: Vec<(u8, [u8; 4])> = goals
+            .iter()
+            .map(|&(id, goal)| (id, self.config.goal_to_raw(mode, goal).to_le_bytes()))
+            .collect();
+        let data: Vec<(u8, &[u8])> = raw
+            .iter()
+            .map(|(id, bytes)| (*id, &bytes[..size as usize]))
+            .collect();
+        let packet = protocol2::sync_write_packet(address, size, &data);
+        self.transact(&packet, 0)?;
+        *self
+            .status
+            .lock()
+            .map_err(|_| HorusError::driver("Lock poisoned"))? = DriverStatus::Running;
+        Ok(())
+    }
+
+    pub fn sync_read_state(&mut self, servo_ids: &[u8]) -> HorusResult<Vec<DynamixelState>> {
+        self.require_v2()?;
+        if servo_ids.len() > MAX_SYNC_IDS {
+            return Err(HorusError::driver(format!(
+                "Sync read supports at most {} servos",
+                MAX_SYNC_IDS
+            )));
+        }
+        // Present current (2), velocity (4) and position (4) are contiguous
+        let packet = protocol2::sync_read_packet(control_table::PRESENT_CURRENT, 10, servo_ids);
+        let status = self.transact(&packet, servo_ids.len())?;
+        Ok(status
+            .iter()
+            .filter(|s| s.error_code() == 0)
+            .filter_map(|s| self.config.decode_state(s.id, &s.params))
+            .collect())
+    }
+
+    pub fn bulk_read(&mut self, reads: &[(u8, u16, u16)]) -> HorusResult<Vec<(u8, Vec<u8>)>> {
+        self.require_v2()?;
+        if reads.len() > MAX_SYNC_IDS {
+            return Err(HorusError::driver(format!(
+                "Bulk read supports at most {} servos",
+                MAX_SYNC_IDS
+            )));
+        }
+        let packet = protocol2::bulk_read_packet(reads);
+        let status = self.transact(&packet, reads.len())?;
+        Ok(status
+            .into_iter()
+            .filter(|s| s.error_code() == 0)
+            .map(|s| (s.id, s.params))
+            .collect())
+    }
+
+    pub fn config(&self) -> &DynamixelConfig {
+        &self.config
+    }
 }
@@ -5,7 +5,8 @@ use std::collections::HashMap;
 use horus_core::driver::DriverStatus;
 use horus_core::error::{HorusError, HorusResult};
 
-use super::{DynamixelCommand, DynamixelConfig, DynamixelMode};
+use super::protocol2::control_table;
+use super::{DynamixelCommand, DynamixelConfig, DynamixelMode, DynamixelState, MAX_SYNC_IDS};
 
 /// Simulated servo state
 #[derive(Debug, Clone, Default)]
@@ -19,14 +20,17 @@ struct ServoState {
     temperature: f32, // Simulated temperature
     #[allow(dead_code)]
     voltage: f32, // Simulated voltage
-    load: f32, // Simulated load percentage
+    load: f32,    // Simulated load percentage
+    current: f64, // Simulated motor current in A
 }
 
+/// Size of the simulated control table (up to Present Temperature)
+const CONTROL_TABLE_SIZE: usize = 147;
+
 /// Simulation Dynamixel driver
 ///
 /// Simulates Dynamixel servo behavior without hardware.
 pub struct SimulationDynamixelDriver {
-    #[allow(dead_code)]
     config: DynamixelConfig,
     status: DriverStatus,
     /// Servo states by ID
@@ -50,6 +54,7 @@ impl SimulationDynamixelDriver {
                     temperature: 30.0,
                     voltage: 12.0,
                     load: 0.0,
+                    current: 0.0,
                 },
             );
         }
@@ -74,6 +79,7 @@ impl SimulationDynamixelDriver {
             servo.torque_enabled = false;
             servo.temperature = 30.0;
             servo.load = 0.0;
+            servo.current = 0.0;
         }
         self.status = DriverStatus::Ready;
         Ok(())
@@ -97,6 +103,10 @@ impl SimulationDynamixelDriver {
         self.status.clone()
     }
 
+    pub fn config(&self) -> &DynamixelConfig {
+        &self.config
+    }
+
     // ========================================================================
     // Actuator methods
     // ========================================================================
@@ -191,12 +201,174 @@ impl SimulationDynamixelDriver {
                     // PWM mode - direct duty cycle control
                     servo.velocity = servo.target * 100.0; // Approximate
                 }
+                DynamixelMode::Current => {
+                    // Unloaded motor: speed proportional to current
+                    servo.velocity = (servo.target * 50.0).clamp(-60.0, 60.0);
+                    servo.position += servo.velocity * 6.0 * dt;
+                    servo.position = servo.position.rem_euclid(360.0);
+                    servo.load = (servo.target.abs() * 20.0).min(100.0) as f32;
+                }
+            }
+
+            if servo.mode != DynamixelMode::Current {
+                servo.current = servo.load as f64 / 100.0 * servo.velocity.signum();
+            } else {
+                servo.current = servo.target;
             }
 
             // Simulate temperature increase based on load
             servo.temperature = 30.0 + servo.load * 0.2;
         }
     }
+
+    // ========================================================================
+    // Protocol 2.0 methods
+    // ========================================================================
+
+    pub fn ping(&mut self, servo_id: u8) -> HorusResult<bool> {
+        self.check_initialized()?;
+        Ok(self.servos.contains_key(&servo_id))
+    }
+
+    pub fn set_operating_mode(&mut self, servo_id: u8, mode: DynamixelMode) -> HorusResult<()> {
+        self.check_initialized()?;
+        let servo = self.servo_mut(servo_id)?;
+        servo.torque_enabled = false;
+        servo.mode = mode;
+        servo.target = match mode {
+            DynamixelMode::Position
+            | DynamixelMode::ExtendedPosition
+            | DynamixelMode::CurrentBasedPosition => servo.position,
+            _ => 0.0,
+        };
+        Ok(())
+    }
+
+    pub fn set_torque_enabled(&mut self, servo_ids: &[u8], enable: bool) -> HorusResult<()> {
+        self.check_initialized()?;
+        for &id in servo_ids {
+            self.servo_mut(id)?.torque_enabled = enable;
+        }
+        Ok(())
+    }
+
+    pub fn sync_write_goals(
+        &mut self,
+        mode: DynamixelMode,
+        goals: &[(u8, f64)],
+    ) -> HorusResult<()> {
+        self.check_initialized()?;
+        self.status = DriverStatus::Running;
+        let (address, _) = mode.goal_register();
+        for &(id, goal) in goals {
+            let servo = self.servo_mut(id)?;
+            // Like the real control table, a goal only acts in its own mode
+            if servo.mode.goal_register().0 != address {
+                continue;
+            }
+            servo.target = match mode {
+                DynamixelMode::Position => (goal.to_degrees() + 180.0).clamp(0.0, 360.0),
+                DynamixelMode::ExtendedPosition | DynamixelMode::CurrentBasedPosition => {
+                    goal.to_degrees() + 180.0
+                }
+                DynamixelMode::Velocity => goal * 60.0 / (2.0 * std::f64::consts::PI),
+                DynamixelMode::Current | DynamixelMode::PWM => goal,
+            };
+        }
+        Ok(())
+    }
+
+    pub fn sync_read_state(&mut self, servo_ids: &[u8]) -> HorusResult<Vec<DynamixelState>> {
+        if servo_ids.len() > MAX_SYNC_IDS {
+            return Err(HorusError::driver(format!(
+                "Sync read supports at most {} servos",
+                MAX_SYNC_IDS
+            )));
+        }
+        let reads: Vec<(u8, u16, u16)> = servo_ids
+            .iter()
+            .map(|&id| (id, control_table::PRESENT_CURRENT, 10))
+            .collect();
+        Ok(self
+            .bulk_read(&reads)?
+            .into_iter()
+            .filter_map(|(id, data)| self.config.decode_state(id, &data))
+            .collect())
+    }
+
+    pub fn bulk_read(&mut self, reads: &[(u8, u16, u16)]) -> HorusResult<Vec<(u8, Vec<u8>)>> {
+        self.check_initialized()?;
+        self.status = DriverStatus::Running;
+        let mut result = Vec::with_capacity(reads.len());
+        for &(id, address, length) in reads {
+            // Unknown IDs do not answer
+            let Some(servo) = self.servos.get(&id) else {
+                continue;
+            };
+            let table = self.control_table(servo);
+            let range = address as usize..address as usize + length as usize;
+            let data = table.get(range).ok_or_else(|| {
+                HorusError::driver(format!(
+                    "Read of {} bytes at {} outside the control table",
+                    length, address
+                ))
+            })?;
+            result.push((id, data.to_vec()));
+        }
+        Ok(result)
+    }
+
+    /// Control table of a simulated X-series servo
+    fn control_table(&self, servo: &ServoState) -> [u8; CONTROL_TABLE_SIZE] {
+        let mut table = [0u8; CONTROL_TABLE_SIZE];
+        let current = (servo.current * 1000.0 / self.config.current_unit_ma).round() as i16;
+        let velocity = (servo.velocity / 0.229).round() as i32;
+        let position = (servo.position / 360.0 * 4096.0).round() as i32;
+        let voltage = (servo.voltage * 10.0).round() as u16;
+
+        table[control_table::OPERATING_MODE as usize] = servo.mode.operating_mode();
+        table[control_table::TORQUE_ENABLE as usize] = servo.torque_enabled as u8;
+        write_le(
+            &mut table,
+            control_table::PRESENT_CURRENT,
+            &current.to_le_bytes(),
+        );
+        write_le(
+            &mut table,
+            control_table::PRESENT_VELOCITY,
+            &velocity.to_le_bytes(),
+        );
+        write_le(
+            &mut table,
+            control_table::PRESENT_POSITION,
+            &position.to_le_bytes(),
+        );
+        write_le(
+            &mut table,
+            control_table::PRESENT_INPUT_VOLTAGE,
+            &voltage.to_le_bytes(),
+        );
+        table[control_table::PRESENT_TEMPERATURE as usize] = servo.temperature as u8;
+        table
+    }
+
+    fn servo_mut(&mut self, servo_id: u8) -> HorusResult<&mut ServoState> {
+        self.servos
+            .get_mut(&servo_id)
+            .ok_or_else(|| HorusError::driver(format!("Servo ID {} not configured", servo_id)))
+    }
+
+    fn check_initialized(&self) -> HorusResult<()> {
+        if !matches!(self.status, DriverStatus::Ready | DriverStatus::Running) {
+            return Err(HorusError::driver("Driver not initialized"));
+        }
+        Ok(())
+    }
+}
+
+fn write_le(table: &mut [u8], address: u16, bytes: &[u8]) {
+    let start = address as usize;
+    table[start..start + bytes.len()].copy_from_slice(bytes);
 }
 
 impl Default for SimulationDynamixelDriver {
@@ -204,3 +376,67 @@ impl Default for SimulationDynamixelDriver {
         Self::new(DynamixelConfig::default())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn driver() -> SimulationDynamixelDriver {
+        let mut driver = SimulationDynamixelDriver::new(DynamixelConfig {
+            servo_ids: vec![1, 2],
+            ..Default::default()
+        });
+        driver.init().unwrap();
+        driver
+    }
+
+    #[test]
+    fn test_sync_write_and_read_position() {
+        let mut driver = driver();
+        driver.set_torque_enabled(&[1, 2], true).unwrap();
+        driver
+            .sync_write_goals(DynamixelMode::Position, &[(1, 0.5), (2, -0.25)])
+            .unwrap();
+        for _ in 0..100 {
+            driver.simulate_tick(0.1);
+        }
+
+        let states = driver.sync_read_state(&[1, 2, 3]).unwrap();
+        // ID 3 does not exist and does not answer
+        assert_eq!(states.len(), 2);
+        assert!((states[0].position - 0.5).abs() < 0.002);
+        assert!((states[1].position + 0.25).abs() < 0.002);
+        assert!(driver.sync_read_state(&[1; MAX_SYNC_IDS + 1]).is_err());
+    }
+
+    #[test]
+    fn test_velocity_and_current_modes() {
+        let mut driver = driver();
+        driver
+            .set_operating_mode(1, DynamixelMode::Velocity)
+            .unwrap();
+        driver
+            .set_operating_mode(2, DynamixelMode::Current)
+            .unwrap();
+        driver.set_torque_enabled(&[1, 2], true).unwrap();
+
+        driver
+            .sync_write_goals(DynamixelMode::Velocity, &[(1, 1.0), (2, 5.0)])
+            .unwrap();
+        driver
+            .sync_write_goals(DynamixelMode::Current, &[(2, 0.4)])
+            .unwrap();
+        driver.simulate_tick(0.01);
+
+        let states = driver.sync_read_state(&[1, 2]).unwrap();
+        // Velocity resolution is 0.229 RPM
+        assert!((states[0].velocity - 1.0).abs() < 0.03);
+        assert!((states[1].current - 0.4).abs() < 0.003);
+
+        let table = driver
+            .bulk_read(&[(1, control_table::OPERATING_MODE, 1), (2, 64, 1)])
+            .unwrap();
+        assert_eq!(table, vec![(1, vec![1]), (2, vec![1])]);
+        assert!(driver.bulk_read(&[(1, 140, 10)]).is_err());
+    }
+}
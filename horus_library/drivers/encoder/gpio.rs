@@ -1,20 +1,28 @@
 //! GPIO Encoder driver
 //!
-//! Quadrature encoder driver using GPIO pins.
+//! Quadrature encoder driver using two GPIO pins of any [`GpioHal`] backend.
+//! A background thread polls both channels and decodes every edge (x4), so
+//! one revolution is `4 * ppr` counts. The poll interval bounds the highest
+//! edge rate that can be followed.
 //! Requires the `gpio-hardware` feature.
 
 use horus_core::driver::DriverStatus;
 use horus_core::error::{HorusError, HorusResult};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use crate::drivers::gpio::{GpioDriver, GpioHal, PinMode};
 use crate::Odometry;
 
+/// Count change for each (previous AB, current AB) state pair
+const QUADRATURE_TABLE: [i8; 16] = [0, -1, 1, 0, 1, 0, 0, -1, -1, 0, 0, 1, 0, 1, -1, 0];
+
 /// GPIO encoder configuration
 #[derive(Debug, Clone)]
 pub struct GpioEncoderConfig {
-    /// GPIO chip (e.g., "/dev/gpiochip0")
-    pub chip: String,
-    /// Channel A pin number
+    /// Channel A pin number (backend numbering, e.g. BCM on a Raspberry Pi)
     pub pin_a: u32,
     /// Channel B pin number
     pub pin_b: u32,
@@ -22,60 +30,168 @@ pub struct GpioEncoderConfig {
     pub ppr: u32,
     /// Wheel radius in meters (for distance calculation)
     pub wheel_radius: f64,
+    /// Input mode of both channels (open-collector encoders need a pull-up)
+    pub pull: PinMode,
+    /// Poll interval of the channels in microseconds
+    pub poll_interval_us: u64,
 }
 
 impl Default for GpioEncoderConfig {
     fn default() -> Self {
         Self {
-            chip: "/dev/gpiochip0".to_string(),
             pin_a: 17,
             pin_b: 27,
             ppr: 1024,
             wheel_radius: 0.05,
+            pull: PinMode::InputPullUp,
+            poll_interval_us: 100,
         }
     }
 }
 
+/// Quadrature decoder of the A/B channel levels
+#[derive(Debug, Default)]
+struct QuadratureDecoder {
+    state: u8,
+}
+
+impl QuadratureDecoder {
+    fn new(a: bool, b: bool) -> Self {
+        Self {
+            state: Self::encode(a, b),
+        }
+    }
+
+    fn encode(a: bool, b: bool) -> u8 {
+        (u8::from(a) << 1) | u8::from(b)
+    }
+
+    /// Count change since the previous levels (0 for a skipped state)
+    fn update(&mut self, a: bool, b: bool) -> i64 {
+        let state = Self::encode(a, b);
+        let delta = QUADRATURE_TABLE[((self.state << 2) | state) as usize];
+        self.state = state;
+        delta as i64
+    }
+}
+
 /// GPIO encoder driver
 pub struct GpioEncoderDriver {
     config: GpioEncoderConfig,
     status: DriverStatus,
-    count: i64,
+    /// GPIO backend while the polling thread is not running
+    gpio: Option<Box<dyn GpioHal>>,
+    count: Arc<AtomicI64>,
     last_count: i64,
+    running: Arc<AtomicBool>,
+    /// Polling thread, hands the GPIO backend back when it stops
+    thread: Option<JoinHandle<Box<dyn GpioHal>>>,
 }
 
 impl GpioEncoderDriver {
     /// Create a new GPIO encoder driver
     pub fn new() -> HorusResult<Self> {
-        Ok(Self {
-            config: GpioEncoderConfig::default(),
-            status: DriverStatus::Uninitialized,
-            count: 0,
-            last_count: 0,
-        })
+        Self::with_config(GpioEncoderConfig::default())
     }
 
     /// Create with custom configuration
+    ///
+    /// The GPIO of the board is detected on `init`.
     pub fn with_config(config: GpioEncoderConfig) -> HorusResult<Self> {
         Ok(Self {
             config,
             status: DriverStatus::Uninitialized,
-            count: 0,
+            gpio: None,
+            count: Arc::new(AtomicI64::new(0)),
             last_count: 0,
+            running: Arc::new(AtomicBool::new(false)),
+            thread: None,
         })
     }
 
+    /// Create with custom configuration and GPIO backend
+    pub fn with_gpio(config: GpioEncoderConfig, gpio: impl GpioHal + 'static) -> HorusResult<Self> {
+        let mut driver = Self::with_config(config)?;
+        driver.gpio = Some(Box::new(gpio));
+        Ok(driver)
+    }
+
     /// Initialize the driver
     pub fn init(&mut self) -> HorusResult<()> {
-        // GPIO setup would go here using gpio_cdev or similar
-        self.count = 0;
+        if self.thread.is_some() {
+            return Ok(());
+        }
+        if !self.config.pull.is_input() {
+            return Err(HorusError::config("Encoder pins must be inputs"));
+        }
+
+        let mut gpio = match self.gpio.take() {
+            Some(gpio) => gpio,
+            None => {
+                let detected = GpioDriver::detect();
+                if !detected.is_hardware() {
+                    self.status = DriverStatus::Error("No GPIO hardware detected".to_string());
+                    return Err(HorusError::driver("No GPIO hardware detected"));
+                }
+                Box::new(detected)
+            }
+        };
+
+        let (pin_a, pin_b) = (self.config.pin_a, self.config.pin_b);
+        let pull = self.config.pull;
+        let mut setup = || -> HorusResult<(bool, bool)> {
+            gpio.setup_pin(pin_a, pull, false)?;
+            gpio.setup_pin(pin_b, pull, false)?;
+            Ok((gpio.read_pin(pin_a)?, gpio.read_pin(pin_b)?))
+        };
+        let (a, b) = match setup() {
+            Ok(levels) => levels,
+            Err(e) => {
+                self.status = DriverStatus::Error(e.to_string());
+                self.gpio = Some(gpio);
+                return Err(e);
+            }
+        };
+
+        self.count.store(0, Ordering::Relaxed);
         self.last_count = 0;
+        self.running.store(true, Ordering::Relaxed);
+
+        let count = self.count.clone();
+        let running = self.running.clone();
+        let interval = Duration::from_micros(self.config.poll_interval_us);
+        let handle = thread::Builder::new()
+            .name("gpio_encoder".to_string())
+            .spawn(move || {
+                let mut decoder = QuadratureDecoder::new(a, b);
+                while running.load(Ordering::Relaxed) {
+                    if let (Ok(a), Ok(b)) = (gpio.read_pin(pin_a), gpio.read_pin(pin_b)) {
+                        let delta = decoder.update(a, b);
+                        if delta != 0 {
+                            count.fetch_add(delta, Ordering::Relaxed);
+                        }
+                    }
+                    thread::sleep(interval);
+                }
+                gpio
+            })
+            .map_err(|e| HorusError::driver(format!("Failed to start encoder thread: {}", e)))?;
+        self.thread = Some(handle);
         self.status = DriverStatus::Ready;
         Ok(())
     }
 
     /// Shutdown the driver
     pub fn shutdown(&mut self) -> HorusResult<()> {
+        self.running.store(false, Ordering::Relaxed);
+        if let Some(handle) = self.thread.take() {
+            let mut gpio = handle
+                .join()
+                .map_err(|_| HorusError::driver("Encoder thread panicked"))?;
+            let _ = gpio.release_pin(self.config.pin_a);
+            let _ = gpio.release_pin(self.config.pin_b);
+            self.gpio = Some(gpio);
+        }
         self.status = DriverStatus::Shutdown;
         Ok(())
     }
@@ -92,16 +208,19 @@ impl GpioEncoderDriver {
 
     /// Read odometry
     pub fn read(&mut self) -> HorusResult<Odometry> {
+        if self.thread.is_none() {
+            return Err(HorusError::driver("GPIO encoder is not initialized"));
+        }
         self.status = DriverStatus::Running;
 
-        let delta = self.count - self.last_count;
-        self.last_count = self.count;
+        let count = self.count.load(Ordering::Relaxed);
+        let delta = count - self.last_count;
+        self.last_count = count;
 
-        // Calculate distance traveled
-        let distance = (delta as f64 / self.config.ppr as f64)
-            * 2.0
-            * std::f64::consts::PI
-            * self.config.wheel_radius;
+        // Calculate distance traveled (x4 decoding)
+        let counts_per_rev = 4.0 * self.config.ppr as f64;
+        let distance =
+            (delta as f64 / counts_per_rev) * 2.0 * std::f64::consts::PI * self.config.wheel_radius;
 
         let timestamp = SystemTime::now()
             .duration_since(UNIX_EPOCH)
@@ -140,20 +259,53 @@ impl GpioEncoderDriver {
         None // Hardware-driven
     }
 
-    /// Get current encoder count
+    /// Get current encoder count (4 per pulse)
     pub fn get_count(&self) -> i64 {
-        self.count
+        self.count.load(Ordering::Relaxed)
     }
 
     /// Reset encoder count
     pub fn reset(&mut self) {
-        self.count = 0;
+        self.count.store(0, Ordering::Relaxed);
         self.last_count = 0;
     }
 }
 
+impl Drop for GpioEncoderDriver {
+    fn drop(&mut self) {
+        let _ = self.shutdown();
+    }
+}
+
 impl Default for GpioEncoderDriver {
     fn default() -> Self {
         Self::new().expect("Failed to create GPIO encoder driver")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quadrature_decoder() {
+        // A leads B: 00 -> 10 -> 11 -> 01 -> 00
+        let forward = [(true, false), (true, true), (false, true), (false, false)];
+        let mut decoder = QuadratureDecoder::new(false, false);
+        let counts: i64 = forward.iter().map(|&(a, b)| decoder.update(a, b)).sum();
+        assert_eq!(counts, 4);
+
+        let counts: i64 = forward
+            .iter()
+            .rev()
+            .skip(1)
+            .chain([(false, false)].iter())
+            .map(|&(a, b)| decoder.update(a, b))
+            .sum();
+        assert_eq!(counts, -4);
+
+        // No change and skipped states do not count
+        assert_eq!(decoder.update(false, false), 0);
+        assert_eq!(decoder.update(true, true), 0);
+    }
+}
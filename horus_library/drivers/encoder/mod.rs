@@ -10,7 +10,7 @@ mod gpio;
 pub use simulation::SimulationEncoderDriver;
 
 #[cfg(feature = "gpio-hardware")]
-pub use gpio::GpioEncoderDriver;
+pub use gpio::{GpioEncoderConfig, GpioEncoderDriver};
 
 use horus_core::driver::DriverStatus;
 use horus_core::error::HorusResult;
@@ -0,0 +1,426 @@
+//! EtherCAT master drivers
+//!
+//! This module provides drivers for an EtherCAT master with cyclic process
+//! data (PDO) exchange and mailbox (CoE SDO) access.
+//!
+//! # Available Drivers
+//!
+//! - `SimulationEthercatDriver` - Always available, in-memory process image
+//! - `SoemEthercatDriver` - SOEM master on a raw Ethernet interface (requires `ethercat-hardware` feature)
+//!
+//! # Process Image
+//!
+//! Slaves are configured in bus order. The process image holds the outputs
+//! of all slaves followed by the inputs of all slaves, matching the layout
+//! produced by SOEM's `ec_config_map`. [`ProcessImageLayout`] gives the
+//! offsets of each slave's data.
+
+mod simulation;
+
+#[cfg(feature = "ethercat-hardware")]
+mod soem;
+
+pub use simulation::SimulationEthercatDriver;
+
+#[cfg(feature = "ethercat-hardware")]
+pub use soem::SoemEthercatDriver;
+
+use std::ops::Range;
+
+use horus_core::driver::DriverStatus;
+use horus_core::error::{HorusError, HorusResult};
+
+/// SDO download applied while the slave is in PRE-OP
+#[derive(Debug, Clone, PartialEq)]
+pub struct SdoWrite {
+    /// Object index
+    pub index: u16,
+    /// Object subindex
+    pub subindex: u8,
+    /// Little-endian object data
+    pub data: Vec<u8>,
+}
+
+impl SdoWrite {
+    pub fn u8(index: u16, subindex: u8, value: u8) -> Self {
+        Self {
+            index,
+            subindex,
+            data: vec![value],
+        }
+    }
+
+    pub fn u16(index: u16, subindex: u8, value: u16) -> Self {
+        Self {
+            index,
+            subindex,
+            data: value.to_le_bytes().to_vec(),
+        }
+    }
+
+    pub fn u32(index: u16, subindex: u8, value: u32) -> Self {
+        Self {
+            index,
+            subindex,
+            data: value.to_le_bytes().to_vec(),
+        }
+    }
+}
+
+/// Configuration of one slave on the bus
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EthercatSlaveConfig {
+    /// Name used in log and error messages
+    pub name: String,
+    /// Output (master to slave) process data size in bytes
+    pub output_bytes: usize,
+    /// Input (slave to master) process data size in bytes
+    pub input_bytes: usize,
+    /// SDO writes applied in PRE-OP, in order (PDO mapping, mode of operation, ...)
+    pub sdo_init: Vec<SdoWrite>,
+}
+
+/// EtherCAT master configuration
+#[derive(Debug, Clone)]
+pub struct EthercatConfig {
+    /// Network interface connected to the bus
+    pub interface: String,
+    /// Process data cycle time in microseconds
+    pub cycle_time_us: u64,
+    /// Process data receive timeout in microseconds
+    pub receive_timeout_us: u64,
+    /// Timeout for state transitions in milliseconds
+    pub state_timeout_ms: u64,
+    /// Expected slaves in bus order
+    pub slaves: Vec<EthercatSlaveConfig>,
+}
+
+impl Default for EthercatConfig {
+    fn default() -> Self {
+        Self {
+            interface: "eth0".to_string(),
+            cycle_time_us: 1000,
+            receive_timeout_us: 500,
+            state_timeout_ms: 2000,
+            slaves: Vec::new(),
+        }
+    }
+}
+
+/// EtherCAT slave state machine state (AL state)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EthercatState {
+    #[default]
+    Init,
+    PreOp,
+    Boot,
+    SafeOp,
+    Op,
+}
+
+impl EthercatState {
+    /// AL state register value
+    pub fn code(&self) -> u16 {
+        match self {
+            Self::Init => 0x01,
+            Self::PreOp => 0x02,
+            Self::Boot => 0x03,
+            Self::SafeOp => 0x04,
+            Self::Op => 0x08,
+        }
+    }
+
+    /// State from an AL state register value (error flag ignored)
+    pub fn from_code(code: u16) -> Option<Self> {
+        match code & 0x0F {
+            0x01 => Some(Self::Init),
+            0x02 => Some(Self::PreOp),
+            0x03 => Some(Self::Boot),
+            0x04 => Some(Self::SafeOp),
+            0x08 => Some(Self::Op),
+            _ => None,
+        }
+    }
+}
+
+/// Offsets of each slave's data in the process image
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProcessImageLayout {
+    outputs: Vec<Range<usize>>,
+    inputs: Vec<Range<usize>>,
+    size: usize,
+}
+
+impl ProcessImageLayout {
+    pub fn new(slaves: &[EthercatSlaveConfig]) -> Self {
+        let mut offset = 0;
+        let mut outputs = Vec::with_capacity(slaves.len());
+        for slave in slaves {
+            outputs.push(offset..offset + slave.output_bytes);
+            offset += slave.output_bytes;
+        }
+        let mut inputs = Vec::with_capacity(slaves.len());
+        for slave in slaves {
+            inputs.push(offset..offset + slave.input_bytes);
+            offset += slave.input_bytes;
+        }
+        Self {
+            outputs,
+            inputs,
+            size: offset,
+        }
+    }
+
+    /// Total process image size in bytes
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Total output size in bytes
+    pub fn output_size(&self) -> usize {
+        self.outputs.last().map(|r| r.end).unwrap_or(0)
+    }
+
+    pub fn outputs(&self, slave: usize) -> Option<Range<usize>> {
+        self.outputs.get(slave).cloned()
+    }
+
+    pub fn inputs(&self, slave: usize) -> Option<Range<usize>> {
+        self.inputs.get(slave).cloned()
+    }
+
+    /// Working counter of a complete exchange
+    ///
+    /// Each slave with outputs counts 1 for the write, each slave with
+    /// inputs counts 1 for the read; a slave with both counts 3 (LRW).
+    pub fn expected_wkc(&self) -> u16 {
+        let outputs = self.outputs.iter().filter(|r| !r.is_empty()).count();
+        let inputs = self.inputs.iter().filter(|r| !r.is_empty()).count();
+        (outputs * 2 + inputs) as u16
+    }
+}
+
+/// Process image shared by the driver backends
+#[derive(Debug, Clone, Default)]
+pub struct ProcessImage {
+    layout: ProcessImageLayout,
+    data: Vec<u8>,
+}
+
+impl ProcessImage {
+    pub fn new(slaves: &[EthercatSlaveConfig]) -> Self {
+        let layout = ProcessImageLayout::new(slaves);
+        let data = vec![0; layout.size()];
+        Self { layout, data }
+    }
+
+    pub fn layout(&self) -> &ProcessImageLayout {
+        &self.layout
+    }
+
+    /// Whole image, outputs first
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    pub fn outputs(&self, slave: usize) -> HorusResult<&[u8]> {
+        let range = self.layout.outputs(slave).ok_or_else(|| no_slave(slave))?;
+        Ok(&self.data[range])
+    }
+
+    pub fn outputs_mut(&mut self, slave: usize) -> HorusResult<&mut [u8]> {
+        let range = self.layout.outputs(slave).ok_or_else(|| no_slave(slave))?;
+        Ok(&mut self.data[range])
+    }
+
+    pub fn inputs(&self, slave: usize) -> HorusResult<&[u8]> {
+        let range = self.layout.inputs(slave).ok_or_else(|| no_slave(slave))?;
+        Ok(&self.data[range])
+    }
+
+    pub fn inputs_mut(&mut self, slave: usize) -> HorusResult<&mut [u8]> {
+        let range = self.layout.inputs(slave).ok_or_else(|| no_slave(slave))?;
+        Ok(&mut self.data[range])
+    }
+}
+
+fn no_slave(slave: usize) -> HorusError {
+    HorusError::driver(format!("EtherCAT slave {} not configured", slave))
+}
+
+/// EtherCAT driver backend selection
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum EthercatDriverBackend {
+    #[default]
+    Simulation,
+    #[cfg(feature = "ethercat-hardware")]
+    Soem,
+}
+
+/// Type-erased EtherCAT driver
+pub enum EthercatDriver {
+    Simulation(SimulationEthercatDriver),
+    #[cfg(feature = "ethercat-hardware")]
+    Soem(SoemEthercatDriver),
+}
+
+impl EthercatDriver {
+    pub fn new(backend: EthercatDriverBackend, config: EthercatConfig) -> HorusResult<Self> {
+        match backend {
+            EthercatDriverBackend::Simulation => {
+                Ok(Self::Simulation(SimulationEthercatDriver::new(config)))
+            }
+            #[cfg(feature = "ethercat-hardware")]
+            EthercatDriverBackend::Soem => Ok(Self::Soem(SoemEthercatDriver::new(config)?)),
+        }
+    }
+
+    pub fn simulation() -> Self {
+        Self::Simulation(SimulationEthercatDriver::new(EthercatConfig::default()))
+    }
+
+    // ========================================================================
+    // Lifecycle methods
+    // ========================================================================
+
+    /// Scan the bus, apply the SDO init lists, map the process data and
+    /// bring all slaves to OP
+    pub fn init(&mut self) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.init(),
+            #[cfg(feature = "ethercat-hardware")]
+            Self::Soem(d) => d.init(),
+        }
+    }
+
+    /// Return all slaves to INIT and close the interface
+    pub fn shutdown(&mut self) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.shutdown(),
+            #[cfg(feature = "ethercat-hardware")]
+            Self::Soem(d) => d.shutdown(),
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        match self {
+            Self::Simulation(d) => d.is_available(),
+            #[cfg(feature = "ethercat-hardware")]
+            Self::Soem(d) => d.is_available(),
+        }
+    }
+
+    pub fn status(&self) -> DriverStatus {
+        match self {
+            Self::Simulation(d) => d.status(),
+            #[cfg(feature = "ethercat-hardware")]
+            Self::Soem(d) => d.status(),
+        }
+    }
+
+    pub fn config(&self) -> &EthercatConfig {
+        match self {
+            Self::Simulation(d) => d.config(),
+            #[cfg(feature = "ethercat-hardware")]
+            Self::Soem(d) => d.config(),
+        }
+    }
+
+    // ========================================================================
+    // Process data methods
+    // ========================================================================
+
+    /// Send the outputs and receive the inputs of all slaves
+    ///
+    /// Returns the working counter; compare against
+    /// [`ProcessImageLayout::expected_wkc`] to detect missing slaves.
+    pub fn exchange(&mut self) -> HorusResult<u16> {
+        match self {
+            Self::Simulation(d) => d.exchange(),
+            #[cfg(feature = "ethercat-hardware")]
+            Self::Soem(d) => d.exchange(),
+        }
+    }
+
+    pub fn layout(&self) -> &ProcessImageLayout {
+        match self {
+            Self::Simulation(d) => d.image().layout(),
+            #[cfg(feature = "ethercat-hardware")]
+            Self::Soem(d) => d.image().layout(),
+        }
+    }
+
+    /// Output process data of a slave, sent on the next exchange
+    pub fn outputs_mut(&mut self, slave: usize) -> HorusResult<&mut [u8]> {
+        match self {
+            Self::Simulation(d) => d.image_mut().outputs_mut(slave),
+            #[cfg(feature = "ethercat-hardware")]
+            Self::Soem(d) => d.image_mut().outputs_mut(slave),
+        }
+    }
+
+    /// Input process data of a slave from the last exchange
+    pub fn inputs(&self, slave: usize) -> HorusResult<&[u8]> {
+        match self {
+            Self::Simulation(d) => d.image().inputs(slave),
+            #[cfg(feature = "ethercat-hardware")]
+            Self::Soem(d) => d.image().inputs(slave),
+        }
+    }
+
+    // ========================================================================
+    // EtherCAT-specific methods
+    // ========================================================================
+
+    /// Lowest AL state of all slaves
+    pub fn state(&mut self) -> HorusResult<EthercatState> {
+        match self {
+            Self::Simulation(d) => d.state(),
+            #[cfg(feature = "ethercat-hardware")]
+            Self::Soem(d) => d.state(),
+        }
+    }
+
+    /// Number of slaves found on the bus
+    pub fn slave_count(&self) -> usize {
+        match self {
+            Self::Simulation(d) => d.slave_count(),
+            #[cfg(feature = "ethercat-hardware")]
+            Self::Soem(d) => d.slave_count(),
+        }
+    }
+
+    /// CoE SDO download (slave index in bus order, starting at 0)
+    pub fn sdo_write(
+        &mut self,
+        slave: usize,
+        index: u16,
+        subindex: u8,
+        data: &[u8],
+    ) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.sdo_write(slave, index, subindex, data),
+            #[cfg(feature = "ethercat-hardware")]
+            Self::Soem(d) => d.sdo_write(slave, index, subindex, data),
+        }
+    }
+
+    /// CoE SDO upload of at most `max_len` bytes
+    pub fn sdo_read(
+        &mut self,
+        slave: usize,
+        index: u16,
+        subindex: u8,
+        max_len: usize,
+    ) -> HorusResult<Vec<u8>> {
+        match self {
+            Self::Simulation(d) => d.sdo_read(slave, index, subindex, max_len),
+            #[cfg(feature = "ethercat-hardware")]
+            Self::Soem(d) => d.sdo_read(slave, index, subindex, max_len),
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! Simulation EtherCAT driver
+
+use std::collections::HashMap;
+
+use horus_core::driver::DriverStatus;
+use horus_core::error::{HorusError, HorusResult};
+
+use super::{EthercatConfig, EthercatState, ProcessImage};
+
+/// Simulation EtherCAT driver
+///
+/// Holds the process image in memory. Outputs stay where the node wrote
+/// them and inputs are only changed through [`image_mut`](Self::image_mut),
+/// so tests can play the role of the slaves. SDOs are stored per slave.
+pub struct SimulationEthercatDriver {
+    config: EthercatConfig,
+    status: DriverStatus,
+    image: ProcessImage,
+    state: EthercatState,
+    /// Object dictionary per (slave, index, subindex)
+    objects: HashMap<(usize, u16, u8), Vec<u8>>,
+    /// Slaves answer process data frames
+    responding: bool,
+}
+
+impl SimulationEthercatDriver {
+    pub fn new(config: EthercatConfig) -> Self {
+        let image = ProcessImage::new(&config.slaves);
+        Self {
+            config,
+            status: DriverStatus::Uninitialized,
+            image,
+            state: EthercatState::Init,
+            objects: HashMap::new(),
+            responding: true,
+        }
+    }
+
+    // ========================================================================
+    // Lifecycle methods
+    // ========================================================================
+
+    pub fn init(&mut self) -> HorusResult<()> {
+        self.objects.clear();
+        self.state = EthercatState::PreOp;
+        for (slave, config) in self.config.slaves.iter().enumerate() {
+            for sdo in &config.sdo_init {
+                self.objects
+                    .insert((slave, sdo.index, sdo.subindex), sdo.data.clone());
+            }
+        }
+        self.image = ProcessImage::new(&self.config.slaves);
+        self.state = EthercatState::Op;
+        self.status = DriverStatus::Ready;
+        Ok(())
+    }
+
+    pub fn shutdown(&mut self) -> HorusResult<()> {
+        self.state = EthercatState::Init;
+        self.status = DriverStatus::Shutdown;
+        Ok(())
+    }
+
+    pub fn is_available(&self) -> bool {
+        true // Simulation is always available
+    }
+
+    pub fn status(&self) -> DriverStatus {
+        self.status.clone()
+    }
+
+    pub fn config(&self) -> &EthercatConfig {
+        &self.config
+    }
+
+    // ========================================================================
+    // Process data methods
+    // ========================================================================
+
+    pub fn exchange(&mut self) -> HorusResult<u16> {
+        self.check_initialized()?;
+        self.status = DriverStatus::Running;
+        if !self.responding {
+            return Ok(0);
+        }
+        Ok(self.image.layout().expected_wkc())
+    }
+
+    pub fn image(&self) -> &ProcessImage {
+        &self.image
+    }
+
+    pub fn image_mut(&mut self) -> &mut ProcessImage {
+        &mut self.image
+    }
+
+    /// Simulate a cable break: exchanges return a working counter of 0
+    pub fn set_responding(&mut self, responding: bool) {
+        self.responding = responding;
+    }
+
+    // ========================================================================
+    // EtherCAT-specific methods
+    // ========================================================================
+
+    pub fn state(&mut self) -> HorusResult<EthercatState> {
+        Ok(self.state)
+    }
+
+    pub fn slave_count(&self) -> usize {
+        self.config.slaves.len()
+    }
+
+    pub fn sdo_write(
+        &mut self,
+        slave: usize,
+        index: u16,
+        subindex: u8,
+        data: &[u8],
+    ) -> HorusResult<()> {
+        self.check_initialized()?;
+        self.check_slave(slave)?;
+        self.objects.insert((slave, index, subindex), data.to_vec());
+        Ok(())
+    }
+
+    pub fn sdo_read(
+        &mut self,
+        slave: usize,
+        index: u16,
+        subindex: u8,
+        max_len: usize,
+    ) -> HorusResult<Vec<u8>> {
+        self.check_initialized()?;
+        self.check_slave(slave)?;
+        let mut data = self
+            .objects
+            .get(&(slave, index, subindex))
+            .cloned()
+            .ok_or_else(|| {
+                HorusError::driver(format!(
+                    "SDO 0x{:04X}:{} not found on slave {}",
+                    index, subindex, slave
+                ))
+            })?;
+        data.truncate(max_len);
+        Ok(data)
+    }
+
+    fn check_initialized(&self) -> HorusResult<()> {
+        if !matches!(self.status, DriverStatus::Ready | DriverStatus::Running) {
+            return Err(HorusError::driver("Driver not initialized"));
+        }
+        Ok(())
+    }
+
+    fn check_slave(&self, slave: usize) -> HorusResult<()> {
+        if slave >= self.config.slaves.len() {
+            return Err(HorusError::driver(format!(
+                "EtherCAT slave {} not configured",
+                slave
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drivers::ethercat::{EthercatSlaveConfig, SdoWrite};
+
+    fn config() -> EthercatConfig {
+        EthercatConfig {
+            slaves: vec![
+                EthercatSlaveConfig {
+                    name: "drive".to_string(),
+                    output_bytes: 4,
+                    input_bytes: 6,
+                    sdo_init: vec![SdoWrite::u8(0x6060, 0, 8)],
+                },
+                EthercatSlaveConfig {
+                    name: "inputs".to_string(),
+                    output_bytes: 0,
+                    input_bytes: 2,
+                    sdo_init: Vec::new(),
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_process_image_layout() {
+        let driver = SimulationEthercatDriver::new(config());
+        let layout = driver.image().layout();
+
+        // Outputs of all slaves first, then inputs
+        assert_eq!(layout.outputs(0), Some(0..4));
+        assert_eq!(layout.outputs(1), Some(4..4));
+        assert_eq!(layout.inputs(0), Some(4..10));
+        assert_eq!(layout.inputs(1), Some(10..12));
+        assert_eq!(layout.size(), 12);
+        assert_eq!(layout.output_size(), 4);
+        assert_eq!(layout.expected_wkc(), 4);
+    }
+
+    #[test]
+    fn test_exchange_and_sdo() {
+        let mut driver = SimulationEthercatDriver::new(config());
+        assert!(driver.exchange().is_err());
+
+        driver.init().unwrap();
+        assert_eq!(driver.state().unwrap(), EthercatState::Op);
+        assert_eq!(driver.sdo_read(0, 0x6060, 0, 1).unwrap(), vec![8]);
+        assert!(driver.sdo_read(1, 0x6060, 0, 1).is_err());
+        assert!(driver.sdo_write(2, 0x6060, 0, &[8]).is_err());
+
+        driver.image_mut().outputs_mut(0).unwrap()[0] = 0x0F;
+        driver.image_mut().inputs_mut(1).unwrap()[1] = 0xAA;
+        assert_eq!(driver.exchange().unwrap(), 4);
+        assert_eq!(driver.status(), DriverStatus::Running);
+        assert_eq!(driver.image().outputs(0).unwrap()[0], 0x0F);
+        assert_eq!(driver.image().inputs(1).unwrap(), &[0, 0xAA]);
+
+        driver.set_responding(false);
+        assert_eq!(driver.exchange().unwrap(), 0);
+
+        driver.shutdown().unwrap();
+        assert_eq!(driver.status(), DriverStatus::Shutdown);
+    }
+}
@@ -0,0 +1,357 @@
+//! EtherCAT master driver using SOEM (Simple Open EtherCAT Master)
+//!
+//! Links against the system `libsoem` (1.4.x). Sending raw Ethernet frames
+//! requires root or `CAP_NET_RAW` on the process.
+
+use std::ffi::CString;
+use std::os::raw::{c_char, c_int, c_void};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use horus_core::driver::DriverStatus;
+use horus_core::error::{HorusError, HorusResult};
+
+use super::{EthercatConfig, EthercatState, ProcessImage};
+
+/// SOEM timeout for SDO transfers in microseconds (EC_TIMEOUTRXM)
+const SDO_TIMEOUT_US: c_int = 700_000;
+
+/// First fields of SOEM's `ec_slavet`
+///
+/// Only `ec_slave[0]` (the group-wide pseudo slave) is accessed, to request
+/// and read the state of all slaves at once.
+#[repr(C)]
+struct EcSlaveHead {
+    state: u16,
+    al_status_code: u16,
+}
+
+#[link(name = "soem")]
+extern "C" {
+    static mut ec_slave: EcSlaveHead;
+
+    fn ec_init(ifname: *const c_char) -> c_int;
+    fn ec_close();
+    fn ec_config_init(usetable: u8) -> c_int;
+    fn ec_config_map(io_map: *mut c_void) -> c_int;
+    fn ec_configdc() -> u8;
+    fn ec_statecheck(slave: u16, reqstate: u16, timeout: c_int) -> u16;
+    fn ec_writestate(slave: u16) -> c_int;
+    fn ec_readstate() -> c_int;
+    fn ec_send_processdata() -> c_int;
+    fn ec_receive_processdata(timeout: c_int) -> c_int;
+    #[link_name = "ec_SDOwrite"]
+    fn ec_sdo_write(
+        slave: u16,
+        index: u16,
+        subindex: u8,
+        ca: u8,
+        psize: c_int,
+        p: *const c_void,
+        timeout: c_int,
+    ) -> c_int;
+    #[link_name = "ec_SDOread"]
+    fn ec_sdo_read(
+        slave: u16,
+        index: u16,
+        subindex: u8,
+        ca: u8,
+        psize: *mut c_int,
+        p: *mut c_void,
+        timeout: c_int,
+    ) -> c_int;
+}
+
+/// SOEM keeps its master context in globals: one master per process
+static MASTER_IN_USE: AtomicBool = AtomicBool::new(false);
+
+/// SOEM EtherCAT master driver
+///
+/// Hardware driver for an EtherCAT bus on a raw Ethernet interface. The
+/// process image buffer is registered with SOEM by `ec_config_map` and is
+/// never reallocated while the driver is open.
+pub struct SoemEthercatDriver {
+    config: EthercatConfig,
+    status: DriverStatus,
+    image: ProcessImage,
+    slave_count: usize,
+    open: bool,
+}
+
+impl SoemEthercatDriver {
+    pub fn new(config: EthercatConfig) -> HorusResult<Self> {
+        let image = ProcessImage::new(&config.slaves);
+        Ok(Self {
+            config,
+            status: DriverStatus::Uninitialized,
+            image,
+            slave_count: 0,
+            open: false,
+        })
+    }
+
+    // ========================================================================
+    // Lifecycle methods
+    // ========================================================================
+
+    pub fn init(&mut self) -> HorusResult<()> {
+        if self.open {
+            self.shutdown()?;
+        }
+        if MASTER_IN_USE.swap(true, Ordering::SeqCst) {
+            return Err(HorusError::driver(
+                "An EtherCAT master is already open in this process",
+            ));
+        }
+
+        match self.open_bus() {
+            Ok(()) => {
+                self.status = DriverStatus::Ready;
+                Ok(())
+            }
+            Err(e) => {
+                self.close_bus();
+                self.status = DriverStatus::Error(e.to_string());
+                Err(e)
+            }
+        }
+    }
+
+    pub fn shutdown(&mut self) -> HorusResult<()> {
+        if self.open {
+            unsafe {
+                ec_slave.state = EthercatState::Init.code();
+                ec_writestate(0);
+            }
+            self.close_bus();
+        }
+        self.status = DriverStatus::Shutdown;
+        Ok(())
+    }
+
+    pub fn is_available(&self) -> bool {
+        std::path::Path::new("/sys/class/net")
+            .join(&self.config.interface)
+            .exists()
+    }
+
+    pub fn status(&self) -> DriverStatus {
+        self.status.clone()
+    }
+
+    pub fn config(&self) -> &EthercatConfig {
+        &self.config
+    }
+
+    fn open_bus(&mut self) -> HorusResult<()> {
+        let ifname = CString::new(self.config.interface.as_str())
+            .map_err(|_| HorusError::driver("Invalid EtherCAT interface name"))?;
+        if unsafe { ec_init(ifname.as_ptr()) } <= 0 {
+            return Err(HorusError::driver(format!(
+                "Failed to open EtherCAT interface {} (needs CAP_NET_RAW)",
+                self.config.interface
+            )));
+        }
+        self.open = true;
+
+        let found = unsafe { ec_config_init(0) };
+        self.slave_count = found.max(0) as usize;
+        if self.slave_count != self.config.slaves.len() {
+            return Err(HorusError::driver(format!(
+                "Expected {} EtherCAT slaves on {}, found {}",
+                self.config.slaves.len(),
+                self.config.interface,
+                self.slave_count
+            )));
+        }
+
+        // Slaves are in PRE-OP after ec_config_init: apply the mailbox setup
+        for (slave, config) in self.config.slaves.iter().enumerate() {
+            for sdo in &config.sdo_init {
+                self.sdo_download(slave, sdo.index, sdo.subindex, &sdo.data)
+                    .map_err(|e| {
+                        HorusError::driver(format!("Slave {} ({}): {}", slave, config.name, e))
+                    })?;
+            }
+        }
+
+        self.image = ProcessImage::new(&self.config.slaves);
+        let mapped =
+            unsafe { ec_config_map(self.image.as_mut_slice().as_mut_ptr() as *mut c_void) };
+        if mapped as usize != self.image.layout().size() {
+            return Err(HorusError::driver(format!(
+                "EtherCAT process image is {} bytes, configured slaves need {}",
+                mapped,
+                self.image.layout().size()
+            )));
+        }
+        unsafe {
+            ec_configdc();
+        }
+
+        let timeout = (self.config.state_timeout_ms * 1000) as c_int;
+        self.request_state(EthercatState::SafeOp, timeout)?;
+
+        // Slaves only enter OP after valid outputs have been received
+        unsafe {
+            ec_send_processdata();
+            ec_receive_processdata(self.config.receive_timeout_us as c_int);
+        }
+        self.request_state(EthercatState::Op, timeout)
+    }
+
+    fn close_bus(&mut self) {
+        if self.open {
+            unsafe { ec_close() };
+            self.open = false;
+        }
+        MASTER_IN_USE.store(false, Ordering::SeqCst);
+    }
+
+    fn request_state(&mut self, state: EthercatState, timeout_us: c_int) -> HorusResult<()> {
+        let reached = unsafe {
+            ec_slave.state = state.code();
+            ec_writestate(0);
+            ec_statecheck(0, state.code(), timeout_us)
+        };
+        if reached != state.code() {
+            let al_status = unsafe { ec_slave.al_status_code };
+            return Err(HorusError::driver(format!(
+                "EtherCAT slaves did not reach {:?} (AL status 0x{:04X})",
+                state, al_status
+            )));
+        }
+        Ok(())
+    }
+
+    // ========================================================================
+    // Process data methods
+    // ========================================================================
+
+    pub fn exchange(&mut self) -> HorusResult<u16> {
+        if !self.open || !matches!(self.status, DriverStatus::Ready | DriverStatus::Running) {
+            return Err(HorusError::driver("Driver not initialized"));
+        }
+        self.status = DriverStatus::Running;
+        let wkc = unsafe {
+            ec_send_processdata();
+            ec_receive_processdata(self.config.receive_timeout_us as c_int)
+        };
+        Ok(wkc.max(0) as u16)
+    }
+
+    pub fn image(&self) -> &ProcessImage {
+        &self.image
+    }
+
+    pub fn image_mut(&mut self) -> &mut ProcessImage {
+        &mut self.image
+    }
+
+    // ========================================================================
+    // EtherCAT-specific methods
+    // ========================================================================
+
+    pub fn state(&mut self) -> HorusResult<EthercatState> {
+        if !self.open {
+            return Err(HorusError::driver("Driver not initialized"));
+        }
+        let code = unsafe {
+            ec_readstate();
+            ec_slave.state
+        };
+        EthercatState::from_code(code)
+            .ok_or_else(|| HorusError::driver(format!("Invalid EtherCAT state 0x{:02X}", code)))
+    }
+
+    pub fn slave_count(&self) -> usize {
+        self.slave_count
+    }
+
+    pub fn sdo_write(
+        &mut self,
+        slave: usize,
+        index: u16,
+        subindex: u8,
+        data: &[u8],
+    ) -> HorusResult<()> {
+        if !self.open {
+            return Err(HorusError::driver("Driver not initialized"));
+        }
+        self.sdo_download(slave, index, subindex, data)
+    }
+
+    pub fn sdo_read(
+        &mut self,
+        slave: usize,
+        index: u16,
+        subindex: u8,
+        max_len: usize,
+    ) -> HorusResult<Vec<u8>> {
+        if !self.open {
+            return Err(HorusError::driver("Driver not initialized"));
+        }
+        let position = self.bus_position(slave)?;
+        let mut data = vec![0u8; max_len];
+        let mut size = max_len as c_int;
+        let wkc = unsafe {
+            ec_sdo_read(
+                position,
+                index,
+                subindex,
+                0,
+                &mut size,
+                data.as_mut_ptr() as *mut c_void,
+                SDO_TIMEOUT_US,
+            )
+        };
+        if wkc <= 0 {
+            return Err(HorusError::driver(format!(
+                "SDO upload 0x{:04X}:{} from slave {} failed",
+                index, subindex, slave
+            )));
+        }
+        data.truncate(size.max(0) as usize);
+        Ok(data)
+    }
+
+    fn sdo_download(&self, slave: usize, index: u16, subindex: u8, data: &[u8]) -> HorusResult<()> {
+        let position = self.bus_position(slave)?;
+        let wkc = unsafe {
+            ec_sdo_write(
+                position,
+                index,
+                subindex,
+                0,
+                data.len() as c_int,
+                data.as_ptr() as *const c_void,
+                SDO_TIMEOUT_US,
+            )
+        };
+        if wkc <= 0 {
+            return Err(HorusError::driver(format!(
+                "SDO download 0x{:04X}:{} to slave {} failed",
+                index, subindex, slave
+            )));
+        }
+        Ok(())
+    }
+
+    /// SOEM numbers slaves from 1; index 0 addresses all slaves
+    fn bus_position(&self, slave: usize) -> HorusResult<u16> {
+        if slave >= self.slave_count {
+            return Err(HorusError::driver(format!(
+                "EtherCAT slave {} not found on the bus",
+                slave
+            )));
+        }
+        Ok(slave as u16 + 1)
+    }
+}
+
+impl Drop for SoemEthercatDriver {
+    fn drop(&mut self) {
+        if self.open {
+            let _ = self.shutdown();
+        }
+    }
+}
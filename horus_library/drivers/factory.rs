@@ -22,19 +22,31 @@ use horus_core::driver::SingleDriverConfig;
 use horus_core::error::{HorusError, HorusResult};
 
 use super::{
-    BatteryDriver, CameraDriver, EncoderDriver, ForceTorqueDriver, GpsDriver, ImuDriver,
-    JoystickDriver, KeyboardDriver, LidarDriver, MotorDriver, ServoDriver, UltrasonicDriver,
+    BatteryDriver, CameraDriver, DepthCameraDriver, EncoderDriver, ForceTorqueDriver, GpioDriver,
+    GpsDriver, ImuDriver, JoystickDriver, KeyboardDriver, Lidar3dDriver, LidarDriver, MotorDriver,
+    ServoDriver, UltrasonicDriver,
 };
 
 use super::battery::BatteryDriverBackend;
-use super::camera::CameraDriverBackend;
+use super::camera::{
+    CameraDriverBackend, GStreamerCameraConfig, GstDecoder, GstSource, VideoCodec,
+};
+use super::depth_camera::{DepthCameraConfig, DepthCameraDriverBackend};
 use super::encoder::EncoderDriverBackend;
 use super::force_torque::ForceTorqueDriverBackend;
+#[cfg(feature = "raspberry-pi")]
+use super::gpio::GpioDriverBackend;
+#[cfg(feature = "jetson")]
+use super::gpio::{JetsonGpio, JetsonGpioConfig, JetsonGpioInterface};
 use super::gps::GpsDriverBackend;
 use super::imu::ImuDriverBackend;
 use super::joystick::JoystickDriverBackend;
 use super::keyboard::KeyboardDriverBackend;
 use super::lidar::LidarDriverBackend;
+use super::lidar3d::{
+    Lidar3dConfig, OusterDriver, OusterMetadata, PcapReplay, SimulationLidar3dConfig,
+    SimulationLidar3dDriver, VelodyneCalibration, VelodyneDriver, VelodyneModel,
+};
 use super::motor::MotorDriverBackend;
 use super::servo::ServoDriverBackend;
 use super::ultrasonic::UltrasonicDriverBackend;
@@ -92,6 +104,10 @@ pub fn create_imu_driver(config: &SingleDriverConfig) -> HorusResult<ImuDriver>
 /// - `simulation` - Always available, generates synthetic images
 /// - `opencv` - OpenCV-based camera (requires `opencv-backend` feature)
 /// - `v4l2` - Video4Linux2 camera (requires `v4l2-backend` feature)
+/// - `gstreamer` - GStreamer pipeline (requires `gstreamer-backend` feature).
+///   `device` is an RTSP URL (`rtsp://...`) or a V4L2 device path; the
+///   `decoder` option selects `auto`, `software`, `nvidia` or `vaapi` and
+///   `codec` selects `h264` or `h265`
 pub fn create_camera_driver(config: &SingleDriverConfig) -> HorusResult<CameraDriver> {
     let backend = match config.backend.as_str() {
         "simulation" | "sim" => CameraDriverBackend::Simulation,
@@ -102,9 +118,12 @@ pub fn create_camera_driver(config: &SingleDriverConfig) -> HorusResult<CameraDr
         #[cfg(feature = "v4l2-backend")]
         "v4l2" => CameraDriverBackend::V4l2,
 
+        #[cfg(feature = "gstreamer-backend")]
+        "gstreamer" | "gst" => return create_gstreamer_camera_driver(config),
+
         other => {
             return Err(HorusError::driver(format!(
-                "Camera backend '{}' is not available. Available: simulation{}{}",
+                "Camera backend '{}' is not available. Available: simulation{}{}{}",
                 other,
                 if cfg!(feature = "opencv-backend") {
                     ", opencv"
@@ -116,6 +135,11 @@ pub fn create_camera_driver(config: &SingleDriverConfig) -> HorusResult<CameraDr
                 } else {
                     ""
                 },
+                if cfg!(feature = "gstreamer-backend") {
+                    ", gstreamer"
+                } else {
+                    ""
+                },
             )));
         }
     };
@@ -123,6 +147,163 @@ pub fn create_camera_driver(config: &SingleDriverConfig) -> HorusResult<CameraDr
     CameraDriver::new(backend)
 }
 
+/// Build the GStreamer camera configuration from a driver config
+pub fn gstreamer_camera_config(config: &SingleDriverConfig) -> HorusResult<GStreamerCameraConfig> {
+    let device = config.device.as_deref().unwrap_or("/dev/video0");
+    let mut camera = if device.starts_with("rtsp://") || device.starts_with("rtsps://") {
+        GStreamerCameraConfig::rtsp(device)
+    } else {
+        GStreamerCameraConfig::usb(device, 640, 480, 30.0)
+    };
+    if let Some(width) = config.width {
+        camera.width = width;
+    }
+    if let Some(height) = config.height {
+        camera.height = height;
+    }
+    if let Some(fps) = config.fps {
+        camera.fps = fps;
+    }
+
+    let option = |name: &str| config.options.get(name).and_then(|v| v.as_str());
+    if let Some(decoder) = option("decoder") {
+        camera.decoder = match decoder {
+            "auto" => GstDecoder::Auto,
+            "software" => GstDecoder::Software,
+            "nvidia" => GstDecoder::Nvidia,
+            "vaapi" => GstDecoder::VaApi,
+            other => {
+                return Err(HorusError::driver(format!(
+                    "Unknown GStreamer decoder '{}'. Available: auto, software, nvidia, vaapi",
+                    other
+                )))
+            }
+        };
+    }
+    if let Some(codec) = option("codec") {
+        camera.codec = match codec {
+            "h264" => VideoCodec::H264,
+            "h265" | "hevc" => VideoCodec::H265,
+            other => {
+                return Err(HorusError::driver(format!(
+                    "Unknown video codec '{}'. Available: h264, h265",
+                    other
+                )))
+            }
+        };
+    }
+    if let Some(pipeline) = option("pipeline") {
+        camera.source = GstSource::Custom(pipeline.to_string());
+    }
+    camera.pipeline()?;
+    Ok(camera)
+}
+
+#[cfg(feature = "gstreamer-backend")]
+fn create_gstreamer_camera_driver(config: &SingleDriverConfig) -> HorusResult<CameraDriver> {
+    CameraDriver::gstreamer(gstreamer_camera_config(config)?)
+}
+
+// ============================================================================
+// Depth Camera Driver Factory
+// ============================================================================
+
+/// Create a depth camera driver from configuration
+///
+/// # Supported Backends
+///
+/// - `simulation` - Always available, generates synthetic RGB-D frames
+/// - `realsense` - Intel RealSense D400/L500 (requires `realsense` feature)
+/// - `zed` - Stereolabs ZED (requires `zed` feature)
+///
+/// See [`depth_camera_config`] for the accepted settings.
+pub fn create_depth_camera_driver(config: &SingleDriverConfig) -> HorusResult<DepthCameraDriver> {
+    let backend = match config.backend.as_str() {
+        "simulation" | "sim" => DepthCameraDriverBackend::Simulation,
+
+        #[cfg(feature = "realsense")]
+        "realsense" => DepthCameraDriverBackend::RealSense,
+
+        #[cfg(feature = "zed")]
+        "zed" => DepthCameraDriverBackend::Zed,
+
+        other => {
+            return Err(HorusError::driver(format!(
+                "Depth camera backend '{}' is not available. Available: simulation{}{}",
+                other,
+                if cfg!(feature = "realsense") {
+                    ", realsense"
+                } else {
+                    ""
+                },
+                if cfg!(feature = "zed") { ", zed" } else { "" },
+            )));
+        }
+    };
+
+    DepthCameraDriver::with_config(backend, depth_camera_config(config)?)
+}
+
+/// Build the depth camera configuration from a driver config
+///
+/// `device` is the camera serial number, `width`/`height`/`fps` set the RGB
+/// stream. Options: `depth_width`/`depth_height` (default: RGB resolution),
+/// `align` (register depth to color, default true), `min_depth`/`max_depth`
+/// in meters and `timeout_ms`.
+pub fn depth_camera_config(config: &SingleDriverConfig) -> HorusResult<DepthCameraConfig> {
+    let mut camera = DepthCameraConfig::default();
+    if let Some(serial) = &config.device {
+        camera.serial = serial.clone();
+    }
+    camera.rgb_resolution = (
+        config.width.unwrap_or(camera.rgb_resolution.0),
+        config.height.unwrap_or(camera.rgb_resolution.1),
+    );
+    if let Some(fps) = config.fps {
+        camera.fps = fps.round() as u32;
+    }
+
+    let number = |name: &str| -> HorusResult<Option<f64>> {
+        match config.options.get(name) {
+            None => Ok(None),
+            Some(value) => value.as_f64().map(Some).ok_or_else(|| {
+                HorusError::config(format!("Depth camera option '{}' must be a number", name))
+            }),
+        }
+    };
+    camera.depth_resolution = (
+        number("depth_width")?.map_or(camera.rgb_resolution.0, |w| w as u32),
+        number("depth_height")?.map_or(camera.rgb_resolution.1, |h| h as u32),
+    );
+    if let Some(align) = config.options.get("align") {
+        camera.align_depth_to_color = align
+            .as_bool()
+            .ok_or_else(|| HorusError::config("Depth camera option 'align' must be a boolean"))?;
+    }
+    if let Some(min) = number("min_depth")? {
+        camera.depth_range.0 = min as f32;
+    }
+    if let Some(max) = number("max_depth")? {
+        camera.depth_range.1 = max as f32;
+    }
+    if let Some(timeout) = number("timeout_ms")? {
+        camera.timeout_ms = timeout as u64;
+    }
+
+    if camera.fps == 0 || camera.rgb_resolution.0 == 0 || camera.rgb_resolution.1 == 0 {
+        return Err(HorusError::config(
+            "Depth camera resolution and fps must be non-zero",
+        ));
+    }
+    if camera.depth_range.0 >= camera.depth_range.1 {
+        return Err(HorusError::config(format!(
+            "Depth camera min_depth {} must be below max_depth {}",
+            camera.depth_range.0, camera.depth_range.1
+        )));
+    }
+    Ok(camera)
+}
+
 // ============================================================================
 // LiDAR Driver Factory
 // ============================================================================
@@ -156,6 +337,150 @@ pub fn create_lidar_driver(config: &SingleDriverConfig) -> HorusResult<LidarDriv
     LidarDriver::new(backend)
 }
 
+// ============================================================================
+// 3D LiDAR Driver Factory
+// ============================================================================
+
+/// Create a 3D LiDAR driver from configuration
+///
+/// # Supported Backends
+///
+/// - `simulation` - Always available, generates synthetic scans
+/// - `velodyne` - Velodyne VLP-16/VLP-32C/HDL-32E; options `model`
+///   (default `vlp16`) and `calibration` (ROS calibration YAML)
+/// - `ouster` - Ouster OS0/OS1/OS2; option `metadata` (sensor metadata JSON,
+///   nominal OS1-64 beams when missing)
+///
+/// See [`lidar3d_config`] for the network and replay settings.
+pub fn create_lidar3d_driver(config: &SingleDriverConfig) -> HorusResult<Lidar3dDriver> {
+    let lidar = lidar3d_config(config)?;
+    let option = |name: &str| config.options.get(name).and_then(|v| v.as_str());
+
+    match config.backend.as_str() {
+        "simulation" | "sim" => Ok(Lidar3dDriver::Simulation(
+            SimulationLidar3dDriver::with_config(SimulationLidar3dConfig {
+                range_min: lidar.range_min,
+                range_max: lidar.range_max,
+                rotation_rate: lidar.rotation_rate,
+                frame_id: lidar.frame_id,
+                ..Default::default()
+            }),
+        )),
+        "velodyne" => {
+            let model = match option("model") {
+                Some(name) => VelodyneModel::from_name(name).ok_or_else(|| {
+                    HorusError::config(format!(
+                        "Unknown Velodyne model '{}'. Available: vlp16, vlp32c, hdl32e",
+                        name
+                    ))
+                })?,
+                None => VelodyneModel::default(),
+            };
+            let driver = match option("calibration") {
+                Some(path) => VelodyneDriver::with_calibration(
+                    lidar,
+                    model,
+                    VelodyneCalibration::from_file(path)?,
+                )?,
+                None => VelodyneDriver::new(lidar, model)?,
+            };
+            Ok(Lidar3dDriver::Velodyne(driver))
+        }
+        "ouster" => {
+            let metadata = match option("metadata") {
+                Some(path) => OusterMetadata::from_file(path)?,
+                None => OusterMetadata::default(),
+            };
+            Ok(Lidar3dDriver::Ouster(OusterDriver::new(lidar, metadata)?))
+        }
+        other => Err(HorusError::driver(format!(
+            "3D LiDAR backend '{}' is not available. Available: simulation, velodyne, ouster",
+            other
+        ))),
+    }
+}
+
+/// Build the 3D LiDAR configuration from a driver config
+///
+/// `fps` is the rotation rate. Options: `udp_port` (default 2368 for
+/// Velodyne, 7502 for Ouster), `bind_address`, `pcap` (replay a capture)
+/// with `pcap_realtime`/`pcap_repeat`, `frame_id`, `min_range`/`max_range`
+/// in meters, `sensor_time` (use the synchronized sensor clock) and
+/// `timeout_ms`.
+pub fn lidar3d_config(config: &SingleDriverConfig) -> HorusResult<Lidar3dConfig> {
+    let mut lidar = Lidar3dConfig {
+        port: if config.backend == "ouster" {
+            7502
+        } else {
+            2368
+        },
+        ..Default::default()
+    };
+    if let Some(fps) = config.fps {
+        lidar.rotation_rate = fps;
+    }
+
+    let number = |name: &str| -> HorusResult<Option<f64>> {
+        match config.options.get(name) {
+            None => Ok(None),
+            Some(value) => value.as_f64().map(Some).ok_or_else(|| {
+                HorusError::config(format!("3D LiDAR option '{}' must be a number", name))
+            }),
+        }
+    };
+    let flag = |name: &str| -> HorusResult<Option<bool>> {
+        match config.options.get(name) {
+            None => Ok(None),
+            Some(value) => value.as_bool().map(Some).ok_or_else(|| {
+                HorusError::config(format!("3D LiDAR option '{}' must be a boolean", name))
+            }),
+        }
+    };
+    let text = |name: &str| config.options.get(name).and_then(|v| v.as_str());
+
+    if let Some(port) = number("udp_port")? {
+        lidar.port = u16::try_from(port as i64)
+            .map_err(|_| HorusError::config(format!("Invalid 3D LiDAR udp_port {}", port)))?;
+    }
+    if let Some(address) = text("bind_address") {
+        lidar.bind_address = address.to_string();
+    }
+    if let Some(path) = text("pcap") {
+        let mut replay = PcapReplay::new(path);
+        replay.realtime = flag("pcap_realtime")?.unwrap_or(false);
+        replay.repeat = flag("pcap_repeat")?.unwrap_or(false);
+        lidar.pcap = Some(replay);
+    }
+    if let Some(frame_id) = text("frame_id") {
+        lidar.frame_id = frame_id.to_string();
+    }
+    if let Some(min) = number("min_range")? {
+        lidar.range_min = min as f32;
+    }
+    if let Some(max) = number("max_range")? {
+        lidar.range_max = max as f32;
+    }
+    if let Some(sensor_time) = flag("sensor_time")? {
+        lidar.sensor_time = sensor_time;
+    }
+    if let Some(timeout) = number("timeout_ms")? {
+        lidar.timeout_ms = timeout as u64;
+    }
+
+    if lidar.rotation_rate <= 0.0 {
+        return Err(HorusError::config(
+            "3D LiDAR rotation rate must be positive",
+        ));
+    }
+    if lidar.range_min >= lidar.range_max {
+        return Err(HorusError::config(format!(
+            "3D LiDAR min_range {} must be below max_range {}",
+            lidar.range_min, lidar.range_max
+        )));
+    }
+    Ok(lidar)
+}
+
 // ============================================================================
 // GPS Driver Factory
 // ============================================================================
@@ -462,6 +787,100 @@ pub fn create_keyboard_driver(config: &SingleDriverConfig) -> HorusResult<Keyboa
     KeyboardDriver::new(backend)
 }
 
+// ============================================================================
+// GPIO Driver Factory
+// ============================================================================
+
+/// Create a GPIO/PWM driver from configuration
+///
+/// # Supported Backends
+///
+/// - `simulation` - Always available, keeps pin states in memory
+/// - `auto` - Backend of the detected board, simulation otherwise
+/// - `raspberry_pi` - Raspberry Pi through rppal (requires `raspberry-pi` feature)
+/// - `jetson` - NVIDIA Jetson (requires `jetson` feature); `device` is the
+///   GPIO chip, options `interface` (`chardev` or `sysfs`), `sysfs_base`,
+///   `pwm_chip`, and `pin_map`/`pwm_map` (pin number -> line offset or
+///   `pwmN` index)
+pub fn create_gpio_driver(config: &SingleDriverConfig) -> HorusResult<GpioDriver> {
+    match config.backend.as_str() {
+        "simulation" | "sim" => Ok(GpioDriver::simulation()),
+        "auto" => Ok(GpioDriver::detect()),
+
+        #[cfg(feature = "raspberry-pi")]
+        "raspberry_pi" | "raspberry-pi" | "rpi" => GpioDriver::new(GpioDriverBackend::RaspberryPi),
+
+        #[cfg(feature = "jetson")]
+        "jetson" => Ok(GpioDriver::Jetson(JetsonGpio::new(jetson_gpio_config(
+            config,
+        )?)?)),
+
+        other => Err(HorusError::driver(format!(
+            "GPIO backend '{}' is not available. Available: simulation, auto{}{}",
+            other,
+            if cfg!(feature = "raspberry-pi") {
+                ", raspberry_pi"
+            } else {
+                ""
+            },
+            if cfg!(feature = "jetson") {
+                ", jetson"
+            } else {
+                ""
+            },
+        ))),
+    }
+}
+
+#[cfg(feature = "jetson")]
+fn jetson_gpio_config(config: &SingleDriverConfig) -> HorusResult<JetsonGpioConfig> {
+    let mut jetson = JetsonGpioConfig::default();
+    if let Some(chip) = &config.device {
+        jetson.chip = chip.into();
+    }
+    let number = |value: &serde_yaml::Value, name: &str| -> HorusResult<u32> {
+        value
+            .as_u64()
+            .and_then(|n| u32::try_from(n).ok())
+            .ok_or_else(|| HorusError::config(format!("Jetson GPIO {} must be a pin number", name)))
+    };
+
+    match config.options.get("interface").and_then(|v| v.as_str()) {
+        None | Some("chardev") => {}
+        Some("sysfs") => {
+            let base = match config.options.get("sysfs_base") {
+                Some(value) => number(value, "sysfs_base")?,
+                None => 0,
+            };
+            jetson.interface = JetsonGpioInterface::Sysfs { base };
+        }
+        Some(other) => {
+            return Err(HorusError::config(format!(
+                "Unknown Jetson GPIO interface '{}'. Available: chardev, sysfs",
+                other
+            )))
+        }
+    }
+    if let Some(chip) = config.options.get("pwm_chip").and_then(|v| v.as_str()) {
+        jetson.pwm_chip = chip.into();
+    }
+    for (name, map) in [
+        ("pin_map", &mut jetson.pin_map),
+        ("pwm_map", &mut jetson.pwm_map),
+    ] {
+        let Some(value) = config.options.get(name) else {
+            continue;
+        };
+        let entries = value
+            .as_mapping()
+            .ok_or_else(|| HorusError::config(format!("Jetson GPIO {} must be a mapping", name)))?;
+        for (pin, target) in entries {
+            map.insert(number(pin, name)?, number(target, name)?);
+        }
+    }
+    Ok(jetson)
+}
+
 // ============================================================================
 // Convenience: Create all drivers from DriversConfig
 // ============================================================================
@@ -473,7 +892,9 @@ use horus_core::driver::DriversConfig;
 pub struct CreatedDrivers {
     pub imu: Option<ImuDriver>,
     pub camera: Option<CameraDriver>,
+    pub depth_camera: Option<DepthCameraDriver>,
     pub lidar: Option<LidarDriver>,
+    pub lidar3d: Option<Lidar3dDriver>,
     pub gps: Option<GpsDriver>,
     pub encoder: Option<EncoderDriver>,
     pub motor: Option<MotorDriver>,
@@ -483,6 +904,7 @@ pub struct CreatedDrivers {
     pub force_torque: Option<ForceTorqueDriver>,
     pub joystick: Option<JoystickDriver>,
     pub keyboard: Option<KeyboardDriver>,
+    pub gpio: Option<GpioDriver>,
 }
 
 /// Create all drivers from a DriversConfig
@@ -515,9 +937,15 @@ pub fn create_drivers_from_config(config: &DriversConfig) -> HorusResult<Created
             "camera" => {
                 drivers.camera = Some(create_camera_driver(driver_config)?);
             }
+            "depth_camera" | "depth-camera" | "rgbd" => {
+                drivers.depth_camera = Some(create_depth_camera_driver(driver_config)?);
+            }
             "lidar" => {
                 drivers.lidar = Some(create_lidar_driver(driver_config)?);
             }
+            "lidar3d" | "lidar_3d" | "lidar-3d" => {
+                drivers.lidar3d = Some(create_lidar3d_driver(driver_config)?);
+            }
             "gps" => {
                 drivers.gps = Some(create_gps_driver(driver_config)?);
             }
@@ -545,6 +973,9 @@ pub fn create_drivers_from_config(config: &DriversConfig) -> HorusResult<Created
             "keyboard" => {
                 drivers.keyboard = Some(create_keyboard_driver(driver_config)?);
             }
+            "gpio" => {
+                drivers.gpio = Some(create_gpio_driver(driver_config)?);
+            }
             _ => {
                 // Unknown driver name, skip (could log a warning here)
             }
@@ -573,14 +1004,27 @@ pub fn list_available_backends() -> std::collections::HashMap<&'static str, Vec<
     camera_backends.push("opencv");
     #[cfg(feature = "v4l2-backend")]
     camera_backends.push("v4l2");
+    #[cfg(feature = "gstreamer-backend")]
+    camera_backends.push("gstreamer");
     backends.insert("camera", camera_backends);
 
+    // Depth camera backends
+    let mut depth_camera_backends = vec!["simulation"];
+    #[cfg(feature = "realsense")]
+    depth_camera_backends.push("realsense");
+    #[cfg(feature = "zed")]
+    depth_camera_backends.push("zed");
+    backends.insert("depth_camera", depth_camera_backends);
+
     // LiDAR backends
     let mut lidar_backends = vec!["simulation"];
     #[cfg(feature = "rplidar")]
     lidar_backends.push("rplidar");
     backends.insert("lidar", lidar_backends);
 
+    // 3D LiDAR backends (network drivers need no feature)
+    backends.insert("lidar3d", vec!["simulation", "velodyne", "ouster"]);
+
     // GPS backends
     let mut gps_backends = vec!["simulation"];
     #[cfg(feature = "nmea-gps")]
@@ -637,12 +1081,21 @@ pub fn list_available_backends() -> std::collections::HashMap<&'static str, Vec<
     keyboard_backends.push("crossterm");
     backends.insert("keyboard", keyboard_backends);
 
+    // GPIO backends
+    let mut gpio_backends = vec!["simulation", "auto"];
+    #[cfg(feature = "raspberry-pi")]
+    gpio_backends.push("raspberry_pi");
+    #[cfg(feature = "jetson")]
+    gpio_backends.push("jetson");
+    backends.insert("gpio", gpio_backends);
+
     backends
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::drivers::gpio::{GpioHal, PinMode};
 
     #[test]
     fn test_create_simulation_imu() {
@@ -726,6 +1179,136 @@ mod tests {
         assert!(backends.get("lidar").unwrap().contains(&"simulation"));
     }
 
+    #[test]
+    fn test_gstreamer_camera_config() {
+        let mut config = SingleDriverConfig {
+            backend: "gstreamer".to_string(),
+            device: Some("rtsp://10.0.0.5/stream".to_string()),
+            ..Default::default()
+        };
+        config
+            .options
+            .insert("decoder".to_string(), serde_yaml::Value::from("nvidia"));
+        config
+            .options
+            .insert("codec".to_string(), serde_yaml::Value::from("h265"));
+        let camera = gstreamer_camera_config(&config).unwrap();
+        assert_eq!(camera.decoder, GstDecoder::Nvidia);
+        assert_eq!(camera.codec, VideoCodec::H265);
+        assert!(matches!(camera.source, GstSource::Rtsp { .. }));
+
+        config
+            .options
+            .insert("decoder".to_string(), serde_yaml::Value::from("cuda"));
+        assert!(gstreamer_camera_config(&config).is_err());
+    }
+
+    #[test]
+    fn test_depth_camera_config() {
+        let yaml = r#"
+drivers:
+  depth_camera:
+    backend: simulation
+    device: "123456789"
+    width: 1280
+    height: 720
+    fps: 15
+    depth_width: 848
+    depth_height: 480
+    align: false
+    max_depth: 4.0
+"#;
+        let config = DriversConfig::from_yaml(yaml).unwrap();
+        let driver_config = config.get_driver("depth_camera").unwrap();
+        let camera = depth_camera_config(driver_config).unwrap();
+        assert_eq!(camera.serial, "123456789");
+        assert_eq!(camera.rgb_resolution, (1280, 720));
+        assert_eq!(camera.depth_resolution, (848, 480));
+        assert_eq!(camera.fps, 15);
+        assert!(!camera.align_depth_to_color);
+        assert_eq!(camera.depth_range, (0.3, 4.0));
+
+        let mut driver = create_depth_camera_driver(driver_config).unwrap();
+        driver.init().unwrap();
+        let frame = driver.read().unwrap();
+        assert_eq!(frame.rgb_resolution, (1280, 720));
+        assert_eq!(frame.depth_resolution, (848, 480));
+        assert_eq!(frame.rgb_info.timestamp, frame.timestamp);
+
+        let mut invalid = driver_config.clone();
+        invalid
+            .options
+            .insert("min_depth".to_string(), serde_yaml::Value::from(5.0));
+        assert!(depth_camera_config(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_lidar3d_config() {
+        let yaml = r#"
+drivers:
+  lidar3d:
+    backend: ouster
+    fps: 20
+    pcap: /data/ouster.pcap
+    pcap_repeat: true
+    frame_id: os1
+    max_range: 80.0
+    sensor_time: true
+"#;
+        let config = DriversConfig::from_yaml(yaml).unwrap();
+        let driver_config = config.get_driver("lidar3d").unwrap();
+        let lidar = lidar3d_config(driver_config).unwrap();
+        assert_eq!(lidar.port, 7502);
+        assert_eq!(lidar.rotation_rate, 20.0);
+        let replay = lidar.pcap.unwrap();
+        assert_eq!(replay.path, std::path::PathBuf::from("/data/ouster.pcap"));
+        assert!(replay.repeat && !replay.realtime);
+        assert_eq!(lidar.frame_id, "os1");
+        assert_eq!(lidar.range_max, 80.0);
+        assert!(lidar.sensor_time);
+
+        let mut velodyne = driver_config.clone();
+        velodyne.backend = "velodyne".to_string();
+        velodyne
+            .options
+            .insert("model".to_string(), serde_yaml::Value::from("vlp32c"));
+        assert!(matches!(
+            create_lidar3d_driver(&velodyne).unwrap(),
+            Lidar3dDriver::Velodyne(_)
+        ));
+        velodyne
+            .options
+            .insert("model".to_string(), serde_yaml::Value::from("hdl64"));
+        assert!(create_lidar3d_driver(&velodyne).is_err());
+
+        let mut invalid = driver_config.clone();
+        invalid
+            .options
+            .insert("udp_port".to_string(), serde_yaml::Value::from(70000));
+        assert!(lidar3d_config(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_create_gpio_driver() {
+        let yaml = r#"
+drivers:
+  gpio:
+    backend: simulation
+"#;
+        let config = DriversConfig::from_yaml(yaml).unwrap();
+        let drivers = create_drivers_from_config(&config).unwrap();
+        let mut gpio = drivers.gpio.unwrap();
+        assert!(!gpio.is_hardware());
+        gpio.setup_pin(17, PinMode::Output, true).unwrap();
+        assert!(gpio.read_pin(17).unwrap());
+
+        let invalid = SingleDriverConfig {
+            backend: "beaglebone".to_string(),
+            ..Default::default()
+        };
+        assert!(create_gpio_driver(&invalid).is_err());
+    }
+
     #[test]
     fn test_create_drivers_from_config() {
         let yaml = r#"
@@ -0,0 +1,230 @@
+//! NVIDIA Jetson GPIO backend
+//!
+//! Digital pins go through the GPIO character device (libgpiod interface,
+//! JetPack 5+) or the legacy sysfs interface (JetPack 4), PWM through the
+//! sysfs PWM controller. Pull resistors are part of the Jetson pinmux
+//! (`jetson-io`), so the pull of input modes is left to it.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use gpio_cdev::{Chip, LineHandle, LineRequestFlags};
+use horus_core::error::{HorusError, HorusResult};
+use sysfs_gpio::{Direction, Pin};
+
+use super::{GpioHal, PinMode, SysfsPwm};
+
+/// How digital pins are accessed
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum JetsonGpioInterface {
+    /// GPIO character device (`/dev/gpiochipN`)
+    #[default]
+    CharDevice,
+    /// Legacy `/sys/class/gpio`; the sysfs number of a line is `base + offset`
+    Sysfs { base: u32 },
+}
+
+/// Jetson GPIO configuration
+#[derive(Debug, Clone)]
+pub struct JetsonGpioConfig {
+    /// GPIO chip of the header lines
+    pub chip: PathBuf,
+    pub interface: JetsonGpioInterface,
+    /// Line offset of each pin number, e.g. 40-pin header pin -> offset;
+    /// pins without an entry are line offsets themselves
+    pub pin_map: HashMap<u32, u32>,
+    /// sysfs PWM controller of the PWM outputs
+    pub pwm_chip: PathBuf,
+    /// `pwmN` index of each PWM channel number; channels without an entry
+    /// are indices themselves
+    pub pwm_map: HashMap<u32, u32>,
+}
+
+impl Default for JetsonGpioConfig {
+    fn default() -> Self {
+        Self {
+            chip: PathBuf::from("/dev/gpiochip0"),
+            interface: JetsonGpioInterface::CharDevice,
+            pin_map: HashMap::new(),
+            pwm_chip: PathBuf::from("/sys/class/pwm/pwmchip0"),
+            pwm_map: HashMap::new(),
+        }
+    }
+}
+
+enum Line {
+    CharDevice(LineHandle),
+    Sysfs(Pin),
+}
+
+/// Jetson GPIO backend
+pub struct JetsonGpio {
+    config: JetsonGpioConfig,
+    chip: Option<Chip>,
+    lines: HashMap<u32, (Line, PinMode)>,
+    pwm: SysfsPwm,
+}
+
+impl JetsonGpio {
+    pub fn new(config: JetsonGpioConfig) -> HorusResult<Self> {
+        let chip = match config.interface {
+            JetsonGpioInterface::CharDevice => Some(Chip::new(&config.chip).map_err(|e| {
+                HorusError::driver(format!(
+                    "Failed to open GPIO chip {}: {}",
+                    config.chip.display(),
+                    e
+                ))
+            })?),
+            JetsonGpioInterface::Sysfs { .. } => {
+                if !std::path::Path::new("/sys/class/gpio").exists() {
+                    return Err(HorusError::driver("sysfs GPIO is not available"));
+                }
+                None
+            }
+        };
+        Ok(Self {
+            pwm: SysfsPwm::new(&config.pwm_chip),
+            config,
+            chip,
+            lines: HashMap::new(),
+        })
+    }
+
+    fn offset(&self, pin: u32) -> u32 {
+        self.config.pin_map.get(&pin).copied().unwrap_or(pin)
+    }
+
+    fn pwm_index(&self, channel: u32) -> u32 {
+        self.config
+            .pwm_map
+            .get(&channel)
+            .copied()
+            .unwrap_or(channel)
+    }
+
+    fn request(&mut self, pin: u32, mode: PinMode, initial: bool) -> HorusResult<Line> {
+        let offset = self.offset(pin);
+        let error = |e: &dyn std::fmt::Display| {
+            HorusError::driver(format!(
+                "Failed to set up GPIO {} (line {}): {}",
+                pin, offset, e
+            ))
+        };
+        match (self.chip.as_mut(), self.config.interface) {
+            (Some(chip), _) => {
+                let flags = if mode.is_input() {
+                    LineRequestFlags::INPUT
+                } else {
+                    LineRequestFlags::OUTPUT
+                };
+                let handle = chip
+                    .get_line(offset)
+                    .and_then(|line| line.request(flags, u8::from(initial), "horus"))
+                    .map_err(|e| error(&e))?;
+                Ok(Line::CharDevice(handle))
+            }
+            (None, JetsonGpioInterface::Sysfs { base }) => {
+                let line = Pin::new((base + offset) as u64);
+                line.export().map_err(|e| error(&e))?;
+                let direction = match (mode.is_input(), initial) {
+                    (true, _) => Direction::In,
+                    (false, true) => Direction::High,
+                    (false, false) => Direction::Low,
+                };
+                line.set_direction(direction).map_err(|e| error(&e))?;
+                Ok(Line::Sysfs(line))
+            }
+            (None, JetsonGpioInterface::CharDevice) => unreachable!("chip opened in new"),
+        }
+    }
+}
+
+impl GpioHal for JetsonGpio {
+    fn backend_name(&self) -> &'static str {
+        "jetson"
+    }
+
+    fn setup_pin(&mut self, pin: u32, mode: PinMode, initial: bool) -> HorusResult<()> {
+        // A line can only be requested once
+        self.release_pin(pin)?;
+        let line = self.request(pin, mode, initial)?;
+        self.lines.insert(pin, (line, mode));
+        Ok(())
+    }
+
+    fn read_pin(&mut self, pin: u32) -> HorusResult<bool> {
+        let (line, _) = self
+            .lines
+            .get(&pin)
+            .ok_or_else(|| HorusError::driver(format!("GPIO {} is not set up", pin)))?;
+        let value = match line {
+            Line::CharDevice(handle) => handle.get_value().map_err(|e| e.to_string()),
+            Line::Sysfs(line) => line.get_value().map_err(|e| e.to_string()),
+        };
+        value
+            .map(|value| value != 0)
+            .map_err(|e| HorusError::driver(format!("Failed to read GPIO {}: {}", pin, e)))
+    }
+
+    fn write_pin(&mut self, pin: u32, value: bool) -> HorusResult<()> {
+        let line = match self.lines.get(&pin) {
+            Some((line, PinMode::Output)) => line,
+            _ => return Err(HorusError::driver(format!("GPIO {} is not an output", pin))),
+        };
+        let result = match line {
+            Line::CharDevice(handle) => {
+                handle.set_value(u8::from(value)).map_err(|e| e.to_string())
+            }
+            Line::Sysfs(line) => line.set_value(u8::from(value)).map_err(|e| e.to_string()),
+        };
+        result.map_err(|e| HorusError::driver(format!("Failed to write GPIO {}: {}", pin, e)))
+    }
+
+    fn release_pin(&mut self, pin: u32) -> HorusResult<()> {
+        if let Some((line, mode)) = self.lines.remove(&pin) {
+            match line {
+                Line::CharDevice(handle) => {
+                    if mode == PinMode::Output {
+                        let _ = handle.set_value(0);
+                    }
+                    // Dropping the handle releases the line
+                }
+                Line::Sysfs(line) => {
+                    if mode == PinMode::Output {
+                        let _ = line.set_value(0);
+                    }
+                    let _ = line.unexport();
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn setup_pwm(&mut self, channel: u32, frequency_hz: f64) -> HorusResult<()> {
+        let index = self.pwm_index(channel);
+        self.pwm.setup(index, frequency_hz)
+    }
+
+    fn set_duty_cycle(&mut self, channel: u32, duty_cycle: f64) -> HorusResult<()> {
+        let index = self.pwm_index(channel);
+        self.pwm.set_duty_cycle(index, duty_cycle)
+    }
+
+    fn pwm_frequency(&self, channel: u32) -> Option<f64> {
+        self.pwm.frequency(self.pwm_index(channel))
+    }
+
+    fn release_pwm(&mut self, channel: u32) -> HorusResult<()> {
+        let index = self.pwm_index(channel);
+        self.pwm.release(index)
+    }
+}
+
+impl Drop for JetsonGpio {
+    fn drop(&mut self) {
+        let pins: Vec<u32> = self.lines.keys().copied().collect();
+        for pin in pins {
+            let _ = self.release_pin(pin);
+        }
+    }
+}
@@ -0,0 +1,384 @@
+//! GPIO/PWM hardware abstraction layer
+//!
+//! Board-independent access to digital pins and PWM outputs. Nodes and
+//! drivers take any [`GpioHal`] so the same code runs on every board.
+//!
+//! # Available Backends
+//!
+//! - `SimulationGpio` - Always available, records outputs and injects inputs
+//! - `RaspberryPiGpio` - Raspberry Pi through rppal (requires `raspberry-pi` feature)
+//! - `JetsonGpio` - NVIDIA Jetson through the GPIO character device or sysfs,
+//!   PWM through sysfs (requires `jetson` feature)
+//!
+//! [`GpioDriver::detect`] picks the backend of the board it runs on.
+//!
+//! # Pin numbering
+//!
+//! Pins use the backend's native numbering: BCM numbers on a Raspberry Pi,
+//! line offsets of the GPIO chip on a Jetson (see [`JetsonGpioConfig`] to
+//! map header pins).
+
+mod simulation;
+mod sysfs_pwm;
+
+#[cfg(feature = "raspberry-pi")]
+mod raspberry_pi;
+
+#[cfg(feature = "jetson")]
+mod jetson;
+
+pub use simulation::SimulationGpio;
+pub use sysfs_pwm::SysfsPwm;
+
+#[cfg(feature = "raspberry-pi")]
+pub use raspberry_pi::RaspberryPiGpio;
+
+#[cfg(feature = "jetson")]
+pub use jetson::{JetsonGpio, JetsonGpioConfig, JetsonGpioInterface};
+
+use std::time::Duration;
+
+use horus_core::error::{HorusError, HorusResult};
+
+/// Digital I/O pin mode
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PinMode {
+    #[default]
+    Input,
+    Output,
+    InputPullUp,
+    InputPullDown,
+}
+
+impl PinMode {
+    pub fn is_input(&self) -> bool {
+        !matches!(self, Self::Output)
+    }
+}
+
+/// Board-independent GPIO and PWM access
+///
+/// ```rust,ignore
+/// use horus_library::drivers::gpio::{GpioDriver, GpioHal, PinMode};
+///
+/// let mut gpio = GpioDriver::detect();
+/// gpio.setup_pin(17, PinMode::InputPullUp, false)?;
+/// let pressed = !gpio.read_pin(17)?;
+/// gpio.setup_pwm(18, 50.0)?;
+/// gpio.set_pulse_width(18, Duration::from_micros(1500))?;
+/// ```
+pub trait GpioHal: Send {
+    /// Backend name for logs
+    fn backend_name(&self) -> &'static str;
+
+    /// Claim a pin; outputs start at `initial`
+    fn setup_pin(&mut self, pin: u32, mode: PinMode, initial: bool) -> HorusResult<()>;
+
+    /// Read the level of a claimed pin
+    fn read_pin(&mut self, pin: u32) -> HorusResult<bool>;
+
+    /// Drive an output pin
+    fn write_pin(&mut self, pin: u32, value: bool) -> HorusResult<()>;
+
+    /// Release a pin, outputs are driven low first
+    fn release_pin(&mut self, pin: u32) -> HorusResult<()>;
+
+    /// Start a PWM output at `frequency_hz` with a duty cycle of 0
+    fn setup_pwm(&mut self, channel: u32, frequency_hz: f64) -> HorusResult<()>;
+
+    /// Set the duty cycle of a PWM output (0.0 to 1.0)
+    fn set_duty_cycle(&mut self, channel: u32, duty_cycle: f64) -> HorusResult<()>;
+
+    /// Frequency of a started PWM output
+    fn pwm_frequency(&self, channel: u32) -> Option<f64>;
+
+    /// Stop a PWM output
+    fn release_pwm(&mut self, channel: u32) -> HorusResult<()>;
+
+    /// Set the high time of each PWM period (servo pulses)
+    fn set_pulse_width(&mut self, channel: u32, width: Duration) -> HorusResult<()> {
+        let frequency = self
+            .pwm_frequency(channel)
+            .ok_or_else(|| HorusError::driver(format!("PWM channel {} is not started", channel)))?;
+        self.set_duty_cycle(channel, width.as_secs_f64() * frequency)
+    }
+}
+
+/// Check a duty cycle and clamp it to 0..=1
+pub(crate) fn checked_duty_cycle(duty_cycle: f64) -> HorusResult<f64> {
+    if duty_cycle.is_nan() {
+        return Err(HorusError::driver("PWM duty cycle is NaN"));
+    }
+    Ok(duty_cycle.clamp(0.0, 1.0))
+}
+
+/// Check a PWM frequency
+pub(crate) fn checked_frequency(frequency_hz: f64) -> HorusResult<f64> {
+    if !(frequency_hz.is_finite() && frequency_hz > 0.0) {
+        return Err(HorusError::driver(format!(
+            "Invalid PWM frequency {} Hz",
+            frequency_hz
+        )));
+    }
+    Ok(frequency_hz)
+}
+
+/// Hobby servo driven by a PWM output
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PwmServo {
+    /// PWM channel (pin) of the servo signal
+    pub channel: u32,
+    /// Pulse frequency in Hz
+    pub frequency_hz: f64,
+    /// Pulse width at `min_angle` in microseconds
+    pub min_pulse_us: f64,
+    /// Pulse width at `max_angle` in microseconds
+    pub max_pulse_us: f64,
+    /// Lowest angle in radians
+    pub min_angle: f64,
+    /// Highest angle in radians
+    pub max_angle: f64,
+}
+
+impl PwmServo {
+    /// Standard 50 Hz servo, 500-2500 µs over ±90°
+    pub fn new(channel: u32) -> Self {
+        Self {
+            channel,
+            frequency_hz: 50.0,
+            min_pulse_us: 500.0,
+            max_pulse_us: 2500.0,
+            min_angle: -std::f64::consts::FRAC_PI_2,
+            max_angle: std::f64::consts::FRAC_PI_2,
+        }
+    }
+
+    /// Pulse width of an angle, clamped to the servo's range
+    pub fn pulse_width(&self, angle: f64) -> Duration {
+        let fraction =
+            ((angle - self.min_angle) / (self.max_angle - self.min_angle)).clamp(0.0, 1.0);
+        let micros = self.min_pulse_us + fraction * (self.max_pulse_us - self.min_pulse_us);
+        Duration::from_secs_f64(micros * 1e-6)
+    }
+}
+
+/// Single-board computer family
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GpioBoard {
+    RaspberryPi,
+    Jetson,
+    Unknown,
+}
+
+impl GpioBoard {
+    /// Identify the board from the device tree model
+    pub fn detect() -> Self {
+        std::fs::read_to_string("/proc/device-tree/model")
+            .map(|model| Self::from_model(&model))
+            .unwrap_or(Self::Unknown)
+    }
+
+    /// Board of a device tree model string
+    pub fn from_model(model: &str) -> Self {
+        if model.contains("Raspberry Pi") {
+            Self::RaspberryPi
+        } else if model.contains("Jetson") || model.contains("NVIDIA") {
+            Self::Jetson
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+/// GPIO backend selection
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum GpioDriverBackend {
+    #[default]
+    Simulation,
+    #[cfg(feature = "raspberry-pi")]
+    RaspberryPi,
+    #[cfg(feature = "jetson")]
+    Jetson,
+}
+
+/// Type-erased GPIO driver for runtime backend selection
+pub enum GpioDriver {
+    Simulation(SimulationGpio),
+    #[cfg(feature = "raspberry-pi")]
+    RaspberryPi(RaspberryPiGpio),
+    #[cfg(feature = "jetson")]
+    Jetson(JetsonGpio),
+}
+
+impl GpioDriver {
+    /// Open the GPIO of the specified backend
+    pub fn new(backend: GpioDriverBackend) -> HorusResult<Self> {
+        match backend {
+            GpioDriverBackend::Simulation => Ok(Self::simulation()),
+            #[cfg(feature = "raspberry-pi")]
+            GpioDriverBackend::RaspberryPi => Ok(Self::RaspberryPi(RaspberryPiGpio::new()?)),
+            #[cfg(feature = "jetson")]
+            GpioDriverBackend::Jetson => {
+                Ok(Self::Jetson(JetsonGpio::new(JetsonGpioConfig::default())?))
+            }
+        }
+    }
+
+    /// Create a simulation driver (always available)
+    pub fn simulation() -> Self {
+        Self::Simulation(SimulationGpio::new())
+    }
+
+    /// Open the GPIO of the board this runs on
+    ///
+    /// Falls back to simulation on unknown boards, when the board's backend
+    /// is not compiled in, or when its GPIO cannot be opened.
+    pub fn detect() -> Self {
+        match GpioBoard::detect() {
+            #[cfg(feature = "raspberry-pi")]
+            GpioBoard::RaspberryPi => {
+                if let Ok(gpio) = RaspberryPiGpio::new() {
+                    return Self::RaspberryPi(gpio);
+                }
+            }
+            #[cfg(feature = "jetson")]
+            GpioBoard::Jetson => {
+                if let Ok(gpio) = JetsonGpio::new(JetsonGpioConfig::default()) {
+                    return Self::Jetson(gpio);
+                }
+            }
+            _ => {}
+        }
+        Self::simulation()
+    }
+
+    /// Whether this drives real pins
+    pub fn is_hardware(&self) -> bool {
+        !matches!(self, Self::Simulation(_))
+    }
+}
+
+impl GpioHal for GpioDriver {
+    fn backend_name(&self) -> &'static str {
+        match self {
+            Self::Simulation(d) => d.backend_name(),
+            #[cfg(feature = "raspberry-pi")]
+            Self::RaspberryPi(d) => d.backend_name(),
+            #[cfg(feature = "jetson")]
+            Self::Jetson(d) => d.backend_name(),
+        }
+    }
+
+    fn setup_pin(&mut self, pin: u32, mode: PinMode, initial: bool) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.setup_pin(pin, mode, initial),
+            #[cfg(feature = "raspberry-pi")]
+            Self::RaspberryPi(d) => d.setup_pin(pin, mode, initial),
+            #[cfg(feature = "jetson")]
+            Self::Jetson(d) => d.setup_pin(pin, mode, initial),
+        }
+    }
+
+    fn read_pin(&mut self, pin: u32) -> HorusResult<bool> {
+        match self {
+            Self::Simulation(d) => d.read_pin(pin),
+            #[cfg(feature = "raspberry-pi")]
+            Self::RaspberryPi(d) => d.read_pin(pin),
+            #[cfg(feature = "jetson")]
+            Self::Jetson(d) => d.read_pin(pin),
+        }
+    }
+
+    fn write_pin(&mut self, pin: u32, value: bool) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.write_pin(pin, value),
+            #[cfg(feature = "raspberry-pi")]
+            Self::RaspberryPi(d) => d.write_pin(pin, value),
+            #[cfg(feature = "jetson")]
+            Self::Jetson(d) => d.write_pin(pin, value),
+        }
+    }
+
+    fn release_pin(&mut self, pin: u32) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.release_pin(pin),
+            #[cfg(feature = "raspberry-pi")]
+            Self::RaspberryPi(d) => d.release_pin(pin),
+            #[cfg(feature = "jetson")]
+            Self::Jetson(d) => d.release_pin(pin),
+        }
+    }
+
+    fn setup_pwm(&mut self, channel: u32, frequency_hz: f64) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.setup_pwm(channel, frequency_hz),
+            #[cfg(feature = "raspberry-pi")]
+            Self::RaspberryPi(d) => d.setup_pwm(channel, frequency_hz),
+            #[cfg(feature = "jetson")]
+            Self::Jetson(d) => d.setup_pwm(channel, frequency_hz),
+        }
+    }
+
+    fn set_duty_cycle(&mut self, channel: u32, duty_cycle: f64) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.set_duty_cycle(channel, duty_cycle),
+            #[cfg(feature = "raspberry-pi")]
+            Self::RaspberryPi(d) => d.set_duty_cycle(channel, duty_cycle),
+            #[cfg(feature = "jetson")]
+            Self::Jetson(d) => d.set_duty_cycle(channel, duty_cycle),
+        }
+    }
+
+    fn pwm_frequency(&self, channel: u32) -> Option<f64> {
+        match self {
+            Self::Simulation(d) => d.pwm_frequency(channel),
+            #[cfg(feature = "raspberry-pi")]
+            Self::RaspberryPi(d) => d.pwm_frequency(channel),
+            #[cfg(feature = "jetson")]
+            Self::Jetson(d) => d.pwm_frequency(channel),
+        }
+    }
+
+    fn release_pwm(&mut self, channel: u32) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.release_pwm(channel),
+            #[cfg(feature = "raspberry-pi")]
+            Self::RaspberryPi(d) => d.release_pwm(channel),
+            #[cfg(feature = "jetson")]
+            Self::Jetson(d) => d.release_pwm(channel),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_board_from_model() {
+        assert_eq!(
+            GpioBoard::from_model("Raspberry Pi 4 Model B Rev 1.4\0"),
+            GpioBoard::RaspberryPi
+        );
+        assert_eq!(
+            GpioBoard::from_model("NVIDIA Jetson Xavier NX Developer Kit\0"),
+            GpioBoard::Jetson
+        );
+        assert_eq!(GpioBoard::from_model("QEMU Virt"), GpioBoard::Unknown);
+    }
+
+    #[test]
+    fn test_servo_pulse_width() {
+        let servo = PwmServo::new(18);
+        assert_eq!(servo.pulse_width(0.0), Duration::from_micros(1500));
+        assert_eq!(servo.pulse_width(-3.0), Duration::from_micros(500));
+        assert_eq!(servo.pulse_width(3.0), Duration::from_micros(2500));
+
+        let sim = SimulationGpio::new();
+        let mut gpio = GpioDriver::Simulation(sim.clone());
+        assert!(gpio.set_pulse_width(18, servo.pulse_width(0.0)).is_err());
+        gpio.setup_pwm(18, servo.frequency_hz).unwrap();
+        gpio.set_pulse_width(18, servo.pulse_width(0.0)).unwrap();
+        assert!((sim.duty_cycle(18).unwrap() - 0.075).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,192 @@
+//! Raspberry Pi GPIO backend (rppal)
+//!
+//! Pins are BCM numbers. PWM on BCM 12/18 (PWM0) and 13/19 (PWM1) uses the
+//! hardware PWM peripheral, which needs the `pwm-2chan` overlay in
+//! `/boot/config.txt`; every other pin falls back to software PWM.
+
+use std::collections::HashMap;
+
+use horus_core::error::{HorusError, HorusResult};
+use rppal::gpio::{Gpio, InputPin, Level, OutputPin};
+use rppal::pwm::{Channel, Polarity, Pwm};
+
+use super::{checked_duty_cycle, checked_frequency, GpioHal, PinMode};
+
+enum PwmOutput {
+    Hardware(Pwm),
+    Software(OutputPin),
+}
+
+/// Raspberry Pi GPIO backend
+pub struct RaspberryPiGpio {
+    gpio: Gpio,
+    inputs: HashMap<u32, InputPin>,
+    outputs: HashMap<u32, OutputPin>,
+    /// PWM output and frequency of each channel
+    pwm: HashMap<u32, (PwmOutput, f64)>,
+}
+
+impl RaspberryPiGpio {
+    /// Open `/dev/gpiomem`
+    pub fn new() -> HorusResult<Self> {
+        let gpio = Gpio::new()
+            .map_err(|e| HorusError::driver(format!("Failed to open Raspberry Pi GPIO: {}", e)))?;
+        Ok(Self {
+            gpio,
+            inputs: HashMap::new(),
+            outputs: HashMap::new(),
+            pwm: HashMap::new(),
+        })
+    }
+
+    fn bcm(pin: u32) -> HorusResult<u8> {
+        u8::try_from(pin)
+            .ok()
+            .filter(|&bcm| bcm < 28)
+            .ok_or_else(|| HorusError::driver(format!("BCM pin {} does not exist", pin)))
+    }
+
+    /// Hardware PWM channel routed to a BCM pin
+    fn hardware_channel(pin: u32) -> Option<Channel> {
+        match pin {
+            12 | 18 => Some(Channel::Pwm0),
+            13 | 19 => Some(Channel::Pwm1),
+            _ => None,
+        }
+    }
+
+    fn take_pin(&mut self, pin: u32) -> HorusResult<rppal::gpio::Pin> {
+        self.inputs.remove(&pin);
+        self.outputs.remove(&pin);
+        self.gpio
+            .get(Self::bcm(pin)?)
+            .map_err(|e| HorusError::driver(format!("Failed to claim GPIO {}: {}", pin, e)))
+    }
+}
+
+fn level(value: bool) -> Level {
+    if value {
+        Level::High
+    } else {
+        Level::Low
+    }
+}
+
+impl GpioHal for RaspberryPiGpio {
+    fn backend_name(&self) -> &'static str {
+        "raspberry_pi"
+    }
+
+    fn setup_pin(&mut self, pin: u32, mode: PinMode, initial: bool) -> HorusResult<()> {
+        let claimed = self.take_pin(pin)?;
+        match mode {
+            PinMode::Input => {
+                self.inputs.insert(pin, claimed.into_input());
+            }
+            PinMode::InputPullUp => {
+                self.inputs.insert(pin, claimed.into_input_pullup());
+            }
+            PinMode::InputPullDown => {
+                self.inputs.insert(pin, claimed.into_input_pulldown());
+            }
+            PinMode::Output => {
+                let mut output = claimed.into_output();
+                output.write(level(initial));
+                self.outputs.insert(pin, output);
+            }
+        }
+        Ok(())
+    }
+
+    fn read_pin(&mut self, pin: u32) -> HorusResult<bool> {
+        if let Some(input) = self.inputs.get(&pin) {
+            Ok(input.is_high())
+        } else if let Some(output) = self.outputs.get(&pin) {
+            Ok(output.is_set_high())
+        } else {
+            Err(HorusError::driver(format!("GPIO {} is not set up", pin)))
+        }
+    }
+
+    fn write_pin(&mut self, pin: u32, value: bool) -> HorusResult<()> {
+        let output = self
+            .outputs
+            .get_mut(&pin)
+            .ok_or_else(|| HorusError::driver(format!("GPIO {} is not an output", pin)))?;
+        output.write(level(value));
+        Ok(())
+    }
+
+    fn release_pin(&mut self, pin: u32) -> HorusResult<()> {
+        if let Some(mut output) = self.outputs.remove(&pin) {
+            output.set_low();
+        }
+        // Dropping the pin restores its previous mode
+        self.inputs.remove(&pin);
+        Ok(())
+    }
+
+    fn setup_pwm(&mut self, channel: u32, frequency_hz: f64) -> HorusResult<()> {
+        let frequency = checked_frequency(frequency_hz)?;
+        self.release_pwm(channel)?;
+        let output = match Self::hardware_channel(channel) {
+            Some(hardware) => PwmOutput::Hardware(
+                Pwm::with_frequency(hardware, frequency, 0.0, Polarity::Normal, true).map_err(
+                    |e| {
+                        HorusError::driver(format!(
+                            "Failed to start hardware PWM on GPIO {}: {}",
+                            channel, e
+                        ))
+                    },
+                )?,
+            ),
+            None => {
+                let mut output = self.take_pin(channel)?.into_output_low();
+                output.set_pwm_frequency(frequency, 0.0).map_err(|e| {
+                    HorusError::driver(format!(
+                        "Failed to start software PWM on GPIO {}: {}",
+                        channel, e
+                    ))
+                })?;
+                PwmOutput::Software(output)
+            }
+        };
+        self.pwm.insert(channel, (output, frequency));
+        Ok(())
+    }
+
+    fn set_duty_cycle(&mut self, channel: u32, duty_cycle: f64) -> HorusResult<()> {
+        let duty_cycle = checked_duty_cycle(duty_cycle)?;
+        let (output, frequency) = self
+            .pwm
+            .get_mut(&channel)
+            .ok_or_else(|| HorusError::driver(format!("PWM channel {} is not started", channel)))?;
+        let result = match output {
+            PwmOutput::Hardware(pwm) => pwm.set_duty_cycle(duty_cycle).map_err(|e| e.to_string()),
+            PwmOutput::Software(pin) => pin
+                .set_pwm_frequency(*frequency, duty_cycle)
+                .map_err(|e| e.to_string()),
+        };
+        result.map_err(|e| {
+            HorusError::driver(format!("Failed to set PWM duty on GPIO {}: {}", channel, e))
+        })
+    }
+
+    fn pwm_frequency(&self, channel: u32) -> Option<f64> {
+        self.pwm.get(&channel).map(|&(_, frequency)| frequency)
+    }
+
+    fn release_pwm(&mut self, channel: u32) -> HorusResult<()> {
+        match self.pwm.remove(&channel) {
+            Some((PwmOutput::Hardware(pwm), _)) => {
+                let _ = pwm.disable();
+            }
+            Some((PwmOutput::Software(mut pin), _)) => {
+                let _ = pin.clear_pwm();
+                pin.set_low();
+            }
+            None => {}
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,176 @@
+//! Simulation GPIO backend
+//!
+//! Always-available backend that keeps pin levels and PWM settings in
+//! memory. Clones share their state, so a test can keep a handle to inject
+//! inputs and check outputs of a node that owns the driver.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use horus_core::error::{HorusError, HorusResult};
+
+use super::{checked_duty_cycle, checked_frequency, GpioHal, PinMode};
+
+#[derive(Debug, Default)]
+struct SimulationState {
+    /// Mode and level of each claimed pin
+    pins: HashMap<u32, (PinMode, bool)>,
+    /// Levels injected with `set_input`
+    inputs: HashMap<u32, bool>,
+    /// Frequency and duty cycle of each PWM output
+    pwm: HashMap<u32, (f64, f64)>,
+}
+
+/// Simulation GPIO backend
+#[derive(Debug, Clone, Default)]
+pub struct SimulationGpio {
+    state: Arc<Mutex<SimulationState>>,
+}
+
+impl SimulationGpio {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, SimulationState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Drive an input pin from outside (overrides its pull)
+    pub fn set_input(&self, pin: u32, level: bool) {
+        self.state().inputs.insert(pin, level);
+    }
+
+    /// Level of an output pin
+    pub fn output(&self, pin: u32) -> Option<bool> {
+        match self.state().pins.get(&pin) {
+            Some(&(PinMode::Output, level)) => Some(level),
+            _ => None,
+        }
+    }
+
+    /// Mode of a claimed pin
+    pub fn mode(&self, pin: u32) -> Option<PinMode> {
+        self.state().pins.get(&pin).map(|&(mode, _)| mode)
+    }
+
+    /// Duty cycle of a PWM output
+    pub fn duty_cycle(&self, channel: u32) -> Option<f64> {
+        self.state().pwm.get(&channel).map(|&(_, duty)| duty)
+    }
+
+    /// High time of each period of a PWM output
+    pub fn pulse_width(&self, channel: u32) -> Option<Duration> {
+        self.state()
+            .pwm
+            .get(&channel)
+            .map(|&(frequency, duty)| Duration::from_secs_f64(duty / frequency))
+    }
+}
+
+impl GpioHal for SimulationGpio {
+    fn backend_name(&self) -> &'static str {
+        "simulation"
+    }
+
+    fn setup_pin(&mut self, pin: u32, mode: PinMode, initial: bool) -> HorusResult<()> {
+        let level = match mode {
+            PinMode::Output => initial,
+            PinMode::InputPullUp => true,
+            PinMode::Input | PinMode::InputPullDown => false,
+        };
+        self.state().pins.insert(pin, (mode, level));
+        Ok(())
+    }
+
+    fn read_pin(&mut self, pin: u32) -> HorusResult<bool> {
+        let state = self.state();
+        let &(mode, level) = state
+            .pins
+            .get(&pin)
+            .ok_or_else(|| HorusError::driver(format!("GPIO {} is not set up", pin)))?;
+        if mode.is_input() {
+            Ok(state.inputs.get(&pin).copied().unwrap_or(level))
+        } else {
+            Ok(level)
+        }
+    }
+
+    fn write_pin(&mut self, pin: u32, value: bool) -> HorusResult<()> {
+        match self.state().pins.get_mut(&pin) {
+            Some((PinMode::Output, level)) => {
+                *level = value;
+                Ok(())
+            }
+            Some(_) => Err(HorusError::driver(format!("GPIO {} is not an output", pin))),
+            None => Err(HorusError::driver(format!("GPIO {} is not set up", pin))),
+        }
+    }
+
+    fn release_pin(&mut self, pin: u32) -> HorusResult<()> {
+        self.state().pins.remove(&pin);
+        Ok(())
+    }
+
+    fn setup_pwm(&mut self, channel: u32, frequency_hz: f64) -> HorusResult<()> {
+        let frequency = checked_frequency(frequency_hz)?;
+        self.state().pwm.insert(channel, (frequency, 0.0));
+        Ok(())
+    }
+
+    fn set_duty_cycle(&mut self, channel: u32, duty_cycle: f64) -> HorusResult<()> {
+        let duty_cycle = checked_duty_cycle(duty_cycle)?;
+        match self.state().pwm.get_mut(&channel) {
+            Some((_, duty)) => {
+                *duty = duty_cycle;
+                Ok(())
+            }
+            None => Err(HorusError::driver(format!(
+                "PWM channel {} is not started",
+                channel
+            ))),
+        }
+    }
+
+    fn pwm_frequency(&self, channel: u32) -> Option<f64> {
+        self.state()
+            .pwm
+            .get(&channel)
+            .map(|&(frequency, _)| frequency)
+    }
+
+    fn release_pwm(&mut self, channel: u32) -> HorusResult<()> {
+        self.state().pwm.remove(&channel);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simulation_pins() {
+        let mut gpio = SimulationGpio::new();
+        let handle = gpio.clone();
+        assert!(gpio.read_pin(4).is_err());
+
+        gpio.setup_pin(4, PinMode::InputPullUp, false).unwrap();
+        gpio.setup_pin(5, PinMode::Output, true).unwrap();
+        assert!(gpio.read_pin(4).unwrap());
+        handle.set_input(4, false);
+        assert!(!gpio.read_pin(4).unwrap());
+
+        assert_eq!(handle.output(5), Some(true));
+        gpio.write_pin(5, false).unwrap();
+        assert_eq!(handle.output(5), Some(false));
+        assert!(gpio.write_pin(4, true).is_err());
+
+        gpio.setup_pwm(12, 1000.0).unwrap();
+        gpio.set_duty_cycle(12, 1.5).unwrap();
+        assert_eq!(handle.duty_cycle(12), Some(1.0));
+        assert!(gpio.set_duty_cycle(12, f64::NAN).is_err());
+        assert!(gpio.setup_pwm(13, 0.0).is_err());
+    }
+}
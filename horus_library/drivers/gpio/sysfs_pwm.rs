@@ -0,0 +1,132 @@
+//! Linux sysfs PWM (`/sys/class/pwm`)
+//!
+//! Used by board backends whose PWM controllers only have a kernel driver
+//! (Jetson). Channels are the `pwmN` outputs of one `pwmchip`.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+use horus_core::error::{HorusError, HorusResult};
+
+use super::{checked_duty_cycle, checked_frequency};
+
+/// PWM outputs of one sysfs `pwmchip`
+#[derive(Debug)]
+pub struct SysfsPwm {
+    chip: PathBuf,
+    /// Period in nanoseconds of each started channel
+    periods: HashMap<u32, u64>,
+}
+
+impl SysfsPwm {
+    /// Use a PWM chip directory, e.g. `/sys/class/pwm/pwmchip0`
+    pub fn new(chip: impl Into<PathBuf>) -> Self {
+        Self {
+            chip: chip.into(),
+            periods: HashMap::new(),
+        }
+    }
+
+    pub fn chip(&self) -> &Path {
+        &self.chip
+    }
+
+    fn channel_file(&self, channel: u32, name: &str) -> PathBuf {
+        self.chip.join(format!("pwm{}", channel)).join(name)
+    }
+
+    fn write(path: &Path, value: impl Display) -> HorusResult<()> {
+        std::fs::write(path, value.to_string())
+            .map_err(|e| HorusError::driver(format!("Failed to write {}: {}", path.display(), e)))
+    }
+
+    /// Export a channel and start it at `frequency_hz` with a duty cycle of 0
+    pub fn setup(&mut self, channel: u32, frequency_hz: f64) -> HorusResult<()> {
+        let period = (1e9 / checked_frequency(frequency_hz)?).round() as u64;
+        let directory = self.chip.join(format!("pwm{}", channel));
+        if !directory.exists() {
+            Self::write(&self.chip.join("export"), channel)?;
+            // udev fixes the permissions of the new directory shortly after
+            for _ in 0..20 {
+                if directory.join("period").exists() {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(5));
+            }
+        }
+
+        // Duty first: it must never exceed the period
+        Self::write(&self.channel_file(channel, "duty_cycle"), 0)?;
+        Self::write(&self.channel_file(channel, "period"), period)?;
+        Self::write(&self.channel_file(channel, "enable"), 1)?;
+        self.periods.insert(channel, period);
+        Ok(())
+    }
+
+    /// Set the duty cycle of a started channel (0.0 to 1.0)
+    pub fn set_duty_cycle(&mut self, channel: u32, duty_cycle: f64) -> HorusResult<()> {
+        let period = *self
+            .periods
+            .get(&channel)
+            .ok_or_else(|| HorusError::driver(format!("PWM channel {} is not started", channel)))?;
+        let duty = (period as f64 * checked_duty_cycle(duty_cycle)?).round() as u64;
+        Self::write(&self.channel_file(channel, "duty_cycle"), duty)
+    }
+
+    /// Frequency of a started channel
+    pub fn frequency(&self, channel: u32) -> Option<f64> {
+        self.periods
+            .get(&channel)
+            .map(|&period| 1e9 / period as f64)
+    }
+
+    /// Disable and unexport a channel
+    pub fn release(&mut self, channel: u32) -> HorusResult<()> {
+        if self.periods.remove(&channel).is_some() {
+            Self::write(&self.channel_file(channel, "enable"), 0)?;
+            Self::write(&self.chip.join("unexport"), channel)?;
+        }
+        Ok(())
+    }
+}
+
+impl Drop for SysfsPwm {
+    fn drop(&mut self) {
+        let channels: Vec<u32> = self.periods.keys().copied().collect();
+        for channel in channels {
+            let _ = self.release(channel);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sysfs_pwm() {
+        let chip = std::env::temp_dir().join(format!("horus_pwmchip_{}", std::process::id()));
+        // An exported channel, as the kernel would create it
+        std::fs::create_dir_all(chip.join("pwm2")).unwrap();
+        let read = |name: &str| std::fs::read_to_string(chip.join("pwm2").join(name)).unwrap();
+
+        let mut pwm = SysfsPwm::new(&chip);
+        assert!(pwm.set_duty_cycle(2, 0.5).is_err());
+        pwm.setup(2, 50.0).unwrap();
+        assert_eq!(read("period"), "20000000");
+        assert_eq!(read("enable"), "1");
+        assert_eq!(pwm.frequency(2), Some(50.0));
+
+        pwm.set_duty_cycle(2, 0.075).unwrap();
+        assert_eq!(read("duty_cycle"), "1500000");
+
+        pwm.release(2).unwrap();
+        assert_eq!(read("enable"), "0");
+        assert_eq!(std::fs::read_to_string(chip.join("unexport")).unwrap(), "2");
+        assert_eq!(pwm.frequency(2), None);
+        std::fs::remove_dir_all(&chip).ok();
+    }
+}
@@ -0,0 +1,366 @@
+//! 3D LiDAR drivers
+//!
+//! This module provides drivers for multi-beam 3D LiDARs. Every driver
+//! returns one full rotation per read as a [`PointCloud`] with the
+//! [`PointCloud::lidar_layout`] fields plus a per-point `time` field.
+//!
+//! # Available Drivers
+//!
+//! - `SimulationLidar3dDriver` - Always available, generates synthetic scans
+//! - `VelodyneDriver` - Velodyne VLP-16, VLP-32C and HDL-32E (UDP)
+//! - `OusterDriver` - Ouster OS0/OS1/OS2 legacy data format (UDP)
+//!
+//! The network drivers only need the standard library and are always
+//! available. They receive packets over UDP or replay a pcap capture
+//! ([`PcapReplay`]) for offline testing. Scans can be deskewed with a
+//! [`MotionCompensator`] reading the sensor motion from HFrame.
+//!
+//! Ring 0 is the lowest beam for every sensor.
+
+mod motion;
+mod ouster;
+mod pcap;
+mod simulation;
+mod source;
+mod velodyne;
+
+// Re-exports
+pub use motion::MotionCompensator;
+pub use ouster::{OusterDecoder, OusterDriver, OusterMetadata};
+pub use pcap::{PcapReader, UdpDatagram};
+pub use simulation::{SimulationLidar3dConfig, SimulationLidar3dDriver};
+pub use velodyne::{
+    resolve_top_of_hour, LaserCorrection, VelodyneCalibration, VelodyneDecoder, VelodyneDriver,
+    VelodyneModel,
+};
+
+use std::path::PathBuf;
+
+use horus_core::driver::DriverStatus;
+use horus_core::error::HorusResult;
+
+use crate::{PointCloud, PointFieldType};
+
+/// Network 3D LiDAR configuration shared by the hardware backends
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lidar3dConfig {
+    /// Local address the data packets are received on
+    pub bind_address: String,
+    /// UDP port of the data packets (Velodyne 2368, Ouster 7502)
+    pub port: u16,
+    /// Replay this capture instead of receiving packets
+    pub pcap: Option<PcapReplay>,
+    /// Frame id of the published clouds
+    pub frame_id: String,
+    /// Points closer than this are dropped (meters)
+    pub range_min: f32,
+    /// Points farther than this are dropped (meters)
+    pub range_max: f32,
+    /// Stamp points with the sensor clock instead of the receive time
+    ///
+    /// Only meaningful when the sensor is synchronized (GPS/PPS or PTP).
+    pub sensor_time: bool,
+    /// How long a read waits for the next packet in milliseconds
+    pub timeout_ms: u64,
+    /// Rotation rate configured on the sensor in Hz
+    pub rotation_rate: f32,
+}
+
+impl Default for Lidar3dConfig {
+    fn default() -> Self {
+        Self {
+            bind_address: "0.0.0.0".to_string(),
+            port: 2368,
+            pcap: None,
+            frame_id: "lidar".to_string(),
+            range_min: 0.3,
+            range_max: 150.0,
+            sensor_time: false,
+            timeout_ms: 1000,
+            rotation_rate: 10.0,
+        }
+    }
+}
+
+/// Offline input from a pcap capture
+#[derive(Debug, Clone, PartialEq)]
+pub struct PcapReplay {
+    /// Capture file (libpcap format, Ethernet or Linux cooked)
+    pub path: PathBuf,
+    /// Pace packets at capture speed instead of as fast as possible
+    pub realtime: bool,
+    /// Start over at the end of the capture
+    pub repeat: bool,
+}
+
+impl PcapReplay {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            realtime: false,
+            repeat: false,
+        }
+    }
+}
+
+/// One return of a 3D LiDAR
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LidarPoint {
+    /// Position in the sensor frame in meters
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub intensity: f32,
+    /// Beam index, 0 is the lowest beam
+    pub ring: u16,
+    /// Capture time in nanoseconds
+    pub timestamp: u64,
+}
+
+impl LidarPoint {
+    /// Distance from the sensor origin in meters
+    pub fn range(&self) -> f32 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+}
+
+/// One full rotation of a 3D LiDAR
+#[derive(Debug, Clone, Default)]
+pub struct Lidar3dFrame {
+    /// Points with `x`, `y`, `z`, `intensity`, `ring` and `time` (seconds
+    /// after the cloud timestamp, which is the first point's time)
+    pub cloud: PointCloud,
+    /// Time of the last point in nanoseconds
+    pub end_time: u64,
+    /// Number of packets in the scan
+    pub packets: u32,
+    /// Points were deskewed to the sensor pose at `end_time`
+    pub compensated: bool,
+}
+
+/// Build the cloud of a scan
+pub fn scan_to_cloud(points: &[LidarPoint], frame_id: &str) -> PointCloud {
+    let mut cloud = PointCloud::lidar_layout().with_frame_id(frame_id);
+    let time_offset = cloud.point_step;
+    cloud
+        .add_field(crate::PointField::new(
+            "time",
+            time_offset,
+            PointFieldType::Float32,
+            1,
+        ))
+        .expect("lidar layout leaves room for the time field");
+    cloud.point_step += PointFieldType::Float32.size();
+    cloud.resize(points.len());
+
+    let start = points.iter().map(|p| p.timestamp).min().unwrap_or(0);
+    cloud.timestamp = start;
+
+    let x = cloud.accessor::<f32>("x").expect("x field");
+    let y = cloud.accessor::<f32>("y").expect("y field");
+    let z = cloud.accessor::<f32>("z").expect("z field");
+    let intensity = cloud.accessor::<f32>("intensity").expect("intensity field");
+    let ring = cloud.accessor::<u16>("ring").expect("ring field");
+    let time = cloud.accessor::<f32>("time").expect("time field");
+    let step = cloud.point_step as usize;
+    for (record, point) in cloud.data.chunks_exact_mut(step).zip(points) {
+        x.set(record, point.x);
+        y.set(record, point.y);
+        z.set(record, point.z);
+        intensity.set(record, point.intensity);
+        ring.set(record, point.ring);
+        time.set(record, ((point.timestamp - start) as f64 * 1e-9) as f32);
+    }
+    cloud
+}
+
+/// Finish a scan: deskew it when a compensator is set and build its cloud
+pub(crate) fn build_frame(
+    mut points: Vec<LidarPoint>,
+    packets: u32,
+    frame_id: &str,
+    compensator: Option<&MotionCompensator>,
+) -> Lidar3dFrame {
+    let end_time = points.iter().map(|p| p.timestamp).max().unwrap_or(0);
+    let compensated = compensator.is_some_and(|c| c.compensate(&mut points, end_time).is_ok());
+    Lidar3dFrame {
+        cloud: scan_to_cloud(&points, frame_id),
+        end_time,
+        packets,
+        compensated,
+    }
+}
+
+/// Ring of each beam: the rank of its elevation angle, lowest first
+pub fn rings_by_elevation(elevations: &[f64]) -> Vec<u16> {
+    let mut order: Vec<usize> = (0..elevations.len()).collect();
+    order.sort_by(|&a, &b| elevations[a].total_cmp(&elevations[b]));
+    let mut rings = vec![0u16; elevations.len()];
+    for (ring, beam) in order.into_iter().enumerate() {
+        rings[beam] = ring as u16;
+    }
+    rings
+}
+
+/// Enum of all available 3D LiDAR driver backends
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Lidar3dDriverBackend {
+    /// Simulation driver (always available)
+    #[default]
+    Simulation,
+    /// Velodyne VLP-16 with its default calibration
+    Velodyne,
+    /// Ouster OS1-64 with nominal beam intrinsics
+    Ouster,
+}
+
+/// Type-erased 3D LiDAR driver for runtime backend selection
+pub enum Lidar3dDriver {
+    Simulation(SimulationLidar3dDriver),
+    Velodyne(VelodyneDriver),
+    Ouster(OusterDriver),
+}
+
+impl Lidar3dDriver {
+    /// Create a new 3D LiDAR driver with the specified backend
+    pub fn new(backend: Lidar3dDriverBackend) -> HorusResult<Self> {
+        match backend {
+            Lidar3dDriverBackend::Simulation => {
+                Ok(Self::Simulation(SimulationLidar3dDriver::new()))
+            }
+            Lidar3dDriverBackend::Velodyne => Ok(Self::Velodyne(VelodyneDriver::new(
+                Lidar3dConfig::default(),
+                VelodyneModel::Vlp16,
+            )?)),
+            Lidar3dDriverBackend::Ouster => Ok(Self::Ouster(OusterDriver::new(
+                Lidar3dConfig {
+                    port: 7502,
+                    ..Default::default()
+                },
+                OusterMetadata::default(),
+            )?)),
+        }
+    }
+
+    /// Create a simulation driver (always available)
+    pub fn simulation() -> Self {
+        Self::Simulation(SimulationLidar3dDriver::new())
+    }
+
+    /// Deskew scans with the sensor motion from HFrame (not for simulation)
+    pub fn set_motion_compensation(&mut self, compensator: Option<MotionCompensator>) {
+        match self {
+            Self::Simulation(_) => {}
+            Self::Velodyne(d) => d.set_motion_compensation(compensator),
+            Self::Ouster(d) => d.set_motion_compensation(compensator),
+        }
+    }
+
+    // ========================================================================
+    // Lifecycle methods
+    // ========================================================================
+
+    pub fn init(&mut self) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.init(),
+            Self::Velodyne(d) => d.init(),
+            Self::Ouster(d) => d.init(),
+        }
+    }
+
+    pub fn shutdown(&mut self) -> HorusResult<()> {
+        match self {
+            Self::Simulation(d) => d.shutdown(),
+            Self::Velodyne(d) => d.shutdown(),
+            Self::Ouster(d) => d.shutdown(),
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        match self {
+            Self::Simulation(d) => d.is_available(),
+            Self::Velodyne(d) => d.is_available(),
+            Self::Ouster(d) => d.is_available(),
+        }
+    }
+
+    pub fn status(&self) -> DriverStatus {
+        match self {
+            Self::Simulation(d) => d.status(),
+            Self::Velodyne(d) => d.status(),
+            Self::Ouster(d) => d.status(),
+        }
+    }
+
+    // ========================================================================
+    // Sensor methods
+    // ========================================================================
+
+    /// Read the next full rotation
+    pub fn read(&mut self) -> HorusResult<Lidar3dFrame> {
+        match self {
+            Self::Simulation(d) => d.read(),
+            Self::Velodyne(d) => d.read(),
+            Self::Ouster(d) => d.read(),
+        }
+    }
+
+    pub fn has_data(&self) -> bool {
+        match self {
+            Self::Simulation(d) => d.has_data(),
+            Self::Velodyne(d) => d.has_data(),
+            Self::Ouster(d) => d.has_data(),
+        }
+    }
+
+    pub fn sample_rate(&self) -> Option<f32> {
+        match self {
+            Self::Simulation(d) => d.sample_rate(),
+            Self::Velodyne(d) => d.sample_rate(),
+            Self::Ouster(d) => d.sample_rate(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scan_to_cloud() {
+        let points = [
+            LidarPoint {
+                x: 1.0,
+                y: 2.0,
+                z: 3.0,
+                intensity: 40.0,
+                ring: 7,
+                timestamp: 1_000_050_000,
+            },
+            LidarPoint {
+                x: -1.0,
+                ring: 0,
+                timestamp: 1_000_000_000,
+                ..Default::default()
+            },
+        ];
+        let cloud = scan_to_cloud(&points, "velodyne");
+        assert_eq!(cloud.point_count(), 2);
+        assert_eq!(cloud.timestamp, 1_000_000_000);
+        assert_eq!(cloud.point_step, 22);
+        assert_eq!(cloud.get::<f32>(0, "z"), Some(3.0));
+        assert_eq!(cloud.get::<u16>(0, "ring"), Some(7));
+        assert_eq!(cloud.get::<f32>(1, "x"), Some(-1.0));
+        let time = cloud.get::<f32>(0, "time").unwrap();
+        assert!((time - 50e-6).abs() < 1e-9);
+        assert_eq!(cloud.get::<f32>(1, "time"), Some(0.0));
+    }
+
+    #[test]
+    fn test_rings_by_elevation() {
+        assert_eq!(
+            rings_by_elevation(&[-15.0, 1.0, -13.0, 3.0]),
+            vec![0, 2, 1, 3]
+        );
+    }
+}
@@ -0,0 +1,606 @@
+//! Ouster LiDAR driver
+//!
+//! Decodes the UDP lidar packets of Ouster OS0, OS1 and OS2 sensors in the
+//! legacy data format (`udp_profile_lidar: LEGACY`). Beam intrinsics come
+//! from the sensor metadata JSON (`ouster-cli source <ip> metadata`, or
+//! `http://<ip>/api/v1/sensor/metadata`).
+
+use std::path::Path;
+
+use horus_core::driver::DriverStatus;
+use horus_core::error::{HorusError, HorusResult};
+use serde_json::Value;
+
+use super::source::PacketSource;
+use super::{
+    build_frame, rings_by_elevation, Lidar3dConfig, Lidar3dFrame, LidarPoint, MotionCompensator,
+};
+
+/// Column header: timestamp, measurement id, frame id, encoder count
+const COLUMN_HEADER: usize = 16;
+/// Pixel: range, reflectivity, signal, near-infrared, unused
+const PIXEL_SIZE: usize = 12;
+const COLUMN_STATUS: usize = 4;
+
+/// Beam intrinsics and data format of an Ouster sensor
+#[derive(Debug, Clone, PartialEq)]
+pub struct OusterMetadata {
+    /// Elevation of each beam in degrees, beam 0 is the top one
+    pub beam_altitude_angles: Vec<f64>,
+    /// Azimuth offset of each beam in degrees
+    pub beam_azimuth_angles: Vec<f64>,
+    /// Distance between the lidar origin and the beam origins in millimeters
+    pub lidar_origin_to_beam_origin_mm: f64,
+    /// Lidar to sensor frame transform (4x4 row-major, translation in mm)
+    pub lidar_to_sensor_transform: [f64; 16],
+    /// Measurements per rotation (512, 1024 or 2048)
+    pub columns_per_frame: u32,
+    /// Measurements per packet
+    pub columns_per_packet: u32,
+}
+
+impl Default for OusterMetadata {
+    /// Nominal OS1-64 at 1024x10
+    fn default() -> Self {
+        Self::uniform(64, 1024, 33.2)
+    }
+}
+
+impl OusterMetadata {
+    /// Evenly spaced beams with the nominal OS1 geometry
+    ///
+    /// Good enough for tests and simulation; use the sensor's metadata for
+    /// real data, beam angles differ by up to a degree between units.
+    pub fn uniform(beams: usize, columns_per_frame: u32, vertical_fov_deg: f64) -> Self {
+        let spacing = if beams > 1 {
+            vertical_fov_deg / (beams - 1) as f64
+        } else {
+            0.0
+        };
+        Self {
+            beam_altitude_angles: (0..beams)
+                .map(|beam| vertical_fov_deg / 2.0 - beam as f64 * spacing)
+                .collect(),
+            beam_azimuth_angles: vec![0.0; beams],
+            lidar_origin_to_beam_origin_mm: 15.806,
+            lidar_to_sensor_transform: [
+                -1.0, 0.0, 0.0, 0.0, //
+                0.0, -1.0, 0.0, 0.0, //
+                0.0, 0.0, 1.0, 36.18, //
+                0.0, 0.0, 0.0, 1.0,
+            ],
+            columns_per_frame,
+            columns_per_packet: 16,
+        }
+    }
+
+    /// Parse the sensor metadata JSON
+    ///
+    /// Accepts both the flat legacy layout and the nested one of newer
+    /// firmware (`beam_intrinsics`, `lidar_data_format`, ...).
+    pub fn from_json(contents: &str) -> HorusResult<Self> {
+        let root: Value = serde_json::from_str(contents)
+            .map_err(|e| HorusError::config(format!("Invalid Ouster metadata: {}", e)))?;
+
+        if let Some(profile) = find(&root, "udp_profile_lidar").and_then(Value::as_str) {
+            if profile != "LEGACY" {
+                return Err(HorusError::config(format!(
+                    "Ouster lidar profile {} is not supported, set udp_profile_lidar to LEGACY",
+                    profile
+                )));
+            }
+        }
+
+        let angles = |key: &str| -> HorusResult<Vec<f64>> {
+            find(&root, key)
+                .and_then(Value::as_array)
+                .and_then(|values| values.iter().map(Value::as_f64).collect())
+                .ok_or_else(|| HorusError::config(format!("Ouster metadata misses {}", key)))
+        };
+        let beam_altitude_angles = angles("beam_altitude_angles")?;
+        let beam_azimuth_angles = angles("beam_azimuth_angles")?;
+        if beam_altitude_angles.len() != beam_azimuth_angles.len() {
+            return Err(HorusError::config(
+                "Ouster metadata has different numbers of altitude and azimuth angles",
+            ));
+        }
+        if let Some(pixels) = find(&root, "pixels_per_column").and_then(Value::as_u64) {
+            if pixels as usize != beam_altitude_angles.len() {
+                return Err(HorusError::config(format!(
+                    "Ouster metadata has {} pixels per column but {} beams",
+                    pixels,
+                    beam_altitude_angles.len()
+                )));
+            }
+        }
+
+        let columns_per_frame = find(&root, "columns_per_frame")
+            .and_then(Value::as_u64)
+            .or_else(|| {
+                // lidar_mode is "<columns>x<rate>"
+                find(&root, "lidar_mode")
+                    .and_then(Value::as_str)
+                    .and_then(|mode| mode.split('x').next())
+                    .and_then(|columns| columns.parse().ok())
+            })
+            .ok_or_else(|| HorusError::config("Ouster metadata misses the lidar mode"))?;
+
+        let mut lidar_to_sensor_transform = [0.0; 16];
+        match find(&root, "lidar_to_sensor_transform").and_then(Value::as_array) {
+            Some(values) if values.len() == 16 => {
+                for (target, value) in lidar_to_sensor_transform.iter_mut().zip(values) {
+                    *target = value.as_f64().ok_or_else(|| {
+                        HorusError::config("Invalid Ouster lidar_to_sensor_transform")
+                    })?;
+                }
+            }
+            Some(_) => {
+                return Err(HorusError::config(
+                    "Ouster lidar_to_sensor_transform must have 16 values",
+                ))
+            }
+            None => {
+                for i in 0..4 {
+                    lidar_to_sensor_transform[i * 5] = 1.0;
+                }
+            }
+        }
+
+        Ok(Self {
+            beam_altitude_angles,
+            beam_azimuth_angles,
+            lidar_origin_to_beam_origin_mm: find(&root, "lidar_origin_to_beam_origin_mm")
+                .and_then(Value::as_f64)
+                .unwrap_or(0.0),
+            lidar_to_sensor_transform,
+            columns_per_frame: columns_per_frame as u32,
+            columns_per_packet: find(&root, "columns_per_packet")
+                .and_then(Value::as_u64)
+                .unwrap_or(16) as u32,
+        })
+    }
+
+    /// Load the sensor metadata JSON
+    pub fn from_file<P: AsRef<Path>>(path: P) -> HorusResult<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            HorusError::config(format!(
+                "Failed to read Ouster metadata {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Self::from_json(&contents)
+    }
+
+    /// Beams per column
+    pub fn pixels_per_column(&self) -> usize {
+        self.beam_altitude_angles.len()
+    }
+}
+
+/// Value of `key` at the top level or in a nested object
+fn find<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+    let object = value.as_object()?;
+    object
+        .get(key)
+        .or_else(|| object.values().find_map(|nested| find(nested, key)))
+}
+
+/// Decoder of Ouster legacy lidar packets
+#[derive(Debug, Clone)]
+pub struct OusterDecoder {
+    metadata: OusterMetadata,
+    rings: Vec<u16>,
+    /// (sin, cos) of each beam's elevation
+    elevation: Vec<(f64, f64)>,
+    /// Azimuth offset of each beam in radians
+    azimuth: Vec<f64>,
+}
+
+impl OusterDecoder {
+    pub fn new(metadata: OusterMetadata) -> HorusResult<Self> {
+        if metadata.pixels_per_column() == 0
+            || metadata.columns_per_frame == 0
+            || metadata.columns_per_packet == 0
+        {
+            return Err(HorusError::config(
+                "Ouster metadata without beams or columns",
+            ));
+        }
+        Ok(Self {
+            rings: rings_by_elevation(&metadata.beam_altitude_angles),
+            elevation: metadata
+                .beam_altitude_angles
+                .iter()
+                .map(|a| a.to_radians().sin_cos())
+                .collect(),
+            azimuth: metadata
+                .beam_azimuth_angles
+                .iter()
+                .map(|a| -a.to_radians())
+                .collect(),
+            metadata,
+        })
+    }
+
+    pub fn metadata(&self) -> &OusterMetadata {
+        &self.metadata
+    }
+
+    fn column_size(&self) -> usize {
+        COLUMN_HEADER + PIXEL_SIZE * self.metadata.pixels_per_column() + COLUMN_STATUS
+    }
+
+    /// Size of a lidar packet
+    pub fn packet_size(&self) -> usize {
+        self.column_size() * self.metadata.columns_per_packet as usize
+    }
+
+    /// Decode a lidar packet into `points`
+    ///
+    /// Points are stamped with their column timestamp (sensor clock, ns).
+    /// Returns the frame id (rotation counter) of the packet.
+    pub fn decode(&self, packet: &[u8], points: &mut Vec<LidarPoint>) -> HorusResult<u16> {
+        points.clear();
+        if packet.len() != self.packet_size() {
+            return Err(HorusError::driver(format!(
+                "Ouster lidar packets have {} bytes with this metadata, got {}",
+                self.packet_size(),
+                packet.len()
+            )));
+        }
+
+        let column_size = self.column_size();
+        let frame_id = u16::from_le_bytes([packet[10], packet[11]]);
+        for column in packet.chunks_exact(column_size) {
+            let status = u32::from_le_bytes(
+                column[column_size - COLUMN_STATUS..]
+                    .try_into()
+                    .expect("4 status bytes"),
+            );
+            if status & 1 == 0 {
+                continue;
+            }
+            let timestamp = u64::from_le_bytes(column[..8].try_into().expect("8 timestamp bytes"));
+            let measurement_id = u16::from_le_bytes([column[8], column[9]]);
+            let encoder = std::f64::consts::TAU
+                * (1.0 - measurement_id as f64 / self.metadata.columns_per_frame as f64);
+
+            for (beam, pixel) in column[COLUMN_HEADER..column_size - COLUMN_STATUS]
+                .chunks_exact(PIXEL_SIZE)
+                .enumerate()
+            {
+                let range_mm =
+                    u32::from_le_bytes([pixel[0], pixel[1], pixel[2], pixel[3]]) & 0x000f_ffff;
+                if range_mm == 0 {
+                    continue;
+                }
+                let mut point = self.point(beam, range_mm as f64, encoder);
+                point.intensity = u16::from_le_bytes([pixel[6], pixel[7]]) as f32;
+                point.timestamp = timestamp;
+                points.push(point);
+            }
+        }
+        Ok(frame_id)
+    }
+
+    /// Position of a return in the sensor frame
+    fn point(&self, beam: usize, range_mm: f64, encoder: f64) -> LidarPoint {
+        let origin = self.metadata.lidar_origin_to_beam_origin_mm;
+        let (sin_altitude, cos_altitude) = self.elevation[beam];
+        let direction = encoder + self.azimuth[beam];
+        let lidar = [
+            (range_mm - origin) * direction.cos() * cos_altitude + origin * encoder.cos(),
+            (range_mm - origin) * direction.sin() * cos_altitude + origin * encoder.sin(),
+            (range_mm - origin) * sin_altitude,
+        ];
+
+        let t = &self.metadata.lidar_to_sensor_transform;
+        let sensor = |row: usize| {
+            t[row * 4] * lidar[0]
+                + t[row * 4 + 1] * lidar[1]
+                + t[row * 4 + 2] * lidar[2]
+                + t[row * 4 + 3]
+        };
+        LidarPoint {
+            x: (sensor(0) * 0.001) as f32,
+            y: (sensor(1) * 0.001) as f32,
+            z: (sensor(2) * 0.001) as f32,
+            ring: self.rings[beam],
+            ..Default::default()
+        }
+    }
+}
+
+/// Ouster LiDAR driver
+///
+/// Receives lidar packets on `config.port` (7502 by default on the sensor)
+/// or replays a capture, and returns one scan per frame id.
+pub struct OusterDriver {
+    config: Lidar3dConfig,
+    decoder: OusterDecoder,
+    status: DriverStatus,
+    source: Option<PacketSource>,
+    compensator: Option<MotionCompensator>,
+    buffer: Vec<u8>,
+    decoded: Vec<LidarPoint>,
+    /// Points of the rotation in progress
+    scan: Vec<LidarPoint>,
+    packets: u32,
+    frame_id: Option<u16>,
+    scan_count: u64,
+}
+
+impl OusterDriver {
+    pub fn new(config: Lidar3dConfig, metadata: OusterMetadata) -> HorusResult<Self> {
+        Ok(Self {
+            config,
+            decoder: OusterDecoder::new(metadata)?,
+            status: DriverStatus::Uninitialized,
+            source: None,
+            compensator: None,
+            buffer: Vec::new(),
+            decoded: Vec::new(),
+            scan: Vec::new(),
+            packets: 0,
+            frame_id: None,
+            scan_count: 0,
+        })
+    }
+
+    /// Get the configuration
+    pub fn config(&self) -> &Lidar3dConfig {
+        &self.config
+    }
+
+    pub fn metadata(&self) -> &OusterMetadata {
+        self.decoder.metadata()
+    }
+
+    /// Deskew scans with the sensor motion from HFrame
+    pub fn set_motion_compensation(&mut self, compensator: Option<MotionCompensator>) {
+        self.compensator = compensator;
+    }
+
+    /// Scans read since init
+    pub fn scan_count(&self) -> u64 {
+        self.scan_count
+    }
+
+    // ========================================================================
+    // Lifecycle methods
+    // ========================================================================
+
+    /// Bind the UDP port (or open the capture)
+    pub fn init(&mut self) -> HorusResult<()> {
+        let source = match PacketSource::open(&self.config) {
+            Ok(source) => source,
+            Err(e) => {
+                self.status = DriverStatus::Error(e.to_string());
+                return Err(e);
+            }
+        };
+        self.source = Some(source);
+        self.scan.clear();
+        self.packets = 0;
+        self.frame_id = None;
+        self.scan_count = 0;
+        self.status = DriverStatus::Ready;
+        Ok(())
+    }
+
+    pub fn shutdown(&mut self) -> HorusResult<()> {
+        self.source = None;
+        self.status = DriverStatus::Shutdown;
+        Ok(())
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.source.is_some()
+    }
+
+    pub fn status(&self) -> DriverStatus {
+        self.status.clone()
+    }
+
+    // ========================================================================
+    // Sensor methods
+    // ========================================================================
+
+    /// Receive packets until a frame is complete
+    pub fn read(&mut self) -> HorusResult<Lidar3dFrame> {
+        loop {
+            let source = self
+                .source
+                .as_mut()
+                .ok_or_else(|| HorusError::driver("Driver not initialized"))?;
+            let received = match source.next_packet(&mut self.buffer) {
+                Ok(Some(received)) => received,
+                Ok(None) => {
+                    return Err(HorusError::driver(format!(
+                        "No Ouster packets on port {} within {} ms",
+                        self.config.port, self.config.timeout_ms
+                    )))
+                }
+                Err(e) => {
+                    self.status = DriverStatus::Error(e.to_string());
+                    return Err(e);
+                }
+            };
+
+            let frame_id = self.decoder.decode(&self.buffer, &mut self.decoded)?;
+            let completed = match self.frame_id {
+                Some(current) if current != frame_id && !self.scan.is_empty() => {
+                    let packets = std::mem::replace(&mut self.packets, 0);
+                    Some((std::mem::take(&mut self.scan), packets))
+                }
+                _ => None,
+            };
+            self.frame_id = Some(frame_id);
+            self.packets += 1;
+
+            // Without time sync the sensor clock counts from boot: keep the
+            // spacing of the columns and end the packet at its receive time
+            let latest = self.decoded.iter().map(|p| p.timestamp).max().unwrap_or(0);
+            for mut point in self.decoded.drain(..) {
+                let range = point.range();
+                if range < self.config.range_min || range > self.config.range_max {
+                    continue;
+                }
+                if !self.config.sensor_time {
+                    point.timestamp = received.saturating_sub(latest - point.timestamp);
+                }
+                self.scan.push(point);
+            }
+
+            if let Some((points, packets)) = completed {
+                self.scan_count += 1;
+                self.status = DriverStatus::Running;
+                return Ok(build_frame(
+                    points,
+                    packets,
+                    &self.config.frame_id,
+                    self.compensator.as_ref(),
+                ));
+            }
+        }
+    }
+
+    pub fn has_data(&self) -> bool {
+        self.source.is_some() && !matches!(self.status, DriverStatus::Error(_))
+    }
+
+    pub fn sample_rate(&self) -> Option<f32> {
+        Some(self.config.rotation_rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drivers::lidar3d::pcap::write_capture;
+    use crate::drivers::lidar3d::PcapReplay;
+
+    /// 16 beams from +15 to -15 degrees, no beam offsets, lidar = sensor frame
+    fn metadata() -> OusterMetadata {
+        let mut metadata = OusterMetadata::uniform(16, 64, 30.0);
+        metadata.lidar_origin_to_beam_origin_mm = 0.0;
+        metadata.lidar_to_sensor_transform = [
+            1.0, 0.0, 0.0, 0.0, //
+            0.0, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0,
+        ];
+        metadata
+    }
+
+    /// Packet of 16 columns starting at `first_column`, with one return per
+    /// listed (column, beam, range in mm)
+    fn packet(
+        decoder: &OusterDecoder,
+        frame_id: u16,
+        first_column: u16,
+        returns: &[(usize, usize, u32)],
+    ) -> Vec<u8> {
+        let column_size = decoder.column_size();
+        let mut packet = vec![0u8; decoder.packet_size()];
+        for (index, column) in packet.chunks_exact_mut(column_size).enumerate() {
+            let measurement_id = first_column + index as u16;
+            let timestamp = 5_000_000_000u64 + measurement_id as u64 * 1_562_500;
+            column[..8].copy_from_slice(&timestamp.to_le_bytes());
+            column[8..10].copy_from_slice(&measurement_id.to_le_bytes());
+            column[10..12].copy_from_slice(&frame_id.to_le_bytes());
+            column[column_size - 4..].copy_from_slice(&u32::MAX.to_le_bytes());
+        }
+        for &(column, beam, range) in returns {
+            let offset = column * column_size + COLUMN_HEADER + beam * PIXEL_SIZE;
+            packet[offset..offset + 4].copy_from_slice(&range.to_le_bytes());
+            packet[offset + 6..offset + 8].copy_from_slice(&300u16.to_le_bytes());
+        }
+        packet
+    }
+
+    #[test]
+    fn test_decode_columns() {
+        let decoder = OusterDecoder::new(metadata()).unwrap();
+        // Beam 0 is the top one (+15 deg), beam 8 is at -1 deg
+        let packet = packet(&decoder, 7, 16, &[(0, 0, 10_000), (0, 8, 4_000)]);
+        let mut points = Vec::new();
+        assert_eq!(decoder.decode(&packet, &mut points).unwrap(), 7);
+        assert_eq!(points.len(), 2);
+
+        // Column 16 of 64: a quarter turn clockwise from x
+        let top = points[0];
+        assert!(top.x.abs() < 1e-4);
+        assert!((top.y - -10.0 * 15f32.to_radians().cos()).abs() < 1e-4);
+        assert!((top.z - 10.0 * 15f32.to_radians().sin()).abs() < 1e-4);
+        assert_eq!(top.ring, 15);
+        assert_eq!(top.intensity, 300.0);
+        assert_eq!(top.timestamp, 5_000_000_000 + 16 * 1_562_500);
+        assert_eq!(points[1].ring, 7);
+
+        assert!(decoder.decode(&packet[1..], &mut points).is_err());
+    }
+
+    #[test]
+    fn test_metadata_json() {
+        let json = r#"{
+            "beam_intrinsics": {
+                "beam_altitude_angles": [2.0, 0.0, -2.0],
+                "beam_azimuth_angles": [3.1, 1.0, -1.0],
+                "lidar_origin_to_beam_origin_mm": 15.8
+            },
+            "config_params": {"lidar_mode": "2048x10", "udp_profile_lidar": "LEGACY"},
+            "lidar_data_format": {"columns_per_packet": 16, "pixels_per_column": 3}
+        }"#;
+        let metadata = OusterMetadata::from_json(json).unwrap();
+        assert_eq!(metadata.beam_altitude_angles, vec![2.0, 0.0, -2.0]);
+        assert_eq!(metadata.columns_per_frame, 2048);
+        assert_eq!(metadata.lidar_origin_to_beam_origin_mm, 15.8);
+        assert_eq!(metadata.lidar_to_sensor_transform[0], 1.0);
+        assert_eq!(metadata.lidar_to_sensor_transform[3], 0.0);
+
+        let dual = json.replace("\"LEGACY\"", "\"RNG19_RFL8_SIG16_NIR16_DUAL\"");
+        assert!(OusterMetadata::from_json(&dual).is_err());
+    }
+
+    #[test]
+    fn test_replay_splits_frames() {
+        let decoder = OusterDecoder::new(metadata()).unwrap();
+        // 4 packets per frame, one return in every column; packets are
+        // larger than the MTU and reach the driver as IP fragments
+        let packets: Vec<Vec<u8>> = (0..10u16)
+            .map(|index| {
+                let returns: Vec<_> = (0..16).map(|column| (column, 3, 5_000)).collect();
+                packet(&decoder, index / 4, index % 4 * 16, &returns)
+            })
+            .collect();
+        let records: Vec<(u64, u16, &[u8])> = packets
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (2_000_000_000 + i as u64 * 25_000_000, 7502, p.as_slice()))
+            .collect();
+        let path = std::env::temp_dir().join(format!("horus_ouster_{}.pcap", std::process::id()));
+        std::fs::write(&path, write_capture(&records, 1500)).unwrap();
+
+        let config = Lidar3dConfig {
+            port: 7502,
+            pcap: Some(PcapReplay::new(&path)),
+            ..Default::default()
+        };
+        let mut driver = OusterDriver::new(config, metadata()).unwrap();
+        driver.init().unwrap();
+
+        for frame_index in 0..2u64 {
+            let frame = driver.read().unwrap();
+            assert_eq!(frame.cloud.point_count(), 64);
+            assert_eq!(frame.packets, 4);
+            // Receive time of the frame's last packet
+            let last_packet = frame_index * 4 + 3;
+            assert_eq!(frame.end_time, 2_000_000_000 + last_packet * 25_000_000);
+        }
+        assert!(driver.read().is_err());
+        std::fs::remove_file(&path).ok();
+    }
+}
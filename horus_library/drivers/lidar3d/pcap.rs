@@ -0,0 +1,375 @@
+//! pcap capture reader
+//!
+//! Reads UDP datagrams from libpcap captures (`tcpdump -w`, Wireshark's
+//! "pcap" format), so recorded LiDAR traffic can be replayed through the
+//! packet decoders. IPv4 fragments are reassembled, which Ouster packets
+//! larger than the MTU need.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, ErrorKind, Read};
+use std::path::Path;
+
+use horus_core::error::{HorusError, HorusResult};
+
+/// Link layer types
+const LINKTYPE_NULL: u32 = 0;
+const LINKTYPE_ETHERNET: u32 = 1;
+const LINKTYPE_RAW: u32 = 101;
+const LINKTYPE_LINUX_SLL: u32 = 113;
+const LINKTYPE_IPV4: u32 = 228;
+const LINKTYPE_LINUX_SLL2: u32 = 276;
+
+const ETHERTYPE_IPV4: u16 = 0x0800;
+const ETHERTYPE_VLAN: u16 = 0x8100;
+const IP_PROTOCOL_UDP: u8 = 17;
+
+/// Incomplete fragmented datagrams kept before the oldest is dropped
+const MAX_PENDING_FRAGMENTS: usize = 64;
+
+/// A UDP datagram read from a capture
+#[derive(Debug, Clone, PartialEq)]
+pub struct UdpDatagram {
+    /// Capture time in nanoseconds since epoch
+    pub timestamp: u64,
+    pub source_port: u16,
+    pub destination_port: u16,
+    pub payload: Vec<u8>,
+}
+
+/// Fragments of one IPv4 datagram
+#[derive(Debug, Default)]
+struct PendingDatagram {
+    /// (byte offset, data) of each fragment
+    fragments: Vec<(usize, Vec<u8>)>,
+    /// Payload length, known once the last fragment arrived
+    total_len: Option<usize>,
+    /// Order of arrival, to drop the oldest incomplete datagram
+    sequence: u64,
+}
+
+impl PendingDatagram {
+    /// Reassembled payload once every byte arrived
+    fn assemble(&self) -> Option<Vec<u8>> {
+        let total_len = self.total_len?;
+        let mut payload = vec![0u8; total_len];
+        let mut covered = vec![false; total_len];
+        for (offset, data) in &self.fragments {
+            let end = (offset + data.len()).min(total_len);
+            if *offset >= end {
+                continue;
+            }
+            payload[*offset..end].copy_from_slice(&data[..end - offset]);
+            covered[*offset..end].iter_mut().for_each(|c| *c = true);
+        }
+        covered.iter().all(|&c| c).then_some(payload)
+    }
+}
+
+/// Reader of libpcap capture files
+///
+/// Supports microsecond and nanosecond captures of either byte order with
+/// Ethernet (including VLAN tags), Linux cooked (SLL, SLL2), raw IPv4 and
+/// BSD loopback link layers. pcapng files are not supported; convert them
+/// with `editcap -F pcap`.
+pub struct PcapReader<R: Read = BufReader<File>> {
+    reader: R,
+    big_endian: bool,
+    nanosecond: bool,
+    linktype: u32,
+    pending: HashMap<([u8; 4], [u8; 4], u16), PendingDatagram>,
+    sequence: u64,
+}
+
+impl PcapReader {
+    /// Open a capture file
+    pub fn open<P: AsRef<Path>>(path: P) -> HorusResult<Self> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| {
+            HorusError::driver(format!("Failed to open capture {}: {}", path.display(), e))
+        })?;
+        Self::new(BufReader::new(file))
+    }
+}
+
+impl<R: Read> PcapReader<R> {
+    /// Read a capture from any byte stream
+    pub fn new(mut reader: R) -> HorusResult<Self> {
+        let mut header = [0u8; 24];
+        reader
+            .read_exact(&mut header)
+            .map_err(|e| HorusError::driver(format!("Failed to read pcap header: {}", e)))?;
+
+        let magic = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+        let (big_endian, nanosecond) = match magic {
+            0xa1b2_c3d4 => (false, false),
+            0xa1b2_3c4d => (false, true),
+            0xd4c3_b2a1 => (true, false),
+            0x4d3c_b2a1 => (true, true),
+            0x0a0d_0d0a => {
+                return Err(HorusError::driver(
+                    "pcapng captures are not supported, convert with `editcap -F pcap`",
+                ))
+            }
+            other => {
+                return Err(HorusError::driver(format!(
+                    "Not a pcap capture (magic {:#010x})",
+                    other
+                )))
+            }
+        };
+
+        let mut this = Self {
+            reader,
+            big_endian,
+            nanosecond,
+            linktype: 0,
+            pending: HashMap::new(),
+            sequence: 0,
+        };
+        this.linktype = this.u32_at(&header, 20);
+        match this.linktype {
+            LINKTYPE_NULL | LINKTYPE_ETHERNET | LINKTYPE_RAW | LINKTYPE_LINUX_SLL
+            | LINKTYPE_IPV4 | LINKTYPE_LINUX_SLL2 => Ok(this),
+            other => Err(HorusError::driver(format!(
+                "Unsupported pcap link type {}",
+                other
+            ))),
+        }
+    }
+
+    /// Next UDP datagram of the capture, `None` at the end of the file
+    ///
+    /// Non-UDP and non-IPv4 packets are skipped.
+    pub fn next_udp(&mut self) -> HorusResult<Option<UdpDatagram>> {
+        while let Some((timestamp, frame)) = self.next_record()? {
+            if let Some(datagram) = self.parse_frame(timestamp, &frame) {
+                return Ok(Some(datagram));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Read the next record: capture time in nanoseconds and link layer frame
+    fn next_record(&mut self) -> HorusResult<Option<(u64, Vec<u8>)>> {
+        let mut header = [0u8; 16];
+        match self.reader.read_exact(&mut header) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => {
+                return Err(HorusError::driver(format!(
+                    "Failed to read pcap record: {}",
+                    e
+                )))
+            }
+        }
+        let seconds = self.u32_at(&header, 0) as u64;
+        let fraction = self.u32_at(&header, 4) as u64;
+        let captured = self.u32_at(&header, 8) as usize;
+        let timestamp = seconds * 1_000_000_000
+            + if self.nanosecond {
+                fraction
+            } else {
+                fraction * 1000
+            };
+
+        let mut frame = vec![0u8; captured];
+        self.reader
+            .read_exact(&mut frame)
+            .map_err(|e| HorusError::driver(format!("Truncated pcap record: {}", e)))?;
+        Ok(Some((timestamp, frame)))
+    }
+
+    fn u32_at(&self, bytes: &[u8], offset: usize) -> u32 {
+        let raw = [
+            bytes[offset],
+            bytes[offset + 1],
+            bytes[offset + 2],
+            bytes[offset + 3],
+        ];
+        if self.big_endian {
+            u32::from_be_bytes(raw)
+        } else {
+            u32::from_le_bytes(raw)
+        }
+    }
+
+    /// UDP datagram carried by a link layer frame
+    fn parse_frame(&mut self, timestamp: u64, frame: &[u8]) -> Option<UdpDatagram> {
+        let ip = match self.linktype {
+            LINKTYPE_ETHERNET => {
+                let mut offset = 12;
+                let mut ethertype = be_u16(frame, offset)?;
+                while ethertype == ETHERTYPE_VLAN {
+                    offset += 4;
+                    ethertype = be_u16(frame, offset)?;
+                }
+                (ethertype == ETHERTYPE_IPV4).then_some(frame.get(offset + 2..)?)?
+            }
+            LINKTYPE_LINUX_SLL => {
+                (be_u16(frame, 14)? == ETHERTYPE_IPV4).then_some(frame.get(16..)?)?
+            }
+            LINKTYPE_LINUX_SLL2 => {
+                (be_u16(frame, 0)? == ETHERTYPE_IPV4).then_some(frame.get(20..)?)?
+            }
+            // Address family in host byte order, 2 is AF_INET everywhere
+            LINKTYPE_NULL => {
+                let family = frame.get(..4)?;
+                (family[0] == 2 || family[3] == 2).then_some(frame.get(4..)?)?
+            }
+            _ => frame,
+        };
+        let payload = self.parse_ipv4(ip)?;
+        let length = be_u16(&payload, 4)? as usize;
+        Some(UdpDatagram {
+            timestamp,
+            source_port: be_u16(&payload, 0)?,
+            destination_port: be_u16(&payload, 2)?,
+            payload: payload.get(8..length.clamp(8, payload.len()))?.to_vec(),
+        })
+    }
+
+    /// UDP segment of an IPv4 packet, reassembling fragments
+    fn parse_ipv4(&mut self, ip: &[u8]) -> Option<Vec<u8>> {
+        let first = *ip.first()?;
+        let header_len = ((first & 0x0f) as usize) * 4;
+        if first >> 4 != 4 || header_len < 20 || ip.get(9)? != &IP_PROTOCOL_UDP {
+            return None;
+        }
+        let total_len = (be_u16(ip, 2)? as usize).min(ip.len());
+        let data = ip.get(header_len..total_len)?;
+
+        let flags = be_u16(ip, 6)?;
+        let more_fragments = flags & 0x2000 != 0;
+        let offset = ((flags & 0x1fff) as usize) * 8;
+        if !more_fragments && offset == 0 {
+            return Some(data.to_vec());
+        }
+
+        let key = (
+            ip.get(12..16)?.try_into().ok()?,
+            ip.get(16..20)?.try_into().ok()?,
+            be_u16(ip, 4)?,
+        );
+        if !self.pending.contains_key(&key) && self.pending.len() >= MAX_PENDING_FRAGMENTS {
+            let oldest = self
+                .pending
+                .iter()
+                .min_by_key(|(_, p)| p.sequence)
+                .map(|(k, _)| *k)?;
+            self.pending.remove(&oldest);
+        }
+        self.sequence += 1;
+        let sequence = self.sequence;
+        let pending = self.pending.entry(key).or_insert_with(|| PendingDatagram {
+            sequence,
+            ..Default::default()
+        });
+        pending.fragments.push((offset, data.to_vec()));
+        if !more_fragments {
+            pending.total_len = Some(offset + data.len());
+        }
+
+        let payload = pending.assemble()?;
+        self.pending.remove(&key);
+        Some(payload)
+    }
+}
+
+fn be_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_be_bytes([
+        *bytes.get(offset)?,
+        *bytes.get(offset + 1)?,
+    ]))
+}
+
+/// Build a microsecond little-endian Ethernet capture (used by tests)
+#[cfg(test)]
+pub(crate) fn write_capture(packets: &[(u64, u16, &[u8])], mtu: usize) -> Vec<u8> {
+    let mut capture = Vec::new();
+    capture.extend_from_slice(&0xa1b2_c3d4u32.to_le_bytes());
+    capture.extend_from_slice(&2u16.to_le_bytes());
+    capture.extend_from_slice(&4u16.to_le_bytes());
+    capture.extend_from_slice(&[0u8; 8]);
+    capture.extend_from_slice(&65535u32.to_le_bytes());
+    capture.extend_from_slice(&LINKTYPE_ETHERNET.to_le_bytes());
+
+    for (id, &(timestamp, port, payload)) in packets.iter().enumerate() {
+        let mut udp = Vec::new();
+        udp.extend_from_slice(&4000u16.to_be_bytes());
+        udp.extend_from_slice(&port.to_be_bytes());
+        udp.extend_from_slice(&((payload.len() + 8) as u16).to_be_bytes());
+        udp.extend_from_slice(&[0, 0]);
+        udp.extend_from_slice(payload);
+
+        // Fragment payload sizes are multiples of 8 bytes
+        let chunk = (mtu - 20) / 8 * 8;
+        let chunks: Vec<&[u8]> = udp.chunks(chunk).collect();
+        for (index, data) in chunks.iter().enumerate() {
+            let more = index + 1 < chunks.len();
+            let offset = (index * chunk / 8) as u16 | if more { 0x2000 } else { 0 };
+            let mut frame = vec![0u8; 12];
+            frame.extend_from_slice(&ETHERTYPE_IPV4.to_be_bytes());
+            frame.push(0x45);
+            frame.push(0);
+            frame.extend_from_slice(&((data.len() + 20) as u16).to_be_bytes());
+            frame.extend_from_slice(&(id as u16).to_be_bytes());
+            frame.extend_from_slice(&offset.to_be_bytes());
+            frame.extend_from_slice(&[64, IP_PROTOCOL_UDP, 0, 0]);
+            frame.extend_from_slice(&[192, 168, 1, 201, 192, 168, 1, 100]);
+            frame.extend_from_slice(data);
+
+            capture.extend_from_slice(&((timestamp / 1_000_000_000) as u32).to_le_bytes());
+            capture.extend_from_slice(&((timestamp % 1_000_000_000 / 1000) as u32).to_le_bytes());
+            capture.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+            capture.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+            capture.extend_from_slice(&frame);
+        }
+    }
+    capture
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_udp_datagrams() {
+        let capture = write_capture(
+            &[
+                (1_700_000_000_000_001_000, 2368, &[1, 2, 3]),
+                (1_700_000_000_000_002_000, 8308, &[4, 5]),
+            ],
+            1500,
+        );
+        let mut reader = PcapReader::new(capture.as_slice()).unwrap();
+
+        let first = reader.next_udp().unwrap().unwrap();
+        assert_eq!(first.timestamp, 1_700_000_000_000_001_000);
+        assert_eq!(first.destination_port, 2368);
+        assert_eq!(first.payload, vec![1, 2, 3]);
+
+        let second = reader.next_udp().unwrap().unwrap();
+        assert_eq!(second.destination_port, 8308);
+        assert_eq!(second.payload, vec![4, 5]);
+
+        assert!(reader.next_udp().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_reassemble_fragments() {
+        let payload: Vec<u8> = (0..4000).map(|i| i as u8).collect();
+        let capture = write_capture(&[(1_000_000_000, 7502, &payload)], 1500);
+        let mut reader = PcapReader::new(capture.as_slice()).unwrap();
+        let datagram = reader.next_udp().unwrap().unwrap();
+        assert_eq!(datagram.payload, payload);
+        assert!(reader.next_udp().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_rejects_pcapng() {
+        let mut capture = vec![0x0a, 0x0d, 0x0d, 0x0a];
+        capture.resize(24, 0);
+        assert!(PcapReader::new(capture.as_slice()).is_err());
+    }
+}
@@ -0,0 +1,122 @@
+//! Packet input of the network LiDAR drivers (UDP socket or pcap replay)
+
+use std::io::ErrorKind;
+use std::net::UdpSocket;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use horus_core::error::{HorusError, HorusResult};
+
+use super::pcap::PcapReader;
+use super::{Lidar3dConfig, PcapReplay};
+
+/// Largest UDP payload
+const MAX_DATAGRAM: usize = 65535;
+
+/// Source of LiDAR data packets
+pub(crate) enum PacketSource {
+    Udp(UdpSocket),
+    Pcap(PcapSource),
+}
+
+pub(crate) struct PcapSource {
+    replay: PcapReplay,
+    port: u16,
+    reader: PcapReader,
+    /// Capture time of the first packet and when it was replayed
+    start: Option<(u64, Instant)>,
+}
+
+impl PacketSource {
+    /// Bind the UDP port or open the capture of a configuration
+    pub fn open(config: &Lidar3dConfig) -> HorusResult<Self> {
+        if let Some(replay) = &config.pcap {
+            return Ok(Self::Pcap(PcapSource {
+                reader: PcapReader::open(&replay.path)?,
+                replay: replay.clone(),
+                port: config.port,
+                start: None,
+            }));
+        }
+
+        let address = format!("{}:{}", config.bind_address, config.port);
+        let socket = UdpSocket::bind(&address).map_err(|e| {
+            HorusError::driver(format!("Failed to bind LiDAR port {}: {}", address, e))
+        })?;
+        socket
+            .set_read_timeout(Some(Duration::from_millis(config.timeout_ms.max(1))))
+            .map_err(|e| HorusError::driver(format!("Failed to set socket timeout: {}", e)))?;
+        Ok(Self::Udp(socket))
+    }
+
+    /// Receive the next packet into `buffer`
+    ///
+    /// Returns the receive time in nanoseconds (the capture time when
+    /// replaying), or `None` when no packet arrived within the timeout.
+    /// The end of a capture that does not repeat is an error.
+    pub fn next_packet(&mut self, buffer: &mut Vec<u8>) -> HorusResult<Option<u64>> {
+        match self {
+            Self::Udp(socket) => {
+                buffer.resize(MAX_DATAGRAM, 0);
+                match socket.recv(buffer) {
+                    Ok(len) => {
+                        buffer.truncate(len);
+                        Ok(Some(now_nanos()))
+                    }
+                    Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {
+                        Ok(None)
+                    }
+                    Err(e) => Err(HorusError::driver(format!(
+                        "Failed to receive LiDAR packet: {}",
+                        e
+                    ))),
+                }
+            }
+            Self::Pcap(source) => source.next_packet(buffer).map(Some),
+        }
+    }
+}
+
+impl PcapSource {
+    fn next_packet(&mut self, buffer: &mut Vec<u8>) -> HorusResult<u64> {
+        let mut restarted = false;
+        loop {
+            let Some(datagram) = self.reader.next_udp()? else {
+                if !self.replay.repeat || restarted {
+                    return Err(HorusError::driver(format!(
+                        "End of capture {}",
+                        self.replay.path.display()
+                    )));
+                }
+                self.reader = PcapReader::open(&self.replay.path)?;
+                self.start = None;
+                restarted = true;
+                continue;
+            };
+            if datagram.destination_port != self.port {
+                continue;
+            }
+
+            if self.replay.realtime {
+                let (first, started) = *self
+                    .start
+                    .get_or_insert((datagram.timestamp, Instant::now()));
+                let due = started + Duration::from_nanos(datagram.timestamp.saturating_sub(first));
+                if let Some(wait) = due.checked_duration_since(Instant::now()) {
+                    thread::sleep(wait);
+                }
+            }
+
+            buffer.clear();
+            buffer.extend_from_slice(&datagram.payload);
+            return Ok(datagram.timestamp);
+        }
+    }
+}
+
+pub(crate) fn now_nanos() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64
+}
@@ -0,0 +1,751 @@
+//! Velodyne LiDAR driver
+//!
+//! Decodes the 1206-byte UDP data packets of Velodyne VLP-16, VLP-32C and
+//! HDL-32E sensors in strongest, last and dual return modes. Each laser is
+//! corrected with a per-ring calibration, either the nominal one of the
+//! model or a ROS `velodyne_pointcloud` calibration file.
+
+use std::path::Path;
+
+use horus_core::driver::DriverStatus;
+use horus_core::error::{HorusError, HorusResult};
+use serde::Deserialize;
+
+use super::source::PacketSource;
+use super::{
+    build_frame, rings_by_elevation, Lidar3dConfig, Lidar3dFrame, LidarPoint, MotionCompensator,
+};
+
+/// Size of a data packet (UDP payload)
+const PACKET_SIZE: usize = 1206;
+const BLOCKS: usize = 12;
+const BLOCK_SIZE: usize = 100;
+const CHANNELS: usize = 32;
+const BLOCK_FLAG: [u8; 2] = [0xff, 0xee];
+const RETURN_DUAL: u8 = 0x39;
+
+/// Velodyne sensor model
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VelodyneModel {
+    /// VLP-16 (Puck), also Puck LITE and Hi-Res with their calibration file
+    #[default]
+    Vlp16,
+    /// VLP-32C (Ultra Puck)
+    Vlp32c,
+    /// HDL-32E
+    Hdl32e,
+}
+
+impl VelodyneModel {
+    /// Parse a model name (`vlp16`, `vlp-32c`, `hdl32e`, ...)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().replace(['-', '_'], "").as_str() {
+            "vlp16" | "puck" => Some(Self::Vlp16),
+            "vlp32c" | "ultrapuck" => Some(Self::Vlp32c),
+            "hdl32e" | "hdl32" => Some(Self::Hdl32e),
+            _ => None,
+        }
+    }
+
+    /// Number of lasers
+    pub fn lasers(&self) -> usize {
+        match self {
+            Self::Vlp16 => 16,
+            Self::Vlp32c | Self::Hdl32e => 32,
+        }
+    }
+
+    /// Product ids reported in the factory byte of the packets
+    fn product_ids(&self) -> &'static [u8] {
+        match self {
+            Self::Vlp16 => &[0x22, 0x24],
+            Self::Vlp32c => &[0x28],
+            Self::Hdl32e => &[0x21],
+        }
+    }
+
+    /// Duration of one firing sequence of all lasers in microseconds
+    fn firing_cycle_us(&self) -> f64 {
+        match self {
+            Self::Vlp16 | Self::Vlp32c => 55.296,
+            Self::Hdl32e => 46.080,
+        }
+    }
+
+    /// Time between consecutive laser firings in microseconds
+    fn firing_interval_us(&self) -> f64 {
+        match self {
+            Self::Vlp16 | Self::Vlp32c => 2.304,
+            Self::Hdl32e => 1.152,
+        }
+    }
+
+    /// Lasers fired simultaneously
+    fn lasers_per_firing(&self) -> usize {
+        match self {
+            Self::Vlp32c => 2,
+            Self::Vlp16 | Self::Hdl32e => 1,
+        }
+    }
+}
+
+/// Correction of one laser
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct LaserCorrection {
+    /// Elevation angle in degrees
+    pub vertical_deg: f64,
+    /// Azimuth offset in degrees
+    pub rotational_deg: f64,
+    /// Range offset in meters
+    pub distance: f64,
+    /// Vertical offset of the laser from the sensor origin in meters
+    pub vertical_offset: f64,
+    /// Horizontal offset of the laser from the sensor origin in meters
+    pub horizontal_offset: f64,
+}
+
+/// Per-laser calibration of a Velodyne sensor
+#[derive(Debug, Clone, PartialEq)]
+pub struct VelodyneCalibration {
+    /// Corrections indexed by laser id
+    pub lasers: Vec<LaserCorrection>,
+    /// Meters per range unit
+    pub distance_resolution: f64,
+}
+
+/// ROS `velodyne_pointcloud` calibration file (angles in radians)
+#[derive(Deserialize)]
+struct CalibrationFile {
+    lasers: Vec<CalibrationEntry>,
+    #[serde(default = "default_distance_resolution")]
+    distance_resolution: f64,
+}
+
+#[derive(Deserialize)]
+struct CalibrationEntry {
+    laser_id: usize,
+    vert_correction: f64,
+    #[serde(default)]
+    rot_correction: f64,
+    #[serde(default)]
+    dist_correction: f64,
+    #[serde(default)]
+    vert_offset_correction: f64,
+    #[serde(default)]
+    horiz_offset_correction: f64,
+}
+
+fn default_distance_resolution() -> f64 {
+    0.002
+}
+
+impl VelodyneCalibration {
+    /// Nominal calibration from the model's user manual
+    pub fn default_for(model: VelodyneModel) -> Self {
+        let lasers = match model {
+            VelodyneModel::Vlp16 => {
+                const VERTICAL: [f64; 16] = [
+                    -15.0, 1.0, -13.0, 3.0, -11.0, 5.0, -9.0, 7.0, -7.0, 9.0, -5.0, 11.0, -3.0,
+                    13.0, -1.0, 15.0,
+                ];
+                const OFFSET_MM: [f64; 16] = [
+                    11.2, -0.7, 9.7, -2.2, 8.1, -3.7, 6.6, -5.1, 5.1, -6.6, 3.7, -8.1, 2.2, -9.7,
+                    0.7, -11.2,
+                ];
+                VERTICAL
+                    .iter()
+                    .zip(OFFSET_MM)
+                    .map(|(&vertical_deg, offset)| LaserCorrection {
+                        vertical_deg,
+                        vertical_offset: offset * 0.001,
+                        ..Default::default()
+                    })
+                    .collect()
+            }
+            VelodyneModel::Vlp32c => {
+                const VERTICAL: [f64; 32] = [
+                    -25.0, -1.0, -1.667, -15.639, -11.31, 0.0, -0.667, -8.843, -7.254, 0.333,
+                    -0.333, -6.148, -5.333, 1.333, 0.667, -4.0, -4.667, 1.667, 1.0, -3.667, -3.333,
+                    3.333, 2.333, -2.667, -3.0, 7.0, 4.667, -2.333, -2.0, 15.0, 10.333, -1.333,
+                ];
+                const ROTATIONAL: [f64; 8] = [1.4, -4.2, 1.4, -1.4, 1.4, -1.4, 4.2, -1.4];
+                VERTICAL
+                    .iter()
+                    .enumerate()
+                    .map(|(laser, &vertical_deg)| LaserCorrection {
+                        vertical_deg,
+                        rotational_deg: ROTATIONAL[laser % ROTATIONAL.len()],
+                        ..Default::default()
+                    })
+                    .collect()
+            }
+            VelodyneModel::Hdl32e => (0..32)
+                .map(|laser| {
+                    // Interleaved: even lasers -30.67..-10.67, odd -9.33..+10.67
+                    let step = 4.0 / 3.0;
+                    let index = (laser / 2) as f64;
+                    let vertical_deg = if laser % 2 == 1 {
+                        -9.333 + index * step
+                    } else {
+                        -30.667 + index * step
+                    };
+                    LaserCorrection {
+                        vertical_deg,
+                        ..Default::default()
+                    }
+                })
+                .collect(),
+        };
+        Self {
+            lasers,
+            distance_resolution: default_distance_resolution(),
+        }
+    }
+
+    /// Parse a ROS `velodyne_pointcloud` calibration (YAML, angles in radians)
+    pub fn from_yaml(contents: &str) -> HorusResult<Self> {
+        let file: CalibrationFile = serde_yaml::from_str(contents)
+            .map_err(|e| HorusError::config(format!("Invalid Velodyne calibration: {}", e)))?;
+        let mut lasers = vec![None; file.lasers.len()];
+        for entry in file.lasers {
+            let slot = lasers.get_mut(entry.laser_id).ok_or_else(|| {
+                HorusError::config(format!(
+                    "Velodyne calibration laser_id {} out of range",
+                    entry.laser_id
+                ))
+            })?;
+            *slot = Some(LaserCorrection {
+                vertical_deg: entry.vert_correction.to_degrees(),
+                rotational_deg: entry.rot_correction.to_degrees(),
+                distance: entry.dist_correction,
+                vertical_offset: entry.vert_offset_correction,
+                horizontal_offset: entry.horiz_offset_correction,
+            });
+        }
+        let lasers = lasers
+            .into_iter()
+            .enumerate()
+            .map(|(id, laser)| {
+                laser.ok_or_else(|| {
+                    HorusError::config(format!("Velodyne calibration misses laser {}", id))
+                })
+            })
+            .collect::<HorusResult<Vec<_>>>()?;
+        Ok(Self {
+            lasers,
+            distance_resolution: file.distance_resolution,
+        })
+    }
+
+    /// Load a ROS `velodyne_pointcloud` calibration file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> HorusResult<Self> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(|e| {
+            HorusError::config(format!(
+                "Failed to read Velodyne calibration {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Self::from_yaml(&contents)
+    }
+}
+
+/// Absolute time of a Velodyne packet timestamp
+///
+/// Packets carry microseconds since the top of the hour. The hour is taken
+/// from `reference_ns` (e.g. the receive time), choosing the candidate
+/// closest to it so packets around the full hour resolve correctly.
+pub fn resolve_top_of_hour(us_past_hour: u32, reference_ns: u64) -> u64 {
+    const HOUR_NS: u64 = 3_600_000_000_000;
+    let offset = (us_past_hour as u64 * 1000).min(HOUR_NS - 1);
+    let hour = reference_ns - reference_ns % HOUR_NS;
+    [hour.saturating_sub(HOUR_NS), hour, hour + HOUR_NS]
+        .into_iter()
+        .map(|start| start + offset)
+        .min_by_key(|time| time.abs_diff(reference_ns))
+        .unwrap_or(hour + offset)
+}
+
+/// Decoder of Velodyne data packets
+#[derive(Debug, Clone)]
+pub struct VelodyneDecoder {
+    model: VelodyneModel,
+    calibration: VelodyneCalibration,
+    rings: Vec<u16>,
+    /// (sin, cos) of each laser's elevation
+    elevation: Vec<(f64, f64)>,
+}
+
+impl VelodyneDecoder {
+    pub fn new(model: VelodyneModel, calibration: VelodyneCalibration) -> HorusResult<Self> {
+        if calibration.lasers.len() != model.lasers() {
+            return Err(HorusError::config(format!(
+                "{:?} has {} lasers, calibration has {}",
+                model,
+                model.lasers(),
+                calibration.lasers.len()
+            )));
+        }
+        let elevations: Vec<f64> = calibration.lasers.iter().map(|l| l.vertical_deg).collect();
+        Ok(Self {
+            model,
+            rings: rings_by_elevation(&elevations),
+            elevation: elevations
+                .iter()
+                .map(|e| e.to_radians().sin_cos())
+                .collect(),
+            calibration,
+        })
+    }
+
+    pub fn model(&self) -> VelodyneModel {
+        self.model
+    }
+
+    /// Decode a data packet into `points`
+    ///
+    /// Each point comes with the azimuth it was fired at (degrees, [0, 360))
+    /// and a timestamp in nanoseconds after the first firing of the packet.
+    /// Returns the packet timestamp in microseconds since the top of the hour.
+    pub fn decode(&self, packet: &[u8], points: &mut Vec<(f32, LidarPoint)>) -> HorusResult<u32> {
+        points.clear();
+        if packet.len() != PACKET_SIZE {
+            return Err(HorusError::driver(format!(
+                "Velodyne data packets have {} bytes, got {}",
+                PACKET_SIZE,
+                packet.len()
+            )));
+        }
+        let product = packet[PACKET_SIZE - 1];
+        if product != 0 && !self.model.product_ids().contains(&product) {
+            return Err(HorusError::driver(format!(
+                "Packet from product id {:#04x}, configured for {:?}",
+                product, self.model
+            )));
+        }
+        let dual = packet[PACKET_SIZE - 2] == RETURN_DUAL;
+        // In dual return mode consecutive blocks hold two returns of one firing
+        let step = if dual { 2 } else { 1 };
+
+        let blocks: Vec<&[u8]> = packet[..BLOCKS * BLOCK_SIZE]
+            .chunks_exact(BLOCK_SIZE)
+            .collect();
+        if blocks.iter().any(|block| block[..2] != BLOCK_FLAG) {
+            return Err(HorusError::driver("Invalid Velodyne block flag"));
+        }
+        let azimuths: Vec<u16> = blocks
+            .iter()
+            .map(|block| u16::from_le_bytes([block[2], block[3]]))
+            .collect();
+        let azimuth_gap = |from: usize, to: usize| {
+            (azimuths[to] as i32 - azimuths[from] as i32).rem_euclid(36000) as f64 / 100.0
+        };
+
+        let lasers = self.model.lasers();
+        let firings_per_block = CHANNELS / lasers;
+        let cycle_us = self.model.firing_cycle_us();
+        let block_us = firings_per_block as f64 * cycle_us;
+
+        for (index, block) in blocks.iter().enumerate() {
+            // Rotation during this block, from the next (or previous) firing
+            let gap = if index + step < BLOCKS {
+                azimuth_gap(index, index + step)
+            } else if index >= step {
+                azimuth_gap(index - step, index)
+            } else {
+                0.0
+            };
+            let sequence = index / step * firings_per_block;
+            let duplicate_of = (dual && index % 2 == 1).then(|| blocks[index - 1]);
+
+            for channel in 0..CHANNELS {
+                let offset = 4 + channel * 3;
+                let raw = u16::from_le_bytes([block[offset], block[offset + 1]]);
+                if raw == 0 {
+                    continue;
+                }
+                // Second return identical to the first one
+                if duplicate_of
+                    .is_some_and(|first| first[offset..offset + 3] == block[offset..offset + 3])
+                {
+                    continue;
+                }
+
+                let firing = channel / lasers;
+                let laser = channel % lasers;
+                let in_block_us = firing as f64 * cycle_us
+                    + (laser / self.model.lasers_per_firing()) as f64
+                        * self.model.firing_interval_us();
+                let time_us = sequence as f64 * cycle_us + in_block_us;
+                let azimuth =
+                    (azimuths[index] as f64 / 100.0 + gap * in_block_us / block_us) % 360.0;
+
+                let mut point = self.point(laser, raw, azimuth);
+                point.intensity = block[offset + 2] as f32;
+                point.timestamp = (time_us * 1000.0).round() as u64;
+                points.push((azimuth as f32, point));
+            }
+        }
+
+        Ok(u32::from_le_bytes([
+            packet[1200],
+            packet[1201],
+            packet[1202],
+            packet[1203],
+        ]))
+    }
+
+    /// Corrected position of a return (x forward, y left, z up)
+    fn point(&self, laser: usize, raw: u16, azimuth_deg: f64) -> LidarPoint {
+        let correction = &self.calibration.lasers[laser];
+        let distance = raw as f64 * self.calibration.distance_resolution + correction.distance;
+        let (sin_vertical, cos_vertical) = self.elevation[laser];
+        let (sin_rotation, cos_rotation) = (azimuth_deg - correction.rotational_deg)
+            .to_radians()
+            .sin_cos();
+
+        // Velodyne frame: y forward, x right, azimuth clockwise from y
+        let xy = distance * cos_vertical - correction.vertical_offset * sin_vertical;
+        let x = xy * sin_rotation - correction.horizontal_offset * cos_rotation;
+        let y = xy * cos_rotation + correction.horizontal_offset * sin_rotation;
+        let z = distance * sin_vertical + correction.vertical_offset * cos_vertical;
+
+        LidarPoint {
+            x: y as f32,
+            y: -x as f32,
+            z: z as f32,
+            ring: self.rings[laser],
+            ..Default::default()
+        }
+    }
+}
+
+/// Velodyne LiDAR driver
+///
+/// Receives data packets on `config.port` (2368 by default) or replays a
+/// capture, and returns one scan per rotation, cut where the azimuth wraps.
+pub struct VelodyneDriver {
+    config: Lidar3dConfig,
+    decoder: VelodyneDecoder,
+    status: DriverStatus,
+    source: Option<PacketSource>,
+    compensator: Option<MotionCompensator>,
+    buffer: Vec<u8>,
+    decoded: Vec<(f32, LidarPoint)>,
+    /// Points of the rotation in progress
+    scan: Vec<LidarPoint>,
+    packets: u32,
+    last_azimuth: Option<f32>,
+    scan_count: u64,
+}
+
+impl VelodyneDriver {
+    /// Create a driver using the nominal calibration of `model`
+    pub fn new(config: Lidar3dConfig, model: VelodyneModel) -> HorusResult<Self> {
+        Self::with_calibration(config, model, VelodyneCalibration::default_for(model))
+    }
+
+    /// Create a driver with a sensor-specific calibration
+    pub fn with_calibration(
+        config: Lidar3dConfig,
+        model: VelodyneModel,
+        calibration: VelodyneCalibration,
+    ) -> HorusResult<Self> {
+        Ok(Self {
+            config,
+            decoder: VelodyneDecoder::new(model, calibration)?,
+            status: DriverStatus::Uninitialized,
+            source: None,
+            compensator: None,
+            buffer: Vec::new(),
+            decoded: Vec::new(),
+            scan: Vec::new(),
+            packets: 0,
+            last_azimuth: None,
+            scan_count: 0,
+        })
+    }
+
+    /// Get the configuration
+    pub fn config(&self) -> &Lidar3dConfig {
+        &self.config
+    }
+
+    pub fn model(&self) -> VelodyneModel {
+        self.decoder.model()
+    }
+
+    /// Deskew scans with the sensor motion from HFrame
+    pub fn set_motion_compensation(&mut self, compensator: Option<MotionCompensator>) {
+        self.compensator = compensator;
+    }
+
+    /// Scans read since init
+    pub fn scan_count(&self) -> u64 {
+        self.scan_count
+    }
+
+    // ========================================================================
+    // Lifecycle methods
+    // ========================================================================
+
+    /// Bind the UDP port (or open the capture)
+    pub fn init(&mut self) -> HorusResult<()> {
+        let source = match PacketSource::open(&self.config) {
+            Ok(source) => source,
+            Err(e) => {
+                self.status = DriverStatus::Error(e.to_string());
+                return Err(e);
+            }
+        };
+        self.source = Some(source);
+        self.scan.clear();
+        self.packets = 0;
+        self.last_azimuth = None;
+        self.scan_count = 0;
+        self.status = DriverStatus::Ready;
+        Ok(())
+    }
+
+    pub fn shutdown(&mut self) -> HorusResult<()> {
+        self.source = None;
+        self.status = DriverStatus::Shutdown;
+        Ok(())
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.source.is_some()
+    }
+
+    pub fn status(&self) -> DriverStatus {
+        self.status.clone()
+    }
+
+    // ========================================================================
+    // Sensor methods
+    // ========================================================================
+
+    /// Receive packets until a rotation is complete
+    pub fn read(&mut self) -> HorusResult<Lidar3dFrame> {
+        loop {
+            let source = self
+                .source
+                .as_mut()
+                .ok_or_else(|| HorusError::driver("Driver not initialized"))?;
+            let received = match source.next_packet(&mut self.buffer) {
+                Ok(Some(received)) => received,
+                Ok(None) => {
+                    return Err(HorusError::driver(format!(
+                        "No Velodyne packets on port {} within {} ms",
+                        self.config.port, self.config.timeout_ms
+                    )))
+                }
+                Err(e) => {
+                    self.status = DriverStatus::Error(e.to_string());
+                    return Err(e);
+                }
+            };
+            // Position (GPS) packets share the port on some setups
+            if self.buffer.len() != PACKET_SIZE {
+                continue;
+            }
+
+            let packet_time = self.decoder.decode(&self.buffer, &mut self.decoded)?;
+            let span = self.decoded.iter().map(|(_, p)| p.timestamp).max();
+            let start = if self.config.sensor_time {
+                resolve_top_of_hour(packet_time, received)
+            } else {
+                // The packet is sent after its last firing
+                received.saturating_sub(span.unwrap_or(0))
+            };
+
+            let mut completed = None;
+            // Whether this packet belongs to the rotation in progress
+            let mut in_scan = false;
+            for (azimuth, mut point) in self.decoded.drain(..) {
+                // Azimuth wrapped: the rotation is complete
+                if self.last_azimuth.is_some_and(|last| azimuth + 180.0 < last)
+                    && !self.scan.is_empty()
+                {
+                    let packets = self.packets + u32::from(in_scan);
+                    completed = Some((std::mem::take(&mut self.scan), packets));
+                    self.packets = 0;
+                }
+                self.last_azimuth = Some(azimuth);
+                in_scan = true;
+
+                let range = point.range();
+                if range < self.config.range_min || range > self.config.range_max {
+                    continue;
+                }
+                point.timestamp += start;
+                self.scan.push(point);
+            }
+            self.packets += u32::from(in_scan);
+
+            if let Some((points, packets)) = completed {
+                self.scan_count += 1;
+                self.status = DriverStatus::Running;
+                return Ok(build_frame(
+                    points,
+                    packets,
+                    &self.config.frame_id,
+                    self.compensator.as_ref(),
+                ));
+            }
+        }
+    }
+
+    pub fn has_data(&self) -> bool {
+        self.source.is_some() && !matches!(self.status, DriverStatus::Error(_))
+    }
+
+    pub fn sample_rate(&self) -> Option<f32> {
+        Some(self.config.rotation_rate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::drivers::lidar3d::pcap::write_capture;
+    use crate::drivers::lidar3d::PcapReplay;
+
+    /// Strongest-return VLP-16 packet with one return per listed (block, channel)
+    fn vlp16_packet(
+        azimuths: [u16; BLOCKS],
+        returns: &[(usize, usize, u16, u8)],
+        timestamp_us: u32,
+    ) -> Vec<u8> {
+        let mut packet = vec![0u8; PACKET_SIZE];
+        for (block, azimuth) in azimuths.iter().enumerate() {
+            let start = block * BLOCK_SIZE;
+            packet[start..start + 2].copy_from_slice(&BLOCK_FLAG);
+            packet[start + 2..start + 4].copy_from_slice(&azimuth.to_le_bytes());
+        }
+        for &(block, channel, raw, reflectivity) in returns {
+            let offset = block * BLOCK_SIZE + 4 + channel * 3;
+            packet[offset..offset + 2].copy_from_slice(&raw.to_le_bytes());
+            packet[offset + 2] = reflectivity;
+        }
+        packet[1200..1204].copy_from_slice(&timestamp_us.to_le_bytes());
+        packet[1204] = 0x37;
+        packet[1205] = 0x22;
+        packet
+    }
+
+    #[test]
+    fn test_resolve_top_of_hour() {
+        const HOUR_NS: u64 = 3_600_000_000_000;
+        let reference = 1000 * HOUR_NS + 10_000_000_000;
+        assert_eq!(
+            resolve_top_of_hour(9_000_000, reference),
+            1000 * HOUR_NS + 9_000_000_000
+        );
+        // Packet from just before the full hour, received just after it
+        assert_eq!(
+            resolve_top_of_hour(3_599_999_000, reference),
+            1000 * HOUR_NS - 1_000_000
+        );
+    }
+
+    #[test]
+    fn test_decode_vlp16_packet() {
+        let decoder = VelodyneDecoder::new(
+            VelodyneModel::Vlp16,
+            VelodyneCalibration::default_for(VelodyneModel::Vlp16),
+        )
+        .unwrap();
+        let azimuths: [u16; BLOCKS] = std::array::from_fn(|block| block as u16 * 20);
+        // Laser 0 (-15 deg) at 10 m, and laser 1 (+1 deg) of the second firing
+        let packet = vlp16_packet(azimuths, &[(0, 0, 5000, 100), (0, 17, 2500, 7)], 1234);
+
+        let mut points = Vec::new();
+        assert_eq!(decoder.decode(&packet, &mut points).unwrap(), 1234);
+        assert_eq!(points.len(), 2);
+
+        let (azimuth, point) = points[0];
+        assert_eq!(azimuth, 0.0);
+        assert!(point.x > 9.6 && point.x < 9.7);
+        assert!(point.y.abs() < 1e-5);
+        assert!((point.z - -2.5774).abs() < 1e-3);
+        assert_eq!(point.ring, 0);
+        assert_eq!(point.intensity, 100.0);
+        assert_eq!(point.timestamp, 0);
+
+        // Second firing: 55.296 + 2.304 us later, half a block of rotation
+        let (azimuth, point) = points[1];
+        assert!((azimuth - 0.2 * 57.6 / 110.592).abs() < 1e-5);
+        assert_eq!(point.timestamp, 57_600);
+        assert_eq!(point.ring, 8);
+        assert!((point.range() - 5.0).abs() < 0.01);
+        // Clockwise rotation: slightly to the right
+        assert!(point.y < 0.0);
+    }
+
+    #[test]
+    fn test_calibration_file() {
+        let yaml = "
+distance_resolution: 0.004
+lasers:
+- {laser_id: 1, vert_correction: 0.0174533, rot_correction: 0.0}
+- {laser_id: 0, vert_correction: -0.0174533, rot_correction: 0.0244, dist_correction: 0.01}
+";
+        let calibration = VelodyneCalibration::from_yaml(yaml).unwrap();
+        assert_eq!(calibration.distance_resolution, 0.004);
+        assert!((calibration.lasers[0].vertical_deg - -1.0).abs() < 1e-4);
+        assert!((calibration.lasers[0].rotational_deg - 1.398).abs() < 1e-3);
+        assert_eq!(calibration.lasers[0].distance, 0.01);
+        assert!((calibration.lasers[1].vertical_deg - 1.0).abs() < 1e-4);
+
+        // A VLP-16 needs 16 lasers
+        assert!(VelodyneDecoder::new(VelodyneModel::Vlp16, calibration).is_err());
+        assert!(
+            VelodyneCalibration::from_yaml("lasers:\n- {laser_id: 3, vert_correction: 0.0}")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_replay_splits_rotations() {
+        // 3 degrees per block: 10 packets per rotation, one return per block
+        let packets: Vec<Vec<u8>> = (0..25u16)
+            .map(|packet| {
+                let azimuths = std::array::from_fn(|block| {
+                    ((packet as usize * BLOCKS + block) * 300 % 36000) as u16
+                });
+                let returns: Vec<_> = (0..BLOCKS).map(|block| (block, 0, 2500, 10)).collect();
+                vlp16_packet(azimuths, &returns, 0)
+            })
+            .collect();
+        let records: Vec<(u64, u16, &[u8])> = packets
+            .iter()
+            .enumerate()
+            .map(|(i, p)| (1_000_000_000 + i as u64 * 10_000_000, 2368, p.as_slice()))
+            .collect();
+        let path = std::env::temp_dir().join(format!("horus_velodyne_{}.pcap", std::process::id()));
+        std::fs::write(&path, write_capture(&records, 1500)).unwrap();
+
+        let config = Lidar3dConfig {
+            pcap: Some(PcapReplay::new(&path)),
+            frame_id: "velodyne".to_string(),
+            ..Default::default()
+        };
+        let mut driver = VelodyneDriver::new(config, VelodyneModel::Vlp16).unwrap();
+        driver.init().unwrap();
+
+        for _ in 0..2 {
+            let frame = driver.read().unwrap();
+            assert_eq!(frame.cloud.point_count(), 120);
+            assert_eq!(frame.packets, 10);
+            assert!(!frame.compensated);
+            assert_eq!(frame.cloud.get::<u16>(0, "ring"), Some(0));
+            assert!(frame.end_time > frame.cloud.timestamp);
+        }
+        // Half a rotation left in the capture
+        assert!(driver.read().is_err());
+        assert_eq!(driver.scan_count(), 2);
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -23,10 +23,11 @@
 //! - `imu` - Inertial Measurement Units (accelerometer, gyroscope, magnetometer)
 //! - `camera` - Vision sensors (RGB, depth, stereo)
 //! - `lidar` - Laser range finders
+//! - `lidar3d` - 3D LiDARs (Velodyne, Ouster)
 //! - `gps` - Global positioning systems
 //! - `encoder` - Rotary encoders for odometry
 //! - `ultrasonic` - Ultrasonic distance sensors
-//! - `depth_camera` - Depth cameras (RealSense, etc.)
+//! - `depth_camera` - Depth cameras (RealSense, ZED)
 //! - `battery` - Battery monitoring
 //! - `force_torque` - Force/torque sensors
 //!
@@ -42,12 +43,14 @@
 //! - `bus` - Communication buses (I2C, SPI, CAN)
 //! - `serial` - Serial port (UART)
 //! - `modbus` - Modbus protocol
+//! - `ethercat` - EtherCAT master (SOEM)
 //!
 //! ## Input
 //! - `joystick` - Gamepad/joystick input
 //! - `keyboard` - Keyboard input
 //!
 //! ## Other
+//! - `gpio` - GPIO/PWM hardware abstraction (Raspberry Pi, Jetson)
 //! - `digital_io` - Digital GPIO input/output
 //!
 //! # Adding a New Driver
@@ -66,6 +69,7 @@ pub mod force_torque;
 pub mod gps;
 pub mod imu;
 pub mod lidar;
+pub mod lidar3d;
 pub mod ultrasonic;
 
 // Actuator drivers
@@ -78,6 +82,7 @@ pub mod stepper;
 
 // Bus drivers
 pub mod bus;
+pub mod ethercat;
 pub mod modbus;
 pub mod serial;
 
@@ -87,6 +92,7 @@ pub mod keyboard;
 
 // Other drivers
 pub mod digital_io;
+pub mod gpio;
 
 // ============================================================================
 // IMU Drivers
@@ -102,7 +108,9 @@ pub use imu::Bno055Driver;
 // ============================================================================
 // Camera Drivers
 // ============================================================================
-pub use camera::{CameraDriver, SimulationCameraDriver};
+pub use camera::{
+    CameraDriver, GStreamerCameraConfig, GstDecoder, GstSource, SimulationCameraDriver, VideoCodec,
+};
 
 #[cfg(feature = "opencv-backend")]
 pub use camera::OpenCvCameraDriver;
@@ -110,6 +118,9 @@ pub use camera::OpenCvCameraDriver;
 #[cfg(feature = "v4l2-backend")]
 pub use camera::V4l2CameraDriver;
 
+#[cfg(feature = "gstreamer-backend")]
+pub use camera::{GStreamerCameraDriver, GstFrame};
+
 // ============================================================================
 // LiDAR Drivers
 // ============================================================================
@@ -118,6 +129,15 @@ pub use lidar::{LidarDriver, SimulationLidarDriver};
 #[cfg(feature = "rplidar")]
 pub use lidar::RplidarDriver;
 
+// ============================================================================
+// 3D LiDAR Drivers
+// ============================================================================
+pub use lidar3d::{
+    Lidar3dConfig, Lidar3dDriver, Lidar3dDriverBackend, Lidar3dFrame, MotionCompensator,
+    OusterDriver, OusterMetadata, PcapReplay, SimulationLidar3dDriver, VelodyneDriver,
+    VelodyneModel,
+};
+
 // ============================================================================
 // GPS Drivers
 // ============================================================================
@@ -132,7 +152,7 @@ pub use gps::NmeaGpsDriver;
 pub use encoder::{EncoderDriver, SimulationEncoderDriver};
 
 #[cfg(feature = "gpio-hardware")]
-pub use encoder::GpioEncoderDriver;
+pub use encoder::{GpioEncoderConfig, GpioEncoderDriver};
 
 // ============================================================================
 // Ultrasonic Drivers
@@ -145,11 +165,17 @@ pub use ultrasonic::GpioUltrasonicDriver;
 // ============================================================================
 // Depth Camera Drivers
 // ============================================================================
-pub use depth_camera::{DepthCameraDriver, DepthCameraFrame, SimulationDepthCameraDriver};
+pub use depth_camera::{
+    DepthCameraConfig, DepthCameraDriver, DepthCameraDriverBackend, DepthCameraFrame,
+    DepthRegistration, SimulationDepthCameraDriver,
+};
 
 #[cfg(feature = "realsense")]
 pub use depth_camera::RealSenseDriver;
 
+#[cfg(feature = "zed")]
+pub use depth_camera::ZedDriver;
+
 // ============================================================================
 // Battery Drivers
 // ============================================================================
@@ -215,7 +241,7 @@ pub use stepper::GpioStepperDriver;
 // ============================================================================
 pub use dynamixel::{
     DynamixelCommand, DynamixelConfig, DynamixelDriver, DynamixelDriverBackend, DynamixelMode,
-    DynamixelProtocol, SimulationDynamixelDriver,
+    DynamixelProtocol, DynamixelState, SimulationDynamixelDriver,
 };
 
 #[cfg(feature = "serial-hardware")]
@@ -267,6 +293,17 @@ pub use modbus::{ModbusConfig, ModbusDriver, ModbusDriverBackend, SimulationModb
 #[cfg(feature = "serial-hardware")]
 pub use modbus::RtuModbusDriver;
 
+// ============================================================================
+// EtherCAT Drivers
+// ============================================================================
+pub use ethercat::{
+    EthercatConfig, EthercatDriver, EthercatDriverBackend, EthercatSlaveConfig, EthercatState,
+    ProcessImage, ProcessImageLayout, SdoWrite, SimulationEthercatDriver,
+};
+
+#[cfg(feature = "ethercat-hardware")]
+pub use ethercat::SoemEthercatDriver;
+
 // ============================================================================
 // Joystick Drivers
 // ============================================================================
@@ -296,13 +333,34 @@ pub use digital_io::{
 #[cfg(feature = "gpio-hardware")]
 pub use digital_io::GpioDigitalIoDriver;
 
+// ============================================================================
+// GPIO/PWM Hardware Abstraction
+// ============================================================================
+pub use gpio::{
+    GpioBoard, GpioDriver, GpioDriverBackend, GpioHal, PwmServo, SimulationGpio, SysfsPwm,
+};
+
+#[cfg(feature = "raspberry-pi")]
+pub use gpio::RaspberryPiGpio;
+
+#[cfg(feature = "jetson")]
+pub use gpio::{JetsonGpio, JetsonGpioConfig, JetsonGpioInterface};
+
 // ============================================================================
 // Driver Factory (runtime driver instantiation from config)
 // ============================================================================
 pub mod factory;
 pub use factory::{
-    create_battery_driver, create_camera_driver, create_drivers_from_config, create_encoder_driver,
-    create_force_torque_driver, create_gps_driver, create_imu_driver, create_joystick_driver,
-    create_keyboard_driver, create_lidar_driver, create_motor_driver, create_servo_driver,
-    create_ultrasonic_driver, list_available_backends, CreatedDrivers,
+    create_battery_driver, create_camera_driver, create_depth_camera_driver,
+    create_drivers_from_config, create_encoder_driver, create_force_torque_driver,
+    create_gpio_driver, create_gps_driver, create_imu_driver, create_joystick_driver,
+    create_keyboard_driver, create_lidar3d_driver, create_lidar_driver, create_motor_driver,
+    create_servo_driver, create_ultrasonic_driver, depth_camera_config, gstreamer_camera_config,
+    lidar3d_config, list_available_backends, CreatedDrivers,
 };
+
+// ============================================================================
+// Simulation Parity (hardware vs simulation interface checks)
+// ============================================================================
+pub mod parity;
+pub use parity::{ParityRegistry, ParityViolation, DEFAULT_RATE_TOLERANCE};
@@ -0,0 +1,559 @@
+//! Simulation parity registry
+//!
+//! Code validated against a simulation driver should behave the same on the
+//! hardware driver it stands in for. This registry records, for every
+//! hardware backend, the topics its node publishes with their message types
+//! and nominal rates, and checks that the simulation backend of the same
+//! category matches them.
+//!
+//! Simulation interfaces are read from the simulation drivers' default
+//! configurations. Hardware interfaces are declared here so they can be
+//! checked without the hardware features compiled in; tests compare them with
+//! the hardware drivers when the features are enabled.
+//!
+//! ```rust,ignore
+//! use horus_library::drivers::parity::{ParityRegistry, DEFAULT_RATE_TOLERANCE};
+//!
+//! let registry = ParityRegistry::builtin();
+//! for violation in registry.verify_all(DEFAULT_RATE_TOLERANCE) {
+//!     eprintln!("{}", violation);
+//! }
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use super::{
+    SimulationBatteryDriver, SimulationCameraDriver, SimulationDepthCameraDriver,
+    SimulationEncoderDriver, SimulationForceTorqueDriver, SimulationGpsDriver, SimulationImuDriver,
+    SimulationLidarDriver, SimulationUltrasonicDriver,
+};
+use crate::{
+    BatteryState, CameraInfo, DepthImage, Image, Imu, JoystickInput, KeyboardInput, LaserScan,
+    NavSatFix, Odometry, PointCloud, Range, WrenchStamped,
+};
+
+/// Allowed relative rate difference between hardware and simulation (10%)
+pub const DEFAULT_RATE_TOLERANCE: f32 = 0.1;
+
+/// A topic published by a driver's node
+#[derive(Debug, Clone, PartialEq)]
+pub struct TopicInterface {
+    pub topic: &'static str,
+    /// Message type name, without module path
+    pub message_type: &'static str,
+    /// Nominal publish rate, `None` for event-driven or interrupt-driven data
+    pub rate_hz: Option<f32>,
+}
+
+impl TopicInterface {
+    pub fn new<T>(topic: &'static str, rate_hz: Option<f32>) -> Self {
+        let type_name = std::any::type_name::<T>();
+        Self {
+            topic,
+            message_type: type_name.rsplit("::").next().unwrap_or(type_name),
+            rate_hz,
+        }
+    }
+}
+
+/// Published interface of one driver backend
+#[derive(Debug, Clone, PartialEq)]
+pub struct DriverInterface {
+    /// Driver category as named in the drivers config (`imu`, `lidar`, ...)
+    pub category: &'static str,
+    /// Backend name (`mpu6050`, `simulation`, ...)
+    pub backend: &'static str,
+    /// Other names the backend is configured with (`ina219` for `i2c`)
+    pub aliases: &'static [&'static str],
+    pub topics: Vec<TopicInterface>,
+}
+
+impl DriverInterface {
+    fn matches(&self, backend: &str) -> bool {
+        self.backend == backend || self.aliases.contains(&backend)
+    }
+}
+
+/// A difference between a hardware backend and its simulation counterpart
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParityViolation {
+    /// The category has no simulation backend
+    NoSimulation { category: String, backend: String },
+    /// The hardware publishes a topic the simulation does not
+    MissingTopic {
+        category: String,
+        backend: String,
+        topic: String,
+    },
+    /// The simulation publishes a topic the hardware does not
+    ExtraTopic {
+        category: String,
+        backend: String,
+        topic: String,
+    },
+    TypeMismatch {
+        category: String,
+        backend: String,
+        topic: String,
+        hardware: String,
+        simulation: String,
+    },
+    RateMismatch {
+        category: String,
+        backend: String,
+        topic: String,
+        hardware_hz: f32,
+        simulation_hz: f32,
+    },
+}
+
+impl fmt::Display for ParityViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoSimulation { category, backend } => write!(
+                f,
+                "{}/{}: no simulation backend for category '{}'",
+                category, backend, category
+            ),
+            Self::MissingTopic {
+                category,
+                backend,
+                topic,
+            } => write!(
+                f,
+                "{}/{}: simulation does not publish '{}'",
+                category, backend, topic
+            ),
+            Self::ExtraTopic {
+                category,
+                backend,
+                topic,
+            } => write!(
+                f,
+                "{}/{}: simulation publishes '{}', which the hardware does not",
+                category, backend, topic
+            ),
+            Self::TypeMismatch {
+                category,
+                backend,
+                topic,
+                hardware,
+                simulation,
+            } => write!(
+                f,
+                "{}/{}: '{}' is {} on hardware but {} in simulation",
+                category, backend, topic, hardware, simulation
+            ),
+            Self::RateMismatch {
+                category,
+                backend,
+                topic,
+                hardware_hz,
+                simulation_hz,
+            } => write!(
+                f,
+                "{}/{}: '{}' runs at {} Hz on hardware but {} Hz in simulation",
+                category, backend, topic, hardware_hz, simulation_hz
+            ),
+        }
+    }
+}
+
+/// Hardware backends paired with the simulation backend of their category
+#[derive(Debug, Clone, Default)]
+pub struct ParityRegistry {
+    hardware: Vec<DriverInterface>,
+    simulation: HashMap<&'static str, DriverInterface>,
+}
+
+impl ParityRegistry {
+    /// Registry of the built-in drivers
+    pub fn builtin() -> Self {
+        let mut registry = Self::default();
+
+        // Simulation backends, at their default configuration
+        let simulation = [
+            imu(SimulationImuDriver::new().sample_rate()),
+            lidar(SimulationLidarDriver::new().sample_rate()),
+            gps(SimulationGpsDriver::new().sample_rate()),
+            camera(SimulationCameraDriver::new().sample_rate()),
+            depth_camera(SimulationDepthCameraDriver::new().sample_rate()),
+            encoder(SimulationEncoderDriver::new().sample_rate()),
+            ultrasonic(SimulationUltrasonicDriver::new().sample_rate()),
+            battery(SimulationBatteryDriver::new().sample_rate()),
+            force_torque(SimulationForceTorqueDriver::new().sample_rate()),
+            joystick(),
+            keyboard(),
+        ];
+        for (category, topics) in simulation {
+            registry.register_simulation(DriverInterface {
+                category,
+                backend: "simulation",
+                aliases: &["sim"],
+                topics,
+            });
+        }
+
+        // Hardware backends, at the rates of their default configuration
+        let hardware: [(&'static str, &'static [&'static str], _); 16] = [
+            ("mpu6050", &[], imu(Some(100.0))),
+            ("bno055", &[], imu(Some(100.0))),
+            ("icm20948", &[], imu(Some(100.0))),
+            ("rplidar", &["rplidar-a2", "rplidar-a3"], lidar(Some(10.0))),
+            ("nmea", &[], gps(Some(1.0))),
+            ("opencv", &[], camera(Some(30.0))),
+            ("v4l2", &[], camera(Some(30.0))),
+            ("realsense", &[], depth_camera(Some(30.0))),
+            ("zed", &[], depth_camera(Some(30.0))),
+            // Counts come from GPIO interrupts
+            ("gpio", &[], encoder(None)),
+            ("gpio", &[], ultrasonic(Some(10.0))),
+            ("i2c", &["ina219", "ina226"], battery(Some(10.0))),
+            ("ati_netft", &["netft", "ati"], force_torque(Some(1000.0))),
+            (
+                "robotiq",
+                &["robotiq_serial", "ft300"],
+                force_torque(Some(100.0)),
+            ),
+            ("gilrs", &["gamepad"], joystick()),
+            ("crossterm", &["terminal"], keyboard()),
+        ];
+        for (backend, aliases, (category, topics)) in hardware {
+            registry.register_hardware(DriverInterface {
+                category,
+                backend,
+                aliases,
+                topics,
+            });
+        }
+
+        registry
+    }
+
+    /// Add a hardware backend, e.g. from an external driver crate
+    pub fn register_hardware(&mut self, interface: DriverInterface) {
+        self.hardware.push(interface);
+    }
+
+    /// Set the simulation backend of a category
+    pub fn register_simulation(&mut self, interface: DriverInterface) {
+        self.simulation.insert(interface.category, interface);
+    }
+
+    /// Hardware backend by category and backend name or alias
+    pub fn hardware(&self, category: &str, backend: &str) -> Option<&DriverInterface> {
+        let category = normalize_category(category);
+        let backend = backend.to_lowercase();
+        self.hardware
+            .iter()
+            .find(|interface| interface.category == category && interface.matches(&backend))
+    }
+
+    pub fn simulation(&self, category: &str) -> Option<&DriverInterface> {
+        self.simulation.get(normalize_category(category).as_str())
+    }
+
+    /// Compare a hardware backend with the simulation of its category
+    pub fn verify(&self, hardware: &DriverInterface, rate_tolerance: f32) -> Vec<ParityViolation> {
+        let category = hardware.category.to_string();
+        let backend = hardware.backend.to_string();
+        let Some(simulation) = self.simulation(hardware.category) else {
+            return vec![ParityViolation::NoSimulation { category, backend }];
+        };
+
+        let mut violations = Vec::new();
+        for expected in &hardware.topics {
+            let Some(actual) = simulation
+                .topics
+                .iter()
+                .find(|topic| topic.topic == expected.topic)
+            else {
+                violations.push(ParityViolation::MissingTopic {
+                    category: category.clone(),
+                    backend: backend.clone(),
+                    topic: expected.topic.to_string(),
+                });
+                continue;
+            };
+
+            if actual.message_type != expected.message_type {
+                violations.push(ParityViolation::TypeMismatch {
+                    category: category.clone(),
+                    backend: backend.clone(),
+                    topic: expected.topic.to_string(),
+                    hardware: expected.message_type.to_string(),
+                    simulation: actual.message_type.to_string(),
+                });
+            }
+
+            // Event-driven data on either side has no rate to compare
+            if let (Some(hardware_hz), Some(simulation_hz)) = (expected.rate_hz, actual.rate_hz) {
+                if (simulation_hz - hardware_hz).abs() > hardware_hz * rate_tolerance {
+                    violations.push(ParityViolation::RateMismatch {
+                        category: category.clone(),
+                        backend: backend.clone(),
+                        topic: expected.topic.to_string(),
+                        hardware_hz,
+                        simulation_hz,
+                    });
+                }
+            }
+        }
+
+        for extra in simulation.topics.iter().filter(|topic| {
+            !hardware
+                .topics
+                .iter()
+                .any(|expected| expected.topic == topic.topic)
+        }) {
+            violations.push(ParityViolation::ExtraTopic {
+                category: category.clone(),
+                backend: backend.clone(),
+                topic: extra.topic.to_string(),
+            });
+        }
+
+        violations
+    }
+
+    /// Verify every registered hardware backend
+    pub fn verify_all(&self, rate_tolerance: f32) -> Vec<ParityViolation> {
+        self.hardware
+            .iter()
+            .flat_map(|hardware| self.verify(hardware, rate_tolerance))
+            .collect()
+    }
+}
+
+/// Category names as accepted by the drivers config: `depth-camera` and
+/// `depth_camera`, `ft` for `force_torque`, `gamepad` for `joystick`
+fn normalize_category(category: &str) -> String {
+    match category.to_lowercase().replace('-', "_").as_str() {
+        "ft" => "force_torque".to_string(),
+        "gamepad" => "joystick".to_string(),
+        other => other.to_string(),
+    }
+}
+
+// Topics of each category's node, with their default names
+
+type CategoryTopics = (&'static str, Vec<TopicInterface>);
+
+fn imu(rate_hz: Option<f32>) -> CategoryTopics {
+    ("imu", vec![TopicInterface::new::<Imu>("imu", rate_hz)])
+}
+
+fn lidar(rate_hz: Option<f32>) -> CategoryTopics {
+    (
+        "lidar",
+        vec![TopicInterface::new::<LaserScan>("scan", rate_hz)],
+    )
+}
+
+fn gps(rate_hz: Option<f32>) -> CategoryTopics {
+    (
+        "gps",
+        vec![TopicInterface::new::<NavSatFix>("gps.fix", rate_hz)],
+    )
+}
+
+fn camera(rate_hz: Option<f32>) -> CategoryTopics {
+    (
+        "camera",
+        vec![
+            TopicInterface::new::<Image>("camera.image", rate_hz),
+            TopicInterface::new::<CameraInfo>("camera.camera_info", rate_hz),
+        ],
+    )
+}
+
+fn depth_camera(rate_hz: Option<f32>) -> CategoryTopics {
+    (
+        "depth_camera",
+        vec![
+            TopicInterface::new::<Image>("depth_camera.rgb.image", rate_hz),
+            TopicInterface::new::<DepthImage>("depth_camera.depth.image", rate_hz),
+            TopicInterface::new::<PointCloud>("depth_camera.pointcloud", rate_hz),
+            TopicInterface::new::<CameraInfo>("depth_camera.camera_info", rate_hz),
+            TopicInterface::new::<CameraInfo>("depth_camera.depth.camera_info", rate_hz),
+        ],
+    )
+}
+
+fn encoder(rate_hz: Option<f32>) -> CategoryTopics {
+    (
+        "encoder",
+        vec![TopicInterface::new::<Odometry>("odom", rate_hz)],
+    )
+}
+
+fn ultrasonic(rate_hz: Option<f32>) -> CategoryTopics {
+    (
+        "ultrasonic",
+        vec![TopicInterface::new::<Range>("ultrasonic.range", rate_hz)],
+    )
+}
+
+fn battery(rate_hz: Option<f32>) -> CategoryTopics {
+    (
+        "battery",
+        vec![TopicInterface::new::<BatteryState>("battery", rate_hz)],
+    )
+}
+
+fn force_torque(rate_hz: Option<f32>) -> CategoryTopics {
+    (
+        "force_torque",
+        vec![TopicInterface::new::<WrenchStamped>(
+            "force_torque.wrench",
+            rate_hz,
+        )],
+    )
+}
+
+/// Input devices publish on events, so only topics and types are compared
+fn joystick() -> CategoryTopics {
+    (
+        "joystick",
+        vec![TopicInterface::new::<JoystickInput>("joystick_input", None)],
+    )
+}
+
+fn keyboard() -> CategoryTopics {
+    (
+        "keyboard",
+        vec![TopicInterface::new::<KeyboardInput>("keyboard_input", None)],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_drivers_have_simulation_parity() {
+        let registry = ParityRegistry::builtin();
+        let violations = registry.verify_all(DEFAULT_RATE_TOLERANCE);
+        // The simulated F/T sensor runs at the ATI rate; the Robotiq FT-300
+        // streams at 100 Hz
+        let unexpected: Vec<_> = violations
+            .iter()
+            .filter(|v| !matches!(v, ParityViolation::RateMismatch { backend, .. } if backend == "robotiq"))
+            .collect();
+        assert!(unexpected.is_empty(), "{:?}", unexpected);
+        assert_eq!(violations.len(), 1);
+
+        assert_eq!(
+            registry
+                .hardware("depth-camera", "realsense")
+                .map(|i| i.topics.len()),
+            Some(5)
+        );
+        assert_eq!(
+            registry.hardware("battery", "INA219").map(|i| i.backend),
+            Some("i2c")
+        );
+        assert!(registry.hardware("imu", "unknown").is_none());
+    }
+
+    #[test]
+    fn test_verify_reports_drift() {
+        let mut registry = ParityRegistry::default();
+        registry.register_simulation(DriverInterface {
+            category: "imu",
+            backend: "simulation",
+            aliases: &[],
+            topics: vec![
+                TopicInterface::new::<Imu>("imu", Some(95.0)),
+                TopicInterface::new::<Imu>("imu.debug", Some(1.0)),
+            ],
+        });
+        let hardware = DriverInterface {
+            category: "imu",
+            backend: "custom",
+            aliases: &[],
+            topics: vec![
+                TopicInterface::new::<Imu>("imu", Some(200.0)),
+                TopicInterface::new::<Imu>("imu.temperature", None),
+            ],
+        };
+
+        let violations = registry.verify(&hardware, DEFAULT_RATE_TOLERANCE);
+        assert_eq!(violations.len(), 3);
+        assert!(
+            matches!(&violations[0], ParityViolation::RateMismatch { topic, .. } if topic == "imu")
+        );
+        assert!(
+            matches!(&violations[1], ParityViolation::MissingTopic { topic, .. } if topic == "imu.temperature")
+        );
+        assert!(
+            matches!(&violations[2], ParityViolation::ExtraTopic { topic, .. } if topic == "imu.debug")
+        );
+
+        // Within tolerance
+        let hardware = DriverInterface {
+            topics: vec![TopicInterface::new::<Imu>("imu", Some(100.0))],
+            ..hardware
+        };
+        registry.register_simulation(DriverInterface {
+            category: "imu",
+            backend: "simulation",
+            aliases: &[],
+            topics: vec![TopicInterface::new::<Imu>("imu", Some(95.0))],
+        });
+        assert!(registry
+            .verify(&hardware, DEFAULT_RATE_TOLERANCE)
+            .is_empty());
+    }
+
+    /// The declared hardware rates match the drivers' default configurations
+    #[test]
+    fn test_hardware_interfaces_match_drivers() {
+        let registry = ParityRegistry::builtin();
+        #[allow(unused_variables)]
+        let rate = |category: &str, backend: &str| {
+            registry.hardware(category, backend).unwrap().topics[0].rate_hz
+        };
+
+        #[cfg(feature = "mpu6050-imu")]
+        assert_eq!(
+            rate("imu", "mpu6050"),
+            crate::drivers::Mpu6050Driver::new().unwrap().sample_rate()
+        );
+        #[cfg(feature = "bno055-imu")]
+        assert_eq!(
+            rate("imu", "bno055"),
+            crate::drivers::Bno055Driver::new().unwrap().sample_rate()
+        );
+        #[cfg(feature = "rplidar")]
+        assert_eq!(
+            rate("lidar", "rplidar"),
+            crate::drivers::RplidarDriver::new().unwrap().sample_rate()
+        );
+        #[cfg(feature = "nmea-gps")]
+        assert_eq!(
+            rate("gps", "nmea"),
+            crate::drivers::NmeaGpsDriver::new().unwrap().sample_rate()
+        );
+        #[cfg(feature = "i2c-hardware")]
+        assert_eq!(
+            rate("battery", "i2c"),
+            crate::drivers::I2cBatteryDriver::new()
+                .unwrap()
+                .sample_rate()
+        );
+        #[cfg(feature = "netft")]
+        assert_eq!(
+            rate("force_torque", "ati_netft"),
+            crate::drivers::AtiNetFtDriver::new().unwrap().sample_rate()
+        );
+        #[cfg(feature = "robotiq-serial")]
+        assert_eq!(
+            rate("force_torque", "robotiq"),
+            crate::drivers::RobotiqSerialDriver::new()
+                .unwrap()
+                .sample_rate()
+        );
+    }
+}
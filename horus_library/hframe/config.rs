@@ -44,6 +44,14 @@ pub struct HFrameConfig {
     ///
     /// Default: 64
     pub chain_cache_size: usize,
+
+    /// Maximum extrapolation for timestamped lookups, in nanoseconds
+    ///
+    /// `HFrame::lookup_transform` extrapolates a frame's motion past the
+    /// ends of its history by at most this much and fails beyond it.
+    ///
+    /// Default: 0 (no extrapolation, like tf2)
+    pub max_extrapolation_ns: u64,
 }
 
 impl Default for HFrameConfig {
@@ -68,6 +76,7 @@ impl HFrameConfig {
             history_len: 32,
             enable_overflow: false,
             chain_cache_size: 64,
+            max_extrapolation_ns: 0,
         }
     }
 
@@ -86,6 +95,7 @@ impl HFrameConfig {
             history_len: 32,
             enable_overflow: false,
             chain_cache_size: 128,
+            max_extrapolation_ns: 0,
         }
     }
 
@@ -104,6 +114,7 @@ impl HFrameConfig {
             history_len: 32,
             enable_overflow: false,
             chain_cache_size: 256,
+            max_extrapolation_ns: 0,
         }
     }
 
@@ -122,6 +133,7 @@ impl HFrameConfig {
             history_len: 32,
             enable_overflow: true, // Allow overflow for safety
             chain_cache_size: 512,
+            max_extrapolation_ns: 0,
         }
     }
 
@@ -140,6 +152,7 @@ impl HFrameConfig {
             history_len: 32,
             enable_overflow: true, // Key difference
             chain_cache_size: 512,
+            max_extrapolation_ns: 0,
         }
     }
 
@@ -252,6 +265,12 @@ impl HFrameConfigBuilder {
         self
     }
 
+    /// Set maximum extrapolation for timestamped lookups (nanoseconds)
+    pub fn max_extrapolation_ns(mut self, ns: u64) -> Self {
+        self.config.max_extrapolation_ns = ns;
+        self
+    }
+
     /// Build and validate the configuration
     pub fn build(self) -> Result<HFrameConfig, String> {
         self.config.validate()?;
@@ -10,7 +10,7 @@ use std::sync::RwLock;
 use super::transform::Transform;
 
 use super::config::HFrameConfig;
-use super::slot::FrameSlot;
+use super::slot::{FrameSlot, TransformEntry};
 use super::types::{FrameId, FrameType, HFrameError, HFrameResult, NO_PARENT};
 
 /// Core HFrame storage with lock-free operations
@@ -305,6 +305,11 @@ impl HFrameCore {
     // Transform Queries
     // ========================================================================
 
+    /// Latest transform entry of a frame (None if never written)
+    pub fn latest(&self, id: FrameId) -> Option<TransformEntry> {
+        self.slots.get(id as usize)?.read_latest()
+    }
+
     /// Resolve transform from src to dst (latest)
     pub fn resolve(&self, src: FrameId, dst: FrameId) -> Option<Transform> {
         if src == dst {
@@ -331,6 +336,36 @@ impl HFrameCore {
         self.compose_chain(&chain, Some(timestamp_ns))
     }
 
+    /// Resolve transform from src to dst at a timestamp without clamping
+    ///
+    /// Unlike [`resolve_at`](Self::resolve_at), a timestamp outside a frame's
+    /// buffered history is only served by extrapolating up to
+    /// `max_extrapolation_ns`. Returns `Err(None)` if no path exists and
+    /// `Err(Some(id))` for the first frame with no transform at `timestamp_ns`.
+    pub fn resolve_at_within(
+        &self,
+        src: FrameId,
+        dst: FrameId,
+        timestamp_ns: u64,
+        max_extrapolation_ns: u64,
+    ) -> Result<Transform, Option<FrameId>> {
+        if src == dst {
+            return Ok(Transform::identity());
+        }
+
+        let chain = self.get_or_compute_chain(src, dst).ok_or(None)?;
+
+        self.compose_chain_with(&chain, |slot| {
+            slot.read_interpolated_within(timestamp_ns, max_extrapolation_ns)
+        })
+        .map_err(Some)
+    }
+
+    /// Time range of a frame's buffered history (None if never written)
+    pub fn time_range(&self, id: FrameId) -> Option<(u64, u64)> {
+        self.slots.get(id as usize)?.time_range()
+    }
+
     /// Check if a transform path exists
     pub fn can_transform(&self, src: FrameId, dst: FrameId) -> bool {
         if src == dst {
@@ -467,12 +502,26 @@ impl HFrameCore {
 
     /// Compose transforms along a chain
     fn compose_chain(&self, chain: &[FrameId], timestamp: Option<u64>) -> Option<Transform> {
+        self.compose_chain_with(chain, |slot| match timestamp {
+            Some(ts) => slot.read_interpolated(ts),
+            None => slot.read_latest().map(|e| e.transform),
+        })
+        .ok()
+    }
+
+    /// Compose transforms along a chain using `read` to sample each frame
+    ///
+    /// Fails with the first dynamic frame `read` returns no transform for.
+    fn compose_chain_with<F>(&self, chain: &[FrameId], read: F) -> Result<Transform, FrameId>
+    where
+        F: Fn(&FrameSlot) -> Option<Transform>,
+    {
         if chain.is_empty() {
-            return Some(Transform::identity());
+            return Ok(Transform::identity());
         }
 
         if chain.len() == 1 {
-            return Some(Transform::identity());
+            return Ok(Transform::identity());
         }
 
         // Find the common ancestor (it's in the middle of the chain)
@@ -501,20 +550,14 @@ impl HFrameCore {
         for &frame_id in chain.iter().take(common_idx) {
             let slot = &self.slots[frame_id as usize];
 
-            let entry = if let Some(ts) = timestamp {
-                slot.read_interpolated(ts)
-            } else {
-                slot.read_latest().map(|e| e.transform)
-            };
-
-            if let Some(tf) = entry {
+            if let Some(tf) = read(slot) {
                 // The stored transform is parent->child
                 // We compose in order: tf_child first (closest to point), then tf_parent
                 // result = tf.compose(result) -- apply tf after existing result
                 result = tf.compose(&result);
             } else if !slot.is_static() {
                 // Dynamic frame with no data
-                return None;
+                return Err(frame_id);
             }
         }
 
@@ -524,21 +567,15 @@ impl HFrameCore {
             let frame_id = chain[i + 1];
             let slot = &self.slots[frame_id as usize];
 
-            let entry = if let Some(ts) = timestamp {
-                slot.read_interpolated(ts)
-            } else {
-                slot.read_latest().map(|e| e.transform)
-            };
-
-            if let Some(tf) = entry {
+            if let Some(tf) = read(slot) {
                 // Going down: need inverse (stored is parent->child, we want child->parent)
                 result = result.compose(&tf.inverse());
             } else if !slot.is_static() {
-                return None;
+                return Err(frame_id);
             }
         }
 
-        Some(result)
+        Ok(result)
     }
 }
 
@@ -0,0 +1,371 @@
+//! Robot descriptions: fixed frame offsets loaded from URDF or YAML
+//!
+//! Sensor mounting offsets belong in one file rather than in every node that
+//! needs them. A [`RobotDescription`] lists the frames of a robot and their
+//! transforms relative to their parents; loading it into an HFrame registers
+//! fixed joints as static frames and movable joints as dynamic frames, which
+//! the nodes driving those joints then update.
+//!
+//! # YAML format
+//!
+//! ```yaml
+//! frames:
+//!   - name: base_link            # root (no parent)
+//!   - name: laser
+//!     parent: base_link
+//!     translation: [0.15, 0.0, 0.2]
+//!     rotation: [0.0, 0.0, 3.14159]  # roll, pitch, yaw (rad)
+//!   - name: wheel_left
+//!     parent: base_link
+//!     translation: [0.0, 0.2, 0.05]
+//!     dynamic: true              # updated at runtime
+//! ```
+//!
+//! # Example
+//! ```rust,ignore
+//! let desc = RobotDescription::load("config/robot.urdf")?;
+//! let hf = HFrame::new();
+//! hf.load_description(&desc)?;
+//! let laser_in_base = hf.tf("laser", "base_link")?;
+//! ```
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::messages::{StaticTransformStamped, TFMessage, MAX_TRANSFORMS_PER_MESSAGE};
+use super::transform::Transform;
+use super::types::{HFrameError, HFrameResult};
+use super::HFrame;
+
+/// One frame of a robot description
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FrameDescription {
+    /// Frame name
+    pub name: String,
+    /// Parent frame (None for a root)
+    #[serde(default)]
+    pub parent: Option<String>,
+    /// Offset from the parent frame (m)
+    #[serde(default)]
+    pub translation: [f64; 3],
+    /// Rotation relative to the parent frame as roll, pitch, yaw (rad)
+    #[serde(default)]
+    pub rotation: [f64; 3],
+    /// Frame moves at runtime (non-fixed joint) and is only registered
+    #[serde(default)]
+    pub dynamic: bool,
+}
+
+impl FrameDescription {
+    /// Fixed frame at an offset from its parent
+    pub fn fixed(name: &str, parent: &str, translation: [f64; 3], rotation: [f64; 3]) -> Self {
+        Self {
+            name: name.to_string(),
+            parent: Some(parent.to_string()),
+            translation,
+            rotation,
+            dynamic: false,
+        }
+    }
+
+    /// Transform from the parent frame to this frame
+    pub fn transform(&self) -> Transform {
+        Transform::from_euler(self.translation, self.rotation)
+    }
+}
+
+/// Frame tree of a robot, ordered parents before children
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RobotDescription {
+    /// Frames in the description
+    pub frames: Vec<FrameDescription>,
+}
+
+impl RobotDescription {
+    /// Build a description from frames, checking names and ordering parents first
+    ///
+    /// Parents that are not part of the description must already exist in the
+    /// HFrame the description is loaded into.
+    pub fn new(frames: Vec<FrameDescription>) -> HFrameResult<Self> {
+        let mut seen = HashSet::new();
+        for frame in &frames {
+            if frame.name.is_empty() {
+                return Err(HFrameError::InvalidDescription(
+                    "frame with empty name".to_string(),
+                ));
+            }
+            if !seen.insert(frame.name.as_str()) {
+                return Err(HFrameError::InvalidDescription(format!(
+                    "frame '{}' defined twice",
+                    frame.name
+                )));
+            }
+        }
+
+        // Parents before children, one tree level per pass so siblings keep
+        // their declaration order; anything left over is part of a cycle
+        let index: HashMap<&str, usize> = frames
+            .iter()
+            .enumerate()
+            .map(|(i, f)| (f.name.as_str(), i))
+            .collect();
+        let mut placed = vec![false; frames.len()];
+        let mut order = Vec::with_capacity(frames.len());
+        while order.len() < frames.len() {
+            let level: Vec<usize> = frames
+                .iter()
+                .enumerate()
+                .filter(|&(i, frame)| {
+                    !placed[i]
+                        && match frame.parent.as_deref().and_then(|p| index.get(p)) {
+                            Some(&parent) => placed[parent],
+                            None => true,
+                        }
+                })
+                .map(|(i, _)| i)
+                .collect();
+            if level.is_empty() {
+                return Err(HFrameError::CycleDetected);
+            }
+            for &i in &level {
+                placed[i] = true;
+            }
+            order.extend(level);
+        }
+
+        let mut frames: Vec<Option<FrameDescription>> = frames.into_iter().map(Some).collect();
+        Ok(Self {
+            frames: order.into_iter().filter_map(|i| frames[i].take()).collect(),
+        })
+    }
+
+    /// Parse the YAML frame list format (see module docs)
+    pub fn from_yaml_str(yaml: &str) -> HFrameResult<Self> {
+        let desc: RobotDescription = serde_yaml::from_str(yaml)
+            .map_err(|e| HFrameError::InvalidDescription(e.to_string()))?;
+        Self::new(desc.frames)
+    }
+
+    /// Parse a URDF robot description
+    ///
+    /// Every link becomes a frame. Links that are the child of a joint are
+    /// placed at the joint origin; fixed joints give static frames and all
+    /// other joint types give dynamic frames.
+    pub fn from_urdf_str(urdf: &str) -> HFrameResult<Self> {
+        let robot = urdf_rs::read_from_string(urdf)
+            .map_err(|e| HFrameError::InvalidDescription(e.to_string()))?;
+
+        let mut frames: Vec<FrameDescription> = robot
+            .joints
+            .iter()
+            .map(|joint| FrameDescription {
+                name: joint.child.link.clone(),
+                parent: Some(joint.parent.link.clone()),
+                translation: [
+                    joint.origin.xyz[0],
+                    joint.origin.xyz[1],
+                    joint.origin.xyz[2],
+                ],
+                rotation: [
+                    joint.origin.rpy[0],
+                    joint.origin.rpy[1],
+                    joint.origin.rpy[2],
+                ],
+                dynamic: !matches!(joint.joint_type, urdf_rs::JointType::Fixed),
+            })
+            .collect();
+
+        // Links that are no joint's child are roots
+        let children: HashSet<&str> = robot
+            .joints
+            .iter()
+            .map(|joint| joint.child.link.as_str())
+            .collect();
+        let roots = robot
+            .links
+            .iter()
+            .filter(|link| !children.contains(link.name.as_str()))
+            .map(|link| FrameDescription {
+                name: link.name.clone(),
+                parent: None,
+                translation: [0.0; 3],
+                rotation: [0.0; 3],
+                dynamic: false,
+            });
+        frames.splice(0..0, roots);
+
+        Self::new(frames)
+    }
+
+    /// Load a description file, URDF for `.urdf`/`.xml` and YAML otherwise
+    pub fn load<P: AsRef<Path>>(path: P) -> HFrameResult<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| HFrameError::InvalidDescription(format!("{}: {}", path.display(), e)))?;
+
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("urdf") | Some("xml") => Self::from_urdf_str(&content),
+            _ => Self::from_yaml_str(&content),
+        }
+    }
+
+    /// Look up a frame by name
+    pub fn frame(&self, name: &str) -> Option<&FrameDescription> {
+        self.frames.iter().find(|f| f.name == name)
+    }
+
+    /// Fixed transforms of all static frames with a parent
+    pub fn static_transforms(&self) -> Vec<StaticTransformStamped> {
+        self.frames
+            .iter()
+            .filter(|f| !f.dynamic)
+            .filter_map(|f| {
+                let parent = f.parent.as_deref()?;
+                Some(StaticTransformStamped::new(parent, &f.name, f.transform()))
+            })
+            .collect()
+    }
+
+    /// Static transforms batched into TF messages stamped with `timestamp`
+    pub fn tf_messages(&self, timestamp: u64) -> Vec<TFMessage> {
+        self.static_transforms()
+            .chunks(MAX_TRANSFORMS_PER_MESSAGE)
+            .map(|chunk| {
+                TFMessage::from_vec(chunk.iter().map(|t| t.to_stamped(timestamp)).collect())
+            })
+            .collect()
+    }
+}
+
+impl HFrame {
+    /// Register the frames of a robot description
+    ///
+    /// Static frames receive their fixed transform; dynamic frames are only
+    /// registered. Frames that already exist are kept (static ones get the
+    /// description's transform), so loading the same description twice is
+    /// harmless.
+    ///
+    /// Returns the number of newly registered frames.
+    pub fn load_description(&self, description: &RobotDescription) -> HFrameResult<usize> {
+        let mut registered = 0;
+        for frame in &description.frames {
+            let parent = frame.parent.as_deref();
+            let transform = frame.transform();
+
+            match self.frame_id(&frame.name) {
+                Some(id) if !frame.dynamic && parent.is_some() => {
+                    if !self.core.is_static(id) {
+                        return Err(HFrameError::FrameAlreadyExists(frame.name.clone()));
+                    }
+                    self.core.set_static_transform(id, &transform);
+                }
+                Some(_) => {}
+                None if frame.dynamic => {
+                    self.register_frame(&frame.name, parent)?;
+                    registered += 1;
+                }
+                None => {
+                    self.register_static_frame(&frame.name, parent, &transform)?;
+                    registered += 1;
+                }
+            }
+        }
+        Ok(registered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const YAML: &str = r#"
+frames:
+  - name: laser
+    parent: base_link
+    translation: [0.15, 0.0, 0.2]
+  - name: base_link
+  - name: wheel_left
+    parent: base_link
+    translation: [0.0, 0.2, 0.05]
+    dynamic: true
+"#;
+
+    #[test]
+    fn test_yaml_orders_parents_first() {
+        let desc = RobotDescription::from_yaml_str(YAML).unwrap();
+        let names: Vec<&str> = desc.frames.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["base_link", "laser", "wheel_left"]);
+        assert_eq!(desc.static_transforms().len(), 1);
+        assert_eq!(desc.tf_messages(0)[0].len(), 1);
+    }
+
+    #[test]
+    fn test_load_into_hframe() {
+        let desc = RobotDescription::from_yaml_str(YAML).unwrap();
+        let hf = HFrame::new();
+        assert_eq!(hf.load_description(&desc).unwrap(), 3);
+        assert_eq!(hf.load_description(&desc).unwrap(), 0);
+
+        let tf = hf.tf("laser", "base_link").unwrap();
+        assert!((tf.translation[0] - 0.15).abs() < 1e-10);
+        assert!((tf.translation[2] - 0.2).abs() < 1e-10);
+
+        let wheel = hf.frame_id("wheel_left").unwrap();
+        assert!(!hf.core.is_static(wheel));
+    }
+
+    #[test]
+    fn test_urdf() {
+        let urdf = r#"
+<robot name="rover">
+  <link name="base_link"/>
+  <link name="camera"/>
+  <link name="arm"/>
+  <joint name="camera_joint" type="fixed">
+    <parent link="base_link"/>
+    <child link="camera"/>
+    <origin xyz="0.2 0 0.3" rpy="0 0 1.5707963"/>
+  </joint>
+  <joint name="arm_joint" type="revolute">
+    <parent link="base_link"/>
+    <child link="arm"/>
+    <origin xyz="0 0 0.1" rpy="0 0 0"/>
+    <axis xyz="0 0 1"/>
+    <limit lower="-1" upper="1" effort="10" velocity="1"/>
+  </joint>
+</robot>
+"#;
+        let desc = RobotDescription::from_urdf_str(urdf).unwrap();
+        assert_eq!(desc.frames[0].name, "base_link");
+        assert_eq!(desc.frames[0].parent, None);
+
+        let camera = desc.frame("camera").unwrap();
+        assert!(!camera.dynamic);
+        assert!((camera.translation[0] - 0.2).abs() < 1e-10);
+        assert!((camera.rotation[2] - std::f64::consts::FRAC_PI_2).abs() < 1e-6);
+        assert!(desc.frame("arm").unwrap().dynamic);
+    }
+
+    #[test]
+    fn test_invalid_descriptions() {
+        let duplicate = vec![
+            FrameDescription::fixed("a", "base", [0.0; 3], [0.0; 3]),
+            FrameDescription::fixed("a", "base", [0.0; 3], [0.0; 3]),
+        ];
+        assert!(matches!(
+            RobotDescription::new(duplicate),
+            Err(HFrameError::InvalidDescription(_))
+        ));
+
+        let cycle = vec![
+            FrameDescription::fixed("a", "b", [0.0; 3], [0.0; 3]),
+            FrameDescription::fixed("b", "a", [0.0; 3], [0.0; 3]),
+        ];
+        assert!(matches!(
+            RobotDescription::new(cycle),
+            Err(HFrameError::CycleDetected)
+        ));
+    }
+}
@@ -5,6 +5,7 @@
 
 use super::transform::Transform;
 use bytemuck::{Pod, Zeroable};
+use horus_core::core::LogSummary;
 use serde::{Deserialize, Serialize};
 
 /// Maximum number of transforms in a batch message
@@ -239,6 +240,12 @@ impl TFMessage {
     }
 }
 
+impl LogSummary for TFMessage {
+    fn log_summary(&self) -> String {
+        format!("TFMessage({} transforms)", self.count)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
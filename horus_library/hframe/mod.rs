@@ -64,6 +64,22 @@
 //! let tf_old = hf.tf_at("camera_frame", "world", past_timestamp)?;
 //! ```
 //!
+//! ## Multi-Robot Trees
+//!
+//! Frames can be mounted under a prefix (`robot1/base_link`) so several robots
+//! share one tree without name collisions, and aliases give frames extra names:
+//!
+//! ```rust,ignore
+//! let robot1 = hf.mount("robot1").attach_to("world");
+//! robot1.register_frame("base_link", None)?;          // "robot1/base_link"
+//! robot1.apply_tf_message(&tf_msg, false)?;           // ingest a robot's TF stream
+//!
+//! hf.merge_tree("robot2", &robot2_hf, Some("world"))?; // copy another HFrame
+//! let tf = hf.tf("robot1/base_link", "robot2/base_link")?;
+//!
+//! hf.add_alias("base_link", "robot1/base_link")?;
+//! ```
+//!
 //! ## Performance Comparison
 //!
 //! | Operation | HFrame | ROS2 TF2 |
@@ -77,7 +93,9 @@
 mod bench;
 mod config;
 mod core;
+mod description;
 mod messages;
+mod mount;
 mod registry;
 mod slot;
 mod transform;
@@ -86,6 +104,8 @@ mod types;
 // Re-export public API
 pub use config::HFrameConfig;
 pub use core::HFrameCore;
+pub use description::{FrameDescription, RobotDescription};
+pub use mount::{prefixed_frame_name, split_frame_prefix, HFrameMount, FRAME_PREFIX_SEPARATOR};
 pub use registry::FrameRegistry;
 pub use slot::{FrameSlot, TransformEntry};
 pub use types::{FrameId, HFrameError, HFrameResult, INVALID_FRAME, NO_PARENT};
@@ -211,6 +231,24 @@ impl HFrame {
         self.registry.all_names()
     }
 
+    /// Add an alternative name for an existing frame
+    ///
+    /// Aliases resolve anywhere a frame name is accepted (queries, updates,
+    /// parents) but are not listed by [`HFrame::all_frames`].
+    pub fn add_alias(&self, alias: &str, target: &str) -> HFrameResult<()> {
+        self.registry.add_alias(alias, target)
+    }
+
+    /// Remove an alias
+    pub fn remove_alias(&self, alias: &str) -> HFrameResult<()> {
+        self.registry.remove_alias(alias)
+    }
+
+    /// Get all aliases as (alias, frame name) pairs
+    pub fn aliases(&self) -> Vec<(String, String)> {
+        self.registry.all_aliases()
+    }
+
     /// Get number of registered frames
     pub fn frame_count(&self) -> usize {
         self.core.frame_count()
@@ -333,6 +371,85 @@ impl HFrame {
         }
     }
 
+    /// Look up the transform from `source` to `target` at a timestamp (tf2 style)
+    ///
+    /// Unlike [`tf_at`](Self::tf_at), which clamps to the nearest buffered
+    /// sample, this fails with [`HFrameError::ExtrapolationLimit`] when any
+    /// frame on the path has no history covering `timestamp_ns`. Frames may
+    /// be extrapolated past their history by up to
+    /// [`HFrameConfig::max_extrapolation_ns`]. A timestamp of 0 returns the
+    /// latest transform.
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// // Deskew a lidar point captured at `point_time` into the odom frame
+    /// let tf = hf.lookup_transform("odom", "lidar", point_time)?;
+    /// let point_in_odom = tf.transform_point(point_in_lidar);
+    /// ```
+    pub fn lookup_transform(
+        &self,
+        target: &str,
+        source: &str,
+        timestamp_ns: u64,
+    ) -> HFrameResult<Transform> {
+        self.lookup_transform_with_extrapolation(
+            target,
+            source,
+            timestamp_ns,
+            self.config.max_extrapolation_ns,
+        )
+    }
+
+    /// Like [`lookup_transform`](Self::lookup_transform) with an explicit extrapolation limit
+    pub fn lookup_transform_with_extrapolation(
+        &self,
+        target: &str,
+        source: &str,
+        timestamp_ns: u64,
+        max_extrapolation_ns: u64,
+    ) -> HFrameResult<Transform> {
+        if timestamp_ns == 0 {
+            return self.tf(source, target);
+        }
+
+        let src_id = self
+            .registry
+            .lookup(source)
+            .ok_or_else(|| HFrameError::FrameNotFound(source.to_string()))?;
+        let dst_id = self
+            .registry
+            .lookup(target)
+            .ok_or_else(|| HFrameError::FrameNotFound(target.to_string()))?;
+
+        self.core
+            .resolve_at_within(src_id, dst_id, timestamp_ns, max_extrapolation_ns)
+            .map_err(|failed| match failed {
+                None => HFrameError::NoPath(source.to_string(), target.to_string()),
+                Some(id) => match self.core.time_range(id) {
+                    Some((oldest, newest)) => HFrameError::ExtrapolationLimit {
+                        frame: self.frame_name(id).unwrap_or_else(|| id.to_string()),
+                        timestamp: timestamp_ns,
+                        oldest,
+                        newest,
+                    },
+                    None => HFrameError::TransformNotAvailable(timestamp_ns),
+                },
+            })
+    }
+
+    /// Check if [`lookup_transform`](Self::lookup_transform) would succeed
+    pub fn can_transform_at(&self, target: &str, source: &str, timestamp_ns: u64) -> bool {
+        self.lookup_transform(target, source, timestamp_ns).is_ok()
+    }
+
+    /// Time range `(oldest, newest)` of a frame's buffered history
+    ///
+    /// Static frames cover all timestamps. Returns `None` for unknown frames
+    /// and dynamic frames that have never been updated.
+    pub fn time_range(&self, name: &str) -> Option<(u64, u64)> {
+        self.core.time_range(self.registry.lookup(name)?)
+    }
+
     // ========================================================================
     // Convenience Methods
     // ========================================================================
@@ -452,11 +569,11 @@ impl std::fmt::Display for HFrameStats {
 }
 
 /// Get current timestamp in nanoseconds
+///
+/// Follows simulated time when `use_sim_time` is enabled, so transforms
+/// stamped here line up with sensor data from sim2d/sim3d.
 pub fn timestamp_now() -> u64 {
-    std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .map(|d| d.as_nanos() as u64)
-        .unwrap_or(0)
+    horus_core::core::clock::now_ns()
 }
 
 #[cfg(test)]
@@ -501,6 +618,56 @@ mod tests {
         assert_eq!(hf.frame_name(0), Some("world".to_string()));
     }
 
+    #[test]
+    fn test_lookup_transform_at_time() {
+        let hf = HFrame::new();
+        hf.register_frame("odom", None).unwrap();
+        hf.register_frame("base_link", Some("odom")).unwrap();
+        hf.register_static_frame(
+            "lidar",
+            Some("base_link"),
+            &Transform::from_translation([0.0, 0.0, 0.3]),
+        )
+        .unwrap();
+
+        hf.update_transform(
+            "base_link",
+            &Transform::from_translation([0.0, 0.0, 0.0]),
+            1000,
+        )
+        .unwrap();
+        hf.update_transform(
+            "base_link",
+            &Transform::from_translation([1.0, 0.0, 0.0]),
+            2000,
+        )
+        .unwrap();
+        assert_eq!(hf.time_range("base_link"), Some((1000, 2000)));
+
+        // Interpolated mid-scan
+        let tf = hf.lookup_transform("odom", "lidar", 1500).unwrap();
+        assert!((tf.translation[0] - 0.5).abs() < 1e-10);
+        assert!((tf.translation[2] - 0.3).abs() < 1e-10);
+
+        // Timestamp 0 means latest
+        let tf = hf.lookup_transform("odom", "lidar", 0).unwrap();
+        assert!((tf.translation[0] - 1.0).abs() < 1e-10);
+
+        // Newer than the history: fails instead of clamping
+        assert!(matches!(
+            hf.lookup_transform("odom", "lidar", 2100),
+            Err(HFrameError::ExtrapolationLimit { ref frame, oldest: 1000, newest: 2000, .. })
+                if frame == "base_link"
+        ));
+        assert!(!hf.can_transform_at("odom", "lidar", 2100));
+
+        // Allowed with an extrapolation limit
+        let tf = hf
+            .lookup_transform_with_extrapolation("odom", "lidar", 2100, 200)
+            .unwrap();
+        assert!((tf.translation[0] - 1.1).abs() < 1e-10);
+    }
+
     #[test]
     fn test_config_presets() {
         let small = HFrame::small();
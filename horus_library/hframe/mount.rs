@@ -0,0 +1,530 @@
+//! Prefix mounting for multi-robot frame trees
+//!
+//! Robots usually share frame names (`base_link`, `odom`, `camera`). Mounting
+//! a robot under a prefix stores its frames as `robot1/base_link`, so several
+//! trees can live in one HFrame without collisions, while code written for a
+//! single robot keeps using unprefixed names through an [`HFrameMount`].
+//! [`HFrame::namespaced`] mounts the tree under the process namespace.
+
+use super::messages::{TFMessage, TransformStamped};
+use super::transform::Transform;
+use super::types::{FrameId, HFrameError, HFrameResult};
+use super::HFrame;
+
+/// Separator between a prefix and a frame name
+pub const FRAME_PREFIX_SEPARATOR: char = '/';
+
+/// Build the full name of `name` under `prefix` (`robot1` + `base_link` -> `robot1/base_link`)
+///
+/// Leading separators on `name` are ignored (`/base_link` is `base_link`), and
+/// an empty prefix leaves the name unchanged.
+pub fn prefixed_frame_name(prefix: &str, name: &str) -> String {
+    let name = name.trim_start_matches(FRAME_PREFIX_SEPARATOR);
+    let prefix = prefix.trim_matches(FRAME_PREFIX_SEPARATOR);
+    if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}{}{}", prefix, FRAME_PREFIX_SEPARATOR, name)
+    }
+}
+
+/// Split a full frame name into its top-level prefix and the rest
+///
+/// `robot1/base_link` -> `(Some("robot1"), "base_link")`, `world` -> `(None, "world")`
+pub fn split_frame_prefix(name: &str) -> (Option<&str>, &str) {
+    match name.split_once(FRAME_PREFIX_SEPARATOR) {
+        Some((prefix, rest)) if !prefix.is_empty() => (Some(prefix), rest),
+        _ => (None, name),
+    }
+}
+
+/// View of an HFrame with all frame names under a prefix
+///
+/// Names are resolved inside the prefix first and fall back to the shared
+/// tree, so a robot can attach its frames to a common `world` or `map` frame.
+/// Root frames registered through the mount are attached to the mount's root
+/// parent when one is set (see [`HFrameMount::attach_to`]).
+///
+/// # Example
+/// ```rust,ignore
+/// let hf = HFrame::new();
+/// hf.register_frame("world", None)?;
+///
+/// let robot1 = hf.mount("robot1").attach_to("world");
+/// robot1.register_frame("base_link", None)?;       // robot1/base_link under world
+/// robot1.register_frame("camera", Some("base_link"))?;
+///
+/// let robot2 = hf.mount("robot2").attach_to("world");
+/// robot2.register_frame("base_link", None)?;       // robot2/base_link
+///
+/// let tf = robot1.tf("camera", "world")?;          // falls back to the shared world
+/// let tf = robot1.tf_to("camera", &robot2, "base_link")?;
+/// let tf = hf.tf("robot1/camera", "robot2/base_link")?;
+/// ```
+#[derive(Clone)]
+pub struct HFrameMount {
+    hf: HFrame,
+    prefix: String,
+    root_parent: Option<String>,
+}
+
+impl HFrameMount {
+    pub(super) fn new(hf: HFrame, prefix: &str) -> Self {
+        Self {
+            hf,
+            prefix: prefix.trim_matches(FRAME_PREFIX_SEPARATOR).to_string(),
+            root_parent: None,
+        }
+    }
+
+    /// Attach root frames registered through this mount to `parent`
+    ///
+    /// `parent` is resolved like any other name (inside the prefix first).
+    pub fn attach_to(mut self, parent: &str) -> Self {
+        self.root_parent = Some(parent.to_string());
+        self
+    }
+
+    /// The mount prefix
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// The underlying (unprefixed) HFrame
+    pub fn hframe(&self) -> &HFrame {
+        &self.hf
+    }
+
+    /// Full name of a frame in this mount
+    pub fn full_name(&self, name: &str) -> String {
+        prefixed_frame_name(&self.prefix, name)
+    }
+
+    /// Resolve a name to a frame ID (prefixed first, then the shared tree)
+    pub fn frame_id(&self, name: &str) -> Option<FrameId> {
+        self.hf
+            .frame_id(&self.full_name(name))
+            .or_else(|| self.hf.frame_id(name))
+    }
+
+    /// Full name a name resolves to, if the frame exists
+    pub fn resolve(&self, name: &str) -> Option<String> {
+        self.frame_id(name).and_then(|id| self.hf.frame_name(id))
+    }
+
+    /// Check if a name resolves to a frame
+    pub fn has_frame(&self, name: &str) -> bool {
+        self.frame_id(name).is_some()
+    }
+
+    /// Names of the frames under this prefix, without the prefix
+    pub fn frames(&self) -> Vec<String> {
+        self.hf
+            .frames_with_prefix(&self.prefix)
+            .into_iter()
+            .map(|name| self.local_name(&name).to_string())
+            .collect()
+    }
+
+    /// Strip this mount's prefix from a full name
+    fn local_name<'a>(&self, full_name: &'a str) -> &'a str {
+        full_name
+            .strip_prefix(self.prefix.as_str())
+            .and_then(|rest| rest.strip_prefix(FRAME_PREFIX_SEPARATOR))
+            .unwrap_or(full_name)
+    }
+
+    /// Full name of the parent for a new frame
+    fn parent_name(&self, parent: Option<&str>) -> HFrameResult<Option<String>> {
+        match parent.or(self.root_parent.as_deref()) {
+            Some(parent) => self
+                .resolve(parent)
+                .map(Some)
+                .ok_or_else(|| HFrameError::ParentNotFound(self.full_name(parent))),
+            None => Ok(None),
+        }
+    }
+
+    fn require(&self, name: &str) -> HFrameResult<FrameId> {
+        self.frame_id(name)
+            .ok_or_else(|| HFrameError::FrameNotFound(self.full_name(name)))
+    }
+
+    // ========================================================================
+    // Registration and updates
+    // ========================================================================
+
+    /// Register a frame under the prefix
+    ///
+    /// Without a parent, the frame is attached to the mount's root parent.
+    pub fn register_frame(&self, name: &str, parent: Option<&str>) -> HFrameResult<FrameId> {
+        let parent = self.parent_name(parent)?;
+        self.hf
+            .register_frame(&self.full_name(name), parent.as_deref())
+    }
+
+    /// Register a static frame under the prefix
+    pub fn register_static_frame(
+        &self,
+        name: &str,
+        parent: Option<&str>,
+        transform: &Transform,
+    ) -> HFrameResult<FrameId> {
+        let parent = self.parent_name(parent)?;
+        self.hf
+            .register_static_frame(&self.full_name(name), parent.as_deref(), transform)
+    }
+
+    /// Update a frame's transform
+    pub fn update_transform(
+        &self,
+        name: &str,
+        transform: &Transform,
+        timestamp_ns: u64,
+    ) -> HFrameResult<()> {
+        let id = self.require(name)?;
+        self.hf.update_transform_by_id(id, transform, timestamp_ns);
+        Ok(())
+    }
+
+    /// Set a static transform
+    pub fn set_static_transform(&self, name: &str, transform: &Transform) -> HFrameResult<()> {
+        let id = self.require(name)?;
+        self.hf.core.set_static_transform(id, transform);
+        Ok(())
+    }
+
+    /// Apply a stamped transform, registering unknown frames under the prefix
+    ///
+    /// Unknown parents become roots attached to the mount's root parent. Use
+    /// this to ingest a robot's TF stream into a shared (e.g. fleet) tree.
+    pub fn apply_transform(&self, tf: &TransformStamped, is_static: bool) -> HFrameResult<()> {
+        let parent = tf.parent_frame_id();
+        let child = tf.child_frame_id();
+
+        if !parent.is_empty() && !self.has_frame(&parent) {
+            self.register_frame(&parent, None)?;
+        }
+        let parent = (!parent.is_empty()).then_some(parent.as_str());
+
+        // The child always belongs to this mount, never to the shared tree
+        let id = match self.hf.frame_id(&self.full_name(&child)) {
+            Some(id) => id,
+            None if is_static => self.register_static_frame(&child, parent, &tf.transform)?,
+            None => self.register_frame(&child, parent)?,
+        };
+
+        if is_static {
+            self.hf.core.set_static_transform(id, &tf.transform);
+        } else {
+            self.hf
+                .update_transform_by_id(id, &tf.transform, tf.timestamp);
+        }
+        Ok(())
+    }
+
+    /// Apply every transform in a TF message (see [`HFrameMount::apply_transform`])
+    pub fn apply_tf_message(&self, msg: &TFMessage, is_static: bool) -> HFrameResult<()> {
+        msg.iter()
+            .try_for_each(|tf| self.apply_transform(tf, is_static))
+    }
+
+    // ========================================================================
+    // Queries
+    // ========================================================================
+
+    /// Latest transform from `src` to `dst` (both resolved through the mount)
+    pub fn tf(&self, src: &str, dst: &str) -> HFrameResult<Transform> {
+        let src_id = self.require(src)?;
+        let dst_id = self.require(dst)?;
+        self.hf
+            .tf_by_id(src_id, dst_id)
+            .ok_or_else(|| HFrameError::NoPath(self.full_name(src), self.full_name(dst)))
+    }
+
+    /// Transform from `src` to `dst` at a timestamp
+    pub fn tf_at(&self, src: &str, dst: &str, timestamp_ns: u64) -> HFrameResult<Transform> {
+        let src_id = self.require(src)?;
+        let dst_id = self.require(dst)?;
+        self.hf
+            .tf_at_by_id(src_id, dst_id, timestamp_ns)
+            .ok_or_else(|| HFrameError::NoPath(self.full_name(src), self.full_name(dst)))
+    }
+
+    /// Latest transform from `src` in this mount to `dst` in another mount
+    ///
+    /// Both trees must be connected through a shared frame (e.g. `world`).
+    pub fn tf_to(&self, src: &str, other: &HFrameMount, dst: &str) -> HFrameResult<Transform> {
+        let src_id = self.require(src)?;
+        let dst_id = other.require(dst)?;
+        self.hf
+            .tf_by_id(src_id, dst_id)
+            .ok_or_else(|| HFrameError::NoPath(self.full_name(src), other.full_name(dst)))
+    }
+
+    /// Check if a transform path exists between two frames
+    pub fn can_transform(&self, src: &str, dst: &str) -> bool {
+        match (self.frame_id(src), self.frame_id(dst)) {
+            (Some(src_id), Some(dst_id)) => self.hf.core.can_transform(src_id, dst_id),
+            _ => false,
+        }
+    }
+}
+
+impl HFrame {
+    /// Get a view of this HFrame with frame names under `prefix`
+    pub fn mount(&self, prefix: &str) -> HFrameMount {
+        HFrameMount::new(self.clone(), prefix)
+    }
+
+    /// Get a view of this HFrame under the process namespace
+    ///
+    /// Frames are stored as `robot1/base_link` when the process runs in a
+    /// namespace (`horus run --namespace`, `HORUS_NAMESPACE` or
+    /// `Scheduler::with_namespace`), matching its namespaced topics, and
+    /// unprefixed otherwise.
+    pub fn namespaced(&self) -> HFrameMount {
+        self.mount(&horus_core::communication::namespace::current_namespace().unwrap_or_default())
+    }
+
+    /// Copy another frame tree into this one under `prefix`
+    ///
+    /// Frames are registered as `prefix/name` with their static/dynamic type,
+    /// and root frames are attached to `attach_to` if given. Frames that were
+    /// merged before are kept and only receive newer transforms, so calling
+    /// this periodically keeps the merged copy up to date.
+    ///
+    /// Returns the number of newly registered frames.
+    pub fn merge_tree(
+        &self,
+        prefix: &str,
+        source: &HFrame,
+        attach_to: Option<&str>,
+    ) -> HFrameResult<usize> {
+        if let Some(parent) = attach_to {
+            if !self.has_frame(parent) {
+                return Err(HFrameError::ParentNotFound(parent.to_string()));
+            }
+        }
+
+        // Parents before children
+        let mut frames: Vec<(usize, FrameId, String)> = source
+            .all_frames()
+            .into_iter()
+            .filter_map(|name| {
+                let id = source.frame_id(&name)?;
+                let mut depth = 0;
+                let mut current = id;
+                while let Some(parent) = source.core.parent(current) {
+                    depth += 1;
+                    current = parent;
+                    if depth > source.config.max_frames {
+                        break;
+                    }
+                }
+                Some((depth, id, name))
+            })
+            .collect();
+        frames.sort();
+
+        let mut registered = 0;
+        for (_, source_id, name) in frames {
+            let full_name = prefixed_frame_name(prefix, &name);
+            let is_static = source.core.is_static(source_id);
+            let latest = source.core.latest(source_id);
+
+            let id = match self.frame_id(&full_name) {
+                Some(id) => id,
+                None => {
+                    let parent = match source.core.parent(source_id) {
+                        Some(parent_id) => source
+                            .frame_name(parent_id)
+                            .map(|parent| prefixed_frame_name(prefix, &parent)),
+                        None => attach_to.map(str::to_string),
+                    };
+                    registered += 1;
+                    if is_static {
+                        self.registry
+                            .register_static(&full_name, parent.as_deref())?
+                    } else {
+                        self.registry.register(&full_name, parent.as_deref())?
+                    }
+                }
+            };
+
+            let Some(entry) = latest else {
+                continue;
+            };
+            if is_static {
+                self.core.set_static_transform(id, &entry.transform);
+            } else {
+                let newer = self
+                    .core
+                    .latest(id)
+                    .is_none_or(|current| entry.timestamp_ns > current.timestamp_ns);
+                if newer {
+                    self.core.update(id, &entry.transform, entry.timestamp_ns);
+                }
+            }
+        }
+
+        Ok(registered)
+    }
+
+    /// Top-level prefixes in use (`robot1` for `robot1/base_link`)
+    pub fn prefixes(&self) -> Vec<String> {
+        let mut prefixes: Vec<String> = self
+            .all_frames()
+            .iter()
+            .filter_map(|name| split_frame_prefix(name).0.map(str::to_string))
+            .collect();
+        prefixes.sort();
+        prefixes.dedup();
+        prefixes
+    }
+
+    /// Full names of all frames under `prefix`
+    pub fn frames_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let prefix = prefix.trim_matches(FRAME_PREFIX_SEPARATOR);
+        if prefix.is_empty() {
+            return self.all_frames();
+        }
+        let mut frames: Vec<String> = self
+            .all_frames()
+            .into_iter()
+            .filter(|name| {
+                name.strip_prefix(prefix)
+                    .is_some_and(|rest| rest.starts_with(FRAME_PREFIX_SEPARATOR))
+            })
+            .collect();
+        frames.sort();
+        frames
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefixed_names() {
+        assert_eq!(
+            prefixed_frame_name("robot1", "base_link"),
+            "robot1/base_link"
+        );
+        assert_eq!(
+            prefixed_frame_name("/robot1/", "/base_link"),
+            "robot1/base_link"
+        );
+        assert_eq!(prefixed_frame_name("", "base_link"), "base_link");
+        assert_eq!(
+            split_frame_prefix("robot1/arm/tool0"),
+            (Some("robot1"), "arm/tool0")
+        );
+        assert_eq!(split_frame_prefix("world"), (None, "world"));
+    }
+
+    #[test]
+    fn test_mount_and_cross_prefix_queries() {
+        let hf = HFrame::new();
+        hf.register_frame("world", None).unwrap();
+
+        let robot1 = hf.mount("robot1").attach_to("world");
+        let robot2 = hf.mount("robot2").attach_to("world");
+        for robot in [&robot1, &robot2] {
+            robot.register_frame("base_link", None).unwrap();
+            robot.register_frame("camera", Some("base_link")).unwrap();
+        }
+
+        assert!(hf.has_frame("robot1/camera"));
+        assert_eq!(hf.parent("robot2/base_link"), Some("world".to_string()));
+        assert_eq!(hf.prefixes(), vec!["robot1", "robot2"]);
+        assert_eq!(robot1.frames(), vec!["base_link", "camera"]);
+
+        robot1
+            .update_transform(
+                "base_link",
+                &Transform::from_translation([1.0, 0.0, 0.0]),
+                10,
+            )
+            .unwrap();
+        robot2
+            .update_transform(
+                "base_link",
+                &Transform::from_translation([0.0, 2.0, 0.0]),
+                10,
+            )
+            .unwrap();
+        robot1
+            .update_transform("camera", &Transform::identity(), 10)
+            .unwrap();
+        robot2
+            .update_transform("camera", &Transform::identity(), 10)
+            .unwrap();
+
+        // Unprefixed names fall back to the shared tree
+        let tf = robot1.tf("camera", "world").unwrap();
+        assert!((tf.translation[0] - 1.0).abs() < 1e-10);
+
+        let tf = robot1.tf_to("camera", &robot2, "base_link").unwrap();
+        assert!((tf.translation[0] - 1.0).abs() < 1e-10);
+        assert!((tf.translation[1] + 2.0).abs() < 1e-10);
+        let direct = hf.tf("robot1/camera", "robot2/base_link").unwrap();
+        assert!((direct.translation[1] - tf.translation[1]).abs() < 1e-10);
+
+        // Aliases let single-robot code address a default robot
+        hf.add_alias("base_link", "robot1/base_link").unwrap();
+        assert_eq!(hf.frame_id("base_link"), hf.frame_id("robot1/base_link"));
+    }
+
+    #[test]
+    fn test_apply_transform_and_merge_tree() {
+        let fleet = HFrame::new();
+        fleet.register_frame("map", None).unwrap();
+
+        // Ingest a TF stream under a prefix
+        let robot = fleet.mount("robot1").attach_to("map");
+        let tf = TransformStamped::new(
+            "odom",
+            "base_link",
+            5,
+            Transform::from_translation([3.0, 0.0, 0.0]),
+        );
+        robot.apply_transform(&tf, false).unwrap();
+        assert_eq!(fleet.parent("robot1/odom"), Some("map".to_string()));
+        assert_eq!(
+            fleet.parent("robot1/base_link"),
+            Some("robot1/odom".to_string())
+        );
+
+        // Merge a robot's own tree under another prefix
+        let local = HFrame::new();
+        local.register_frame("odom", None).unwrap();
+        local.register_frame("base_link", Some("odom")).unwrap();
+        local
+            .register_static_frame(
+                "lidar",
+                Some("base_link"),
+                &Transform::from_translation([0.0, 0.0, 0.3]),
+            )
+            .unwrap();
+        local
+            .update_transform(
+                "base_link",
+                &Transform::from_translation([0.0, 1.0, 0.0]),
+                7,
+            )
+            .unwrap();
+
+        assert_eq!(fleet.merge_tree("robot2", &local, Some("map")).unwrap(), 3);
+        assert_eq!(fleet.merge_tree("robot2", &local, Some("map")).unwrap(), 0);
+        assert_eq!(fleet.parent("robot2/odom"), Some("map".to_string()));
+
+        fleet
+            .update_transform("robot2/odom", &Transform::identity(), 7)
+            .unwrap();
+        let tf = fleet.tf("robot2/lidar", "map").unwrap();
+        assert!((tf.translation[1] - 1.0).abs() < 1e-10);
+        assert!((tf.translation[2] - 0.3).abs() < 1e-10);
+    }
+}
@@ -20,6 +20,9 @@ pub struct FrameRegistry {
     /// ID to name mapping (indexed by frame ID)
     id_to_name: RwLock<Vec<Option<String>>>,
 
+    /// Alternative names resolving to a frame (alias to ID)
+    aliases: RwLock<HashMap<String, FrameId>>,
+
     /// Reference to core storage
     core: Arc<HFrameCore>,
 
@@ -36,6 +39,7 @@ impl FrameRegistry {
         Self {
             name_to_id: RwLock::new(HashMap::with_capacity(max_frames)),
             id_to_name: RwLock::new(vec![None; max_frames]),
+            aliases: RwLock::new(HashMap::new()),
             core,
             next_id: RwLock::new(0),
             max_frames,
@@ -46,19 +50,14 @@ impl FrameRegistry {
     ///
     /// Returns the assigned frame ID.
     pub fn register(&self, name: &str, parent_name: Option<&str>) -> HFrameResult<FrameId> {
-        // Check for existing frame
-        {
-            let name_map = self.name_to_id.read().unwrap();
-            if name_map.contains_key(name) {
-                return Err(HFrameError::FrameAlreadyExists(name.to_string()));
-            }
+        // Check for existing frame or alias
+        if self.exists(name) {
+            return Err(HFrameError::FrameAlreadyExists(name.to_string()));
         }
 
-        // Resolve parent ID
+        // Resolve parent ID (aliases allowed)
         let parent_id = if let Some(parent) = parent_name {
-            let name_map = self.name_to_id.read().unwrap();
-            *name_map
-                .get(parent)
+            self.lookup(parent)
                 .ok_or_else(|| HFrameError::ParentNotFound(parent.to_string()))?
         } else {
             NO_PARENT
@@ -86,19 +85,14 @@ impl FrameRegistry {
 
     /// Register a static frame
     pub fn register_static(&self, name: &str, parent_name: Option<&str>) -> HFrameResult<FrameId> {
-        // Check for existing frame
-        {
-            let name_map = self.name_to_id.read().unwrap();
-            if name_map.contains_key(name) {
-                return Err(HFrameError::FrameAlreadyExists(name.to_string()));
-            }
+        // Check for existing frame or alias
+        if self.exists(name) {
+            return Err(HFrameError::FrameAlreadyExists(name.to_string()));
         }
 
-        // Resolve parent ID
+        // Resolve parent ID (aliases allowed)
         let parent_id = if let Some(parent) = parent_name {
-            let name_map = self.name_to_id.read().unwrap();
-            *name_map
-                .get(parent)
+            self.lookup(parent)
                 .ok_or_else(|| HFrameError::ParentNotFound(parent.to_string()))?
         } else {
             NO_PARENT
@@ -152,14 +146,22 @@ impl FrameRegistry {
             }
         }
 
+        // Drop aliases of the removed frame
+        self.aliases
+            .write()
+            .unwrap()
+            .retain(|_, target| *target != id);
+
         Ok(())
     }
 
-    /// Look up frame ID by name
+    /// Look up frame ID by name or alias
     #[inline]
     pub fn lookup(&self, name: &str) -> Option<FrameId> {
-        let name_map = self.name_to_id.read().unwrap();
-        name_map.get(name).copied()
+        if let Some(id) = self.name_to_id.read().unwrap().get(name) {
+            return Some(*id);
+        }
+        self.aliases.read().unwrap().get(name).copied()
     }
 
     /// Look up frame name by ID
@@ -173,10 +175,9 @@ impl FrameRegistry {
         }
     }
 
-    /// Check if a frame exists
+    /// Check if a frame or alias exists
     pub fn exists(&self, name: &str) -> bool {
-        let name_map = self.name_to_id.read().unwrap();
-        name_map.contains_key(name)
+        self.lookup(name).is_some()
     }
 
     /// Get all registered frame names
@@ -207,11 +208,8 @@ impl FrameRegistry {
     /// Rename a frame
     pub fn rename(&self, old_name: &str, new_name: &str) -> HFrameResult<()> {
         // Check new name doesn't exist
-        {
-            let name_map = self.name_to_id.read().unwrap();
-            if name_map.contains_key(new_name) {
-                return Err(HFrameError::FrameAlreadyExists(new_name.to_string()));
-            }
+        if self.exists(new_name) {
+            return Err(HFrameError::FrameAlreadyExists(new_name.to_string()));
         }
 
         // Get ID for old name
@@ -238,6 +236,41 @@ impl FrameRegistry {
         Ok(())
     }
 
+    /// Add an alias that resolves to an existing frame
+    ///
+    /// `target` may itself be an alias. Aliases follow the frame through
+    /// renames and are removed when the frame is unregistered.
+    pub fn add_alias(&self, alias: &str, target: &str) -> HFrameResult<()> {
+        if self.exists(alias) {
+            return Err(HFrameError::FrameAlreadyExists(alias.to_string()));
+        }
+        let id = self
+            .lookup(target)
+            .ok_or_else(|| HFrameError::FrameNotFound(target.to_string()))?;
+
+        self.aliases.write().unwrap().insert(alias.to_string(), id);
+        Ok(())
+    }
+
+    /// Remove an alias
+    pub fn remove_alias(&self, alias: &str) -> HFrameResult<()> {
+        self.aliases
+            .write()
+            .unwrap()
+            .remove(alias)
+            .map(|_| ())
+            .ok_or_else(|| HFrameError::FrameNotFound(alias.to_string()))
+    }
+
+    /// Get all aliases as (alias, frame name) pairs
+    pub fn all_aliases(&self) -> Vec<(String, String)> {
+        let aliases = self.aliases.read().unwrap();
+        aliases
+            .iter()
+            .filter_map(|(alias, id)| Some((alias.clone(), self.lookup_name(*id)?)))
+            .collect()
+    }
+
     /// Clear all frames
     pub fn clear(&self) {
         let mut name_map = self.name_to_id.write().unwrap();
@@ -245,6 +278,7 @@ impl FrameRegistry {
         let mut next_id = self.next_id.write().unwrap();
 
         name_map.clear();
+        self.aliases.write().unwrap().clear();
         for slot in id_map.iter_mut() {
             *slot = None;
         }
@@ -379,6 +413,32 @@ mod tests {
         assert_eq!(id1, id2);
     }
 
+    #[test]
+    fn test_aliases() {
+        let registry = make_registry();
+
+        let base = registry.register("robot1/base_link", None).unwrap();
+        registry.add_alias("base_link", "robot1/base_link").unwrap();
+        registry.add_alias("base", "base_link").unwrap();
+
+        assert_eq!(registry.lookup("base_link"), Some(base));
+        assert_eq!(registry.lookup("base"), Some(base));
+
+        // Aliases resolve as parents and can't be shadowed by frames
+        let camera = registry.register("camera", Some("base")).unwrap();
+        assert_eq!(registry.core.parent(camera), Some(base));
+        assert!(matches!(
+            registry.register("base_link", None),
+            Err(HFrameError::FrameAlreadyExists(_))
+        ));
+
+        // Aliases aren't listed as frames and go away with their frame
+        assert_eq!(registry.all_names().len(), 2);
+        registry.unregister("robot1/base_link").unwrap();
+        assert_eq!(registry.lookup("base_link"), None);
+        assert!(registry.all_aliases().is_empty());
+    }
+
     #[test]
     fn test_all_names() {
         let registry = make_registry();
@@ -311,6 +311,49 @@ impl FrameSlot {
         }
     }
 
+    /// Read transform at a timestamp without clamping to the buffered range
+    ///
+    /// Inside the history this matches [`read_interpolated`](Self::read_interpolated).
+    /// Outside it, the motion between the two nearest entries is extrapolated
+    /// by up to `max_extrapolation_ns`; further out, or with no data, returns `None`.
+    pub fn read_interpolated_within(
+        &self,
+        target_ts: u64,
+        max_extrapolation_ns: u64,
+    ) -> Option<Transform> {
+        // Static frames ignore timestamp
+        if self.is_static() {
+            return self.read_latest().map(|e| e.transform);
+        }
+
+        loop {
+            let v1 = self.version.load(Ordering::Acquire);
+            if v1 & 1 == 1 {
+                std::hint::spin_loop();
+                continue;
+            }
+
+            let seq = self.sequence.load(Ordering::Acquire);
+            if seq == 0 {
+                return None;
+            }
+
+            let result = unsafe {
+                self.extrapolate_at_timestamp(
+                    &*self.history.get(),
+                    seq,
+                    target_ts,
+                    max_extrapolation_ns,
+                )
+            };
+
+            let v2 = self.version.load(Ordering::Acquire);
+            if v1 == v2 {
+                return result;
+            }
+        }
+    }
+
     // ========================================================================
     // Internal Helpers
     // ========================================================================
@@ -385,6 +428,53 @@ impl FrameSlot {
         }
     }
 
+    /// Interpolate inside the buffered range, extrapolate up to a limit outside it
+    unsafe fn extrapolate_at_timestamp(
+        &self,
+        history: &[TransformEntry],
+        seq: u64,
+        target_ts: u64,
+        max_extrapolation_ns: u64,
+    ) -> Option<Transform> {
+        let available = seq.min(self.history_capacity as u64) as usize;
+        if available == 0 {
+            return None;
+        }
+
+        let entry =
+            |offset: usize| &history[((seq - 1 - offset as u64) as usize) % self.history_capacity];
+        let newest = entry(0);
+        let oldest = entry(available - 1);
+
+        if target_ts > newest.timestamp_ns {
+            if target_ts - newest.timestamp_ns > max_extrapolation_ns {
+                return None;
+            }
+            if available < 2 || entry(1).timestamp_ns >= newest.timestamp_ns {
+                return Some(newest.transform);
+            }
+            let prev = entry(1);
+            let t = (target_ts - prev.timestamp_ns) as f64
+                / (newest.timestamp_ns - prev.timestamp_ns) as f64;
+            return Some(prev.transform.extrapolate(&newest.transform, t));
+        }
+
+        if target_ts < oldest.timestamp_ns {
+            if oldest.timestamp_ns - target_ts > max_extrapolation_ns {
+                return None;
+            }
+            if available < 2 || entry(available - 2).timestamp_ns <= oldest.timestamp_ns {
+                return Some(oldest.transform);
+            }
+            let next = entry(available - 2);
+            let t = -((oldest.timestamp_ns - target_ts) as f64)
+                / (next.timestamp_ns - oldest.timestamp_ns) as f64;
+            return Some(oldest.transform.extrapolate(&next.transform, t));
+        }
+
+        self.interpolate_at_timestamp(history, seq, target_ts)
+    }
+
     /// Get time range of buffered transforms
     pub fn time_range(&self) -> Option<(u64, u64)> {
         if self.is_static() {
@@ -522,6 +612,33 @@ mod tests {
         assert!((tf_25.translation[0] - 2.5).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_bounded_extrapolation() {
+        let slot = FrameSlot::new(16);
+        slot.init_dynamic(NO_PARENT);
+        assert!(slot.read_interpolated_within(100, 50).is_none());
+
+        slot.update(&Transform::from_translation([0.0, 0.0, 0.0]), 100);
+        slot.update(&Transform::from_translation([10.0, 0.0, 0.0]), 200);
+
+        // Inside the buffer: plain interpolation
+        let tf = slot.read_interpolated_within(150, 0).unwrap();
+        assert!((tf.translation[0] - 5.0).abs() < 1e-10);
+
+        // Past either end without extrapolation allowed
+        assert!(slot.read_interpolated_within(210, 0).is_none());
+        assert!(slot.read_interpolated_within(90, 0).is_none());
+
+        // Within the limit the motion continues
+        let tf = slot.read_interpolated_within(250, 50).unwrap();
+        assert!((tf.translation[0] - 15.0).abs() < 1e-10);
+        let tf = slot.read_interpolated_within(80, 50).unwrap();
+        assert!((tf.translation[0] + 2.0).abs() < 1e-10);
+
+        // Beyond the limit
+        assert!(slot.read_interpolated_within(251, 50).is_none());
+    }
+
     #[test]
     fn test_concurrent_access() {
         use std::sync::{Arc, Barrier};
@@ -226,6 +226,25 @@ impl Transform {
         }
     }
 
+    /// Linear extrapolation along the motion from `self` to `other`
+    ///
+    /// Same as [`interpolate`](Self::interpolate) without clamping `t`:
+    /// `t > 1` continues past `other`, `t < 0` runs back before `self`.
+    pub fn extrapolate(&self, other: &Transform, t: f64) -> Transform {
+        let translation = [
+            self.translation[0] + t * (other.translation[0] - self.translation[0]),
+            self.translation[1] + t * (other.translation[1] - self.translation[1]),
+            self.translation[2] + t * (other.translation[2] - self.translation[2]),
+        ];
+
+        let rotation = quaternion_slerp(self.rotation, other.rotation, t);
+
+        Transform {
+            translation,
+            rotation,
+        }
+    }
+
     /// Check if transform is approximately identity
     pub fn is_identity(&self, epsilon: f64) -> bool {
         let t_zero = self.translation[0].abs() < epsilon
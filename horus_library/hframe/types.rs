@@ -38,6 +38,16 @@ pub enum HFrameError {
     #[error("Transform not available at timestamp {0}")]
     TransformNotAvailable(u64),
 
+    #[error(
+        "Transform of frame '{frame}' not available at {timestamp} (buffered {oldest}..{newest})"
+    )]
+    ExtrapolationLimit {
+        frame: String,
+        timestamp: u64,
+        oldest: u64,
+        newest: u64,
+    },
+
     #[error("Cycle detected in frame tree")]
     CycleDetected,
 
@@ -49,6 +59,9 @@ pub enum HFrameError {
 
     #[error("Configuration error: {0}")]
     ConfigError(String),
+
+    #[error("Invalid robot description: {0}")]
+    InvalidDescription(String),
 }
 
 /// Result type for HFrame operations
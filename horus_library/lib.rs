@@ -81,6 +81,7 @@ pub use drivers::{CameraDriver, SimulationCameraDriver};
 // LiDAR
 #[cfg(feature = "rplidar")]
 pub use drivers::RplidarDriver;
+pub use drivers::{Lidar3dDriver, OusterDriver, SimulationLidar3dDriver, VelodyneDriver};
 pub use drivers::{LidarDriver, SimulationLidarDriver};
 
 // GPS
@@ -96,6 +96,56 @@ impl MotorCommand {
     }
 }
 
+/// Charge request sent to a charging manager (e.g. by a docking node)
+///
+/// Send `start` once the robot is docked and the charge contacts are made,
+/// and `stop` before undocking.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+pub struct ChargeCommand {
+    /// Command (see `COMMAND_*`)
+    pub command: u8,
+    /// Maximum charge current in amperes (0 = charging manager default)
+    pub max_current: f32,
+    /// Target state of charge (0-100, 0 = charging manager default)
+    pub target_percentage: f32,
+    /// Timestamp in nanoseconds since epoch
+    pub timestamp: u64,
+}
+
+impl ChargeCommand {
+    pub const COMMAND_STOP: u8 = 0;
+    pub const COMMAND_START: u8 = 1;
+
+    /// Request charging with the charging manager's default limits
+    pub fn start() -> Self {
+        Self {
+            command: Self::COMMAND_START,
+            max_current: 0.0,
+            target_percentage: 0.0,
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos() as u64,
+        }
+    }
+
+    /// Request charging up to a target state of charge
+    pub fn start_to(target_percentage: f32) -> Self {
+        Self {
+            target_percentage,
+            ..Self::start()
+        }
+    }
+
+    /// Stop charging
+    pub fn stop() -> Self {
+        Self {
+            command: Self::COMMAND_STOP,
+            ..Self::start()
+        }
+    }
+}
+
 /// Differential drive motor commands
 ///
 /// Commands for a two-wheeled differential drive robot.
@@ -435,6 +485,182 @@ impl JointCommand {
     }
 }
 
+/// Waypoint of a joint trajectory
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JointTrajectoryPoint {
+    /// Joint positions (rad or m), one per joint name
+    pub positions: Vec<f64>,
+    /// Joint velocities; empty to let the executor choose
+    pub velocities: Vec<f64>,
+    /// Joint accelerations; empty for zero (quintic interpolation only)
+    pub accelerations: Vec<f64>,
+    /// Time from trajectory start in seconds
+    pub time_from_start: f64,
+}
+
+impl JointTrajectoryPoint {
+    /// Create a point with positions only
+    pub fn new(positions: Vec<f64>, time_from_start: f64) -> Self {
+        Self {
+            positions,
+            velocities: Vec::new(),
+            accelerations: Vec::new(),
+            time_from_start,
+        }
+    }
+
+    /// Create a point with positions and velocities
+    pub fn with_velocities(
+        positions: Vec<f64>,
+        velocities: Vec<f64>,
+        time_from_start: f64,
+    ) -> Self {
+        Self {
+            positions,
+            velocities,
+            accelerations: Vec::new(),
+            time_from_start,
+        }
+    }
+}
+
+/// Timed sequence of joint-space waypoints for arm control
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JointTrajectory {
+    /// Names of the joints, in the order used by every point
+    pub joint_names: Vec<String>,
+    /// Waypoints with strictly increasing `time_from_start`
+    pub points: Vec<JointTrajectoryPoint>,
+    /// Timestamp in nanoseconds since epoch
+    pub timestamp: u64,
+}
+
+impl JointTrajectory {
+    /// Maximum number of joints (limited by `JointCommand`)
+    pub const MAX_JOINTS: usize = 16;
+
+    /// Create a trajectory for the given joints
+    pub fn new(joint_names: &[&str]) -> Self {
+        Self {
+            joint_names: joint_names.iter().map(|n| n.to_string()).collect(),
+            points: Vec::new(),
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos() as u64,
+        }
+    }
+
+    /// Append a position-only waypoint
+    pub fn add_point(&mut self, positions: Vec<f64>, time_from_start: f64) {
+        self.points
+            .push(JointTrajectoryPoint::new(positions, time_from_start));
+    }
+
+    /// Total duration in seconds
+    pub fn duration(&self) -> f64 {
+        self.points.last().map_or(0.0, |p| p.time_from_start)
+    }
+
+    /// Check the trajectory is well formed
+    pub fn validate(&self) -> Result<(), String> {
+        let joints = self.joint_names.len();
+        if joints == 0 || joints > Self::MAX_JOINTS {
+            return Err(format!(
+                "trajectory must have 1 to {} joints, got {}",
+                Self::MAX_JOINTS,
+                joints
+            ));
+        }
+        for (i, name) in self.joint_names.iter().enumerate() {
+            if self.joint_names[..i].contains(name) {
+                return Err(format!("joint '{}' listed twice", name));
+            }
+        }
+        if self.points.is_empty() {
+            return Err("trajectory has no points".to_string());
+        }
+
+        let mut last_time = f64::NEG_INFINITY;
+        for (i, point) in self.points.iter().enumerate() {
+            let sizes_ok = point.positions.len() == joints
+                && (point.velocities.is_empty() || point.velocities.len() == joints)
+                && (point.accelerations.is_empty() || point.accelerations.len() == joints);
+            if !sizes_ok {
+                return Err(format!("point {} does not match {} joints", i, joints));
+            }
+            let finite = point
+                .positions
+                .iter()
+                .chain(&point.velocities)
+                .chain(&point.accelerations)
+                .all(|v| v.is_finite());
+            if !finite {
+                return Err(format!("point {} has non-finite values", i));
+            }
+            if !(point.time_from_start >= 0.0 && point.time_from_start > last_time) {
+                return Err(format!("point {} is not after the previous point", i));
+            }
+            last_time = point.time_from_start;
+        }
+        Ok(())
+    }
+}
+
+/// Execution state of a joint trajectory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(u8)]
+#[derive(Default)]
+pub enum TrajectoryState {
+    /// No trajectory received yet
+    #[default]
+    Idle = 0,
+    /// Trajectory is being executed
+    Executing = 1,
+    /// Final point reached
+    Succeeded = 2,
+    /// Stopped before the end (cancelled or replaced)
+    Aborted = 3,
+    /// Trajectory was invalid and not executed
+    Rejected = 4,
+}
+
+/// Progress feedback from a joint trajectory executor
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct JointTrajectoryFeedback {
+    /// Execution state
+    pub state: TrajectoryState,
+    /// Time since the trajectory started (s)
+    pub elapsed: f64,
+    /// Total trajectory duration (s)
+    pub duration: f64,
+    /// Index of the next waypoint to be reached
+    pub point_index: u32,
+    /// Number of waypoints
+    pub point_count: u32,
+    /// Timestamp in nanoseconds since epoch
+    pub timestamp: u64,
+}
+
+impl JointTrajectoryFeedback {
+    /// Fraction of the trajectory completed (0.0 to 1.0)
+    pub fn progress(&self) -> f64 {
+        if self.duration > 0.0 {
+            (self.elapsed / self.duration).clamp(0.0, 1.0)
+        } else if self.state == TrajectoryState::Succeeded {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+impl LogSummary for ChargeCommand {
+    fn log_summary(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
 impl LogSummary for MotorCommand {
     fn log_summary(&self) -> String {
         format!("{:?}", self)
@@ -595,6 +821,23 @@ impl LogSummary for JointCommand {
     }
 }
 
+impl LogSummary for JointTrajectory {
+    fn log_summary(&self) -> String {
+        format!(
+            "JointTrajectory({} joints, {} points, {:.2}s)",
+            self.joint_names.len(),
+            self.points.len(),
+            self.duration()
+        )
+    }
+}
+
+impl LogSummary for JointTrajectoryFeedback {
+    fn log_summary(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
 /// Stepper Motor Command for precise position control
 ///
 /// Controls stepper motors with step/direction interface.
@@ -0,0 +1,488 @@
+// Occupancy grid map files
+//
+// Two formats are supported:
+//
+// - PGM + YAML, as written by ROS map_server / map_saver and most SLAM
+//   packages. The YAML file names the image and gives the resolution,
+//   origin and thresholds:
+//
+//   ```yaml
+//   image: office.pgm
+//   resolution: 0.05
+//   origin: [-10.0, -10.0, 0.0]
+//   negate: 0
+//   occupied_thresh: 0.65
+//   free_thresh: 0.196
+//   mode: trinary
+//   ```
+//
+// - A compact binary format (`.hmap`): a fixed header followed by the
+//   run-length encoded cells, which keeps mostly-unknown maps small.
+//
+// `OccupancyGrid::load` and `OccupancyGrid::save` pick the format from the
+// file extension.
+
+use crate::messages::geometry::Pose2D;
+use crate::messages::navigation::OccupancyGrid;
+use horus_core::{HorusError, HorusResult};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Magic bytes of the binary map format
+const BINARY_MAGIC: &[u8; 4] = b"HMAP";
+
+/// Binary map format version
+const BINARY_VERSION: u8 = 1;
+
+/// Header size of the binary format in bytes
+const BINARY_HEADER_LEN: usize = 4 + 1 + 4 + 4 + 4 + 3 * 8 + 32 + 8;
+
+/// PGM values written by `save_map`, matching map_saver
+const PGM_FREE: u8 = 254;
+const PGM_OCCUPIED: u8 = 0;
+const PGM_UNKNOWN: u8 = 205;
+
+/// How PGM pixel values are turned into occupancy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum MapMode {
+    /// Free, occupied or unknown by threshold
+    #[default]
+    Trinary,
+    /// Values between the thresholds scale linearly to 1..99
+    Scale,
+    /// Pixel values are occupancy values (0..100, anything else unknown)
+    Raw,
+}
+
+/// Map YAML file contents
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MapMetadata {
+    /// Image file, relative to the YAML file
+    pub image: String,
+    /// Meters per cell
+    pub resolution: f64,
+    /// Pose of the lower-left cell [x, y, yaw]
+    pub origin: [f64; 3],
+    /// Whether white means occupied (0 or 1)
+    #[serde(default)]
+    pub negate: u8,
+    /// Occupancy probability above which a cell is occupied
+    #[serde(default = "default_occupied_thresh")]
+    pub occupied_thresh: f64,
+    /// Occupancy probability below which a cell is free
+    #[serde(default = "default_free_thresh")]
+    pub free_thresh: f64,
+    /// Pixel interpretation
+    #[serde(default)]
+    pub mode: MapMode,
+}
+
+fn default_occupied_thresh() -> f64 {
+    0.65
+}
+
+fn default_free_thresh() -> f64 {
+    0.196
+}
+
+impl MapMetadata {
+    /// Occupancy value of a PGM pixel
+    fn occupancy(&self, value: u8, max_value: u16) -> i8 {
+        if self.mode == MapMode::Raw {
+            return if value <= 100 { value as i8 } else { -1 };
+        }
+        let max = max_value as f64;
+        let p = if self.negate != 0 {
+            value as f64 / max
+        } else {
+            (max - value as f64) / max
+        };
+        if p > self.occupied_thresh {
+            100
+        } else if p < self.free_thresh {
+            0
+        } else if self.mode == MapMode::Scale {
+            let scaled = (p - self.free_thresh) / (self.occupied_thresh - self.free_thresh);
+            (1.0 + 98.0 * scaled).round() as i8
+        } else {
+            -1
+        }
+    }
+}
+
+/// Load a map from a map_server YAML file and the PGM image it names
+pub fn load_map(yaml_path: impl AsRef<Path>) -> HorusResult<OccupancyGrid> {
+    let yaml_path = yaml_path.as_ref();
+    let text = std::fs::read_to_string(yaml_path)?;
+    let metadata: MapMetadata = serde_yaml::from_str(&text).map_err(|e| {
+        HorusError::ParseError(format!("Invalid map file {}: {}", yaml_path.display(), e))
+    })?;
+    if metadata.resolution <= 0.0 {
+        return Err(HorusError::InvalidInput(format!(
+            "Map resolution must be positive, got {}",
+            metadata.resolution
+        )));
+    }
+
+    let image_path = resolve_image(yaml_path, &metadata.image);
+    let bytes = std::fs::read(&image_path)?;
+    let pgm = parse_pgm(&bytes).map_err(|e| {
+        HorusError::ParseError(format!("Invalid PGM {}: {}", image_path.display(), e))
+    })?;
+
+    let origin = Pose2D::new(metadata.origin[0], metadata.origin[1], metadata.origin[2]);
+    let mut grid = OccupancyGrid::new(pgm.width, pgm.height, metadata.resolution as f32, origin);
+    // Image row 0 is the top of the map, grid row 0 the bottom
+    for (row, pixels) in pgm.pixels.chunks_exact(pgm.width as usize).enumerate() {
+        let y = pgm.height as usize - 1 - row;
+        let cells = &mut grid.data[y * pgm.width as usize..(y + 1) * pgm.width as usize];
+        for (cell, &value) in cells.iter_mut().zip(pixels) {
+            *cell = metadata.occupancy(value, pgm.max_value);
+        }
+    }
+    Ok(grid)
+}
+
+/// Save a map as a YAML file plus a PGM image with the same stem
+///
+/// Cells are written trinary: free (0..=25), occupied (>= 65) or unknown.
+pub fn save_map(grid: &OccupancyGrid, yaml_path: impl AsRef<Path>) -> HorusResult<()> {
+    let yaml_path = yaml_path.as_ref();
+    check_grid(grid)?;
+    let image_path = yaml_path.with_extension("pgm");
+    let image_name = image_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .ok_or_else(|| {
+            HorusError::InvalidInput(format!("Invalid map path {}", yaml_path.display()))
+        })?;
+
+    let metadata = MapMetadata {
+        image: image_name,
+        resolution: grid.resolution as f64,
+        origin: [grid.origin.x, grid.origin.y, grid.origin.theta],
+        negate: 0,
+        occupied_thresh: default_occupied_thresh(),
+        free_thresh: default_free_thresh(),
+        mode: MapMode::Trinary,
+    };
+
+    let (width, height) = (grid.width as usize, grid.height as usize);
+    let mut pgm = format!(
+        "P5\n# horus map, {:.3} m/cell\n{} {}\n255\n",
+        grid.resolution, width, height
+    )
+    .into_bytes();
+    pgm.reserve(width * height);
+    for y in (0..height).rev() {
+        for &cell in &grid.data[y * width..(y + 1) * width] {
+            pgm.push(match cell {
+                0..=25 => PGM_FREE,
+                65..=100 => PGM_OCCUPIED,
+                _ => PGM_UNKNOWN,
+            });
+        }
+    }
+
+    let yaml = serde_yaml::to_string(&metadata)
+        .map_err(|e| HorusError::Serialization(format!("Failed to write map YAML: {}", e)))?;
+    std::fs::write(&image_path, pgm)?;
+    std::fs::write(yaml_path, yaml)?;
+    Ok(())
+}
+
+/// Encode a map in the compact binary format
+///
+/// Layout (little-endian): magic "HMAP", version, width, height, resolution
+/// (f32), origin x/y/theta (f64), frame_id (32 bytes), timestamp, then runs
+/// of (LEB128 length, cell value).
+pub fn encode_binary(grid: &OccupancyGrid) -> HorusResult<Vec<u8>> {
+    check_grid(grid)?;
+    let mut out = Vec::with_capacity(BINARY_HEADER_LEN + 64);
+    out.extend_from_slice(BINARY_MAGIC);
+    out.push(BINARY_VERSION);
+    out.extend_from_slice(&grid.width.to_le_bytes());
+    out.extend_from_slice(&grid.height.to_le_bytes());
+    out.extend_from_slice(&grid.resolution.to_le_bytes());
+    out.extend_from_slice(&grid.origin.x.to_le_bytes());
+    out.extend_from_slice(&grid.origin.y.to_le_bytes());
+    out.extend_from_slice(&grid.origin.theta.to_le_bytes());
+    out.extend_from_slice(&grid.frame_id);
+    out.extend_from_slice(&grid.timestamp.to_le_bytes());
+
+    let cells = &grid.data[..(grid.width * grid.height) as usize];
+    let mut i = 0;
+    while i < cells.len() {
+        let value = cells[i];
+        let run = cells[i..].iter().take_while(|&&c| c == value).count();
+        let mut len = run as u64;
+        loop {
+            let byte = (len & 0x7f) as u8;
+            len >>= 7;
+            if len == 0 {
+                out.push(byte);
+                break;
+            }
+            out.push(byte | 0x80);
+        }
+        out.push(value as u8);
+        i += run;
+    }
+    Ok(out)
+}
+
+/// Decode a map from the compact binary format
+pub fn decode_binary(bytes: &[u8]) -> HorusResult<OccupancyGrid> {
+    let invalid = |msg: &str| HorusError::ParseError(format!("Invalid binary map: {}", msg));
+    if bytes.len() < BINARY_HEADER_LEN || &bytes[..4] != BINARY_MAGIC {
+        return Err(invalid("missing HMAP header"));
+    }
+    if bytes[4] != BINARY_VERSION {
+        return Err(invalid(&format!("unsupported version {}", bytes[4])));
+    }
+
+    let mut pos = 5;
+    let mut take = |n: usize| {
+        let field = &bytes[pos..pos + n];
+        pos += n;
+        field
+    };
+    let width = u32::from_le_bytes(take(4).try_into().unwrap());
+    let height = u32::from_le_bytes(take(4).try_into().unwrap());
+    let resolution = f32::from_le_bytes(take(4).try_into().unwrap());
+    let x = f64::from_le_bytes(take(8).try_into().unwrap());
+    let y = f64::from_le_bytes(take(8).try_into().unwrap());
+    let theta = f64::from_le_bytes(take(8).try_into().unwrap());
+    let frame_id: [u8; 32] = take(32).try_into().unwrap();
+    let timestamp = u64::from_le_bytes(take(8).try_into().unwrap());
+
+    let cell_count = width as usize * height as usize;
+    let mut data = Vec::with_capacity(cell_count);
+    let mut rest = &bytes[BINARY_HEADER_LEN..];
+    while !rest.is_empty() {
+        let mut len: u64 = 0;
+        let mut shift = 0;
+        loop {
+            let (&byte, tail) = rest.split_first().ok_or_else(|| invalid("truncated run"))?;
+            rest = tail;
+            if shift > 56 {
+                return Err(invalid("run length overflow"));
+            }
+            len |= ((byte & 0x7f) as u64) << shift;
+            shift += 7;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        let (&value, tail) = rest.split_first().ok_or_else(|| invalid("truncated run"))?;
+        rest = tail;
+        if data.len() as u64 + len > cell_count as u64 {
+            return Err(invalid("more cells than width x height"));
+        }
+        data.resize(data.len() + len as usize, value as i8);
+    }
+    if data.len() != cell_count {
+        return Err(invalid("fewer cells than width x height"));
+    }
+
+    Ok(OccupancyGrid {
+        resolution,
+        width,
+        height,
+        origin: Pose2D::new(x, y, theta),
+        data,
+        frame_id,
+        timestamp,
+        ..Default::default()
+    })
+}
+
+impl OccupancyGrid {
+    /// Load a map, YAML + PGM for `.yaml`/`.yml` paths, binary otherwise
+    pub fn load(path: impl AsRef<Path>) -> HorusResult<Self> {
+        let path = path.as_ref();
+        if is_yaml(path) {
+            load_map(path)
+        } else {
+            decode_binary(&std::fs::read(path)?)
+        }
+    }
+
+    /// Save a map, YAML + PGM for `.yaml`/`.yml` paths, binary otherwise
+    pub fn save(&self, path: impl AsRef<Path>) -> HorusResult<()> {
+        let path = path.as_ref();
+        if is_yaml(path) {
+            save_map(self, path)
+        } else {
+            std::fs::write(path, encode_binary(self)?)?;
+            Ok(())
+        }
+    }
+}
+
+fn is_yaml(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|ext| ext.to_str()),
+        Some("yaml") | Some("yml")
+    )
+}
+
+fn resolve_image(yaml_path: &Path, image: &str) -> PathBuf {
+    let image = Path::new(image);
+    if image.is_absolute() {
+        return image.to_path_buf();
+    }
+    yaml_path
+        .parent()
+        .map(|dir| dir.join(image))
+        .unwrap_or_else(|| image.to_path_buf())
+}
+
+fn check_grid(grid: &OccupancyGrid) -> HorusResult<()> {
+    if grid.width == 0 || grid.height == 0 || grid.data.len() < (grid.width * grid.height) as usize
+    {
+        return Err(HorusError::InvalidInput(format!(
+            "Occupancy grid {}x{} has {} cells",
+            grid.width,
+            grid.height,
+            grid.data.len()
+        )));
+    }
+    Ok(())
+}
+
+/// Decoded 8-bit grayscale PGM
+struct Pgm {
+    width: u32,
+    height: u32,
+    max_value: u16,
+    pixels: Vec<u8>,
+}
+
+/// Parse a binary (P5) or ASCII (P2) PGM with 8-bit samples
+fn parse_pgm(bytes: &[u8]) -> Result<Pgm, String> {
+    let mut pos = 0;
+    let mut token = || -> Result<String, String> {
+        // Skip whitespace and comments
+        loop {
+            match bytes.get(pos) {
+                Some(b) if b.is_ascii_whitespace() => pos += 1,
+                Some(b'#') => {
+                    while bytes.get(pos).is_some_and(|&b| b != b'\n') {
+                        pos += 1;
+                    }
+                }
+                Some(_) => break,
+                None => return Err("unexpected end of header".to_string()),
+            }
+        }
+        let start = pos;
+        while bytes.get(pos).is_some_and(|b| !b.is_ascii_whitespace()) {
+            pos += 1;
+        }
+        Ok(String::from_utf8_lossy(&bytes[start..pos]).into_owned())
+    };
+
+    let magic = token()?;
+    let number = |s: String| s.parse::<u32>().map_err(|_| format!("bad number '{}'", s));
+    let width = number(token()?)?;
+    let height = number(token()?)?;
+    let max_value = number(token()?)?;
+    if width == 0 || height == 0 || max_value == 0 || max_value > 255 {
+        return Err(format!(
+            "unsupported {}x{} image with max value {}",
+            width, height, max_value
+        ));
+    }
+    let count = width as usize * height as usize;
+
+    let pixels = match magic.as_str() {
+        "P5" => {
+            // A single whitespace byte separates the header from the samples
+            let start = pos + 1;
+            bytes
+                .get(start..start + count)
+                .ok_or("image data is truncated")?
+                .to_vec()
+        }
+        "P2" => {
+            let mut pixels = Vec::with_capacity(count);
+            for _ in 0..count {
+                let value = number(token()?)?;
+                pixels.push(value.min(255) as u8);
+            }
+            pixels
+        }
+        other => return Err(format!("unsupported format '{}'", other)),
+    };
+
+    Ok(Pgm {
+        width,
+        height,
+        max_value: max_value as u16,
+        pixels,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_grid() -> OccupancyGrid {
+        let mut grid = OccupancyGrid::new(6, 4, 0.05, Pose2D::new(-1.0, -2.0, 0.0));
+        for x in 0..6 {
+            grid.set_occupancy(x, 0, 0);
+        }
+        grid.set_occupancy(2, 3, 100);
+        grid
+    }
+
+    #[test]
+    fn test_binary_roundtrip() {
+        let grid = sample_grid();
+        let bytes = encode_binary(&grid).unwrap();
+        // Header plus a handful of runs
+        assert!(bytes.len() < BINARY_HEADER_LEN + 16);
+
+        let decoded = decode_binary(&bytes).unwrap();
+        assert_eq!((decoded.width, decoded.height), (6, 4));
+        assert_eq!(decoded.data, grid.data);
+        assert_eq!(decoded.origin.x, -1.0);
+        assert!(decode_binary(&bytes[..bytes.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_pgm_yaml_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("horus_map_io_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let yaml = dir.join("office.yaml");
+
+        let grid = sample_grid();
+        grid.save(&yaml).unwrap();
+        assert!(dir.join("office.pgm").exists());
+
+        let loaded = OccupancyGrid::load(&yaml).unwrap();
+        assert_eq!((loaded.width, loaded.height), (6, 4));
+        assert_eq!(loaded.resolution, 0.05);
+        assert_eq!(loaded.origin.y, -2.0);
+        assert_eq!(loaded.data, grid.data);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_load_ros_map() {
+        let metadata: MapMetadata =
+            serde_yaml::from_str("image: map.pgm\nresolution: 0.1\norigin: [0.0, 0.0, 0.0]\n")
+                .unwrap();
+        assert_eq!(metadata.mode, MapMode::Trinary);
+        assert_eq!(metadata.occupancy(254, 255), 0);
+        assert_eq!(metadata.occupancy(0, 255), 100);
+        assert_eq!(metadata.occupancy(205, 255), -1);
+
+        // ASCII PGM, top row first
+        let pgm = parse_pgm(b"P2\n# comment\n2 2\n255\n0 254\n205 254\n").unwrap();
+        assert_eq!(pgm.pixels, vec![0, 254, 205, 254]);
+    }
+}
@@ -22,6 +22,7 @@ pub mod diagnostics;
 pub mod force;
 pub mod geometry;
 pub mod io;
+pub mod map_io;
 pub mod ml;
 pub mod navigation;
 pub mod perception;
@@ -42,12 +43,15 @@ pub mod snake_state;
 pub use geometry::{Point3, Pose2D, Quaternion, Transform, Twist, Vector3};
 
 // Sensor
-pub use sensor::{BatteryState, Imu, LaserScan, NavSatFix, Odometry, Range};
+pub use sensor::{
+    BatteryState, CellDiagnostics, ChargerStatus, Imu, LaserScan, NavSatFix, Odometry, Range,
+};
 
 // Control
 pub use control::{
-    DifferentialDriveCommand, JointCommand, MotorCommand, PidConfig, PwmCommand, ServoCommand,
-    StepperCommand, TrajectoryPoint,
+    ChargeCommand, DifferentialDriveCommand, JointCommand, JointTrajectory,
+    JointTrajectoryFeedback, JointTrajectoryPoint, MotorCommand, PidConfig, PwmCommand,
+    ServoCommand, StepperCommand, TrajectoryPoint, TrajectoryState,
 };
 
 // Diagnostics
@@ -58,11 +62,13 @@ pub use diagnostics::{
 
 // Vision
 pub use vision::{
-    CameraInfo, CompressedImage, Detection, DetectionArray, Image, ImageEncoding, RegionOfInterest,
+    CameraInfo, CompressedImage, Detection, DetectionArray, DistortionModel, FiducialArray,
+    FiducialDetection, FiducialFamily, Image, ImageEncoding, RegionOfInterest,
 };
 
 // Navigation
-pub use navigation::{CostMap, Goal, OccupancyGrid, Path, PathPlan};
+pub use map_io::{MapMetadata, MapMode};
+pub use navigation::{CostMap, Goal, OccupancyGrid, OccupancyGridUpdate, Path, PathPlan};
 
 // Force
 pub use force::{ForceCommand, ImpedanceParameters, TactileArray, WrenchStamped};
@@ -74,7 +80,10 @@ pub use io::{
 };
 
 // Perception
-pub use perception::{BoundingBox3D, DepthImage, PlaneDetection, PointCloud};
+pub use perception::{
+    BoundingBox3D, DepthImage, FieldAccessor, PlaneDetection, PointCloud, PointField,
+    PointFieldType, PointScalar,
+};
 
 // Coordination
 pub use coordination::{FleetStatus, FormationControl, RobotState, TaskAssignment};
@@ -435,6 +435,102 @@ impl OccupancyGrid {
         }
         false
     }
+
+    /// Copy a rectangular region into an update message
+    pub fn region(&self, x: u32, y: u32, width: u32, height: u32) -> Option<OccupancyGridUpdate> {
+        if width == 0 || height == 0 || x + width > self.width || y + height > self.height {
+            return None;
+        }
+        let mut data = Vec::with_capacity((width * height) as usize);
+        for row in y..y + height {
+            let start = (row * self.width + x) as usize;
+            data.extend_from_slice(self.data.get(start..start + width as usize)?);
+        }
+        Some(OccupancyGridUpdate {
+            x,
+            y,
+            width,
+            height,
+            data,
+            frame_id: self.frame_id,
+            timestamp: self.timestamp,
+        })
+    }
+
+    /// Smallest update turning `previous` into this grid
+    ///
+    /// None when nothing changed or the grids have different sizes (send
+    /// the full grid instead).
+    pub fn diff(&self, previous: &OccupancyGrid) -> Option<OccupancyGridUpdate> {
+        if self.width != previous.width
+            || self.height != previous.height
+            || self.data.len() != previous.data.len()
+        {
+            return None;
+        }
+        let (mut min_x, mut min_y, mut max_x, mut max_y) = (u32::MAX, u32::MAX, 0, 0);
+        for (i, (new, old)) in self.data.iter().zip(&previous.data).enumerate() {
+            if new != old {
+                let (x, y) = (i as u32 % self.width, i as u32 / self.width);
+                min_x = min_x.min(x);
+                min_y = min_y.min(y);
+                max_x = max_x.max(x);
+                max_y = max_y.max(y);
+            }
+        }
+        if min_x == u32::MAX {
+            return None;
+        }
+        self.region(min_x, min_y, max_x - min_x + 1, max_y - min_y + 1)
+    }
+
+    /// Write an update into the grid, returns false if it doesn't fit
+    pub fn apply_update(&mut self, update: &OccupancyGridUpdate) -> bool {
+        if !update.is_valid()
+            || update.x + update.width > self.width
+            || update.y + update.height > self.height
+            || self.data.len() < (self.width * self.height) as usize
+        {
+            return false;
+        }
+        for (row, values) in update.data.chunks_exact(update.width as usize).enumerate() {
+            let start = ((update.y + row as u32) * self.width + update.x) as usize;
+            self.data[start..start + values.len()].copy_from_slice(values);
+        }
+        self.timestamp = update.timestamp;
+        true
+    }
+}
+
+/// Incremental change to an occupancy grid
+///
+/// Mapping nodes publish the full `OccupancyGrid` occasionally (and to late
+/// subscribers) and stream the changed rectangle of each update, produced by
+/// `OccupancyGrid::diff` or `OccupancyGrid::region`. Consumers apply it with
+/// `OccupancyGrid::apply_update`.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct OccupancyGridUpdate {
+    /// Left column of the region in grid cells
+    pub x: u32,
+    /// Bottom row of the region in grid cells
+    pub y: u32,
+    /// Region width in cells
+    pub width: u32,
+    /// Region height in cells
+    pub height: u32,
+    /// Row-major cell values (-1=unknown, 0=free, 100=occupied)
+    pub data: Vec<i8>,
+    /// Frame ID for map coordinates
+    pub frame_id: [u8; 32],
+    /// Timestamp of the map state this update produces
+    pub timestamp: u64,
+}
+
+impl OccupancyGridUpdate {
+    /// Check the data covers the region
+    pub fn is_valid(&self) -> bool {
+        self.width > 0 && self.height > 0 && self.data.len() == (self.width * self.height) as usize
+    }
 }
 
 /// Cost map for navigation planning
@@ -638,6 +734,15 @@ impl LogSummary for OccupancyGrid {
     }
 }
 
+impl LogSummary for OccupancyGridUpdate {
+    fn log_summary(&self) -> String {
+        format!(
+            "OccupancyGridUpdate({}x{} at ({}, {}))",
+            self.width, self.height, self.x, self.y
+        )
+    }
+}
+
 impl LogSummary for CostMap {
     fn log_summary(&self) -> String {
         format!("{:?}", self)
@@ -722,6 +827,28 @@ mod tests {
         let (x, y) = grid.grid_to_world(10, 10).unwrap();
         assert!(grid.is_occupied(x, y));
     }
+
+    #[test]
+    fn test_occupancy_grid_updates() {
+        let previous = OccupancyGrid::new(20, 10, 0.1, Pose2D::origin());
+        let mut current = previous.clone();
+        current.set_occupancy(3, 2, 100);
+        current.set_occupancy(5, 4, 0);
+
+        let update = current.diff(&previous).unwrap();
+        assert_eq!(
+            (update.x, update.y, update.width, update.height),
+            (3, 2, 3, 3)
+        );
+        assert!(previous.diff(&previous).is_none());
+
+        let mut replica = previous.clone();
+        assert!(replica.apply_update(&update));
+        assert_eq!(replica.data, current.data);
+
+        let mut small = OccupancyGrid::new(4, 4, 0.1, Pose2D::origin());
+        assert!(!small.apply_update(&update));
+    }
 }
 
 /// Simplified path plan message for basic navigation
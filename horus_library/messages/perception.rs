@@ -32,6 +32,75 @@ pub enum PointFieldType {
     Float64 = 8,
 }
 
+impl PointFieldType {
+    /// Size in bytes of one element
+    pub fn size(&self) -> u32 {
+        match self {
+            PointFieldType::Int8 | PointFieldType::UInt8 => 1,
+            PointFieldType::Int16 | PointFieldType::UInt16 => 2,
+            PointFieldType::Int32 | PointFieldType::UInt32 | PointFieldType::Float32 => 4,
+            PointFieldType::Float64 => 8,
+        }
+    }
+
+    /// Read one little-endian element as f64
+    fn read_f64(&self, bytes: &[u8]) -> f64 {
+        match self {
+            PointFieldType::Int8 => i8::read_le(bytes) as f64,
+            PointFieldType::UInt8 => u8::read_le(bytes) as f64,
+            PointFieldType::Int16 => i16::read_le(bytes) as f64,
+            PointFieldType::UInt16 => u16::read_le(bytes) as f64,
+            PointFieldType::Int32 => i32::read_le(bytes) as f64,
+            PointFieldType::UInt32 => u32::read_le(bytes) as f64,
+            PointFieldType::Float32 => f32::read_le(bytes) as f64,
+            PointFieldType::Float64 => f64::read_le(bytes),
+        }
+    }
+}
+
+/// Scalar type that can be stored in a point field
+pub trait PointScalar: Copy + 'static {
+    /// Field type matching this scalar
+    const TYPE: PointFieldType;
+
+    /// Read from little-endian bytes
+    fn read_le(bytes: &[u8]) -> Self;
+
+    /// Write as little-endian bytes
+    fn write_le(self, bytes: &mut [u8]);
+}
+
+macro_rules! impl_point_scalar {
+    ($($ty:ty => $field:ident),* $(,)?) => {
+        $(
+            impl PointScalar for $ty {
+                const TYPE: PointFieldType = PointFieldType::$field;
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    let mut raw = [0u8; std::mem::size_of::<$ty>()];
+                    raw.copy_from_slice(&bytes[..std::mem::size_of::<$ty>()]);
+                    <$ty>::from_le_bytes(raw)
+                }
+
+                fn write_le(self, bytes: &mut [u8]) {
+                    bytes[..std::mem::size_of::<$ty>()].copy_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_point_scalar!(
+    i8 => Int8,
+    u8 => UInt8,
+    i16 => Int16,
+    u16 => UInt16,
+    i32 => Int32,
+    u32 => UInt32,
+    f32 => Float32,
+    f64 => Float64,
+);
+
 /// Point field descriptor
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
 pub struct PointField {
@@ -71,16 +140,56 @@ impl PointField {
 
     /// Get size in bytes of this field
     pub fn field_size(&self) -> u32 {
-        let type_size = match self.datatype {
-            PointFieldType::Int8 | PointFieldType::UInt8 => 1,
-            PointFieldType::Int16 | PointFieldType::UInt16 => 2,
-            PointFieldType::Int32 | PointFieldType::UInt32 | PointFieldType::Float32 => 4,
-            PointFieldType::Float64 => 8,
-        };
-        type_size * self.count
+        self.datatype.size() * self.count
+    }
+}
+
+/// Typed view of one field, resolved once and applied to each point record
+#[derive(Debug, Clone, Copy)]
+pub struct FieldAccessor<T> {
+    offset: usize,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T: PointScalar> FieldAccessor<T> {
+    /// Read the field from a point record
+    pub fn get(&self, point: &[u8]) -> T {
+        T::read_le(&point[self.offset..])
+    }
+
+    /// Write the field into a point record
+    pub fn set(&self, point: &mut [u8], value: T) {
+        value.write_le(&mut point[self.offset..]);
+    }
+}
+
+/// Iterator over the point records of a cloud, honouring `point_step` and `row_step`
+pub struct PointIter<'a> {
+    cloud: &'a PointCloud,
+    index: usize,
+    len: usize,
+}
+
+impl<'a> Iterator for PointIter<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let point = self.cloud.point(self.index);
+        self.index += 1;
+        point
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
     }
 }
 
+impl ExactSizeIterator for PointIter<'_> {}
+
 /// 3D point cloud message
 ///
 /// Represents a collection of 3D points with optional color, intensity,
@@ -271,6 +380,172 @@ impl PointCloud {
         self.frame_id[len] = 0;
         self
     }
+
+    /// Create an empty unorganized cloud with `fields` packed in order
+    ///
+    /// ```rust,ignore
+    /// let mut cloud = PointCloud::with_layout(&[
+    ///     ("x", PointFieldType::Float32),
+    ///     ("y", PointFieldType::Float32),
+    ///     ("z", PointFieldType::Float32),
+    ///     ("intensity", PointFieldType::Float32),
+    ///     ("ring", PointFieldType::UInt16),
+    /// ])?;
+    /// cloud.resize(scan.len());
+    /// ```
+    pub fn with_layout(fields: &[(&str, PointFieldType)]) -> Result<Self, &'static str> {
+        let mut cloud = Self::new();
+        let mut offset = 0;
+        for &(name, datatype) in fields {
+            cloud.add_field(PointField::new(name, offset, datatype, 1))?;
+            offset += datatype.size();
+        }
+        cloud.point_step = offset;
+        cloud.height = 1;
+        cloud.is_dense = true;
+        Ok(cloud)
+    }
+
+    /// XYZ + intensity + ring layout used by multi-beam 3D lidars
+    pub fn lidar_layout() -> Self {
+        Self::with_layout(&[
+            ("x", PointFieldType::Float32),
+            ("y", PointFieldType::Float32),
+            ("z", PointFieldType::Float32),
+            ("intensity", PointFieldType::Float32),
+            ("ring", PointFieldType::UInt16),
+        ])
+        .expect("lidar layout fits the field table")
+    }
+
+    /// Resize to `points` zeroed points in a single row
+    pub fn resize(&mut self, points: usize) {
+        self.width = points as u32;
+        self.height = 1;
+        self.row_step = self.point_step * self.width;
+        self.data.clear();
+        self.data.resize(self.row_step as usize, 0);
+    }
+
+    /// Append a zeroed point to an unorganized cloud and return its record
+    pub fn push_point(&mut self) -> &mut [u8] {
+        let step = self.point_step as usize;
+        self.height = 1;
+        self.width += 1;
+        self.row_step = self.point_step * self.width;
+        let start = self.data.len();
+        self.data.resize(start + step, 0);
+        &mut self.data[start..]
+    }
+
+    /// Valid field descriptors
+    pub fn field_list(&self) -> &[PointField] {
+        &self.fields[..(self.field_count as usize).min(self.fields.len())]
+    }
+
+    /// Field descriptor by name
+    pub fn field(&self, name: &str) -> Option<&PointField> {
+        self.field_list().iter().find(|f| f.name_str() == name)
+    }
+
+    /// Typed accessor for field `name`, None if missing, of another type or
+    /// extending past `point_step`
+    pub fn accessor<T: PointScalar>(&self, name: &str) -> Option<FieldAccessor<T>> {
+        let field = self.field(name)?;
+        if field.datatype != T::TYPE || field.offset + field.datatype.size() > self.point_step {
+            return None;
+        }
+        Some(FieldAccessor {
+            offset: field.offset as usize,
+            _marker: std::marker::PhantomData,
+        })
+    }
+
+    /// Byte record of point `index` (row-major for organized clouds)
+    pub fn point(&self, index: usize) -> Option<&[u8]> {
+        let range = self.point_range(index)?;
+        self.data.get(range)
+    }
+
+    /// Mutable byte record of point `index`
+    pub fn point_mut(&mut self, index: usize) -> Option<&mut [u8]> {
+        let range = self.point_range(index)?;
+        self.data.get_mut(range)
+    }
+
+    fn point_range(&self, index: usize) -> Option<std::ops::Range<usize>> {
+        if self.width == 0 || index >= self.point_count() as usize {
+            return None;
+        }
+        let row_step = if self.row_step > 0 {
+            self.row_step
+        } else {
+            self.point_step * self.width
+        } as usize;
+        let (row, col) = (index / self.width as usize, index % self.width as usize);
+        let start = row * row_step + col * self.point_step as usize;
+        Some(start..start + self.point_step as usize)
+    }
+
+    /// Iterate over point records
+    pub fn points(&self) -> PointIter<'_> {
+        PointIter {
+            cloud: self,
+            index: 0,
+            len: self.point_count() as usize,
+        }
+    }
+
+    /// Iterate over field `name` as `T`, None if the field is missing or of another type
+    pub fn iter_field<T: PointScalar>(&self, name: &str) -> Option<impl Iterator<Item = T> + '_> {
+        let accessor = self.accessor::<T>(name)?;
+        Some(self.points().map(move |point| accessor.get(point)))
+    }
+
+    /// Iterate over field `name` converted to f64, whatever its stored type
+    pub fn iter_as_f64(&self, name: &str) -> Option<impl Iterator<Item = f64> + '_> {
+        let field = *self.field(name)?;
+        let offset = field.offset as usize;
+        if field.offset + field.datatype.size() > self.point_step {
+            return None;
+        }
+        Some(
+            self.points()
+                .map(move |point| field.datatype.read_f64(&point[offset..])),
+        )
+    }
+
+    /// Iterate over Float32 x, y, z coordinates
+    pub fn iter_xyz(&self) -> Option<impl Iterator<Item = [f32; 3]> + '_> {
+        let x = self.accessor::<f32>("x")?;
+        let y = self.accessor::<f32>("y")?;
+        let z = self.accessor::<f32>("z")?;
+        Some(
+            self.points()
+                .map(move |point| [x.get(point), y.get(point), z.get(point)]),
+        )
+    }
+
+    /// Read field `name` of point `index`
+    pub fn get<T: PointScalar>(&self, index: usize, name: &str) -> Option<T> {
+        let accessor = self.accessor::<T>(name)?;
+        Some(accessor.get(self.point(index)?))
+    }
+
+    /// Write field `name` of point `index`
+    pub fn set<T: PointScalar>(
+        &mut self,
+        index: usize,
+        name: &str,
+        value: T,
+    ) -> Result<(), &'static str> {
+        let accessor = self
+            .accessor::<T>(name)
+            .ok_or("Field missing or of a different type")?;
+        let point = self.point_mut(index).ok_or("Point index out of range")?;
+        accessor.set(point, value);
+        Ok(())
+    }
 }
 
 /// 3D bounding box
@@ -696,3 +971,58 @@ impl LogSummary for PlaneArray {
         format!("PlaneArray({} planes)", self.count)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point_cloud_layout_and_accessors() {
+        let mut cloud = PointCloud::lidar_layout();
+        assert_eq!(cloud.point_step, 18);
+        assert_eq!(cloud.field("ring").unwrap().offset, 16);
+
+        let x = cloud.accessor::<f32>("x").unwrap();
+        let ring = cloud.accessor::<u16>("ring").unwrap();
+        for i in 0..3 {
+            let point = cloud.push_point();
+            x.set(point, i as f32);
+            ring.set(point, 10 + i);
+        }
+        cloud.set(1, "intensity", 0.5f32).unwrap();
+
+        assert!(cloud.is_valid());
+        assert_eq!(cloud.point_count(), 3);
+        assert_eq!(cloud.get::<u16>(2, "ring"), Some(12));
+        assert_eq!(cloud.get::<f32>(1, "intensity"), Some(0.5));
+        // Wrong type or unknown field
+        assert!(cloud.accessor::<f32>("ring").is_none());
+        assert!(cloud.set(0, "rgb", 1u32).is_err());
+
+        let xs: Vec<[f32; 3]> = cloud.iter_xyz().unwrap().collect();
+        assert_eq!(xs[2], [2.0, 0.0, 0.0]);
+        let rings: Vec<f64> = cloud.iter_as_f64("ring").unwrap().collect();
+        assert_eq!(rings, vec![10.0, 11.0, 12.0]);
+    }
+
+    #[test]
+    fn test_point_cloud_row_step_padding() {
+        let mut cloud = PointCloud::with_layout(&[("z", PointFieldType::Float32)]).unwrap();
+        cloud.width = 2;
+        cloud.height = 2;
+        cloud.row_step = 12; // 4 bytes of padding per row
+        cloud.data = vec![0; 24];
+        for i in 0..4 {
+            cloud.set(i, "z", i as f32).unwrap();
+        }
+        assert_eq!(
+            f32::from_le_bytes(cloud.data[12..16].try_into().unwrap()),
+            2.0
+        );
+        let z: Vec<f32> = cloud.iter_field("z").unwrap().collect();
+        assert_eq!(z, vec![0.0, 1.0, 2.0, 3.0]);
+
+        let legacy = PointCloud::xyz(&[Point3::new(1.0, 2.0, 3.0)]);
+        assert_eq!(legacy.iter_xyz().unwrap().next(), Some([1.0, 2.0, 3.0]));
+    }
+}
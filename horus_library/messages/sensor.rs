@@ -393,6 +393,124 @@ impl BatteryState {
     }
 }
 
+/// Cell-level battery diagnostics reported by a BMS
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct CellDiagnostics {
+    /// Cell voltages in volts
+    #[serde(with = "serde_arrays")]
+    pub cell_voltages: [f32; 16],
+    /// Cell (or sensor) temperatures in celsius (NaN if not reported)
+    #[serde(with = "serde_arrays")]
+    pub cell_temperatures: [f32; 16],
+    /// Number of valid cell voltage readings
+    pub cell_count: u8,
+    /// Lowest cell voltage in volts
+    pub min_cell_voltage: f32,
+    /// Highest cell voltage in volts
+    pub max_cell_voltage: f32,
+    /// Bit mask of cells currently being balanced (bit n = cell n)
+    pub balancing_mask: u16,
+    /// Bit mask of cells above the over-voltage limit
+    pub over_voltage_mask: u16,
+    /// Bit mask of cells below the under-voltage limit
+    pub under_voltage_mask: u16,
+    /// Charge cycle count reported by the BMS
+    pub cycle_count: u32,
+    /// Timestamp in nanoseconds since epoch
+    pub timestamp: u64,
+}
+
+impl Default for CellDiagnostics {
+    fn default() -> Self {
+        Self {
+            cell_voltages: [0.0; 16],
+            cell_temperatures: [f32::NAN; 16],
+            cell_count: 0,
+            min_cell_voltage: 0.0,
+            max_cell_voltage: 0.0,
+            balancing_mask: 0,
+            over_voltage_mask: 0,
+            under_voltage_mask: 0,
+            cycle_count: 0,
+            timestamp: 0,
+        }
+    }
+}
+
+impl CellDiagnostics {
+    /// Spread between the highest and lowest cell in volts
+    pub fn imbalance(&self) -> f32 {
+        self.max_cell_voltage - self.min_cell_voltage
+    }
+
+    /// Valid cell voltages
+    pub fn cells(&self) -> &[f32] {
+        &self.cell_voltages[..(self.cell_count as usize).min(16)]
+    }
+}
+
+/// Charging state reported by a charging manager
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+pub struct ChargerStatus {
+    /// Charging state (see `STATE_*`)
+    pub state: u8,
+    /// Active faults (bit mask of `FAULT_*`)
+    pub faults: u16,
+    /// Pack voltage in volts
+    pub voltage: f32,
+    /// Pack current in amperes (positive = charging)
+    pub current: f32,
+    /// State of charge (0-100)
+    pub percentage: f32,
+    /// Charge current currently commanded to the charger in amperes
+    pub commanded_current: f32,
+    /// Charge voltage currently commanded to the charger in volts
+    pub commanded_voltage: f32,
+    /// Target state of charge at which charging completes (0-100)
+    pub target_percentage: f32,
+    /// Timestamp in nanoseconds since epoch
+    pub timestamp: u64,
+}
+
+impl ChargerStatus {
+    pub const STATE_IDLE: u8 = 0;
+    pub const STATE_CHARGING: u8 = 1;
+    pub const STATE_COMPLETE: u8 = 2;
+    pub const STATE_FAULT: u8 = 3;
+
+    pub const FAULT_CELL_OVER_VOLTAGE: u16 = 1 << 0;
+    pub const FAULT_CELL_UNDER_VOLTAGE: u16 = 1 << 1;
+    pub const FAULT_OVER_TEMPERATURE: u16 = 1 << 2;
+    pub const FAULT_UNDER_TEMPERATURE: u16 = 1 << 3;
+    pub const FAULT_COMMS_TIMEOUT: u16 = 1 << 4;
+    pub const FAULT_BMS_ALARM: u16 = 1 << 5;
+
+    /// Check if the charger is delivering current
+    pub fn is_charging(&self) -> bool {
+        self.state == Self::STATE_CHARGING
+    }
+
+    /// Check if any fault is active
+    pub fn has_fault(&self) -> bool {
+        self.faults != 0
+    }
+}
+
+impl LogSummary for CellDiagnostics {
+    fn log_summary(&self) -> String {
+        format!(
+            "Cells: n={}, min={:.3}V, max={:.3}V, balancing=0x{:04X}",
+            self.cell_count, self.min_cell_voltage, self.max_cell_voltage, self.balancing_mask
+        )
+    }
+}
+
+impl LogSummary for ChargerStatus {
+    fn log_summary(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
 impl LogSummary for LaserScan {
     fn log_summary(&self) -> String {
         format!("{:?}", self)
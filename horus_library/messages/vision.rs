@@ -224,6 +224,43 @@ impl CompressedImage {
     }
 }
 
+/// Lens distortion model of a calibrated camera
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum DistortionModel {
+    /// No distortion (rectified or ideal pinhole camera)
+    #[default]
+    None,
+    /// Brown-Conrady radial-tangential model [k1, k2, p1, p2, k3]
+    PlumbBob,
+    /// Rational radial model [k1, k2, p1, p2, k3, k4, k5, k6]
+    RationalPolynomial,
+    /// Kannala-Brandt fisheye model [k1, k2, k3, k4]
+    Equidistant,
+}
+
+impl DistortionModel {
+    /// Model name as used in ROS calibration files
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DistortionModel::None => "none",
+            DistortionModel::PlumbBob => "plumb_bob",
+            DistortionModel::RationalPolynomial => "rational_polynomial",
+            DistortionModel::Equidistant => "equidistant",
+        }
+    }
+
+    /// Parse a model name ("plumb_bob", "rational_polynomial", "equidistant", "fisheye")
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "" | "none" => Some(DistortionModel::None),
+            "plumb_bob" | "radtan" => Some(DistortionModel::PlumbBob),
+            "rational_polynomial" => Some(DistortionModel::RationalPolynomial),
+            "equidistant" | "fisheye" => Some(DistortionModel::Equidistant),
+            _ => None,
+        }
+    }
+}
+
 /// Camera calibration information
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct CameraInfo {
@@ -249,6 +286,11 @@ pub struct CameraInfo {
     /// [0,   0,   1,   0 ]
     #[serde(with = "serde_arrays")]
     pub projection_matrix: [f64; 12],
+    /// Extrinsics: transform from the robot base frame to the camera optical
+    /// frame (3x4 [R | t], row-major), used to project lidar or other sensor
+    /// data into the image
+    #[serde(with = "serde_arrays")]
+    pub extrinsics: [f64; 12],
     /// Frame ID (camera identifier)
     pub frame_id: [u8; 32],
     /// Timestamp in nanoseconds since epoch
@@ -265,6 +307,7 @@ impl Default for CameraInfo {
             camera_matrix: [0.0; 9],
             rectification_matrix: [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0], // Identity
             projection_matrix: [0.0; 12],
+            extrinsics: [1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0], // Identity
             frame_id: [0; 32],
             timestamp: 0,
         }
@@ -305,6 +348,120 @@ impl CameraInfo {
         self.distortion_model[len] = 0;
         self
     }
+
+    /// Set the distortion model and its coefficients (at most 8 are used)
+    pub fn with_distortion(mut self, model: DistortionModel, coefficients: &[f64]) -> Self {
+        let len = coefficients.len().min(8);
+        self.distortion_coefficients = [0.0; 8];
+        self.distortion_coefficients[..len].copy_from_slice(&coefficients[..len]);
+        self.distortion_model = [0; 16];
+        self.with_distortion_model(model.as_str())
+    }
+
+    /// Set the base-to-camera transform (row-major rotation and translation in meters)
+    pub fn with_extrinsics(mut self, rotation: [f64; 9], translation: [f64; 3]) -> Self {
+        for row in 0..3 {
+            self.extrinsics[row * 4..row * 4 + 3].copy_from_slice(&rotation[row * 3..row * 3 + 3]);
+            self.extrinsics[row * 4 + 3] = translation[row];
+        }
+        self
+    }
+
+    /// Parsed distortion model
+    ///
+    /// An empty or unknown model name counts as plumb_bob when any
+    /// coefficient is set, and as no distortion otherwise.
+    pub fn distortion(&self) -> DistortionModel {
+        let end = self
+            .distortion_model
+            .iter()
+            .position(|&b| b == 0)
+            .unwrap_or(16);
+        let name = String::from_utf8_lossy(&self.distortion_model[..end]);
+        match DistortionModel::from_name(&name) {
+            Some(DistortionModel::None) | None
+                if self.distortion_coefficients.iter().any(|&k| k != 0.0) =>
+            {
+                DistortionModel::PlumbBob
+            }
+            Some(model) => model,
+            None => DistortionModel::None,
+        }
+    }
+
+    /// Apply lens distortion to normalized image coordinates (x/z, y/z)
+    pub fn distort(&self, x: f64, y: f64) -> (f64, f64) {
+        let d = &self.distortion_coefficients;
+        match self.distortion() {
+            DistortionModel::None => (x, y),
+            DistortionModel::PlumbBob | DistortionModel::RationalPolynomial => {
+                let r2 = x * x + y * y;
+                let r4 = r2 * r2;
+                let r6 = r4 * r2;
+                let radial = (1.0 + d[0] * r2 + d[1] * r4 + d[4] * r6)
+                    / (1.0 + d[5] * r2 + d[6] * r4 + d[7] * r6);
+                let xd = x * radial + 2.0 * d[2] * x * y + d[3] * (r2 + 2.0 * x * x);
+                let yd = y * radial + d[2] * (r2 + 2.0 * y * y) + 2.0 * d[3] * x * y;
+                (xd, yd)
+            }
+            DistortionModel::Equidistant => {
+                let r = (x * x + y * y).sqrt();
+                if r < 1e-12 {
+                    return (x, y);
+                }
+                let theta = r.atan();
+                let t2 = theta * theta;
+                let theta_d = theta * (1.0 + t2 * (d[0] + t2 * (d[1] + t2 * (d[2] + t2 * d[3]))));
+                let scale = theta_d / r;
+                (x * scale, y * scale)
+            }
+        }
+    }
+
+    /// Transform a point from the robot base frame into the camera optical frame
+    pub fn base_to_camera(&self, point: [f64; 3]) -> [f64; 3] {
+        let e = &self.extrinsics;
+        let [x, y, z] = point;
+        [
+            e[0] * x + e[1] * y + e[2] * z + e[3],
+            e[4] * x + e[5] * y + e[6] * z + e[7],
+            e[8] * x + e[9] * y + e[10] * z + e[11],
+        ]
+    }
+
+    /// Project a point in the camera optical frame to raw (distorted) pixel coordinates
+    ///
+    /// Returns None for points behind the camera. The result may lie outside
+    /// the image.
+    pub fn project_point(&self, point: [f64; 3]) -> Option<(f64, f64)> {
+        let [x, y, z] = point;
+        if z <= 0.0 {
+            return None;
+        }
+        let (xd, yd) = self.distort(x / z, y / z);
+        let k = &self.camera_matrix;
+        Some((k[0] * xd + k[1] * yd + k[2], k[4] * yd + k[5]))
+    }
+
+    /// Project a point in the camera optical frame to rectified pixel
+    /// coordinates using the projection matrix
+    pub fn project_rectified(&self, point: [f64; 3]) -> Option<(f64, f64)> {
+        let p = &self.projection_matrix;
+        let [x, y, z] = point;
+        let w = p[8] * x + p[9] * y + p[10] * z + p[11];
+        if w <= 0.0 {
+            return None;
+        }
+        Some((
+            (p[0] * x + p[1] * y + p[2] * z + p[3]) / w,
+            (p[4] * x + p[5] * y + p[6] * z + p[7]) / w,
+        ))
+    }
+
+    /// Whether pixel coordinates fall inside the image
+    pub fn contains(&self, u: f64, v: f64) -> bool {
+        u >= 0.0 && v >= 0.0 && u < self.width as f64 && v < self.height as f64
+    }
 }
 
 /// Region of Interest in an image
@@ -461,6 +618,147 @@ impl DetectionArray {
     }
 }
 
+/// Fiducial marker family (dictionary)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+pub enum FiducialFamily {
+    /// ArUco 4x4 bits, 50 markers
+    Aruco4x4_50,
+    /// ArUco 5x5 bits, 100 markers
+    Aruco5x5_100,
+    /// ArUco 6x6 bits, 250 markers
+    Aruco6x6_250,
+    /// Original ArUco dictionary (5x5 bits, 1024 markers)
+    ArucoOriginal,
+    /// AprilTag 16h5 (30 markers)
+    AprilTag16h5,
+    /// AprilTag 25h9 (35 markers)
+    AprilTag25h9,
+    /// AprilTag 36h10 (2320 markers)
+    AprilTag36h10,
+    /// AprilTag 36h11 (587 markers), the usual choice for docking and calibration
+    #[default]
+    AprilTag36h11,
+}
+
+impl FiducialFamily {
+    /// Family name ("tag36h11", "aruco_4x4_50", ...)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FiducialFamily::Aruco4x4_50 => "aruco_4x4_50",
+            FiducialFamily::Aruco5x5_100 => "aruco_5x5_100",
+            FiducialFamily::Aruco6x6_250 => "aruco_6x6_250",
+            FiducialFamily::ArucoOriginal => "aruco_original",
+            FiducialFamily::AprilTag16h5 => "tag16h5",
+            FiducialFamily::AprilTag25h9 => "tag25h9",
+            FiducialFamily::AprilTag36h10 => "tag36h10",
+            FiducialFamily::AprilTag36h11 => "tag36h11",
+        }
+    }
+
+    /// Parse a family name as returned by [`as_str`](Self::as_str)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "aruco_4x4_50" => Some(FiducialFamily::Aruco4x4_50),
+            "aruco_5x5_100" => Some(FiducialFamily::Aruco5x5_100),
+            "aruco_6x6_250" => Some(FiducialFamily::Aruco6x6_250),
+            "aruco_original" => Some(FiducialFamily::ArucoOriginal),
+            "tag16h5" => Some(FiducialFamily::AprilTag16h5),
+            "tag25h9" => Some(FiducialFamily::AprilTag25h9),
+            "tag36h10" => Some(FiducialFamily::AprilTag36h10),
+            "tag36h11" => Some(FiducialFamily::AprilTag36h11),
+            _ => None,
+        }
+    }
+}
+
+/// Detected fiducial marker (AprilTag/ArUco) with its 6-DoF pose
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
+pub struct FiducialDetection {
+    /// Marker ID within the family
+    pub id: u32,
+    /// Marker family
+    pub family: FiducialFamily,
+    /// Corner pixels (x, y): top-left, top-right, bottom-right, bottom-left
+    pub corners: [[f64; 2]; 4],
+    /// Marker side length in meters used for the pose
+    pub size: f64,
+    /// Marker pose in the camera optical frame (x right, y down, z forward);
+    /// the marker z axis points out of the marker face
+    pub pose: crate::messages::geometry::Transform,
+    /// RMS reprojection error of the corners in pixels
+    pub reprojection_error: f64,
+}
+
+impl FiducialDetection {
+    /// Center of the marker in pixels
+    pub fn center(&self) -> (f64, f64) {
+        let x = self.corners.iter().map(|c| c[0]).sum::<f64>() / 4.0;
+        let y = self.corners.iter().map(|c| c[1]).sum::<f64>() / 4.0;
+        (x, y)
+    }
+
+    /// Distance from the camera to the marker center in meters
+    pub fn distance(&self) -> f64 {
+        let [x, y, z] = self.pose.translation;
+        (x * x + y * y + z * z).sqrt()
+    }
+}
+
+/// Fiducial markers detected in one image
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct FiducialArray {
+    /// Detected markers (max 32)
+    #[serde(with = "serde_arrays")]
+    pub detections: [FiducialDetection; 32],
+    /// Number of valid detections
+    pub count: u8,
+    /// Camera frame ID of the poses
+    pub frame_id: [u8; 32],
+    /// Timestamp of the source image in nanoseconds since epoch
+    pub timestamp: u64,
+}
+
+impl FiducialArray {
+    /// Create an empty array for a camera frame
+    pub fn new(frame_id: &str, timestamp: u64) -> Self {
+        let mut array = Self {
+            timestamp,
+            ..Default::default()
+        };
+        let frame_bytes = frame_id.as_bytes();
+        let len = frame_bytes.len().min(31);
+        array.frame_id[..len].copy_from_slice(&frame_bytes[..len]);
+        array
+    }
+
+    /// Add a detection
+    pub fn add_detection(&mut self, detection: FiducialDetection) -> Result<(), &'static str> {
+        if self.count >= 32 {
+            return Err("Maximum 32 fiducials supported");
+        }
+
+        self.detections[self.count as usize] = detection;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Get valid detections
+    pub fn get_detections(&self) -> &[FiducialDetection] {
+        &self.detections[..self.count as usize]
+    }
+
+    /// Find a marker by ID
+    pub fn find(&self, id: u32) -> Option<&FiducialDetection> {
+        self.get_detections().iter().find(|d| d.id == id)
+    }
+
+    /// Get frame ID as string
+    pub fn frame_id_str(&self) -> String {
+        let end = self.frame_id.iter().position(|&b| b == 0).unwrap_or(32);
+        String::from_utf8_lossy(&self.frame_id[..end]).into_owned()
+    }
+}
+
 /// Stereo camera pair information
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, Default)]
 pub struct StereoInfo {
@@ -557,6 +855,23 @@ impl LogSummary for DetectionArray {
     }
 }
 
+impl LogSummary for FiducialDetection {
+    fn log_summary(&self) -> String {
+        format!(
+            "Fiducial({} #{}, {:.2}m)",
+            self.family.as_str(),
+            self.id,
+            self.distance()
+        )
+    }
+}
+
+impl LogSummary for FiducialArray {
+    fn log_summary(&self) -> String {
+        format!("FiducialArray({} markers)", self.count)
+    }
+}
+
 impl LogSummary for StereoInfo {
     fn log_summary(&self) -> String {
         format!(
@@ -603,6 +918,48 @@ mod tests {
         assert_eq!(info.principal_point(), (320.0, 240.0));
     }
 
+    #[test]
+    fn test_camera_distortion_and_projection() {
+        let info = CameraInfo::new(640, 480, 500.0, 500.0, 320.0, 240.0);
+        assert_eq!(info.distortion(), DistortionModel::None);
+        assert_eq!(info.project_point([0.0, 0.0, 2.0]), Some((320.0, 240.0)));
+        assert_eq!(info.project_point([0.0, 0.0, -1.0]), None);
+        assert_eq!(
+            info.project_rectified([1.0, 0.0, 2.0]),
+            Some((570.0, 240.0))
+        );
+
+        // Barrel distortion pulls off-centre points towards the centre
+        let info = info.with_distortion(DistortionModel::PlumbBob, &[-0.2, 0.05, 0.0, 0.0, 0.0]);
+        assert_eq!(info.distortion(), DistortionModel::PlumbBob);
+        let (u, v) = info.project_point([1.0, 0.0, 2.0]).unwrap();
+        assert!(u < 570.0 && u > 320.0);
+        assert_eq!(v, 240.0);
+
+        let fisheye = CameraInfo::new(640, 480, 300.0, 300.0, 320.0, 240.0)
+            .with_distortion(DistortionModel::Equidistant, &[0.0; 4]);
+        let (xd, _) = fisheye.distort(1.0, 0.0);
+        assert!((xd - std::f64::consts::FRAC_PI_4).abs() < 1e-12);
+
+        // Coefficients without a model name fall back to plumb_bob
+        let mut unnamed = CameraInfo::new(640, 480, 500.0, 500.0, 320.0, 240.0);
+        unnamed.distortion_coefficients[0] = 0.1;
+        assert_eq!(unnamed.distortion(), DistortionModel::PlumbBob);
+    }
+
+    #[test]
+    fn test_camera_extrinsics() {
+        // Base frame (x forward, z up) to optical frame (z forward, y down), camera 0.5m up
+        let info = CameraInfo::new(640, 480, 500.0, 500.0, 320.0, 240.0).with_extrinsics(
+            [0.0, -1.0, 0.0, 0.0, 0.0, -1.0, 1.0, 0.0, 0.0],
+            [0.0, 0.5, 0.0],
+        );
+        let point = info.base_to_camera([4.0, 0.0, 0.5]);
+        assert_eq!(point, [0.0, 0.0, 4.0]);
+        let (u, v) = info.project_point(point).unwrap();
+        assert!(info.contains(u, v));
+    }
+
     #[test]
     fn test_detection_array() {
         let mut array = DetectionArray::new();
@@ -0,0 +1,365 @@
+use crate::{EmergencyStop, ImpedanceParameters, Transform, WrenchStamped};
+use horus_core::error::HorusResult;
+
+// Import algorithms from horus_library/algorithms
+use crate::algorithms::admittance::{Admittance, AdmittanceStatus, AXES};
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const AXIS_NAMES: [&str; AXES] = ["fx", "fy", "fz", "tx", "ty", "tz"];
+
+/// Admittance Controller Node - Force-compliant Cartesian control for arms
+///
+/// Consumes wrench feedback from a force/torque sensor driver and shifts the
+/// incoming Cartesian target so the arm yields to contact forces. Useful for
+/// peg-in-hole insertion, surface following and hand guiding.
+///
+/// Stiffness, damping, inertia and safety force limits are configured per
+/// axis, either through setters or at runtime via `ImpedanceParameters` on
+/// the params topic. When a safety limit is exceeded the node stops
+/// forwarding commands, holds the last compliant pose and engages the
+/// emergency stop topic.
+///
+/// A wrench older than the wrench timeout (by its timestamp) is treated as
+/// zero, so a sensor that stops publishing doesn't keep pushing the arm.
+///
+/// This node is a thin wrapper around the pure algorithm in horus_library/algorithms.
+pub struct AdmittanceControllerNode {
+    // Publishers and Subscribers
+    command_publisher: Hub<Transform>,
+    estop_publisher: Hub<EmergencyStop>,
+    wrench_subscriber: Hub<WrenchStamped>,
+    target_subscriber: Hub<Transform>,
+    params_subscriber: Hub<ImpedanceParameters>,
+
+    // Algorithm instance
+    admittance: Admittance,
+
+    // Node state
+    target: Option<Transform>,
+    wrench: [f64; AXES],
+    wrench_stamp_ns: u64,
+    wrench_stale: bool,
+    last_command: Option<Transform>,
+    enabled: bool,
+    faulted: bool,
+    last_time: u64,
+
+    // Configuration
+    wrench_timeout_ms: u64,
+}
+
+impl AdmittanceControllerNode {
+    /// Create a new admittance controller node with default topics
+    pub fn new() -> Result<Self> {
+        Self::new_with_topics(
+            "force_torque.wrench",
+            "admittance.target",
+            "admittance.command",
+            "admittance.params",
+        )
+    }
+
+    /// Create a new admittance controller node with custom topics
+    pub fn new_with_topics(
+        wrench_topic: &str,
+        target_topic: &str,
+        command_topic: &str,
+        params_topic: &str,
+    ) -> Result<Self> {
+        let params = ImpedanceParameters::new();
+        let mut admittance = Admittance::new();
+        admittance.set_stiffness(params.stiffness);
+        admittance.set_damping(params.damping);
+        admittance.set_mass(params.inertia);
+        admittance.set_force_limits(params.force_limits);
+
+        Ok(Self {
+            command_publisher: Hub::new(command_topic)?,
+            estop_publisher: Hub::new("emergency_stop")?,
+            wrench_subscriber: Hub::new(wrench_topic)?,
+            target_subscriber: Hub::new(target_topic)?,
+            params_subscriber: Hub::new(params_topic)?,
+
+            admittance,
+
+            target: None,
+            wrench: [0.0; AXES],
+            wrench_stamp_ns: 0,
+            wrench_stale: false,
+            last_command: None,
+            enabled: true,
+            faulted: false,
+            last_time: 0,
+
+            wrench_timeout_ms: 100,
+        })
+    }
+
+    /// Set stiffness per axis [Kx, Ky, Kz, Krx, Kry, Krz]
+    pub fn set_stiffness(&mut self, stiffness: [f64; AXES]) {
+        self.admittance.set_stiffness(stiffness);
+    }
+
+    /// Set damping per axis [Dx, Dy, Dz, Drx, Dry, Drz]
+    pub fn set_damping(&mut self, damping: [f64; AXES]) {
+        self.admittance.set_damping(damping);
+    }
+
+    /// Set virtual inertia per axis [Mx, My, Mz, Mrx, Mry, Mrz]
+    pub fn set_inertia(&mut self, inertia: [f64; AXES]) {
+        self.admittance.set_mass(inertia);
+    }
+
+    /// Set safety force/torque limits per axis
+    pub fn set_force_limits(&mut self, limits: [f64; AXES]) {
+        self.admittance.set_force_limits(limits);
+    }
+
+    /// Set the desired contact wrench (e.g. constant downward force for insertion)
+    pub fn set_force_reference(&mut self, reference: [f64; AXES]) {
+        self.admittance.set_force_reference(reference);
+    }
+
+    /// Set deadband below which force errors are ignored
+    pub fn set_force_deadband(&mut self, deadband: [f64; AXES]) {
+        self.admittance.set_force_deadband(deadband);
+    }
+
+    /// Set maximum Cartesian deviation from the target per axis
+    pub fn set_max_offset(&mut self, max_offset: [f64; AXES]) {
+        self.admittance.set_max_offset(max_offset);
+    }
+
+    /// Set the age in milliseconds after which the wrench counts as zero (0 = disable)
+    pub fn set_wrench_timeout(&mut self, timeout_ms: u64) {
+        self.wrench_timeout_ms = timeout_ms;
+    }
+
+    /// Set the emergency stop topic used when a force limit is exceeded
+    pub fn set_estop_topic(&mut self, topic: &str) -> Result<()> {
+        self.estop_publisher = Hub::new(topic)?;
+        Ok(())
+    }
+
+    /// Enable or disable compliance (disabled = targets pass through unchanged)
+    pub fn set_enabled(&mut self, enabled: bool) {
+        if !enabled {
+            self.admittance.reset();
+        }
+        self.enabled = enabled;
+    }
+
+    /// Clear a force-limit fault and resume forwarding commands
+    pub fn clear_fault(&mut self) {
+        self.faulted = false;
+        self.admittance.reset();
+        self.last_time = 0;
+    }
+
+    /// Whether the node is holding position after a force-limit violation
+    pub fn is_faulted(&self) -> bool {
+        self.faulted
+    }
+
+    /// Current compliant offset [x, y, z, rx, ry, rz]
+    pub fn get_offset(&self) -> [f64; AXES] {
+        self.admittance.offset()
+    }
+
+    fn apply_params(&mut self, params: &ImpedanceParameters) {
+        self.admittance.set_stiffness(params.stiffness);
+        self.admittance.set_damping(params.damping);
+        self.admittance.set_mass(params.inertia);
+        self.admittance.set_force_limits(params.force_limits);
+        self.set_enabled(params.enabled);
+    }
+
+    /// Latch new wrench feedback and zero it once it is older than the timeout
+    ///
+    /// Returns true when the wrench has just gone stale.
+    fn update_wrench(&mut self, received: Option<WrenchStamped>, now_ns: u64) -> bool {
+        if let Some(wrench) = received {
+            self.wrench = [
+                wrench.force.x,
+                wrench.force.y,
+                wrench.force.z,
+                wrench.torque.x,
+                wrench.torque.y,
+                wrench.torque.z,
+            ];
+            // Drivers that don't stamp their messages are aged from reception
+            self.wrench_stamp_ns = if wrench.timestamp > 0 {
+                wrench.timestamp
+            } else {
+                now_ns
+            };
+        }
+
+        let timeout_ns = self.wrench_timeout_ms.saturating_mul(1_000_000);
+        let stale = timeout_ns > 0 && now_ns.saturating_sub(self.wrench_stamp_ns) > timeout_ns;
+        if stale {
+            self.wrench = [0.0; AXES];
+        }
+        let became_stale = stale && !self.wrench_stale && self.wrench_stamp_ns > 0;
+        self.wrench_stale = stale;
+        became_stale
+    }
+
+    fn compliant_command(&self, target: &Transform) -> Transform {
+        if !self.enabled {
+            return *target;
+        }
+        let (translation, rotation) = self
+            .admittance
+            .apply_offset(target.translation, target.rotation);
+        Transform {
+            translation,
+            rotation,
+            timestamp: target.timestamp,
+        }
+    }
+
+    fn trigger_fault(&mut self, axis: usize, value: f64, ctx: Option<&mut NodeInfo>) {
+        self.faulted = true;
+        let reason = format!(
+            "Admittance force limit: {} = {:.2} (limit exceeded)",
+            AXIS_NAMES[axis], value
+        );
+        if let Some(ctx) = ctx {
+            ctx.log_error(&reason);
+        }
+        let estop = EmergencyStop::engage(&reason).with_source("AdmittanceControllerNode");
+        let _ = self.estop_publisher.send(estop, &mut None);
+    }
+}
+
+impl Node for AdmittanceControllerNode {
+    fn name(&self) -> &'static str {
+        "AdmittanceControllerNode"
+    }
+
+    fn shutdown(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        ctx.log_info("AdmittanceControllerNode shutting down - holding last command");
+
+        // Re-send the last commanded pose so downstream controllers hold still
+        if let Some(command) = self.last_command {
+            let _ = self.command_publisher.send(command, &mut None);
+        }
+        self.admittance.reset();
+        Ok(())
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        let current_time = now.as_micros() as u64;
+
+        // Calculate delta time
+        let dt = if self.last_time > 0 {
+            (current_time.saturating_sub(self.last_time)) as f64 / 1_000_000.0
+        } else {
+            0.001 // 1ms default
+        };
+        self.last_time = current_time;
+
+        // Check for new impedance configuration
+        if let Some(params) = self.params_subscriber.recv(&mut None) {
+            self.apply_params(&params);
+        }
+
+        // Check for new Cartesian target
+        if let Some(target) = self.target_subscriber.recv(&mut None) {
+            self.target = Some(target);
+        }
+
+        // Check for new wrench feedback, dropping it once the sensor goes quiet
+        let received = self.wrench_subscriber.recv(&mut None);
+        if self.update_wrench(received, now.as_nanos() as u64) {
+            if let Some(ctx) = ctx.as_deref_mut() {
+                ctx.log_warning(&format!(
+                    "No wrench feedback for {} ms, treating it as zero",
+                    self.wrench_timeout_ms
+                ));
+            }
+        }
+
+        if self.faulted {
+            return;
+        }
+
+        if self.enabled {
+            if let AdmittanceStatus::ForceLimitExceeded { axis, value } =
+                self.admittance.update(self.wrench, dt)
+            {
+                self.trigger_fault(axis, value, ctx);
+                return;
+            }
+        }
+
+        if let Some(target) = self.target {
+            let command = self.compliant_command(&target);
+            let _ = self.command_publisher.send(command, &mut None);
+            self.last_command = Some(command);
+        }
+    }
+}
+
+// Default impl removed - use AdmittanceControllerNode::new() instead which returns HorusResult
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Vector3;
+
+    fn wrench(fz: f64, timestamp: u64) -> WrenchStamped {
+        let mut wrench = WrenchStamped::force_only(Vector3::new(0.0, 0.0, fz));
+        wrench.timestamp = timestamp;
+        wrench
+    }
+
+    #[test]
+    fn test_stale_wrench_is_zeroed() {
+        let mut node = AdmittanceControllerNode::new_with_topics(
+            "test_admittance_stale.wrench",
+            "test_admittance_stale.target",
+            "test_admittance_stale.command",
+            "test_admittance_stale.params",
+        )
+        .unwrap();
+        let t0: u64 = 1_000_000_000_000;
+
+        assert!(!node.update_wrench(Some(wrench(-5.0, t0)), t0));
+        assert_eq!(node.wrench[2], -5.0);
+        assert!(!node.update_wrench(None, t0 + 50_000_000));
+        assert_eq!(node.wrench[2], -5.0);
+
+        // The sensor went quiet: reported once, zero from then on
+        assert!(node.update_wrench(None, t0 + 150_000_000));
+        assert_eq!(node.wrench, [0.0; AXES]);
+        assert!(!node.update_wrench(None, t0 + 200_000_000));
+
+        // A delayed message is judged by its own timestamp
+        node.update_wrench(Some(wrench(-5.0, t0)), t0 + 300_000_000);
+        assert_eq!(node.wrench, [0.0; AXES]);
+        node.update_wrench(Some(wrench(-5.0, 0)), t0 + 300_000_000);
+        assert_eq!(node.wrench[2], -5.0);
+    }
+
+    #[test]
+    fn test_wrench_timeout_can_be_disabled() {
+        let mut node = AdmittanceControllerNode::new_with_topics(
+            "test_admittance_no_timeout.wrench",
+            "test_admittance_no_timeout.target",
+            "test_admittance_no_timeout.command",
+            "test_admittance_no_timeout.params",
+        )
+        .unwrap();
+        node.set_wrench_timeout(0);
+        node.update_wrench(Some(wrench(3.0, 1)), 10_000_000_000);
+        assert!(!node.update_wrench(None, 20_000_000_000));
+        assert_eq!(node.wrench[2], 3.0);
+    }
+}
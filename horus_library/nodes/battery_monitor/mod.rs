@@ -1,6 +1,9 @@
-use crate::BatteryState;
+use crate::{BatteryState, EmergencyStop, Goal};
 use horus_core::error::HorusResult;
 
+// Import algorithms from horus_library/algorithms
+use crate::algorithms::soc_estimator::{OcvCurve, SocEstimator};
+
 type Result<T> = HorusResult<T>;
 use horus_core::{Hub, Node, NodeInfo, NodeInfoExt};
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -38,10 +41,12 @@ use i2cdev::linux::LinuxI2CDevice;
 /// # Features
 /// - Voltage monitoring with cell-level detection
 /// - Current sensing (charge/discharge)
-/// - State of charge (SOC) estimation
+/// - State of charge (SOC) estimation: coulomb counting corrected with
+///   the open-circuit voltage curve of the chemistry
 /// - Remaining capacity and runtime calculation
 /// - Temperature monitoring
-/// - Low battery warnings and critical alerts
+/// - Low/critical levels from SOC and voltage thresholds
+/// - Critical battery action: return-to-dock goal or emergency stop
 /// - Charge cycle counting
 /// - Cell balancing status
 ///
@@ -65,6 +70,13 @@ use i2cdev::linux::LinuxI2CDevice;
 /// battery.set_capacity(5000.0); // 5000 mAh
 /// battery.set_low_voltage_threshold(10.5); // 3.5V per cell
 /// battery.set_critical_voltage_threshold(9.9); // 3.3V per cell
+///
+/// // Drive back to the dock at 10% SOC
+/// battery.set_soc_thresholds(25.0, 10.0);
+/// battery.set_critical_action(CriticalBatteryAction::ReturnToDock {
+///     topic: "goal".to_string(),
+///     goal: Goal::new(Pose2D::new(0.0, 0.0, 0.0), 0.1, 0.1),
+/// })?;
 /// ```
 pub struct BatteryMonitorNode<P = PassThrough<BatteryState>>
 where
@@ -88,6 +100,13 @@ where
     low_voltage: f32,      // V (low warning)
     critical_voltage: f32, // V (critical shutdown)
 
+    // State of charge thresholds
+    low_soc: f32,      // % (low warning)
+    critical_soc: f32, // % (critical action)
+
+    // SOC estimation (coulomb counting + OCV correction)
+    soc_estimator: SocEstimator,
+
     // Current state
     voltage: f32,             // V
     current: f32,             // A (negative = discharging)
@@ -106,13 +125,18 @@ where
     history_index: usize,
 
     // Alerts
-    low_battery_warned: bool,
-    critical_battery_warned: bool,
+    level: BatteryLevel,
     over_current_warned: bool,
     over_temperature_warned: bool,
     max_current: f32,     // A
     max_temperature: f32, // °C
 
+    // Critical battery behavior
+    critical_action: CriticalBatteryAction,
+    dock_publisher: Option<Hub<Goal>>,
+    estop_publisher: Option<Hub<EmergencyStop>>,
+    critical_action_triggered: bool,
+
     // Hardware I2C device (INA219/INA226 current/voltage sensor)
     #[cfg(feature = "i2c-hardware")]
     i2c_device: Option<LinuxI2CDevice>,
@@ -147,6 +171,25 @@ pub enum MonitorInterface {
     Simulated, // Software simulation
 }
 
+/// Battery level from the SOC and voltage thresholds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BatteryLevel {
+    Normal,
+    Low,      // Below the low SOC or voltage threshold
+    Critical, // Below the critical SOC or voltage threshold
+}
+
+/// Safety behavior triggered once when the battery becomes critical
+#[derive(Debug, Clone)]
+pub enum CriticalBatteryAction {
+    /// Log only
+    None,
+    /// Publish a navigation goal (e.g. the dock pose) on `topic`
+    ReturnToDock { topic: String, goal: Goal },
+    /// Engage the emergency stop published on `topic`
+    EmergencyStop { topic: String },
+}
+
 impl BatteryMonitorNode {
     /// Create a new battery monitor node
     pub fn new() -> Result<Self> {
@@ -166,6 +209,9 @@ impl BatteryMonitorNode {
             nominal_voltage: 11.1, // 3.7V × 3
             low_voltage: 10.5,     // 3.5V × 3
             critical_voltage: 9.9, // 3.3V × 3
+            low_soc: 20.0,
+            critical_soc: 10.0,
+            soc_estimator: SocEstimator::new(5.0, 3, OcvCurve::lipo()),
             voltage: 11.1,
             current: 0.0,
             charge_mah: 5000.0,
@@ -179,12 +225,15 @@ impl BatteryMonitorNode {
             last_sample_time: 0,
             voltage_history: [11.1; 10],
             history_index: 0,
-            low_battery_warned: false,
-            critical_battery_warned: false,
+            level: BatteryLevel::Normal,
             over_current_warned: false,
             over_temperature_warned: false,
             max_current: 100.0,    // 100A default max
             max_temperature: 60.0, // 60°C default max
+            critical_action: CriticalBatteryAction::None,
+            dock_publisher: None,
+            estop_publisher: None,
+            critical_action_triggered: false,
             #[cfg(feature = "i2c-hardware")]
             i2c_device: None,
             hardware_enabled: false,
@@ -203,17 +252,25 @@ impl BatteryMonitorNode {
     pub fn builder() -> BatteryMonitorNodeBuilder<PassThrough<BatteryState>> {
         BatteryMonitorNodeBuilder::new()
     }
+}
 
+impl<P> BatteryMonitorNode<P>
+where
+    P: Processor<BatteryState>,
+{
     /// Set number of cells in series
     pub fn set_cell_count(&mut self, count: u8) {
         self.cell_count = count.clamp(1, 16);
         self.update_voltage_thresholds();
+        let curve = self.soc_estimator.curve().clone();
+        self.soc_estimator.set_curve(curve, self.cell_count);
     }
 
     /// Set battery capacity in mAh
     pub fn set_capacity(&mut self, capacity_mah: f32) {
         self.capacity_mah = capacity_mah;
-        self.charge_mah = capacity_mah; // Reset to full
+        self.soc_estimator.set_capacity(capacity_mah / 1000.0);
+        self.reset_soc(100.0); // Reset to full
     }
 
     /// Set battery chemistry
@@ -228,6 +285,71 @@ impl BatteryMonitorNode {
             BatteryChemistry::Custom => self.nominal_voltage_per_cell,
         };
         self.update_voltage_thresholds();
+
+        let curve = match chemistry {
+            BatteryChemistry::LiPo => OcvCurve::lipo(),
+            BatteryChemistry::LiFePO4 => OcvCurve::lifepo4(),
+            BatteryChemistry::LiIon => OcvCurve::li_ion(),
+            BatteryChemistry::NiMH => OcvCurve::nimh(),
+            BatteryChemistry::LeadAcid => OcvCurve::lead_acid(),
+            BatteryChemistry::Custom => self.soc_estimator.curve().clone(),
+        };
+        self.soc_estimator.set_curve(curve, self.cell_count);
+    }
+
+    /// Set the open-circuit voltage curve per cell (e.g. for custom chemistry)
+    pub fn set_ocv_curve(&mut self, curve: OcvCurve) {
+        self.soc_estimator.set_curve(curve, self.cell_count);
+    }
+
+    /// Set the pack internal resistance in ohms
+    ///
+    /// Lets the SOC estimate follow the load-corrected voltage while
+    /// driving, instead of only when the battery rests.
+    pub fn set_internal_resistance(&mut self, ohms: f32) {
+        self.soc_estimator.set_internal_resistance(ohms);
+    }
+
+    /// Set low and critical SOC thresholds in percent
+    ///
+    /// The battery is low/critical when either the SOC or the voltage
+    /// threshold is reached.
+    pub fn set_soc_thresholds(&mut self, low: f32, critical: f32) {
+        self.low_soc = low.clamp(0.0, 100.0);
+        self.critical_soc = critical.clamp(0.0, self.low_soc);
+    }
+
+    /// Set the behavior triggered when the battery becomes critical
+    ///
+    /// The action runs once per critical episode and is re-armed when the
+    /// battery is back to normal (after charging).
+    pub fn set_critical_action(&mut self, action: CriticalBatteryAction) -> Result<()> {
+        self.dock_publisher = None;
+        self.estop_publisher = None;
+        match &action {
+            CriticalBatteryAction::None => {}
+            CriticalBatteryAction::ReturnToDock { topic, .. } => {
+                self.dock_publisher = Some(Hub::new(topic)?);
+            }
+            CriticalBatteryAction::EmergencyStop { topic } => {
+                self.estop_publisher = Some(Hub::new(topic)?);
+            }
+        }
+        self.critical_action = action;
+        self.critical_action_triggered = false;
+        Ok(())
+    }
+
+    /// Set the state of charge (e.g. 100% after a full charge)
+    pub fn reset_soc(&mut self, percentage: f32) {
+        self.soc_estimator.reset(percentage);
+        self.percentage = self.soc_estimator.soc();
+        self.charge_mah = self.percentage / 100.0 * self.capacity_mah;
+    }
+
+    /// Get the battery level
+    pub fn get_level(&self) -> BatteryLevel {
+        self.level
     }
 
     /// Set monitoring interface
@@ -311,33 +433,10 @@ impl BatteryMonitorNode {
         }
     }
 
-    /// Estimate state of charge from voltage (reserved for alternative SOC method)
-    fn _estimate_soc_from_voltage(&self, voltage: f32) -> f32 {
-        // Simple linear interpolation between full and empty
-        let range = self.full_voltage - self.critical_voltage;
-        if range <= 0.0 {
-            return 50.0;
-        }
-
-        let normalized = (voltage - self.critical_voltage) / range;
-        (normalized * 100.0).clamp(0.0, 100.0)
-    }
-
-    /// Update charge based on current integration (Coulomb counting)
+    /// Update charge estimate (Coulomb counting with OCV correction)
     fn update_charge(&mut self, dt: f32) {
-        if self.current.abs() < 0.01 {
-            return; // Negligible current
-        }
-
-        // Convert current (A) and time (s) to charge (mAh)
-        let delta_charge = (self.current * dt * 1000.0) / 3600.0; // A·s to mAh
-        self.charge_mah += delta_charge;
-
-        // Clamp to valid range
-        self.charge_mah = self.charge_mah.clamp(0.0, self.capacity_mah);
-
-        // Update percentage
-        self.percentage = (self.charge_mah / self.capacity_mah * 100.0).clamp(0.0, 100.0);
+        self.percentage = self.soc_estimator.update(self.voltage, self.current, dt);
+        self.charge_mah = self.percentage / 100.0 * self.capacity_mah;
 
         // Detect charging state
         if self.current > 0.1 {
@@ -355,6 +454,10 @@ impl BatteryMonitorNode {
 
     /// Apply voltage filtering
     fn filter_voltage(&mut self, raw_voltage: f32) -> f32 {
+        // Start the average at the first reading, for the initial SOC
+        if !self.soc_estimator.is_initialized() {
+            self.voltage_history = [raw_voltage; 10];
+        }
         self.voltage_history[self.history_index] = raw_voltage;
         self.history_index = (self.history_index + 1) % self.voltage_history.len();
 
@@ -458,26 +561,108 @@ impl BatteryMonitorNode {
         self.temperature = ambient + heating;
     }
 
+    /// Battery level with hysteresis (5% SOC, 0.2V) when leaving a level
+    fn evaluate_level(&self) -> BatteryLevel {
+        let reached = |soc: f32, voltage: f32, current_level: bool| {
+            let (soc_margin, voltage_margin) = if current_level {
+                (5.0, 0.2)
+            } else {
+                (0.0, 0.0)
+            };
+            self.percentage <= soc + soc_margin || self.voltage <= voltage + voltage_margin
+        };
+
+        if reached(
+            self.critical_soc,
+            self.critical_voltage,
+            self.level == BatteryLevel::Critical,
+        ) {
+            BatteryLevel::Critical
+        } else if reached(
+            self.low_soc,
+            self.low_voltage,
+            self.level >= BatteryLevel::Low,
+        ) {
+            BatteryLevel::Low
+        } else {
+            BatteryLevel::Normal
+        }
+    }
+
+    /// Run the configured critical battery action
+    fn trigger_critical_action(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+
+        match &self.critical_action {
+            CriticalBatteryAction::None => {}
+            CriticalBatteryAction::ReturnToDock { topic, goal } => {
+                if let Some(publisher) = &self.dock_publisher {
+                    let mut goal = *goal;
+                    goal.timestamp = timestamp;
+                    match publisher.send(goal, &mut None) {
+                        Ok(()) => ctx.log_warning(&format!(
+                            "Critical battery: sent return-to-dock goal on '{}'",
+                            topic
+                        )),
+                        Err(e) => ctx.log_error(&format!("Failed to send dock goal: {:?}", e)),
+                    }
+                }
+            }
+            CriticalBatteryAction::EmergencyStop { topic } => {
+                if let Some(publisher) = &self.estop_publisher {
+                    let estop = EmergencyStop::engage(&format!(
+                        "Critical battery: {:.2}V ({:.0}%)",
+                        self.voltage, self.percentage
+                    ))
+                    .with_source("battery_monitor");
+                    match publisher.send(estop, &mut None) {
+                        Ok(()) => ctx.log_warning(&format!(
+                            "Critical battery: emergency stop engaged on '{}'",
+                            topic
+                        )),
+                        Err(e) => ctx.log_error(&format!("Failed to send emergency stop: {:?}", e)),
+                    }
+                }
+            }
+        }
+    }
+
     /// Check for alert conditions
     fn check_alerts(&mut self, mut ctx: Option<&mut NodeInfo>) {
-        // Low voltage warning
-        if self.voltage <= self.low_voltage && !self.low_battery_warned {
-            self.low_battery_warned = true;
-            ctx.log_warning(&format!(
-                "Low battery: {:.2}V ({:.0}%)",
-                self.voltage, self.percentage
-            ));
-        } else if self.voltage > self.low_voltage + 0.2 {
-            self.low_battery_warned = false;
+        // Low/critical battery level
+        let level = self.evaluate_level();
+        if level != self.level {
+            match level {
+                BatteryLevel::Low if self.level == BatteryLevel::Normal => {
+                    ctx.log_warning(&format!(
+                        "Low battery: {:.2}V ({:.0}%)",
+                        self.voltage, self.percentage
+                    ));
+                }
+                BatteryLevel::Critical => {
+                    ctx.log_error(&format!(
+                        "CRITICAL BATTERY: {:.2}V ({:.0}%) - SHUTDOWN IMMINENT",
+                        self.voltage, self.percentage
+                    ));
+                }
+                BatteryLevel::Normal => {
+                    ctx.log_info(&format!(
+                        "Battery level normal: {:.2}V ({:.0}%)",
+                        self.voltage, self.percentage
+                    ));
+                    self.critical_action_triggered = false;
+                }
+                _ => {}
+            }
+            self.level = level;
         }
 
-        // Critical voltage alert
-        if self.voltage <= self.critical_voltage && !self.critical_battery_warned {
-            self.critical_battery_warned = true;
-            ctx.log_error(&format!(
-                "CRITICAL BATTERY: {:.2}V ({:.0}%) - SHUTDOWN IMMINENT",
-                self.voltage, self.percentage
-            ));
+        if self.level == BatteryLevel::Critical && !self.critical_action_triggered {
+            self.critical_action_triggered = true;
+            self.trigger_critical_action(ctx.as_deref_mut());
         }
 
         // Over-current warning
@@ -848,6 +1033,9 @@ where
             nominal_voltage: 11.1,
             low_voltage: 10.5,
             critical_voltage: 9.9,
+            low_soc: 20.0,
+            critical_soc: 10.0,
+            soc_estimator: SocEstimator::new(5.0, 3, OcvCurve::lipo()),
             voltage: 11.1,
             current: 0.0,
             charge_mah: 5000.0,
@@ -861,12 +1049,15 @@ where
             last_sample_time: 0,
             voltage_history: [11.1; 10],
             history_index: 0,
-            low_battery_warned: false,
-            critical_battery_warned: false,
+            level: BatteryLevel::Normal,
             over_current_warned: false,
             over_temperature_warned: false,
             max_current: 100.0,
             max_temperature: 60.0,
+            critical_action: CriticalBatteryAction::None,
+            dock_publisher: None,
+            estop_publisher: None,
+            critical_action_triggered: false,
             i2c_device: None,
             hardware_enabled: false,
             i2c_address: 0x40,
@@ -893,6 +1084,9 @@ where
             nominal_voltage: 11.1,
             low_voltage: 10.5,
             critical_voltage: 9.9,
+            low_soc: 20.0,
+            critical_soc: 10.0,
+            soc_estimator: SocEstimator::new(5.0, 3, OcvCurve::lipo()),
             voltage: 11.1,
             current: 0.0,
             charge_mah: 5000.0,
@@ -906,12 +1100,15 @@ where
             last_sample_time: 0,
             voltage_history: [11.1; 10],
             history_index: 0,
-            low_battery_warned: false,
-            critical_battery_warned: false,
+            level: BatteryLevel::Normal,
             over_current_warned: false,
             over_temperature_warned: false,
             max_current: 100.0,
             max_temperature: 60.0,
+            critical_action: CriticalBatteryAction::None,
+            dock_publisher: None,
+            estop_publisher: None,
+            critical_action_triggered: false,
             hardware_enabled: false,
             i2c_address: 0x40,
             i2c_bus: 1,
@@ -27,6 +27,9 @@ use crate::drivers::camera::OpenCvCameraDriver;
 #[cfg(feature = "v4l2-backend")]
 use crate::drivers::camera::V4l2CameraDriver;
 
+#[cfg(feature = "gstreamer-backend")]
+use crate::drivers::camera::GStreamerCameraDriver;
+
 /// Camera backend type (deprecated - use CameraDriverBackend instead)
 ///
 /// This enum is kept for backward compatibility. New code should use
@@ -70,6 +73,8 @@ impl From<CameraBackend> for CameraDriverBackend {
 /// - `SimulationCameraDriver` - Always available, generates synthetic images
 /// - `OpenCvCameraDriver` - OpenCV-based camera (requires `opencv-backend` feature)
 /// - `V4l2CameraDriver` - Video4Linux2 camera (requires `v4l2-backend` feature)
+/// - `GStreamerCameraDriver` - RTSP/CSI/USB through GStreamer with hardware
+///   H.264/H.265 decoding (requires `gstreamer-backend` feature)
 ///
 /// # Example
 ///
@@ -592,3 +597,7 @@ pub type OpenCvCameraNode<P = PassThrough<Image>> = CameraNode<OpenCvCameraDrive
 #[cfg(feature = "v4l2-backend")]
 /// CameraNode with V4l2CameraDriver
 pub type V4l2CameraNode<P = PassThrough<Image>> = CameraNode<V4l2CameraDriver, P>;
+
+#[cfg(feature = "gstreamer-backend")]
+/// CameraNode with GStreamerCameraDriver
+pub type GStreamerCameraNode<P = PassThrough<Image>> = CameraNode<GStreamerCameraDriver, P>;
@@ -0,0 +1,825 @@
+//! DBC file parser and CAN signal codec
+//!
+//! Parses the message (`BO_`) and signal (`SG_`) definitions of a Vector DBC
+//! file, together with value descriptions (`VAL_`) and float signal types
+//! (`SIG_VALTYPE_`), and converts between raw CAN payloads and physical
+//! signal values.
+//!
+//! # Supported
+//! - Standard (11-bit) and extended (29-bit) identifiers
+//! - Little-endian (Intel) and big-endian (Motorola) signals up to 64 bits
+//! - Unsigned, signed, IEEE float and double signals
+//! - Simple multiplexing (one multiplexor signal per message)
+//! - CAN-FD payloads up to 64 bytes
+//!
+//! Attributes, comments, environment variables and signal groups are skipped.
+//!
+//! # Example
+//! ```rust,ignore
+//! use horus_library::nodes::can_dbc::dbc::DbcDatabase;
+//!
+//! let dbc = DbcDatabase::from_file("config/bms.dbc")?;
+//! let message = dbc.message_by_name("BMS_Status").unwrap();
+//! for (signal, value) in message.decode(frame.data_slice()) {
+//!     println!("{} = {}", signal, value);
+//! }
+//!
+//! let data = message.encode(&[("ChargeEnable", 1.0), ("MaxCurrent", 20.0)]);
+//! let frame = message.to_frame(&data);
+//! ```
+
+use crate::CanFrame;
+use horus_core::error::{HorusError, HorusResult};
+use std::path::Path;
+
+type Result<T> = HorusResult<T>;
+
+/// Bit ordering of a signal in the payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteOrder {
+    /// Intel: start bit is the least significant bit (`@1`)
+    LittleEndian,
+    /// Motorola: start bit is the most significant bit (`@0`)
+    BigEndian,
+}
+
+/// Representation of the raw signal value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueType {
+    Unsigned,
+    Signed,
+    /// IEEE 754 single precision (32-bit signals)
+    Float32,
+    /// IEEE 754 double precision (64-bit signals)
+    Float64,
+}
+
+/// Role of a signal in a multiplexed message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Multiplex {
+    /// Always present
+    None,
+    /// Selects which multiplexed signals are present (`M`)
+    Multiplexor,
+    /// Present when the multiplexor has this value (`m<value>`)
+    Multiplexed(u64),
+}
+
+/// Signal definition (`SG_`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbcSignal {
+    pub name: String,
+    /// Start bit as written in the DBC file
+    pub start_bit: u32,
+    /// Length in bits (1-64)
+    pub length: u32,
+    pub byte_order: ByteOrder,
+    pub value_type: ValueType,
+    /// physical = raw * factor + offset
+    pub factor: f64,
+    pub offset: f64,
+    /// Physical range; not enforced when `min == max`
+    pub min: f64,
+    pub max: f64,
+    pub unit: String,
+    pub receivers: Vec<String>,
+    pub multiplex: Multiplex,
+    /// Value descriptions (`VAL_`), e.g. `(0, "Off")`
+    pub value_descriptions: Vec<(i64, String)>,
+}
+
+impl DbcSignal {
+    /// Bit positions of the signal, most significant bit last for Intel and
+    /// first for Motorola, as byte * 8 + bit
+    fn bit_positions(&self) -> impl Iterator<Item = u32> + '_ {
+        let mut pos = self.start_bit;
+        (0..self.length).map(move |i| match self.byte_order {
+            ByteOrder::LittleEndian => self.start_bit + i,
+            ByteOrder::BigEndian => {
+                let current = pos;
+                // Motorola bits run from bit 7 down to bit 0, then continue
+                // at bit 7 of the next byte
+                let bit = pos % 8;
+                pos = if bit == 0 { pos + 15 } else { pos - 1 };
+                current
+            }
+        })
+    }
+
+    /// Highest byte index the signal occupies
+    fn last_byte(&self) -> usize {
+        self.bit_positions().map(|p| p / 8).max().unwrap_or(0) as usize
+    }
+
+    /// Raw bits of the signal, or `None` if the payload is too short
+    pub fn decode_raw(&self, data: &[u8]) -> Option<u64> {
+        if self.length == 0 || self.last_byte() >= data.len() {
+            return None;
+        }
+        let mut raw = 0u64;
+        for (i, pos) in self.bit_positions().enumerate() {
+            let bit = ((data[(pos / 8) as usize] >> (pos % 8)) & 1) as u64;
+            match self.byte_order {
+                ByteOrder::LittleEndian => raw |= bit << i,
+                ByteOrder::BigEndian => raw = (raw << 1) | bit,
+            }
+        }
+        Some(raw)
+    }
+
+    /// Raw value as a number (sign-extended or reinterpreted as float)
+    fn raw_to_value(&self, raw: u64) -> f64 {
+        match self.value_type {
+            ValueType::Unsigned => raw as f64,
+            ValueType::Signed => {
+                if self.length < 64 && raw >> (self.length - 1) & 1 == 1 {
+                    (raw | (u64::MAX << self.length)) as i64 as f64
+                } else {
+                    raw as i64 as f64
+                }
+            }
+            ValueType::Float32 => f32::from_bits(raw as u32) as f64,
+            ValueType::Float64 => f64::from_bits(raw),
+        }
+    }
+
+    /// Physical value of the signal, or `None` if the payload is too short
+    pub fn decode(&self, data: &[u8]) -> Option<f64> {
+        self.decode_raw(data)
+            .map(|raw| self.raw_to_value(raw) * self.factor + self.offset)
+    }
+
+    /// Raw bits for a physical value, clamped to the signal range
+    pub fn encode_raw(&self, value: f64) -> u64 {
+        let value = if self.max > self.min {
+            value.clamp(self.min, self.max)
+        } else {
+            value
+        };
+        let scaled = if self.factor != 0.0 {
+            (value - self.offset) / self.factor
+        } else {
+            0.0
+        };
+        let mask = if self.length >= 64 {
+            u64::MAX
+        } else {
+            (1u64 << self.length) - 1
+        };
+        match self.value_type {
+            ValueType::Float32 => (scaled as f32).to_bits() as u64,
+            ValueType::Float64 => scaled.to_bits(),
+            ValueType::Unsigned => {
+                // Float to int casts saturate, so only the upper bound needs care
+                (scaled.round().max(0.0) as u64).min(mask)
+            }
+            ValueType::Signed => {
+                let max = (mask >> 1) as i64;
+                let clamped = (scaled.round() as i64).clamp(-max - 1, max);
+                clamped as u64 & mask
+            }
+        }
+    }
+
+    /// Write a physical value into the payload; `false` if it does not fit
+    pub fn encode(&self, value: f64, data: &mut [u8]) -> bool {
+        if self.length == 0 || self.last_byte() >= data.len() {
+            return false;
+        }
+        let raw = self.encode_raw(value);
+        let length = self.length as usize;
+        for (i, pos) in self.bit_positions().enumerate() {
+            let bit_index = match self.byte_order {
+                ByteOrder::LittleEndian => i,
+                ByteOrder::BigEndian => length - 1 - i,
+            };
+            let byte = &mut data[(pos / 8) as usize];
+            let mask = 1u8 << (pos % 8);
+            if (raw >> bit_index) & 1 == 1 {
+                *byte |= mask;
+            } else {
+                *byte &= !mask;
+            }
+        }
+        true
+    }
+
+    /// Description of a physical value from the `VAL_` table, if any
+    pub fn describe(&self, value: f64) -> Option<&str> {
+        let raw = ((value - self.offset) / self.factor).round() as i64;
+        self.value_descriptions
+            .iter()
+            .find(|(v, _)| *v == raw)
+            .map(|(_, d)| d.as_str())
+    }
+}
+
+/// Message definition (`BO_`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DbcMessage {
+    /// CAN identifier without the extended flag
+    pub id: u32,
+    pub is_extended: bool,
+    pub name: String,
+    /// Payload length in bytes
+    pub dlc: u8,
+    /// Transmitting node
+    pub sender: String,
+    pub signals: Vec<DbcSignal>,
+}
+
+impl DbcMessage {
+    /// Signal by name
+    pub fn signal(&self, name: &str) -> Option<&DbcSignal> {
+        self.signals.iter().find(|s| s.name == name)
+    }
+
+    /// Whether a frame carries this message
+    pub fn matches(&self, frame: &CanFrame) -> bool {
+        frame.id == self.id && frame.is_extended == self.is_extended && !frame.is_rtr
+    }
+
+    /// Physical values of the signals present in the payload
+    ///
+    /// Multiplexed signals are only included when the multiplexor selects
+    /// them. Signals extending past the end of a short payload are skipped.
+    pub fn decode(&self, data: &[u8]) -> Vec<(&str, f64)> {
+        let selector = self
+            .signals
+            .iter()
+            .find(|s| s.multiplex == Multiplex::Multiplexor)
+            .and_then(|s| s.decode_raw(data));
+
+        self.signals
+            .iter()
+            .filter(|s| match s.multiplex {
+                Multiplex::Multiplexed(value) => selector == Some(value),
+                _ => true,
+            })
+            .filter_map(|s| s.decode(data).map(|v| (s.name.as_str(), v)))
+            .collect()
+    }
+
+    /// Payload of `dlc` bytes with the given physical signal values
+    ///
+    /// Signals without a value are sent as raw zero. Unknown signal names
+    /// are ignored.
+    pub fn encode(&self, values: &[(&str, f64)]) -> Vec<u8> {
+        let mut data = vec![0u8; self.dlc as usize];
+        for (name, value) in values {
+            if let Some(signal) = self.signal(name) {
+                signal.encode(*value, &mut data);
+            }
+        }
+        data
+    }
+
+    /// CAN frame carrying a payload of this message
+    pub fn to_frame(&self, data: &[u8]) -> CanFrame {
+        let mut frame = if data.len() > CanFrame::MAX_DLC as usize {
+            CanFrame::new_fd(self.id, data, false)
+        } else {
+            CanFrame::new(self.id, data)
+        };
+        if self.is_extended {
+            frame.id = self.id & CanFrame::MAX_EXTENDED_ID;
+            frame.is_extended = true;
+        }
+        frame
+    }
+}
+
+/// Parsed DBC file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DbcDatabase {
+    /// Network nodes (`BU_`)
+    pub nodes: Vec<String>,
+    pub messages: Vec<DbcMessage>,
+}
+
+/// Flag marking extended identifiers in `BO_` lines
+const EXTENDED_ID_FLAG: u32 = 0x8000_0000;
+
+/// Keywords of statements terminated by `;`, which may span several lines
+const TERMINATED_KEYWORDS: &[&str] = &[
+    "CM_",
+    "VAL_",
+    "VAL_TABLE_",
+    "BA_DEF_",
+    "BA_DEF_DEF_",
+    "BA_",
+    "BA_DEF_REL_",
+    "BA_DEF_DEF_REL_",
+    "BA_REL_",
+    "SIG_VALTYPE_",
+    "SIG_GROUP_",
+    "SG_MUL_VAL_",
+    "BO_TX_BU_",
+    "EV_",
+    "ENVVAR_DATA_",
+];
+
+impl DbcDatabase {
+    /// Parse the contents of a DBC file
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut db = DbcDatabase::default();
+        // Index of the message the following SG_ lines belong to
+        let mut current: Option<usize> = None;
+        // Inside the indented symbol list following `NS_ :`
+        let mut in_symbols = false;
+        let mut lines = text.lines().enumerate();
+
+        while let Some((index, line)) = lines.next() {
+            let line_number = index + 1;
+            let trimmed = line.trim();
+            let keyword = trimmed.split_whitespace().next().unwrap_or("");
+
+            if in_symbols && (trimmed.is_empty() || line.starts_with(char::is_whitespace)) {
+                continue;
+            }
+            in_symbols = keyword == "NS_";
+            if in_symbols {
+                continue;
+            }
+
+            if TERMINATED_KEYWORDS.contains(&keyword) {
+                let mut statement = trimmed.to_string();
+                while !is_terminated(&statement) {
+                    match lines.next() {
+                        Some((_, next)) => {
+                            statement.push('\n');
+                            statement.push_str(next);
+                        }
+                        None => {
+                            return Err(parse_error(line_number, "unterminated statement"));
+                        }
+                    }
+                }
+                match keyword {
+                    "VAL_" => db.parse_value_descriptions(&statement, line_number)?,
+                    "SIG_VALTYPE_" => db.parse_value_type(&statement, line_number)?,
+                    _ => {}
+                }
+                continue;
+            }
+
+            match keyword {
+                "BO_" => {
+                    current = db.parse_message(trimmed, line_number)?;
+                }
+                "SG_" => {
+                    let signal = parse_signal(trimmed, line_number)?;
+                    // Signals of skipped pseudo-messages are dropped too
+                    if let Some(message) = current.and_then(|i| db.messages.get_mut(i)) {
+                        message.signals.push(signal);
+                    }
+                }
+                "BU_:" | "BU_" => {
+                    let list = trimmed.split_once(':').map(|(_, l)| l).unwrap_or("");
+                    db.nodes = list.split_whitespace().map(str::to_string).collect();
+                }
+                _ => {
+                    if trimmed.is_empty() {
+                        current = None;
+                    }
+                }
+            }
+        }
+
+        Ok(db)
+    }
+
+    /// Read and parse a DBC file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let text = std::fs::read_to_string(path.as_ref()).map_err(|e| {
+            HorusError::Config(format!(
+                "cannot read DBC file '{}': {}",
+                path.as_ref().display(),
+                e
+            ))
+        })?;
+        Self::parse(&text)
+    }
+
+    /// Message by CAN identifier
+    pub fn message(&self, id: u32, is_extended: bool) -> Option<&DbcMessage> {
+        self.messages
+            .iter()
+            .find(|m| m.id == id && m.is_extended == is_extended)
+    }
+
+    /// Message by name
+    pub fn message_by_name(&self, name: &str) -> Option<&DbcMessage> {
+        self.messages.iter().find(|m| m.name == name)
+    }
+
+    /// Message carried by a frame
+    pub fn message_for_frame(&self, frame: &CanFrame) -> Option<&DbcMessage> {
+        self.messages.iter().find(|m| m.matches(frame))
+    }
+
+    /// `BO_ <id> <name>: <dlc> <sender>`; returns the message index, or
+    /// `None` for pseudo-messages such as `VECTOR__INDEPENDENT_SIG_MSG`
+    fn parse_message(&mut self, line: &str, line_number: usize) -> Result<Option<usize>> {
+        let rest = line["BO_".len()..].trim();
+        let (head, tail) = rest
+            .split_once(':')
+            .ok_or_else(|| parse_error(line_number, "missing ':' in message definition"))?;
+        let mut head = head.split_whitespace();
+        let raw_id = parse_number::<u32>(head.next(), line_number, "message id")?;
+        let name = head
+            .next()
+            .ok_or_else(|| parse_error(line_number, "missing message name"))?;
+        let mut tail = tail.split_whitespace();
+        let dlc = parse_number::<u8>(tail.next(), line_number, "message length")?;
+        let sender = tail.next().unwrap_or("Vector__XXX").to_string();
+
+        let is_extended = raw_id & EXTENDED_ID_FLAG != 0;
+        let id = raw_id & !EXTENDED_ID_FLAG;
+        if id > CanFrame::MAX_EXTENDED_ID || (!is_extended && id > CanFrame::MAX_STANDARD_ID) {
+            return Ok(None);
+        }
+
+        self.messages.push(DbcMessage {
+            id,
+            is_extended,
+            name: name.to_string(),
+            dlc,
+            sender,
+            signals: Vec::new(),
+        });
+        Ok(Some(self.messages.len() - 1))
+    }
+
+    /// `VAL_ <id> <signal> <value> "<description>" ... ;`
+    fn parse_value_descriptions(&mut self, statement: &str, line_number: usize) -> Result<()> {
+        let body = statement["VAL_".len()..].trim().trim_end_matches(';');
+        let mut tokens = Tokens::new(body);
+        let Some(first) = tokens.next_word() else {
+            return Ok(());
+        };
+        // Environment variable tables have no message id
+        let Ok(raw_id) = first.parse::<u32>() else {
+            return Ok(());
+        };
+        let signal_name = tokens
+            .next_word()
+            .ok_or_else(|| parse_error(line_number, "missing signal name in VAL_"))?;
+
+        let mut descriptions = Vec::new();
+        while let Some(value) = tokens.next_word() {
+            let value = value
+                .parse::<f64>()
+                .map_err(|_| parse_error(line_number, "invalid value in VAL_"))?;
+            let description = tokens
+                .next_quoted()
+                .ok_or_else(|| parse_error(line_number, "missing description in VAL_"))?;
+            descriptions.push((value as i64, description.to_string()));
+        }
+
+        if let Some(signal) = self.signal_mut(raw_id, signal_name) {
+            signal.value_descriptions = descriptions;
+        }
+        Ok(())
+    }
+
+    /// `SIG_VALTYPE_ <id> <signal> : <1 = float, 2 = double> ;`
+    fn parse_value_type(&mut self, statement: &str, line_number: usize) -> Result<()> {
+        let body = statement["SIG_VALTYPE_".len()..]
+            .trim()
+            .trim_end_matches(';')
+            .replace(':', " ");
+        let mut parts = body.split_whitespace();
+        let raw_id = parse_number::<u32>(parts.next(), line_number, "message id")?;
+        let signal_name = parts
+            .next()
+            .ok_or_else(|| parse_error(line_number, "missing signal name in SIG_VALTYPE_"))?;
+        let value_type = match parts.next() {
+            Some("1") => ValueType::Float32,
+            Some("2") => ValueType::Float64,
+            _ => return Ok(()),
+        };
+        if let Some(signal) = self.signal_mut(raw_id, signal_name) {
+            signal.value_type = value_type;
+        }
+        Ok(())
+    }
+
+    fn signal_mut(&mut self, raw_id: u32, name: &str) -> Option<&mut DbcSignal> {
+        let is_extended = raw_id & EXTENDED_ID_FLAG != 0;
+        let id = raw_id & !EXTENDED_ID_FLAG;
+        self.messages
+            .iter_mut()
+            .find(|m| m.id == id && m.is_extended == is_extended)
+            .and_then(|m| m.signals.iter_mut().find(|s| s.name == name))
+    }
+}
+
+/// `SG_ <name> [M|m<n>] : <start>|<length>@<order><sign> (<factor>,<offset>) [<min>|<max>] "<unit>" <receivers>`
+fn parse_signal(line: &str, line_number: usize) -> Result<DbcSignal> {
+    let rest = line["SG_".len()..].trim();
+    let (head, body) = rest
+        .split_once(':')
+        .ok_or_else(|| parse_error(line_number, "missing ':' in signal definition"))?;
+    let mut head = head.split_whitespace();
+    let name = head
+        .next()
+        .ok_or_else(|| parse_error(line_number, "missing signal name"))?
+        .to_string();
+    let multiplex = match head.next() {
+        None => Multiplex::None,
+        Some("M") => Multiplex::Multiplexor,
+        Some(token) if token.starts_with('m') => {
+            // Extended multiplexing (`m3M`) is treated as plain `m3`
+            let digits: String = token[1..]
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+            Multiplex::Multiplexed(parse_number(
+                Some(digits.as_str()),
+                line_number,
+                "multiplexer value",
+            )?)
+        }
+        Some(_) => return Err(parse_error(line_number, "invalid multiplexer indicator")),
+    };
+
+    let mut tokens = Tokens::new(body);
+    let layout = tokens
+        .next_word()
+        .ok_or_else(|| parse_error(line_number, "missing signal layout"))?;
+    let (position, format) = layout
+        .split_once('@')
+        .ok_or_else(|| parse_error(line_number, "missing '@' in signal layout"))?;
+    let (start, length) = position
+        .split_once('|')
+        .ok_or_else(|| parse_error(line_number, "missing '|' in signal layout"))?;
+    let start_bit = parse_number::<u32>(Some(start), line_number, "start bit")?;
+    let length = parse_number::<u32>(Some(length), line_number, "signal length")?;
+    if length == 0 || length > 64 {
+        return Err(parse_error(line_number, "signal length must be 1-64 bits"));
+    }
+    let byte_order = match format.chars().next() {
+        Some('0') => ByteOrder::BigEndian,
+        Some('1') => ByteOrder::LittleEndian,
+        _ => return Err(parse_error(line_number, "invalid byte order")),
+    };
+    let value_type = match format.chars().nth(1) {
+        Some('+') => ValueType::Unsigned,
+        Some('-') => ValueType::Signed,
+        _ => return Err(parse_error(line_number, "invalid value sign")),
+    };
+
+    let scaling = tokens
+        .next_delimited('(', ')')
+        .ok_or_else(|| parse_error(line_number, "missing (factor,offset)"))?;
+    let (factor, offset) = parse_pair(scaling, ',', line_number, "factor/offset")?;
+    let range = tokens
+        .next_delimited('[', ']')
+        .ok_or_else(|| parse_error(line_number, "missing [min|max]"))?;
+    let (min, max) = parse_pair(range, '|', line_number, "range")?;
+    let unit = tokens.next_quoted().unwrap_or("").to_string();
+    let receivers = tokens
+        .remainder()
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|r| !r.is_empty())
+        .map(str::to_string)
+        .collect();
+
+    Ok(DbcSignal {
+        name,
+        start_bit,
+        length,
+        byte_order,
+        value_type,
+        factor,
+        offset,
+        min,
+        max,
+        unit,
+        receivers,
+        multiplex,
+        value_descriptions: Vec::new(),
+    })
+}
+
+fn parse_error(line_number: usize, message: &str) -> HorusError {
+    HorusError::ParseError(format!("DBC line {}: {}", line_number, message))
+}
+
+fn parse_number<T: std::str::FromStr>(
+    token: Option<&str>,
+    line_number: usize,
+    what: &str,
+) -> Result<T> {
+    token
+        .and_then(|t| t.trim().parse().ok())
+        .ok_or_else(|| parse_error(line_number, &format!("invalid {}", what)))
+}
+
+fn parse_pair(text: &str, separator: char, line_number: usize, what: &str) -> Result<(f64, f64)> {
+    let (a, b) = text
+        .split_once(separator)
+        .ok_or_else(|| parse_error(line_number, &format!("invalid {}", what)))?;
+    Ok((
+        parse_number(Some(a), line_number, what)?,
+        parse_number(Some(b), line_number, what)?,
+    ))
+}
+
+/// Whether a statement contains a `;` outside of quoted strings
+fn is_terminated(statement: &str) -> bool {
+    let mut quoted = false;
+    let mut escaped = false;
+    for c in statement.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ';' if !quoted => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Minimal tokenizer for the parts of a DBC statement
+struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { rest: text }
+    }
+
+    /// Next whitespace-separated word
+    fn next_word(&mut self) -> Option<&'a str> {
+        let text = self.rest.trim_start();
+        if text.is_empty() || text.starts_with('"') {
+            return None;
+        }
+        let end = text.find(char::is_whitespace).unwrap_or(text.len());
+        self.rest = &text[end..];
+        Some(&text[..end])
+    }
+
+    /// Contents of the next `open ... close` group
+    fn next_delimited(&mut self, open: char, close: char) -> Option<&'a str> {
+        let text = self.rest.trim_start().strip_prefix(open)?;
+        let end = text.find(close)?;
+        self.rest = &text[end + close.len_utf8()..];
+        Some(&text[..end])
+    }
+
+    /// Contents of the next quoted string
+    fn next_quoted(&mut self) -> Option<&'a str> {
+        self.next_delimited('"', '"')
+    }
+
+    fn remainder(&self) -> &'a str {
+        self.rest.trim()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DBC: &str = r#"VERSION ""
+
+NS_ :
+	NS_DESC_
+	CM_
+	BA_DEF_
+
+BS_:
+
+BU_: BMS Motor Robot
+
+BO_ 849 BMS_Status: 8 BMS
+ SG_ PackVoltage : 0|16@1+ (0.01,0) [0|655.35] "V" Robot
+ SG_ PackCurrent : 16|16@1- (0.1,0) [-3276.8|3276.7] "A" Robot
+ SG_ SOC : 32|8@1+ (0.5,0) [0|100] "%" Robot
+ SG_ State : 40|2@1+ (1,0) [0|3] "" Robot
+
+BO_ 2365522164 MotorCmd: 8 Robot
+ SG_ Speed : 7|16@0- (0.1,0) [-1000|1000] "rpm" Motor
+ SG_ Enable : 23|1@0+ (1,0) [0|1] "" Motor
+ SG_ Torque : 32|32@1- (1,0) [0|0] "Nm" Motor
+
+BO_ 1536 Diag: 8 Motor
+ SG_ Page M : 0|8@1+ (1,0) [0|255] "" Robot
+ SG_ Temperature m0 : 8|8@1- (1,-40) [-40|215] "degC" Robot
+ SG_ ErrorCode m1 : 8|16@1+ (1,0) [0|65535] "" Robot
+
+BO_ 3221225472 VECTOR__INDEPENDENT_SIG_MSG: 0 Vector__XXX
+ SG_ Unused : 0|8@1+ (1,0) [0|0] "" Vector__XXX
+
+CM_ SG_ 849 PackVoltage "Pack voltage;
+measured at the contactor";
+BA_DEF_ SG_ "GenSigStartValue" INT 0 10000;
+VAL_ 849 State 0 "Idle" 1 "Charging" 2 "Discharging" 3 "Fault" ;
+SIG_VALTYPE_ 2365522164 Torque : 1;
+"#;
+
+    #[test]
+    fn test_parse_database() {
+        let db = DbcDatabase::parse(DBC).unwrap();
+        assert_eq!(db.nodes, vec!["BMS", "Motor", "Robot"]);
+        assert_eq!(db.messages.len(), 3);
+
+        let bms = db.message(0x351, false).unwrap();
+        assert_eq!(bms.name, "BMS_Status");
+        assert_eq!(bms.sender, "BMS");
+        assert_eq!(bms.signals.len(), 4);
+        let current = bms.signal("PackCurrent").unwrap();
+        assert_eq!(current.value_type, ValueType::Signed);
+        assert_eq!(current.factor, 0.1);
+        assert_eq!(current.unit, "A");
+        assert_eq!(current.receivers, vec!["Robot"]);
+        assert_eq!(bms.signal("State").unwrap().describe(3.0), Some("Fault"));
+
+        let motor = db.message_by_name("MotorCmd").unwrap();
+        assert!(motor.is_extended);
+        assert_eq!(motor.id, 0x0CFF_00F4);
+        assert_eq!(
+            motor.signal("Speed").unwrap().byte_order,
+            ByteOrder::BigEndian
+        );
+        assert_eq!(
+            motor.signal("Torque").unwrap().value_type,
+            ValueType::Float32
+        );
+
+        let diag = db.message_by_name("Diag").unwrap();
+        assert_eq!(diag.signals[0].multiplex, Multiplex::Multiplexor);
+        assert_eq!(diag.signals[2].multiplex, Multiplex::Multiplexed(1));
+
+        assert!(DbcDatabase::parse("BO_ 100 Broken 8 X").is_err());
+        assert!(DbcDatabase::parse("BO_ 100 M: 8 X\n SG_ S : 0|8@2+ (1,0) [0|0] \"\" X").is_err());
+    }
+
+    #[test]
+    fn test_little_endian_decode_encode() {
+        let db = DbcDatabase::parse(DBC).unwrap();
+        let bms = db.message_by_name("BMS_Status").unwrap();
+
+        // 52.00 V, -12.5 A, 87.5 %, state 2
+        let data = [0x50, 0x14, 0x83, 0xFF, 0xAF, 0x02, 0x00, 0x00];
+        let values = bms.decode(&data);
+        assert_eq!(values.len(), 4);
+        assert!((values[0].1 - 52.0).abs() < 1e-9);
+        assert!((values[1].1 + 12.5).abs() < 1e-9);
+        assert_eq!(values[2], ("SOC", 87.5));
+        assert_eq!(values[3], ("State", 2.0));
+
+        let encoded = bms.encode(&[
+            ("PackVoltage", 52.0),
+            ("PackCurrent", -12.5),
+            ("SOC", 87.5),
+            ("State", 2.0),
+        ]);
+        assert_eq!(encoded, data);
+
+        // Out of range values are clamped, short payloads are skipped
+        let clamped = bms.encode(&[("SOC", 150.0)]);
+        assert_eq!(clamped[4], 200);
+        assert_eq!(bms.decode(&data[..4]).len(), 2);
+    }
+
+    #[test]
+    fn test_big_endian_and_float_signals() {
+        let db = DbcDatabase::parse(DBC).unwrap();
+        let motor = db.message_by_name("MotorCmd").unwrap();
+
+        let data = motor.encode(&[("Speed", -150.0), ("Enable", 1.0), ("Torque", 2.5)]);
+        // -1500 = 0xFA24, most significant byte first
+        assert_eq!(&data[..3], &[0xFA, 0x24, 0x80]);
+        assert_eq!(&data[4..8], &2.5f32.to_le_bytes());
+
+        let values = motor.decode(&data);
+        assert_eq!(
+            values,
+            vec![("Speed", -150.0), ("Enable", 1.0), ("Torque", 2.5)]
+        );
+
+        let frame = motor.to_frame(&data);
+        assert!(frame.is_extended);
+        assert_eq!(frame.id, 0x0CFF_00F4);
+        assert!(motor.matches(&frame));
+        assert_eq!(db.message_for_frame(&frame).unwrap().name, "MotorCmd");
+    }
+
+    #[test]
+    fn test_multiplexed_signals() {
+        let db = DbcDatabase::parse(DBC).unwrap();
+        let diag = db.message_by_name("Diag").unwrap();
+
+        let page0 = diag.decode(&[0, 65, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(page0, vec![("Page", 0.0), ("Temperature", 25.0)]);
+
+        let page1 = diag.decode(&[1, 0x34, 0x12, 0, 0, 0, 0, 0]);
+        assert_eq!(page1, vec![("Page", 1.0), ("ErrorCode", 4660.0)]);
+    }
+}
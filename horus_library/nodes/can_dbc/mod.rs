@@ -0,0 +1,980 @@
+use crate::{AnalogIO, BatteryState, CanFrame, CmdVel, MotorCommand};
+use horus_core::config::ProjectConfig;
+use horus_core::error::{HorusError, HorusResult};
+use serde::{Deserialize, Serialize};
+
+pub mod dbc;
+
+pub use dbc::{ByteOrder, DbcDatabase, DbcMessage, DbcSignal, Multiplex, ValueType};
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo, NodeInfoExt};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// HORUS message type a DBC message is mapped to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MappedMessageType {
+    /// `BatteryState` (fields: voltage, current, charge, capacity, percentage,
+    /// temperature, power_supply_status, cell_voltage_<n>)
+    BatteryState,
+    /// `CmdVel` (fields: linear, angular)
+    CmdVel,
+    /// `MotorCommand` (fields: motor_id, mode, target, max_velocity,
+    /// max_acceleration, feed_forward, enable)
+    MotorCommand,
+    /// `AnalogIO` with one channel per signal (see `SignalMapping::signals`)
+    AnalogIo,
+}
+
+impl MappedMessageType {
+    /// Whether a field name exists in this message type
+    pub fn has_field(self, field: &str) -> bool {
+        match self {
+            MappedMessageType::BatteryState => {
+                matches!(
+                    field,
+                    "voltage"
+                        | "current"
+                        | "charge"
+                        | "capacity"
+                        | "percentage"
+                        | "temperature"
+                        | "power_supply_status"
+                ) || cell_index(field).is_some()
+            }
+            MappedMessageType::CmdVel => matches!(field, "linear" | "angular"),
+            MappedMessageType::MotorCommand => matches!(
+                field,
+                "motor_id"
+                    | "mode"
+                    | "target"
+                    | "max_velocity"
+                    | "max_acceleration"
+                    | "feed_forward"
+                    | "enable"
+            ),
+            MappedMessageType::AnalogIo => false,
+        }
+    }
+}
+
+/// Mapping between one DBC message and one HORUS topic
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignalMapping {
+    /// DBC message name
+    pub message: String,
+    /// HORUS topic
+    pub topic: String,
+    /// HORUS message type on the topic
+    #[serde(rename = "type")]
+    pub message_type: MappedMessageType,
+    /// Message field -> DBC signal
+    #[serde(default)]
+    pub fields: BTreeMap<String, String>,
+    /// Signals carried as `AnalogIO` channels, in channel order (all signals when empty)
+    #[serde(default)]
+    pub signals: Vec<String>,
+    /// Fixed signal values sent with every encoded frame
+    #[serde(default)]
+    pub constants: BTreeMap<String, f64>,
+    /// Resend the last encoded frame at this period, for controllers with a command timeout
+    #[serde(default)]
+    pub period_ms: Option<u64>,
+}
+
+/// Configuration of the `CanDbcNode`
+///
+/// Read from the `can_dbc` section of `horus.yaml`:
+///
+/// ```yaml
+/// can_dbc:
+///   dbc: config/robot.dbc   # relative to horus.yaml
+///   interface: can0         # CanBusNode topics can.can0.rx / can.can0.tx
+///   decode:
+///     - message: BMS_Status
+///       topic: battery
+///       type: battery_state
+///       fields:
+///         voltage: PackVoltage
+///         current: PackCurrent
+///         percentage: SOC
+///     - message: Motor_Feedback
+///       topic: motor.feedback
+///       type: analog_io       # all signals as channels
+///   encode:
+///     - message: Motor_Command
+///       topic: cmd_vel
+///       type: cmd_vel
+///       fields:
+///         linear: TargetSpeed
+///         angular: TargetYawRate
+///       constants:
+///         Enable: 1
+///       period_ms: 20
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CanDbcConfig {
+    /// DBC file
+    pub dbc: PathBuf,
+    /// CAN interface of the `CanBusNode` to read and write frames through
+    pub interface: String,
+    /// CAN messages published as HORUS messages
+    pub decode: Vec<SignalMapping>,
+    /// HORUS messages sent as CAN messages
+    pub encode: Vec<SignalMapping>,
+}
+
+impl Default for CanDbcConfig {
+    fn default() -> Self {
+        Self {
+            dbc: PathBuf::new(),
+            interface: "can0".to_string(),
+            decode: Vec::new(),
+            encode: Vec::new(),
+        }
+    }
+}
+
+impl CanDbcConfig {
+    /// Section of `horus.yaml` holding this configuration
+    pub const SECTION: &'static str = "can_dbc";
+
+    /// Read the `can_dbc` section of a project config
+    pub fn from_project_config(project: &ProjectConfig) -> Result<Self> {
+        match project.extra.get(Self::SECTION) {
+            Some(section) => serde_yaml::from_value(section.clone()).map_err(|e| {
+                HorusError::Config(format!("invalid '{}' section: {}", Self::SECTION, e))
+            }),
+            None => Err(HorusError::Config(format!(
+                "missing '{}' section",
+                Self::SECTION
+            ))),
+        }
+    }
+
+    /// Read the `can_dbc` section of a `horus.yaml` file
+    ///
+    /// A relative DBC path is resolved against the directory of the file.
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let project = ProjectConfig::from_file(path.as_ref())?;
+        let mut config = Self::from_project_config(&project)?;
+        if config.dbc.is_relative() {
+            if let Some(dir) = path.as_ref().parent() {
+                config.dbc = dir.join(&config.dbc);
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Index of a `cell_voltage_<n>` field
+fn cell_index(field: &str) -> Option<usize> {
+    field
+        .strip_prefix("cell_voltage_")
+        .and_then(|n| n.parse::<usize>().ok())
+        .filter(|&n| n < 16)
+}
+
+/// Value of a mapped HORUS message
+#[derive(Debug, Clone)]
+enum MappedValue {
+    BatteryState(BatteryState),
+    CmdVel(CmdVel),
+    MotorCommand(MotorCommand),
+    AnalogIo(Box<AnalogIO>),
+}
+
+impl MappedValue {
+    fn new(message_type: MappedMessageType) -> Self {
+        match message_type {
+            MappedMessageType::BatteryState => MappedValue::BatteryState(BatteryState::default()),
+            MappedMessageType::CmdVel => MappedValue::CmdVel(CmdVel::zero()),
+            MappedMessageType::MotorCommand => MappedValue::MotorCommand(MotorCommand::default()),
+            MappedMessageType::AnalogIo => MappedValue::AnalogIo(Box::new(AnalogIO::new(0))),
+        }
+    }
+
+    /// Set a field from a physical signal value
+    fn set(&mut self, field: &str, value: f64) {
+        match self {
+            MappedValue::BatteryState(m) => match field {
+                "voltage" => m.voltage = value as f32,
+                "current" => m.current = value as f32,
+                "charge" => m.charge = value as f32,
+                "capacity" => m.capacity = value as f32,
+                "percentage" => m.percentage = value as f32,
+                "temperature" => m.temperature = value as f32,
+                "power_supply_status" => m.power_supply_status = value as u8,
+                _ => {
+                    if let Some(cell) = cell_index(field) {
+                        m.cell_voltages[cell] = value as f32;
+                        m.cell_count = m.cell_count.max(cell as u8 + 1);
+                    }
+                }
+            },
+            MappedValue::CmdVel(m) => match field {
+                "linear" => m.linear = value as f32,
+                "angular" => m.angular = value as f32,
+                _ => {}
+            },
+            MappedValue::MotorCommand(m) => match field {
+                "motor_id" => m.motor_id = value as u8,
+                "mode" => m.mode = value as u8,
+                "target" => m.target = value,
+                "max_velocity" => m.max_velocity = value,
+                "max_acceleration" => m.max_acceleration = value,
+                "feed_forward" => m.feed_forward = value,
+                "enable" => m.enable = value != 0.0,
+                _ => {}
+            },
+            MappedValue::AnalogIo(m) => {
+                if let Ok(channel) = field.parse::<u8>() {
+                    m.set_channel(channel, value);
+                }
+            }
+        }
+    }
+
+    /// Field as a physical signal value
+    fn get(&self, field: &str) -> Option<f64> {
+        match self {
+            MappedValue::BatteryState(m) => match field {
+                "voltage" => Some(m.voltage as f64),
+                "current" => Some(m.current as f64),
+                "charge" => Some(m.charge as f64),
+                "capacity" => Some(m.capacity as f64),
+                "percentage" => Some(m.percentage as f64),
+                "temperature" => Some(m.temperature as f64),
+                "power_supply_status" => Some(m.power_supply_status as f64),
+                _ => cell_index(field).map(|cell| m.cell_voltages[cell] as f64),
+            },
+            MappedValue::CmdVel(m) => match field {
+                "linear" => Some(m.linear as f64),
+                "angular" => Some(m.angular as f64),
+                _ => None,
+            },
+            MappedValue::MotorCommand(m) => match field {
+                "motor_id" => Some(m.motor_id as f64),
+                "mode" => Some(m.mode as f64),
+                "target" => Some(m.target),
+                "max_velocity" => Some(m.max_velocity),
+                "max_acceleration" => Some(m.max_acceleration),
+                "feed_forward" => Some(m.feed_forward),
+                "enable" => Some(if m.enable { 1.0 } else { 0.0 }),
+                _ => None,
+            },
+            MappedValue::AnalogIo(m) => field.parse::<u8>().ok().and_then(|c| m.get_channel(c)),
+        }
+    }
+
+    fn set_timestamp(&mut self, timestamp: u64) {
+        match self {
+            MappedValue::BatteryState(m) => m.timestamp = timestamp,
+            MappedValue::CmdVel(m) => m.stamp_nanos = timestamp,
+            MappedValue::MotorCommand(m) => m.timestamp = timestamp,
+            MappedValue::AnalogIo(m) => m.timestamp = timestamp,
+        }
+    }
+}
+
+/// Hub for one of the mapped message types
+enum MappedHub {
+    BatteryState(Hub<BatteryState>),
+    CmdVel(Hub<CmdVel>),
+    MotorCommand(Hub<MotorCommand>),
+    AnalogIo(Hub<AnalogIO>),
+}
+
+impl MappedHub {
+    fn new(message_type: MappedMessageType, topic: &str) -> Result<Self> {
+        Ok(match message_type {
+            MappedMessageType::BatteryState => MappedHub::BatteryState(Hub::new(topic)?),
+            MappedMessageType::CmdVel => MappedHub::CmdVel(Hub::new(topic)?),
+            MappedMessageType::MotorCommand => MappedHub::MotorCommand(Hub::new(topic)?),
+            MappedMessageType::AnalogIo => MappedHub::AnalogIo(Hub::new(topic)?),
+        })
+    }
+
+    fn send(&self, value: &MappedValue, ctx: &mut Option<&mut NodeInfo>) {
+        match (self, value) {
+            (MappedHub::BatteryState(hub), MappedValue::BatteryState(m)) => {
+                let _ = hub.send(*m, ctx);
+            }
+            (MappedHub::CmdVel(hub), MappedValue::CmdVel(m)) => {
+                let _ = hub.send(*m, ctx);
+            }
+            (MappedHub::MotorCommand(hub), MappedValue::MotorCommand(m)) => {
+                let _ = hub.send(*m, ctx);
+            }
+            (MappedHub::AnalogIo(hub), MappedValue::AnalogIo(m)) => {
+                let _ = hub.send((**m).clone(), ctx);
+            }
+            _ => {}
+        }
+    }
+
+    fn recv(&self, ctx: &mut Option<&mut NodeInfo>) -> Option<MappedValue> {
+        match self {
+            MappedHub::BatteryState(hub) => hub.recv(ctx).map(MappedValue::BatteryState),
+            MappedHub::CmdVel(hub) => hub.recv(ctx).map(MappedValue::CmdVel),
+            MappedHub::MotorCommand(hub) => hub.recv(ctx).map(MappedValue::MotorCommand),
+            MappedHub::AnalogIo(hub) => hub.recv(ctx).map(|m| MappedValue::AnalogIo(Box::new(m))),
+        }
+    }
+}
+
+/// Published HORUS topic, shared by the decode mappings that target it
+struct DecodeOutput {
+    topic: String,
+    message_type: MappedMessageType,
+    publisher: MappedHub,
+    value: MappedValue,
+}
+
+/// CAN message -> topic
+struct Decoder {
+    message: usize,
+    output: usize,
+    /// (field, signal)
+    bindings: Vec<(String, String)>,
+}
+
+/// Topic -> CAN message
+struct Encoder {
+    message: usize,
+    subscriber: MappedHub,
+    /// (field, signal)
+    bindings: Vec<(String, String)>,
+    constants: Vec<(String, f64)>,
+    period: Option<Duration>,
+    last_frame: Option<CanFrame>,
+    last_sent: Option<Instant>,
+}
+
+/// CAN DBC Node - DBC-based mapping between CAN frames and HORUS messages
+///
+/// Decodes the raw frames of a `CanBusNode` into typed HORUS messages and
+/// encodes HORUS messages into outgoing frames, using the message and signal
+/// definitions of a DBC file. Which DBC messages map to which topics, and
+/// which signal fills which message field, is set in the `can_dbc` section of
+/// `horus.yaml` (see `CanDbcConfig`). This lets commercial motor controllers,
+/// BMS units and other CAN devices be integrated from their DBC file alone.
+///
+/// Several decode mappings may publish to the same topic: each CAN message
+/// then updates its fields of the shared message, e.g. a BMS sending voltage
+/// and state of charge in separate frames yields one `BatteryState`.
+///
+/// # Topics
+/// - Subscribes: `can.<interface>.rx` (`CanFrame`), plus the encode topics
+/// - Publishes: `can.<interface>.tx` (`CanFrame`), plus the decode topics
+///
+/// # Example
+/// ```rust,ignore
+/// use horus_library::nodes::{CanBusNode, CanDbcNode};
+///
+/// let can = CanBusNode::new("can0")?;
+/// let mapper = CanDbcNode::from_config_file("horus.yaml")?;
+/// ```
+pub struct CanDbcNode {
+    // Publishers and Subscribers
+    rx_subscriber: Hub<CanFrame>,
+    tx_publisher: Hub<CanFrame>,
+    outputs: Vec<DecodeOutput>,
+
+    // DBC database and mappings
+    database: DbcDatabase,
+    decoders: Vec<Decoder>,
+    encoders: Vec<Encoder>,
+
+    // Configuration
+    config: CanDbcConfig,
+
+    // State
+    frames_decoded: u64,
+    frames_encoded: u64,
+    unmapped_frames: u64,
+}
+
+impl CanDbcNode {
+    /// Create a node configured from the `can_dbc` section of a `horus.yaml` file
+    pub fn from_config_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::new_with_config(CanDbcConfig::from_file(path)?)
+    }
+
+    /// Create a node from a configuration, loading its DBC file
+    pub fn new_with_config(config: CanDbcConfig) -> Result<Self> {
+        let database = DbcDatabase::from_file(&config.dbc)?;
+        Self::new_with_database(config, database)
+    }
+
+    /// Create a node from a configuration and an already parsed DBC
+    pub fn new_with_database(config: CanDbcConfig, database: DbcDatabase) -> Result<Self> {
+        let rx_topic = format!("can.{}.rx", config.interface);
+        let tx_topic = format!("can.{}.tx", config.interface);
+        Self::new_with_topics(config, database, &rx_topic, &tx_topic)
+    }
+
+    /// Create a node with custom CAN frame topics
+    pub fn new_with_topics(
+        config: CanDbcConfig,
+        database: DbcDatabase,
+        rx_topic: &str,
+        tx_topic: &str,
+    ) -> Result<Self> {
+        let mut outputs: Vec<DecodeOutput> = Vec::new();
+        let mut decoders = Vec::new();
+        for mapping in &config.decode {
+            let (message, bindings) = resolve_mapping(&database, mapping)?;
+
+            let output = match outputs.iter().position(|o| o.topic == mapping.topic) {
+                Some(index) => {
+                    let output = &outputs[index];
+                    if output.message_type != mapping.message_type
+                        || mapping.message_type == MappedMessageType::AnalogIo
+                    {
+                        return Err(HorusError::Config(format!(
+                            "topic '{}' is mapped more than once with incompatible types",
+                            mapping.topic
+                        )));
+                    }
+                    index
+                }
+                None => {
+                    let mut value = MappedValue::new(mapping.message_type);
+                    if let MappedValue::AnalogIo(analog) = &mut value {
+                        analog.channel_count = bindings.len() as u8;
+                        let dbc_message = &database.messages[message];
+                        for (channel, (_, signal)) in bindings.iter().enumerate() {
+                            let unit = dbc_message
+                                .signal(signal)
+                                .map(|s| s.unit.as_str())
+                                .unwrap_or("");
+                            analog.set_channel_info(channel as u8, signal, unit);
+                        }
+                    }
+                    outputs.push(DecodeOutput {
+                        topic: mapping.topic.clone(),
+                        message_type: mapping.message_type,
+                        publisher: MappedHub::new(mapping.message_type, &mapping.topic)?,
+                        value,
+                    });
+                    outputs.len() - 1
+                }
+            };
+
+            decoders.push(Decoder {
+                message,
+                output,
+                bindings,
+            });
+        }
+
+        let mut encoders = Vec::new();
+        for mapping in &config.encode {
+            let (message, bindings) = resolve_mapping(&database, mapping)?;
+            let dbc_message = &database.messages[message];
+            for signal in mapping.constants.keys() {
+                if dbc_message.signal(signal).is_none() {
+                    return Err(HorusError::Config(format!(
+                        "signal '{}' not found in DBC message '{}'",
+                        signal, mapping.message
+                    )));
+                }
+            }
+
+            encoders.push(Encoder {
+                message,
+                subscriber: MappedHub::new(mapping.message_type, &mapping.topic)?,
+                bindings,
+                constants: mapping
+                    .constants
+                    .iter()
+                    .map(|(signal, &value)| (signal.clone(), value))
+                    .collect(),
+                period: mapping.period_ms.map(Duration::from_millis),
+                last_frame: None,
+                last_sent: None,
+            });
+        }
+
+        Ok(Self {
+            rx_subscriber: Hub::new(rx_topic)?,
+            tx_publisher: Hub::new(tx_topic)?,
+            outputs,
+
+            database,
+            decoders,
+            encoders,
+
+            config,
+
+            frames_decoded: 0,
+            frames_encoded: 0,
+            unmapped_frames: 0,
+        })
+    }
+
+    /// Parsed DBC file
+    pub fn get_database(&self) -> &DbcDatabase {
+        &self.database
+    }
+
+    /// Current configuration
+    pub fn get_config(&self) -> &CanDbcConfig {
+        &self.config
+    }
+
+    /// Get statistics (frames decoded, frames encoded, received frames without a mapping)
+    pub fn get_stats(&self) -> (u64, u64, u64) {
+        (
+            self.frames_decoded,
+            self.frames_encoded,
+            self.unmapped_frames,
+        )
+    }
+
+    fn current_time_ns() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as u64
+    }
+
+    /// Update the messages fed by a received frame; returns the indices of the updated outputs
+    fn decode_frame(&mut self, frame: &CanFrame) -> Vec<usize> {
+        let mut updated = Vec::new();
+        if frame.is_rtr || frame.is_error {
+            return updated;
+        }
+        let timestamp = if frame.timestamp > 0 {
+            frame.timestamp
+        } else {
+            Self::current_time_ns()
+        };
+
+        for decoder in &self.decoders {
+            let message = &self.database.messages[decoder.message];
+            if !message.matches(frame) {
+                continue;
+            }
+            let signals = message.decode(frame.data_slice());
+            let output = &mut self.outputs[decoder.output];
+            let mut changed = false;
+            for (field, signal) in &decoder.bindings {
+                if let Some(&(_, value)) = signals.iter().find(|(name, _)| name == signal) {
+                    output.value.set(field, value);
+                    changed = true;
+                }
+            }
+            if changed {
+                output.value.set_timestamp(timestamp);
+                if !updated.contains(&decoder.output) {
+                    updated.push(decoder.output);
+                }
+            }
+        }
+
+        if updated.is_empty() {
+            self.unmapped_frames += 1;
+        } else {
+            self.frames_decoded += 1;
+        }
+        updated
+    }
+
+    /// Frame for a HORUS message of an encode mapping
+    fn encode_value(&self, encoder: &Encoder, value: &MappedValue) -> CanFrame {
+        let mut values: Vec<(&str, f64)> = encoder
+            .constants
+            .iter()
+            .map(|(signal, value)| (signal.as_str(), *value))
+            .collect();
+        for (field, signal) in &encoder.bindings {
+            if let Some(v) = value.get(field) {
+                values.push((signal.as_str(), v));
+            }
+        }
+
+        let message = &self.database.messages[encoder.message];
+        let mut frame = message.to_frame(&message.encode(&values));
+        frame.set_interface(&self.config.interface);
+        frame
+    }
+
+    /// Send commands received on the encode topics, and resend periodic ones
+    fn process_encoders(&mut self, ctx: &mut Option<&mut NodeInfo>) {
+        let now = Instant::now();
+        for i in 0..self.encoders.len() {
+            let mut frame = None;
+            while let Some(value) = self.encoders[i].subscriber.recv(ctx) {
+                frame = Some(self.encode_value(&self.encoders[i], &value));
+            }
+
+            let encoder = &mut self.encoders[i];
+            let frame = match frame {
+                Some(frame) => {
+                    encoder.last_frame = Some(frame);
+                    Some(frame)
+                }
+                None => match (encoder.period, encoder.last_sent, encoder.last_frame) {
+                    (Some(period), Some(sent), Some(mut frame))
+                        if now.duration_since(sent) >= period =>
+                    {
+                        frame.timestamp = Self::current_time_ns();
+                        Some(frame)
+                    }
+                    _ => None,
+                },
+            };
+
+            if let Some(frame) = frame {
+                encoder.last_sent = Some(now);
+                let _ = self.tx_publisher.send(frame, ctx);
+                self.frames_encoded += 1;
+            }
+        }
+    }
+}
+
+/// Index of the DBC message and the (field, signal) bindings of a mapping
+fn resolve_mapping(
+    database: &DbcDatabase,
+    mapping: &SignalMapping,
+) -> Result<(usize, Vec<(String, String)>)> {
+    let index = database
+        .messages
+        .iter()
+        .position(|m| m.name == mapping.message)
+        .ok_or_else(|| {
+            HorusError::Config(format!("DBC message '{}' not found", mapping.message))
+        })?;
+    let message = &database.messages[index];
+
+    let bindings: Vec<(String, String)> = if mapping.message_type == MappedMessageType::AnalogIo {
+        let signals: Vec<&str> = if mapping.signals.is_empty() {
+            message.signals.iter().map(|s| s.name.as_str()).collect()
+        } else {
+            mapping.signals.iter().map(String::as_str).collect()
+        };
+        if signals.len() > 16 {
+            return Err(HorusError::Config(format!(
+                "'{}' maps {} signals, AnalogIO holds at most 16",
+                mapping.message,
+                signals.len()
+            )));
+        }
+        signals
+            .iter()
+            .enumerate()
+            .map(|(channel, signal)| (channel.to_string(), signal.to_string()))
+            .collect()
+    } else {
+        for field in mapping.fields.keys() {
+            if !mapping.message_type.has_field(field) {
+                return Err(HorusError::Config(format!(
+                    "unknown field '{}' for {:?}",
+                    field, mapping.message_type
+                )));
+            }
+        }
+        mapping
+            .fields
+            .iter()
+            .map(|(field, signal)| (field.clone(), signal.clone()))
+            .collect()
+    };
+
+    for (_, signal) in &bindings {
+        if message.signal(signal).is_none() {
+            return Err(HorusError::Config(format!(
+                "signal '{}' not found in DBC message '{}'",
+                signal, mapping.message
+            )));
+        }
+    }
+    Ok((index, bindings))
+}
+
+impl Node for CanDbcNode {
+    fn name(&self) -> &'static str {
+        "CanDbcNode"
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        ctx.log_info(&format!(
+            "CanDbcNode: {} DBC messages, {} decoded and {} encoded on {}",
+            self.database.messages.len(),
+            self.decoders.len(),
+            self.encoders.len(),
+            self.config.interface
+        ));
+        Ok(())
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        while let Some(frame) = self.rx_subscriber.recv(&mut ctx) {
+            let updated = self.decode_frame(&frame);
+            if updated.is_empty() {
+                ctx.log_debug(&format!("CanDbcNode: no mapping for 0x{:X}", frame.id));
+            }
+            for index in updated {
+                let output = &self.outputs[index];
+                output.publisher.send(&output.value, &mut ctx);
+            }
+        }
+
+        self.process_encoders(&mut ctx);
+    }
+}
+
+// Default impl removed - use CanDbcNode::from_config_file() or ::new_with_config() which return HorusResult
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DBC: &str = r#"
+BO_ 849 BMS_Voltage: 4 BMS
+ SG_ PackVoltage : 0|16@1+ (0.01,0) [0|655.35] "V" Robot
+ SG_ PackCurrent : 16|16@1- (0.1,0) [-3276.8|3276.7] "A" Robot
+
+BO_ 853 BMS_Soc: 2 BMS
+ SG_ SOC : 0|8@1+ (1,0) [0|100] "%" Robot
+ SG_ Cell1 : 8|8@1+ (0.02,0) [0|5.1] "V" Robot
+
+BO_ 512 Drive_Cmd: 8 Robot
+ SG_ Speed : 0|16@1- (0.001,0) [-10|10] "m/s" Motor
+ SG_ YawRate : 16|16@1- (0.001,0) [-10|10] "rad/s" Motor
+ SG_ Enable : 32|1@1+ (1,0) [0|1] "" Motor
+
+BO_ 640 Motor_Feedback: 4 Motor
+ SG_ Rpm : 0|16@1- (1,0) [0|0] "rpm" Robot
+ SG_ Temperature : 16|8@1+ (1,-40) [-40|215] "degC" Robot
+"#;
+
+    fn config(yaml: &str) -> CanDbcConfig {
+        let project = ProjectConfig::from_yaml(yaml).unwrap();
+        CanDbcConfig::from_project_config(&project).unwrap()
+    }
+
+    #[test]
+    fn test_config_from_project_yaml() {
+        let config = config(
+            r#"
+name: rover
+can_dbc:
+  dbc: robot.dbc
+  decode:
+    - message: BMS_Voltage
+      topic: battery
+      type: battery_state
+      fields:
+        voltage: PackVoltage
+  encode:
+    - message: Drive_Cmd
+      topic: cmd_vel
+      type: cmd_vel
+      fields:
+        linear: Speed
+      constants:
+        Enable: 1
+      period_ms: 20
+"#,
+        );
+        assert_eq!(config.interface, "can0");
+        assert_eq!(config.dbc, PathBuf::from("robot.dbc"));
+        assert_eq!(
+            config.decode[0].message_type,
+            MappedMessageType::BatteryState
+        );
+        assert_eq!(config.decode[0].fields["voltage"], "PackVoltage");
+        assert_eq!(config.encode[0].constants["Enable"], 1.0);
+        assert_eq!(config.encode[0].period_ms, Some(20));
+
+        let project = ProjectConfig::from_yaml("name: rover").unwrap();
+        assert!(CanDbcConfig::from_project_config(&project).is_err());
+    }
+
+    #[test]
+    fn test_decodes_frames_into_shared_battery_state() {
+        let mut node = CanDbcNode::new_with_topics(
+            config(
+                r#"
+can_dbc:
+  decode:
+    - message: BMS_Voltage
+      topic: battery
+      type: battery_state
+      fields:
+        voltage: PackVoltage
+        current: PackCurrent
+    - message: BMS_Soc
+      topic: battery
+      type: battery_state
+      fields:
+        percentage: SOC
+        cell_voltage_0: Cell1
+"#,
+            ),
+            DbcDatabase::parse(DBC).unwrap(),
+            "test_can_dbc_battery.rx",
+            "test_can_dbc_battery.tx",
+        )
+        .unwrap();
+
+        // 48.00 V, -5.0 A
+        let updated = node.decode_frame(&CanFrame::new(0x351, &[0xC0, 0x12, 0xCE, 0xFF]));
+        assert_eq!(updated, vec![0]);
+        let updated = node.decode_frame(&CanFrame::new(0x355, &[76, 200]));
+        assert_eq!(updated, vec![0]);
+
+        match &node.outputs[0].value {
+            MappedValue::BatteryState(battery) => {
+                assert!((battery.voltage - 48.0).abs() < 1e-4);
+                assert!((battery.current + 5.0).abs() < 1e-4);
+                assert_eq!(battery.percentage, 76.0);
+                assert_eq!(battery.cell_count, 1);
+                assert!((battery.cell_voltages[0] - 4.0).abs() < 1e-4);
+                assert!(battery.timestamp > 0);
+            }
+            other => panic!("unexpected message {:?}", other),
+        }
+
+        // Unknown ids and extended frames with a mapped id are not decoded
+        assert!(node.decode_frame(&CanFrame::new(0x123, &[0; 8])).is_empty());
+        assert!(node
+            .decode_frame(&CanFrame::new_extended(0x351, &[0; 4]))
+            .is_empty());
+        assert_eq!(node.get_stats(), (2, 0, 2));
+    }
+
+    #[test]
+    fn test_analog_io_channels() {
+        let mut node = CanDbcNode::new_with_topics(
+            config(
+                r#"
+can_dbc:
+  decode:
+    - message: Motor_Feedback
+      topic: motor.feedback
+      type: analog_io
+"#,
+            ),
+            DbcDatabase::parse(DBC).unwrap(),
+            "test_can_dbc_analog.rx",
+            "test_can_dbc_analog.tx",
+        )
+        .unwrap();
+
+        node.decode_frame(&CanFrame::new(0x280, &[0x18, 0xFC, 75, 0]));
+        match &node.outputs[0].value {
+            MappedValue::AnalogIo(analog) => {
+                assert_eq!(analog.channel_count, 2);
+                assert_eq!(analog.get_channel(0), Some(-1000.0));
+                assert_eq!(analog.get_channel(1), Some(35.0));
+                assert_eq!(&analog.channel_labels[1][..11], b"Temperature");
+                assert_eq!(&analog.unit_labels[0][..3], b"rpm");
+            }
+            other => panic!("unexpected message {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_encodes_commands_with_constants() {
+        let node = CanDbcNode::new_with_topics(
+            config(
+                r#"
+can_dbc:
+  interface: can1
+  encode:
+    - message: Drive_Cmd
+      topic: cmd_vel
+      type: cmd_vel
+      fields:
+        linear: Speed
+        angular: YawRate
+      constants:
+        Enable: 1
+"#,
+            ),
+            DbcDatabase::parse(DBC).unwrap(),
+            "test_can_dbc_encode.rx",
+            "test_can_dbc_encode.tx",
+        )
+        .unwrap();
+
+        let frame = node.encode_value(
+            &node.encoders[0],
+            &MappedValue::CmdVel(CmdVel::new(0.5, -0.25)),
+        );
+        assert_eq!(frame.id, 0x200);
+        assert_eq!(frame.dlc, 8);
+        // 500 and -250 in mm/s and mrad/s, enable bit set
+        assert_eq!(frame.data_slice(), &[0xF4, 0x01, 0x06, 0xFF, 0x01, 0, 0, 0]);
+        assert_eq!(frame.get_interface(), "can1");
+    }
+
+    #[test]
+    fn test_invalid_mappings_are_rejected() {
+        let mapping = |message: &str, field: &str, signal: &str| {
+            config(&format!(
+                "can_dbc:\n  decode:\n    - message: {}\n      topic: battery\n      type: battery_state\n      fields:\n        {}: {}\n",
+                message, field, signal
+            ))
+        };
+        assert!(CanDbcNode::new_with_topics(
+            mapping("Missing", "voltage", "PackVoltage"),
+            DbcDatabase::parse(DBC).unwrap(),
+            "test_can_dbc_bad_message.rx",
+            "test_can_dbc_bad_message.tx"
+        )
+        .is_err());
+        assert!(CanDbcNode::new_with_topics(
+            mapping("BMS_Voltage", "volts", "PackVoltage"),
+            DbcDatabase::parse(DBC).unwrap(),
+            "test_can_dbc_bad_field.rx",
+            "test_can_dbc_bad_field.tx"
+        )
+        .is_err());
+        assert!(CanDbcNode::new_with_topics(
+            mapping("BMS_Voltage", "voltage", "Missing"),
+            DbcDatabase::parse(DBC).unwrap(),
+            "test_can_dbc_bad_signal.rx",
+            "test_can_dbc_bad_signal.tx"
+        )
+        .is_err());
+        assert!(CanDbcNode::new_with_topics(
+            mapping("BMS_Voltage", "voltage", "PackVoltage"),
+            DbcDatabase::parse(DBC).unwrap(),
+            "test_can_dbc_good.rx",
+            "test_can_dbc_good.tx"
+        )
+        .is_ok());
+
+        // One topic cannot carry two message types
+        let conflicting = config(
+            r#"
+can_dbc:
+  decode:
+    - message: BMS_Voltage
+      topic: shared
+      type: battery_state
+    - message: Motor_Feedback
+      topic: shared
+      type: analog_io
+"#,
+        );
+        assert!(CanDbcNode::new_with_topics(
+            conflicting,
+            DbcDatabase::parse(DBC).unwrap(),
+            "test_can_dbc_conflict.rx",
+            "test_can_dbc_conflict.tx"
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,981 @@
+use crate::drivers::bus::I2cDriver;
+use crate::{BatteryState, CanFrame, CellDiagnostics, ChargeCommand, ChargerStatus};
+use horus_core::error::{HorusError, HorusResult};
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Upper bound on CAN frames drained per tick
+const MAX_FRAMES_PER_TICK: usize = 256;
+
+/// State of charge drop (percentage points) before a completed charge resumes
+const RECHARGE_HYSTERESIS: f32 = 5.0;
+
+// ============================================================================
+// SMBus Smart Battery (SBS 1.1) / Smart Charger
+// ============================================================================
+
+/// Smart Battery Data Specification command codes
+mod sbs {
+    pub const TEMPERATURE: u8 = 0x08; // 0.1 K
+    pub const VOLTAGE: u8 = 0x09; // mV
+    pub const CURRENT: u8 = 0x0A; // mA, signed, positive = charging
+    pub const RELATIVE_STATE_OF_CHARGE: u8 = 0x0D; // %
+    pub const REMAINING_CAPACITY: u8 = 0x0F; // mAh
+    pub const FULL_CHARGE_CAPACITY: u8 = 0x10; // mAh
+    pub const CHARGING_CURRENT: u8 = 0x14; // mA (also the Smart Charger command)
+    pub const CHARGING_VOLTAGE: u8 = 0x15; // mV (also the Smart Charger command)
+    pub const BATTERY_STATUS: u8 = 0x16;
+    pub const CYCLE_COUNT: u8 = 0x17;
+    /// Per-cell voltages (bq20z/bq40z extension), cell 4 down to cell 1
+    pub const CELL_VOLTAGE_4: u8 = 0x3C;
+
+    pub const STATUS_OVER_CHARGED_ALARM: u16 = 0x8000;
+    pub const STATUS_TERMINATE_CHARGE_ALARM: u16 = 0x4000;
+    pub const STATUS_OVER_TEMP_ALARM: u16 = 0x1000;
+    pub const STATUS_FULLY_CHARGED: u16 = 0x0020;
+
+    /// Default SMBus addresses (7-bit)
+    pub const BATTERY_ADDRESS: u16 = 0x0B;
+    pub const CHARGER_ADDRESS: u16 = 0x09;
+}
+
+/// Raw SBS register words read from a smart battery
+#[derive(Debug, Clone, Default)]
+struct SbsWords {
+    temperature: u16,
+    voltage: u16,
+    current: u16,
+    relative_soc: u16,
+    remaining_capacity: u16,
+    full_charge_capacity: u16,
+    charging_current: u16,
+    charging_voltage: u16,
+    battery_status: u16,
+    cycle_count: u16,
+    /// Cell 1 first
+    cell_voltages: Vec<u16>,
+}
+
+fn decode_sbs(words: &SbsWords) -> BmsReading {
+    let status = words.battery_status;
+    BmsReading {
+        voltage: words.voltage as f32 / 1000.0,
+        current: words.current as i16 as f32 / 1000.0,
+        percentage: (words.relative_soc as f32).min(100.0),
+        temperature: words.temperature as f32 / 10.0 - 273.15,
+        remaining_ah: words.remaining_capacity as f32 / 1000.0,
+        full_capacity_ah: words.full_charge_capacity as f32 / 1000.0,
+        cell_voltages: words
+            .cell_voltages
+            .iter()
+            .filter(|mv| **mv > 0)
+            .map(|mv| *mv as f32 / 1000.0)
+            .collect(),
+        cycle_count: words.cycle_count as u32,
+        // The battery broadcasts 0 / 0xFFFF when it wants no charge
+        charge_current_limit: match words.charging_current {
+            0xFFFF => None,
+            ma => Some(ma as f32 / 1000.0),
+        },
+        charge_voltage_limit: match words.charging_voltage {
+            0 | 0xFFFF => None,
+            mv => Some(mv as f32 / 1000.0),
+        },
+        charge_allowed: status & sbs::STATUS_TERMINATE_CHARGE_ALARM == 0,
+        alarm: status & (sbs::STATUS_OVER_CHARGED_ALARM | sbs::STATUS_OVER_TEMP_ALARM) != 0,
+        fully_charged: status & sbs::STATUS_FULLY_CHARGED != 0,
+    }
+}
+
+fn sbs_read_word(bus: &mut I2cDriver, address: u16, command: u8) -> Result<u16> {
+    bus.write_bytes(address, &[command])?;
+    match bus.read_bytes(address, 2)?[..] {
+        [lo, hi, ..] => Ok(u16::from_le_bytes([lo, hi])),
+        _ => Err(HorusError::driver(format!(
+            "Short SMBus read of command 0x{:02X}",
+            command
+        ))),
+    }
+}
+
+fn sbs_write_word(bus: &mut I2cDriver, address: u16, command: u8, value: u16) -> Result<()> {
+    let [lo, hi] = value.to_le_bytes();
+    bus.write_bytes(address, &[command, lo, hi])
+}
+
+// ============================================================================
+// CAN BMS (Pylontech/SMA-style broadcast) / TC-Elcon style charger
+// ============================================================================
+
+/// CAN identifiers of the widely used BMS-to-inverter broadcast protocol
+mod can_bms {
+    /// Charge voltage limit, charge/discharge current limits
+    pub const LIMITS: u32 = 0x351;
+    /// State of charge and health
+    pub const SOC: u32 = 0x355;
+    /// Pack voltage, current and temperature
+    pub const MEASUREMENTS: u32 = 0x356;
+    /// Protection and warning flags
+    pub const ALARMS: u32 = 0x359;
+    /// Charge/discharge request flags
+    pub const REQUEST: u32 = 0x35C;
+
+    pub const REQUEST_CHARGE_ENABLE: u8 = 0x80;
+
+    /// Default charger command ID (29-bit, TC/Elcon charger protocol)
+    pub const CHARGER_COMMAND_ID: u32 = 0x1806_E5F4;
+}
+
+fn le_u16(data: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([data[offset], data[offset + 1]])
+}
+
+/// Merge a CAN BMS frame into `reading`, returning true if it was recognized
+///
+/// With `cell_base_id` set, frames `base + n` carry the voltages of cells
+/// `4n..4n+3` as little-endian millivolts (0 = cell not present).
+fn apply_can_frame(
+    reading: &mut BmsReading,
+    id: u32,
+    data: &[u8],
+    cell_base_id: Option<u32>,
+) -> bool {
+    match id {
+        can_bms::LIMITS if data.len() >= 4 => {
+            reading.charge_voltage_limit = Some(le_u16(data, 0) as f32 / 10.0);
+            reading.charge_current_limit = Some(le_u16(data, 2) as i16 as f32 / 10.0);
+        }
+        can_bms::SOC if data.len() >= 2 => {
+            reading.percentage = (le_u16(data, 0) as f32).min(100.0);
+        }
+        can_bms::MEASUREMENTS if data.len() >= 6 => {
+            reading.voltage = le_u16(data, 0) as i16 as f32 / 100.0;
+            reading.current = le_u16(data, 2) as i16 as f32 / 10.0;
+            reading.temperature = le_u16(data, 4) as i16 as f32 / 10.0;
+        }
+        can_bms::ALARMS if data.len() >= 2 => {
+            // Bytes 0-1 are protection flags, bytes 2-3 warnings
+            reading.alarm = data[0] != 0 || data[1] != 0;
+        }
+        can_bms::REQUEST if !data.is_empty() => {
+            reading.charge_allowed = data[0] & can_bms::REQUEST_CHARGE_ENABLE != 0;
+        }
+        _ => {
+            let Some(frame) = cell_base_id
+                .and_then(|base| id.checked_sub(base))
+                .filter(|n| *n < 4)
+            else {
+                return false;
+            };
+            let first = frame as usize * 4;
+            if reading.cell_voltages.len() < first + 4 {
+                reading.cell_voltages.resize(first + 4, 0.0);
+            }
+            for (i, chunk) in data.chunks_exact(2).take(4).enumerate() {
+                reading.cell_voltages[first + i] =
+                    u16::from_le_bytes([chunk[0], chunk[1]]) as f32 / 1000.0;
+            }
+            // Drop trailing cells that aren't present
+            while reading.cell_voltages.last() == Some(&0.0) {
+                reading.cell_voltages.pop();
+            }
+        }
+    }
+    true
+}
+
+/// Charger command frame: voltage and current in 0.1 units (big-endian), byte 4 = 0 start / 1 stop
+fn charger_frame(id: u32, voltage: f32, current: f32, enable: bool) -> CanFrame {
+    let volts = ((voltage * 10.0).round() as u16).to_be_bytes();
+    let amps = ((current * 10.0).round() as u16).to_be_bytes();
+    let data = [
+        volts[0],
+        volts[1],
+        amps[0],
+        amps[1],
+        if enable { 0 } else { 1 },
+        0,
+        0,
+        0,
+    ];
+    CanFrame::new_extended(id, &data)
+}
+
+// ============================================================================
+// Node
+// ============================================================================
+
+/// Latest BMS measurements, independent of protocol
+#[derive(Debug, Clone)]
+struct BmsReading {
+    voltage: f32,
+    /// Amperes, positive = charging
+    current: f32,
+    percentage: f32,
+    /// Celsius (NaN if unknown)
+    temperature: f32,
+    remaining_ah: f32,
+    full_capacity_ah: f32,
+    cell_voltages: Vec<f32>,
+    cycle_count: u32,
+    /// Charge limits requested by the BMS
+    charge_current_limit: Option<f32>,
+    charge_voltage_limit: Option<f32>,
+    charge_allowed: bool,
+    alarm: bool,
+    fully_charged: bool,
+}
+
+impl Default for BmsReading {
+    fn default() -> Self {
+        Self {
+            voltage: 0.0,
+            current: 0.0,
+            percentage: 0.0,
+            temperature: f32::NAN,
+            remaining_ah: f32::NAN,
+            full_capacity_ah: f32::NAN,
+            cell_voltages: Vec::new(),
+            cycle_count: 0,
+            charge_current_limit: None,
+            charge_voltage_limit: None,
+            charge_allowed: true,
+            alarm: false,
+            fully_charged: false,
+        }
+    }
+}
+
+/// Link to the battery management system
+#[allow(clippy::large_enum_variant)]
+enum BmsLink {
+    /// SMBus Smart Battery with an optional SMBus Smart Charger
+    SmartBattery {
+        bus: I2cDriver,
+        battery_address: u16,
+        charger_address: Option<u16>,
+    },
+    /// CAN BMS through a `CanBusNode`'s topics
+    Can {
+        rx: Hub<CanFrame>,
+        tx: Hub<CanFrame>,
+        cell_base_id: Option<u32>,
+        charger_id: Option<u32>,
+    },
+}
+
+/// Charging Manager Node - BMS and smart charger integration
+///
+/// Reads the battery management system, decides when charging may run, and
+/// drives the charger accordingly. Charging is requested with `ChargeCommand`
+/// messages, typically sent by the docking node once the charge contacts are
+/// made (`start`) and before leaving the dock (`stop`). The node publishes the
+/// charging state so the docking node knows when the pack is full.
+///
+/// # Supported Protocols
+/// - **SMBus Smart Battery** (SBS 1.1) fuel gauges, with charge control through
+///   an SMBus Smart Charger (`ChargingCurrent`/`ChargingVoltage` writes).
+///   Per-cell voltages are read from the bq20z/bq40z extension registers.
+/// - **CAN BMS** speaking the common BMS-to-inverter broadcast (IDs
+///   0x351/0x355/0x356/0x359/0x35C), with charge control through TC/Elcon-style
+///   chargers (extended ID 0x1806E5F4). CAN traffic goes through a
+///   `CanBusNode` (`can.<interface>.rx` / `can.<interface>.tx`).
+///
+/// # Safety
+/// Charging stops and the node enters the fault state on cell over/under
+/// voltage, charge temperature out of range, BMS protection alarms, or loss of
+/// BMS communication. Faults are latched until the next `start` command.
+///
+/// # Topics
+/// - Subscribes: `charger.command` (`ChargeCommand`)
+/// - Publishes: `battery` (`BatteryState`), `battery.cells` (`CellDiagnostics`),
+///   `charger.status` (`ChargerStatus`)
+///
+/// # Example
+/// ```rust,ignore
+/// use horus_library::nodes::ChargingManagerNode;
+///
+/// let mut charging = ChargingManagerNode::can_bms("can0")?;
+/// charging.set_cell_frame_base_id(Some(0x370));
+/// charging.set_max_charge_current(20.0);
+/// charging.set_target_percentage(90.0);
+/// ```
+pub struct ChargingManagerNode {
+    link: BmsLink,
+
+    // Topics
+    command_subscriber: Hub<ChargeCommand>,
+    battery_publisher: Hub<BatteryState>,
+    cells_publisher: Hub<CellDiagnostics>,
+    status_publisher: Hub<ChargerStatus>,
+
+    // Configuration
+    target_percentage: f32,
+    max_charge_current: f32,         // A
+    max_charge_voltage: Option<f32>, // V (None = BMS limit only)
+    cell_under_voltage: f32,         // V
+    cell_over_voltage: f32,          // V
+    min_charge_temperature: f32,     // °C
+    max_charge_temperature: f32,     // °C
+    comms_timeout: Duration,
+    poll_interval: Duration,
+
+    // State
+    reading: BmsReading,
+    state: u8,
+    faults: u16,
+    requested: Option<ChargeCommand>,
+    commanded_current: f32,
+    commanded_voltage: f32,
+    charger_enabled: bool,
+    last_bms_update_ms: Option<u64>,
+    requested_at_ms: u64,
+    last_poll_ms: u64,
+    error_count: u64,
+}
+
+impl ChargingManagerNode {
+    /// Create a charging manager for an SMBus Smart Battery on the given I2C bus
+    pub fn smart_battery(bus: I2cDriver) -> Result<Self> {
+        Self::with_link(BmsLink::SmartBattery {
+            bus,
+            battery_address: sbs::BATTERY_ADDRESS,
+            charger_address: Some(sbs::CHARGER_ADDRESS),
+        })
+    }
+
+    /// Create a charging manager for a CAN BMS on the given interface
+    ///
+    /// Requires a `CanBusNode` for the same interface.
+    pub fn can_bms(interface: &str) -> Result<Self> {
+        Self::with_link(BmsLink::Can {
+            rx: Hub::new(format!("can.{}.rx", interface))?,
+            tx: Hub::new(format!("can.{}.tx", interface))?,
+            cell_base_id: None,
+            charger_id: Some(can_bms::CHARGER_COMMAND_ID),
+        })
+    }
+
+    fn with_link(link: BmsLink) -> Result<Self> {
+        Ok(Self {
+            link,
+            command_subscriber: Hub::new("charger.command")?,
+            battery_publisher: Hub::new("battery")?,
+            cells_publisher: Hub::new("battery.cells")?,
+            status_publisher: Hub::new("charger.status")?,
+            target_percentage: 100.0,
+            max_charge_current: 5.0,
+            max_charge_voltage: None,
+            cell_under_voltage: 2.5,
+            cell_over_voltage: 4.25,
+            min_charge_temperature: 0.0,
+            max_charge_temperature: 45.0,
+            comms_timeout: Duration::from_secs(5),
+            poll_interval: Duration::from_millis(500),
+            reading: BmsReading::default(),
+            state: ChargerStatus::STATE_IDLE,
+            faults: 0,
+            requested: None,
+            commanded_current: 0.0,
+            commanded_voltage: 0.0,
+            charger_enabled: false,
+            last_bms_update_ms: None,
+            requested_at_ms: 0,
+            last_poll_ms: 0,
+            error_count: 0,
+        })
+    }
+
+    /// Set SMBus addresses of the smart battery and charger (None = no charger control)
+    pub fn set_smbus_addresses(&mut self, battery: u16, charger: Option<u16>) {
+        if let BmsLink::SmartBattery {
+            battery_address,
+            charger_address,
+            ..
+        } = &mut self.link
+        {
+            *battery_address = battery;
+            *charger_address = charger;
+        }
+    }
+
+    /// Set the CAN ID of the first cell voltage frame (None = no cell frames)
+    pub fn set_cell_frame_base_id(&mut self, base_id: Option<u32>) {
+        if let BmsLink::Can { cell_base_id, .. } = &mut self.link {
+            *cell_base_id = base_id;
+        }
+    }
+
+    /// Set the CAN ID of charger command frames (None = no charger control)
+    pub fn set_charger_can_id(&mut self, id: Option<u32>) {
+        if let BmsLink::Can { charger_id, .. } = &mut self.link {
+            *charger_id = id;
+        }
+    }
+
+    /// Set the default state of charge at which charging completes (0-100)
+    pub fn set_target_percentage(&mut self, percentage: f32) {
+        self.target_percentage = percentage.clamp(1.0, 100.0);
+    }
+
+    /// Set the maximum charge current in amperes
+    pub fn set_max_charge_current(&mut self, amps: f32) {
+        self.max_charge_current = amps.max(0.0);
+    }
+
+    /// Set the maximum charge voltage in volts (None = use the BMS limit)
+    pub fn set_max_charge_voltage(&mut self, volts: Option<f32>) {
+        self.max_charge_voltage = volts;
+    }
+
+    /// Set the cell voltage window outside of which charging faults
+    pub fn set_cell_voltage_limits(&mut self, under_voltage: f32, over_voltage: f32) {
+        self.cell_under_voltage = under_voltage;
+        self.cell_over_voltage = over_voltage;
+    }
+
+    /// Set the temperature range in which charging is allowed (°C)
+    pub fn set_charge_temperature_range(&mut self, min: f32, max: f32) {
+        self.min_charge_temperature = min;
+        self.max_charge_temperature = max;
+    }
+
+    /// Set how long the BMS may be silent before charging faults
+    pub fn set_comms_timeout(&mut self, timeout: Duration) {
+        self.comms_timeout = timeout;
+    }
+
+    /// Set how often the BMS is polled and the charger refreshed
+    pub fn set_poll_interval(&mut self, interval: Duration) {
+        self.poll_interval = interval;
+    }
+
+    /// Current charging state (`ChargerStatus::STATE_*`)
+    pub fn state(&self) -> u8 {
+        self.state
+    }
+
+    /// Active faults (`ChargerStatus::FAULT_*`)
+    pub fn faults(&self) -> u16 {
+        self.faults
+    }
+
+    fn current_time_ms() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64
+    }
+
+    fn handle_command(&mut self, command: ChargeCommand, now_ms: u64) {
+        if command.command == ChargeCommand::COMMAND_START {
+            // A new start request clears latched faults
+            if self.requested.is_none() || self.state == ChargerStatus::STATE_FAULT {
+                self.requested_at_ms = now_ms;
+                self.state = ChargerStatus::STATE_IDLE;
+                self.faults = 0;
+            }
+            self.requested = Some(command);
+        } else {
+            self.requested = None;
+        }
+    }
+
+    /// Read the BMS, returning true if fresh data arrived
+    fn poll_bms(&mut self, poll_smbus: bool) -> Result<bool> {
+        match &mut self.link {
+            BmsLink::SmartBattery {
+                bus,
+                battery_address,
+                ..
+            } => {
+                if !poll_smbus {
+                    return Ok(false);
+                }
+                let address = *battery_address;
+                let mut read = |command| sbs_read_word(bus, address, command);
+                let mut words = SbsWords {
+                    temperature: read(sbs::TEMPERATURE)?,
+                    voltage: read(sbs::VOLTAGE)?,
+                    current: read(sbs::CURRENT)?,
+                    relative_soc: read(sbs::RELATIVE_STATE_OF_CHARGE)?,
+                    remaining_capacity: read(sbs::REMAINING_CAPACITY)?,
+                    full_charge_capacity: read(sbs::FULL_CHARGE_CAPACITY)?,
+                    charging_current: read(sbs::CHARGING_CURRENT)?,
+                    charging_voltage: read(sbs::CHARGING_VOLTAGE)?,
+                    battery_status: read(sbs::BATTERY_STATUS)?,
+                    cycle_count: read(sbs::CYCLE_COUNT)?,
+                    cell_voltages: Vec::new(),
+                };
+                // Cell registers are optional; gauges without them NACK
+                for cell in 0..4 {
+                    match read(sbs::CELL_VOLTAGE_4 + 3 - cell) {
+                        Ok(mv) if mv != 0xFFFF => words.cell_voltages.push(mv),
+                        _ => break,
+                    }
+                }
+                self.reading = decode_sbs(&words);
+                Ok(true)
+            }
+            BmsLink::Can {
+                rx, cell_base_id, ..
+            } => {
+                let mut updated = false;
+                for _ in 0..MAX_FRAMES_PER_TICK {
+                    let Some(frame) = rx.recv(&mut None) else {
+                        break;
+                    };
+                    let len = (frame.dlc as usize).min(frame.data.len());
+                    updated |= apply_can_frame(
+                        &mut self.reading,
+                        frame.id,
+                        &frame.data[..len],
+                        *cell_base_id,
+                    );
+                }
+                Ok(updated)
+            }
+        }
+    }
+
+    fn evaluate_faults(&self, now_ms: u64) -> u16 {
+        let reading = &self.reading;
+        let mut faults = 0;
+
+        if reading
+            .cell_voltages
+            .iter()
+            .any(|v| *v > self.cell_over_voltage)
+        {
+            faults |= ChargerStatus::FAULT_CELL_OVER_VOLTAGE;
+        }
+        if reading
+            .cell_voltages
+            .iter()
+            .any(|v| *v > 0.0 && *v < self.cell_under_voltage)
+        {
+            faults |= ChargerStatus::FAULT_CELL_UNDER_VOLTAGE;
+        }
+        if reading.temperature > self.max_charge_temperature {
+            faults |= ChargerStatus::FAULT_OVER_TEMPERATURE;
+        }
+        if reading.temperature < self.min_charge_temperature {
+            faults |= ChargerStatus::FAULT_UNDER_TEMPERATURE;
+        }
+        if reading.alarm {
+            faults |= ChargerStatus::FAULT_BMS_ALARM;
+        }
+
+        // Silent BMS: measured from the last update, or from the start request
+        let last_heard = self
+            .last_bms_update_ms
+            .unwrap_or(0)
+            .max(self.requested_at_ms);
+        if now_ms.saturating_sub(last_heard) > self.comms_timeout.as_millis() as u64 {
+            faults |= ChargerStatus::FAULT_COMMS_TIMEOUT;
+        }
+
+        faults
+    }
+
+    /// Advance the charging state machine and compute charger setpoints
+    fn update_state(&mut self, now_ms: u64) {
+        let Some(request) = self.requested else {
+            self.state = ChargerStatus::STATE_IDLE;
+            self.faults = 0;
+            self.commanded_current = 0.0;
+            self.commanded_voltage = 0.0;
+            return;
+        };
+
+        let target = if request.target_percentage > 0.0 {
+            request.target_percentage.min(100.0)
+        } else {
+            self.target_percentage
+        };
+
+        if self.state != ChargerStatus::STATE_FAULT {
+            self.faults = self.evaluate_faults(now_ms);
+        }
+
+        let percentage = self.reading.percentage;
+        self.state = if self.state == ChargerStatus::STATE_FAULT || self.faults != 0 {
+            ChargerStatus::STATE_FAULT
+        } else if self.reading.fully_charged
+            || percentage >= target
+            || (self.state == ChargerStatus::STATE_COMPLETE
+                && percentage > target - RECHARGE_HYSTERESIS)
+        {
+            ChargerStatus::STATE_COMPLETE
+        } else {
+            ChargerStatus::STATE_CHARGING
+        };
+
+        if self.state != ChargerStatus::STATE_CHARGING || !self.reading.charge_allowed {
+            self.commanded_current = 0.0;
+            self.commanded_voltage = 0.0;
+            return;
+        }
+
+        let mut current = self.max_charge_current;
+        if request.max_current > 0.0 {
+            current = current.min(request.max_current);
+        }
+        if let Some(limit) = self.reading.charge_current_limit {
+            current = current.min(limit.max(0.0));
+        }
+        let voltage = match (self.max_charge_voltage, self.reading.charge_voltage_limit) {
+            (Some(configured), Some(limit)) => configured.min(limit),
+            (Some(v), None) | (None, Some(v)) => v,
+            (None, None) => 0.0,
+        };
+
+        // Without a voltage limit the charger can't be driven safely
+        if voltage <= 0.0 {
+            self.commanded_current = 0.0;
+            self.commanded_voltage = 0.0;
+        } else {
+            self.commanded_current = current;
+            self.commanded_voltage = voltage;
+        }
+    }
+
+    /// Send setpoints to the charger (refreshed every poll while charging)
+    fn drive_charger(&mut self) -> Result<()> {
+        let enable = self.commanded_current > 0.0;
+        if !enable && !self.charger_enabled {
+            return Ok(());
+        }
+
+        match &mut self.link {
+            BmsLink::SmartBattery {
+                bus,
+                charger_address: Some(charger),
+                ..
+            } => {
+                let charger = *charger;
+                let (current_ma, voltage_mv) = if enable {
+                    (
+                        (self.commanded_current * 1000.0).round() as u16,
+                        (self.commanded_voltage * 1000.0).round() as u16,
+                    )
+                } else {
+                    (0, 0)
+                };
+                sbs_write_word(bus, charger, sbs::CHARGING_VOLTAGE, voltage_mv)?;
+                sbs_write_word(bus, charger, sbs::CHARGING_CURRENT, current_ma)?;
+            }
+            BmsLink::Can {
+                tx,
+                charger_id: Some(id),
+                ..
+            } => {
+                let frame =
+                    charger_frame(*id, self.commanded_voltage, self.commanded_current, enable);
+                tx.send(frame, &mut None).map_err(|_| {
+                    HorusError::communication("Failed to send charger command frame")
+                })?;
+            }
+            _ => {}
+        }
+
+        self.charger_enabled = enable;
+        Ok(())
+    }
+
+    fn publish(&mut self, ctx: &mut Option<&mut NodeInfo>) {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos() as u64;
+        let reading = &self.reading;
+
+        let mut battery = BatteryState {
+            voltage: reading.voltage,
+            current: reading.current,
+            charge: reading.remaining_ah,
+            capacity: reading.full_capacity_ah,
+            percentage: reading.percentage,
+            power_supply_status: if reading.fully_charged
+                || self.state == ChargerStatus::STATE_COMPLETE
+            {
+                BatteryState::STATUS_FULL
+            } else if reading.current > 0.0 {
+                BatteryState::STATUS_CHARGING
+            } else if reading.current < 0.0 {
+                BatteryState::STATUS_DISCHARGING
+            } else {
+                BatteryState::STATUS_UNKNOWN
+            },
+            temperature: reading.temperature,
+            timestamp,
+            ..Default::default()
+        };
+        let cell_count = reading.cell_voltages.len().min(16);
+        battery.cell_voltages[..cell_count].copy_from_slice(&reading.cell_voltages[..cell_count]);
+        battery.cell_count = cell_count as u8;
+        let _ = self.battery_publisher.send(battery, ctx);
+
+        if cell_count > 0 {
+            let cells = &reading.cell_voltages[..cell_count];
+            let mut diagnostics = CellDiagnostics {
+                cell_count: cell_count as u8,
+                min_cell_voltage: cells.iter().copied().fold(f32::INFINITY, f32::min),
+                max_cell_voltage: cells.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+                cycle_count: reading.cycle_count,
+                timestamp,
+                ..Default::default()
+            };
+            diagnostics.cell_voltages[..cell_count].copy_from_slice(cells);
+            for (i, v) in cells.iter().enumerate() {
+                if *v > self.cell_over_voltage {
+                    diagnostics.over_voltage_mask |= 1 << i;
+                }
+                if *v > 0.0 && *v < self.cell_under_voltage {
+                    diagnostics.under_voltage_mask |= 1 << i;
+                }
+            }
+            let _ = self.cells_publisher.send(diagnostics, ctx);
+        }
+
+        let status = ChargerStatus {
+            state: self.state,
+            faults: self.faults,
+            voltage: reading.voltage,
+            current: reading.current,
+            percentage: reading.percentage,
+            commanded_current: self.commanded_current,
+            commanded_voltage: self.commanded_voltage,
+            target_percentage: self
+                .requested
+                .filter(|r| r.target_percentage > 0.0)
+                .map(|r| r.target_percentage)
+                .unwrap_or(self.target_percentage),
+            timestamp,
+        };
+        let _ = self.status_publisher.send(status, ctx);
+    }
+}
+
+impl Node for ChargingManagerNode {
+    fn name(&self) -> &'static str {
+        "ChargingManagerNode"
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        let protocol = match &mut self.link {
+            BmsLink::SmartBattery { bus, .. } => {
+                bus.init()?;
+                "SMBus smart battery"
+            }
+            BmsLink::Can { .. } => "CAN BMS",
+        };
+        ctx.log_info(&format!(
+            "ChargingManagerNode using {} (max {:.1}A, target {:.0}%)",
+            protocol, self.max_charge_current, self.target_percentage
+        ));
+        Ok(())
+    }
+
+    fn shutdown(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        // Never leave the charger running without supervision
+        self.requested = None;
+        self.update_state(Self::current_time_ms());
+        if let Err(e) = self.drive_charger() {
+            ctx.log_warning(&format!("Failed to stop charger: {}", e));
+        }
+        if let BmsLink::SmartBattery { bus, .. } = &mut self.link {
+            bus.shutdown()?;
+        }
+        ctx.log_info(&format!(
+            "ChargingManagerNode shutting down ({} BMS errors)",
+            self.error_count
+        ));
+        Ok(())
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let now_ms = Self::current_time_ms();
+
+        while let Some(command) = self.command_subscriber.recv(&mut ctx) {
+            self.handle_command(command, now_ms);
+        }
+
+        let poll_due =
+            now_ms.saturating_sub(self.last_poll_ms) >= self.poll_interval.as_millis() as u64;
+        match self.poll_bms(poll_due) {
+            Ok(true) => self.last_bms_update_ms = Some(now_ms),
+            Ok(false) => {}
+            Err(e) => {
+                self.error_count += 1;
+                if let Some(ctx) = ctx.as_deref_mut() {
+                    ctx.log_warning(&format!("BMS read failed: {}", e));
+                }
+            }
+        }
+        if !poll_due {
+            return;
+        }
+        self.last_poll_ms = now_ms;
+
+        let previous_state = self.state;
+        self.update_state(now_ms);
+        if self.state != previous_state {
+            if let Some(ctx) = ctx.as_deref_mut() {
+                if self.state == ChargerStatus::STATE_FAULT {
+                    ctx.log_error(&format!(
+                        "Charging stopped on fault (flags 0x{:04X})",
+                        self.faults
+                    ));
+                } else {
+                    ctx.log_info(&format!(
+                        "Charging state {} -> {}",
+                        previous_state, self.state
+                    ));
+                }
+            }
+        }
+
+        if let Err(e) = self.drive_charger() {
+            self.error_count += 1;
+            if let Some(ctx) = ctx.as_deref_mut() {
+                ctx.log_warning(&format!("Charger command failed: {}", e));
+            }
+        }
+
+        self.publish(&mut ctx);
+    }
+}
+
+// Default impl removed - use ChargingManagerNode::smart_battery() or ::can_bms() which return HorusResult
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_sbs() {
+        let words = SbsWords {
+            temperature: 2981, // 25.0 °C
+            voltage: 16_400,
+            current: (-1500i16) as u16,
+            relative_soc: 87,
+            remaining_capacity: 4350,
+            full_charge_capacity: 5000,
+            charging_current: 2000,
+            charging_voltage: 16_800,
+            battery_status: sbs::STATUS_TERMINATE_CHARGE_ALARM,
+            cycle_count: 12,
+            cell_voltages: vec![4100, 4105, 4098, 4097],
+        };
+        let reading = decode_sbs(&words);
+        assert!((reading.temperature - 24.95).abs() < 0.01);
+        assert_eq!(reading.voltage, 16.4);
+        assert_eq!(reading.current, -1.5);
+        assert_eq!(reading.percentage, 87.0);
+        assert_eq!(reading.charge_current_limit, Some(2.0));
+        assert_eq!(reading.charge_voltage_limit, Some(16.8));
+        assert!(!reading.charge_allowed);
+        assert!(!reading.alarm);
+        assert_eq!(reading.cell_voltages.len(), 4);
+    }
+
+    #[test]
+    fn test_can_frames_and_charger_command() {
+        let mut reading = BmsReading::default();
+        assert!(apply_can_frame(
+            &mut reading,
+            can_bms::MEASUREMENTS,
+            &[0x88, 0x15, 0x64, 0x00, 0xFA, 0x00],
+            None
+        ));
+        assert_eq!(reading.voltage, 55.12);
+        assert_eq!(reading.current, 10.0);
+        assert_eq!(reading.temperature, 25.0);
+
+        assert!(apply_can_frame(
+            &mut reading,
+            can_bms::LIMITS,
+            &[0x2C, 0x02, 0xC8, 0x00],
+            None
+        ));
+        assert_eq!(reading.charge_voltage_limit, Some(55.6));
+        assert_eq!(reading.charge_current_limit, Some(20.0));
+
+        // Cells 4..7 from the second cell frame
+        assert!(apply_can_frame(
+            &mut reading,
+            0x371,
+            &[0x68, 0x10, 0x6A, 0x10, 0x00, 0x00, 0x00, 0x00],
+            Some(0x370)
+        ));
+        assert_eq!(reading.cell_voltages.len(), 6);
+        assert_eq!(reading.cell_voltages[5], 4.202);
+        assert!(!apply_can_frame(&mut reading, 0x123, &[0; 8], Some(0x370)));
+
+        let frame = charger_frame(can_bms::CHARGER_COMMAND_ID, 55.6, 20.0, true);
+        assert!(frame.is_extended);
+        assert_eq!(&frame.data[..5], &[0x02, 0x2C, 0x00, 0xC8, 0x00]);
+    }
+
+    #[test]
+    fn test_charging_state_machine() {
+        let mut node = ChargingManagerNode::can_bms("test_charging_sm").unwrap();
+        node.set_max_charge_current(10.0);
+        node.reading = BmsReading {
+            percentage: 50.0,
+            temperature: 25.0,
+            charge_voltage_limit: Some(55.6),
+            charge_current_limit: Some(20.0),
+            cell_voltages: vec![3.3; 16],
+            ..Default::default()
+        };
+
+        node.update_state(1_000);
+        assert_eq!(node.state(), ChargerStatus::STATE_IDLE);
+
+        node.handle_command(ChargeCommand::start_to(80.0), 1_000);
+        node.last_bms_update_ms = Some(1_000);
+        node.update_state(1_500);
+        assert_eq!(node.state(), ChargerStatus::STATE_CHARGING);
+        assert_eq!(node.commanded_current, 10.0);
+        assert_eq!(node.commanded_voltage, 55.6);
+
+        // Target reached, then resumes only after the hysteresis band
+        node.reading.percentage = 80.0;
+        node.update_state(2_000);
+        assert_eq!(node.state(), ChargerStatus::STATE_COMPLETE);
+        assert_eq!(node.commanded_current, 0.0);
+        node.reading.percentage = 77.0;
+        node.update_state(2_000);
+        assert_eq!(node.state(), ChargerStatus::STATE_COMPLETE);
+        node.reading.percentage = 74.0;
+        node.update_state(2_000);
+        assert_eq!(node.state(), ChargerStatus::STATE_CHARGING);
+
+        // Over-temperature latches a fault until the next start
+        node.reading.temperature = 50.0;
+        node.update_state(2_000);
+        assert_eq!(node.state(), ChargerStatus::STATE_FAULT);
+        assert_eq!(node.faults(), ChargerStatus::FAULT_OVER_TEMPERATURE);
+        node.reading.temperature = 25.0;
+        node.update_state(2_000);
+        assert_eq!(node.state(), ChargerStatus::STATE_FAULT);
+        node.handle_command(ChargeCommand::start_to(80.0), 3_000);
+        node.last_bms_update_ms = Some(3_000);
+        node.update_state(3_000);
+        assert_eq!(node.state(), ChargerStatus::STATE_CHARGING);
+
+        // A silent BMS faults after the timeout
+        node.update_state(3_000 + 6_000);
+        assert_eq!(node.faults(), ChargerStatus::FAULT_COMMS_TIMEOUT);
+
+        node.handle_command(ChargeCommand::stop(), 10_000);
+        node.update_state(10_000);
+        assert_eq!(node.state(), ChargerStatus::STATE_IDLE);
+        assert_eq!(node.faults(), 0);
+    }
+}
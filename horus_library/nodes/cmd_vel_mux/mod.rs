@@ -0,0 +1,450 @@
+use crate::{CmdVel, EmergencyStop};
+use horus_core::error::{HorusError, HorusResult};
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo};
+use std::time::{Duration, Instant};
+
+/// Upper bound on messages drained from one input per tick
+const MAX_DRAIN_PER_TICK: usize = 64;
+
+/// Control period assumed for the first tick
+const DEFAULT_DT: f64 = 0.02;
+
+/// Command source feeding the multiplexer
+struct MuxInput {
+    name: String,
+    subscriber: Hub<CmdVel>,
+    priority: u8,
+    timeout: Duration,
+    latest: Option<(CmdVel, Instant)>,
+}
+
+impl MuxInput {
+    fn is_fresh(&self, now: Instant) -> bool {
+        self.latest
+            .is_some_and(|(_, received)| now.saturating_duration_since(received) <= self.timeout)
+    }
+}
+
+/// Command Velocity Multiplexer Node - Priority arbitration and smoothing of `CmdVel`
+///
+/// Subscribes to several command sources (teleop, autonomy, docking, ...)
+/// and forwards the highest-priority source that has published within its
+/// timeout. When a source goes quiet, control falls back to the next one;
+/// when all are quiet the output ramps down to zero.
+///
+/// The output is clamped to velocity limits and rate-limited by separate
+/// acceleration and deceleration limits, so switching sources never causes
+/// a velocity step. An engaged `EmergencyStop` bypasses smoothing and holds
+/// the output at zero until released.
+///
+/// # Example
+/// ```rust,ignore
+/// use horus_library::nodes::CmdVelMuxNode;
+/// use std::time::Duration;
+///
+/// let mut mux = CmdVelMuxNode::new()?;                    // publishes "cmd_vel"
+/// mux.add_input("teleop", "cmd_vel.teleop", 100, Duration::from_millis(500))?;
+/// mux.add_input("nav", "cmd_vel.nav", 10, Duration::from_millis(300))?;
+/// mux.set_velocity_limits(1.0, 2.0);
+/// mux.set_acceleration_limits(0.8, 1.5, 3.0);
+/// ```
+pub struct CmdVelMuxNode {
+    // Publishers and Subscribers
+    cmd_publisher: Hub<CmdVel>,
+    estop_subscriber: Hub<EmergencyStop>,
+    inputs: Vec<MuxInput>,
+
+    // Limits
+    max_linear: f64,
+    max_angular: f64,
+    max_linear_accel: f64,
+    max_linear_decel: f64,
+    max_angular_accel: f64,
+
+    // Node state
+    output: (f64, f64),
+    active: Option<usize>,
+    estop_engaged: bool,
+    idle: bool,
+    last_tick: Option<Instant>,
+}
+
+impl CmdVelMuxNode {
+    /// Create a multiplexer publishing on "cmd_vel" and watching "emergency_stop"
+    pub fn new() -> Result<Self> {
+        Self::new_with_topics("cmd_vel", "emergency_stop")
+    }
+
+    /// Create a multiplexer with custom output and emergency stop topics
+    pub fn new_with_topics(output_topic: &str, estop_topic: &str) -> Result<Self> {
+        Ok(Self {
+            cmd_publisher: Hub::new(output_topic)?,
+            estop_subscriber: Hub::new(estop_topic)?,
+            inputs: Vec::new(),
+
+            max_linear: 1.0,
+            max_angular: 2.0,
+            max_linear_accel: 1.0,
+            max_linear_decel: 2.0,
+            max_angular_accel: 4.0,
+
+            output: (0.0, 0.0),
+            active: None,
+            estop_engaged: false,
+            idle: true,
+            last_tick: None,
+        })
+    }
+
+    /// Add a command source
+    ///
+    /// Higher `priority` wins; a source is ignored once it has been silent
+    /// for longer than `timeout`. Sources with equal priority are preferred
+    /// in the order they were added.
+    pub fn add_input(
+        &mut self,
+        name: &str,
+        topic: &str,
+        priority: u8,
+        timeout: Duration,
+    ) -> Result<()> {
+        if self.inputs.iter().any(|input| input.name == name) {
+            return Err(HorusError::InvalidInput(format!(
+                "CmdVelMuxNode already has an input named '{}'",
+                name
+            )));
+        }
+        self.inputs.push(MuxInput {
+            name: name.to_string(),
+            subscriber: Hub::new(topic)?,
+            priority,
+            timeout,
+            latest: None,
+        });
+        Ok(())
+    }
+
+    /// Set maximum linear (m/s) and angular (rad/s) output velocity
+    pub fn set_velocity_limits(&mut self, max_linear: f64, max_angular: f64) {
+        self.max_linear = max_linear.abs();
+        self.max_angular = max_angular.abs();
+    }
+
+    /// Set linear acceleration, linear deceleration (m/s²) and angular acceleration (rad/s²)
+    ///
+    /// Use `f64::INFINITY` to disable smoothing on an axis.
+    pub fn set_acceleration_limits(&mut self, linear_accel: f64, linear_decel: f64, angular: f64) {
+        self.max_linear_accel = linear_accel.abs();
+        self.max_linear_decel = linear_decel.abs();
+        self.max_angular_accel = angular.abs();
+    }
+
+    /// Name of the source currently in control
+    pub fn active_input(&self) -> Option<&str> {
+        self.active.map(|i| self.inputs[i].name.as_str())
+    }
+
+    /// Check if an emergency stop is holding the output at zero
+    pub fn is_estopped(&self) -> bool {
+        self.estop_engaged
+    }
+
+    /// Last published (linear, angular) velocity
+    pub fn get_output(&self) -> (f64, f64) {
+        self.output
+    }
+
+    /// Highest-priority source with a fresh command
+    fn select(&self, now: Instant) -> Option<usize> {
+        let mut best: Option<usize> = None;
+        for (i, input) in self.inputs.iter().enumerate() {
+            if input.is_fresh(now) && best.is_none_or(|b| input.priority > self.inputs[b].priority)
+            {
+                best = Some(i);
+            }
+        }
+        best
+    }
+
+    /// Move the output toward `target` within the acceleration limits
+    fn smooth(&self, target: (f64, f64), dt: f64) -> (f64, f64) {
+        let (v, w) = self.output;
+        let (target_v, target_w) = (
+            target.0.clamp(-self.max_linear, self.max_linear),
+            target.1.clamp(-self.max_angular, self.max_angular),
+        );
+
+        // Slowing down (toward zero without crossing it) uses the deceleration limit
+        let slowing = target_v.abs() < v.abs() && target_v * v >= 0.0;
+        let step = |limit: f64| if dt > 0.0 { limit * dt } else { 0.0 };
+        let linear_limit = step(if slowing {
+            self.max_linear_decel
+        } else {
+            self.max_linear_accel
+        });
+        let angular_limit = step(self.max_angular_accel);
+
+        (
+            v + (target_v - v).clamp(-linear_limit, linear_limit),
+            w + (target_w - w).clamp(-angular_limit, angular_limit),
+        )
+    }
+
+    /// Compute the command for this tick, None when there is nothing to publish
+    fn update(&mut self, now: Instant) -> Option<(f64, f64)> {
+        let dt = self
+            .last_tick
+            .map(|last| now.saturating_duration_since(last).as_secs_f64())
+            .unwrap_or(DEFAULT_DT);
+        self.last_tick = Some(now);
+
+        if self.estop_engaged {
+            self.active = None;
+            self.output = (0.0, 0.0);
+            self.idle = false;
+            return Some(self.output);
+        }
+
+        self.active = self.select(now);
+        let target = match self.active {
+            Some(i) => {
+                let (cmd, _) = self.inputs[i].latest.unwrap();
+                (cmd.linear as f64, cmd.angular as f64)
+            }
+            None => (0.0, 0.0),
+        };
+
+        // Once every source is silent and the robot has stopped, publish one
+        // final zero and then stay quiet
+        if self.active.is_none() && self.output == (0.0, 0.0) {
+            if self.idle {
+                return None;
+            }
+            self.idle = true;
+            return Some(self.output);
+        }
+
+        self.idle = false;
+        self.output = self.smooth(target, dt);
+        Some(self.output)
+    }
+}
+
+impl Node for CmdVelMuxNode {
+    fn name(&self) -> &'static str {
+        "CmdVelMuxNode"
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        if self.inputs.is_empty() {
+            ctx.log_warning("CmdVelMuxNode has no inputs - add them with add_input()");
+        }
+        Ok(())
+    }
+
+    fn shutdown(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        ctx.log_info("CmdVelMuxNode shutting down - stopping robot");
+        self.output = (0.0, 0.0);
+        let _ = self.cmd_publisher.send(CmdVel::zero(), &mut None);
+        Ok(())
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let now = Instant::now();
+
+        // Keep only the newest command from each source
+        for input in &mut self.inputs {
+            for _ in 0..MAX_DRAIN_PER_TICK {
+                match input.subscriber.recv(&mut None) {
+                    Some(cmd) => input.latest = Some((cmd, now)),
+                    None => break,
+                }
+            }
+        }
+
+        if let Some(estop) = self.estop_subscriber.recv(&mut None) {
+            if estop.engaged != self.estop_engaged {
+                if let Some(ctx) = ctx.as_mut() {
+                    if estop.engaged {
+                        ctx.log_warning(
+                            "CmdVelMuxNode: emergency stop engaged, output held at zero",
+                        );
+                    } else {
+                        ctx.log_info("CmdVelMuxNode: emergency stop released");
+                    }
+                }
+            }
+            self.estop_engaged = estop.engaged;
+        }
+
+        let previous = self.active;
+        let command = self.update(now);
+
+        if self.active != previous {
+            if let Some(ctx) = ctx.as_mut() {
+                let message = match self.active_input() {
+                    Some(name) => format!("CmdVelMuxNode: '{}' in control", name),
+                    None => "CmdVelMuxNode: no active input".to_string(),
+                };
+                ctx.log_info(&message);
+            }
+        }
+
+        if let Some((linear, angular)) = command {
+            let _ = self
+                .cmd_publisher
+                .send(CmdVel::new(linear as f32, angular as f32), &mut None);
+        }
+    }
+}
+
+// Default impl removed - use CmdVelMuxNode::new() instead which returns HorusResult
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Teleop (priority 100) and nav (priority 10) inputs, no acceleration limits
+    fn with_inputs(mut node: CmdVelMuxNode, teleop: &str, nav: &str) -> CmdVelMuxNode {
+        node.add_input("teleop", teleop, 100, Duration::from_millis(500))
+            .unwrap();
+        node.add_input("nav", nav, 10, Duration::from_millis(500))
+            .unwrap();
+        node.set_acceleration_limits(f64::INFINITY, f64::INFINITY, f64::INFINITY);
+        node
+    }
+
+    fn feed(node: &mut CmdVelMuxNode, input: usize, linear: f32, angular: f32, at: Instant) {
+        node.inputs[input].latest = Some((CmdVel::new(linear, angular), at));
+    }
+
+    #[test]
+    fn test_priority_and_timeout() {
+        let mut node = with_inputs(
+            CmdVelMuxNode::new_with_topics("test_mux_priority.out", "test_mux_priority.estop")
+                .unwrap(),
+            "test_mux_priority.teleop",
+            "test_mux_priority.nav",
+        );
+        assert!(node
+            .add_input("nav", "test_mux_priority.dup", 1, Duration::ZERO)
+            .is_err());
+
+        let t0 = Instant::now();
+        assert_eq!(node.update(t0), None);
+
+        feed(&mut node, 1, 0.5, 0.0, t0);
+        assert_eq!(
+            node.update(t0 + Duration::from_millis(10)),
+            Some((0.5, 0.0))
+        );
+        assert_eq!(node.active_input(), Some("nav"));
+
+        // Teleop takes over while it keeps publishing
+        feed(&mut node, 1, 0.5, 0.0, t0 + Duration::from_millis(100));
+        feed(&mut node, 0, 0.25, 0.5, t0 + Duration::from_millis(100));
+        assert_eq!(
+            node.update(t0 + Duration::from_millis(100)),
+            Some((0.25, 0.5))
+        );
+        assert_eq!(node.active_input(), Some("teleop"));
+
+        // Teleop goes quiet, navigation resumes
+        feed(&mut node, 1, 0.75, 0.0, t0 + Duration::from_millis(550));
+        let t = t0 + Duration::from_millis(700);
+        assert_eq!(node.update(t), Some((0.75, 0.0)));
+        assert_eq!(node.active_input(), Some("nav"));
+
+        // Everything silent: ramp to zero, confirm once, then stay quiet
+        let t = t0 + Duration::from_secs(2);
+        assert_eq!(node.update(t), Some((0.0, 0.0)));
+        assert_eq!(node.update(t + Duration::from_millis(10)), Some((0.0, 0.0)));
+        assert_eq!(node.update(t + Duration::from_millis(20)), None);
+        assert_eq!(node.active_input(), None);
+    }
+
+    #[test]
+    fn test_acceleration_limits() {
+        let mut node = with_inputs(
+            CmdVelMuxNode::new_with_topics("test_mux_smooth.out", "test_mux_smooth.estop").unwrap(),
+            "test_mux_smooth.teleop",
+            "test_mux_smooth.nav",
+        );
+        node.set_velocity_limits(1.0, 1.0);
+        node.set_acceleration_limits(1.0, 2.0, 4.0);
+
+        let t0 = Instant::now();
+        node.last_tick = Some(t0);
+        feed(&mut node, 1, 5.0, -3.0, t0);
+        let (v, w) = node.update(t0 + Duration::from_millis(100)).unwrap();
+        assert!((v - 0.1).abs() < 1e-9);
+        assert!((w + 0.4).abs() < 1e-9);
+
+        // Ramp up to the velocity limit
+        let mut t = t0 + Duration::from_millis(100);
+        for _ in 0..20 {
+            t += Duration::from_millis(100);
+            feed(&mut node, 1, 5.0, -3.0, t);
+            node.update(t);
+        }
+        assert_eq!(node.get_output(), (1.0, -1.0));
+
+        // Braking uses the deceleration limit
+        t += Duration::from_millis(100);
+        feed(&mut node, 1, 0.0, 0.0, t);
+        let (v, _) = node.update(t).unwrap();
+        assert!((v - 0.8).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_estop_overrides_inputs() {
+        let mut node = with_inputs(
+            CmdVelMuxNode::new_with_topics("test_mux_estop.out", "test_mux_estop.estop").unwrap(),
+            "test_mux_estop.teleop",
+            "test_mux_estop.nav",
+        );
+        node.set_acceleration_limits(0.5, 0.5, 0.5);
+        let t0 = Instant::now();
+        node.output = (0.8, 0.2);
+        feed(&mut node, 0, 0.8, 0.2, t0);
+
+        node.estop_engaged = true;
+        assert_eq!(node.update(t0), Some((0.0, 0.0)));
+        assert_eq!(node.active_input(), None);
+        assert!(node.is_estopped());
+
+        // Released: ramps up again from zero
+        node.estop_engaged = false;
+        let (v, _) = node.update(t0 + Duration::from_millis(100)).unwrap();
+        assert!((v - 0.05).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_tick_arbitrates_hub_inputs() {
+        // Teleop override on top of navigation, wired through real topics
+        let mut node = with_inputs(
+            CmdVelMuxNode::new_with_topics("test_mux_hubs.out", "test_mux_hubs.estop").unwrap(),
+            "test_mux_hubs.teleop",
+            "test_mux_hubs.nav",
+        );
+        let output: Hub<CmdVel> = Hub::new("test_mux_hubs.out").unwrap();
+        let teleop: Hub<CmdVel> = Hub::new("test_mux_hubs.teleop").unwrap();
+        let nav: Hub<CmdVel> = Hub::new("test_mux_hubs.nav").unwrap();
+
+        nav.send(CmdVel::new(0.5, 0.0), &mut None).unwrap();
+        node.tick(None);
+        let cmd = output.recv(&mut None).unwrap();
+        assert_eq!((cmd.linear, cmd.angular), (0.5, 0.0));
+        assert_eq!(node.active_input(), Some("nav"));
+
+        nav.send(CmdVel::new(0.5, 0.0), &mut None).unwrap();
+        teleop.send(CmdVel::new(0.25, 0.5), &mut None).unwrap();
+        node.tick(None);
+        let cmd = output.recv(&mut None).unwrap();
+        assert_eq!((cmd.linear, cmd.angular), (0.25, 0.5));
+        assert_eq!(node.active_input(), Some("teleop"));
+    }
+}
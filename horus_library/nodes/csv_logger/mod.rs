@@ -0,0 +1,610 @@
+use horus_core::core::LogSummary;
+use horus_core::error::{HorusError, HorusResult};
+use serde_json::Value;
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo};
+use std::fs::{self, File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Upper bound on messages drained from a topic per tick
+const MAX_DRAIN_PER_TICK: usize = 4096;
+
+type Poller = Box<dyn FnMut() -> Option<Value> + Send>;
+
+struct TopicLog {
+    topic: String,
+    fields: Vec<String>,
+    poll: Poller,
+    writer: Option<BufWriter<File>>,
+    bytes_written: u64,
+    rows_written: u64,
+}
+
+impl TopicLog {
+    /// Path of the active file; rotated files get `.1`, `.2`, ... before the extension
+    ///
+    /// Encoded stems never contain a dot, so a rotated file can't collide
+    /// with the active file of another topic.
+    fn file_path(&self, directory: &Path, index: usize) -> PathBuf {
+        let stem = sanitize_topic(&self.topic);
+        if index == 0 {
+            directory.join(format!("{}.csv", stem))
+        } else {
+            directory.join(format!("{}.{}.csv", stem, index))
+        }
+    }
+}
+
+/// CSV Logger Node - Quick topic capture to rotating CSV files
+///
+/// Subscribes to a configured set of topics and appends one row per message
+/// to `<directory>/<topic>.csv`, prefixed with a wall-clock timestamp. In the
+/// file name `_` is doubled and characters other than letters, digits and `-`
+/// become `_XX` (hex), so `motors.cmd_vel` logs to `motors_2Ecmd__vel.csv`
+/// and distinct topics never share a file. Columns
+/// are selected with dotted field paths (e.g. `pose.x`); with no selection the
+/// first message is flattened and all of its leaf fields become columns.
+///
+/// Files are rotated once they exceed the configured size: `odom.csv` becomes
+/// `odom.1.csv`, `odom.1.csv` becomes `odom.2.csv`, and files beyond the
+/// retention limit are deleted. An existing file whose header doesn't match
+/// the columns is rotated away instead of being appended to.
+///
+/// Intended for hardware bring-up where a spreadsheet-friendly dump is all that
+/// is needed. Use the record/replay subsystem for full-fidelity recordings.
+pub struct CsvLoggerNode {
+    // Logged topics
+    topics: Vec<TopicLog>,
+
+    // Configuration
+    directory: PathBuf,
+    max_file_bytes: u64,
+    max_files: usize,
+    flush_every_tick: bool,
+
+    // State
+    write_errors: u64,
+}
+
+impl CsvLoggerNode {
+    /// Create a new CSV logger writing into "csv_logs"
+    pub fn new() -> Result<Self> {
+        Self::new_with_directory("csv_logs")
+    }
+
+    /// Create a new CSV logger writing into a custom directory
+    pub fn new_with_directory<P: AsRef<Path>>(directory: P) -> Result<Self> {
+        let directory = directory.as_ref().to_path_buf();
+        fs::create_dir_all(&directory).map_err(|e| {
+            HorusError::Config(format!(
+                "Failed to create CSV log directory {}: {}",
+                directory.display(),
+                e
+            ))
+        })?;
+
+        Ok(Self {
+            topics: Vec::new(),
+
+            directory,
+            max_file_bytes: 10 * 1024 * 1024,
+            max_files: 5,
+            flush_every_tick: true,
+
+            write_errors: 0,
+        })
+    }
+
+    /// Log a topic, using every leaf field of the first message as a column
+    pub fn add_topic<T>(&mut self, topic: &str) -> Result<()>
+    where
+        T: Send
+            + Sync
+            + 'static
+            + Clone
+            + std::fmt::Debug
+            + serde::Serialize
+            + serde::de::DeserializeOwned
+            + LogSummary,
+    {
+        self.add_topic_fields::<T>(topic, &[])
+    }
+
+    /// Log a topic, keeping only the given dotted field paths (e.g. `pose.x`)
+    pub fn add_topic_fields<T>(&mut self, topic: &str, fields: &[&str]) -> Result<()>
+    where
+        T: Send
+            + Sync
+            + 'static
+            + Clone
+            + std::fmt::Debug
+            + serde::Serialize
+            + serde::de::DeserializeOwned
+            + LogSummary,
+    {
+        let hub: Hub<T> = Hub::new(topic)?;
+        let poll: Poller = Box::new(move || {
+            hub.recv(&mut None)
+                .and_then(|msg| serde_json::to_value(msg).ok())
+        });
+
+        self.remove_topic(topic);
+        self.topics.push(TopicLog {
+            topic: topic.to_string(),
+            fields: fields.iter().map(|f| f.to_string()).collect(),
+            poll,
+            writer: None,
+            bytes_written: 0,
+            rows_written: 0,
+        });
+        Ok(())
+    }
+
+    /// Stop logging a topic and close its file
+    pub fn remove_topic(&mut self, topic: &str) {
+        if let Some(pos) = self.topics.iter().position(|t| t.topic == topic) {
+            let mut log = self.topics.remove(pos);
+            if let Some(writer) = log.writer.as_mut() {
+                let _ = writer.flush();
+            }
+        }
+    }
+
+    /// Rotate files once they grow past this many bytes (minimum 1 KiB)
+    pub fn set_max_file_bytes(&mut self, bytes: u64) {
+        self.max_file_bytes = bytes.max(1024);
+    }
+
+    /// Number of files kept per topic, including the active one (minimum 1)
+    pub fn set_max_files(&mut self, count: usize) {
+        self.max_files = count.max(1);
+    }
+
+    /// Flush files at the end of every tick (default) or leave it to the OS
+    pub fn set_flush_every_tick(&mut self, enabled: bool) {
+        self.flush_every_tick = enabled;
+    }
+
+    /// Directory the CSV files are written to
+    pub fn get_directory(&self) -> &Path {
+        &self.directory
+    }
+
+    /// Number of rows written for a topic since the node was created
+    pub fn get_rows_written(&self, topic: &str) -> u64 {
+        self.topics
+            .iter()
+            .find(|t| t.topic == topic)
+            .map(|t| t.rows_written)
+            .unwrap_or(0)
+    }
+
+    /// Number of failed file operations
+    pub fn get_write_errors(&self) -> u64 {
+        self.write_errors
+    }
+
+    /// Flush all open files
+    pub fn flush(&mut self) {
+        for log in &mut self.topics {
+            if let Some(writer) = log.writer.as_mut() {
+                if writer.flush().is_err() {
+                    self.write_errors += 1;
+                }
+            }
+        }
+    }
+
+    fn write_row(&mut self, index: usize, value: Value) -> std::io::Result<()> {
+        let directory = self.directory.clone();
+        let max_file_bytes = self.max_file_bytes;
+        let max_files = self.max_files;
+        let log = &mut self.topics[index];
+
+        // Columns are fixed by the first message when no fields were selected
+        if log.fields.is_empty() {
+            let mut columns = Vec::new();
+            flatten_columns(&value, "", &mut columns);
+            log.fields = columns;
+        }
+
+        if log.writer.is_none() {
+            open_active(log, &directory, max_files)?;
+        }
+        // Also covers reopening a file that was already full before a restart
+        if log.bytes_written >= max_file_bytes {
+            if let Some(mut writer) = log.writer.take() {
+                writer.flush()?;
+            }
+            rotate_files(log, &directory, max_files)?;
+            open_active(log, &directory, max_files)?;
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        let row = format_row(timestamp, &value, &log.fields);
+        if let Some(writer) = log.writer.as_mut() {
+            writer.write_all(row.as_bytes())?;
+        }
+        log.bytes_written += row.len() as u64;
+        log.rows_written += 1;
+        Ok(())
+    }
+}
+
+/// Open the active file for appending, writing the header if it is new
+///
+/// Appending keeps earlier rows after a node restart or a write error. A file
+/// written with other columns (e.g. by a differently configured logger) is
+/// rotated first, so no file mixes two layouts.
+fn open_active(log: &mut TopicLog, directory: &Path, max_files: usize) -> std::io::Result<()> {
+    let path = log.file_path(directory, 0);
+    let header = format_header(&log.fields);
+    if read_header(&path)?.is_some_and(|existing| existing != header) {
+        rotate_files(log, directory, max_files)?;
+    }
+
+    let file = OpenOptions::new().append(true).create(true).open(&path)?;
+    let mut writer = BufWriter::new(file);
+    log.bytes_written = writer.get_ref().metadata()?.len();
+    if log.bytes_written == 0 {
+        writer.write_all(header.as_bytes())?;
+        log.bytes_written = header.len() as u64;
+    }
+    log.writer = Some(writer);
+    Ok(())
+}
+
+/// First line of an existing, non-empty file
+fn read_header(path: &Path) -> std::io::Result<Option<String>> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let mut line = String::new();
+    BufReader::new(file).read_line(&mut line)?;
+    Ok(Some(line).filter(|line| !line.is_empty()))
+}
+
+/// Shift `topic.csv` -> `topic.1.csv` -> ... and drop files past the limit
+fn rotate_files(log: &TopicLog, directory: &Path, max_files: usize) -> std::io::Result<()> {
+    if max_files <= 1 {
+        return match fs::remove_file(log.file_path(directory, 0)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        };
+    }
+
+    let oldest = log.file_path(directory, max_files - 1);
+    if oldest.exists() {
+        fs::remove_file(&oldest)?;
+    }
+    for index in (0..max_files - 1).rev() {
+        let from = log.file_path(directory, index);
+        if from.exists() {
+            fs::rename(&from, log.file_path(directory, index + 1))?;
+        }
+    }
+    Ok(())
+}
+
+/// Turn a topic name into a safe file stem, one per topic
+///
+/// Letters, digits and `-` are kept, `_` is doubled and every other byte
+/// becomes `_XX`; hex digits never start with `_`, so the encoding is
+/// reversible. Dots are encoded too, since they separate the rotation index.
+fn sanitize_topic(topic: &str) -> String {
+    let mut stem = String::with_capacity(topic.len());
+    for byte in topic.bytes() {
+        match byte {
+            b'_' => stem.push_str("__"),
+            b'-' => stem.push('-'),
+            _ if byte.is_ascii_alphanumeric() => stem.push(byte as char),
+            _ => stem.push_str(&format!("_{:02X}", byte)),
+        }
+    }
+    stem
+}
+
+/// Collect dotted paths to every non-object leaf of a message
+fn flatten_columns(value: &Value, prefix: &str, columns: &mut Vec<String>) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", prefix, key)
+                };
+                flatten_columns(child, &path, columns);
+            }
+        }
+        _ if prefix.is_empty() => columns.push("value".to_string()),
+        _ => columns.push(prefix.to_string()),
+    }
+}
+
+/// Look up a dotted path; array elements can be addressed by index (`position.0`)
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    if path == "value" && !value.is_object() {
+        return Some(value);
+    }
+    path.split('.')
+        .try_fold(value, |current, key| match current {
+            Value::Object(map) => map.get(key),
+            Value::Array(items) => key.parse::<usize>().ok().and_then(|i| items.get(i)),
+            _ => None,
+        })
+}
+
+/// Quote a cell if it contains separators, quotes or line breaks
+fn escape_cell(cell: &str) -> String {
+    if cell.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", cell.replace('"', "\"\""))
+    } else {
+        cell.to_string()
+    }
+}
+
+fn format_header(fields: &[String]) -> String {
+    let mut header = String::from("timestamp_ms");
+    for field in fields {
+        header.push(',');
+        header.push_str(&escape_cell(field));
+    }
+    header.push('\n');
+    header
+}
+
+fn format_row(timestamp_ms: u64, value: &Value, fields: &[String]) -> String {
+    let mut row = timestamp_ms.to_string();
+    for field in fields {
+        row.push(',');
+        match lookup(value, field) {
+            Some(Value::Null) | None => {}
+            Some(Value::String(s)) => row.push_str(&escape_cell(s)),
+            Some(other) => row.push_str(&escape_cell(&other.to_string())),
+        }
+    }
+    row.push('\n');
+    row
+}
+
+impl Node for CsvLoggerNode {
+    fn name(&self) -> &'static str {
+        "CsvLoggerNode"
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        ctx.log_info(&format!(
+            "CsvLoggerNode logging {} topics to {}",
+            self.topics.len(),
+            self.directory.display()
+        ));
+        Ok(())
+    }
+
+    fn shutdown(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        self.flush();
+        let rows: u64 = self.topics.iter().map(|t| t.rows_written).sum();
+        ctx.log_info(&format!(
+            "CsvLoggerNode shutting down after {} rows ({} write errors)",
+            rows, self.write_errors
+        ));
+        Ok(())
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        for index in 0..self.topics.len() {
+            for _ in 0..MAX_DRAIN_PER_TICK {
+                let Some(value) = (self.topics[index].poll)() else {
+                    break;
+                };
+                if let Err(e) = self.write_row(index, value) {
+                    self.write_errors += 1;
+                    // Reopen on the next message rather than writing to a bad handle
+                    self.topics[index].writer = None;
+                    if let Some(ctx) = ctx.as_deref_mut() {
+                        ctx.log_warning(&format!(
+                            "CSV write failed for topic '{}': {}",
+                            self.topics[index].topic, e
+                        ));
+                    }
+                    break;
+                }
+            }
+        }
+
+        if self.flush_every_tick {
+            self.flush();
+        }
+    }
+}
+
+// Default impl removed - use CsvLoggerNode::new() instead which returns HorusResult
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatten_and_format() {
+        let value = serde_json::json!({
+            "pose": {"x": 1.5, "y": -2.0},
+            "frame_id": "odom, base",
+            "covariance": [0.1, 0.2]
+        });
+
+        let mut columns = Vec::new();
+        flatten_columns(&value, "", &mut columns);
+        assert!(columns.contains(&"pose.x".to_string()));
+        assert!(columns.contains(&"pose.y".to_string()));
+        assert!(columns.contains(&"covariance".to_string()));
+
+        let fields = vec![
+            "pose.x".to_string(),
+            "frame_id".to_string(),
+            "covariance.1".to_string(),
+            "missing".to_string(),
+        ];
+        assert_eq!(
+            format_header(&fields),
+            "timestamp_ms,pose.x,frame_id,covariance.1,missing\n"
+        );
+        assert_eq!(
+            format_row(42, &value, &fields),
+            "42,1.5,\"odom, base\",0.2,\n"
+        );
+    }
+
+    #[test]
+    fn test_scalar_message_and_escaping() {
+        let mut columns = Vec::new();
+        flatten_columns(&serde_json::json!(3.25), "", &mut columns);
+        assert_eq!(columns, vec!["value".to_string()]);
+        assert_eq!(
+            format_row(1, &serde_json::json!(3.25), &columns),
+            "1,3.25\n"
+        );
+
+        assert_eq!(escape_cell("plain"), "plain");
+        assert_eq!(escape_cell("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(sanitize_topic("robot/arm:joints"), "robot_2Farm_3Ajoints");
+        assert_eq!(sanitize_topic("odom.1"), "odom_2E1");
+        assert_eq!(sanitize_topic("motors.cmd_vel"), "motors_2Ecmd__vel");
+    }
+
+    #[test]
+    fn test_sanitize_topic_is_injective() {
+        let topics = ["a.b", "a_b", "a_2Eb", "a/b", "a__b", "a_.b", "a._b", "ä"];
+        let stems: std::collections::HashSet<String> =
+            topics.iter().map(|t| sanitize_topic(t)).collect();
+        assert_eq!(stems.len(), topics.len());
+        assert!(stems.iter().all(|stem| !stem.contains('.')));
+    }
+
+    fn test_directory(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("horus_csv_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    /// Log `values` as topic "odom" without going through a Hub
+    fn push_topic(logger: &mut CsvLoggerNode, values: Vec<Value>) {
+        let mut values = values.into_iter();
+        logger.topics.push(TopicLog {
+            topic: "odom".to_string(),
+            fields: vec!["x".to_string()],
+            poll: Box::new(move || values.next()),
+            writer: None,
+            bytes_written: 0,
+            rows_written: 0,
+        });
+    }
+
+    fn data_rows(path: &Path) -> usize {
+        fs::read_to_string(path)
+            .unwrap()
+            .lines()
+            .filter(|line| !line.starts_with("timestamp_ms"))
+            .count()
+    }
+
+    #[test]
+    fn test_size_based_rotation() {
+        let directory = test_directory("rotation");
+        let mut logger = CsvLoggerNode::new_with_directory(&directory).unwrap();
+        logger.set_max_file_bytes(1024);
+        logger.set_max_files(3);
+        push_topic(
+            &mut logger,
+            (0..200)
+                .map(|i| serde_json::json!({"x": format!("{:040}", i)}))
+                .collect(),
+        );
+        logger.tick(None);
+
+        assert_eq!(logger.get_rows_written("odom"), 200);
+        assert_eq!(logger.get_write_errors(), 0);
+        let active = directory.join("odom.csv");
+        let rotated = directory.join("odom.1.csv");
+        assert!(directory.join("odom.2.csv").exists());
+        assert!(!directory.join("odom.3.csv").exists());
+
+        // Every file starts with the header and stays close to the limit
+        for path in [&active, &rotated] {
+            let contents = fs::read_to_string(path).unwrap();
+            assert!(contents.starts_with("timestamp_ms,x\n"));
+            assert!(contents.len() < 1024 + 64);
+        }
+        // The newest row is in the active file
+        let contents = fs::read_to_string(&active).unwrap();
+        assert!(contents.trim_end().ends_with(&format!("{:040}", 199)));
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn test_reopen_does_not_truncate() {
+        let directory = test_directory("reopen");
+        let path = directory.join("odom.csv");
+        let mut logger = CsvLoggerNode::new_with_directory(&directory).unwrap();
+        push_topic(
+            &mut logger,
+            (0..3).map(|i| serde_json::json!({"x": i})).collect(),
+        );
+        logger.tick(None);
+        assert_eq!(data_rows(&path), 3);
+
+        // Same as after a write error: the handle is dropped and reopened
+        logger.topics[0].writer = None;
+        let mut values = vec![serde_json::json!({"x": 3})].into_iter();
+        logger.topics[0].poll = Box::new(move || values.next());
+        logger.tick(None);
+        assert_eq!(data_rows(&path), 4);
+
+        // A fresh node, as after a restart, appends without a second header
+        let mut restarted = CsvLoggerNode::new_with_directory(&directory).unwrap();
+        push_topic(&mut restarted, vec![serde_json::json!({"x": 4})]);
+        restarted.tick(None);
+        let contents = fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.matches("timestamp_ms").count(), 1);
+        assert_eq!(data_rows(&path), 5);
+        assert!(!directory.join("odom.1.csv").exists());
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn test_reopen_with_other_columns_rotates() {
+        let directory = test_directory("columns");
+        let path = directory.join("odom.csv");
+        let mut logger = CsvLoggerNode::new_with_directory(&directory).unwrap();
+        push_topic(&mut logger, vec![serde_json::json!({"x": 1, "y": 2})]);
+        logger.tick(None);
+
+        // Restarted with another column selection
+        let mut restarted = CsvLoggerNode::new_with_directory(&directory).unwrap();
+        push_topic(&mut restarted, vec![serde_json::json!({"x": 3, "y": 4})]);
+        restarted.topics[0].fields = vec!["y".to_string()];
+        restarted.tick(None);
+
+        assert_eq!(restarted.get_write_errors(), 0);
+        let rotated = fs::read_to_string(directory.join("odom.1.csv")).unwrap();
+        assert!(rotated.starts_with("timestamp_ms,x\n"));
+        let contents = fs::read_to_string(&path).unwrap();
+        assert!(contents.starts_with("timestamp_ms,y\n"));
+        assert!(contents.trim_end().ends_with(",4"));
+        assert_eq!(data_rows(&path), 1);
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+}
@@ -1,8 +1,14 @@
-use crate::{CameraInfo, DepthImage, Image, PointCloud};
-use horus_core::error::HorusResult;
+use crate::drivers::depth_camera::{
+    DepthCameraConfig, DepthCameraDriver, DepthCameraDriverBackend, DepthCameraFrame,
+};
+use crate::drivers::factory::depth_camera_config;
+use crate::{CameraInfo, DepthImage, DistortionModel, Image, PointCloud, PointFieldType};
+use horus_core::driver::{DriversConfig, SingleDriverConfig};
+use horus_core::error::{HorusError, HorusResult};
 
 type Result<T> = HorusResult<T>;
 use horus_core::{Hub, Node, NodeInfo, NodeInfoExt};
+use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 // Processor imports for hybrid pattern
@@ -10,16 +16,6 @@ use crate::nodes::processor::{
     ClosureProcessor, FilterProcessor, PassThrough, Pipeline, Processor,
 };
 
-#[cfg(feature = "realsense")]
-use realsense_rust::{
-    config::Config,
-    context::Context,
-    frame::{ColorFrame, DepthFrame},
-    kind::{Rs2CameraInfo, Rs2Format, Rs2StreamKind},
-    pipeline::InactivePipeline,
-    pipeline::Pipeline,
-};
-
 /// Depth Camera Node
 ///
 /// Interface for 3D vision sensors that capture RGB-D (color + depth) data.
@@ -60,6 +56,13 @@ use realsense_rust::{
 ///     .build()?;
 /// ```
 ///
+/// # Hardware
+///
+/// The RealSense (`realsense` feature) and ZED (`zed` feature) backends read
+/// color and depth from the same capture, so the RGB image, depth image,
+/// camera infos and point cloud of a frame all carry the same timestamp.
+/// A camera that is unplugged is reopened as soon as it comes back.
+///
 /// # Example
 /// ```rust,ignore
 /// use horus_library::nodes::DepthCameraNode;
@@ -80,6 +83,7 @@ where
     depth_publisher: Hub<DepthImage>,
     pointcloud_publisher: Hub<PointCloud>,
     camera_info_publisher: Hub<CameraInfo>,
+    depth_info_publisher: Hub<CameraInfo>,
 
     // Configuration
     camera_model: CameraModel,
@@ -129,12 +133,18 @@ where
     last_frame_time: u64,
     backend: DepthBackend,
 
-    // Hardware drivers
-    #[cfg(feature = "realsense")]
-    pipeline: Option<Pipeline>,
+    // Hardware driver
+    driver: Option<DepthCameraDriver>,
+    read_timeout_ms: u64,
+    max_read_failures: u32,
+    reconnect_interval_ms: u64,
 
-    #[cfg(feature = "realsense")]
-    config: Option<Config>,
+    // Connection state
+    connected: bool,
+    read_failures: u32,
+    connect_failures: u32,
+    last_connect_attempt: u64,
+    disconnects: u64,
 
     // Simulated data (for testing without hardware)
     simulation_mode: bool,
@@ -201,6 +211,7 @@ impl DepthCameraNode {
             depth_publisher: Hub::new("depth_camera.depth.image")?,
             pointcloud_publisher: Hub::new("depth_camera.pointcloud")?,
             camera_info_publisher: Hub::new("depth_camera.camera_info")?,
+            depth_info_publisher: Hub::new("depth_camera.depth.camera_info")?,
             camera_model: model,
             device_serial: String::new(),
             resolution: (640, 480),
@@ -235,10 +246,15 @@ impl DepthCameraNode {
             dropped_frames: 0,
             last_frame_time: 0,
             backend,
-            #[cfg(feature = "realsense")]
-            pipeline: None,
-            #[cfg(feature = "realsense")]
-            config: None,
+            driver: None,
+            read_timeout_ms: 1000,
+            max_read_failures: 3,
+            reconnect_interval_ms: 1000,
+            connected: false,
+            read_failures: 0,
+            connect_failures: 0,
+            last_connect_attempt: 0,
+            disconnects: 0,
             simulation_mode: backend == DepthBackend::Simulation,
             info_counter: 0,
             processor: PassThrough::new(),
@@ -250,16 +266,77 @@ impl DepthCameraNode {
         Ok(node)
     }
 
+    /// Create a node from the `drivers.depth_camera` section of a `horus.yaml` file
+    pub fn from_config_file<P: AsRef<Path>>(model: CameraModel, path: P) -> Result<Self> {
+        let config = DriversConfig::from_file(path)?;
+        Self::new_with_driver_config(model, config.get_driver("depth_camera")?)
+    }
+
+    /// Create a node from a driver config
+    ///
+    /// `backend` is `simulation`, `realsense` or `zed`. Serial number,
+    /// resolution, frame rate and the depth options are read as described
+    /// in [`depth_camera_config`].
+    pub fn new_with_driver_config(model: CameraModel, config: &SingleDriverConfig) -> Result<Self> {
+        let backend = match config.backend.as_str() {
+            "simulation" | "sim" => DepthBackend::Simulation,
+            "realsense" => DepthBackend::RealSense,
+            "zed" => DepthBackend::ZED,
+            other => {
+                return Err(HorusError::config(format!(
+                    "Unknown depth camera backend '{}'. Available: simulation, realsense, zed",
+                    other
+                )))
+            }
+        };
+        let camera = depth_camera_config(config)?;
+        let mut node = Self::new_with_backend(model, backend)?;
+        node.apply_camera_config(&camera);
+        Ok(node)
+    }
+
     /// Create a builder for advanced configuration
     pub fn builder() -> DepthCameraNodeBuilder<PassThrough<DepthImage>> {
         DepthCameraNodeBuilder::new()
     }
+}
 
+impl<P> DepthCameraNode<P>
+where
+    P: Processor<DepthImage>,
+{
     /// Set depth camera backend
     pub fn set_backend(&mut self, backend: DepthBackend) {
         self.backend = backend;
         self.simulation_mode = backend == DepthBackend::Simulation;
         self.is_streaming = false;
+        self.driver = None;
+        self.connected = false;
+    }
+
+    /// Apply serial number, resolutions, frame rate, alignment and depth range
+    /// of a hardware camera configuration (before `init`)
+    pub fn apply_camera_config(&mut self, config: &DepthCameraConfig) {
+        self.device_serial = config.serial.clone();
+        self.set_resolution(config.rgb_resolution.0, config.rgb_resolution.1);
+        self.set_depth_resolution(config.depth_resolution.0, config.depth_resolution.1);
+        self.set_frame_rate(config.fps);
+        self.align_depth_to_color = config.align_depth_to_color;
+        self.depth_range = config.depth_range;
+        self.read_timeout_ms = config.timeout_ms;
+    }
+
+    /// Hardware camera configuration for the current settings
+    pub fn camera_config(&self) -> DepthCameraConfig {
+        DepthCameraConfig {
+            serial: self.device_serial.clone(),
+            rgb_resolution: self.resolution,
+            depth_resolution: self.depth_resolution,
+            fps: self.frame_rate,
+            align_depth_to_color: self.align_depth_to_color,
+            depth_range: self.depth_range,
+            timeout_ms: self.read_timeout_ms,
+        }
     }
 
     /// Apply camera model presets
@@ -459,208 +536,134 @@ impl DepthCameraNode {
         self.simulation_mode = enable;
     }
 
-    /// Initialize hardware camera
-    fn initialize_camera(&mut self, mut ctx: Option<&mut NodeInfo>) -> bool {
-        match self.backend {
-            DepthBackend::Simulation => {
-                // Simulation requires no hardware initialization
-                true
-            }
-            #[cfg(feature = "realsense")]
-            DepthBackend::RealSense => {
-                ctx.log_info("Initializing RealSense camera");
-
-                // Create context
-                let context = match Context::new() {
-                    Ok(ctx) => ctx,
-                    Err(e) => {
-                        ctx.log_error(&format!("Failed to create RealSense context: {:?}", e));
-                        ctx.log_warning("Falling back to simulation mode");
-                        self.backend = DepthBackend::Simulation;
-                        self.simulation_mode = true;
-                        return true;
-                    }
-                };
-
-                // Query devices
-                let devices = match context.query_devices(None) {
-                    Ok(devs) => devs,
-                    Err(e) => {
-                        ctx.log_error(&format!("Failed to query RealSense devices: {:?}", e));
-                        ctx.log_warning("Falling back to simulation mode");
-                        self.backend = DepthBackend::Simulation;
-                        self.simulation_mode = true;
-                        return true;
-                    }
-                };
-
-                if devices.len() == 0 {
-                    ctx.log_error("No RealSense devices found");
-                    ctx.log_warning("Falling back to simulation mode");
-                    self.backend = DepthBackend::Simulation;
-                    self.simulation_mode = true;
-                    return true;
-                }
+    /// Consecutive failed reads after which the camera counts as disconnected
+    pub fn set_max_read_failures(&mut self, failures: u32) {
+        self.max_read_failures = failures.max(1);
+    }
 
-                ctx.log_info(&format!("Found {} RealSense device(s)", devices.len()));
-
-                // Create pipeline
-                let pipeline = match InactivePipeline::try_from(&context) {
-                    Ok(p) => p,
-                    Err(e) => {
-                        ctx.log_error(&format!("Failed to create RealSense pipeline: {:?}", e));
-                        ctx.log_warning("Falling back to simulation mode");
-                        self.backend = DepthBackend::Simulation;
-                        self.simulation_mode = true;
-                        return true;
-                    }
-                };
-
-                // Configure streams
-                let mut config = Config::new();
-
-                if self.enable_rgb {
-                    config
-                        .enable_stream(
-                            Rs2StreamKind::Color,
-                            None,
-                            self.resolution.0 as usize,
-                            self.resolution.1 as usize,
-                            Rs2Format::Rgb8,
-                            self.frame_rate as usize,
-                        )
-                        .ok();
-                }
+    /// Time between attempts to reopen a disconnected camera
+    pub fn set_reconnect_interval(&mut self, interval_ms: u64) {
+        self.reconnect_interval_ms = interval_ms;
+    }
 
-                if self.enable_depth {
-                    config
-                        .enable_stream(
-                            Rs2StreamKind::Depth,
-                            None,
-                            self.depth_resolution.0 as usize,
-                            self.depth_resolution.1 as usize,
-                            Rs2Format::Z16,
-                            self.frame_rate as usize,
-                        )
-                        .ok();
-                }
+    /// Whether the hardware camera is open and delivering frames
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
 
-                // Start pipeline
-                match pipeline.start(Some(config)) {
-                    Ok(active_pipeline) => {
-                        self.pipeline = Some(active_pipeline);
-                        ctx.log_info("RealSense camera initialized successfully");
-                        true
-                    }
-                    Err(e) => {
-                        ctx.log_error(&format!("Failed to start RealSense pipeline: {:?}", e));
-                        ctx.log_warning("Falling back to simulation mode");
-                        self.backend = DepthBackend::Simulation;
-                        self.simulation_mode = true;
-                        true
-                    }
-                }
-            }
-            #[cfg(not(feature = "realsense"))]
-            DepthBackend::RealSense => {
-                ctx.log_warning("RealSense backend requested but realsense feature not enabled");
+    /// Number of times the hardware camera was lost
+    pub fn get_disconnect_count(&self) -> u64 {
+        self.disconnects
+    }
+
+    /// Create the hardware driver for the selected backend and open the camera
+    ///
+    /// Backends whose feature is not enabled fall back to simulation. A
+    /// camera that is not connected yet is opened later by `tick`.
+    fn initialize_camera(&mut self, mut ctx: Option<&mut NodeInfo>) -> bool {
+        let backend: Option<DepthCameraDriverBackend> = match self.backend {
+            #[cfg(feature = "realsense")]
+            DepthBackend::RealSense => Some(DepthCameraDriverBackend::RealSense),
+            #[cfg(feature = "zed")]
+            DepthBackend::ZED => Some(DepthCameraDriverBackend::Zed),
+            #[allow(unreachable_patterns)]
+            _ => None,
+        };
+        let Some(backend) = backend else {
+            if self.backend != DepthBackend::Simulation {
+                ctx.log_warning(&format!(
+                    "{:?} backend requested but its feature is not enabled",
+                    self.backend
+                ));
                 ctx.log_warning("Falling back to simulation mode");
                 self.backend = DepthBackend::Simulation;
                 self.simulation_mode = true;
-                true
             }
-            #[cfg(feature = "zed")]
-            DepthBackend::ZED => {
-                ctx.log_info("Initializing ZED camera");
-
-                // ZED SDK initialization via shared memory interface
-                // The ZED SDK uses its own internal shared memory for high-performance streaming
-                // We interface via the sl::Camera API patterns
-
-                // Check for ZED camera by looking for the device
-                let zed_device_path = "/dev/zed"; // ZED cameras appear as USB devices
-                let zed_by_id = std::path::Path::new("/dev/serial/by-id");
-
-                let mut zed_found = false;
-
-                // Check by-id directory for ZED devices
-                if zed_by_id.exists() {
-                    if let Ok(entries) = std::fs::read_dir(zed_by_id) {
-                        for entry in entries.flatten() {
-                            if let Some(name) = entry.file_name().to_str() {
-                                if name.to_lowercase().contains("stereolabs")
-                                    || name.to_lowercase().contains("zed")
-                                {
-                                    ctx.log_info(&format!("Found ZED device: {}", name));
-                                    zed_found = true;
-                                    break;
-                                }
-                            }
-                        }
-                    }
-                }
-
-                // Also check /dev/video* for ZED (appears as UVC device)
-                for i in 0..10 {
-                    let video_path = format!("/dev/video{}", i);
-                    if std::path::Path::new(&video_path).exists() {
-                        // Check if this is a ZED camera by reading device info
-                        let sys_path =
-                            format!("/sys/class/video4linux/video{}/device/manufacturer", i);
-                        if let Ok(manufacturer) = std::fs::read_to_string(&sys_path) {
-                            if manufacturer.to_lowercase().contains("stereolabs") {
-                                ctx.log_info(&format!("Found ZED camera at /dev/video{}", i));
-                                zed_found = true;
-                                break;
-                            }
-                        }
-                        // Also check product name
-                        let product_path =
-                            format!("/sys/class/video4linux/video{}/device/product", i);
-                        if let Ok(product) = std::fs::read_to_string(&product_path) {
-                            if product.to_lowercase().contains("zed") {
-                                ctx.log_info(&format!("Found ZED camera at /dev/video{}", i));
-                                zed_found = true;
-                                break;
-                            }
-                        }
-                    }
-                }
-
-                if !zed_found {
-                    ctx.log_warning("No ZED camera detected");
-                    ctx.log_warning("Falling back to simulation mode");
-                    self.backend = DepthBackend::Simulation;
-                    self.simulation_mode = true;
-                    return true;
-                }
+            return true;
+        };
 
-                // ZED SDK would be initialized here with proper bindings
-                // For now, we use the UVC interface for basic capture
-                // Full ZED SDK integration requires the proprietary libsl_zed.so
+        match DepthCameraDriver::with_config(backend, self.camera_config()) {
+            Ok(driver) => {
+                self.driver = Some(driver);
+                self.connect(ctx);
+                true
+            }
+            Err(e) => {
+                ctx.log_error(&format!("Failed to create depth camera driver: {}", e));
+                false
+            }
+        }
+    }
 
+    /// Open the hardware camera (again after it was unplugged)
+    fn connect(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        self.last_connect_attempt = now_millis();
+        let Some(driver) = self.driver.as_mut() else {
+            return;
+        };
+        match driver.init() {
+            Ok(()) => {
                 ctx.log_info(&format!(
-                    "ZED camera initialized: {}x{} @ {}fps, depth range {:.2}-{:.2}m",
+                    "{:?} depth camera connected: {}x{} @ {}fps, depth {}",
+                    self.backend,
                     self.resolution.0,
                     self.resolution.1,
                     self.frame_rate,
-                    self.depth_range.0,
-                    self.depth_range.1
+                    if self.align_depth_to_color {
+                        "aligned to color"
+                    } else {
+                        "unaligned"
+                    }
                 ));
+                self.connected = true;
+                self.connect_failures = 0;
+                self.read_failures = 0;
+            }
+            Err(e) => {
+                if self.connect_failures == 0 {
+                    ctx.log_warning(&format!(
+                        "Depth camera not available ({}), retrying every {} ms",
+                        e, self.reconnect_interval_ms
+                    ));
+                }
+                self.connect_failures += 1;
+            }
+        }
+    }
 
-                // Note: Full ZED functionality requires linking against ZED SDK
-                // This implementation provides detection and fallback
-                // For production use, compile with zed-sys crate bindings
+    /// Read the next frame from the hardware camera
+    ///
+    /// After `max_read_failures` failed reads in a row the camera is closed
+    /// and reopened every `reconnect_interval_ms` until it is back.
+    fn read_hardware(&mut self, mut ctx: Option<&mut NodeInfo>) -> Option<DepthCameraFrame> {
+        if !self.connected {
+            if now_millis().saturating_sub(self.last_connect_attempt) < self.reconnect_interval_ms {
+                return None;
+            }
+            self.connect(ctx.as_deref_mut());
+            if !self.connected {
+                return None;
+            }
+        }
 
-                true
+        let driver = self.driver.as_mut()?;
+        match driver.read() {
+            Ok(frame) => {
+                self.read_failures = 0;
+                Some(frame)
             }
-            #[cfg(not(feature = "zed"))]
-            DepthBackend::ZED => {
-                ctx.log_warning("ZED backend requested but zed feature not enabled");
-                ctx.log_warning("Falling back to simulation mode");
-                self.backend = DepthBackend::Simulation;
-                self.simulation_mode = true;
-                true
+            Err(e) => {
+                self.read_failures += 1;
+                self.dropped_frames += 1;
+                if self.read_failures >= self.max_read_failures {
+                    ctx.log_warning(&format!("Depth camera lost ({}), reconnecting", e));
+                    let _ = driver.shutdown();
+                    self.connected = false;
+                    self.read_failures = 0;
+                    self.disconnects += 1;
+                    self.last_connect_attempt = now_millis();
+                }
+                None
             }
         }
     }
@@ -740,42 +743,73 @@ impl DepthCameraNode {
         data
     }
 
-    /// Generate point cloud from depth image
-    fn generate_pointcloud(&self, depth_data: &[u16]) -> PointCloud {
-        let (width, height) = self.depth_resolution;
-        let mut cloud = PointCloud::new();
+    /// Simulated frame using the configured intrinsics
+    fn simulate_frame(&self, timestamp: u64) -> DepthCameraFrame {
+        let mut rgb_info = CameraInfo::new(
+            self.resolution.0,
+            self.resolution.1,
+            self.rgb_fx,
+            self.rgb_fy,
+            self.rgb_cx,
+            self.rgb_cy,
+        )
+        .with_distortion(DistortionModel::PlumbBob, &self.rgb_distortion);
+        let mut depth_info = CameraInfo::new(
+            self.depth_resolution.0,
+            self.depth_resolution.1,
+            self.depth_fx,
+            self.depth_fy,
+            self.depth_cx,
+            self.depth_cy,
+        )
+        .with_distortion(DistortionModel::PlumbBob, &self.depth_distortion);
+        rgb_info.timestamp = timestamp;
+        depth_info.timestamp = timestamp;
+
+        DepthCameraFrame {
+            rgb_data: if self.enable_rgb {
+                self.simulate_rgb_frame()
+            } else {
+                Vec::new()
+            },
+            depth_data: if self.enable_depth {
+                self.simulate_depth_frame()
+            } else {
+                Vec::new()
+            },
+            rgb_resolution: self.resolution,
+            depth_resolution: self.depth_resolution,
+            rgb_info,
+            depth_info,
+            depth_units: self.depth_units,
+            aligned: false,
+            timestamp,
+        }
+    }
 
-        // Set frame ID
-        let frame_bytes = self.pointcloud_frame_id.as_bytes();
-        let len = frame_bytes.len().min(31);
-        cloud.frame_id[..len].copy_from_slice(&frame_bytes[..len]);
-        cloud.frame_id[len] = 0;
+    /// Generate point cloud from depth image
+    fn generate_pointcloud(
+        &self,
+        depth_data: &[u16],
+        info: &CameraInfo,
+        depth_units: f32,
+        frame_id: &str,
+        timestamp: u64,
+    ) -> PointCloud {
+        let (width, height) = (info.width, info.height);
+        let (fx, fy) = info.focal_lengths();
+        let (cx, cy) = info.principal_point();
+        let mut cloud = PointCloud::with_layout(&[
+            ("x", PointFieldType::Float32),
+            ("y", PointFieldType::Float32),
+            ("z", PointFieldType::Float32),
+        ])
+        .expect("xyz layout fits the field table")
+        .with_frame_id(frame_id);
 
         cloud.width = width;
         cloud.height = height;
         cloud.is_dense = false;
-
-        // Set up point cloud fields (XYZ format)
-        cloud.fields[0] = crate::perception::PointField::new(
-            "x",
-            0,
-            crate::perception::PointFieldType::Float32,
-            1,
-        );
-        cloud.fields[1] = crate::perception::PointField::new(
-            "y",
-            4,
-            crate::perception::PointFieldType::Float32,
-            1,
-        );
-        cloud.fields[2] = crate::perception::PointField::new(
-            "z",
-            8,
-            crate::perception::PointFieldType::Float32,
-            1,
-        );
-        cloud.field_count = 3;
-        cloud.point_step = 12; // 3 * 4 bytes
         cloud.row_step = width * cloud.point_step;
 
         // Generate points into binary data
@@ -783,24 +817,19 @@ impl DepthCameraNode {
         for y in 0..height {
             for x in 0..width {
                 let depth_u16 = depth_data[(y * width + x) as usize];
-                let depth_m = depth_u16 as f32 * self.depth_units;
+                let depth_m = depth_u16 as f32 * depth_units;
 
                 // Back-project to 3D using pinhole model
-                let point_x = if depth_m >= self.depth_range.0 && depth_m <= self.depth_range.1 {
-                    ((x as f64 - self.depth_cx) * depth_m as f64 / self.depth_fx) as f32
-                } else {
-                    f32::NAN // Invalid depth
-                };
-                let point_y = if depth_m >= self.depth_range.0 && depth_m <= self.depth_range.1 {
-                    ((y as f64 - self.depth_cy) * depth_m as f64 / self.depth_fy) as f32
-                } else {
-                    f32::NAN
-                };
-                let point_z = if depth_m >= self.depth_range.0 && depth_m <= self.depth_range.1 {
-                    depth_m
-                } else {
-                    f32::NAN
-                };
+                let (point_x, point_y, point_z) =
+                    if depth_m >= self.depth_range.0 && depth_m <= self.depth_range.1 {
+                        (
+                            ((x as f64 - cx) * depth_m as f64 / fx) as f32,
+                            ((y as f64 - cy) * depth_m as f64 / fy) as f32,
+                            depth_m,
+                        )
+                    } else {
+                        (f32::NAN, f32::NAN, f32::NAN) // Invalid depth
+                    };
 
                 // Write as binary (little-endian f32)
                 data.extend_from_slice(&point_x.to_le_bytes());
@@ -810,199 +839,101 @@ impl DepthCameraNode {
         }
 
         cloud.data = data;
-        cloud.timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_nanos() as u64;
+        cloud.timestamp = timestamp;
 
         cloud
     }
 
-    /// Capture RGB frame from hardware
-    #[cfg(feature = "realsense")]
-    fn capture_rgb_hardware(&mut self) -> Option<Vec<u8>> {
-        if let Some(ref mut pipeline) = self.pipeline {
-            if let Ok(frames) = pipeline.wait(Some(std::time::Duration::from_millis(1000))) {
-                if let Some(color_frame) = frames.color_frame() {
-                    let data = color_frame.get_data().to_vec();
-                    return Some(data);
-                }
-            }
-        }
-        None
-    }
-
-    /// Capture depth frame from hardware
-    #[cfg(feature = "realsense")]
-    fn capture_depth_hardware(&mut self) -> Option<Vec<u16>> {
-        if let Some(ref mut pipeline) = self.pipeline {
-            if let Ok(frames) = pipeline.wait(Some(std::time::Duration::from_millis(1000))) {
-                if let Some(depth_frame) = frames.depth_frame() {
-                    // Convert depth data to u16 vector
-                    let data_bytes = depth_frame.get_data();
-                    let mut depth_data = Vec::with_capacity(data_bytes.len() / 2);
-
-                    for chunk in data_bytes.chunks_exact(2) {
-                        let value = u16::from_le_bytes([chunk[0], chunk[1]]);
-                        depth_data.push(value);
-                    }
+    /// Publish RGB image, depth image, point cloud and camera infos of one
+    /// frame, all stamped with the capture time of the frame
+    fn publish_frame(&mut self, frame: DepthCameraFrame, mut ctx: Option<&mut NodeInfo>) {
+        let timestamp = frame.timestamp;
+        // Registered depth is in the color optical frame
+        let depth_frame_id = if frame.aligned {
+            self.rgb_frame_id.clone()
+        } else {
+            self.depth_frame_id.clone()
+        };
 
-                    return Some(depth_data);
-                }
+        if self.enable_rgb && !frame.rgb_data.is_empty() {
+            let (width, height) = frame.rgb_resolution;
+            let image = Image {
+                width,
+                height,
+                encoding: crate::vision::ImageEncoding::Rgb8,
+                step: width * 3,
+                data: frame.rgb_data,
+                frame_id: frame_id_bytes(&self.rgb_frame_id),
+                timestamp,
+            };
+            if let Err(e) = self.rgb_publisher.send(image, &mut None) {
+                ctx.log_error(&format!("Failed to publish RGB image: {:?}", e));
             }
         }
-        None
-    }
 
-    /// Publish RGB image
-    fn publish_rgb(&mut self, mut ctx: Option<&mut NodeInfo>) {
-        if !self.enable_rgb {
-            return;
-        }
-
-        let rgb_data = match self.backend {
-            DepthBackend::Simulation => self.simulate_rgb_frame(),
-            #[cfg(feature = "realsense")]
-            DepthBackend::RealSense => match self.capture_rgb_hardware() {
-                Some(data) => data,
-                None => {
-                    ctx.log_debug("Failed to capture RGB frame from hardware, using simulation");
-                    self.simulate_rgb_frame()
+        if self.enable_depth && !frame.depth_data.is_empty() {
+            if self.enable_pointcloud {
+                let cloud = self.generate_pointcloud(
+                    &frame.depth_data,
+                    &frame.depth_info,
+                    frame.depth_units,
+                    &depth_frame_id,
+                    timestamp,
+                );
+                if let Err(e) = self.pointcloud_publisher.send(cloud, &mut None) {
+                    ctx.log_error(&format!("Failed to publish point cloud: {:?}", e));
                 }
-            },
-            #[cfg(not(feature = "realsense"))]
-            DepthBackend::RealSense => self.simulate_rgb_frame(),
-            _ => self.simulate_rgb_frame(),
-        };
-
-        let mut image = Image {
-            width: self.resolution.0,
-            height: self.resolution.1,
-            encoding: crate::vision::ImageEncoding::Rgb8,
-            step: self.resolution.0 * 3,
-            data: rgb_data,
-            frame_id: [0; 32],
-            timestamp: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_nanos() as u64,
-        };
-
-        // Set frame ID
-        let frame_bytes = self.rgb_frame_id.as_bytes();
-        let len = frame_bytes.len().min(31);
-        image.frame_id[..len].copy_from_slice(&frame_bytes[..len]);
-        image.frame_id[len] = 0;
-
-        if let Err(e) = self.rgb_publisher.send(image, &mut None) {
-            ctx.log_error(&format!("Failed to publish RGB image: {:?}", e));
-        }
-    }
-
-    /// Publish depth image
-    fn publish_depth(&mut self, mut ctx: Option<&mut NodeInfo>) -> Vec<u16> {
-        if !self.enable_depth {
-            return Vec::new();
-        }
+            }
 
-        let depth_data = match self.backend {
-            DepthBackend::Simulation => self.simulate_depth_frame(),
-            #[cfg(feature = "realsense")]
-            DepthBackend::RealSense => match self.capture_depth_hardware() {
-                Some(data) => data,
-                None => {
-                    ctx.log_debug("Failed to capture depth frame from hardware, using simulation");
-                    self.simulate_depth_frame()
+            let (width, height) = frame.depth_resolution;
+            let depth_image = DepthImage {
+                width,
+                height,
+                depths: frame.depth_data,
+                min_depth: (self.depth_range.0 * 1000.0) as u16, // Convert meters to mm
+                max_depth: (self.depth_range.1 * 1000.0) as u16, // Convert meters to mm
+                depth_scale: frame.depth_units * 1000.0,         // mm per unit
+                frame_id: frame_id_bytes(&depth_frame_id),
+                timestamp,
+            };
+
+            // Process through pipeline
+            if let Some(processed) = self.processor.process(depth_image) {
+                if let Err(e) = self.depth_publisher.send(processed, &mut None) {
+                    ctx.log_error(&format!("Failed to publish depth image: {:?}", e));
                 }
-            },
-            #[cfg(not(feature = "realsense"))]
-            DepthBackend::RealSense => self.simulate_depth_frame(),
-            _ => self.simulate_depth_frame(),
-        };
-
-        let mut depth_image = DepthImage {
-            width: self.depth_resolution.0,
-            height: self.depth_resolution.1,
-            depths: depth_data.clone(),
-            min_depth: (self.depth_range.0 * 1000.0) as u16, // Convert meters to mm
-            max_depth: (self.depth_range.1 * 1000.0) as u16, // Convert meters to mm
-            depth_scale: 1.0,                                // 1mm per unit
-            frame_id: [0; 32],
-            timestamp: SystemTime::now()
-                .duration_since(UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_nanos() as u64,
-        };
-
-        // Set frame ID
-        let frame_bytes = self.depth_frame_id.as_bytes();
-        let len = frame_bytes.len().min(31);
-        depth_image.frame_id[..len].copy_from_slice(&frame_bytes[..len]);
-        depth_image.frame_id[len] = 0;
-
-        // Process through pipeline
-        if let Some(processed) = self.processor.process(depth_image) {
-            if let Err(e) = self.depth_publisher.send(processed, &mut None) {
-                ctx.log_error(&format!("Failed to publish depth image: {:?}", e));
             }
         }
 
-        depth_data
-    }
-
-    /// Publish point cloud
-    fn publish_pointcloud(&mut self, depth_data: &[u16], mut ctx: Option<&mut NodeInfo>) {
-        if !self.enable_pointcloud {
-            return;
+        let mut rgb_info = frame.rgb_info;
+        rgb_info.frame_id = frame_id_bytes(&self.rgb_frame_id);
+        rgb_info.timestamp = timestamp;
+        if let Err(e) = self.camera_info_publisher.send(rgb_info, &mut None) {
+            ctx.log_error(&format!("Failed to publish camera info: {:?}", e));
         }
 
-        let cloud = self.generate_pointcloud(depth_data);
-
-        if let Err(e) = self.pointcloud_publisher.send(cloud, &mut None) {
-            ctx.log_error(&format!("Failed to publish point cloud: {:?}", e));
+        let mut depth_info = frame.depth_info;
+        depth_info.frame_id = frame_id_bytes(&depth_frame_id);
+        depth_info.timestamp = timestamp;
+        if let Err(e) = self.depth_info_publisher.send(depth_info, &mut None) {
+            ctx.log_error(&format!("Failed to publish depth camera info: {:?}", e));
         }
     }
+}
 
-    /// Publish camera info
-    fn publish_camera_info(&mut self, mut ctx: Option<&mut NodeInfo>) {
-        let mut info = CameraInfo::default();
-        info.width = self.resolution.0;
-        info.height = self.resolution.1;
-
-        // Set camera matrix (3x3 matrix in row-major order)
-        // [fx, 0,  cx]
-        // [0,  fy, cy]
-        // [0,  0,  1 ]
-        info.camera_matrix[0] = self.rgb_fx;
-        info.camera_matrix[4] = self.rgb_fy;
-        info.camera_matrix[2] = self.rgb_cx;
-        info.camera_matrix[5] = self.rgb_cy;
-        info.camera_matrix[8] = 1.0;
-
-        // Set distortion coefficients (k1, k2, p1, p2, k3)
-        for i in 0..5 {
-            info.distortion_coefficients[i] = self.rgb_distortion[i];
-        }
-
-        // Set distortion model
-        let model = b"plumb_bob\0";
-        info.distortion_model[..model.len()].copy_from_slice(model);
-
-        // Set frame ID
-        let frame_bytes = self.rgb_frame_id.as_bytes();
-        let len = frame_bytes.len().min(31);
-        info.frame_id[..len].copy_from_slice(&frame_bytes[..len]);
-        info.frame_id[len] = 0;
-
-        info.timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_nanos() as u64;
+/// Null-terminated frame ID (at most 31 bytes)
+fn frame_id_bytes(frame_id: &str) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    let len = frame_id.len().min(31);
+    bytes[..len].copy_from_slice(&frame_id.as_bytes()[..len]);
+    bytes
+}
 
-        if let Err(e) = self.camera_info_publisher.send(info, &mut None) {
-            ctx.log_error(&format!("Failed to publish camera info: {:?}", e));
-        }
-    }
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
 }
 
 impl<P> Node for DepthCameraNode<P>
@@ -1021,15 +952,12 @@ where
             DepthBackend::Simulation => {
                 ctx.log_info("Depth camera simulation mode enabled");
             }
-            DepthBackend::RealSense => {
-                ctx.log_info("Initializing RealSense depth camera");
+            DepthBackend::RealSense | DepthBackend::ZED => {
+                ctx.log_info(&format!("Initializing {:?} depth camera", self.backend));
                 if !self.initialize_camera(Some(ctx)) {
-                    ctx.log_error("Failed to initialize RealSense camera");
+                    ctx.log_error("Failed to initialize depth camera");
                 }
             }
-            DepthBackend::ZED => {
-                ctx.log_info("ZED backend requested (not yet implemented)");
-            }
         }
 
         Ok(())
@@ -1056,22 +984,18 @@ where
             }
         }
 
-        // Publish RGB
-        self.publish_rgb(ctx.as_deref_mut());
-
-        // Publish depth and get data for point cloud
-        let depth_data = self.publish_depth(ctx.as_deref_mut());
-
-        // Publish point cloud if enabled
-        if !depth_data.is_empty() {
-            self.publish_pointcloud(&depth_data, ctx.as_deref_mut());
-        }
+        // Color and depth of one capture
+        let frame = if self.simulation_mode {
+            self.simulate_frame(current_time)
+        } else {
+            match self.read_hardware(ctx.as_deref_mut()) {
+                Some(frame) => frame,
+                None => return,
+            }
+        };
+        self.publish_frame(frame, ctx.as_deref_mut());
 
-        // Publish camera info periodically
         self.info_counter += 1;
-        if self.info_counter % 30 == 0 {
-            self.publish_camera_info(ctx.as_deref_mut());
-        }
 
         // Periodic status logging
         if self.info_counter % 300 == 0 {
@@ -1099,12 +1023,12 @@ where
             self.is_streaming = false;
         }
 
-        #[cfg(feature = "realsense")]
-        {
-            if self.pipeline.is_some() {
-                ctx.log_info("Closing RealSense pipeline");
-                self.pipeline = None;
+        if let Some(driver) = self.driver.as_mut() {
+            ctx.log_info("Closing depth camera");
+            if let Err(e) = driver.shutdown() {
+                ctx.log_warning(&format!("Failed to close depth camera: {}", e));
             }
+            self.connected = false;
         }
 
         Ok(())
@@ -1270,6 +1194,7 @@ where
             depth_publisher: node.depth_publisher,
             pointcloud_publisher: node.pointcloud_publisher,
             camera_info_publisher: node.camera_info_publisher,
+            depth_info_publisher: node.depth_info_publisher,
             camera_model: node.camera_model,
             device_serial: node.device_serial,
             resolution: node.resolution,
@@ -1304,10 +1229,15 @@ where
             dropped_frames: node.dropped_frames,
             last_frame_time: node.last_frame_time,
             backend: node.backend,
-            #[cfg(feature = "realsense")]
-            pipeline: node.pipeline,
-            #[cfg(feature = "realsense")]
-            config: node.config,
+            driver: node.driver,
+            read_timeout_ms: node.read_timeout_ms,
+            max_read_failures: node.max_read_failures,
+            reconnect_interval_ms: node.reconnect_interval_ms,
+            connected: node.connected,
+            read_failures: node.read_failures,
+            connect_failures: node.connect_failures,
+            last_connect_attempt: node.last_connect_attempt,
+            disconnects: node.disconnects,
             simulation_mode: node.simulation_mode,
             info_counter: node.info_counter,
             processor: self.processor,
@@ -1,5 +1,6 @@
+use crate::drivers::gpio::{GpioHal, PinMode};
 use crate::DigitalIO;
-use horus_core::error::HorusResult;
+use horus_core::error::{HorusError, HorusResult};
 
 // Type alias for cleaner signatures
 type Result<T> = HorusResult<T>;
@@ -56,6 +57,11 @@ where
     last_update_time: u64,
     publish_interval: u64, // milliseconds
 
+    // Hardware (DigitalIO pin index -> GPIO pin)
+    gpio: Option<Box<dyn GpioHal>>,
+    input_gpio_pins: Vec<u32>,
+    output_gpio_pins: Vec<u32>,
+
     // Simulation
     simulate_inputs: bool,
     sim_input_pattern: u8,
@@ -94,6 +100,10 @@ impl DigitalIONode {
             last_update_time: 0,
             publish_interval: 100, // 100ms = 10 Hz
 
+            gpio: None,
+            input_gpio_pins: Vec::new(),
+            output_gpio_pins: Vec::new(),
+
             simulate_inputs: true,
             sim_input_pattern: 0,
             processor: PassThrough::new(),
@@ -171,39 +181,51 @@ where
         self.simulate_inputs = enabled;
     }
 
-    #[cfg(feature = "raspberry-pi")]
-    fn read_gpio_pin(&self, pin: u8) -> bool {
-        use rppal::gpio::{Gpio, Level};
+    /// Drive the pins through a GPIO backend
+    ///
+    /// Input `i` of the node reads `input_pins[i]` (with pull-up) and output
+    /// `i` drives `output_pins[i]`; pin counts follow the slices and input
+    /// simulation is turned off.
+    pub fn set_gpio(
+        &mut self,
+        gpio: impl GpioHal + 'static,
+        input_pins: &[u32],
+        output_pins: &[u32],
+    ) -> Result<()> {
+        if input_pins.len() > u8::MAX as usize || output_pins.len() > u8::MAX as usize {
+            return Err(HorusError::config("Too many digital I/O pins"));
+        }
 
-        if let Ok(gpio) = Gpio::new() {
-            if let Ok(input_pin) = gpio.get(pin) {
-                let input = input_pin.into_input_pullup();
-                return input.read() == Level::High;
-            }
+        let mut gpio = Box::new(gpio);
+        for &pin in input_pins {
+            gpio.setup_pin(pin, PinMode::InputPullUp, false)?;
+        }
+        for &pin in output_pins {
+            gpio.setup_pin(pin, PinMode::Output, false)?;
         }
-        false
-    }
 
-    #[cfg(not(feature = "raspberry-pi"))]
-    fn read_gpio_pin(&self, _pin: u8) -> bool {
-        false // GPIO not available
+        self.set_pin_counts(input_pins.len() as u8, output_pins.len() as u8);
+        self.gpio = Some(gpio);
+        self.input_gpio_pins = input_pins.to_vec();
+        self.output_gpio_pins = output_pins.to_vec();
+        self.simulate_inputs = false;
+        Ok(())
     }
 
-    #[cfg(feature = "raspberry-pi")]
-    fn write_gpio_pin(&self, pin: u8, state: bool) {
-        use rppal::gpio::{Gpio, Level};
-
-        if let Ok(gpio) = Gpio::new() {
-            if let Ok(output_pin) = gpio.get(pin) {
-                let mut output = output_pin.into_output();
-                let _ = output.write(if state { Level::High } else { Level::Low });
-            }
+    fn read_gpio_pin(&mut self, pin: u8) -> bool {
+        match (self.gpio.as_mut(), self.input_gpio_pins.get(pin as usize)) {
+            (Some(gpio), Some(&gpio_pin)) => gpio.read_pin(gpio_pin).unwrap_or(false),
+            _ => false, // GPIO not available
         }
     }
 
-    #[cfg(not(feature = "raspberry-pi"))]
-    fn write_gpio_pin(&self, _pin: u8, _state: bool) {
-        // GPIO not available - would control industrial I/O module here
+    fn write_gpio_pin(&mut self, pin: u8, state: bool) {
+        if let (Some(gpio), Some(&gpio_pin)) =
+            (self.gpio.as_mut(), self.output_gpio_pins.get(pin as usize))
+        {
+            let _ = gpio.write_pin(gpio_pin, state);
+        }
+        // Without a GPIO backend this would control an industrial I/O module
     }
 
     fn read_input_pins(&mut self) {
@@ -221,7 +243,7 @@ where
         }
     }
 
-    fn write_output_pin(&self, pin: u8, state: bool) {
+    fn write_output_pin(&mut self, pin: u8, state: bool) {
         // Write to actual hardware
         self.write_gpio_pin(pin, state);
     }
@@ -360,8 +382,11 @@ where
         "DigitalIONode"
     }
 
-    fn init(&mut self, _ctx: &mut NodeInfo) -> Result<()> {
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
         self.processor.on_start();
+        if !self.simulate_inputs && self.gpio.is_none() {
+            ctx.log_warning("DigitalIONode has no GPIO backend - call set_gpio() to map pins");
+        }
         Ok(())
     }
 
@@ -375,6 +400,12 @@ where
             self.write_output_pin(pin, false);
         }
 
+        if let Some(gpio) = self.gpio.as_mut() {
+            for &pin in self.input_gpio_pins.iter().chain(&self.output_gpio_pins) {
+                let _ = gpio.release_pin(pin);
+            }
+        }
+
         ctx.log_info("All digital outputs set to LOW safely");
         Ok(())
     }
@@ -544,6 +575,10 @@ where
             last_update_time: 0,
             publish_interval: 100,
 
+            gpio: None,
+            input_gpio_pins: Vec::new(),
+            output_gpio_pins: Vec::new(),
+
             simulate_inputs: true,
             sim_input_pattern: 0,
             processor: self.processor,
@@ -0,0 +1,501 @@
+use horus_core::core::LogSummary;
+use horus_core::error::{HorusError, HorusResult};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde_json::Value;
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo};
+use std::time::{Duration, Instant};
+
+/// Upper bound on messages forwarded from a topic per tick
+const MAX_DRAIN_PER_TICK: usize = 4096;
+
+/// Environment variable set by `horus test --sim` and simulators
+pub const SIMULATION_MODE_ENV: &str = "HORUS_SIMULATION_MODE";
+
+type Poller = Box<dyn FnMut() -> Option<Value> + Send>;
+type Forwarder = Box<dyn FnMut(Value) -> bool + Send>;
+
+/// Signal added to a message field while a disturbance is active
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Perturbation {
+    /// Constant bias
+    Offset(f64),
+    /// `amplitude * sin(2π f t)`, t from the start of the disturbance
+    Sinusoid { amplitude: f64, frequency_hz: f64 },
+    /// Gaussian steps per message, accumulated and bounded by `limit`
+    RandomWalk { step_std: f64, limit: f64 },
+    /// Independent Gaussian noise on every message
+    Noise { std_dev: f64 },
+}
+
+/// A perturbation of one message field for a bounded time
+///
+/// `field` is a dotted path into the serialized message (`linear`,
+/// `angular_velocity.2`). Numeric arrays selected as a whole are perturbed
+/// element-wise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Disturbance {
+    pub topic: String,
+    pub field: String,
+    pub perturbation: Perturbation,
+    /// Delay after the node's first tick
+    pub start: Duration,
+    pub duration: Duration,
+}
+
+impl Disturbance {
+    /// Disturbance active from the first tick for 10 seconds
+    pub fn new(topic: &str, field: &str, perturbation: Perturbation) -> Self {
+        Self {
+            topic: topic.to_string(),
+            field: field.to_string(),
+            perturbation,
+            start: Duration::ZERO,
+            duration: Duration::from_secs(10),
+        }
+    }
+
+    /// Start `delay` after the node's first tick
+    pub fn starting_after(mut self, delay: Duration) -> Self {
+        self.start = delay;
+        self
+    }
+
+    /// Stay active for `duration`
+    pub fn lasting(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    fn is_active(&self, elapsed: Duration) -> bool {
+        elapsed >= self.start && elapsed < self.start + self.duration
+    }
+}
+
+struct ActiveDisturbance {
+    spec: Disturbance,
+    walk: f64,
+}
+
+impl ActiveDisturbance {
+    /// Advance per-message state (random walk) before applying
+    fn step(&mut self, rng: &mut StdRng) {
+        if let Perturbation::RandomWalk { step_std, limit } = self.spec.perturbation {
+            self.walk = (self.walk + gaussian(rng) * step_std).clamp(-limit.abs(), limit.abs());
+        }
+    }
+
+    /// Value added to one number, `t` seconds into the disturbance
+    fn sample(&self, t: f64, rng: &mut StdRng) -> f64 {
+        match self.spec.perturbation {
+            Perturbation::Offset(offset) => offset,
+            Perturbation::Sinusoid {
+                amplitude,
+                frequency_hz,
+            } => amplitude * (std::f64::consts::TAU * frequency_hz * t).sin(),
+            Perturbation::RandomWalk { .. } => self.walk,
+            Perturbation::Noise { std_dev } => gaussian(rng) * std_dev,
+        }
+    }
+}
+
+struct DisturbedTopic {
+    input: String,
+    output: String,
+    poll: Poller,
+    forward: Forwarder,
+}
+
+/// Disturbance Node - Perturbs command and sensor topics for robustness testing
+///
+/// Forwards each configured input topic to an output topic (e.g. `cmd_vel`
+/// to `cmd_vel.disturbed`) and, during each disturbance's time window, adds
+/// offsets, sinusoids, random walks or noise to selected numeric fields.
+/// Outside the windows messages pass through unchanged, so the node can stay
+/// in the graph between test phases.
+///
+/// Injection only runs in simulation (`HORUS_SIMULATION_MODE=1`) unless
+/// `allow_hardware` is called, which also clamps every injected value to a
+/// maximum magnitude.
+pub struct DisturbanceNode {
+    // Forwarded topics
+    topics: Vec<DisturbedTopic>,
+    disturbances: Vec<ActiveDisturbance>,
+
+    // Configuration
+    hardware_limit: Option<f64>,
+    rng: StdRng,
+
+    // State
+    started_at: Option<Instant>,
+    injecting: bool,
+    messages_disturbed: u64,
+    forward_errors: u64,
+}
+
+impl DisturbanceNode {
+    /// Create a disturbance node with no topics
+    pub fn new() -> Result<Self> {
+        Ok(Self {
+            topics: Vec::new(),
+            disturbances: Vec::new(),
+
+            hardware_limit: None,
+            rng: StdRng::from_entropy(),
+
+            started_at: None,
+            injecting: false,
+            messages_disturbed: 0,
+            forward_errors: 0,
+        })
+    }
+
+    /// Forward `input` to `output`, perturbing it while disturbances are active
+    pub fn add_topic<T>(&mut self, input: &str, output: &str) -> Result<()>
+    where
+        T: Send
+            + Sync
+            + 'static
+            + Clone
+            + std::fmt::Debug
+            + serde::Serialize
+            + serde::de::DeserializeOwned
+            + LogSummary,
+    {
+        if input == output {
+            return Err(HorusError::Config(format!(
+                "Disturbance output must differ from input topic '{}'",
+                input
+            )));
+        }
+
+        let subscriber: Hub<T> = Hub::new(input)?;
+        let publisher: Hub<T> = Hub::new(output)?;
+        let poll: Poller = Box::new(move || {
+            subscriber
+                .recv(&mut None)
+                .and_then(|msg| serde_json::to_value(msg).ok())
+        });
+        let forward: Forwarder = Box::new(move |value| {
+            serde_json::from_value::<T>(value)
+                .map(|msg| publisher.send(msg, &mut None).is_ok())
+                .unwrap_or(false)
+        });
+
+        self.topics.retain(|t| t.input != input);
+        self.topics.push(DisturbedTopic {
+            input: input.to_string(),
+            output: output.to_string(),
+            poll,
+            forward,
+        });
+        Ok(())
+    }
+
+    /// Schedule a disturbance on a topic added with `add_topic`
+    pub fn add_disturbance(&mut self, disturbance: Disturbance) -> Result<()> {
+        if !self.topics.iter().any(|t| t.input == disturbance.topic) {
+            return Err(HorusError::Config(format!(
+                "Disturbance on unknown topic '{}' (add it with add_topic first)",
+                disturbance.topic
+            )));
+        }
+        self.disturbances.push(ActiveDisturbance {
+            spec: disturbance,
+            walk: 0.0,
+        });
+        Ok(())
+    }
+
+    /// Allow injection outside simulation, clamping every injected value to
+    /// `±max_magnitude`
+    pub fn allow_hardware(&mut self, max_magnitude: f64) {
+        self.hardware_limit = Some(max_magnitude.abs());
+    }
+
+    /// Seed the random generator for reproducible noise and random walks
+    pub fn set_seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    /// Output topic for an input topic
+    pub fn get_output_topic(&self, input: &str) -> Option<&str> {
+        self.topics
+            .iter()
+            .find(|t| t.input == input)
+            .map(|t| t.output.as_str())
+    }
+
+    /// Whether perturbations are applied (simulation or `allow_hardware`)
+    pub fn is_injecting(&self) -> bool {
+        self.injecting
+    }
+
+    /// Disturbances active at this moment
+    pub fn get_active_disturbances(&self) -> Vec<&Disturbance> {
+        let Some(started) = self.started_at else {
+            return Vec::new();
+        };
+        let elapsed = started.elapsed();
+        self.disturbances
+            .iter()
+            .map(|d| &d.spec)
+            .filter(|d| self.injecting && d.is_active(elapsed))
+            .collect()
+    }
+
+    /// Number of messages forwarded with at least one perturbation
+    pub fn get_messages_disturbed(&self) -> u64 {
+        self.messages_disturbed
+    }
+
+    /// Number of messages that could not be forwarded
+    pub fn get_forward_errors(&self) -> u64 {
+        self.forward_errors
+    }
+
+    /// Apply the disturbances active at `elapsed` to a message of `topic`
+    fn disturb(&mut self, topic: &str, value: &mut Value, elapsed: Duration) -> bool {
+        let mut disturbed = false;
+        for active in &mut self.disturbances {
+            if active.spec.topic != topic || !active.spec.is_active(elapsed) {
+                continue;
+            }
+            let Some(field) = lookup_mut(value, &active.spec.field) else {
+                continue;
+            };
+            active.step(&mut self.rng);
+            let t = (elapsed - active.spec.start).as_secs_f64();
+            let limit = self.hardware_limit;
+            let rng = &mut self.rng;
+            disturbed |= perturb(field, &mut || {
+                let delta = active.sample(t, rng);
+                match limit {
+                    Some(max) => delta.clamp(-max, max),
+                    None => delta,
+                }
+            });
+        }
+        disturbed
+    }
+}
+
+/// Add a delta to a number, or to every number of an array
+fn perturb(value: &mut Value, delta: &mut dyn FnMut() -> f64) -> bool {
+    match value {
+        Value::Number(n) => {
+            let Some(x) = n.as_f64() else {
+                return false;
+            };
+            let y = x + delta();
+            // Integer fields stay integers so the message still deserializes
+            let perturbed = if n.is_f64() {
+                serde_json::Number::from_f64(y).map(Value::Number)
+            } else if n.is_u64() {
+                Some(Value::from(y.round().max(0.0) as u64))
+            } else {
+                Some(Value::from(y.round() as i64))
+            };
+            match perturbed {
+                Some(p) => {
+                    *value = p;
+                    true
+                }
+                None => false,
+            }
+        }
+        Value::Array(items) => {
+            // Perturb every element; `any` would stop at the first change
+            let mut changed = false;
+            for item in items.iter_mut() {
+                changed |= perturb(item, delta);
+            }
+            changed
+        }
+        _ => false,
+    }
+}
+
+/// Look up a dotted path; array elements can be addressed by index (`ranges.0`)
+fn lookup_mut<'a>(value: &'a mut Value, path: &str) -> Option<&'a mut Value> {
+    if path == "value" && !value.is_object() {
+        return Some(value);
+    }
+    path.split('.')
+        .try_fold(value, |current, key| match current {
+            Value::Object(map) => map.get_mut(key),
+            Value::Array(items) => key.parse::<usize>().ok().and_then(|i| items.get_mut(i)),
+            _ => None,
+        })
+}
+
+/// Standard normal sample (Box-Muller)
+fn gaussian(rng: &mut StdRng) -> f64 {
+    let u1: f64 = rng.gen_range(f64::MIN_POSITIVE..1.0);
+    let u2: f64 = rng.gen();
+    (-2.0 * u1.ln()).sqrt() * (std::f64::consts::TAU * u2).cos()
+}
+
+impl Node for DisturbanceNode {
+    fn name(&self) -> &'static str {
+        "DisturbanceNode"
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        let simulation = std::env::var(SIMULATION_MODE_ENV).is_ok_and(|v| v == "1");
+        self.injecting = simulation || self.hardware_limit.is_some();
+
+        if self.injecting {
+            ctx.log_info(&format!(
+                "DisturbanceNode injecting {} disturbances on {} topics{}",
+                self.disturbances.len(),
+                self.topics.len(),
+                match self.hardware_limit {
+                    Some(max) if !simulation => format!(" (hardware, clamped to ±{})", max),
+                    _ => String::new(),
+                }
+            ));
+        } else {
+            ctx.log_warning(
+                "DisturbanceNode: not in simulation and allow_hardware not set, forwarding topics unchanged",
+            );
+        }
+        Ok(())
+    }
+
+    fn shutdown(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        ctx.log_info(&format!(
+            "DisturbanceNode shutting down after {} disturbed messages ({} forward errors)",
+            self.messages_disturbed, self.forward_errors
+        ));
+        Ok(())
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let started = *self.started_at.get_or_insert_with(Instant::now);
+        let elapsed = started.elapsed();
+
+        for index in 0..self.topics.len() {
+            for _ in 0..MAX_DRAIN_PER_TICK {
+                let Some(original) = (self.topics[index].poll)() else {
+                    break;
+                };
+
+                let mut value = original.clone();
+                let topic = self.topics[index].input.clone();
+                let disturbed = self.injecting && self.disturb(&topic, &mut value, elapsed);
+
+                if disturbed && (self.topics[index].forward)(value) {
+                    self.messages_disturbed += 1;
+                } else if !(self.topics[index].forward)(original) {
+                    self.forward_errors += 1;
+                    if let Some(ctx) = ctx.as_deref_mut() {
+                        ctx.log_warning(&format!(
+                            "Failed to forward '{}' to '{}'",
+                            topic, self.topics[index].output
+                        ));
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Default impl removed - use DisturbanceNode::new() instead which returns HorusResult
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn node_with(disturbances: Vec<Disturbance>) -> DisturbanceNode {
+        let mut node = DisturbanceNode::new().unwrap();
+        node.add_topic::<String>("test_disturbance_in", "test_disturbance_out")
+            .unwrap();
+        for disturbance in disturbances {
+            node.add_disturbance(disturbance).unwrap();
+        }
+        node.set_seed(7);
+        node.injecting = true;
+        node
+    }
+
+    #[test]
+    fn test_disturbance_windows_and_fields() {
+        let mut node = node_with(vec![
+            Disturbance::new("test_disturbance_in", "linear", Perturbation::Offset(0.5))
+                .starting_after(Duration::from_secs(1))
+                .lasting(Duration::from_secs(2)),
+            Disturbance::new(
+                "test_disturbance_in",
+                "ranges",
+                Perturbation::Sinusoid {
+                    amplitude: 2.0,
+                    frequency_hz: 0.25,
+                },
+            ),
+        ]);
+        let message = json!({"linear": 1.0, "ranges": [1.0, 2.0], "seq": 3});
+
+        // Before the offset window only the sinusoid applies (0 at t=0)
+        let mut value = message.clone();
+        assert!(node.disturb("test_disturbance_in", &mut value, Duration::ZERO));
+        assert_eq!(
+            value,
+            json!({"linear": 1.0, "ranges": [1.0, 2.0], "seq": 3})
+        );
+
+        // At t=1s: offset starts, sinusoid at a quarter period
+        let mut value = message.clone();
+        node.disturb("test_disturbance_in", &mut value, Duration::from_secs(1));
+        assert_eq!(value["linear"], json!(1.5));
+        assert_eq!(value["ranges"], json!([3.0, 4.0]));
+
+        // After both windows nothing changes
+        let mut value = message.clone();
+        assert!(!node.disturb("test_disturbance_in", &mut value, Duration::from_secs(20)));
+        assert_eq!(value, message);
+    }
+
+    #[test]
+    fn test_hardware_clamp_and_integers() {
+        let mut node = node_with(vec![
+            Disturbance::new("test_disturbance_in", "linear", Perturbation::Offset(5.0)),
+            Disturbance::new(
+                "test_disturbance_in",
+                "seq",
+                Perturbation::Noise { std_dev: 100.0 },
+            ),
+        ]);
+        node.allow_hardware(0.2);
+
+        let mut value = json!({"linear": 1.0, "seq": 3});
+        node.disturb("test_disturbance_in", &mut value, Duration::ZERO);
+        assert_eq!(value["linear"], json!(1.2));
+        assert!(value["seq"].is_i64() || value["seq"].is_u64());
+
+        assert!(node
+            .add_disturbance(Disturbance::new("missing", "x", Perturbation::Offset(1.0)))
+            .is_err());
+    }
+
+    #[test]
+    fn test_random_walk_is_bounded() {
+        let mut node = node_with(vec![Disturbance::new(
+            "test_disturbance_in",
+            "linear",
+            Perturbation::RandomWalk {
+                step_std: 1.0,
+                limit: 0.3,
+            },
+        )]);
+        for _ in 0..100 {
+            let mut value = json!({"linear": 0.0});
+            node.disturb("test_disturbance_in", &mut value, Duration::ZERO);
+            assert!(value["linear"].as_f64().unwrap().abs() <= 0.3);
+        }
+    }
+}
@@ -0,0 +1,710 @@
+use crate::hframe::{timestamp_now, HFrame, Transform};
+use crate::{Imu, NavSatFix, Odometry, Pose2D, Twist};
+use horus_core::config::ProjectConfig;
+use horus_core::error::{HorusError, HorusResult};
+use serde::{Deserialize, Serialize};
+
+// Import algorithms from horus_library/algorithms
+use crate::algorithms::ekf::EKF;
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo};
+use std::path::Path;
+use std::time::Instant;
+
+/// Earth radius used for the local GPS projection (m)
+const EARTH_RADIUS: f64 = 6_371_000.0;
+
+/// State variable a sensor can contribute to the filter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FusedVariable {
+    /// Position along the world x axis (m)
+    X,
+    /// Position along the world y axis (m)
+    Y,
+    /// Heading (rad)
+    Yaw,
+    /// Forward velocity in the robot frame (m/s)
+    Vx,
+    /// Lateral velocity in the robot frame (m/s)
+    Vy,
+    /// Angular velocity (rad/s)
+    YawRate,
+}
+
+impl FusedVariable {
+    /// Variance used when neither the config nor the message provides one
+    fn fallback_variance(self) -> f64 {
+        match self {
+            FusedVariable::X | FusedVariable::Y => 0.25,
+            FusedVariable::Yaw => 0.05,
+            FusedVariable::Vx | FusedVariable::Vy => 0.01,
+            FusedVariable::YawRate => 0.01,
+        }
+    }
+}
+
+/// Per-sensor fusion settings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FusionSensorConfig {
+    /// Whether the sensor is fused at all
+    pub enabled: bool,
+    /// Topic override (the node's default topic when unset)
+    pub topic: Option<String>,
+    /// Variables taken from this sensor (the sensor's default set when empty)
+    pub fuse: Vec<FusedVariable>,
+    /// Measurement variance per entry of `fuse`; empty to use the message covariance
+    pub variances: Vec<f64>,
+}
+
+impl Default for FusionSensorConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            topic: None,
+            fuse: Vec::new(),
+            variances: Vec::new(),
+        }
+    }
+}
+
+impl FusionSensorConfig {
+    fn variables(&self, defaults: &[FusedVariable]) -> Vec<FusedVariable> {
+        if self.fuse.is_empty() {
+            defaults.to_vec()
+        } else {
+            self.fuse.clone()
+        }
+    }
+
+    /// Configured variance of the `i`-th fused variable, else the message's, else a fallback
+    fn variance(&self, i: usize, variable: FusedVariable, message: f64) -> f64 {
+        match self.variances.get(i) {
+            Some(&v) if v > 0.0 => v,
+            _ if message > 0.0 && message.is_finite() => message,
+            _ => variable.fallback_variance(),
+        }
+    }
+}
+
+/// Configuration of the `EkfLocalizationNode`
+///
+/// Read from the `ekf_localization` section of `horus.yaml`:
+///
+/// ```yaml
+/// ekf_localization:
+///   world_frame: odom
+///   base_frame: base_link
+///   process_noise: [0.05, 0.05, 0.03, 0.2, 0.2, 0.1]  # x, y, yaw, vx, vy, yaw_rate
+///   odom:
+///     fuse: [vx, yaw_rate]
+///     variances: [0.01, 0.02]
+///   imu:
+///     topic: imu.base
+///     fuse: [yaw, yaw_rate]
+///   gps:
+///     enabled: false
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EkfLocalizationConfig {
+    /// Frame the fused pose is expressed in
+    pub world_frame: String,
+    /// Robot frame
+    pub base_frame: String,
+    /// Process noise per state variable per second (x, y, yaw, vx, vy, yaw_rate)
+    pub process_noise: [f64; 6],
+    /// Wheel (or visual) odometry
+    pub odom: FusionSensorConfig,
+    /// Inertial measurement unit
+    pub imu: FusionSensorConfig,
+    /// GPS receiver
+    pub gps: FusionSensorConfig,
+    /// GPS reference point (latitude, longitude); the first fix when unset
+    pub datum: Option<[f64; 2]>,
+}
+
+impl Default for EkfLocalizationConfig {
+    fn default() -> Self {
+        Self {
+            world_frame: "odom".to_string(),
+            base_frame: "base_link".to_string(),
+            process_noise: [0.1, 0.1, 0.05, 0.2, 0.2, 0.1],
+            odom: FusionSensorConfig::default(),
+            imu: FusionSensorConfig::default(),
+            gps: FusionSensorConfig::default(),
+            datum: None,
+        }
+    }
+}
+
+impl EkfLocalizationConfig {
+    /// Section of `horus.yaml` holding this configuration
+    pub const SECTION: &'static str = "ekf_localization";
+
+    /// Default variables fused from odometry
+    pub const ODOM_DEFAULTS: &'static [FusedVariable] =
+        &[FusedVariable::Vx, FusedVariable::Vy, FusedVariable::YawRate];
+    /// Default variables fused from the IMU
+    pub const IMU_DEFAULTS: &'static [FusedVariable] = &[FusedVariable::YawRate];
+    /// Default variables fused from GPS
+    pub const GPS_DEFAULTS: &'static [FusedVariable] = &[FusedVariable::X, FusedVariable::Y];
+
+    /// Read the `ekf_localization` section of a project config (defaults if absent)
+    pub fn from_project_config(project: &ProjectConfig) -> Result<Self> {
+        match project.extra.get(Self::SECTION) {
+            Some(section) => serde_yaml::from_value(section.clone()).map_err(|e| {
+                HorusError::Config(format!("invalid '{}' section: {}", Self::SECTION, e))
+            }),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Read the `ekf_localization` section of a `horus.yaml` file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let project = ProjectConfig::from_file(path.as_ref())?;
+        Self::from_project_config(&project)
+    }
+}
+
+/// EKF Localization Node - Configurable odometry, IMU and GPS fusion
+///
+/// A robot_localization-style state estimator: fuses any subset of the pose,
+/// velocity and heading measurements from `Odometry`, `Imu` and `NavSatFix`
+/// into a 2D state (x, y, yaw, vx, vy, yaw rate) with an Extended Kalman
+/// Filter, and publishes the result as `Odometry` every tick.
+///
+/// Which variables each sensor contributes and with what variance is set in
+/// the `ekf_localization` section of `horus.yaml` (see `EkfLocalizationConfig`).
+/// Variances not configured are taken from the message covariance.
+///
+/// GPS fixes are projected onto a local plane around the datum, with x east
+/// and y north, so the world frame is assumed to be ENU-aligned when GPS is
+/// fused. When an HFrame is attached, the `world_frame -> base_frame`
+/// transform is updated with every estimate.
+///
+/// This node is a thin wrapper around the pure algorithm in horus_library/algorithms.
+pub struct EkfLocalizationNode {
+    // Publishers and Subscribers
+    output_publisher: Hub<Odometry>,
+    odom_subscriber: Option<Hub<Odometry>>,
+    imu_subscriber: Option<Hub<Imu>>,
+    gps_subscriber: Option<Hub<NavSatFix>>,
+
+    // Algorithm instance
+    ekf: EKF,
+
+    // Configuration
+    config: EkfLocalizationConfig,
+    hframe: Option<HFrame>,
+
+    // State
+    datum: Option<(f64, f64)>,
+    last_predict: Option<Instant>,
+    measurement_count: u64,
+}
+
+impl EkfLocalizationNode {
+    /// Create a node with the default configuration and topics
+    pub fn new() -> Result<Self> {
+        Self::new_with_config(EkfLocalizationConfig::default())
+    }
+
+    /// Create a node configured from the `ekf_localization` section of a `horus.yaml` file
+    pub fn from_config_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::new_with_config(EkfLocalizationConfig::from_file(path)?)
+    }
+
+    /// Create a node with the given configuration and default topics
+    pub fn new_with_config(config: EkfLocalizationConfig) -> Result<Self> {
+        Self::new_with_topics(config, "odom.filtered", "odom", "imu", "gps")
+    }
+
+    /// Create a node with custom topics (per-sensor topics in the config take precedence)
+    pub fn new_with_topics(
+        config: EkfLocalizationConfig,
+        output_topic: &str,
+        odom_topic: &str,
+        imu_topic: &str,
+        gps_topic: &str,
+    ) -> Result<Self> {
+        fn subscriber<T>(sensor: &FusionSensorConfig, default_topic: &str) -> Result<Option<Hub<T>>>
+        where
+            T: Send
+                + Sync
+                + 'static
+                + Clone
+                + std::fmt::Debug
+                + serde::Serialize
+                + serde::de::DeserializeOwned,
+        {
+            if !sensor.enabled {
+                return Ok(None);
+            }
+            let topic = sensor.topic.as_deref().unwrap_or(default_topic);
+            Ok(Some(Hub::new(topic)?))
+        }
+
+        let mut ekf = EKF::new();
+        let mut process_noise = [[0.0; 6]; 6];
+        for (i, &q) in config.process_noise.iter().enumerate() {
+            process_noise[i][i] = q;
+        }
+        ekf.set_process_noise(process_noise);
+
+        Ok(Self {
+            output_publisher: Hub::new(output_topic)?,
+            odom_subscriber: subscriber(&config.odom, odom_topic)?,
+            imu_subscriber: subscriber(&config.imu, imu_topic)?,
+            gps_subscriber: subscriber(&config.gps, gps_topic)?,
+
+            ekf,
+
+            datum: config.datum.map(|[lat, lon]| (lat, lon)),
+            config,
+            hframe: None,
+
+            last_predict: None,
+            measurement_count: 0,
+        })
+    }
+
+    /// Update the `world_frame -> base_frame` transform in this HFrame
+    ///
+    /// Missing frames are registered at startup.
+    pub fn set_hframe(&mut self, hframe: HFrame) {
+        self.hframe = Some(hframe);
+    }
+
+    /// Set initial pose estimate
+    pub fn set_initial_pose(&mut self, x: f64, y: f64, theta: f64) {
+        let mut state = self.ekf.get_state();
+        state[EKF::X] = x;
+        state[EKF::Y] = y;
+        state[EKF::THETA] = theta;
+        self.ekf.set_state(state);
+    }
+
+    /// Current configuration
+    pub fn get_config(&self) -> &EkfLocalizationConfig {
+        &self.config
+    }
+
+    /// Get current pose estimate (x, y, theta)
+    pub fn get_pose(&self) -> (f64, f64, f64) {
+        self.ekf.get_pose()
+    }
+
+    /// Get current velocity in the robot frame (vx, vy, omega)
+    pub fn get_velocity(&self) -> (f64, f64, f64) {
+        let (vx, vy, omega) = self.ekf.get_velocity();
+        let (_, _, theta) = self.ekf.get_pose();
+        let (sin, cos) = theta.sin_cos();
+        (vx * cos + vy * sin, -vx * sin + vy * cos, omega)
+    }
+
+    /// Get position uncertainty (standard deviation in meters)
+    pub fn get_position_uncertainty(&self) -> f64 {
+        self.ekf.get_position_uncertainty()
+    }
+
+    /// Number of measurements fused so far
+    pub fn measurement_count(&self) -> u64 {
+        self.measurement_count
+    }
+
+    /// GPS reference point (latitude, longitude), once known
+    pub fn get_datum(&self) -> Option<(f64, f64)> {
+        self.datum
+    }
+
+    /// Reset the filter to the origin with high uncertainty
+    pub fn reset(&mut self) {
+        self.ekf.reset();
+        self.last_predict = None;
+    }
+
+    /// Advance the filter to `now`
+    fn predict_to(&mut self, now: Instant) {
+        if let Some(last) = self.last_predict {
+            let dt = now.saturating_duration_since(last).as_secs_f64();
+            if dt > 0.0 {
+                self.ekf.predict(dt);
+            }
+        }
+        self.last_predict = Some(now);
+    }
+
+    /// Fuse the configured variables, with body-frame velocities rotated into the world frame
+    fn fuse(&mut self, sensor: &FusionSensorConfig, values: &[(FusedVariable, f64, f64)]) {
+        let mut indices = Vec::with_capacity(values.len());
+        let mut measurement = Vec::with_capacity(values.len());
+        let mut variances = Vec::with_capacity(values.len());
+
+        let mut body_velocity: Option<([f64; 2], [f64; 2])> = None;
+        for (i, &(variable, value, message_variance)) in values.iter().enumerate() {
+            let variance = sensor.variance(i, variable, message_variance);
+            let index = match variable {
+                FusedVariable::X => EKF::X,
+                FusedVariable::Y => EKF::Y,
+                FusedVariable::Yaw => EKF::THETA,
+                FusedVariable::YawRate => EKF::OMEGA,
+                FusedVariable::Vx | FusedVariable::Vy => {
+                    // A missing lateral component is taken as zero (nonholonomic base)
+                    let axis = (variable == FusedVariable::Vy) as usize;
+                    let (v, var) = body_velocity.get_or_insert((
+                        [0.0; 2],
+                        [
+                            FusedVariable::Vx.fallback_variance(),
+                            FusedVariable::Vy.fallback_variance(),
+                        ],
+                    ));
+                    v[axis] = value;
+                    var[axis] = variance;
+                    continue;
+                }
+            };
+            indices.push(index);
+            measurement.push(value);
+            variances.push(variance);
+        }
+
+        if let Some(([vx, vy], [var_x, var_y])) = body_velocity {
+            let (_, _, theta) = self.ekf.get_pose();
+            let (sin, cos) = theta.sin_cos();
+            indices.extend([EKF::VX, EKF::VY]);
+            measurement.extend([vx * cos - vy * sin, vx * sin + vy * cos]);
+            variances.extend([
+                cos * cos * var_x + sin * sin * var_y,
+                sin * sin * var_x + cos * cos * var_y,
+            ]);
+        }
+
+        self.ekf.update_partial(&indices, &measurement, &variances);
+        self.measurement_count += 1;
+    }
+
+    fn fuse_odometry(&mut self, odom: &Odometry) {
+        let sensor = self.config.odom.clone();
+        let values: Vec<_> = sensor
+            .variables(EkfLocalizationConfig::ODOM_DEFAULTS)
+            .into_iter()
+            .map(|variable| {
+                let (value, variance) = match variable {
+                    FusedVariable::X => (odom.pose.x, odom.pose_covariance[0]),
+                    FusedVariable::Y => (odom.pose.y, odom.pose_covariance[7]),
+                    FusedVariable::Yaw => (odom.pose.theta, odom.pose_covariance[35]),
+                    FusedVariable::Vx => (odom.twist.linear[0], odom.twist_covariance[0]),
+                    FusedVariable::Vy => (odom.twist.linear[1], odom.twist_covariance[7]),
+                    FusedVariable::YawRate => (odom.twist.angular[2], odom.twist_covariance[35]),
+                };
+                (variable, value, variance)
+            })
+            .collect();
+        self.fuse(&sensor, &values);
+    }
+
+    fn fuse_imu(&mut self, imu: &Imu) {
+        let sensor = self.config.imu.clone();
+        let [qx, qy, qz, qw] = imu.orientation;
+        let has_orientation = imu.orientation_covariance[0] >= 0.0;
+
+        let values: Vec<_> = sensor
+            .variables(EkfLocalizationConfig::IMU_DEFAULTS)
+            .into_iter()
+            .filter_map(|variable| match variable {
+                FusedVariable::Yaw if has_orientation => {
+                    let yaw = (2.0 * (qw * qz + qx * qy)).atan2(1.0 - 2.0 * (qy * qy + qz * qz));
+                    Some((variable, yaw, imu.orientation_covariance[8]))
+                }
+                FusedVariable::YawRate => Some((
+                    variable,
+                    imu.angular_velocity[2],
+                    imu.angular_velocity_covariance[8],
+                )),
+                _ => None,
+            })
+            .collect();
+        if !values.is_empty() {
+            self.fuse(&sensor, &values);
+        }
+    }
+
+    fn fuse_gps(&mut self, fix: &NavSatFix) {
+        if !fix.has_fix() || !fix.is_valid() {
+            return;
+        }
+        let (lat0, lon0) = *self.datum.get_or_insert((fix.latitude, fix.longitude));
+
+        // Local tangent plane around the datum: x east, y north
+        let east = (fix.longitude - lon0).to_radians() * lat0.to_radians().cos() * EARTH_RADIUS;
+        let north = (fix.latitude - lat0).to_radians() * EARTH_RADIUS;
+        let known = fix.position_covariance_type != NavSatFix::COVARIANCE_TYPE_UNKNOWN;
+        let accuracy = (fix.horizontal_accuracy() as f64).powi(2);
+
+        let sensor = self.config.gps.clone();
+        let values: Vec<_> = sensor
+            .variables(EkfLocalizationConfig::GPS_DEFAULTS)
+            .into_iter()
+            .filter_map(|variable| match variable {
+                FusedVariable::X => Some((
+                    variable,
+                    east,
+                    if known {
+                        fix.position_covariance[0]
+                    } else {
+                        accuracy
+                    },
+                )),
+                FusedVariable::Y => Some((
+                    variable,
+                    north,
+                    if known {
+                        fix.position_covariance[4]
+                    } else {
+                        accuracy
+                    },
+                )),
+                _ => None,
+            })
+            .collect();
+        if !values.is_empty() {
+            self.fuse(&sensor, &values);
+        }
+    }
+
+    /// Fused estimate as an odometry message
+    fn estimate(&self) -> Odometry {
+        let (x, y, theta) = self.ekf.get_pose();
+        let (vx, vy, omega) = self.get_velocity();
+        let covariance = self.ekf.get_covariance();
+
+        let mut odom = Odometry::new();
+        odom.set_frames(&self.config.world_frame, &self.config.base_frame);
+        odom.update(
+            Pose2D::new(x, y, theta),
+            Twist::new([vx, vy, 0.0], [0.0, 0.0, omega]),
+        );
+
+        // (x, y, yaw) map to entries (0, 1, 5) of the 6x6 message covariances
+        let slots = [0, 1, 5];
+        for (i, &si) in slots.iter().enumerate() {
+            for (j, &sj) in slots.iter().enumerate() {
+                odom.pose_covariance[si * 6 + sj] = covariance[i][j];
+                odom.twist_covariance[si * 6 + sj] = covariance[i + 3][j + 3];
+            }
+        }
+        odom
+    }
+
+    fn publish(&mut self) {
+        let odom = self.estimate();
+
+        if let Some(hframe) = &self.hframe {
+            let transform =
+                Transform::from_euler([odom.pose.x, odom.pose.y, 0.0], [0.0, 0.0, odom.pose.theta]);
+            let _ = hframe.update_transform(&self.config.base_frame, &transform, timestamp_now());
+        }
+
+        let _ = self.output_publisher.send(odom, &mut None);
+    }
+}
+
+impl Node for EkfLocalizationNode {
+    fn name(&self) -> &'static str {
+        "EkfLocalizationNode"
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        if let Some(hframe) = &self.hframe {
+            let (world, base) = (&self.config.world_frame, &self.config.base_frame);
+            if !hframe.has_frame(world) {
+                hframe
+                    .register_frame(world, None)
+                    .map_err(|e| HorusError::InitializationFailed(e.to_string()))?;
+            }
+            if !hframe.has_frame(base) {
+                hframe
+                    .register_frame(base, Some(world))
+                    .map_err(|e| HorusError::InitializationFailed(e.to_string()))?;
+            }
+        }
+
+        let sensors: Vec<&str> = [
+            ("odom", self.odom_subscriber.is_some()),
+            ("imu", self.imu_subscriber.is_some()),
+            ("gps", self.gps_subscriber.is_some()),
+        ]
+        .iter()
+        .filter(|(_, enabled)| *enabled)
+        .map(|(name, _)| *name)
+        .collect();
+        ctx.log_info(&format!(
+            "EkfLocalizationNode: fusing {} in frame '{}'",
+            sensors.join(", "),
+            self.config.world_frame
+        ));
+        Ok(())
+    }
+
+    fn tick(&mut self, _ctx: Option<&mut NodeInfo>) {
+        self.predict_to(Instant::now());
+
+        if let Some(odom) = self
+            .odom_subscriber
+            .as_ref()
+            .and_then(|h| h.recv(&mut None))
+        {
+            self.fuse_odometry(&odom);
+        }
+        if let Some(imu) = self.imu_subscriber.as_ref().and_then(|h| h.recv(&mut None)) {
+            self.fuse_imu(&imu);
+        }
+        if let Some(fix) = self.gps_subscriber.as_ref().and_then(|h| h.recv(&mut None)) {
+            self.fuse_gps(&fix);
+        }
+
+        self.publish();
+    }
+}
+
+// Default impl removed - use EkfLocalizationNode::new() instead which returns HorusResult
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_config_from_project_yaml() {
+        let yaml = r#"
+name: rover
+ekf_localization:
+  world_frame: map
+  odom:
+    fuse: [vx, yaw_rate]
+    variances: [0.02, 0.05]
+  imu:
+    topic: imu.base
+  gps:
+    enabled: false
+"#;
+        let project = ProjectConfig::from_yaml(yaml).unwrap();
+        let config = EkfLocalizationConfig::from_project_config(&project).unwrap();
+        assert_eq!(config.world_frame, "map");
+        assert_eq!(config.base_frame, "base_link");
+        assert_eq!(
+            config.odom.fuse,
+            vec![FusedVariable::Vx, FusedVariable::YawRate]
+        );
+        assert_eq!(config.imu.topic.as_deref(), Some("imu.base"));
+        assert!(config.imu.enabled);
+        assert!(!config.gps.enabled);
+
+        // Missing section gives defaults
+        let project = ProjectConfig::from_yaml("name: rover").unwrap();
+        let config = EkfLocalizationConfig::from_project_config(&project).unwrap();
+        assert_eq!(config, EkfLocalizationConfig::default());
+
+        let project =
+            ProjectConfig::from_yaml("ekf_localization:\n  odom:\n    fuse: [z]").unwrap();
+        assert!(EkfLocalizationConfig::from_project_config(&project).is_err());
+    }
+
+    #[test]
+    fn test_fuses_odometry_and_imu() {
+        let mut node = EkfLocalizationNode::new_with_topics(
+            EkfLocalizationConfig::default(),
+            "test_ekf_fuse.out",
+            "test_ekf_fuse.odom",
+            "test_ekf_fuse.imu",
+            "test_ekf_fuse.gps",
+        )
+        .unwrap();
+        node.set_initial_pose(0.0, 0.0, std::f64::consts::FRAC_PI_2);
+        let t0 = Instant::now();
+        node.predict_to(t0);
+
+        // Driving forward while facing +y
+        let mut odom = Odometry::new();
+        odom.twist = Twist::new_2d(1.0, 0.0);
+        let mut imu = Imu::new();
+        imu.angular_velocity = [0.0, 0.0, 0.0];
+
+        for i in 1..=20 {
+            node.predict_to(t0 + Duration::from_millis(100 * i));
+            node.fuse_odometry(&odom);
+            node.fuse_imu(&imu);
+        }
+
+        let (x, y, theta) = node.get_pose();
+        assert!(x.abs() < 0.1, "x = {}", x);
+        assert!(y > 1.5 && y < 2.1, "y = {}", y);
+        assert!((theta - std::f64::consts::FRAC_PI_2).abs() < 0.05);
+
+        let (vx, vy, _) = node.get_velocity();
+        assert!((vx - 1.0).abs() < 0.05 && vy.abs() < 0.05);
+        assert_eq!(node.measurement_count(), 40);
+
+        let estimate = node.estimate();
+        assert!(estimate.pose_covariance[0] > 0.0);
+        assert!((estimate.twist.linear[0] - vx).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_gps_relative_to_datum() {
+        let mut config = EkfLocalizationConfig::default();
+        config.gps.variances = vec![0.01, 0.01];
+        let mut node = EkfLocalizationNode::new_with_topics(
+            config,
+            "test_ekf_gps.out",
+            "test_ekf_gps.odom",
+            "test_ekf_gps.imu",
+            "test_ekf_gps.gps",
+        )
+        .unwrap();
+
+        // First fix becomes the datum
+        node.fuse_gps(&NavSatFix::from_coordinates(47.0, 8.0, 400.0));
+        assert_eq!(node.get_datum(), Some((47.0, 8.0)));
+
+        // ~11 m north and ~7.6 m east of the datum
+        let fix = NavSatFix::from_coordinates(47.0001, 8.0001, 400.0);
+        for _ in 0..100 {
+            node.fuse_gps(&fix);
+        }
+        let (x, y, _) = node.get_pose();
+        assert!((y - 11.12).abs() < 0.2, "y = {}", y);
+        assert!((x - 7.58).abs() < 0.2, "x = {}", x);
+
+        // Fixes without a solution are ignored
+        let count = node.measurement_count();
+        node.fuse_gps(&NavSatFix::new());
+        assert_eq!(node.measurement_count(), count);
+    }
+
+    #[test]
+    fn test_updates_hframe() {
+        let hf = HFrame::new();
+        let mut node = EkfLocalizationNode::new_with_topics(
+            EkfLocalizationConfig::default(),
+            "test_ekf_hframe.out",
+            "test_ekf_hframe.odom",
+            "test_ekf_hframe.imu",
+            "test_ekf_hframe.gps",
+        )
+        .unwrap();
+        node.set_hframe(hf.clone());
+        node.init(&mut NodeInfo::new("EkfLocalizationNode".to_string(), false))
+            .unwrap();
+        node.set_initial_pose(1.0, 2.0, 0.0);
+        node.publish();
+
+        let tf = hf.tf("base_link", "odom").unwrap();
+        assert!((tf.translation[0] - 1.0).abs() < 1e-9);
+        assert!((tf.translation[1] - 2.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,694 @@
+use crate::drivers::ethercat::{
+    EthercatConfig, EthercatDriver, EthercatDriverBackend, EthercatSlaveConfig, SdoWrite,
+};
+use crate::{EmergencyStop, JointCommand};
+use horus_core::core::{RTNode, RTPriority};
+use horus_core::error::{HorusError, HorusResult};
+
+// Import algorithms from horus_library/algorithms
+use crate::algorithms::cia402::{Cia402Drive, Cia402State, Cia402Target, OperationMode};
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo};
+use std::time::Duration;
+
+/// RxPDO 0x1600: controlword, target position, target velocity, target torque, mode
+const RX_PDO_ENTRIES: [u32; 5] = [
+    0x6040_0010,
+    0x607A_0020,
+    0x60FF_0020,
+    0x6071_0010,
+    0x6060_0008,
+];
+/// TxPDO 0x1A00: statusword, actual position, actual velocity, actual torque, mode display
+const TX_PDO_ENTRIES: [u32; 5] = [
+    0x6041_0010,
+    0x6064_0020,
+    0x606C_0020,
+    0x6077_0010,
+    0x6061_0008,
+];
+/// Size of both PDOs in bytes
+const PDO_BYTES: usize = 13;
+
+/// Configuration of one servo axis (one drive per slave, in bus order)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServoAxisConfig {
+    /// Joint name used in `JointCommand` messages
+    pub joint: String,
+    /// Cyclic synchronous mode (position, velocity or torque)
+    pub mode: OperationMode,
+    /// Encoder counts per radian at the joint (including gearing)
+    pub counts_per_radian: f64,
+    /// Motor rated torque in Nm (object 0x6076), torques are per mille of it
+    pub rated_torque: f64,
+}
+
+impl ServoAxisConfig {
+    /// Cyclic synchronous position axis
+    pub fn new(joint: &str, counts_per_radian: f64) -> Self {
+        Self {
+            joint: joint.to_string(),
+            mode: OperationMode::CyclicSynchronousPosition,
+            counts_per_radian,
+            rated_torque: 1.0,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: OperationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn with_rated_torque(mut self, rated_torque: f64) -> Self {
+        self.rated_torque = rated_torque;
+        self
+    }
+}
+
+/// Feedback of one axis from the last cycle
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ServoAxisFeedback {
+    pub statusword: u16,
+    pub state: Cia402State,
+    /// Position in radians
+    pub position: f64,
+    /// Velocity in rad/s
+    pub velocity: f64,
+    /// Torque in Nm
+    pub torque: f64,
+    /// Mode of operation display (0x6061)
+    pub mode: i8,
+}
+
+/// Setpoints of one axis
+#[derive(Debug, Clone, Copy, Default)]
+struct AxisTarget {
+    position: f64,
+    velocity: f64,
+    torque: f64,
+}
+
+/// EtherCAT Servo Node - CiA-402 servo drives on an EtherCAT bus
+///
+/// Runs the EtherCAT process data cycle and the CiA-402 state machine of
+/// each drive. Joint setpoints are taken from `JointCommand` messages and
+/// actual joint positions, velocities and torques are published as
+/// `JointCommand` joint states.
+///
+/// The standard PDO mapping is written to every drive during bus start-up,
+/// together with the mode of operation and the interpolation period. Drives
+/// are enabled automatically; while a drive is not enabled its position
+/// setpoint follows the actual position so enabling never causes a jump.
+///
+/// An engaged emergency stop puts all drives into quick stop. Repeated
+/// working counter errors (a slave not answering) do the same and mark the
+/// bus as faulted until [`reset_faults`](Self::reset_faults).
+///
+/// The node implements `RTNode`; add it with `Scheduler::add_rt` using
+/// its `wcet_budget()` and `deadline()` so the cycle runs in the real-time
+/// loop at `rate_hz()`.
+///
+/// This node is a thin wrapper around the pure algorithm in horus_library/algorithms.
+pub struct EthercatServoNode {
+    // Publishers and Subscribers
+    command_subscriber: Hub<JointCommand>,
+    estop_subscriber: Hub<EmergencyStop>,
+    state_publisher: Hub<JointCommand>,
+
+    // Driver (handles hardware abstraction)
+    driver: EthercatDriver,
+
+    // Algorithm instances (one per axis)
+    drives: Vec<Cia402Drive>,
+
+    // Configuration
+    axes: Vec<ServoAxisConfig>,
+    cycle_time: Duration,
+    max_wkc_errors: u32,
+
+    // State
+    targets: Vec<AxisTarget>,
+    feedback: Vec<ServoAxisFeedback>,
+    enabled: bool,
+    estop_active: bool,
+    safe_stop: bool,
+    bus_fault: bool,
+    wkc_errors: u32,
+    cycle_count: u64,
+}
+
+impl EthercatServoNode {
+    /// Create a new EtherCAT servo node with default topics and a 1 ms cycle
+    ///
+    /// Uses the SOEM master with the `ethercat-hardware` feature and the
+    /// simulation driver otherwise.
+    pub fn new(interface: &str, axes: Vec<ServoAxisConfig>) -> Result<Self> {
+        Self::new_with_topics(
+            interface,
+            axes,
+            "joint_command",
+            "joint_states",
+            "emergency_stop",
+        )
+    }
+
+    /// Create a new EtherCAT servo node with custom topics
+    pub fn new_with_topics(
+        interface: &str,
+        axes: Vec<ServoAxisConfig>,
+        command_topic: &str,
+        state_topic: &str,
+        estop_topic: &str,
+    ) -> Result<Self> {
+        #[cfg(feature = "ethercat-hardware")]
+        let backend = EthercatDriverBackend::Soem;
+        #[cfg(not(feature = "ethercat-hardware"))]
+        let backend = EthercatDriverBackend::Simulation;
+
+        let config = Self::bus_config(interface, 1000, &axes);
+        let driver = EthercatDriver::new(backend, config)?;
+        Self::new_with_driver(driver, axes, command_topic, state_topic, estop_topic)
+    }
+
+    /// Create a node on an existing driver
+    ///
+    /// The driver must be configured with [`bus_config`](Self::bus_config)
+    /// for the same axes.
+    pub fn new_with_driver(
+        driver: EthercatDriver,
+        axes: Vec<ServoAxisConfig>,
+        command_topic: &str,
+        state_topic: &str,
+        estop_topic: &str,
+    ) -> Result<Self> {
+        if axes.is_empty() || axes.len() > 16 {
+            return Err(HorusError::InvalidInput(
+                "EtherCAT servo node needs 1 to 16 axes".to_string(),
+            ));
+        }
+        for axis in &axes {
+            if !axis.mode.is_cyclic() {
+                return Err(HorusError::InvalidInput(format!(
+                    "Axis {}: only cyclic synchronous modes are supported",
+                    axis.joint
+                )));
+            }
+            if axis.counts_per_radian == 0.0 || axis.rated_torque <= 0.0 {
+                return Err(HorusError::InvalidInput(format!(
+                    "Axis {}: counts per radian and rated torque must be set",
+                    axis.joint
+                )));
+            }
+        }
+        let config = driver.config();
+        if config.slaves.len() != axes.len()
+            || config
+                .slaves
+                .iter()
+                .any(|s| s.output_bytes != PDO_BYTES || s.input_bytes != PDO_BYTES)
+        {
+            return Err(HorusError::InvalidInput(
+                "EtherCAT driver is not configured for the servo axes".to_string(),
+            ));
+        }
+        let cycle_time = Duration::from_micros(config.cycle_time_us.max(1));
+
+        Ok(Self {
+            command_subscriber: Hub::new(command_topic)?,
+            estop_subscriber: Hub::new(estop_topic)?,
+            state_publisher: Hub::new(state_topic)?,
+
+            driver,
+
+            drives: vec![Cia402Drive::new(); axes.len()],
+
+            cycle_time,
+            max_wkc_errors: 3,
+
+            targets: vec![AxisTarget::default(); axes.len()],
+            feedback: vec![ServoAxisFeedback::default(); axes.len()],
+            axes,
+            enabled: true,
+            estop_active: false,
+            safe_stop: false,
+            bus_fault: false,
+            wkc_errors: 0,
+            cycle_count: 0,
+        })
+    }
+
+    /// EtherCAT bus configuration for the axes
+    ///
+    /// Each slave gets the standard CiA-402 PDO mapping, its mode of
+    /// operation and the interpolation period of the cycle time.
+    pub fn bus_config(
+        interface: &str,
+        cycle_time_us: u64,
+        axes: &[ServoAxisConfig],
+    ) -> EthercatConfig {
+        let slaves = axes
+            .iter()
+            .map(|axis| EthercatSlaveConfig {
+                name: axis.joint.clone(),
+                output_bytes: PDO_BYTES,
+                input_bytes: PDO_BYTES,
+                sdo_init: Self::axis_sdo_init(axis, cycle_time_us),
+            })
+            .collect();
+
+        EthercatConfig {
+            interface: interface.to_string(),
+            cycle_time_us,
+            receive_timeout_us: cycle_time_us / 2,
+            slaves,
+            ..Default::default()
+        }
+    }
+
+    fn axis_sdo_init(axis: &ServoAxisConfig, cycle_time_us: u64) -> Vec<SdoWrite> {
+        let mut sdo = Vec::new();
+        for (assign, pdo, entries) in [
+            (0x1C12, 0x1600, RX_PDO_ENTRIES),
+            (0x1C13, 0x1A00, TX_PDO_ENTRIES),
+        ] {
+            // Mappings can only be changed while unassigned and empty
+            sdo.push(SdoWrite::u8(assign, 0, 0));
+            sdo.push(SdoWrite::u8(pdo, 0, 0));
+            for (i, entry) in entries.iter().enumerate() {
+                sdo.push(SdoWrite::u32(pdo, i as u8 + 1, *entry));
+            }
+            sdo.push(SdoWrite::u8(pdo, 0, entries.len() as u8));
+            sdo.push(SdoWrite::u16(assign, 1, pdo));
+            sdo.push(SdoWrite::u8(assign, 0, 1));
+        }
+        sdo.push(SdoWrite::u8(0x6060, 0, axis.mode.code() as u8));
+
+        // Interpolation time period: value * 10^index seconds, in ms where possible
+        let mut value = cycle_time_us;
+        let mut index: i8 = -6;
+        loop {
+            let remainder = value % 10;
+            if value == 0 || remainder != 0 || (index >= -3 && value <= u8::MAX as u64) {
+                break;
+            }
+            value /= 10;
+            index += 1;
+        }
+        sdo.push(SdoWrite::u8(0x60C2, 1, value.min(u8::MAX as u64) as u8));
+        sdo.push(SdoWrite::u8(0x60C2, 2, index as u8));
+        sdo
+    }
+
+    /// Enable or disable all drives (disabled = power stage off)
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    /// Consecutive working counter errors before the bus is faulted
+    pub fn set_max_wkc_errors(&mut self, max_errors: u32) {
+        self.max_wkc_errors = max_errors.max(1);
+    }
+
+    /// Clear the bus fault and reset drive faults
+    pub fn reset_faults(&mut self) {
+        self.bus_fault = false;
+        self.safe_stop = false;
+        self.wkc_errors = 0;
+        for drive in &mut self.drives {
+            drive.reset_fault();
+        }
+    }
+
+    /// Get feedback of all axes
+    pub fn get_feedback(&self) -> &[ServoAxisFeedback] {
+        &self.feedback
+    }
+
+    /// Get the CiA-402 state of all axes
+    pub fn get_states(&self) -> Vec<Cia402State> {
+        self.drives.iter().map(|d| d.state()).collect()
+    }
+
+    pub fn is_bus_faulted(&self) -> bool {
+        self.bus_fault
+    }
+
+    pub fn get_cycle_time(&self) -> Duration {
+        self.cycle_time
+    }
+
+    pub fn get_cycle_count(&self) -> u64 {
+        self.cycle_count
+    }
+
+    pub fn get_driver(&self) -> &EthercatDriver {
+        &self.driver
+    }
+
+    pub fn get_driver_mut(&mut self) -> &mut EthercatDriver {
+        &mut self.driver
+    }
+
+    fn apply_command(&mut self, command: &JointCommand) {
+        let count = (command.joint_count as usize).min(16);
+        for i in 0..count {
+            let name = &command.joint_names[i];
+            let end = name.iter().position(|&b| b == 0).unwrap_or(name.len());
+            let Ok(name) = std::str::from_utf8(&name[..end]) else {
+                continue;
+            };
+            let Some(axis) = self.axes.iter().position(|a| a.joint == name) else {
+                continue;
+            };
+            let target = &mut self.targets[axis];
+            match command.modes[i] {
+                JointCommand::MODE_POSITION => target.position = command.positions[i],
+                JointCommand::MODE_VELOCITY => target.velocity = command.velocities[i],
+                JointCommand::MODE_EFFORT => target.torque = command.efforts[i],
+                _ => {}
+            }
+        }
+    }
+
+    fn read_inputs(&mut self) {
+        for (slave, axis) in self.axes.iter().enumerate() {
+            let Ok(inputs) = self.driver.inputs(slave) else {
+                continue;
+            };
+            let statusword = u16::from_le_bytes([inputs[0], inputs[1]]);
+            let position = i32::from_le_bytes([inputs[2], inputs[3], inputs[4], inputs[5]]);
+            let velocity = i32::from_le_bytes([inputs[6], inputs[7], inputs[8], inputs[9]]);
+            let torque = i16::from_le_bytes([inputs[10], inputs[11]]);
+
+            self.feedback[slave] = ServoAxisFeedback {
+                statusword,
+                state: Cia402State::from_statusword(statusword),
+                position: position as f64 / axis.counts_per_radian,
+                velocity: velocity as f64 / axis.counts_per_radian,
+                torque: torque as f64 / 1000.0 * axis.rated_torque,
+                mode: inputs[12] as i8,
+            };
+        }
+    }
+
+    fn write_outputs(&mut self) {
+        for (slave, axis) in self.axes.iter().enumerate() {
+            let target = self.targets[slave];
+            let controlword = self.drives[slave].controlword();
+            let position = (target.position * axis.counts_per_radian).round() as i32;
+            let velocity = (target.velocity * axis.counts_per_radian).round() as i32;
+            let torque = (target.torque / axis.rated_torque * 1000.0)
+                .round()
+                .clamp(i16::MIN as f64, i16::MAX as f64) as i16;
+
+            let Ok(outputs) = self.driver.outputs_mut(slave) else {
+                continue;
+            };
+            outputs[0..2].copy_from_slice(&controlword.to_le_bytes());
+            outputs[2..6].copy_from_slice(&position.to_le_bytes());
+            outputs[6..10].copy_from_slice(&velocity.to_le_bytes());
+            outputs[10..12].copy_from_slice(&torque.to_le_bytes());
+            outputs[12] = axis.mode.code() as u8;
+        }
+    }
+
+    /// One process data cycle
+    fn cycle(&mut self, ctx: Option<&mut NodeInfo>) {
+        self.cycle_count += 1;
+
+        // Exchange first so the cycle-to-cycle jitter stays minimal
+        let expected = self.driver.layout().expected_wkc();
+        let wkc_ok = match self.driver.exchange() {
+            Ok(wkc) => wkc >= expected,
+            Err(_) => false,
+        };
+        if wkc_ok {
+            self.wkc_errors = 0;
+            self.read_inputs();
+        } else {
+            self.wkc_errors += 1;
+            if self.wkc_errors >= self.max_wkc_errors && !self.bus_fault {
+                self.bus_fault = true;
+                if let Some(ctx) = ctx {
+                    ctx.log_error(&format!(
+                        "EtherCAT working counter below {} for {} cycles - stopping drives",
+                        expected, self.wkc_errors
+                    ));
+                }
+            }
+        }
+
+        let target = if self.estop_active || self.safe_stop || self.bus_fault {
+            Cia402Target::QuickStop
+        } else if self.enabled {
+            Cia402Target::Enabled
+        } else {
+            Cia402Target::Disabled
+        };
+
+        for (axis, drive) in self.drives.iter_mut().enumerate() {
+            drive.set_target(target);
+            drive.update(self.feedback[axis].statusword);
+
+            // Track the actual position until the drive is enabled
+            if !drive.state().is_enabled() || target != Cia402Target::Enabled {
+                self.targets[axis] = AxisTarget {
+                    position: self.feedback[axis].position,
+                    velocity: 0.0,
+                    torque: 0.0,
+                };
+            }
+        }
+
+        self.write_outputs();
+    }
+
+    fn publish_states(&mut self) {
+        let mut states = JointCommand::new();
+        for (axis, feedback) in self.axes.iter().zip(&self.feedback) {
+            let _ = states.add_position(&axis.joint, feedback.position);
+            let i = states.joint_count as usize - 1;
+            states.velocities[i] = feedback.velocity;
+            states.efforts[i] = feedback.torque;
+        }
+        let _ = self.state_publisher.send(states, &mut None);
+    }
+}
+
+impl Node for EthercatServoNode {
+    fn name(&self) -> &'static str {
+        "EthercatServoNode"
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        self.driver.init()?;
+        ctx.log_info(&format!(
+            "EtherCAT bus on {} in OP with {} drives, cycle {} us",
+            self.driver.config().interface,
+            self.driver.slave_count(),
+            self.cycle_time.as_micros()
+        ));
+        Ok(())
+    }
+
+    fn shutdown(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        ctx.log_info("EthercatServoNode shutting down - disabling drives");
+
+        // Send a few cycles of shutdown so the drives switch off their power stage
+        self.enabled = false;
+        for _ in 0..3 {
+            self.cycle(None);
+            std::thread::sleep(self.cycle_time);
+        }
+        self.driver.shutdown()
+    }
+
+    fn tick(&mut self, ctx: Option<&mut NodeInfo>) {
+        // Check for emergency stop
+        while let Some(estop) = self.estop_subscriber.recv(&mut None) {
+            self.estop_active = estop.engaged;
+        }
+
+        // Check for new joint setpoints
+        while let Some(command) = self.command_subscriber.recv(&mut None) {
+            self.apply_command(&command);
+        }
+
+        self.cycle(ctx);
+        self.publish_states();
+    }
+
+    fn rate_hz(&self) -> Option<f64> {
+        Some(1.0 / self.cycle_time.as_secs_f64())
+    }
+
+    fn is_healthy(&self) -> bool {
+        !self.bus_fault && !self.drives.iter().any(|d| d.state().is_fault())
+    }
+}
+
+impl RTNode for EthercatServoNode {
+    fn wcet_budget(&self) -> Duration {
+        self.cycle_time / 2
+    }
+
+    fn deadline(&self) -> Duration {
+        self.cycle_time
+    }
+
+    fn rt_priority(&self) -> RTPriority {
+        RTPriority::Critical
+    }
+
+    fn is_safe_state(&self) -> bool {
+        !self.drives.iter().any(|d| d.state().is_enabled())
+    }
+
+    fn enter_safe_state(&mut self, ctx: &mut NodeInfo) {
+        ctx.log_warning("EthercatServoNode entering safe state - quick stop");
+        self.safe_stop = true;
+        self.cycle(Some(ctx));
+    }
+}
+
+// Default impl removed - use EthercatServoNode::new() instead which returns HorusResult
+
+#[cfg(test)]
+#[allow(irrefutable_let_patterns)]
+mod tests {
+    use super::*;
+
+    /// Initialized simulated bus for `axes`
+    fn sim_driver(axes: &[ServoAxisConfig]) -> EthercatDriver {
+        let config = EthercatServoNode::bus_config("sim0", 1000, axes);
+        let mut driver = EthercatDriver::new(EthercatDriverBackend::Simulation, config).unwrap();
+        driver.init().unwrap();
+        driver
+    }
+
+    /// Write the drive side of the process image
+    fn set_inputs(node: &mut EthercatServoNode, slave: usize, statusword: u16, position: i32) {
+        let EthercatDriver::Simulation(driver) = node.get_driver_mut() else {
+            unreachable!()
+        };
+        let inputs = driver.image_mut().inputs_mut(slave).unwrap();
+        inputs[0..2].copy_from_slice(&statusword.to_le_bytes());
+        inputs[2..6].copy_from_slice(&position.to_le_bytes());
+    }
+
+    fn outputs(node: &EthercatServoNode, slave: usize) -> (u16, i32) {
+        let EthercatDriver::Simulation(driver) = node.get_driver() else {
+            unreachable!()
+        };
+        let outputs = driver.image().outputs(slave).unwrap();
+        (
+            u16::from_le_bytes([outputs[0], outputs[1]]),
+            i32::from_le_bytes([outputs[2], outputs[3], outputs[4], outputs[5]]),
+        )
+    }
+
+    #[test]
+    fn test_bus_config_pdo_mapping() {
+        let axes = vec![ServoAxisConfig::new("joint1", 10000.0)];
+        let config = EthercatServoNode::bus_config("eth1", 1000, &axes);
+        let slave = &config.slaves[0];
+        assert_eq!(slave.output_bytes, PDO_BYTES);
+        assert_eq!(slave.input_bytes, PDO_BYTES);
+
+        let mapped_bits: u32 = RX_PDO_ENTRIES.iter().map(|e| e & 0xFF).sum();
+        assert_eq!(mapped_bits as usize, PDO_BYTES * 8);
+        assert!(slave.sdo_init.contains(&SdoWrite::u16(0x1C12, 1, 0x1600)));
+        assert!(slave.sdo_init.contains(&SdoWrite::u8(0x6060, 0, 8)));
+        // 1000 us = 1 * 10^-3 s
+        assert!(slave.sdo_init.contains(&SdoWrite::u8(0x60C2, 1, 1)));
+        assert!(slave
+            .sdo_init
+            .contains(&SdoWrite::u8(0x60C2, 2, (-3i8) as u8)));
+    }
+
+    #[test]
+    fn test_invalid_axes_rejected() {
+        let axes =
+            vec![ServoAxisConfig::new("joint1", 10000.0).with_mode(OperationMode::ProfilePosition)];
+        let config = EthercatServoNode::bus_config("sim0", 1000, &axes);
+        let driver = EthercatDriver::new(EthercatDriverBackend::Simulation, config).unwrap();
+        assert!(EthercatServoNode::new_with_driver(driver, axes, "a", "b", "c").is_err());
+    }
+
+    #[test]
+    fn test_enable_and_follow_commands() {
+        let axes = vec![ServoAxisConfig::new("joint1", 1000.0)];
+        let mut node = EthercatServoNode::new_with_driver(
+            sim_driver(&axes),
+            axes,
+            "test_ethercat_enable.command",
+            "test_ethercat_enable.states",
+            "test_ethercat_enable.estop",
+        )
+        .unwrap();
+
+        // Drive powers up at 1.5 rad
+        set_inputs(&mut node, 0, 0x0250, 1500);
+        node.cycle(None);
+        assert_eq!(outputs(&node, 0), (0x0006, 1500));
+
+        set_inputs(&mut node, 0, 0x0231, 1500);
+        node.cycle(None);
+        set_inputs(&mut node, 0, 0x0233, 1500);
+        node.cycle(None);
+        assert_eq!(outputs(&node, 0), (0x000F, 1500));
+
+        set_inputs(&mut node, 0, 0x0237, 1500);
+        node.cycle(None);
+        assert_eq!(node.get_states(), vec![Cia402State::OperationEnabled]);
+
+        let mut command = JointCommand::new();
+        command.add_position("joint1", 2.0).unwrap();
+        command.add_position("unknown", 5.0).unwrap();
+        node.apply_command(&command);
+        node.cycle(None);
+        assert_eq!(outputs(&node, 0), (0x000F, 2000));
+        assert!((node.get_feedback()[0].position - 1.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_estop_and_bus_fault_quick_stop() {
+        let axes = vec![ServoAxisConfig::new("joint1", 1000.0)];
+        let mut node = EthercatServoNode::new_with_driver(
+            sim_driver(&axes),
+            axes,
+            "test_ethercat_estop.command",
+            "test_ethercat_estop.states",
+            "test_ethercat_estop.estop",
+        )
+        .unwrap();
+        set_inputs(&mut node, 0, 0x0237, 0);
+
+        node.estop_active = true;
+        node.cycle(None);
+        assert_eq!(outputs(&node, 0).0, 0x0002);
+        node.estop_active = false;
+        node.cycle(None);
+        assert_eq!(outputs(&node, 0).0, 0x000F);
+
+        node.set_max_wkc_errors(2);
+        if let EthercatDriver::Simulation(driver) = node.get_driver_mut() {
+            driver.set_responding(false);
+        }
+        node.cycle(None);
+        assert!(!node.is_bus_faulted());
+        node.cycle(None);
+        assert!(node.is_bus_faulted());
+        assert!(!node.is_healthy());
+        assert_eq!(outputs(&node, 0).0, 0x0002);
+
+        if let EthercatDriver::Simulation(driver) = node.get_driver_mut() {
+            driver.set_responding(true);
+        }
+        node.reset_faults();
+        node.cycle(None);
+        assert_eq!(outputs(&node, 0).0, 0x000F);
+    }
+}
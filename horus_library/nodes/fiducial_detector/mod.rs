@@ -0,0 +1,516 @@
+use crate::hframe::{HFrame, Transform};
+use crate::{CameraInfo, FiducialArray, FiducialDetection, FiducialFamily, Image, ImageEncoding};
+use horus_core::error::{HorusError, HorusResult};
+use std::collections::HashMap;
+
+// Import algorithms from horus_library/algorithms
+use crate::algorithms::fiducial;
+use crate::algorithms::image_ops;
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo, NodeInfoExt};
+
+#[cfg(feature = "opencv")]
+use opencv::{
+    core::{Mat, Point2f, Vector},
+    objdetect,
+    prelude::*,
+};
+
+/// Fiducial Detector Node - AprilTag/ArUco detection with 6-DoF poses
+///
+/// Finds square fiducial markers in camera images and estimates each
+/// marker's pose in the camera optical frame from its corners, the marker
+/// size and the `CameraInfo` calibration. Typical uses are docking onto a
+/// tagged charger, camera-to-robot calibration and localizing objects.
+///
+/// Markers are detected with the OpenCV ArUco module, which also decodes the
+/// AprilTag families. Detections are published as a `FiducialArray`. When an
+/// HFrame is attached, every seen marker also gets a `tag_<id>` frame under
+/// the camera frame.
+///
+/// Poses require the camera calibration: images are skipped until a
+/// `CameraInfo` has been received or set with `set_camera_info()`.
+///
+/// This node is a thin wrapper around the pure algorithm in horus_library/algorithms.
+pub struct FiducialDetectorNode {
+    // Publishers and Subscribers
+    image_subscriber: Hub<Image>,
+    camera_info_subscriber: Hub<CameraInfo>,
+    fiducial_publisher: Hub<FiducialArray>,
+
+    // Configuration
+    family: FiducialFamily,
+    tag_size: f64,
+    tag_sizes: HashMap<u32, f64>,
+    camera_frame: Option<String>,
+    tag_frame_prefix: String,
+    max_reprojection_error: f64,
+    hframe: Option<HFrame>,
+
+    // State
+    camera_info: Option<CameraInfo>,
+    last_detections: FiducialArray,
+    images_processed: u64,
+    markers_detected: u64,
+
+    #[cfg(feature = "opencv")]
+    detector: Option<objdetect::ArucoDetector>,
+}
+
+impl FiducialDetectorNode {
+    /// Create a new fiducial detector with default topics
+    pub fn new() -> Result<Self> {
+        Self::new_with_topics("camera.image", "camera.info", "fiducials")
+    }
+
+    /// Create a new fiducial detector with custom topics
+    pub fn new_with_topics(
+        image_topic: &str,
+        camera_info_topic: &str,
+        output_topic: &str,
+    ) -> Result<Self> {
+        Ok(Self {
+            image_subscriber: Hub::new(image_topic)?,
+            camera_info_subscriber: Hub::new(camera_info_topic)?,
+            fiducial_publisher: Hub::new(output_topic)?,
+
+            family: FiducialFamily::AprilTag36h11,
+            tag_size: 0.16,
+            tag_sizes: HashMap::new(),
+            camera_frame: None,
+            tag_frame_prefix: "tag_".to_string(),
+            max_reprojection_error: 3.0,
+            hframe: None,
+
+            camera_info: None,
+            last_detections: FiducialArray::default(),
+            images_processed: 0,
+            markers_detected: 0,
+
+            #[cfg(feature = "opencv")]
+            detector: None,
+        })
+    }
+
+    /// Set the marker family to detect
+    pub fn set_family(&mut self, family: FiducialFamily) {
+        self.family = family;
+        #[cfg(feature = "opencv")]
+        {
+            self.detector = None;
+        }
+    }
+
+    /// Set the default marker side length in meters (black border, outer edge)
+    pub fn set_tag_size(&mut self, size: f64) {
+        self.tag_size = size;
+    }
+
+    /// Set the side length of one marker ID, overriding the default
+    pub fn set_tag_size_for_id(&mut self, id: u32, size: f64) {
+        self.tag_sizes.insert(id, size);
+    }
+
+    /// Set the camera frame ID (default: the image's frame ID, or "camera")
+    pub fn set_camera_frame(&mut self, frame: &str) {
+        self.camera_frame = Some(frame.to_string());
+    }
+
+    /// Set the prefix of the marker frames published to HFrame
+    pub fn set_tag_frame_prefix(&mut self, prefix: &str) {
+        self.tag_frame_prefix = prefix.to_string();
+    }
+
+    /// Drop detections whose corners reproject worse than this (pixels)
+    pub fn set_max_reprojection_error(&mut self, pixels: f64) {
+        self.max_reprojection_error = pixels;
+    }
+
+    /// Use a fixed calibration instead of waiting for `CameraInfo` messages
+    pub fn set_camera_info(&mut self, info: CameraInfo) {
+        self.camera_info = Some(info);
+    }
+
+    /// Publish `<prefix><id>` frames for detected markers into this HFrame
+    ///
+    /// The camera frame is registered as a root frame if it does not exist
+    /// yet; register it under the robot first (e.g. with
+    /// `StaticTransformNode`) to get marker poses in the robot frame.
+    pub fn set_hframe(&mut self, hframe: HFrame) {
+        self.hframe = Some(hframe);
+    }
+
+    /// Get the marker family
+    pub fn get_family(&self) -> FiducialFamily {
+        self.family
+    }
+
+    /// Get the detections of the last processed image
+    pub fn get_last_detections(&self) -> &FiducialArray {
+        &self.last_detections
+    }
+
+    /// Get statistics (images processed, markers detected)
+    pub fn get_stats(&self) -> (u64, u64) {
+        (self.images_processed, self.markers_detected)
+    }
+
+    /// Marker side length for an ID
+    fn tag_size_for(&self, id: u32) -> f64 {
+        self.tag_sizes.get(&id).copied().unwrap_or(self.tag_size)
+    }
+
+    fn frame_for(&self, image: &Image) -> String {
+        if let Some(frame) = &self.camera_frame {
+            return frame.clone();
+        }
+        let end = image.frame_id.iter().position(|&b| b == 0).unwrap_or(32);
+        match String::from_utf8_lossy(&image.frame_id[..end]) {
+            name if name.is_empty() => "camera".to_string(),
+            name => name.into_owned(),
+        }
+    }
+
+    /// Estimate poses for detected corners, dropping unusable markers
+    fn build_detections(
+        &self,
+        markers: &[(u32, [[f64; 2]; 4])],
+        camera: &CameraInfo,
+        frame: &str,
+        timestamp: u64,
+    ) -> FiducialArray {
+        let mut array = FiducialArray::new(frame, timestamp);
+        for &(id, corners) in markers {
+            let size = self.tag_size_for(id);
+            let pose = match fiducial::estimate_marker_pose(&corners, size, camera) {
+                Ok(pose) if pose.reprojection_error <= self.max_reprojection_error => pose,
+                _ => continue,
+            };
+            let detection = FiducialDetection {
+                id,
+                family: self.family,
+                corners,
+                size,
+                pose: pose.transform(),
+                reprojection_error: pose.reprojection_error,
+            };
+            if array.add_detection(detection).is_err() {
+                break;
+            }
+        }
+        array
+    }
+
+    /// Update the marker frames in the attached HFrame
+    fn update_hframe(&self, array: &FiducialArray) {
+        let hframe = match &self.hframe {
+            Some(hframe) => hframe,
+            None => return,
+        };
+        let camera_frame = array.frame_id_str();
+        if !hframe.has_frame(&camera_frame) && hframe.register_frame(&camera_frame, None).is_err() {
+            return;
+        }
+
+        for detection in array.get_detections() {
+            let name = format!("{}{}", self.tag_frame_prefix, detection.id);
+            if !hframe.has_frame(&name)
+                && hframe.register_frame(&name, Some(&camera_frame)).is_err()
+            {
+                continue;
+            }
+            let transform = Transform::new(detection.pose.translation, detection.pose.rotation);
+            let _ = hframe.update_transform(&name, &transform, array.timestamp);
+        }
+    }
+
+    /// Find marker corners in an image
+    #[cfg(feature = "opencv")]
+    fn detect_markers(&mut self, image: &Image) -> Result<Vec<(u32, [[f64; 2]; 4])>> {
+        let opencv_error = |e: opencv::Error| HorusError::Driver(format!("OpenCV: {}", e));
+
+        if self.detector.is_none() {
+            let dictionary = objdetect::get_predefined_dictionary(dictionary_type(self.family))
+                .map_err(opencv_error)?;
+            let mut parameters = objdetect::DetectorParameters::default().map_err(opencv_error)?;
+            parameters.set_corner_refinement_method(
+                objdetect::CornerRefineMethod::CORNER_REFINE_SUBPIX as i32,
+            );
+            let refine = objdetect::RefineParameters::new(10.0, 3.0, true).map_err(opencv_error)?;
+            self.detector = Some(
+                objdetect::ArucoDetector::new(&dictionary, &parameters, refine)
+                    .map_err(opencv_error)?,
+            );
+        }
+        let detector = match &self.detector {
+            Some(detector) => detector,
+            None => return Ok(Vec::new()),
+        };
+
+        let pixels = gray_pixels(image)?;
+        let mat = Mat::from_slice(&pixels).map_err(opencv_error)?;
+        let gray = mat
+            .reshape(1, image.height as i32)
+            .and_then(|m| m.try_clone())
+            .map_err(opencv_error)?;
+
+        let mut corners: Vector<Vector<Point2f>> = Vector::new();
+        let mut ids: Vector<i32> = Vector::new();
+        let mut rejected: Vector<Vector<Point2f>> = Vector::new();
+        detector
+            .detect_markers(&gray, &mut corners, &mut ids, &mut rejected)
+            .map_err(opencv_error)?;
+
+        Ok(ids
+            .iter()
+            .zip(corners.iter())
+            .filter(|(_, c)| c.len() == 4)
+            .map(|(id, c)| {
+                let mut points = [[0.0; 2]; 4];
+                for (point, p) in points.iter_mut().zip(c.iter()) {
+                    *point = [p.x as f64, p.y as f64];
+                }
+                (id as u32, points)
+            })
+            .collect())
+    }
+
+    /// Find marker corners in an image (no detector without OpenCV)
+    #[cfg(not(feature = "opencv"))]
+    fn detect_markers(&mut self, _image: &Image) -> Result<Vec<(u32, [[f64; 2]; 4])>> {
+        Ok(Vec::new())
+    }
+
+    fn process_image(&mut self, image: &Image) -> Result<Option<FiducialArray>> {
+        let camera = match self.camera_info {
+            Some(camera) => camera,
+            None => return Ok(None),
+        };
+
+        let markers = self.detect_markers(image)?;
+        let frame = self.frame_for(image);
+        let array = self.build_detections(&markers, &camera, &frame, image.timestamp);
+
+        self.images_processed += 1;
+        self.markers_detected += array.count as u64;
+        self.update_hframe(&array);
+        self.last_detections = array.clone();
+        Ok(Some(array))
+    }
+}
+
+/// Contiguous 8-bit grayscale pixels of an image
+fn gray_pixels(image: &Image) -> Result<Vec<u8>> {
+    let gray = if image.encoding == ImageEncoding::Mono8 {
+        if !image.is_valid() {
+            return Err(HorusError::InvalidInput(format!(
+                "Invalid {}x{} image",
+                image.width, image.height
+            )));
+        }
+        image.clone()
+    } else {
+        image_ops::convert(image, ImageEncoding::Mono8)?
+    };
+
+    let (width, height) = (gray.width as usize, gray.height as usize);
+    Ok(gray
+        .data
+        .chunks(gray.step as usize)
+        .take(height)
+        .flat_map(|row| &row[..width])
+        .copied()
+        .collect())
+}
+
+#[cfg(feature = "opencv")]
+fn dictionary_type(family: FiducialFamily) -> objdetect::PredefinedDictionaryType {
+    use objdetect::PredefinedDictionaryType as Dict;
+    match family {
+        FiducialFamily::Aruco4x4_50 => Dict::DICT_4X4_50,
+        FiducialFamily::Aruco5x5_100 => Dict::DICT_5X5_100,
+        FiducialFamily::Aruco6x6_250 => Dict::DICT_6X6_250,
+        FiducialFamily::ArucoOriginal => Dict::DICT_ARUCO_ORIGINAL,
+        FiducialFamily::AprilTag16h5 => Dict::DICT_APRILTAG_16h5,
+        FiducialFamily::AprilTag25h9 => Dict::DICT_APRILTAG_25h9,
+        FiducialFamily::AprilTag36h10 => Dict::DICT_APRILTAG_36h10,
+        FiducialFamily::AprilTag36h11 => Dict::DICT_APRILTAG_36h11,
+    }
+}
+
+impl Node for FiducialDetectorNode {
+    fn name(&self) -> &'static str {
+        "FiducialDetectorNode"
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        ctx.log_info(&format!(
+            "FiducialDetectorNode: detecting {} markers, default size {:.3} m",
+            self.family.as_str(),
+            self.tag_size
+        ));
+        #[cfg(not(feature = "opencv"))]
+        ctx.log_warning(
+            "FiducialDetectorNode: opencv feature not enabled, no markers will be detected",
+        );
+        Ok(())
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        while let Some(info) = self.camera_info_subscriber.recv(&mut None) {
+            self.camera_info = Some(info);
+        }
+
+        if let Some(image) = self.image_subscriber.recv(&mut None) {
+            match self.process_image(&image) {
+                Ok(Some(array)) => {
+                    let _ = self.fiducial_publisher.send(array, &mut None);
+                }
+                Ok(None) => {
+                    ctx.log_debug("FiducialDetectorNode: waiting for camera info");
+                }
+                Err(e) => {
+                    ctx.log_warning(&format!("FiducialDetectorNode: {}", e));
+                }
+            }
+        }
+    }
+}
+
+// Default impl removed - use FiducialDetectorNode::new() instead which returns HorusResult
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn camera() -> CameraInfo {
+        CameraInfo::new(640, 480, 500.0, 500.0, 320.0, 240.0)
+    }
+
+    /// 10cm marker facing the camera 1m ahead, 20cm marker 2m ahead
+    fn markers() -> Vec<(u32, [[f64; 2]; 4])> {
+        vec![
+            (
+                3,
+                [
+                    [295.0, 215.0],
+                    [345.0, 215.0],
+                    [345.0, 265.0],
+                    [295.0, 265.0],
+                ],
+            ),
+            (
+                7,
+                [
+                    [395.0, 215.0],
+                    [445.0, 215.0],
+                    [445.0, 265.0],
+                    [395.0, 265.0],
+                ],
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_builds_detections_with_per_id_sizes() {
+        let mut node = FiducialDetectorNode::new_with_topics(
+            "test_fiducial_build.image",
+            "test_fiducial_build.info",
+            "test_fiducial_build.out",
+        )
+        .unwrap();
+        node.set_tag_size(0.1);
+        node.set_tag_size_for_id(7, 0.2);
+
+        let array = node.build_detections(&markers(), &camera(), "front_camera", 42);
+        assert_eq!(array.count, 2);
+        assert_eq!(array.frame_id_str(), "front_camera");
+        assert_eq!(array.timestamp, 42);
+
+        let near = array.find(3).unwrap();
+        assert_eq!(near.family, FiducialFamily::AprilTag36h11);
+        assert!((near.pose.translation[2] - 1.0).abs() < 1e-6);
+        assert!((near.distance() - 1.0).abs() < 1e-6);
+
+        let far = array.find(7).unwrap();
+        assert!((far.size - 0.2).abs() < 1e-12);
+        assert!((far.pose.translation[2] - 2.0).abs() < 1e-6);
+        assert!((far.pose.translation[0] - 0.4).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_drops_inconsistent_markers() {
+        let mut node = FiducialDetectorNode::new_with_topics(
+            "test_fiducial_drop.image",
+            "test_fiducial_drop.info",
+            "test_fiducial_drop.out",
+        )
+        .unwrap();
+        node.set_max_reprojection_error(1.0);
+
+        // Not a projected square: corners pulled out of shape
+        let skewed = vec![(
+            1,
+            [
+                [100.0, 100.0],
+                [200.0, 130.0],
+                [150.0, 140.0],
+                [120.0, 300.0],
+            ],
+        )];
+        let array = node.build_detections(&skewed, &camera(), "camera", 0);
+        assert_eq!(array.count, 0);
+    }
+
+    #[test]
+    fn test_publishes_tag_frames_to_hframe() {
+        let hf = HFrame::new();
+        let mut node = FiducialDetectorNode::new_with_topics(
+            "test_fiducial_hframe.image",
+            "test_fiducial_hframe.info",
+            "test_fiducial_hframe.out",
+        )
+        .unwrap();
+        node.set_tag_size(0.1);
+        node.set_hframe(hf.clone());
+
+        let array = node.build_detections(&markers(), &camera(), "camera_optical", 1);
+        node.update_hframe(&array);
+        assert!(hf.has_frame("camera_optical"));
+        assert!(hf.has_frame("tag_3"));
+
+        // Marker center is 1m ahead of the camera
+        let tag = hf.tf("tag_3", "camera_optical").unwrap();
+        assert!((tag.translation[2] - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_gray_pixels_strips_padding() {
+        let mut image = Image::new(2, 2, ImageEncoding::Mono8, vec![1, 2, 0, 3, 4, 0]);
+        image.step = 3;
+        assert_eq!(gray_pixels(&image).unwrap(), vec![1, 2, 3, 4]);
+
+        let rgb = Image::new(1, 1, ImageEncoding::Rgb8, vec![255, 255, 255]);
+        assert_eq!(gray_pixels(&rgb).unwrap(), vec![255]);
+    }
+
+    #[test]
+    fn test_waits_for_camera_info() {
+        let mut node = FiducialDetectorNode::new_with_topics(
+            "test_fiducial_wait.image",
+            "test_fiducial_wait.info",
+            "test_fiducial_wait.out",
+        )
+        .unwrap();
+        let image = Image::new(4, 4, ImageEncoding::Mono8, vec![0; 16]);
+        assert!(node.process_image(&image).unwrap().is_none());
+
+        node.set_camera_info(camera());
+        let array = node.process_image(&image).unwrap().unwrap();
+        assert_eq!(array.count, 0);
+        assert_eq!(array.frame_id_str(), "camera");
+        assert_eq!(node.get_stats(), (1, 0));
+    }
+}
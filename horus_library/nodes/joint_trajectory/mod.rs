@@ -0,0 +1,379 @@
+use crate::{
+    JointCommand, JointTrajectory, JointTrajectoryFeedback, JointTrajectoryPoint, TrajectoryState,
+};
+use horus_core::error::HorusResult;
+
+// Import algorithms from horus_library/algorithms
+use crate::algorithms::trajectory::{Interpolation, JointSample, JointSpline};
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo};
+use std::collections::HashMap;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Trajectory being executed
+struct ActiveTrajectory {
+    joint_names: Vec<String>,
+    spline: JointSpline,
+    start: Instant,
+    point_offset: usize, // 1 if the current state was prepended as a waypoint
+}
+
+/// Joint Trajectory Node - Spline interpolation of joint trajectories for arm control
+///
+/// Receives `JointTrajectory` waypoints and streams interpolated position
+/// commands (with feed-forward velocities) as `JointCommand` every tick, so
+/// joint controllers receive a smooth setpoint at the control rate.
+///
+/// A new trajectory replaces the one in progress. When the first waypoint
+/// lies in the future, motion starts from the last commanded positions and
+/// velocities, so replacing a trajectory mid-motion does not cause a jump.
+///
+/// Progress is published as `JointTrajectoryFeedback` every tick while
+/// executing, and once more when the trajectory ends.
+///
+/// This node is a thin wrapper around the pure algorithm in horus_library/algorithms.
+pub struct JointTrajectoryNode {
+    // Publishers and Subscribers
+    trajectory_subscriber: Hub<JointTrajectory>,
+    command_publisher: Hub<JointCommand>,
+    feedback_publisher: Hub<JointTrajectoryFeedback>,
+
+    // Configuration
+    interpolation: Interpolation,
+    start_from_current: bool,
+
+    // State
+    active: Option<ActiveTrajectory>,
+    commanded: HashMap<String, (f64, f64)>, // last position and velocity per joint
+    feedback: JointTrajectoryFeedback,
+}
+
+impl JointTrajectoryNode {
+    /// Create a new joint trajectory node with default topics
+    pub fn new() -> Result<Self> {
+        Self::new_with_topics(
+            "joint_trajectory",
+            "joint_command",
+            "joint_trajectory.feedback",
+        )
+    }
+
+    /// Create a new joint trajectory node with custom topics
+    pub fn new_with_topics(
+        trajectory_topic: &str,
+        command_topic: &str,
+        feedback_topic: &str,
+    ) -> Result<Self> {
+        Ok(Self {
+            trajectory_subscriber: Hub::new(trajectory_topic)?,
+            command_publisher: Hub::new(command_topic)?,
+            feedback_publisher: Hub::new(feedback_topic)?,
+
+            interpolation: Interpolation::Cubic,
+            start_from_current: true,
+
+            active: None,
+            commanded: HashMap::new(),
+            feedback: JointTrajectoryFeedback::default(),
+        })
+    }
+
+    /// Set the interpolation used for new trajectories
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Start from the last commanded state when the first waypoint is in the future
+    pub fn set_start_from_current(&mut self, enabled: bool) {
+        self.start_from_current = enabled;
+    }
+
+    /// Stop the current trajectory and hold the last commanded positions
+    pub fn cancel(&mut self) {
+        if self.active.take().is_some() {
+            self.feedback.state = TrajectoryState::Aborted;
+            self.hold();
+            self.publish_feedback();
+        }
+    }
+
+    /// Current execution state
+    pub fn get_state(&self) -> TrajectoryState {
+        self.feedback.state
+    }
+
+    /// Latest progress feedback
+    pub fn get_feedback(&self) -> JointTrajectoryFeedback {
+        self.feedback
+    }
+
+    /// Check if a trajectory is being executed
+    pub fn is_executing(&self) -> bool {
+        self.active.is_some()
+    }
+
+    /// Last commanded position of a joint
+    pub fn get_commanded_position(&self, joint: &str) -> Option<f64> {
+        self.commanded.get(joint).map(|&(position, _)| position)
+    }
+
+    /// Start executing a trajectory at `now`
+    fn accept(
+        &mut self,
+        trajectory: &JointTrajectory,
+        now: Instant,
+    ) -> std::result::Result<(), String> {
+        trajectory.validate()?;
+
+        let mut points = trajectory.points.clone();
+        let mut point_offset = 0;
+
+        // Blend in from where the joints were last commanded
+        let current: Option<Vec<(f64, f64)>> = trajectory
+            .joint_names
+            .iter()
+            .map(|name| self.commanded.get(name).copied())
+            .collect();
+        if let (true, Some(current)) = (self.start_from_current, current) {
+            if points[0].time_from_start > 0.0 {
+                points.insert(
+                    0,
+                    JointTrajectoryPoint::with_velocities(
+                        current.iter().map(|&(p, _)| p).collect(),
+                        current.iter().map(|&(_, v)| v).collect(),
+                        0.0,
+                    ),
+                );
+                point_offset = 1;
+            }
+        }
+
+        let spline = JointSpline::new(&points, self.interpolation).map_err(|e| e.to_string())?;
+
+        // Report the trajectory being replaced as aborted
+        if self.active.take().is_some() {
+            self.feedback.state = TrajectoryState::Aborted;
+            self.publish_feedback();
+        }
+
+        self.feedback = JointTrajectoryFeedback {
+            state: TrajectoryState::Executing,
+            elapsed: 0.0,
+            duration: spline.duration(),
+            point_index: 0,
+            point_count: trajectory.points.len() as u32,
+            timestamp: timestamp_nanos(),
+        };
+        self.active = Some(ActiveTrajectory {
+            joint_names: trajectory.joint_names.clone(),
+            spline,
+            start: now,
+            point_offset,
+        });
+        Ok(())
+    }
+
+    /// Advance the active trajectory to `now`, returning the sampled setpoint
+    fn update(&mut self, now: Instant) -> Option<(Vec<String>, JointSample)> {
+        let active = self.active.as_ref()?;
+        let elapsed = now.saturating_duration_since(active.start).as_secs_f64();
+        let sample = active.spline.sample(elapsed);
+        let names = active.joint_names.clone();
+
+        let finished = elapsed >= active.spline.duration();
+        let next = active.spline.next_point(elapsed);
+        self.feedback.elapsed = elapsed.min(active.spline.duration());
+        self.feedback.point_index = next.saturating_sub(active.point_offset) as u32;
+        self.feedback.timestamp = timestamp_nanos();
+        if finished {
+            self.feedback.state = TrajectoryState::Succeeded;
+            self.active = None;
+        }
+
+        for (i, name) in names.iter().enumerate() {
+            self.commanded
+                .insert(name.clone(), (sample.positions[i], sample.velocities[i]));
+        }
+        Some((names, sample))
+    }
+
+    fn publish_command(&mut self, names: &[String], sample: &JointSample) {
+        let mut command = JointCommand::new();
+        for (i, name) in names.iter().enumerate() {
+            if command.add_position(name, sample.positions[i]).is_ok() {
+                command.velocities[i] = sample.velocities[i];
+            }
+        }
+        let _ = self.command_publisher.send(command, &mut None);
+    }
+
+    /// Command the last positions at zero velocity
+    fn hold(&mut self) {
+        let mut command = JointCommand::new();
+        for (name, (position, velocity)) in self.commanded.iter_mut() {
+            *velocity = 0.0;
+            let _ = command.add_position(name, *position);
+        }
+        if command.joint_count > 0 {
+            let _ = self.command_publisher.send(command, &mut None);
+        }
+    }
+
+    fn publish_feedback(&mut self) {
+        let _ = self.feedback_publisher.send(self.feedback, &mut None);
+    }
+}
+
+fn timestamp_nanos() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos() as u64
+}
+
+impl Node for JointTrajectoryNode {
+    fn name(&self) -> &'static str {
+        "JointTrajectoryNode"
+    }
+
+    fn shutdown(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        ctx.log_info("JointTrajectoryNode shutting down - holding current positions");
+        self.cancel();
+        Ok(())
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let now = Instant::now();
+
+        if let Some(trajectory) = self.trajectory_subscriber.recv(&mut None) {
+            if self.active.is_some() {
+                if let Some(ctx) = ctx.as_mut() {
+                    ctx.log_info("JointTrajectoryNode: replacing trajectory in progress");
+                }
+            }
+            if let Err(reason) = self.accept(&trajectory, now) {
+                if let Some(ctx) = ctx.as_mut() {
+                    ctx.log_warning(&format!(
+                        "JointTrajectoryNode: rejected trajectory: {}",
+                        reason
+                    ));
+                }
+                // Keep executing the previous trajectory, if any
+                if self.active.is_none() {
+                    self.feedback = JointTrajectoryFeedback {
+                        state: TrajectoryState::Rejected,
+                        timestamp: timestamp_nanos(),
+                        ..Default::default()
+                    };
+                    self.publish_feedback();
+                }
+            }
+        }
+
+        if let Some((names, sample)) = self.update(now) {
+            self.publish_command(&names, &sample);
+            self.publish_feedback();
+            if self.feedback.state == TrajectoryState::Succeeded {
+                if let Some(ctx) = ctx.as_mut() {
+                    ctx.log_info("JointTrajectoryNode: trajectory complete");
+                }
+            }
+        }
+    }
+}
+
+// Default impl removed - use JointTrajectoryNode::new() instead which returns HorusResult
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn trajectory(target: f64, duration: f64) -> JointTrajectory {
+        let mut trajectory = JointTrajectory::new(&["shoulder", "elbow"]);
+        trajectory.add_point(vec![target, -target], duration);
+        trajectory
+    }
+
+    #[test]
+    fn test_executes_to_completion() {
+        let mut node = JointTrajectoryNode::new_with_topics(
+            "test_jtraj_complete.in",
+            "test_jtraj_complete.cmd",
+            "test_jtraj_complete.fb",
+        )
+        .unwrap();
+        let t0 = Instant::now();
+
+        // No commanded state yet: the single waypoint is held until its time
+        let mut start = JointTrajectory::new(&["shoulder", "elbow"]);
+        start.add_point(vec![0.0, 0.0], 0.0);
+        node.accept(&start, t0).unwrap();
+        node.update(t0);
+        assert_eq!(node.get_state(), TrajectoryState::Succeeded);
+
+        // Now blends from the commanded state
+        node.accept(&trajectory(1.0, 2.0), t0).unwrap();
+        assert_eq!(node.get_feedback().point_count, 1);
+
+        let (_, mid) = node.update(t0 + Duration::from_secs(1)).unwrap();
+        assert!((mid.positions[0] - 0.5).abs() < 1e-9);
+        assert!((mid.positions[1] + 0.5).abs() < 1e-9);
+        assert!(node.is_executing());
+        assert!((node.get_feedback().progress() - 0.5).abs() < 1e-9);
+
+        let (_, end) = node.update(t0 + Duration::from_secs(3)).unwrap();
+        assert_eq!(end.positions, vec![1.0, -1.0]);
+        assert_eq!(node.get_state(), TrajectoryState::Succeeded);
+        assert_eq!(node.get_feedback().point_index, 1);
+        assert!(!node.is_executing());
+        assert_eq!(node.get_commanded_position("elbow"), Some(-1.0));
+    }
+
+    #[test]
+    fn test_replacing_keeps_motion_continuous() {
+        let mut node = JointTrajectoryNode::new_with_topics(
+            "test_jtraj_replace.in",
+            "test_jtraj_replace.cmd",
+            "test_jtraj_replace.fb",
+        )
+        .unwrap();
+        node.commanded.insert("shoulder".to_string(), (0.0, 0.0));
+        node.commanded.insert("elbow".to_string(), (0.0, 0.0));
+
+        let t0 = Instant::now();
+        node.accept(&trajectory(2.0, 2.0), t0).unwrap();
+        let t1 = t0 + Duration::from_millis(800);
+        let (_, before) = node.update(t1).unwrap();
+
+        // New goal mid-motion starts from the current position and velocity
+        node.accept(&trajectory(-1.0, 1.0), t1).unwrap();
+        let (_, after) = node.update(t1).unwrap();
+        assert!((after.positions[0] - before.positions[0]).abs() < 1e-9);
+        assert!((after.velocities[0] - before.velocities[0]).abs() < 1e-9);
+
+        node.cancel();
+        assert_eq!(node.get_state(), TrajectoryState::Aborted);
+        assert!(!node.is_executing());
+    }
+
+    #[test]
+    fn test_rejects_invalid_trajectory() {
+        let mut node = JointTrajectoryNode::new_with_topics(
+            "test_jtraj_reject.in",
+            "test_jtraj_reject.cmd",
+            "test_jtraj_reject.fb",
+        )
+        .unwrap();
+        let mut bad = JointTrajectory::new(&["shoulder", "elbow"]);
+        bad.add_point(vec![1.0], 1.0);
+        assert!(node.accept(&bad, Instant::now()).is_err());
+
+        let mut bad = trajectory(1.0, 1.0);
+        bad.add_point(vec![0.0, 0.0], 0.5);
+        assert!(node.accept(&bad, Instant::now()).is_err());
+        assert!(!node.is_executing());
+    }
+}
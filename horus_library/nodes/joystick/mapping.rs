@@ -0,0 +1,242 @@
+//! Joystick mapping profiles
+//!
+//! A profile names the buttons and axes of a controller, sets deadzones and
+//! exponential curves per axis, and optionally maps sticks to [`CmdVel`]
+//! with an enable (dead-man) and a turbo button. Profiles are YAML:
+//!
+//! ```yaml
+//! name: Xbox
+//! deadzone: 0.1
+//! buttons: { 0: A, 1: B, 4: LB, 6: RB }
+//! axes: { 0: LeftX, 1: LeftY }
+//! expo: { 0: 0.3, 1: 0.3 }
+//! teleop:
+//!   linear_axis: 1
+//!   angular_axis: 0
+//!   invert_angular: true
+//!   linear_scale: 0.5
+//!   angular_scale: 1.0
+//!   enable_button: 4
+//!   turbo_button: 6
+//! ```
+//!
+//! Button and axis IDs are the ones [`JoystickInputNode`](super::JoystickInputNode)
+//! publishes. Profiles for Xbox, PS5 and Logitech F710 controllers are built
+//! in (see [`JoystickProfile::builtin`]).
+
+use crate::CmdVel;
+use horus_core::error::{HorusError, HorusResult};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+
+/// Names of the built-in profiles
+pub const BUILTIN_PROFILES: &[&str] = &["xbox", "ps5", "logitech_f710"];
+
+/// Controller mapping profile
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct JoystickProfile {
+    /// Profile name for logs
+    pub name: String,
+    /// Deadzone of axes without their own (0.0 to 1.0)
+    pub deadzone: f32,
+    /// Published name of each button ID
+    pub buttons: BTreeMap<u32, String>,
+    /// Published name of each axis ID
+    pub axes: BTreeMap<u32, String>,
+    /// Deadzone of single axes
+    pub axis_deadzones: BTreeMap<u32, f32>,
+    /// Exponential curve of single axes (0.0 = linear, 1.0 = cubic)
+    pub expo: BTreeMap<u32, f32>,
+    /// Stick to velocity mapping
+    pub teleop: Option<TeleopMapping>,
+}
+
+impl Default for JoystickProfile {
+    fn default() -> Self {
+        Self {
+            name: "Generic".to_string(),
+            deadzone: 0.1,
+            buttons: BTreeMap::new(),
+            axes: BTreeMap::new(),
+            axis_deadzones: BTreeMap::new(),
+            expo: BTreeMap::new(),
+            teleop: None,
+        }
+    }
+}
+
+impl JoystickProfile {
+    /// Parse a YAML profile
+    pub fn from_yaml(yaml: &str) -> HorusResult<Self> {
+        serde_yaml::from_str(yaml)
+            .map_err(|e| HorusError::Config(format!("invalid joystick profile: {}", e)))
+    }
+
+    /// Read a YAML profile file
+    pub fn from_file<P: AsRef<Path>>(path: P) -> HorusResult<Self> {
+        let path = path.as_ref();
+        let yaml = std::fs::read_to_string(path).map_err(|e| {
+            HorusError::Config(format!(
+                "failed to read joystick profile {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        Self::from_yaml(&yaml)
+    }
+
+    /// Built-in profile by name (see [`BUILTIN_PROFILES`])
+    pub fn builtin(name: &str) -> Option<Self> {
+        let yaml = match name {
+            "xbox" => include_str!("profiles/xbox.yaml"),
+            "ps5" | "dualsense" => include_str!("profiles/ps5.yaml"),
+            "logitech_f710" | "f710" => include_str!("profiles/logitech_f710.yaml"),
+            _ => return None,
+        };
+        Some(Self::from_yaml(yaml).expect("built-in joystick profiles are valid"))
+    }
+}
+
+/// Stick to [`CmdVel`] mapping with enable and turbo buttons
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeleopMapping {
+    /// Axis of the forward velocity
+    pub linear_axis: u32,
+    /// Axis of the yaw rate
+    pub angular_axis: u32,
+    pub invert_linear: bool,
+    pub invert_angular: bool,
+    /// Forward velocity at full deflection in m/s
+    pub linear_scale: f32,
+    /// Yaw rate at full deflection in rad/s
+    pub angular_scale: f32,
+    /// Forward velocity at full deflection with turbo held
+    pub turbo_linear_scale: f32,
+    /// Yaw rate at full deflection with turbo held
+    pub turbo_angular_scale: f32,
+    /// Button that must be held to drive; `None` drives at all times
+    pub enable_button: Option<u32>,
+    /// Button that switches to the turbo scales while held
+    pub turbo_button: Option<u32>,
+}
+
+impl Default for TeleopMapping {
+    fn default() -> Self {
+        Self {
+            linear_axis: 1,
+            angular_axis: 0,
+            invert_linear: false,
+            invert_angular: false,
+            linear_scale: 0.5,
+            angular_scale: 1.0,
+            turbo_linear_scale: 1.0,
+            turbo_angular_scale: 2.0,
+            enable_button: Some(4),
+            turbo_button: None,
+        }
+    }
+}
+
+impl TeleopMapping {
+    /// Velocity command for the current axis values and button states
+    ///
+    /// Returns `None` while the enable button is released.
+    pub fn command(
+        &self,
+        axes: &HashMap<u32, f32>,
+        buttons: &HashMap<u32, bool>,
+    ) -> Option<CmdVel> {
+        let held = |button: Option<u32>| {
+            button.is_some_and(|id| buttons.get(&id).copied().unwrap_or(false))
+        };
+        if self.enable_button.is_some() && !held(self.enable_button) {
+            return None;
+        }
+
+        let (linear_scale, angular_scale) = if held(self.turbo_button) {
+            (self.turbo_linear_scale, self.turbo_angular_scale)
+        } else {
+            (self.linear_scale, self.angular_scale)
+        };
+        let axis = |id: u32, invert: bool| {
+            let value = axes.get(&id).copied().unwrap_or(0.0).clamp(-1.0, 1.0);
+            if invert {
+                -value
+            } else {
+                value
+            }
+        };
+        Some(CmdVel::new(
+            axis(self.linear_axis, self.invert_linear) * linear_scale,
+            axis(self.angular_axis, self.invert_angular) * angular_scale,
+        ))
+    }
+}
+
+/// Apply an exponential curve to an axis value
+///
+/// Blends the linear and cubic response: `expo` 0.0 is linear, 1.0 is fully
+/// cubic, which gives finer control around center at the same full range.
+pub fn apply_expo(value: f32, expo: f32) -> f32 {
+    let expo = expo.clamp(0.0, 1.0);
+    (1.0 - expo) * value + expo * value * value * value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_profiles() {
+        for name in BUILTIN_PROFILES {
+            let profile = JoystickProfile::builtin(name).unwrap();
+            let teleop = profile.teleop.as_ref().unwrap();
+            assert!(profile.buttons.contains_key(&teleop.enable_button.unwrap()));
+            assert!(profile.axes.contains_key(&teleop.linear_axis));
+        }
+        let ps5 = JoystickProfile::builtin("ps5").unwrap();
+        assert_eq!(ps5.buttons[&0], "Cross");
+        assert!(JoystickProfile::builtin("n64").is_none());
+
+        let partial = JoystickProfile::from_yaml("name: Pad\nbuttons: {0: Fire}").unwrap();
+        assert_eq!(partial.deadzone, 0.1);
+        assert!(partial.teleop.is_none());
+        assert!(JoystickProfile::from_yaml("deadzone: [1]").is_err());
+    }
+
+    #[test]
+    fn test_expo() {
+        assert_eq!(apply_expo(0.5, 0.0), 0.5);
+        assert!((apply_expo(-1.0, 0.7) + 1.0).abs() < 1e-6);
+        assert!((apply_expo(0.5, 1.0) - 0.125).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_teleop_enable_and_turbo() {
+        let teleop = TeleopMapping {
+            invert_angular: true,
+            turbo_button: Some(6),
+            ..Default::default()
+        };
+        let axes = HashMap::from([(1, 1.0), (0, 0.5)]);
+        let mut buttons = HashMap::new();
+        assert!(teleop.command(&axes, &buttons).is_none());
+
+        buttons.insert(4, true);
+        let cmd = teleop.command(&axes, &buttons).unwrap();
+        assert_eq!((cmd.linear, cmd.angular), (0.5, -0.5));
+
+        buttons.insert(6, true);
+        let cmd = teleop.command(&axes, &buttons).unwrap();
+        assert_eq!((cmd.linear, cmd.angular), (1.0, -1.0));
+
+        let always_on = TeleopMapping {
+            enable_button: None,
+            ..Default::default()
+        };
+        assert!(always_on.command(&axes, &HashMap::new()).is_some());
+    }
+}
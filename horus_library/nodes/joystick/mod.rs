@@ -1,6 +1,11 @@
-use crate::JoystickInput;
+use crate::{CmdVel, JoystickInput};
 use horus_core::error::HorusResult;
 use std::collections::HashMap;
+use std::path::Path;
+
+pub mod mapping;
+
+pub use mapping::{apply_expo, JoystickProfile, TeleopMapping, BUILTIN_PROFILES};
 
 // Type alias for cleaner signatures
 type Result<T> = HorusResult<T>;
@@ -48,6 +53,20 @@ impl Default for AxisCalibration {
 /// Captures real joystick/gamepad input using the gilrs library.
 /// Publishes button presses and axis movements to the Hub.
 ///
+/// # Mapping Profiles
+///
+/// A [`JoystickProfile`] names buttons and axes and sets deadzones and
+/// exponential curves. With a [`TeleopMapping`] and a `CmdVel` topic the
+/// node also publishes velocity commands while the enable button is held,
+/// and one zero command when it is released:
+///
+/// ```rust,ignore
+/// let mut node = JoystickInputNode::builder()
+///     .with_profile(JoystickProfile::builtin("xbox").unwrap())
+///     .with_cmd_vel_topic("cmd_vel")
+///     .build()?;
+/// ```
+///
 /// # Hybrid Pattern
 ///
 /// This node supports the hybrid pattern for custom processing:
@@ -98,6 +117,16 @@ where
     // Per-axis deadzones
     per_axis_deadzones: HashMap<u32, f32>,
 
+    // Per-axis exponential curves
+    axis_expo: HashMap<u32, f32>,
+
+    // Velocity mapping (enable/turbo buttons)
+    teleop: Option<TeleopMapping>,
+    cmd_vel_publisher: Option<Hub<CmdVel>>,
+    axis_values: HashMap<u32, f32>,
+    button_states: HashMap<u32, bool>,
+    teleop_active: bool,
+
     // Processor for hybrid pattern
     processor: P,
 }
@@ -133,6 +162,12 @@ impl JoystickInputNode {
                 custom_axis_names: HashMap::new(),
                 axis_calibrations: HashMap::new(),
                 per_axis_deadzones: HashMap::new(),
+                axis_expo: HashMap::new(),
+                teleop: None,
+                cmd_vel_publisher: None,
+                axis_values: HashMap::new(),
+                button_states: HashMap::new(),
+                teleop_active: false,
                 processor: PassThrough::new(),
             })
         }
@@ -153,6 +188,12 @@ impl JoystickInputNode {
                 custom_axis_names: HashMap::new(),
                 axis_calibrations: HashMap::new(),
                 per_axis_deadzones: HashMap::new(),
+                axis_expo: HashMap::new(),
+                teleop: None,
+                cmd_vel_publisher: None,
+                axis_values: HashMap::new(),
+                button_states: HashMap::new(),
+                teleop_active: false,
                 processor: PassThrough::new(),
             })
         }
@@ -236,6 +277,44 @@ where
         self.custom_axis_names.insert(axis_id, name);
     }
 
+    /// Set the exponential curve of an axis (0.0 = linear, 1.0 = cubic)
+    pub fn set_axis_expo(&mut self, axis_id: u32, expo: f32) {
+        self.axis_expo.insert(axis_id, expo.clamp(0.0, 1.0));
+    }
+
+    /// Apply a mapping profile (names, deadzones, curves and velocity mapping)
+    pub fn set_profile(&mut self, profile: JoystickProfile) {
+        self.set_deadzone(profile.deadzone);
+        for (axis_id, deadzone) in profile.axis_deadzones {
+            self.set_axis_deadzone(axis_id, deadzone);
+        }
+        for (axis_id, expo) in profile.expo {
+            self.set_axis_expo(axis_id, expo);
+        }
+        self.custom_button_names.extend(profile.buttons);
+        self.custom_axis_names.extend(profile.axes);
+        if profile.teleop.is_some() {
+            self.teleop = profile.teleop;
+        }
+    }
+
+    /// Read and apply a YAML mapping profile
+    pub fn load_profile<Q: AsRef<Path>>(&mut self, path: Q) -> Result<()> {
+        self.set_profile(JoystickProfile::from_file(path)?);
+        Ok(())
+    }
+
+    /// Set the stick to velocity mapping
+    pub fn set_teleop(&mut self, teleop: TeleopMapping) {
+        self.teleop = Some(teleop);
+    }
+
+    /// Publish mapped velocity commands on a topic
+    pub fn set_cmd_vel_topic(&mut self, topic: &str) -> Result<()> {
+        self.cmd_vel_publisher = Some(Hub::new(topic)?);
+        Ok(())
+    }
+
     /// Calibrate a specific axis
     pub fn calibrate_axis(&mut self, axis_id: u32, center: f32, min: f32, max: f32) {
         self.axis_calibrations
@@ -332,11 +411,33 @@ where
         }
     }
 
-    /// Process axis value through all filters (calibration, deadzone, inversion)
+    /// Process axis value through all filters (calibration, deadzone, expo, inversion)
     fn process_axis_value(&self, value: f32, axis_id: u32) -> f32 {
         let calibrated = self.apply_calibration(value, axis_id);
         let deadzone_applied = self.apply_deadzone(calibrated, axis_id);
-        self.apply_inversion(deadzone_applied, axis_id)
+        let curved = match self.axis_expo.get(&axis_id) {
+            Some(&expo) => apply_expo(deadzone_applied, expo),
+            None => deadzone_applied,
+        };
+        self.apply_inversion(curved, axis_id)
+    }
+
+    /// Publish the mapped velocity command, and one stop when driving ends
+    fn publish_cmd_vel(&mut self, ctx: &mut Option<&mut NodeInfo>) {
+        let (Some(teleop), Some(publisher)) = (&self.teleop, &self.cmd_vel_publisher) else {
+            return;
+        };
+        match teleop.command(&self.axis_values, &self.button_states) {
+            Some(cmd) => {
+                publisher.send(cmd, ctx).ok();
+                self.teleop_active = true;
+            }
+            None if self.teleop_active => {
+                publisher.send(CmdVel::zero(), ctx).ok();
+                self.teleop_active = false;
+            }
+            None => {}
+        }
     }
 
     /// Get button name based on mapping profile
@@ -447,6 +548,9 @@ where
                     EventType::ButtonPressed(button, _) => {
                         let button_id = button_to_id(button);
                         let button_name = self.get_button_name(button, button_id);
+                        if gamepad_id == self.device_id {
+                            self.button_states.insert(button_id, true);
+                        }
 
                         let joystick_input = JoystickInput::new_button(
                             gamepad_id,
@@ -467,6 +571,9 @@ where
                     EventType::ButtonReleased(button, _) => {
                         let button_id = button_to_id(button);
                         let button_name = self.get_button_name(button, button_id);
+                        if gamepad_id == self.device_id {
+                            self.button_states.insert(button_id, false);
+                        }
 
                         let joystick_input =
                             JoystickInput::new_button(gamepad_id, button_id, button_name, false);
@@ -482,6 +589,9 @@ where
 
                         // Process axis value through calibration, deadzone, and inversion
                         let processed_value = self.process_axis_value(value, axis_id);
+                        if gamepad_id == self.device_id {
+                            self.axis_values.insert(axis_id, processed_value);
+                        }
 
                         let joystick_input = JoystickInput::new_axis(
                             gamepad_id,
@@ -514,6 +624,11 @@ where
                     }
                     EventType::Disconnected => {
                         ctx.log_info(&format!("Gamepad {} disconnected", gamepad_id));
+                        if gamepad_id == self.device_id {
+                            // Stop driving with a lost controller
+                            self.axis_values.clear();
+                            self.button_states.clear();
+                        }
 
                         // Publish disconnection event
                         let disconnection_event = JoystickInput::new_connection(gamepad_id, false);
@@ -545,6 +660,8 @@ where
                 self.last_input_time = current_time;
             }
         }
+
+        self.publish_cmd_vel(&mut ctx);
     }
 }
 
@@ -593,6 +710,8 @@ where
     P: Processor<JoystickInput>,
 {
     topic: String,
+    profile: Option<JoystickProfile>,
+    cmd_vel_topic: Option<String>,
     processor: P,
 }
 
@@ -601,6 +720,8 @@ impl JoystickInputNodeBuilder<PassThrough<JoystickInput>> {
     pub fn new() -> Self {
         Self {
             topic: "joystick_input".to_string(),
+            profile: None,
+            cmd_vel_topic: None,
             processor: PassThrough::new(),
         }
     }
@@ -622,6 +743,18 @@ where
         self
     }
 
+    /// Apply a mapping profile
+    pub fn with_profile(mut self, profile: JoystickProfile) -> Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    /// Publish mapped velocity commands on a topic
+    pub fn with_cmd_vel_topic(mut self, topic: &str) -> Self {
+        self.cmd_vel_topic = Some(topic.to_string());
+        self
+    }
+
     /// Set a custom processor
     pub fn with_processor<P2>(self, processor: P2) -> JoystickInputNodeBuilder<P2>
     where
@@ -629,6 +762,8 @@ where
     {
         JoystickInputNodeBuilder {
             topic: self.topic,
+            profile: self.profile,
+            cmd_vel_topic: self.cmd_vel_topic,
             processor,
         }
     }
@@ -652,6 +787,8 @@ where
     {
         JoystickInputNodeBuilder {
             topic: self.topic,
+            profile: self.profile,
+            cmd_vel_topic: self.cmd_vel_topic,
             processor: Pipeline::new(self.processor, ClosureProcessor::new(f)),
         }
     }
@@ -675,6 +812,8 @@ where
     {
         JoystickInputNodeBuilder {
             topic: self.topic,
+            profile: self.profile,
+            cmd_vel_topic: self.cmd_vel_topic,
             processor: Pipeline::new(self.processor, FilterProcessor::new(f)),
         }
     }
@@ -689,14 +828,19 @@ where
     {
         JoystickInputNodeBuilder {
             topic: self.topic,
+            profile: self.profile,
+            cmd_vel_topic: self.cmd_vel_topic,
             processor: Pipeline::new(self.processor, next),
         }
     }
 
     /// Build the node
     pub fn build(self) -> Result<JoystickInputNode<P>> {
+        let profile = self.profile;
+        let cmd_vel_topic = self.cmd_vel_topic;
+
         #[cfg(feature = "gilrs")]
-        {
+        let mut node = {
             let gilrs = Gilrs::new().map_err(|e| {
                 horus_core::error::HorusError::InitializationFailed(format!(
                     "Failed to initialize gilrs: {}",
@@ -704,7 +848,7 @@ where
                 ))
             })?;
 
-            Ok(JoystickInputNode {
+            JoystickInputNode {
                 publisher: Hub::new(&self.topic)?,
                 gilrs,
                 device_id: 0,
@@ -718,28 +862,46 @@ where
                 custom_axis_names: HashMap::new(),
                 axis_calibrations: HashMap::new(),
                 per_axis_deadzones: HashMap::new(),
+                axis_expo: HashMap::new(),
+                teleop: None,
+                cmd_vel_publisher: None,
+                axis_values: HashMap::new(),
+                button_states: HashMap::new(),
+                teleop_active: false,
                 processor: self.processor,
-            })
-        }
+            }
+        };
 
         #[cfg(not(feature = "gilrs"))]
-        {
-            Ok(JoystickInputNode {
-                publisher: Hub::new(&self.topic)?,
-                last_input_time: 0,
-                device_id: 0,
-                deadzone: 0.1,
-                axis_invert_x: false,
-                axis_invert_y: false,
-                axis_invert_rx: false,
-                axis_invert_ry: false,
-                button_mapping: ButtonMapping::Generic,
-                custom_button_names: HashMap::new(),
-                custom_axis_names: HashMap::new(),
-                axis_calibrations: HashMap::new(),
-                per_axis_deadzones: HashMap::new(),
-                processor: self.processor,
-            })
+        let mut node = JoystickInputNode {
+            publisher: Hub::new(&self.topic)?,
+            last_input_time: 0,
+            device_id: 0,
+            deadzone: 0.1,
+            axis_invert_x: false,
+            axis_invert_y: false,
+            axis_invert_rx: false,
+            axis_invert_ry: false,
+            button_mapping: ButtonMapping::Generic,
+            custom_button_names: HashMap::new(),
+            custom_axis_names: HashMap::new(),
+            axis_calibrations: HashMap::new(),
+            per_axis_deadzones: HashMap::new(),
+            axis_expo: HashMap::new(),
+            teleop: None,
+            cmd_vel_publisher: None,
+            axis_values: HashMap::new(),
+            button_states: HashMap::new(),
+            teleop_active: false,
+            processor: self.processor,
+        };
+
+        if let Some(profile) = profile {
+            node.set_profile(profile);
+        }
+        if let Some(topic) = cmd_vel_topic {
+            node.set_cmd_vel_topic(&topic)?;
         }
+        Ok(node)
     }
 }
@@ -0,0 +1,277 @@
+use crate::{CmdVel, LaserScan, Odometry, PathPlan};
+use horus_core::error::HorusResult;
+
+// Import algorithms from horus_library/algorithms
+use crate::algorithms::dwa::{Dwa, DwaConfig, Footprint};
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Local Planner Node - Dynamic Window Approach obstacle-aware path following
+///
+/// Follows the global path from `PathPlannerNode` while reacting to obstacles
+/// in the latest laser scan. Each tick it samples velocity commands reachable
+/// under the acceleration limits, simulates them against the robot footprint
+/// and publishes the best collision-free `CmdVel`.
+///
+/// When no trajectory is safe the node publishes a stop command and keeps
+/// re-evaluating, so it resumes as soon as the obstacle moves away or the
+/// global planner produces a new path.
+///
+/// This node is a thin wrapper around the pure algorithm in horus_library/algorithms.
+pub struct LocalPlannerNode {
+    // Publishers and Subscribers
+    cmd_publisher: Hub<CmdVel>,
+    path_subscriber: Hub<PathPlan>,
+    odometry_subscriber: Hub<Odometry>,
+    lidar_subscriber: Hub<LaserScan>,
+
+    // Algorithm instance
+    dwa: Dwa,
+
+    // Node state
+    pose: Option<(f64, f64, f64)>,
+    velocity: (f64, f64),
+    latest_scan: Option<LaserScan>,
+    scan_offset: (f64, f64, f64), // lidar pose in the robot frame
+    active: bool,
+    blocked: bool,
+    last_time: u64,
+}
+
+impl LocalPlannerNode {
+    /// Create a new local planner node with default topics
+    pub fn new() -> Result<Self> {
+        Self::new_with_topics("cmd_vel", "path_plan", "odom", "lidar_scan")
+    }
+
+    /// Create a new local planner node with custom topics
+    pub fn new_with_topics(
+        cmd_topic: &str,
+        path_topic: &str,
+        odom_topic: &str,
+        lidar_topic: &str,
+    ) -> Result<Self> {
+        Ok(Self {
+            cmd_publisher: Hub::new(cmd_topic)?,
+            path_subscriber: Hub::new(path_topic)?,
+            odometry_subscriber: Hub::new(odom_topic)?,
+            lidar_subscriber: Hub::new(lidar_topic)?,
+
+            dwa: Dwa::new(),
+
+            pose: None,
+            velocity: (0.0, 0.0),
+            latest_scan: None,
+            scan_offset: (0.0, 0.0, 0.0),
+            active: false,
+            blocked: false,
+            last_time: 0,
+        })
+    }
+
+    /// Set linear (m/s) and angular (rad/s) velocity limits
+    ///
+    /// A negative `min_linear` allows the planner to reverse.
+    pub fn set_velocity_limits(&mut self, min_linear: f64, max_linear: f64, max_angular: f64) {
+        self.dwa
+            .set_velocity_limits(min_linear, max_linear, max_angular);
+    }
+
+    /// Set linear (m/s²) and angular (rad/s²) acceleration limits
+    pub fn set_acceleration_limits(&mut self, linear: f64, angular: f64) {
+        self.dwa.set_acceleration_limits(linear, angular);
+    }
+
+    /// Use a circular footprint of the given radius (m)
+    pub fn set_robot_radius(&mut self, radius: f64) {
+        self.dwa.set_footprint(Footprint::Circle(radius));
+    }
+
+    /// Use a polygonal footprint in the robot frame (x forward, y left)
+    pub fn set_footprint(&mut self, points: Vec<(f64, f64)>) {
+        self.dwa.set_footprint(Footprint::Polygon(points));
+    }
+
+    /// Set the lidar mounting pose relative to the robot origin
+    pub fn set_lidar_offset(&mut self, x: f64, y: f64, theta: f64) {
+        self.scan_offset = (x, y, theta);
+    }
+
+    /// Set the simulation horizon (s)
+    pub fn set_sim_time(&mut self, seconds: f64) {
+        self.dwa.config_mut().sim_time = seconds.max(0.1);
+    }
+
+    /// Set the distance to the final waypoint at which the goal is reached (m)
+    pub fn set_goal_tolerance(&mut self, tolerance: f64) {
+        self.dwa.config_mut().goal_tolerance = tolerance;
+    }
+
+    /// Set the cost weights for path distance, goal progress, obstacle proximity and speed
+    pub fn set_weights(&mut self, path: f64, goal: f64, obstacle: f64, speed: f64) {
+        let config = self.dwa.config_mut();
+        config.path_weight = path;
+        config.goal_weight = goal;
+        config.obstacle_weight = obstacle;
+        config.speed_weight = speed;
+    }
+
+    /// Replace the whole planner configuration
+    pub fn set_config(&mut self, config: DwaConfig) {
+        *self.dwa.config_mut() = config;
+    }
+
+    /// Current planner configuration
+    pub fn get_config(&self) -> &DwaConfig {
+        self.dwa.config()
+    }
+
+    /// Trajectory selected on the last tick (x, y, theta)
+    pub fn get_trajectory(&self) -> &[(f64, f64, f64)] {
+        self.dwa.trajectory()
+    }
+
+    /// Check if the node is following a path
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Check if the node stopped because no trajectory was collision-free
+    pub fn is_blocked(&self) -> bool {
+        self.blocked
+    }
+
+    /// Drop the current path and stop the robot
+    pub fn cancel(&mut self) {
+        self.dwa.set_path(Vec::new());
+        self.active = false;
+        self.blocked = false;
+        self.publish(0.0, 0.0);
+    }
+
+    /// Obstacle points of a scan in the world frame
+    fn scan_points(&self, scan: &LaserScan, pose: (f64, f64, f64)) -> Vec<(f64, f64)> {
+        let (ox, oy, otheta) = self.scan_offset;
+        let (sin, cos) = pose.2.sin_cos();
+        let sensor_x = pose.0 + ox * cos - oy * sin;
+        let sensor_y = pose.1 + ox * sin + oy * cos;
+        let sensor_theta = pose.2 + otheta;
+
+        scan.ranges
+            .iter()
+            .enumerate()
+            .filter(|&(i, _)| scan.is_range_valid(i))
+            .map(|(i, &range)| {
+                let angle = sensor_theta + scan.angle_at(i) as f64;
+                (
+                    sensor_x + range as f64 * angle.cos(),
+                    sensor_y + range as f64 * angle.sin(),
+                )
+            })
+            .collect()
+    }
+
+    fn publish(&mut self, linear: f64, angular: f64) {
+        self.velocity = (linear, angular);
+        let _ = self
+            .cmd_publisher
+            .send(CmdVel::new(linear as f32, angular as f32), &mut None);
+    }
+}
+
+impl Node for LocalPlannerNode {
+    fn name(&self) -> &'static str {
+        "LocalPlannerNode"
+    }
+
+    fn shutdown(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        ctx.log_info("LocalPlannerNode shutting down - stopping robot");
+        self.publish(0.0, 0.0);
+        Ok(())
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let current_time = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_micros() as u64;
+
+        // Calculate delta time
+        let dt = if self.last_time > 0 {
+            (current_time.saturating_sub(self.last_time)) as f64 / 1_000_000.0
+        } else {
+            0.1 // 100ms default
+        };
+        self.last_time = current_time;
+
+        // Check for a new global path
+        if let Some(plan) = self.path_subscriber.recv(&mut None) {
+            let path: Vec<(f64, f64)> = plan
+                .waypoints
+                .iter()
+                .map(|w| (w[0] as f64, w[1] as f64))
+                .collect();
+            self.active = !path.is_empty();
+            self.dwa.set_path(path);
+        }
+
+        // Check for new odometry
+        if let Some(odom) = self.odometry_subscriber.recv(&mut None) {
+            self.pose = Some((odom.pose.x, odom.pose.y, odom.pose.theta));
+            self.velocity = (odom.twist.linear[0], odom.twist.angular[2]);
+        }
+
+        // Keep the latest scan; obstacles are placed using the pose at planning time
+        if let Some(scan) = self.lidar_subscriber.recv(&mut None) {
+            self.latest_scan = Some(scan);
+        }
+
+        let pose = match self.pose {
+            Some(pose) if self.active => pose,
+            _ => return,
+        };
+
+        let obstacles = self
+            .latest_scan
+            .as_ref()
+            .map(|scan| self.scan_points(scan, pose))
+            .unwrap_or_default();
+        self.dwa.set_obstacles(obstacles);
+
+        if self.dwa.is_goal_reached(pose) {
+            if let Some(ctx) = ctx.as_mut() {
+                ctx.log_info("LocalPlannerNode: goal reached");
+            }
+            self.active = false;
+            self.blocked = false;
+            self.publish(0.0, 0.0);
+            return;
+        }
+
+        match self.dwa.compute_velocity(pose, self.velocity, dt) {
+            Some((linear, angular)) => {
+                if self.blocked {
+                    if let Some(ctx) = ctx.as_mut() {
+                        ctx.log_info("LocalPlannerNode: path clear, resuming");
+                    }
+                    self.blocked = false;
+                }
+                self.publish(linear, angular);
+            }
+            None => {
+                if !self.blocked {
+                    if let Some(ctx) = ctx.as_mut() {
+                        ctx.log_warning("LocalPlannerNode: path blocked, stopping");
+                    }
+                    self.blocked = true;
+                }
+                self.publish(0.0, 0.0);
+            }
+        }
+    }
+}
+
+// Default impl removed - use LocalPlannerNode::new() instead which returns HorusResult
@@ -0,0 +1,475 @@
+// Inference backends for HORUS
+//
+// A common interface over the runtimes that execute a model, so nodes and
+// applications pick the runtime from configuration instead of code:
+//
+// ```yaml
+// inference:
+//   model: models/yolov8n.onnx
+//   backend: tensorrt        # onnx (default) | tensorrt
+//   precision: fp16          # fp32 | fp16 | int8 (TensorRT only)
+//   engine_cache_dir: /var/cache/horus/trt
+//   warmup_iterations: 5
+// ```
+//
+// ```rust,ignore
+// use horus_library::nodes::ml_inference::{BackendConfig, InferenceNode};
+//
+// let config: BackendConfig = serde_yaml::from_str(yaml)?;
+// let node = InferenceNode::new("models/yolov8n.onnx", "ml/input", "ml/output", config)?;
+// scheduler.add(Box::new(node), 1, Some(true));
+// ```
+//
+// Both backends run on ONNX Runtime; `tensorrt` registers the TensorRT
+// execution provider (with CUDA and CPU fallback), which builds an engine on
+// first load. Engine building takes seconds, so `InferenceNode::init` runs
+// warmup passes before the scheduler starts ticking.
+
+use super::onnx_inference::{InferenceConfig, ONNXInferenceNode};
+use super::tensorrt_inference::{TensorRTConfig, TensorRTInferenceNode, TensorRTPrecision};
+use crate::messages::ml::{DataType, InferenceMetrics, ModelInfo, Tensor};
+use horus_core::{HorusError, HorusResult, Hub, Node, NodeInfo};
+use ndarray::{ArrayD, IxDyn};
+use ort::{session::Session, value::Tensor as OrtTensor};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+/// Runtime executing a model
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BackendKind {
+    /// ONNX Runtime (CPU, or CUDA with `use_gpu`)
+    #[default]
+    Onnx,
+    /// NVIDIA TensorRT through ONNX Runtime's TensorRT execution provider
+    TensorRT,
+}
+
+impl FromStr for BackendKind {
+    type Err = HorusError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "onnx" | "ort" => Ok(BackendKind::Onnx),
+            "tensorrt" | "trt" => Ok(BackendKind::TensorRT),
+            other => Err(HorusError::Config(format!(
+                "Unknown inference backend '{}' (expected 'onnx' or 'tensorrt')",
+                other
+            ))),
+        }
+    }
+}
+
+impl std::fmt::Display for BackendKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BackendKind::Onnx => write!(f, "onnx"),
+            BackendKind::TensorRT => write!(f, "tensorrt"),
+        }
+    }
+}
+
+/// Backend selection and runtime options
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BackendConfig {
+    /// Runtime to load the model with
+    pub backend: BackendKind,
+    /// Engine precision (TensorRT only)
+    pub precision: TensorRTPrecision,
+    /// GPU device ID
+    pub device_id: i32,
+    /// Use the CUDA execution provider (ONNX only, TensorRT always uses the GPU)
+    pub use_gpu: bool,
+    /// Directory for serialized TensorRT engines
+    pub engine_cache_dir: Option<PathBuf>,
+    /// INT8 calibration table (TensorRT only)
+    pub int8_calibration_table: Option<PathBuf>,
+    /// Inference passes run during node `init()` (0 disables warmup)
+    pub warmup_iterations: usize,
+    /// Input shape used for the warmup passes
+    pub warmup_shape: Vec<usize>,
+}
+
+impl Default for BackendConfig {
+    fn default() -> Self {
+        Self {
+            backend: BackendKind::Onnx,
+            precision: TensorRTPrecision::FP16,
+            device_id: 0,
+            use_gpu: false,
+            engine_cache_dir: None,
+            int8_calibration_table: None,
+            warmup_iterations: 3,
+            warmup_shape: vec![1, 3, 640, 640], // Default YOLO shape
+        }
+    }
+}
+
+impl BackendConfig {
+    /// ONNX Runtime backend
+    pub fn onnx() -> Self {
+        Self::default()
+    }
+
+    /// TensorRT backend with the given precision
+    pub fn tensorrt(precision: TensorRTPrecision) -> Self {
+        Self {
+            backend: BackendKind::TensorRT,
+            precision,
+            ..Self::default()
+        }
+    }
+
+    /// Set GPU device ID
+    pub fn with_device_id(mut self, device_id: i32) -> Self {
+        self.device_id = device_id;
+        self
+    }
+
+    /// Use the CUDA execution provider for the ONNX backend
+    pub fn with_gpu(mut self, enable: bool) -> Self {
+        self.use_gpu = enable;
+        self
+    }
+
+    /// Set TensorRT engine cache directory
+    pub fn with_cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.engine_cache_dir = Some(dir.into());
+        self
+    }
+
+    /// Set the INT8 calibration table
+    pub fn with_int8_calibration_table(mut self, path: impl Into<PathBuf>) -> Self {
+        self.int8_calibration_table = Some(path.into());
+        self
+    }
+
+    /// Set warmup passes and the input shape they use
+    pub fn with_warmup(mut self, iterations: usize, shape: Vec<usize>) -> Self {
+        self.warmup_iterations = iterations;
+        self.warmup_shape = shape;
+        self
+    }
+
+    /// Options for the ONNX Runtime session
+    fn inference_config(&self) -> InferenceConfig {
+        InferenceConfig {
+            use_gpu: self.use_gpu,
+            device_id: self.device_id.max(0) as u32,
+            ..InferenceConfig::default()
+        }
+    }
+
+    /// Options for the TensorRT execution provider
+    pub fn tensorrt_config(&self) -> TensorRTConfig {
+        let mut config = TensorRTConfig {
+            precision: self.precision,
+            device_id: self.device_id,
+            engine_cache_dir: self.engine_cache_dir.clone(),
+            int8_calibration_table: self.int8_calibration_table.clone(),
+            warmup_iterations: self.warmup_iterations,
+            ..TensorRTConfig::default()
+        };
+        if let Some(&batch) = self.warmup_shape.first() {
+            config.batch_size = batch.max(1);
+        }
+        config
+    }
+}
+
+/// A loaded model that turns input tensors into output tensors
+pub trait InferenceBackend: Send {
+    /// Runtime executing the model
+    fn kind(&self) -> BackendKind;
+
+    /// Metadata of the loaded model
+    fn model_info(&self) -> &ModelInfo;
+
+    /// Run the model on `input`, returning its first output
+    fn infer(&mut self, input: &Tensor) -> HorusResult<Tensor>;
+
+    /// Run `iterations` passes on a zero tensor of `shape`, returning the total time
+    ///
+    /// The first TensorRT pass builds (or deserializes) the engine, so this
+    /// moves that cost out of the first tick.
+    fn warmup(&mut self, shape: &[usize], iterations: usize) -> HorusResult<Duration> {
+        let input = Tensor {
+            data: vec![0.0; shape.iter().product()],
+            shape: shape.to_vec(),
+            dtype: DataType::Float32,
+            name: None,
+        };
+        let start = Instant::now();
+        for _ in 0..iterations {
+            self.infer(&input)?;
+        }
+        Ok(start.elapsed())
+    }
+}
+
+/// Backend running an ONNX Runtime session (CPU, CUDA or TensorRT provider)
+pub struct OrtBackend {
+    kind: BackendKind,
+    session: Session,
+    model_info: ModelInfo,
+}
+
+impl InferenceBackend for OrtBackend {
+    fn kind(&self) -> BackendKind {
+        self.kind
+    }
+
+    fn model_info(&self) -> &ModelInfo {
+        &self.model_info
+    }
+
+    fn infer(&mut self, input: &Tensor) -> HorusResult<Tensor> {
+        let array = ArrayD::from_shape_vec(IxDyn(&input.shape), input.data.clone())
+            .map_err(|e| HorusError::Config(format!("Tensor conversion failed: {}", e)))?;
+        let input_tensor = OrtTensor::from_array(array)
+            .map_err(|e| HorusError::Config(format!("Failed to create input tensor: {}", e)))?;
+
+        let outputs = self
+            .session
+            .run(ort::inputs![input_tensor])
+            .map_err(|e| HorusError::Config(format!("Inference failed: {}", e)))?;
+
+        let first_output = outputs
+            .iter()
+            .next()
+            .ok_or_else(|| HorusError::Config("No outputs from model".to_string()))?;
+        let output_array: ndarray::ArrayViewD<f32> = first_output
+            .1
+            .try_extract_array()
+            .map_err(|e| HorusError::Config(format!("Failed to extract output: {}", e)))?;
+
+        Ok(Tensor {
+            data: output_array.iter().copied().collect(),
+            shape: output_array.shape().to_vec(),
+            dtype: DataType::Float32,
+            name: Some(self.model_info.name.clone()),
+        })
+    }
+}
+
+/// Load `model_path` with the backend selected in `config`
+pub fn load_backend(
+    model_path: &str,
+    config: &BackendConfig,
+) -> HorusResult<Box<dyn InferenceBackend>> {
+    let (session, model_info) = match config.backend {
+        BackendKind::Onnx => {
+            let session = ONNXInferenceNode::load_model(model_path, &config.inference_config())?;
+            let model_info = ONNXInferenceNode::extract_model_info(&session, model_path)?;
+            (session, model_info)
+        }
+        BackendKind::TensorRT => {
+            let session =
+                TensorRTInferenceNode::create_session(model_path, &config.tensorrt_config())?;
+            let (model_info, _) = TensorRTInferenceNode::extract_model_info(&session, model_path)?;
+            (session, model_info)
+        }
+    };
+
+    Ok(Box::new(OrtBackend {
+        kind: config.backend,
+        session,
+        model_info,
+    }))
+}
+
+/// Tensor-in, tensor-out inference node over any `InferenceBackend`
+///
+/// Publishes outputs on `output_topic` and metrics on `<output_topic>.metrics`.
+/// Warmup runs in `init()`, so a node whose engine fails to build stops
+/// scheduler startup instead of failing on every tick.
+pub struct InferenceNode {
+    /// Input hub for tensors
+    tensor_sub: Hub<Tensor>,
+    /// Output hub for tensors
+    tensor_pub: Hub<Tensor>,
+    /// Output hub for metrics
+    metrics_pub: Hub<InferenceMetrics>,
+    /// Loaded model
+    backend: Box<dyn InferenceBackend>,
+    /// Backend configuration
+    config: BackendConfig,
+    /// Frame counter
+    frame_count: u64,
+    /// Last inference latency for metrics
+    last_latency_ms: f32,
+}
+
+impl InferenceNode {
+    /// Load `model_path` with the configured backend
+    pub fn new(
+        model_path: &str,
+        input_topic: &str,
+        output_topic: &str,
+        config: BackendConfig,
+    ) -> HorusResult<Self> {
+        let backend = load_backend(model_path, &config)?;
+        Self::with_backend(backend, input_topic, output_topic, config)
+    }
+
+    /// Wrap an already loaded backend
+    pub fn with_backend(
+        backend: Box<dyn InferenceBackend>,
+        input_topic: &str,
+        output_topic: &str,
+        config: BackendConfig,
+    ) -> HorusResult<Self> {
+        Ok(Self {
+            tensor_sub: Hub::new(input_topic)?,
+            tensor_pub: Hub::new(output_topic)?,
+            metrics_pub: Hub::new(&format!("{}.metrics", output_topic))?,
+            backend,
+            config,
+            frame_count: 0,
+            last_latency_ms: 0.0,
+        })
+    }
+
+    /// The loaded backend
+    pub fn backend(&self) -> &dyn InferenceBackend {
+        self.backend.as_ref()
+    }
+}
+
+impl Node for InferenceNode {
+    fn name(&self) -> &'static str {
+        "InferenceNode"
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> HorusResult<()> {
+        if self.config.warmup_iterations == 0 {
+            return Ok(());
+        }
+        let elapsed = self
+            .backend
+            .warmup(&self.config.warmup_shape, self.config.warmup_iterations)?;
+        ctx.log_info(&format!(
+            "{} backend warmup: {} passes in {:.1}ms",
+            self.backend.kind(),
+            self.config.warmup_iterations,
+            elapsed.as_secs_f64() * 1000.0
+        ));
+        Ok(())
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let Some(tensor) = self.tensor_sub.recv(&mut ctx) else {
+            return;
+        };
+
+        let start = Instant::now();
+        match self.backend.infer(&tensor) {
+            Ok(output) => {
+                self.last_latency_ms = start.elapsed().as_secs_f32() * 1000.0;
+                let _ = self.tensor_pub.send(output, &mut ctx);
+            }
+            Err(e) => {
+                eprintln!("{} inference failed: {}", self.backend.kind(), e);
+                return;
+            }
+        }
+
+        self.frame_count += 1;
+
+        // Publish metrics periodically
+        if self.frame_count % 30 == 0 {
+            let throughput = if self.last_latency_ms > 0.0 {
+                1000.0 / self.last_latency_ms
+            } else {
+                0.0
+            };
+
+            let metrics = InferenceMetrics {
+                latency_ms: self.last_latency_ms,
+                throughput,
+                model_name: self.backend.model_info().name.clone(),
+                batch_size: tensor.shape.first().copied().unwrap_or(1),
+                timestamp_ns: 0,
+            };
+            let _ = self.metrics_pub.send(metrics, &mut ctx);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::messages::ml::ModelFormat;
+
+    struct CountingBackend {
+        model_info: ModelInfo,
+        calls: usize,
+    }
+
+    impl InferenceBackend for CountingBackend {
+        fn kind(&self) -> BackendKind {
+            BackendKind::TensorRT
+        }
+
+        fn model_info(&self) -> &ModelInfo {
+            &self.model_info
+        }
+
+        fn infer(&mut self, input: &Tensor) -> HorusResult<Tensor> {
+            self.calls += 1;
+            Ok(input.clone())
+        }
+    }
+
+    #[test]
+    fn test_backend_config_from_config_file() {
+        let config: BackendConfig = serde_json::from_str(
+            r#"{"backend": "tensorrt", "precision": "int8", "warmup_iterations": 5}"#,
+        )
+        .unwrap();
+        assert_eq!(config.backend, BackendKind::TensorRT);
+        assert_eq!(config.precision, TensorRTPrecision::INT8);
+        assert_eq!(config.warmup_iterations, 5);
+        assert_eq!(config.warmup_shape, vec![1, 3, 640, 640]);
+
+        let trt = config.tensorrt_config();
+        assert_eq!(trt.precision, TensorRTPrecision::INT8);
+        assert_eq!(trt.warmup_iterations, 5);
+
+        let default: BackendConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(default.backend, BackendKind::Onnx);
+    }
+
+    #[test]
+    fn test_backend_kind_from_str() {
+        assert_eq!(
+            "TensorRT".parse::<BackendKind>().unwrap(),
+            BackendKind::TensorRT
+        );
+        assert_eq!("trt".parse::<BackendKind>().unwrap(), BackendKind::TensorRT);
+        assert_eq!("onnx".parse::<BackendKind>().unwrap(), BackendKind::Onnx);
+        assert!("openvino".parse::<BackendKind>().is_err());
+    }
+
+    #[test]
+    fn test_default_warmup_runs_iterations() {
+        let mut backend = CountingBackend {
+            model_info: ModelInfo {
+                name: "counting".to_string(),
+                version: "1.0.0".to_string(),
+                format: ModelFormat::TensorRT,
+                input_shapes: vec![],
+                output_shapes: vec![],
+                input_names: vec![],
+                output_names: vec![],
+                metadata: std::collections::HashMap::new(),
+            },
+            calls: 0,
+        };
+        backend.warmup(&[1, 3, 8, 8], 4).unwrap();
+        assert_eq!(backend.calls, 4);
+    }
+}
@@ -2,14 +2,28 @@
 //
 // Production-ready ML inference nodes for HORUS
 
+#[cfg(feature = "onnx")]
+pub mod backend;
+
 #[cfg(feature = "onnx")]
 pub mod onnx_inference;
 
+#[cfg(feature = "onnx")]
+pub mod tensorrt_inference;
+
 #[cfg(feature = "tflite-inference")]
 pub mod tflite_inference;
 
+#[cfg(feature = "onnx")]
+pub use backend::{
+    load_backend, BackendConfig, BackendKind, InferenceBackend, InferenceNode, OrtBackend,
+};
+
 #[cfg(feature = "onnx")]
 pub use onnx_inference::{InferenceConfig, ONNXInferenceNode};
 
+#[cfg(feature = "onnx")]
+pub use tensorrt_inference::{TensorRTConfig, TensorRTInferenceNode, TensorRTPrecision};
+
 #[cfg(feature = "tflite-inference")]
 pub use tflite_inference::{TFLiteConfig, TFLiteInferenceNode};
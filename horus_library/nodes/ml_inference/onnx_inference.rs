@@ -163,7 +163,7 @@ impl ONNXInferenceNode {
     }
 
     /// Load ONNX model and create session
-    fn load_model(model_path: &str, config: &InferenceConfig) -> HorusResult<Session> {
+    pub(crate) fn load_model(model_path: &str, config: &InferenceConfig) -> HorusResult<Session> {
         if !Path::new(model_path).exists() {
             return Err(HorusError::Config(format!(
                 "Model file not found: {}",
@@ -206,7 +206,10 @@ impl ONNXInferenceNode {
     }
 
     /// Extract model metadata
-    fn extract_model_info(session: &Session, model_path: &str) -> HorusResult<ModelInfo> {
+    pub(crate) fn extract_model_info(
+        session: &Session,
+        model_path: &str,
+    ) -> HorusResult<ModelInfo> {
         let inputs = &session.inputs;
         let outputs = &session.outputs;
 
@@ -61,6 +61,7 @@
 use crate::messages::ml::{InferenceMetrics, ModelFormat, ModelInfo, Predictions, Tensor};
 use crate::messages::{Image, ImageEncoding};
 use horus_core::{HorusError, HorusResult, Hub, Node, NodeInfo};
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
@@ -86,7 +87,8 @@ use ort::{
 };
 
 /// TensorRT precision mode
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum TensorRTPrecision {
     /// Full precision (FP32) - highest accuracy, slowest
     FP32,
@@ -137,6 +139,11 @@ pub struct TensorRTConfig {
     pub enable_cuda_graph: bool,
     /// Maximum number of TensorRT optimization profiles
     pub max_optimization_profiles: i32,
+    /// INT8 calibration table, needed for INT8 models without Q/DQ nodes
+    pub int8_calibration_table: Option<PathBuf>,
+    /// Inference passes run in `init()` so engine building and CUDA
+    /// allocation happen before the first tick
+    pub warmup_iterations: usize,
 }
 
 impl Default for TensorRTConfig {
@@ -158,6 +165,8 @@ impl Default for TensorRTConfig {
             dla_core: 0,
             enable_cuda_graph: false, // Can cause issues with dynamic shapes
             max_optimization_profiles: 1,
+            int8_calibration_table: None,
+            warmup_iterations: 3,
         }
     }
 }
@@ -179,6 +188,18 @@ impl TensorRTConfig {
         self
     }
 
+    /// Set the INT8 calibration table
+    pub fn with_int8_calibration_table(mut self, path: impl Into<PathBuf>) -> Self {
+        self.int8_calibration_table = Some(path.into());
+        self
+    }
+
+    /// Set the number of warmup passes run in `init()` (0 disables warmup)
+    pub fn with_warmup(mut self, iterations: usize) -> Self {
+        self.warmup_iterations = iterations;
+        self
+    }
+
     /// Set batch size
     pub fn with_batch_size(mut self, batch_size: usize) -> Self {
         self.batch_size = batch_size;
@@ -327,7 +348,10 @@ impl TensorRTInferenceNode {
     }
 
     /// Create ONNX Runtime session with TensorRT execution provider
-    fn create_session(model_path: &str, config: &TensorRTConfig) -> HorusResult<Session> {
+    pub(crate) fn create_session(
+        model_path: &str,
+        config: &TensorRTConfig,
+    ) -> HorusResult<Session> {
         if !Path::new(model_path).exists() {
             return Err(HorusError::Config(format!(
                 "Model file not found: {}",
@@ -344,6 +368,13 @@ impl TensorRTInferenceNode {
             )
             .with_int8(config.precision == TensorRTPrecision::INT8);
 
+        if config.precision == TensorRTPrecision::INT8 {
+            if let Some(table) = &config.int8_calibration_table {
+                trt_ep =
+                    trt_ep.with_int8_calibration_table_name(table.to_string_lossy().to_string());
+            }
+        }
+
         // Set cache path if specified
         if let Some(cache_dir) = &config.engine_cache_dir {
             // Create cache directory if it doesn't exist
@@ -367,9 +398,7 @@ impl TensorRTInferenceNode {
             .with_optimization_level(GraphOptimizationLevel::Level3)
             .map_err(|e| HorusError::Config(format!("Failed to set optimization level: {}", e)))?
             .with_execution_providers([trt_ep.build(), cuda_ep.build()])
-            .map_err(|e| {
-                HorusError::Config(format!("Failed to add execution providers: {}", e))
-            })?
+            .map_err(|e| HorusError::Config(format!("Failed to add execution providers: {}", e)))?
             .commit_from_file(model_path)
             .map_err(|e| {
                 HorusError::Config(format!("Failed to load model '{}': {}", model_path, e))
@@ -385,7 +414,7 @@ impl TensorRTInferenceNode {
     }
 
     /// Extract model info from session
-    fn extract_model_info(
+    pub(crate) fn extract_model_info(
         session: &Session,
         model_path: &str,
     ) -> HorusResult<(ModelInfo, Vec<usize>)> {
@@ -457,8 +486,7 @@ impl TensorRTInferenceNode {
                     };
 
                     // Normalize with ImageNet stats
-                    let normalized =
-                        (pixel - self.config.norm_mean[c]) / self.config.norm_std[c];
+                    let normalized = (pixel - self.config.norm_mean[c]) / self.config.norm_std[c];
 
                     let dst_idx = c * target_h * target_w + y * target_w + x;
                     nchw_data[dst_idx] = normalized;
@@ -500,6 +528,16 @@ impl TensorRTInferenceNode {
         Ok(output_array.as_slice().unwrap_or(&[]).to_vec())
     }
 
+    /// Run the configured warmup passes on a zero input
+    fn warmup(&mut self) -> HorusResult<std::time::Duration> {
+        let start = Instant::now();
+        for _ in 0..self.config.warmup_iterations {
+            let input = ndarray::ArrayD::<f32>::zeros(IxDyn(&self.input_shape));
+            self.run_inference(input)?;
+        }
+        Ok(start.elapsed())
+    }
+
     /// Parse detection outputs (supports YOLO-style outputs)
     fn parse_detections(&self, output: &[f32], _img_width: u32, _img_height: u32) -> Predictions {
         let mut class_ids = Vec::new();
@@ -541,6 +579,20 @@ impl Node for TensorRTInferenceNode {
         "TensorRTInferenceNode"
     }
 
+    fn init(&mut self, ctx: &mut NodeInfo) -> HorusResult<()> {
+        if self.config.warmup_iterations == 0 {
+            return Ok(());
+        }
+        let elapsed = self.warmup()?;
+        ctx.log_info(&format!(
+            "TensorRT warmup: {} passes with {:?} precision in {:.1}ms",
+            self.config.warmup_iterations,
+            self.config.precision,
+            elapsed.as_secs_f64() * 1000.0
+        ));
+        Ok(())
+    }
+
     fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
         // Process image input
         if let Some(ref image_sub) = self.image_sub {
@@ -555,8 +607,7 @@ impl Node for TensorRTInferenceNode {
 
                 match self.run_inference(input) {
                     Ok(output) => {
-                        let predictions =
-                            self.parse_detections(&output, image.width, image.height);
+                        let predictions = self.parse_detections(&output, image.width, image.height);
                         let _ = self.predictions_pub.send(predictions, &mut ctx);
                     }
                     Err(e) => {
@@ -742,4 +793,14 @@ mod tests {
         assert!(config.use_dla);
         assert_eq!(config.dla_core, 1);
     }
+
+    #[test]
+    fn test_precision_serde_names() {
+        let precision: TensorRTPrecision = serde_json::from_str("\"int8\"").unwrap();
+        assert_eq!(precision, TensorRTPrecision::INT8);
+        assert_eq!(
+            serde_json::to_string(&TensorRTPrecision::FP16).unwrap(),
+            "\"fp16\""
+        );
+    }
 }
@@ -9,6 +9,9 @@
 //! ## Safety & Monitoring (Critical for Industrial Use)
 //! - `EmergencyStopNode` - Hardware emergency stop handler
 //! - `SafetyMonitorNode` - Critical safety system monitoring
+//! - `SnapshotNode` - Low-rate topic snapshots for fleet/cloud dashboards
+//! - `CsvLoggerNode` - Topic-to-CSV data capture with file rotation
+//! - `DisturbanceNode` - Time-bounded perturbations of topics for robustness tests
 //!
 //! ## Sensor Interfaces (Essential Building Blocks)
 //! - `CameraNode` - Vision input from cameras
@@ -19,6 +22,7 @@
 //! - `GpsNode` - GPS/GNSS positioning for outdoor navigation
 //! - `UltrasonicNode` - Ultrasonic distance sensors (HC-SR04, JSN-SR04T, etc.)
 //! - `BatteryMonitorNode` - Battery voltage, current, and health monitoring
+//! - `ChargingManagerNode` - BMS/smart charger integration (SMBus smart batteries, CAN BMS)
 //! - `ForceTorqueSensorNode` - 6-axis force/torque sensors (ATI, Robotiq, OnRobot, etc.)
 //!
 //! ## Control & Actuation (Movement and Control)
@@ -26,18 +30,27 @@
 //! - `BldcMotorNode` - Brushless DC motor control (ESC protocols: PWM, DShot, OneShot, CAN)
 //! - `StepperMotorNode` - Stepper motor control (A4988, DRV8825, TMC2208, etc.)
 //! - `DifferentialDriveNode` - Mobile robot base control
+//! - `CmdVelMuxNode` - Priority arbitration and acceleration limiting of velocity commands
 //! - `DynamixelNode` - Dynamixel smart servo control (Protocol 1.0/2.0)
 //! - `RoboclawMotorNode` - Roboclaw motor controller (BasicMicro 2x7A to 2x160A models)
 //! - `PidControllerNode` - Generic PID control
+//! - `AdmittanceControllerNode` - Force-compliant Cartesian control for arms
+//! - `JointTrajectoryNode` - Cubic/quintic joint trajectory execution for arms
 //! - `ServoControllerNode` - RC/Industrial servo control
 //!
 //! ## Navigation (Path Planning and Localization)
 //! - `PathPlannerNode` - A*/RRT path planning algorithms
+//! - `LocalPlannerNode` - DWA local planner producing obstacle-aware `CmdVel`
 //! - `LocalizationNode` - Robot position estimation
+//! - `EkfLocalizationNode` - Configurable EKF fusion of odometry, IMU and GPS
+//! - `SlamNode` - 2D scan-matching SLAM producing an occupancy grid map
 //! - `CollisionDetectorNode` - Real-time collision avoidance
+//! - `StaticTransformNode` - Fixed frames from a URDF/YAML robot description
 //!
 //! ## Industrial Integration (Production Ready)
 //! - `CanBusNode` - CAN bus communication (SocketCAN, automotive, industrial)
+//! - `CanDbcNode` - DBC-based mapping between CAN frames and typed messages
+//! - `EthercatServoNode` - CiA-402 servo drives on an EtherCAT bus (SOEM master)
 //! - `ModbusNode` - Modbus TCP/RTU protocol handler
 //! - `DigitalIONode` - Digital I/O interface
 //! - `SerialNode` - UART/Serial communication (GPS, Arduino, sensors)
@@ -45,10 +58,12 @@
 //!
 //! ## Vision & Image Processing
 //! - `ImageProcessorNode` - Image preprocessing and filtering
+//! - `FiducialDetectorNode` - AprilTag/ArUco marker detection with 6-DoF poses
 //!
 //! ## Input Devices
 //! - `KeyboardInputNode` - Keyboard input capture
 //! - `JoystickInputNode` - Gamepad/joystick input
+//! - `TeleopSenderNode` / `TeleopReceiverNode` - Velocity teleop over UDP with safety timeout
 //!
 //! # Usage Examples
 //!
@@ -89,14 +104,28 @@
 pub mod processor;
 
 // Hardware-independent nodes (always available)
+pub mod admittance_controller;
+pub mod can_dbc;
+pub mod charging_manager;
+pub mod cmd_vel_mux;
 pub mod collision_detector;
+pub mod csv_logger;
 pub mod differential_drive;
+pub mod disturbance;
+pub mod ekf_localization;
 pub mod emergency_stop;
+pub mod ethercat_servo;
+pub mod joint_trajectory;
+pub mod local_planner;
 pub mod localization;
+pub mod network_teleop;
 pub mod odometry;
 pub mod path_planner;
 pub mod pid_controller;
 pub mod safety_monitor;
+pub mod slam;
+pub mod snapshot;
+pub mod static_transform;
 
 // Vision nodes (require camera backends)
 #[cfg(any(
@@ -110,6 +139,9 @@ pub mod camera;
 #[cfg(feature = "realsense")]
 pub mod depth_camera;
 
+#[cfg(feature = "opencv-backend")]
+pub mod fiducial_detector;
+
 #[cfg(feature = "opencv-backend")]
 pub mod image_processor;
 
@@ -192,14 +224,30 @@ pub mod llm;
 // Re-export node types for convenience
 //
 // Hardware-independent nodes (always available)
+pub use admittance_controller::AdmittanceControllerNode;
+pub use can_dbc::{CanDbcConfig, CanDbcNode, MappedMessageType, SignalMapping};
+pub use charging_manager::ChargingManagerNode;
+pub use cmd_vel_mux::CmdVelMuxNode;
 pub use collision_detector::CollisionDetectorNode;
+pub use csv_logger::CsvLoggerNode;
 pub use differential_drive::DifferentialDriveNode;
+pub use disturbance::{Disturbance, DisturbanceNode, Perturbation};
+pub use ekf_localization::{
+    EkfLocalizationConfig, EkfLocalizationNode, FusedVariable, FusionSensorConfig,
+};
 pub use emergency_stop::EmergencyStopNode;
+pub use ethercat_servo::{EthercatServoNode, ServoAxisConfig, ServoAxisFeedback};
+pub use joint_trajectory::JointTrajectoryNode;
+pub use local_planner::LocalPlannerNode;
 pub use localization::LocalizationNode;
+pub use network_teleop::{TeleopReceiverNode, TeleopSenderNode};
 pub use odometry::OdometryNode;
 pub use path_planner::PathPlannerNode;
 pub use pid_controller::PidControllerNode;
 pub use safety_monitor::SafetyMonitorNode;
+pub use slam::SlamNode;
+pub use snapshot::{CloudSnapshotUploader, SnapshotNode, SnapshotUploader};
+pub use static_transform::StaticTransformNode;
 
 // Vision nodes
 #[cfg(any(
@@ -213,12 +261,15 @@ pub use camera::CameraNode;
 #[cfg(feature = "realsense")]
 pub use depth_camera::DepthCameraNode;
 
+#[cfg(feature = "opencv-backend")]
+pub use fiducial_detector::FiducialDetectorNode;
+
 #[cfg(feature = "opencv-backend")]
 pub use image_processor::ImageProcessorNode;
 
 // Input device nodes
 #[cfg(feature = "gilrs")]
-pub use joystick::JoystickInputNode;
+pub use joystick::{JoystickInputNode, JoystickProfile, TeleopMapping};
 
 #[cfg(feature = "crossterm")]
 pub use keyboard_input::KeyboardInputNode;
@@ -287,6 +338,12 @@ pub use spi_bus::SpiBusNode;
 #[cfg(feature = "onnx")]
 pub use ml_inference::{InferenceConfig, ONNXInferenceNode};
 
+#[cfg(feature = "onnx")]
+pub use ml_inference::{
+    BackendConfig, BackendKind, InferenceBackend, InferenceNode, TensorRTConfig,
+    TensorRTInferenceNode, TensorRTPrecision,
+};
+
 #[cfg(feature = "tflite-inference")]
 pub use ml_inference::{TFLiteConfig, TFLiteInferenceNode};
 
@@ -0,0 +1,613 @@
+use crate::CmdVel;
+use horus_core::error::{HorusError, HorusResult};
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo};
+use std::io::ErrorKind;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+pub mod protocol;
+
+pub use protocol::{TeleopAck, TeleopCommand, DEFAULT_TELEOP_PORT};
+
+/// Upper bound on datagrams read per tick
+const MAX_DATAGRAMS_PER_TICK: usize = 256;
+
+fn bind_nonblocking(addr: &str) -> Result<UdpSocket> {
+    let socket = UdpSocket::bind(addr).map_err(|e| {
+        HorusError::communication(format!("Failed to bind teleop socket {}: {}", addr, e))
+    })?;
+    socket.set_nonblocking(true).map_err(|e| {
+        HorusError::communication(format!("Failed to set teleop socket non-blocking: {}", e))
+    })?;
+    Ok(socket)
+}
+
+/// Teleop Sender Node - Streams operator velocity commands to a robot over UDP
+///
+/// Runs on the operator machine next to a [`JoystickInputNode`](crate::nodes::JoystickInputNode)
+/// that maps the gamepad to `CmdVel`. Every tick (up to the send rate) the
+/// newest command is sent to the robot with a sequence number and send time.
+/// While the joystick publishes nothing (enable button released) the node
+/// keeps sending disabled zero commands as a heartbeat. Acknowledgements from
+/// the robot give the round-trip time, which is forwarded to the robot.
+///
+/// # Example
+/// ```rust,ignore
+/// use horus_library::nodes::{JoystickInputNode, JoystickProfile, TeleopSenderNode};
+///
+/// let joystick = JoystickInputNode::builder()
+///     .with_profile(JoystickProfile::builtin("xbox").unwrap())
+///     .with_cmd_vel_topic("cmd_vel")
+///     .build()?;
+/// let sender = TeleopSenderNode::new("robot.local:7460")?;
+/// ```
+pub struct TeleopSenderNode {
+    cmd_subscriber: Hub<CmdVel>,
+    socket: UdpSocket,
+    robot: SocketAddr,
+
+    // Configuration
+    send_interval: Duration,
+    input_timeout: Duration,
+
+    // Link state
+    session: u32,
+    sequence: u32,
+    clock: Instant,
+    latest: Option<(CmdVel, Instant)>,
+    last_send: Option<Instant>,
+    last_ack: Option<Instant>,
+    rtt: Option<Duration>,
+    send_failed: bool,
+    link_up: bool,
+}
+
+impl TeleopSenderNode {
+    /// Create a sender reading "cmd_vel" and sending to `robot` (`host:port`)
+    pub fn new(robot: &str) -> Result<Self> {
+        Self::new_with_topic(robot, "cmd_vel")
+    }
+
+    /// Create a sender reading commands from a custom topic
+    pub fn new_with_topic(robot: &str, cmd_topic: &str) -> Result<Self> {
+        let robot_addr = robot
+            .to_socket_addrs()
+            .ok()
+            .and_then(|mut addrs| addrs.next())
+            .ok_or_else(|| {
+                HorusError::Config(format!("Cannot resolve teleop robot address '{}'", robot))
+            })?;
+        let bind = if robot_addr.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+
+        // Distinguishes this run from earlier ones with restarted sequence numbers
+        let session = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .subsec_nanos()
+            ^ std::process::id().rotate_left(16);
+
+        Ok(Self {
+            cmd_subscriber: Hub::new(cmd_topic)?,
+            socket: bind_nonblocking(bind)?,
+            robot: robot_addr,
+
+            send_interval: Duration::from_millis(20), // 50 Hz
+            input_timeout: Duration::from_millis(200),
+
+            session,
+            sequence: 0,
+            clock: Instant::now(),
+            latest: None,
+            last_send: None,
+            last_ack: None,
+            rtt: None,
+            send_failed: false,
+            link_up: false,
+        })
+    }
+
+    /// Set the maximum send rate in Hz
+    pub fn set_send_rate(&mut self, rate_hz: f64) {
+        self.send_interval = Duration::from_secs_f64(1.0 / rate_hz.clamp(1.0, 1000.0));
+    }
+
+    /// Set how long a joystick command stays valid without a newer one
+    pub fn set_input_timeout(&mut self, timeout: Duration) {
+        self.input_timeout = timeout;
+    }
+
+    /// Last measured round-trip time to the robot
+    pub fn get_round_trip_time(&self) -> Option<Duration> {
+        self.rtt
+    }
+
+    /// Check if the robot acknowledged a command within the last second
+    pub fn is_connected(&self) -> bool {
+        self.link_up
+    }
+
+    /// Address commands are sent to
+    pub fn robot_addr(&self) -> SocketAddr {
+        self.robot
+    }
+
+    fn clock_ns(&self, now: Instant) -> u64 {
+        now.saturating_duration_since(self.clock).as_nanos() as u64
+    }
+
+    /// Read acknowledgements and update the round-trip time
+    fn receive_acks(&mut self, now: Instant) {
+        let mut buf = [0u8; 64];
+        for _ in 0..MAX_DATAGRAMS_PER_TICK {
+            let (len, from) = match self.socket.recv_from(&mut buf) {
+                Ok(received) => received,
+                Err(_) => break,
+            };
+            let ack = match TeleopAck::decode(&buf[..len]) {
+                Some(ack) if from == self.robot && ack.session == self.session => ack,
+                _ => continue,
+            };
+            let rtt_ns = self.clock_ns(now).saturating_sub(ack.echo_ns);
+            self.rtt = Some(Duration::from_nanos(rtt_ns));
+            self.last_ack = Some(now);
+        }
+    }
+
+    /// Command to send now, `None` while rate limited
+    fn next_command(&mut self, now: Instant) -> Option<TeleopCommand> {
+        if self
+            .last_send
+            .is_some_and(|last| now.saturating_duration_since(last) < self.send_interval)
+        {
+            return None;
+        }
+        self.last_send = Some(now);
+        self.sequence = self.sequence.wrapping_add(1);
+
+        let fresh = self
+            .latest
+            .filter(|(_, received)| now.saturating_duration_since(*received) <= self.input_timeout);
+        let (enabled, linear, angular) = match fresh {
+            Some((cmd, _)) => (true, cmd.linear, cmd.angular),
+            None => (false, 0.0, 0.0),
+        };
+        Some(TeleopCommand {
+            session: self.session,
+            sequence: self.sequence,
+            sent_ns: self.clock_ns(now),
+            rtt_us: self
+                .rtt
+                .map_or(0, |rtt| rtt.as_micros().clamp(1, u32::MAX as u128) as u32),
+            enabled,
+            linear,
+            angular,
+        })
+    }
+}
+
+impl Node for TeleopSenderNode {
+    fn name(&self) -> &'static str {
+        "TeleopSenderNode"
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        ctx.log_info(&format!(
+            "TeleopSenderNode sending to {} (session {:08x})",
+            self.robot, self.session
+        ));
+        Ok(())
+    }
+
+    fn shutdown(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        ctx.log_info("TeleopSenderNode shutting down - sending stop");
+        self.latest = None;
+        self.last_send = None;
+        if let Some(command) = self.next_command(Instant::now()) {
+            let _ = self.socket.send_to(&command.encode(), self.robot);
+        }
+        Ok(())
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let now = Instant::now();
+
+        while let Some(cmd) = self.cmd_subscriber.recv(&mut None) {
+            self.latest = Some((cmd, now));
+        }
+        self.receive_acks(now);
+
+        if let Some(command) = self.next_command(now) {
+            match self.socket.send_to(&command.encode(), self.robot) {
+                Ok(_) => self.send_failed = false,
+                Err(e) => {
+                    if !self.send_failed {
+                        if let Some(ctx) = ctx.as_mut() {
+                            ctx.log_warning(&format!(
+                                "TeleopSenderNode: send to {} failed: {}",
+                                self.robot, e
+                            ));
+                        }
+                    }
+                    self.send_failed = true;
+                }
+            }
+        }
+
+        let link_up = self
+            .last_ack
+            .is_some_and(|last| now.saturating_duration_since(last) < Duration::from_secs(1));
+        if link_up != self.link_up {
+            if let Some(ctx) = ctx.as_mut() {
+                if link_up {
+                    ctx.log_info(&format!("TeleopSenderNode: robot {} connected", self.robot));
+                } else {
+                    ctx.log_warning(&format!(
+                        "TeleopSenderNode: no answer from robot {}",
+                        self.robot
+                    ));
+                }
+            }
+            self.link_up = link_up;
+        }
+    }
+}
+
+/// Sender the receiver is locked to
+#[derive(Debug, Clone, Copy)]
+struct TeleopPeer {
+    addr: SocketAddr,
+    session: u32,
+    last_sequence: u32,
+    last_packet: Instant,
+    command: TeleopCommand,
+}
+
+/// Teleop Receiver Node - Robot side of the network teleop link
+///
+/// Receives commands from a [`TeleopSenderNode`], acknowledges each one and
+/// publishes the newest enabled command as `CmdVel` every tick. Duplicate
+/// and out-of-order datagrams are dropped and gaps in the sequence are
+/// counted as lost. The node locks to one sender; another sender is only
+/// accepted after the current one has timed out.
+///
+/// When no datagram arrives within the timeout, the operator releases the
+/// enable button, or the reported latency exceeds the limit, one zero
+/// command is published and the node stays silent until driving resumes, so
+/// a [`CmdVelMuxNode`](crate::nodes::CmdVelMuxNode) falls back to its other
+/// inputs.
+///
+/// # Example
+/// ```rust,ignore
+/// use horus_library::nodes::TeleopReceiverNode;
+/// use std::time::Duration;
+///
+/// let mut receiver = TeleopReceiverNode::new_with_topic("0.0.0.0:7460", "cmd_vel.teleop")?;
+/// receiver.set_timeout(Duration::from_millis(250));
+/// receiver.set_max_latency(Some(Duration::from_millis(150)));
+/// ```
+pub struct TeleopReceiverNode {
+    cmd_publisher: Hub<CmdVel>,
+    socket: UdpSocket,
+
+    // Configuration
+    timeout: Duration,
+    max_latency: Option<Duration>,
+
+    // Link state
+    peer: Option<TeleopPeer>,
+    driving: bool,
+    packets_received: u64,
+    packets_lost: u64,
+    packets_rejected: u64,
+}
+
+impl TeleopReceiverNode {
+    /// Create a receiver on UDP port 7460 publishing "cmd_vel"
+    pub fn new() -> Result<Self> {
+        Self::new_with_topic(&format!("0.0.0.0:{}", DEFAULT_TELEOP_PORT), "cmd_vel")
+    }
+
+    /// Create a receiver bound to `bind_addr` publishing on a custom topic
+    pub fn new_with_topic(bind_addr: &str, cmd_topic: &str) -> Result<Self> {
+        Ok(Self {
+            cmd_publisher: Hub::new(cmd_topic)?,
+            socket: bind_nonblocking(bind_addr)?,
+
+            timeout: Duration::from_millis(300),
+            max_latency: None,
+
+            peer: None,
+            driving: false,
+            packets_received: 0,
+            packets_lost: 0,
+            packets_rejected: 0,
+        })
+    }
+
+    /// Set how long the last command stays valid without a newer datagram
+    pub fn set_timeout(&mut self, timeout: Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Stop while the one-way latency reported by the sender exceeds `max`
+    pub fn set_max_latency(&mut self, max: Option<Duration>) {
+        self.max_latency = max;
+    }
+
+    /// Local address of the socket
+    pub fn local_addr(&self) -> Result<SocketAddr> {
+        self.socket
+            .local_addr()
+            .map_err(|e| HorusError::communication(format!("Teleop socket address: {}", e)))
+    }
+
+    /// Address of the sender in control
+    pub fn sender_addr(&self) -> Option<SocketAddr> {
+        self.peer.map(|peer| peer.addr)
+    }
+
+    /// Estimated one-way latency (half the sender's round-trip time)
+    pub fn get_latency(&self) -> Option<Duration> {
+        self.peer
+            .map(|peer| peer.command.rtt_us)
+            .filter(|&rtt_us| rtt_us > 0)
+            .map(|rtt_us| Duration::from_micros(rtt_us as u64 / 2))
+    }
+
+    /// Check if datagrams arrived within the timeout
+    pub fn is_connected(&self) -> bool {
+        self.is_fresh(Instant::now())
+    }
+
+    /// Commands accepted from the sender in control
+    pub fn get_packets_received(&self) -> u64 {
+        self.packets_received
+    }
+
+    /// Sequence numbers that never arrived
+    pub fn get_packets_lost(&self) -> u64 {
+        self.packets_lost
+    }
+
+    /// Malformed, duplicate, out-of-order or foreign datagrams
+    pub fn get_packets_rejected(&self) -> u64 {
+        self.packets_rejected
+    }
+
+    fn is_fresh(&self, now: Instant) -> bool {
+        self.peer
+            .is_some_and(|peer| now.saturating_duration_since(peer.last_packet) <= self.timeout)
+    }
+
+    /// Check a datagram and make it the current command; returns the ack to send
+    fn accept(&mut self, buf: &[u8], from: SocketAddr, now: Instant) -> Option<TeleopAck> {
+        let Some(command) = TeleopCommand::decode(buf) else {
+            self.packets_rejected += 1;
+            return None;
+        };
+
+        let fresh = self.is_fresh(now);
+        match self.peer.as_mut() {
+            Some(peer) if peer.addr == from && peer.session == command.session => {
+                // Wrapping comparison: anything not newer is a duplicate or late
+                let ahead = command.sequence.wrapping_sub(peer.last_sequence);
+                if ahead == 0 || ahead > u32::MAX / 2 {
+                    self.packets_rejected += 1;
+                    return None;
+                }
+                self.packets_lost += (ahead - 1) as u64;
+                peer.last_sequence = command.sequence;
+                peer.last_packet = now;
+                peer.command = command;
+            }
+            Some(_) if fresh => {
+                self.packets_rejected += 1;
+                return None;
+            }
+            _ => {
+                self.peer = Some(TeleopPeer {
+                    addr: from,
+                    session: command.session,
+                    last_sequence: command.sequence,
+                    last_packet: now,
+                    command,
+                });
+            }
+        }
+
+        self.packets_received += 1;
+        Some(TeleopAck {
+            session: command.session,
+            sequence: command.sequence,
+            echo_ns: command.sent_ns,
+        })
+    }
+
+    /// Command to publish this tick, None when there is nothing to publish
+    fn update(&mut self, now: Instant) -> Option<CmdVel> {
+        let latency_ok = match (self.max_latency, self.get_latency()) {
+            (Some(max), Some(latency)) => latency <= max,
+            _ => true,
+        };
+        let command = self
+            .peer
+            .map(|peer| peer.command)
+            .filter(|command| command.enabled && latency_ok && self.is_fresh(now));
+
+        match command {
+            Some(command) => {
+                self.driving = true;
+                Some(CmdVel::new(command.linear, command.angular))
+            }
+            None if self.driving => {
+                self.driving = false;
+                Some(CmdVel::zero())
+            }
+            None => None,
+        }
+    }
+}
+
+impl Node for TeleopReceiverNode {
+    fn name(&self) -> &'static str {
+        "TeleopReceiverNode"
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        ctx.log_info(&format!(
+            "TeleopReceiverNode listening on {}",
+            self.local_addr()?
+        ));
+        Ok(())
+    }
+
+    fn shutdown(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        ctx.log_info("TeleopReceiverNode shutting down - stopping robot");
+        if self.driving {
+            self.driving = false;
+            let _ = self.cmd_publisher.send(CmdVel::zero(), &mut None);
+        }
+        Ok(())
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let now = Instant::now();
+        let was_fresh = self.is_fresh(now);
+        let previous_sender = self.sender_addr();
+
+        let mut buf = [0u8; 64];
+        for _ in 0..MAX_DATAGRAMS_PER_TICK {
+            let (len, from) = match self.socket.recv_from(&mut buf) {
+                Ok(received) => received,
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                // e.g. ICMP port unreachable reported for an earlier ack
+                Err(_) => continue,
+            };
+            if let Some(ack) = self.accept(&buf[..len], from, now) {
+                let _ = self.socket.send_to(&ack.encode(), from);
+            }
+        }
+
+        if let Some(ctx) = ctx.as_mut() {
+            let sender = self.sender_addr();
+            if self.is_fresh(now) && (!was_fresh || sender != previous_sender) {
+                ctx.log_info(&format!(
+                    "TeleopReceiverNode: operator {} connected",
+                    sender.map(|addr| addr.to_string()).unwrap_or_default()
+                ));
+            } else if was_fresh && !self.is_fresh(now) {
+                ctx.log_warning("TeleopReceiverNode: teleop link lost - stopping robot");
+            }
+        }
+
+        if let Some(cmd) = self.update(now) {
+            let _ = self.cmd_publisher.send(cmd, &mut None);
+        }
+    }
+}
+
+// Default impl removed - use TeleopReceiverNode::new() instead which returns HorusResult
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(session: u32, sequence: u32, enabled: bool) -> [u8; TeleopCommand::LEN] {
+        TeleopCommand {
+            session,
+            sequence,
+            sent_ns: sequence as u64 * 1_000_000,
+            rtt_us: 20_000,
+            enabled,
+            linear: 0.5,
+            angular: 0.25,
+        }
+        .encode()
+    }
+
+    #[test]
+    fn test_receiver_sequence_and_timeout() {
+        let mut receiver =
+            TeleopReceiverNode::new_with_topic("127.0.0.1:0", "test_teleop_rx.cmd").unwrap();
+        let operator: SocketAddr = "127.0.0.1:40000".parse().unwrap();
+        let intruder: SocketAddr = "127.0.0.1:40001".parse().unwrap();
+        let t0 = Instant::now();
+        assert!(receiver.update(t0).is_none());
+
+        let ack = receiver.accept(&command(7, 1, true), operator, t0).unwrap();
+        assert_eq!((ack.sequence, ack.echo_ns), (1, 1_000_000));
+        let cmd = receiver.update(t0).unwrap();
+        assert_eq!((cmd.linear, cmd.angular), (0.5, 0.25));
+        assert_eq!(receiver.get_latency(), Some(Duration::from_millis(10)));
+
+        // Gaps count as lost, duplicates/late datagrams and other senders are rejected
+        let t = t0 + Duration::from_millis(50);
+        assert!(receiver.accept(&command(7, 4, true), operator, t).is_some());
+        assert!(receiver.accept(&command(7, 3, true), operator, t).is_none());
+        assert!(receiver.accept(&command(7, 4, true), operator, t).is_none());
+        assert!(receiver.accept(&command(9, 1, true), intruder, t).is_none());
+        assert!(receiver.accept(b"garbage", operator, t).is_none());
+        assert_eq!(receiver.get_packets_lost(), 2);
+        assert_eq!(receiver.get_packets_rejected(), 4);
+
+        // Latency limit stops the robot
+        receiver.set_max_latency(Some(Duration::from_millis(5)));
+        assert_eq!(receiver.update(t).map(|cmd| cmd.linear), Some(0.0));
+        receiver.set_max_latency(None);
+        assert!(receiver.update(t).is_some());
+
+        // Packets stop: one zero command, then silence
+        let t = t + Duration::from_millis(400);
+        let cmd = receiver.update(t).unwrap();
+        assert_eq!((cmd.linear, cmd.angular), (0.0, 0.0));
+        assert!(receiver.update(t).is_none());
+
+        // After the timeout another sender may take over
+        assert!(receiver.accept(&command(9, 1, true), intruder, t).is_some());
+        assert_eq!(receiver.sender_addr(), Some(intruder));
+    }
+
+    #[test]
+    fn test_sender_heartbeat_and_rtt() {
+        let mut receiver =
+            TeleopReceiverNode::new_with_topic("127.0.0.1:0", "test_teleop_link.rx").unwrap();
+        let robot = receiver.local_addr().unwrap().to_string();
+        let mut sender = TeleopSenderNode::new_with_topic(&robot, "test_teleop_link.tx").unwrap();
+
+        let t0 = Instant::now();
+        sender.latest = Some((CmdVel::new(0.3, -0.2), t0));
+        let first = sender.next_command(t0).unwrap();
+        assert!(first.enabled);
+        assert!(sender.next_command(t0).is_none()); // Rate limited
+
+        // Joystick silent: disabled heartbeat
+        let t = t0 + Duration::from_millis(300);
+        let heartbeat = sender.next_command(t).unwrap();
+        assert!(!heartbeat.enabled);
+        assert_eq!(heartbeat.sequence, first.sequence + 1);
+
+        // Round trip over loopback
+        let ack = receiver
+            .accept(&heartbeat.encode(), "127.0.0.1:1".parse().unwrap(), t)
+            .unwrap();
+        let sender_addr =
+            SocketAddr::from(([127, 0, 0, 1], sender.socket.local_addr().unwrap().port()));
+        // Acks from anyone but the robot are ignored
+        sender.socket.send_to(&ack.encode(), sender_addr).unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        sender.receive_acks(t + Duration::from_millis(5));
+        assert!(sender.get_round_trip_time().is_none());
+
+        receiver.socket.send_to(&ack.encode(), sender_addr).unwrap();
+        std::thread::sleep(Duration::from_millis(20));
+        sender.receive_acks(t + Duration::from_millis(5));
+        assert_eq!(sender.get_round_trip_time(), Some(Duration::from_millis(5)));
+    }
+}
@@ -0,0 +1,169 @@
+//! Wire format of the network teleop link
+//!
+//! Two fixed-size little-endian datagrams share an 8-byte header
+//! (`HTEL`, version, kind, flags, reserved):
+//!
+//! - [`TeleopCommand`] (operator -> robot): session, sequence, send time,
+//!   last round-trip time and the velocity command
+//! - [`TeleopAck`] (robot -> operator): echoes session, sequence and send
+//!   time so the operator measures the round trip on its own clock
+
+/// Header magic of every teleop datagram
+pub const MAGIC: [u8; 4] = *b"HTEL";
+
+/// Protocol version
+pub const VERSION: u8 = 1;
+
+/// Default UDP port of the robot side
+pub const DEFAULT_TELEOP_PORT: u16 = 7460;
+
+const KIND_COMMAND: u8 = 1;
+const KIND_ACK: u8 = 2;
+const FLAG_ENABLED: u8 = 0x01;
+const HEADER_LEN: usize = 8;
+
+fn header(kind: u8, flags: u8) -> [u8; HEADER_LEN] {
+    [
+        MAGIC[0], MAGIC[1], MAGIC[2], MAGIC[3], VERSION, kind, flags, 0,
+    ]
+}
+
+/// Flags of a datagram with a valid header of `kind`
+fn check_header(buf: &[u8], kind: u8, len: usize) -> Option<u8> {
+    if buf.len() == len && buf[..4] == MAGIC && buf[4] == VERSION && buf[5] == kind {
+        Some(buf[6])
+    } else {
+        None
+    }
+}
+
+fn u32_at(buf: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(buf[offset..offset + 4].try_into().unwrap())
+}
+
+fn u64_at(buf: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(buf[offset..offset + 8].try_into().unwrap())
+}
+
+fn f32_at(buf: &[u8], offset: usize) -> f32 {
+    f32::from_le_bytes(buf[offset..offset + 4].try_into().unwrap())
+}
+
+/// Velocity command sent by the operator
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TeleopCommand {
+    /// Random per sender start, lets the robot tell restarted senders apart
+    pub session: u32,
+    /// Incremented for every datagram
+    pub sequence: u32,
+    /// Send time on the sender's monotonic clock in nanoseconds
+    pub sent_ns: u64,
+    /// Last measured round-trip time in microseconds (0 = not measured yet)
+    pub rtt_us: u32,
+    /// False while the operator is not driving (enable button released)
+    pub enabled: bool,
+    /// Forward velocity in m/s
+    pub linear: f32,
+    /// Yaw rate in rad/s
+    pub angular: f32,
+}
+
+impl TeleopCommand {
+    pub const LEN: usize = HEADER_LEN + 28;
+
+    pub fn encode(&self) -> [u8; Self::LEN] {
+        let flags = if self.enabled { FLAG_ENABLED } else { 0 };
+        let mut buf = [0u8; Self::LEN];
+        buf[..HEADER_LEN].copy_from_slice(&header(KIND_COMMAND, flags));
+        buf[8..12].copy_from_slice(&self.session.to_le_bytes());
+        buf[12..16].copy_from_slice(&self.sequence.to_le_bytes());
+        buf[16..24].copy_from_slice(&self.sent_ns.to_le_bytes());
+        buf[24..28].copy_from_slice(&self.rtt_us.to_le_bytes());
+        buf[28..32].copy_from_slice(&self.linear.to_le_bytes());
+        buf[32..36].copy_from_slice(&self.angular.to_le_bytes());
+        buf
+    }
+
+    /// Decode a datagram; `None` for foreign, malformed or non-finite commands
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        let flags = check_header(buf, KIND_COMMAND, Self::LEN)?;
+        let command = Self {
+            session: u32_at(buf, 8),
+            sequence: u32_at(buf, 12),
+            sent_ns: u64_at(buf, 16),
+            rtt_us: u32_at(buf, 24),
+            enabled: flags & FLAG_ENABLED != 0,
+            linear: f32_at(buf, 28),
+            angular: f32_at(buf, 32),
+        };
+        (command.linear.is_finite() && command.angular.is_finite()).then_some(command)
+    }
+}
+
+/// Acknowledgement of a command, sent back by the robot
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TeleopAck {
+    pub session: u32,
+    pub sequence: u32,
+    /// `sent_ns` of the acknowledged command
+    pub echo_ns: u64,
+}
+
+impl TeleopAck {
+    pub const LEN: usize = HEADER_LEN + 16;
+
+    pub fn encode(&self) -> [u8; Self::LEN] {
+        let mut buf = [0u8; Self::LEN];
+        buf[..HEADER_LEN].copy_from_slice(&header(KIND_ACK, 0));
+        buf[8..12].copy_from_slice(&self.session.to_le_bytes());
+        buf[12..16].copy_from_slice(&self.sequence.to_le_bytes());
+        buf[16..24].copy_from_slice(&self.echo_ns.to_le_bytes());
+        buf
+    }
+
+    pub fn decode(buf: &[u8]) -> Option<Self> {
+        check_header(buf, KIND_ACK, Self::LEN)?;
+        Some(Self {
+            session: u32_at(buf, 8),
+            sequence: u32_at(buf, 12),
+            echo_ns: u64_at(buf, 16),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let command = TeleopCommand {
+            session: 0xDEAD_BEEF,
+            sequence: 42,
+            sent_ns: 1_234_567_890,
+            rtt_us: 3_500,
+            enabled: true,
+            linear: 0.5,
+            angular: -1.25,
+        };
+        let buf = command.encode();
+        assert_eq!(&buf[..4], b"HTEL");
+        assert_eq!(TeleopCommand::decode(&buf), Some(command));
+        // Kinds are not interchangeable, lengths are checked
+        assert_eq!(TeleopAck::decode(&buf), None);
+        assert_eq!(TeleopCommand::decode(&buf[..20]), None);
+
+        let nan = TeleopCommand {
+            linear: f32::NAN,
+            ..command
+        };
+        assert_eq!(TeleopCommand::decode(&nan.encode()), None);
+
+        let ack = TeleopAck {
+            session: 7,
+            sequence: 42,
+            echo_ns: 99,
+        };
+        assert_eq!(TeleopAck::decode(&ack.encode()), Some(ack));
+    }
+}
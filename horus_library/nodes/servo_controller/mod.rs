@@ -1,5 +1,6 @@
 use crate::{JointCommand, ServoCommand};
-use horus_core::error::HorusResult;
+use horus_core::driver::DriverStatus;
+use horus_core::error::{HorusError, HorusResult};
 
 // Type alias for cleaner signatures
 type Result<T> = HorusResult<T>;
@@ -7,15 +8,48 @@ use horus_core::{Hub, Node, NodeInfo};
 use std::collections::HashMap;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+// Import driver types
+use crate::drivers::dynamixel::{
+    DynamixelConfig, DynamixelDriver, DynamixelDriverBackend, DynamixelMode, MAX_SYNC_IDS,
+};
+use crate::drivers::gpio::{GpioHal, PwmServo};
+
 /// Servo Controller Node - Multi-servo control for robot arms and actuators
 ///
 /// Controls multiple servo motors with position, velocity, and torque commands.
 /// Supports both individual servo commands and coordinated joint control.
+///
+/// Without a driver the servos are simulated. With a Dynamixel driver
+/// (Protocol 2.0) every tick writes the goals of all servos with one sync
+/// write per control mode and reads position, velocity and current of up to
+/// 32 servos with one sync read. With PWM servos every tick sends the
+/// interpolated position of each servo as a pulse width through a GPIO
+/// backend (see [`GpioHal`]).
+///
+/// # Example
+/// ```rust,ignore
+/// use horus_library::drivers::{DynamixelConfig, DynamixelMode};
+/// use horus_library::nodes::ServoControllerNode;
+///
+/// let config = DynamixelConfig {
+///     port: "/dev/ttyUSB0".to_string(),
+///     servo_ids: vec![1, 2, 3],
+///     ..Default::default()
+/// };
+/// let mut arm = ServoControllerNode::new_dynamixel(config)?;
+/// arm.set_control_mode(2, DynamixelMode::Velocity)?; // Servo index 2 (ID 3)
+/// ```
 pub struct ServoControllerNode {
     servo_subscriber: Hub<ServoCommand>,
     joint_subscriber: Hub<JointCommand>,
     status_publisher: Hub<JointCommand>, // Publishes current servo states
 
+    // Driver
+    dynamixel: Option<DynamixelDriver>, // None = simulated servos
+    servo_ids: Vec<u8>,                 // Dynamixel ID of each servo index
+    pwm: Option<Box<dyn GpioHal>>,      // None = no PWM servos
+    pwm_servos: Vec<PwmServo>,          // PWM output of each servo index
+
     // Configuration
     servo_count: u8,
     position_limits: HashMap<u8, (f64, f64)>, // (min, max) for each servo
@@ -25,8 +59,13 @@ pub struct ServoControllerNode {
     // State
     current_positions: HashMap<u8, f64>,
     current_velocities: HashMap<u8, f64>,
+    current_efforts: HashMap<u8, f64>,
     target_positions: HashMap<u8, f64>,
+    target_velocities: HashMap<u8, f64>,
+    target_efforts: HashMap<u8, f64>,
+    control_modes: HashMap<u8, DynamixelMode>,
     last_update_time: u64,
+    read_errors: u64,
 
     // Control parameters
     position_tolerance: f64,
@@ -51,6 +90,11 @@ impl ServoControllerNode {
             joint_subscriber: Hub::new(joint_topic)?,
             status_publisher: Hub::new(status_topic)?,
 
+            dynamixel: None,
+            servo_ids: Vec::new(),
+            pwm: None,
+            pwm_servos: Vec::new(),
+
             servo_count: 6, // Default 6-DOF robot arm
             position_limits: HashMap::new(),
             velocity_limits: HashMap::new(),
@@ -58,8 +102,13 @@ impl ServoControllerNode {
 
             current_positions: HashMap::new(),
             current_velocities: HashMap::new(),
+            current_efforts: HashMap::new(),
             target_positions: HashMap::new(),
+            target_velocities: HashMap::new(),
+            target_efforts: HashMap::new(),
+            control_modes: HashMap::new(),
             last_update_time: 0,
+            read_errors: 0,
 
             position_tolerance: 0.01, // 1 degree tolerance
             default_velocity: 1.0,    // 1 rad/s default
@@ -67,6 +116,115 @@ impl ServoControllerNode {
         })
     }
 
+    /// Create a servo controller for Dynamixel servos with default topics
+    ///
+    /// Uses the serial backend when built with `serial-hardware`, the
+    /// simulation backend otherwise. Servo index `i` is `config.servo_ids[i]`.
+    pub fn new_dynamixel(config: DynamixelConfig) -> Result<Self> {
+        #[cfg(feature = "serial-hardware")]
+        let backend = DynamixelDriverBackend::Serial;
+        #[cfg(not(feature = "serial-hardware"))]
+        let backend = DynamixelDriverBackend::Simulation;
+
+        let servo_ids = config.servo_ids.clone();
+        let mut node = Self::new()?;
+        node.set_dynamixel_driver(DynamixelDriver::new(backend, config)?, servo_ids)?;
+        Ok(node)
+    }
+
+    /// Drive the servos with a Dynamixel driver
+    ///
+    /// `servo_ids[i]` is the Dynamixel ID of servo index `i`. Sets the servo
+    /// count; all servos start in position mode.
+    pub fn set_dynamixel_driver(
+        &mut self,
+        driver: DynamixelDriver,
+        servo_ids: Vec<u8>,
+    ) -> Result<()> {
+        if self.pwm.is_some() {
+            return Err(HorusError::config(
+                "Servo controller already drives PWM servos",
+            ));
+        }
+        if servo_ids.is_empty() || servo_ids.len() > MAX_SYNC_IDS {
+            return Err(HorusError::config(format!(
+                "Dynamixel servo count must be 1-{}, got {}",
+                MAX_SYNC_IDS,
+                servo_ids.len()
+            )));
+        }
+        self.set_servo_count(servo_ids.len() as u8);
+        self.servo_ids = servo_ids;
+        self.dynamixel = Some(driver);
+        Ok(())
+    }
+
+    /// Drive hobby servos with PWM outputs of a GPIO backend
+    ///
+    /// `servos[i]` is the output of servo index `i`. Sets the servo count and
+    /// limits each servo's position to its angle range.
+    pub fn set_pwm_servos(
+        &mut self,
+        gpio: impl GpioHal + 'static,
+        servos: Vec<PwmServo>,
+    ) -> Result<()> {
+        if self.dynamixel.is_some() {
+            return Err(HorusError::config(
+                "Servo controller already drives Dynamixel servos",
+            ));
+        }
+        if servos.is_empty() || servos.len() > u8::MAX as usize {
+            return Err(HorusError::config(format!(
+                "PWM servo count must be 1-{}, got {}",
+                u8::MAX,
+                servos.len()
+            )));
+        }
+
+        let mut gpio = Box::new(gpio);
+        for servo in &servos {
+            gpio.setup_pwm(servo.channel, servo.frequency_hz)?;
+        }
+
+        self.set_servo_count(servos.len() as u8);
+        for (i, servo) in servos.iter().enumerate() {
+            self.set_position_limits(i as u8, servo.min_angle, servo.max_angle);
+        }
+        self.pwm = Some(gpio);
+        self.pwm_servos = servos;
+        Ok(())
+    }
+
+    /// Set the control mode of a servo
+    ///
+    /// Position modes follow `positions`, velocity mode follows `velocities`
+    /// (rad/s) and current/PWM modes follow `efforts` (motor current in A or
+    /// duty fraction) of incoming joint commands. Once the driver is running
+    /// the servo's torque is briefly disabled to change the operating mode.
+    pub fn set_control_mode(&mut self, servo_id: u8, mode: DynamixelMode) -> Result<()> {
+        if servo_id >= self.servo_count {
+            return Err(HorusError::config(format!(
+                "Servo {} out of range (servo count {})",
+                servo_id, self.servo_count
+            )));
+        }
+        self.control_modes.insert(servo_id, mode);
+        self.target_velocities.insert(servo_id, 0.0);
+        self.target_efforts.insert(servo_id, 0.0);
+        if let Some(&current) = self.current_positions.get(&servo_id) {
+            self.target_positions.insert(servo_id, current);
+        }
+
+        let dxl_id = self.servo_ids.get(servo_id as usize).copied();
+        if let (Some(driver), Some(dxl_id)) = (self.dynamixel.as_mut(), dxl_id) {
+            if matches!(driver.status(), DriverStatus::Ready | DriverStatus::Running) {
+                driver.set_operating_mode(dxl_id, mode)?;
+                driver.set_torque_enabled(&[dxl_id], true)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Set number of servos to control
     pub fn set_servo_count(&mut self, count: u8) {
         self.servo_count = count;
@@ -110,6 +268,29 @@ impl ServoControllerNode {
         self.current_positions.get(&servo_id).copied()
     }
 
+    /// Get current velocity of a servo
+    pub fn get_velocity(&self, servo_id: u8) -> Option<f64> {
+        self.current_velocities.get(&servo_id).copied()
+    }
+
+    /// Get current effort of a servo (motor current in A with a Dynamixel driver)
+    pub fn get_effort(&self, servo_id: u8) -> Option<f64> {
+        self.current_efforts.get(&servo_id).copied()
+    }
+
+    /// Get the control mode of a servo
+    pub fn get_control_mode(&self, servo_id: u8) -> DynamixelMode {
+        self.control_modes
+            .get(&servo_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Number of ticks in which not all Dynamixel servos answered the sync read
+    pub fn get_read_errors(&self) -> u64 {
+        self.read_errors
+    }
+
     /// Get all current positions
     pub fn get_all_positions(&self) -> Vec<f64> {
         (0..self.servo_count)
@@ -150,16 +331,138 @@ impl ServoControllerNode {
         // Handle multi-joint command
         let joint_count = command.positions.len().min(self.servo_count as usize);
 
-        for (i, &position) in command.positions.iter().take(joint_count).enumerate() {
+        for i in 0..joint_count {
             let servo_id = i as u8;
-            let clamped_position = self.clamp_position(servo_id, position);
+            match self.get_control_mode(servo_id) {
+                DynamixelMode::Velocity => {
+                    let limit = self
+                        .velocity_limits
+                        .get(&servo_id)
+                        .copied()
+                        .unwrap_or(f64::INFINITY);
+                    self.target_velocities
+                        .insert(servo_id, command.velocities[i].clamp(-limit, limit));
+                }
+                DynamixelMode::Current | DynamixelMode::PWM => {
+                    let limit = self
+                        .torque_limits
+                        .get(&servo_id)
+                        .copied()
+                        .unwrap_or(f64::INFINITY);
+                    self.target_efforts
+                        .insert(servo_id, command.efforts[i].clamp(-limit, limit));
+                }
+                _ => {
+                    let clamped_position = self.clamp_position(servo_id, command.positions[i]);
 
-            if self.is_position_valid(servo_id, clamped_position) {
-                self.target_positions.insert(servo_id, clamped_position);
+                    if self.is_position_valid(servo_id, clamped_position) {
+                        self.target_positions.insert(servo_id, clamped_position);
+                    }
+                }
             }
         }
     }
 
+    /// Goals of all servos grouped by control mode, as (Dynamixel ID, goal)
+    fn dynamixel_goals(&self) -> Vec<(DynamixelMode, Vec<(u8, f64)>)> {
+        let mut groups: Vec<(DynamixelMode, Vec<(u8, f64)>)> = Vec::new();
+        for (i, &dxl_id) in self.servo_ids.iter().enumerate() {
+            let servo_id = i as u8;
+            let mode = self.get_control_mode(servo_id);
+            let targets = match mode {
+                DynamixelMode::Velocity => &self.target_velocities,
+                DynamixelMode::Current | DynamixelMode::PWM => &self.target_efforts,
+                _ => &self.target_positions,
+            };
+            let goal = targets.get(&servo_id).copied().unwrap_or(0.0);
+
+            match groups.iter_mut().find(|(m, _)| *m == mode) {
+                Some((_, goals)) => goals.push((dxl_id, goal)),
+                None => groups.push((mode, vec![(dxl_id, goal)])),
+            }
+        }
+        groups
+    }
+
+    /// Read position, velocity and current of all Dynamixel servos
+    ///
+    /// Returns the number of servos that answered.
+    fn read_dynamixel_states(&mut self) -> Result<usize> {
+        let Some(driver) = self.dynamixel.as_mut() else {
+            return Ok(0);
+        };
+        let states = driver.sync_read_state(&self.servo_ids)?;
+        for state in &states {
+            if let Some(i) = self.servo_ids.iter().position(|&id| id == state.id) {
+                let servo_id = i as u8;
+                self.current_positions.insert(servo_id, state.position);
+                self.current_velocities.insert(servo_id, state.velocity);
+                self.current_efforts.insert(servo_id, state.current);
+            }
+        }
+        Ok(states.len())
+    }
+
+    /// Write goals to and read states from the Dynamixel bus
+    fn update_dynamixel(&mut self, dt: f64, mut ctx: Option<&mut NodeInfo>) {
+        let goals = self.dynamixel_goals();
+        let Some(driver) = self.dynamixel.as_mut() else {
+            return;
+        };
+
+        match driver {
+            DynamixelDriver::Simulation(sim) => sim.simulate_tick(dt),
+            #[cfg(feature = "serial-hardware")]
+            DynamixelDriver::Serial(_) => {}
+        }
+
+        for (mode, goals) in goals {
+            if let Err(e) = driver.sync_write_goals(mode, &goals) {
+                if let Some(ctx) = ctx.as_deref_mut() {
+                    ctx.log_warning(&format!("Dynamixel sync write failed: {}", e));
+                }
+            }
+        }
+
+        match self.read_dynamixel_states() {
+            Ok(count) if count == self.servo_ids.len() => {}
+            Ok(count) => {
+                self.read_errors += 1;
+                if let Some(ctx) = ctx {
+                    ctx.log_warning(&format!(
+                        "Dynamixel sync read: {} of {} servos answered",
+                        count,
+                        self.servo_ids.len()
+                    ));
+                }
+            }
+            Err(e) => {
+                self.read_errors += 1;
+                if let Some(ctx) = ctx {
+                    ctx.log_warning(&format!("Dynamixel sync read failed: {}", e));
+                }
+            }
+        }
+    }
+
+    /// Send the current position of every PWM servo
+    fn write_pwm_servos(&mut self, ctx: Option<&mut NodeInfo>) {
+        let Some(gpio) = self.pwm.as_mut() else {
+            return;
+        };
+        let mut failed = None;
+        for (i, servo) in self.pwm_servos.iter().enumerate() {
+            let position = self.current_positions.get(&(i as u8)).copied();
+            let width = servo.pulse_width(position.unwrap_or(0.0));
+            if let Err(e) = gpio.set_pulse_width(servo.channel, width) {
+                failed = Some(e);
+            }
+        }
+        if let (Some(e), Some(ctx)) = (failed, ctx) {
+            ctx.log_warning(&format!("PWM servo write failed: {}", e));
+        }
+    }
+
     fn update_servo_positions(&mut self, dt: f64) {
         for servo_id in 0..self.servo_count {
             if let (Some(&current), Some(&target)) = (
@@ -205,13 +508,21 @@ impl ServoControllerNode {
         // Create joint state message with current positions
         let mut positions = [0.0f64; 16];
         let mut velocities = [0.0f64; 16];
-        let efforts = [0.0f64; 16];
+        let mut efforts = [0.0f64; 16];
         let mut joint_names = [[0u8; 32]; 16];
-        let modes = [0u8; 16]; // Position control mode
+        let mut modes = [JointCommand::MODE_POSITION; 16];
 
+        // JointCommand carries up to 16 joints; further servos are only
+        // available through the getters
         for i in 0..self.servo_count.min(16) {
             positions[i as usize] = self.current_positions.get(&i).copied().unwrap_or(0.0);
             velocities[i as usize] = self.current_velocities.get(&i).copied().unwrap_or(0.0);
+            efforts[i as usize] = self.current_efforts.get(&i).copied().unwrap_or(0.0);
+            modes[i as usize] = match self.get_control_mode(i) {
+                DynamixelMode::Velocity => JointCommand::MODE_VELOCITY,
+                DynamixelMode::Current | DynamixelMode::PWM => JointCommand::MODE_EFFORT,
+                _ => JointCommand::MODE_POSITION,
+            };
 
             // Set joint name
             let joint_name = format!("joint_{}", i);
@@ -222,7 +533,7 @@ impl ServoControllerNode {
 
         let joint_state = JointCommand {
             joint_names,
-            joint_count: self.servo_count,
+            joint_count: self.servo_count.min(16),
             positions,
             velocities,
             efforts,
@@ -246,6 +557,8 @@ impl ServoControllerNode {
             if let Some(&current) = self.current_positions.get(&servo_id) {
                 self.target_positions.insert(servo_id, current);
             }
+            self.target_velocities.insert(servo_id, 0.0);
+            self.target_efforts.insert(servo_id, 0.0);
         }
     }
 }
@@ -255,6 +568,46 @@ impl Node for ServoControllerNode {
         "ServoControllerNode"
     }
 
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        let Some(driver) = self.dynamixel.as_mut() else {
+            return Ok(());
+        };
+        driver.init()?;
+        for (i, &dxl_id) in self.servo_ids.iter().enumerate() {
+            let mode = self
+                .control_modes
+                .get(&(i as u8))
+                .copied()
+                .unwrap_or_default();
+            driver.set_operating_mode(dxl_id, mode)?;
+        }
+
+        // Hold the present positions instead of jumping to zero
+        let count = self.read_dynamixel_states()?;
+        if count < self.servo_ids.len() {
+            return Err(HorusError::driver(format!(
+                "Only {} of {} Dynamixel servos answered",
+                count,
+                self.servo_ids.len()
+            )));
+        }
+        for servo_id in 0..self.servo_count {
+            if let Some(&current) = self.current_positions.get(&servo_id) {
+                self.target_positions.insert(servo_id, current);
+            }
+        }
+
+        if let Some(driver) = self.dynamixel.as_mut() {
+            driver.set_torque_enabled(&self.servo_ids, true)?;
+        }
+        ctx.log_info(&format!(
+            "Dynamixel bus ready: {} servos (IDs {:?})",
+            self.servo_ids.len(),
+            self.servo_ids
+        ));
+        Ok(())
+    }
+
     fn shutdown(&mut self, ctx: &mut NodeInfo) -> Result<()> {
         ctx.log_info("ServoControllerNode shutting down - stopping all servos");
 
@@ -266,11 +619,21 @@ impl Node for ServoControllerNode {
             self.current_velocities.insert(servo_id, 0.0);
         }
 
+        if let Some(driver) = self.dynamixel.as_mut() {
+            let _ = driver.set_torque_enabled(&self.servo_ids, false);
+            driver.shutdown()?;
+        }
+        if let Some(gpio) = self.pwm.as_mut() {
+            for servo in &self.pwm_servos {
+                let _ = gpio.release_pwm(servo.channel);
+            }
+        }
+
         ctx.log_info("All servos stopped safely");
         Ok(())
     }
 
-    fn tick(&mut self, _ctx: Option<&mut NodeInfo>) {
+    fn tick(&mut self, ctx: Option<&mut NodeInfo>) {
         let current_time = SystemTime::now()
             .duration_since(UNIX_EPOCH)
             .unwrap()
@@ -295,7 +658,12 @@ impl Node for ServoControllerNode {
         }
 
         // Update servo positions based on targets
-        self.update_servo_positions(dt);
+        if self.dynamixel.is_some() {
+            self.update_dynamixel(dt, ctx);
+        } else {
+            self.update_servo_positions(dt);
+            self.write_pwm_servos(ctx);
+        }
 
         // Publish current joint states
         self.publish_joint_states();
@@ -303,3 +671,86 @@ impl Node for ServoControllerNode {
 }
 
 // Default impl removed - use ServoControllerNode::new() instead which returns HorusResult
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dynamixel_node(ids: Vec<u8>) -> ServoControllerNode {
+        let config = DynamixelConfig {
+            servo_ids: ids.clone(),
+            ..Default::default()
+        };
+        let driver = DynamixelDriver::new(DynamixelDriverBackend::Simulation, config).unwrap();
+        let mut node = ServoControllerNode::new_with_topics(
+            "test_dxl.servo",
+            "test_dxl.joint",
+            "test_dxl.states",
+        )
+        .unwrap();
+        node.set_dynamixel_driver(driver, ids).unwrap();
+        node
+    }
+
+    #[test]
+    fn test_dynamixel_servo_limit() {
+        let config = DynamixelConfig::default();
+        let driver = DynamixelDriver::new(DynamixelDriverBackend::Simulation, config).unwrap();
+        let mut node = ServoControllerNode::new().unwrap();
+        let ids: Vec<u8> = (1..=33).collect();
+        assert!(node.set_dynamixel_driver(driver, ids).is_err());
+    }
+
+    #[test]
+    fn test_dynamixel_modes() {
+        let mut node = dynamixel_node(vec![1, 2, 3]);
+        node.init(&mut NodeInfo::new("servo_controller".to_string(), false))
+            .unwrap();
+        node.set_control_mode(1, DynamixelMode::Velocity).unwrap();
+        node.set_control_mode(2, DynamixelMode::Current).unwrap();
+        assert!(node.set_control_mode(3, DynamixelMode::Current).is_err());
+
+        let mut command = JointCommand::new();
+        command.positions[0] = 0.2;
+        command.velocities[1] = 1.0;
+        command.efforts[2] = 0.5;
+        command.joint_count = 3;
+        node.handle_joint_command(command);
+
+        for _ in 0..100 {
+            node.update_dynamixel(0.01, None);
+        }
+        assert_eq!(node.get_read_errors(), 0);
+        assert!((node.get_position(0).unwrap() - 0.2).abs() < 0.01);
+        assert!((node.get_velocity(1).unwrap() - 1.0).abs() < 0.05);
+        assert!((node.get_effort(2).unwrap() - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_pwm_servos() {
+        use crate::drivers::gpio::SimulationGpio;
+
+        let gpio = SimulationGpio::new();
+        let mut node = ServoControllerNode::new_with_topics(
+            "test_pwm.servo",
+            "test_pwm.joint",
+            "test_pwm.states",
+        )
+        .unwrap();
+        node.set_pwm_servos(gpio.clone(), vec![PwmServo::new(18), PwmServo::new(19)])
+            .unwrap();
+        node.set_interpolation(false);
+
+        let mut command = JointCommand::new();
+        command.positions[0] = std::f64::consts::FRAC_PI_2;
+        command.positions[1] = 3.0; // Beyond the servo range
+        command.joint_count = 2;
+        node.handle_joint_command(command);
+        node.update_servo_positions(0.01);
+        node.write_pwm_servos(None);
+
+        let micros = |channel| gpio.pulse_width(channel).unwrap().as_secs_f64() * 1e6;
+        assert!((micros(18) - 2500.0).abs() < 1.0);
+        assert!((micros(19) - 2500.0).abs() < 1.0);
+    }
+}
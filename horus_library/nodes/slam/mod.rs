@@ -0,0 +1,309 @@
+use crate::{LaserScan, OccupancyGrid, OccupancyGridUpdate, Odometry, Pose2D};
+use horus_core::error::HorusResult;
+
+// Import algorithms from horus_library/algorithms
+use crate::algorithms::slam::{GridSlam, SlamConfig};
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo};
+use std::time::{Duration, Instant};
+
+/// SLAM Node - 2D scan-matching SLAM from laser scans and odometry
+///
+/// Builds an occupancy grid map while correcting the odometry drift of the
+/// robot pose, so demos can map and navigate without an external SLAM stack.
+/// Each `LaserScan` is matched against the map built so far (correlative
+/// matching around the odometry prediction) and then added to the map.
+///
+/// The full `OccupancyGrid` is published periodically, for late subscribers,
+/// and the changed region after every map update as `OccupancyGridUpdate`.
+/// The corrected pose is published as `Odometry` in the map frame.
+///
+/// Odometry is optional; without it, the motion between scans is found by
+/// matching alone and must stay within the search window.
+///
+/// This node is a thin wrapper around the pure algorithm in horus_library/algorithms.
+pub struct SlamNode {
+    // Publishers and Subscribers
+    scan_subscriber: Hub<LaserScan>,
+    odom_subscriber: Hub<Odometry>,
+    map_publisher: Hub<OccupancyGrid>,
+    map_update_publisher: Hub<OccupancyGridUpdate>,
+    pose_publisher: Hub<Odometry>,
+
+    // Algorithm instance
+    slam: GridSlam,
+
+    // Configuration
+    map_frame: String,
+    base_frame: String,
+    laser_offset: (f64, f64, f64),
+    map_publish_interval: Duration,
+
+    // State
+    last_odom: Option<Odometry>,
+    published_map: Option<OccupancyGrid>,
+    last_full_publish: Option<Instant>,
+}
+
+impl SlamNode {
+    /// Create a new SLAM node with default topics
+    pub fn new() -> Result<Self> {
+        Self::new_with_topics("lidar_scan", "odom", "map", "slam.pose")
+    }
+
+    /// Create a new SLAM node with custom topics
+    ///
+    /// Map updates are published on `"<map_topic>.updates"`.
+    pub fn new_with_topics(
+        scan_topic: &str,
+        odom_topic: &str,
+        map_topic: &str,
+        pose_topic: &str,
+    ) -> Result<Self> {
+        Ok(Self {
+            scan_subscriber: Hub::new(scan_topic)?,
+            odom_subscriber: Hub::new(odom_topic)?,
+            map_publisher: Hub::new(map_topic)?,
+            map_update_publisher: Hub::new(format!("{}.updates", map_topic))?,
+            pose_publisher: Hub::new(pose_topic)?,
+
+            slam: GridSlam::new(SlamConfig::default()),
+
+            map_frame: "map".to_string(),
+            base_frame: "base_link".to_string(),
+            laser_offset: (0.0, 0.0, 0.0),
+            map_publish_interval: Duration::from_secs(2),
+
+            last_odom: None,
+            published_map: None,
+            last_full_publish: None,
+        })
+    }
+
+    /// Set map size, resolution and matching parameters (clears the map)
+    pub fn set_slam_config(&mut self, config: SlamConfig) {
+        self.slam = GridSlam::new(config);
+        self.published_map = None;
+    }
+
+    /// Set frame IDs for the map and the robot
+    pub fn set_frame_ids(&mut self, map_frame: &str, base_frame: &str) {
+        self.map_frame = map_frame.to_string();
+        self.base_frame = base_frame.to_string();
+    }
+
+    /// Set the laser pose on the robot (x, y, theta in the robot frame)
+    pub fn set_laser_offset(&mut self, x: f64, y: f64, theta: f64) {
+        self.laser_offset = (x, y, theta);
+    }
+
+    /// Set how often the full map is published (updates are sent in between)
+    pub fn set_map_publish_interval(&mut self, interval: Duration) {
+        self.map_publish_interval = interval;
+    }
+
+    /// Set the robot's start pose in the map frame (before the first scan)
+    pub fn set_initial_pose(&mut self, x: f64, y: f64, theta: f64) {
+        self.slam.set_pose(x, y, theta);
+    }
+
+    /// Get the corrected pose (x, y, theta) in the map frame
+    pub fn get_pose(&self) -> (f64, f64, f64) {
+        self.slam.pose()
+    }
+
+    /// Get the current map
+    pub fn get_map(&self) -> OccupancyGrid {
+        let config = self.slam.config();
+        let mut grid = OccupancyGrid::new(
+            config.width as u32,
+            config.height as u32,
+            config.resolution as f32,
+            Pose2D::new(config.origin.0, config.origin.1, 0.0),
+        );
+        grid.data = self.slam.occupancy_data();
+
+        let frame = self.map_frame.as_bytes();
+        let len = frame.len().min(31);
+        grid.frame_id[..len].copy_from_slice(&frame[..len]);
+        grid
+    }
+
+    /// Number of scans added to the map
+    pub fn scans_integrated(&self) -> usize {
+        self.slam.scans_integrated()
+    }
+
+    /// Score of the last scan match (fraction of points on mapped obstacles)
+    pub fn match_score(&self) -> f64 {
+        self.slam.match_score()
+    }
+
+    /// Clear the map and restart at the origin
+    pub fn reset(&mut self) {
+        self.slam.reset();
+        self.last_odom = None;
+        self.published_map = None;
+    }
+
+    /// Scan endpoints in the robot frame
+    fn scan_points(&self, scan: &LaserScan) -> Vec<(f64, f64)> {
+        let (ox, oy, otheta) = self.laser_offset;
+        (0..scan.ranges.len())
+            .filter(|&i| scan.is_range_valid(i))
+            .map(|i| {
+                let range = scan.ranges[i] as f64;
+                let angle = otheta + scan.angle_at(i) as f64;
+                (ox + range * angle.cos(), oy + range * angle.sin())
+            })
+            .collect()
+    }
+
+    /// Match and map a scan, returns true if the map changed
+    fn process_scan(&mut self, scan: &LaserScan) -> bool {
+        let points = self.scan_points(scan);
+        let odom = self
+            .last_odom
+            .as_ref()
+            .map(|o| (o.pose.x, o.pose.y, o.pose.theta));
+
+        let integrated = self.slam.scans_integrated();
+        self.slam.process(odom, &points);
+        self.slam.scans_integrated() != integrated
+    }
+
+    fn publish_pose(&mut self, timestamp: u64) {
+        let (x, y, theta) = self.slam.pose();
+        let mut pose = Odometry::new();
+        pose.set_frames(&self.map_frame, &self.base_frame);
+        pose.pose = Pose2D::new(x, y, theta);
+        if let Some(odom) = &self.last_odom {
+            pose.twist = odom.twist;
+        }
+        pose.timestamp = timestamp;
+        let _ = self.pose_publisher.send(pose, &mut None);
+    }
+
+    fn publish_map(&mut self, now: Instant) {
+        let grid = self.get_map();
+        let full_due = match (&self.published_map, self.last_full_publish) {
+            (Some(_), Some(last)) => now.duration_since(last) >= self.map_publish_interval,
+            _ => true,
+        };
+
+        if full_due {
+            let _ = self.map_publisher.send(grid.clone(), &mut None);
+            self.last_full_publish = Some(now);
+        } else if let Some(update) = self
+            .published_map
+            .as_ref()
+            .and_then(|previous| grid.diff(previous))
+        {
+            let _ = self.map_update_publisher.send(update, &mut None);
+        }
+        self.published_map = Some(grid);
+    }
+}
+
+impl Node for SlamNode {
+    fn name(&self) -> &'static str {
+        "SlamNode"
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        let config = self.slam.config();
+        ctx.log_info(&format!(
+            "SlamNode: {}x{} map at {:.2} m/cell in frame '{}'",
+            config.width, config.height, config.resolution, self.map_frame
+        ));
+        Ok(())
+    }
+
+    fn tick(&mut self, _ctx: Option<&mut NodeInfo>) {
+        if let Some(odom) = self.odom_subscriber.recv(&mut None) {
+            self.last_odom = Some(odom);
+        }
+
+        if let Some(scan) = self.scan_subscriber.recv(&mut None) {
+            let map_changed = self.process_scan(&scan);
+            self.publish_pose(scan.timestamp);
+
+            let now = Instant::now();
+            let full_due = self
+                .last_full_publish
+                .is_some_and(|last| now.duration_since(last) >= self.map_publish_interval);
+            if map_changed || full_due {
+                self.publish_map(now);
+            }
+        }
+    }
+}
+
+// Default impl removed - use SlamNode::new() instead which returns HorusResult
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scan from the origin of a circular room of radius 2.02m
+    fn circle_scan() -> LaserScan {
+        let mut scan = LaserScan::new();
+        scan.ranges = [2.02; 360];
+        scan.ranges[90] = 0.0; // Invalid reading
+        scan
+    }
+
+    #[test]
+    fn test_scan_points_with_offset() {
+        let mut node = SlamNode::new_with_topics(
+            "test_slam_points.scan",
+            "test_slam_points.odom",
+            "test_slam_points.map",
+            "test_slam_points.pose",
+        )
+        .unwrap();
+        node.set_laser_offset(0.2, 0.0, std::f64::consts::PI);
+
+        let scan = circle_scan();
+        let points = node.scan_points(&scan);
+        assert_eq!(points.len(), 359);
+
+        // First beam (angle_min = -π) points forward once the laser is turned around
+        assert!((points[0].0 - 2.22).abs() < 1e-5);
+        assert!(points[0].1.abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_maps_scan_and_publishes_pose() {
+        let mut node = SlamNode::new_with_topics(
+            "test_slam_map.scan",
+            "test_slam_map.odom",
+            "test_slam_map.map",
+            "test_slam_map.pose",
+        )
+        .unwrap();
+        node.set_frame_ids("world", "robot");
+        assert!(node.process_scan(&circle_scan()));
+        assert_eq!(node.scans_integrated(), 1);
+
+        let map = node.get_map();
+        assert_eq!((map.width, map.height), (400, 400));
+        assert_eq!(&map.frame_id[..6], b"world\0");
+        assert!(map.is_occupied(2.02, 0.01));
+        assert!(map.is_free(1.0, 0.0));
+
+        // Same scan from the same place: nothing new to map
+        assert!(!node.process_scan(&circle_scan()));
+        let (x, y, _) = node.get_pose();
+        assert!(x.abs() < 1e-9 && y.abs() < 1e-9);
+
+        node.publish_pose(0);
+        node.publish_map(Instant::now());
+        assert!(node.published_map.is_some());
+
+        node.reset();
+        assert_eq!(node.scans_integrated(), 0);
+    }
+}
@@ -0,0 +1,597 @@
+use horus_core::core::LogSummary;
+use horus_core::error::HorusResult;
+use horus_core::scheduling::CloudUploader;
+use serde_json::{Map, Value};
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Slowest supported snapshot rate (one document every 10 s)
+pub const MIN_SNAPSHOT_RATE_HZ: f64 = 0.1;
+/// Fastest supported snapshot rate (one document per second)
+pub const MAX_SNAPSHOT_RATE_HZ: f64 = 1.0;
+
+/// Upper bound on messages drained from a topic per snapshot
+const MAX_DRAIN_PER_SAMPLE: usize = 4096;
+
+/// Documents waiting for the upload thread before new ones are dropped
+const UPLOAD_QUEUE_DEPTH: usize = 4;
+
+/// Destination for snapshot documents (MQTT client, HTTP uploader, ...)
+///
+/// Implemented for closures, so an existing client can be plugged in with
+/// `node.set_uploader(move |doc: &str| client.publish("robot/state", doc))`.
+pub trait SnapshotUploader: Send {
+    /// Upload one serialized state document
+    fn upload(&mut self, document: &str) -> std::result::Result<(), String>;
+}
+
+impl<F> SnapshotUploader for F
+where
+    F: FnMut(&str) -> std::result::Result<(), String> + Send,
+{
+    fn upload(&mut self, document: &str) -> std::result::Result<(), String> {
+        self(document)
+    }
+}
+
+/// Hands snapshot documents to the cloud recording uploader
+///
+/// Each document is stored as `<key_prefix>/<timestamp_ms>.json` under the
+/// uploader's configured prefix, so any `CloudConfig` backend (local, S3,
+/// GCS, Azure) can collect fleet state next to recordings.
+///
+/// ```rust,ignore
+/// let cloud = CloudUploader::new(CloudConfig::s3("fleet-data", "eu-west-1", "robots"))?;
+/// snapshot.set_uploader(CloudSnapshotUploader::new(cloud, "rover-7/state"));
+/// ```
+pub struct CloudSnapshotUploader {
+    uploader: CloudUploader,
+    key_prefix: String,
+}
+
+impl CloudSnapshotUploader {
+    pub fn new(uploader: CloudUploader, key_prefix: &str) -> Self {
+        Self {
+            uploader,
+            key_prefix: key_prefix.trim_end_matches('/').to_string(),
+        }
+    }
+
+    /// Object key of a document, named after its timestamp
+    fn key_for(&self, document: &str) -> String {
+        let timestamp_ms = serde_json::from_str::<Value>(document)
+            .ok()
+            .and_then(|doc| doc["timestamp_ms"].as_u64())
+            .unwrap_or_else(|| {
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_millis() as u64
+            });
+        format!("{}/{}.json", self.key_prefix, timestamp_ms)
+    }
+}
+
+impl SnapshotUploader for CloudSnapshotUploader {
+    fn upload(&mut self, document: &str) -> std::result::Result<(), String> {
+        let key = self.key_for(document);
+        self.uploader
+            .upload_bytes(document.as_bytes(), &key)
+            .map(|_| ())
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Runs the uploader on its own thread
+///
+/// Uploads are network round-trips, so `tick()` only queues documents and
+/// collects the outcome of earlier uploads. Dropping the worker closes the
+/// queue; the thread finishes the documents already queued and exits.
+struct UploadWorker {
+    documents: SyncSender<String>,
+    results: Receiver<std::result::Result<(), String>>,
+}
+
+impl UploadWorker {
+    fn spawn(mut uploader: Box<dyn SnapshotUploader>) -> Self {
+        let (documents, queue) = mpsc::sync_channel::<String>(UPLOAD_QUEUE_DEPTH);
+        let (report, results) = mpsc::channel();
+        std::thread::Builder::new()
+            .name("snapshot-upload".to_string())
+            .spawn(move || {
+                for document in queue {
+                    if report.send(uploader.upload(&document)).is_err() {
+                        break;
+                    }
+                }
+            })
+            .expect("failed to spawn snapshot upload thread");
+        Self { documents, results }
+    }
+}
+
+type Poller = Box<dyn FnMut() -> Option<Value> + Send>;
+
+struct TopicSampler {
+    topic: String,
+    fields: Vec<String>,
+    poll: Poller,
+    latest: Option<Value>,
+    updated_at: Option<Instant>,
+}
+
+impl TopicSampler {
+    /// Drain pending messages, keeping only the newest one
+    fn sample(&mut self, now: Instant) {
+        if let Some(value) = (self.poll)() {
+            self.latest = Some(select_fields(value, &self.fields));
+            self.updated_at = Some(now);
+        }
+    }
+}
+
+/// Snapshot Node - Low-rate state documents for fleet dashboards
+///
+/// Samples a configured set of topics at 0.1-1 Hz, keeps the newest message
+/// from each, and bundles them into a single compact JSON document. The
+/// document is published on the output topic (for a bridge to forward) and
+/// handed to an optional `SnapshotUploader`: a `CloudSnapshotUploader` or
+/// any closure wrapping a client (MQTT, HTTP).
+///
+/// Dashboards get periodic robot state without streaming every topic at its
+/// full rate. Individual fields can be selected per topic to keep documents
+/// small (e.g. only `x`, `y` and `theta` from an odometry message). Topics
+/// that stop publishing are dropped from `topics` and listed under `stale`.
+pub struct SnapshotNode {
+    // Publishers
+    document_publisher: Hub<String>,
+
+    // Sampled topics
+    samplers: Vec<TopicSampler>,
+    uploader: Option<UploadWorker>,
+
+    // Configuration
+    robot_id: String,
+    period: Duration,
+    stale_after_periods: u32,
+
+    // State
+    last_snapshot: Option<Instant>,
+    sequence: u64,
+    upload_failures: u64,
+    last_document: Option<String>,
+}
+
+impl SnapshotNode {
+    /// Create a new snapshot node publishing on "snapshot.state"
+    pub fn new() -> Result<Self> {
+        Self::new_with_topic("snapshot.state")
+    }
+
+    /// Create a new snapshot node with a custom output topic
+    pub fn new_with_topic(output_topic: &str) -> Result<Self> {
+        Ok(Self {
+            document_publisher: Hub::new(output_topic)?,
+
+            samplers: Vec::new(),
+            uploader: None,
+
+            robot_id: "robot".to_string(),
+            period: Duration::from_secs(5),
+            stale_after_periods: 3,
+
+            last_snapshot: None,
+            sequence: 0,
+            upload_failures: 0,
+            last_document: None,
+        })
+    }
+
+    /// Add a topic to the snapshot, including the full message
+    pub fn add_topic<T>(&mut self, topic: &str) -> Result<()>
+    where
+        T: Send
+            + Sync
+            + 'static
+            + Clone
+            + std::fmt::Debug
+            + serde::Serialize
+            + serde::de::DeserializeOwned
+            + LogSummary,
+    {
+        self.add_topic_fields::<T>(topic, &[])
+    }
+
+    /// Add a topic to the snapshot, keeping only the named top-level fields
+    pub fn add_topic_fields<T>(&mut self, topic: &str, fields: &[&str]) -> Result<()>
+    where
+        T: Send
+            + Sync
+            + 'static
+            + Clone
+            + std::fmt::Debug
+            + serde::Serialize
+            + serde::de::DeserializeOwned
+            + LogSummary,
+    {
+        let hub: Hub<T> = Hub::new(topic)?;
+        let poll: Poller = Box::new(move || {
+            let mut newest = None;
+            for _ in 0..MAX_DRAIN_PER_SAMPLE {
+                match hub.recv(&mut None) {
+                    Some(msg) => newest = Some(msg),
+                    None => break,
+                }
+            }
+            newest.and_then(|msg| serde_json::to_value(msg).ok())
+        });
+
+        self.samplers.retain(|s| s.topic != topic);
+        self.samplers.push(TopicSampler {
+            topic: topic.to_string(),
+            fields: fields.iter().map(|f| f.to_string()).collect(),
+            poll,
+            latest: None,
+            updated_at: None,
+        });
+        Ok(())
+    }
+
+    /// Stop including a topic in snapshots
+    pub fn remove_topic(&mut self, topic: &str) {
+        self.samplers.retain(|s| s.topic != topic);
+    }
+
+    /// Set the snapshot rate in Hz (clamped to 0.1-1 Hz)
+    pub fn set_rate(&mut self, rate_hz: f64) {
+        let rate = if rate_hz.is_finite() {
+            rate_hz.clamp(MIN_SNAPSHOT_RATE_HZ, MAX_SNAPSHOT_RATE_HZ)
+        } else {
+            MIN_SNAPSHOT_RATE_HZ
+        };
+        self.period = Duration::from_secs_f64(1.0 / rate);
+    }
+
+    /// Set the robot identifier included in every document
+    pub fn set_robot_id(&mut self, robot_id: &str) {
+        self.robot_id = robot_id.to_string();
+    }
+
+    /// Mark a topic stale after this many snapshot periods without new data
+    pub fn set_stale_after_periods(&mut self, periods: u32) {
+        self.stale_after_periods = periods.max(1);
+    }
+
+    /// Set the uploader that receives each document (e.g. an MQTT client)
+    ///
+    /// The uploader runs on a dedicated thread, so a slow upload never
+    /// delays the scheduler.
+    pub fn set_uploader<U: SnapshotUploader + 'static>(&mut self, uploader: U) {
+        self.uploader = Some(UploadWorker::spawn(Box::new(uploader)));
+    }
+
+    /// Number of documents produced so far
+    pub fn get_sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Number of uploads that returned an error or were dropped
+    ///
+    /// Failures are reported by the upload thread and counted on the next tick.
+    pub fn get_upload_failures(&self) -> u64 {
+        self.upload_failures
+    }
+
+    /// Most recently produced document
+    pub fn get_last_document(&self) -> Option<&str> {
+        self.last_document.as_deref()
+    }
+
+    /// Sample all topics and build a document immediately
+    pub fn snapshot_now(&mut self) -> String {
+        let now = Instant::now();
+        for sampler in &mut self.samplers {
+            sampler.sample(now);
+        }
+
+        let stale_after = self.period * self.stale_after_periods;
+        let mut topics = Map::new();
+        let mut stale = Vec::new();
+        for sampler in &self.samplers {
+            let Some(value) = &sampler.latest else {
+                continue;
+            };
+            let age = sampler
+                .updated_at
+                .map(|t| now.duration_since(t))
+                .unwrap_or_default();
+            // Old values would look current on a dashboard
+            if age > stale_after {
+                stale.push(Value::String(sampler.topic.clone()));
+            } else {
+                topics.insert(sampler.topic.clone(), value.clone());
+            }
+        }
+
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+
+        let document = build_document(&self.robot_id, self.sequence, timestamp, topics, stale);
+        self.sequence += 1;
+        self.last_snapshot = Some(now);
+        self.last_document = Some(document.clone());
+        document
+    }
+
+    /// Count and log the uploads that failed since the last call
+    fn collect_upload_results(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let Some(worker) = &self.uploader else {
+            return;
+        };
+        for result in worker.results.try_iter() {
+            if let Err(e) = result {
+                self.upload_failures += 1;
+                if let Some(ctx) = ctx.as_deref_mut() {
+                    ctx.log_warning(&format!("Snapshot upload failed: {}", e));
+                }
+            }
+        }
+    }
+
+    /// Hand a document to the upload thread without waiting for it
+    fn queue_upload(&mut self, document: &str, ctx: Option<&mut NodeInfo>) {
+        let Some(worker) = &self.uploader else {
+            return;
+        };
+        let error = match worker.documents.try_send(document.to_string()) {
+            Ok(()) => return,
+            // The next snapshot carries fresher state anyway
+            Err(TrySendError::Full(_)) => "upload queue full, dropping snapshot",
+            Err(TrySendError::Disconnected(_)) => "upload thread stopped",
+        };
+        self.upload_failures += 1;
+        if let Some(ctx) = ctx {
+            ctx.log_warning(&format!("Snapshot upload failed: {}", error));
+        }
+    }
+}
+
+/// Keep only the selected top-level fields of an object (all fields if none selected)
+fn select_fields(value: Value, fields: &[String]) -> Value {
+    if fields.is_empty() {
+        return value;
+    }
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .filter(|(key, _)| fields.iter().any(|f| f == key))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+/// Serialize a compact state document
+fn build_document(
+    robot_id: &str,
+    sequence: u64,
+    timestamp_ms: u64,
+    topics: Map<String, Value>,
+    stale: Vec<Value>,
+) -> String {
+    let mut document = Map::new();
+    document.insert("robot_id".to_string(), Value::from(robot_id));
+    document.insert("seq".to_string(), Value::from(sequence));
+    document.insert("timestamp_ms".to_string(), Value::from(timestamp_ms));
+    document.insert("topics".to_string(), Value::Object(topics));
+    if !stale.is_empty() {
+        document.insert("stale".to_string(), Value::Array(stale));
+    }
+    Value::Object(document).to_string()
+}
+
+impl Node for SnapshotNode {
+    fn name(&self) -> &'static str {
+        "SnapshotNode"
+    }
+
+    fn shutdown(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        self.collect_upload_results(Some(&mut *ctx));
+        ctx.log_info(&format!(
+            "SnapshotNode shutting down after {} snapshots ({} upload failures)",
+            self.sequence, self.upload_failures
+        ));
+        Ok(())
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        self.collect_upload_results(ctx.as_deref_mut());
+
+        let due = self
+            .last_snapshot
+            .map(|t| t.elapsed() >= self.period)
+            .unwrap_or(true);
+        if !due || self.samplers.is_empty() {
+            return;
+        }
+
+        let document = self.snapshot_now();
+
+        self.queue_upload(&document, ctx);
+
+        let _ = self.document_publisher.send(document, &mut None);
+    }
+}
+
+// Default impl removed - use SnapshotNode::new() instead which returns HorusResult
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_fields() {
+        let value = serde_json::json!({"x": 1.0, "y": 2.0, "theta": 0.5, "covariance": [0.0, 0.0]});
+        let selected = select_fields(value.clone(), &["x".to_string(), "theta".to_string()]);
+        assert_eq!(selected, serde_json::json!({"x": 1.0, "theta": 0.5}));
+
+        // No selection keeps the whole message
+        assert_eq!(select_fields(value.clone(), &[]), value);
+    }
+
+    #[test]
+    fn test_build_document() {
+        let mut topics = Map::new();
+        topics.insert("battery".to_string(), serde_json::json!({"voltage": 24.1}));
+
+        let doc = build_document("rover-7", 3, 1_000, topics.clone(), Vec::new());
+        let parsed: Value = serde_json::from_str(&doc).unwrap();
+        assert_eq!(parsed["robot_id"], "rover-7");
+        assert_eq!(parsed["seq"], 3);
+        assert_eq!(parsed["topics"]["battery"]["voltage"], 24.1);
+        assert!(parsed.get("stale").is_none());
+
+        let doc = build_document("rover-7", 4, 2_000, topics, vec![Value::from("battery")]);
+        let parsed: Value = serde_json::from_str(&doc).unwrap();
+        assert_eq!(parsed["stale"][0], "battery");
+    }
+
+    fn sampler(topic: &str, values: Vec<Value>) -> TopicSampler {
+        let mut values = values.into_iter();
+        TopicSampler {
+            topic: topic.to_string(),
+            fields: Vec::new(),
+            poll: Box::new(move || values.next()),
+            latest: None,
+            updated_at: None,
+        }
+    }
+
+    fn documents(hub: &Hub<String>) -> Vec<Value> {
+        std::iter::from_fn(|| hub.recv(&mut None))
+            .map(|doc| serde_json::from_str(&doc).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_tick_publishes_at_configured_rate() {
+        let topic = format!("test_snapshot_rate_{}", std::process::id());
+        let mut node = SnapshotNode::new_with_topic(&topic).unwrap();
+        let output: Hub<String> = Hub::new(&topic).unwrap();
+        node.set_rate(1.0);
+        node.samplers.push(sampler(
+            "battery",
+            vec![serde_json::json!({"voltage": 24.1})],
+        ));
+
+        // First tick snapshots immediately, the next ones wait for the period
+        for _ in 0..5 {
+            node.tick(None);
+        }
+        let docs = documents(&output);
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0]["seq"], 0);
+        assert_eq!(docs[0]["topics"]["battery"]["voltage"], 24.1);
+
+        node.last_snapshot = Some(Instant::now() - Duration::from_millis(1001));
+        node.tick(None);
+        node.tick(None);
+        let docs = documents(&output);
+        assert_eq!(docs.len(), 1);
+        assert_eq!(docs[0]["seq"], 1);
+        assert_eq!(node.get_sequence(), 2);
+    }
+
+    #[test]
+    fn test_tick_drops_stale_topics() {
+        let topic = format!("test_snapshot_stale_{}", std::process::id());
+        let mut node = SnapshotNode::new_with_topic(&topic).unwrap();
+        let output: Hub<String> = Hub::new(&topic).unwrap();
+        node.set_rate(1.0);
+        node.set_stale_after_periods(2);
+        node.samplers
+            .push(sampler("odom", vec![serde_json::json!({"x": 1.0})]));
+        node.samplers.push(sampler(
+            "battery",
+            vec![serde_json::json!({"voltage": 24.1})],
+        ));
+        node.tick(None);
+
+        // Odometry went quiet three periods ago, battery is still fresh
+        node.samplers[0].updated_at = Some(Instant::now() - Duration::from_secs(3));
+        node.samplers[1].poll = Box::new({
+            let mut values = vec![serde_json::json!({"voltage": 24.0})].into_iter();
+            move || values.next()
+        });
+        node.last_snapshot = None;
+        node.tick(None);
+
+        let docs = documents(&output);
+        let latest = docs.last().unwrap();
+        assert!(latest["topics"].get("odom").is_none());
+        assert_eq!(latest["stale"], serde_json::json!(["odom"]));
+        assert_eq!(latest["topics"]["battery"]["voltage"], 24.0);
+    }
+
+    #[test]
+    fn test_uploads_run_off_the_tick_thread() {
+        let topic = format!("test_snapshot_upload_{}", std::process::id());
+        let mut node = SnapshotNode::new_with_topic(&topic).unwrap();
+        node.samplers.push(sampler(
+            "battery",
+            vec![serde_json::json!({"voltage": 24.1})],
+        ));
+
+        // The uploader blocks until released, like a stalled network request
+        let (started, uploading) = mpsc::channel::<()>();
+        let (release, gate) = mpsc::channel::<()>();
+        node.set_uploader(move |_: &str| {
+            let _ = started.send(());
+            let _ = gate.recv();
+            Err("unreachable".to_string())
+        });
+
+        node.tick(None);
+        uploading.recv_timeout(Duration::from_secs(5)).unwrap();
+        let start = Instant::now();
+        for _ in 0..UPLOAD_QUEUE_DEPTH + 2 {
+            node.last_snapshot = None;
+            node.tick(None);
+        }
+        assert!(start.elapsed() < Duration::from_secs(1));
+        // One document is in flight, the queue is full, the last two are dropped
+        assert_eq!(node.get_upload_failures(), 2);
+
+        drop(release);
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while node.get_upload_failures() < UPLOAD_QUEUE_DEPTH as u64 + 3
+            && Instant::now() < deadline
+        {
+            std::thread::sleep(Duration::from_millis(10));
+            node.collect_upload_results(None);
+        }
+        assert_eq!(node.get_upload_failures(), UPLOAD_QUEUE_DEPTH as u64 + 3);
+    }
+
+    #[test]
+    fn test_cloud_uploader_stores_documents() {
+        use horus_core::scheduling::CloudConfig;
+
+        let dir = std::env::temp_dir().join(format!("horus_snapshot_cloud_{}", std::process::id()));
+        let cloud = CloudUploader::new(CloudConfig::local(&dir, "fleet")).unwrap();
+        let mut uploader = CloudSnapshotUploader::new(cloud, "rover-7/");
+
+        let document = build_document("rover-7", 0, 1_234, Map::new(), Vec::new());
+        uploader.upload(&document).unwrap();
+        let stored = std::fs::read_to_string(dir.join("fleet/rover-7/1234.json")).unwrap();
+        assert_eq!(stored, document);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
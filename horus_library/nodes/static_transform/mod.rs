@@ -0,0 +1,128 @@
+use crate::hframe::{timestamp_now, HFrame, RobotDescription, TFMessage};
+use horus_core::error::{HorusError, HorusResult};
+
+// Type alias for cleaner signatures
+type Result<T> = HorusResult<T>;
+use horus_core::{Hub, Node, NodeInfo};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Static Transform Node - Broadcasts fixed frames from a robot description
+///
+/// Reads a URDF or YAML robot description once and publishes every fixed
+/// transform (sensor mounts, chassis offsets) on `tf_static`, so the offsets
+/// live in one file instead of being hardcoded in each node that needs them.
+///
+/// If an HFrame is attached with `set_hframe()`, the description is also
+/// loaded into it at startup. Movable joints are registered there as dynamic
+/// frames for the nodes that drive them to update.
+///
+/// The transforms are re-published periodically so that subscribers started
+/// later still receive them.
+pub struct StaticTransformNode {
+    publisher: Hub<TFMessage>,
+
+    // Robot description and optional shared frame tree
+    description: RobotDescription,
+    hframe: Option<HFrame>,
+
+    // Configuration
+    republish_interval: Duration,
+
+    // State
+    last_publish: Option<Instant>,
+}
+
+impl StaticTransformNode {
+    /// Create a node publishing the description at `path` on "tf_static"
+    pub fn new<P: AsRef<Path>>(path: P) -> Result<Self> {
+        Self::new_with_topic(path, "tf_static")
+    }
+
+    /// Create a node publishing the description at `path` on a custom topic
+    pub fn new_with_topic<P: AsRef<Path>>(path: P, topic: &str) -> Result<Self> {
+        let description = RobotDescription::load(path)
+            .map_err(|e| HorusError::Config(format!("StaticTransformNode: {}", e)))?;
+        Self::from_description(description, topic)
+    }
+
+    /// Create a node from an already parsed description
+    pub fn from_description(description: RobotDescription, topic: &str) -> Result<Self> {
+        Ok(Self {
+            publisher: Hub::new(topic)?,
+            description,
+            hframe: None,
+            republish_interval: Duration::from_secs(1),
+            last_publish: None,
+        })
+    }
+
+    /// Load the description into this HFrame at startup
+    ///
+    /// `HFrame` clones share their frame tree, so pass a clone of the tree the
+    /// rest of the application uses.
+    pub fn set_hframe(&mut self, hframe: HFrame) {
+        self.hframe = Some(hframe);
+    }
+
+    /// Set how often the transforms are re-published (zero publishes once)
+    pub fn set_republish_interval(&mut self, interval: Duration) {
+        self.republish_interval = interval;
+    }
+
+    /// Robot description being broadcast
+    pub fn get_description(&self) -> &RobotDescription {
+        &self.description
+    }
+
+    /// Number of fixed transforms broadcast each time
+    pub fn static_transform_count(&self) -> usize {
+        self.description.static_transforms().len()
+    }
+
+    fn publish(&mut self, now: Instant) {
+        for msg in self.description.tf_messages(timestamp_now()) {
+            let _ = self.publisher.send(msg, &mut None);
+        }
+        self.last_publish = Some(now);
+    }
+}
+
+impl Node for StaticTransformNode {
+    fn name(&self) -> &'static str {
+        "StaticTransformNode"
+    }
+
+    fn init(&mut self, ctx: &mut NodeInfo) -> Result<()> {
+        if let Some(hframe) = &self.hframe {
+            let registered = hframe
+                .load_description(&self.description)
+                .map_err(|e| HorusError::InitializationFailed(e.to_string()))?;
+            ctx.log_info(&format!(
+                "StaticTransformNode: registered {} frames in HFrame",
+                registered
+            ));
+        }
+
+        ctx.log_info(&format!(
+            "StaticTransformNode: broadcasting {} fixed transforms",
+            self.static_transform_count()
+        ));
+        Ok(())
+    }
+
+    fn tick(&mut self, _ctx: Option<&mut NodeInfo>) {
+        let now = Instant::now();
+        let due = match self.last_publish {
+            None => true,
+            Some(_) if self.republish_interval.is_zero() => false,
+            Some(last) => now.duration_since(last) >= self.republish_interval,
+        };
+
+        if due {
+            self.publish(now);
+        }
+    }
+}
+
+// Default impl removed - use StaticTransformNode::new() instead which returns HorusResult
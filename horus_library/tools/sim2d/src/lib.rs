@@ -51,6 +51,9 @@ pub mod sensors;
 // 2D Joint system for articulated robots
 pub mod joint;
 
+// Lockstep co-simulation with a HORUS scheduler
+pub mod lockstep;
+
 // Python API module (optional, enabled with "python" feature)
 #[cfg(feature = "python")]
 pub mod python_api;
@@ -294,6 +297,9 @@ fn create_app(
         articulated: None,
         preset: None,
         gravity: false,
+        lockstep: false,
+        lockstep_topic: horus_core::scheduling::DEFAULT_LOCKSTEP_TOPIC.to_string(),
+        use_sim_time: false,
     };
 
     let app_config = AppConfig {
@@ -321,15 +327,21 @@ fn create_app(
             .insert_resource(UltrasonicSensors::default())
             .insert_resource(ContactSensors::default())
             .insert_resource(PreviousVelocity::default())
+            .insert_resource(SimTime::new())
             .insert_resource(ObstacleIdCounter::default())
             .add_systems(Startup, setup)
             // Tick start - runs first to mark beginning of frame
             .add_systems(Update, tick_start_system)
+            // Commands are applied before the physics step, sensors read the result
+            .add_systems(
+                Update,
+                (horus_system, physics_system)
+                    .chain()
+                    .after(tick_start_system),
+            )
             .add_systems(
                 Update,
                 (
-                    horus_system,
-                    physics_system,
                     telemetry_system,
                     odometry_publish_system,
                     imu_system,
@@ -340,7 +352,7 @@ fn create_app(
                     contact_system,
                     dynamic_obstacle_system,
                 )
-                    .after(tick_start_system),
+                    .after(physics_system),
             );
     } else {
         // GUI mode - full visualization
@@ -380,21 +392,27 @@ fn create_app(
         .insert_resource(UltrasonicSensors::default())
         .insert_resource(ContactSensors::default())
         .insert_resource(PreviousVelocity::default())
+        .insert_resource(SimTime::new())
         .insert_resource(CollisionState::default())
         .insert_resource(ObstacleIdCounter::default())
         .add_systems(Startup, setup)
         // Tick start - runs first to mark beginning of frame
         .add_systems(Update, tick_start_system)
+        // Commands are applied before the physics step, sensors read the result
+        .add_systems(
+            Update,
+            (horus_system, physics_system)
+                .chain()
+                .after(tick_start_system),
+        )
         .add_systems(
             Update,
             (
-                horus_system,
-                physics_system,
                 telemetry_system,
                 visual_sync_system,
                 visual_component_sync_system,
             )
-                .after(tick_start_system),
+                .after(physics_system),
         )
         .add_systems(
             Update,
@@ -407,7 +425,7 @@ fn create_app(
                 ultrasonic_system,
                 contact_system,
             )
-                .after(tick_start_system),
+                .after(physics_system),
         )
         .add_systems(
             Update,
@@ -0,0 +1,71 @@
+//! Lockstep co-simulation with a HORUS scheduler
+//!
+//! With `--lockstep`, every frame ends by handing control to a scheduler
+//! running `Scheduler::with_lockstep`: sim2d publishes the step, waits until
+//! the scheduler has ticked its nodes once, and only then applies the new
+//! commands and takes the next physics step. Each frame advances simulated
+//! time by exactly one physics `dt`, independent of wall-clock speed.
+
+use crate::PhysicsWorld;
+use bevy::prelude::*;
+use horus_core::scheduling::{LockstepConfig, LockstepDriver};
+use std::time::Duration;
+use tracing::{error, info};
+
+/// Lockstep driver shared by the simulation systems
+#[derive(Resource)]
+pub struct SimLockstep {
+    driver: LockstepDriver,
+}
+
+impl SimLockstep {
+    /// Simulated time after the last completed step
+    pub fn sim_time(&self) -> Duration {
+        self.driver.sim_time()
+    }
+}
+
+/// Adds lockstep stepping to a sim2d app
+pub struct LockstepPlugin {
+    config: LockstepConfig,
+}
+
+impl LockstepPlugin {
+    pub fn new(config: LockstepConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Plugin for LockstepPlugin {
+    fn build(&self, app: &mut App) {
+        match LockstepDriver::new(self.config.clone()) {
+            Ok(driver) => {
+                info!("Lockstep enabled on '{}'", self.config.topic);
+                app.insert_resource(SimLockstep { driver })
+                    .add_systems(PostUpdate, lockstep_system);
+            }
+            Err(e) => error!("Failed to enable lockstep: {}", e),
+        }
+    }
+}
+
+/// Publish the finished step and wait for the scheduler's cycle
+///
+/// Runs in `PostUpdate`, after physics and every sensor system of the frame.
+pub fn lockstep_system(
+    mut lockstep: ResMut<SimLockstep>,
+    physics_world: Res<PhysicsWorld>,
+    ui_state: Res<crate::ui::UiState>,
+    mut exit: EventWriter<AppExit>,
+) {
+    // No physics step was taken while paused
+    if ui_state.paused {
+        return;
+    }
+
+    let dt = Duration::from_secs_f32(physics_world.integration_parameters.dt);
+    if let Err(e) = lockstep.driver.step(dt) {
+        error!("Lockstep stopped: {}", e);
+        exit.send(AppExit::error());
+    }
+}
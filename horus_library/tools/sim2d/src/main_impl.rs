@@ -77,6 +77,18 @@ pub struct Args {
     /// Enable gravity (for side-view humanoid simulation)
     #[arg(long)]
     pub gravity: bool,
+
+    /// Step in lockstep with a HORUS scheduler (deterministic co-simulation)
+    #[arg(long)]
+    pub lockstep: bool,
+
+    /// Topic prefix for the lockstep step/ack handshake
+    #[arg(long, default_value = horus_core::scheduling::DEFAULT_LOCKSTEP_TOPIC)]
+    pub lockstep_topic: String,
+
+    /// Stamp sensor data with simulated time (also set by HORUS_USE_SIM_TIME)
+    #[arg(long)]
+    pub use_sim_time: bool,
 }
 
 /// Robot configuration
@@ -418,6 +430,45 @@ impl Default for PreviousVelocity {
     }
 }
 
+/// Simulated time, advanced by every physics step and published on the
+/// `clock` topic for nodes running with `use_sim_time`
+#[derive(Resource)]
+pub struct SimTime {
+    elapsed: std::time::Duration,
+    publisher: Option<horus_core::core::ClockPublisher>,
+}
+
+impl SimTime {
+    pub fn new() -> Self {
+        let publisher = horus_core::core::ClockPublisher::new()
+            .map_err(|e| warn!("Failed to create clock publisher: {:?}", e))
+            .ok();
+        Self {
+            elapsed: std::time::Duration::ZERO,
+            publisher,
+        }
+    }
+
+    /// Simulated time since the simulation started
+    pub fn elapsed(&self) -> std::time::Duration {
+        self.elapsed
+    }
+
+    fn advance(&mut self, dt: f32) {
+        self.elapsed += std::time::Duration::from_secs_f32(dt);
+        horus_core::core::clock::set_sim_time(self.elapsed);
+        if let Some(ref publisher) = self.publisher {
+            publisher.publish(self.elapsed);
+        }
+    }
+}
+
+impl Default for SimTime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Collision tracking resource
 #[derive(Resource, Default)]
 pub struct CollisionState {
@@ -1337,7 +1388,12 @@ pub fn horus_system(
 }
 
 /// Physics update system
-pub fn physics_system(mut physics_world: ResMut<PhysicsWorld>, ui_state: Res<ui::UiState>) {
+pub fn physics_system(
+    mut physics_world: ResMut<PhysicsWorld>,
+    ui_state: Res<ui::UiState>,
+    lockstep: Option<Res<crate::lockstep::SimLockstep>>,
+    mut sim_time: ResMut<SimTime>,
+) {
     // Don't run physics if paused
     if ui_state.paused {
         return;
@@ -1359,9 +1415,11 @@ pub fn physics_system(mut physics_world: ResMut<PhysicsWorld>, ui_state: Res<ui:
         ref event_handler,
     } = *physics_world;
 
-    // Apply simulation speed
+    // Apply simulation speed (lockstep always advances by exactly one dt)
     let mut params = *integration_parameters;
-    params.dt *= ui_state.simulation_speed;
+    if lockstep.is_none() {
+        params.dt *= ui_state.simulation_speed;
+    }
 
     physics_pipeline.step(
         gravity,
@@ -1378,6 +1436,8 @@ pub fn physics_system(mut physics_world: ResMut<PhysicsWorld>, ui_state: Res<ui:
         physics_hooks,
         event_handler,
     );
+
+    sim_time.advance(params.dt);
 }
 
 /// Tick start system - marks the beginning of a simulation frame for metrics tracking
@@ -1577,10 +1637,7 @@ pub fn odometry_publish_system(
                     let angvel = rigid_body.angvel();
 
                     // Get current timestamp
-                    let timestamp = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or_else(|_| std::time::Duration::from_secs(0))
-                        .as_nanos() as u64;
+                    let timestamp = horus_core::core::clock::now_ns();
 
                     // Create odometry message
                     let mut odom = Odometry::new();
@@ -1620,6 +1677,7 @@ pub fn imu_system(
     physics_world: Res<PhysicsWorld>,
     mut horus_comm: Option<ResMut<HorusComm>>,
     mut prev_vel: ResMut<PreviousVelocity>,
+    lockstep: Option<Res<crate::lockstep::SimLockstep>>,
 ) {
     if let Some(ref mut comm) = horus_comm {
         let HorusComm {
@@ -1637,9 +1695,13 @@ pub fn imu_system(
                     let rot = rigid_body.rotation();
                     let angvel = rigid_body.angvel();
 
-                    // Calculate time delta
+                    // Calculate time delta (one physics step per frame in lockstep)
                     let now = std::time::Instant::now();
-                    let dt = now.duration_since(prev_vel.timestamp).as_secs_f32();
+                    let dt = if lockstep.is_some() {
+                        physics_world.integration_parameters.dt
+                    } else {
+                        now.duration_since(prev_vel.timestamp).as_secs_f32()
+                    };
 
                     // Calculate linear acceleration (change in velocity / time)
                     let accel_x = if dt > 0.0 {
@@ -1678,10 +1740,7 @@ pub fn imu_system(
                     imu.linear_acceleration = [accel_x as f64, accel_y as f64, 0.0];
 
                     // Set timestamp
-                    imu.timestamp = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or_else(|_| std::time::Duration::from_secs(0))
-                        .as_nanos() as u64;
+                    imu.timestamp = horus_core::core::clock::now_ns();
 
                     // Publish to this robot's IMU topic
                     if let Err(e) = hubs.imu_pub.send(imu, &mut Some(node_info)) {
@@ -1782,10 +1841,7 @@ pub fn lidar_system(
                     }
 
                     // Set timestamp
-                    scan.timestamp = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or_else(|_| std::time::Duration::from_secs(0))
-                        .as_nanos() as u64;
+                    scan.timestamp = horus_core::core::clock::now_ns();
 
                     // Publish to this robot's LiDAR topic
                     if let Err(e) = hubs.lidar_pub.send(scan, &mut Some(node_info)) {
@@ -3200,6 +3256,10 @@ pub fn run_simulation(args: Args) -> Result<()> {
     use std::sync::mpsc::channel;
     use std::sync::{Arc, Mutex};
 
+    if args.use_sim_time {
+        horus_core::core::clock::set_use_sim_time(true);
+    }
+
     let app_config = AppConfig::new(args.clone());
     let headless = args.headless;
 
@@ -3221,14 +3281,20 @@ pub fn run_simulation(args: Args) -> Result<()> {
             .insert_resource(UltrasonicSensors::default())
             .insert_resource(ContactSensors::default())
             .insert_resource(PreviousVelocity::default())
+            .insert_resource(SimTime::new())
             .insert_resource(ObstacleIdCounter::default())
             .add_systems(bevy::prelude::Startup, setup)
             .add_systems(bevy::prelude::Update, tick_start_system)
+            // Commands are applied before the physics step, sensors read the result
+            .add_systems(
+                bevy::prelude::Update,
+                (horus_system, physics_system)
+                    .chain()
+                    .after(tick_start_system),
+            )
             .add_systems(
                 bevy::prelude::Update,
                 (
-                    horus_system,
-                    physics_system,
                     telemetry_system,
                     odometry_publish_system,
                     imu_system,
@@ -3239,7 +3305,7 @@ pub fn run_simulation(args: Args) -> Result<()> {
                     contact_system,
                     dynamic_obstacle_system,
                 )
-                    .after(tick_start_system),
+                    .after(physics_system),
             )
             // Articulated robot systems
             .add_systems(
@@ -3288,20 +3354,26 @@ pub fn run_simulation(args: Args) -> Result<()> {
         .insert_resource(UltrasonicSensors::default())
         .insert_resource(ContactSensors::default())
         .insert_resource(PreviousVelocity::default())
+        .insert_resource(SimTime::new())
         .insert_resource(CollisionState::default())
         .insert_resource(ObstacleIdCounter::default())
         .add_systems(bevy::prelude::Startup, setup)
         .add_systems(bevy::prelude::Update, tick_start_system)
+        // Commands are applied before the physics step, sensors read the result
+        .add_systems(
+            bevy::prelude::Update,
+            (horus_system, physics_system)
+                .chain()
+                .after(tick_start_system),
+        )
         .add_systems(
             bevy::prelude::Update,
             (
-                horus_system,
-                physics_system,
                 telemetry_system,
                 visual_sync_system,
                 visual_component_sync_system,
             )
-                .after(tick_start_system),
+                .after(physics_system),
         )
         .add_systems(
             bevy::prelude::Update,
@@ -3314,7 +3386,7 @@ pub fn run_simulation(args: Args) -> Result<()> {
                 ultrasonic_system,
                 contact_system,
             )
-                .after(tick_start_system),
+                .after(physics_system),
         )
         .add_systems(
             bevy::prelude::Update,
@@ -3374,6 +3446,12 @@ pub fn run_simulation(args: Args) -> Result<()> {
         }
     }
 
+    if args.lockstep {
+        app.add_plugins(crate::lockstep::LockstepPlugin::new(
+            horus_core::scheduling::LockstepConfig::default().with_topic(&args.lockstep_topic),
+        ));
+    }
+
     app.run();
     Ok(())
 }
@@ -22,33 +22,7 @@ def make_sim3d_env(task_name, rank, seed=0):
         seed: Random seed
     """
     def _init():
-        # Observation dimensions per task
-        obs_dims = {
-            "reaching": 10,
-            "balancing": 8,
-            "locomotion": 12,
-            "navigation": 20,
-            "manipulation": 15,
-            "push": 18,
-        }
-
-        # Action dimensions per task
-        action_dims = {
-            "reaching": 6,
-            "balancing": 1,
-            "locomotion": 2,
-            "navigation": 2,
-            "manipulation": 7,
-            "push": 2,
-        }
-
-        env = sim3d_rl.make_env(
-            task_name,
-            obs_dim=obs_dims[task_name],
-            action_dim=action_dims[task_name]
-        )
-        env.reset(seed=seed + rank)
-        return env
+        return sim3d_rl.make(task_name, seed=seed + rank)
     return _init
 
 
@@ -153,23 +127,10 @@ def test_environment(task_name, num_episodes=5):
     """
     print(f"Testing {task_name} environment...")
 
-    obs_dims = {
-        "reaching": 10, "balancing": 8, "locomotion": 12,
-        "navigation": 20, "manipulation": 15, "push": 18,
-    }
-    action_dims = {
-        "reaching": 6, "balancing": 1, "locomotion": 2,
-        "navigation": 2, "manipulation": 7, "push": 2,
-    }
-
-    env = sim3d_rl.make_env(
-        task_name,
-        obs_dim=obs_dims[task_name],
-        action_dim=action_dims[task_name]
-    )
+    env = sim3d_rl.make(task_name)
 
     for episode in range(num_episodes):
-        obs = env.reset()
+        obs, info = env.reset()
         total_reward = 0
         steps = 0
         done = False
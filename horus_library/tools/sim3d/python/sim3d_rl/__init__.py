@@ -10,13 +10,25 @@ __version__ = "0.1.6"
 from .sim3d_rl import (
     Sim3DEnv,
     VecSim3DEnv,
+    load_task_spec,
     make_env,
     make_vec_env,
 )
 
+# Gymnasium wrappers
+from .env import TASKS, Sim3DGymEnv, make, make_vec, register_envs
+
+register_envs()
+
 __all__ = [
     "Sim3DEnv",
     "VecSim3DEnv",
+    "load_task_spec",
     "make_env",
     "make_vec_env",
+    "TASKS",
+    "Sim3DGymEnv",
+    "make",
+    "make_vec",
+    "register_envs",
 ]
@@ -0,0 +1,188 @@
+"""
+Gymnasium wrappers for Sim3D RL tasks
+
+`Sim3DGymEnv` adapts the native `Sim3DEnv` to the `gymnasium.Env` interface
+(Box spaces, `reset(seed=..., options=...)` returning `(obs, info)`, and the
+five-tuple `step`), so environments can be handed directly to
+Stable-Baselines3, CleanRL, or any other Gymnasium-based trainer.
+
+Environments can be built from a task name or from a task YAML file:
+
+    env = sim3d_rl.make("reaching", seed=0)
+    env = sim3d_rl.make("configs/rl/reaching.yaml")
+    envs = sim3d_rl.make_vec("navigation", num_envs=8, seed=0)
+
+Built-in tasks are also registered with Gymnasium as `Sim3D-<Task>-v0`.
+"""
+
+import os
+from typing import Any, Callable, Dict, Optional, Tuple
+
+import gymnasium as gym
+import numpy as np
+from gymnasium import spaces
+
+from .sim3d_rl import Sim3DEnv, load_task_spec
+
+TASKS = (
+    "reaching",
+    "balancing",
+    "locomotion",
+    "navigation",
+    "manipulation",
+    "push",
+)
+
+
+def _box(space: Dict[str, Any]) -> spaces.Box:
+    """Convert a native space description into a gymnasium Box."""
+    return spaces.Box(
+        low=np.float32(space["low"]),
+        high=np.float32(space["high"]),
+        shape=tuple(space["shape"]),
+        dtype=np.float32,
+    )
+
+
+def _is_config_path(task: str) -> bool:
+    return task.endswith((".yaml", ".yml")) or os.path.isfile(task)
+
+
+class Sim3DGymEnv(gym.Env):
+    """
+    Gymnasium environment running a Sim3D task headlessly.
+
+    Args:
+        task: Built-in task name (see `TASKS`)
+        config: Path to a task YAML file; overrides `task` and the dimension
+            arguments when given
+        obs_dim: Observation dimension (defaults to the task's own)
+        action_dim: Action dimension (defaults to the task's own)
+        max_steps: Episode time limit; episodes are truncated after this many
+            steps
+        seed: Seed applied when the environment is created, so the first
+            `reset()` is already reproducible
+        render_mode: Accepted for Gymnasium compatibility; headless
+            environments do not render
+    """
+
+    metadata = {"render_modes": []}
+
+    def __init__(
+        self,
+        task: str = "reaching",
+        config: Optional[str] = None,
+        obs_dim: Optional[int] = None,
+        action_dim: Optional[int] = None,
+        max_steps: Optional[int] = None,
+        seed: Optional[int] = None,
+        render_mode: Optional[str] = None,
+    ):
+        super().__init__()
+        if config is not None:
+            self._env = Sim3DEnv.from_yaml(os.fspath(config))
+            if seed is not None:
+                self._env.seed(seed)
+        else:
+            self._env = Sim3DEnv(task, obs_dim, action_dim, max_steps, seed)
+
+        self.task = self._env.task
+        self.max_episode_steps = self._env.max_episode_steps
+        self.render_mode = render_mode
+        self.observation_space = _box(self._env.observation_space())
+        self.action_space = _box(self._env.action_space())
+        if seed is not None:
+            self.action_space.seed(seed)
+
+    def reset(
+        self,
+        *,
+        seed: Optional[int] = None,
+        options: Optional[Dict[str, Any]] = None,
+    ) -> Tuple[np.ndarray, Dict[str, Any]]:
+        super().reset(seed=seed)
+        obs = self._env.reset(seed)
+        return np.asarray(obs, dtype=np.float32), {}
+
+    def step(
+        self, action: np.ndarray
+    ) -> Tuple[np.ndarray, float, bool, bool, Dict[str, Any]]:
+        action = np.asarray(action, dtype=np.float32).reshape(-1)
+        obs, reward, terminated, truncated, info = self._env.step(action.tolist())
+        return (
+            np.asarray(obs, dtype=np.float32),
+            float(reward),
+            bool(terminated),
+            bool(truncated),
+            dict(info),
+        )
+
+    def render(self) -> None:
+        return None
+
+    def close(self) -> None:
+        self._env.close()
+
+
+def make(task: str = "reaching", **kwargs: Any) -> Sim3DGymEnv:
+    """
+    Create a Gymnasium environment from a task name or task YAML path.
+
+    Keyword arguments are forwarded to `Sim3DGymEnv`.
+    """
+    task = os.fspath(task)
+    if _is_config_path(task):
+        return Sim3DGymEnv(config=task, **kwargs)
+    return Sim3DGymEnv(task=task, **kwargs)
+
+
+def _env_fn(
+    task: str, seed: Optional[int], kwargs: Dict[str, Any]
+) -> Callable[[], Sim3DGymEnv]:
+    def _init() -> Sim3DGymEnv:
+        return make(task, seed=seed, **kwargs)
+
+    return _init
+
+
+def make_vec(
+    task: str = "reaching",
+    num_envs: int = 1,
+    seed: Optional[int] = None,
+    asynchronous: bool = False,
+    **kwargs: Any,
+) -> gym.vector.VectorEnv:
+    """
+    Create `num_envs` independent instances as a Gymnasium vector env.
+
+    Instance `i` is seeded with `seed + i`, matching how Gymnasium seeds
+    sub-environments on `reset(seed=seed)`. When loading a task YAML without
+    an explicit seed, the seed from the file is used as the base. With
+    `asynchronous=True` each instance runs in its own process.
+    """
+    if num_envs < 1:
+        raise ValueError("num_envs must be at least 1")
+
+    task = os.fspath(task)
+    if seed is None and _is_config_path(task):
+        seed = load_task_spec(task)["seed"]
+
+    env_fns = [
+        _env_fn(task, None if seed is None else seed + i, kwargs)
+        for i in range(num_envs)
+    ]
+    if asynchronous:
+        return gym.vector.AsyncVectorEnv(env_fns)
+    return gym.vector.SyncVectorEnv(env_fns)
+
+
+def register_envs() -> None:
+    """Register `Sim3D-<Task>-v0` ids for every built-in task."""
+    for task in TASKS:
+        env_id = f"Sim3D-{task.capitalize()}-v0"
+        if env_id not in gym.registry:
+            gym.register(
+                id=env_id,
+                entry_point="sim3d_rl.env:Sim3DGymEnv",
+                kwargs={"task": task},
+            )
@@ -52,6 +52,18 @@ pub struct Cli {
     #[arg(long, default_value = "sim3d_robot")]
     pub robot_name: String,
 
+    /// Step in lockstep with a HORUS scheduler (deterministic co-simulation)
+    #[arg(long, default_value_t = false)]
+    pub lockstep: bool,
+
+    /// Topic prefix for the lockstep step/ack handshake
+    #[arg(long, default_value = horus_core::scheduling::DEFAULT_LOCKSTEP_TOPIC)]
+    pub lockstep_topic: String,
+
+    /// Stamp sensor data with simulated time (also set by HORUS_USE_SIM_TIME)
+    #[arg(long, default_value_t = false)]
+    pub use_sim_time: bool,
+
     /// Capture a screenshot and exit (internal - for automated testing via horus_mcp)
     #[arg(long, hide = true)]
     pub screenshot: Option<PathBuf>,
@@ -76,11 +76,12 @@ use pyo3::prelude::*;
 #[cfg(feature = "python")]
 #[pymodule]
 fn sim3d_rl(m: &Bound<PyModule>) -> PyResult<()> {
-    use rl::python::{make_env, make_vec_env, PySim3DEnv, PyVecSim3DEnv};
+    use rl::python::{load_task_spec, make_env, make_vec_env, PySim3DEnv, PyVecSim3DEnv};
 
     m.add_class::<PySim3DEnv>()?;
     m.add_class::<PyVecSim3DEnv>()?;
     m.add_function(wrap_pyfunction!(make_env, m)?)?;
     m.add_function(wrap_pyfunction!(make_vec_env, m)?)?;
+    m.add_function(wrap_pyfunction!(load_task_spec, m)?)?;
     Ok(())
 }
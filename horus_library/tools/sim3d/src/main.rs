@@ -125,6 +125,10 @@ fn main() {
     info!("Starting sim3d");
     info!("Mode: {:?}", cli.mode);
 
+    if cli.use_sim_time {
+        horus_core::core::clock::set_use_sim_time(true);
+    }
+
     match cli.mode {
         Mode::Visual => run_visual_mode(cli),
         Mode::Headless => run_headless_mode(cli),
@@ -467,8 +471,15 @@ fn run_visual_mode(cli: Cli) {
     app.insert_resource(PhysicsWorld::default())
         .insert_resource(HFrameTree::with_root("world"))
         .insert_resource(SpawnedObjects::default())
-        .insert_resource(cli)
-        .init_resource::<systems::physics_step::PhysicsAccumulator>();
+        .init_resource::<systems::physics_step::PhysicsAccumulator>()
+        .init_resource::<systems::physics_step::SimTime>();
+
+    if cli.lockstep {
+        app.add_plugins(systems::lockstep::LockstepPlugin::new(
+            horus_core::scheduling::LockstepConfig::default().with_topic(&cli.lockstep_topic),
+        ));
+    }
+    app.insert_resource(cli);
 
     // Configure system set ordering: Physics -> Sensors -> TF
     app.configure_sets(
@@ -572,8 +583,15 @@ fn run_headless_mode(cli: Cli) {
     app.insert_resource(PhysicsWorld::default())
         .insert_resource(HFrameTree::with_root("world"))
         .insert_resource(SpawnedObjects::default())
-        .insert_resource(cli)
-        .init_resource::<systems::physics_step::PhysicsAccumulator>();
+        .init_resource::<systems::physics_step::PhysicsAccumulator>()
+        .init_resource::<systems::physics_step::SimTime>();
+
+    if cli.lockstep {
+        app.add_plugins(systems::lockstep::LockstepPlugin::new(
+            horus_core::scheduling::LockstepConfig::default().with_topic(&cli.lockstep_topic),
+        ));
+    }
+    app.insert_resource(cli);
 
     // Configure system set ordering: Physics -> Sensors -> TF
     app.configure_sets(
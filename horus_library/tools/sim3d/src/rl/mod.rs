@@ -6,6 +6,7 @@ pub mod controllers;
 pub mod curriculum;
 pub mod domain_randomization;
 pub mod reward_shaping;
+pub mod spec;
 pub mod tasks;
 
 #[cfg(feature = "python")]
@@ -25,6 +26,9 @@ pub use reward_shaping::{
     CompositeReward, RewardComponent, RewardFunctions, RewardManager, RewardStats,
 };
 
+// Re-export task specifications
+pub use spec::{SpaceSpec, TaskSpec};
+
 /// Observation space for RL tasks
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct Observation {
@@ -149,6 +153,10 @@ pub trait RLTask: Send + Sync {
     /// Get task configuration
     fn config(&self) -> &TaskConfig;
 
+    /// Reseed the task's random number generator so that subsequent resets
+    /// (target sampling, initial perturbations) are reproducible.
+    fn seed(&mut self, _seed: u64) {}
+
     /// Reset the environment to initial state
     fn reset(&mut self, world: &mut World) -> Observation;
 
@@ -14,7 +14,7 @@ use numpy::{PyArray1, PyArray2, PyArrayMethods, ToPyArray};
 use bevy::prelude::*;
 use std::sync::{Arc, Mutex};
 
-use super::{tasks::*, Action, Observation, RLTask, RLTaskManager, StepResult};
+use super::{Action, RLTaskManager, TaskSpec};
 
 /// Python-exposed RL environment (Gymnasium compatible)
 #[cfg(feature = "python")]
@@ -22,50 +22,118 @@ use super::{tasks::*, Action, Observation, RLTask, RLTaskManager, StepResult};
 pub struct PySim3DEnv {
     task_manager: Arc<Mutex<RLTaskManager>>,
     world: Arc<Mutex<World>>,
+    spec: TaskSpec,
     obs_dim: usize,
     action_dim: usize,
+    max_episode_steps: usize,
+    episode_steps: usize,
     episode_count: usize,
 }
 
 #[cfg(feature = "python")]
-#[pymethods]
 impl PySim3DEnv {
-    #[new]
-    fn new(task_type: &str, obs_dim: usize, action_dim: usize) -> PyResult<Self> {
-        let mut world = World::new();
-
-        // Create task based on type
-        let task: Box<dyn RLTask> = match task_type {
-            "reaching" => Box::new(ReachingTask::new(obs_dim, action_dim)),
-            "balancing" => Box::new(BalancingTask::new(obs_dim, action_dim)),
-            "locomotion" => Box::new(LocomotionTask::new(obs_dim, action_dim)),
-            "navigation" => Box::new(NavigationTask::new(obs_dim, action_dim)),
-            "manipulation" => Box::new(ManipulationTask::new(obs_dim, action_dim)),
-            "push" => Box::new(PushTask::new(obs_dim, action_dim)),
-            _ => return Err(pyo3::exceptions::PyValueError::new_err(
-                format!("Unknown task type: {}. Available: reaching, balancing, locomotion, navigation, manipulation, push", task_type)
-            )),
-        };
+    /// Build an environment from a task spec
+    pub fn from_spec(spec: TaskSpec) -> PyResult<Self> {
+        let task = spec.build().map_err(spec_error)?;
+        let max_episode_steps = spec.episode_limit(task.as_ref());
 
         let mut task_manager = RLTaskManager::new();
         task_manager.set_task(task);
 
         Ok(Self {
             task_manager: Arc::new(Mutex::new(task_manager)),
-            world: Arc::new(Mutex::new(world)),
-            obs_dim,
-            action_dim,
+            world: Arc::new(Mutex::new(World::new())),
+            obs_dim: spec.obs_dim(),
+            action_dim: spec.action_dim(),
+            spec,
+            max_episode_steps,
+            episode_steps: 0,
             episode_count: 0,
         })
     }
 
+    fn reseed(&mut self, seed: u64) {
+        let mut task_manager = self.task_manager.lock().unwrap();
+        if let Some(task) = task_manager.current_task.as_mut() {
+            task.seed(seed);
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+fn spec_error(err: crate::error::EnhancedError) -> PyErr {
+    pyo3::exceptions::PyValueError::new_err(err.to_string())
+}
+
+#[cfg(feature = "python")]
+fn spec_for(
+    task_type: &str,
+    obs_dim: Option<usize>,
+    action_dim: Option<usize>,
+    max_steps: Option<usize>,
+    seed: Option<u64>,
+) -> TaskSpec {
+    let mut spec = TaskSpec::new(task_type);
+    spec.observation.dim = obs_dim;
+    spec.action.dim = action_dim;
+    spec.max_steps = max_steps;
+    spec.seed = seed;
+    spec
+}
+
+#[cfg(feature = "python")]
+fn space_dict(py: Python, dim: usize, (low, high): (f32, f32)) -> PyResult<Py<PyDict>> {
+    let space = PyDict::new_bound(py);
+    space.set_item("type", "Box")?;
+    space.set_item("shape", vec![dim])?;
+    space.set_item("low", low)?;
+    space.set_item("high", high)?;
+    space.set_item("dtype", "float32")?;
+    Ok(space.unbind())
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PySim3DEnv {
+    #[new]
+    #[pyo3(signature = (task_type, obs_dim=None, action_dim=None, max_steps=None, seed=None))]
+    fn new(
+        task_type: &str,
+        obs_dim: Option<usize>,
+        action_dim: Option<usize>,
+        max_steps: Option<usize>,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        Self::from_spec(spec_for(task_type, obs_dim, action_dim, max_steps, seed))
+    }
+
+    /// Create an environment from a task YAML file
+    #[staticmethod]
+    fn from_yaml(path: &str) -> PyResult<Self> {
+        Self::from_spec(TaskSpec::from_yaml_file(path).map_err(spec_error)?)
+    }
+
+    /// Reseed the task's random number generator
+    fn seed(&mut self, seed: u64) {
+        self.reseed(seed);
+    }
+
     /// Reset the environment (Gym/Gymnasium API)
-    fn reset(&mut self, py: Python) -> PyResult<Py<PyArray1<f32>>> {
+    ///
+    /// Passing a seed reseeds the task before sampling the initial state, so
+    /// the same seed always produces the same sequence of episodes.
+    #[pyo3(signature = (seed=None))]
+    fn reset(&mut self, py: Python, seed: Option<u64>) -> PyResult<Py<PyArray1<f32>>> {
+        if let Some(seed) = seed {
+            self.reseed(seed);
+        }
+
         let mut task_manager = self.task_manager.lock().unwrap();
         let mut world = self.world.lock().unwrap();
 
         if let Some(obs) = task_manager.reset(&mut world) {
             self.episode_count += 1;
+            self.episode_steps = 0;
             let obs_array = obs.data.to_pyarray_bound(py).to_owned();
             Ok(obs_array.unbind())
         } else {
@@ -76,17 +144,32 @@ impl PySim3DEnv {
     }
 
     /// Step the environment (Gym/Gymnasium API)
+    ///
+    /// Actions are clipped to the action space bounds. Episodes that reach
+    /// the spec's `max_steps` without terminating are reported as truncated.
     fn step(
         &mut self,
         py: Python,
         action: Vec<f32>,
     ) -> PyResult<(Py<PyArray1<f32>>, f32, bool, bool, Py<PyDict>)> {
+        if action.len() != self.action_dim {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "Expected action of length {}, got {}",
+                self.action_dim,
+                action.len()
+            )));
+        }
+
         let mut task_manager = self.task_manager.lock().unwrap();
         let mut world = self.world.lock().unwrap();
 
-        let action_obj = Action::Continuous(action);
+        let (low, high) = self.spec.action_bounds();
+        let action_obj = Action::Continuous(action.iter().map(|a| a.clamp(low, high)).collect());
 
         if let Some(result) = task_manager.step(&mut world, &action_obj) {
+            self.episode_steps += 1;
+            let truncated =
+                result.truncated || (!result.done && self.episode_steps >= self.max_episode_steps);
             let obs_array = result.observation.data.to_pyarray_bound(py).to_owned();
 
             // Create info dict
@@ -103,7 +186,7 @@ impl PySim3DEnv {
                 obs_array.unbind(),
                 result.reward,
                 result.done,
-                result.truncated,
+                truncated,
                 info.unbind(),
             ))
         } else {
@@ -115,22 +198,12 @@ impl PySim3DEnv {
 
     /// Get observation space (Gym/Gymnasium API)
     fn observation_space(&self, py: Python) -> PyResult<Py<PyDict>> {
-        let space = PyDict::new_bound(py);
-        space.set_item("type", "Box")?;
-        space.set_item("shape", vec![self.obs_dim])?;
-        space.set_item("dtype", "float32")?;
-        Ok(space.unbind())
+        space_dict(py, self.obs_dim, self.spec.observation_bounds())
     }
 
     /// Get action space (Gym/Gymnasium API)
     fn action_space(&self, py: Python) -> PyResult<Py<PyDict>> {
-        let space = PyDict::new_bound(py);
-        space.set_item("type", "Box")?;
-        space.set_item("shape", vec![self.action_dim])?;
-        space.set_item("low", -1.0)?;
-        space.set_item("high", 1.0)?;
-        space.set_item("dtype", "float32")?;
-        Ok(space.unbind())
+        space_dict(py, self.action_dim, self.spec.action_bounds())
     }
 
     /// Render the environment (optional)
@@ -146,6 +219,18 @@ impl PySim3DEnv {
         Ok(())
     }
 
+    /// Name of the task this environment runs
+    #[getter]
+    fn task(&self) -> String {
+        self.spec.task.clone()
+    }
+
+    /// Episode time limit used for truncation
+    #[getter]
+    fn max_episode_steps(&self) -> usize {
+        self.max_episode_steps
+    }
+
     /// Get current episode count
     #[getter]
     fn episode_count(&self) -> usize {
@@ -169,24 +254,62 @@ pub struct PyVecSim3DEnv {
 }
 
 #[cfg(feature = "python")]
-#[pymethods]
 impl PyVecSim3DEnv {
-    #[new]
-    fn new(task_type: &str, obs_dim: usize, action_dim: usize, num_envs: usize) -> PyResult<Self> {
+    /// Build `num_envs` copies of a task spec; instance `i` is seeded with
+    /// `seed + i` when the spec carries a seed
+    pub fn from_spec(spec: TaskSpec, num_envs: usize) -> PyResult<Self> {
+        if num_envs == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "num_envs must be greater than zero",
+            ));
+        }
+
         let mut envs = Vec::with_capacity(num_envs);
-        for _ in 0..num_envs {
-            envs.push(PySim3DEnv::new(task_type, obs_dim, action_dim)?);
+        for i in 0..num_envs {
+            let mut env_spec = spec.clone();
+            env_spec.seed = spec.seed.map(|seed| seed.wrapping_add(i as u64));
+            envs.push(PySim3DEnv::from_spec(env_spec)?);
         }
 
         Ok(Self { envs, num_envs })
     }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PyVecSim3DEnv {
+    #[new]
+    #[pyo3(signature = (task_type, obs_dim=None, action_dim=None, num_envs=1, max_steps=None, seed=None))]
+    fn new(
+        task_type: &str,
+        obs_dim: Option<usize>,
+        action_dim: Option<usize>,
+        num_envs: usize,
+        max_steps: Option<usize>,
+        seed: Option<u64>,
+    ) -> PyResult<Self> {
+        Self::from_spec(
+            spec_for(task_type, obs_dim, action_dim, max_steps, seed),
+            num_envs,
+        )
+    }
 
-    /// Reset all environments
-    fn reset(&mut self, py: Python) -> PyResult<Py<PyArray2<f32>>> {
+    /// Create a vectorized environment from a task YAML file
+    #[staticmethod]
+    fn from_yaml(path: &str, num_envs: usize) -> PyResult<Self> {
+        Self::from_spec(
+            TaskSpec::from_yaml_file(path).map_err(spec_error)?,
+            num_envs,
+        )
+    }
+
+    /// Reset all environments; with a seed, instance `i` uses `seed + i`
+    #[pyo3(signature = (seed=None))]
+    fn reset(&mut self, py: Python, seed: Option<u64>) -> PyResult<Py<PyArray2<f32>>> {
         let mut observations = Vec::new();
 
-        for env in &mut self.envs {
-            let obs = env.reset(py)?;
+        for (i, env) in self.envs.iter_mut().enumerate() {
+            let obs = env.reset(py, seed.map(|seed| seed.wrapping_add(i as u64)))?;
             let obs_data: Vec<f32> = obs.bind(py).to_vec()?;
             observations.extend(obs_data);
         }
@@ -241,7 +364,7 @@ impl PyVecSim3DEnv {
 
             // Auto-reset if episode is done
             if done || truncated {
-                let _ = env.reset(py)?;
+                let _ = env.reset(py, None)?;
             }
         }
 
@@ -275,56 +398,55 @@ impl PyVecSim3DEnv {
 /// Utility function to create environment from config
 #[cfg(feature = "python")]
 #[pyfunction]
+#[pyo3(signature = (task_type, obs_dim=None, action_dim=None, max_steps=None, seed=None))]
 pub fn make_env(
     task_type: &str,
     obs_dim: Option<usize>,
     action_dim: Option<usize>,
+    max_steps: Option<usize>,
+    seed: Option<u64>,
 ) -> PyResult<PySim3DEnv> {
-    // Default dimensions based on task type
-    let (default_obs, default_act) = match task_type {
-        "reaching" => (10, 6),
-        "balancing" => (6, 1),
-        "locomotion" => (22, 12),
-        "navigation" => (21, 2),
-        "manipulation" => (25, 4),
-        "push" => (30, 2),
-        _ => (10, 6),
-    };
-
-    PySim3DEnv::new(
-        task_type,
-        obs_dim.unwrap_or(default_obs),
-        action_dim.unwrap_or(default_act),
-    )
+    PySim3DEnv::from_spec(spec_for(task_type, obs_dim, action_dim, max_steps, seed))
 }
 
 /// Utility function to create vectorized environment
 #[cfg(feature = "python")]
 #[pyfunction]
+#[pyo3(signature = (task_type, num_envs, obs_dim=None, action_dim=None, max_steps=None, seed=None))]
 pub fn make_vec_env(
     task_type: &str,
     num_envs: usize,
     obs_dim: Option<usize>,
     action_dim: Option<usize>,
+    max_steps: Option<usize>,
+    seed: Option<u64>,
 ) -> PyResult<PyVecSim3DEnv> {
-    let (default_obs, default_act) = match task_type {
-        "reaching" => (10, 6),
-        "balancing" => (6, 1),
-        "locomotion" => (22, 12),
-        "navigation" => (21, 2),
-        "manipulation" => (25, 4),
-        "push" => (30, 2),
-        _ => (10, 6),
-    };
-
-    PyVecSim3DEnv::new(
-        task_type,
-        obs_dim.unwrap_or(default_obs),
-        action_dim.unwrap_or(default_act),
+    PyVecSim3DEnv::from_spec(
+        spec_for(task_type, obs_dim, action_dim, max_steps, seed),
         num_envs,
     )
 }
 
+/// Parse a task YAML file into a dict (task, max_steps, seed, spaces)
+#[cfg(feature = "python")]
+#[pyfunction]
+pub fn load_task_spec(py: Python, path: &str) -> PyResult<Py<PyDict>> {
+    let spec = TaskSpec::from_yaml_file(path).map_err(spec_error)?;
+    let dict = PyDict::new_bound(py);
+    dict.set_item("task", &spec.task)?;
+    dict.set_item("max_steps", spec.max_steps)?;
+    dict.set_item("seed", spec.seed)?;
+    dict.set_item(
+        "observation_space",
+        space_dict(py, spec.obs_dim(), spec.observation_bounds())?,
+    )?;
+    dict.set_item(
+        "action_space",
+        space_dict(py, spec.action_dim(), spec.action_bounds())?,
+    )?;
+    Ok(dict.unbind())
+}
+
 // Note: Python module is defined in lib.rs
 
 #[cfg(test)]
@@ -333,20 +455,14 @@ mod tests {
 
     #[test]
     fn test_task_dimensions() {
-        // Verify default dimensions match task implementations
-        let reaching_dims = (10, 6);
-        let balancing_dims = (6, 1);
-        let locomotion_dims = (22, 12);
-        let navigation_dims = (21, 2);
-        let manipulation_dims = (25, 4);
-        let push_dims = (30, 2);
+        use crate::rl::spec::default_dims;
 
         // These should match the observation sizes in each task's get_observation
-        assert_eq!(reaching_dims, (10, 6));
-        assert_eq!(balancing_dims, (6, 1));
-        assert_eq!(locomotion_dims, (22, 12));
-        assert_eq!(navigation_dims, (21, 2));
-        assert_eq!(manipulation_dims, (25, 4));
-        assert_eq!(push_dims, (30, 2));
+        assert_eq!(default_dims("reaching"), Some((10, 6)));
+        assert_eq!(default_dims("balancing"), Some((6, 1)));
+        assert_eq!(default_dims("locomotion"), Some((22, 12)));
+        assert_eq!(default_dims("navigation"), Some((21, 2)));
+        assert_eq!(default_dims("manipulation"), Some((25, 4)));
+        assert_eq!(default_dims("push"), Some((30, 2)));
     }
 }
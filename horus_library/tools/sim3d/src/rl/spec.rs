@@ -0,0 +1,249 @@
+//! Task specifications for headless RL environments
+//!
+//! A `TaskSpec` describes everything an external trainer needs to know about
+//! an environment: which task to build, the observation/action dimensions and
+//! bounds, the episode time limit, and an optional seed. Specs are loaded from
+//! YAML so that experiments can be versioned alongside training scripts:
+//!
+//! ```yaml
+//! task: reaching
+//! max_steps: 500
+//! seed: 42
+//! action:
+//!   low: -1.0
+//!   high: 1.0
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::tasks::*;
+use super::RLTask;
+use crate::error::{EnhancedError, ErrorCategory, Result};
+
+/// Names of the built-in tasks, in the order they are documented
+pub const TASK_NAMES: [&str; 6] = [
+    "reaching",
+    "balancing",
+    "locomotion",
+    "navigation",
+    "manipulation",
+    "push",
+];
+
+/// Default (obs_dim, action_dim) for a built-in task
+pub fn default_dims(task: &str) -> Option<(usize, usize)> {
+    match task {
+        "reaching" => Some((10, 6)),
+        "balancing" => Some((6, 1)),
+        "locomotion" => Some((22, 12)),
+        "navigation" => Some((21, 2)),
+        "manipulation" => Some((25, 4)),
+        "push" => Some((30, 2)),
+        _ => None,
+    }
+}
+
+/// Bounds and size of a continuous (Box) space
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpaceSpec {
+    /// Number of dimensions; `None` uses the task default
+    pub dim: Option<usize>,
+    /// Lower bound applied to every dimension
+    pub low: Option<f32>,
+    /// Upper bound applied to every dimension
+    pub high: Option<f32>,
+}
+
+/// Declarative description of an RL environment
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TaskSpec {
+    /// Built-in task name (see [`TASK_NAMES`])
+    pub task: String,
+    /// Episode time limit; episodes are truncated after this many steps
+    #[serde(default)]
+    pub max_steps: Option<usize>,
+    /// Seed for the task's random number generator
+    #[serde(default)]
+    pub seed: Option<u64>,
+    #[serde(default)]
+    pub observation: SpaceSpec,
+    #[serde(default)]
+    pub action: SpaceSpec,
+}
+
+impl TaskSpec {
+    /// Spec for a built-in task with all defaults
+    pub fn new(task: impl Into<String>) -> Self {
+        Self {
+            task: task.into(),
+            max_steps: None,
+            seed: None,
+            observation: SpaceSpec::default(),
+            action: SpaceSpec::default(),
+        }
+    }
+
+    /// Parse a spec from a YAML string
+    pub fn from_yaml_str(yaml: &str) -> Result<Self> {
+        let spec: Self = serde_yaml::from_str(yaml).map_err(EnhancedError::from)?;
+        spec.validate()?;
+        Ok(spec)
+    }
+
+    /// Load a spec from a YAML file
+    pub fn from_yaml_file<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let path_buf = path.as_ref().to_path_buf();
+        let content = std::fs::read_to_string(&path_buf).map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                EnhancedError::file_not_found(&path_buf)
+            } else {
+                EnhancedError::new(format!("Failed to read task spec: {}", e))
+                    .with_file(&path_buf)
+                    .with_category(ErrorCategory::FileNotFound)
+            }
+        })?;
+        Self::from_yaml_str(&content).map_err(|e| e.with_file(&path_buf))
+    }
+
+    /// Check the task name and bounds
+    pub fn validate(&self) -> Result<()> {
+        if default_dims(&self.task).is_none() {
+            return Err(EnhancedError::validation_failed(
+                "task",
+                format!("unknown task '{}'", self.task),
+            )
+            .with_suggestion(format!("Available tasks: {}", TASK_NAMES.join(", "))));
+        }
+        if self.max_steps == Some(0) {
+            return Err(EnhancedError::validation_failed(
+                "max_steps",
+                "must be greater than zero",
+            ));
+        }
+        for (field, space) in [("observation", &self.observation), ("action", &self.action)] {
+            if space.dim == Some(0) {
+                return Err(EnhancedError::validation_failed(
+                    format!("{}.dim", field),
+                    "must be greater than zero",
+                ));
+            }
+            if let (Some(low), Some(high)) = (space.low, space.high) {
+                if low >= high {
+                    return Err(EnhancedError::validation_failed(
+                        field,
+                        format!("low ({}) must be less than high ({})", low, high),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Observation dimension, falling back to the task default
+    pub fn obs_dim(&self) -> usize {
+        self.observation
+            .dim
+            .or_else(|| default_dims(&self.task).map(|(obs, _)| obs))
+            .unwrap_or(10)
+    }
+
+    /// Action dimension, falling back to the task default
+    pub fn action_dim(&self) -> usize {
+        self.action
+            .dim
+            .or_else(|| default_dims(&self.task).map(|(_, act)| act))
+            .unwrap_or(6)
+    }
+
+    /// Observation bounds; unbounded unless the spec says otherwise
+    pub fn observation_bounds(&self) -> (f32, f32) {
+        (
+            self.observation.low.unwrap_or(f32::NEG_INFINITY),
+            self.observation.high.unwrap_or(f32::INFINITY),
+        )
+    }
+
+    /// Action bounds; tasks expect normalized actions in [-1, 1] by default
+    pub fn action_bounds(&self) -> (f32, f32) {
+        (
+            self.action.low.unwrap_or(-1.0),
+            self.action.high.unwrap_or(1.0),
+        )
+    }
+
+    /// Build the task described by this spec, seeding it if a seed is set
+    pub fn build(&self) -> Result<Box<dyn RLTask>> {
+        self.validate()?;
+
+        let (obs_dim, action_dim) = (self.obs_dim(), self.action_dim());
+        let mut task: Box<dyn RLTask> = match self.task.as_str() {
+            "reaching" => Box::new(ReachingTask::new(obs_dim, action_dim)),
+            "balancing" => Box::new(BalancingTask::new(obs_dim, action_dim)),
+            "locomotion" => Box::new(LocomotionTask::new(obs_dim, action_dim)),
+            "navigation" => Box::new(NavigationTask::new(obs_dim, action_dim)),
+            "manipulation" => Box::new(ManipulationTask::new(obs_dim, action_dim)),
+            "push" => Box::new(PushTask::new(obs_dim, action_dim)),
+            _ => unreachable!("task name checked by validate"),
+        };
+
+        if let Some(seed) = self.seed {
+            task.seed(seed);
+        }
+        Ok(task)
+    }
+
+    /// Episode time limit, falling back to the task's own limit
+    pub fn episode_limit(&self, task: &dyn RLTask) -> usize {
+        self.max_steps.unwrap_or(task.config().max_steps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_minimal_spec() {
+        let spec = TaskSpec::from_yaml_str("task: balancing").unwrap();
+        assert_eq!(spec.obs_dim(), 6);
+        assert_eq!(spec.action_dim(), 1);
+        assert_eq!(spec.action_bounds(), (-1.0, 1.0));
+        assert_eq!(spec.seed, None);
+    }
+
+    #[test]
+    fn test_parse_full_spec() {
+        let yaml = r#"
+task: navigation
+max_steps: 200
+seed: 7
+observation:
+  dim: 24
+action:
+  low: -0.5
+  high: 0.5
+"#;
+        let spec = TaskSpec::from_yaml_str(yaml).unwrap();
+        assert_eq!(spec.max_steps, Some(200));
+        assert_eq!(spec.seed, Some(7));
+        assert_eq!(spec.obs_dim(), 24);
+        assert_eq!(spec.action_dim(), 2);
+        assert_eq!(spec.action_bounds(), (-0.5, 0.5));
+    }
+
+    #[test]
+    fn test_rejects_invalid_specs() {
+        assert!(TaskSpec::from_yaml_str("task: juggling").is_err());
+        assert!(TaskSpec::from_yaml_str("task: push\nmax_steps: 0").is_err());
+        assert!(TaskSpec::from_yaml_str("task: push\naction: {low: 1.0, high: -1.0}").is_err());
+    }
+
+    #[test]
+    fn test_default_dims_cover_all_tasks() {
+        for task in TASK_NAMES {
+            assert!(default_dims(task).is_some(), "missing dims for {}", task);
+        }
+    }
+}
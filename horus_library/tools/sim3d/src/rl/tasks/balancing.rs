@@ -1,5 +1,6 @@
 use bevy::prelude::*;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 use crate::physics::rigid_body::RigidBodyComponent;
 use crate::physics::world::PhysicsWorld;
@@ -15,6 +16,7 @@ pub struct BalancingTask {
     cart_entity: Option<Entity>,
     pole_entity: Option<Entity>,
     episode_info: EpisodeInfo,
+    rng: StdRng,
     current_step: usize,
     angle_limit: f32,
     position_limit: f32,
@@ -39,6 +41,7 @@ impl BalancingTask {
             cart_entity: None,
             pole_entity: None,
             episode_info: EpisodeInfo::default(),
+            rng: StdRng::from_entropy(),
             current_step: 0,
             angle_limit,
             position_limit,
@@ -138,6 +141,10 @@ impl RLTask for BalancingTask {
         &self.config
     }
 
+    fn seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
     fn reset(&mut self, world: &mut World) -> Observation {
         // Reset episode info
         self.episode_info = EpisodeInfo::default();
@@ -151,7 +158,7 @@ impl RLTask for BalancingTask {
         // Reset cart to center
         if let Some(entity) = self.cart_entity {
             if let Some(mut transform) = world.get_mut::<Transform>(entity) {
-                let mut rng = rand::thread_rng();
+                let rng = &mut self.rng;
                 // Small random initial position
                 transform.translation.x = rng.gen_range(-0.1..0.1);
                 transform.translation.y = 0.5;
@@ -162,7 +169,7 @@ impl RLTask for BalancingTask {
         // Reset pole to near-vertical with small random perturbation
         if let Some(entity) = self.pole_entity {
             if let Some(mut transform) = world.get_mut::<Transform>(entity) {
-                let mut rng = rand::thread_rng();
+                let rng = &mut self.rng;
                 let small_angle = rng.gen_range(-0.05..0.05); // ~3 degrees
                 transform.rotation = Quat::from_rotation_z(small_angle);
             }
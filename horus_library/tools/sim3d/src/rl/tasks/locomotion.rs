@@ -1,5 +1,6 @@
 use bevy::prelude::*;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 use crate::physics::rigid_body::RigidBodyComponent;
 use crate::physics::world::PhysicsWorld;
@@ -23,6 +24,7 @@ pub struct LocomotionTask {
     max_torques: Vec<f32>,
     target_velocity: Vec3,
     episode_info: EpisodeInfo,
+    rng: StdRng,
     current_step: usize,
     height_limit: f32,
     initial_height: f32,
@@ -53,6 +55,7 @@ impl LocomotionTask {
             max_torques: Vec::new(),
             target_velocity: Vec3::new(1.0, 0.0, 0.0),
             episode_info: EpisodeInfo::default(),
+            rng: StdRng::from_entropy(),
             current_step: 0,
             height_limit,
             initial_height: 1.0,
@@ -161,7 +164,7 @@ impl LocomotionTask {
 
     /// Sample random target velocity
     fn sample_target_velocity(&mut self) {
-        let mut rng = rand::thread_rng();
+        let rng = &mut self.rng;
         let speed = rng.gen_range(0.5..2.0);
         let direction = rng.gen_range(-std::f32::consts::PI..std::f32::consts::PI);
 
@@ -174,6 +177,10 @@ impl RLTask for LocomotionTask {
         &self.config
     }
 
+    fn seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
     fn reset(&mut self, world: &mut World) -> Observation {
         // Reset episode info
         self.episode_info = EpisodeInfo::default();
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 use crate::physics::rigid_body::RigidBodyComponent;
 use crate::physics::world::PhysicsWorld;
@@ -16,6 +17,7 @@ pub struct ManipulationTask {
     object_entity: Option<Entity>,
     target_position: Vec3,
     episode_info: EpisodeInfo,
+    rng: StdRng,
     current_step: usize,
     target_tolerance: f32,
     grasp_threshold: f32,
@@ -43,6 +45,7 @@ impl ManipulationTask {
             object_entity: None,
             target_position: Vec3::ZERO,
             episode_info: EpisodeInfo::default(),
+            rng: StdRng::from_entropy(),
             current_step: 0,
             target_tolerance,
             grasp_threshold,
@@ -53,7 +56,7 @@ impl ManipulationTask {
 
     /// Sample target position for object
     fn sample_target(&mut self) {
-        let mut rng = rand::thread_rng();
+        let rng = &mut self.rng;
         self.target_position = Vec3::new(
             rng.gen_range(-0.5..0.5),
             rng.gen_range(0.5..1.0),
@@ -212,6 +215,10 @@ impl RLTask for ManipulationTask {
         &self.config
     }
 
+    fn seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
     fn reset(&mut self, world: &mut World) -> Observation {
         // Reset episode info
         self.episode_info = EpisodeInfo::default();
@@ -230,7 +237,7 @@ impl RLTask for ManipulationTask {
         // Reset gripper position
         if let Some(entity) = self.gripper_entity {
             if let Some(mut transform) = world.get_mut::<Transform>(entity) {
-                let mut rng = rand::thread_rng();
+                let rng = &mut self.rng;
                 transform.translation =
                     Vec3::new(rng.gen_range(-0.3..0.3), 1.0, rng.gen_range(-0.3..0.3));
             }
@@ -239,7 +246,7 @@ impl RLTask for ManipulationTask {
         // Reset object position if it exists
         if let Some(entity) = self.object_entity {
             if let Some(mut transform) = world.get_mut::<Transform>(entity) {
-                let mut rng = rand::thread_rng();
+                let rng = &mut self.rng;
                 transform.translation =
                     Vec3::new(rng.gen_range(-0.4..0.4), 0.5, rng.gen_range(-0.4..0.4));
             }
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 use crate::physics::rigid_body::RigidBodyComponent;
 use crate::physics::world::PhysicsWorld;
@@ -15,6 +16,7 @@ pub struct NavigationTask {
     robot_entity: Option<Entity>,
     goal_position: Vec3,
     episode_info: EpisodeInfo,
+    rng: StdRng,
     current_step: usize,
     goal_tolerance: f32,
     max_distance: f32,
@@ -42,6 +44,7 @@ impl NavigationTask {
             robot_entity: None,
             goal_position: Vec3::ZERO,
             episode_info: EpisodeInfo::default(),
+            rng: StdRng::from_entropy(),
             current_step: 0,
             goal_tolerance,
             max_distance,
@@ -53,7 +56,7 @@ impl NavigationTask {
 
     /// Sample a random goal position
     fn sample_goal(&mut self) {
-        let mut rng = rand::thread_rng();
+        let rng = &mut self.rng;
         let distance = rng.gen_range(5.0..15.0);
         let angle = rng.gen_range(0.0..std::f32::consts::TAU);
 
@@ -149,6 +152,10 @@ impl RLTask for NavigationTask {
         &self.config
     }
 
+    fn seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
     fn reset(&mut self, world: &mut World) -> Observation {
         // Reset episode info
         self.episode_info = EpisodeInfo::default();
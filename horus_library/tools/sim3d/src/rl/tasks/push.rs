@@ -1,5 +1,6 @@
 use bevy::prelude::*;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 use crate::physics::rigid_body::RigidBodyComponent;
 use crate::physics::world::PhysicsWorld;
@@ -16,6 +17,7 @@ pub struct PushTask {
     object_entity: Option<Entity>,
     target_position: Vec3,
     episode_info: EpisodeInfo,
+    rng: StdRng,
     current_step: usize,
     target_tolerance: f32,
     object_velocity_bonus: f32,
@@ -43,6 +45,7 @@ impl PushTask {
             object_entity: None,
             target_position: Vec3::ZERO,
             episode_info: EpisodeInfo::default(),
+            rng: StdRng::from_entropy(),
             current_step: 0,
             target_tolerance,
             object_velocity_bonus,
@@ -53,7 +56,7 @@ impl PushTask {
 
     /// Sample target position for object
     fn sample_target(&mut self) {
-        let mut rng = rand::thread_rng();
+        let rng = &mut self.rng;
         let distance = rng.gen_range(2.0..5.0);
         let angle = rng.gen_range(0.0..std::f32::consts::TAU);
 
@@ -204,6 +207,10 @@ impl RLTask for PushTask {
         &self.config
     }
 
+    fn seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
     fn reset(&mut self, world: &mut World) -> Observation {
         // Reset episode info
         self.episode_info = EpisodeInfo::default();
@@ -229,7 +236,7 @@ impl RLTask for PushTask {
         // Reset object position
         if let Some(entity) = self.object_entity {
             if let Some(mut transform) = world.get_mut::<Transform>(entity) {
-                let mut rng = rand::thread_rng();
+                let rng = &mut self.rng;
                 transform.translation =
                     Vec3::new(rng.gen_range(-1.0..1.0), 0.5, rng.gen_range(-1.0..1.0));
             }
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 use crate::physics::rigid_body::RigidBodyComponent;
 use crate::rl::{
@@ -14,6 +15,7 @@ pub struct ReachingTask {
     target_position: Vec3,
     end_effector_entity: Option<Entity>,
     episode_info: EpisodeInfo,
+    rng: StdRng,
     current_step: usize,
     initial_distance: f32,
     target_tolerance: f32,
@@ -39,6 +41,7 @@ impl ReachingTask {
             target_position: Vec3::ZERO,
             end_effector_entity: None,
             episode_info: EpisodeInfo::default(),
+            rng: StdRng::from_entropy(),
             current_step: 0,
             initial_distance: 0.0,
             target_tolerance,
@@ -48,7 +51,7 @@ impl ReachingTask {
 
     /// Sample a random target position within workspace
     fn sample_target(&mut self) {
-        let mut rng = rand::thread_rng();
+        let rng = &mut self.rng;
         self.target_position = Vec3::new(
             rng.gen_range(-1.0..1.0),
             rng.gen_range(0.3..1.5),
@@ -100,6 +103,10 @@ impl RLTask for ReachingTask {
         &self.config
     }
 
+    fn seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
     fn reset(&mut self, world: &mut World) -> Observation {
         // Reset episode info
         self.episode_info = EpisodeInfo::default();
@@ -319,6 +326,20 @@ mod tests {
         assert!(task.target_position.z.abs() <= 1.0);
     }
 
+    #[test]
+    fn test_seeded_targets_are_reproducible() {
+        let mut a = ReachingTask::new(10, 6);
+        let mut b = ReachingTask::new(10, 6);
+        a.seed(42);
+        b.seed(42);
+
+        for _ in 0..3 {
+            a.sample_target();
+            b.sample_target();
+            assert_eq!(a.target_position, b.target_position);
+        }
+    }
+
     #[test]
     fn test_observation_size() {
         let task = ReachingTask::new(10, 6);
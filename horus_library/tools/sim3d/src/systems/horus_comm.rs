@@ -10,6 +10,8 @@ use crate::horus_native::HorusComm;
 use crate::physics::diff_drive::CmdVel;
 use crate::physics::rigid_body::RigidBodyComponent;
 use crate::physics::PhysicsWorld;
+use crate::systems::physics_step::physics_step_system;
+use crate::systems::sync_visual::sync_velocities_from_physics_system;
 
 /// Component linking a Bevy entity to a HORUS robot name
 #[derive(Component, Clone)]
@@ -66,10 +68,7 @@ pub fn horus_odom_publish_system(
                     use horus_library::messages::geometry::{Pose2D, Twist};
                     use horus_library::messages::sensor::Odometry;
 
-                    let timestamp = std::time::SystemTime::now()
-                        .duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or_default()
-                        .as_nanos() as u64;
+                    let timestamp = horus_core::core::clock::now_ns();
 
                     let mut odom = Odometry {
                         pose: Pose2D {
@@ -147,10 +146,7 @@ pub fn horus_imu_publish_system(
                         angular_velocity_covariance: [0.0; 9],
                         linear_acceleration: accel,
                         linear_acceleration_covariance: [0.0; 9],
-                        timestamp: std::time::SystemTime::now()
-                            .duration_since(std::time::UNIX_EPOCH)
-                            .unwrap_or_default()
-                            .as_nanos() as u64,
+                        timestamp: horus_core::core::clock::now_ns(),
                     };
 
                     let _ = hub.send(imu, &mut None::<&mut NodeInfo>);
@@ -168,9 +164,10 @@ impl Plugin for HorusCommPlugin {
         app.add_systems(
             Update,
             (
-                horus_cmd_vel_system,
-                horus_odom_publish_system,
-                horus_imu_publish_system,
+                // Commands are applied before the physics step, state is published after it
+                horus_cmd_vel_system.before(physics_step_system),
+                (horus_odom_publish_system, horus_imu_publish_system)
+                    .after(sync_velocities_from_physics_system),
             ),
         );
     }
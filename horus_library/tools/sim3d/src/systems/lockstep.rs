@@ -0,0 +1,57 @@
+//! Lockstep co-simulation with a HORUS scheduler
+//!
+//! With `--lockstep`, physics advances by exactly one `FIXED_DT` per frame
+//! and every frame ends by handing control to a scheduler running
+//! `Scheduler::with_lockstep`. The next frame (and its commands) only starts
+//! once the scheduler has ticked its nodes for the published step.
+
+use bevy::prelude::*;
+use horus_core::scheduling::{LockstepConfig, LockstepDriver};
+use std::time::Duration;
+
+use crate::systems::physics_step::FIXED_DT;
+
+/// Lockstep driver; its presence switches physics to one step per frame
+#[derive(Resource)]
+pub struct SimLockstep {
+    driver: LockstepDriver,
+}
+
+impl SimLockstep {
+    /// Simulated time after the last completed step
+    pub fn sim_time(&self) -> Duration {
+        self.driver.sim_time()
+    }
+}
+
+/// Plugin adding lockstep stepping to a sim3d app
+pub struct LockstepPlugin {
+    config: LockstepConfig,
+}
+
+impl LockstepPlugin {
+    pub fn new(config: LockstepConfig) -> Self {
+        Self { config }
+    }
+}
+
+impl Plugin for LockstepPlugin {
+    fn build(&self, app: &mut App) {
+        match LockstepDriver::new(self.config.clone()) {
+            Ok(driver) => {
+                info!("Lockstep enabled on '{}'", self.config.topic);
+                app.insert_resource(SimLockstep { driver })
+                    .add_systems(PostUpdate, lockstep_system);
+            }
+            Err(e) => error!("Failed to enable lockstep: {}", e),
+        }
+    }
+}
+
+/// Publish the finished step and block until the scheduler acknowledges it
+pub fn lockstep_system(mut lockstep: ResMut<SimLockstep>, mut exit: EventWriter<AppExit>) {
+    if let Err(e) = lockstep.driver.step(Duration::from_secs_f32(FIXED_DT)) {
+        error!("Lockstep stopped: {}", e);
+        exit.send(AppExit::error());
+    }
+}
@@ -1,6 +1,7 @@
 pub mod hframe_update;
 pub mod horus_comm;
 pub mod horus_sync;
+pub mod lockstep;
 pub mod monitor;
 pub mod physics_step;
 pub mod sensor_update;
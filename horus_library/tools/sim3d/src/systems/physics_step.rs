@@ -1,5 +1,11 @@
 use crate::physics::PhysicsWorld;
+use crate::systems::lockstep::SimLockstep;
 use bevy::prelude::*;
+use horus_core::core::{clock, ClockPublisher};
+use std::time::Duration;
+
+/// Fixed physics timestep (240 Hz)
+pub const FIXED_DT: f32 = 1.0 / 240.0;
 
 /// Physics accumulator resource for fixed timestep simulation
 #[derive(Resource, Default)]
@@ -7,16 +13,64 @@ pub struct PhysicsAccumulator {
     pub accumulated_time: f32,
 }
 
+/// Simulated time, published on the `clock` topic once per frame for nodes
+/// running with `use_sim_time`
+#[derive(Resource)]
+pub struct SimTime {
+    steps: u64,
+    publisher: Option<ClockPublisher>,
+}
+
+impl Default for SimTime {
+    fn default() -> Self {
+        let publisher = ClockPublisher::new()
+            .map_err(|e| warn!("Failed to create clock publisher: {}", e))
+            .ok();
+        Self {
+            steps: 0,
+            publisher,
+        }
+    }
+}
+
+impl SimTime {
+    /// Simulated time since the simulation started
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_secs_f64(self.steps as f64 * FIXED_DT as f64)
+    }
+
+    fn publish(&self) {
+        let elapsed = self.elapsed();
+        clock::set_sim_time(elapsed);
+        if let Some(ref publisher) = self.publisher {
+            publisher.publish(elapsed);
+        }
+    }
+}
+
 pub fn physics_step_system(
     mut physics_world: ResMut<PhysicsWorld>,
     mut accumulator: ResMut<PhysicsAccumulator>,
+    mut sim_time: ResMut<SimTime>,
     time: Res<Time>,
+    lockstep: Option<Res<SimLockstep>>,
 ) {
-    const FIXED_DT: f32 = 1.0 / 240.0; // 240 Hz physics
+    // Lockstep: exactly one step per frame, paced by the scheduler
+    if lockstep.is_some() {
+        physics_world.step();
+        sim_time.steps += 1;
+        sim_time.publish();
+        return;
+    }
 
+    let start_steps = sim_time.steps;
     accumulator.accumulated_time += time.delta_secs();
     while accumulator.accumulated_time >= FIXED_DT {
         physics_world.step();
+        sim_time.steps += 1;
         accumulator.accumulated_time -= FIXED_DT;
     }
+    if sim_time.steps != start_steps {
+        sim_time.publish();
+    }
 }
@@ -0,0 +1,213 @@
+//! `#[derive(HorusMessage)]` implementation
+//!
+//! Adds the HORUS message traits to an existing type definition, keeping its
+//! doc comments, serde attributes and impls as written.
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{Attribute, Data, DeriveInput, Error, Fields, Result, Type};
+
+/// Options from `#[horus(...)]` attributes
+#[derive(Default)]
+struct MessageOptions {
+    /// Implement `Pod` and `Zeroable`
+    pod: bool,
+    /// The type implements `LogSummary` itself
+    custom_log_summary: bool,
+}
+
+impl MessageOptions {
+    fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut options = MessageOptions::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("horus")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("pod") {
+                    options.pod = true;
+                    Ok(())
+                } else if meta.path.is_ident("custom_log_summary") {
+                    options.custom_log_summary = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("Unknown horus option. Expected: pod or custom_log_summary"))
+                }
+            })?;
+        }
+        Ok(options)
+    }
+}
+
+pub fn derive_horus_message(input: DeriveInput) -> Result<TokenStream> {
+    let options = MessageOptions::from_attrs(&input.attrs)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let log_summary_impl = if options.custom_log_summary {
+        quote! {}
+    } else {
+        quote! {
+            impl #impl_generics ::horus::core::LogSummary for #name #ty_generics #where_clause {
+                fn log_summary(&self) -> ::std::string::String {
+                    format!("{:?}", self)
+                }
+            }
+        }
+    };
+
+    let schema = schema_of(&input);
+    let fingerprint_impl = quote! {
+        impl #impl_generics ::horus::core::MessageFingerprint for #name #ty_generics #where_clause {
+            const SCHEMA: &'static str = #schema;
+            const FINGERPRINT: u64 = ::horus::core::schema_fingerprint(Self::SCHEMA);
+        }
+    };
+
+    let pod_impl = if options.pod {
+        pod_impl(&input)?
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        #log_summary_impl
+        #fingerprint_impl
+        #pod_impl
+    })
+}
+
+/// `Pod` and `Zeroable` impls, with compile-time checks that every field is
+/// `Pod` and that the layout has no padding bytes
+fn pod_impl(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                name,
+                "#[horus(pod)] is only supported on structs",
+            ))
+        }
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "#[horus(pod)] is not supported on generic types",
+        ));
+    }
+    if !has_stable_repr(&input.attrs) {
+        return Err(Error::new_spanned(
+            name,
+            "#[horus(pod)] requires #[repr(C)] or #[repr(transparent)]",
+        ));
+    }
+
+    let field_types: Vec<&Type> = fields.iter().map(|field| &field.ty).collect();
+    let padding_message = format!("`{}` has padding bytes and cannot be Pod", name);
+
+    Ok(quote! {
+        const _: () = {
+            const fn assert_pod<T: ::bytemuck::Pod>() {}
+            #(assert_pod::<#field_types>();)*
+            assert!(
+                ::std::mem::size_of::<#name>() == 0 #(+ ::std::mem::size_of::<#field_types>())*,
+                #padding_message
+            );
+        };
+
+        // SAFETY: every field is Pod and the fields cover the whole struct
+        // (both checked above), and the repr fixes the layout
+        unsafe impl ::bytemuck::Zeroable for #name {}
+        unsafe impl ::bytemuck::Pod for #name {}
+    })
+}
+
+/// Whether the type has `#[repr(C)]`, `#[repr(transparent)]` or `#[repr(packed)]`
+fn has_stable_repr(attrs: &[Attribute]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("repr"))
+        .any(|attr| {
+            let mut stable = false;
+            let _ = attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("C")
+                    || meta.path.is_ident("transparent")
+                    || meta.path.is_ident("packed")
+                {
+                    stable = true;
+                }
+                // Skip arguments such as `packed(2)` or `align(8)`
+                if meta.input.peek(syn::token::Paren) {
+                    let _content;
+                    syn::parenthesized!(_content in meta.input);
+                }
+                Ok(())
+            });
+            stable
+        })
+}
+
+/// Canonical declaration of the type, e.g. `Pose{x:f64,y:f64}`
+fn schema_of(input: &DeriveInput) -> String {
+    let mut schema = format!(
+        "{}{}",
+        input.ident,
+        compact(input.generics.to_token_stream())
+    );
+    match &input.data {
+        Data::Struct(data) => schema.push_str(&fields_schema(&data.fields)),
+        Data::Enum(data) => {
+            let variants: Vec<String> = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let mut item = format!("{}{}", variant.ident, fields_schema(&variant.fields));
+                    if let Some((_, discriminant)) = &variant.discriminant {
+                        item.push('=');
+                        item.push_str(&compact(discriminant.to_token_stream()));
+                    }
+                    item
+                })
+                .collect();
+            schema = format!("enum {}{{{}}}", schema, variants.join(","));
+        }
+        Data::Union(data) => schema.push_str(&fields_schema(&Fields::Named(data.fields.clone()))),
+    }
+    schema
+}
+
+fn fields_schema(fields: &Fields) -> String {
+    match fields {
+        Fields::Named(named) => {
+            let items: Vec<String> = named
+                .named
+                .iter()
+                .map(|field| {
+                    format!(
+                        "{}:{}",
+                        field.ident.as_ref().expect("named field"),
+                        compact(field.ty.to_token_stream())
+                    )
+                })
+                .collect();
+            format!("{{{}}}", items.join(","))
+        }
+        Fields::Unnamed(unnamed) => {
+            let items: Vec<String> = unnamed
+                .unnamed
+                .iter()
+                .map(|field| compact(field.ty.to_token_stream()))
+                .collect();
+            format!("({})", items.join(","))
+        }
+        Fields::Unit => String::new(),
+    }
+}
+
+/// Token string without whitespace, so formatting does not change the schema
+fn compact(tokens: TokenStream) -> String {
+    tokens
+        .to_string()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect()
+}
@@ -9,8 +9,10 @@
 //!
 //! - `node!` - Generate Node trait implementation with automatic topic registration
 //! - `message!` - Define message types with serialization traits
+//! - `#[derive(HorusMessage)]` - Add message traits to an existing type definition
 //! - `zero_copy_message!` - Define zero-copy messages with compile-time layout verification
 //! - `fixed_string!` - Generate fixed-size string types for zero-copy messages
+//! - `topics!` - Declare the project's topics as typed constants
 //!
 //! ## Safety
 //!
@@ -19,8 +21,10 @@
 
 use proc_macro::TokenStream;
 
+mod derive_message;
 mod message;
 mod node;
+mod topics;
 mod zero_copy;
 
 /// Generate a HORUS node implementation with automatic topic registration.
@@ -64,18 +68,84 @@ mod zero_copy;
 /// - Complete struct definition with Hub fields
 /// - `new()` constructor that creates all Hubs
 /// - `Node` trait implementation
-/// - `Default` trait implementation
+/// - `Default` trait implementation (when no params are required)
 /// - Automatic snake_case node naming
 ///
 /// # Sections
 ///
 /// - `pub {}` - Publishers (optional, can be empty)
 /// - `sub {}` - Subscribers (optional, can be empty)
+/// - `params {}` - Constructor parameters (optional)
 /// - `data {}` - Internal state fields (optional)
+/// - `timers {}` - Periodic callbacks run from tick (optional)
 /// - `tick {}` - Main update logic (required)
 /// - `init(ctx) {}` - Initialization (optional)
 /// - `shutdown(ctx) {}` - Cleanup (optional)
 /// - `impl {}` - Additional methods (optional)
+///
+/// # Generic Nodes and Params
+///
+/// Nodes can be generic over message or configuration types, with bounds in
+/// angle brackets or a `where` clause. Params without a default become
+/// arguments of `new()`; params with a default get a `with_<name>()` setter.
+/// Data defaults can refer to params by name:
+///
+/// ```rust,ignore
+/// node! {
+///     ThresholdFilter<T> where T: PartialOrd + Clone + Debug + Send + Sync + LogSummary + 'static {
+///         pub { passed: T -> "filter.passed" }
+///         sub { input: T -> "filter.input" }
+///
+///         params {
+///             threshold: T,
+///             window: usize = 10,
+///         }
+///
+///         data {
+///             recent: Vec<T> = Vec::with_capacity(window),
+///         }
+///
+///         tick(ctx) {
+///             if let Some(value) = self.input.recv(ctx) {
+///                 if value > self.threshold {
+///                     self.passed.send(value, ctx).ok();
+///                 }
+///             }
+///         }
+///     }
+/// }
+///
+/// let filter = ThresholdFilter::new(0.5_f32).with_window(20);
+/// ```
+///
+/// # Timers
+///
+/// A `timers` section runs callbacks at a fixed period without tracking the
+/// last run by hand. Periods are literals in `s`, `ms`, `us` or `hz`:
+///
+/// ```rust,ignore
+/// node! {
+///     Telemetry {
+///         pub { status: Status -> "robot.status" }
+///
+///         timers {
+///             heartbeat: 1.0s => {
+///                 self.status.send(Status::alive(), ctx).ok();
+///             }
+///             stats: 10hz => {
+///                 self.publish_stats();
+///             }
+///         }
+///
+///         tick(ctx) {}
+///     }
+/// }
+/// ```
+///
+/// Due timers run at the start of `tick`, in declaration order, with access
+/// to `self` (and `ctx` when the tick section takes it). Each runs at most
+/// once per tick, first on the first tick, so timers cannot fire faster than
+/// the node ticks.
 #[proc_macro]
 pub fn node(input: TokenStream) -> TokenStream {
     node::impl_node_macro(input)
@@ -113,6 +183,44 @@ pub fn node(input: TokenStream) -> TokenStream {
 /// }
 /// ```
 ///
+/// ## Enum-style (for modes and states):
+///
+/// ```rust,ignore
+/// message! {
+///     /// Drive mode
+///     enum Mode {
+///         Idle,
+///         Teleop,
+///         Auto,
+///     }
+/// }
+/// ```
+///
+/// Enums must be fieldless. They are `#[repr(u8)]`, default to their first
+/// variant and convert to and from `u8` (`Mode::from_u8`, `TryFrom<u8>`),
+/// so they can be used as fields of other messages instead of raw `u8`
+/// constants.
+///
+/// ## Optional fields
+///
+/// `Option<T>` has no fixed memory layout. Use `Optional<T>` (a validity
+/// flag followed by the value) for fields that may be unset:
+///
+/// ```rust,ignore
+/// message! {
+///     DriveStatus {
+///         mode: Mode,
+///         target_speed: Optional<f32>,
+///     }
+/// }
+/// ```
+///
+/// ## Trace IDs
+///
+/// A struct message with a `trace_id: TraceId` field implements `Traced`, so
+/// nodes can stamp it with `ctx.stamp(&mut msg)` and continue the trace of a
+/// received message with `ctx.trace_from(&msg)`.
+///
 /// # Generated Code
 ///
 /// For `message!(Position = (f32, f32))`, generates:
@@ -154,6 +262,49 @@ pub fn message(input: TokenStream) -> TokenStream {
     TokenStream::from(output)
 }
 
+/// Implement the HORUS message traits for an existing type definition.
+///
+/// Unlike `message!`, the type is written as plain Rust, so doc comments,
+/// serde attributes, other derives and impls compose as usual:
+///
+/// ```rust,ignore
+/// use horus::prelude::*;
+///
+/// /// Wheel odometry from the motor controller
+/// #[derive(Debug, Clone, Copy, Serialize, Deserialize, HorusMessage)]
+/// #[repr(C)]
+/// #[horus(pod)]
+/// pub struct WheelOdometry {
+///     #[serde(rename = "ts")]
+///     pub timestamp_ns: u64,
+///     pub left_ticks: i32,
+///     pub right_ticks: i32,
+/// }
+///
+/// assert_eq!(WheelOdometry::SCHEMA, "WheelOdometry{timestamp_ns:u64,left_ticks:i32,right_ticks:i32}");
+/// ```
+///
+/// # Generated Code
+///
+/// - `LogSummary` using `Debug` (skip with `#[horus(custom_log_summary)]`
+///   to implement it by hand, e.g. for large messages)
+/// - `MessageFingerprint`: `SCHEMA`, the declaration in canonical form, and
+///   `FINGERPRINT`, its 64-bit hash, for checking that two processes agree
+///   on a message layout
+/// - With `#[horus(pod)]`: `Pod` and `Zeroable`. The struct must be `Copy`
+///   and `#[repr(C)]` or `#[repr(transparent)]`; compilation fails if a field
+///   is not `Pod` or the layout has padding bytes
+///
+/// Structs, enums and generic types are supported; `#[horus(pod)]` is
+/// limited to non-generic structs.
+#[proc_macro_derive(HorusMessage, attributes(horus))]
+pub fn derive_horus_message(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    derive_message::derive_horus_message(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
 /// Define a zero-copy message with compile-time verified layout.
 ///
 /// This macro creates message types optimized for high-performance recording:
@@ -208,6 +359,8 @@ pub fn message(input: TokenStream) -> TokenStream {
 /// - `Default` impl with zeroed values
 /// - `SIZE` constant for compile-time size
 /// - `as_bytes()` and `from_bytes()` for zero-copy access
+/// - `as_trimmed_bytes()` and `from_trimmed_bytes()`, which leave the unused
+///   capacity of a trailing `BoundedVec` off the wire
 /// - `LogSummary` impl for logging
 /// - `Serialize` impl for JSON/MessagePack compatibility
 ///
@@ -218,6 +371,29 @@ pub fn message(input: TokenStream) -> TokenStream {
 /// - Floats: `f32`, `f64`
 /// - Arrays: `[T; N]` where T is a primitive
 /// - Fixed strings: Use `FixedString<N>` (see `fixed_string!` macro)
+/// - Bounded sequences: `BoundedVec<T, N>` holds up to `N` Pod elements
+///
+/// # Variable-Length Data
+///
+/// A `BoundedVec<T, N>` field stores a length plus room for `N` elements, so
+/// the message stays Pod. Declared as the last field, only the used elements
+/// need to be sent:
+///
+/// ```rust,ignore
+/// zero_copy_message! {
+///     Detections {
+///         timestamp_ns: u64,
+///         objects: BoundedVec<Detection, 256>,
+///     }
+/// }
+///
+/// let bytes = msg.as_trimmed_bytes(); // header + used objects only
+/// let restored = Detections::from_trimmed_bytes(bytes).unwrap();
+/// ```
+///
+/// The layout of every `BoundedVec` field is checked at compile time, so
+/// elements with an alignment above 8 (which would leave padding) are
+/// rejected.
 ///
 /// # Performance
 ///
@@ -274,3 +450,37 @@ pub fn fixed_string(input: TokenStream) -> TokenStream {
     let output = zero_copy::generate_fixed_string(input);
     TokenStream::from(output)
 }
+
+/// Declare a project's topic registry.
+///
+/// Each entry binds a topic name to its message type. The macro generates a
+/// `topics` module with one `Topic<T>` constant per entry, named after the
+/// topic (`"sensors.lidar"` becomes `topics::SENSORS_LIDAR`), and a
+/// `topics::ALL` list of every name and type:
+///
+/// ```rust,ignore
+/// use horus::prelude::*;
+///
+/// topics! {
+///     /// Scans from the front lidar
+///     "sensors.lidar": LaserScan,
+///     "motors.cmd_vel": CmdVel,
+/// }
+///
+/// let scans = Hub::new(topics::SENSORS_LIDAR)?; // Hub<LaserScan>
+/// let cmd: Hub<CmdVel> = Hub::new(topics::SENSORS_LIDAR)?; // compile error: wrong type
+/// ```
+///
+/// Topic names are checked at compile time: segments separated by `.` or
+/// `/` made of ASCII letters, digits, `_` and `-`, without duplicates.
+/// Endpoints such as `@router` are not part of the registry.
+///
+/// `horus check` reads the registry and reports string topics in Rust and
+/// Python code that are missing from it or used with another message type.
+#[proc_macro]
+pub fn topics(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as topics::TopicsInput);
+    topics::generate_topics(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
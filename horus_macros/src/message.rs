@@ -8,22 +8,66 @@ use syn::{
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     token::Comma,
-    Field, Ident, Result, Token, Type,
+    Attribute, Field, Fields, Ident, Result, Token, Type, Variant,
 };
 
 /// Parse either tuple-style or struct-style message definition
 pub enum MessageInput {
     /// Tuple-style: `Position = (f32, f32)`
-    Tuple { name: Ident, types: Vec<Type> },
+    Tuple {
+        attrs: Vec<Attribute>,
+        name: Ident,
+        types: Vec<Type>,
+    },
     /// Struct-style: `MyMessage { x: u8, y: u8 }`
     Struct {
+        attrs: Vec<Attribute>,
+        name: Ident,
+        fields: Vec<Field>,
+    },
+    /// Enum-style: `enum Mode { Idle, Teleop, Auto }`
+    Enum {
+        attrs: Vec<Attribute>,
         name: Ident,
-        fields: Vec<(Ident, Type)>,
+        variants: Vec<Variant>,
     },
 }
 
 impl Parse for MessageInput {
     fn parse(input: ParseStream) -> Result<Self> {
+        // Parse attributes (doc comments, etc.)
+        let attrs = input.call(Attribute::parse_outer)?;
+
+        if input.peek(Token![enum]) {
+            // Enum-style: enum Mode { Idle, Teleop, Auto }
+            input.parse::<Token![enum]>()?;
+            let name: Ident = input.parse()?;
+
+            let content;
+            syn::braced!(content in input);
+
+            let variants: Punctuated<Variant, Comma> =
+                content.parse_terminated(Variant::parse, Token![,])?;
+            if variants.is_empty() {
+                return Err(syn::Error::new(
+                    name.span(),
+                    "message enums need at least one variant",
+                ));
+            }
+            if let Some(variant) = variants.iter().find(|v| !matches!(v.fields, Fields::Unit)) {
+                return Err(syn::Error::new_spanned(
+                    &variant.fields,
+                    "message enums must be fieldless; use a struct message for data",
+                ));
+            }
+
+            return Ok(MessageInput::Enum {
+                attrs,
+                name,
+                variants: variants.into_iter().collect(),
+            });
+        }
+
         let name: Ident = input.parse()?;
 
         // Check if it's tuple-style (with =) or struct-style (with {)
@@ -38,7 +82,7 @@ impl Parse for MessageInput {
                 content.parse_terminated(Type::parse, Token![,])?;
             let types: Vec<Type> = types.into_iter().collect();
 
-            Ok(MessageInput::Tuple { name, types })
+            Ok(MessageInput::Tuple { attrs, name, types })
         } else {
             // Struct-style: MyMessage { x: u8, y: u8 }
             let content;
@@ -47,12 +91,11 @@ impl Parse for MessageInput {
             let fields: Punctuated<Field, Comma> =
                 content.parse_terminated(Field::parse_named, Token![,])?;
 
-            let fields: Vec<(Ident, Type)> = fields
-                .into_iter()
-                .map(|f| (f.ident.unwrap(), f.ty))
-                .collect();
-
-            Ok(MessageInput::Struct { name, fields })
+            Ok(MessageInput::Struct {
+                attrs,
+                name,
+                fields: fields.into_iter().collect(),
+            })
         }
     }
 }
@@ -60,18 +103,28 @@ impl Parse for MessageInput {
 /// Generate the complete message implementation
 pub fn generate_message(input: MessageInput) -> TokenStream {
     match input {
-        MessageInput::Tuple { name, types } => generate_tuple_message(name, types),
-        MessageInput::Struct { name, fields } => generate_struct_message(name, fields),
+        MessageInput::Tuple { attrs, name, types } => generate_tuple_message(attrs, name, types),
+        MessageInput::Struct {
+            attrs,
+            name,
+            fields,
+        } => generate_struct_message(attrs, name, fields),
+        MessageInput::Enum {
+            attrs,
+            name,
+            variants,
+        } => generate_enum_message(attrs, name, variants),
     }
 }
 
 /// Generate a tuple-style message
-fn generate_tuple_message(name: Ident, types: Vec<Type>) -> TokenStream {
+fn generate_tuple_message(attrs: Vec<Attribute>, name: Ident, types: Vec<Type>) -> TokenStream {
     let field_list = types.iter().map(|ty| {
         quote! { pub #ty }
     });
 
     quote! {
+        #(#attrs)*
         #[derive(Debug, Clone, ::horus::serde::Serialize, ::horus::serde::Deserialize)]
         #[repr(C)]
         pub struct #name(#(#field_list),*);
@@ -86,18 +139,51 @@ fn generate_tuple_message(name: Ident, types: Vec<Type>) -> TokenStream {
 }
 
 /// Generate a struct-style message with named fields
-fn generate_struct_message(name: Ident, fields: Vec<(Ident, Type)>) -> TokenStream {
-    let field_defs = fields.iter().map(|(field_name, field_type)| {
-        quote! { pub #field_name: #field_type }
+fn generate_struct_message(attrs: Vec<Attribute>, name: Ident, fields: Vec<Field>) -> TokenStream {
+    let field_defs = fields.iter().map(|field| {
+        let field_attrs = &field.attrs;
+        let field_name = &field.ident;
+        let field_type = &field.ty;
+        quote! {
+            #(#field_attrs)*
+            pub #field_name: #field_type
+        }
     });
 
+    // A `trace_id: TraceId` field makes the message traceable
+    let traced_impl = fields
+        .iter()
+        .find(|field| {
+            field
+                .ident
+                .as_ref()
+                .is_some_and(|ident| ident == "trace_id")
+        })
+        .filter(|field| is_trace_id_type(&field.ty))
+        .map(|_| {
+            quote! {
+                impl ::horus::core::Traced for #name {
+                    fn trace_id(&self) -> ::horus::core::TraceId {
+                        self.trace_id
+                    }
+
+                    fn set_trace_id(&mut self, trace_id: ::horus::core::TraceId) {
+                        self.trace_id = trace_id;
+                    }
+                }
+            }
+        });
+
     quote! {
+        #(#attrs)*
         #[derive(Debug, Clone, ::horus::serde::Serialize, ::horus::serde::Deserialize)]
         #[repr(C)]
         pub struct #name {
             #(#field_defs),*
         }
 
+        #traced_impl
+
         // Auto-implement LogSummary using Debug
         // Uses the LogSummary trait that should be in scope from horus::prelude::*
         impl ::horus::core::LogSummary for #name {
@@ -111,3 +197,84 @@ fn generate_struct_message(name: Ident, fields: Vec<(Ident, Type)>) -> TokenStre
         // If they don't, users can still use the type, just without zero-copy optimization
     }
 }
+
+/// Whether a field type is `TraceId` (by its last path segment)
+fn is_trace_id_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "TraceId"),
+        _ => false,
+    }
+}
+
+/// Generate a fieldless `#[repr(u8)]` enum message
+fn generate_enum_message(
+    attrs: Vec<Attribute>,
+    name: Ident,
+    variants: Vec<Variant>,
+) -> TokenStream {
+    let variant_names: Vec<&Ident> = variants.iter().map(|v| &v.ident).collect();
+    let first = variant_names[0];
+
+    quote! {
+        #(#attrs)*
+        #[derive(
+            Debug,
+            Clone,
+            Copy,
+            PartialEq,
+            Eq,
+            Hash,
+            ::horus::serde::Serialize,
+            ::horus::serde::Deserialize,
+        )]
+        #[repr(u8)]
+        pub enum #name {
+            #(#variants),*
+        }
+
+        impl #name {
+            /// All variants in declaration order
+            pub const VARIANTS: &'static [#name] = &[#(#name::#variant_names),*];
+
+            /// Wire representation
+            pub const fn as_u8(self) -> u8 {
+                self as u8
+            }
+
+            /// Decode the wire representation, `None` for unknown values
+            pub fn from_u8(value: u8) -> ::std::option::Option<Self> {
+                Self::VARIANTS.iter().copied().find(|v| *v as u8 == value)
+            }
+        }
+
+        impl ::std::default::Default for #name {
+            fn default() -> Self {
+                #name::#first
+            }
+        }
+
+        impl ::std::convert::From<#name> for u8 {
+            fn from(value: #name) -> u8 {
+                value as u8
+            }
+        }
+
+        impl ::std::convert::TryFrom<u8> for #name {
+            type Error = u8;
+
+            fn try_from(value: u8) -> ::std::result::Result<Self, u8> {
+                Self::from_u8(value).ok_or(value)
+            }
+        }
+
+        impl ::horus::core::LogSummary for #name {
+            fn log_summary(&self) -> ::std::string::String {
+                format!("{:?}", self)
+            }
+        }
+    }
+}
@@ -3,7 +3,7 @@ use quote::quote;
 use syn::{
     braced, parenthesized,
     parse::{Parse, ParseStream},
-    parse_macro_input, Block, Error, Expr, Ident, Result, Token, Type,
+    parse_macro_input, Block, Error, Expr, GenericParam, Generics, Ident, Result, Token, Type,
 };
 
 /// Represents a topic definition in pub/sub sections
@@ -23,6 +23,12 @@ struct DataField {
     default: Option<(Token![=], Expr)>,
 }
 
+/// Params section for constructor parameters
+struct ParamsSection {
+    _params_token: Ident, // "params" keyword
+    fields: Vec<DataField>,
+}
+
 /// Publisher section
 struct PubSection {
     _pub_token: Ident, // "pub" keyword
@@ -62,6 +68,19 @@ struct ShutdownSection {
     body: Block,
 }
 
+/// Timers section for periodic callbacks run from tick
+struct TimersSection {
+    _timers_token: Ident, // "timers" keyword
+    timers: Vec<TimerDef>,
+}
+
+/// A periodic callback: `heartbeat: 1.0s => { ... }`
+struct TimerDef {
+    name: Ident,
+    period_ns: u64,
+    body: Block,
+}
+
 /// Optional impl block for additional methods
 struct ImplSection {
     _impl_token: Token![impl],
@@ -84,11 +103,14 @@ struct NameSection {
 /// The complete node definition
 struct NodeDef {
     name: Ident,
-    name_section: Option<NameSection>, // Optional explicit node name override
+    generics: Generics,                    // Optional generics and where clause
+    params_section: Option<ParamsSection>, // Optional constructor parameters
+    name_section: Option<NameSection>,     // Optional explicit node name override
     pub_section: Option<PubSection>,
     sub_section: Option<SubSection>,
     data_section: Option<DataSection>,
-    tick_section: TickSection, // Required
+    timers_section: Option<TimersSection>, // Optional periodic callbacks
+    tick_section: TickSection,             // Required
     init_section: Option<InitSection>,
     shutdown_section: Option<ShutdownSection>,
     impl_section: Option<ImplSection>,
@@ -100,13 +122,19 @@ impl Parse for NodeDef {
         // Parse node name
         let name: Ident = input.parse()?;
 
+        // Parse optional generics: ThresholdFilter<T: Clone> where T: Debug
+        let mut generics: Generics = input.parse()?;
+        generics.where_clause = input.parse()?;
+
         let content;
         braced!(content in input);
 
         let mut name_section = None;
+        let mut params_section = None;
         let mut pub_section = None;
         let mut sub_section = None;
         let mut data_section = None;
+        let mut timers_section = None;
         let mut tick_section = None;
         let mut init_section = None;
         let mut shutdown_section = None;
@@ -136,6 +164,15 @@ impl Parse for NodeDef {
                         }
                         sub_section = Some(parse_sub_section(&content, section_name)?);
                     }
+                    "params" => {
+                        if params_section.is_some() {
+                            return Err(Error::new(
+                                section_name.span(),
+                                "Duplicate 'params' section",
+                            ));
+                        }
+                        params_section = Some(parse_params_section(&content, section_name)?);
+                    }
                     "data" => {
                         if data_section.is_some() {
                             return Err(Error::new(
@@ -145,6 +182,15 @@ impl Parse for NodeDef {
                         }
                         data_section = Some(parse_data_section(&content, section_name)?);
                     }
+                    "timers" => {
+                        if timers_section.is_some() {
+                            return Err(Error::new(
+                                section_name.span(),
+                                "Duplicate 'timers' section",
+                            ));
+                        }
+                        timers_section = Some(parse_timers_section(&content, section_name)?);
+                    }
                     "tick" => {
                         if tick_section.is_some() {
                             return Err(Error::new(
@@ -192,7 +238,7 @@ impl Parse for NodeDef {
                     }
                     _ => {
                         return Err(Error::new(section_name.span(),
-                            format!("Unknown section '{}'. Expected: pub, sub, data, tick, init, shutdown, rate, name, or impl", section_str)));
+                            format!("Unknown section '{}'. Expected: pub, sub, params, data, timers, tick, init, shutdown, rate, name, or impl", section_str)));
                     }
                 }
             } else if lookahead.peek(Token![impl]) {
@@ -211,10 +257,13 @@ impl Parse for NodeDef {
 
         Ok(NodeDef {
             name,
+            generics,
+            params_section,
             name_section,
             pub_section,
             sub_section,
             data_section,
+            timers_section,
             tick_section,
             init_section,
             shutdown_section,
@@ -289,7 +338,26 @@ fn parse_sub_section(input: ParseStream, sub_token: Ident) -> Result<SubSection>
     })
 }
 
+fn parse_params_section(input: ParseStream, params_token: Ident) -> Result<ParamsSection> {
+    // Same syntax as data: `name: Type` or `name: Type = default`
+    let fields = parse_fields(input)?;
+
+    Ok(ParamsSection {
+        _params_token: params_token,
+        fields,
+    })
+}
+
 fn parse_data_section(input: ParseStream, data_token: Ident) -> Result<DataSection> {
+    let fields = parse_fields(input)?;
+
+    Ok(DataSection {
+        _data_token: data_token,
+        fields,
+    })
+}
+
+fn parse_fields(input: ParseStream) -> Result<Vec<DataField>> {
     let content;
     braced!(content in input);
 
@@ -320,12 +388,74 @@ fn parse_data_section(input: ParseStream, data_token: Ident) -> Result<DataSecti
         }
     }
 
-    Ok(DataSection {
-        _data_token: data_token,
-        fields,
+    Ok(fields)
+}
+
+fn parse_timers_section(input: ParseStream, timers_token: Ident) -> Result<TimersSection> {
+    let content;
+    braced!(content in input);
+
+    let mut timers: Vec<TimerDef> = Vec::new();
+
+    while !content.is_empty() {
+        let name: Ident = content.parse()?;
+        if timers.iter().any(|timer| timer.name == name) {
+            return Err(Error::new(
+                name.span(),
+                format!("Duplicate timer '{}'", name),
+            ));
+        }
+        content.parse::<Token![:]>()?;
+        let period_ns = parse_timer_period(&content)?;
+        content.parse::<Token![=>]>()?;
+        let body: Block = content.parse()?;
+
+        timers.push(TimerDef {
+            name,
+            period_ns,
+            body,
+        });
+
+        if content.peek(Token![,]) {
+            content.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(TimersSection {
+        _timers_token: timers_token,
+        timers,
     })
 }
 
+/// Parse a period literal with a unit suffix: `1.0s`, `100ms`, `500us` or `10hz`
+fn parse_timer_period(input: ParseStream) -> Result<u64> {
+    let lit: syn::Lit = input.parse()?;
+    let (value, suffix) = match &lit {
+        syn::Lit::Float(float) => (float.base10_parse::<f64>()?, float.suffix()),
+        syn::Lit::Int(int) => (int.base10_parse::<u64>()? as f64, int.suffix()),
+        _ => (0.0, ""),
+    };
+
+    let period_secs = match suffix {
+        "s" => value,
+        "ms" => value / 1e3,
+        "us" => value / 1e6,
+        "hz" | "Hz" if value > 0.0 => 1.0 / value,
+        _ => {
+            return Err(Error::new(
+                lit.span(),
+                "Expected a timer period like `1.0s`, `100ms`, `500us` or `10hz`",
+            ))
+        }
+    };
+
+    let period_ns = (period_secs * 1e9).round();
+    if !(1.0..=u64::MAX as f64).contains(&period_ns) {
+        return Err(Error::new(lit.span(), "Timer period must be positive"));
+    }
+    Ok(period_ns as u64)
+}
+
 fn parse_tick_section(input: ParseStream, tick_token: Ident) -> Result<TickSection> {
     // Check for optional (ctx) argument
     let ctx_arg = if input.peek(syn::token::Paren) {
@@ -422,6 +552,8 @@ pub fn impl_node_macro(input: TokenStream) -> TokenStream {
     let node_def = parse_macro_input!(input as NodeDef);
 
     let struct_name = &node_def.name;
+    let generics = &node_def.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     // Use explicit name if provided, otherwise auto-generate from struct name
     let node_name_str = if let Some(ref name_section) = node_def.name_section {
@@ -455,15 +587,55 @@ pub fn impl_node_macro(input: TokenStream) -> TokenStream {
         }
     }
 
-    // Add data fields
-    if let Some(ref data_section) = node_def.data_section {
-        for field in &data_section.fields {
-            let name = &field.name;
-            let ty = &field.ty;
-            struct_fields.push(quote! {
-                #name: #ty
-            });
-        }
+    // Add params and data fields
+    let params_fields = node_def
+        .params_section
+        .as_ref()
+        .map_or(&[][..], |section| &section.fields[..]);
+    let data_fields = node_def
+        .data_section
+        .as_ref()
+        .map_or(&[][..], |section| &section.fields[..]);
+    for field in params_fields.iter().chain(data_fields) {
+        let name = &field.name;
+        let ty = &field.ty;
+        struct_fields.push(quote! {
+            #name: #ty
+        });
+    }
+
+    // Each timer keeps the deadline of its next run; `None` runs on the first tick
+    let timers = node_def
+        .timers_section
+        .as_ref()
+        .map_or(&[][..], |section| &section.timers[..]);
+    let timer_fields: Vec<_> = timers
+        .iter()
+        .map(|timer| syn::Ident::new(&format!("_timer_{}", timer.name), timer.name.span()))
+        .collect();
+    for field in &timer_fields {
+        struct_fields.push(quote! {
+            #field: ::std::option::Option<::std::time::Instant>
+        });
+    }
+
+    // Generic parameters may only appear in method bodies, so mark them as used
+    let has_generics = !generics.params.is_empty();
+    if has_generics {
+        let marker_types = generics.params.iter().filter_map(|param| match param {
+            GenericParam::Type(ty) => {
+                let ident = &ty.ident;
+                Some(quote! { fn() -> #ident })
+            }
+            GenericParam::Lifetime(lt) => {
+                let lifetime = &lt.lifetime;
+                Some(quote! { &#lifetime () })
+            }
+            GenericParam::Const(_) => None,
+        });
+        struct_fields.push(quote! {
+            _marker: ::std::marker::PhantomData<(#(#marker_types,)*)>
+        });
     }
 
     // Generate constructor field initializations
@@ -493,33 +665,90 @@ pub fn impl_node_macro(input: TokenStream) -> TokenStream {
         }
     }
 
-    // Initialize data fields
-    if let Some(ref data_section) = node_def.data_section {
-        for field in &data_section.fields {
-            let name = &field.name;
-            if let Some((_, ref default_expr)) = field.default {
-                constructor_fields.push(quote! {
-                    #name: #default_expr
-                });
-            } else {
-                constructor_fields.push(quote! {
-                    #name: Default::default()
+    // Params without a default become constructor arguments. Defaulted params
+    // and data fields are bound first, so data defaults can use any param.
+    let mut constructor_args = Vec::new();
+    let mut constructor_bindings = Vec::new();
+    let mut param_setters = Vec::new();
+    for field in params_fields {
+        let name = &field.name;
+        let ty = &field.ty;
+        match field.default {
+            Some((_, ref default_expr)) => {
+                constructor_bindings.push(quote! { let #name: #ty = #default_expr; });
+                let setter = syn::Ident::new(&format!("with_{}", name), name.span());
+                param_setters.push(quote! {
+                    pub fn #setter(mut self, #name: #ty) -> Self {
+                        self.#name = #name;
+                        self
+                    }
                 });
             }
+            None => constructor_args.push(quote! { #name: #ty }),
+        }
+        constructor_fields.push(quote! { #name });
+    }
+
+    // Initialize data fields
+    for field in data_fields {
+        let name = &field.name;
+        let ty = &field.ty;
+        if let Some((_, ref default_expr)) = field.default {
+            constructor_bindings.push(quote! { let #name: #ty = #default_expr; });
+        } else {
+            constructor_bindings.push(quote! { let #name: #ty = Default::default(); });
         }
+        constructor_fields.push(quote! { #name });
+    }
+
+    for field in &timer_fields {
+        constructor_fields.push(quote! { #field: ::std::option::Option::None });
     }
 
+    if has_generics {
+        constructor_fields.push(quote! { _marker: ::std::marker::PhantomData });
+    }
+
+    // Due timers run before the tick body. Deadlines advance by whole periods
+    // so the rate does not drift, and restart from now after a stall.
+    let timer_runs = timers.iter().zip(&timer_fields).map(|(timer, field)| {
+        let period_ns = timer.period_ns;
+        let body = &timer.body;
+        quote! {
+            if self.#field.is_none_or(|deadline| __timer_now >= deadline) {
+                let __period = ::std::time::Duration::from_nanos(#period_ns);
+                self.#field = ::std::option::Option::Some(match self.#field {
+                    ::std::option::Option::Some(deadline) if __timer_now < deadline + __period => {
+                        deadline + __period
+                    }
+                    _ => __timer_now + __period,
+                });
+                #body
+            }
+        }
+    });
+    let timers_prelude = if timers.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            let __timer_now = ::std::time::Instant::now();
+            #(#timer_runs)*
+        }
+    };
+
     // Generate tick implementation
     let tick_body = &node_def.tick_section.body;
     let tick_impl = if node_def.tick_section.ctx_arg.is_some() {
         quote! {
             fn tick(&mut self, mut ctx: Option<&mut horus_core::core::NodeInfo>) {
+                #timers_prelude
                 #tick_body
             }
         }
     } else {
         quote! {
             fn tick(&mut self, _ctx: Option<&mut horus_core::core::NodeInfo>) {
+                #timers_prelude
                 #tick_body
             }
         }
@@ -569,7 +798,7 @@ pub fn impl_node_macro(input: TokenStream) -> TokenStream {
     let impl_methods = if let Some(ref impl_section) = node_def.impl_section {
         let impl_body = &impl_section.body;
         quote! {
-            impl #struct_name #impl_body
+            impl #impl_generics #struct_name #ty_generics #where_clause #impl_body
         }
     } else {
         quote! {}
@@ -637,21 +866,38 @@ pub fn impl_node_macro(input: TokenStream) -> TokenStream {
         quote! {}
     };
 
+    // Default is only possible when every param has a default
+    let default_impl = if constructor_args.is_empty() {
+        quote! {
+            impl #impl_generics Default for #struct_name #ty_generics #where_clause {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     // Generate the complete output
     let expanded = quote! {
-        pub struct #struct_name {
+        pub struct #struct_name #generics #where_clause {
             #(#struct_fields,)*
         }
 
-        impl #struct_name {
-            pub fn new() -> Self {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #[allow(clippy::too_many_arguments)]
+            pub fn new(#(#constructor_args),*) -> Self {
+                #(#constructor_bindings)*
                 Self {
                     #(#constructor_fields,)*
                 }
             }
+
+            #(#param_setters)*
         }
 
-        impl horus_core::core::node::Node for #struct_name {
+        impl #impl_generics horus_core::core::node::Node for #struct_name #ty_generics #where_clause {
             fn name(&self) -> &'static str {
                 #node_name_str
             }
@@ -664,11 +910,7 @@ pub fn impl_node_macro(input: TokenStream) -> TokenStream {
             #rate_impl
         }
 
-        impl Default for #struct_name {
-            fn default() -> Self {
-                Self::new()
-            }
-        }
+        #default_impl
 
         #impl_methods
     };
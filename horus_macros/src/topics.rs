@@ -0,0 +1,155 @@
+//! Topic registry macro implementation
+//!
+//! Provides the `topics!` macro, which declares a project's topics with their
+//! message types and generates typed constants in a `topics` module.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use std::collections::HashMap;
+use syn::{
+    parse::{Parse, ParseStream},
+    Attribute, Error, Ident, LitStr, Result, Token, Type,
+};
+
+/// A registry entry: `"sensors.lidar": LaserScan`
+struct TopicEntry {
+    attrs: Vec<Attribute>,
+    name: LitStr,
+    ty: Type,
+}
+
+pub struct TopicsInput {
+    entries: Vec<TopicEntry>,
+}
+
+impl Parse for TopicsInput {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut entries = Vec::new();
+
+        while !input.is_empty() {
+            let attrs = input.call(Attribute::parse_outer)?;
+            let name: LitStr = input.parse()?;
+            input.parse::<Token![:]>()?;
+            let ty: Type = input.parse()?;
+
+            entries.push(TopicEntry { attrs, name, ty });
+
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(TopicsInput { entries })
+    }
+}
+
+pub fn generate_topics(input: TopicsInput) -> Result<TokenStream> {
+    let mut seen_topics: HashMap<String, &LitStr> = HashMap::new();
+    let mut seen_consts: HashMap<String, &LitStr> = HashMap::new();
+    let mut constants = Vec::new();
+    let mut infos = Vec::new();
+
+    for entry in &input.entries {
+        let topic = entry.name.value();
+        validate_topic_name(&topic).map_err(|msg| Error::new(entry.name.span(), msg))?;
+
+        if seen_topics.insert(topic.clone(), &entry.name).is_some() {
+            return Err(Error::new(
+                entry.name.span(),
+                format!("Duplicate topic '{}'", topic),
+            ));
+        }
+
+        let const_name = constant_name(&topic);
+        if const_name == "ALL" {
+            return Err(Error::new(
+                entry.name.span(),
+                "Topic 'all' would shadow the generated `topics::ALL` list",
+            ));
+        }
+        if let Some(other) = seen_consts.insert(const_name.clone(), &entry.name) {
+            return Err(Error::new(
+                entry.name.span(),
+                format!(
+                    "Topics '{}' and '{}' both map to the constant {}",
+                    other.value(),
+                    topic,
+                    const_name
+                ),
+            ));
+        }
+
+        let attrs = &entry.attrs;
+        let ty = &entry.ty;
+        let const_ident = Ident::new(&const_name, entry.name.span());
+        let name = &entry.name;
+        let type_name = quote!(#ty).to_string().replace(' ', "");
+        constants.push(quote! {
+            #(#attrs)*
+            pub const #const_ident: ::horus::communication::Topic<#ty> =
+                ::horus::communication::Topic::new(#name);
+        });
+        infos.push(quote! {
+            ::horus::communication::TopicInfo {
+                name: #name,
+                type_name: #type_name,
+            }
+        });
+    }
+
+    Ok(quote! {
+        /// Topic registry declared with `topics!`
+        pub mod topics {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #(#constants)*
+
+            /// Every topic in the registry
+            pub const ALL: &[::horus::communication::TopicInfo] = &[#(#infos),*];
+        }
+    })
+}
+
+/// Check a topic name: dot or slash separated segments of ASCII letters,
+/// digits, `_` and `-`
+fn validate_topic_name(topic: &str) -> std::result::Result<(), String> {
+    if topic.is_empty() {
+        return Err("Topic name cannot be empty".to_string());
+    }
+    if let Some(c) = topic
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '/')))
+    {
+        return Err(format!(
+            "Invalid character {:?} in topic '{}' (endpoints like '@router' are chosen when creating the Hub)",
+            c, topic
+        ));
+    }
+    if topic.split(['.', '/']).any(str::is_empty) {
+        return Err(format!(
+            "Topic '{}' has an empty segment (leading, trailing or doubled separator)",
+            topic
+        ));
+    }
+    Ok(())
+}
+
+/// `sensors.lidar` → `SENSORS_LIDAR`
+fn constant_name(topic: &str) -> String {
+    let name: String = topic
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
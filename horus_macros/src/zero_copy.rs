@@ -8,6 +8,7 @@
 //! - **Compile-time size calculation**: Know exact message size at compile time
 //! - **Memory alignment guarantees**: Proper alignment for zero-copy access
 //! - **Fixed-size strings**: Predictable memory layout with bounded strings
+//! - **Bounded sequences**: `BoundedVec<T, N>` fields, trimmed on the wire when last
 //! - **bytemuck integration**: Automatic Pod + Zeroable derivation
 //! - **Endianness aware**: Optional field for cross-platform compatibility
 //!
@@ -44,6 +45,7 @@ pub struct ZeroCopyMessageInput {
 }
 
 pub struct ZeroCopyField {
+    pub attrs: Vec<Attribute>,
     pub name: Ident,
     pub ty: Type,
 }
@@ -66,6 +68,7 @@ impl Parse for ZeroCopyMessageInput {
         let fields: Vec<ZeroCopyField> = fields
             .into_iter()
             .map(|f| ZeroCopyField {
+                attrs: f.attrs,
                 name: f.ident.unwrap(),
                 ty: f.ty,
             })
@@ -88,9 +91,13 @@ pub fn generate_zero_copy_message(input: ZeroCopyMessageInput) -> TokenStream {
     } = input;
 
     let field_defs = fields.iter().map(|f| {
+        let field_attrs = &f.attrs;
         let field_name = &f.name;
         let field_type = &f.ty;
-        quote! { pub #field_name: #field_type }
+        quote! {
+            #(#field_attrs)*
+            pub #field_name: #field_type
+        }
     });
 
     let field_names: Vec<_> = fields.iter().map(|f| &f.name).collect();
@@ -98,6 +105,12 @@ pub fn generate_zero_copy_message(input: ZeroCopyMessageInput) -> TokenStream {
     // Generate size assertion for compile-time verification
     let size_check_name = format_ident!("_SIZE_CHECK_{}", name);
 
+    // `BoundedVec` fields must have a padding-free layout to be Pod
+    let bounded_vec_checks = fields.iter().filter(|f| is_bounded_vec(&f.ty)).map(|f| {
+        let field_type = &f.ty;
+        quote! { let () = <#field_type>::LAYOUT_CHECK; }
+    });
+
     // Generate field offset assertions
     let offset_checks = fields.iter().map(|f| {
         let field_name = &f.name;
@@ -118,6 +131,53 @@ pub fn generate_zero_copy_message(input: ZeroCopyMessageInput) -> TokenStream {
         quote! { #field_name: <#field_type as ::core::default::Default>::default() }
     });
 
+    // A trailing `BoundedVec` lets the unused capacity be left off the wire
+    let trimmed = match fields.last() {
+        Some(last) if is_bounded_vec(&last.ty) => {
+            let last_name = &last.name;
+            let last_type = &last.ty;
+            quote! {
+                /// Bytes up to the last used element of the trailing `BoundedVec`
+                #[inline]
+                pub fn as_trimmed_bytes(&self) -> &[u8] {
+                    let tail = self.#last_name;
+                    let end = ::std::mem::offset_of!(Self, #last_name) + tail.used_size();
+                    &self.as_bytes()[..end]
+                }
+
+                /// Create from bytes produced by `as_trimmed_bytes`
+                ///
+                /// Returns `None` if the length does not match the encoded
+                /// element count.
+                pub fn from_trimmed_bytes(bytes: &[u8]) -> Option<Self> {
+                    let offset = ::std::mem::offset_of!(Self, #last_name);
+                    if bytes.len() < offset + <#last_type>::HEADER_SIZE || bytes.len() > Self::SIZE {
+                        return None;
+                    }
+                    #[allow(clippy::let_unit_value)]
+                    let () = <#last_type>::LAYOUT_CHECK;
+                    let mut result = Self::zeroed();
+                    ::bytemuck::bytes_of_mut(&mut result)[..bytes.len()].copy_from_slice(bytes);
+                    let tail = result.#last_name;
+                    (offset + tail.used_size() == bytes.len()).then_some(result)
+                }
+            }
+        }
+        _ => quote! {
+            /// Same as `as_bytes` (no trailing `BoundedVec` to trim)
+            #[inline]
+            pub fn as_trimmed_bytes(&self) -> &[u8] {
+                self.as_bytes()
+            }
+
+            /// Same as `from_bytes_copy` (no trailing `BoundedVec` to trim)
+            #[inline]
+            pub fn from_trimmed_bytes(bytes: &[u8]) -> Option<Self> {
+                Self::from_bytes_copy(bytes)
+            }
+        },
+    };
+
     quote! {
         /// Zero-copy message with compile-time verified layout
         #(#attrs)*
@@ -128,10 +188,11 @@ pub fn generate_zero_copy_message(input: ZeroCopyMessageInput) -> TokenStream {
         }
 
         // Compile-time size verification
-        #[allow(dead_code)]
+        #[allow(dead_code, clippy::let_unit_value)]
         const #size_check_name: () = {
             // Verify that the struct is actually Pod-safe
             assert!(std::mem::align_of::<#name>() <= 8, "Alignment must be <= 8");
+            #(#bounded_vec_checks)*
         };
 
         #(#offset_checks)*
@@ -189,6 +250,8 @@ pub fn generate_zero_copy_message(input: ZeroCopyMessageInput) -> TokenStream {
                     None
                 }
             }
+
+            #trimmed
         }
 
         // Implement LogSummary for efficient logging
@@ -207,7 +270,7 @@ pub fn generate_zero_copy_message(input: ZeroCopyMessageInput) -> TokenStream {
                 use ::horus::serde::ser::SerializeStruct;
                 let mut state = serializer.serialize_struct(stringify!(#name), #(#field_names.to_string().len())+*)?;
                 #(
-                    state.serialize_field(stringify!(#field_names), &self.#field_names)?;
+                    state.serialize_field(stringify!(#field_names), &{ self.#field_names })?;
                 )*
                 state.end()
             }
@@ -215,6 +278,18 @@ pub fn generate_zero_copy_message(input: ZeroCopyMessageInput) -> TokenStream {
     }
 }
 
+/// Whether a field type is `BoundedVec<T, N>` (by its last path segment)
+fn is_bounded_vec(ty: &Type) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "BoundedVec"),
+        _ => false,
+    }
+}
+
 /// Input for fixed_string! helper macro
 pub struct FixedStringInput {
     pub size: usize,
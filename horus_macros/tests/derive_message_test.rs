@@ -0,0 +1,83 @@
+//! Tests for #[derive(HorusMessage)]
+
+// Generated code refers to `::horus::core`, which re-exports horus_core
+extern crate horus_core as horus;
+
+#[cfg(test)]
+mod tests {
+    use horus::core::{LogSummary, MessageFingerprint};
+    use horus_macros::HorusMessage;
+    use serde::{Deserialize, Serialize};
+
+    /// Wheel odometry with serde attributes kept as written
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, HorusMessage)]
+    #[repr(C)]
+    #[horus(pod)]
+    struct WheelOdometry {
+        #[serde(rename = "ts")]
+        timestamp_ns: u64,
+        left_ticks: i32,
+        right_ticks: i32,
+    }
+
+    #[derive(Debug, Clone, Copy, HorusMessage)]
+    #[repr(u8)]
+    #[allow(dead_code)]
+    enum DriveMode {
+        Idle = 0,
+        Auto = 1,
+    }
+
+    #[derive(Debug, Clone, HorusMessage)]
+    #[horus(custom_log_summary)]
+    struct Frame<T> {
+        pixels: Vec<T>,
+    }
+
+    impl<T> LogSummary for Frame<T> {
+        fn log_summary(&self) -> String {
+            format!("Frame({} px)", self.pixels.len())
+        }
+    }
+
+    #[test]
+    fn test_derive_log_summary_and_pod() {
+        let odom = WheelOdometry {
+            timestamp_ns: 7,
+            left_ticks: -3,
+            right_ticks: 4,
+        };
+        assert_eq!(
+            odom.log_summary(),
+            "WheelOdometry { timestamp_ns: 7, left_ticks: -3, right_ticks: 4 }"
+        );
+
+        let bytes = bytemuck::bytes_of(&odom);
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(*bytemuck::from_bytes::<WheelOdometry>(bytes), odom);
+
+        let json = serde_json::to_string(&odom).unwrap();
+        assert!(json.starts_with("{\"ts\":7"));
+
+        let frame = Frame {
+            pixels: vec![0u8; 12],
+        };
+        assert_eq!(frame.log_summary(), "Frame(12 px)");
+        assert_eq!(DriveMode::Auto.log_summary(), "Auto");
+    }
+
+    #[test]
+    fn test_derive_fingerprint() {
+        assert_eq!(
+            WheelOdometry::SCHEMA,
+            "WheelOdometry{timestamp_ns:u64,left_ticks:i32,right_ticks:i32}"
+        );
+        assert_eq!(
+            WheelOdometry::FINGERPRINT,
+            horus::core::schema_fingerprint(WheelOdometry::SCHEMA)
+        );
+        assert_eq!(DriveMode::SCHEMA, "enum DriveMode{Idle=0,Auto=1}");
+        assert_eq!(<Frame<u8>>::SCHEMA, "Frame<T>{pixels:Vec<T>}");
+        assert_ne!(WheelOdometry::FINGERPRINT, DriveMode::FINGERPRINT);
+    }
+}
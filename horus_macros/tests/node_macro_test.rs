@@ -343,4 +343,126 @@ mod tests {
         assert_eq!(node.name(), "imu_front");
         assert_eq!(node.get_sample_count(), 0);
     }
+
+    // Test generic node with constructor params
+    #[test]
+    fn test_generic_node_with_params() {
+        use crate::tests::mock::horus_core;
+        use horus_core::core::node::Node;
+
+        node! {
+            ThresholdFilter<T> where T: PartialOrd + Clone {
+                pub {
+                    passed: T -> "filter.passed",
+                }
+
+                sub {
+                    input: T -> "filter.input",
+                }
+
+                params {
+                    threshold: T,
+                    window: usize = 10,
+                }
+
+                data {
+                    recent: Vec<T> = Vec::with_capacity(window),
+                }
+
+                tick {
+                    if let Some(value) = self.input.recv(None) {
+                        if value > self.threshold {
+                            self.passed.send(value, None).ok();
+                        }
+                    }
+                }
+
+                impl {
+                    pub fn accepts(&self, value: &T) -> bool {
+                        *value > self.threshold
+                    }
+                }
+            }
+        }
+
+        let filter = ThresholdFilter::new(0.5_f32).with_window(20);
+        assert_eq!(filter.name(), "threshold_filter");
+        assert_eq!(filter.window, 20);
+        assert!(filter.recent.capacity() >= 10);
+        assert!(filter.accepts(&0.75));
+        assert!(!filter.accepts(&0.25));
+
+        let strings = ThresholdFilter::new(String::from("m"));
+        assert!(strings.accepts(&String::from("z")));
+    }
+
+    // Test that params with defaults keep Default available
+    #[test]
+    fn test_defaulted_params_keep_default() {
+        use crate::tests::mock::horus_core;
+
+        node! {
+            Counter<const STEP: u32> {
+                params {
+                    limit: u32 = 100,
+                }
+
+                data {
+                    count: u32,
+                }
+
+                tick {
+                    self.count = (self.count + STEP).min(self.limit);
+                }
+            }
+        }
+
+        let counter = Counter::<5>::default();
+        assert_eq!(counter.limit, 100);
+        assert_eq!(counter.count, 0);
+        assert_eq!(Counter::<5>::new().with_limit(3).limit, 3);
+    }
+
+    // Test that timers run on the first tick and then at their period
+    #[test]
+    fn test_timers_section() {
+        use crate::tests::mock::horus_core;
+        use horus_core::core::node::Node;
+
+        node! {
+            Heartbeat {
+                data {
+                    beats: u32,
+                    fast_runs: u32,
+                    ticks: u32,
+                }
+
+                timers {
+                    heartbeat: 1.0s => {
+                        self.beats += 1;
+                    }
+                    fast: 1ms => {
+                        self.fast_runs += 1;
+                    }
+                }
+
+                tick {
+                    self.ticks += 1;
+                }
+            }
+        }
+
+        let mut node = Heartbeat::new();
+        node.tick(None);
+        node.tick(None);
+        assert_eq!(node.ticks, 2);
+        assert_eq!(node.beats, 1);
+        assert!(node.fast_runs >= 1);
+
+        let fast_runs = node.fast_runs;
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        node.tick(None);
+        assert_eq!(node.beats, 1);
+        assert_eq!(node.fast_runs, fast_runs + 1);
+    }
 }
@@ -0,0 +1,48 @@
+//! Tests for the topics! registry macro
+
+// Generated code refers to `::horus::communication`, which re-exports horus_core
+extern crate horus_core as horus;
+
+#[derive(Debug, Clone)]
+pub struct LaserScan;
+
+#[derive(Debug, Clone)]
+pub struct CmdVel;
+
+horus_macros::topics! {
+    /// Scans from the front lidar
+    "sensors.lidar": LaserScan,
+    "motors/cmd-vel": CmdVel,
+    "2d.map": Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use horus::communication::{Topic, TopicInfo, TopicName};
+
+    fn name_of<T>(topic: impl TopicName<T>) -> String {
+        topic.topic_name().to_string()
+    }
+
+    #[test]
+    fn test_topics_constants() {
+        let lidar: Topic<LaserScan> = topics::SENSORS_LIDAR;
+        assert_eq!(lidar.name(), "sensors.lidar");
+        assert_eq!(name_of::<CmdVel>(topics::MOTORS_CMD_VEL), "motors/cmd-vel");
+        assert_eq!(topics::_2D_MAP.to_string(), "2d.map");
+    }
+
+    #[test]
+    fn test_topics_registry_list() {
+        assert_eq!(topics::ALL.len(), 3);
+        assert_eq!(
+            topics::ALL[0],
+            TopicInfo {
+                name: "sensors.lidar",
+                type_name: "LaserScan",
+            }
+        );
+        assert_eq!(topics::ALL[2].type_name, "Vec<u8>");
+    }
+}
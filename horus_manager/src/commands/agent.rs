@@ -0,0 +1,601 @@
+//! Agent command - Run nodes on this machine for a `horus launch` elsewhere
+//!
+//! `horus agent start` is a small daemon for multi-host systems (robot and
+//! base station). A launch file assigns nodes to hosts; `horus launch` asks
+//! each host's agent to start them, routes topics used on more than one host
+//! through a router, and reports remote nodes along with local ones.
+//!
+//! ```yaml
+//! hosts:
+//!   robot: 192.168.1.20        # agent address, default port 7878
+//! nodes:
+//!   - name: lidar_driver
+//!     host: robot
+//!     package: lidar_driver
+//!     topics: [scan]
+//!   - name: mapper             # runs locally
+//!     package: mapper
+//!     topics: [scan, map]
+//! ```
+//!
+//! The protocol is one JSON request per line over TCP, each answered by one
+//! JSON line. Requests carry the token from `HORUS_AGENT_TOKEN`; an agent
+//! without a token only listens on the loopback interface.
+
+use super::launch::{launch_node, node_ready, LaunchNode};
+use colored::*;
+use horus_core::error::{HorusError, HorusResult};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{IpAddr, Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::path::PathBuf;
+use std::process::Child;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+/// Default agent port
+pub const DEFAULT_AGENT_PORT: u16 = 7878;
+
+/// Environment variable with the shared agent token
+pub const AGENT_TOKEN_ENV: &str = "HORUS_AGENT_TOKEN";
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Request sent to an agent
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum AgentRequest {
+    Hello,
+    /// Start a node, restarting it if it is already running
+    Start {
+        node: Box<LaunchNode>,
+        #[serde(default)]
+        env: HashMap<String, String>,
+        #[serde(default)]
+        namespace: Option<String>,
+    },
+    Stop {
+        name: String,
+    },
+    Status,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Envelope {
+    #[serde(default)]
+    token: Option<String>,
+    request: AgentRequest,
+}
+
+/// Agent reply
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "reply", rename_all = "snake_case")]
+pub enum AgentResponse {
+    Hello {
+        hostname: String,
+        version: String,
+        /// Address the request came from, as seen by the agent
+        peer: IpAddr,
+    },
+    Started {
+        pid: u32,
+    },
+    Stopped,
+    Status {
+        nodes: Vec<RemoteNodeStatus>,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// State of a node started by an agent
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RemoteNodeStatus {
+    pub name: String,
+    pub pid: u32,
+    pub running: bool,
+    /// Exit code once the process has exited (None if killed by a signal)
+    pub exit_code: Option<i32>,
+    /// Heartbeat reports `Running` since the node was started
+    pub ready: bool,
+}
+
+/// Agent identity returned by `hello`
+#[derive(Debug, Clone)]
+pub struct AgentInfo {
+    pub hostname: String,
+    pub version: String,
+    /// This machine's address as seen by the agent
+    pub peer: IpAddr,
+}
+
+/// Connection to an agent
+pub struct AgentClient {
+    address: String,
+    token: Option<String>,
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl AgentClient {
+    /// Connect to `host` or `host:port`, with the token from `HORUS_AGENT_TOKEN`
+    pub fn connect(address: &str) -> HorusResult<Self> {
+        let address = with_default_port(address);
+        let socket = address
+            .to_socket_addrs()
+            .map_err(|e| agent_error(&address, e))?
+            .next()
+            .ok_or_else(|| HorusError::Config(format!("Cannot resolve agent '{}'", address)))?;
+
+        let stream = TcpStream::connect_timeout(&socket, CONNECT_TIMEOUT).map_err(|e| {
+            HorusError::Communication(format!(
+                "Cannot reach agent at {}: {} (is 'horus agent start' running there?)",
+                address, e
+            ))
+        })?;
+        stream
+            .set_read_timeout(Some(REQUEST_TIMEOUT))
+            .map_err(|e| agent_error(&address, e))?;
+        let writer = stream.try_clone().map_err(|e| agent_error(&address, e))?;
+
+        Ok(Self {
+            address,
+            token: std::env::var(AGENT_TOKEN_ENV).ok(),
+            reader: BufReader::new(stream),
+            writer,
+        })
+    }
+
+    pub fn address(&self) -> &str {
+        &self.address
+    }
+
+    fn request(&mut self, request: AgentRequest) -> HorusResult<AgentResponse> {
+        let envelope = Envelope {
+            token: self.token.clone(),
+            request,
+        };
+        let mut line = serde_json::to_string(&envelope)?;
+        line.push('\n');
+        self.writer
+            .write_all(line.as_bytes())
+            .map_err(|e| agent_error(&self.address, e))?;
+
+        let mut reply = String::new();
+        if self
+            .reader
+            .read_line(&mut reply)
+            .map_err(|e| agent_error(&self.address, e))?
+            == 0
+        {
+            return Err(HorusError::Communication(format!(
+                "Agent at {} closed the connection",
+                self.address
+            )));
+        }
+
+        match serde_json::from_str(&reply)? {
+            AgentResponse::Error { message } => Err(HorusError::Communication(format!(
+                "Agent at {}: {}",
+                self.address, message
+            ))),
+            response => Ok(response),
+        }
+    }
+
+    pub fn hello(&mut self) -> HorusResult<AgentInfo> {
+        match self.request(AgentRequest::Hello)? {
+            AgentResponse::Hello {
+                hostname,
+                version,
+                peer,
+            } => Ok(AgentInfo {
+                hostname,
+                version,
+                peer,
+            }),
+            other => Err(unexpected(&self.address, &other)),
+        }
+    }
+
+    /// Start a node, returning its PID on the agent's machine
+    pub fn start(
+        &mut self,
+        node: &LaunchNode,
+        env: &HashMap<String, String>,
+        namespace: &Option<String>,
+    ) -> HorusResult<u32> {
+        let request = AgentRequest::Start {
+            node: Box::new(node.clone()),
+            env: env.clone(),
+            namespace: namespace.clone(),
+        };
+        match self.request(request)? {
+            AgentResponse::Started { pid } => Ok(pid),
+            other => Err(unexpected(&self.address, &other)),
+        }
+    }
+
+    pub fn stop(&mut self, name: &str) -> HorusResult<()> {
+        match self.request(AgentRequest::Stop {
+            name: name.to_string(),
+        })? {
+            AgentResponse::Stopped => Ok(()),
+            other => Err(unexpected(&self.address, &other)),
+        }
+    }
+
+    pub fn status(&mut self) -> HorusResult<Vec<RemoteNodeStatus>> {
+        match self.request(AgentRequest::Status)? {
+            AgentResponse::Status { nodes } => Ok(nodes),
+            other => Err(unexpected(&self.address, &other)),
+        }
+    }
+}
+
+/// `host` → `host:7878`; addresses with a port are kept
+fn with_default_port(address: &str) -> String {
+    if address.parse::<SocketAddr>().is_ok() {
+        return address.to_string();
+    }
+    if let Ok(ip) = address.parse::<IpAddr>() {
+        return SocketAddr::new(ip, DEFAULT_AGENT_PORT).to_string();
+    }
+    match address.rsplit_once(':') {
+        Some((_, port)) if port.parse::<u16>().is_ok() => address.to_string(),
+        _ => format!("{}:{}", address, DEFAULT_AGENT_PORT),
+    }
+}
+
+fn agent_error(address: &str, e: impl std::fmt::Display) -> HorusError {
+    HorusError::Communication(format!("Agent at {}: {}", address, e))
+}
+
+fn unexpected(address: &str, response: &AgentResponse) -> HorusError {
+    HorusError::Communication(format!(
+        "Agent at {} sent an unexpected reply: {:?}",
+        address, response
+    ))
+}
+
+/// A node process owned by the agent
+struct AgentProcess {
+    child: Child,
+    started: SystemTime,
+    exit_code: Option<i32>,
+    running: bool,
+}
+
+type Processes = Arc<Mutex<HashMap<String, AgentProcess>>>;
+
+/// Run the agent until Ctrl+C, then stop the nodes it started
+pub fn run_agent(
+    bind: &str,
+    port: u16,
+    token: Option<String>,
+    workdir: Option<PathBuf>,
+) -> HorusResult<()> {
+    let bind_ip: IpAddr = bind
+        .parse()
+        .map_err(|_| HorusError::Config(format!("Invalid bind address '{}'", bind)))?;
+    let token = token.or_else(|| std::env::var(AGENT_TOKEN_ENV).ok());
+    if token.is_none() && !bind_ip.is_loopback() {
+        return Err(HorusError::Config(format!(
+            "Refusing to accept commands on {} without a token. Set {} or --token, or bind to 127.0.0.1",
+            bind, AGENT_TOKEN_ENV
+        )));
+    }
+
+    if let Some(ref dir) = workdir {
+        std::env::set_current_dir(dir).map_err(|e| {
+            HorusError::Config(format!("Cannot use workdir {}: {}", dir.display(), e))
+        })?;
+    }
+
+    let listener = TcpListener::bind((bind_ip, port))?;
+    listener.set_nonblocking(true)?;
+
+    println!("{}", "HORUS Agent".green().bold());
+    println!();
+    println!("  {} {}:{}", "Listening:".cyan(), bind, port);
+    println!(
+        "  {} {}",
+        "Workdir:".cyan(),
+        std::env::current_dir()
+            .map(|d| d.display().to_string())
+            .unwrap_or_default()
+    );
+    println!(
+        "  {} {}",
+        "Token:".cyan(),
+        if token.is_some() {
+            "required"
+        } else {
+            "none (loopback only)"
+        }
+    );
+    println!();
+    println!(
+        "{}",
+        "Press Ctrl+C to stop the agent and its nodes...".dimmed()
+    );
+
+    let running = Arc::new(AtomicBool::new(true));
+    let r = running.clone();
+    ctrlc::set_handler(move || {
+        r.store(false, Ordering::SeqCst);
+    })
+    .ok();
+
+    let processes: Processes = Arc::new(Mutex::new(HashMap::new()));
+    let token = Arc::new(token);
+
+    while running.load(Ordering::SeqCst) {
+        match listener.accept() {
+            Ok((stream, peer)) => {
+                let processes = processes.clone();
+                let token = token.clone();
+                std::thread::spawn(move || {
+                    if let Err(e) = serve_connection(stream, peer, &token, &processes) {
+                        eprintln!("  {} Connection from {}: {}", "".yellow(), peer, e);
+                    }
+                });
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            Err(e) => eprintln!("  {} Accept failed: {}", "".yellow(), e),
+        }
+    }
+
+    println!();
+    println!("{}", "Stopping agent nodes...".yellow().bold());
+    let mut processes = processes.lock().unwrap_or_else(|e| e.into_inner());
+    for (name, process) in processes.drain() {
+        print!("  {} Stopping {}...", "".yellow(), name);
+        terminate(process.child);
+        println!(" {}", "stopped".green());
+    }
+
+    Ok(())
+}
+
+fn serve_connection(
+    stream: TcpStream,
+    peer: SocketAddr,
+    token: &Option<String>,
+    processes: &Processes,
+) -> std::io::Result<()> {
+    stream.set_nonblocking(false)?;
+    let mut writer = stream.try_clone()?;
+    let reader = BufReader::new(stream);
+
+    for line in reader.lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (response, authorized) = match serde_json::from_str::<Envelope>(&line) {
+            Ok(envelope) if token.is_some() && envelope.token != *token => (
+                AgentResponse::Error {
+                    message: format!("invalid token (set {})", AGENT_TOKEN_ENV),
+                },
+                false,
+            ),
+            Ok(envelope) => (handle_request(envelope.request, peer, processes), true),
+            Err(e) => (
+                AgentResponse::Error {
+                    message: format!("invalid request: {}", e),
+                },
+                true,
+            ),
+        };
+
+        let mut reply = serde_json::to_string(&response)?;
+        reply.push('\n');
+        writer.write_all(reply.as_bytes())?;
+
+        if !authorized {
+            eprintln!(
+                "  {} Rejected request from {}: bad token",
+                "".yellow(),
+                peer
+            );
+            writer.shutdown(Shutdown::Both)?;
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_request(request: AgentRequest, peer: SocketAddr, processes: &Processes) -> AgentResponse {
+    let mut processes = processes.lock().unwrap_or_else(|e| e.into_inner());
+
+    match request {
+        AgentRequest::Hello => AgentResponse::Hello {
+            hostname: hostname::get()
+                .map(|h| h.to_string_lossy().to_string())
+                .unwrap_or_default(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            peer: peer.ip(),
+        },
+
+        AgentRequest::Start {
+            node,
+            env,
+            namespace,
+        } => {
+            if let Some(previous) = processes.remove(&node.name) {
+                println!("  {} Restarting {}", "".yellow(), node.name);
+                terminate(previous.child);
+            }
+            match launch_node(&node, &env, &namespace) {
+                Ok(child) => {
+                    let pid = child.id();
+                    println!(
+                        "  {} Started {} for {} (PID: {})",
+                        "".green(),
+                        node.name.white().bold(),
+                        peer.ip(),
+                        pid
+                    );
+                    processes.insert(
+                        node.name.clone(),
+                        AgentProcess {
+                            child,
+                            started: SystemTime::now(),
+                            exit_code: None,
+                            running: true,
+                        },
+                    );
+                    AgentResponse::Started { pid }
+                }
+                Err(e) => AgentResponse::Error {
+                    message: e.to_string(),
+                },
+            }
+        }
+
+        AgentRequest::Stop { name } => match processes.remove(&name) {
+            Some(process) => {
+                println!("  {} Stopping {}", "".yellow(), name);
+                terminate(process.child);
+                AgentResponse::Stopped
+            }
+            None => AgentResponse::Error {
+                message: format!("no node named '{}'", name),
+            },
+        },
+
+        AgentRequest::Status => {
+            let mut nodes: Vec<RemoteNodeStatus> = processes
+                .iter_mut()
+                .map(|(name, process)| {
+                    if process.running {
+                        if let Ok(Some(status)) = process.child.try_wait() {
+                            process.running = false;
+                            process.exit_code = status.code();
+                        }
+                    }
+                    RemoteNodeStatus {
+                        name: name.clone(),
+                        pid: process.child.id(),
+                        running: process.running,
+                        exit_code: process.exit_code,
+                        ready: process.running && node_ready(name, process.started),
+                    }
+                })
+                .collect();
+            nodes.sort_by(|a, b| a.name.cmp(&b.name));
+            AgentResponse::Status { nodes }
+        }
+    }
+}
+
+/// Stop a node process: SIGTERM, then kill if it is still running
+fn terminate(mut child: Child) {
+    #[cfg(unix)]
+    unsafe {
+        libc::kill(child.id() as i32, libc::SIGTERM);
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = child.kill();
+    }
+
+    std::thread::sleep(Duration::from_millis(500));
+    if child.try_wait().map(|s| s.is_none()).unwrap_or(false) {
+        let _ = child.kill();
+    }
+    let _ = child.wait();
+}
+
+/// Print the nodes an agent is running
+pub fn print_agent_status(address: &str) -> HorusResult<()> {
+    let mut client = AgentClient::connect(address)?;
+    let info = client.hello()?;
+    let nodes = client.status()?;
+
+    println!(
+        "{} {} ({}, horus {})",
+        "Agent".green().bold(),
+        client.address(),
+        info.hostname,
+        info.version
+    );
+    println!();
+
+    if nodes.is_empty() {
+        println!("  {}", "No nodes started by this agent.".dimmed());
+        return Ok(());
+    }
+
+    println!(
+        "  {:<30} {:>8} {:>10}",
+        "NAME".dimmed(),
+        "PID".dimmed(),
+        "STATE".dimmed()
+    );
+    println!("  {}", "-".repeat(50).dimmed());
+    for node in &nodes {
+        let state = match (node.running, node.ready, node.exit_code) {
+            (true, true, _) => "running".green(),
+            (true, false, _) => "starting".yellow(),
+            (false, _, Some(0)) => "exited".dimmed(),
+            (false, _, Some(code)) => format!("failed ({})", code).red(),
+            (false, _, None) => "killed".red(),
+        };
+        println!("  {:<30} {:>8} {:>10}", node.name, node.pid, state);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_default_port() {
+        assert_eq!(with_default_port("192.168.1.20"), "192.168.1.20:7878");
+        assert_eq!(with_default_port("192.168.1.20:9000"), "192.168.1.20:9000");
+        assert_eq!(with_default_port("robot.local"), "robot.local:7878");
+        assert_eq!(with_default_port("robot.local:9000"), "robot.local:9000");
+        assert_eq!(with_default_port("::1"), "[::1]:7878");
+    }
+
+    #[test]
+    fn test_agent_protocol_roundtrip() {
+        let node: LaunchNode = serde_yaml::from_str("name: lidar\ncommand: lidar_driver").unwrap();
+        let envelope = Envelope {
+            token: Some("secret".to_string()),
+            request: AgentRequest::Start {
+                node: Box::new(node),
+                env: HashMap::new(),
+                namespace: None,
+            },
+        };
+        let json = serde_json::to_string(&envelope).unwrap();
+        assert!(json.contains(r#""op":"start""#));
+
+        let parsed: Envelope = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.token.as_deref(), Some("secret"));
+        match parsed.request {
+            AgentRequest::Start { node, .. } => {
+                assert_eq!(node.name, "lidar");
+                assert_eq!(node.command.as_deref(), Some("lidar_driver"));
+            }
+            other => panic!("unexpected request {:?}", other),
+        }
+
+        let reply: AgentResponse =
+            serde_json::from_str(r#"{"reply":"error","message":"no node named 'x'"}"#).unwrap();
+        assert!(matches!(reply, AgentResponse::Error { .. }));
+    }
+}
@@ -0,0 +1,1054 @@
+//! Audit command - License and provenance report for workspace dependencies
+//!
+//! `horus pkg audit` collects every dependency of the workspace:
+//! - packages installed in `.horus/packages` (HORUS registry, PyPI, crates.io,
+//!   path and system references), including the Python packages pip pulled in
+//! - path and git dependencies declared in `horus.yaml`
+//! - the crates of the workspace's `Cargo.toml` (via `cargo metadata`)
+//!
+//! For each it reports version, license and where it came from, checks the
+//! license against the workspace policy and looks up known advisories
+//! (RustSec and PyPI, through the OSV database). The policy is the `audit`
+//! section of `horus.yaml`, or a separate file given with `--policy`:
+//!
+//! ```yaml
+//! audit:
+//!   allow: [MIT, Apache-2.0, BSD-*]   # if set, only these are accepted
+//!   deny: [GPL-*, AGPL-*]             # default: GPL-*, AGPL-*, SSPL-*
+//!   allow_unknown: false              # packages without a detectable license
+//!   ignore_advisories: [RUSTSEC-2020-0071]
+//! ```
+//!
+//! The command fails when a license is denied or an advisory applies, so it
+//! can gate CI before shipping.
+
+use colored::*;
+use horus_core::error::{HorusError, HorusResult};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::dependency_resolver::DependencySource;
+
+const OSV_QUERY_BATCH_URL: &str = "https://api.osv.dev/v1/querybatch";
+const CRATES_IO_API: &str = "https://crates.io/api/v1/crates";
+const USER_AGENT: &str = concat!("horus/", env!("CARGO_PKG_VERSION"));
+
+/// Where a dependency came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub enum AuditSource {
+    Registry,
+    Path,
+    Git,
+    PyPI,
+    CratesIO,
+    System,
+}
+
+impl fmt::Display for AuditSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            AuditSource::Registry => "registry",
+            AuditSource::Path => "path",
+            AuditSource::Git => "git",
+            AuditSource::PyPI => "pypi",
+            AuditSource::CratesIO => "crates.io",
+            AuditSource::System => "system",
+        };
+        f.write_str(name)
+    }
+}
+
+impl AuditSource {
+    /// OSV ecosystem name, for sources with an advisory database
+    fn osv_ecosystem(self) -> Option<&'static str> {
+        match self {
+            AuditSource::PyPI => Some("PyPI"),
+            AuditSource::CratesIO => Some("crates.io"),
+            _ => None,
+        }
+    }
+}
+
+/// A dependency with its license and provenance
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditedPackage {
+    pub name: String,
+    pub version: String,
+    pub source: AuditSource,
+    /// Registry checksum, path, or git URL and revision
+    pub origin: String,
+    /// SPDX-style license expression, None if it could not be determined
+    pub license: Option<String>,
+    pub verdict: LicenseVerdict,
+    pub advisories: Vec<String>,
+}
+
+/// Result of checking a license expression against the policy
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LicenseVerdict {
+    Allowed,
+    Denied,
+    Unknown,
+}
+
+/// License policy of the workspace
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditPolicy {
+    /// Accepted licenses; empty accepts everything not denied
+    #[serde(default)]
+    pub allow: Vec<String>,
+    /// Rejected licenses, `*` matches any suffix (e.g. `GPL-*`)
+    #[serde(default = "default_deny")]
+    pub deny: Vec<String>,
+    /// Accept packages whose license can't be determined
+    #[serde(default)]
+    pub allow_unknown: bool,
+    /// Advisory IDs that are known and accepted
+    #[serde(default)]
+    pub ignore_advisories: Vec<String>,
+}
+
+fn default_deny() -> Vec<String> {
+    ["GPL-*", "AGPL-*", "SSPL-*"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect()
+}
+
+impl Default for AuditPolicy {
+    fn default() -> Self {
+        Self {
+            allow: Vec::new(),
+            deny: default_deny(),
+            allow_unknown: false,
+            ignore_advisories: Vec::new(),
+        }
+    }
+}
+
+impl AuditPolicy {
+    /// Load the `audit` section of `horus.yaml`, or a standalone policy file
+    pub fn load(workspace: &Path, policy_file: Option<&Path>) -> HorusResult<Self> {
+        let (path, standalone) = match policy_file {
+            Some(path) => (path.to_path_buf(), true),
+            None => (workspace.join("horus.yaml"), false),
+        };
+        if !path.exists() {
+            if standalone {
+                return Err(HorusError::Config(format!(
+                    "Policy file not found: {}",
+                    path.display()
+                )));
+            }
+            return Ok(Self::default());
+        }
+
+        let content = fs::read_to_string(&path).map_err(HorusError::Io)?;
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&content).map_err(|e| {
+            HorusError::Config(format!("Failed to parse {}: {}", path.display(), e))
+        })?;
+        // A standalone file may hold the policy at the top level
+        let section = match yaml.get("audit") {
+            Some(section) => section.clone(),
+            None if standalone => yaml,
+            None => return Ok(Self::default()),
+        };
+        serde_yaml::from_value(section).map_err(|e| {
+            HorusError::Config(format!("Invalid audit policy in {}: {}", path.display(), e))
+        })
+    }
+
+    /// Check a license expression such as `MIT OR Apache-2.0`
+    ///
+    /// One alternative of an `OR` (or `/`) must be acceptable; all terms of
+    /// an `AND` must be.
+    pub fn check(&self, license: Option<&str>) -> LicenseVerdict {
+        let Some(expression) = license.filter(|l| !l.trim().is_empty()) else {
+            return if self.allow_unknown {
+                LicenseVerdict::Allowed
+            } else {
+                LicenseVerdict::Unknown
+            };
+        };
+
+        let cleaned = expression.replace(['(', ')'], " ");
+        let acceptable = split_keyword(&cleaned.replace('/', " OR "), "OR")
+            .iter()
+            .any(|alternative| {
+                split_keyword(alternative, "AND")
+                    .iter()
+                    .all(|term| self.accepts(term))
+            });
+        if acceptable {
+            LicenseVerdict::Allowed
+        } else {
+            LicenseVerdict::Denied
+        }
+    }
+
+    fn accepts(&self, term: &str) -> bool {
+        // "Apache-2.0 WITH LLVM-exception" is judged by its license
+        let id = term.split(" WITH ").next().unwrap_or(term).trim();
+        if self.deny.iter().any(|pattern| license_matches(pattern, id)) {
+            return false;
+        }
+        self.allow.is_empty()
+            || self
+                .allow
+                .iter()
+                .any(|pattern| license_matches(pattern, id))
+    }
+}
+
+/// Split on a keyword surrounded by whitespace, case-insensitively
+fn split_keyword(expression: &str, keyword: &str) -> Vec<String> {
+    let mut parts = vec![String::new()];
+    for word in expression.split_whitespace() {
+        if word.eq_ignore_ascii_case(keyword) {
+            parts.push(String::new());
+        } else {
+            let part = parts.last_mut().expect("parts is never empty");
+            if !part.is_empty() {
+                part.push(' ');
+            }
+            part.push_str(word);
+        }
+    }
+    parts.retain(|p| !p.is_empty());
+    parts
+}
+
+/// Match a license id against a policy entry, `*` matches any suffix
+fn license_matches(pattern: &str, id: &str) -> bool {
+    let pattern = pattern.to_ascii_lowercase();
+    let id = id.to_ascii_lowercase();
+    match pattern.strip_suffix('*') {
+        Some(prefix) => id.starts_with(prefix),
+        None => id == pattern || id.strip_suffix("-only") == Some(pattern.as_str()),
+    }
+}
+
+/// Run `horus pkg audit`
+pub fn run_audit(policy_file: Option<PathBuf>, offline: bool, json: bool) -> HorusResult<()> {
+    let workspace = crate::workspace::find_workspace_root()
+        .or_else(|| std::env::current_dir().ok())
+        .ok_or_else(|| HorusError::Config("Could not determine workspace".to_string()))?;
+    let policy = AuditPolicy::load(&workspace, policy_file.as_deref())?;
+
+    if !json {
+        println!(
+            "{} Auditing dependencies of {}",
+            "".cyan(),
+            workspace.display().to_string().white()
+        );
+    }
+
+    let mut packages = collect_packages(&workspace, offline);
+    if !offline {
+        lookup_crates_io_licenses(&mut packages);
+    }
+    for package in &mut packages {
+        package.verdict = policy.check(package.license.as_deref());
+    }
+
+    let advisories_checked = !offline && check_advisories(&mut packages, &policy);
+
+    let denied = packages
+        .iter()
+        .filter(|p| p.verdict == LicenseVerdict::Denied)
+        .count();
+    let unknown = packages
+        .iter()
+        .filter(|p| p.verdict == LicenseVerdict::Unknown)
+        .count();
+    let vulnerable = packages.iter().filter(|p| !p.advisories.is_empty()).count();
+
+    if json {
+        let report = serde_json::json!({
+            "workspace": workspace,
+            "packages": packages,
+            "advisories_checked": advisories_checked,
+            "denied": denied,
+            "unknown": unknown,
+            "vulnerable": vulnerable,
+        });
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).map_err(|e| HorusError::Config(e.to_string()))?
+        );
+    } else {
+        print_report(&packages, advisories_checked, offline);
+    }
+
+    if denied > 0 || unknown > 0 || vulnerable > 0 {
+        return Err(HorusError::Config(format!(
+            "Audit failed: {} denied license(s), {} unknown license(s), {} package(s) with advisories",
+            denied, unknown, vulnerable
+        )));
+    }
+    Ok(())
+}
+
+fn print_report(packages: &[AuditedPackage], advisories_checked: bool, offline: bool) {
+    if packages.is_empty() {
+        println!("  {} No dependencies found", "".dimmed());
+        return;
+    }
+
+    println!();
+    println!(
+        "  {:<32} {:<14} {:<10} {:<28} {}",
+        "PACKAGE".bold(),
+        "VERSION".bold(),
+        "SOURCE".bold(),
+        "LICENSE".bold(),
+        "ORIGIN".bold()
+    );
+    for package in packages {
+        let license = package.license.as_deref().unwrap_or("unknown");
+        let license = match package.verdict {
+            LicenseVerdict::Allowed => license.green(),
+            LicenseVerdict::Denied => license.red().bold(),
+            LicenseVerdict::Unknown => license.yellow(),
+        };
+        println!(
+            "  {:<32} {:<14} {:<10} {:<28} {}",
+            package.name,
+            package.version,
+            package.source.to_string(),
+            license,
+            package.origin.dimmed()
+        );
+        for advisory in &package.advisories {
+            println!("    {} {}", "advisory".red().bold(), advisory);
+        }
+    }
+    println!();
+
+    if !advisories_checked {
+        let reason = if offline {
+            "offline mode"
+        } else {
+            "advisory database unreachable"
+        };
+        println!("  {} Advisories not checked ({})", "".yellow(), reason);
+    }
+    println!("  {} {} package(s) audited", "".green(), packages.len());
+}
+
+// ============================================================================
+// Collection
+// ============================================================================
+
+/// Collect the dependencies of a workspace, one entry per name, version and source
+pub fn collect_packages(workspace: &Path, offline: bool) -> Vec<AuditedPackage> {
+    let mut found: BTreeMap<(String, String, AuditSource), AuditedPackage> = BTreeMap::new();
+    let mut add = |package: AuditedPackage| {
+        let key = (
+            package.name.clone(),
+            package.version.clone(),
+            package.source,
+        );
+        found.entry(key).or_insert(package);
+    };
+
+    for package in installed_packages(&workspace.join(".horus/packages")) {
+        add(package);
+    }
+    for package in declared_packages(workspace) {
+        add(package);
+    }
+    for package in cargo_packages(workspace, offline) {
+        add(package);
+    }
+
+    found.into_values().collect()
+}
+
+fn audited(name: &str, version: &str, source: AuditSource, origin: String) -> AuditedPackage {
+    AuditedPackage {
+        name: name.to_string(),
+        version: version.to_string(),
+        source,
+        origin,
+        license: None,
+        verdict: LicenseVerdict::Unknown,
+        advisories: Vec::new(),
+    }
+}
+
+/// Packages installed by `horus pkg install`
+fn installed_packages(packages_dir: &Path) -> Vec<AuditedPackage> {
+    let mut packages = Vec::new();
+    let Ok(entries) = fs::read_dir(packages_dir) else {
+        return packages;
+    };
+
+    for entry in entries.flatten() {
+        let entry_path = entry.path();
+        let file_name = entry.file_name().to_string_lossy().to_string();
+
+        // Path (*.path.json) and system (*.system.json) package references
+        if file_name.ends_with(".json") {
+            let Some(metadata) = read_json(&entry_path) else {
+                continue;
+            };
+            let name = metadata["name"].as_str().unwrap_or("unknown");
+            if file_name.contains(".path.") {
+                let source_path = metadata["source_path"].as_str().unwrap_or("");
+                let version = metadata["version"].as_str().unwrap_or("dev");
+                let mut package =
+                    audited(name, version, AuditSource::Path, source_path.to_string());
+                package.license = license_from_dir(Path::new(source_path));
+                packages.push(package);
+            } else if file_name.contains(".system.") {
+                let version = metadata["version"].as_str().unwrap_or("unknown");
+                packages.push(audited(
+                    name,
+                    version,
+                    AuditSource::System,
+                    "system package manager".to_string(),
+                ));
+            }
+            continue;
+        }
+
+        // Directories, or symlinks into the global cache
+        let actual_path = fs::canonicalize(&entry_path).unwrap_or(entry_path.clone());
+        if !actual_path.is_dir() {
+            continue;
+        }
+        let metadata = read_json(&actual_path.join("metadata.json")).unwrap_or_default();
+        let name = metadata["name"].as_str().unwrap_or(&file_name).to_string();
+        let version = metadata["version"]
+            .as_str()
+            .unwrap_or("unknown")
+            .to_string();
+        let source = match metadata["source"].as_str() {
+            Some("PyPI") => AuditSource::PyPI,
+            Some("CratesIO") => AuditSource::CratesIO,
+            _ if file_name.starts_with("pypi_")
+                || actual_path.to_string_lossy().contains("pypi_") =>
+            {
+                AuditSource::PyPI
+            }
+            _ => AuditSource::Registry,
+        };
+
+        if source == AuditSource::PyPI {
+            // pip installs the requested package and its dependencies side by side
+            let python = python_packages(&actual_path);
+            if !python.is_empty() {
+                packages.extend(python);
+                continue;
+            }
+        }
+
+        let origin = match metadata["checksum"].as_str() {
+            Some(checksum) if !checksum.is_empty() => format!("sha256:{}", checksum),
+            _ => actual_path.display().to_string(),
+        };
+        let mut package = audited(&name, &version, source, origin);
+        package.license = license_from_dir(&actual_path);
+        packages.push(package);
+    }
+
+    packages
+}
+
+/// Python packages in a pip `--target` directory, from their `.dist-info`
+fn python_packages(dir: &Path) -> Vec<AuditedPackage> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("dist-info"))
+        .filter_map(|dist_info| {
+            let metadata = fs::read_to_string(dist_info.join("METADATA")).ok()?;
+            let info = parse_python_metadata(&metadata)?;
+            let mut package = audited(
+                &info.name,
+                &info.version,
+                AuditSource::PyPI,
+                format!("pypi.org/project/{}", info.name),
+            );
+            package.license = info.license.or_else(|| license_from_dir(&dist_info));
+            Some(package)
+        })
+        .collect()
+}
+
+/// Name, version and license of a Python core metadata file
+#[derive(Debug, PartialEq)]
+struct PythonMetadata {
+    name: String,
+    version: String,
+    license: Option<String>,
+}
+
+fn parse_python_metadata(metadata: &str) -> Option<PythonMetadata> {
+    let mut name = None;
+    let mut version = None;
+    let mut expression = None;
+    let mut license = None;
+    let mut classifier = None;
+
+    // Headers end at the first empty line, the description follows
+    for line in metadata.lines().take_while(|line| !line.is_empty()) {
+        if let Some(value) = line.strip_prefix("Name: ") {
+            name = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("Version: ") {
+            version = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("License-Expression: ") {
+            expression = Some(value.trim().to_string());
+        } else if let Some(value) = line.strip_prefix("License: ") {
+            // Some packages paste the whole license text here
+            let value = value.trim();
+            if !value.is_empty() && value != "UNKNOWN" && value.len() <= 64 {
+                license = Some(value.to_string());
+            }
+        } else if let Some(value) = line.strip_prefix("Classifier: License :: ") {
+            if classifier.is_none() {
+                classifier = license_from_classifier(value);
+            }
+        }
+    }
+
+    Some(PythonMetadata {
+        name: name?,
+        version: version.unwrap_or_else(|| "unknown".to_string()),
+        license: expression.or(classifier).or(license),
+    })
+}
+
+/// SPDX id for a `License :: OSI Approved :: ...` trove classifier
+fn license_from_classifier(classifier: &str) -> Option<String> {
+    let name = classifier.rsplit(" :: ").next()?.trim();
+    let id = match name {
+        "MIT License" => "MIT",
+        "Apache Software License" => "Apache-2.0",
+        "BSD License" => "BSD-3-Clause",
+        "ISC License (ISCL)" => "ISC",
+        "Mozilla Public License 2.0 (MPL 2.0)" => "MPL-2.0",
+        "GNU General Public License v2 (GPLv2)" => "GPL-2.0",
+        "GNU General Public License v3 (GPLv3)" => "GPL-3.0",
+        "GNU Lesser General Public License v2 or later (LGPLv2+)" => "LGPL-2.1",
+        "GNU Lesser General Public License v3 (LGPLv3)" => "LGPL-3.0",
+        "GNU Affero General Public License v3" => "AGPL-3.0",
+        "Python Software Foundation License" => "PSF-2.0",
+        "The Unlicense (Unlicense)" => "Unlicense",
+        "Public Domain" => "Public-Domain",
+        _ => return Some(name.to_string()),
+    };
+    Some(id.to_string())
+}
+
+/// Path and git dependencies declared in `horus.yaml`
+fn declared_packages(workspace: &Path) -> Vec<AuditedPackage> {
+    let horus_yaml = workspace.join("horus.yaml");
+    let Some(horus_yaml) = horus_yaml.to_str().filter(|_| horus_yaml.exists()) else {
+        return Vec::new();
+    };
+    let Ok(deps) = crate::commands::run::parse_horus_yaml_dependencies_v2(horus_yaml) else {
+        return Vec::new();
+    };
+
+    let git_cache = dirs::home_dir().map(|home| home.join(".horus/cache"));
+    deps.into_iter()
+        .filter_map(|dep| match dep.source {
+            DependencySource::Path(path) => {
+                let path = if path.is_relative() {
+                    workspace.join(path)
+                } else {
+                    path
+                };
+                let version = package_version(&path).unwrap_or_else(|| "dev".to_string());
+                let mut package = audited(
+                    &dep.name,
+                    &version,
+                    AuditSource::Path,
+                    path.display().to_string(),
+                );
+                package.license = license_from_dir(&path);
+                Some(package)
+            }
+            DependencySource::Git {
+                url,
+                branch,
+                tag,
+                rev,
+            } => {
+                // Checkouts live in ~/.horus/cache/git_<name>_<url hash>[_<ref>]
+                let checkout = git_cache
+                    .as_ref()
+                    .and_then(|cache| find_git_checkout(cache, &dep.name));
+                let commit = checkout.as_deref().and_then(git_head);
+                let reference = rev.or(tag).or(branch);
+                let origin = match (&commit, &reference) {
+                    (Some(commit), _) => format!("{}#{}", url, commit),
+                    (None, Some(reference)) => format!("{}#{}", url, reference),
+                    (None, None) => url,
+                };
+                let version = checkout
+                    .as_deref()
+                    .and_then(package_version)
+                    .unwrap_or_else(|| "git".to_string());
+                let mut package = audited(&dep.name, &version, AuditSource::Git, origin);
+                package.license = checkout.as_deref().and_then(license_from_dir);
+                Some(package)
+            }
+            DependencySource::Registry => None,
+        })
+        .collect()
+}
+
+fn find_git_checkout(cache: &Path, name: &str) -> Option<PathBuf> {
+    let prefix = format!("git_{}_", name);
+    fs::read_dir(cache)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with(&prefix))
+        })
+}
+
+fn git_head(dir: &Path) -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .current_dir(dir)
+        .output()
+        .ok()?;
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Crates of the workspace's Cargo project, from `cargo metadata`
+fn cargo_packages(workspace: &Path, offline: bool) -> Vec<AuditedPackage> {
+    let Some(manifest_dir) = [workspace.to_path_buf(), workspace.join(".horus")]
+        .into_iter()
+        .find(|dir| dir.join("Cargo.toml").exists())
+    else {
+        return Vec::new();
+    };
+
+    let run = |offline: bool| {
+        let mut cmd = Command::new("cargo");
+        cmd.args(["metadata", "--format-version", "1"])
+            .current_dir(&manifest_dir);
+        if offline {
+            cmd.arg("--offline");
+        }
+        cmd.output().ok().filter(|output| output.status.success())
+    };
+    // Prefer what is already fetched, only go online if that is not enough
+    let Some(output) = run(true).or_else(|| if offline { None } else { run(false) }) else {
+        return Vec::new();
+    };
+    let Ok(metadata) = serde_json::from_slice::<serde_json::Value>(&output.stdout) else {
+        return Vec::new();
+    };
+    parse_cargo_metadata(&metadata)
+}
+
+fn parse_cargo_metadata(metadata: &serde_json::Value) -> Vec<AuditedPackage> {
+    let members: Vec<&str> = metadata["workspace_members"]
+        .as_array()
+        .map(|ids| ids.iter().filter_map(|id| id.as_str()).collect())
+        .unwrap_or_default();
+
+    metadata["packages"]
+        .as_array()
+        .map(|packages| packages.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter(|package| {
+            package["id"]
+                .as_str()
+                .is_some_and(|id| !members.contains(&id))
+        })
+        .filter_map(|package| {
+            let name = package["name"].as_str()?;
+            let version = package["version"].as_str()?;
+            let manifest_dir = package["manifest_path"]
+                .as_str()
+                .and_then(|p| Path::new(p).parent())
+                .map(Path::to_path_buf);
+
+            let (source, origin) = match package["source"].as_str() {
+                None => (
+                    AuditSource::Path,
+                    manifest_dir
+                        .as_ref()
+                        .map(|dir| dir.display().to_string())
+                        .unwrap_or_default(),
+                ),
+                Some(source) if source.starts_with("git+") => (
+                    AuditSource::Git,
+                    source.trim_start_matches("git+").to_string(),
+                ),
+                Some(source) if source.contains("crates.io-index") => {
+                    (AuditSource::CratesIO, format!("crates.io/crates/{}", name))
+                }
+                Some(source) => (AuditSource::Registry, source.to_string()),
+            };
+
+            let mut audited_package = audited(name, version, source, origin);
+            audited_package.license = match package["license"].as_str() {
+                Some(license) => Some(license.to_string()),
+                None => package["license_file"]
+                    .as_str()
+                    .zip(manifest_dir.as_ref())
+                    .and_then(|(file, dir)| fs::read_to_string(dir.join(file)).ok())
+                    .and_then(|text| classify_license_text(&text).map(String::from)),
+            };
+            Some(audited_package)
+        })
+        .collect()
+}
+
+// ============================================================================
+// License detection
+// ============================================================================
+
+/// License declared in a package directory's manifests or license file
+pub fn license_from_dir(dir: &Path) -> Option<String> {
+    if let Some(license) = manifest_field(dir, "license") {
+        return Some(license);
+    }
+
+    let entries = fs::read_dir(dir).ok()?;
+    let mut files: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|n| n.to_str())
+                .map(|n| n.to_ascii_uppercase())
+                .is_some_and(|n| n.starts_with("LICENSE") || n.starts_with("COPYING"))
+        })
+        .collect();
+    // Also look inside a `licenses/` directory (Python wheels)
+    if let Ok(entries) = fs::read_dir(dir.join("licenses")) {
+        files.extend(entries.flatten().map(|entry| entry.path()));
+    }
+    files.sort();
+
+    let mut licenses: Vec<&str> = files
+        .iter()
+        .filter_map(|path| fs::read_to_string(path).ok())
+        .filter_map(|text| classify_license_text(&text))
+        .collect();
+    licenses.dedup();
+    match licenses.len() {
+        0 => None,
+        // LICENSE-MIT and LICENSE-APACHE side by side mean a dual license
+        _ => Some(licenses.join(" OR ")),
+    }
+}
+
+/// Version declared in a package directory's manifests
+fn package_version(dir: &Path) -> Option<String> {
+    manifest_field(dir, "version")
+}
+
+/// A top-level field of horus.yaml, or of `[package]` / `[project]` in
+/// Cargo.toml / pyproject.toml
+fn manifest_field(dir: &Path, field: &str) -> Option<String> {
+    if let Ok(content) = fs::read_to_string(dir.join("horus.yaml")) {
+        if let Ok(yaml) = serde_yaml::from_str::<serde_yaml::Value>(&content) {
+            if let Some(value) = yaml.get(field).and_then(|v| v.as_str()) {
+                return Some(value.to_string());
+            }
+        }
+    }
+
+    for (manifest, table) in [("Cargo.toml", "package"), ("pyproject.toml", "project")] {
+        let Ok(content) = fs::read_to_string(dir.join(manifest)) else {
+            continue;
+        };
+        let Ok(toml) = content.parse::<toml::Table>() else {
+            continue;
+        };
+        let value = toml.get(table).and_then(|t| t.get(field));
+        // pyproject.toml may use `license = { text = "..." }`
+        let value = value
+            .and_then(|v| v.as_str())
+            .or_else(|| value.and_then(|v| v.get("text")).and_then(|v| v.as_str()));
+        if let Some(value) = value {
+            return Some(value.to_string());
+        }
+    }
+    None
+}
+
+/// Recognize common license texts
+pub fn classify_license_text(text: &str) -> Option<&'static str> {
+    let text: String = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let has = |needle: &str| text.contains(needle);
+
+    let license = if has("GNU AFFERO GENERAL PUBLIC LICENSE") {
+        "AGPL-3.0"
+    } else if has("GNU LESSER GENERAL PUBLIC LICENSE") {
+        if has("Version 3") {
+            "LGPL-3.0"
+        } else {
+            "LGPL-2.1"
+        }
+    } else if has("GNU GENERAL PUBLIC LICENSE") {
+        if has("Version 3") {
+            "GPL-3.0"
+        } else {
+            "GPL-2.0"
+        }
+    } else if has("Apache License") && has("Version 2.0") {
+        "Apache-2.0"
+    } else if has("Mozilla Public License Version 2.0") {
+        "MPL-2.0"
+    } else if has("Boost Software License") {
+        "BSL-1.0"
+    } else if has("free and unencumbered software released into the public domain") {
+        "Unlicense"
+    } else if has("Permission is hereby granted, free of charge") {
+        "MIT"
+    } else if has("Permission to use, copy, modify, and/or distribute this software") {
+        "ISC"
+    } else if has("Redistribution and use in source and binary forms") {
+        if has("Neither the name") || has("names of its contributors") {
+            "BSD-3-Clause"
+        } else {
+            "BSD-2-Clause"
+        }
+    } else {
+        return None;
+    };
+    Some(license)
+}
+
+fn read_json(path: &Path) -> Option<serde_json::Value> {
+    let content = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+// ============================================================================
+// Online lookups
+// ============================================================================
+
+/// Fill in licenses of crates installed with `cargo install` from crates.io
+fn lookup_crates_io_licenses(packages: &mut [AuditedPackage]) {
+    let client = reqwest::blocking::Client::new();
+    for package in packages
+        .iter_mut()
+        .filter(|p| p.source == AuditSource::CratesIO && p.license.is_none())
+    {
+        let url = format!("{}/{}/{}", CRATES_IO_API, package.name, package.version);
+        let response = client
+            .get(&url)
+            .header("User-Agent", USER_AGENT)
+            .timeout(std::time::Duration::from_secs(10))
+            .send()
+            .ok()
+            .filter(|r| r.status().is_success())
+            .and_then(|r| r.json::<serde_json::Value>().ok());
+        if let Some(license) = response
+            .as_ref()
+            .and_then(|body| body["version"]["license"].as_str())
+        {
+            package.license = Some(license.to_string());
+        }
+    }
+}
+
+/// Query OSV for advisories of crates.io and PyPI packages
+///
+/// Returns false if the database could not be reached.
+fn check_advisories(packages: &mut [AuditedPackage], policy: &AuditPolicy) -> bool {
+    let queried: Vec<usize> = packages
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| p.source.osv_ecosystem().is_some() && p.version != "unknown")
+        .map(|(i, _)| i)
+        .collect();
+    if queried.is_empty() {
+        return true;
+    }
+
+    let queries: Vec<serde_json::Value> = queried
+        .iter()
+        .map(|&i| {
+            let package = &packages[i];
+            serde_json::json!({
+                "package": {
+                    "name": package.name,
+                    "ecosystem": package.source.osv_ecosystem(),
+                },
+                "version": package.version,
+            })
+        })
+        .collect();
+
+    let response = reqwest::blocking::Client::new()
+        .post(OSV_QUERY_BATCH_URL)
+        .header("User-Agent", USER_AGENT)
+        .timeout(std::time::Duration::from_secs(30))
+        .json(&serde_json::json!({ "queries": queries }))
+        .send()
+        .ok()
+        .filter(|r| r.status().is_success())
+        .and_then(|r| r.json::<serde_json::Value>().ok());
+    let Some(results) = response
+        .as_ref()
+        .and_then(|body| body["results"].as_array())
+    else {
+        return false;
+    };
+
+    for (&i, result) in queried.iter().zip(results) {
+        let ids = result["vulns"]
+            .as_array()
+            .map(|vulns| vulns.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|vuln| vuln["id"].as_str())
+            .filter(|id| !policy.ignore_advisories.iter().any(|ignored| ignored == id))
+            .map(String::from);
+        packages[i].advisories.extend(ids);
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_license_expressions() {
+        let policy = AuditPolicy::default();
+        assert_eq!(
+            policy.check(Some("MIT OR Apache-2.0")),
+            LicenseVerdict::Allowed
+        );
+        assert_eq!(
+            policy.check(Some("MIT/Apache-2.0")),
+            LicenseVerdict::Allowed
+        );
+        assert_eq!(policy.check(Some("GPL-3.0-only")), LicenseVerdict::Denied);
+        // LGPL is not caught by GPL-*
+        assert_eq!(policy.check(Some("LGPL-2.1")), LicenseVerdict::Allowed);
+        // One acceptable alternative is enough, every AND term must be acceptable
+        assert_eq!(
+            policy.check(Some("(MIT OR GPL-2.0)")),
+            LicenseVerdict::Allowed
+        );
+        assert_eq!(
+            policy.check(Some("MIT AND AGPL-3.0")),
+            LicenseVerdict::Denied
+        );
+        assert_eq!(policy.check(None), LicenseVerdict::Unknown);
+
+        let strict = AuditPolicy {
+            allow: vec![
+                "MIT".to_string(),
+                "Apache-2.0".to_string(),
+                "BSD-*".to_string(),
+            ],
+            allow_unknown: true,
+            ..AuditPolicy::default()
+        };
+        assert_eq!(
+            strict.check(Some("Apache-2.0 WITH LLVM-exception")),
+            LicenseVerdict::Allowed
+        );
+        assert_eq!(strict.check(Some("BSD-3-Clause")), LicenseVerdict::Allowed);
+        assert_eq!(strict.check(Some("MPL-2.0")), LicenseVerdict::Denied);
+        assert_eq!(strict.check(None), LicenseVerdict::Allowed);
+    }
+
+    #[test]
+    fn test_parse_python_metadata() {
+        let metadata = "Metadata-Version: 2.1\n\
+                        Name: numpy\n\
+                        Version: 1.26.4\n\
+                        License: Copyright (c) 2005-2023, NumPy Developers.\n\
+                        Classifier: Development Status :: 5 - Production/Stable\n\
+                        Classifier: License :: OSI Approved :: BSD License\n\
+                        \n\
+                        License: not a header\n";
+        assert_eq!(
+            parse_python_metadata(metadata),
+            Some(PythonMetadata {
+                name: "numpy".to_string(),
+                version: "1.26.4".to_string(),
+                license: Some("BSD-3-Clause".to_string()),
+            })
+        );
+
+        let metadata = "Name: pyserial\nVersion: 3.5\nLicense-Expression: BSD-3-Clause\n";
+        assert_eq!(
+            parse_python_metadata(metadata).unwrap().license.as_deref(),
+            Some("BSD-3-Clause")
+        );
+    }
+
+    #[test]
+    fn test_installed_packages_and_license_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let packages_dir = dir.path().join("packages");
+
+        let lidar = packages_dir.join("lidar-driver");
+        fs::create_dir_all(&lidar).unwrap();
+        fs::write(
+            lidar.join("metadata.json"),
+            r#"{"name": "lidar-driver", "version": "0.3.1", "checksum": "abc123"}"#,
+        )
+        .unwrap();
+        fs::write(
+            lidar.join("LICENSE-MIT"),
+            "Permission is hereby granted, free of charge, to any person",
+        )
+        .unwrap();
+        fs::write(
+            lidar.join("LICENSE-APACHE"),
+            "Apache License\n   Version 2.0, January 2004",
+        )
+        .unwrap();
+
+        let pypi = packages_dir.join("pyserial");
+        let dist_info = pypi.join("pyserial-3.5.dist-info");
+        fs::create_dir_all(&dist_info).unwrap();
+        fs::write(
+            pypi.join("metadata.json"),
+            r#"{"name": "pyserial", "version": "3.5", "source": "PyPI"}"#,
+        )
+        .unwrap();
+        fs::write(
+            dist_info.join("METADATA"),
+            "Name: pyserial\nVersion: 3.5\nLicense: BSD\n",
+        )
+        .unwrap();
+
+        let mut packages = installed_packages(&packages_dir);
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+        assert_eq!(packages.len(), 2);
+
+        assert_eq!(packages[0].name, "lidar-driver");
+        assert_eq!(packages[0].source, AuditSource::Registry);
+        assert_eq!(packages[0].origin, "sha256:abc123");
+        assert_eq!(packages[0].license.as_deref(), Some("Apache-2.0 OR MIT"));
+
+        assert_eq!(packages[1].name, "pyserial");
+        assert_eq!(packages[1].source, AuditSource::PyPI);
+        assert_eq!(packages[1].license.as_deref(), Some("BSD"));
+    }
+}
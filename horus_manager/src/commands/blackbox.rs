@@ -0,0 +1,269 @@
+//! Blackbox command - Post-mortem inspection of black box flight recordings
+//!
+//! Decodes the memory-mapped event rings written by schedulers with the black
+//! box enabled (`~/.horus/blackbox/*.bbx`). Rings survive process crashes, so
+//! this works after an incident without the scheduler running.
+
+use super::log::parse_since;
+use colored::*;
+use horus_core::error::{HorusError, HorusResult};
+use horus_core::scheduling::blackbox::{blackbox_dir, BlackBox, BlackBoxEvent, BlackBoxRecord};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// List available black box ring files
+pub fn list_rings() -> HorusResult<()> {
+    let rings = find_rings()?;
+
+    if rings.is_empty() {
+        println!("{}", "No black box recordings found.".yellow());
+        println!(
+            "  {} Enable the black box in the scheduler config to record events",
+            "Tip:".dimmed()
+        );
+        return Ok(());
+    }
+
+    println!("{}", "Black box recordings:".green().bold());
+    println!();
+    for (path, modified) in rings {
+        let events = BlackBox::read_persisted(&path)
+            .map(|r| r.len().to_string())
+            .unwrap_or_else(|_| "invalid".to_string());
+        println!(
+            "  {} {} ({} events, last write {})",
+            "".cyan(),
+            path.display(),
+            events,
+            format_time(modified)
+        );
+    }
+
+    Ok(())
+}
+
+/// Decode and print events from a ring file
+///
+/// Without `file`, the most recently written ring in `~/.horus/blackbox` is used.
+pub fn dump(
+    file: Option<&Path>,
+    since: Option<&str>,
+    count: Option<usize>,
+    anomalies_only: bool,
+    json: bool,
+) -> HorusResult<()> {
+    let path = match file {
+        Some(p) => p.to_path_buf(),
+        None => match find_rings()?.into_iter().next() {
+            Some((path, _)) => path,
+            None => {
+                return Err(HorusError::NotFound(format!(
+                    "No black box recordings found in {}",
+                    blackbox_dir().display()
+                )))
+            }
+        },
+    };
+
+    let mut records = BlackBox::read_persisted(&path).map_err(|e| {
+        HorusError::InvalidInput(format!("Failed to read {}: {}", path.display(), e))
+    })?;
+
+    // --since is relative to the last recorded event, not to now: a dump is
+    // usually taken some time after the incident
+    if let Some(since_time) = parse_since(since)? {
+        let window_us = SystemTime::now()
+            .duration_since(since_time)
+            .unwrap_or_default()
+            .as_micros() as u64;
+        let last_us = records.last().map(|r| r.timestamp_us).unwrap_or(0);
+        let min_us = last_us.saturating_sub(window_us);
+        records.retain(|r| r.timestamp_us >= min_us);
+    }
+
+    if anomalies_only {
+        records.retain(|r| is_anomaly(&r.event));
+    }
+
+    if let Some(n) = count {
+        let skip = records.len().saturating_sub(n);
+        records.drain(..skip);
+    }
+
+    if json {
+        let out = serde_json::to_string_pretty(&records)
+            .map_err(|e| HorusError::Serialization(e.to_string()))?;
+        println!("{}", out);
+        return Ok(());
+    }
+
+    println!("{}", "HORUS Black Box Dump".green().bold());
+    println!();
+    println!("  {} {}", "File:".cyan(), path.display());
+    if let Some(s) = since {
+        println!("  {} last {} before final event", "Window:".cyan(), s);
+    }
+    println!("  {} {}", "Events:".cyan(), records.len());
+    println!();
+
+    if records.is_empty() {
+        println!("{}", "No events in range.".dimmed());
+        return Ok(());
+    }
+
+    for record in &records {
+        print_record(record);
+    }
+
+    Ok(())
+}
+
+/// Ring files sorted by modification time, newest first
+fn find_rings() -> HorusResult<Vec<(PathBuf, SystemTime)>> {
+    let dir = blackbox_dir();
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut rings: Vec<(PathBuf, SystemTime)> = fs::read_dir(&dir)
+        .map_err(HorusError::Io)?
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().map(|e| e == "bbx").unwrap_or(false))
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect();
+
+    rings.sort_by_key(|ring| std::cmp::Reverse(ring.1));
+    Ok(rings)
+}
+
+fn is_anomaly(event: &BlackBoxEvent) -> bool {
+    matches!(
+        event,
+        BlackBoxEvent::NodeError { .. }
+            | BlackBoxEvent::DeadlineMiss { .. }
+            | BlackBoxEvent::WCETViolation { .. }
+            | BlackBoxEvent::EmergencyStop { .. }
+            | BlackBoxEvent::WatchdogEscalation { .. }
+            | BlackBoxEvent::NodeStateChange { .. }
+            | BlackBoxEvent::CircuitBreakerChange { .. }
+    )
+}
+
+fn print_record(record: &BlackBoxRecord) {
+    let time = UNIX_EPOCH + std::time::Duration::from_micros(record.timestamp_us);
+    let prefix = format!("{} [tick {}]", format_time(time), record.tick);
+
+    let (label, details) = describe(&record.event);
+    let label = if is_anomaly(&record.event) {
+        label.red().bold()
+    } else {
+        label.cyan()
+    };
+
+    println!("{} {} {}", prefix.dimmed(), label, details);
+}
+
+fn describe(event: &BlackBoxEvent) -> (&'static str, String) {
+    match event {
+        BlackBoxEvent::SchedulerStart {
+            name, node_count, ..
+        } => ("SchedulerStart", format!("{} ({} nodes)", name, node_count)),
+        BlackBoxEvent::SchedulerStop {
+            reason,
+            total_ticks,
+        } => (
+            "SchedulerStop",
+            format!("{} after {} ticks", reason, total_ticks),
+        ),
+        BlackBoxEvent::NodeAdded { name, priority } => {
+            ("NodeAdded", format!("{} (priority {})", name, priority))
+        }
+        BlackBoxEvent::NodeTick {
+            name,
+            duration_us,
+            success,
+        } => (
+            "NodeTick",
+            format!(
+                "{} {}us{}",
+                name,
+                duration_us,
+                if *success { "" } else { " FAILED" }
+            ),
+        ),
+        BlackBoxEvent::NodeError { name, error } => ("NodeError", format!("{}: {}", name, error)),
+        BlackBoxEvent::DeadlineMiss {
+            name,
+            deadline_us,
+            actual_us,
+        } => (
+            "DeadlineMiss",
+            format!("{} took {}us (deadline {}us)", name, actual_us, deadline_us),
+        ),
+        BlackBoxEvent::WCETViolation {
+            name,
+            budget_us,
+            actual_us,
+        } => (
+            "WCETViolation",
+            format!("{} took {}us (budget {}us)", name, actual_us, budget_us),
+        ),
+        BlackBoxEvent::CircuitBreakerChange {
+            name,
+            new_state,
+            failure_count,
+        } => (
+            "CircuitBreaker",
+            format!("{} -> {} ({} failures)", name, new_state, failure_count),
+        ),
+        BlackBoxEvent::LearningComplete {
+            duration_ms,
+            tier_summary,
+        } => (
+            "LearningComplete",
+            format!("{}ms: {}", duration_ms, tier_summary),
+        ),
+        BlackBoxEvent::JITCompilation { name, success } => (
+            "JITCompilation",
+            format!("{} {}", name, if *success { "ok" } else { "failed" }),
+        ),
+        BlackBoxEvent::EmergencyStop { reason } => ("EmergencyStop", reason.clone()),
+        BlackBoxEvent::WatchdogEscalation {
+            name,
+            step,
+            action,
+            reason,
+        } => (
+            "WatchdogEscalation",
+            format!("{} step {}: {} ({})", name, step + 1, action, reason),
+        ),
+        BlackBoxEvent::NodeStateChange {
+            name,
+            previous_state,
+            new_state,
+            restart_count,
+        } => (
+            "NodeStateChange",
+            format!(
+                "{}: {} -> {} (restarts: {})",
+                name, previous_state, new_state, restart_count
+            ),
+        ),
+        BlackBoxEvent::Custom { category, message } => {
+            ("Custom", format!("[{}] {}", category, message))
+        }
+    }
+}
+
+fn format_time(time: SystemTime) -> String {
+    let duration = time.duration_since(UNIX_EPOCH).unwrap_or_default();
+    let datetime =
+        chrono::DateTime::from_timestamp(duration.as_secs() as i64, duration.subsec_nanos())
+            .unwrap_or_else(chrono::Utc::now);
+    datetime.format("%Y-%m-%d %H:%M:%S%.3f").to_string()
+}
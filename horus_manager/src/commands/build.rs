@@ -0,0 +1,476 @@
+//! Workspace builds - build every HORUS package of a monorepo at once
+//!
+//! `horus build` without files, in a directory whose horus.yaml has a
+//! `workspace:` section (or that has no horus.yaml but contains packages),
+//! builds all member packages:
+//!
+//! ```yaml
+//! workspace:
+//!   members: [drivers/*, planner, apps/rover]
+//! ```
+//!
+//! Without `members`, every directory below the root holding a horus.yaml is
+//! a member. Members are built in dependency order (a member depending on
+//! another by name or by `path:`) into one cargo target dir,
+//! `.horus/target` at the root, so shared crates compile once. Each member's
+//! sources are hashed together with the hashes of the members it depends
+//! on; members whose hash matches the last successful build are skipped.
+
+use anyhow::{bail, Context, Result};
+use colored::*;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Hashes of the last successful member builds, under the root `.horus/`
+const STATE_FILE: &str = "workspace-build.json";
+
+/// Directories never hashed or searched for members
+const SKIPPED_DIRS: &[&str] = &[".horus", "target", ".git", "node_modules", "__pycache__"];
+
+/// How deep to search for members when `members` isn't set
+const MAX_DISCOVERY_DEPTH: usize = 4;
+
+/// Cargo target shared by the members of a workspace build
+#[derive(Debug, Clone)]
+pub struct SharedTarget {
+    /// Crate and binary name of the member
+    pub package_name: String,
+    /// Absolute cargo target dir
+    pub target_dir: PathBuf,
+}
+
+/// A HORUS package of the workspace
+#[derive(Debug, Clone, PartialEq)]
+pub struct Member {
+    pub name: String,
+    /// Absolute path of the package directory
+    pub dir: PathBuf,
+    /// Indices of the members this one depends on
+    pub depends_on: Vec<usize>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct BuildState {
+    #[serde(default)]
+    members: BTreeMap<String, BuiltMember>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct BuiltMember {
+    hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    artifact: Option<PathBuf>,
+}
+
+/// The members of a workspace, in build order
+#[derive(Debug)]
+pub struct WorkspaceBuild {
+    pub root: PathBuf,
+    pub members: Vec<Member>,
+}
+
+impl WorkspaceBuild {
+    /// Workspace rooted at `root`, None if it isn't a multi-package workspace
+    pub fn discover(root: &Path) -> Result<Option<Self>> {
+        let root = root
+            .canonicalize()
+            .with_context(|| format!("Failed to resolve {}", root.display()))?;
+        let manifest = root.join("horus.yaml");
+
+        let member_dirs = if manifest.exists() {
+            let yaml: serde_yaml::Value = serde_yaml::from_str(&fs::read_to_string(&manifest)?)
+                .with_context(|| format!("Invalid {}", manifest.display()))?;
+            let Some(workspace) = yaml.get("workspace") else {
+                return Ok(None);
+            };
+            match workspace.get("members").and_then(|m| m.as_sequence()) {
+                Some(patterns) => {
+                    let patterns: Vec<&str> = patterns.iter().filter_map(|p| p.as_str()).collect();
+                    expand_members(&root, &patterns)?
+                }
+                None => find_members(&root),
+            }
+        } else {
+            find_members(&root)
+        };
+
+        if member_dirs.is_empty() {
+            return Ok(None);
+        }
+        let members = order_members(read_members(member_dirs)?)?;
+        Ok(Some(Self { root, members }))
+    }
+
+    /// Shared cargo target dir
+    pub fn target_dir(&self) -> PathBuf {
+        self.root.join(".horus").join("target")
+    }
+
+    /// Build all members in order, skipping unchanged ones
+    pub fn build(&self, release: bool, clean: bool) -> Result<()> {
+        let profile = if release { "release" } else { "debug" };
+        let state_path = self.root.join(".horus").join(STATE_FILE);
+        let mut state = if clean {
+            BuildState::default()
+        } else {
+            fs::read_to_string(&state_path)
+                .ok()
+                .and_then(|s| serde_json::from_str::<BuildState>(&s).ok())
+                .unwrap_or_default()
+        };
+
+        println!(
+            "{} Building workspace {} ({} packages, {} mode)",
+            "".cyan(),
+            self.root.display().to_string().green(),
+            self.members.len(),
+            profile.yellow()
+        );
+
+        let mut hashes: Vec<String> = Vec::with_capacity(self.members.len());
+        let (mut built, mut skipped) = (0, 0);
+        for member in &self.members {
+            let dep_hashes: Vec<&str> = member
+                .depends_on
+                .iter()
+                .map(|&i| hashes[i].as_str())
+                .collect();
+            let hash = member_hash(&member.dir, &dep_hashes, profile)?;
+            let key = format!("{}:{}", member.name, profile);
+
+            let up_to_date = state
+                .members
+                .get(&key)
+                .is_some_and(|prev| prev.hash == hash && prev.artifact.iter().all(|a| a.exists()));
+            if up_to_date {
+                println!("  {} {} (unchanged)", "-".dimmed(), member.name.dimmed());
+                skipped += 1;
+                hashes.push(hash);
+                continue;
+            }
+
+            println!("\n{} {}", "==>".cyan().bold(), member.name.bold());
+            let shared = SharedTarget {
+                package_name: crate_name(&member.name),
+                target_dir: self.target_dir(),
+            };
+            let artifact = build_member(member, release, clean, &shared)
+                .with_context(|| format!("Failed to build workspace member '{}'", member.name))?;
+
+            state.members.insert(
+                key,
+                BuiltMember {
+                    hash: hash.clone(),
+                    artifact,
+                },
+            );
+            // Save after every member so a later failure keeps earlier progress
+            fs::create_dir_all(self.root.join(".horus"))?;
+            fs::write(&state_path, serde_json::to_string_pretty(&state)?)?;
+            built += 1;
+            hashes.push(hash);
+        }
+
+        println!(
+            "\n{} Workspace built: {} rebuilt, {} unchanged",
+            "".green(),
+            built,
+            skipped
+        );
+        Ok(())
+    }
+}
+
+/// Run the single-project build inside a member directory
+fn build_member(
+    member: &Member,
+    release: bool,
+    clean: bool,
+    shared: &SharedTarget,
+) -> Result<Option<PathBuf>> {
+    let previous = std::env::current_dir()?;
+    std::env::set_current_dir(&member.dir)
+        .with_context(|| format!("Failed to enter {}", member.dir.display()))?;
+    let result = super::run::build_project(Vec::new(), release, clean, Some(shared));
+    std::env::set_current_dir(previous)?;
+    result
+}
+
+/// Directories below `root` holding a horus.yaml, not nested in each other
+fn find_members(root: &Path) -> Vec<PathBuf> {
+    let mut members: Vec<PathBuf> = Vec::new();
+    let mut walker = WalkDir::new(root)
+        .min_depth(1)
+        .max_depth(MAX_DISCOVERY_DEPTH)
+        .sort_by_file_name()
+        .into_iter();
+    while let Some(entry) = walker.next() {
+        let Ok(entry) = entry else {
+            continue;
+        };
+        if !entry.file_type().is_dir() {
+            continue;
+        }
+        if is_skipped(entry.path()) {
+            walker.skip_current_dir();
+            continue;
+        }
+        if entry.path().join("horus.yaml").is_file() {
+            members.push(entry.path().to_path_buf());
+            walker.skip_current_dir();
+        }
+    }
+    members
+}
+
+/// Member directories from `members:` entries (`dir` or `dir/*`)
+fn expand_members(root: &Path, patterns: &[&str]) -> Result<Vec<PathBuf>> {
+    let mut members = Vec::new();
+    for pattern in patterns {
+        if let Some(parent) = pattern.strip_suffix("/*") {
+            let parent = root.join(parent);
+            let mut dirs: Vec<PathBuf> = fs::read_dir(&parent)
+                .with_context(|| format!("Workspace member '{}' not found", pattern))?
+                .filter_map(|e| e.ok().map(|e| e.path()))
+                .filter(|p| p.join("horus.yaml").is_file())
+                .collect();
+            dirs.sort();
+            members.extend(dirs);
+        } else {
+            let dir = root.join(pattern);
+            if !dir.join("horus.yaml").is_file() {
+                bail!("Workspace member '{}' has no horus.yaml", pattern);
+            }
+            members.push(dir);
+        }
+    }
+    members.dedup();
+    Ok(members)
+}
+
+/// Read names and workspace-internal dependencies of the members
+fn read_members(dirs: Vec<PathBuf>) -> Result<Vec<Member>> {
+    use crate::dependency_resolver::DependencySource;
+
+    let dirs: Vec<PathBuf> = dirs
+        .into_iter()
+        .map(|d| d.canonicalize().unwrap_or(d))
+        .collect();
+    let names: Vec<String> = dirs.iter().map(|d| member_name(d)).collect();
+
+    let mut members = Vec::with_capacity(dirs.len());
+    for (dir, name) in dirs.iter().zip(&names) {
+        let manifest = dir.join("horus.yaml");
+        let specs = super::run::parse_horus_yaml_dependencies_v2(&manifest.to_string_lossy())
+            .with_context(|| format!("Failed to read dependencies of {}", manifest.display()))?;
+
+        let mut depends_on = Vec::new();
+        for spec in specs {
+            let target = match &spec.source {
+                DependencySource::Path(path) => {
+                    let path = dir.join(path);
+                    let path = path.canonicalize().unwrap_or(path);
+                    dirs.iter().position(|d| *d == path)
+                }
+                _ => names.iter().position(|n| *n == spec.name),
+            };
+            if let Some(i) = target.filter(|&i| dirs[i] != *dir) {
+                if !depends_on.contains(&i) {
+                    depends_on.push(i);
+                }
+            }
+        }
+        members.push(Member {
+            name: name.clone(),
+            dir: dir.clone(),
+            depends_on,
+        });
+    }
+    Ok(members)
+}
+
+/// Sort members so dependencies come first, keeping discovery order otherwise
+fn order_members(members: Vec<Member>) -> Result<Vec<Member>> {
+    let mut order: Vec<usize> = Vec::with_capacity(members.len());
+    let mut placed = vec![false; members.len()];
+    while order.len() < members.len() {
+        let ready = (0..members.len())
+            .find(|&i| !placed[i] && members[i].depends_on.iter().all(|&d| placed[d]));
+        let Some(next) = ready else {
+            let cycle: Vec<&str> = (0..members.len())
+                .filter(|&i| !placed[i])
+                .map(|i| members[i].name.as_str())
+                .collect();
+            bail!(
+                "Dependency cycle between workspace members: {}",
+                cycle.join(", ")
+            );
+        };
+        placed[next] = true;
+        order.push(next);
+    }
+
+    // Re-index dependencies to positions in the new order
+    let position: HashMap<usize, usize> = order.iter().enumerate().map(|(p, &i)| (i, p)).collect();
+    Ok(order
+        .iter()
+        .map(|&i| {
+            let mut member = members[i].clone();
+            member.depends_on = member.depends_on.iter().map(|d| position[d]).collect();
+            member
+        })
+        .collect())
+}
+
+/// Name from horus.yaml, falling back to the directory name
+fn member_name(dir: &Path) -> String {
+    fs::read_to_string(dir.join("horus.yaml"))
+        .ok()
+        .and_then(|s| serde_yaml::from_str::<serde_yaml::Value>(&s).ok())
+        .and_then(|y| y.get("name").and_then(|n| n.as_str()).map(str::to_string))
+        .unwrap_or_else(|| {
+            dir.file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        })
+}
+
+/// Cargo package name of a member
+fn crate_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c.to_ascii_lowercase()
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    if name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        name
+    } else {
+        format!("horus-{}", name)
+    }
+}
+
+fn is_skipped(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|n| n.to_str())
+        .is_some_and(|n| n.starts_with('.') || SKIPPED_DIRS.contains(&n))
+}
+
+/// Hash of a member's sources, its dependencies' hashes and the build settings
+fn member_hash(dir: &Path, dep_hashes: &[&str], profile: &str) -> Result<String> {
+    let mut files: Vec<PathBuf> = WalkDir::new(dir)
+        .into_iter()
+        .filter_entry(|e| e.depth() == 0 || !is_skipped(e.path()))
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .map(|e| e.into_path())
+        .collect();
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for file in &files {
+        let relative = file.strip_prefix(dir).unwrap_or(file);
+        hasher.update(relative.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher
+            .update(fs::read(file).with_context(|| format!("Failed to read {}", file.display()))?);
+        hasher.update([0]);
+    }
+    for dep in dep_hashes {
+        hasher.update(dep.as_bytes());
+    }
+    hasher.update(profile.as_bytes());
+    for var in ["HORUS_DRIVERS", "HORUS_ENABLE"] {
+        hasher.update(std::env::var(var).unwrap_or_default().as_bytes());
+        hasher.update([0]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn package(root: &Path, dir: &str, yaml: &str) {
+        let dir = root.join(dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("horus.yaml"), yaml).unwrap();
+        fs::write(dir.join("main.py"), "print('hi')\n").unwrap();
+    }
+
+    #[test]
+    fn test_discover_and_order() {
+        let root = tempfile::tempdir().unwrap();
+        package(
+            root.path(),
+            "apps/rover",
+            "name: rover\ndependencies:\n  planner:\n    path: ../../planner\n",
+        );
+        package(
+            root.path(),
+            "planner",
+            "name: planner\ndependencies:\n  - lidar_driver\n",
+        );
+        package(root.path(), "drivers/lidar", "name: lidar_driver\n");
+
+        let workspace = WorkspaceBuild::discover(root.path()).unwrap().unwrap();
+        let names: Vec<&str> = workspace.members.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["lidar_driver", "planner", "rover"]);
+        assert_eq!(workspace.members[2].depends_on, [1]);
+        assert_eq!(workspace.members[1].depends_on, [0]);
+
+        // A plain project at the root is not a workspace
+        fs::write(root.path().join("horus.yaml"), "name: rover\n").unwrap();
+        assert!(WorkspaceBuild::discover(root.path()).unwrap().is_none());
+
+        fs::write(
+            root.path().join("horus.yaml"),
+            "workspace:\n  members: [planner, drivers/*]\n",
+        )
+        .unwrap();
+        let workspace = WorkspaceBuild::discover(root.path()).unwrap().unwrap();
+        assert_eq!(workspace.members.len(), 2);
+    }
+
+    #[test]
+    fn test_dependency_cycle() {
+        let root = tempfile::tempdir().unwrap();
+        package(root.path(), "a", "name: a\ndependencies: [b]\n");
+        package(root.path(), "b", "name: b\ndependencies: [a]\n");
+        let err = WorkspaceBuild::discover(root.path()).unwrap_err();
+        assert!(err.to_string().contains("cycle"));
+    }
+
+    #[test]
+    fn test_member_hash() {
+        let root = tempfile::tempdir().unwrap();
+        package(root.path(), "a", "name: a\n");
+        let dir = root.path().join("a");
+
+        let hash = member_hash(&dir, &[], "debug").unwrap();
+        assert_eq!(hash, member_hash(&dir, &[], "debug").unwrap());
+        assert_ne!(hash, member_hash(&dir, &[], "release").unwrap());
+        assert_ne!(hash, member_hash(&dir, &["dep"], "debug").unwrap());
+
+        // Build output doesn't count, sources do
+        fs::create_dir_all(dir.join(".horus/target")).unwrap();
+        fs::write(dir.join(".horus/target/out"), "x").unwrap();
+        assert_eq!(hash, member_hash(&dir, &[], "debug").unwrap());
+        fs::write(dir.join("main.py"), "print('changed')\n").unwrap();
+        assert_ne!(hash, member_hash(&dir, &[], "debug").unwrap());
+    }
+
+    #[test]
+    fn test_crate_name() {
+        assert_eq!(crate_name("Lidar Driver"), "lidar-driver");
+        assert_eq!(crate_name("2d_mapper"), "horus-2d_mapper");
+    }
+}
@@ -0,0 +1,2020 @@
+//! Check command - Validate horus.yaml, source files or a whole workspace
+//!
+//! Every finding is recorded as a [`Diagnostic`] (file, severity, code,
+//! message, span). `horus check` prints them as colored text while it runs;
+//! `--format json` prints the [`CheckReport`] as JSON instead, for editor
+//! integrations and CI annotations, and `--sarif` writes it as SARIF 2.1.0.
+
+use crate::commands::run::parse_horus_yaml_dependencies_v2;
+use crate::dependency_resolver::{DependencySource, DependencySpec};
+use crate::sarif::{relative_uri, Rule, SarifReport};
+use crate::static_analysis;
+use anyhow::{bail, Result};
+use colored::*;
+use serde_json::{json, Value};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use walkdir::WalkDir;
+
+/// Version of the `--format json` output
+pub const JSON_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum CheckFormat {
+    /// Colored progress and summary
+    #[default]
+    Text,
+    /// One JSON document with all diagnostics, nothing else on stdout
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// Location in a file, 1-based
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub line: usize,
+    pub column: Option<usize>,
+}
+
+impl Span {
+    pub fn line(line: usize) -> Self {
+        Self { line, column: None }
+    }
+}
+
+/// A single finding of `horus check`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Diagnostic {
+    pub file: PathBuf,
+    pub severity: Severity,
+    pub rule: Rule,
+    pub message: String,
+    pub span: Option<Span>,
+}
+
+impl Diagnostic {
+    /// Stable diagnostic code (`horus/manifest`)
+    pub fn code(&self) -> &'static str {
+        self.rule.id()
+    }
+}
+
+/// Result of a check
+#[derive(Debug, Clone)]
+pub struct CheckReport {
+    /// Checked path; diagnostic files are reported relative to it
+    pub root: PathBuf,
+    pub files_checked: usize,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl CheckReport {
+    pub fn errors(&self) -> usize {
+        self.count(Severity::Error)
+    }
+
+    pub fn warnings(&self) -> usize {
+        self.count(Severity::Warning)
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.diagnostics
+            .iter()
+            .filter(|d| d.severity == severity)
+            .count()
+    }
+
+    /// The `--format json` document
+    pub fn to_json(&self) -> Value {
+        let diagnostics: Vec<Value> = self
+            .diagnostics
+            .iter()
+            .map(|d| {
+                json!({
+                    "file": relative_uri(&self.root, &d.file),
+                    "severity": d.severity.as_str(),
+                    "code": d.code(),
+                    "message": d.message,
+                    "span": d.span.map(|s| json!({ "line": s.line, "column": s.column })),
+                })
+            })
+            .collect();
+        json!({
+            "version": JSON_FORMAT_VERSION,
+            "root": self.root.display().to_string(),
+            "files_checked": self.files_checked,
+            "summary": { "errors": self.errors(), "warnings": self.warnings() },
+            "diagnostics": diagnostics,
+        })
+    }
+
+    pub fn to_sarif(&self) -> SarifReport<'_> {
+        SarifReport::new(&self.root, &self.diagnostics)
+    }
+}
+
+/// Check a workspace directory, a Rust or Python file, or a horus.yaml
+///
+/// With `quiet`, warnings are dropped from the report.
+pub fn run_check(path: &Path, quiet: bool, format: CheckFormat) -> Result<CheckReport> {
+    if !path.exists() {
+        bail!("Path not found: {}", path.display());
+    }
+    let root = if path.is_dir() {
+        path.to_path_buf()
+    } else {
+        path.parent().unwrap_or(Path::new(".")).to_path_buf()
+    };
+    let mut checker = Checker {
+        report: CheckReport {
+            root,
+            files_checked: 0,
+            diagnostics: Vec::new(),
+        },
+        quiet,
+        text: format == CheckFormat::Text,
+    };
+
+    if path.is_dir() {
+        checker.workspace(path);
+    } else {
+        match path.extension().and_then(|s| s.to_str()) {
+            Some("rs") => checker.rust_file(path)?,
+            Some("py") => checker.python_file(path),
+            _ => checker.manifest(path),
+        }
+    }
+    Ok(checker.report)
+}
+
+/// Collects diagnostics, printing progress in text mode
+struct Checker {
+    report: CheckReport,
+    quiet: bool,
+    text: bool,
+}
+
+impl Checker {
+    fn say(&self, line: impl Display) {
+        if self.text {
+            println!("{}", line);
+        }
+    }
+
+    /// Start a `  ▸ label... ` progress line, finished by [`Checker::mark`]
+    fn step(&self, label: &str) {
+        if self.text {
+            print!("  {} {}... ", "".cyan(), label);
+            std::io::stdout().flush().ok();
+        }
+    }
+
+    fn mark(&self, mark: impl Display) {
+        self.say(mark);
+    }
+
+    fn error(&mut self, rule: Rule, file: &Path, span: Option<Span>, message: impl Into<String>) {
+        self.push(Severity::Error, rule, file, span, message.into());
+    }
+
+    fn warning(&mut self, rule: Rule, file: &Path, span: Option<Span>, message: impl Into<String>) {
+        if !self.quiet {
+            self.push(Severity::Warning, rule, file, span, message.into());
+        }
+    }
+
+    fn push(
+        &mut self,
+        severity: Severity,
+        rule: Rule,
+        file: &Path,
+        span: Option<Span>,
+        message: String,
+    ) {
+        self.report.diagnostics.push(Diagnostic {
+            file: file.to_path_buf(),
+            severity,
+            rule,
+            message,
+            span,
+        });
+    }
+
+    fn relative<'a>(&self, path: &'a Path) -> &'a Path {
+        path.strip_prefix(&self.report.root).unwrap_or(path)
+    }
+
+    // ═══════════════════════════════════════════════════════════════════
+    // Workspace
+    // ═══════════════════════════════════════════════════════════════════
+
+    fn workspace(&mut self, target_path: &Path) {
+        self.say(format!(
+            "{} Scanning workspace: {}\n",
+            "".cyan().bold(),
+            target_path
+                .canonicalize()
+                .unwrap_or(target_path.to_path_buf())
+                .display()
+        ));
+
+        let mut horus_yamls: Vec<PathBuf> = Vec::new();
+        let mut rust_files: Vec<PathBuf> = Vec::new();
+        let mut python_files: Vec<PathBuf> = Vec::new();
+
+        // Collect all files to check
+        for entry in WalkDir::new(target_path)
+            .into_iter()
+            .filter_entry(|e| {
+                let name = e.file_name().to_string_lossy();
+                // Skip hidden dirs, target, node_modules, __pycache__, .horus
+                e.depth() == 0
+                    || (!name.starts_with('.')
+                        && name != "target"
+                        && name != "node_modules"
+                        && name != "__pycache__")
+            })
+            .filter_map(|e| e.ok())
+        {
+            let path = entry.path();
+            if path.is_file() {
+                let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+                let ext = path.extension().and_then(|e| e.to_str());
+
+                if filename == "horus.yaml" {
+                    horus_yamls.push(path.to_path_buf());
+                } else if ext == Some("rs") && filename != "build.rs" {
+                    rust_files.push(path.to_path_buf());
+                } else if ext == Some("py") {
+                    python_files.push(path.to_path_buf());
+                }
+            }
+        }
+
+        self.say(format!("  Found {} horus.yaml file(s)", horus_yamls.len()));
+        self.say(format!("  Found {} Rust file(s)", rust_files.len()));
+        self.say(format!("  Found {} Python file(s)\n", python_files.len()));
+
+        // Find Cargo.toml directories for deep Rust checking
+        let mut cargo_dirs: Vec<PathBuf> = WalkDir::new(target_path)
+            .into_iter()
+            .filter_entry(|e| {
+                let name = e.file_name().to_string_lossy();
+                e.depth() == 0
+                    || (!name.starts_with('.') && name != "target" && name != "node_modules")
+            })
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_name() == "Cargo.toml")
+            .filter_map(|e| e.path().parent().map(Path::to_path_buf))
+            .collect();
+        cargo_dirs.sort();
+        cargo_dirs.dedup();
+
+        self.workspace_manifests(&horus_yamls);
+        self.workspace_cargo(&cargo_dirs);
+        self.workspace_python(&python_files);
+        self.workspace_topics(&horus_yamls, &rust_files, &python_files);
+        self.workspace_ticks(&rust_files, &python_files);
+
+        // Summary
+        self.say(format!("\n{}", "━".repeat(60).dimmed()));
+        self.say(format!("{} Workspace Check Summary\n", "".cyan().bold()));
+        self.say(format!("  Files checked: {}", self.report.files_checked));
+        let (errors, warnings) = (self.report.errors(), self.report.warnings());
+        if errors == 0 && warnings == 0 {
+            self.say(format!("  Status: {} All checks passed!", "".green()));
+        } else {
+            if errors > 0 {
+                self.say(format!("  Errors: {} {}", "".red(), errors));
+            }
+            if warnings > 0 {
+                self.say(format!("  Warnings: {} {}", "".yellow(), warnings));
+            }
+        }
+    }
+
+    fn phase(&self, title: String) {
+        self.say(format!("\n{}", "━".repeat(60).dimmed()));
+        self.say(format!("{} {}\n", "".cyan().bold(), title));
+    }
+
+    /// Phase 1: Validate horus.yaml manifests
+    fn workspace_manifests(&mut self, horus_yamls: &[PathBuf]) {
+        if horus_yamls.is_empty() {
+            return;
+        }
+        self.phase("Phase 1: Validating horus.yaml manifests...".to_string());
+
+        for yaml_path in horus_yamls {
+            self.say(format!(
+                "  {} {}",
+                "".cyan(),
+                self.relative(yaml_path).display()
+            ));
+            self.report.files_checked += 1;
+
+            let content = match fs::read_to_string(yaml_path) {
+                Ok(content) => content,
+                Err(e) => {
+                    self.say(format!("      {} Read error: {}", "".red(), e));
+                    self.error(
+                        Rule::Manifest,
+                        yaml_path,
+                        None,
+                        format!("Read error: {}", e),
+                    );
+                    continue;
+                }
+            };
+            let yaml = match serde_yaml::from_str::<serde_yaml::Value>(&content) {
+                Ok(yaml) => yaml,
+                Err(e) => {
+                    self.say(format!("      {} YAML parse error: {}", "".red(), e));
+                    self.error(
+                        Rule::Manifest,
+                        yaml_path,
+                        yaml_span(&e),
+                        format!("YAML parse error: {}", e),
+                    );
+                    continue;
+                }
+            };
+
+            let mut file_errors: Vec<(Rule, String)> = Vec::new();
+            let base_dir = yaml_path.parent().unwrap_or(Path::new("."));
+
+            // Required fields
+            if yaml.get("name").is_none() {
+                file_errors.push((Rule::Manifest, "missing 'name' field".to_string()));
+            }
+            let language = yaml.get("language").and_then(|l| l.as_str());
+            if language.is_none() {
+                file_errors.push((Rule::Manifest, "missing 'language' field".to_string()));
+            }
+
+            // Check main file exists
+            if let Some(lang) = language {
+                let main_exists = match lang {
+                    "rust" => {
+                        base_dir.join("main.rs").exists()
+                            || base_dir.join("src/main.rs").exists()
+                            || base_dir.join("Cargo.toml").exists()
+                    }
+                    "python" => base_dir.join("main.py").exists(),
+                    _ => true,
+                };
+                if !main_exists {
+                    file_errors.push((
+                        Rule::Manifest,
+                        format!("main file not found for '{}'", lang),
+                    ));
+                }
+            }
+
+            // Validate path dependencies exist
+            if let Ok(deps) = parse_horus_yaml_dependencies_v2(yaml_path.to_str().unwrap_or("")) {
+                for dep in &deps {
+                    if let DependencySource::Path(path) = &dep.source {
+                        let dep_path = base_dir.join(path);
+                        if !dep_path.exists() {
+                            file_errors.push((
+                                Rule::Dependency,
+                                format!(
+                                    "dependency '{}' path not found: {}",
+                                    dep.name,
+                                    dep_path.display()
+                                ),
+                            ));
+                        }
+                    }
+                }
+            }
+
+            if file_errors.is_empty() {
+                self.say(format!("      {} manifest valid", "".green()));
+            }
+            for (rule, err) in file_errors {
+                self.say(format!("      {} {}", "".red(), err));
+                self.error(rule, yaml_path, None, err);
+            }
+        }
+    }
+
+    /// Phase 2: Deep Rust compilation check (cargo check)
+    fn workspace_cargo(&mut self, cargo_dirs: &[PathBuf]) {
+        if cargo_dirs.is_empty() {
+            return;
+        }
+        self.phase("Phase 2: Deep Rust check (cargo check)...".to_string());
+
+        for cargo_dir in cargo_dirs {
+            let rel_path = self.relative(cargo_dir);
+            let display_path = if rel_path.as_os_str().is_empty() {
+                ".".to_string()
+            } else {
+                rel_path.display().to_string()
+            };
+            if self.text {
+                print!("  {} {} ... ", "".cyan(), display_path);
+                std::io::stdout().flush().ok();
+            }
+            self.report.files_checked += 1;
+
+            let output = Command::new("cargo")
+                .arg("check")
+                .arg("--message-format=short")
+                .current_dir(cargo_dir)
+                .output();
+
+            match output {
+                Ok(result) if result.status.success() => self.mark("".green()),
+                Ok(result) => {
+                    self.mark("".red());
+                    let stderr = String::from_utf8_lossy(&result.stderr);
+                    for line in stderr.lines().take(5) {
+                        if line.contains("error") {
+                            self.say(format!("      {} {}", "".red(), line.trim()));
+                        }
+                    }
+                    let before = self.report.diagnostics.len();
+                    for line in stderr.lines().filter(|l| l.contains(": error")) {
+                        self.report
+                            .diagnostics
+                            .push(cargo_diagnostic(cargo_dir, line));
+                    }
+                    if self.report.diagnostics.len() == before {
+                        self.error(
+                            Rule::CargoCheck,
+                            &cargo_dir.join("Cargo.toml"),
+                            None,
+                            "cargo check failed",
+                        );
+                    }
+                }
+                Err(e) => {
+                    self.say(format!("{} cargo error: {}", "".yellow(), e));
+                    self.warning(
+                        Rule::Tooling,
+                        &cargo_dir.join("Cargo.toml"),
+                        None,
+                        format!("Could not run cargo check: {}", e),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Phase 3: Python validation (syntax + imports)
+    fn workspace_python(&mut self, python_files: &[PathBuf]) {
+        if python_files.is_empty() {
+            return;
+        }
+        self.phase("Phase 3: Python validation (syntax + imports)...".to_string());
+
+        for py_path in python_files {
+            if self.text {
+                print!("  {} {} ", "".cyan(), self.relative(py_path).display());
+                std::io::stdout().flush().ok();
+            }
+            self.report.files_checked += 1;
+
+            match Command::new("python3")
+                .arg("-m")
+                .arg("py_compile")
+                .arg(py_path)
+                .output()
+            {
+                Ok(result) if result.status.success() => {
+                    // Syntax OK - now check imports
+                    let import_check = Command::new("python3")
+                        .arg("-c")
+                        .arg(import_check_script(py_path))
+                        .output();
+                    match import_check {
+                        Ok(r) if !r.status.success() => {
+                            self.mark("".yellow());
+                            let err = String::from_utf8_lossy(&r.stderr);
+                            let first = err.lines().next().unwrap_or("import failed").trim();
+                            self.say(format!("      {} {}", "".yellow(), first));
+                            self.warning(Rule::PythonImport, py_path, None, first);
+                        }
+                        _ => self.mark("".green()),
+                    }
+                }
+                Ok(result) => {
+                    self.mark("".red());
+                    let error = String::from_utf8_lossy(&result.stderr);
+                    self.say(format!(
+                        "      {} {}",
+                        "".red(),
+                        error.lines().next().unwrap_or("").trim()
+                    ));
+                    self.error(
+                        Rule::PythonSyntax,
+                        py_path,
+                        python_error_line(&error).map(Span::line),
+                        error.trim(),
+                    );
+                }
+                Err(_) => {
+                    self.mark("⊘".dimmed());
+                    self.warning(
+                        Rule::Tooling,
+                        py_path,
+                        None,
+                        "python3 not found, Python files not checked",
+                    );
+                }
+            }
+        }
+    }
+
+    /// Phases 4 and 5: Topic usage against the topics! registry and the
+    /// publisher/subscriber graph
+    fn workspace_topics(
+        &mut self,
+        horus_yamls: &[PathBuf],
+        rust_files: &[PathBuf],
+        python_files: &[PathBuf],
+    ) {
+        let mut topic_registry = Vec::new();
+        let mut topic_usages = Vec::new();
+        for yaml_path in horus_yamls {
+            if let Ok(content) = fs::read_to_string(yaml_path) {
+                topic_registry.extend(static_analysis::manifest_topics(&content, yaml_path));
+            }
+        }
+        for rs_path in rust_files {
+            if let Ok(content) = fs::read_to_string(rs_path) {
+                let scan = static_analysis::scan_rust_topics(&content, rs_path);
+                topic_registry.extend(scan.registry);
+                topic_usages.extend(scan.usages);
+            }
+        }
+        for py_path in python_files {
+            if let Ok(content) = fs::read_to_string(py_path) {
+                topic_usages.extend(static_analysis::python_topic_usages(&content, py_path));
+            }
+        }
+
+        if !topic_registry.is_empty() {
+            self.workspace_registry(&topic_registry, &topic_usages);
+        }
+        if topic_usages.iter().any(|usage| usage.role.is_some()) {
+            self.workspace_graph(&topic_registry, &topic_usages);
+        }
+    }
+
+    fn workspace_registry(
+        &mut self,
+        topic_registry: &[static_analysis::RegisteredTopic],
+        topic_usages: &[static_analysis::TopicUsage],
+    ) {
+        self.phase(format!(
+            "Phase 4: Topic registry ({} topics, {} usages)...",
+            topic_registry.len(),
+            topic_usages.len()
+        ));
+
+        let issues = static_analysis::check_topic_usages(topic_registry, topic_usages);
+        if issues.is_empty() {
+            self.say(format!(
+                "  {} all topic usages match the registry",
+                "".green()
+            ));
+        }
+        for issue in &issues {
+            match issue {
+                static_analysis::TopicIssue::TypeMismatch { usage, registered } => {
+                    let message = format!(
+                        "'{}' used as {} but registered as {}",
+                        usage.topic,
+                        usage.type_name.as_deref().unwrap_or("?"),
+                        registered
+                    );
+                    self.say(format!(
+                        "  {} {}:{} {}",
+                        "".red(),
+                        self.relative(&usage.file).display(),
+                        usage.line,
+                        message
+                    ));
+                    self.error(
+                        Rule::TopicTypeMismatch,
+                        &usage.file,
+                        Some(Span::line(usage.line)),
+                        message,
+                    );
+                }
+                static_analysis::TopicIssue::Unregistered { usage, suggestion } => {
+                    let message = format!(
+                        "'{}' is not in the topic registry{}",
+                        usage.topic,
+                        suggestion
+                            .as_ref()
+                            .map(|name| format!(" (did you mean '{}'?)", name))
+                            .unwrap_or_default()
+                    );
+                    if !self.quiet {
+                        self.say(format!(
+                            "  {} {}:{} {}",
+                            "".yellow(),
+                            self.relative(&usage.file).display(),
+                            usage.line,
+                            message
+                        ));
+                    }
+                    self.warning(
+                        Rule::TopicUnregistered,
+                        &usage.file,
+                        Some(Span::line(usage.line)),
+                        message,
+                    );
+                }
+            }
+        }
+    }
+
+    fn workspace_graph(
+        &mut self,
+        topic_registry: &[static_analysis::RegisteredTopic],
+        topic_usages: &[static_analysis::TopicUsage],
+    ) {
+        let topics: HashSet<&str> = topic_usages.iter().map(|u| u.topic.as_str()).collect();
+        self.phase(format!(
+            "Phase 5: Topic graph ({} topics, {} endpoints)...",
+            topics.len(),
+            topic_usages.len()
+        ));
+
+        let issues = static_analysis::check_topic_graph(topic_registry, topic_usages);
+        if issues.is_empty() {
+            self.say(format!(
+                "  {} every topic has a publisher and a subscriber",
+                "".green()
+            ));
+        }
+        for issue in issues {
+            let (rule, message, usage) = match issue {
+                static_analysis::GraphIssue::NoPublisher { subscriber } => (
+                    Rule::TopicNoPublisher,
+                    format!("'{}' is subscribed but never published", subscriber.topic),
+                    subscriber,
+                ),
+                static_analysis::GraphIssue::NoSubscriber { publisher } => (
+                    Rule::TopicNoSubscriber,
+                    format!("'{}' is published but never subscribed", publisher.topic),
+                    publisher,
+                ),
+                static_analysis::GraphIssue::TypeConflict { first, other } => {
+                    let message = format!(
+                        "'{}' used as {} here but as {} at {}:{}",
+                        other.topic,
+                        other.type_name.as_deref().unwrap_or("?"),
+                        first.type_name.as_deref().unwrap_or("?"),
+                        self.relative(&first.file).display(),
+                        first.line
+                    );
+                    self.say(format!(
+                        "  {} {}:{} {}",
+                        "".red(),
+                        self.relative(&other.file).display(),
+                        other.line,
+                        message
+                    ));
+                    self.error(
+                        Rule::TopicTypeConflict,
+                        &other.file,
+                        Some(Span::line(other.line)),
+                        message,
+                    );
+                    continue;
+                }
+            };
+            if !self.quiet {
+                self.say(format!(
+                    "  {} {}:{} {}",
+                    "".yellow(),
+                    self.relative(&usage.file).display(),
+                    usage.line,
+                    message
+                ));
+            }
+            self.warning(rule, &usage.file, Some(Span::line(usage.line)), message);
+        }
+    }
+
+    /// Phase 6: Real-time hazards in tick()
+    fn workspace_ticks(&mut self, rust_files: &[PathBuf], python_files: &[PathBuf]) {
+        let mut hazards = Vec::new();
+        for rs_path in rust_files {
+            if let Ok(content) = fs::read_to_string(rs_path) {
+                hazards.extend(static_analysis::scan_rust_tick_hazards(&content, rs_path));
+            }
+        }
+        for py_path in python_files {
+            if let Ok(content) = fs::read_to_string(py_path) {
+                hazards.extend(static_analysis::python_tick_hazards(&content, py_path));
+            }
+        }
+        if hazards.is_empty() || self.quiet {
+            return;
+        }
+
+        self.phase(format!(
+            "Phase 6: Real-time hazards in tick() ({} found)...",
+            hazards.len()
+        ));
+        self.tick_hazards(&hazards);
+    }
+
+    /// Report tick() hazards as warnings
+    fn tick_hazards(&mut self, hazards: &[static_analysis::TickHazard]) {
+        for hazard in hazards {
+            if !self.quiet {
+                self.say(format!(
+                    "  {} {}:{} {} [{}]",
+                    "".yellow(),
+                    self.relative(&hazard.file).display(),
+                    hazard.line,
+                    hazard.message,
+                    hazard.kind.id()
+                ));
+            }
+            self.warning(
+                tick_rule(hazard.kind),
+                &hazard.file,
+                Some(Span::line(hazard.line)),
+                hazard.message.clone(),
+            );
+        }
+        if !hazards.is_empty() && !self.quiet {
+            self.say(format!(
+                "    {} suppress with a `horus:allow({})` comment on the line",
+                "Hint:".dimmed(),
+                hazards[0].kind.id()
+            ));
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════
+    // Single files
+    // ═══════════════════════════════════════════════════════════════════
+
+    fn rust_file(&mut self, path: &Path) -> Result<()> {
+        self.say(format!(
+            "{} Checking Rust file: {}\n",
+            "".cyan(),
+            path.display()
+        ));
+        self.report.files_checked += 1;
+
+        self.step("Parsing Rust syntax");
+        let content = fs::read_to_string(path)?;
+        match syn::parse_file(&content) {
+            Ok(_) => {
+                self.mark("".green());
+                self.say(format!("\n{} Syntax check passed!", "".green().bold()));
+            }
+            Err(e) => {
+                self.mark("".red());
+                self.say(format!("\n{} Syntax error:", "[FAIL]".red().bold()));
+                self.say(format!("  {}", e));
+                let start = e.span().start();
+                self.error(
+                    Rule::RustSyntax,
+                    path,
+                    Some(Span {
+                        line: start.line,
+                        column: Some(start.column + 1),
+                    }),
+                    format!("Rust syntax error: {}", e),
+                );
+                return Ok(());
+            }
+        }
+
+        let hazards = static_analysis::scan_rust_tick_hazards(&content, path);
+        if !hazards.is_empty() && !self.quiet {
+            self.say(format!(
+                "\n{} Real-time hazards in tick():",
+                "[WARNING]".yellow()
+            ));
+        }
+        self.tick_hazards(&hazards);
+
+        // Hardware requirements are advice printed for humans
+        if self.text {
+            if let Err(e) = crate::commands::run::check_hardware_requirements(path, "rust") {
+                eprintln!("\n{} Hardware check error: {}", "[WARNING]".yellow(), e);
+            }
+        }
+        Ok(())
+    }
+
+    fn python_file(&mut self, path: &Path) {
+        self.say(format!(
+            "{} Checking Python file: {}\n",
+            "".cyan(),
+            path.display()
+        ));
+        self.report.files_checked += 1;
+
+        self.step("Parsing Python syntax");
+        match Command::new("python3")
+            .arg("-m")
+            .arg("py_compile")
+            .arg(path)
+            .output()
+        {
+            Ok(result) if result.status.success() => {
+                self.mark("".green());
+                self.say(format!("\n{} Syntax check passed!", "".green().bold()));
+
+                let hazards = fs::read_to_string(path)
+                    .map(|content| static_analysis::python_tick_hazards(&content, path))
+                    .unwrap_or_default();
+                if !hazards.is_empty() && !self.quiet {
+                    self.say(format!(
+                        "\n{} Real-time hazards in tick():",
+                        "[WARNING]".yellow()
+                    ));
+                }
+                self.tick_hazards(&hazards);
+            }
+            Ok(result) => {
+                self.mark("".red());
+                let error = String::from_utf8_lossy(&result.stderr);
+                self.say(format!("\n{} Syntax error:", "[FAIL]".red().bold()));
+                self.say(format!("  {}", error));
+                self.error(
+                    Rule::PythonSyntax,
+                    path,
+                    python_error_line(&error).map(Span::line),
+                    format!("Python syntax error: {}", error.trim()),
+                );
+            }
+            Err(e) => {
+                self.mark("[WARNING]".yellow());
+                self.say(format!(
+                    "\n{} Could not check Python syntax (python3 not found): {}",
+                    "[WARNING]".yellow(),
+                    e
+                ));
+                self.warning(
+                    Rule::Tooling,
+                    path,
+                    None,
+                    format!("Could not check Python syntax (python3 not found): {}", e),
+                );
+            }
+        }
+    }
+
+    // ═══════════════════════════════════════════════════════════════════
+    // horus.yaml
+    // ═══════════════════════════════════════════════════════════════════
+
+    fn manifest(&mut self, horus_yaml_path: &Path) {
+        self.say(format!(
+            "{} Checking {}...\n",
+            "".cyan(),
+            horus_yaml_path.display()
+        ));
+        self.report.files_checked += 1;
+        let base_dir = horus_yaml_path.parent().unwrap_or(Path::new("."));
+
+        // 1. YAML Syntax Validation
+        self.step("Validating YAML syntax");
+        let yaml_value: Option<serde_yaml::Value> = match fs::read_to_string(horus_yaml_path) {
+            Ok(content) => {
+                self.mark("".green());
+                match serde_yaml::from_str(&content) {
+                    Ok(val) => Some(val),
+                    Err(e) => {
+                        self.error(
+                            Rule::Manifest,
+                            horus_yaml_path,
+                            yaml_span(&e),
+                            format!("Invalid YAML syntax: {}", e),
+                        );
+                        None
+                    }
+                }
+            }
+            Err(e) => {
+                self.mark("".red());
+                self.error(
+                    Rule::Manifest,
+                    horus_yaml_path,
+                    None,
+                    format!("Cannot read file: {}", e),
+                );
+                None
+            }
+        };
+
+        // 2. Manifest fields
+        if let Some(yaml) = &yaml_value {
+            self.manifest_fields(horus_yaml_path, yaml);
+        }
+
+        // 3. Parse Dependencies
+        self.step("Parsing dependencies");
+        let dep_specs = match parse_horus_yaml_dependencies_v2(&horus_yaml_path.to_string_lossy()) {
+            Ok(specs) => {
+                self.mark("".green());
+                specs
+            }
+            Err(e) => {
+                self.mark("".red());
+                self.error(
+                    Rule::Dependency,
+                    horus_yaml_path,
+                    None,
+                    format!("Failed to parse dependencies: {}", e),
+                );
+                Vec::new()
+            }
+        };
+        let our_name = yaml_value
+            .as_ref()
+            .and_then(|y| y.get("name"))
+            .and_then(|n| n.as_str())
+            .unwrap_or("");
+        self.dependencies(horus_yaml_path, our_name, &dep_specs);
+
+        // 8. Workspace Structure Check
+        self.say("");
+        self.step("Checking workspace structure");
+        let horus_dir = base_dir.join(".horus");
+        if horus_dir.is_dir() {
+            self.mark("".green());
+        } else {
+            self.mark("".yellow());
+            self.warning(
+                Rule::Environment,
+                horus_yaml_path,
+                None,
+                "No .horus/ workspace directory found - will be created on first run",
+            );
+        }
+
+        // 9. Dependency Installation Check
+        self.step("Checking installed dependencies");
+        if horus_dir.exists() {
+            let packages_dir = horus_dir.join("packages");
+            if packages_dir.exists() {
+                let missing_deps: Vec<&str> = dep_specs
+                    .iter()
+                    // Path deps are checked above, git deps are cloned by horus run
+                    .filter(|spec| matches!(spec.source, DependencySource::Registry))
+                    .filter(|spec| !packages_dir.join(&spec.name).exists())
+                    .map(|spec| spec.name.as_str())
+                    .collect();
+                if missing_deps.is_empty() {
+                    self.mark("".green());
+                } else {
+                    self.mark("".yellow());
+                    self.warning(
+                        Rule::Dependency,
+                        horus_yaml_path,
+                        None,
+                        format!(
+                            "Missing dependencies: {} (run 'horus run' to install)",
+                            missing_deps.join(", ")
+                        ),
+                    );
+                }
+            } else {
+                self.mark("".yellow());
+                self.warning(
+                    Rule::Dependency,
+                    horus_yaml_path,
+                    None,
+                    "No packages directory - dependencies not installed yet",
+                );
+            }
+        } else {
+            self.mark("⊘".dimmed());
+        }
+
+        let language = yaml_value
+            .as_ref()
+            .and_then(|y| y.get("language"))
+            .and_then(|l| l.as_str());
+
+        // 10. Toolchain Check
+        self.step("Checking toolchain");
+        match language {
+            Some(language) => {
+                let toolchain = match language {
+                    "rust" => Some("rustc"),
+                    "python" => Some("python3"),
+                    _ => None,
+                };
+                let available = toolchain.is_some_and(|cmd| {
+                    Command::new(cmd)
+                        .arg("--version")
+                        .output()
+                        .is_ok_and(|o| o.status.success())
+                });
+                if available {
+                    self.mark("".green());
+                } else {
+                    self.mark("".red());
+                    self.error(
+                        Rule::Tooling,
+                        horus_yaml_path,
+                        None,
+                        format!("Required toolchain for '{}' not found in PATH", language),
+                    );
+                }
+            }
+            None => self.mark("⊘".dimmed()),
+        }
+
+        // 11. Code Validation
+        self.step("Validating code syntax");
+        match language {
+            Some("rust") => self.manifest_rust_build(base_dir),
+            Some("python") => self.manifest_python_syntax(base_dir),
+            _ => self.mark("⊘".dimmed()),
+        }
+
+        // 12. HORUS System Check
+        self.say("");
+        self.step("Checking HORUS installation");
+        self.mark(format!("v{}", env!("CARGO_PKG_VERSION").dimmed()));
+
+        self.environment(horus_yaml_path, base_dir);
+
+        // 15. API Usage Check (basic pattern matching)
+        self.step("Checking API usage");
+        match language {
+            Some("rust") => self.rust_api_usage(base_dir, &dep_specs),
+            Some("python") => self.python_api_usage(base_dir, &dep_specs),
+            _ => self.mark("⊘".dimmed()),
+        }
+
+        self.manifest_summary();
+    }
+
+    fn manifest_fields(&mut self, file: &Path, yaml: &serde_yaml::Value) {
+        let base_dir = file.parent().unwrap_or(Path::new("."));
+
+        self.step("Checking required fields");
+        let missing_fields: Vec<&str> = ["name", "version"]
+            .into_iter()
+            .filter(|field| yaml.get(field).is_none())
+            .collect();
+        if missing_fields.is_empty() {
+            self.mark("".green());
+        } else {
+            self.mark("".red());
+            self.error(
+                Rule::Manifest,
+                file,
+                None,
+                format!("Missing required fields: {}", missing_fields.join(", ")),
+            );
+        }
+
+        // Optional fields
+        for field in ["description", "author"] {
+            if yaml.get(field).is_none() {
+                self.warning(
+                    Rule::Manifest,
+                    file,
+                    None,
+                    format!("Optional field missing: {}", field),
+                );
+            }
+        }
+
+        // License warning (encourage projects to declare their license)
+        self.step("Checking license field");
+        match yaml.get("license").and_then(|l| l.as_str()) {
+            Some(license) if !license.trim().is_empty() => {
+                self.mark(format!("{} ({})", "".green(), license.dimmed()));
+            }
+            _ => {
+                self.mark("[WARNING]".yellow());
+                self.warning(
+                    Rule::Manifest,
+                    file,
+                    None,
+                    "No license specified. Consider adding a license field (e.g., Apache-2.0, BSD-3-Clause).",
+                );
+            }
+        }
+
+        // Language validation
+        self.step("Validating language field");
+        match yaml.get("language").and_then(|l| l.as_str()) {
+            Some("rust" | "python") => self.mark("".green()),
+            Some(language) => {
+                self.mark("".red());
+                self.error(
+                    Rule::Manifest,
+                    file,
+                    None,
+                    format!("Invalid language '{}' - must be: rust or python", language),
+                );
+            }
+            None => {
+                self.mark("".red());
+                self.error(
+                    Rule::Manifest,
+                    file,
+                    None,
+                    "Missing or invalid 'language' field - must be: rust or python",
+                );
+            }
+        }
+
+        // Version format validation
+        self.step("Validating version format");
+        match yaml.get("version") {
+            Some(serde_yaml::Value::String(version)) => match semver::Version::parse(version) {
+                Ok(_) => self.mark("".green()),
+                Err(e) => {
+                    self.mark("".red());
+                    self.error(
+                        Rule::Manifest,
+                        file,
+                        None,
+                        format!(
+                            "Invalid version format '{}': {} (must be valid semver like 0.1.0)",
+                            version, e
+                        ),
+                    );
+                }
+            },
+            Some(_) => {
+                self.mark("".red());
+                self.error(Rule::Manifest, file, None, "Version field must be a string");
+            }
+            None => self.mark("⊘".dimmed()),
+        }
+
+        // Project name validation
+        self.step("Validating project name");
+        match yaml.get("name").and_then(|n| n.as_str()) {
+            Some(name) => {
+                let name_issues = name_issues(name);
+                if name_issues.is_empty() {
+                    self.mark("".green());
+                    if name.chars().any(|c| c.is_uppercase()) {
+                        self.warning(
+                            Rule::Manifest,
+                            file,
+                            None,
+                            format!(
+                                "Project name '{}' contains uppercase - consider using lowercase",
+                                name
+                            ),
+                        );
+                    }
+                } else {
+                    self.mark("".red());
+                    for issue in name_issues {
+                        self.error(
+                            Rule::Manifest,
+                            file,
+                            None,
+                            format!("Invalid project name: {}", issue),
+                        );
+                    }
+                }
+            }
+            None => self.mark("⊘".dimmed()),
+        }
+
+        // Main file existence check
+        self.step("Checking for main file");
+        let main_files: &[&str] = match yaml.get("language").and_then(|l| l.as_str()) {
+            Some("rust") => &["main.rs", "src/main.rs"],
+            Some("python") => &["main.py"],
+            _ => &[],
+        };
+        if main_files.is_empty() {
+            self.mark("⊘".dimmed());
+        } else if main_files.iter().any(|f| base_dir.join(f).exists()) {
+            self.mark("".green());
+        } else {
+            self.mark("".yellow());
+            self.warning(
+                Rule::Manifest,
+                file,
+                None,
+                format!(
+                    "No main file found - expected one of: {}",
+                    main_files.join(", ")
+                ),
+            );
+        }
+    }
+
+    fn dependencies(&mut self, file: &Path, our_name: &str, dep_specs: &[DependencySpec]) {
+        let base_dir = file.parent().unwrap_or(Path::new("."));
+
+        // 4. Check for Duplicates
+        if !dep_specs.is_empty() {
+            self.step("Checking for duplicates");
+            let mut seen = HashSet::new();
+            let duplicates: Vec<&str> = dep_specs
+                .iter()
+                .filter(|spec| !seen.insert(&spec.name))
+                .map(|spec| spec.name.as_str())
+                .collect();
+            if duplicates.is_empty() {
+                self.mark("".green());
+            } else {
+                self.mark("".red());
+                self.error(
+                    Rule::Dependency,
+                    file,
+                    None,
+                    format!("Duplicate dependencies: {}", duplicates.join(", ")),
+                );
+            }
+        }
+
+        // 5. Validate Path Dependencies
+        self.say(format!("\n  {} Checking path dependencies...", "".cyan()));
+        let mut path_deps_found = false;
+        for spec in dep_specs {
+            let DependencySource::Path(path) = &spec.source else {
+                continue;
+            };
+            path_deps_found = true;
+            let resolved_path = base_dir.join(path);
+            if resolved_path.is_dir() {
+                self.say(format!(
+                    "    {} {} ({})",
+                    "".green(),
+                    spec.name,
+                    path.display()
+                ));
+            } else if resolved_path.exists() {
+                self.say(format!(
+                    "    {} {} ({}) - Not a directory",
+                    "[FAIL]".red(),
+                    spec.name,
+                    path.display()
+                ));
+                self.error(
+                    Rule::Dependency,
+                    file,
+                    None,
+                    format!(
+                        "Path dependency '{}' is not a directory: {}",
+                        spec.name,
+                        path.display()
+                    ),
+                );
+            } else {
+                self.say(format!(
+                    "    {} {} ({}) - Path not found",
+                    "[FAIL]".red(),
+                    spec.name,
+                    path.display()
+                ));
+                self.error(
+                    Rule::Dependency,
+                    file,
+                    None,
+                    format!(
+                        "Path dependency '{}' not found: {}",
+                        spec.name,
+                        path.display()
+                    ),
+                );
+            }
+        }
+        if !path_deps_found {
+            self.say(format!("    {} No path dependencies", "".dimmed()));
+        }
+
+        // 6. Circular Dependency Detection (path dependencies pointing back at us)
+        self.say(format!(
+            "\n  {} Checking for circular dependencies...",
+            "".cyan()
+        ));
+        let mut circular_found = false;
+        for spec in dep_specs {
+            let DependencySource::Path(path) = &spec.source else {
+                continue;
+            };
+            let target_yaml = base_dir.join(path).join("horus.yaml");
+            if !target_yaml.exists() {
+                continue;
+            }
+            let Ok(target_deps) = parse_horus_yaml_dependencies_v2(&target_yaml.to_string_lossy())
+            else {
+                continue;
+            };
+            let points_back = target_deps.iter().any(|target_dep| {
+                target_dep.name == our_name
+                    && matches!(target_dep.source, DependencySource::Path(_))
+            });
+            if points_back {
+                circular_found = true;
+                self.say(format!(
+                    "    {} Circular: {} <-> {}",
+                    "[FAIL]".red(),
+                    our_name,
+                    spec.name
+                ));
+                self.error(
+                    Rule::Dependency,
+                    file,
+                    None,
+                    format!(
+                        "Circular dependency detected: {} -> {} -> {}",
+                        our_name, spec.name, our_name
+                    ),
+                );
+            }
+        }
+        if !circular_found {
+            self.say(format!("    {} No circular dependencies", "".green()));
+        }
+
+        // 7. Version Constraint Validation
+        self.say("");
+        self.step("Validating version constraints");
+        for spec in dep_specs {
+            if spec.requirement.to_string() == "*" {
+                self.warning(
+                    Rule::Dependency,
+                    file,
+                    None,
+                    format!(
+                        "Dependency '{}' uses wildcard version (*) - consider pinning to a specific version",
+                        spec.name
+                    ),
+                );
+            }
+        }
+        self.mark("".green());
+    }
+
+    fn manifest_rust_build(&mut self, base_dir: &Path) {
+        let has_cargo = base_dir.join("Cargo.toml").exists();
+        let has_main = base_dir.join("main.rs").exists() || base_dir.join("src/main.rs").exists();
+        if !has_cargo && !has_main {
+            self.mark("⊘".dimmed());
+            return;
+        }
+        let file = if has_cargo {
+            base_dir.join("Cargo.toml")
+        } else {
+            base_dir.to_path_buf()
+        };
+        match Command::new("cargo")
+            .arg("build")
+            .arg("--quiet")
+            .current_dir(base_dir)
+            .output()
+        {
+            Ok(output) if output.status.success() => self.mark("".green()),
+            Ok(_) => {
+                self.mark("".red());
+                self.error(
+                    Rule::CargoCheck,
+                    &file,
+                    None,
+                    "Rust code has compilation errors (run 'cargo build' for details)",
+                );
+            }
+            Err(_) => {
+                self.mark("".yellow());
+                self.warning(
+                    Rule::Tooling,
+                    &file,
+                    None,
+                    "Could not run 'cargo build' - skipping code validation",
+                );
+            }
+        }
+    }
+
+    fn manifest_python_syntax(&mut self, base_dir: &Path) {
+        let main_py = base_dir.join("main.py");
+        if !main_py.exists() {
+            self.mark("⊘".dimmed());
+            return;
+        }
+        match Command::new("python3")
+            .arg("-m")
+            .arg("py_compile")
+            .arg(&main_py)
+            .output()
+        {
+            Ok(output) if output.status.success() => self.mark("".green()),
+            Ok(output) => {
+                self.mark("".red());
+                let error = String::from_utf8_lossy(&output.stderr);
+                self.error(
+                    Rule::PythonSyntax,
+                    &main_py,
+                    python_error_line(&error).map(Span::line),
+                    "Python code has syntax errors",
+                );
+            }
+            Err(_) => {
+                self.mark("".yellow());
+                self.warning(
+                    Rule::Tooling,
+                    &main_py,
+                    None,
+                    "Could not validate Python syntax",
+                );
+            }
+        }
+    }
+
+    /// Registry, shared memory and disk space
+    fn environment(&mut self, file: &Path, base_dir: &Path) {
+        // 13. Registry Connectivity
+        self.step("Checking registry connectivity");
+        let registry_available = Command::new("ping")
+            .args(["-c", "1", "-W", "1", "registry.horus.rs"])
+            .output()
+            .is_ok_and(|o| o.status.success());
+        if registry_available {
+            self.mark("".green());
+        } else {
+            self.mark("⊘".dimmed());
+            self.warning(
+                Rule::Environment,
+                file,
+                None,
+                "Registry not reachable - package installation may fail",
+            );
+        }
+
+        // 14. System Requirements Check
+        self.step("Checking system requirements");
+        let sys_issues = shared_memory_issues();
+        if sys_issues.is_empty() {
+            self.mark("".green());
+        } else {
+            self.mark("".yellow());
+            for issue in sys_issues {
+                self.warning(
+                    Rule::Environment,
+                    file,
+                    None,
+                    format!("System issue: {}", issue),
+                );
+            }
+        }
+
+        // Disk Space Check
+        self.step("Checking available disk space");
+        match available_disk_mb(base_dir) {
+            Some(available_mb) if available_mb < 100 => {
+                self.mark(format!("{} ({}MB free)", "".red(), available_mb));
+                self.error(
+                    Rule::Environment,
+                    file,
+                    None,
+                    format!(
+                        "Critically low disk space: only {}MB available",
+                        available_mb
+                    ),
+                );
+            }
+            Some(available_mb) if available_mb < 500 => {
+                self.mark(format!("{} ({}MB free)", "".yellow(), available_mb));
+                self.warning(
+                    Rule::Environment,
+                    file,
+                    None,
+                    format!(
+                        "Low disk space: only {}MB available (recommended: 500MB+)",
+                        available_mb
+                    ),
+                );
+            }
+            Some(available_mb) => {
+                self.mark(format!("{} ({}MB free)", "".green(), available_mb));
+            }
+            None => self.mark("⊘".dimmed()),
+        }
+    }
+
+    fn rust_api_usage(&mut self, base_dir: &Path, dep_specs: &[DependencySpec]) {
+        let uses_horus = dep_specs
+            .iter()
+            .any(|spec| spec.name == "horus" || spec.name == "horus_macros");
+        if !uses_horus {
+            self.mark("⊘".dimmed());
+            return;
+        }
+        let main_paths = [base_dir.join("main.rs"), base_dir.join("src/main.rs")];
+        let has_scheduler = main_paths.iter().any(|main_path| {
+            fs::read_to_string(main_path).is_ok_and(|content| {
+                content.contains("Scheduler::new") || content.contains("scheduler.register")
+            })
+        });
+        if has_scheduler {
+            self.mark("".green());
+        } else {
+            self.mark("".yellow());
+            let file = main_paths
+                .iter()
+                .find(|p| p.exists())
+                .cloned()
+                .unwrap_or_else(|| base_dir.join("horus.yaml"));
+            self.warning(
+                Rule::ApiUsage,
+                &file,
+                None,
+                "HORUS dependency found but no Scheduler usage detected",
+            );
+        }
+    }
+
+    fn python_api_usage(&mut self, base_dir: &Path, dep_specs: &[DependencySpec]) {
+        let main_py = base_dir.join("main.py");
+        let uses_horus = dep_specs.iter().any(|spec| spec.name == "horus_py");
+        match fs::read_to_string(&main_py) {
+            Ok(content) if uses_horus => {
+                if content.contains("import horus") || content.contains("from horus") {
+                    self.mark("".green());
+                } else {
+                    self.mark("".yellow());
+                    self.warning(
+                        Rule::ApiUsage,
+                        &main_py,
+                        None,
+                        "horus_py dependency but no 'import horus' found",
+                    );
+                }
+            }
+            _ => self.mark("⊘".dimmed()),
+        }
+
+        // Check external Python dependencies
+        self.step("Checking Python external dependencies");
+        if !main_py.exists() {
+            self.mark("⊘".dimmed());
+            return;
+        }
+        match parse_python_imports(&main_py) {
+            Ok(imports) if !imports.is_empty() => {
+                // Packages horus run installed into the workspace venv count too
+                let venv = crate::python_env::PythonEnv::at(base_dir);
+                let venv = venv.is_usable().then_some(venv);
+                let missing_packages: Vec<&str> = imports
+                    .iter()
+                    .filter(|package| match &venv {
+                        Some(venv) => !venv.has_package(package),
+                        None => !system_python_package_exists(package),
+                    })
+                    .map(String::as_str)
+                    .collect();
+
+                if missing_packages.is_empty() {
+                    self.mark(format!("{} ({})", "".green(), imports.len()));
+                } else {
+                    self.mark("".red());
+                    self.error(
+                        Rule::PythonImport,
+                        &main_py,
+                        None,
+                        format!(
+                            "Missing Python packages: {} (add them to horus.yaml dependencies; horus run installs them into {})",
+                            missing_packages.join(", "),
+                            crate::python_env::VENV_DIR
+                        ),
+                    );
+                }
+            }
+            Ok(_) => self.mark("⊘".dimmed()),
+            Err(e) => {
+                self.mark("".yellow());
+                self.warning(
+                    Rule::PythonImport,
+                    &main_py,
+                    None,
+                    format!("Could not parse Python imports: {}", e),
+                );
+            }
+        }
+    }
+
+    /// Warnings list and numbered errors, as `horus check horus.yaml` always printed
+    fn manifest_summary(&self) {
+        if !self.text {
+            return;
+        }
+        let (errors, warnings): (Vec<&Diagnostic>, Vec<&Diagnostic>) = self
+            .report
+            .diagnostics
+            .iter()
+            .partition(|d| d.severity == Severity::Error);
+
+        println!();
+        if !self.quiet {
+            if warnings.is_empty() {
+                println!("{} No warnings detected.", "".green());
+            } else {
+                println!("{} {} warning(s):", "[WARNING]".yellow(), warnings.len());
+                for warning in &warnings {
+                    println!("  - {}", warning.message);
+                }
+            }
+            println!();
+        }
+
+        if errors.is_empty() {
+            println!("{} All checks passed!", "".green().bold());
+        } else {
+            println!(
+                "{} {} error(s) found:\n",
+                "[FAIL]".red().bold(),
+                errors.len()
+            );
+            for (i, err) in errors.iter().enumerate() {
+                println!("  {}. {}", i + 1, err.message);
+            }
+            println!();
+        }
+    }
+}
+
+/// Problems with a project name
+/// SARIF rule of a tick() hazard
+fn tick_rule(kind: static_analysis::TickHazardKind) -> Rule {
+    use static_analysis::TickHazardKind;
+    match kind {
+        TickHazardKind::Sleep => Rule::TickSleep,
+        TickHazardKind::BlockingIo => Rule::TickBlockingIo,
+        TickHazardKind::UnboundedLoop => Rule::TickUnboundedLoop,
+        TickHazardKind::LargeAllocation => Rule::TickLargeAllocation,
+    }
+}
+
+fn name_issues(name: &str) -> Vec<&'static str> {
+    let mut issues = Vec::new();
+    if name.is_empty() {
+        issues.push("name cannot be empty");
+    }
+    if name.contains(' ') {
+        issues.push("name cannot contain spaces");
+    }
+    if name
+        .chars()
+        .any(|c| !c.is_ascii_alphanumeric() && c != '_' && c != '-')
+    {
+        issues.push("name can only contain letters, numbers, hyphens, and underscores");
+    }
+    issues
+}
+
+fn yaml_span(error: &serde_yaml::Error) -> Option<Span> {
+    error.location().map(|l| Span {
+        line: l.line(),
+        column: Some(l.column()),
+    })
+}
+
+/// Diagnostic of a `cargo check --message-format=short` line such as
+/// `src/main.rs:3:5: error[E0425]: cannot find value`
+///
+/// Lines without a location are attributed to the crate's Cargo.toml.
+pub fn cargo_diagnostic(crate_dir: &Path, line: &str) -> Diagnostic {
+    let mut parts = line.splitn(4, ':');
+    let located = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(file), Some(line_no), Some(column), Some(message)) => {
+            line_no.parse::<usize>().ok().map(|line_no| {
+                (
+                    crate_dir.join(file),
+                    Span {
+                        line: line_no,
+                        column: column.trim().parse().ok(),
+                    },
+                    message.trim(),
+                )
+            })
+        }
+        _ => None,
+    };
+    let (file, span, message) = match located {
+        Some((file, span, message)) => (file, Some(span), message),
+        None => (crate_dir.join("Cargo.toml"), None, line.trim()),
+    };
+    Diagnostic {
+        file,
+        severity: Severity::Error,
+        rule: Rule::CargoCheck,
+        message: message.to_string(),
+        span,
+    }
+}
+
+/// Line number of a Python error (`File "main.py", line 3`)
+pub fn python_error_line(stderr: &str) -> Option<usize> {
+    stderr.lines().rev().find_map(|line| {
+        let (_, rest) = line.split_once(", line ")?;
+        rest.split(|c: char| !c.is_ascii_digit())
+            .next()?
+            .parse()
+            .ok()
+    })
+}
+
+/// Python script importing every top-level module a file imports
+fn import_check_script(py_path: &Path) -> String {
+    format!(
+        r#"
+import ast, sys
+try:
+    with open('{}') as f:
+        tree = ast.parse(f.read())
+    imports = set()
+    for node in ast.walk(tree):
+        if isinstance(node, ast.Import):
+            for alias in node.names:
+                imports.add(alias.name.split('.')[0])
+        elif isinstance(node, ast.ImportFrom) and node.module:
+            imports.add(node.module.split('.')[0])
+    for imp in imports:
+        if imp not in ('__future__',):
+            __import__(imp)
+except ModuleNotFoundError as e:
+    print(f'ModuleNotFoundError: {{e.name}}', file=sys.stderr)
+    sys.exit(1)
+except ImportError as e:
+    print(f'ImportError: {{e}}', file=sys.stderr)
+    sys.exit(1)
+"#,
+        py_path.display()
+    )
+}
+
+/// Third-party modules imported by a Python file
+pub fn parse_python_imports(python_file: &Path) -> std::io::Result<Vec<String>> {
+    const STDLIB_MODULES: &[&str] = &[
+        "os",
+        "sys",
+        "re",
+        "json",
+        "math",
+        "time",
+        "datetime",
+        "collections",
+        "itertools",
+        "functools",
+        "pathlib",
+        "typing",
+        "abc",
+        "io",
+        "logging",
+        "argparse",
+        "subprocess",
+        "threading",
+        "multiprocessing",
+        "queue",
+        "socket",
+        "http",
+        "urllib",
+        "email",
+        "xml",
+        "html",
+        "random",
+        "string",
+        "unittest",
+        "pytest",
+        "asyncio",
+        "concurrent",
+        "pickle",
+        "copy",
+        "enum",
+        "dataclasses",
+        "contextlib",
+        "warnings",
+        "traceback",
+        "pdb",
+        "timeit",
+    ];
+
+    let content = fs::read_to_string(python_file)?;
+    let mut imports: Vec<String> = Vec::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        let module = if let Some(rest) = trimmed.strip_prefix("import ") {
+            // "import X", "import X as Y", "import X, Y"
+            rest.split([' ', ',']).next()
+        } else if let Some(rest) = trimmed.strip_prefix("from ") {
+            // "from X import Y"
+            rest.split_whitespace().next()
+        } else {
+            None
+        };
+        let Some(module) = module.and_then(|m| m.split('.').next()) else {
+            continue;
+        };
+        if !module.is_empty() && !imports.iter().any(|i| i == module) {
+            imports.push(module.to_string());
+        }
+    }
+
+    imports.retain(|module| !STDLIB_MODULES.contains(&module.as_str()) && module != "horus");
+    Ok(imports)
+}
+
+fn system_python_package_exists(package: &str) -> bool {
+    Command::new("python3")
+        .args(["-m", "pip", "show", package])
+        .output()
+        .is_ok_and(|o| o.status.success())
+}
+
+/// Problems with the shared memory directory
+fn shared_memory_issues() -> Vec<&'static str> {
+    let mut issues = Vec::new();
+    // On Linux, check /dev/shm permissions; elsewhere, that the base dir can be created
+    #[cfg(target_os = "linux")]
+    if horus_core::memory::has_native_shm() {
+        use std::os::unix::fs::PermissionsExt;
+        let dev_shm = Path::new("/dev/shm");
+        if !dev_shm.exists() {
+            issues.push("/dev/shm not available");
+        } else if let Ok(metadata) = fs::metadata(dev_shm) {
+            if metadata.permissions().mode() & 0o777 != 0o777 {
+                issues.push("/dev/shm permissions restrictive");
+            }
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    if fs::create_dir_all(horus_core::memory::shm_base_dir()).is_err() {
+        issues.push("Cannot create shared memory directory");
+    }
+    issues
+}
+
+/// Free space in MB on the filesystem of `dir` (Linux only)
+fn available_disk_mb(dir: &Path) -> Option<u64> {
+    if !cfg!(target_os = "linux") {
+        return None;
+    }
+    let output = Command::new("df").arg("-BM").arg(dir).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    // Filesystem  1M-blocks  Used Available Use% Mounted
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let line = stdout.lines().nth(1)?;
+    line.split_whitespace()
+        .nth(3)?
+        .strip_suffix('M')?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(dir: &Path, yaml: &str) -> PathBuf {
+        let path = dir.join("horus.yaml");
+        fs::write(&path, yaml).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_manifest_diagnostics() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = manifest(
+            dir.path(),
+            "name: Bad Name\nversion: one\nlanguage: cobol\ndependencies:\n  lidar:\n    path: ./missing\n",
+        );
+
+        let report = run_check(&path, true, CheckFormat::Json).unwrap();
+        assert_eq!(report.warnings(), 0, "quiet drops warnings");
+        let messages: Vec<&str> = report
+            .diagnostics
+            .iter()
+            .map(|d| d.message.as_str())
+            .collect();
+        assert!(messages
+            .iter()
+            .any(|m| m.starts_with("Invalid version format")));
+        assert!(messages
+            .iter()
+            .any(|m| m.starts_with("Invalid language 'cobol'")));
+        assert!(messages.contains(&"Invalid project name: name cannot contain spaces"));
+
+        let missing = report
+            .diagnostics
+            .iter()
+            .find(|d| d.message.starts_with("Path dependency 'lidar' not found"))
+            .unwrap();
+        assert_eq!(missing.code(), "horus/dependency");
+        assert_eq!(missing.file, path);
+    }
+
+    #[test]
+    fn test_yaml_error_span_and_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = manifest(dir.path(), "name: a\nversion: [\n");
+
+        let report = run_check(&path, true, CheckFormat::Json).unwrap();
+        let json = report.to_json();
+        assert_eq!(json["version"], JSON_FORMAT_VERSION);
+        assert_eq!(json["summary"]["errors"], report.errors());
+
+        let yaml_error = json["diagnostics"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|d| d["code"] == "horus/manifest")
+            .unwrap();
+        assert_eq!(yaml_error["file"], "horus.yaml");
+        assert_eq!(yaml_error["severity"], "error");
+        assert!(yaml_error["span"]["line"].as_u64().unwrap() >= 2);
+    }
+
+    #[test]
+    fn test_rust_syntax_span() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("node.rs");
+        fs::write(&path, "fn main() {\n    let x = ;\n}\n").unwrap();
+
+        let report = run_check(&path, false, CheckFormat::Json).unwrap();
+        assert_eq!(report.errors(), 1);
+        let diagnostic = &report.diagnostics[0];
+        assert_eq!(diagnostic.rule, Rule::RustSyntax);
+        assert_eq!(diagnostic.span.map(|s| s.line), Some(2));
+    }
+
+    #[test]
+    fn test_cargo_diagnostic() {
+        let crate_dir = Path::new("/work/robot/controller");
+        let d = cargo_diagnostic(
+            crate_dir,
+            "src/main.rs:3:5: error[E0425]: cannot find value `x` in this scope",
+        );
+        assert_eq!(d.file, crate_dir.join("src/main.rs"));
+        assert_eq!(
+            d.span,
+            Some(Span {
+                line: 3,
+                column: Some(5)
+            })
+        );
+        assert_eq!(
+            d.message,
+            "error[E0425]: cannot find value `x` in this scope"
+        );
+
+        let d = cargo_diagnostic(crate_dir, "error: could not compile `controller`");
+        assert_eq!(d.file, crate_dir.join("Cargo.toml"));
+        assert!(d.span.is_none());
+    }
+
+    #[test]
+    fn test_python_helpers() {
+        assert_eq!(
+            python_error_line("  File \"main.py\", line 12\n    x = \nSyntaxError: invalid syntax"),
+            Some(12)
+        );
+        assert_eq!(python_error_line("SyntaxError"), None);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("main.py");
+        fs::write(
+            &path,
+            "import os\nimport numpy as np\nfrom cv2 import imread\nimport horus\n# import torch\nimport yaml, json\n",
+        )
+        .unwrap();
+        assert_eq!(
+            parse_python_imports(&path).unwrap(),
+            ["numpy", "cv2", "yaml"]
+        );
+    }
+}
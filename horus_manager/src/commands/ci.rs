@@ -0,0 +1,535 @@
+//! CI command - Generate CI pipelines for HORUS workspaces
+//!
+//! `horus ci init` writes a GitHub Actions workflow or a GitLab CI config
+//! tailored to the workspace. The pipeline:
+//! - builds the workspace
+//! - runs `horus check --sarif` and publishes the findings
+//! - runs unit and integration tests with simulation drivers (`horus test --sim`),
+//!   so no hardware or display is needed
+//! - runs benchmarks and fails on regressions against the default branch
+//!   (`horus ci bench-gate`)
+
+use colored::*;
+use horus_core::error::{HorusError, HorusResult};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// File `horus check --sarif` writes in CI
+const SARIF_FILE: &str = "horus-check.sarif";
+/// Benchmark results of the current run
+const BENCH_CURRENT: &str = "bench-current.txt";
+/// Benchmark results of the last default-branch run, restored from the CI cache
+const BENCH_BASELINE: &str = ".horus-ci/bench-baseline.txt";
+/// Slowdown in percent that fails the benchmark gate
+pub const DEFAULT_BENCH_THRESHOLD: f64 = 10.0;
+
+const HORUS_INSTALL: &str =
+    "cargo install --git https://github.com/softmata/horus horus_manager --locked";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum CiProvider {
+    Github,
+    Gitlab,
+}
+
+impl CiProvider {
+    fn config_path(self) -> &'static str {
+        match self {
+            CiProvider::Github => ".github/workflows/horus.yml",
+            CiProvider::Gitlab => ".gitlab-ci.yml",
+        }
+    }
+}
+
+/// What the pipeline needs to cover, detected from the workspace
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct CiProfile {
+    /// horus.yaml at the root: build with `horus build`
+    horus_project: bool,
+    /// Python sources, which need the horus Python package for `horus check`
+    python: bool,
+    /// Crates with a `benches/` directory, relative to the root
+    bench_dirs: Vec<PathBuf>,
+}
+
+impl CiProfile {
+    fn detect(root: &Path) -> Self {
+        let mut profile = CiProfile {
+            horus_project: root.join("horus.yaml").exists(),
+            ..Default::default()
+        };
+
+        for entry in WalkDir::new(root)
+            .into_iter()
+            .filter_entry(|e| {
+                let name = e.file_name().to_string_lossy();
+                e.depth() == 0
+                    || !(name.starts_with('.')
+                        || name == "target"
+                        || name == "node_modules"
+                        || name == "__pycache__")
+            })
+            .filter_map(|e| e.ok())
+        {
+            let path = entry.path();
+            if path.extension().is_some_and(|ext| ext == "py") {
+                profile.python = true;
+            }
+            if entry.file_name() == "benches" && path.is_dir() {
+                if let Some(crate_dir) = path.parent() {
+                    if crate_dir.join("Cargo.toml").exists() {
+                        let relative = crate_dir.strip_prefix(root).unwrap_or(crate_dir);
+                        profile.bench_dirs.push(relative.to_path_buf());
+                    }
+                }
+            }
+        }
+        profile.bench_dirs.sort();
+        profile
+    }
+
+    fn build_command(&self) -> &'static str {
+        if self.horus_project {
+            "horus build"
+        } else {
+            "cargo build --workspace --all-targets"
+        }
+    }
+
+    /// Shell command running every benchmark suite in bencher format
+    fn bench_command(&self) -> String {
+        self.bench_dirs
+            .iter()
+            .map(|dir| {
+                let dir = dir.to_string_lossy();
+                let dir = if dir.is_empty() { "." } else { &dir };
+                format!(
+                    "(cd {} && cargo bench -- --output-format bencher) | tee -a {}",
+                    dir, BENCH_CURRENT
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Generate a CI config for the workspace at `path`
+pub fn init(provider: CiProvider, path: &Path, force: bool) -> HorusResult<()> {
+    let profile = CiProfile::detect(path);
+    let config_path = path.join(provider.config_path());
+
+    if config_path.exists() && !force {
+        return Err(HorusError::Config(format!(
+            "{} already exists (use --force to overwrite)",
+            config_path.display()
+        )));
+    }
+
+    let config = match provider {
+        CiProvider::Github => github_workflow(&profile),
+        CiProvider::Gitlab => gitlab_config(&profile),
+    };
+
+    if let Some(parent) = config_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&config_path, config)?;
+
+    println!(
+        "{} Generated {}",
+        "".green(),
+        config_path.display().to_string().cyan()
+    );
+    println!("  {} Build: {}", "".cyan(), profile.build_command());
+    println!("  {} Check: horus check --sarif {}", "".cyan(), SARIF_FILE);
+    println!("  {} Tests: horus test --sim --integration", "".cyan());
+    if profile.bench_dirs.is_empty() {
+        println!(
+            "  {} Benchmarks: none found (add a benches/ directory and re-run to gate regressions)",
+            "".dimmed()
+        );
+    } else {
+        println!(
+            "  {} Benchmarks: {} suite(s), failing on >{}% slowdown",
+            "".cyan(),
+            profile.bench_dirs.len(),
+            DEFAULT_BENCH_THRESHOLD
+        );
+    }
+    Ok(())
+}
+
+fn github_workflow(profile: &CiProfile) -> String {
+    let python_setup = if profile.python {
+        r#"      - uses: actions/setup-python@v5
+        with:
+          python-version: "3.11"
+      - run: pip install horus
+"#
+    } else {
+        ""
+    };
+    let setup = format!(
+        r#"      - uses: actions/checkout@v4
+      - uses: dtolnay/rust-toolchain@stable
+      - uses: Swatinem/rust-cache@v2
+{python_setup}      - name: Install HORUS
+        run: {HORUS_INSTALL}
+"#
+    );
+
+    let mut workflow = format!(
+        r#"# Generated by `horus ci init`
+name: HORUS CI
+
+on:
+  push:
+    branches: [main, master]
+  pull_request:
+
+env:
+  CARGO_TERM_COLOR: always
+
+jobs:
+  build:
+    runs-on: ubuntu-latest
+    steps:
+{setup}      - name: Build
+        run: {build}
+
+  check:
+    runs-on: ubuntu-latest
+    permissions:
+      contents: read
+      security-events: write
+    steps:
+{setup}      - name: horus check
+        run: horus check --sarif {SARIF_FILE}
+      - name: Upload findings
+        if: always()
+        uses: github/codeql-action/upload-sarif@v3
+        with:
+          sarif_file: {SARIF_FILE}
+
+  test:
+    runs-on: ubuntu-latest
+    needs: build
+    steps:
+{setup}      - name: Tests (simulation drivers)
+        run: horus test --sim
+      - name: Integration tests (simulation drivers)
+        run: horus test --sim --integration
+"#,
+        build = profile.build_command(),
+    );
+
+    if !profile.bench_dirs.is_empty() {
+        workflow.push_str(&format!(
+            r#"
+  bench:
+    runs-on: ubuntu-latest
+    needs: build
+    steps:
+{setup}      - name: Restore benchmark baseline
+        uses: actions/cache/restore@v4
+        with:
+          path: {BENCH_BASELINE}
+          key: horus-bench-${{{{ github.run_id }}}}
+          restore-keys: horus-bench-
+      - name: Benchmarks
+        run: |
+{bench}
+      - name: Benchmark regression gate
+        run: horus ci bench-gate --baseline {BENCH_BASELINE} --current {BENCH_CURRENT} --threshold {DEFAULT_BENCH_THRESHOLD}
+      - name: Update benchmark baseline
+        if: github.ref_name == github.event.repository.default_branch
+        run: mkdir -p $(dirname {BENCH_BASELINE}) && cp {BENCH_CURRENT} {BENCH_BASELINE}
+      - name: Save benchmark baseline
+        if: github.ref_name == github.event.repository.default_branch
+        uses: actions/cache/save@v4
+        with:
+          path: {BENCH_BASELINE}
+          key: horus-bench-${{{{ github.run_id }}}}
+"#,
+            bench = indent(&profile.bench_command(), 10),
+        ));
+    }
+    workflow
+}
+
+fn gitlab_config(profile: &CiProfile) -> String {
+    let python_setup = if profile.python {
+        r#"  - apt-get update && apt-get install -y python3-pip
+  - pip3 install --break-system-packages horus
+"#
+    } else {
+        ""
+    };
+    let stages = if profile.bench_dirs.is_empty() {
+        "[build, check, test]"
+    } else {
+        "[build, check, test, bench]"
+    };
+
+    let mut config = format!(
+        r#"# Generated by `horus ci init`
+image: rust:latest
+
+stages: {stages}
+
+variables:
+  CARGO_HOME: $CI_PROJECT_DIR/.cargo
+
+cache:
+  key: horus-cargo
+  paths:
+    - .cargo/
+    - target/
+
+before_script:
+  - export PATH="$CARGO_HOME/bin:$PATH"
+  - command -v horus || {HORUS_INSTALL}
+{python_setup}
+build:
+  stage: build
+  script:
+    - {build}
+
+check:
+  stage: check
+  script:
+    - horus check --sarif {SARIF_FILE}
+  artifacts:
+    when: always
+    paths:
+      - {SARIF_FILE}
+
+test:
+  stage: test
+  script:
+    - horus test --sim
+    - horus test --sim --integration
+"#,
+        build = profile.build_command(),
+    );
+
+    if !profile.bench_dirs.is_empty() {
+        config.push_str(&format!(
+            r#"
+bench:
+  stage: bench
+  cache:
+    - key: horus-cargo
+      paths:
+        - .cargo/
+        - target/
+    - key: horus-bench
+      paths:
+        - .horus-ci/
+  script:
+{bench}
+    - horus ci bench-gate --baseline {BENCH_BASELINE} --current {BENCH_CURRENT} --threshold {DEFAULT_BENCH_THRESHOLD}
+    - |
+      if [ "$CI_COMMIT_BRANCH" = "$CI_DEFAULT_BRANCH" ]; then
+        mkdir -p $(dirname {BENCH_BASELINE}) && cp {BENCH_CURRENT} {BENCH_BASELINE}
+      fi
+"#,
+            bench = profile
+                .bench_command()
+                .lines()
+                .map(|line| format!("    - {}", line))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ));
+    }
+    config
+}
+
+fn indent(text: &str, spaces: usize) -> String {
+    let pad = " ".repeat(spaces);
+    text.lines()
+        .map(|line| format!("{}{}", pad, line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Parse `cargo bench -- --output-format bencher` output into ns/iter per
+/// benchmark, e.g. `test link/send ... bench:       1,234 ns/iter (+/- 56)`
+pub fn parse_bencher_output(text: &str) -> BTreeMap<String, f64> {
+    text.lines()
+        .filter_map(|line| {
+            let rest = line.trim().strip_prefix("test ")?;
+            let (name, result) = rest.split_once(" ... bench:")?;
+            let mut fields = result.split_whitespace();
+            let value = fields.next()?.replace(',', "").parse::<f64>().ok()?;
+            (fields.next()? == "ns/iter").then(|| (name.trim().to_string(), value))
+        })
+        .collect()
+}
+
+/// Benchmark slower than the baseline by more than the threshold
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchRegression {
+    pub name: String,
+    pub baseline_ns: f64,
+    pub current_ns: f64,
+}
+
+impl BenchRegression {
+    pub fn change_percent(&self) -> f64 {
+        (self.current_ns / self.baseline_ns - 1.0) * 100.0
+    }
+}
+
+/// Benchmarks present in both runs that slowed down by more than `threshold` percent
+pub fn find_regressions(
+    baseline: &BTreeMap<String, f64>,
+    current: &BTreeMap<String, f64>,
+    threshold: f64,
+) -> Vec<BenchRegression> {
+    current
+        .iter()
+        .filter_map(|(name, &current_ns)| {
+            let baseline_ns = *baseline.get(name)?;
+            (baseline_ns > 0.0 && current_ns > baseline_ns * (1.0 + threshold / 100.0)).then(|| {
+                BenchRegression {
+                    name: name.clone(),
+                    baseline_ns,
+                    current_ns,
+                }
+            })
+        })
+        .collect()
+}
+
+/// Compare benchmark results against a baseline and fail on regressions
+///
+/// A missing baseline (first run on a branch) passes.
+pub fn bench_gate(baseline_path: &Path, current_path: &Path, threshold: f64) -> HorusResult<()> {
+    let current = parse_bencher_output(&fs::read_to_string(current_path)?);
+    if current.is_empty() {
+        return Err(HorusError::Config(format!(
+            "No benchmark results in {} (expected `cargo bench -- --output-format bencher` output)",
+            current_path.display()
+        )));
+    }
+
+    let Ok(baseline_text) = fs::read_to_string(baseline_path) else {
+        println!(
+            "{} No benchmark baseline at {}; {} result(s) recorded, gate skipped",
+            "".yellow(),
+            baseline_path.display(),
+            current.len()
+        );
+        return Ok(());
+    };
+    let baseline = parse_bencher_output(&baseline_text);
+
+    println!(
+        "{} Benchmarks vs baseline (threshold {}%)\n",
+        "".cyan().bold(),
+        threshold
+    );
+    for (name, current_ns) in &current {
+        match baseline.get(name) {
+            Some(baseline_ns) if *baseline_ns > 0.0 => {
+                let change = (current_ns / baseline_ns - 1.0) * 100.0;
+                let change_text = format!("{:+.1}%", change);
+                let change_text = if change > threshold {
+                    change_text.red().bold()
+                } else if change < -threshold {
+                    change_text.green()
+                } else {
+                    change_text.normal()
+                };
+                println!(
+                    "  {:<48} {:>12.0} ns {:>12.0} ns  {}",
+                    name, baseline_ns, current_ns, change_text
+                );
+            }
+            _ => println!("  {:<48} {:>15} {:>12.0} ns  new", name, "-", current_ns),
+        }
+    }
+    println!();
+
+    let regressions = find_regressions(&baseline, &current, threshold);
+    if regressions.is_empty() {
+        println!("{} No regressions", "".green());
+        return Ok(());
+    }
+    for regression in &regressions {
+        println!(
+            "  {} {} is {:.1}% slower",
+            "".red(),
+            regression.name,
+            regression.change_percent()
+        );
+    }
+    Err(HorusError::Config(format!(
+        "{} benchmark regression(s) above {}%",
+        regressions.len(),
+        threshold
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_bencher_output_and_regressions() {
+        let baseline = parse_bencher_output(
+            "running 3 tests\n\
+             test link/send ... bench:       1,000 ns/iter (+/- 12)\n\
+             test hub/recv ... bench:         200 ns/iter (+/- 3)\n\
+             test removed ... bench:          50 ns/iter (+/- 1)\n",
+        );
+        let current = parse_bencher_output(
+            "test link/send ... bench:       1,150 ns/iter (+/- 20)\n\
+             test hub/recv ... bench:         205 ns/iter (+/- 3)\n\
+             test added ... bench:            10 ns/iter (+/- 1)\n",
+        );
+        assert_eq!(baseline.len(), 3);
+        assert_eq!(baseline["link/send"], 1000.0);
+
+        let regressions = find_regressions(&baseline, &current, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].name, "link/send");
+        assert!((regressions[0].change_percent() - 15.0).abs() < 1e-9);
+        assert!(find_regressions(&baseline, &current, 20.0).is_empty());
+    }
+
+    #[test]
+    fn test_generated_configs() {
+        let profile = CiProfile {
+            horus_project: true,
+            python: true,
+            bench_dirs: vec![PathBuf::new(), PathBuf::from("crates/planner")],
+        };
+
+        let github = github_workflow(&profile);
+        assert!(github.contains("run: horus build"));
+        assert!(github.contains("horus check --sarif horus-check.sarif"));
+        assert!(github.contains("horus test --sim --integration"));
+        assert!(github.contains("pip install horus"));
+        assert!(github.contains(
+            "          (cd crates/planner && cargo bench -- --output-format bencher) | tee -a bench-current.txt"
+        ));
+        assert!(github.contains("key: horus-bench-${{ github.run_id }}"));
+
+        let gitlab = gitlab_config(&profile);
+        assert!(gitlab.contains("stages: [build, check, test, bench]"));
+        assert!(gitlab.contains(
+            "    - (cd . && cargo bench -- --output-format bencher) | tee -a bench-current.txt"
+        ));
+
+        let minimal = CiProfile::default();
+        let github = github_workflow(&minimal);
+        assert!(github.contains("cargo build --workspace --all-targets"));
+        assert!(!github.contains("bench"));
+        assert!(!github.contains("setup-python"));
+        assert!(!gitlab_config(&minimal).contains("bench"));
+    }
+}
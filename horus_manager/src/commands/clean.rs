@@ -4,6 +4,7 @@
 
 use colored::*;
 use horus_core::error::{HorusError, HorusResult};
+use horus_core::memory::{hugetlbfs_dir, shm_base_dir};
 use std::path::Path;
 
 /// Run the clean command
@@ -76,25 +77,23 @@ fn clean_build_cache(dry_run: bool) -> HorusResult<bool> {
     Ok(false)
 }
 
-/// Clean shared memory
+/// Clean shared memory, including huge page segments on hugetlbfs
 fn clean_shared_memory(dry_run: bool) -> HorusResult<bool> {
-    let shm_base = if cfg!(target_os = "macos") {
-        "/tmp/horus"
-    } else {
-        "/dev/shm/horus"
-    };
-
-    let shm_path = Path::new(shm_base);
+    let shm_dir = shm_base_dir();
+    let mut cleaned = false;
 
-    if shm_path.exists() {
-        let size = get_dir_size(shm_path);
-        let file_count = count_files(shm_path);
+    for dir in std::iter::once(shm_dir.clone()).chain(hugetlbfs_dir()) {
+        if !dir.exists() {
+            continue;
+        }
+        let size = get_dir_size(&dir);
+        let file_count = count_files(&dir);
 
         if dry_run {
             println!(
                 "  {} Would remove {} ({}, {} files)",
                 "".cyan(),
-                shm_base.white(),
+                dir.display().to_string().white(),
                 format_size(size),
                 file_count
             );
@@ -102,18 +101,24 @@ fn clean_shared_memory(dry_run: bool) -> HorusResult<bool> {
             println!(
                 "  {} Removing {} ({}, {} files)",
                 "".cyan(),
-                shm_base.white(),
+                dir.display().to_string().white(),
                 format_size(size),
                 file_count
             );
-            std::fs::remove_dir_all(shm_path).map_err(HorusError::Io)?;
+            std::fs::remove_dir_all(&dir).map_err(HorusError::Io)?;
         }
-        return Ok(true);
-    } else {
-        println!("  {} No shared memory at {}", "".dimmed(), shm_base);
+        cleaned = true;
     }
 
-    Ok(false)
+    if !cleaned {
+        println!(
+            "  {} No shared memory at {}",
+            "".dimmed(),
+            shm_dir.display()
+        );
+    }
+
+    Ok(cleaned)
 }
 
 /// Clean HORUS cache directory
@@ -186,7 +191,7 @@ fn count_files(path: &Path) -> usize {
 }
 
 /// Format byte size for display
-fn format_size(bytes: u64) -> String {
+pub(crate) fn format_size(bytes: u64) -> String {
     if bytes < 1024 {
         format!("{} B", bytes)
     } else if bytes < 1024 * 1024 {
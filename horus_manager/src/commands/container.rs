@@ -0,0 +1,547 @@
+//! Containerized workflows: Dockerfile/devcontainer scaffolding for new
+//! projects and `horus run --container`.
+//!
+//! HORUS nodes talk over shared memory and frequently need raw hardware
+//! access (serial ports, cameras, I2C/SPI buses), so every container started
+//! here shares the host IPC namespace and `/dev/shm`, runs on the host
+//! network, and gets the robot devices that exist on the host passed through.
+
+use anyhow::{anyhow, bail, Context, Result};
+use colored::*;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+/// Workspace mount point inside the container
+pub const CONTAINER_WORKDIR: &str = "/workspace";
+
+/// Host environment forwarded into the container when set
+const FORWARDED_ENV: &[&str] = &[
+    "HORUS_DRIVERS",
+    "HORUS_ENABLE",
+    "HORUS_RECORD_SESSION",
+    "HORUS_DEV",
+    "HORUS_NAMESPACE",
+    "HORUS_DOMAIN_ID",
+    "RUST_LOG",
+];
+
+/// Device node patterns passed through to the container when present
+const DEVICE_PATTERNS: &[(&str, &str)] = &[
+    ("/dev", "ttyUSB"),
+    ("/dev", "ttyACM"),
+    ("/dev", "ttyTHS"),
+    ("/dev", "video"),
+    ("/dev", "i2c-"),
+    ("/dev", "spidev"),
+    ("/dev", "gpiochip"),
+    ("/dev/input", "js"),
+    ("/dev/input", "event"),
+    ("/dev/bus", "usb"),
+];
+
+/// Container engine used to build and run images
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerEngine {
+    Docker,
+    Podman,
+}
+
+impl ContainerEngine {
+    /// Detect an available engine, honoring `HORUS_CONTAINER_ENGINE`
+    pub fn detect() -> Result<Self> {
+        if let Ok(engine) = env::var("HORUS_CONTAINER_ENGINE") {
+            return match engine.to_lowercase().as_str() {
+                "docker" => Ok(ContainerEngine::Docker),
+                "podman" => Ok(ContainerEngine::Podman),
+                other => bail!(
+                    "Unknown HORUS_CONTAINER_ENGINE '{}' (expected docker or podman)",
+                    other
+                ),
+            };
+        }
+
+        for engine in [ContainerEngine::Docker, ContainerEngine::Podman] {
+            let available = Command::new(engine.binary())
+                .arg("--version")
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .map(|s| s.success())
+                .unwrap_or(false);
+            if available {
+                return Ok(engine);
+            }
+        }
+
+        bail!(
+            "No container engine found.\n\n\
+             Install Docker (https://docs.docker.com/engine/install/) or Podman,\n\
+             or set HORUS_CONTAINER_ENGINE to the engine you want to use."
+        )
+    }
+
+    pub fn binary(&self) -> &'static str {
+        match self {
+            ContainerEngine::Docker => "docker",
+            ContainerEngine::Podman => "podman",
+        }
+    }
+}
+
+/// Generate the Dockerfile for a project
+pub fn generate_dockerfile(language: &str) -> String {
+    let python_packages = if language == "python" {
+        " \\\n        python3 python3-dev python3-pip python3-venv"
+    } else {
+        ""
+    };
+
+    format!(
+        r#"# HORUS development image (generated by `horus new --docker`)
+#
+# Build:  docker build -t my-robot .
+# Run:    horus run --container
+#
+# Shared memory and hardware access are configured by `horus run --container`
+# (--ipc=host, /dev/shm mount, device passthrough). When running the image by
+# hand, pass at least: --ipc=host -v /dev/shm:/dev/shm --network host
+
+FROM rust:1-bookworm
+
+RUN apt-get update && apt-get install -y --no-install-recommends \
+        build-essential pkg-config libssl-dev libudev-dev git curl{python} \
+    && rm -rf /var/lib/apt/lists/*
+
+# HORUS source checkout (used by `horus run` for path dependencies)
+ARG HORUS_REF=main
+RUN git clone --depth 1 --branch ${{HORUS_REF}} https://github.com/softmata/horus /horus \
+    && cargo install --locked --path /horus/horus_manager \
+    && rm -rf /horus/target
+
+ENV HORUS_SOURCE=/horus \
+    HORUS_CONTAINER=1
+
+WORKDIR {workdir}
+COPY . {workdir}
+
+CMD ["horus", "run"]
+"#,
+        python = python_packages,
+        workdir = CONTAINER_WORKDIR,
+    )
+}
+
+/// Generate the .dockerignore for a project
+pub fn generate_dockerignore() -> String {
+    r#"# Build artifacts and local HORUS environment
+.horus/target/
+.horus/cache/
+.horus/packages/
+.horus/bin/
+.horus/lib/
+target/
+__pycache__/
+*.pyc
+*.log
+.git/
+"#
+    .to_string()
+}
+
+/// Generate `.devcontainer/devcontainer.json` for a project
+pub fn generate_devcontainer(name: &str) -> String {
+    let config = serde_json::json!({
+        "name": name,
+        "build": {
+            "dockerfile": "../Dockerfile",
+            "context": ".."
+        },
+        "workspaceFolder": CONTAINER_WORKDIR,
+        "workspaceMount": format!(
+            "source=${{localWorkspaceFolder}},target={},type=bind",
+            CONTAINER_WORKDIR
+        ),
+        "runArgs": [
+            "--ipc=host",
+            "--network=host",
+            "--privileged",
+            "-v", "/dev:/dev",
+            "-v", "/dev/shm:/dev/shm",
+            "--cap-add=SYS_NICE",
+            "--ulimit", "rtprio=99",
+            "--ulimit", "memlock=-1"
+        ],
+        "containerEnv": {
+            "HORUS_CONTAINER": "1"
+        },
+        "customizations": {
+            "vscode": {
+                "extensions": [
+                    "rust-lang.rust-analyzer",
+                    "ms-python.python"
+                ]
+            }
+        }
+    });
+
+    let mut out = serde_json::to_string_pretty(&config).unwrap_or_default();
+    out.push('\n');
+    out
+}
+
+/// Write Dockerfile, .dockerignore and devcontainer config into a project
+pub fn write_docker_files(project_path: &Path, name: &str, language: &str) -> Result<()> {
+    fs::write(
+        project_path.join("Dockerfile"),
+        generate_dockerfile(language),
+    )
+    .context("Failed to write Dockerfile")?;
+    fs::write(project_path.join(".dockerignore"), generate_dockerignore())
+        .context("Failed to write .dockerignore")?;
+
+    let devcontainer_dir = project_path.join(".devcontainer");
+    fs::create_dir_all(&devcontainer_dir).context("Failed to create .devcontainer directory")?;
+    fs::write(
+        devcontainer_dir.join("devcontainer.json"),
+        generate_devcontainer(name),
+    )
+    .context("Failed to write devcontainer.json")?;
+
+    println!(
+        "  {} Created Dockerfile, .dockerignore and .devcontainer/",
+        "✓".green()
+    );
+    Ok(())
+}
+
+/// Collect `--device` arguments for robot hardware present under `root`
+///
+/// `root` is the filesystem root (normally `/`), parameterised for testing.
+pub fn device_args(root: &Path) -> Vec<String> {
+    let mut devices = Vec::new();
+
+    for (dir, prefix) in DEVICE_PATTERNS {
+        let host_dir = root.join(dir.trim_start_matches('/'));
+        let Ok(entries) = fs::read_dir(&host_dir) else {
+            continue;
+        };
+        let mut matched: Vec<String> = entries
+            .flatten()
+            .filter_map(|e| e.file_name().to_str().map(|s| s.to_string()))
+            .filter(|n| n.starts_with(prefix))
+            .map(|n| format!("{}/{}", dir, n))
+            .collect();
+        matched.sort();
+        devices.extend(matched);
+    }
+
+    devices
+        .into_iter()
+        .flat_map(|d| ["--device".to_string(), d])
+        .collect()
+}
+
+/// Image tag used for a workspace
+pub fn image_name(workspace: &Path) -> String {
+    let name = workspace
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or("workspace");
+    let sanitized: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    let sanitized = sanitized.trim_matches(|c| c == '-' || c == '.');
+    let sanitized = if sanitized.is_empty() {
+        "workspace"
+    } else {
+        sanitized
+    };
+    format!("horus-{}:dev", sanitized)
+}
+
+/// Arguments for `<engine> run`, excluding the command executed in the container
+pub fn run_args(
+    workspace: &Path,
+    image: &str,
+    devices: &[String],
+    env_vars: &[(String, String)],
+    tty: bool,
+) -> Vec<String> {
+    let volume_name = image
+        .trim_end_matches(":dev")
+        .replace(|c: char| !c.is_ascii_alphanumeric() && c != '-', "-");
+
+    let mut args: Vec<String> = vec!["run".into(), "--rm".into(), "-i".into()];
+    if tty {
+        args.push("-t".into());
+    }
+    args.extend(
+        [
+            // Shared memory transport
+            "--ipc=host",
+            "-v",
+            "/dev/shm:/dev/shm",
+            // Network transport and discovery
+            "--network",
+            "host",
+            // Real-time scheduling
+            "--cap-add",
+            "SYS_NICE",
+            "--ulimit",
+            "rtprio=99",
+            "--ulimit",
+            "memlock=-1",
+            "-e",
+            "HORUS_CONTAINER=1",
+        ]
+        .iter()
+        .map(|s| s.to_string()),
+    );
+
+    args.push("-v".into());
+    args.push(format!("{}:{}", workspace.display(), CONTAINER_WORKDIR));
+    // Keep container build artifacts and the cargo registry off the host tree
+    args.push("-v".into());
+    args.push(format!(
+        "{}-target:{}/.horus/target",
+        volume_name, CONTAINER_WORKDIR
+    ));
+    args.push("-v".into());
+    args.push("horus-cargo-registry:/usr/local/cargo/registry".into());
+    args.push("-w".into());
+    args.push(CONTAINER_WORKDIR.into());
+
+    for (key, value) in env_vars {
+        args.push("-e".into());
+        args.push(format!("{}={}", key, value));
+    }
+    args.extend(devices.iter().cloned());
+    args.push(image.to_string());
+    args
+}
+
+/// Build the workspace image and run `horus run` inside it
+pub fn run_in_container(
+    files: Vec<PathBuf>,
+    args: Vec<String>,
+    release: bool,
+    clean: bool,
+    image: Option<String>,
+    launch_args: Vec<String>,
+) -> Result<()> {
+    let engine = ContainerEngine::detect()?;
+    let workspace = env::current_dir().context("Failed to get current directory")?;
+
+    let image = match image {
+        Some(image) => image,
+        None => {
+            let image = image_name(&workspace);
+            build_image(engine, &workspace, &image)?;
+            image
+        }
+    };
+
+    let devices = device_args(Path::new("/"));
+    let tty = atty_stdin();
+
+    let forwarded: Vec<(String, String)> = FORWARDED_ENV
+        .iter()
+        .filter_map(|var| env::var(var).ok().map(|v| (var.to_string(), v)))
+        .collect();
+
+    let mut cmd_args = run_args(&workspace, &image, &devices, &forwarded, tty);
+    cmd_args.push("horus".into());
+    cmd_args.push("run".into());
+    for file in &files {
+        cmd_args.push(file.display().to_string());
+    }
+    if release {
+        cmd_args.push("--release".into());
+    }
+    if clean {
+        cmd_args.push("--clean".into());
+    }
+    for arg in launch_args {
+        cmd_args.push("--arg".into());
+        cmd_args.push(arg);
+    }
+    if !args.is_empty() {
+        cmd_args.push("--".into());
+        cmd_args.extend(args);
+    }
+
+    println!(
+        "{} Running in container {} ({})",
+        "[*]".cyan(),
+        image.green(),
+        engine.binary()
+    );
+    println!(
+        "  {} shared memory: --ipc=host, /dev/shm mounted",
+        "•".dimmed()
+    );
+    if devices.is_empty() {
+        println!("  {} devices: none detected", "•".dimmed());
+    } else {
+        let names: Vec<&str> = devices
+            .iter()
+            .filter(|d| d.as_str() != "--device")
+            .map(|d| d.as_str())
+            .collect();
+        println!("  {} devices: {}", "•".dimmed(), names.join(", "));
+    }
+
+    let status = Command::new(engine.binary())
+        .args(&cmd_args)
+        .status()
+        .with_context(|| format!("Failed to start {}", engine.binary()))?;
+
+    if !status.success() {
+        return Err(anyhow!(
+            "Container exited with status {}",
+            status.code().unwrap_or(-1)
+        ));
+    }
+    Ok(())
+}
+
+fn build_image(engine: ContainerEngine, workspace: &Path, image: &str) -> Result<()> {
+    let dockerfile = workspace.join("Dockerfile");
+    let generated;
+    let dockerfile = if dockerfile.exists() {
+        dockerfile
+    } else {
+        // Projects created without --docker still get a working image
+        let language = if workspace.join("main.py").exists() {
+            "python"
+        } else {
+            "rust"
+        };
+        let dir = workspace.join(".horus");
+        fs::create_dir_all(&dir).context("Failed to create .horus directory")?;
+        generated = dir.join("Dockerfile");
+        fs::write(&generated, generate_dockerfile(language))
+            .context("Failed to write generated Dockerfile")?;
+        println!(
+            "  {} No Dockerfile found, using generated {}",
+            "•".dimmed(),
+            ".horus/Dockerfile".cyan()
+        );
+        generated
+    };
+
+    println!("{} Building image {}", "[*]".cyan(), image.green());
+    let status = Command::new(engine.binary())
+        .arg("build")
+        .arg("-t")
+        .arg(image)
+        .arg("-f")
+        .arg(&dockerfile)
+        .arg(workspace)
+        .status()
+        .with_context(|| format!("Failed to start {} build", engine.binary()))?;
+
+    if !status.success() {
+        bail!("Image build failed for {}", image);
+    }
+    Ok(())
+}
+
+fn atty_stdin() -> bool {
+    use std::io::IsTerminal;
+    std::io::stdin().is_terminal()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_dockerfile_language_packages() {
+        let rust = generate_dockerfile("rust");
+        assert!(rust.contains("FROM rust:1-bookworm"));
+        assert!(rust.contains("HORUS_SOURCE=/horus"));
+        assert!(!rust.contains("python3-pip"));
+
+        let python = generate_dockerfile("python");
+        assert!(python.contains("python3-pip"));
+    }
+
+    #[test]
+    fn test_devcontainer_shares_shm() {
+        let json: serde_json::Value =
+            serde_json::from_str(&generate_devcontainer("robot")).unwrap();
+        let run_args: Vec<&str> = json["runArgs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap())
+            .collect();
+        assert!(run_args.contains(&"--ipc=host"));
+        assert!(run_args.contains(&"/dev/shm:/dev/shm"));
+        assert_eq!(json["name"], "robot");
+    }
+
+    #[test]
+    fn test_device_args_detects_hardware() {
+        let root = TempDir::new().unwrap();
+        let dev = root.path().join("dev");
+        fs::create_dir_all(dev.join("input")).unwrap();
+        for name in ["ttyUSB0", "ttyACM1", "video0", "null", "tty0"] {
+            fs::write(dev.join(name), "").unwrap();
+        }
+        fs::write(dev.join("input/js0"), "").unwrap();
+
+        let args = device_args(root.path());
+        assert_eq!(
+            args,
+            vec![
+                "--device",
+                "/dev/ttyUSB0",
+                "--device",
+                "/dev/ttyACM1",
+                "--device",
+                "/dev/video0",
+                "--device",
+                "/dev/input/js0",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_image_name_sanitized() {
+        assert_eq!(
+            image_name(Path::new("/home/me/My Robot")),
+            "horus-my-robot:dev"
+        );
+        assert_eq!(image_name(Path::new("/")), "horus-workspace:dev");
+    }
+
+    #[test]
+    fn test_run_args_configure_shm() {
+        let args = run_args(
+            Path::new("/home/me/robot"),
+            "horus-robot:dev",
+            &["--device".into(), "/dev/ttyUSB0".into()],
+            &[("HORUS_DRIVERS".into(), "lidar".into())],
+            false,
+        );
+        assert!(args.contains(&"HORUS_DRIVERS=lidar".to_string()));
+        assert!(args.contains(&"--ipc=host".to_string()));
+        assert!(args.contains(&"/dev/shm:/dev/shm".to_string()));
+        assert!(args.contains(&"/home/me/robot:/workspace".to_string()));
+        assert!(!args.contains(&"-t".to_string()));
+        assert_eq!(args.last().unwrap(), "horus-robot:dev");
+    }
+}
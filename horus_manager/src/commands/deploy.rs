@@ -1,14 +1,33 @@
 //! Deploy command - Deploy HORUS projects to remote robots
 //!
 //! Handles cross-compilation, file transfer, and remote execution.
+//!
+//! Each deployment is uploaded as a new release and activated atomically:
+//!
+//! ```text
+//! <dir>/releases/20250101-120000/   bin/, horus.yaml, assets
+//! <dir>/current -> releases/20250101-120000
+//! ```
+//!
+//! If the service does not come back up (or its health URL does not answer),
+//! `current` is switched back to the previous release and the service is
+//! restarted again.
 
 use colored::*;
 use horus_core::error::{HorusError, HorusResult};
+use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 
+/// Deployment targets file
+const DEPLOY_CONFIG_PATH: &str = ".horus/deploy.yaml";
+
+/// Local staging directory for the release bundle
+const STAGING_DIR: &str = ".horus/deploy_staging";
+
 /// Supported target architectures for robotics platforms
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TargetArch {
     /// ARM64 (Raspberry Pi 4/5, Jetson Nano/Xavier/Orin)
     Aarch64,
@@ -18,38 +37,164 @@ pub enum TargetArch {
     X86_64,
     /// Current host architecture
     Native,
+    /// Any other Rust target triple
+    Triple(String),
 }
 
 impl TargetArch {
     fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
-            "aarch64" | "arm64" | "jetson" | "pi4" | "pi5" => Some(TargetArch::Aarch64),
-            "armv7" | "arm" | "pi3" | "pi2" => Some(TargetArch::Armv7),
-            "x86_64" | "x64" | "amd64" | "intel" => Some(TargetArch::X86_64),
+            "aarch64" | "arm64" | "jetson" | "pi4" | "pi5" | "aarch64-unknown-linux-gnu" => {
+                Some(TargetArch::Aarch64)
+            }
+            "armv7" | "arm" | "pi3" | "pi2" | "armv7-unknown-linux-gnueabihf" => {
+                Some(TargetArch::Armv7)
+            }
+            "x86_64" | "x64" | "amd64" | "intel" | "x86_64-unknown-linux-gnu" => {
+                Some(TargetArch::X86_64)
+            }
             "native" | "host" | "local" => Some(TargetArch::Native),
+            triple if triple.split('-').count() >= 3 => Some(TargetArch::Triple(s.to_string())),
             _ => None,
         }
     }
 
-    fn rust_target(&self) -> &'static str {
+    fn rust_target(&self) -> &str {
         match self {
             TargetArch::Aarch64 => "aarch64-unknown-linux-gnu",
             TargetArch::Armv7 => "armv7-unknown-linux-gnueabihf",
             TargetArch::X86_64 => "x86_64-unknown-linux-gnu",
             TargetArch::Native => "", // Use default
+            TargetArch::Triple(triple) => triple,
         }
     }
 
-    fn display_name(&self) -> &'static str {
+    fn display_name(&self) -> &str {
         match self {
             TargetArch::Aarch64 => "ARM64 (aarch64)",
             TargetArch::Armv7 => "ARM32 (armv7)",
             TargetArch::X86_64 => "x86_64",
             TargetArch::Native => "native",
+            TargetArch::Triple(triple) => triple,
+        }
+    }
+}
+
+/// Tool used to compile for the target
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BuildTool {
+    /// `cargo build` with a rustup target (needs a cross linker)
+    Cargo,
+    /// `cross build` (Docker-based toolchains)
+    Cross,
+    /// `cargo zigbuild` (zig as cross linker)
+    Zigbuild,
+}
+
+impl BuildTool {
+    fn from_str(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "cargo" => Some(BuildTool::Cargo),
+            "cross" => Some(BuildTool::Cross),
+            "zigbuild" | "cargo-zigbuild" | "zig" => Some(BuildTool::Zigbuild),
+            _ => None,
+        }
+    }
+
+    /// Prefer cross, then cargo-zigbuild, for cross-compilation
+    fn detect(arch: &TargetArch) -> Self {
+        if arch.rust_target().is_empty() {
+            BuildTool::Cargo
+        } else if command_exists("cross") {
+            BuildTool::Cross
+        } else if command_exists("cargo-zigbuild") {
+            BuildTool::Zigbuild
+        } else {
+            BuildTool::Cargo
+        }
+    }
+
+    /// Program and arguments of the build command
+    fn build_command(&self, target: &str, release: bool) -> (&'static str, Vec<String>) {
+        let (program, mut args) = match self {
+            BuildTool::Cargo => ("cargo", vec!["build".to_string()]),
+            BuildTool::Cross => ("cross", vec!["build".to_string()]),
+            BuildTool::Zigbuild => ("cargo", vec!["zigbuild".to_string()]),
+        };
+        if release {
+            args.push("--release".to_string());
+        }
+        if !target.is_empty() {
+            args.push("--target".to_string());
+            args.push(target.to_string());
+        }
+        (program, args)
+    }
+}
+
+impl std::fmt::Display for BuildTool {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BuildTool::Cargo => write!(f, "cargo"),
+            BuildTool::Cross => write!(f, "cross"),
+            BuildTool::Zigbuild => write!(f, "cargo-zigbuild"),
         }
     }
 }
 
+/// Saved deployment target in `.horus/deploy.yaml`
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DeployTarget {
+    pub host: String,
+    pub arch: Option<String>,
+    pub dir: Option<String>,
+    pub port: Option<u16>,
+    pub identity: Option<PathBuf>,
+    pub builder: Option<String>,
+    /// systemd unit to restart after activating a release
+    pub service: Option<String>,
+    /// URL checked on the robot after the restart
+    pub health_url: Option<String>,
+    /// Extra files and directories to deploy
+    #[serde(default)]
+    pub assets: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DeployFile {
+    #[serde(default)]
+    targets: BTreeMap<String, DeployTarget>,
+}
+
+fn load_deploy_targets(path: &Path) -> HorusResult<BTreeMap<String, DeployTarget>> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+    let content = std::fs::read_to_string(path)?;
+    let file: DeployFile = serde_yaml::from_str(&content)
+        .map_err(|e| HorusError::Config(format!("Invalid {}: {}", path.display(), e)))?;
+    Ok(file.targets)
+}
+
+/// Command line options of `horus deploy`
+#[derive(Debug, Default)]
+pub struct DeployOptions {
+    /// Target host (user@host) or configured target name
+    pub target: String,
+    pub remote_dir: Option<String>,
+    pub arch: Option<String>,
+    pub builder: Option<String>,
+    pub service: Option<String>,
+    pub health_url: Option<String>,
+    pub run_after: bool,
+    pub release: bool,
+    pub port: Option<u16>,
+    pub identity: Option<PathBuf>,
+    /// Number of releases kept on the robot
+    pub keep_releases: usize,
+    pub dry_run: bool,
+}
+
 /// Deploy configuration
 #[derive(Debug)]
 pub struct DeployConfig {
@@ -59,6 +204,8 @@ pub struct DeployConfig {
     pub remote_dir: String,
     /// Target architecture
     pub arch: TargetArch,
+    /// Cross-compilation tool
+    pub builder: BuildTool,
     /// Whether to run after deploying
     pub run_after: bool,
     /// Whether to build in release mode
@@ -67,8 +214,14 @@ pub struct DeployConfig {
     pub port: u16,
     /// SSH identity file
     pub identity: Option<PathBuf>,
-    /// Extra rsync excludes
-    pub excludes: Vec<String>,
+    /// systemd unit to restart
+    pub service: Option<String>,
+    /// Health URL checked on the robot after the restart
+    pub health_url: Option<String>,
+    /// Files and directories deployed next to the binaries
+    pub assets: Vec<String>,
+    /// Number of releases kept on the robot
+    pub keep_releases: usize,
 }
 
 impl Default for DeployConfig {
@@ -77,49 +230,90 @@ impl Default for DeployConfig {
             target: String::new(),
             remote_dir: "~/horus_deploy".to_string(),
             arch: TargetArch::Aarch64,
+            builder: BuildTool::Cargo,
             run_after: false,
             release: true,
             port: 22,
             identity: None,
-            excludes: vec![],
+            service: None,
+            health_url: None,
+            assets: vec![],
+            keep_releases: 3,
+        }
+    }
+}
+
+impl DeployConfig {
+    /// Merge command line options over a saved target
+    fn resolve(options: DeployOptions, saved: Option<DeployTarget>) -> HorusResult<Self> {
+        let saved = saved.unwrap_or_default();
+        let target = if saved.host.is_empty() {
+            options.target
+        } else {
+            saved.host
+        };
+
+        let arch = match options.arch.or(saved.arch) {
+            Some(arch) => TargetArch::from_str(&arch)
+                .ok_or_else(|| HorusError::Config(format!("Unknown architecture '{}'", arch)))?,
+            None => detect_target_arch(&target),
+        };
+        let builder = match options.builder.or(saved.builder) {
+            Some(tool) => BuildTool::from_str(&tool).ok_or_else(|| {
+                HorusError::Config(format!(
+                    "Unknown build tool '{}' (use cargo, cross or zigbuild)",
+                    tool
+                ))
+            })?,
+            None => BuildTool::detect(&arch),
+        };
+
+        let mut assets: Vec<String> = ["horus.yaml", "assets", "config"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        for asset in saved.assets {
+            if !assets.contains(&asset) {
+                assets.push(asset);
+            }
         }
+
+        Ok(Self {
+            target,
+            remote_dir: options
+                .remote_dir
+                .or(saved.dir)
+                .unwrap_or_else(|| "~/horus_deploy".to_string()),
+            arch,
+            builder,
+            run_after: options.run_after,
+            release: options.release,
+            port: options.port.or(saved.port).unwrap_or(22),
+            identity: options.identity.or(saved.identity),
+            service: options.service.or(saved.service),
+            health_url: options.health_url.or(saved.health_url),
+            assets,
+            keep_releases: options.keep_releases.max(1),
+        })
+    }
+
+    fn releases_dir(&self) -> String {
+        format!("{}/releases", self.remote_dir)
+    }
+
+    fn release_dir(&self, release_id: &str) -> String {
+        format!("{}/releases/{}", self.remote_dir, release_id)
     }
 }
 
 /// Run the deploy command
-#[allow(clippy::too_many_arguments)]
-pub fn run_deploy(
-    target: &str,
-    remote_dir: Option<String>,
-    arch: Option<String>,
-    run_after: bool,
-    release: bool,
-    port: u16,
-    identity: Option<PathBuf>,
-    dry_run: bool,
-) -> HorusResult<()> {
-    // Parse target architecture
-    let target_arch = arch
-        .as_ref()
-        .and_then(|a| TargetArch::from_str(a))
-        .unwrap_or_else(|| detect_target_arch(target));
-
-    let config = DeployConfig {
-        target: target.to_string(),
-        remote_dir: remote_dir.unwrap_or_else(|| "~/horus_deploy".to_string()),
-        arch: target_arch,
-        run_after,
-        release,
-        port,
-        identity,
-        excludes: vec![
-            "target".to_string(),
-            ".git".to_string(),
-            "node_modules".to_string(),
-            "__pycache__".to_string(),
-            "*.pyc".to_string(),
-        ],
-    };
+pub fn run_deploy(options: DeployOptions) -> HorusResult<()> {
+    let saved = load_deploy_targets(Path::new(DEPLOY_CONFIG_PATH))?
+        .get(&options.target)
+        .cloned();
+    let dry_run = options.dry_run;
+    let config = DeployConfig::resolve(options, saved)?;
+    let release_id = chrono::Local::now().format("%Y%m%d-%H%M%S").to_string();
 
     println!("{}", "HORUS Deploy".green().bold());
     println!();
@@ -130,11 +324,15 @@ pub fn run_deploy(
         "Architecture:".cyan(),
         config.arch.display_name()
     );
+    println!("  {} {}", "Build tool:".cyan(), config.builder);
     println!(
         "  {} {}",
         "Build mode:".cyan(),
         if config.release { "release" } else { "debug" }
     );
+    if let Some(ref service) = config.service {
+        println!("  {} {}", "Service:".cyan(), service);
+    }
     println!("  {} {}", "Run after:".cyan(), config.run_after);
     println!();
 
@@ -146,67 +344,130 @@ pub fn run_deploy(
                 .bold()
         );
         println!();
-        print_deploy_plan(&config);
+        print_deploy_plan(&config, &release_id);
         return Ok(());
     }
 
     // Step 1: Build for target
     println!("{}", "Step 1: Building project...".cyan().bold());
-    build_for_target(&config)?;
+    let build_dir = find_build_dir()?;
+    build_for_target(&config, &build_dir)?;
+    let binaries = collect_binaries(&config, &build_dir)?;
 
-    // Step 2: Sync files
+    // Step 2: Upload the release
     println!();
-    println!("{}", "Step 2: Syncing files to target...".cyan().bold());
-    sync_to_target(&config)?;
+    println!("{}", "Step 2: Uploading release...".cyan().bold());
+    stage_release(&binaries)?;
+    if let Err(e) = sync_to_target(&config, &release_id) {
+        // Best effort: don't leave half-uploaded releases behind
+        let _ = ssh_run(
+            &config,
+            &format!("rm -rf {}", config.release_dir(&release_id)),
+            false,
+        );
+        return Err(e);
+    }
 
-    // Step 3: Run if requested
+    // Step 3: Activate and restart
+    println!();
+    println!("{}", "Step 3: Activating release...".cyan().bold());
+    let previous = activate_release(&config, &format!("releases/{}", release_id))?;
+    if let Err(e) = restart_and_verify(&config) {
+        eprintln!("  {} {}", "".red(), e);
+        rollback(&config, previous.as_deref(), &release_id)?;
+        return Err(HorusError::Config(format!(
+            "Deployment of release {} failed and was rolled back",
+            release_id
+        )));
+    }
+    prune_releases(&config);
+
+    // Step 4: Run if requested
     if config.run_after {
         println!();
-        println!("{}", "Step 3: Running on target...".cyan().bold());
-        run_on_target(&config)?;
+        println!("{}", "Step 4: Running on target...".cyan().bold());
+        let binary = binaries
+            .first()
+            .and_then(|path| path.file_name())
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_else(|| "horus-project".to_string());
+        run_on_target(&config, &binary)?;
     }
 
-    println!();
-    println!("{} Deployment complete!", "".green());
     println!();
     println!(
-        "  {} ssh {}:{} to access your robot",
-        "Tip:".dimmed(),
-        config.target,
-        config.port
+        "{} Deployment complete! (release {})",
+        "".green(),
+        release_id
     );
+    println!();
+    if config.service.is_none() {
+        println!(
+            "  {} Run the robot as a systemd service with ExecStart={}/current/bin/<binary>",
+            "Tip:".dimmed(),
+            config.remote_dir
+        );
+        println!("       and pass --service <UNIT> to restart it and roll back failed deployments");
+    } else {
+        println!(
+            "  {} ssh -p {} {} to access your robot",
+            "Tip:".dimmed(),
+            config.port,
+            config.target
+        );
+    }
 
     Ok(())
 }
 
 /// Print what would be done in dry-run mode
-fn print_deploy_plan(config: &DeployConfig) {
-    let target = config.arch.rust_target();
-    let mode = if config.release { "--release" } else { "" };
+fn print_deploy_plan(config: &DeployConfig, release_id: &str) {
+    let (program, args) = config
+        .builder
+        .build_command(config.arch.rust_target(), config.release);
 
     println!("  1. Build:");
-    if target.is_empty() {
-        println!("     cargo build {}", mode);
-    } else {
-        println!("     cargo build {} --target {}", mode, target);
-    }
+    println!("     {} {}", program, args.join(" "));
 
     println!();
-    println!("  2. Sync files:");
+    println!("  2. Upload binaries and assets:");
     println!(
-        "     rsync -avz --delete -e 'ssh -p {}' ./ {}:{}",
-        config.port, config.target, config.remote_dir
+        "     rsync -az -e 'ssh -p {}' {}/bin {} {}:{}/",
+        config.port,
+        STAGING_DIR,
+        config.assets.join(" "),
+        config.target,
+        config.release_dir(release_id)
     );
 
-    if config.run_after {
-        println!();
-        println!("  3. Run on target:");
+    println!();
+    println!("  3. Activate:");
+    println!(
+        "     ssh -p {} {} '{}'",
+        config.port,
+        config.target,
+        activate_script(config, &format!("releases/{}", release_id))
+    );
+    if let Some(ref service) = config.service {
+        println!(
+            "     ssh -p {} {} 'sudo systemctl restart {}'",
+            config.port, config.target, service
+        );
         println!(
-            "     ssh -p {} {} 'cd {} && ./target/{}/horus_project'",
+            "     ssh -p {} {} '{}'",
             config.port,
             config.target,
-            config.remote_dir,
-            if config.release { "release" } else { "debug" }
+            verify_script(service, config.health_url.as_deref())
+        );
+        println!("     (on failure: switch back to the previous release and restart)");
+    }
+
+    if config.run_after {
+        println!();
+        println!("  4. Run on target:");
+        println!(
+            "     ssh -p {} {} 'cd {}/current && ./bin/<binary>'",
+            config.port, config.target, config.remote_dir
         );
     }
 }
@@ -231,12 +492,34 @@ fn detect_target_arch(target: &str) -> TargetArch {
     }
 }
 
+fn command_exists(program: &str) -> bool {
+    Command::new(program)
+        .arg("--version")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .is_ok()
+}
+
+/// Cargo project to build: the project itself or the one `horus run` generated
+fn find_build_dir() -> HorusResult<PathBuf> {
+    if Path::new("Cargo.toml").exists() {
+        Ok(PathBuf::from("."))
+    } else if Path::new(".horus/Cargo.toml").exists() {
+        Ok(PathBuf::from(".horus"))
+    } else {
+        Err(HorusError::Config(
+            "No Cargo project found. Run 'horus run --build-only' once to generate it.".to_string(),
+        ))
+    }
+}
+
 /// Build the project for target architecture
-fn build_for_target(config: &DeployConfig) -> HorusResult<()> {
+fn build_for_target(config: &DeployConfig, build_dir: &Path) -> HorusResult<()> {
     let target = config.arch.rust_target();
 
-    // Check if cross-compilation target is installed
-    if !target.is_empty() {
+    // Plain cargo and zigbuild need the rustup target; cross brings its own
+    if !target.is_empty() && config.builder != BuildTool::Cross {
         print!("  {} Checking target {}... ", "".cyan(), target);
         let check = Command::new("rustup")
             .args(["target", "list", "--installed"])
@@ -271,18 +554,11 @@ fn build_for_target(config: &DeployConfig) -> HorusResult<()> {
     }
 
     // Build the project
-    let mut cmd = Command::new("cargo");
-    cmd.arg("build");
-
-    if config.release {
-        cmd.arg("--release");
-    }
+    let (program, args) = config.builder.build_command(target, config.release);
+    let mut cmd = Command::new(program);
+    cmd.args(&args).current_dir(build_dir);
 
-    if !target.is_empty() {
-        cmd.args(["--target", target]);
-    }
-
-    print!("  {} Building", "".cyan());
+    print!("  {} Building with {}", "".cyan(), config.builder);
     if !target.is_empty() {
         print!(" for {}", config.arch.display_name());
     }
@@ -293,18 +569,89 @@ fn build_for_target(config: &DeployConfig) -> HorusResult<()> {
 
     let status = cmd
         .status()
-        .map_err(|e| HorusError::Config(format!("Failed to run cargo: {}", e)))?;
+        .map_err(|e| HorusError::Config(format!("Failed to run {}: {}", program, e)))?;
 
     if !status.success() {
-        return Err(HorusError::Config("Build failed".to_string()));
+        let hint = if config.builder == BuildTool::Cargo && !target.is_empty() {
+            " (install 'cross' or 'cargo-zigbuild' if the linker for the target is missing)"
+        } else {
+            ""
+        };
+        return Err(HorusError::Config(format!("Build failed{}", hint)));
     }
 
     println!("  {} Build complete", "".green());
     Ok(())
 }
 
-/// Sync files to target using rsync
-fn sync_to_target(config: &DeployConfig) -> HorusResult<()> {
+/// Paths of the built binaries
+fn collect_binaries(config: &DeployConfig, build_dir: &Path) -> HorusResult<Vec<PathBuf>> {
+    let mode = if config.release { "release" } else { "debug" };
+    let target = config.arch.rust_target();
+    let out_dir = if target.is_empty() {
+        build_dir.join("target").join(mode)
+    } else {
+        build_dir.join("target").join(target).join(mode)
+    };
+
+    let binaries: Vec<PathBuf> = find_binary_names(&build_dir.join("Cargo.toml"))
+        .into_iter()
+        .map(|name| out_dir.join(name))
+        .filter(|path| path.is_file())
+        .collect();
+    if binaries.is_empty() {
+        return Err(HorusError::Config(format!(
+            "No binaries found in {}",
+            out_dir.display()
+        )));
+    }
+    Ok(binaries)
+}
+
+/// Copy binaries into the staging directory
+fn stage_release(binaries: &[PathBuf]) -> HorusResult<()> {
+    let bin_dir = Path::new(STAGING_DIR).join("bin");
+    if bin_dir.exists() {
+        std::fs::remove_dir_all(&bin_dir)?;
+    }
+    std::fs::create_dir_all(&bin_dir)?;
+    for binary in binaries {
+        if let Some(name) = binary.file_name() {
+            std::fs::copy(binary, bin_dir.join(name))?;
+            println!("  {} {}", "".cyan(), name.to_string_lossy());
+        }
+    }
+    Ok(())
+}
+
+/// SSH command with port and identity
+fn ssh_command(config: &DeployConfig, tty: bool) -> Command {
+    let mut cmd = Command::new("ssh");
+    cmd.args(["-p", &config.port.to_string()]);
+    if let Some(ref identity) = config.identity {
+        cmd.args(["-i", &identity.to_string_lossy()]);
+    }
+    if tty {
+        cmd.arg("-t");
+    }
+    cmd.arg(&config.target);
+    cmd
+}
+
+/// Run a shell command on the target; returns whether it succeeded
+fn ssh_run(config: &DeployConfig, script: &str, tty: bool) -> HorusResult<bool> {
+    let status = ssh_command(config, tty)
+        .arg(script)
+        .stdout(Stdio::inherit())
+        .stderr(Stdio::inherit())
+        .stdin(Stdio::inherit())
+        .status()
+        .map_err(|e| HorusError::Config(format!("Failed to run SSH: {}", e)))?;
+    Ok(status.success())
+}
+
+/// Upload binaries and assets into a new release directory
+fn sync_to_target(config: &DeployConfig, release_id: &str) -> HorusResult<()> {
     // Check if rsync is available
     if Command::new("rsync").arg("--version").output().is_err() {
         return Err(HorusError::Config(
@@ -312,14 +659,19 @@ fn sync_to_target(config: &DeployConfig) -> HorusResult<()> {
         ));
     }
 
+    let release_dir = config.release_dir(release_id);
+    if !ssh_run(config, &format!("mkdir -p {}", release_dir), false)? {
+        return Err(HorusError::Config(format!(
+            "Failed to create {} on {}",
+            release_dir, config.target
+        )));
+    }
+
     // Build rsync command
     let mut cmd = Command::new("rsync");
-    cmd.args(["-avz", "--delete", "--progress"]);
-
-    // Add excludes
-    for exclude in &config.excludes {
-        cmd.args(["--exclude", exclude]);
-    }
+    cmd.args(["-az", "--progress"]);
+    // Unchanged files are hard-linked from the active release
+    cmd.arg("--link-dest=../../current/");
 
     // SSH options
     let ssh_cmd = if let Some(ref identity) = config.identity {
@@ -329,9 +681,14 @@ fn sync_to_target(config: &DeployConfig) -> HorusResult<()> {
     };
     cmd.args(["-e", &ssh_cmd]);
 
-    // Source and destination
-    cmd.arg("./");
-    cmd.arg(format!("{}:{}/", config.target, config.remote_dir));
+    // Sources and destination
+    cmd.arg(Path::new(STAGING_DIR).join("bin"));
+    for asset in &config.assets {
+        if Path::new(asset).exists() {
+            cmd.arg(asset.trim_end_matches('/'));
+        }
+    }
+    cmd.arg(format!("{}:{}/", config.target, release_dir));
 
     println!("  {} Syncing files...", "".cyan());
 
@@ -350,34 +707,119 @@ fn sync_to_target(config: &DeployConfig) -> HorusResult<()> {
     Ok(())
 }
 
-/// Run the project on the target
-fn run_on_target(config: &DeployConfig) -> HorusResult<()> {
-    // Find the binary name from Cargo.toml
-    let binary_name = find_binary_name().unwrap_or_else(|| "horus_project".to_string());
+/// Shell script pointing `current` at `release` atomically; prints the previous release
+fn activate_script(config: &DeployConfig, release: &str) -> String {
+    format!(
+        "cd {} && readlink current; ln -sfn {} current.new && mv -Tf current.new current",
+        config.remote_dir, release
+    )
+}
 
-    let target = config.arch.rust_target();
-    let mode = if config.release { "release" } else { "debug" };
+/// Shell script checking the service (and health URL) after a restart
+fn verify_script(service: &str, health_url: Option<&str>) -> String {
+    let mut script = format!("sleep 3 && systemctl is-active --quiet {}", service);
+    if let Some(url) = health_url {
+        script.push_str(&format!(
+            " && for i in $(seq 1 10); do curl -fsS --max-time 2 -o /dev/null {} && exit 0; sleep 1; done; exit 1",
+            url
+        ));
+    }
+    script
+}
 
-    // Build the path to the binary
-    let binary_path = if target.is_empty() {
-        format!("./target/{}/{}", mode, binary_name)
-    } else {
-        format!("./target/{}/{}/{}", target, mode, binary_name)
+/// Switch `current` to a release; returns the previously active release
+fn activate_release(config: &DeployConfig, release: &str) -> HorusResult<Option<String>> {
+    let output = ssh_command(config, false)
+        .arg(activate_script(config, release))
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| HorusError::Config(format!("Failed to run SSH: {}", e)))?;
+    if !output.status.success() {
+        return Err(HorusError::Config(format!(
+            "Failed to activate {} on {}",
+            release, config.target
+        )));
+    }
+
+    let previous = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    println!("  {} {} is now current", "".green(), release);
+    Ok(Some(previous).filter(|p| !p.is_empty()))
+}
+
+/// Restart the service and wait until it is healthy
+fn restart_and_verify(config: &DeployConfig) -> HorusResult<()> {
+    let Some(ref service) = config.service else {
+        return Ok(());
     };
 
-    let remote_cmd = format!("cd {} && {}", config.remote_dir, binary_path);
+    println!("  {} Restarting {}...", "".cyan(), service);
+    if !ssh_run(config, &format!("sudo systemctl restart {}", service), true)? {
+        return Err(HorusError::Config(format!(
+            "Failed to restart service {}",
+            service
+        )));
+    }
 
-    // Build SSH command
-    let mut cmd = Command::new("ssh");
-    cmd.args(["-p", &config.port.to_string()]);
+    println!("  {} Checking service health...", "".cyan());
+    if !ssh_run(
+        config,
+        &verify_script(service, config.health_url.as_deref()),
+        false,
+    )? {
+        return Err(HorusError::Config(format!(
+            "Service {} is not healthy after the restart",
+            service
+        )));
+    }
+    println!("  {} {} is running", "".green(), service);
+    Ok(())
+}
 
-    if let Some(ref identity) = config.identity {
-        cmd.args(["-i", &identity.to_string_lossy()]);
+/// Reactivate the previous release and remove the failed one
+fn rollback(config: &DeployConfig, previous: Option<&str>, release_id: &str) -> HorusResult<()> {
+    let Some(previous) = previous else {
+        eprintln!("  {} No previous release to roll back to", "".yellow());
+        return Ok(());
+    };
+
+    println!();
+    println!(
+        "{}",
+        format!("Rolling back to {}...", previous).yellow().bold()
+    );
+    activate_release(config, previous)?;
+    let _ = ssh_run(
+        config,
+        &format!("rm -rf {}", config.release_dir(release_id)),
+        false,
+    );
+    if let Err(e) = restart_and_verify(config) {
+        return Err(HorusError::Config(format!(
+            "Rollback to {} failed: {}",
+            previous, e
+        )));
     }
+    println!("  {} Rolled back to {}", "".green(), previous);
+    Ok(())
+}
+
+/// Remove all but the newest releases
+fn prune_releases(config: &DeployConfig) {
+    let script = format!(
+        "cd {} && ls -1 | sort -r | tail -n +{} | xargs -r rm -rf",
+        config.releases_dir(),
+        config.keep_releases + 1
+    );
+    let _ = ssh_run(config, &script, false);
+}
+
+/// Run the project on the target
+fn run_on_target(config: &DeployConfig, binary_name: &str) -> HorusResult<()> {
+    let binary_path = format!("./bin/{}", binary_name);
+    let remote_cmd = format!("cd {}/current && {}", config.remote_dir, binary_path);
 
     // Allocate a TTY for interactive use
-    cmd.arg("-t");
-    cmd.arg(&config.target);
+    let mut cmd = ssh_command(config, true);
     cmd.arg(&remote_cmd);
 
     println!("  {} Running: {}", "".cyan(), binary_path);
@@ -407,49 +849,34 @@ fn run_on_target(config: &DeployConfig) -> HorusResult<()> {
     Ok(())
 }
 
-/// Find the binary name from Cargo.toml
-fn find_binary_name() -> Option<String> {
-    let cargo_toml = Path::new("Cargo.toml");
-    if !cargo_toml.exists() {
-        return None;
-    }
-
-    let content = std::fs::read_to_string(cargo_toml).ok()?;
-
-    // Try to find [[bin]] name first
-    for line in content.lines() {
-        let line = line.trim();
-        if line.starts_with("name") && line.contains('=') {
-            let parts: Vec<&str> = line.splitn(2, '=').collect();
-            if parts.len() == 2 {
-                let name = parts[1].trim().trim_matches('"').trim_matches('\'');
-                return Some(name.to_string());
-            }
-        }
-    }
+/// Binary names from Cargo.toml: `[[bin]]` targets, or the package name
+fn find_binary_names(cargo_toml: &Path) -> Vec<String> {
+    let Ok(content) = std::fs::read_to_string(cargo_toml) else {
+        return Vec::new();
+    };
+    let Ok(manifest) = content.parse::<toml::Table>() else {
+        return Vec::new();
+    };
 
-    // Fall back to package name
-    let mut in_package = false;
-    for line in content.lines() {
-        let line = line.trim();
-        if line == "[package]" {
-            in_package = true;
-            continue;
-        }
-        if line.starts_with('[') {
-            in_package = false;
-            continue;
-        }
-        if in_package && line.starts_with("name") {
-            let parts: Vec<&str> = line.splitn(2, '=').collect();
-            if parts.len() == 2 {
-                let name = parts[1].trim().trim_matches('"').trim_matches('\'');
-                return Some(name.to_string());
-            }
-        }
+    let bins: Vec<String> = manifest
+        .get("bin")
+        .and_then(|bins| bins.as_array())
+        .map(|bins| {
+            bins.iter()
+                .filter_map(|bin| bin.get("name")?.as_str().map(String::from))
+                .collect()
+        })
+        .unwrap_or_default();
+    if !bins.is_empty() {
+        return bins;
     }
 
-    None
+    manifest
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(|name| name.as_str())
+        .map(|name| vec![name.to_string()])
+        .unwrap_or_default()
 }
 
 /// List available deployment targets from config
@@ -457,13 +884,21 @@ pub fn list_targets() -> HorusResult<()> {
     println!("{}", "Deployment Targets".green().bold());
     println!();
 
-    // Check for .horus/deploy.yaml
-    let config_path = Path::new(".horus/deploy.yaml");
-    if config_path.exists() {
-        println!("  {} Found .horus/deploy.yaml", "".cyan());
-        if let Ok(content) = std::fs::read_to_string(config_path) {
-            println!();
-            println!("{}", content);
+    let targets = load_deploy_targets(Path::new(DEPLOY_CONFIG_PATH))?;
+    if !targets.is_empty() {
+        for (name, target) in &targets {
+            println!(
+                "  {} {} ({})",
+                name.cyan().bold(),
+                target.host,
+                target.arch.as_deref().unwrap_or("aarch64")
+            );
+            if let Some(ref dir) = target.dir {
+                println!("      dir: {}", dir);
+            }
+            if let Some(ref service) = target.service {
+                println!("      service: {}", service);
+            }
         }
     } else {
         println!("  {}", "No deployment targets configured.".dimmed());
@@ -478,10 +913,14 @@ pub fn list_targets() -> HorusResult<()> {
         println!("        host: pi@192.168.1.100");
         println!("        arch: aarch64");
         println!("        dir: ~/my_robot");
+        println!("        service: my_robot       # systemd unit to restart");
+        println!("        health_url: http://localhost:8081/readyz");
         println!("      jetson:");
         println!("        host: nvidia@jetson.local");
         println!("        arch: aarch64");
         println!("        dir: ~/horus_app");
+        println!("        builder: zigbuild");
+        println!("        assets: [models, maps]");
     }
 
     println!();
@@ -490,6 +929,99 @@ pub fn list_targets() -> HorusResult<()> {
     println!("    armv7    - Raspberry Pi 2/3, older ARM boards");
     println!("    x86_64   - Intel NUC, standard PCs");
     println!("    native   - Same as build host");
+    println!("    Any Rust target triple, e.g. aarch64-unknown-linux-musl");
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_target_arch_accepts_triples() {
+        assert_eq!(
+            TargetArch::from_str("aarch64-unknown-linux-gnu"),
+            Some(TargetArch::Aarch64)
+        );
+        assert_eq!(
+            TargetArch::from_str("aarch64-unknown-linux-musl")
+                .unwrap()
+                .rust_target(),
+            "aarch64-unknown-linux-musl"
+        );
+        assert_eq!(TargetArch::from_str("sparc"), None);
+    }
+
+    #[test]
+    fn test_build_commands() {
+        let target = "aarch64-unknown-linux-gnu";
+        assert_eq!(
+            BuildTool::Cross.build_command(target, true),
+            (
+                "cross",
+                vec![
+                    "build".to_string(),
+                    "--release".to_string(),
+                    "--target".to_string(),
+                    target.to_string()
+                ]
+            )
+        );
+        let (program, args) = BuildTool::Zigbuild.build_command(target, false);
+        assert_eq!(program, "cargo");
+        assert_eq!(args[0], "zigbuild");
+        assert!(!args.contains(&"--release".to_string()));
+    }
+
+    #[test]
+    fn test_resolve_saved_target() {
+        let saved = DeployTarget {
+            host: "pi@robot.local".to_string(),
+            arch: Some("armv7".to_string()),
+            dir: Some("~/robot".to_string()),
+            service: Some("robot".to_string()),
+            assets: vec!["models".to_string()],
+            ..Default::default()
+        };
+        let options = DeployOptions {
+            target: "robot".to_string(),
+            builder: Some("cross".to_string()),
+            port: Some(2222),
+            release: true,
+            keep_releases: 3,
+            ..Default::default()
+        };
+
+        let config = DeployConfig::resolve(options, Some(saved)).unwrap();
+        assert_eq!(config.target, "pi@robot.local");
+        assert_eq!(config.arch, TargetArch::Armv7);
+        assert_eq!(config.builder, BuildTool::Cross);
+        assert_eq!(config.port, 2222);
+        assert_eq!(config.service.as_deref(), Some("robot"));
+        assert!(config.assets.contains(&"horus.yaml".to_string()));
+        assert!(config.assets.contains(&"models".to_string()));
+        assert_eq!(config.release_dir("1"), "~/robot/releases/1");
+    }
+
+    #[test]
+    fn test_deploy_file_and_binary_names() {
+        let dir = tempfile::tempdir().unwrap();
+        let deploy_yaml = dir.path().join("deploy.yaml");
+        std::fs::write(
+            &deploy_yaml,
+            "targets:\n  robot:\n    host: pi@robot.local\n    health_url: http://localhost:8081/readyz\n",
+        )
+        .unwrap();
+        let targets = load_deploy_targets(&deploy_yaml).unwrap();
+        assert_eq!(targets["robot"].host, "pi@robot.local");
+
+        let cargo_toml = dir.path().join("Cargo.toml");
+        std::fs::write(
+            &cargo_toml,
+            "[package]\nname = \"my_robot\"\n\n[[bin]]\nname = \"controller\"\npath = \"src/main.rs\"\n",
+        )
+        .unwrap();
+        assert_eq!(find_binary_names(&cargo_toml), vec!["controller"]);
+    }
+}
@@ -1,9 +1,16 @@
 //! Doctor command - System diagnostics for HORUS
 //!
-//! Checks system configuration and reports on environment health.
+//! Checks system configuration and reports on environment health, including
+//! IPC state left behind by crashed processes (cleaned with `--fix`).
 
+use super::clean::format_size;
 use colored::*;
+use horus_core::communication::namespace::{validate_namespace, NAMESPACE_ENV};
 use horus_core::error::HorusResult;
+use horus_core::memory::stale::{
+    find_stale_link_sockets, find_stale_segments, find_untracked_segments, reclaim_stale_segments,
+};
+use horus_core::memory::{shm_base_dir, DOMAIN_ID_ENV};
 use std::path::Path;
 use std::process::Command;
 
@@ -15,8 +22,8 @@ pub enum CheckStatus {
     Error,
 }
 
-/// Run all diagnostic checks
-pub fn run_doctor(verbose: bool) -> HorusResult<()> {
+/// Run all diagnostic checks, removing stale IPC state if `fix` is set
+pub fn run_doctor(verbose: bool, fix: bool) -> HorusResult<()> {
     println!("{}", "HORUS System Diagnostics".green().bold());
     println!();
 
@@ -35,6 +42,14 @@ pub fn run_doctor(verbose: bool) -> HorusResult<()> {
     let (status, msg) = check_shared_memory(verbose);
     print_check("Shared memory", status, &msg, &mut warnings, &mut errors);
 
+    // Leftovers of crashed processes
+    let (status, msg) = check_stale_ipc(verbose, fix);
+    print_check("Stale IPC", status, &msg, &mut warnings, &mut errors);
+
+    // HORUS environment variables
+    let (status, msg) = check_environment(verbose);
+    print_check("Environment", status, &msg, &mut warnings, &mut errors);
+
     // Python bindings
     let (status, msg) = check_python(verbose);
     print_check("Python bindings", status, &msg, &mut warnings, &mut errors);
@@ -151,13 +166,16 @@ fn check_cargo(_verbose: bool) -> (CheckStatus, String) {
 }
 
 fn check_shared_memory(verbose: bool) -> (CheckStatus, String) {
-    let shm_path = if cfg!(target_os = "macos") {
-        "/tmp/horus"
+    // Linux uses tmpfs; elsewhere regions are tracked under the HORUS base
+    // directory (marker files on Windows), which is created on demand
+    let path = if cfg!(target_os = "linux") {
+        Path::new("/dev/shm").to_path_buf()
     } else {
-        "/dev/shm"
+        let base = shm_base_dir();
+        let _ = std::fs::create_dir_all(&base);
+        base
     };
-
-    let path = Path::new(shm_path);
+    let shm_path = path.display().to_string();
     if path.exists() {
         // Try to create a test file
         let test_path = path.join("horus_doctor_test");
@@ -186,6 +204,92 @@ fn check_shared_memory(verbose: bool) -> (CheckStatus, String) {
     }
 }
 
+fn check_stale_ipc(verbose: bool, fix: bool) -> (CheckStatus, String) {
+    let segments = find_stale_segments();
+    let sockets = find_stale_link_sockets();
+
+    if segments.is_empty() && sockets.is_empty() {
+        let untracked = find_untracked_segments().len();
+        return if verbose && untracked > 0 {
+            (
+                CheckStatus::Ok,
+                format!(
+                    "None ({} untracked segments, `horus clean --shm` removes them)",
+                    untracked
+                ),
+            )
+        } else {
+            (CheckStatus::Ok, "None".to_string())
+        };
+    }
+
+    let bytes: usize = segments.iter().map(|s| s.size).sum();
+    let mut found = format!(
+        "{} stale segment(s) ({}), {} stale link socket(s)",
+        segments.len(),
+        format_size(bytes as u64),
+        sockets.len()
+    );
+    if verbose && !segments.is_empty() {
+        let names: Vec<&str> = segments.iter().map(|s| s.name.as_str()).collect();
+        found.push_str(&format!(": {}", names.join(", ")));
+    }
+
+    if !fix {
+        return (
+            CheckStatus::Warning,
+            format!("{} (run `horus doctor --fix`)", found),
+        );
+    }
+
+    let reclaimed = reclaim_stale_segments().len();
+    let removed = sockets
+        .iter()
+        .filter(|path| std::fs::remove_file(path).is_ok())
+        .count();
+    if reclaimed == segments.len() && removed == sockets.len() {
+        (CheckStatus::Ok, format!("Removed {}", found))
+    } else {
+        (
+            CheckStatus::Warning,
+            format!(
+                "Removed {} of {} segment(s) and {} of {} socket(s)",
+                reclaimed,
+                segments.len(),
+                removed,
+                sockets.len()
+            ),
+        )
+    }
+}
+
+fn check_environment(_verbose: bool) -> (CheckStatus, String) {
+    let mut problems = Vec::new();
+
+    if let Ok(domain) = std::env::var(DOMAIN_ID_ENV) {
+        if !domain.trim().is_empty() && domain.trim().parse::<u32>().is_err() {
+            problems.push(format!(
+                "{}='{}' is not a number (default domain used)",
+                DOMAIN_ID_ENV, domain
+            ));
+        }
+    }
+    if let Ok(namespace) = std::env::var(NAMESPACE_ENV) {
+        if let Err(e) = validate_namespace(&namespace) {
+            problems.push(format!("{}: {}", NAMESPACE_ENV, e));
+        }
+    }
+
+    if problems.is_empty() {
+        let domain = horus_core::memory::domain_id()
+            .map(|id| format!("domain {}", id))
+            .unwrap_or_else(|| "default domain".to_string());
+        (CheckStatus::Ok, domain)
+    } else {
+        (CheckStatus::Warning, problems.join("; "))
+    }
+}
+
 fn check_python(verbose: bool) -> (CheckStatus, String) {
     // Check if pyhorus is installed
     match Command::new("python3")
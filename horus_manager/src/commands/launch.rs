@@ -1,13 +1,24 @@
 //! Launch command - Multi-node launch from YAML files
 //!
-//! Launches multiple HORUS nodes from a configuration file.
+//! Launches multiple HORUS nodes from a configuration file. Nodes can run on
+//! other machines through `horus agent` (see [`super::agent`]).
+//!
+//! The same configuration can be embedded in a project's `horus.yaml` as a
+//! `launch:` section (or a bare `processes:` list), in which case `horus run`
+//! launches and supervises the whole group.
 
+use super::agent::{AgentClient, RemoteNodeStatus};
 use colored::*;
+use horus_core::communication::network::{BRIDGE_ROUTER_ENV, BRIDGE_TOPICS_ENV};
+use horus_core::core::{NodeHeartbeat, NodeState};
 use horus_core::error::{HorusError, HorusResult};
+use horus_core::memory::shm_topics_dir;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::path::Path;
 use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 /// Node configuration in a launch file
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,6 +50,14 @@ pub struct LaunchNode {
     #[serde(default)]
     pub command: Option<String>,
 
+    /// Source file (`.rs` or `.py`) built and run with `horus run`
+    #[serde(default)]
+    pub file: Option<String>,
+
+    /// Build `file` in release mode
+    #[serde(default)]
+    pub release: bool,
+
     /// Arguments to pass
     #[serde(default)]
     pub args: Vec<String>,
@@ -58,17 +77,92 @@ pub struct LaunchNode {
     /// Restart policy: "never", "always", "on-failure"
     #[serde(default = "default_restart")]
     pub restart: String,
+
+    /// Maximum number of restarts (unlimited if unset)
+    #[serde(default)]
+    pub max_restarts: Option<u32>,
+
+    /// Delay before restarting an exited node (seconds)
+    #[serde(default = "default_restart_delay")]
+    pub restart_delay: f64,
+
+    /// Readiness conditions that must hold before this node is started
+    #[serde(default)]
+    pub wait_for: Vec<WaitFor>,
+
+    /// Maximum time to wait for `wait_for` conditions (seconds)
+    #[serde(default = "default_wait_timeout")]
+    pub wait_timeout: f64,
+
+    /// What to do when `wait_for` fails: "abort", "skip", "continue"
+    #[serde(default = "default_wait_policy")]
+    pub on_wait_timeout: String,
+
+    /// Host to run on, a name from the launch file's `hosts` (default: this machine)
+    #[serde(default)]
+    pub host: Option<String>,
+
+    /// Topics the node publishes or subscribes to; topics used on more than
+    /// one host are bridged through the router
+    #[serde(default)]
+    pub topics: Vec<String>,
 }
 
 fn default_restart() -> String {
     "never".to_string()
 }
 
+fn default_restart_delay() -> f64 {
+    1.0
+}
+
+fn default_wait_timeout() -> f64 {
+    30.0
+}
+
+fn default_wait_policy() -> String {
+    "abort".to_string()
+}
+
+/// A readiness condition (exactly one of `node` or `topic`)
+///
+/// ```yaml
+/// wait_for:
+///   - node: lidar_driver   # heartbeat reports Running
+///   - topic: scan          # topic exists in shared memory
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaitFor {
+    /// Node that must report `Running` in its heartbeat (its readiness event)
+    #[serde(default)]
+    pub node: Option<String>,
+
+    /// Topic that must exist in shared memory
+    #[serde(default)]
+    pub topic: Option<String>,
+}
+
+impl std::fmt::Display for WaitFor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match (&self.node, &self.topic) {
+            (Some(node), _) => write!(f, "node '{}' ready", node),
+            (None, Some(topic)) => write!(f, "topic '{}'", topic),
+            (None, None) => write!(f, "<empty>"),
+        }
+    }
+}
+
+/// Result of waiting for a node's readiness conditions
+enum WaitOutcome {
+    Ready,
+    Failed(String),
+}
+
 /// Launch file configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LaunchConfig {
     /// Nodes to launch
-    #[serde(default)]
+    #[serde(default, alias = "processes")]
     pub nodes: Vec<LaunchNode>,
 
     /// Global environment variables
@@ -82,10 +176,28 @@ pub struct LaunchConfig {
     /// Session name
     #[serde(default)]
     pub session: Option<String>,
+
+    /// Agents of other machines by host name (`robot: 192.168.1.20`)
+    #[serde(default)]
+    pub hosts: HashMap<String, String>,
+
+    /// Router for bridged topics (`ip:port`), started on this machine if unset
+    #[serde(default)]
+    pub router: Option<String>,
+
+    /// Resolved launch arguments (declared under `args`, set with `--arg`)
+    #[serde(skip)]
+    pub args: BTreeMap<String, String>,
 }
 
-/// Run the launch command
-pub fn run_launch(file: &Path, dry_run: bool, namespace: Option<String>) -> HorusResult<()> {
+/// Port of the router `horus launch` starts for bridged topics
+const DEFAULT_ROUTER_PORT: u16 = 7777;
+
+/// Load a launch file, or the launch section of a project's `horus.yaml`
+fn load_launch_config(
+    file: &Path,
+    overrides: &BTreeMap<String, String>,
+) -> HorusResult<LaunchConfig> {
     // Check if file exists
     if !file.exists() {
         return Err(HorusError::Config(format!(
@@ -94,17 +206,446 @@ pub fn run_launch(file: &Path, dry_run: bool, namespace: Option<String>) -> Horu
         )));
     }
 
+    if file.file_name().and_then(|n| n.to_str()) == Some("horus.yaml") {
+        return load_project_launch(file, overrides)?.ok_or_else(|| {
+            HorusError::Config(format!(
+                "No 'launch' or 'processes' section in {}",
+                file.display()
+            ))
+        });
+    }
+
     // Read and parse the launch file
     let content = std::fs::read_to_string(file).map_err(HorusError::Io)?;
 
-    let config: LaunchConfig = serde_yaml::from_str(&content)
+    let doc: serde_yaml::Value = serde_yaml::from_str(&content)
         .map_err(|e| HorusError::Config(format!("Failed to parse launch file: {}", e)))?;
+    resolve_launch(doc, base_dir(file), overrides, 0)
+}
+
+/// Read the launch group declared in a project's `horus.yaml`
+///
+/// Either a full `launch:` section (same schema as a launch file) or a bare
+/// `processes:` list is accepted. Returns `None` if neither is present. The
+/// session defaults to the project name.
+pub fn load_project_launch(
+    manifest: &Path,
+    overrides: &BTreeMap<String, String>,
+) -> HorusResult<Option<LaunchConfig>> {
+    let content = std::fs::read_to_string(manifest).map_err(HorusError::Io)?;
+    parse_project_launch(&content, base_dir(manifest), overrides).map_err(|e| match e {
+        HorusError::Config(msg) => HorusError::Config(format!("{}: {}", manifest.display(), msg)),
+        other => other,
+    })
+}
+
+fn parse_project_launch(
+    content: &str,
+    base_dir: &Path,
+    overrides: &BTreeMap<String, String>,
+) -> HorusResult<Option<LaunchConfig>> {
+    let yaml: serde_yaml::Value = serde_yaml::from_str(content)
+        .map_err(|e| HorusError::Config(format!("Failed to parse: {}", e)))?;
+
+    let doc = if let Some(launch) = yaml.get("launch") {
+        launch.clone()
+    } else if let Some(processes) = yaml.get("processes") {
+        let mut doc = serde_yaml::Mapping::new();
+        if let Some(args) = yaml.get("args") {
+            doc.insert("args".into(), args.clone());
+        }
+        doc.insert("nodes".into(), processes.clone());
+        serde_yaml::Value::Mapping(doc)
+    } else {
+        return Ok(None);
+    };
+
+    let mut config = resolve_launch(doc, base_dir, overrides, 0)?;
+    if config.session.is_none() {
+        config.session = yaml
+            .get("name")
+            .and_then(|n| n.as_str())
+            .map(|n| n.to_string());
+    }
+    Ok(Some(config))
+}
+
+/// Parse `--arg NAME=VALUE` command-line launch arguments
+pub fn parse_launch_args(args: &[String]) -> HorusResult<BTreeMap<String, String>> {
+    args.iter()
+        .map(|arg| match arg.split_once('=') {
+            Some((name, value)) if !name.trim().is_empty() => {
+                Ok((name.trim().to_string(), value.to_string()))
+            }
+            _ => Err(HorusError::Config(format!(
+                "Invalid launch argument '{}' (expected NAME=VALUE)",
+                arg
+            ))),
+        })
+        .collect()
+}
+
+/// Launch and supervise the process group declared in `horus.yaml` (used by `horus run`)
+pub fn run_project_launch(
+    manifest: &Path,
+    config: LaunchConfig,
+    release: bool,
+    namespace: Option<String>,
+) -> HorusResult<()> {
+    let mut config = config;
+    if release {
+        for node in &mut config.nodes {
+            node.release = true;
+        }
+    }
+    launch_config(config, manifest, false, namespace)
+}
+
+/// Run the launch command
+pub fn run_launch(
+    file: &Path,
+    dry_run: bool,
+    namespace: Option<String>,
+    launch_args: &[String],
+) -> HorusResult<()> {
+    let overrides = parse_launch_args(launch_args)?;
+    let config = load_launch_config(file, &overrides)?;
+    launch_config(config, file, dry_run, namespace)
+}
+
+fn base_dir(file: &Path) -> &Path {
+    file.parent().unwrap_or_else(|| Path::new("."))
+}
+
+/// Maximum nesting of launch `include`s
+const MAX_INCLUDE_DEPTH: usize = 8;
+
+/// Resolve arguments, substitutions, conditionals and includes of a raw
+/// launch document, then parse it
+///
+/// ```yaml
+/// args:
+///   sim: false
+///   robot_name: { default: rover, description: Robot name }
+/// include:
+///   - file: sim.yaml
+///     if: sim
+/// nodes:
+///   - name: ${arg:robot_name}_driver
+///     command: driver --port ${env:ROBOT_PORT:-/dev/ttyUSB0}
+///     unless: sim
+/// ```
+fn resolve_launch(
+    doc: serde_yaml::Value,
+    base_dir: &Path,
+    overrides: &BTreeMap<String, String>,
+    depth: usize,
+) -> HorusResult<LaunchConfig> {
+    if depth > MAX_INCLUDE_DEPTH {
+        return Err(HorusError::Config(format!(
+            "Launch includes nested deeper than {} levels (circular include?)",
+            MAX_INCLUDE_DEPTH
+        )));
+    }
+
+    let mut doc = doc;
+    let Some(map) = doc.as_mapping_mut() else {
+        return Err(HorusError::Config(
+            "Launch configuration must be a mapping".to_string(),
+        ));
+    };
+
+    // Declared arguments: defaults, overridden from the command line (or the
+    // including file). Undeclared overrides are passed through to includes.
+    let mut args = overrides.clone();
+    if let Some(declared) = map.remove("args") {
+        let declared = declared.as_mapping().cloned().ok_or_else(|| {
+            HorusError::Config("'args' must map argument names to defaults".to_string())
+        })?;
+        for (name, spec) in declared {
+            let name = scalar_to_string(&name)
+                .ok_or_else(|| HorusError::Config("Invalid launch argument name".to_string()))?;
+            if args.contains_key(&name) {
+                continue;
+            }
+            let default = match &spec {
+                serde_yaml::Value::Mapping(m) => m.get("default").cloned(),
+                serde_yaml::Value::Null => None,
+                other => Some(other.clone()),
+            };
+            if let Some(default) = default.as_ref().and_then(scalar_to_string) {
+                let value = substitute(&default, &args)?;
+                args.insert(name, value);
+            }
+        }
+    }
+
+    let includes = map.remove("include");
+    substitute_value(&mut doc, &args)?;
+    if let Some(map) = doc.as_mapping_mut() {
+        for key in ["nodes", "processes"] {
+            if let Some(nodes) = map.get_mut(key) {
+                filter_conditional(nodes, &args)?;
+            }
+        }
+    }
+
+    let mut config: LaunchConfig = serde_yaml::from_value(doc)
+        .map_err(|e| HorusError::Config(format!("Failed to parse launch file: {}", e)))?;
+
+    // Conditional includes, resolved with the arguments of this file
+    if let Some(mut includes) = includes {
+        substitute_value(&mut includes, &args)?;
+        filter_conditional(&mut includes, &args)?;
+        for include in includes.as_sequence().cloned().unwrap_or_default() {
+            let (file, include_args) = match &include {
+                serde_yaml::Value::String(file) => (file.clone(), serde_yaml::Mapping::new()),
+                serde_yaml::Value::Mapping(m) => (
+                    m.get("file")
+                        .and_then(|f| f.as_str())
+                        .ok_or_else(|| {
+                            HorusError::Config("Launch include needs a 'file'".to_string())
+                        })?
+                        .to_string(),
+                    m.get("args")
+                        .and_then(|a| a.as_mapping())
+                        .cloned()
+                        .unwrap_or_default(),
+                ),
+                _ => {
+                    return Err(HorusError::Config(
+                        "Launch include must be a file name or mapping".to_string(),
+                    ))
+                }
+            };
+
+            let mut child_args = args.clone();
+            for (name, value) in &include_args {
+                if let (Some(name), Some(value)) = (scalar_to_string(name), scalar_to_string(value))
+                {
+                    child_args.insert(name, value);
+                }
+            }
+
+            let path = base_dir.join(&file);
+            let content = std::fs::read_to_string(&path).map_err(|e| {
+                HorusError::Config(format!("Failed to read include {}: {}", path.display(), e))
+            })?;
+            let child_doc: serde_yaml::Value = serde_yaml::from_str(&content).map_err(|e| {
+                HorusError::Config(format!("Failed to parse include {}: {}", path.display(), e))
+            })?;
+            let child = resolve_launch(child_doc, self::base_dir(&path), &child_args, depth + 1)?;
+
+            // The including file wins on conflicts
+            config.nodes.extend(child.nodes);
+            for (k, v) in child.env {
+                config.env.entry(k).or_insert(v);
+            }
+            for (k, v) in child.hosts {
+                config.hosts.entry(k).or_insert(v);
+            }
+            for (k, v) in child.args {
+                args.entry(k).or_insert(v);
+            }
+        }
+    }
+
+    config.args = args;
+    Ok(config)
+}
+
+fn scalar_to_string(value: &serde_yaml::Value) -> Option<String> {
+    match value {
+        serde_yaml::Value::String(s) => Some(s.clone()),
+        serde_yaml::Value::Bool(b) => Some(b.to_string()),
+        serde_yaml::Value::Number(n) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// Expand `${arg:NAME}`, `${env:NAME}`, `${NAME}` and `${NAME:-default}` in a string
+///
+/// `$${` produces a literal `${`.
+fn substitute(input: &str, args: &BTreeMap<String, String>) -> HorusResult<String> {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+
+        if rest.starts_with("$${") {
+            out.push_str("${");
+            rest = &rest[3..];
+            continue;
+        }
+        if !rest.starts_with("${") {
+            out.push('$');
+            rest = &rest[1..];
+            continue;
+        }
+
+        let end = rest.find('}').ok_or_else(|| {
+            HorusError::Config(format!("Unterminated substitution in '{}'", input))
+        })?;
+        let expr = &rest[2..end];
+        rest = &rest[end + 1..];
+
+        if let Some(name) = expr.strip_prefix("arg:") {
+            let name = name.trim();
+            let value = args.get(name).ok_or_else(|| {
+                HorusError::Config(format!(
+                    "Launch argument '{}' is not set (declare a default under 'args' or pass --arg {}=VALUE)",
+                    name, name
+                ))
+            })?;
+            out.push_str(value);
+        } else {
+            let expr = expr.strip_prefix("env:").unwrap_or(expr);
+            let (name, default) = match expr.split_once(":-") {
+                Some((name, default)) => (name.trim(), Some(default)),
+                None => (expr.trim(), None),
+            };
+            match (std::env::var(name), default) {
+                (Ok(value), _) => out.push_str(&value),
+                (Err(_), Some(default)) => out.push_str(default),
+                (Err(_), None) => {
+                    return Err(HorusError::Config(format!(
+                        "Environment variable '{}' is not set (use ${{{}:-default}})",
+                        name, name
+                    )))
+                }
+            }
+        }
+    }
+
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// Apply [`substitute`] to every string in a YAML value
+///
+/// A string that is exactly one substitution takes the type of its value, so
+/// `rate_hz: ${arg:rate}` stays a number and `if: ${arg:sim}` a boolean.
+fn substitute_value(
+    value: &mut serde_yaml::Value,
+    args: &BTreeMap<String, String>,
+) -> HorusResult<()> {
+    match value {
+        serde_yaml::Value::String(s) if s.contains('$') => {
+            let whole = s.starts_with("${") && s.ends_with('}') && s.matches("${").count() == 1;
+            let expanded = substitute(s, args)?;
+            *value = match serde_yaml::from_str::<serde_yaml::Value>(&expanded) {
+                Ok(typed @ (serde_yaml::Value::Bool(_) | serde_yaml::Value::Number(_)))
+                    if whole =>
+                {
+                    typed
+                }
+                _ => serde_yaml::Value::String(expanded),
+            };
+        }
+        serde_yaml::Value::Sequence(items) => {
+            for item in items {
+                substitute_value(item, args)?;
+            }
+        }
+        serde_yaml::Value::Mapping(map) => {
+            for (_, v) in map.iter_mut() {
+                substitute_value(v, args)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Drop sequence entries whose `if`/`unless` condition does not hold
+fn filter_conditional(
+    items: &mut serde_yaml::Value,
+    args: &BTreeMap<String, String>,
+) -> HorusResult<()> {
+    let Some(seq) = items.as_sequence_mut() else {
+        return Ok(());
+    };
+
+    let mut kept = Vec::with_capacity(seq.len());
+    for mut item in seq.drain(..) {
+        let mut include = true;
+        if let Some(map) = item.as_mapping_mut() {
+            if let Some(cond) = map.remove("if") {
+                include &= evaluate_condition(&cond, args)?;
+            }
+            if let Some(cond) = map.remove("unless") {
+                include &= !evaluate_condition(&cond, args)?;
+            }
+        }
+        if include {
+            kept.push(item);
+        }
+    }
+    *seq = kept;
+    Ok(())
+}
+
+/// Evaluate an `if`/`unless` condition
+///
+/// Accepts booleans, an argument name (`sim`), a negation (`!sim`) and
+/// comparisons (`mode == sim`, `mode != hardware`).
+fn evaluate_condition(
+    cond: &serde_yaml::Value,
+    args: &BTreeMap<String, String>,
+) -> HorusResult<bool> {
+    let expr = match cond {
+        serde_yaml::Value::Bool(b) => return Ok(*b),
+        other => scalar_to_string(other)
+            .ok_or_else(|| HorusError::Config("Invalid launch condition".to_string()))?,
+    };
+    let expr = expr.trim();
 
+    let operand = |s: &str| {
+        let s = s.trim();
+        args.get(s).cloned().unwrap_or_else(|| s.to_string())
+    };
+    if let Some((lhs, rhs)) = expr.split_once("!=") {
+        return Ok(operand(lhs) != operand(rhs));
+    }
+    if let Some((lhs, rhs)) = expr.split_once("==") {
+        return Ok(operand(lhs) == operand(rhs));
+    }
+    if let Some(inner) = expr.strip_prefix('!') {
+        return Ok(!evaluate_condition(
+            &serde_yaml::Value::String(inner.to_string()),
+            args,
+        )?);
+    }
+
+    let value = operand(expr);
+    match value.to_lowercase().as_str() {
+        "true" | "yes" | "on" | "1" => Ok(true),
+        "false" | "no" | "off" | "0" | "" => Ok(false),
+        _ => Err(HorusError::Config(format!(
+            "Cannot evaluate launch condition '{}' (expected a boolean argument or comparison)",
+            expr
+        ))),
+    }
+}
+
+fn launch_config(
+    config: LaunchConfig,
+    file: &Path,
+    dry_run: bool,
+    namespace: Option<String>,
+) -> HorusResult<()> {
     if config.nodes.is_empty() {
         println!("{}", "No nodes defined in launch file.".yellow());
         return Ok(());
     }
 
+    validate_wait_conditions(&config.nodes)?;
+    validate_restart_policies(&config.nodes)?;
+    validate_hosts(&config)?;
+    let bridged = bridged_topics(&config.nodes);
+
     let session_name = config.session.clone().unwrap_or_else(|| {
         file.file_stem()
             .map(|s| s.to_string_lossy().to_string())
@@ -121,7 +662,18 @@ pub fn run_launch(file: &Path, dry_run: bool, namespace: Option<String>) -> Horu
     if let Some(ref ns) = global_namespace {
         println!("  {} {}", "Namespace:".cyan(), ns);
     }
+    if !config.args.is_empty() {
+        let args: Vec<String> = config
+            .args
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect();
+        println!("  {} {}", "Arguments:".cyan(), args.join(", "));
+    }
     println!("  {} {}", "Nodes:".cyan(), config.nodes.len());
+    if !bridged.is_empty() {
+        println!("  {} {}", "Bridged topics:".cyan(), bridged.join(", "));
+    }
     println!();
 
     if dry_run {
@@ -141,14 +693,55 @@ pub fn run_launch(file: &Path, dry_run: bool, namespace: Option<String>) -> Horu
     // Sort nodes by dependencies (topological sort)
     let ordered_nodes = sort_by_dependencies(&config.nodes)?;
 
+    // Connect to the agents of other hosts, and route shared topics through a router
+    let mut remote = RemoteHosts::connect(&config)?;
+    let mut router = None;
+    let mut local_env = config.env.clone();
+    let mut host_env: HashMap<String, HashMap<String, String>> = HashMap::new();
+    if !bridged.is_empty() {
+        let router_addr = match config.router {
+            Some(ref addr) => addr.parse::<SocketAddr>().ok(),
+            None => {
+                router = Some(start_router(DEFAULT_ROUTER_PORT)?);
+                None
+            }
+        };
+        let local_router = router_addr.unwrap_or(SocketAddr::new(
+            IpAddr::V4(Ipv4Addr::LOCALHOST),
+            DEFAULT_ROUTER_PORT,
+        ));
+        local_env.extend(bridge_env(local_router, &bridged));
+        for (host, peer) in &remote.peers {
+            let host_router = router_addr.unwrap_or(SocketAddr::new(*peer, DEFAULT_ROUTER_PORT));
+            let mut env = config.env.clone();
+            env.extend(bridge_env(host_router, &bridged));
+            host_env.insert(host.clone(), env);
+        }
+    }
+
     // Launch nodes
     println!("{}", "Launching nodes...".cyan().bold());
     println!();
 
     let mut processes: Vec<(String, Child)> = Vec::new();
+    let mut local_nodes: HashMap<String, LaunchNode> = HashMap::new();
     let mut started_nodes: Vec<String> = Vec::new();
+    let mut skipped_nodes: Vec<String> = Vec::new();
+    let launch_start = SystemTime::now();
 
     for node in &ordered_nodes {
+        // Nodes depending on a skipped node are skipped as well
+        if let Some(dep) = launch_dependencies(node).find(|d| skipped_nodes.contains(d)) {
+            println!(
+                "  {} Skipping {} (dependency '{}' was skipped)",
+                "".yellow(),
+                node.name,
+                dep
+            );
+            skipped_nodes.push(node.name.clone());
+            continue;
+        }
+
         // Check dependencies
         for dep in &node.depends_on {
             if !started_nodes.contains(dep) {
@@ -159,6 +752,49 @@ pub fn run_launch(file: &Path, dry_run: bool, namespace: Option<String>) -> Horu
             }
         }
 
+        // Wait for readiness conditions
+        if !node.wait_for.is_empty() {
+            println!(
+                "  {} Waiting for {} before {} (timeout {:.1}s)",
+                "".dimmed(),
+                node.wait_for
+                    .iter()
+                    .map(|w| w.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                node.name,
+                node.wait_timeout
+            );
+
+            if let WaitOutcome::Failed(reason) =
+                wait_for_conditions(node, &mut processes, &mut remote, launch_start)
+            {
+                match node.on_wait_timeout.as_str() {
+                    "skip" => {
+                        println!("  {} Skipping {}: {}", "".yellow(), node.name, reason);
+                        skipped_nodes.push(node.name.clone());
+                        continue;
+                    }
+                    "continue" => {
+                        println!(
+                            "  {} {}, launching {} anyway",
+                            "".yellow(),
+                            reason,
+                            node.name
+                        );
+                    }
+                    _ => {
+                        eprintln!("  {} {}", "".red(), reason);
+                        stop_processes(processes, &mut remote, router);
+                        return Err(HorusError::Timeout(format!(
+                            "Node '{}' not started: {}",
+                            node.name, reason
+                        )));
+                    }
+                }
+            }
+        }
+
         // Apply start delay if specified
         if let Some(delay) = node.start_delay {
             if delay > 0.0 {
@@ -174,12 +810,36 @@ pub fn run_launch(file: &Path, dry_run: bool, namespace: Option<String>) -> Horu
             (None, None) => node.name.clone(),
         };
 
+        if let Some(ref host) = node.host {
+            print!(
+                "  {} Launching {} on {}...",
+                "".cyan(),
+                full_name.white().bold(),
+                host
+            );
+            let env = host_env.get(host).unwrap_or(&config.env);
+            match remote.start(node, env, &global_namespace) {
+                Ok(pid) => {
+                    println!(" {} (PID: {} on {})", "started".green(), pid, host);
+                    started_nodes.push(node.name.clone());
+                }
+                Err(e) => {
+                    println!(" {}", "failed".red());
+                    eprintln!("    Error: {}", e);
+                    stop_processes(processes, &mut remote, router);
+                    return Err(e);
+                }
+            }
+            continue;
+        }
+
         print!("  {} Launching {}...", "".cyan(), full_name.white().bold());
 
-        match launch_node(node, &config.env, &global_namespace) {
+        match launch_node(node, &local_env, &global_namespace) {
             Ok(child) => {
                 println!(" {} (PID: {})", "started".green(), child.id());
                 processes.push((node.name.clone(), child));
+                local_nodes.insert(node.name.clone(), node.clone());
                 started_nodes.push(node.name.clone());
             }
             Err(e) => {
@@ -187,16 +847,7 @@ pub fn run_launch(file: &Path, dry_run: bool, namespace: Option<String>) -> Horu
                 eprintln!("    Error: {}", e);
 
                 // Clean up already started processes
-                println!();
-                println!("{}", "Cleaning up started nodes...".yellow());
-                for (name, mut proc) in processes {
-                    print!("  {} Stopping {}...", "".yellow(), name);
-                    if proc.kill().is_ok() {
-                        println!(" {}", "stopped".green());
-                    } else {
-                        println!(" {}", "already stopped".dimmed());
-                    }
-                }
+                stop_processes(processes, &mut remote, router);
 
                 return Err(e);
             }
@@ -204,11 +855,21 @@ pub fn run_launch(file: &Path, dry_run: bool, namespace: Option<String>) -> Horu
     }
 
     println!();
-    println!(
-        "{} All {} nodes launched successfully!",
-        "".green(),
-        processes.len()
-    );
+    if skipped_nodes.is_empty() {
+        println!(
+            "{} All {} nodes launched successfully!",
+            "".green(),
+            started_nodes.len()
+        );
+    } else {
+        println!(
+            "{} {} nodes launched, {} skipped: {}",
+            "".yellow(),
+            started_nodes.len(),
+            skipped_nodes.len(),
+            skipped_nodes.join(", ")
+        );
+    }
     println!();
     println!(
         "  {} Use 'horus node list' to see running nodes",
@@ -233,6 +894,9 @@ pub fn run_launch(file: &Path, dry_run: bool, namespace: Option<String>) -> Horu
     .ok();
 
     // Wait for shutdown signal
+    let mut last_remote_poll = Instant::now();
+    let mut restart_counts: HashMap<String, u32> = HashMap::new();
+    let mut pending_restarts: Vec<(String, Instant)> = Vec::new();
     while running.load(std::sync::atomic::Ordering::SeqCst) {
         // Check if any process has exited
         let mut stopped_indices: Vec<usize> = Vec::new();
@@ -250,6 +914,36 @@ pub fn run_launch(file: &Path, dry_run: bool, namespace: Option<String>) -> Horu
                         println!("{} Node '{}' completed", "".dimmed(), name);
                     }
                     stopped_indices.push(i);
+
+                    // Schedule a respawn according to the node's restart policy
+                    if let Some(node) = local_nodes.get(name.as_str()) {
+                        let count = restart_counts.entry(name.clone()).or_insert(0);
+                        if should_restart(node, status.success(), *count) {
+                            *count += 1;
+                            println!(
+                                "  {} Restarting {} in {:.1}s (restart {}{})",
+                                "".cyan(),
+                                name,
+                                node.restart_delay,
+                                count,
+                                node.max_restarts
+                                    .map(|m| format!("/{}", m))
+                                    .unwrap_or_default()
+                            );
+                            pending_restarts.push((
+                                name.clone(),
+                                Instant::now() + Duration::from_secs_f64(node.restart_delay),
+                            ));
+                        } else if node.restart != "never" {
+                            println!(
+                                "  {} Not restarting {} (restart: {}, {} restarts)",
+                                "".dimmed(),
+                                name,
+                                node.restart,
+                                count
+                            );
+                        }
+                    }
                 }
                 Ok(None) => {} // Still running
                 Err(e) => {
@@ -263,7 +957,48 @@ pub fn run_launch(file: &Path, dry_run: bool, namespace: Option<String>) -> Horu
             processes.remove(i);
         }
 
-        if processes.is_empty() {
+        // Respawn nodes whose restart delay has elapsed
+        let now = Instant::now();
+        let (due, waiting): (Vec<_>, Vec<_>) =
+            pending_restarts.into_iter().partition(|(_, at)| *at <= now);
+        pending_restarts = waiting;
+        for (name, _) in due {
+            let Some(node) = local_nodes.get(&name) else {
+                continue;
+            };
+            match launch_node(node, &local_env, &global_namespace) {
+                Ok(child) => {
+                    println!(
+                        "{} Node '{}' restarted (PID: {})",
+                        "".green(),
+                        name,
+                        child.id()
+                    );
+                    processes.push((name, child));
+                }
+                Err(e) => eprintln!("{} Failed to restart '{}': {}", "".red(), name, e),
+            }
+        }
+
+        // Remote nodes are polled through their agents once per second
+        if last_remote_poll.elapsed() >= Duration::from_secs(1) {
+            last_remote_poll = Instant::now();
+            for (name, host, status) in remote.poll_exited() {
+                match status.exit_code {
+                    Some(0) => println!("{} Node '{}' on {} completed", "".dimmed(), name, host),
+                    Some(code) => println!(
+                        "{} Node '{}' on {} exited with code {}",
+                        "".yellow(),
+                        name,
+                        host,
+                        code
+                    ),
+                    None => println!("{} Node '{}' on {} was killed", "".yellow(), name, host),
+                }
+            }
+        }
+
+        if processes.is_empty() && remote.nodes.is_empty() && pending_restarts.is_empty() {
             println!("{}", "All nodes have stopped.".dimmed());
             break;
         }
@@ -296,6 +1031,11 @@ pub fn run_launch(file: &Path, dry_run: bool, namespace: Option<String>) -> Horu
         let _ = proc.wait();
         println!(" {}", "stopped".green());
     }
+    remote.stop_all();
+    if let Some(mut router) = router {
+        let _ = router.kill();
+        let _ = router.wait();
+    }
 
     println!();
     println!("{} All nodes stopped.", "".green());
@@ -314,12 +1054,22 @@ fn print_launch_plan(config: &LaunchConfig, global_namespace: &Option<String>) {
 
         println!("  {}. {}", i + 1, full_name.white().bold());
 
+        if let Some(ref host) = node.host {
+            let address = config.hosts.get(host).map(String::as_str).unwrap_or("?");
+            println!("     {} {} ({})", "Host:".dimmed(), host, address);
+        }
         if let Some(ref pkg) = node.package {
             println!("     {} {}", "Package:".dimmed(), pkg);
         }
         if let Some(ref cmd) = node.command {
             println!("     {} {}", "Command:".dimmed(), cmd);
         }
+        if let Some(ref file) = node.file {
+            println!("     {} {}", "File:".dimmed(), file);
+        }
+        if !node.args.is_empty() {
+            println!("     {} {}", "Args:".dimmed(), node.args.join(" "));
+        }
         if let Some(priority) = node.priority {
             println!("     {} {}", "Priority:".dimmed(), priority);
         }
@@ -339,13 +1089,412 @@ fn print_launch_plan(config: &LaunchConfig, global_namespace: &Option<String>) {
                 node.depends_on.join(", ")
             );
         }
+        if !node.wait_for.is_empty() {
+            let conditions: Vec<String> = node.wait_for.iter().map(|w| w.to_string()).collect();
+            println!(
+                "     {} {} (timeout {:.1}s, on timeout: {})",
+                "Waits for:".dimmed(),
+                conditions.join(", "),
+                node.wait_timeout,
+                node.on_wait_timeout
+            );
+        }
         if let Some(delay) = node.start_delay {
             println!("     {} {:.1}s", "Delay:".dimmed(), delay);
         }
+        if node.restart != "never" {
+            println!(
+                "     {} {} (max {}, delay {:.1}s)",
+                "Restart:".dimmed(),
+                node.restart,
+                node.max_restarts
+                    .map(|m| m.to_string())
+                    .unwrap_or_else(|| "unlimited".to_string()),
+                node.restart_delay
+            );
+        }
         println!();
     }
 }
 
+/// Stop already started processes after a failed launch
+fn stop_processes(
+    processes: Vec<(String, Child)>,
+    remote: &mut RemoteHosts,
+    router: Option<Child>,
+) {
+    println!();
+    println!("{}", "Cleaning up started nodes...".yellow());
+    for (name, mut proc) in processes {
+        print!("  {} Stopping {}...", "".yellow(), name);
+        if proc.kill().is_ok() {
+            println!(" {}", "stopped".green());
+        } else {
+            println!(" {}", "already stopped".dimmed());
+        }
+    }
+    remote.stop_all();
+    if let Some(mut router) = router {
+        let _ = router.kill();
+        let _ = router.wait();
+    }
+}
+
+/// Check restart policies and that each node declares exactly one program
+fn validate_restart_policies(nodes: &[LaunchNode]) -> HorusResult<()> {
+    for node in nodes {
+        if !matches!(node.restart.as_str(), "never" | "always" | "on-failure") {
+            return Err(HorusError::Config(format!(
+                "Node '{}': invalid restart policy '{}' (expected never, always or on-failure)",
+                node.name, node.restart
+            )));
+        }
+        if !node.restart_delay.is_finite() || node.restart_delay < 0.0 {
+            return Err(HorusError::Config(format!(
+                "Node '{}': restart_delay must be a non-negative number of seconds",
+                node.name
+            )));
+        }
+        let programs = [
+            node.command.is_some(),
+            node.package.is_some(),
+            node.file.is_some(),
+        ]
+        .iter()
+        .filter(|set| **set)
+        .count();
+        if programs > 1 {
+            return Err(HorusError::Config(format!(
+                "Node '{}' must specify only one of 'command', 'package' or 'file'",
+                node.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Whether an exited node should be restarted
+fn should_restart(node: &LaunchNode, success: bool, restarts: u32) -> bool {
+    let policy_allows = match node.restart.as_str() {
+        "always" => true,
+        "on-failure" => !success,
+        _ => false,
+    };
+    policy_allows && node.max_restarts.is_none_or(|max| restarts < max)
+}
+
+/// Check that nodes run on declared hosts and the router address is valid
+fn validate_hosts(config: &LaunchConfig) -> HorusResult<()> {
+    for node in &config.nodes {
+        if let Some(ref host) = node.host {
+            if !config.hosts.contains_key(host) {
+                return Err(HorusError::Config(format!(
+                    "Node '{}': unknown host '{}' (declare it under 'hosts')",
+                    node.name, host
+                )));
+            }
+        }
+    }
+    if let Some(ref router) = config.router {
+        router.parse::<SocketAddr>().map_err(|_| {
+            HorusError::Config(format!(
+                "Invalid router address '{}' (expected ip:port)",
+                router
+            ))
+        })?;
+    }
+    Ok(())
+}
+
+/// Topics used by nodes on more than one host
+fn bridged_topics(nodes: &[LaunchNode]) -> Vec<String> {
+    let mut hosts: BTreeMap<&str, HashSet<Option<&str>>> = BTreeMap::new();
+    for node in nodes {
+        for topic in &node.topics {
+            hosts
+                .entry(topic.as_str())
+                .or_default()
+                .insert(node.host.as_deref());
+        }
+    }
+    hosts
+        .into_iter()
+        .filter(|(_, hosts)| hosts.len() > 1)
+        .map(|(topic, _)| topic.to_string())
+        .collect()
+}
+
+/// Environment routing the bridged topics of a node through `router`
+fn bridge_env(router: SocketAddr, topics: &[String]) -> HashMap<String, String> {
+    HashMap::from([
+        (BRIDGE_ROUTER_ENV.to_string(), router.to_string()),
+        (BRIDGE_TOPICS_ENV.to_string(), topics.join(",")),
+    ])
+}
+
+/// Start `horus_router` on this machine for bridged topics
+fn start_router(port: u16) -> HorusResult<Child> {
+    print!("  {} Starting router on port {}...", "".cyan(), port);
+    let child = Command::new("horus_router")
+        .args(["--port", &port.to_string()])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| {
+            println!(" {}", "failed".red());
+            HorusError::Config(format!(
+                "Bridged topics need a router ({}). Install horus_router or set 'router' in the launch file",
+                e
+            ))
+        })?;
+    println!(" {} (PID: {})", "started".green(), child.id());
+
+    // Give the router time to bind before nodes connect
+    std::thread::sleep(Duration::from_millis(300));
+    Ok(child)
+}
+
+/// Agents of the hosts used by a launch, and the nodes started through them
+struct RemoteHosts {
+    agents: HashMap<String, AgentClient>,
+    /// This machine's address as seen by each host
+    peers: HashMap<String, IpAddr>,
+    /// Running remote nodes: (node, host)
+    nodes: Vec<(String, String)>,
+}
+
+impl RemoteHosts {
+    fn connect(config: &LaunchConfig) -> HorusResult<Self> {
+        let mut remote = Self {
+            agents: HashMap::new(),
+            peers: HashMap::new(),
+            nodes: Vec::new(),
+        };
+
+        let used: BTreeSet<&String> = config
+            .nodes
+            .iter()
+            .filter_map(|node| node.host.as_ref())
+            .collect();
+        for host in used {
+            let address = &config.hosts[host];
+            print!("  {} Connecting to {} ({})...", "".cyan(), host, address);
+            let connected = AgentClient::connect(address)
+                .and_then(|mut agent| agent.hello().map(|info| (agent, info)));
+            match connected {
+                Ok((agent, info)) => {
+                    println!(" {} ({})", "connected".green(), info.hostname);
+                    remote.peers.insert(host.clone(), info.peer);
+                    remote.agents.insert(host.clone(), agent);
+                }
+                Err(e) => {
+                    println!(" {}", "failed".red());
+                    return Err(e);
+                }
+            }
+        }
+        if !remote.agents.is_empty() {
+            println!();
+        }
+
+        Ok(remote)
+    }
+
+    fn start(
+        &mut self,
+        node: &LaunchNode,
+        env: &HashMap<String, String>,
+        namespace: &Option<String>,
+    ) -> HorusResult<u32> {
+        let host = node.host.clone().unwrap_or_default();
+        let agent = self
+            .agents
+            .get_mut(&host)
+            .ok_or_else(|| HorusError::Config(format!("No agent for host '{}'", host)))?;
+        let pid = agent.start(node, env, namespace)?;
+        self.nodes.push((node.name.clone(), host));
+        Ok(pid)
+    }
+
+    /// Status of a remote node, None for local nodes or unreachable agents
+    fn node_status(&mut self, name: &str) -> Option<RemoteNodeStatus> {
+        let host = self.nodes.iter().find(|(node, _)| node == name)?.1.clone();
+        self.agents
+            .get_mut(&host)?
+            .status()
+            .ok()?
+            .into_iter()
+            .find(|status| status.name == name)
+    }
+
+    /// Remote nodes that exited since the last poll: (node, host, status)
+    fn poll_exited(&mut self) -> Vec<(String, String, RemoteNodeStatus)> {
+        let mut exited = Vec::new();
+        for (host, agent) in &mut self.agents {
+            if !self.nodes.iter().any(|(_, h)| h == host) {
+                continue;
+            }
+            match agent.status() {
+                Ok(statuses) => {
+                    for status in statuses.into_iter().filter(|s| !s.running) {
+                        if let Some(i) = self
+                            .nodes
+                            .iter()
+                            .position(|(node, h)| *node == status.name && h == host)
+                        {
+                            let (name, host) = self.nodes.remove(i);
+                            exited.push((name, host, status));
+                        }
+                    }
+                }
+                Err(e) => eprintln!("{} Cannot poll {}: {}", "".yellow(), host, e),
+            }
+        }
+        exited
+    }
+
+    /// Ask the agents to stop every remote node
+    fn stop_all(&mut self) {
+        for (name, host) in std::mem::take(&mut self.nodes) {
+            print!("  {} Stopping {} on {}...", "".yellow(), name, host);
+            match self.agents.get_mut(&host).map(|agent| agent.stop(&name)) {
+                Some(Ok(())) => println!(" {}", "stopped".green()),
+                Some(Err(e)) => println!(" {} ({})", "failed".red(), e),
+                None => println!(" {}", "no agent".red()),
+            }
+        }
+    }
+}
+
+/// Check `wait_for` entries and timeout policies before launching anything
+fn validate_wait_conditions(nodes: &[LaunchNode]) -> HorusResult<()> {
+    for node in nodes {
+        for condition in &node.wait_for {
+            if condition.node.is_some() == condition.topic.is_some() {
+                return Err(HorusError::Config(format!(
+                    "Node '{}': each wait_for entry needs exactly one of 'node' or 'topic'",
+                    node.name
+                )));
+            }
+        }
+        if !matches!(node.on_wait_timeout.as_str(), "abort" | "skip" | "continue") {
+            return Err(HorusError::Config(format!(
+                "Node '{}': invalid on_wait_timeout '{}' (expected abort, skip or continue)",
+                node.name, node.on_wait_timeout
+            )));
+        }
+        if node.wait_timeout.is_nan() || node.wait_timeout <= 0.0 {
+            return Err(HorusError::Config(format!(
+                "Node '{}': wait_timeout must be positive",
+                node.name
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Names a node must be launched after: `depends_on` plus `wait_for` nodes
+fn launch_dependencies(node: &LaunchNode) -> impl Iterator<Item = &String> {
+    node.depends_on
+        .iter()
+        .chain(node.wait_for.iter().filter_map(|w| w.node.as_ref()))
+}
+
+/// Poll readiness conditions until all hold, the timeout expires, or a
+/// launched node that is waited for exits
+fn wait_for_conditions(
+    node: &LaunchNode,
+    processes: &mut [(String, Child)],
+    remote: &mut RemoteHosts,
+    launch_start: SystemTime,
+) -> WaitOutcome {
+    let deadline = Instant::now() + Duration::from_secs_f64(node.wait_timeout);
+
+    loop {
+        let pending: Vec<&WaitFor> = node
+            .wait_for
+            .iter()
+            .filter(|w| !condition_met(w, launch_start, remote))
+            .collect();
+
+        if pending.is_empty() {
+            return WaitOutcome::Ready;
+        }
+
+        for condition in &pending {
+            let Some(ref dep) = condition.node else {
+                continue;
+            };
+            if let Some((_, proc)) = processes.iter_mut().find(|(name, _)| name == dep) {
+                if let Ok(Some(status)) = proc.try_wait() {
+                    return WaitOutcome::Failed(format!(
+                        "node '{}' exited ({}) before becoming ready",
+                        dep, status
+                    ));
+                }
+            }
+            if let Some(status) = remote.node_status(dep).filter(|s| !s.running) {
+                return WaitOutcome::Failed(format!(
+                    "node '{}' exited ({}) before becoming ready",
+                    dep,
+                    status
+                        .exit_code
+                        .map(|code| format!("exit code {}", code))
+                        .unwrap_or_else(|| "killed".to_string())
+                ));
+            }
+        }
+
+        if Instant::now() >= deadline {
+            let pending: Vec<String> = pending.iter().map(|w| w.to_string()).collect();
+            return WaitOutcome::Failed(format!(
+                "timed out after {:.1}s waiting for {}",
+                node.wait_timeout,
+                pending.join(", ")
+            ));
+        }
+
+        std::thread::sleep(Duration::from_millis(100));
+    }
+}
+
+/// Check a single readiness condition
+///
+/// Only state written after `launch_start` counts, so heartbeats and topic
+/// files left over from a previous run don't satisfy the condition. Remote
+/// nodes are checked through their agent; topics only on this machine.
+fn condition_met(condition: &WaitFor, launch_start: SystemTime, remote: &mut RemoteHosts) -> bool {
+    if let Some(ref node) = condition.node {
+        if let Some(status) = remote.node_status(node) {
+            return status.ready;
+        }
+        return node_ready(node, launch_start);
+    }
+
+    if let Some(ref topic) = condition.topic {
+        let dir = shm_topics_dir();
+        return [dir.join(format!("horus_{}", topic)), dir.join(topic)]
+            .iter()
+            .filter_map(|path| std::fs::metadata(path).ok())
+            .filter_map(|meta| meta.modified().ok())
+            .any(|modified| modified >= launch_start);
+    }
+
+    false
+}
+
+/// Whether a node on this machine reports `Running` in a heartbeat written
+/// after `since`
+pub(crate) fn node_ready(node: &str, since: SystemTime) -> bool {
+    let since_secs = since
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    NodeHeartbeat::read_from_file(node)
+        .map(|hb| hb.state == NodeState::Running && hb.heartbeat_timestamp >= since_secs)
+        .unwrap_or(false)
+}
+
 /// Sort nodes by dependencies (topological sort)
 fn sort_by_dependencies(nodes: &[LaunchNode]) -> HorusResult<Vec<LaunchNode>> {
     let mut result = Vec::new();
@@ -373,7 +1522,7 @@ fn sort_by_dependencies(nodes: &[LaunchNode]) -> HorusResult<Vec<LaunchNode>> {
 
         temp_visited.insert(node.name.clone());
 
-        for dep in &node.depends_on {
+        for dep in launch_dependencies(node) {
             if let Some(dep_node) = node_map.get(dep.as_str()) {
                 visit(dep_node, node_map, visited, temp_visited, result)?;
             }
@@ -401,7 +1550,7 @@ fn sort_by_dependencies(nodes: &[LaunchNode]) -> HorusResult<Vec<LaunchNode>> {
 }
 
 /// Launch a single node
-fn launch_node(
+pub(crate) fn launch_node(
     node: &LaunchNode,
     global_env: &HashMap<String, String>,
     namespace: &Option<String>,
@@ -420,6 +1569,18 @@ fn launch_node(
             c.args(&parts[1..]);
         }
         c
+    } else if let Some(ref file) = node.file {
+        // Build and run a project source file
+        let horus_bin = std::env::current_exe().unwrap_or_else(|_| "horus".into());
+        let mut c = Command::new(horus_bin);
+        c.args(["run", file.as_str()]);
+        if node.release {
+            c.arg("--release");
+        }
+        if !node.args.is_empty() {
+            c.arg("--");
+        }
+        c
     } else if let Some(ref package) = node.package {
         // Run as a HORUS package
         let horus_bin = std::env::current_exe().unwrap_or_else(|_| "horus".into());
@@ -428,7 +1589,7 @@ fn launch_node(
         c
     } else {
         return Err(HorusError::Config(format!(
-            "Node '{}' must specify either 'command' or 'package'",
+            "Node '{}' must specify 'command', 'package' or 'file'",
             node.name
         )));
     };
@@ -479,18 +1640,9 @@ fn launch_node(
 }
 
 /// List nodes in a launch file
-pub fn list_launch_nodes(file: &Path) -> HorusResult<()> {
-    if !file.exists() {
-        return Err(HorusError::Config(format!(
-            "Launch file not found: {}",
-            file.display()
-        )));
-    }
-
-    let content = std::fs::read_to_string(file).map_err(HorusError::Io)?;
-
-    let config: LaunchConfig = serde_yaml::from_str(&content)
-        .map_err(|e| HorusError::Config(format!("Failed to parse launch file: {}", e)))?;
+pub fn list_launch_nodes(file: &Path, launch_args: &[String]) -> HorusResult<()> {
+    let overrides = parse_launch_args(launch_args)?;
+    let config = load_launch_config(file, &overrides)?;
 
     println!("{}", "Launch File Contents".green().bold());
     println!();
@@ -539,3 +1691,327 @@ pub fn list_launch_nodes(file: &Path) -> HorusResult<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(yaml: &str) -> LaunchConfig {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+
+    #[test]
+    fn test_wait_for_parsing_and_ordering() {
+        let config = parse(
+            r#"
+nodes:
+  - name: consumer
+    command: consumer
+    wait_for:
+      - node: driver
+      - topic: scan
+    wait_timeout: 5
+    on_wait_timeout: skip
+  - name: driver
+    command: driver
+"#,
+        );
+
+        let consumer = &config.nodes[0];
+        assert_eq!(consumer.wait_for.len(), 2);
+        assert_eq!(consumer.wait_for[1].topic.as_deref(), Some("scan"));
+        assert_eq!(consumer.on_wait_timeout, "skip");
+        assert_eq!(config.nodes[1].wait_timeout, 30.0);
+        assert_eq!(config.nodes[1].on_wait_timeout, "abort");
+        assert!(validate_wait_conditions(&config.nodes).is_ok());
+
+        // wait_for nodes are launched first, like depends_on
+        let ordered = sort_by_dependencies(&config.nodes).unwrap();
+        assert_eq!(ordered[0].name, "driver");
+        assert_eq!(ordered[1].name, "consumer");
+    }
+
+    #[test]
+    fn test_wait_for_validation() {
+        let both = parse(
+            r#"
+nodes:
+  - name: a
+    command: a
+    wait_for:
+      - node: b
+        topic: scan
+"#,
+        );
+        assert!(validate_wait_conditions(&both.nodes).is_err());
+
+        let bad_policy = parse(
+            r#"
+nodes:
+  - name: a
+    command: a
+    on_wait_timeout: retry
+"#,
+        );
+        assert!(validate_wait_conditions(&bad_policy.nodes).is_err());
+    }
+
+    #[test]
+    fn test_project_launch_section() {
+        let no_args = BTreeMap::new();
+        let config = parse_project_launch(
+            r#"
+name: rover
+version: 0.1.0
+launch:
+  env:
+    RUST_LOG: info
+  processes:
+    - name: lidar
+      file: src/lidar.rs
+      args: [--port, /dev/ttyUSB0]
+      restart: on-failure
+      max_restarts: 3
+    - name: planner
+      file: planner.py
+      depends_on: [lidar]
+      env:
+        PLANNER_MODE: fast
+"#,
+            Path::new("."),
+            &no_args,
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(config.session.as_deref(), Some("rover"));
+        assert_eq!(config.env["RUST_LOG"], "info");
+        assert_eq!(config.nodes.len(), 2);
+        assert_eq!(config.nodes[0].file.as_deref(), Some("src/lidar.rs"));
+        assert_eq!(config.nodes[0].restart_delay, 1.0);
+        assert!(validate_restart_policies(&config.nodes).is_ok());
+
+        // Bare `processes:` list
+        let bare = parse_project_launch(
+            r#"
+name: rover
+processes:
+  - name: driver
+    command: ./driver
+"#,
+            Path::new("."),
+            &no_args,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(bare.nodes[0].command.as_deref(), Some("./driver"));
+
+        // Projects without a launch group run as before
+        assert!(
+            parse_project_launch("name: rover\n", Path::new("."), &no_args)
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    fn resolve(yaml: &str, overrides: &[(&str, &str)]) -> HorusResult<LaunchConfig> {
+        let overrides = overrides
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        resolve_launch(
+            serde_yaml::from_str(yaml).unwrap(),
+            Path::new("."),
+            &overrides,
+            0,
+        )
+    }
+
+    const SIM_OR_HARDWARE: &str = r#"
+args:
+  sim: false
+  robot_name: { default: rover, description: Robot name }
+  rate: 50
+nodes:
+  - name: ${arg:robot_name}_driver
+    command: driver --port ${HORUS_TEST_UNSET_PORT:-/dev/ttyUSB0}
+    rate_hz: ${arg:rate}
+    unless: sim
+  - name: simulator
+    command: sim3d --robot ${arg:robot_name}
+    if: ${arg:sim}
+  - name: planner
+    command: planner
+    if: robot_name != drone
+"#;
+
+    #[test]
+    fn test_launch_args_and_conditionals() {
+        let hardware = resolve(SIM_OR_HARDWARE, &[]).unwrap();
+        let names: Vec<&str> = hardware.nodes.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, vec!["rover_driver", "planner"]);
+        assert_eq!(
+            hardware.nodes[0].command.as_deref(),
+            Some("driver --port /dev/ttyUSB0")
+        );
+        assert_eq!(hardware.nodes[0].rate_hz, Some(50));
+        assert_eq!(hardware.args["robot_name"], "rover");
+
+        let sim = resolve(SIM_OR_HARDWARE, &[("sim", "true"), ("robot_name", "drone")]).unwrap();
+        let names: Vec<&str> = sim.nodes.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, vec!["simulator"]);
+        assert_eq!(sim.nodes[0].command.as_deref(), Some("sim3d --robot drone"));
+    }
+
+    #[test]
+    fn test_substitution_errors_and_escapes() {
+        let args: BTreeMap<String, String> = [("a".to_string(), "1".to_string())].into();
+        assert_eq!(substitute("x${arg:a}y", &args).unwrap(), "x1y");
+        assert_eq!(
+            substitute("$${arg:a} $HOME", &args).unwrap(),
+            "${arg:a} $HOME"
+        );
+        assert!(substitute("${arg:missing}", &args).is_err());
+        assert!(substitute("${HORUS_TEST_UNSET_VAR}", &args).is_err());
+        assert!(substitute("${arg:a", &args).is_err());
+
+        let value = serde_yaml::Value::String("maybe".to_string());
+        assert!(evaluate_condition(&value, &args).is_err());
+        let negated = serde_yaml::Value::String("!a".to_string());
+        assert!(!evaluate_condition(&negated, &args).unwrap());
+
+        assert!(parse_launch_args(&["sim=true".to_string()]).is_ok());
+        assert!(parse_launch_args(&["sim".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_conditional_include() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("sim.yaml"),
+            r#"
+args:
+  world: empty
+nodes:
+  - name: gazebo_${arg:world}
+    command: sim3d --world ${arg:world}
+"#,
+        )
+        .unwrap();
+        let main = dir.path().join("robot.yaml");
+        std::fs::write(
+            &main,
+            r#"
+args:
+  sim: false
+include:
+  - file: sim.yaml
+    if: sim
+    args:
+      world: warehouse
+nodes:
+  - name: controller
+    command: controller
+"#,
+        )
+        .unwrap();
+
+        let hardware = load_launch_config(&main, &BTreeMap::new()).unwrap();
+        assert_eq!(hardware.nodes.len(), 1);
+
+        let overrides = parse_launch_args(&["sim=true".to_string()]).unwrap();
+        let sim = load_launch_config(&main, &overrides).unwrap();
+        let names: Vec<&str> = sim.nodes.iter().map(|n| n.name.as_str()).collect();
+        assert_eq!(names, vec!["controller", "gazebo_warehouse"]);
+    }
+
+    #[test]
+    fn test_restart_policy() {
+        let config = parse(
+            r#"
+nodes:
+  - name: always
+    command: a
+    restart: always
+    max_restarts: 2
+  - name: failure
+    command: b
+    restart: on-failure
+  - name: once
+    command: c
+"#,
+        );
+        let (always, failure, once) = (&config.nodes[0], &config.nodes[1], &config.nodes[2]);
+
+        assert!(should_restart(always, true, 0));
+        assert!(should_restart(always, false, 1));
+        assert!(!should_restart(always, false, 2));
+        assert!(should_restart(failure, false, 10));
+        assert!(!should_restart(failure, true, 0));
+        assert!(!should_restart(once, false, 0));
+
+        let invalid = parse(
+            r#"
+nodes:
+  - name: a
+    command: a
+    restart: sometimes
+"#,
+        );
+        assert!(validate_restart_policies(&invalid.nodes).is_err());
+
+        let two_programs = parse(
+            r#"
+nodes:
+  - name: a
+    command: a
+    file: a.py
+"#,
+        );
+        assert!(validate_restart_policies(&two_programs.nodes).is_err());
+    }
+
+    #[test]
+    fn test_multi_host_bridging() {
+        let config = parse(
+            r#"
+hosts:
+  robot: 192.168.1.20
+nodes:
+  - name: lidar
+    command: lidar
+    host: robot
+    topics: [scan, lidar.diagnostics]
+  - name: base
+    command: base
+    host: robot
+    topics: [cmd_vel, odom]
+  - name: mapper
+    command: mapper
+    topics: [scan, odom, map]
+  - name: viewer
+    command: viewer
+    topics: [map]
+"#,
+        );
+        assert!(validate_hosts(&config).is_ok());
+        assert_eq!(bridged_topics(&config.nodes), vec!["odom", "scan"]);
+
+        let env = bridge_env(
+            "192.168.1.10:7777".parse().unwrap(),
+            &["odom".to_string(), "scan".to_string()],
+        );
+        assert_eq!(env[BRIDGE_ROUTER_ENV], "192.168.1.10:7777");
+        assert_eq!(env[BRIDGE_TOPICS_ENV], "odom,scan");
+
+        let unknown_host = parse(
+            r#"
+nodes:
+  - name: lidar
+    command: lidar
+    host: robot
+"#,
+        );
+        assert!(validate_hosts(&unknown_host).is_err());
+    }
+}
@@ -112,7 +112,7 @@ pub fn view_logs(
 }
 
 /// Parse "since" duration string (e.g., "5m", "1h", "30s")
-fn parse_since(since: Option<&str>) -> HorusResult<Option<SystemTime>> {
+pub(crate) fn parse_since(since: Option<&str>) -> HorusResult<Option<SystemTime>> {
     let since = match since {
         Some(s) => s,
         None => return Ok(None),
@@ -1,5 +1,12 @@
+pub mod agent;
+pub mod audit;
+pub mod blackbox;
 pub mod bridge;
+pub mod build;
+pub mod check;
+pub mod ci;
 pub mod clean;
+pub mod container;
 pub mod deploy;
 pub mod doctor;
 pub mod github_auth;
@@ -9,10 +16,14 @@ pub mod init;
 pub mod launch;
 pub mod log;
 pub mod msg;
+pub mod msg_python;
 pub mod new;
 pub mod node;
+pub mod node_create;
 pub mod param;
 pub mod pkg;
 pub mod run;
+pub mod service;
+pub mod templates;
 pub mod test;
 pub mod topic;
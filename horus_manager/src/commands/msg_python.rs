@@ -0,0 +1,1332 @@
+//! Python code generation for Rust message types
+//!
+//! Scans Rust sources for `message!` and `zero_copy_message!` definitions and
+//! generates matching Python dataclasses that pack to and unpack from the same
+//! binary layout, so Python nodes can exchange raw message bytes with Rust
+//! nodes without hand-written mirrors.
+//!
+//! Layout rules follow the macros:
+//! - `message!` types are `#[repr(C)]`: fields are naturally aligned and
+//!   padding is emitted as `x` pad bytes.
+//! - `zero_copy_message!` types are `#[repr(C, packed)]`: no padding.
+//!
+//! Only fixed-size fields can be mirrored (primitives, arrays, `FixedStringN`,
+//! `Optional<T>` and other generated message types, including `message!`
+//! enums, which become `IntEnum`s). Messages with heap-backed fields such as
+//! `String` or `Vec<T>` have no stable binary layout and are skipped with a
+//! warning. Layouts assume a little-endian 64-bit target.
+
+use colored::*;
+use horus_core::error::{HorusError, HorusResult};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::visit::Visit;
+use syn::{Attribute, Field, Ident, Token, Variant};
+
+/// Which macro defined a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MessageKind {
+    /// `message!` - `#[repr(C)]`
+    Message,
+    /// `zero_copy_message!` - `#[repr(C, packed)]`
+    ZeroCopy,
+}
+
+impl MessageKind {
+    fn macro_name(self) -> &'static str {
+        match self {
+            MessageKind::Message => "message!",
+            MessageKind::ZeroCopy => "zero_copy_message!",
+        }
+    }
+}
+
+/// A message definition found in Rust source
+#[derive(Debug, Clone)]
+struct RustMessage {
+    name: String,
+    kind: MessageKind,
+    doc: Vec<String>,
+    fields: Vec<RustField>,
+    /// Variants of a `message!` enum
+    variants: Option<Vec<RustVariant>>,
+    source: PathBuf,
+}
+
+#[derive(Debug, Clone)]
+struct RustField {
+    name: String,
+    ty: syn::Type,
+    doc: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct RustVariant {
+    name: String,
+    value: u8,
+    doc: Vec<String>,
+}
+
+/// Macro body: `Name { field: Type, .. }`, `Name = (Type, ..)` or
+/// `enum Name { Variant, .. }`
+struct MacroBody {
+    attrs: Vec<Attribute>,
+    name: Ident,
+    fields: Vec<Field>,
+    variants: Option<Vec<Variant>>,
+    tuple: bool,
+}
+
+impl Parse for MacroBody {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+
+        if input.peek(Token![enum]) {
+            input.parse::<Token![enum]>()?;
+            let name: Ident = input.parse()?;
+            let content;
+            syn::braced!(content in input);
+            let variants = content.parse_terminated(Variant::parse, Token![,])?;
+            return Ok(Self {
+                attrs,
+                name,
+                fields: Vec::new(),
+                variants: Some(variants.into_iter().collect()),
+                tuple: false,
+            });
+        }
+
+        let name: Ident = input.parse()?;
+
+        if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            let content;
+            syn::parenthesized!(content in input);
+            let fields = content.parse_terminated(Field::parse_unnamed, Token![,])?;
+            return Ok(Self {
+                attrs,
+                name,
+                fields: fields.into_iter().collect(),
+                variants: None,
+                tuple: true,
+            });
+        }
+
+        let content;
+        syn::braced!(content in input);
+        let fields: Punctuated<Field, Token![,]> =
+            content.parse_terminated(Field::parse_named, Token![,])?;
+        Ok(Self {
+            attrs,
+            name,
+            fields: fields.into_iter().collect(),
+            variants: None,
+            tuple: false,
+        })
+    }
+}
+
+/// Collects message macro invocations from a parsed file
+struct MessageVisitor<'a> {
+    source: &'a Path,
+    messages: Vec<RustMessage>,
+    warnings: Vec<String>,
+}
+
+impl<'ast> Visit<'ast> for MessageVisitor<'_> {
+    fn visit_item_macro(&mut self, node: &'ast syn::ItemMacro) {
+        let kind = match node.mac.path.segments.last() {
+            Some(segment) if segment.ident == "message" => MessageKind::Message,
+            Some(segment) if segment.ident == "zero_copy_message" => MessageKind::ZeroCopy,
+            _ => return,
+        };
+
+        let body = match node.mac.parse_body::<MacroBody>() {
+            Ok(body) => body,
+            Err(e) => {
+                self.warnings.push(format!(
+                    "{}: could not parse {} body: {}",
+                    self.source.display(),
+                    kind.macro_name(),
+                    e
+                ));
+                return;
+            }
+        };
+
+        let variants = match body.variants.as_deref().map(enum_variants).transpose() {
+            Ok(variants) => variants,
+            Err(e) => {
+                self.warnings.push(format!(
+                    "{}: skipping `{}`: {}",
+                    self.source.display(),
+                    body.name,
+                    e
+                ));
+                return;
+            }
+        };
+
+        self.messages.push(RustMessage {
+            name: body.name.to_string(),
+            kind,
+            doc: doc_lines(&body.attrs),
+            fields: body
+                .fields
+                .into_iter()
+                .enumerate()
+                .map(|(index, field)| RustField {
+                    name: match (&field.ident, body.tuple) {
+                        (Some(ident), false) => syn::ext::IdentExt::unraw(ident).to_string(),
+                        _ => format!("_{}", index),
+                    },
+                    doc: doc_lines(&field.attrs),
+                    ty: field.ty,
+                })
+                .collect(),
+            variants,
+            source: self.source.to_path_buf(),
+        });
+    }
+}
+
+/// Enum variants with their `u8` discriminants
+fn enum_variants(variants: &[Variant]) -> Result<Vec<RustVariant>, String> {
+    let mut next: u16 = 0;
+    variants
+        .iter()
+        .map(|variant| {
+            if let Some((_, expr)) = &variant.discriminant {
+                next = match expr {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(n),
+                        ..
+                    }) => n
+                        .base10_parse::<u16>()
+                        .map_err(|e| format!("invalid discriminant: {}", e))?,
+                    _ => return Err("discriminants must be integer literals".to_string()),
+                };
+            }
+            let value = u8::try_from(next)
+                .map_err(|_| format!("discriminant of `{}` does not fit in u8", variant.ident))?;
+            next += 1;
+            Ok(RustVariant {
+                name: variant.ident.to_string(),
+                value,
+                doc: doc_lines(&variant.attrs),
+            })
+        })
+        .collect()
+}
+
+/// `///` doc comment lines from attributes
+fn doc_lines(attrs: &[Attribute]) -> Vec<String> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(nv) => match &nv.value {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(s),
+                    ..
+                }) => Some(s.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+// ============================================================================
+// Type model and layout
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Primitive {
+    Bool,
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    F32,
+    F64,
+}
+
+impl Primitive {
+    fn from_ident(ident: &str) -> Option<Self> {
+        Some(match ident {
+            "bool" => Primitive::Bool,
+            "i8" => Primitive::I8,
+            "u8" => Primitive::U8,
+            "i16" => Primitive::I16,
+            "u16" => Primitive::U16,
+            "i32" => Primitive::I32,
+            "u32" => Primitive::U32,
+            "i64" | "isize" => Primitive::I64,
+            "u64" | "usize" => Primitive::U64,
+            "f32" => Primitive::F32,
+            "f64" => Primitive::F64,
+            _ => return None,
+        })
+    }
+
+    /// `struct` module format character
+    fn format_char(self) -> char {
+        match self {
+            Primitive::Bool => '?',
+            Primitive::I8 => 'b',
+            Primitive::U8 => 'B',
+            Primitive::I16 => 'h',
+            Primitive::U16 => 'H',
+            Primitive::I32 => 'i',
+            Primitive::U32 => 'I',
+            Primitive::I64 => 'q',
+            Primitive::U64 => 'Q',
+            Primitive::F32 => 'f',
+            Primitive::F64 => 'd',
+        }
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Primitive::Bool | Primitive::I8 | Primitive::U8 => 1,
+            Primitive::I16 | Primitive::U16 => 2,
+            Primitive::I32 | Primitive::U32 | Primitive::F32 => 4,
+            Primitive::I64 | Primitive::U64 | Primitive::F64 => 8,
+        }
+    }
+
+    fn python_type(self) -> &'static str {
+        match self {
+            Primitive::Bool => "bool",
+            Primitive::F32 | Primitive::F64 => "float",
+            _ => "int",
+        }
+    }
+
+    fn python_default(self) -> &'static str {
+        match self {
+            Primitive::Bool => "False",
+            Primitive::F32 | Primitive::F64 => "0.0",
+            _ => "0",
+        }
+    }
+}
+
+/// Fixed-size field type
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FieldType {
+    Primitive(Primitive),
+    /// `[u8; N]`, mapped to `bytes`
+    Bytes(usize),
+    /// `FixedStringN` from `fixed_string!` (`data: [u8; N], len: u8`)
+    FixedString(usize),
+    Array(Box<FieldType>, usize),
+    /// `Optional<T>` (`valid: bool, value: T`), mapped to `Optional[T]`
+    Optional(Box<FieldType>),
+    /// A `message!` enum (`#[repr(u8)]`) and its default variant
+    Enum {
+        name: String,
+        default: String,
+    },
+    /// Another generated message
+    Named(String),
+}
+
+impl FieldType {
+    /// Resolve a Rust type, or explain why it has no fixed layout
+    fn from_rust(ty: &syn::Type) -> Result<Self, String> {
+        match ty {
+            syn::Type::Array(array) => {
+                let len = match &array.len {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(n),
+                        ..
+                    }) => n
+                        .base10_parse::<usize>()
+                        .map_err(|e| format!("invalid array length: {}", e))?,
+                    _ => return Err("array length must be an integer literal".to_string()),
+                };
+                let elem = Self::from_rust(&array.elem)?;
+                Ok(match elem {
+                    FieldType::Primitive(Primitive::U8) => FieldType::Bytes(len),
+                    elem => FieldType::Array(Box::new(elem), len),
+                })
+            }
+            syn::Type::Paren(paren) => Self::from_rust(&paren.elem),
+            syn::Type::Path(path) if path.qself.is_none() => {
+                let segment = path
+                    .path
+                    .segments
+                    .last()
+                    .ok_or_else(|| "empty type path".to_string())?;
+                let ident = segment.ident.to_string();
+                if let ("Optional", syn::PathArguments::AngleBracketed(args)) =
+                    (ident.as_str(), &segment.arguments)
+                {
+                    if let (1, Some(syn::GenericArgument::Type(inner))) =
+                        (args.args.len(), args.args.first())
+                    {
+                        return Ok(FieldType::Optional(Box::new(Self::from_rust(inner)?)));
+                    }
+                }
+                if !segment.arguments.is_empty() || ident == "String" {
+                    return Err(format!(
+                        "`{}` has no fixed binary layout",
+                        type_to_string(ty)
+                    ));
+                }
+                if let Some(primitive) = Primitive::from_ident(&ident) {
+                    return Ok(FieldType::Primitive(primitive));
+                }
+                if let Some(capacity) = ident
+                    .strip_prefix("FixedString")
+                    .and_then(|n| n.parse::<usize>().ok())
+                {
+                    return Ok(FieldType::FixedString(capacity));
+                }
+                Ok(FieldType::Named(ident))
+            }
+            _ => Err(format!("unsupported type `{}`", type_to_string(ty))),
+        }
+    }
+
+    /// Message types this type refers to
+    fn named(&self) -> Option<&str> {
+        match self {
+            FieldType::Named(name) => Some(name),
+            FieldType::Array(elem, _) | FieldType::Optional(elem) => elem.named(),
+            _ => None,
+        }
+    }
+
+    /// Replace references to enums (name -> default variant)
+    fn with_enums(self, enums: &HashMap<String, String>) -> Self {
+        match self {
+            FieldType::Named(name) => match enums.get(&name) {
+                Some(default) => FieldType::Enum {
+                    default: default.clone(),
+                    name,
+                },
+                None => FieldType::Named(name),
+            },
+            FieldType::Array(elem, n) => FieldType::Array(Box::new(elem.with_enums(enums)), n),
+            FieldType::Optional(elem) => FieldType::Optional(Box::new(elem.with_enums(enums))),
+            ty => ty,
+        }
+    }
+
+    /// Whether this type or any element type matches
+    fn contains(&self, pred: fn(&FieldType) -> bool) -> bool {
+        pred(self)
+            || match self {
+                FieldType::Array(elem, _) | FieldType::Optional(elem) => elem.contains(pred),
+                _ => false,
+            }
+    }
+}
+
+/// Rust spelling of a type for messages
+fn type_to_string(ty: &syn::Type) -> String {
+    match ty {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .iter()
+            .map(|segment| match &segment.arguments {
+                syn::PathArguments::AngleBracketed(args) => {
+                    let args: Vec<String> = args
+                        .args
+                        .iter()
+                        .map(|arg| match arg {
+                            syn::GenericArgument::Type(ty) => type_to_string(ty),
+                            _ => "_".to_string(),
+                        })
+                        .collect();
+                    format!("{}<{}>", segment.ident, args.join(", "))
+                }
+                _ => segment.ident.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("::"),
+        syn::Type::Reference(reference) => format!("&{}", type_to_string(&reference.elem)),
+        syn::Type::Slice(slice) => format!("[{}]", type_to_string(&slice.elem)),
+        syn::Type::Array(array) => format!("[{}; _]", type_to_string(&array.elem)),
+        syn::Type::Tuple(tuple) => format!(
+            "({})",
+            tuple
+                .elems
+                .iter()
+                .map(type_to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        _ => "_".to_string(),
+    }
+}
+
+/// Binary layout of a type as `struct` format items
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Layout {
+    format: Vec<String>,
+    size: usize,
+    align: usize,
+}
+
+impl Layout {
+    fn of(ty: &FieldType, resolved: &HashMap<String, Layout>) -> Layout {
+        match ty {
+            FieldType::Primitive(p) => Layout {
+                format: vec![p.format_char().to_string()],
+                size: p.size(),
+                align: p.size(),
+            },
+            FieldType::Bytes(n) => Layout {
+                format: vec![format!("{}s", n)],
+                size: *n,
+                align: 1,
+            },
+            FieldType::FixedString(n) => Layout {
+                format: vec![format!("{}s", n), "B".to_string()],
+                size: n + 1,
+                align: 1,
+            },
+            FieldType::Array(elem, n) => {
+                if let FieldType::Primitive(p) = **elem {
+                    return Layout {
+                        format: vec![format!("{}{}", n, p.format_char())],
+                        size: n * p.size(),
+                        align: p.size(),
+                    };
+                }
+                let inner = Layout::of(elem, resolved);
+                Layout {
+                    format: (0..*n).flat_map(|_| inner.format.clone()).collect(),
+                    size: n * inner.size,
+                    align: inner.align,
+                }
+            }
+            FieldType::Optional(elem) => struct_layout(
+                MessageKind::Message,
+                &[FieldType::Primitive(Primitive::Bool), (**elem).clone()],
+                resolved,
+            ),
+            FieldType::Enum { .. } => Layout::of(&FieldType::Primitive(Primitive::U8), resolved),
+            FieldType::Named(name) => resolved[name].clone(),
+        }
+    }
+
+    fn format_string(&self) -> String {
+        format!("<{}", self.format.join(" "))
+    }
+}
+
+/// A message with resolved field types and layout
+#[derive(Debug, Clone)]
+struct ResolvedMessage {
+    message: RustMessage,
+    fields: Vec<FieldType>,
+    layout: Layout,
+}
+
+fn struct_layout(
+    kind: MessageKind,
+    fields: &[FieldType],
+    resolved: &HashMap<String, Layout>,
+) -> Layout {
+    let packed = kind == MessageKind::ZeroCopy;
+    let mut format = Vec::new();
+    let mut offset: usize = 0;
+    let mut align = 1;
+
+    for field in fields {
+        let layout = Layout::of(field, resolved);
+        if !packed {
+            let padding = offset.next_multiple_of(layout.align) - offset;
+            if padding > 0 {
+                format.push(format!("{}x", padding));
+            }
+            offset += padding;
+            align = align.max(layout.align);
+        }
+        format.extend(layout.format);
+        offset += layout.size;
+    }
+
+    if !packed {
+        let padding = offset.next_multiple_of(align) - offset;
+        if padding > 0 {
+            format.push(format!("{}x", padding));
+        }
+        offset += padding;
+    }
+
+    Layout {
+        format,
+        size: offset,
+        align,
+    }
+}
+
+/// Resolve field types and layouts, dependencies first
+///
+/// Returns the resolved messages and a warning for every message skipped.
+fn resolve(messages: Vec<RustMessage>) -> (Vec<ResolvedMessage>, Vec<String>) {
+    let mut warnings = Vec::new();
+    let mut pending: Vec<(RustMessage, Vec<FieldType>)> = Vec::new();
+    let mut seen: HashMap<String, PathBuf> = HashMap::new();
+    let mut resolved: Vec<ResolvedMessage> = Vec::new();
+    let mut layouts: HashMap<String, Layout> = HashMap::new();
+    // Enum name -> default (first) variant
+    let mut enums: HashMap<String, String> = HashMap::new();
+
+    for message in messages {
+        if let Some(first) = seen.get(&message.name) {
+            warnings.push(format!(
+                "{}: skipping duplicate definition of `{}` (first defined in {})",
+                message.source.display(),
+                message.name,
+                first.display()
+            ));
+            continue;
+        }
+        seen.insert(message.name.clone(), message.source.clone());
+
+        // Enums are a single byte and need no resolving
+        if let Some(first) = message.variants.as_ref().and_then(|v| v.first()) {
+            let layout = Layout::of(&FieldType::Primitive(Primitive::U8), &layouts);
+            enums.insert(message.name.clone(), first.name.clone());
+            layouts.insert(message.name.clone(), layout.clone());
+            resolved.push(ResolvedMessage {
+                message,
+                fields: Vec::new(),
+                layout,
+            });
+            continue;
+        }
+
+        let fields: Result<Vec<_>, String> = message
+            .fields
+            .iter()
+            .map(|f| FieldType::from_rust(&f.ty).map_err(|e| format!("field `{}`: {}", f.name, e)))
+            .collect();
+        match fields {
+            Ok(fields) => pending.push((message, fields)),
+            Err(e) => warnings.push(format!("skipping `{}`: {}", message.name, e)),
+        }
+    }
+
+    loop {
+        let before = pending.len();
+        let mut waiting = Vec::new();
+        for (message, fields) in pending {
+            let ready = fields
+                .iter()
+                .filter_map(FieldType::named)
+                .all(|name| layouts.contains_key(name));
+            if !ready {
+                waiting.push((message, fields));
+                continue;
+            }
+            let fields: Vec<FieldType> = fields.into_iter().map(|f| f.with_enums(&enums)).collect();
+            let layout = struct_layout(message.kind, &fields, &layouts);
+            layouts.insert(message.name.clone(), layout.clone());
+            resolved.push(ResolvedMessage {
+                message,
+                fields,
+                layout,
+            });
+        }
+        pending = waiting;
+        if pending.is_empty() || pending.len() == before {
+            break;
+        }
+    }
+
+    // Whatever is left refers to unknown, skipped or recursive types
+    for (message, fields) in &pending {
+        let missing: Vec<&str> = fields
+            .iter()
+            .filter_map(FieldType::named)
+            .filter(|name| !layouts.contains_key(*name))
+            .collect();
+        warnings.push(format!(
+            "skipping `{}`: no fixed layout for `{}` (not a message!/zero_copy_message! type in the inputs)",
+            message.name,
+            missing.join("`, `")
+        ));
+    }
+
+    (resolved, warnings)
+}
+
+// ============================================================================
+// Python emission
+// ============================================================================
+
+const PYTHON_RESERVED: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "self", "try",
+    "while", "with", "yield",
+];
+
+fn python_ident(name: &str) -> String {
+    if PYTHON_RESERVED.contains(&name) {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
+
+fn python_type(ty: &FieldType) -> String {
+    match ty {
+        FieldType::Primitive(p) => p.python_type().to_string(),
+        FieldType::Bytes(_) => "bytes".to_string(),
+        FieldType::FixedString(_) => "str".to_string(),
+        FieldType::Array(elem, _) => format!("List[{}]", python_type(elem)),
+        FieldType::Optional(elem) => format!("Optional[{}]", python_type(elem)),
+        FieldType::Enum { name, .. } | FieldType::Named(name) => name.clone(),
+    }
+}
+
+/// Default value expression (a fresh object for mutable types)
+fn python_default_value(ty: &FieldType) -> String {
+    match ty {
+        FieldType::Primitive(p) => p.python_default().to_string(),
+        FieldType::Bytes(n) => format!("bytes({})", n),
+        FieldType::FixedString(_) => "\"\"".to_string(),
+        FieldType::Array(elem, n) => match **elem {
+            FieldType::Primitive(p) => format!("[{}] * {}", p.python_default(), n),
+            _ => format!("[{} for _ in range({})]", python_default_value(elem), n),
+        },
+        FieldType::Optional(_) => "None".to_string(),
+        FieldType::Enum { name, default } => format!("{}.{}", name, python_ident(default)),
+        FieldType::Named(name) => format!("{}()", name),
+    }
+}
+
+/// Dataclass field default
+fn python_field_default(ty: &FieldType) -> String {
+    match ty {
+        FieldType::Array(..) => format!(
+            "field(default_factory=lambda: {})",
+            python_default_value(ty)
+        ),
+        FieldType::Named(name) => format!("field(default_factory={})", name),
+        _ => python_default_value(ty),
+    }
+}
+
+/// Statements appending the flattened `struct` values of `expr` to `values`
+fn emit_pack(out: &mut String, ty: &FieldType, expr: &str, indent: usize, depth: usize) {
+    let pad = "    ".repeat(indent);
+    match ty {
+        FieldType::Primitive(_) => {
+            let _ = writeln!(out, "{}values.append({})", pad, expr);
+        }
+        FieldType::Bytes(_) => {
+            let _ = writeln!(out, "{}values.append(bytes({}))", pad, expr);
+        }
+        FieldType::FixedString(n) => {
+            let _ = writeln!(out, "{}values.extend(_pack_str({}, {}))", pad, expr, n);
+        }
+        FieldType::Enum { .. } => {
+            let _ = writeln!(out, "{}values.append(int({}))", pad, expr);
+        }
+        FieldType::Optional(elem) => {
+            // Unset fields still occupy their bytes; pack the default value
+            let _ = writeln!(out, "{}values.append({} is not None)", pad, expr);
+            let value = format!(
+                "({} if {} is not None else {})",
+                expr,
+                expr,
+                python_default_value(elem)
+            );
+            emit_pack(out, elem, &value, indent, depth);
+        }
+        FieldType::Named(_) => {
+            let _ = writeln!(out, "{}values.extend({}._values())", pad, expr);
+        }
+        FieldType::Array(elem, _) => {
+            if let FieldType::Primitive(_) = **elem {
+                let _ = writeln!(out, "{}values.extend({})", pad, expr);
+                return;
+            }
+            let item = format!("item{}", depth);
+            let _ = writeln!(out, "{}for {} in {}:", pad, item, expr);
+            emit_pack(out, elem, &item, indent + 1, depth + 1);
+        }
+    }
+}
+
+/// Expression rebuilding a value from the `it` iterator of `struct` values
+fn unpack_expr(ty: &FieldType) -> String {
+    match ty {
+        FieldType::Primitive(_) | FieldType::Bytes(_) => "next(it)".to_string(),
+        FieldType::FixedString(_) => "_unpack_str(next(it), next(it))".to_string(),
+        FieldType::Optional(elem) => format!("_unpack_opt(next(it), {})", unpack_expr(elem)),
+        FieldType::Enum { name, .. } => format!("{}(next(it))", name),
+        FieldType::Named(name) => format!("{}._from_values(it)", name),
+        FieldType::Array(elem, n) => format!("[{} for _ in range({})]", unpack_expr(elem), n),
+    }
+}
+
+fn emit_docstring(out: &mut String, lines: &[String], indent: &str) {
+    let mut lines: Vec<&str> = lines.iter().map(|l| l.as_str()).collect();
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+    let text = lines
+        .join("\n")
+        .replace('\\', "\\\\")
+        .replace("\"\"\"", "\\\"\\\"\\\"");
+    if text.contains('\n') {
+        let _ = writeln!(out, "{}\"\"\"", indent);
+        for line in text.lines() {
+            if line.is_empty() {
+                out.push('\n');
+            } else {
+                let _ = writeln!(out, "{}{}", indent, line);
+            }
+        }
+        let _ = writeln!(out, "{}\"\"\"", indent);
+    } else {
+        let _ = writeln!(out, "{}\"\"\"{}\"\"\"", indent, text);
+    }
+}
+
+/// Source path relative to the working directory
+fn display_source(message: &RustMessage, base: &Path) -> String {
+    message
+        .source
+        .strip_prefix(base)
+        .unwrap_or(&message.source)
+        .display()
+        .to_string()
+}
+
+fn emit_enum(out: &mut String, message: &RustMessage, variants: &[RustVariant], base: &Path) {
+    let name = &message.name;
+    let mut doc = message.doc.clone();
+    if doc.iter().any(|l| !l.is_empty()) {
+        doc.push(String::new());
+    }
+    doc.push(format!(
+        "Mirrors `{}` ({} enum in {}, 1 byte).",
+        name,
+        message.kind.macro_name(),
+        display_source(message, base)
+    ));
+
+    let _ = writeln!(out, "\n\nclass {}(IntEnum):", name);
+    emit_docstring(out, &doc, "    ");
+    out.push('\n');
+    for variant in variants {
+        for line in variant.doc.iter().filter(|l| !l.is_empty()) {
+            let _ = writeln!(out, "    #: {}", line);
+        }
+        let _ = writeln!(
+            out,
+            "    {} = {}",
+            python_ident(&variant.name),
+            variant.value
+        );
+    }
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    def to_bytes(self) -> bytes:");
+    let _ = writeln!(out, "        return bytes((self,))");
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    @classmethod");
+    let _ = writeln!(out, "    def from_bytes(cls, data: bytes) -> {}:", name);
+    let _ = writeln!(out, "        return cls(data[0])");
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    @classmethod");
+    let _ = writeln!(out, "    def message_size(cls) -> int:");
+    let _ = writeln!(out, "        return 1");
+}
+
+fn emit_class(out: &mut String, resolved: &ResolvedMessage, base: &Path) {
+    let ResolvedMessage {
+        message,
+        fields,
+        layout,
+    } = resolved;
+    let name = &message.name;
+
+    if let Some(variants) = &message.variants {
+        emit_enum(out, message, variants, base);
+        return;
+    }
+
+    let source = display_source(message, base);
+    let mut doc = message.doc.clone();
+    if doc.iter().any(|l| !l.is_empty()) {
+        doc.push(String::new());
+    }
+    doc.push(format!(
+        "Mirrors `{}` ({} in {}, {} bytes{}).",
+        name,
+        message.kind.macro_name(),
+        source,
+        layout.size,
+        if message.kind == MessageKind::ZeroCopy {
+            ", packed"
+        } else {
+            ""
+        }
+    ));
+
+    let _ = writeln!(out, "\n\n@dataclass");
+    let _ = writeln!(out, "class {}:", name);
+    emit_docstring(out, &doc, "    ");
+    out.push('\n');
+
+    for (field, ty) in message.fields.iter().zip(fields) {
+        for line in field.doc.iter().filter(|l| !l.is_empty()) {
+            let _ = writeln!(out, "    #: {}", line);
+        }
+        let _ = writeln!(
+            out,
+            "    {}: {} = {}",
+            python_ident(&field.name),
+            python_type(ty),
+            python_field_default(ty)
+        );
+    }
+    if !message.fields.is_empty() {
+        out.push('\n');
+    }
+
+    let _ = writeln!(
+        out,
+        "    FORMAT: ClassVar[str] = \"{}\"",
+        layout.format_string()
+    );
+    let _ = writeln!(out, "    SIZE: ClassVar[int] = {}", layout.size);
+    let _ = writeln!(
+        out,
+        "    _STRUCT: ClassVar[struct.Struct] = struct.Struct(FORMAT)"
+    );
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    def to_bytes(self) -> bytes:");
+    let _ = writeln!(out, "        \"\"\"Pack into the Rust binary layout.\"\"\"");
+    let _ = writeln!(out, "        return self._STRUCT.pack(*self._values())");
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    @classmethod");
+    let _ = writeln!(out, "    def from_bytes(cls, data: bytes) -> {}:", name);
+    let _ = writeln!(
+        out,
+        "        \"\"\"Unpack from the Rust binary layout (trailing bytes are ignored).\"\"\""
+    );
+    let _ = writeln!(
+        out,
+        "        return cls._from_values(iter(cls._STRUCT.unpack_from(data)))"
+    );
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    @classmethod");
+    let _ = writeln!(out, "    def message_size(cls) -> int:");
+    let _ = writeln!(out, "        return cls.SIZE");
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    def _values(self) -> list:");
+    let _ = writeln!(out, "        values: list = []");
+    for (field, ty) in message.fields.iter().zip(fields) {
+        let expr = format!("self.{}", python_ident(&field.name));
+        emit_pack(out, ty, &expr, 2, 0);
+    }
+    let _ = writeln!(out, "        return values");
+
+    let _ = writeln!(out);
+    let _ = writeln!(out, "    @classmethod");
+    let _ = writeln!(out, "    def _from_values(cls, it: Iterator) -> {}:", name);
+    if message.fields.is_empty() {
+        let _ = writeln!(out, "        return cls()");
+    } else {
+        let _ = writeln!(out, "        return cls(");
+        for (field, ty) in message.fields.iter().zip(fields) {
+            let _ = writeln!(
+                out,
+                "            {}={},",
+                python_ident(&field.name),
+                unpack_expr(ty)
+            );
+        }
+        let _ = writeln!(out, "        )");
+    }
+}
+
+/// Generate the Python module for the resolved messages
+fn generate_module(messages: &[ResolvedMessage], sources: &[PathBuf], base: &Path) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "# Generated by `horus msg gen-python`. Do not edit.");
+    let _ = writeln!(out, "#");
+    let _ = writeln!(out, "# Sources:");
+    for source in sources {
+        let _ = writeln!(
+            out,
+            "#   {}",
+            source.strip_prefix(base).unwrap_or(source).display()
+        );
+    }
+    let _ = writeln!(
+        out,
+        "\"\"\"Python mirrors of HORUS message types.\n\n\
+         Each class packs to and unpacks from the in-memory layout of the Rust\n\
+         type (little-endian), so raw message bytes can be exchanged between Rust\n\
+         and Python nodes.\n\"\"\""
+    );
+    out.push('\n');
+    let _ = writeln!(out, "from __future__ import annotations");
+    out.push('\n');
+    let _ = writeln!(out, "import struct");
+    let _ = writeln!(out, "from dataclasses import dataclass, field");
+    if messages.iter().any(|m| m.message.variants.is_some()) {
+        let _ = writeln!(out, "from enum import IntEnum");
+    }
+    let _ = writeln!(out, "from typing import ClassVar, Iterator, List, Optional");
+
+    out.push('\n');
+    let _ = writeln!(out, "__all__ = [");
+    for resolved in messages {
+        let _ = writeln!(out, "    \"{}\",", resolved.message.name);
+    }
+    let _ = writeln!(out, "]");
+
+    let uses = |pred: fn(&FieldType) -> bool| {
+        messages
+            .iter()
+            .any(|m| m.fields.iter().any(|ty| ty.contains(pred)))
+    };
+    if uses(|ty| matches!(ty, FieldType::FixedString(_))) {
+        out.push_str(
+            "\n\n\
+def _pack_str(value: str, capacity: int) -> tuple:
+    data = value.encode(\"utf-8\")[:capacity]
+    return data, len(data)
+
+
+def _unpack_str(data: bytes, length: int) -> str:
+    return data[:length].decode(\"utf-8\", errors=\"replace\")
+",
+        );
+    }
+    if uses(|ty| matches!(ty, FieldType::Optional(_))) {
+        out.push_str(
+            "\n\n\
+def _unpack_opt(valid: bool, value):
+    return value if valid else None
+",
+        );
+    }
+
+    for resolved in messages {
+        emit_class(&mut out, resolved, base);
+    }
+
+    out
+}
+
+// ============================================================================
+// Command
+// ============================================================================
+
+/// Rust source files under the given inputs (sorted for deterministic output)
+fn rust_sources(inputs: &[PathBuf]) -> HorusResult<Vec<PathBuf>> {
+    fn walk(dir: &Path, files: &mut Vec<PathBuf>) -> HorusResult<()> {
+        for entry in fs::read_dir(dir).map_err(HorusError::Io)? {
+            let path = entry.map_err(HorusError::Io)?.path();
+            let hidden = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with('.') || n == "target");
+            if hidden {
+                continue;
+            }
+            if path.is_dir() {
+                walk(&path, files)?;
+            } else if path.extension().is_some_and(|e| e == "rs") {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    for input in inputs {
+        if input.is_dir() {
+            walk(input, &mut files)?;
+        } else if input.exists() {
+            files.push(input.clone());
+        } else {
+            return Err(HorusError::Config(format!(
+                "Input not found: {}",
+                input.display()
+            )));
+        }
+    }
+    files.sort();
+    files.dedup();
+    Ok(files)
+}
+
+/// Find message definitions in the given source files
+fn collect_messages(files: &[PathBuf]) -> (Vec<RustMessage>, Vec<String>) {
+    let mut messages = Vec::new();
+    let mut warnings = Vec::new();
+
+    for file in files {
+        let content = match fs::read_to_string(file) {
+            Ok(content) => content,
+            Err(e) => {
+                warnings.push(format!("{}: {}", file.display(), e));
+                continue;
+            }
+        };
+        // Cheap pre-filter before parsing
+        if !content.contains("message!") {
+            continue;
+        }
+        match syn::parse_file(&content) {
+            Ok(ast) => {
+                let mut visitor = MessageVisitor {
+                    source: file,
+                    messages: Vec::new(),
+                    warnings: Vec::new(),
+                };
+                visitor.visit_file(&ast);
+                messages.extend(visitor.messages);
+                warnings.extend(visitor.warnings);
+            }
+            Err(e) => warnings.push(format!("{}: failed to parse: {}", file.display(), e)),
+        }
+    }
+
+    (messages, warnings)
+}
+
+/// Generate Python dataclasses for `message!`/`zero_copy_message!` types
+///
+/// Writes to `output`, or to stdout if not given.
+pub fn generate_python(inputs: &[PathBuf], output: Option<&Path>) -> HorusResult<()> {
+    let files = rust_sources(inputs)?;
+    let (messages, mut warnings) = collect_messages(&files);
+
+    if messages.is_empty() {
+        return Err(HorusError::Config(format!(
+            "No message!/zero_copy_message! definitions found in {}",
+            inputs
+                .iter()
+                .map(|p| p.display().to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )));
+    }
+
+    let (resolved, resolve_warnings) = resolve(messages);
+    warnings.extend(resolve_warnings);
+    for warning in &warnings {
+        eprintln!("{} {}", "warning:".yellow().bold(), warning);
+    }
+    if resolved.is_empty() {
+        return Err(HorusError::Config(
+            "None of the message types have a fixed binary layout".to_string(),
+        ));
+    }
+
+    let mut sources: Vec<PathBuf> = resolved.iter().map(|m| m.message.source.clone()).collect();
+    sources.sort();
+    sources.dedup();
+    let base = std::env::current_dir().unwrap_or_default();
+    let code = generate_module(&resolved, &sources, &base);
+
+    match output {
+        Some(path) => {
+            if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+                fs::create_dir_all(parent).map_err(HorusError::Io)?;
+            }
+            fs::write(path, code).map_err(HorusError::Io)?;
+            println!(
+                "{} Generated {} Python message type(s) in {}",
+                "✓".green(),
+                resolved.len(),
+                path.display().to_string().cyan()
+            );
+        }
+        None => print!("{}", code),
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages_from(source: &str) -> Vec<RustMessage> {
+        let ast = syn::parse_file(source).unwrap();
+        let path = PathBuf::from("messages.rs");
+        let mut visitor = MessageVisitor {
+            source: &path,
+            messages: Vec::new(),
+            warnings: Vec::new(),
+        };
+        visitor.visit_file(&ast);
+        assert!(visitor.warnings.is_empty(), "{:?}", visitor.warnings);
+        visitor.messages
+    }
+
+    #[test]
+    fn test_repr_c_and_packed_layouts() {
+        let messages = messages_from(
+            r#"
+            message! {
+                /// Wheel state
+                WheelState {
+                    position: f64,
+                    stalled: bool,
+                    current: f32,
+                    id: u8,
+                }
+            }
+
+            message!(Position = (f32, f32));
+
+            zero_copy_message! {
+                ImuReading {
+                    timestamp_ns: u64,
+                    accel: [f32; 3],
+                    frame_id: FixedString16,
+                    status: u8,
+                    raw: [u8; 4],
+                }
+            }
+            "#,
+        );
+        let (resolved, warnings) = resolve(messages);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+
+        let wheel = &resolved[0].layout;
+        assert_eq!(wheel.format_string(), "<d ? 3x f B 7x");
+        assert_eq!((wheel.size, wheel.align), (24, 8));
+
+        assert_eq!(resolved[1].message.fields[1].name, "_1");
+        assert_eq!(resolved[1].layout.format_string(), "<f f");
+
+        let imu = &resolved[2].layout;
+        assert_eq!(imu.format_string(), "<Q 3f 16s B B 4s");
+        assert_eq!(imu.size, 8 + 12 + 17 + 1 + 4);
+    }
+
+    #[test]
+    fn test_nested_messages_and_skips() {
+        let messages = messages_from(
+            r#"
+            message! {
+                Pack {
+                    cells: [Cell; 2],
+                    r#type: u16,
+                }
+            }
+            message! {
+                Cell {
+                    voltage: f32,
+                    balancing: bool,
+                }
+            }
+            message! {
+                Log {
+                    text: String,
+                }
+            }
+            message! {
+                Fleet {
+                    packs: [Pack; 2],
+                    robot: Robot,
+                }
+            }
+            "#,
+        );
+        let (resolved, warnings) = resolve(messages);
+
+        // Dependencies come first
+        let names: Vec<_> = resolved.iter().map(|m| m.message.name.as_str()).collect();
+        assert_eq!(names, vec!["Cell", "Pack"]);
+        assert_eq!(resolved[1].layout.format_string(), "<f ? 3x f ? 3x H 2x");
+        assert_eq!(resolved[1].layout.size, 20);
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("`Log`") && warnings[0].contains("String"));
+        assert!(warnings[1].contains("`Fleet`") && warnings[1].contains("Robot"));
+
+        let code = generate_module(&resolved, &[], Path::new(""));
+        assert!(code.contains(
+            "    cells: List[Cell] = field(default_factory=lambda: [Cell() for _ in range(2)])"
+        ));
+        assert!(code.contains("    type: int = 0"));
+        assert!(code.contains(
+            "        for item0 in self.cells:\n            values.extend(item0._values())"
+        ));
+        assert!(code.contains("            cells=[Cell._from_values(it) for _ in range(2)],"));
+    }
+
+    #[test]
+    fn test_enums_and_optional_fields() {
+        let messages = messages_from(
+            r#"
+            message! {
+                DriveStatus {
+                    mode: Mode,
+                    target_speed: Optional<f32>,
+                    limits: Optional<[f32; 2]>,
+                }
+            }
+            message! {
+                /// Drive mode
+                enum Mode {
+                    None,
+                    Teleop,
+                    Auto = 5,
+                }
+            }
+            "#,
+        );
+        let variants = messages[1].variants.as_ref().unwrap();
+        let values: Vec<u8> = variants.iter().map(|v| v.value).collect();
+        assert_eq!(values, vec![0, 1, 5]);
+
+        let (resolved, warnings) = resolve(messages);
+        assert!(warnings.is_empty(), "{:?}", warnings);
+
+        let names: Vec<_> = resolved.iter().map(|m| m.message.name.as_str()).collect();
+        assert_eq!(names, vec!["Mode", "DriveStatus"]);
+        let status = &resolved[1].layout;
+        assert_eq!(status.format_string(), "<B 3x ? 3x f ? 3x 2f");
+        assert_eq!(status.size, 24);
+
+        let code = generate_module(&resolved, &[], Path::new(""));
+        assert!(code.contains("class Mode(IntEnum):"));
+        assert!(code.contains("    None_ = 0\n    Teleop = 1\n    Auto = 5\n"));
+        assert!(code.contains("    mode: Mode = Mode.None_"));
+        assert!(code.contains("    target_speed: Optional[float] = None"));
+        assert!(code.contains("        values.append(int(self.mode))"));
+        assert!(code.contains(
+            "        values.append(self.limits is not None)\n        values.extend((self.limits if self.limits is not None else [0.0] * 2))"
+        ));
+        assert!(code.contains("            target_speed=_unpack_opt(next(it), next(it)),"));
+        assert!(code.contains("            mode=Mode(next(it)),"));
+    }
+}
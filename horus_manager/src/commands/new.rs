@@ -1,3 +1,4 @@
+use crate::commands::templates::Template;
 use crate::version;
 use anyhow::{Context, Result};
 use colored::*;
@@ -10,6 +11,8 @@ pub fn create_new_project(
     path: Option<PathBuf>,
     language: String,
     use_macro: bool,
+    docker: bool,
+    template: Option<Template>,
 ) -> Result<()> {
     // Check version compatibility before creating project
     version::check_and_prompt_update()?;
@@ -38,13 +41,15 @@ pub fn create_new_project(
     };
 
     // Ask about macros if Rust was selected interactively (and not already set via flag)
-    let use_macro = if language == "rust" && is_interactive {
+    let use_macro = if language == "rust" && is_interactive && template.is_none() {
         prompt_use_macro()?
     } else {
         use_macro
     };
 
-    let description = "A HORUS robotics project".to_string();
+    let description = template
+        .map_or("A HORUS robotics project", Template::description)
+        .to_string();
     let author = get_author()?;
 
     // Create project directory
@@ -64,15 +69,33 @@ pub fn create_new_project(
         &author,
         &language,
         use_macro,
+        template,
     )?;
 
-    // Generate main file based on language
-    match language.as_str() {
-        "rust" => {
-            create_main_rs(&project_path, use_macro)?;
+    // Generate main file based on language, or the template's files
+    if let Some(template) = template {
+        for (file, content) in template.files(&language) {
+            fs::write(project_path.join(file), content)
+                .with_context(|| format!("Failed to write {}", file))?;
+        }
+        println!(
+            "  {} Generated the {} template",
+            "✓".green(),
+            template.name().cyan()
+        );
+    } else {
+        match language.as_str() {
+            "rust" => {
+                create_main_rs(&project_path, use_macro)?;
+            }
+            "python" => create_main_py(&project_path)?,
+            _ => unreachable!(),
         }
-        "python" => create_main_py(&project_path)?,
-        _ => unreachable!(),
+    }
+
+    // Optional container scaffolding
+    if docker {
+        crate::commands::container::write_docker_files(&project_path, &name, &language)?;
     }
 
     // Register workspace in ~/.horus/workspaces.json
@@ -88,7 +111,24 @@ pub fn create_new_project(
     println!("\n{}", "✓ Project created successfully!".green().bold());
     println!("\nTo get started:");
     println!("  {} {}", "cd".cyan(), name);
+    if let Some(template) = template {
+        println!("  {} (in another terminal)", template.sim_command().cyan());
+    }
     println!("  {} (auto-installs dependencies)", "horus run".cyan());
+    if template.is_some() {
+        let test_command = if language == "python" {
+            "python -m pytest"
+        } else {
+            "horus test"
+        };
+        println!("  {} (run the template's tests)", test_command.cyan());
+    }
+    if docker {
+        println!(
+            "  {} (build and run inside Docker)",
+            "horus run --container".cyan()
+        );
+    }
 
     Ok(())
 }
@@ -222,6 +262,7 @@ fn create_horus_yaml(
     author: &str,
     language: &str,
     use_macro: bool,
+    template: Option<Template>,
 ) -> Result<()> {
     // Determine dependencies based on language
     let dependencies = match language {
@@ -260,6 +301,29 @@ fn create_horus_yaml(
         _ => "",
     };
 
+    // Topics of the template, checked by `horus check`
+    let topics = template
+        .map(|template| {
+            let mut section = String::from(
+                "# Topics of this robot. External topics are published or subscribed by the\n\
+                 # simulator or hardware drivers rather than by this project's nodes.\n\
+                 topics:\n",
+            );
+            for topic in template.topics() {
+                if topic.external {
+                    section.push_str(&format!(
+                        "  {}: {{type: {}, external: true}}\n",
+                        topic.name, topic.type_name
+                    ));
+                } else {
+                    section.push_str(&format!("  {}: {}\n", topic.name, topic.type_name));
+                }
+            }
+            section.push('\n');
+            section
+        })
+        .unwrap_or_default();
+
     let content = format!(
         r#"name: {}
 version: 0.1.6
@@ -270,7 +334,7 @@ language: {}
 horus_id: null  # Auto-generated on first dependency resolution
 
 {}
-# Optional: Ignore files, directories, and packages during horus run/check
+{}# Optional: Ignore files, directories, and packages during horus run/check
 # ignore:
 #   files:
 #     - "debug_*.py"
@@ -283,7 +347,7 @@ horus_id: null  # Auto-generated on first dependency resolution
 #     - "ipython"
 #     - "jupyter"
 "#,
-        name, description, author, language, dependencies
+        name, description, author, language, dependencies, topics
     );
 
     fs::write(project_path.join("horus.yaml"), content)?;
@@ -0,0 +1,902 @@
+//! Node create command - Add a new node to an existing project
+//!
+//! Generates a node (trait or `node!` macro style in Rust, `horus.Node` in
+//! Python) with the requested publishers and subscribers and wires it into the
+//! project: the node is added to the scheduler in `main.rs` or to `horus.run`
+//! in `main.py`. Projects that declare a `launch:` section (or a `processes:`
+//! list) in `horus.yaml` get the node in its own file under `nodes/` and a new
+//! launch entry instead.
+
+use colored::*;
+use horus_core::error::{HorusError, HorusResult};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// A topic given on the command line as `TOPIC` or `TOPIC:TYPE`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicSpec {
+    pub name: String,
+    pub type_name: Option<String>,
+}
+
+impl FromStr for TopicSpec {
+    type Err = String;
+
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let (name, type_name) = match spec.split_once(':') {
+            Some((name, type_name)) => (name.trim(), Some(type_name.trim())),
+            None => (spec.trim(), None),
+        };
+        if name.is_empty() {
+            return Err(format!("missing topic name in '{}'", spec));
+        }
+        if type_name.is_some_and(|t| t.is_empty()) {
+            return Err(format!("missing message type in '{}'", spec));
+        }
+        Ok(Self {
+            name: name.to_string(),
+            type_name: type_name.map(str::to_string),
+        })
+    }
+}
+
+/// The node to generate
+#[derive(Debug, Clone)]
+pub struct NodeSpec {
+    /// Node name as given (`obstacle_detector`, `ObstacleDetector`, ...)
+    pub name: String,
+    pub publishers: Vec<TopicSpec>,
+    pub subscribers: Vec<TopicSpec>,
+    /// Generate a `node!` macro instead of a `Node` impl (Rust only)
+    pub use_macro: bool,
+    /// Tick rate in Hz
+    pub rate: Option<u32>,
+    /// Scheduler priority, defaults to after the nodes already added
+    pub priority: Option<u32>,
+}
+
+impl NodeSpec {
+    /// Node name in snake case, used for the runtime name, files and Python
+    pub fn snake_name(&self) -> String {
+        snake_case(&self.name)
+    }
+
+    /// Struct name of the Rust node
+    pub fn struct_name(&self) -> String {
+        self.snake_name()
+            .split('_')
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                let mut chars = part.chars();
+                chars
+                    .next()
+                    .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    fn validate(&self, language: &str) -> HorusResult<()> {
+        let valid = self
+            .name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic())
+            && self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+        if !valid {
+            return Err(HorusError::Config(format!(
+                "Invalid node name '{}': use letters, digits, '_' or '-', starting with a letter",
+                self.name
+            )));
+        }
+        if language == "rust" {
+            if let Some(topic) = self.topics().find(|t| t.type_name.is_none()) {
+                return Err(HorusError::Config(format!(
+                    "Topic '{}' needs a message type for Rust nodes (e.g. {}:CmdVel)",
+                    topic.name, topic.name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn topics(&self) -> impl Iterator<Item = &TopicSpec> {
+        self.publishers.iter().chain(&self.subscribers)
+    }
+
+    /// Rust field names of the publishers and subscribers, in order
+    fn field_names(&self) -> Vec<String> {
+        let mut names: Vec<String> = Vec::new();
+        for topic in self.topics() {
+            let base = topic.name.split('@').next().unwrap_or(&topic.name);
+            let short = base.rsplit(['/', '.']).next().unwrap_or(base);
+            let mut name = identifier(short);
+            if names.contains(&name) {
+                name = identifier(base);
+            }
+            while names.contains(&name) {
+                name.push('_');
+            }
+            names.push(name);
+        }
+        names
+    }
+}
+
+/// Create a node in the project at `path` (default: current directory)
+pub fn create_node(spec: &NodeSpec, path: Option<PathBuf>) -> HorusResult<()> {
+    let project = path.unwrap_or_else(|| PathBuf::from("."));
+    let manifest_path = project.join("horus.yaml");
+    let manifest = fs::read_to_string(&manifest_path).ok();
+
+    let language = project_language(&project, manifest.as_deref()).ok_or_else(|| {
+        HorusError::Config(format!(
+            "No HORUS project found in {} (run `horus new` first)",
+            project.display()
+        ))
+    })?;
+    spec.validate(&language)?;
+    if language == "python" && spec.use_macro {
+        println!(
+            "  {} --macro only applies to Rust nodes, ignoring it",
+            "[!]".yellow()
+        );
+    }
+
+    let snake = spec.snake_name();
+    let extension = if language == "rust" { "rs" } else { "py" };
+
+    // Compute every edit before writing anything
+    let mut writes: Vec<(PathBuf, String)> = Vec::new();
+    let mut manifest_content = manifest.clone();
+
+    if let Some(content) = manifest.as_deref().filter(|m| has_launch_section(m)) {
+        let file = format!("nodes/{}.{}", snake, extension);
+        let node_path = project.join(&file);
+        if node_path.exists() {
+            return Err(HorusError::Config(format!(
+                "{} already exists",
+                node_path.display()
+            )));
+        }
+        let source = if language == "rust" {
+            standalone_rust(spec)
+        } else {
+            standalone_python(spec)
+        };
+        writes.push((node_path, source));
+        manifest_content = Some(append_launch_node(content, spec, &file)?);
+    } else {
+        let main_path = main_file(&project, &language).ok_or_else(|| {
+            HorusError::Config(format!(
+                "No main.{} found in {}",
+                extension,
+                project.display()
+            ))
+        })?;
+        let content = fs::read_to_string(&main_path).map_err(HorusError::Io)?;
+        let updated = if language == "rust" {
+            insert_rust_node(&content, spec)?
+        } else {
+            insert_python_node(&content, spec)?
+        };
+        writes.push((main_path, updated));
+    }
+
+    if let Some(content) = manifest_content.as_deref() {
+        let content = append_topics(content, spec);
+        if manifest.as_deref() != Some(content.as_str()) {
+            writes.push((manifest_path, content));
+        }
+    }
+
+    for (file, content) in &writes {
+        if let Some(parent) = file.parent() {
+            fs::create_dir_all(parent).map_err(HorusError::Io)?;
+        }
+        fs::write(file, content).map_err(HorusError::Io)?;
+        println!("  {} Updated {}", "✓".green(), file.display());
+    }
+
+    println!(
+        "\n{} Created node '{}'",
+        "✓".green().bold(),
+        snake.green().bold()
+    );
+    if language == "rust"
+        && spec.use_macro
+        && manifest
+            .as_deref()
+            .is_some_and(|m| !m.contains("horus_macros"))
+    {
+        println!(
+            "  {} Add {} to the dependencies in horus.yaml",
+            "[!]".yellow(),
+            "horus_macros".cyan()
+        );
+    }
+    println!("  {} to verify the topics", "horus check".cyan());
+    Ok(())
+}
+
+/// Language of the project: `language:` in horus.yaml, else the main file
+fn project_language(project: &Path, manifest: Option<&str>) -> Option<String> {
+    let declared = manifest
+        .and_then(|m| serde_yaml::from_str::<serde_yaml::Value>(m).ok())
+        .and_then(|yaml| {
+            yaml.get("language")
+                .and_then(|l| l.as_str())
+                .map(str::to_string)
+        });
+    match declared.as_deref() {
+        Some("rust") | Some("python") => declared,
+        _ => ["rust", "python"]
+            .into_iter()
+            .find(|language| main_file(project, language).is_some())
+            .map(str::to_string),
+    }
+}
+
+fn main_file(project: &Path, language: &str) -> Option<PathBuf> {
+    let candidates: &[&str] = match language {
+        "rust" => &["main.rs", "src/main.rs"],
+        _ => &["main.py"],
+    };
+    candidates
+        .iter()
+        .map(|file| project.join(file))
+        .find(|path| path.is_file())
+}
+
+fn has_launch_section(manifest: &str) -> bool {
+    serde_yaml::from_str::<serde_yaml::Value>(manifest)
+        .is_ok_and(|yaml| yaml.get("launch").is_some() || yaml.get("processes").is_some())
+}
+
+/// Rust source of the node definition
+pub fn rust_node(spec: &NodeSpec) -> String {
+    let name = spec.struct_name();
+    let fields = spec.field_names();
+    let (pub_fields, sub_fields) = fields.split_at(spec.publishers.len());
+    let type_of = |topic: &TopicSpec| topic.type_name.clone().unwrap_or_default();
+
+    let mut tick = String::new();
+    for (field, topic) in sub_fields.iter().zip(&spec.subscribers) {
+        tick.push_str(&format!(
+            "if let Some(msg) = self.{}.recv(&mut ctx) {{\n    // Handle the {} message\n    let _ = msg;\n}}\n",
+            field,
+            type_of(topic)
+        ));
+    }
+    for (field, topic) in pub_fields.iter().zip(&spec.publishers) {
+        tick.push_str(&format!(
+            "// self.{}.send({}::default(), &mut ctx).ok();\n",
+            field,
+            type_of(topic)
+        ));
+    }
+    if tick.is_empty() {
+        tick.push_str("// Your node logic here\n");
+    }
+
+    if spec.use_macro {
+        let mut out = format!("node! {{\n    {} {{\n", name);
+        for (section, fields, topics) in [
+            ("pub", pub_fields, &spec.publishers),
+            ("sub", sub_fields, &spec.subscribers),
+        ] {
+            if topics.is_empty() {
+                continue;
+            }
+            out.push_str(&format!("        {} {{\n", section));
+            for (field, topic) in fields.iter().zip(topics) {
+                out.push_str(&format!(
+                    "            {}: {} -> \"{}\",\n",
+                    field,
+                    type_of(topic),
+                    topic.name
+                ));
+            }
+            out.push_str("        }\n\n");
+        }
+        if let Some(rate) = spec.rate {
+            out.push_str(&format!("        rate {}\n\n", rate));
+        }
+        let tick_header = if spec.topics().next().is_none() {
+            "tick {"
+        } else {
+            "tick(ctx) {"
+        };
+        out.push_str(&format!("        {}\n", tick_header));
+        out.push_str(&indent(&tick, 12));
+        out.push_str("        }\n    }\n}\n");
+        return out;
+    }
+
+    let mut out = format!("struct {} {{\n", name);
+    for (field, topic) in fields.iter().zip(spec.topics()) {
+        out.push_str(&format!("    {}: Hub<{}>,\n", field, type_of(topic)));
+    }
+    out.push_str(&format!(
+        "}}\n\nimpl {} {{\n    fn new() -> HorusResult<Self> {{\n        Ok(Self {{\n",
+        name
+    ));
+    for (field, topic) in fields.iter().zip(spec.topics()) {
+        out.push_str(&format!(
+            "            {}: Hub::new(\"{}\")?,\n",
+            field, topic.name
+        ));
+    }
+    out.push_str(&format!(
+        "        }})\n    }}\n}}\n\nimpl Node for {} {{\n    fn name(&self) -> &'static str {{\n        \"{}\"\n    }}\n\n",
+        name,
+        spec.snake_name()
+    ));
+    if let Some(rate) = spec.rate {
+        out.push_str(&format!(
+            "    fn rate_hz(&self) -> Option<f64> {{\n        Some({}.0)\n    }}\n\n",
+            rate
+        ));
+    }
+    let ctx = if spec.topics().next().is_some() {
+        "mut ctx"
+    } else {
+        "_ctx"
+    };
+    out.push_str(&format!(
+        "    fn tick(&mut self, {}: Option<&mut NodeInfo>) {{\n",
+        ctx
+    ));
+    out.push_str(&indent(&tick, 8));
+    out.push_str("    }\n}\n");
+    out
+}
+
+/// Add the node to a Rust main file: the definition goes before `fn main` and
+/// a `scheduler.add(...)` before the scheduler is run
+pub fn insert_rust_node(content: &str, spec: &NodeSpec) -> HorusResult<String> {
+    let name = spec.struct_name();
+    let defined = content.lines().any(|line| {
+        let line = line.trim();
+        let line = line.strip_prefix("pub ").unwrap_or(line);
+        let line = line.strip_prefix("struct ").unwrap_or(line);
+        line.strip_prefix(name.as_str())
+            .is_some_and(|rest| rest.starts_with([' ', '{', ';', '(']))
+            && line.ends_with(['{', ';'])
+    });
+    if defined {
+        return Err(HorusError::Config(format!(
+            "A node named {} already exists",
+            name
+        )));
+    }
+
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    let main = lines
+        .iter()
+        .position(|line| line.starts_with("fn main(") || line.starts_with("pub fn main("))
+        .ok_or_else(|| HorusError::Config("No `fn main` found".to_string()))?;
+
+    // The scheduler variable and the line running it
+    let scheduler = lines[main..]
+        .iter()
+        .find_map(|line| {
+            let rest = line.trim().strip_prefix("let mut ")?;
+            let (var, value) = rest.split_once('=')?;
+            let var = var.split(':').next()?.trim();
+            value.contains("Scheduler::new").then(|| var.to_string())
+        })
+        .ok_or_else(|| HorusError::Config("No `Scheduler::new()` found in main".to_string()))?;
+    let run_call = format!("{}.run", scheduler);
+    let run = lines[main..]
+        .iter()
+        .position(|line| line.trim().starts_with(&run_call))
+        .map(|offset| main + offset)
+        .ok_or_else(|| HorusError::Config(format!("No `{}()` call found in main", run_call)))?;
+    let indentation: String = lines[run]
+        .chars()
+        .take_while(|c| c.is_whitespace())
+        .collect();
+
+    // Add the node right after the last statement before the run call and
+    // its comment
+    let mut at = run;
+    while at > main && {
+        let previous = lines[at - 1].trim();
+        previous.is_empty() || previous.starts_with("//")
+    } {
+        at -= 1;
+    }
+
+    let priority = spec.priority.unwrap_or_else(|| {
+        lines[main..]
+            .iter()
+            .filter(|line| line.contains(&format!("{}.add(", scheduler)))
+            .count() as u32
+    });
+    let constructor = if spec.use_macro { "new()" } else { "new()?" };
+    lines.insert(
+        at,
+        format!(
+            "{}{}.add(Box::new({}::{}), {}, Some(true));",
+            indentation, scheduler, name, constructor, priority
+        ),
+    );
+
+    // Keep attributes such as `#[tokio::main]` attached to main
+    let mut before = main;
+    while before > 0 && lines[before - 1].trim_start().starts_with("#[") {
+        before -= 1;
+    }
+    let definition = rust_node(spec);
+    for (offset, line) in definition.lines().chain([""]).enumerate() {
+        lines.insert(before + offset, line.to_string());
+    }
+
+    if spec.use_macro && !content.contains("use horus_macros::node;") {
+        let after = lines
+            .iter()
+            .position(|line| line.starts_with("use horus::prelude::*;"))
+            .map_or(0, |index| index + 1);
+        lines.insert(after, "use horus_macros::node;".to_string());
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// A Rust node in its own file, run by a launch entry
+pub fn standalone_rust(spec: &NodeSpec) -> String {
+    let macro_use = if spec.use_macro {
+        "use horus_macros::node;\n"
+    } else {
+        ""
+    };
+    let constructor = if spec.use_macro { "new()" } else { "new()?" };
+    format!(
+        "use horus::prelude::*;\n{}\n{}\nfn main() -> HorusResult<()> {{\n    let mut scheduler = Scheduler::new();\n    scheduler.add(Box::new({}::{}), {}, Some(true));\n    scheduler.run()\n}}\n",
+        macro_use,
+        rust_node(spec),
+        spec.struct_name(),
+        constructor,
+        spec.priority.unwrap_or(0)
+    )
+}
+
+/// Python source of the tick function and the `horus.Node`
+pub fn python_node(spec: &NodeSpec) -> String {
+    let snake = spec.snake_name();
+    let mut out = format!(
+        "def {}(node):\n    \"\"\"Tick function of the {} node.\"\"\"\n",
+        snake, snake
+    );
+    for topic in &spec.subscribers {
+        out.push_str(&format!(
+            "    if node.has_msg(\"{0}\"):\n        msg = node.get(\"{0}\")\n        # Handle the message...\n\n",
+            topic.name
+        ));
+    }
+    for topic in &spec.publishers {
+        out.push_str(&format!("    # node.send(\"{}\", data)\n", topic.name));
+    }
+    if spec.subscribers.is_empty() {
+        if spec.publishers.is_empty() {
+            out.push_str("    # Your node logic here\n");
+        }
+        out.push_str("    pass\n");
+    }
+    out.push_str("\n\n");
+
+    out.push_str(&format!(
+        "{}_node = horus.Node(\n    name=\"{}\",\n",
+        snake, snake
+    ));
+    for (key, topics) in [("pubs", &spec.publishers), ("subs", &spec.subscribers)] {
+        if topics.is_empty() {
+            continue;
+        }
+        let value = if topics.iter().any(|t| t.type_name.is_some()) {
+            let entries: Vec<String> = topics
+                .iter()
+                .map(|t| match &t.type_name {
+                    Some(type_name) => format!("\"{}\": {{\"type\": horus.{}}}", t.name, type_name),
+                    None => format!("\"{}\": {{}}", t.name),
+                })
+                .collect();
+            format!("{{{}}}", entries.join(", "))
+        } else {
+            let entries: Vec<String> = topics.iter().map(|t| format!("\"{}\"", t.name)).collect();
+            format!("[{}]", entries.join(", "))
+        };
+        out.push_str(&format!("    {}={},\n", key, value));
+    }
+    out.push_str(&format!(
+        "    tick={},\n    rate={},\n)\n",
+        snake,
+        spec.rate.unwrap_or(30)
+    ));
+    out
+}
+
+/// Add the node to a Python main file, before the `__main__` block, and pass
+/// it to `horus.run(...)`
+pub fn insert_python_node(content: &str, spec: &NodeSpec) -> HorusResult<String> {
+    let snake = spec.snake_name();
+    let variable = format!("{}_node", snake);
+    if content.contains(&format!("def {}(", snake)) || content.contains(&format!("{} =", variable))
+    {
+        return Err(HorusError::Config(format!(
+            "A node named {} already exists",
+            snake
+        )));
+    }
+
+    let mut lines: Vec<String> = content.lines().map(str::to_string).collect();
+    if let Some(run) = lines
+        .iter()
+        .rposition(|line| line.trim_start().starts_with("horus.run("))
+    {
+        let line = &lines[run];
+        let open = line.find("horus.run(").unwrap_or(0) + "horus.run(".len();
+        let updated = match line.rfind(')').filter(|&close| close >= open) {
+            Some(close) => {
+                let args: Vec<&str> = line[open..close]
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|a| !a.is_empty())
+                    .collect();
+                let at = args
+                    .iter()
+                    .position(|a| a.contains('='))
+                    .unwrap_or(args.len());
+                let mut args: Vec<String> = args.iter().map(|a| a.to_string()).collect();
+                args.insert(at, variable.clone());
+                format!("{}{}{}", &line[..open], args.join(", "), &line[close..])
+            }
+            None => format!("{}{}, {}", &line[..open], variable, &line[open..]),
+        };
+        lines[run] = updated;
+    } else {
+        println!(
+            "  {} No horus.run(...) call found, run {} yourself",
+            "[!]".yellow(),
+            variable.cyan()
+        );
+    }
+
+    let mut at = lines
+        .iter()
+        .position(|line| line.starts_with("if __name__"))
+        .unwrap_or(lines.len());
+    while at > 0 && lines[at - 1].trim().is_empty() {
+        lines.remove(at - 1);
+        at -= 1;
+    }
+    let mut block: Vec<String> = Vec::new();
+    if at > 0 {
+        block.extend([String::new(), String::new()]);
+    }
+    block.extend(python_node(spec).lines().map(str::to_string));
+    if at < lines.len() {
+        block.extend([String::new(), String::new()]);
+    }
+    lines.splice(at..at, block);
+
+    if !lines.iter().any(|line| line.trim() == "import horus") {
+        let after = lines
+            .iter()
+            .position(|line| line.starts_with("import ") || line.starts_with("from "))
+            .unwrap_or(0);
+        lines.insert(after, "import horus".to_string());
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// A Python node in its own file, run by a launch entry
+pub fn standalone_python(spec: &NodeSpec) -> String {
+    format!(
+        "import horus\n\n\n{}\n\nif __name__ == \"__main__\":\n    horus.run({}_node)\n",
+        python_node(spec),
+        spec.snake_name()
+    )
+}
+
+/// Append a launch entry for `file` to the `launch:` nodes or `processes:`
+/// list of a `horus.yaml`, keeping the rest of the file untouched
+pub fn append_launch_node(manifest: &str, spec: &NodeSpec, file: &str) -> HorusResult<String> {
+    let snake = spec.snake_name();
+    let lines: Vec<&str> = manifest.lines().collect();
+
+    let key = match lines.iter().position(|line| line.starts_with("launch:")) {
+        Some(launch) => lines[launch + 1..]
+            .iter()
+            .take_while(|line| line.trim().is_empty() || line.starts_with([' ', '#']))
+            .position(|line| {
+                let key = line.trim_start();
+                key.starts_with("nodes:") || key.starts_with("processes:")
+            })
+            .map(|offset| launch + 1 + offset),
+        None => lines.iter().position(|line| line.starts_with("processes:")),
+    }
+    .ok_or_else(|| HorusError::Config("No launch node list found in horus.yaml".to_string()))?;
+
+    let key_indent = indentation(lines[key]);
+    if !lines[key].trim_end().ends_with(':') {
+        return Err(HorusError::Config(format!(
+            "Cannot extend the inline launch list `{}` in horus.yaml",
+            lines[key].trim()
+        )));
+    }
+
+    // The list ends at the first line that is indented less, or as much as
+    // the key without being a list item
+    let mut last = key;
+    let mut item_indent = None;
+    for (index, line) in lines.iter().enumerate().skip(key + 1) {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let indent = indentation(line);
+        let is_item = trimmed.starts_with("- ") || trimmed == "-";
+        if indent < key_indent || (indent == key_indent && !is_item) {
+            break;
+        }
+        if is_item && item_indent.is_none() {
+            item_indent = Some(indent);
+        }
+        if trimmed.starts_with("- name:")
+            && trimmed["- name:".len()..].trim().trim_matches(['"', '\'']) == snake
+        {
+            return Err(HorusError::Config(format!(
+                "A launch entry named {} already exists",
+                snake
+            )));
+        }
+        last = index;
+    }
+
+    let pad = " ".repeat(item_indent.unwrap_or(key_indent + 2));
+    let mut entry = vec![
+        format!("{}- name: {}", pad, snake),
+        format!("{}  file: {}", pad, file),
+    ];
+    if let Some(priority) = spec.priority {
+        entry.push(format!("{}  priority: {}", pad, priority));
+    }
+    if let Some(rate) = spec.rate {
+        entry.push(format!("{}  rate_hz: {}", pad, rate));
+    }
+
+    let mut out: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    out.splice(last + 1..last + 1, entry);
+    Ok(out.join("\n") + "\n")
+}
+
+/// Register the typed topics of the node in the `topics:` section of a
+/// `horus.yaml`, if the project has one
+pub fn append_topics(manifest: &str, spec: &NodeSpec) -> String {
+    let lines: Vec<&str> = manifest.lines().collect();
+    let Some(key) = lines.iter().position(|line| line.trim_end() == "topics:") else {
+        return manifest.to_string();
+    };
+    let registered: Vec<String> = crate::static_analysis::manifest_topics(manifest, Path::new(""))
+        .into_iter()
+        .map(|topic| topic.name)
+        .collect();
+
+    let mut last = key;
+    let mut entry_indent = None;
+    for (index, line) in lines.iter().enumerate().skip(key + 1) {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if indentation(line) == 0 {
+            break;
+        }
+        entry_indent.get_or_insert(indentation(line));
+        last = index;
+    }
+    let pad = " ".repeat(entry_indent.unwrap_or(2));
+
+    let mut names: Vec<&str> = Vec::new();
+    let mut added: Vec<String> = Vec::new();
+    for topic in spec.topics() {
+        let Some(type_name) = &topic.type_name else {
+            continue;
+        };
+        let name = topic.name.split('@').next().unwrap_or(&topic.name);
+        if registered.iter().any(|r| r == name) || names.contains(&name) {
+            continue;
+        }
+        names.push(name);
+        added.push(format!("{}{}: {}", pad, name, type_name));
+    }
+    if added.is_empty() {
+        return manifest.to_string();
+    }
+
+    let mut out: Vec<String> = lines.iter().map(|line| line.to_string()).collect();
+    out.splice(last + 1..last + 1, added);
+    out.join("\n") + "\n"
+}
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::new();
+    let mut previous: Option<char> = None;
+    for c in name.chars() {
+        if c == '-' || c == '_' {
+            if !out.ends_with('_') {
+                out.push('_');
+            }
+        } else if c.is_ascii_uppercase() {
+            if previous.is_some_and(|p| p.is_ascii_lowercase() || p.is_ascii_digit()) {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+        previous = Some(c);
+    }
+    out.trim_matches('_').to_string()
+}
+
+/// A Rust identifier from a topic name
+fn identifier(topic: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "box", "fn", "impl", "in", "loop", "match", "mod", "move", "ref", "self", "struct",
+        "type", "use",
+    ];
+    let mut name: String = topic
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    name = name.trim_matches('_').to_string();
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert_str(0, "topic_");
+    }
+    if KEYWORDS.contains(&name.as_str()) {
+        name.push_str("_topic");
+    }
+    name
+}
+
+fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+fn indent(text: &str, width: usize) -> String {
+    let pad = " ".repeat(width);
+    text.lines()
+        .map(|line| {
+            if line.is_empty() {
+                "\n".to_string()
+            } else {
+                format!("{}{}\n", pad, line)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(use_macro: bool) -> NodeSpec {
+        NodeSpec {
+            name: "ObstacleDetector".to_string(),
+            publishers: vec!["robot.cmd_vel:CmdVel".parse().unwrap()],
+            subscribers: vec!["robot/scan:LaserScan".parse().unwrap()],
+            use_macro,
+            rate: Some(20),
+            priority: None,
+        }
+    }
+
+    const MAIN_RS: &str = r#"use horus::prelude::*;
+
+struct Controller {
+    cmd_vel: Hub<CmdVel>,
+}
+
+fn main() -> HorusResult<()> {
+    let mut scheduler = Scheduler::new();
+    scheduler.add(Box::new(Controller::new()?), 0, Some(true));
+
+    // Run the scheduler
+    scheduler.run()
+}
+"#;
+
+    #[test]
+    fn test_names() {
+        let spec = spec(false);
+        assert_eq!(spec.snake_name(), "obstacle_detector");
+        assert_eq!(spec.struct_name(), "ObstacleDetector");
+        assert_eq!(spec.field_names(), vec!["cmd_vel", "scan"]);
+        assert_eq!(snake_case("lidar-driver"), "lidar_driver");
+        assert_eq!(identifier("type"), "type_topic");
+        assert!("robot.cmd_vel:".parse::<TopicSpec>().is_err());
+        assert_eq!(
+            "odom".parse::<TopicSpec>().unwrap(),
+            TopicSpec {
+                name: "odom".to_string(),
+                type_name: None
+            }
+        );
+    }
+
+    #[test]
+    fn test_insert_rust_node() {
+        let out = insert_rust_node(MAIN_RS, &spec(false)).unwrap();
+        assert!(out.contains(
+            "struct ObstacleDetector {\n    cmd_vel: Hub<CmdVel>,\n    scan: Hub<LaserScan>,\n}"
+        ));
+        assert!(out.contains("scan: Hub::new(\"robot/scan\")?,"));
+        assert!(out.contains("Some(20.0)"));
+        // Definition before main, added after the existing node and before the run comment
+        assert!(
+            out.find("impl Node for ObstacleDetector").unwrap() < out.find("fn main()").unwrap()
+        );
+        assert!(out.contains(
+            "Some(true));\n    scheduler.add(Box::new(ObstacleDetector::new()?), 1, Some(true));\n\n    // Run"
+        ));
+        assert!(insert_rust_node(&out, &spec(false)).is_err());
+
+        let out = insert_rust_node(MAIN_RS, &spec(true)).unwrap();
+        assert!(out.contains("use horus_macros::node;"));
+        assert!(out.contains("node! {\n    ObstacleDetector {\n        pub {\n            cmd_vel: CmdVel -> \"robot.cmd_vel\",\n        }"));
+        assert!(out.contains("        rate 20\n"));
+        assert!(out.contains("Box::new(ObstacleDetector::new()), 1"));
+
+        let untyped = NodeSpec {
+            publishers: vec!["odom".parse().unwrap()],
+            ..spec(false)
+        };
+        assert!(untyped.validate("rust").is_err());
+        assert!(untyped.validate("python").is_ok());
+    }
+
+    #[test]
+    fn test_insert_python_node() {
+        let main_py = "import horus\n\ndef controller(node):\n    pass\n\nnode = horus.Node(name=\"controller\", tick=controller)\n\nif __name__ == \"__main__\":\n    horus.run(node, duration=10)\n";
+        let out = insert_python_node(main_py, &spec(false)).unwrap();
+        assert!(out.contains("horus.run(node, obstacle_detector_node, duration=10)"));
+        assert!(out.contains("    if node.has_msg(\"robot/scan\"):"));
+        assert!(out.contains("pubs={\"robot.cmd_vel\": {\"type\": horus.CmdVel}},"));
+        assert!(out.contains("    rate=20,\n)\n\n\nif __name__"));
+        assert!(insert_python_node(&out, &spec(false)).is_err());
+    }
+
+    #[test]
+    fn test_manifest_edits() {
+        let manifest = "name: robot\nlanguage: rust\n\ntopics:\n  robot.cmd_vel: CmdVel\n\nlaunch:\n  session: robot\n  nodes:\n    - name: driver\n      file: nodes/driver.rs\n\n# trailing comment\n";
+        let spec = NodeSpec {
+            priority: Some(2),
+            ..spec(false)
+        };
+        let out = append_launch_node(manifest, &spec, "nodes/obstacle_detector.rs").unwrap();
+        assert!(out.contains(
+            "      file: nodes/driver.rs\n    - name: obstacle_detector\n      file: nodes/obstacle_detector.rs\n      priority: 2\n      rate_hz: 20\n"
+        ));
+        let yaml: serde_yaml::Value = serde_yaml::from_str(&out).unwrap();
+        assert_eq!(yaml["launch"]["nodes"].as_sequence().unwrap().len(), 2);
+        assert!(append_launch_node(&out, &spec, "x.rs").is_err());
+
+        let out = append_topics(manifest, &spec);
+        assert!(out.contains("  robot.cmd_vel: CmdVel\n  robot/scan: LaserScan\n"));
+        assert_eq!(append_topics("name: robot\n", &spec), "name: robot\n");
+    }
+}
@@ -1,5 +1,8 @@
+use crate::commands::build::{SharedTarget, WorkspaceBuild};
 use crate::dependency_resolver::DependencySpec;
+use crate::lockfile::{self, Lockfile, LOCKFILE_NAME};
 use crate::progress::{self, finish_error, finish_success};
+use crate::python_env::PythonEnv;
 use crate::version;
 use anyhow::{anyhow, bail, Context, Result};
 use colored::*;
@@ -171,6 +174,26 @@ impl CargoPackage {
 }
 
 pub fn execute_build_only(files: Vec<PathBuf>, release: bool, clean: bool) -> Result<()> {
+    if files.is_empty() {
+        if let Some(workspace) = WorkspaceBuild::discover(Path::new("."))? {
+            return workspace.build(release, clean);
+        }
+    }
+    build_project(files, release, clean, None).map(|_| ())
+}
+
+/// Build the project in the current directory
+///
+/// With a shared target the generated crate is named after the member and
+/// built into the workspace target dir. Returns the built binary, if any.
+pub(crate) fn build_project(
+    files: Vec<PathBuf>,
+    release: bool,
+    clean: bool,
+    shared: Option<&SharedTarget>,
+) -> Result<Option<PathBuf>> {
+    let package_name = shared.map_or("horus-project", |s| s.package_name.as_str());
+
     // Handle clean build
     if clean {
         println!("{} Cleaning build cache...", "[CLEAN]".cyan());
@@ -227,6 +250,7 @@ pub fn execute_build_only(files: Vec<PathBuf>, release: bool, clean: bool) -> Re
                 "".cyan(),
                 target_file.display()
             );
+            Ok(None)
         }
         "rust" => {
             // Setup Rust build using Cargo in .horus workspace
@@ -251,7 +275,7 @@ pub fn execute_build_only(files: Vec<PathBuf>, release: bool, clean: bool) -> Re
 
             let mut cargo_toml = format!(
                 r#"[package]
-name = "horus-project"
+name = "{name}"
 version = "0.1.6"
 edition = "2021"
 
@@ -259,12 +283,13 @@ edition = "2021"
 [workspace]
 
 [[bin]]
-name = "horus-project"
+name = "{name}"
 path = "{}"
 
 [dependencies]
 "#,
-                source_relative_path
+                source_relative_path,
+                name = package_name
             );
 
             // Auto-detect nodes and required features
@@ -480,6 +505,9 @@ path = "{}"
             let mut cmd = Command::new("cargo");
             cmd.arg("build");
             cmd.current_dir(".horus");
+            if let Some(shared) = shared {
+                cmd.env("CARGO_TARGET_DIR", &shared.target_dir);
+            }
             // Capture output to avoid mixing with spinner
             cmd.stdout(std::process::Stdio::piped());
             cmd.stderr(std::process::Stdio::piped());
@@ -510,14 +538,16 @@ path = "{}"
             }
 
             let profile = if release { "release" } else { "debug" };
-            let binary_path = format!(".horus/target/{}/horus-project", profile);
+            let binary_path = shared
+                .map_or_else(|| PathBuf::from(".horus/target"), |s| s.target_dir.clone())
+                .join(profile)
+                .join(package_name);
 
-            finish_success(&spinner, &format!("Built: {}", binary_path));
+            finish_success(&spinner, &format!("Built: {}", binary_path.display()));
+            Ok(Some(binary_path))
         }
         _ => bail!("Unsupported language: {}", language),
     }
-
-    Ok(())
 }
 
 pub fn execute_run(
@@ -525,6 +555,7 @@ pub fn execute_run(
     args: Vec<String>,
     release: bool,
     clean: bool,
+    launch_args: Vec<String>,
 ) -> Result<()> {
     // Handle clean build
     if clean {
@@ -550,6 +581,29 @@ pub fn execute_run(
         mode.yellow()
     );
 
+    // Namespace from horus.yaml, unless set with --namespace or the environment
+    apply_project_namespace()?;
+
+    // A launch group in horus.yaml takes over `horus run` without arguments
+    if files.is_empty() && Path::new("horus.yaml").exists() {
+        let manifest = Path::new("horus.yaml");
+        let overrides = super::launch::parse_launch_args(&launch_args)
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+        if let Some(config) = super::launch::load_project_launch(manifest, &overrides)
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?
+        {
+            if !args.is_empty() {
+                eprintln!(
+                    "{} Ignoring trailing arguments; set per-process 'args' in horus.yaml",
+                    "[WARN]".yellow()
+                );
+            }
+            let namespace = env::var(horus_core::communication::namespace::NAMESPACE_ENV).ok();
+            return super::launch::run_project_launch(manifest, config, release, namespace)
+                .map_err(|e| anyhow::anyhow!(e.to_string()));
+        }
+    }
+
     // Step 1: Resolve target(s) - file(s), directory, or pattern
     let execution_targets = if files.is_empty() {
         vec![ExecutionTarget::File(auto_detect_main_file()?)]
@@ -581,6 +635,25 @@ pub fn execute_run(
     Ok(())
 }
 
+/// Export the `namespace` of horus.yaml to the program (see `horus run --namespace`)
+fn apply_project_namespace() -> Result<()> {
+    use horus_core::communication::namespace::{validate_namespace, NAMESPACE_ENV};
+
+    if env::var_os(NAMESPACE_ENV).is_some() || !Path::new("horus.yaml").exists() {
+        return Ok(());
+    }
+    let content = fs::read_to_string("horus.yaml")?;
+    let Ok(yaml) = serde_yaml::from_str::<serde_yaml::Value>(&content) else {
+        return Ok(());
+    };
+    if let Some(namespace) = yaml.get("namespace").and_then(|n| n.as_str()) {
+        validate_namespace(namespace).map_err(|e| anyhow::anyhow!(e.to_string()))?;
+        env::set_var(NAMESPACE_ENV, namespace);
+        eprintln!("{} Namespace: {}", "".cyan(), namespace.green());
+    }
+    Ok(())
+}
+
 fn execute_single_file(
     file_path: PathBuf,
     args: Vec<String>,
@@ -3354,114 +3427,17 @@ fn install_pip_packages(packages: Vec<PipPackage>) -> Result<()> {
 
     println!("{} Resolving Python packages...", "[PYTHON]".cyan());
 
-    let global_cache = home_dir().join(".horus/cache");
-    let local_packages = PathBuf::from(".horus/packages");
-
-    fs::create_dir_all(&global_cache)?;
-    fs::create_dir_all(&local_packages)?;
-
-    // Use system Python's pip directly
-    let python_cmd = detect_python_interpreter()?;
+    let venv = PythonEnv::current()?;
+    venv.ensure()?;
 
+    let requirements: Vec<String> = packages.iter().map(|p| p.requirement_string()).collect();
+    let installed = venv.install(&requirements)?;
     for pkg in &packages {
-        // Check if package exists in system first
-        if let Ok(Some(system_version)) = detect_system_python_package(&pkg.name) {
-            // Auto-use system package for horus-robotics (core dependency)
-            if pkg.name == "horus-robotics" {
-                create_system_reference_python_run(&pkg.name, &system_version)?;
-                continue;
-            }
-
-            let local_link = local_packages.join(&pkg.name);
-
-            // Skip if already handled
-            if local_link.exists() || local_link.read_link().is_ok() {
-                continue;
-            }
-
-            // Prompt user for choice
-            match prompt_system_package_choice_run(&pkg.name, &system_version)? {
-                SystemPackageChoiceRun::UseSystem => {
-                    create_system_reference_python_run(&pkg.name, &system_version)?;
-                    continue;
-                }
-                SystemPackageChoiceRun::InstallHORUS => {
-                    println!("  {} Installing isolated copy to HORUS...", "".blue());
-                    // Continue with installation below
-                }
-                SystemPackageChoiceRun::Cancel => {
-                    println!("  {} Skipped {}", "⊘".yellow(), pkg.name);
-                    continue;
-                }
-            }
-        }
-
-        // Get actual version by querying PyPI or using installed version
-        let version_str = pkg
-            .version
-            .as_ref()
-            .map(|v| {
-                v.replace(">=", "")
-                    .replace("==", "")
-                    .replace("~=", "")
-                    .replace(">", "")
-                    .replace("<", "")
-            })
-            .unwrap_or_else(|| "latest".to_string());
-
-        // Cache directory with pypi_ prefix to distinguish from HORUS packages
-        let pkg_cache_dir = global_cache.join(format!("pypi_{}@{}", pkg.name, version_str));
-
-        let local_link = local_packages.join(&pkg.name);
-
-        // If already symlinked, skip
-        if local_link.exists() || local_link.read_link().is_ok() {
-            println!("  {} {} (already linked)", "".green(), pkg.name);
-            continue;
-        }
-
-        // If not cached, install to global cache
-        if !pkg_cache_dir.exists() {
-            println!("  {} Installing {} to global cache...", "".cyan(), pkg.name);
-
-            fs::create_dir_all(&pkg_cache_dir)?;
-
-            // Install package with pip to cache directory using system pip
-            let mut cmd = Command::new(&python_cmd);
-            cmd.args([
-                "-m",
-                "pip",
-                "install",
-                "--target",
-                pkg_cache_dir.to_str().unwrap(),
-            ]);
-            cmd.arg(pkg.requirement_string());
-
-            let output = cmd.output().context("Failed to run pip install")?;
-
-            if !output.status.success() {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                bail!("pip install failed for {}: {}", pkg.name, stderr);
-            }
-
-            // Create metadata.json for package tracking
-            let metadata = serde_json::json!({
-                "name": pkg.name,
-                "version": version_str,
-                "source": "PyPI"
-            });
-            let metadata_path = pkg_cache_dir.join("metadata.json");
-            fs::write(&metadata_path, serde_json::to_string_pretty(&metadata)?)?;
-
-            println!("  {} Cached {}", "".green(), pkg.name);
+        if installed.contains(&pkg.requirement_string()) {
+            println!("  {} Installed {}", "".green(), pkg.requirement_string());
         } else {
-            println!("  {} {} -> global cache", "↗".cyan(), pkg.name);
+            println!("  {} {} (up to date)", "".green(), pkg.name);
         }
-
-        // Symlink from local packages to global cache
-        symlink(&pkg_cache_dir, &local_link)
-            .context(format!("Failed to symlink {} from global cache", pkg.name))?;
-        println!("  {} Linked {}", "".green(), pkg.name);
     }
 
     Ok(())
@@ -3631,11 +3607,37 @@ fn resolve_horus_packages(dependencies: HashSet<String>) -> Result<()> {
     fs::create_dir_all(&global_cache)?;
     fs::create_dir_all(&local_packages)?;
 
+    // Versions pinned by a previous resolution
+    let lockfile = Lockfile::load(Path::new("."))?;
+
     // Collect missing packages first
     let mut missing_packages = Vec::new();
 
     for package in &dependencies {
         let local_link = local_packages.join(package);
+        let locked = lockfile.as_ref().and_then(|l| l.get(package));
+
+        // Relink packages whose installed version differs from the lockfile
+        if let (Some(locked), Some(installed)) = (
+            locked,
+            lockfile::installed_metadata(&local_packages, package),
+        ) {
+            if installed.version != locked.version {
+                println!(
+                    "  {} {} v{} -> v{} (locked in {})",
+                    "".cyan(),
+                    package,
+                    installed.version,
+                    locked.version,
+                    LOCKFILE_NAME
+                );
+                if local_link.symlink_metadata()?.is_symlink() {
+                    fs::remove_file(&local_link)?;
+                } else {
+                    fs::remove_dir_all(&local_link)?;
+                }
+            }
+        }
 
         // Skip if already linked
         if local_link.exists() {
@@ -3643,8 +3645,17 @@ fn resolve_horus_packages(dependencies: HashSet<String>) -> Result<()> {
             continue;
         }
 
-        // Check global cache
-        let cached_versions = find_cached_versions(&global_cache, package)?;
+        // Check global cache, only accepting the locked version
+        let lookup = match locked {
+            Some(locked) => format!("{}@{}", package, locked.version),
+            None => package.clone(),
+        };
+        let cached_versions: Vec<PathBuf> = find_cached_versions(&global_cache, &lookup)?
+            .into_iter()
+            .filter(|path| {
+                locked.is_none() || path.file_name() == Some(std::ffi::OsStr::new(&lookup))
+            })
+            .collect();
 
         if let Some(cached) = cached_versions.first() {
             // Check if we're using a different version than requested
@@ -3741,6 +3752,32 @@ fn resolve_horus_packages(dependencies: HashSet<String>) -> Result<()> {
             let client = RegistryClient::new();
             let target = workspace::detect_or_select_workspace(true)?;
 
+            // Locked packages are installed at exactly their locked version
+            if let Some(ref lockfile) = lockfile {
+                let mut unlocked = Vec::new();
+                for package in missing_packages.drain(..) {
+                    let Some(locked) = lockfile.get(&package) else {
+                        unlocked.push(package);
+                        continue;
+                    };
+                    print!(
+                        "  {} Installing {} v{} (locked)... ",
+                        "".cyan(),
+                        package.yellow(),
+                        locked.version
+                    );
+                    io::stdout().flush()?;
+                    match client.install_locked(&package, locked, target.clone()) {
+                        Ok(_) => println!("{}", "".green()),
+                        Err(e) => {
+                            println!("{}", "".red());
+                            bail!("Failed to install {} v{}: {}", package, locked.version, e);
+                        }
+                    }
+                }
+                missing_packages = unlocked;
+            }
+
             // Try to use structured dependencies from horus.yaml
             let horus_yaml_path = Path::new("horus.yaml");
             let use_structured_deps = horus_yaml_path.exists();
@@ -3858,6 +3895,51 @@ fn resolve_horus_packages(dependencies: HashSet<String>) -> Result<()> {
         }
     }
 
+    update_lockfile(lockfile, &local_packages, &dependencies)
+}
+
+/// Check installed packages against `horus.lock`, locking any new ones
+///
+/// Writes the lockfile on the first resolution of a project.
+fn update_lockfile(
+    lockfile: Option<Lockfile>,
+    local_packages: &Path,
+    dependencies: &HashSet<String>,
+) -> Result<()> {
+    let mut names: Vec<&String> = dependencies.iter().collect();
+    names.sort();
+    let installed = Lockfile::from_installed(local_packages, &names);
+    let Some(mut lockfile) = lockfile else {
+        if !installed.packages.is_empty() {
+            installed.save(Path::new("."))?;
+            println!(
+                "  {} Locked {} package(s) in {}",
+                "".green(),
+                installed.packages.len(),
+                LOCKFILE_NAME
+            );
+        }
+        return Ok(());
+    };
+
+    let mut added = 0;
+    for package in installed.packages {
+        if lockfile.get(&package.name).is_some() {
+            lockfile.check_installed(local_packages, &package.name)?;
+        } else {
+            lockfile.insert(package);
+            added += 1;
+        }
+    }
+    if added > 0 {
+        lockfile.save(Path::new("."))?;
+        println!(
+            "  {} Added {} package(s) to {}",
+            "".green(),
+            added,
+            LOCKFILE_NAME
+        );
+    }
     Ok(())
 }
 
@@ -4082,13 +4164,10 @@ fn execute_python_node(file: PathBuf, args: Vec<String>, _release: bool) -> Resu
 }
 
 fn detect_python_interpreter() -> Result<String> {
-    // Use system Python - packages are in PYTHONPATH via .horus/packages/
-    for cmd in &["python3", "python"] {
-        if Command::new(cmd).arg("--version").output().is_ok() {
-            return Ok(cmd.to_string());
-        }
-    }
-    bail!("No Python interpreter found. Install Python 3.7+ and ensure it's in PATH.");
+    // Interpreter of the workspace venv; HORUS packages are in PYTHONPATH via .horus/packages/
+    let venv = PythonEnv::current()?;
+    venv.ensure()?;
+    Ok(venv.python().to_string_lossy().into_owned())
 }
 
 fn setup_python_environment() -> Result<()> {
@@ -4769,95 +4848,6 @@ fn create_system_reference_cargo_run(package_name: &str, system_version: &str) -
     Ok(())
 }
 
-fn detect_system_python_package(package_name: &str) -> Result<Option<String>> {
-    use std::process::Command;
-
-    // Check if package is installed in system Python using pip show
-    let output = Command::new("python3")
-        .args(["-m", "pip", "show", package_name])
-        .output();
-
-    if let Ok(output) = output {
-        if output.status.success() {
-            let stdout = String::from_utf8_lossy(&output.stdout);
-            // Parse version from pip show output
-            for line in stdout.lines() {
-                if line.starts_with("Version:") {
-                    let version = line.trim_start_matches("Version:").trim().to_string();
-                    return Ok(Some(version));
-                }
-            }
-            // Package found but version unknown
-            return Ok(Some("unknown".to_string()));
-        }
-    }
-
-    Ok(None)
-}
-
-fn prompt_system_package_choice_run(
-    package_name: &str,
-    system_version: &str,
-) -> Result<SystemPackageChoiceRun> {
-    use std::io::{self, Write};
-
-    println!(
-        "\n{} PyPI package {} found in system (version: {})",
-        "[WARNING]".yellow(),
-        package_name.green(),
-        system_version.cyan()
-    );
-    println!("\nWhat would you like to do?");
-    println!("  [1] {} Use system package (create reference)", "".green());
-    println!(
-        "  [2] {} Install to HORUS (isolated environment)",
-        "".blue()
-    );
-    println!("  [3] {} Skip this package", "⊘".yellow());
-
-    print!("\nChoice [1-3]: ");
-    io::stdout().flush()?;
-
-    let mut input = String::new();
-    io::stdin().read_line(&mut input)?;
-
-    match input.trim() {
-        "1" => Ok(SystemPackageChoiceRun::UseSystem),
-        "2" => Ok(SystemPackageChoiceRun::InstallHORUS),
-        "3" => Ok(SystemPackageChoiceRun::Cancel),
-        _ => {
-            println!("Invalid choice, defaulting to Install to HORUS");
-            Ok(SystemPackageChoiceRun::InstallHORUS)
-        }
-    }
-}
-
-fn create_system_reference_python_run(package_name: &str, system_version: &str) -> Result<()> {
-    println!("  {} Creating reference to system package...", "".green());
-
-    let packages_dir = PathBuf::from(".horus/packages");
-    fs::create_dir_all(&packages_dir)?;
-
-    let metadata_file = packages_dir.join(format!("{}.system.json", package_name));
-    let metadata = serde_json::json!({
-        "name": package_name,
-        "version": system_version,
-        "source": "System",
-        "package_type": "PyPI"
-    });
-
-    fs::write(&metadata_file, serde_json::to_string_pretty(&metadata)?)?;
-
-    println!("  {} Using system package", "".green());
-    println!(
-        "  {} Reference created: {}",
-        "".cyan(),
-        metadata_file.display()
-    );
-
-    Ok(())
-}
-
 /// Auto-create or update horus.yaml with detected dependencies
 fn auto_update_horus_yaml(
     file: &Path,
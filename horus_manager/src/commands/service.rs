@@ -0,0 +1,424 @@
+//! Service command - Run a HORUS project as a systemd service
+//!
+//! Generates a unit with real-time limits (`LimitRTPRIO`, `LimitMEMLOCK`), a
+//! restart policy and the build environment, installs it as a system service
+//! (`/etc/systemd/system`) or user service (`~/.config/systemd/user`) and
+//! enables it so the robot starts the stack on boot.
+
+use colored::*;
+use horus_core::error::{HorusError, HorusResult};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// What goes into the generated unit
+#[derive(Debug, Clone)]
+pub struct ServiceSpec {
+    /// Unit name without `.service`
+    pub name: String,
+    pub description: String,
+    pub working_dir: PathBuf,
+    pub exec_start: String,
+    /// Install as a user service instead of a system service
+    pub user_service: bool,
+    /// Account a system service runs as
+    pub run_as: Option<String>,
+    /// `LimitRTPRIO`; `None` disables the real-time limits
+    pub rt_priority: Option<u8>,
+    /// `Environment=` entries (KEY=VALUE)
+    pub env: Vec<String>,
+    pub env_file: Option<PathBuf>,
+    /// `WatchdogSec`; the scheduler must use `with_health_probe("systemd")`
+    pub watchdog_secs: Option<u64>,
+    /// `RestartSec`
+    pub restart_secs: u64,
+}
+
+impl ServiceSpec {
+    fn unit_file_name(&self) -> String {
+        format!("{}.service", self.name)
+    }
+}
+
+/// Directory for system or user units
+fn unit_dir(user_service: bool) -> HorusResult<PathBuf> {
+    if user_service {
+        let config = dirs::config_dir().ok_or_else(|| {
+            HorusError::Config("Could not find the user config directory".to_string())
+        })?;
+        Ok(config.join("systemd").join("user"))
+    } else {
+        Ok(PathBuf::from("/etc/systemd/system"))
+    }
+}
+
+/// Render the systemd unit
+pub fn generate_unit(spec: &ServiceSpec) -> String {
+    let mut unit = String::new();
+
+    unit.push_str("# Generated by `horus service install`\n");
+    unit.push_str("[Unit]\n");
+    unit.push_str(&format!("Description={}\n", spec.description));
+    unit.push_str("After=network-online.target\n");
+    unit.push_str("Wants=network-online.target\n");
+    // Give up after 5 crashes within a minute instead of restarting forever
+    unit.push_str("StartLimitIntervalSec=60\n");
+    unit.push_str("StartLimitBurst=5\n");
+
+    unit.push_str("\n[Service]\n");
+    if spec.watchdog_secs.is_some() {
+        unit.push_str("Type=notify\n");
+        unit.push_str("NotifyAccess=all\n");
+    } else {
+        unit.push_str("Type=simple\n");
+    }
+    if let Some(ref user) = spec.run_as {
+        unit.push_str(&format!("User={}\n", user));
+    }
+    unit.push_str(&format!(
+        "WorkingDirectory={}\n",
+        spec.working_dir.display()
+    ));
+    unit.push_str(&format!("ExecStart={}\n", spec.exec_start));
+    unit.push_str("Restart=on-failure\n");
+    unit.push_str(&format!("RestartSec={}\n", spec.restart_secs));
+    if let Some(secs) = spec.watchdog_secs {
+        unit.push_str(&format!("WatchdogSec={}\n", secs));
+    }
+    // Let the scheduler shut nodes down before SIGKILL
+    unit.push_str("KillSignal=SIGINT\n");
+    unit.push_str("TimeoutStopSec=10\n");
+
+    if let Some(priority) = spec.rt_priority {
+        unit.push_str("\n# Real-time scheduling and memory locking\n");
+        unit.push_str(&format!("LimitRTPRIO={}\n", priority));
+        unit.push_str("LimitMEMLOCK=infinity\n");
+        unit.push_str("LimitNICE=-20\n");
+    }
+
+    if !spec.env.is_empty() || spec.env_file.is_some() {
+        unit.push('\n');
+    }
+    for var in &spec.env {
+        unit.push_str(&format!("Environment=\"{}\"\n", var.replace('"', "\\\"")));
+    }
+    if let Some(ref env_file) = spec.env_file {
+        unit.push_str(&format!("EnvironmentFile={}\n", env_file.display()));
+    }
+
+    unit.push_str("\n[Install]\n");
+    if spec.user_service {
+        unit.push_str("WantedBy=default.target\n");
+    } else {
+        unit.push_str("WantedBy=multi-user.target\n");
+    }
+
+    unit
+}
+
+/// Options of `horus service install`
+#[derive(Debug, Default)]
+pub struct InstallOptions {
+    pub name: Option<String>,
+    pub user: bool,
+    pub exec: Option<String>,
+    pub env: Vec<String>,
+    pub env_file: Option<PathBuf>,
+    pub rt_priority: u8,
+    pub no_rt: bool,
+    pub watchdog: Option<u64>,
+    pub start: bool,
+    pub no_enable: bool,
+    pub print: bool,
+}
+
+/// Generate, install and enable the unit for the current project
+pub fn install(options: InstallOptions) -> HorusResult<()> {
+    let working_dir = std::env::current_dir()?;
+    let name = match options.name {
+        Some(name) => name,
+        None => default_service_name(&working_dir),
+    };
+    validate_service_name(&name)?;
+
+    for var in &options.env {
+        if !var.contains('=') {
+            return Err(HorusError::Config(format!(
+                "Invalid environment variable '{}' (expected KEY=VALUE)",
+                var
+            )));
+        }
+    }
+
+    let exec_start = match options.exec {
+        Some(exec) => exec,
+        None => {
+            let horus = std::env::current_exe()?;
+            format!("{} run --release", horus.display())
+        }
+    };
+
+    // `horus run` needs cargo and friends from the installing user's PATH
+    let mut env = Vec::new();
+    if let Ok(path) = std::env::var("PATH") {
+        env.push(format!("PATH={}", path));
+    }
+    env.extend(options.env);
+
+    let spec = ServiceSpec {
+        description: format!("HORUS robot stack ({})", name),
+        name,
+        working_dir,
+        exec_start,
+        user_service: options.user,
+        run_as: if options.user { None } else { invoking_user() },
+        rt_priority: if options.no_rt {
+            None
+        } else {
+            Some(options.rt_priority.clamp(1, 99))
+        },
+        env,
+        env_file: options.env_file,
+        watchdog_secs: options.watchdog,
+        restart_secs: 2,
+    };
+    let unit = generate_unit(&spec);
+
+    if options.print {
+        print!("{}", unit);
+        return Ok(());
+    }
+
+    let unit_dir = unit_dir(spec.user_service)?;
+    let unit_path = unit_dir.join(spec.unit_file_name());
+    std::fs::create_dir_all(&unit_dir).map_err(|e| permission_hint(e, &unit_dir))?;
+    std::fs::write(&unit_path, &unit).map_err(|e| permission_hint(e, &unit_path))?;
+    println!(
+        "{} Installed {}",
+        "".green(),
+        unit_path.display().to_string().cyan()
+    );
+
+    systemctl(spec.user_service, &["daemon-reload"])?;
+    if !options.no_enable {
+        systemctl(spec.user_service, &["enable", &spec.unit_file_name()])?;
+        println!("  {} Enabled on boot", "".green());
+    }
+    if options.start {
+        systemctl(spec.user_service, &["restart", &spec.unit_file_name()])?;
+        println!("  {} Started", "".green());
+    }
+
+    println!();
+    let scope = if spec.user_service { " --user" } else { "" };
+    println!(
+        "  {} systemctl{} status {}",
+        "Status:".dimmed(),
+        scope,
+        spec.name
+    );
+    println!(
+        "  {} journalctl{} -u {} -f",
+        "Logs:".dimmed(),
+        scope,
+        spec.name
+    );
+    if spec.user_service {
+        println!(
+            "  {} Run 'loginctl enable-linger {}' so the service starts at boot without a login",
+            "Tip:".dimmed(),
+            std::env::var("USER").unwrap_or_else(|_| "$USER".to_string())
+        );
+    }
+    if spec.watchdog_secs.is_some() {
+        println!(
+            "  {} The scheduler must use .with_health_probe(\"systemd\") to feed the watchdog",
+            "Note:".yellow()
+        );
+    }
+
+    Ok(())
+}
+
+/// Stop, disable and remove the unit
+pub fn uninstall(name: Option<String>, user: bool) -> HorusResult<()> {
+    let name = match name {
+        Some(name) => name,
+        None => default_service_name(&std::env::current_dir()?),
+    };
+    validate_service_name(&name)?;
+    let unit_file = format!("{}.service", name);
+
+    let unit_path = unit_dir(user)?.join(&unit_file);
+    if !unit_path.exists() {
+        return Err(HorusError::Config(format!(
+            "Service '{}' is not installed ({} not found)",
+            name,
+            unit_path.display()
+        )));
+    }
+
+    // Stopping a service that is not running is fine
+    let _ = systemctl(user, &["disable", "--now", &unit_file]);
+    std::fs::remove_file(&unit_path).map_err(|e| permission_hint(e, &unit_path))?;
+    systemctl(user, &["daemon-reload"])?;
+
+    println!("{} Removed service '{}'", "".green(), name);
+    Ok(())
+}
+
+/// Show `systemctl status` of the unit
+pub fn status(name: Option<String>, user: bool) -> HorusResult<()> {
+    let name = match name {
+        Some(name) => name,
+        None => default_service_name(&std::env::current_dir()?),
+    };
+    let mut cmd = Command::new("systemctl");
+    if user {
+        cmd.arg("--user");
+    }
+    // status exits non-zero for stopped units, which is not an error here
+    cmd.args(["status", "--no-pager", &format!("{}.service", name)])
+        .status()
+        .map_err(|e| HorusError::Config(format!("Failed to run systemctl: {}", e)))?;
+    Ok(())
+}
+
+fn systemctl(user: bool, args: &[&str]) -> HorusResult<()> {
+    let mut cmd = Command::new("systemctl");
+    if user {
+        cmd.arg("--user");
+    }
+    let status = cmd
+        .args(args)
+        .status()
+        .map_err(|e| HorusError::Config(format!("Failed to run systemctl: {}", e)))?;
+    if !status.success() {
+        return Err(HorusError::Config(format!(
+            "systemctl {} failed",
+            args.join(" ")
+        )));
+    }
+    Ok(())
+}
+
+fn permission_hint(e: std::io::Error, path: &Path) -> HorusError {
+    if e.kind() == std::io::ErrorKind::PermissionDenied {
+        HorusError::Config(format!(
+            "Permission denied writing {}. Run with sudo, or use --user for a user service",
+            path.display()
+        ))
+    } else {
+        HorusError::Io(e)
+    }
+}
+
+/// Project name from horus.yaml, or the directory name
+fn default_service_name(project_dir: &Path) -> String {
+    let from_yaml = std::fs::read_to_string(project_dir.join("horus.yaml"))
+        .ok()
+        .and_then(|content| serde_yaml::from_str::<serde_yaml::Value>(&content).ok())
+        .and_then(|yaml| yaml.get("name")?.as_str().map(String::from));
+    let name = from_yaml.unwrap_or_else(|| {
+        project_dir
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("robot")
+            .to_string()
+    });
+
+    let name: String = name
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+    format!("horus-{}", name.trim_matches('-'))
+}
+
+fn validate_service_name(name: &str) -> HorusResult<()> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '@'));
+    if valid {
+        Ok(())
+    } else {
+        Err(HorusError::Config(format!(
+            "Invalid service name '{}' (use letters, digits, '-', '_', '.' or '@')",
+            name
+        )))
+    }
+}
+
+/// User that ran the command, also under sudo
+fn invoking_user() -> Option<String> {
+    std::env::var("SUDO_USER")
+        .or_else(|_| std::env::var("USER"))
+        .ok()
+        .filter(|user| !user.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec() -> ServiceSpec {
+        ServiceSpec {
+            name: "horus-rover".to_string(),
+            description: "HORUS robot stack (horus-rover)".to_string(),
+            working_dir: PathBuf::from("/home/pi/rover"),
+            exec_start: "/usr/local/bin/horus run --release".to_string(),
+            user_service: false,
+            run_as: Some("pi".to_string()),
+            rt_priority: Some(90),
+            env: vec!["HORUS_ROBOT_ID=rover-1".to_string()],
+            env_file: None,
+            watchdog_secs: None,
+            restart_secs: 2,
+        }
+    }
+
+    #[test]
+    fn test_generate_system_unit() {
+        let unit = generate_unit(&spec());
+        assert!(unit.contains("User=pi\n"));
+        assert!(unit.contains("WorkingDirectory=/home/pi/rover\n"));
+        assert!(unit.contains("ExecStart=/usr/local/bin/horus run --release\n"));
+        assert!(unit.contains("Restart=on-failure\n"));
+        assert!(unit.contains("LimitRTPRIO=90\n"));
+        assert!(unit.contains("LimitMEMLOCK=infinity\n"));
+        assert!(unit.contains("Environment=\"HORUS_ROBOT_ID=rover-1\"\n"));
+        assert!(unit.contains("WantedBy=multi-user.target\n"));
+        assert!(unit.contains("Type=simple\n"));
+    }
+
+    #[test]
+    fn test_generate_user_unit_with_watchdog() {
+        let unit = generate_unit(&ServiceSpec {
+            user_service: true,
+            run_as: None,
+            rt_priority: None,
+            watchdog_secs: Some(10),
+            ..spec()
+        });
+        assert!(!unit.contains("User="));
+        assert!(!unit.contains("LimitRTPRIO"));
+        assert!(unit.contains("Type=notify\n"));
+        assert!(unit.contains("WatchdogSec=10\n"));
+        assert!(unit.contains("WantedBy=default.target\n"));
+    }
+
+    #[test]
+    fn test_default_service_name() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("horus.yaml"), "name: My Rover\n").unwrap();
+        assert_eq!(default_service_name(dir.path()), "horus-my-rover");
+        assert!(validate_service_name("horus-my-rover").is_ok());
+        assert!(validate_service_name("bad name").is_err());
+    }
+}
@@ -0,0 +1,1327 @@
+//! Project templates for `horus new --template`
+//!
+//! Each template is a small but complete robot: nodes wired through typed
+//! topics, the topic list for horus.yaml, a simulator world and tests, in
+//! Rust and in Python. Topics marked external are published or consumed by
+//! the simulator (or the hardware drivers that replace it), not by the
+//! project's own nodes.
+
+/// Robot archetype of a new project
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Template {
+    /// Wheeled robot avoiding obstacles with a 2D lidar
+    DiffDrive,
+    /// Two-link planar arm reaching waypoints with inverse kinematics
+    Arm,
+    /// Four-legged robot walking with a trot gait
+    Quadruped,
+    /// Camera frames through a detector and a tracker
+    PerceptionPipeline,
+}
+
+/// Topic declared in the generated horus.yaml
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TemplateTopic {
+    pub name: &'static str,
+    pub type_name: &'static str,
+    /// Published or subscribed by the simulator rather than the project
+    pub external: bool,
+}
+
+const fn topic(name: &'static str, type_name: &'static str) -> TemplateTopic {
+    TemplateTopic {
+        name,
+        type_name,
+        external: false,
+    }
+}
+
+const fn external(name: &'static str, type_name: &'static str) -> TemplateTopic {
+    TemplateTopic {
+        name,
+        type_name,
+        external: true,
+    }
+}
+
+const DIFF_DRIVE_TOPICS: &[TemplateTopic] = &[
+    external("robot.scan", "LaserScan"),
+    external("robot.odom", "Odometry"),
+    external("robot.cmd_vel", "CmdVel"),
+];
+
+const ARM_TOPICS: &[TemplateTopic] = &[
+    external("arm.joint_cmd", "JointCommand"),
+    topic("arm.target", "Pose2D"),
+];
+
+const QUADRUPED_TOPICS: &[TemplateTopic] = &[
+    topic("quadruped.cmd_vel", "CmdVel"),
+    external("quadruped.imu", "Imu"),
+    external("quadruped.joint_cmd", "JointCommand"),
+];
+
+const PERCEPTION_TOPICS: &[TemplateTopic] = &[
+    topic("camera.image", "Image"),
+    topic("perception.detections", "Detection"),
+    external("perception.tracks", "Detection"),
+];
+
+impl Template {
+    pub fn name(self) -> &'static str {
+        match self {
+            Template::DiffDrive => "diff-drive",
+            Template::Arm => "arm",
+            Template::Quadruped => "quadruped",
+            Template::PerceptionPipeline => "perception-pipeline",
+        }
+    }
+
+    /// Description written to horus.yaml
+    pub fn description(self) -> &'static str {
+        match self {
+            Template::DiffDrive => "Differential-drive robot that wanders around obstacles",
+            Template::Arm => "Two-link arm reaching waypoints with inverse kinematics",
+            Template::Quadruped => "Quadruped walking with a trot gait",
+            Template::PerceptionPipeline => "Camera, detector and tracker pipeline",
+        }
+    }
+
+    pub fn topics(self) -> &'static [TemplateTopic] {
+        match self {
+            Template::DiffDrive => DIFF_DRIVE_TOPICS,
+            Template::Arm => ARM_TOPICS,
+            Template::Quadruped => QUADRUPED_TOPICS,
+            Template::PerceptionPipeline => PERCEPTION_TOPICS,
+        }
+    }
+
+    /// Command starting the simulator on the template's world
+    pub fn sim_command(self) -> &'static str {
+        match self {
+            Template::DiffDrive => "horus sim2d --world world.yaml",
+            Template::Arm => "horus sim2d --world world.yaml --preset arm_2dof",
+            Template::Quadruped => "horus sim3d --world world.yaml --robot-name quadruped",
+            Template::PerceptionPipeline => "horus sim3d --world world.yaml",
+        }
+    }
+
+    /// Files of the template as (path relative to the project, content)
+    pub fn files(self, language: &str) -> Vec<(&'static str, &'static str)> {
+        let mut files = match (self, language) {
+            (Template::DiffDrive, "python") => vec![
+                ("main.py", DIFF_DRIVE_PY),
+                ("test_main.py", DIFF_DRIVE_TEST_PY),
+            ],
+            (Template::DiffDrive, _) => vec![("main.rs", DIFF_DRIVE_RS)],
+            (Template::Arm, "python") => {
+                vec![("main.py", ARM_PY), ("test_main.py", ARM_TEST_PY)]
+            }
+            (Template::Arm, _) => vec![("main.rs", ARM_RS)],
+            (Template::Quadruped, "python") => vec![
+                ("main.py", QUADRUPED_PY),
+                ("test_main.py", QUADRUPED_TEST_PY),
+            ],
+            (Template::Quadruped, _) => vec![("main.rs", QUADRUPED_RS)],
+            (Template::PerceptionPipeline, "python") => vec![
+                ("main.py", PERCEPTION_PY),
+                ("test_main.py", PERCEPTION_TEST_PY),
+            ],
+            (Template::PerceptionPipeline, _) => vec![("main.rs", PERCEPTION_RS)],
+        };
+        files.push((
+            "world.yaml",
+            match self {
+                Template::DiffDrive => DIFF_DRIVE_WORLD,
+                Template::Arm => ARM_WORLD,
+                Template::Quadruped => QUADRUPED_WORLD,
+                Template::PerceptionPipeline => PERCEPTION_WORLD,
+            },
+        ));
+        files
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// diff-drive
+// ═══════════════════════════════════════════════════════════════════════════
+
+const DIFF_DRIVE_RS: &str = r#"// Differential-drive robot that wanders around obstacles
+//
+// Start the simulator in another terminal:
+//   horus sim2d --world world.yaml
+// then `horus run`. `horus test` runs the tests at the bottom of this file.
+
+use horus::prelude::*;
+
+/// Obstacles closer than this (meters) block the way
+const SAFE_DISTANCE: f32 = 0.8;
+/// Forward speed (m/s)
+const CRUISE_SPEED: f32 = 0.5;
+/// Turning speed (rad/s)
+const TURN_SPEED: f32 = 1.0;
+
+/// Velocity command for a scan whose middle reading points straight ahead:
+/// drive while the front is clear, otherwise turn toward the open side
+fn steer(ranges: &[f32]) -> CmdVel {
+    let closest = |from: usize, to: usize| {
+        ranges[from..to]
+            .iter()
+            .copied()
+            .filter(|range| *range > 0.0)
+            .fold(f32::INFINITY, f32::min)
+    };
+    let n = ranges.len();
+    let front = closest(n / 2 - n / 12, n / 2 + n / 12);
+    if front > SAFE_DISTANCE {
+        return CmdVel::new(CRUISE_SPEED, 0.0);
+    }
+    let right = closest(n / 4, n / 2);
+    let left = closest(n / 2, 3 * n / 4);
+    let turn = if left > right { TURN_SPEED } else { -TURN_SPEED };
+    CmdVel::new(0.0, turn)
+}
+
+/// Turns lidar scans into velocity commands
+struct Wanderer {
+    scan: Hub<LaserScan>,
+    cmd_vel: Hub<CmdVel>,
+}
+
+impl Wanderer {
+    fn new() -> HorusResult<Self> {
+        Ok(Self {
+            scan: Hub::new("robot.scan")?,
+            cmd_vel: Hub::new("robot.cmd_vel")?,
+        })
+    }
+}
+
+impl Node for Wanderer {
+    fn name(&self) -> &'static str {
+        "wanderer"
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        if let Some(scan) = self.scan.recv(&mut ctx) {
+            self.cmd_vel.send(steer(&scan.ranges), &mut ctx).ok();
+        }
+    }
+}
+
+/// Logs the distance driven, from odometry
+struct OdomMonitor {
+    odom: Hub<Odometry>,
+    last: Option<Pose2D>,
+    distance: f64,
+}
+
+impl OdomMonitor {
+    fn new() -> HorusResult<Self> {
+        Ok(Self {
+            odom: Hub::new("robot.odom")?,
+            last: None,
+            distance: 0.0,
+        })
+    }
+}
+
+impl Node for OdomMonitor {
+    fn name(&self) -> &'static str {
+        "odom_monitor"
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let Some(odom) = self.odom.recv(&mut ctx) else {
+            return;
+        };
+        if let Some(last) = self.last {
+            let step = last.distance_to(&odom.pose);
+            // Log every meter
+            if (self.distance + step).floor() > self.distance.floor() {
+                if let Some(ctx) = ctx.as_deref() {
+                    ctx.log_info(&format!("driven {:.0} m", self.distance + step));
+                }
+            }
+            self.distance += step;
+        }
+        self.last = Some(odom.pose);
+    }
+}
+
+fn main() -> HorusResult<()> {
+    let mut scheduler = Scheduler::new();
+    scheduler.add(Box::new(Wanderer::new()?), 0, Some(true));
+    scheduler.add(Box::new(OdomMonitor::new()?), 1, Some(false));
+    scheduler.run()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drives_forward_when_clear() {
+        let cmd = steer(&[5.0; 360]);
+        assert!(cmd.linear > 0.0);
+        assert_eq!(cmd.angular, 0.0);
+    }
+
+    #[test]
+    fn turns_away_from_obstacles() {
+        let mut ranges = [5.0; 360];
+        // Wall ahead and on the left
+        for range in &mut ranges[160..270] {
+            *range = 0.5;
+        }
+        let cmd = steer(&ranges);
+        assert_eq!(cmd.linear, 0.0);
+        assert!(cmd.angular < 0.0);
+    }
+}
+"#;
+
+const DIFF_DRIVE_PY: &str = r#"# Differential-drive robot that wanders around obstacles
+#
+# Start the simulator in another terminal:
+#   horus sim2d --world world.yaml
+# then `horus run`. `python -m pytest` runs test_main.py.
+
+import horus
+
+SAFE_DISTANCE = 0.8  # Obstacles closer than this (meters) block the way
+CRUISE_SPEED = 0.5   # m/s
+TURN_SPEED = 1.0     # rad/s
+
+
+def closest(ranges):
+    valid = [r for r in ranges if r > 0.0]
+    return min(valid) if valid else float("inf")
+
+
+def steer(ranges):
+    """Velocity command for a scan whose middle reading points straight ahead:
+    drive while the front is clear, otherwise turn toward the open side."""
+    n = len(ranges)
+    front = closest(ranges[n // 2 - n // 12:n // 2 + n // 12])
+    if front > SAFE_DISTANCE:
+        return {"linear": CRUISE_SPEED, "angular": 0.0}
+    right = closest(ranges[n // 4:n // 2])
+    left = closest(ranges[n // 2:3 * n // 4])
+    return {"linear": 0.0, "angular": TURN_SPEED if left > right else -TURN_SPEED}
+
+
+def wander(node):
+    if node.has_msg("robot.scan"):
+        scan = node.get("robot.scan")
+        node.send("robot.cmd_vel", steer(list(scan.ranges)))
+
+
+odometry = {"last": None, "distance": 0.0}
+
+
+def track_distance(node):
+    """Log the distance driven, every meter."""
+    if not node.has_msg("robot.odom"):
+        return
+    pose = node.get("robot.odom").pose
+    last = odometry["last"]
+    if last is not None:
+        step = ((pose.x - last[0]) ** 2 + (pose.y - last[1]) ** 2) ** 0.5
+        if int(odometry["distance"] + step) > int(odometry["distance"]):
+            print("driven %d m" % (odometry["distance"] + step))
+        odometry["distance"] += step
+    odometry["last"] = (pose.x, pose.y)
+
+
+def main():
+    wanderer = horus.Node(
+        name="wanderer",
+        subs=["robot.scan"],
+        pubs=["robot.cmd_vel"],
+        tick=wander,
+        rate=20,
+    )
+    monitor = horus.Node(name="odom_monitor", subs=["robot.odom"], tick=track_distance, rate=10)
+    horus.run(wanderer, monitor)
+
+
+if __name__ == "__main__":
+    main()
+"#;
+
+const DIFF_DRIVE_TEST_PY: &str = r#"from main import steer
+
+
+def test_drives_forward_when_clear():
+    cmd = steer([5.0] * 360)
+    assert cmd["linear"] > 0.0
+    assert cmd["angular"] == 0.0
+
+
+def test_turns_away_from_obstacles():
+    ranges = [5.0] * 360
+    # Wall ahead and on the left
+    ranges[160:270] = [0.5] * 110
+    cmd = steer(ranges)
+    assert cmd["linear"] == 0.0
+    assert cmd["angular"] < 0.0
+"#;
+
+const DIFF_DRIVE_WORLD: &str = r#"# sim2d world: a walled room with a few obstacles
+#   horus sim2d --world world.yaml
+
+width: 12.0
+height: 10.0
+
+obstacles:
+  # Outer walls
+  - pos: [6.0, 0.1]
+    size: [12.0, 0.2]
+    shape: rectangle
+  - pos: [6.0, 9.9]
+    size: [12.0, 0.2]
+    shape: rectangle
+  - pos: [0.1, 5.0]
+    size: [0.2, 10.0]
+    shape: rectangle
+  - pos: [11.9, 5.0]
+    size: [0.2, 10.0]
+    shape: rectangle
+
+  # Furniture
+  - pos: [4.0, 6.5]
+    size: [2.0, 1.0]
+    shape: rectangle
+    color: [0.6, 0.4, 0.2]
+  - pos: [8.5, 3.0]
+    size: [1.2, 1.2]
+    shape: circle
+    color: [0.5, 0.5, 0.5]
+  - pos: [8.0, 7.5]
+    size: [0.2, 3.0]
+    shape: rectangle
+    color: [0.3, 0.3, 0.3]
+"#;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// arm
+// ═══════════════════════════════════════════════════════════════════════════
+
+const ARM_RS: &str = r#"// Two-link planar arm reaching a list of waypoints
+//
+// Start the simulator in another terminal:
+//   horus sim2d --world world.yaml --preset arm_2dof
+// then `horus run`. `horus test` runs the tests at the bottom of this file.
+
+use horus::prelude::*;
+use std::f64::consts::FRAC_PI_2;
+
+/// Link lengths (meters), matching the arm_2dof preset
+const UPPER_ARM: f64 = 0.4;
+const FOREARM: f64 = 0.35;
+/// Joint limits (radians)
+const JOINT_LIMIT: f64 = FRAC_PI_2;
+/// Wrist targets (meters, in the base frame), visited in a loop
+const WAYPOINTS: [(f64, f64); 4] = [(0.6, 0.0), (0.4, 0.4), (0.2, 0.5), (0.5, -0.3)];
+/// Time spent at each waypoint
+const DWELL: Duration = Duration::from_secs(2);
+
+/// Wrist position for the joint angles
+fn forward_kinematics(shoulder: f64, elbow: f64) -> (f64, f64) {
+    (
+        UPPER_ARM * shoulder.cos() + FOREARM * (shoulder + elbow).cos(),
+        UPPER_ARM * shoulder.sin() + FOREARM * (shoulder + elbow).sin(),
+    )
+}
+
+/// Joint angles placing the wrist at (x, y), or None when the point is out
+/// of reach or outside the joint limits
+fn inverse_kinematics(x: f64, y: f64) -> Option<(f64, f64)> {
+    let cos_elbow = (x * x + y * y - UPPER_ARM.powi(2) - FOREARM.powi(2))
+        / (2.0 * UPPER_ARM * FOREARM);
+    if !(-1.0..=1.0).contains(&cos_elbow) {
+        return None;
+    }
+    // Try elbow-down, then elbow-up
+    [cos_elbow.acos(), -cos_elbow.acos()]
+        .into_iter()
+        .map(|elbow| {
+            let shoulder =
+                y.atan2(x) - (FOREARM * elbow.sin()).atan2(UPPER_ARM + FOREARM * elbow.cos());
+            (shoulder, elbow)
+        })
+        .find(|(shoulder, elbow)| shoulder.abs() <= JOINT_LIMIT && elbow.abs() <= JOINT_LIMIT)
+}
+
+/// Publishes the current waypoint as the target
+struct WaypointSequencer {
+    target: Hub<Pose2D>,
+    index: usize,
+    since: Instant,
+}
+
+impl WaypointSequencer {
+    fn new() -> HorusResult<Self> {
+        Ok(Self {
+            target: Hub::new("arm.target")?,
+            index: 0,
+            since: Instant::now(),
+        })
+    }
+}
+
+impl Node for WaypointSequencer {
+    fn name(&self) -> &'static str {
+        "waypoint_sequencer"
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        if self.since.elapsed() >= DWELL {
+            self.index = (self.index + 1) % WAYPOINTS.len();
+            self.since = Instant::now();
+        }
+        let (x, y) = WAYPOINTS[self.index];
+        let target = Pose2D {
+            x,
+            y,
+            ..Default::default()
+        };
+        self.target.send(target, &mut ctx).ok();
+    }
+}
+
+/// Turns targets into joint position commands
+struct ReachController {
+    target: Hub<Pose2D>,
+    joint_cmd: Hub<JointCommand>,
+}
+
+impl ReachController {
+    fn new() -> HorusResult<Self> {
+        Ok(Self {
+            target: Hub::new("arm.target")?,
+            joint_cmd: Hub::new("arm.joint_cmd")?,
+        })
+    }
+}
+
+impl Node for ReachController {
+    fn name(&self) -> &'static str {
+        "reach_controller"
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let Some(target) = self.target.recv(&mut ctx) else {
+            return;
+        };
+        match inverse_kinematics(target.x, target.y) {
+            Some((shoulder, elbow)) => {
+                let mut cmd = JointCommand::new();
+                cmd.add_position("shoulder", shoulder).ok();
+                cmd.add_position("elbow", elbow).ok();
+                self.joint_cmd.send(cmd, &mut ctx).ok();
+            }
+            None => {
+                if let Some(ctx) = ctx.as_deref_mut() {
+                    ctx.log_warning(&format!(
+                        "target ({:.2}, {:.2}) is out of reach",
+                        target.x, target.y
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn main() -> HorusResult<()> {
+    let mut scheduler = Scheduler::new();
+    scheduler.add(Box::new(WaypointSequencer::new()?), 0, Some(false));
+    scheduler.add(Box::new(ReachController::new()?), 1, Some(true));
+    scheduler.run()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reaches_every_waypoint() {
+        for (x, y) in WAYPOINTS {
+            let (shoulder, elbow) = inverse_kinematics(x, y).expect("waypoint in reach");
+            let (wx, wy) = forward_kinematics(shoulder, elbow);
+            assert!((wx - x).abs() < 1e-9 && (wy - y).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn rejects_unreachable_targets() {
+        assert!(inverse_kinematics(1.0, 0.0).is_none());
+        assert!(inverse_kinematics(0.01, 0.0).is_none());
+    }
+}
+"#;
+
+const ARM_PY: &str = r#"# Two-link planar arm reaching a list of waypoints
+#
+# Start the simulator in another terminal:
+#   horus sim2d --world world.yaml --preset arm_2dof
+# then `horus run`. `python -m pytest` runs test_main.py.
+
+import math
+import time
+
+import horus
+
+UPPER_ARM = 0.4  # Link lengths (meters), matching the arm_2dof preset
+FOREARM = 0.35
+JOINT_LIMIT = math.pi / 2
+WAYPOINTS = [(0.6, 0.0), (0.4, 0.4), (0.2, 0.5), (0.5, -0.3)]
+DWELL = 2.0  # Seconds at each waypoint
+
+
+def forward_kinematics(shoulder, elbow):
+    return (
+        UPPER_ARM * math.cos(shoulder) + FOREARM * math.cos(shoulder + elbow),
+        UPPER_ARM * math.sin(shoulder) + FOREARM * math.sin(shoulder + elbow),
+    )
+
+
+def inverse_kinematics(x, y):
+    """Joint angles placing the wrist at (x, y), or None when the point is out
+    of reach or outside the joint limits."""
+    cos_elbow = (x * x + y * y - UPPER_ARM ** 2 - FOREARM ** 2) / (2 * UPPER_ARM * FOREARM)
+    if not -1.0 <= cos_elbow <= 1.0:
+        return None
+    # Try elbow-down, then elbow-up
+    for elbow in (math.acos(cos_elbow), -math.acos(cos_elbow)):
+        shoulder = math.atan2(y, x) - math.atan2(
+            FOREARM * math.sin(elbow), UPPER_ARM + FOREARM * math.cos(elbow)
+        )
+        if abs(shoulder) <= JOINT_LIMIT and abs(elbow) <= JOINT_LIMIT:
+            return shoulder, elbow
+    return None
+
+
+def sequence(node):
+    index = int(time.monotonic() / DWELL) % len(WAYPOINTS)
+    x, y = WAYPOINTS[index]
+    node.send("arm.target", {"x": x, "y": y})
+
+
+def reach(node):
+    if not node.has_msg("arm.target"):
+        return
+    target = node.get("arm.target")
+    joints = inverse_kinematics(target["x"], target["y"])
+    if joints is None:
+        print("target (%.2f, %.2f) is out of reach" % (target["x"], target["y"]))
+        return
+    node.send("arm.joint_cmd", {"name": ["shoulder", "elbow"], "position": list(joints)})
+
+
+def main():
+    sequencer = horus.Node(name="waypoint_sequencer", pubs=["arm.target"], tick=sequence, rate=10)
+    controller = horus.Node(
+        name="reach_controller",
+        subs=["arm.target"],
+        pubs=["arm.joint_cmd"],
+        tick=reach,
+        rate=10,
+    )
+    horus.run(sequencer, controller)
+
+
+if __name__ == "__main__":
+    main()
+"#;
+
+const ARM_TEST_PY: &str = r#"from main import WAYPOINTS, forward_kinematics, inverse_kinematics
+
+
+def test_reaches_every_waypoint():
+    for x, y in WAYPOINTS:
+        joints = inverse_kinematics(x, y)
+        assert joints is not None
+        wx, wy = forward_kinematics(*joints)
+        assert abs(wx - x) < 1e-9 and abs(wy - y) < 1e-9
+
+
+def test_rejects_unreachable_targets():
+    assert inverse_kinematics(1.0, 0.0) is None
+    assert inverse_kinematics(0.01, 0.0) is None
+"#;
+
+const ARM_WORLD: &str = r#"# sim2d world: a table with the objects the arm reaches for
+#   horus sim2d --world world.yaml --preset arm_2dof
+
+width: 4.0
+height: 3.0
+
+obstacles:
+  # Table edge below the arm base
+  - pos: [2.0, 0.9]
+    size: [3.0, 0.1]
+    shape: rectangle
+    color: [0.6, 0.4, 0.2]
+
+  # Objects at the waypoints
+  - pos: [2.6, 1.5]
+    size: [0.08, 0.08]
+    shape: circle
+    color: [1.0, 0.2, 0.2]
+  - pos: [2.4, 1.9]
+    size: [0.08, 0.08]
+    shape: circle
+    color: [0.2, 0.8, 0.2]
+  - pos: [2.2, 2.0]
+    size: [0.08, 0.08]
+    shape: circle
+    color: [0.2, 0.4, 1.0]
+"#;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// quadruped
+// ═══════════════════════════════════════════════════════════════════════════
+
+const QUADRUPED_RS: &str = r#"// Quadruped walking with a trot gait
+//
+// Start the simulator in another terminal:
+//   horus sim3d --world world.yaml --robot-name quadruped
+// then `horus run`. `horus test` runs the tests at the bottom of this file.
+
+use horus::prelude::*;
+use std::f64::consts::{PI, TAU};
+
+const LEGS: [&str; 4] = ["front_left", "front_right", "rear_left", "rear_right"];
+/// Steps per second
+const STEP_RATE: f64 = 2.0;
+/// Hip swing and knee lift at full stride (radians)
+const HIP_SWING: f64 = 0.4;
+const KNEE_LIFT: f64 = 0.6;
+/// Speed (m/s) that maps to a full stride
+const MAX_SPEED: f64 = 0.5;
+/// Body tilt (radians) at which the robot stops and stands
+const MAX_TILT: f64 = 0.5;
+
+/// (hip, knee) angles of each leg in a trot: diagonal legs move together,
+/// a leg lifts its knee while swinging forward
+fn trot(phase: f64, stride: f64) -> [(f64, f64); 4] {
+    let mut angles = [(0.0, 0.0); 4];
+    for (leg, angle) in angles.iter_mut().enumerate() {
+        // front_left + rear_right, then front_right + rear_left
+        let offset = if leg == 0 || leg == 3 { 0.0 } else { PI };
+        let leg_phase = phase + offset;
+        let hip = stride * HIP_SWING * leg_phase.sin();
+        let knee = stride.abs() * KNEE_LIFT * leg_phase.cos().max(0.0);
+        *angle = (hip, knee);
+    }
+    angles
+}
+
+/// Angle between the body's up axis and vertical, from an IMU quaternion [x, y, z, w]
+fn tilt(orientation: [f64; 4]) -> f64 {
+    let [x, y, _, _] = orientation;
+    (1.0 - 2.0 * (x * x + y * y)).clamp(-1.0, 1.0).acos()
+}
+
+/// Sends a constant walking command; replace with teleop or a planner
+struct Commander {
+    cmd_vel: Hub<CmdVel>,
+}
+
+impl Node for Commander {
+    fn name(&self) -> &'static str {
+        "commander"
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        self.cmd_vel.send(CmdVel::new(0.3, 0.0), &mut ctx).ok();
+    }
+}
+
+/// Turns velocity commands into joint positions
+struct GaitGenerator {
+    cmd_vel: Hub<CmdVel>,
+    imu: Hub<Imu>,
+    joint_cmd: Hub<JointCommand>,
+    stride: f64,
+    upright: bool,
+    start: Instant,
+}
+
+impl GaitGenerator {
+    fn new() -> HorusResult<Self> {
+        Ok(Self {
+            cmd_vel: Hub::new("quadruped.cmd_vel")?,
+            imu: Hub::new("quadruped.imu")?,
+            joint_cmd: Hub::new("quadruped.joint_cmd")?,
+            stride: 0.0,
+            upright: true,
+            start: Instant::now(),
+        })
+    }
+}
+
+impl Node for GaitGenerator {
+    fn name(&self) -> &'static str {
+        "gait_generator"
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        if let Some(cmd) = self.cmd_vel.recv(&mut ctx) {
+            self.stride = (f64::from(cmd.linear) / MAX_SPEED).clamp(-1.0, 1.0);
+        }
+        if let Some(imu) = self.imu.recv(&mut ctx) {
+            let upright = tilt(imu.orientation) < MAX_TILT;
+            if self.upright && !upright {
+                if let Some(ctx) = ctx.as_deref_mut() {
+                    ctx.log_warning("body tilted, standing still");
+                }
+            }
+            self.upright = upright;
+        }
+
+        let stride = if self.upright { self.stride } else { 0.0 };
+        let phase = self.start.elapsed().as_secs_f64() * STEP_RATE * TAU;
+        let mut cmd = JointCommand::new();
+        for (leg, (hip, knee)) in LEGS.iter().zip(trot(phase, stride)) {
+            cmd.add_position(&format!("{}_hip", leg), hip).ok();
+            cmd.add_position(&format!("{}_knee", leg), knee).ok();
+        }
+        self.joint_cmd.send(cmd, &mut ctx).ok();
+    }
+}
+
+fn main() -> HorusResult<()> {
+    let mut scheduler = Scheduler::new();
+    scheduler.add(
+        Box::new(Commander {
+            cmd_vel: Hub::new("quadruped.cmd_vel")?,
+        }),
+        0,
+        Some(false),
+    );
+    scheduler.add(Box::new(GaitGenerator::new()?), 1, Some(true));
+    scheduler.run()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diagonal_legs_move_together() {
+        for step in 0..16 {
+            let angles = trot(step as f64 * TAU / 16.0, 1.0);
+            assert_eq!(angles[0], angles[3]);
+            assert_eq!(angles[1], angles[2]);
+        }
+    }
+
+    #[test]
+    fn zero_stride_stands_still() {
+        assert!(trot(1.0, 0.0)
+            .iter()
+            .all(|(hip, knee)| *hip == 0.0 && *knee == 0.0));
+    }
+
+    #[test]
+    fn tilt_from_quaternion() {
+        assert!(tilt([0.0, 0.0, 0.0, 1.0]).abs() < 1e-9);
+        // 90 degrees around x
+        let half = (PI / 4.0).sin();
+        assert!((tilt([half, 0.0, 0.0, half]) - PI / 2.0).abs() < 1e-9);
+    }
+}
+"#;
+
+const QUADRUPED_PY: &str = r#"# Quadruped walking with a trot gait
+#
+# Start the simulator in another terminal:
+#   horus sim3d --world world.yaml --robot-name quadruped
+# then `horus run`. `python -m pytest` runs test_main.py.
+
+import math
+import time
+
+import horus
+
+LEGS = ["front_left", "front_right", "rear_left", "rear_right"]
+STEP_RATE = 2.0   # Steps per second
+HIP_SWING = 0.4   # Radians at full stride
+KNEE_LIFT = 0.6
+MAX_SPEED = 0.5   # Speed (m/s) that maps to a full stride
+MAX_TILT = 0.5    # Body tilt (radians) at which the robot stands still
+
+START = time.monotonic()
+state = {"stride": 0.0, "upright": True}
+
+
+def trot(phase, stride):
+    """(hip, knee) angles of each leg: diagonal legs move together, a leg
+    lifts its knee while swinging forward."""
+    angles = []
+    for leg in range(4):
+        offset = 0.0 if leg in (0, 3) else math.pi
+        leg_phase = phase + offset
+        hip = stride * HIP_SWING * math.sin(leg_phase)
+        knee = abs(stride) * KNEE_LIFT * max(math.cos(leg_phase), 0.0)
+        angles.append((hip, knee))
+    return angles
+
+
+def tilt(orientation):
+    """Angle between the body's up axis and vertical, from a quaternion [x, y, z, w]."""
+    x, y = orientation[0], orientation[1]
+    return math.acos(max(-1.0, min(1.0, 1.0 - 2.0 * (x * x + y * y))))
+
+
+def command(node):
+    # Constant walking command; replace with teleop or a planner
+    node.send("quadruped.cmd_vel", {"linear": 0.3, "angular": 0.0})
+
+
+def walk(node):
+    if node.has_msg("quadruped.cmd_vel"):
+        cmd = node.get("quadruped.cmd_vel")
+        state["stride"] = max(-1.0, min(1.0, cmd["linear"] / MAX_SPEED))
+    if node.has_msg("quadruped.imu"):
+        state["upright"] = tilt(node.get("quadruped.imu").orientation) < MAX_TILT
+
+    stride = state["stride"] if state["upright"] else 0.0
+    phase = (time.monotonic() - START) * STEP_RATE * 2 * math.pi
+    names, positions = [], []
+    for leg, (hip, knee) in zip(LEGS, trot(phase, stride)):
+        names += [leg + "_hip", leg + "_knee"]
+        positions += [hip, knee]
+    node.send("quadruped.joint_cmd", {"name": names, "position": positions})
+
+
+def main():
+    commander = horus.Node(name="commander", pubs=["quadruped.cmd_vel"], tick=command, rate=10)
+    gait = horus.Node(
+        name="gait_generator",
+        subs=["quadruped.cmd_vel", "quadruped.imu"],
+        pubs=["quadruped.joint_cmd"],
+        tick=walk,
+        rate=100,
+    )
+    horus.run(commander, gait)
+
+
+if __name__ == "__main__":
+    main()
+"#;
+
+const QUADRUPED_TEST_PY: &str = r#"import math
+
+from main import tilt, trot
+
+
+def test_diagonal_legs_move_together():
+    for step in range(16):
+        angles = trot(step * 2 * math.pi / 16, 1.0)
+        assert angles[0] == angles[3]
+        assert angles[1] == angles[2]
+
+
+def test_zero_stride_stands_still():
+    assert all(hip == 0.0 and knee == 0.0 for hip, knee in trot(1.0, 0.0))
+
+
+def test_tilt_from_quaternion():
+    assert abs(tilt([0.0, 0.0, 0.0, 1.0])) < 1e-9
+    half = math.sin(math.pi / 4)
+    assert abs(tilt([half, 0.0, 0.0, half]) - math.pi / 2) < 1e-9
+"#;
+
+const QUADRUPED_WORLD: &str = r#"# sim3d world: flat ground with a gentle ramp and a few steps
+#   horus sim3d --world world.yaml --robot-name quadruped
+
+name: "Quadruped Playground"
+description: "Flat ground, a ramp and low steps to walk over"
+gravity: -9.81
+
+objects:
+  - name: "ground"
+    shape: {type: ground, size_x: 20.0, size_z: 20.0}
+    position: [0.0, 0.0, 0.0]
+    is_static: true
+    friction: 0.9
+    color: [0.4, 0.5, 0.4]
+
+  - name: "ramp"
+    shape: {type: box, size: [2.0, 0.1, 1.5]}
+    position: [3.0, 0.15, 0.0]
+    rotation: [0.0, 0.0, 0.15]
+    is_static: true
+    friction: 0.9
+    color: [0.6, 0.6, 0.6]
+
+  - name: "step_1"
+    shape: {type: box, size: [0.4, 0.05, 1.5]}
+    position: [-2.0, 0.025, 0.0]
+    is_static: true
+    color: [0.7, 0.5, 0.3]
+
+  - name: "step_2"
+    shape: {type: box, size: [0.4, 0.1, 1.5]}
+    position: [-2.4, 0.05, 0.0]
+    is_static: true
+    color: [0.7, 0.5, 0.3]
+"#;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// perception-pipeline
+// ═══════════════════════════════════════════════════════════════════════════
+
+const PERCEPTION_RS: &str = r#"// Perception pipeline: camera -> detector -> tracker
+//
+// SyntheticCamera draws a bright square moving over a dark background; swap
+// it for a camera driver publishing "camera.image". The simulator world has
+// objects for a simulated camera:
+//   horus sim3d --world world.yaml
+// `horus test` runs the tests at the bottom of this file.
+
+use horus::prelude::*;
+
+const WIDTH: u32 = 160;
+const HEIGHT: u32 = 120;
+/// Pixels brighter than this belong to an object
+const THRESHOLD: u8 = 128;
+/// Detections whose centers are closer than this (pixels) keep their track
+const MAX_JUMP: f64 = 20.0;
+
+/// Bounding box of the pixels brighter than THRESHOLD in a mono image
+fn detect(width: u32, pixels: &[u8]) -> Option<RegionOfInterest> {
+    let (mut min_x, mut min_y, mut max_x, mut max_y) = (u32::MAX, u32::MAX, 0, 0);
+    for (i, pixel) in pixels.iter().enumerate() {
+        if *pixel > THRESHOLD {
+            let (x, y) = (i as u32 % width, i as u32 / width);
+            min_x = min_x.min(x);
+            min_y = min_y.min(y);
+            max_x = max_x.max(x);
+            max_y = max_y.max(y);
+        }
+    }
+    (min_x <= max_x).then(|| RegionOfInterest::new(min_x, min_y, max_x - min_x + 1, max_y - min_y + 1))
+}
+
+fn center(roi: &RegionOfInterest) -> (f64, f64) {
+    (
+        roi.x_offset as f64 + roi.width as f64 / 2.0,
+        roi.y_offset as f64 + roi.height as f64 / 2.0,
+    )
+}
+
+/// Publishes frames with a moving bright square
+struct SyntheticCamera {
+    image: Hub<Image>,
+    frame: Vec<u8>,
+    step: u32,
+}
+
+impl Node for SyntheticCamera {
+    fn name(&self) -> &'static str {
+        "synthetic_camera"
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        self.frame.fill(0);
+        let left = self.step % (WIDTH - 20);
+        for y in 50..70 {
+            let row = (y * WIDTH + left) as usize;
+            self.frame[row..row + 20].fill(255);
+        }
+        self.step += 1;
+        let image = Image::new(WIDTH, HEIGHT, ImageEncoding::Mono8, self.frame.clone());
+        self.image.send(image, &mut ctx).ok();
+    }
+}
+
+/// Finds the bright object in each frame
+struct Detector {
+    image: Hub<Image>,
+    detections: Hub<Detection>,
+}
+
+impl Node for Detector {
+    fn name(&self) -> &'static str {
+        "detector"
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let Some(image) = self.image.recv(&mut ctx) else {
+            return;
+        };
+        if let Some(roi) = detect(image.width, &image.data) {
+            self.detections
+                .send(Detection::new("object", 1.0, roi), &mut ctx)
+                .ok();
+        }
+    }
+}
+
+/// Gives detections a track id, kept while the object moves smoothly
+struct Tracker {
+    detections: Hub<Detection>,
+    tracks: Hub<Detection>,
+    last: Option<(f64, f64)>,
+    next_id: u32,
+}
+
+impl Node for Tracker {
+    fn name(&self) -> &'static str {
+        "tracker"
+    }
+
+    fn tick(&mut self, mut ctx: Option<&mut NodeInfo>) {
+        let Some(mut detection) = self.detections.recv(&mut ctx) else {
+            return;
+        };
+        let (x, y) = center(&detection.bbox);
+        let same = self
+            .last
+            .is_some_and(|(lx, ly)| (x - lx).hypot(y - ly) < MAX_JUMP);
+        if !same {
+            self.next_id += 1;
+        }
+        self.last = Some((x, y));
+        detection.track_id = self.next_id;
+        self.tracks.send(detection, &mut ctx).ok();
+    }
+}
+
+fn main() -> HorusResult<()> {
+    let mut scheduler = Scheduler::new();
+    scheduler.add(
+        Box::new(SyntheticCamera {
+            image: Hub::new("camera.image")?,
+            frame: vec![0; (WIDTH * HEIGHT) as usize],
+            step: 0,
+        }),
+        0,
+        Some(false),
+    );
+    scheduler.add(
+        Box::new(Detector {
+            image: Hub::new("camera.image")?,
+            detections: Hub::new("perception.detections")?,
+        }),
+        1,
+        Some(false),
+    );
+    scheduler.add(
+        Box::new(Tracker {
+            detections: Hub::new("perception.detections")?,
+            tracks: Hub::new("perception.tracks")?,
+            last: None,
+            next_id: 0,
+        }),
+        2,
+        Some(true),
+    );
+    scheduler.run()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_bright_square() {
+        let mut pixels = vec![0u8; 100];
+        for y in 2..5 {
+            for x in 3..7 {
+                pixels[y * 10 + x] = 200;
+            }
+        }
+        let roi = detect(10, &pixels).unwrap();
+        assert_eq!(
+            (roi.x_offset, roi.y_offset, roi.width, roi.height),
+            (3, 2, 4, 3)
+        );
+        assert_eq!(center(&roi), (5.0, 3.5));
+    }
+
+    #[test]
+    fn dark_image_has_no_detection() {
+        assert!(detect(10, &[0u8; 100]).is_none());
+    }
+}
+"#;
+
+const PERCEPTION_PY: &str = r#"# Perception pipeline: camera -> detector -> tracker
+#
+# synthetic_camera draws a bright square moving over a dark background; swap
+# it for a camera driver publishing "camera.image". The simulator world has
+# objects for a simulated camera:
+#   horus sim3d --world world.yaml
+# `python -m pytest` runs test_main.py.
+
+import math
+
+import horus
+
+WIDTH, HEIGHT = 160, 120
+THRESHOLD = 128  # Pixels brighter than this belong to an object
+MAX_JUMP = 20.0  # Detections closer than this (pixels) keep their track
+
+state = {"step": 0, "last": None, "next_id": 0}
+
+
+def detect(width, pixels):
+    """Bounding box (x, y, w, h) of the pixels brighter than THRESHOLD."""
+    points = [(i % width, i // width) for i, p in enumerate(pixels) if p > THRESHOLD]
+    if not points:
+        return None
+    xs, ys = [x for x, _ in points], [y for _, y in points]
+    return min(xs), min(ys), max(xs) - min(xs) + 1, max(ys) - min(ys) + 1
+
+
+def center(box):
+    x, y, w, h = box
+    return x + w / 2, y + h / 2
+
+
+def camera(node):
+    frame = bytearray(WIDTH * HEIGHT)
+    left = state["step"] % (WIDTH - 20)
+    for y in range(50, 70):
+        frame[y * WIDTH + left:y * WIDTH + left + 20] = b"\xff" * 20
+    state["step"] += 1
+    node.send("camera.image", {"width": WIDTH, "height": HEIGHT, "data": bytes(frame)})
+
+
+def detector(node):
+    if not node.has_msg("camera.image"):
+        return
+    image = node.get("camera.image")
+    box = detect(image["width"], image["data"])
+    if box is not None:
+        node.send("perception.detections", {"class": "object", "bbox": box})
+
+
+def tracker(node):
+    if not node.has_msg("perception.detections"):
+        return
+    detection = node.get("perception.detections")
+    x, y = center(detection["bbox"])
+    last = state["last"]
+    if last is None or math.hypot(x - last[0], y - last[1]) >= MAX_JUMP:
+        state["next_id"] += 1
+    state["last"] = (x, y)
+    detection["track_id"] = state["next_id"]
+    node.send("perception.tracks", detection)
+
+
+def main():
+    horus.run(
+        horus.Node(name="synthetic_camera", pubs=["camera.image"], tick=camera, rate=15),
+        horus.Node(
+            name="detector",
+            subs=["camera.image"],
+            pubs=["perception.detections"],
+            tick=detector,
+            rate=15,
+        ),
+        horus.Node(
+            name="tracker",
+            subs=["perception.detections"],
+            pubs=["perception.tracks"],
+            tick=tracker,
+            rate=15,
+        ),
+    )
+
+
+if __name__ == "__main__":
+    main()
+"#;
+
+const PERCEPTION_TEST_PY: &str = r#"from main import center, detect
+
+
+def test_detects_bright_square():
+    pixels = [0] * 100
+    for y in range(2, 5):
+        for x in range(3, 7):
+            pixels[y * 10 + x] = 200
+    box = detect(10, pixels)
+    assert box == (3, 2, 4, 3)
+    assert center(box) == (5.0, 3.5)
+
+
+def test_dark_image_has_no_detection():
+    assert detect(10, [0] * 100) is None
+"#;
+
+const PERCEPTION_WORLD: &str = r#"# sim3d world: a table with objects in front of the camera
+#   horus sim3d --world world.yaml
+
+name: "Perception Bench"
+description: "Colored objects on a table for the detector"
+gravity: -9.81
+
+objects:
+  - name: "ground"
+    shape: {type: ground, size_x: 10.0, size_z: 10.0}
+    position: [0.0, 0.0, 0.0]
+    is_static: true
+    color: [0.3, 0.3, 0.3]
+
+  - name: "table"
+    shape: {type: box, size: [1.5, 0.05, 0.8]}
+    position: [1.5, 0.75, 0.0]
+    is_static: true
+    color: [0.6, 0.4, 0.2]
+
+  - name: "red_cube"
+    shape: {type: box, size: [0.1, 0.1, 0.1]}
+    position: [1.3, 0.83, 0.2]
+    color: [1.0, 0.1, 0.1]
+
+  - name: "green_ball"
+    shape: {type: sphere, radius: 0.06}
+    position: [1.6, 0.84, -0.1]
+    color: [0.1, 0.9, 0.1]
+
+  - name: "blue_can"
+    shape: {type: cylinder, radius: 0.04, height: 0.12}
+    position: [1.8, 0.84, 0.15]
+    color: [0.1, 0.3, 1.0]
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_templates_have_sources_and_world() {
+        for template in [
+            Template::DiffDrive,
+            Template::Arm,
+            Template::Quadruped,
+            Template::PerceptionPipeline,
+        ] {
+            let rust = template.files("rust");
+            assert!(rust.iter().any(|(path, _)| *path == "main.rs"));
+            let python = template.files("python");
+            assert!(python.iter().any(|(path, _)| *path == "test_main.py"));
+            for (_, content) in rust.iter().chain(&python) {
+                assert!(!content.is_empty());
+            }
+
+            let (_, world) = *python.iter().find(|(p, _)| *p == "world.yaml").unwrap();
+            serde_yaml::from_str::<serde_yaml::Value>(world).expect("world is valid YAML");
+
+            // Every topic in horus.yaml appears in the sources
+            for topic in template.topics() {
+                for (_, source) in [rust[0], python[0]] {
+                    assert!(source.contains(topic.name), "{}", topic.name);
+                }
+            }
+        }
+    }
+}
@@ -3,6 +3,8 @@
 //! Features beyond cargo test:
 //! - Build-before-test: Ensures Cargo.toml is generated/updated
 //! - Simulation mode: Enables simulation drivers for hardware-free testing
+//! - Simulation parity: Checks that the simulation drivers publish the same
+//!   topics, types and rates as the configured hardware drivers
 //! - Default single-threaded: Prevents shared memory conflicts (--parallel to override)
 //! - Integration test mode: Runs tests marked #[ignore]
 
@@ -14,6 +16,7 @@ use std::path::PathBuf;
 use std::process::Command;
 
 use crate::commands::run;
+use horus_library::drivers::parity::{ParityRegistry, DEFAULT_RATE_TOLERANCE};
 
 /// Check if Cargo.toml needs regeneration
 #[allow(clippy::ptr_arg)]
@@ -70,6 +73,7 @@ pub fn run_tests(
     no_build: bool,
     _no_cleanup: bool, // Legacy parameter, kept for API compatibility
     verbose: bool,
+    no_parity: bool,
 ) -> Result<()> {
     let horus_dir = PathBuf::from(".horus");
 
@@ -109,6 +113,10 @@ pub fn run_tests(
             "  {} Simulation mode enabled (no hardware required)",
             "[*]".cyan()
         );
+
+        if !no_parity {
+            verify_simulation_parity(verbose)?;
+        }
     }
 
     // Step 3: Build cargo test command
@@ -204,6 +212,60 @@ pub fn run_tests(
     Ok(())
 }
 
+/// Check the project's hardware drivers against their simulation counterparts
+///
+/// Tests run in simulation only prove something about the hardware if the
+/// simulation drivers publish the same topics, types and rates. Drivers
+/// without an explicit hardware backend in horus.yaml are not checked.
+fn verify_simulation_parity(verbose: bool) -> Result<()> {
+    let config = run::get_active_drivers();
+    let registry = ParityRegistry::builtin();
+
+    let mut backends: Vec<(&String, &String)> = config
+        .backends
+        .iter()
+        .filter(|(_, backend)| !matches!(backend.as_str(), "simulation" | "sim"))
+        .collect();
+    backends.sort();
+    if backends.is_empty() {
+        return Ok(());
+    }
+
+    println!("  {} Checking simulation parity", "[*]".cyan());
+
+    let mut violations = Vec::new();
+    for (driver, backend) in backends {
+        match registry.hardware(driver, backend) {
+            Some(hardware) => {
+                let found = registry.verify(hardware, DEFAULT_RATE_TOLERANCE);
+                if found.is_empty() && verbose {
+                    println!("    {} {}: {}", "[+]".green(), driver, backend);
+                }
+                violations.extend(found);
+            }
+            None => println!(
+                "    {} {}: no parity data for backend '{}', not checked",
+                "[!]".yellow(),
+                driver,
+                backend
+            ),
+        }
+    }
+
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    for violation in &violations {
+        println!("    {} {}", "[-]".red(), violation);
+    }
+    println!("    Rerun with {} to test anyway.", "--no-parity".cyan());
+    anyhow::bail!(
+        "{} simulation parity violation(s): simulation results may not hold on hardware",
+        violations.len()
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
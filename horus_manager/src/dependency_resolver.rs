@@ -166,6 +166,7 @@ pub struct DependencyResolver<'a> {
     provider: &'a dyn PackageProvider,
     resolved: HashMap<PackageName, Version>,
     requirements: HashMap<PackageName, Vec<VersionReq>>,
+    locked: HashMap<PackageName, Version>, // Versions from horus.lock, preferred when compatible
 }
 
 impl<'a> DependencyResolver<'a> {
@@ -174,9 +175,16 @@ impl<'a> DependencyResolver<'a> {
             provider,
             resolved: HashMap::new(),
             requirements: HashMap::new(),
+            locked: HashMap::new(),
         }
     }
 
+    /// Prefer these versions (from `horus.lock`) over newer compatible ones
+    pub fn with_locked(mut self, locked: HashMap<PackageName, Version>) -> Self {
+        self.locked = locked;
+        self
+    }
+
     /// Resolve dependencies starting from root requirements
     pub fn resolve(&mut self, root_deps: Vec<DependencySpec>) -> Result<Vec<ResolvedDependency>> {
         println!("Resolving dependencies...");
@@ -320,10 +328,17 @@ impl<'a> DependencyResolver<'a> {
 
     fn find_best_version_with_requirements(
         &self,
-        _package: &str,
+        package: &str,
         versions: &[Version],
         requirements: &[VersionReq],
     ) -> Option<Version> {
+        // Keep the locked version while it still satisfies every requirement
+        if let Some(locked) = self.locked.get(package) {
+            if versions.contains(locked) && requirements.iter().all(|req| req.matches(locked)) {
+                return Some(locked.clone());
+            }
+        }
+
         // Find the highest version that satisfies all requirements
         let mut candidates: Vec<Version> = versions
             .iter()
@@ -335,3 +350,54 @@ impl<'a> DependencyResolver<'a> {
         candidates.pop() // Return highest version
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StaticProvider;
+
+    impl PackageProvider for StaticProvider {
+        fn get_available_versions(&self, _package: &str) -> Result<Vec<Version>> {
+            Ok(vec![
+                Version::new(1, 0, 0),
+                Version::new(1, 2, 0),
+                Version::new(2, 0, 0),
+            ])
+        }
+
+        fn get_dependencies(
+            &self,
+            _package: &str,
+            _version: &Version,
+        ) -> Result<Vec<DependencySpec>> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn resolve(spec: &str, locked: Option<Version>) -> Version {
+        let provider = StaticProvider;
+        let locked = locked
+            .map(|v| HashMap::from([("lidar".to_string(), v)]))
+            .unwrap_or_default();
+        let resolved = DependencyResolver::new(&provider)
+            .with_locked(locked)
+            .resolve(vec![DependencySpec::parse(spec).unwrap()])
+            .unwrap();
+        resolved[0].version.clone()
+    }
+
+    #[test]
+    fn test_prefers_locked_version() {
+        assert_eq!(resolve("lidar@^1", None), Version::new(1, 2, 0));
+        assert_eq!(
+            resolve("lidar@^1", Some(Version::new(1, 0, 0))),
+            Version::new(1, 0, 0)
+        );
+        // A lock that no longer satisfies horus.yaml is ignored
+        assert_eq!(
+            resolve("lidar@^2", Some(Version::new(1, 0, 0))),
+            Version::new(2, 0, 0)
+        );
+    }
+}
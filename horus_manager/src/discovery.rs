@@ -1,6 +1,6 @@
 use horus_core::core::{HealthStatus, NetworkStatus, NodeHeartbeat, NodeState};
 use horus_core::error::HorusResult;
-use horus_core::memory::{shm_heartbeats_dir, shm_network_dir, shm_topics_dir};
+use horus_core::memory::{hugetlbfs_dir, shm_heartbeats_dir, shm_network_dir, shm_topics_dir};
 use std::path::Path;
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
@@ -25,6 +25,36 @@ pub struct NodeStatus {
     pub actual_rate_hz: u32,
     pub publishers: Vec<TopicInfo>,
     pub subscribers: Vec<TopicInfo>,
+    pub timing: NodeTimingStats,
+}
+
+/// Tick timing statistics reported in node heartbeats
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeTimingStats {
+    pub deadline_misses: u64,
+    pub jitter_p95_us: f64,
+    pub jitter_p99_us: f64,
+    pub tick_p95_us: f64,
+    pub worst_tick_us: f64,
+}
+
+impl NodeTimingStats {
+    pub fn from_heartbeat(heartbeat: &NodeHeartbeat) -> Self {
+        Self {
+            deadline_misses: heartbeat.deadline_misses,
+            jitter_p95_us: heartbeat.jitter_p95_us,
+            jitter_p99_us: heartbeat.jitter_p99_us,
+            tick_p95_us: heartbeat.tick_p95_us,
+            worst_tick_us: heartbeat.worst_tick_us,
+        }
+    }
+
+    /// Read timing statistics from a node's heartbeat file (zeroed if unavailable)
+    pub fn read(node_name: &str) -> Self {
+        NodeHeartbeat::read_from_file(node_name)
+            .map(|heartbeat| Self::from_heartbeat(&heartbeat))
+            .unwrap_or_default()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -272,6 +302,7 @@ fn discover_nodes_from_heartbeats() -> HorusResult<Vec<NodeStatus>> {
                                 actual_rate_hz: heartbeat.actual_rate_hz,
                                 publishers: Vec::new(),
                                 subscribers: Vec::new(),
+                                timing: NodeTimingStats::from_heartbeat(&heartbeat),
                             });
                         }
                     }
@@ -415,6 +446,7 @@ fn discover_nodes_from_registry() -> anyhow::Result<Vec<NodeStatus>> {
                     category: ProcessCategory::Node,
                     publishers,
                     subscribers,
+                    timing: NodeTimingStats::default(),
                 });
             }
         }
@@ -513,6 +545,7 @@ fn discover_horus_processes() -> anyhow::Result<Vec<NodeStatus>> {
                             actual_rate_hz: actual_rate,
                             publishers: Vec::new(),
                             subscribers: Vec::new(),
+                            timing: NodeTimingStats::read(&name),
                         });
                     }
                 }
@@ -1546,6 +1579,13 @@ fn discover_shared_memory_uncached() -> HorusResult<Vec<SharedMemoryInfo>> {
         topics.extend(scan_topics_directory(&topics_path)?);
     }
 
+    // Topics created with huge pages live on hugetlbfs
+    if let Some(huge_topics_path) = hugetlbfs_dir().map(|dir| dir.join("topics")) {
+        if huge_topics_path.exists() {
+            topics.extend(scan_topics_directory(&huge_topics_path)?);
+        }
+    }
+
     Ok(topics)
 }
 
@@ -1652,11 +1692,18 @@ fn scan_topics_directory(shm_path: &Path) -> HorusResult<Vec<SharedMemoryInfo>>
                 }
             } else if metadata.is_dir() && name == "horus_links" {
                 // Link topics - files inside horus_links subdirectory
-                topics.extend(scan_links_directory(
+                scan_nested_directory(&path, "links", &registry_topics, &active_nodes, &mut topics);
+            } else if let Some(namespace) =
+                name.strip_prefix("horus_").filter(|_| metadata.is_dir())
+            {
+                // Namespaced topics (`robot1/scan`) - files under horus_<namespace>/
+                scan_nested_directory(
                     &path,
+                    namespace,
                     &registry_topics,
                     &active_nodes,
-                )?);
+                    &mut topics,
+                );
             }
         }
     }
@@ -1664,35 +1711,40 @@ fn scan_topics_directory(shm_path: &Path) -> HorusResult<Vec<SharedMemoryInfo>>
     Ok(topics)
 }
 
-/// Scan the horus_links directory for Link shared memory files
-fn scan_links_directory(
-    links_path: &Path,
+/// Scan a directory of topics whose names start with `prefix/`
+///
+/// Used for Link topics (`links/<topic>`) and namespaced topics
+/// (`robot1/scan`, nested namespaces recurse).
+fn scan_nested_directory(
+    dir: &Path,
+    prefix: &str,
     registry_topics: &StdHashMap<String, (String, Vec<String>, Vec<String>)>,
     active_nodes: &[String],
-) -> HorusResult<Vec<SharedMemoryInfo>> {
-    let mut topics = Vec::new();
-
-    if let Ok(entries) = std::fs::read_dir(links_path) {
-        for entry in entries.flatten() {
-            let path = entry.path();
-            if let Ok(metadata) = entry.metadata() {
-                if metadata.is_file() {
-                    if let Some(name) = path.file_name().and_then(|s| s.to_str()) {
-                        // Link topic name format: links/<topic>
-                        let topic_name = format!("links/{}", name);
-                        if let Some(mut info) =
-                            scan_topic_file(&path, name, registry_topics, active_nodes)
-                        {
-                            info.topic_name = topic_name;
-                            topics.push(info);
-                        }
-                    }
-                }
+    topics: &mut Vec<SharedMemoryInfo>,
+) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let (Ok(metadata), Some(name)) = (
+            entry.metadata(),
+            path.file_name()
+                .and_then(|s| s.to_str())
+                .map(str::to_string),
+        ) else {
+            continue;
+        };
+        let topic_name = format!("{}/{}", prefix, name);
+        if metadata.is_file() {
+            if let Some(mut info) = scan_topic_file(&path, &name, registry_topics, active_nodes) {
+                info.topic_name = topic_name;
+                topics.push(info);
             }
+        } else if metadata.is_dir() {
+            scan_nested_directory(&path, &topic_name, registry_topics, active_nodes, topics);
         }
     }
-
-    Ok(topics)
 }
 
 /// Scan a single topic file and create SharedMemoryInfo
@@ -1703,6 +1755,12 @@ fn scan_topic_file(
     active_nodes: &[String],
 ) -> Option<SharedMemoryInfo> {
     let metadata = std::fs::metadata(path).ok()?;
+    // Windows mappings are represented by a marker file recording their size
+    #[cfg(target_os = "windows")]
+    let size = horus_core::memory::ShmMarker::read(path)
+        .map(|m| m.size as u64)
+        .unwrap_or(metadata.len());
+    #[cfg(not(target_os = "windows"))]
     let size = metadata.len();
     let modified = metadata.modified().ok();
 
@@ -1915,7 +1973,16 @@ fn load_topic_metadata_from_registry() -> StdHashMap<String, (String, Vec<String
     topic_map
 }
 
+// Windows has no /proc: the region's marker file lists the attached processes
+#[cfg(target_os = "windows")]
+fn find_accessing_processes_fast(shm_path: &Path, _shm_name: &str) -> Vec<u32> {
+    horus_core::memory::ShmMarker::read(shm_path)
+        .map(|marker| marker.pids)
+        .unwrap_or_default()
+}
+
 // Fast version: Check memory maps for HORUS processes to find mmap'd shared memory
+#[cfg(not(target_os = "windows"))]
 fn find_accessing_processes_fast(shm_path: &Path, shm_name: &str) -> Vec<u32> {
     let mut processes = Vec::new();
     let shm_path_str = shm_path.to_string_lossy();
@@ -2077,6 +2144,7 @@ fn enrich_nodes_with_heartbeats(nodes: &mut [NodeStatus]) {
             node.tick_count = tick_count;
             node.error_count = error_count;
             node.actual_rate_hz = actual_rate;
+            node.timing = NodeTimingStats::read(&node.name);
         }
     }
 }
@@ -2160,6 +2228,7 @@ mod tests {
             actual_rate_hz: 50,
             publishers: vec![],
             subscribers: vec![],
+            timing: NodeTimingStats::default(),
         };
 
         assert_eq!(node.name, "test_node");
@@ -2198,6 +2267,7 @@ mod tests {
             actual_rate_hz: 0,
             publishers: vec![pub_topic],
             subscribers: vec![sub_topic],
+            timing: NodeTimingStats::default(),
         };
 
         assert_eq!(node.publishers.len(), 1);
@@ -2567,6 +2637,7 @@ mod tests {
             actual_rate_hz: 0,
             publishers: vec![],
             subscribers: vec![],
+            timing: NodeTimingStats::default(),
         }];
 
         cache.update_nodes(nodes);
@@ -2642,6 +2713,7 @@ mod tests {
                 type_name: "Msg".to_string(),
             }],
             subscribers: vec![],
+            timing: NodeTimingStats::default(),
         };
 
         let cloned = node.clone();
@@ -2693,6 +2765,7 @@ mod tests {
             actual_rate_hz: 0,
             publishers: vec![],
             subscribers: vec![],
+            timing: NodeTimingStats::default(),
         };
 
         match node_healthy.health {
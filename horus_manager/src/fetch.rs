@@ -0,0 +1,463 @@
+//! Concurrent, resumable package downloads
+//!
+//! Registry archives are downloaded by a shared async HTTP client into
+//! `~/.horus/cache/downloads`, several at a time, each with its own progress
+//! bar. Interrupted downloads resume from the partial file with an HTTP
+//! `Range` request, and failed requests are retried with exponential backoff.
+//!
+//! Installation reads the finished archives from disk, so unpacking, linking
+//! and workspace edits stay sequential while the network-bound part of a
+//! workspace restore runs in parallel.
+
+use crate::progress::{self, finish_error, finish_success, HorusMultiProgress};
+use anyhow::{anyhow, Result};
+use futures::StreamExt;
+use indicatif::ProgressBar;
+use reqwest::header::RANGE;
+use reqwest::StatusCode;
+use sha2::{Digest, Sha256};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+
+/// Default number of simultaneous downloads
+pub const DEFAULT_DOWNLOAD_JOBS: usize = 4;
+
+/// Environment variable overriding the number of simultaneous downloads
+pub const DOWNLOAD_JOBS_ENV: &str = "HORUS_DOWNLOAD_JOBS";
+
+/// Timeout for establishing a connection
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Suffix of incomplete downloads
+const PARTIAL_SUFFIX: &str = ".part";
+
+/// Retry schedule for registry requests
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Attempts including the first one
+    pub max_attempts: u32,
+    /// Delay before the first retry (doubled for each further retry)
+    pub initial_backoff: Duration,
+    /// Upper bound on the delay between attempts
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(8),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Delay after the given failed attempt (1-based)
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32 << attempt.saturating_sub(1).min(16);
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+
+    /// Whether a response status is worth retrying
+    pub fn is_retryable_status(status: StatusCode) -> bool {
+        status.is_server_error()
+            || status == StatusCode::TOO_MANY_REQUESTS
+            || status == StatusCode::REQUEST_TIMEOUT
+    }
+
+    /// Whether a transport error is worth retrying
+    pub fn is_retryable_error(error: &reqwest::Error) -> bool {
+        error.is_connect() || error.is_timeout() || error.is_request() || error.is_body()
+    }
+}
+
+/// Number of simultaneous downloads (`HORUS_DOWNLOAD_JOBS`, default 4)
+pub fn download_jobs() -> usize {
+    std::env::var(DOWNLOAD_JOBS_ENV)
+        .ok()
+        .and_then(|v| v.parse::<usize>().ok())
+        .filter(|&jobs| jobs > 0)
+        .unwrap_or(DEFAULT_DOWNLOAD_JOBS)
+}
+
+fn user_agent() -> String {
+    format!("horus/{}", env!("CARGO_PKG_VERSION"))
+}
+
+/// Blocking HTTP client shared by every `RegistryClient`, so connections
+/// are pooled across requests
+pub fn blocking_client() -> reqwest::blocking::Client {
+    static CLIENT: OnceLock<reqwest::blocking::Client> = OnceLock::new();
+    CLIENT
+        .get_or_init(|| {
+            reqwest::blocking::Client::builder()
+                .connect_timeout(CONNECT_TIMEOUT)
+                .user_agent(user_agent())
+                .build()
+                .unwrap_or_else(|_| reqwest::blocking::Client::new())
+        })
+        .clone()
+}
+
+/// Send a blocking GET request, retrying transport errors and
+/// server-side failures with backoff
+pub fn get_with_retry(
+    client: &reqwest::blocking::Client,
+    url: &str,
+) -> reqwest::Result<reqwest::blocking::Response> {
+    let policy = RetryPolicy::default();
+    let mut attempt = 1;
+    loop {
+        let result = client.get(url).send();
+        let retry = match &result {
+            Ok(response) => RetryPolicy::is_retryable_status(response.status()),
+            Err(e) => RetryPolicy::is_retryable_error(e),
+        };
+        if !retry || attempt >= policy.max_attempts {
+            return result;
+        }
+        std::thread::sleep(policy.backoff(attempt));
+        attempt += 1;
+    }
+}
+
+/// Runtime driving the async downloads.
+///
+/// A single long-lived runtime keeps the async client's connection pool
+/// valid between calls.
+fn runtime() -> &'static tokio::runtime::Runtime {
+    static RUNTIME: OnceLock<tokio::runtime::Runtime> = OnceLock::new();
+    RUNTIME.get_or_init(|| {
+        tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(2)
+            .thread_name("horus-fetch")
+            .enable_all()
+            .build()
+            .expect("failed to start download runtime")
+    })
+}
+
+fn async_client() -> &'static reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(|| {
+        reqwest::Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .user_agent(user_agent())
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new())
+    })
+}
+
+/// Run a future on the download runtime and wait for it.
+///
+/// Works from plain threads as well as from `spawn_blocking` tasks of another
+/// runtime, where `Runtime::block_on` would panic.
+fn run<F>(future: F) -> F::Output
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    let handle = runtime().spawn(future);
+    match futures::executor::block_on(handle) {
+        Ok(output) => output,
+        Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+        Err(e) => panic!("download task failed: {}", e),
+    }
+}
+
+/// A package archive to download
+#[derive(Debug, Clone)]
+pub struct DownloadRequest {
+    /// Package name
+    pub name: String,
+    /// Exact version, or `None` for the latest
+    pub version: Option<String>,
+    /// Archive URL
+    pub url: String,
+}
+
+/// A downloaded package archive
+#[derive(Debug, Clone)]
+pub struct DownloadedPackage {
+    pub name: String,
+    pub version: Option<String>,
+    /// Archive path in the download cache
+    pub path: PathBuf,
+    /// SHA-256 of the archive (hex)
+    pub checksum: String,
+}
+
+/// Directory holding downloaded and partially downloaded archives
+pub fn download_dir() -> Result<PathBuf> {
+    let home = dirs::home_dir().ok_or_else(|| anyhow!("Could not find home directory"))?;
+    Ok(home.join(".horus/cache/downloads"))
+}
+
+/// Cache path of a package archive
+pub fn archive_path(dir: &Path, name: &str, version: Option<&str>) -> PathBuf {
+    let safe_name = crate::registry::package_name_to_path(name).replace('/', "--");
+    dir.join(format!(
+        "{}@{}.tar.gz",
+        safe_name,
+        version.unwrap_or("latest")
+    ))
+}
+
+fn partial_path(archive: &Path) -> PathBuf {
+    let mut path = archive.as_os_str().to_owned();
+    path.push(PARTIAL_SUFFIX);
+    PathBuf::from(path)
+}
+
+/// Outcome of a single download attempt
+enum AttemptError {
+    /// Worth retrying (resuming from the partial file)
+    Retry(anyhow::Error),
+    Fatal(anyhow::Error),
+}
+
+impl From<std::io::Error> for AttemptError {
+    fn from(e: std::io::Error) -> Self {
+        AttemptError::Fatal(e.into())
+    }
+}
+
+fn transport_error(e: reqwest::Error) -> AttemptError {
+    if RetryPolicy::is_retryable_error(&e) {
+        AttemptError::Retry(e.into())
+    } else {
+        AttemptError::Fatal(e.into())
+    }
+}
+
+/// Download into `partial`, resuming from whatever it already holds
+async fn attempt_download(
+    request: &DownloadRequest,
+    partial: &Path,
+    pb: &ProgressBar,
+) -> std::result::Result<(), AttemptError> {
+    let existing = tokio::fs::metadata(partial)
+        .await
+        .map(|m| m.len())
+        .unwrap_or(0);
+
+    let mut builder = async_client().get(&request.url);
+    if existing > 0 {
+        builder = builder.header(RANGE, format!("bytes={}-", existing));
+    }
+    let mut response = builder.send().await.map_err(transport_error)?;
+
+    let status = response.status();
+    if status == StatusCode::RANGE_NOT_SATISFIABLE {
+        // The partial file no longer matches the archive; start over
+        tokio::fs::remove_file(partial).await?;
+        return Err(AttemptError::Retry(anyhow!("stale partial download")));
+    }
+    if RetryPolicy::is_retryable_status(status) {
+        return Err(AttemptError::Retry(anyhow!("registry returned {}", status)));
+    }
+    if status == StatusCode::NOT_FOUND {
+        return Err(AttemptError::Fatal(anyhow!(
+            "Package not found: {}",
+            request.name
+        )));
+    }
+    if !status.is_success() {
+        return Err(AttemptError::Fatal(anyhow!(
+            "Failed to download {}: registry returned {}",
+            request.name,
+            status
+        )));
+    }
+
+    let resumed = existing > 0 && status == StatusCode::PARTIAL_CONTENT;
+    let mut file = if resumed {
+        tokio::fs::OpenOptions::new()
+            .append(true)
+            .open(partial)
+            .await?
+    } else {
+        tokio::fs::File::create(partial).await?
+    };
+    let offset = if resumed { existing } else { 0 };
+    if let Some(len) = response.content_length() {
+        pb.set_length(offset + len);
+    }
+    pb.set_position(offset);
+
+    while let Some(chunk) = response.chunk().await.map_err(transport_error)? {
+        file.write_all(&chunk).await?;
+        pb.inc(chunk.len() as u64);
+    }
+    file.flush().await?;
+    Ok(())
+}
+
+async fn sha256_file(path: &Path) -> Result<String> {
+    let data = tokio::fs::read(path).await?;
+    let mut hasher = Sha256::new();
+    hasher.update(&data);
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Download one archive with retries, reusing a finished archive of the same
+/// version from the cache
+async fn download(
+    request: DownloadRequest,
+    dir: PathBuf,
+    policy: RetryPolicy,
+    pb: ProgressBar,
+) -> Result<DownloadedPackage> {
+    let archive = archive_path(&dir, &request.name, request.version.as_deref());
+    let partial = partial_path(&archive);
+    let label = match &request.version {
+        Some(version) => format!("{} v{}", request.name, version),
+        None => request.name.clone(),
+    };
+
+    if request.version.is_none() {
+        // "latest" may have moved since the last attempt
+        let _ = tokio::fs::remove_file(&archive).await;
+        let _ = tokio::fs::remove_file(&partial).await;
+    } else if tokio::fs::metadata(&archive).await.is_ok() {
+        finish_success(&pb, &format!("{} (cached)", label));
+        return Ok(DownloadedPackage {
+            checksum: sha256_file(&archive).await?,
+            name: request.name,
+            version: request.version,
+            path: archive,
+        });
+    }
+
+    tokio::fs::create_dir_all(&dir).await?;
+    let mut attempt = 1;
+    loop {
+        match attempt_download(&request, &partial, &pb).await {
+            Ok(()) => break,
+            Err(AttemptError::Retry(e)) if attempt < policy.max_attempts => {
+                let delay = policy.backoff(attempt);
+                pb.set_message(format!(
+                    "{} (retry {}/{} in {:.1}s: {})",
+                    label,
+                    attempt,
+                    policy.max_attempts - 1,
+                    delay.as_secs_f32(),
+                    e
+                ));
+                tokio::time::sleep(delay).await;
+                pb.set_message(label.clone());
+                attempt += 1;
+            }
+            Err(AttemptError::Retry(e)) | Err(AttemptError::Fatal(e)) => {
+                finish_error(&pb, &format!("{}: {}", label, e));
+                return Err(e);
+            }
+        }
+    }
+
+    tokio::fs::rename(&partial, &archive).await?;
+    finish_success(&pb, &format!("Downloaded {}", label));
+    Ok(DownloadedPackage {
+        checksum: sha256_file(&archive).await?,
+        name: request.name,
+        version: request.version,
+        path: archive,
+    })
+}
+
+/// Download archives concurrently, at most `jobs` at a time.
+///
+/// Results are returned in request order.
+pub fn download_packages(
+    requests: Vec<DownloadRequest>,
+    jobs: usize,
+    policy: RetryPolicy,
+) -> Vec<Result<DownloadedPackage>> {
+    let dir = match download_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            let message = e.to_string();
+            return requests
+                .iter()
+                .map(|_| Err(anyhow!("{}", message)))
+                .collect();
+        }
+    };
+
+    let multi = HorusMultiProgress::new(progress::is_quiet());
+    let downloads: Vec<_> = requests
+        .into_iter()
+        .map(|request| {
+            let label = match &request.version {
+                Some(version) => format!("{} v{}", request.name, version),
+                None => request.name.clone(),
+            };
+            let pb = multi.add_download_bar(&label);
+            download(request, dir.clone(), policy, pb)
+        })
+        .collect();
+
+    let jobs = jobs.max(1);
+    run(async move {
+        let mut results: Vec<(usize, Result<DownloadedPackage>)> =
+            futures::stream::iter(downloads.into_iter().enumerate())
+                .map(|(index, download)| async move { (index, download.await) })
+                .buffer_unordered(jobs)
+                .collect()
+                .await;
+        results.sort_by_key(|(index, _)| *index);
+        results.into_iter().map(|(_, result)| result).collect()
+    })
+}
+
+/// Download a single archive
+pub fn download_package(request: DownloadRequest) -> Result<DownloadedPackage> {
+    download_packages(vec![request], 1, RetryPolicy::default())
+        .pop()
+        .unwrap_or_else(|| Err(anyhow!("download did not run")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_up_to_limit() {
+        let policy = RetryPolicy {
+            max_attempts: 6,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(1),
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(250));
+        assert_eq!(policy.backoff(2), Duration::from_millis(500));
+        assert_eq!(policy.backoff(3), Duration::from_secs(1));
+        assert_eq!(policy.backoff(40), Duration::from_secs(1));
+
+        assert!(RetryPolicy::is_retryable_status(StatusCode::BAD_GATEWAY));
+        assert!(RetryPolicy::is_retryable_status(
+            StatusCode::TOO_MANY_REQUESTS
+        ));
+        assert!(!RetryPolicy::is_retryable_status(StatusCode::NOT_FOUND));
+    }
+
+    #[test]
+    fn test_archive_paths() {
+        let dir = Path::new("/cache");
+        let archive = archive_path(dir, "lidar-driver", Some("1.2.0"));
+        assert_eq!(archive, Path::new("/cache/lidar-driver@1.2.0.tar.gz"));
+        assert_eq!(
+            partial_path(&archive),
+            Path::new("/cache/lidar-driver@1.2.0.tar.gz.part")
+        );
+        assert_eq!(
+            archive_path(dir, "@org/pkg", None),
+            Path::new("/cache/@org--pkg@latest.tar.gz")
+        );
+    }
+}
@@ -244,6 +244,82 @@ pub fn discover_graph_data() -> (Vec<GraphNode>, Vec<GraphEdge>) {
     (graph_nodes, graph_edges)
 }
 
+/// Topic with publishers but no subscribers, or the other way around
+#[derive(Debug, Clone, PartialEq)]
+pub struct DanglingTopic {
+    pub topic: String,
+    /// True if the topic is published (and nobody subscribes)
+    pub published: bool,
+    /// Unmatched topic of the other side with a similar name, a likely typo
+    pub similar: Option<String>,
+}
+
+/// Find topics that only have publishers or only have subscribers
+///
+/// Topics without any edges are not reported, their role is unknown.
+pub fn find_dangling_topics(nodes: &[GraphNode], edges: &[GraphEdge]) -> Vec<DanglingTopic> {
+    let mut published_only = Vec::new();
+    let mut subscribed_only = Vec::new();
+    for node in nodes.iter().filter(|n| n.node_type == NodeType::Topic) {
+        let published = edges
+            .iter()
+            .any(|e| e.edge_type == EdgeType::Publish && e.to == node.id);
+        let subscribed = edges
+            .iter()
+            .any(|e| e.edge_type == EdgeType::Subscribe && e.from == node.id);
+        match (published, subscribed) {
+            (true, false) => published_only.push(node.label.as_str()),
+            (false, true) => subscribed_only.push(node.label.as_str()),
+            _ => {}
+        }
+    }
+
+    let dangling = |topic: &str, published: bool, others: &[&str]| DanglingTopic {
+        topic: topic.to_string(),
+        published,
+        similar: similar_topic(topic, others).map(str::to_string),
+    };
+    let mut result: Vec<DanglingTopic> = published_only
+        .iter()
+        .map(|topic| dangling(topic, true, &subscribed_only))
+        .chain(
+            subscribed_only
+                .iter()
+                .map(|topic| dangling(topic, false, &published_only)),
+        )
+        .collect();
+    result.sort_by(|a, b| a.topic.cmp(&b.topic));
+    result
+}
+
+/// Closest candidate within two edits (and under half the name), ignoring case
+/// and `/` vs `.` separators
+pub fn similar_topic<'a>(topic: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    let normalize = |name: &str| name.trim_matches('/').replace('/', ".").to_lowercase();
+    let topic = normalize(topic);
+    candidates
+        .iter()
+        .map(|&candidate| (candidate, edit_distance(&topic, &normalize(candidate))))
+        .filter(|&(_, distance)| distance <= 2 && distance * 2 < topic.chars().count())
+        .min_by_key(|&(_, distance)| distance)
+        .map(|(candidate, _)| candidate)
+}
+
+/// Levenshtein distance in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -626,4 +702,81 @@ mod tests {
         assert!((new_position.x - 5.0).abs() < 0.001);
         assert!((new_position.y - 10.0).abs() < 0.001);
     }
+
+    // =====================
+    // Dangling Topic Tests
+    // =====================
+    fn topic_node(name: &str) -> GraphNode {
+        GraphNode {
+            id: format!("topic_{}", name),
+            label: name.to_string(),
+            node_type: NodeType::Topic,
+            position: Pos2::ZERO,
+            velocity: Vec2::ZERO,
+            pid: None,
+            active: true,
+        }
+    }
+
+    fn edge(from: &str, to: &str, edge_type: EdgeType) -> GraphEdge {
+        GraphEdge {
+            from: from.to_string(),
+            to: to.to_string(),
+            edge_type,
+            active: true,
+        }
+    }
+
+    #[test]
+    fn test_find_dangling_topics() {
+        let nodes = vec![
+            topic_node("sensors.lidar"),
+            topic_node("sensors.lidr"),
+            topic_node("cmd_vel"),
+            topic_node("diagnostics"),
+            topic_node("unused"),
+        ];
+        let edges = vec![
+            edge("process_1_driver", "topic_sensors.lidar", EdgeType::Publish),
+            edge("topic_sensors.lidr", "process_2_slam", EdgeType::Subscribe),
+            edge("process_2_slam", "topic_cmd_vel", EdgeType::Publish),
+            edge("topic_cmd_vel", "process_3_base", EdgeType::Subscribe),
+            edge("process_3_base", "topic_diagnostics", EdgeType::Publish),
+        ];
+
+        let dangling = find_dangling_topics(&nodes, &edges);
+        assert_eq!(
+            dangling,
+            vec![
+                DanglingTopic {
+                    topic: "diagnostics".to_string(),
+                    published: true,
+                    similar: None,
+                },
+                DanglingTopic {
+                    topic: "sensors.lidar".to_string(),
+                    published: true,
+                    similar: Some("sensors.lidr".to_string()),
+                },
+                DanglingTopic {
+                    topic: "sensors.lidr".to_string(),
+                    published: false,
+                    similar: Some("sensors.lidar".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_similar_topic() {
+        let candidates = ["robot/odom", "camera.image", "imu"];
+        assert_eq!(similar_topic("robot.odom", &candidates), Some("robot/odom"));
+        assert_eq!(
+            similar_topic("Camera.Imag", &candidates),
+            Some("camera.image")
+        );
+        assert_eq!(similar_topic("gps", &candidates), None);
+        assert_eq!(similar_topic("im", &candidates), None);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
 }
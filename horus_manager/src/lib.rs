@@ -6,13 +6,20 @@ pub mod commands;
 pub mod config;
 pub mod dependency_resolver;
 pub mod discovery;
+pub mod fetch;
 pub mod graph;
+pub mod lockfile;
 pub mod monitor;
+pub mod monitor_api;
+pub mod monitor_plot;
+pub mod monitor_remote;
 pub mod monitor_tui;
 pub mod node_detector;
 pub mod plugins;
 pub mod progress;
+pub mod python_env;
 pub mod registry;
+pub mod sarif;
 pub mod security;
 pub mod static_analysis;
 pub mod system_deps;
@@ -0,0 +1,259 @@
+//! Dependency lockfile (`horus.lock`)
+//!
+//! The first time a project's registry dependencies are resolved, the exact
+//! versions and archive checksums that were installed are written to
+//! `horus.lock` next to `horus.yaml`. `horus run` and `horus build` then
+//! install those versions instead of the latest matching one, and refuse
+//! archives whose checksum changed, so every machine and CI job builds the
+//! same dependency tree. `horus pkg update` re-resolves and rewrites the file.
+//!
+//! Commit `horus.lock` to version control.
+
+use crate::dependency_resolver::ResolvedDependency;
+use crate::registry::PackageMetadata;
+use anyhow::{bail, Context, Result};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Lockfile name, in the project directory
+pub const LOCKFILE_NAME: &str = "horus.lock";
+
+/// Current lockfile format
+const LOCKFILE_VERSION: u32 = 1;
+
+const HEADER: &str = "# This file is generated by horus. Do not edit it by hand.\n\
+                      # Run 'horus pkg update' to refresh it.\n\n";
+
+/// Exact dependency versions of a project
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Lockfile {
+    pub version: u32,
+    #[serde(default, rename = "package")]
+    pub packages: Vec<LockedDependency>,
+}
+
+/// A locked registry package
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LockedDependency {
+    pub name: String,
+    pub version: String,
+    /// Hex SHA-256 of the package archive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+}
+
+impl Default for Lockfile {
+    fn default() -> Self {
+        Self {
+            version: LOCKFILE_VERSION,
+            packages: Vec::new(),
+        }
+    }
+}
+
+impl Lockfile {
+    /// Path of the lockfile of a project
+    pub fn path(project_dir: &Path) -> PathBuf {
+        project_dir.join(LOCKFILE_NAME)
+    }
+
+    /// Lockfile of a project, None if there is none yet
+    pub fn load(project_dir: &Path) -> Result<Option<Self>> {
+        let path = Self::path(project_dir);
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e).with_context(|| format!("Failed to read {}", path.display())),
+        };
+        let lockfile: Self =
+            toml::from_str(&contents).with_context(|| format!("Invalid {}", path.display()))?;
+        if lockfile.version > LOCKFILE_VERSION {
+            bail!(
+                "{} was written by a newer horus (format {}), please upgrade",
+                path.display(),
+                lockfile.version
+            );
+        }
+        Ok(Some(lockfile))
+    }
+
+    /// Write the lockfile of a project, packages sorted by name
+    pub fn save(&self, project_dir: &Path) -> Result<()> {
+        let mut sorted = self.clone();
+        sorted.packages.sort_by(|a, b| a.name.cmp(&b.name));
+        let path = Self::path(project_dir);
+        std::fs::write(&path, format!("{}{}", HEADER, toml::to_string(&sorted)?))
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Locked entry of a package
+    pub fn get(&self, name: &str) -> Option<&LockedDependency> {
+        self.packages.iter().find(|p| p.name == name)
+    }
+
+    /// Add or replace the entry of a package
+    pub fn insert(&mut self, package: LockedDependency) {
+        self.packages.retain(|p| p.name != package.name);
+        self.packages.push(package);
+    }
+
+    /// Drop the entry of a package; false if it wasn't locked
+    pub fn remove(&mut self, name: &str) -> bool {
+        let count = self.packages.len();
+        self.packages.retain(|p| p.name != name);
+        self.packages.len() != count
+    }
+
+    /// Locked versions, for [`crate::dependency_resolver::DependencyResolver::with_locked`]
+    pub fn versions(&self) -> HashMap<String, Version> {
+        self.packages
+            .iter()
+            .filter_map(|p| Some((p.name.clone(), Version::parse(&p.version).ok()?)))
+            .collect()
+    }
+
+    /// Lock resolved versions, taking checksums from the installed packages
+    pub fn from_resolved(resolved: &[ResolvedDependency], packages_dir: &Path) -> Self {
+        let mut lockfile = Self::default();
+        for dep in resolved {
+            let checksum = installed_metadata(packages_dir, &dep.name)
+                .filter(|m| m.version == dep.version.to_string())
+                .and_then(|m| m.checksum);
+            lockfile.insert(LockedDependency {
+                name: dep.name.clone(),
+                version: dep.version.to_string(),
+                checksum,
+            });
+        }
+        lockfile
+    }
+
+    /// Lock the installed versions of the named packages
+    ///
+    /// Packages without registry metadata (path, git, system) are skipped.
+    pub fn from_installed<S: AsRef<str>>(packages_dir: &Path, names: &[S]) -> Self {
+        let mut lockfile = Self::default();
+        for name in names {
+            if let Some(metadata) = installed_metadata(packages_dir, name.as_ref()) {
+                lockfile.insert(LockedDependency {
+                    name: metadata.name,
+                    version: metadata.version,
+                    checksum: metadata.checksum.filter(|c| !c.is_empty()),
+                });
+            }
+        }
+        lockfile
+    }
+
+    /// Check that an installed package matches its locked version and checksum
+    pub fn check_installed(&self, packages_dir: &Path, name: &str) -> Result<()> {
+        let (Some(locked), Some(installed)) =
+            (self.get(name), installed_metadata(packages_dir, name))
+        else {
+            return Ok(());
+        };
+        if installed.version != locked.version {
+            bail!(
+                "{} v{} is installed but {} locks v{}",
+                name,
+                installed.version,
+                LOCKFILE_NAME,
+                locked.version
+            );
+        }
+        if let (Some(expected), Some(actual)) = (&locked.checksum, &installed.checksum) {
+            if !expected.eq_ignore_ascii_case(actual) {
+                bail!(
+                    "Checksum of {} v{} differs from {} ({} != {}). The registry archive changed; \
+                     run 'horus pkg update {}' if this is expected",
+                    name,
+                    locked.version,
+                    LOCKFILE_NAME,
+                    actual,
+                    expected,
+                    name
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Metadata of a package installed in `packages_dir` (`.horus/packages`)
+pub fn installed_metadata(packages_dir: &Path, name: &str) -> Option<PackageMetadata> {
+    let path = packages_dir
+        .join(crate::registry::package_name_to_path(name))
+        .join("metadata.json");
+    serde_json::from_str(&std::fs::read_to_string(path).ok()?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn install(packages_dir: &Path, name: &str, version: &str, checksum: &str) {
+        let dir = packages_dir.join(name);
+        std::fs::create_dir_all(&dir).unwrap();
+        let metadata = PackageMetadata {
+            name: name.to_string(),
+            version: version.to_string(),
+            checksum: Some(checksum.to_string()),
+            signed_by: None,
+        };
+        std::fs::write(
+            dir.join("metadata.json"),
+            serde_json::to_string(&metadata).unwrap(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(Lockfile::load(dir.path()).unwrap().is_none());
+
+        let mut lockfile = Lockfile::default();
+        lockfile.insert(LockedDependency {
+            name: "slam".to_string(),
+            version: "2.0.1".to_string(),
+            checksum: None,
+        });
+        lockfile.insert(LockedDependency {
+            name: "lidar-driver".to_string(),
+            version: "1.2.0".to_string(),
+            checksum: Some("abc123".to_string()),
+        });
+        lockfile.save(dir.path()).unwrap();
+
+        let contents = std::fs::read_to_string(Lockfile::path(dir.path())).unwrap();
+        assert!(contents.starts_with("# This file is generated by horus"));
+        assert!(contents.find("lidar-driver") < contents.find("slam"));
+
+        let loaded = Lockfile::load(dir.path()).unwrap().unwrap();
+        assert_eq!(loaded.get("lidar-driver").unwrap().version, "1.2.0");
+        assert_eq!(loaded.versions().get("slam"), Some(&Version::new(2, 0, 1)));
+    }
+
+    #[test]
+    fn test_from_installed_and_check() {
+        let dir = tempfile::tempdir().unwrap();
+        let packages = dir.path().join(".horus/packages");
+        install(&packages, "lidar-driver", "1.2.0", "abc123");
+
+        let mut lockfile = Lockfile::from_installed(&packages, &["lidar-driver", "missing"]);
+        assert_eq!(lockfile.packages.len(), 1);
+        assert!(lockfile.check_installed(&packages, "lidar-driver").is_ok());
+
+        // Same version, different archive
+        install(&packages, "lidar-driver", "1.2.0", "def456");
+        assert!(lockfile.check_installed(&packages, "lidar-driver").is_err());
+
+        install(&packages, "lidar-driver", "1.3.0", "abc123");
+        assert!(lockfile.check_installed(&packages, "lidar-driver").is_err());
+
+        assert!(lockfile.remove("lidar-driver"));
+        assert!(lockfile.check_installed(&packages, "lidar-driver").is_ok());
+    }
+}
@@ -2,13 +2,14 @@ use clap::{CommandFactory, Parser, Subcommand};
 use clap_complete::generate;
 use colored::*;
 use horus_core::error::{HorusError, HorusResult};
-use horus_core::memory::{has_native_shm, shm_base_dir};
 use std::fs;
 use std::io;
 use std::path::{Path, PathBuf};
 
 // Use modules from the library instead of redeclaring them
-use horus_manager::{commands, monitor, monitor_tui, registry, security, workspace};
+use horus_manager::{
+    commands, monitor, monitor_remote, monitor_tui, registry, security, workspace,
+};
 
 /// Calculate the total size of a directory recursively
 fn dir_size(path: &Path) -> std::io::Result<u64> {
@@ -79,6 +80,17 @@ enum Commands {
         /// Use Rust with macros
         #[arg(short = 'm', long = "macro", conflicts_with = "python")]
         use_macro: bool,
+        /// Also generate a Dockerfile and devcontainer configuration
+        #[arg(long = "docker")]
+        docker: bool,
+        /// Start from a robot template with nodes, topics, a simulation world and tests
+        #[arg(
+            short = 't',
+            long = "template",
+            value_enum,
+            conflicts_with = "use_macro"
+        )]
+        template: Option<commands::templates::Template>,
     },
 
     /// Run a HORUS project or file(s)
@@ -114,6 +126,30 @@ enum Commands {
         #[arg(long = "record")]
         record: Option<String>,
 
+        /// Build and run inside a container (Docker or Podman) with /dev/shm
+        /// and detected hardware devices passed through
+        #[arg(long = "container")]
+        container: bool,
+
+        /// Container image to use instead of building the project Dockerfile
+        #[arg(long = "image", requires = "container")]
+        image: Option<String>,
+
+        /// Launch argument for a horus.yaml launch group (repeatable)
+        /// Example: --arg sim=true --arg robot_name=rover2
+        #[arg(long = "arg", value_name = "NAME=VALUE")]
+        launch_args: Vec<String>,
+
+        /// Namespace prefixed to all topics and frames (e.g. robot1)
+        /// Overrides `namespace` in horus.yaml
+        #[arg(long = "namespace", value_name = "NAMESPACE")]
+        namespace: Option<String>,
+
+        /// Development mode: reload Python nodes when their files change,
+        /// without restarting the scheduler
+        #[arg(long = "dev")]
+        dev: bool,
+
         /// Additional arguments to pass to the program (use -- to separate)
         #[arg(last = true)]
         args: Vec<String>,
@@ -128,6 +164,14 @@ enum Commands {
         /// Only show errors, suppress warnings
         #[arg(short = 'q', long = "quiet")]
         quiet: bool,
+
+        /// Output format: colored text, or JSON diagnostics for editors and CI
+        #[arg(long = "format", value_enum, default_value_t = commands::check::CheckFormat::Text)]
+        format: commands::check::CheckFormat,
+
+        /// Also write the findings as SARIF 2.1.0 (for CI code scanning)
+        #[arg(long = "sarif", value_name = "FILE")]
+        sarif: Option<PathBuf>,
     },
 
     /// Run tests for the HORUS project
@@ -156,6 +200,10 @@ enum Commands {
         #[arg(long = "sim")]
         simulation: bool,
 
+        /// Skip checking that simulation drivers match the configured hardware drivers
+        #[arg(long = "no-parity")]
+        no_parity: bool,
+
         /// Run integration tests (tests marked #[ignore])
         #[arg(long = "integration")]
         integration: bool,
@@ -183,7 +231,15 @@ enum Commands {
         enable: Option<Vec<String>>,
     },
 
+    /// Generate CI pipelines and run CI gates
+    Ci {
+        #[command(subcommand)]
+        command: CiCommands,
+    },
+
     /// Build the HORUS project without running
+    ///
+    /// At the root of a multi-package workspace, builds every member package
     Build {
         /// File(s) to build (optional, auto-detects if not specified)
         files: Vec<PathBuf>,
@@ -224,6 +280,47 @@ enum Commands {
         /// Reset password before starting
         #[arg(short = 'r', long = "reset-password")]
         reset_password: bool,
+
+        /// Run a headless monitoring agent (authenticated API only) for remote dashboards
+        #[arg(long = "agent", conflicts_with_all = ["tui", "remote"])]
+        agent: bool,
+
+        /// Address the agent listens on (non-loopback requires TLS or --insecure)
+        #[arg(
+            long = "bind",
+            value_name = "ADDR",
+            default_value = "127.0.0.1",
+            requires = "agent"
+        )]
+        bind: std::net::IpAddr,
+
+        /// PEM certificate for serving the agent over HTTPS
+        #[arg(long = "tls-cert", value_name = "FILE", requires_all = ["agent", "tls_key"])]
+        tls_cert: Option<PathBuf>,
+
+        /// PEM private key for serving the agent over HTTPS
+        #[arg(long = "tls-key", value_name = "FILE", requires_all = ["agent", "tls_cert"])]
+        tls_key: Option<PathBuf>,
+
+        /// Allow the agent to serve plain HTTP on a non-loopback address
+        #[arg(long = "insecure", requires = "agent")]
+        insecure: bool,
+
+        /// Show the dashboard of a remote agent (host or host:port)
+        #[arg(long = "remote", value_name = "HOST", conflicts_with = "tui")]
+        remote: Option<String>,
+
+        /// Create an API token for the /api/v1 monitor API and print it
+        #[arg(long = "create-token", value_name = "NAME")]
+        create_token: Option<String>,
+
+        /// Revoke an API token
+        #[arg(long = "revoke-token", value_name = "NAME")]
+        revoke_token: Option<String>,
+
+        /// List API tokens
+        #[arg(long = "list-tokens")]
+        list_tokens: bool,
     },
 
     /// Topic interaction (list, echo, publish)
@@ -261,6 +358,10 @@ enum Commands {
         /// Show detailed diagnostic information
         #[arg(short = 'v', long = "verbose")]
         verbose: bool,
+
+        /// Remove shared memory and sockets left behind by crashed processes
+        #[arg(long = "fix")]
+        fix: bool,
     },
 
     /// Hardware discovery and platform detection
@@ -286,7 +387,7 @@ enum Commands {
 
     /// Launch multiple nodes from a YAML file
     Launch {
-        /// Path to launch file (YAML)
+        /// Path to launch file (YAML), or a horus.yaml with a launch section
         file: std::path::PathBuf,
 
         /// Show what would launch without actually launching
@@ -300,6 +401,16 @@ enum Commands {
         /// List nodes in the launch file without launching
         #[arg(long = "list")]
         list: bool,
+
+        /// Launch argument, substituted for ${arg:NAME} (repeatable)
+        #[arg(long = "arg", value_name = "NAME=VALUE")]
+        launch_args: Vec<String>,
+    },
+
+    /// Run nodes for `horus launch` on this machine, or query a remote agent
+    Agent {
+        #[command(subcommand)]
+        command: AgentCommands,
     },
 
     /// Message type introspection
@@ -339,6 +450,12 @@ enum Commands {
         clear_all: bool,
     },
 
+    /// Black box flight recorder (post-mortem event dump)
+    Blackbox {
+        #[command(subcommand)]
+        command: BlackboxCommands,
+    },
+
     /// Package management
     Pkg {
         #[command(subcommand)]
@@ -402,6 +519,14 @@ enum Commands {
         /// Enable gravity (for side-view humanoid simulation)
         #[arg(long)]
         gravity: bool,
+
+        /// Step in lockstep with a scheduler using `Scheduler::with_lockstep`
+        #[arg(long)]
+        lockstep: bool,
+
+        /// Stamp sensor data with simulated time (for nodes using sim time)
+        #[arg(long)]
+        use_sim_time: bool,
     },
 
     /// Run 3D simulator
@@ -425,6 +550,14 @@ enum Commands {
         /// Robot name for HORUS topics (e.g., turtlebot.cmd_vel)
         #[arg(long, default_value = "sim3d_robot")]
         robot_name: String,
+
+        /// Step in lockstep with a scheduler using `Scheduler::with_lockstep`
+        #[arg(long)]
+        lockstep: bool,
+
+        /// Stamp sensor data with simulated time (for nodes using sim time)
+        #[arg(long)]
+        use_sim_time: bool,
     },
 
     /// Driver management (list, info, search)
@@ -436,17 +569,37 @@ enum Commands {
     /// Deploy project to a remote robot
     Deploy {
         /// Target host (user@host or configured target name)
-        #[arg(required_unless_present = "list")]
+        #[arg(required_unless_present_any = ["list", "host"], conflicts_with = "host")]
         target: Option<String>,
 
+        /// Target host (same as the positional argument)
+        #[arg(long = "host", value_name = "HOST")]
+        host: Option<String>,
+
         /// Remote directory to deploy to (default: ~/horus_deploy)
         #[arg(short = 'd', long = "dir")]
         remote_dir: Option<String>,
 
-        /// Target architecture (aarch64, armv7, x86_64, native)
-        #[arg(short = 'a', long = "arch")]
+        /// Target architecture (aarch64, armv7, x86_64, native) or Rust target triple
+        #[arg(short = 'a', long = "arch", visible_alias = "target")]
         arch: Option<String>,
 
+        /// Cross-compilation tool: cargo, cross or zigbuild (default: auto-detect)
+        #[arg(long = "builder", value_name = "TOOL")]
+        builder: Option<String>,
+
+        /// systemd unit to restart after deploying (rolls back if it fails)
+        #[arg(long = "service", value_name = "UNIT")]
+        service: Option<String>,
+
+        /// URL on the robot that must answer after the restart (e.g. http://localhost:8081/readyz)
+        #[arg(long = "health-url", value_name = "URL", requires = "service")]
+        health_url: Option<String>,
+
+        /// Number of releases to keep on the robot
+        #[arg(long = "keep", value_name = "N", default_value = "3")]
+        keep: usize,
+
         /// Run the project after deploying
         #[arg(short = 'r', long = "run")]
         run_after: bool,
@@ -456,8 +609,8 @@ enum Commands {
         debug: bool,
 
         /// SSH port (default: 22)
-        #[arg(short = 'p', long = "port", default_value = "22")]
-        port: u16,
+        #[arg(short = 'p', long = "port")]
+        port: Option<u16>,
 
         /// SSH identity file
         #[arg(short = 'i', long = "identity")]
@@ -472,6 +625,12 @@ enum Commands {
         list: bool,
     },
 
+    /// Run the project as a systemd service (install, uninstall, status)
+    Service {
+        #[command(subcommand)]
+        command: ServiceCommands,
+    },
+
     /// Add a package, driver, or plugin (smart auto-detection)
     Add {
         /// Package/driver/plugin name to add
@@ -550,6 +709,9 @@ enum PkgCommands {
         /// Target workspace/project name (if not in workspace)
         #[arg(short = 't', long = "target")]
         target: Option<String>,
+        /// Install even if the package is unsigned or signed by an untrusted key
+        #[arg(long = "allow-unsigned")]
+        allow_unsigned: bool,
     },
 
     /// Remove an installed package
@@ -593,6 +755,52 @@ enum PkgCommands {
         #[arg(short = 'y', long = "yes")]
         yes: bool,
     },
+
+    /// Audit licenses, provenance and advisories of workspace dependencies
+    Audit {
+        /// Policy file (default: `audit` section of horus.yaml)
+        #[arg(short = 'p', long = "policy")]
+        policy: Option<PathBuf>,
+        /// Skip advisory and crates.io lookups
+        #[arg(long)]
+        offline: bool,
+        /// Output the report as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Re-resolve dependencies to their newest compatible versions and rewrite horus.lock
+    Update {
+        /// Only update this package (default: all)
+        package: Option<String>,
+    },
+
+    /// Manage publisher keys trusted to sign installed packages
+    Trust {
+        #[command(subcommand)]
+        command: TrustCommands,
+    },
+
+    /// Show the public key your published packages are signed with
+    SigningKey,
+}
+
+#[derive(Subcommand)]
+enum TrustCommands {
+    /// List trusted publisher keys
+    List,
+    /// Trust a publisher key
+    Add {
+        /// Name for the publisher
+        name: String,
+        /// Hex ed25519 public key (shown by 'horus pkg signing-key')
+        public_key: String,
+    },
+    /// Stop trusting a publisher key
+    Remove {
+        /// Publisher name or public key
+        key: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -1225,6 +1433,36 @@ enum NodeCommands {
         /// Node name
         name: String,
     },
+
+    /// Generate a new node in the current project and wire it in
+    Create {
+        /// Node name (e.g. obstacle_detector)
+        name: String,
+
+        /// Topic to publish, as TOPIC:TYPE (repeatable)
+        #[arg(long = "pub", value_name = "TOPIC:TYPE")]
+        publishers: Vec<commands::node_create::TopicSpec>,
+
+        /// Topic to subscribe to, as TOPIC:TYPE (repeatable)
+        #[arg(long = "sub", value_name = "TOPIC:TYPE")]
+        subscribers: Vec<commands::node_create::TopicSpec>,
+
+        /// Use the node! macro instead of implementing the Node trait (Rust)
+        #[arg(long = "macro")]
+        use_macro: bool,
+
+        /// Tick rate in Hz
+        #[arg(short = 'r', long = "rate")]
+        rate: Option<u32>,
+
+        /// Scheduler priority (default: after the existing nodes)
+        #[arg(short = 'p', long = "priority")]
+        priority: Option<u32>,
+
+        /// Project directory (default: current directory)
+        #[arg(long = "path")]
+        path: Option<PathBuf>,
+    },
 }
 
 #[derive(Subcommand)]
@@ -1289,6 +1527,168 @@ enum ParamCommands {
     Dump,
 }
 
+#[derive(Subcommand)]
+enum BlackboxCommands {
+    /// Decode and print recorded events (newest recording by default)
+    Dump {
+        /// Ring file to read (default: most recent in ~/.horus/blackbox)
+        #[arg(short = 'f', long = "file")]
+        file: Option<PathBuf>,
+
+        /// Only events from the last duration before the final event (e.g., "30s", "5m")
+        #[arg(short = 's', long = "since")]
+        since: Option<String>,
+
+        /// Only show the last N events
+        #[arg(short = 'n', long = "count")]
+        count: Option<usize>,
+
+        /// Only show errors, deadline misses, e-stops and crashes
+        #[arg(short = 'a', long = "anomalies")]
+        anomalies: bool,
+
+        /// Output as JSON
+        #[arg(long = "json")]
+        json: bool,
+    },
+
+    /// List available black box recordings
+    List,
+}
+
+#[derive(Subcommand)]
+enum ServiceCommands {
+    /// Generate and install a systemd unit for the current project, enabled on boot
+    Install {
+        /// Service name (default: horus-<project name>)
+        #[arg(short = 'n', long = "name")]
+        name: Option<String>,
+
+        /// Install as a user service (~/.config/systemd/user) instead of a system service
+        #[arg(long = "user")]
+        user: bool,
+
+        /// Command to run (default: horus run --release)
+        #[arg(long = "exec", value_name = "CMD")]
+        exec: Option<String>,
+
+        /// Environment variable for the service (repeatable)
+        #[arg(short = 'e', long = "env", value_name = "KEY=VALUE")]
+        env: Vec<String>,
+
+        /// File with environment variables
+        #[arg(long = "env-file", value_name = "PATH")]
+        env_file: Option<PathBuf>,
+
+        /// Maximum real-time priority (LimitRTPRIO)
+        #[arg(long = "rt-priority", default_value = "99")]
+        rt_priority: u8,
+
+        /// Don't raise real-time and memory locking limits
+        #[arg(long = "no-rt", conflicts_with = "rt_priority")]
+        no_rt: bool,
+
+        /// Restart when the scheduler stops feeding the systemd watchdog for SECS
+        #[arg(long = "watchdog", value_name = "SECS")]
+        watchdog: Option<u64>,
+
+        /// Start (or restart) the service now
+        #[arg(long = "start")]
+        start: bool,
+
+        /// Don't enable the service on boot
+        #[arg(long = "no-enable")]
+        no_enable: bool,
+
+        /// Print the unit instead of installing it
+        #[arg(long = "print")]
+        print: bool,
+    },
+
+    /// Stop, disable and remove the service
+    Uninstall {
+        /// Service name (default: horus-<project name>)
+        #[arg(short = 'n', long = "name")]
+        name: Option<String>,
+
+        /// User service
+        #[arg(long = "user")]
+        user: bool,
+    },
+
+    /// Show the service status
+    Status {
+        /// Service name (default: horus-<project name>)
+        #[arg(short = 'n', long = "name")]
+        name: Option<String>,
+
+        /// User service
+        #[arg(long = "user")]
+        user: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum CiCommands {
+    /// Generate a CI config that builds, checks, tests and benchmarks the workspace
+    Init {
+        /// CI system to generate for
+        #[arg(short = 'p', long = "provider", value_enum, default_value = "github")]
+        provider: commands::ci::CiProvider,
+
+        /// Workspace directory (default: current directory)
+        #[arg(long = "path", default_value = ".")]
+        path: PathBuf,
+
+        /// Overwrite an existing config
+        #[arg(short = 'f', long = "force")]
+        force: bool,
+    },
+
+    /// Fail if benchmarks regressed against a baseline (bencher format)
+    BenchGate {
+        /// Baseline results (a missing file passes)
+        #[arg(long = "baseline")]
+        baseline: PathBuf,
+
+        /// Results of the current run
+        #[arg(long = "current")]
+        current: PathBuf,
+
+        /// Allowed slowdown in percent
+        #[arg(short = 't', long = "threshold", default_value_t = commands::ci::DEFAULT_BENCH_THRESHOLD)]
+        threshold: f64,
+    },
+}
+
+#[derive(Subcommand)]
+enum AgentCommands {
+    /// Start an agent that launch files can start nodes through
+    Start {
+        /// Address to listen on
+        #[arg(short = 'b', long = "bind", default_value = "0.0.0.0")]
+        bind: String,
+
+        /// Port to listen on
+        #[arg(short = 'p', long = "port", default_value_t = commands::agent::DEFAULT_AGENT_PORT)]
+        port: u16,
+
+        /// Token clients must present (default: $HORUS_AGENT_TOKEN)
+        #[arg(long = "token")]
+        token: Option<String>,
+
+        /// Working directory for started nodes
+        #[arg(short = 'w', long = "workdir")]
+        workdir: Option<PathBuf>,
+    },
+
+    /// Show the nodes running on an agent
+    Status {
+        /// Agent address (host or host:port)
+        address: String,
+    },
+}
+
 #[derive(Subcommand)]
 enum MsgCommands {
     /// List all message types
@@ -1313,6 +1713,17 @@ enum MsgCommands {
         /// Message type name
         name: String,
     },
+
+    /// Generate Python dataclasses for message!/zero_copy_message! types
+    GenPython {
+        /// Rust source files or directories to scan
+        #[arg(default_value = "src")]
+        inputs: Vec<PathBuf>,
+
+        /// Output Python file (prints to stdout if not specified)
+        #[arg(short = 'o', long = "output")]
+        output: Option<PathBuf>,
+    },
 }
 
 /// Parse override argument in format "node.output=value"
@@ -1382,6 +1793,7 @@ fn main() {
                 | "sim3d"
                 | "driver"
                 | "deploy"
+                | "service"
                 | "record"
                 | "completion"
                 | "help"
@@ -1434,6 +1846,8 @@ fn run_command(command: Commands) -> HorusResult<()> {
             python,
             rust,
             use_macro,
+            docker,
+            template,
         } => {
             let language = if python {
                 "python"
@@ -1443,1316 +1857,148 @@ fn run_command(command: Commands) -> HorusResult<()> {
                 "" // Will use interactive prompt
             };
 
-            commands::new::create_new_project(name, path, language.to_string(), use_macro)
-                .map_err(|e| HorusError::Config(e.to_string()))
+            commands::new::create_new_project(
+                name,
+                path,
+                language.to_string(),
+                use_macro,
+                docker,
+                template,
+            )
+            .map_err(|e| HorusError::Config(e.to_string()))
         }
 
         Commands::Run {
             files,
             release,
-            clean,
-            quiet,
-            drivers,
-            enable,
-            args,
-            record,
-        } => {
-            // Set quiet mode for progress indicators
-            horus_manager::progress::set_quiet(quiet);
-
-            // Store drivers override in environment variable for later use
-            if let Some(ref driver_list) = drivers {
-                std::env::set_var("HORUS_DRIVERS", driver_list.join(","));
-            }
-
-            // Store enable capabilities in environment variable for later use
-            if let Some(ref enable_list) = enable {
-                std::env::set_var("HORUS_ENABLE", enable_list.join(","));
-            }
-
-            // If recording enabled, set environment variable for nodes to pick up
-            if let Some(ref session_name) = record {
-                std::env::set_var("HORUS_RECORD_SESSION", session_name);
-                println!(
-                    "{} Recording enabled: session '{}'",
-                    "[RECORD]".yellow().bold(),
-                    session_name
-                );
-            }
-
-            // Build and run
-            commands::run::execute_run(files, args, release, clean)
-                .map_err(|e| HorusError::Config(e.to_string()))
-        }
-
-        Commands::Build {
-            files,
-            release,
-            clean,
-            quiet,
-            drivers,
-            enable,
-        } => {
-            // Set quiet mode for progress indicators
-            horus_manager::progress::set_quiet(quiet);
-
-            // Store drivers override in environment variable for later use
-            if let Some(ref driver_list) = drivers {
-                std::env::set_var("HORUS_DRIVERS", driver_list.join(","));
-            }
-
-            // Store enable capabilities in environment variable for later use
-            if let Some(ref enable_list) = enable {
-                std::env::set_var("HORUS_ENABLE", enable_list.join(","));
-            }
-
-            // Build only - compile but don't execute
-            commands::run::execute_build_only(files, release, clean)
-                .map_err(|e| HorusError::Config(e.to_string()))
-        }
-
-        Commands::Check { path, quiet } => {
-            use horus_manager::commands::run::parse_horus_yaml_dependencies_v2;
-            use horus_manager::dependency_resolver::DependencySource;
-            use std::collections::HashSet;
-            use walkdir::WalkDir;
-
-            let target_path = path.unwrap_or_else(|| PathBuf::from("."));
-
-            if !target_path.exists() {
-                println!(
-                    "{} Path not found: {}",
-                    "[FAIL]".red(),
-                    target_path.display()
-                );
-                return Err(HorusError::Config("Path not found".to_string()));
-            }
-
-            // Check if it's a directory (workspace scan) or single file
-            if target_path.is_dir() {
-                println!(
-                    "{} Scanning workspace: {}\n",
-                    "".cyan().bold(),
-                    target_path
-                        .canonicalize()
-                        .unwrap_or(target_path.clone())
-                        .display()
-                );
-
-                let mut total_errors = 0;
-                let mut total_warnings = 0;
-                let mut files_checked = 0;
-                let mut horus_yamls: Vec<PathBuf> = Vec::new();
-                let mut rust_files: Vec<PathBuf> = Vec::new();
-                let mut python_files: Vec<PathBuf> = Vec::new();
-
-                // Collect all files to check
-                for entry in WalkDir::new(&target_path)
-                    .into_iter()
-                    .filter_entry(|e| {
-                        let name = e.file_name().to_string_lossy();
-                        // Skip hidden dirs, target, node_modules, __pycache__, .horus
-                        !name.starts_with('.')
-                            && name != "target"
-                            && name != "node_modules"
-                            && name != "__pycache__"
-                            && name != ".horus"
-                    })
-                    .filter_map(|e| e.ok())
-                {
-                    let path = entry.path();
-                    if path.is_file() {
-                        let filename = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-                        let ext = path.extension().and_then(|e| e.to_str());
-
-                        if filename == "horus.yaml" {
-                            horus_yamls.push(path.to_path_buf());
-                        } else if ext == Some("rs") && filename != "build.rs" {
-                            rust_files.push(path.to_path_buf());
-                        } else if ext == Some("py") {
-                            python_files.push(path.to_path_buf());
-                        }
-                    }
-                }
-
-                println!("  Found {} horus.yaml file(s)", horus_yamls.len());
-                println!("  Found {} Rust file(s)", rust_files.len());
-                println!("  Found {} Python file(s)\n", python_files.len());
-
-                // Find Cargo.toml directories for deep Rust checking
-                let mut cargo_dirs: HashSet<PathBuf> = HashSet::new();
-                for entry in WalkDir::new(&target_path)
-                    .into_iter()
-                    .filter_entry(|e| {
-                        let name = e.file_name().to_string_lossy();
-                        !name.starts_with('.') && name != "target" && name != "node_modules"
-                    })
-                    .filter_map(|e| e.ok())
-                {
-                    if entry.file_name() == "Cargo.toml" {
-                        if let Some(parent) = entry.path().parent() {
-                            cargo_dirs.insert(parent.to_path_buf());
-                        }
-                    }
-                }
-
-                // ═══════════════════════════════════════════════════════════
-                // PHASE 1: Validate horus.yaml manifests
-                // ═══════════════════════════════════════════════════════════
-                if !horus_yamls.is_empty() {
-                    println!("{}", "━".repeat(60).dimmed());
-                    println!(
-                        "{} Phase 1: Validating horus.yaml manifests...\n",
-                        "".cyan().bold()
-                    );
-
-                    for yaml_path in &horus_yamls {
-                        let rel_path = yaml_path.strip_prefix(&target_path).unwrap_or(yaml_path);
-                        println!("  {} {}", "".cyan(), rel_path.display());
-
-                        match fs::read_to_string(yaml_path) {
-                            Ok(content) => {
-                                match serde_yaml::from_str::<serde_yaml::Value>(&content) {
-                                    Ok(yaml) => {
-                                        let mut file_errors: Vec<String> = Vec::new();
-                                        let base_dir = yaml_path.parent().unwrap_or(Path::new("."));
-
-                                        // Required fields
-                                        if yaml.get("name").is_none() {
-                                            file_errors.push("missing 'name' field".to_string());
-                                        }
-                                        let language =
-                                            yaml.get("language").and_then(|l| l.as_str());
-                                        if language.is_none() {
-                                            file_errors
-                                                .push("missing 'language' field".to_string());
-                                        }
-
-                                        // Check main file exists
-                                        if let Some(lang) = language {
-                                            let main_exists = match lang {
-                                                "rust" => {
-                                                    base_dir.join("main.rs").exists()
-                                                        || base_dir.join("src/main.rs").exists()
-                                                        || base_dir.join("Cargo.toml").exists()
-                                                }
-                                                "python" => base_dir.join("main.py").exists(),
-                                                _ => true,
-                                            };
-                                            if !main_exists {
-                                                file_errors.push(format!(
-                                                    "main file not found for '{}'",
-                                                    lang
-                                                ));
-                                            }
-                                        }
-
-                                        // Validate path dependencies exist
-                                        if let Ok(deps) = parse_horus_yaml_dependencies_v2(
-                                            yaml_path.to_str().unwrap_or(""),
-                                        ) {
-                                            for dep in &deps {
-                                                if let DependencySource::Path(path_str) =
-                                                    &dep.source
-                                                {
-                                                    let dep_path =
-                                                        if Path::new(path_str).is_absolute() {
-                                                            PathBuf::from(path_str)
-                                                        } else {
-                                                            base_dir.join(path_str)
-                                                        };
-                                                    if !dep_path.exists() {
-                                                        file_errors.push(format!(
-                                                            "dependency '{}' path not found: {}",
-                                                            dep.name,
-                                                            dep_path.display()
-                                                        ));
-                                                    }
-                                                }
-                                            }
-                                        }
-
-                                        if file_errors.is_empty() {
-                                            println!("      {} manifest valid", "".green());
-                                        } else {
-                                            for err in &file_errors {
-                                                println!("      {} {}", "".red(), err);
-                                            }
-                                            total_errors += file_errors.len();
-                                        }
-                                    }
-                                    Err(e) => {
-                                        println!("      {} YAML parse error: {}", "".red(), e);
-                                        total_errors += 1;
-                                    }
-                                }
-                            }
-                            Err(e) => {
-                                println!("      {} Read error: {}", "".red(), e);
-                                total_errors += 1;
-                            }
-                        }
-                        files_checked += 1;
-                    }
-                }
-
-                // ═══════════════════════════════════════════════════════════
-                // PHASE 2: Deep Rust compilation check (cargo check)
-                // ═══════════════════════════════════════════════════════════
-                if !cargo_dirs.is_empty() {
-                    println!("\n{}", "━".repeat(60).dimmed());
-                    println!(
-                        "{} Phase 2: Deep Rust check (cargo check)...\n",
-                        "".cyan().bold()
-                    );
-
-                    for cargo_dir in &cargo_dirs {
-                        let rel_path = cargo_dir.strip_prefix(&target_path).unwrap_or(cargo_dir);
-                        let display_path = if rel_path.as_os_str().is_empty() {
-                            "."
-                        } else {
-                            rel_path.to_str().unwrap_or(".")
-                        };
-                        print!("  {} {} ... ", "".cyan(), display_path);
-                        std::io::Write::flush(&mut std::io::stdout()).ok();
-
-                        let output = std::process::Command::new("cargo")
-                            .arg("check")
-                            .arg("--message-format=short")
-                            .current_dir(cargo_dir)
-                            .output();
-
-                        match output {
-                            Ok(result) if result.status.success() => {
-                                println!("{}", "".green());
-                            }
-                            Ok(result) => {
-                                println!("{}", "".red());
-                                let stderr = String::from_utf8_lossy(&result.stderr);
-                                for line in stderr.lines().take(5) {
-                                    if line.contains("error") {
-                                        println!("      {} {}", "".red(), line.trim());
-                                    }
-                                }
-                                total_errors += 1;
-                            }
-                            Err(e) => {
-                                println!("{} cargo error: {}", "".yellow(), e);
-                                total_warnings += 1;
-                            }
-                        }
-                        files_checked += 1;
-                    }
-                }
-
-                // ═══════════════════════════════════════════════════════════
-                // PHASE 3: Python validation (syntax + imports)
-                // ═══════════════════════════════════════════════════════════
-                if !python_files.is_empty() {
-                    println!("\n{}", "━".repeat(60).dimmed());
-                    println!(
-                        "{} Phase 3: Python validation (syntax + imports)...\n",
-                        "".cyan().bold()
-                    );
-
-                    for py_path in &python_files {
-                        print!(
-                            "  {} {} ",
-                            "".cyan(),
-                            py_path
-                                .strip_prefix(&target_path)
-                                .unwrap_or(py_path)
-                                .display()
-                        );
-
-                        // Syntax check
-                        let syntax_check = std::process::Command::new("python3")
-                            .arg("-m")
-                            .arg("py_compile")
-                            .arg(py_path)
-                            .output();
-
-                        match syntax_check {
-                            Ok(result) if result.status.success() => {
-                                // Syntax OK - now check imports
-                                let import_script = format!(
-                                    r#"
-import ast, sys
-try:
-    with open('{}') as f:
-        tree = ast.parse(f.read())
-    imports = set()
-    for node in ast.walk(tree):
-        if isinstance(node, ast.Import):
-            for alias in node.names:
-                imports.add(alias.name.split('.')[0])
-        elif isinstance(node, ast.ImportFrom) and node.module:
-            imports.add(node.module.split('.')[0])
-    for imp in imports:
-        if imp not in ('__future__',):
-            __import__(imp)
-except ModuleNotFoundError as e:
-    print(f'ModuleNotFoundError: {{e.name}}', file=sys.stderr)
-    sys.exit(1)
-except ImportError as e:
-    print(f'ImportError: {{e}}', file=sys.stderr)
-    sys.exit(1)
-"#,
-                                    py_path.display()
-                                );
-
-                                let import_check = std::process::Command::new("python3")
-                                    .arg("-c")
-                                    .arg(&import_script)
-                                    .output();
-
-                                match import_check {
-                                    Ok(r) if r.status.success() => {
-                                        println!("{}", "".green());
-                                    }
-                                    Ok(r) => {
-                                        println!("{}", "".yellow());
-                                        let err = String::from_utf8_lossy(&r.stderr);
-                                        if !err.is_empty() {
-                                            println!(
-                                                "      {} {}",
-                                                "".yellow(),
-                                                err.lines().next().unwrap_or("").trim()
-                                            );
-                                        }
-                                        total_warnings += 1;
-                                    }
-                                    Err(_) => println!("{}", "".green()),
-                                }
-                            }
-                            Ok(result) => {
-                                println!("{}", "".red());
-                                let error = String::from_utf8_lossy(&result.stderr);
-                                if !error.is_empty() {
-                                    println!(
-                                        "      {} {}",
-                                        "".red(),
-                                        error.lines().next().unwrap_or("").trim()
-                                    );
-                                }
-                                total_errors += 1;
-                            }
-                            Err(_) => {
-                                println!("{}", "⊘".dimmed());
-                                if !quiet {
-                                    total_warnings += 1;
-                                }
-                            }
-                        }
-                        files_checked += 1;
-                    }
-                }
-
-                // Summary
-                println!("\n{}", "━".repeat(60).dimmed());
-                println!("{} Workspace Check Summary\n", "".cyan().bold());
-                println!("  Files checked: {}", files_checked);
-
-                if total_errors == 0 && total_warnings == 0 {
-                    println!("  Status: {} All checks passed!", "".green());
-                } else {
-                    if total_errors > 0 {
-                        println!("  Errors: {} {}", "".red(), total_errors);
-                    }
-                    if total_warnings > 0 && !quiet {
-                        println!("  Warnings: {} {}", "".yellow(), total_warnings);
-                    }
-                }
-                println!();
-
-                if total_errors > 0 {
-                    return Err(HorusError::Config(format!(
-                        "{} error(s) found",
-                        total_errors
-                    )));
-                }
-                return Ok(());
-            }
-
-            // Single file check (existing logic)
-            let horus_yaml_path = target_path;
-
-            // Check if it's a source file (.rs, .py) or horus.yaml
-            let extension = horus_yaml_path.extension().and_then(|s| s.to_str());
-
-            match extension {
-                Some("rs") => {
-                    // Check Rust file
-                    println!(
-                        "{} Checking Rust file: {}\n",
-                        "".cyan(),
-                        horus_yaml_path.display()
-                    );
-
-                    print!("  {} Parsing Rust syntax... ", "".cyan());
-                    let content = fs::read_to_string(&horus_yaml_path)?;
-
-                    // Use syn to parse Rust code
-                    match syn::parse_file(&content) {
-                        Ok(_) => {
-                            println!("{}", "".green());
-                            println!("\n{} Syntax check passed!", "".green().bold());
-                        }
-                        Err(e) => {
-                            println!("{}", "".red());
-                            println!("\n{} Syntax error:", "[FAIL]".red().bold());
-                            println!("  {}", e);
-                            return Err(HorusError::Config(format!("Rust syntax error: {}", e)));
-                        }
-                    }
-
-                    // Check hardware requirements
-                    use horus_manager::commands::run::check_hardware_requirements;
-                    if let Err(e) = check_hardware_requirements(&horus_yaml_path, "rust") {
-                        eprintln!("\n{} Hardware check error: {}", "[WARNING]".yellow(), e);
-                    }
-
-                    return Ok(());
-                }
-                Some("py") => {
-                    // Check Python file
-                    println!(
-                        "{} Checking Python file: {}\n",
-                        "".cyan(),
-                        horus_yaml_path.display()
-                    );
-
-                    print!("  {} Parsing Python syntax... ", "".cyan());
-
-                    // Use python3 to check syntax
-                    let output = std::process::Command::new("python3")
-                        .arg("-m")
-                        .arg("py_compile")
-                        .arg(&horus_yaml_path)
-                        .output();
-
-                    match output {
-                        Ok(result) if result.status.success() => {
-                            println!("{}", "".green());
-                            println!("\n{} Syntax check passed!", "".green().bold());
-                        }
-                        Ok(result) => {
-                            println!("{}", "".red());
-                            let error = String::from_utf8_lossy(&result.stderr);
-                            println!("\n{} Syntax error:", "[FAIL]".red().bold());
-                            println!("  {}", error);
-                            return Err(HorusError::Config(format!(
-                                "Python syntax error: {}",
-                                error
-                            )));
-                        }
-                        Err(e) => {
-                            println!("{}", "[WARNING]".yellow());
-                            println!(
-                                "\n{} Could not check Python syntax (python3 not found): {}",
-                                "[WARNING]".yellow(),
-                                e
-                            );
-                        }
-                    }
-                    return Ok(());
-                }
-                _ => {
-                    // Assume it's horus.yaml or yaml file
-                    println!("{} Checking {}...\n", "".cyan(), horus_yaml_path.display());
-                }
-            }
-
-            let mut errors = Vec::new();
-            let mut warn_msgs = Vec::new();
-            let base_dir = horus_yaml_path.parent().unwrap_or(Path::new("."));
-
-            // 1. YAML Syntax Validation
-            print!("  {} Validating YAML syntax... ", "".cyan());
-            let yaml_content = match fs::read_to_string(&horus_yaml_path) {
-                Ok(content) => {
-                    println!("{}", "".green());
-                    content
-                }
-                Err(e) => {
-                    println!("{}", "".red());
-                    errors.push(format!("Cannot read file: {}", e));
-                    String::new()
-                }
-            };
-
-            let yaml_value: Option<serde_yaml::Value> = if !yaml_content.is_empty() {
-                match serde_yaml::from_str(&yaml_content) {
-                    Ok(val) => Some(val),
-                    Err(e) => {
-                        errors.push(format!("Invalid YAML syntax: {}", e));
-                        None
-                    }
-                }
-            } else {
-                None
-            };
-
-            // 2. Required Fields Check
-            if let Some(ref yaml) = yaml_value {
-                print!("  {} Checking required fields... ", "".cyan());
-                let mut missing_fields = Vec::new();
-
-                if yaml.get("name").is_none() {
-                    missing_fields.push("name");
-                }
-                if yaml.get("version").is_none() {
-                    missing_fields.push("version");
-                }
-
-                if missing_fields.is_empty() {
-                    println!("{}", "".green());
-                } else {
-                    println!("{}", "".red());
-                    errors.push(format!(
-                        "Missing required fields: {}",
-                        missing_fields.join(", ")
-                    ));
-                }
-
-                // Optional fields warning
-                if !quiet {
-                    if yaml.get("description").is_none() {
-                        warn_msgs.push("Optional field missing: description".to_string());
-                    }
-                    if yaml.get("author").is_none() {
-                        warn_msgs.push("Optional field missing: author".to_string());
-                    }
-                }
-
-                // License warning (encourage projects to declare their license)
-                print!("  {} Checking license field... ", "".cyan());
-                let missing_license_warning = "No license specified. Consider adding a license field (e.g., Apache-2.0, BSD-3-Clause).";
-                if let Some(license) = yaml.get("license").and_then(|l| l.as_str()) {
-                    if license.trim().is_empty() {
-                        println!("{}", "[WARNING]".yellow());
-                        warn_msgs.push(missing_license_warning.to_string());
-                    } else {
-                        println!("{} ({})", "".green(), license.dimmed());
-                    }
-                } else {
-                    println!("{}", "[WARNING]".yellow());
-                    warn_msgs.push(missing_license_warning.to_string());
-                }
-
-                // Language validation
-                print!("  {} Validating language field... ", "".cyan());
-                if let Some(language) = yaml.get("language").and_then(|l| l.as_str()) {
-                    if language == "rust" || language == "python" {
-                        println!("{}", "".green());
-                    } else {
-                        println!("{}", "".red());
-                        errors.push(format!(
-                            "Invalid language '{}' - must be: rust or python",
-                            language
-                        ));
-                    }
-                } else {
-                    println!("{}", "".red());
-                    errors.push(
-                        "Missing or invalid 'language' field - must be: rust or python".to_string(),
-                    );
-                }
-
-                // Version format validation
-                print!("  {} Validating version format... ", "".cyan());
-                if let Some(version_str) = yaml.get("version").and_then(|v| v.as_str()) {
-                    use semver::Version;
-                    match Version::parse(version_str) {
-                        Ok(_) => println!("{}", "".green()),
-                        Err(e) => {
-                            println!("{}", "".red());
-                            errors.push(format!(
-                                "Invalid version format '{}': {} (must be valid semver like 0.1.0)",
-                                version_str, e
-                            ));
-                        }
-                    }
-                } else if yaml.get("version").is_some() {
-                    println!("{}", "".red());
-                    errors.push("Version field must be a string".to_string());
-                }
-
-                // Project name validation
-                print!("  {} Validating project name... ", "".cyan());
-                if let Some(name) = yaml.get("name").and_then(|n| n.as_str()) {
-                    let mut name_issues = Vec::new();
-
-                    if name.is_empty() {
-                        name_issues.push("name cannot be empty");
-                    }
-                    if name.contains(' ') {
-                        name_issues.push("name cannot contain spaces");
-                    }
-                    if name
-                        .chars()
-                        .any(|c| !c.is_ascii_alphanumeric() && c != '_' && c != '-')
-                    {
-                        name_issues.push(
-                            "name can only contain letters, numbers, hyphens, and underscores",
-                        );
-                    }
-
-                    if name_issues.is_empty() {
-                        println!("{}", "".green());
-                        // Warn if uppercase
-                        if !quiet && name.chars().any(|c| c.is_uppercase()) {
-                            warn_msgs.push(format!(
-                                "Project name '{}' contains uppercase - consider using lowercase",
-                                name
-                            ));
-                        }
-                    } else {
-                        println!("{}", "".red());
-                        for issue in name_issues {
-                            errors.push(format!("Invalid project name: {}", issue));
-                        }
-                    }
-                }
-
-                // Main file existence check
-                print!("  {} Checking for main file... ", "".cyan());
-                if let Some(language) = yaml.get("language").and_then(|l| l.as_str()) {
-                    let main_files = match language {
-                        "rust" => vec!["main.rs", "src/main.rs"],
-                        "python" => vec!["main.py"],
-                        _ => vec![],
-                    };
-
-                    let mut found = false;
-                    for main_file in &main_files {
-                        let path = base_dir.join(main_file);
-                        if path.exists() {
-                            println!("{}", "".green());
-                            found = true;
-                            break;
-                        }
-                    }
-
-                    if !found && !main_files.is_empty() {
-                        println!("{}", "".yellow());
-                        if !quiet {
-                            warn_msgs.push(format!(
-                                "No main file found - expected one of: {}",
-                                main_files.join(", ")
-                            ));
-                        }
-                    }
-                } else {
-                    println!("{}", "⊘".dimmed());
-                }
-            }
-
-            // 3. Parse Dependencies
-            print!("  {} Parsing dependencies... ", "".cyan());
-            let dep_specs =
-                match parse_horus_yaml_dependencies_v2(horus_yaml_path.to_str().unwrap()) {
-                    Ok(specs) => {
-                        println!("{}", "".green());
-                        specs
-                    }
-                    Err(e) => {
-                        println!("{}", "".red());
-                        errors.push(format!("Failed to parse dependencies: {}", e));
-                        Vec::new()
-                    }
-                };
-
-            // 4. Check for Duplicates
-            if !dep_specs.is_empty() {
-                print!("  {} Checking for duplicates... ", "".cyan());
-                let mut seen = HashSet::new();
-                let mut duplicates = Vec::new();
-
-                for spec in &dep_specs {
-                    if !seen.insert(&spec.name) {
-                        duplicates.push(spec.name.clone());
-                    }
-                }
-
-                if duplicates.is_empty() {
-                    println!("{}", "".green());
-                } else {
-                    println!("{}", "".red());
-                    errors.push(format!("Duplicate dependencies: {}", duplicates.join(", ")));
-                }
-            }
-
-            // 5. Validate Path Dependencies
-            println!("\n  {} Checking path dependencies...", "".cyan());
-            let mut path_deps_found = false;
-
-            for spec in &dep_specs {
-                use horus_manager::dependency_resolver::DependencySource;
-                if let DependencySource::Path(ref path) = spec.source {
-                    path_deps_found = true;
-                    let resolved_path = if path.is_absolute() {
-                        path.clone()
-                    } else {
-                        base_dir.join(path)
-                    };
-
-                    if resolved_path.exists() {
-                        if resolved_path.is_dir() {
-                            println!("    {} {} ({})", "".green(), spec.name, path.display());
-                        } else {
-                            println!(
-                                "    {} {} ({}) - Not a directory",
-                                "[FAIL]".red(),
-                                spec.name,
-                                path.display()
-                            );
-                            errors.push(format!(
-                                "Path dependency '{}' is not a directory: {}",
-                                spec.name,
-                                path.display()
-                            ));
-                        }
-                    } else {
-                        println!(
-                            "    {} {} ({}) - Path not found",
-                            "[FAIL]".red(),
-                            spec.name,
-                            path.display()
-                        );
-                        errors.push(format!(
-                            "Path dependency '{}' not found: {}",
-                            spec.name,
-                            path.display()
-                        ));
-                    }
-                }
-            }
-
-            if !path_deps_found {
-                println!("    {} No path dependencies", "".dimmed());
-            }
-
-            // 6. Circular Dependency Detection (Simple Check)
-            println!("\n  {} Checking for circular dependencies...", "".cyan());
-            let mut circular_found = false;
-
-            for spec in &dep_specs {
-                use horus_manager::dependency_resolver::DependencySource;
-                if let DependencySource::Path(ref path) = spec.source {
-                    let resolved_path = if path.is_absolute() {
-                        path.clone()
-                    } else {
-                        base_dir.join(path)
-                    };
-
-                    // Check if target has horus.yaml
-                    let target_yaml = resolved_path.join("horus.yaml");
-                    if target_yaml.exists() {
-                        // Check if it references us back
-                        if let Ok(target_deps) =
-                            parse_horus_yaml_dependencies_v2(target_yaml.to_str().unwrap())
-                        {
-                            let our_name = yaml_value
-                                .as_ref()
-                                .and_then(|y| y.get("name"))
-                                .and_then(|n| n.as_str())
-                                .unwrap_or("");
-
-                            for target_dep in target_deps {
-                                if target_dep.name == our_name {
-                                    if let DependencySource::Path(_) = target_dep.source {
-                                        circular_found = true;
-                                        errors.push(format!(
-                                            "Circular dependency detected: {} -> {} -> {}",
-                                            our_name, spec.name, our_name
-                                        ));
-                                        println!(
-                                            "    {} Circular: {} <-> {}",
-                                            "[FAIL]".red(),
-                                            our_name,
-                                            spec.name
-                                        );
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-
-            if !circular_found {
-                println!("    {} No circular dependencies", "".green());
-            }
-
-            // 7. Version Constraint Validation
-            print!("\n  {} Validating version constraints... ", "".cyan());
-
-            for spec in &dep_specs {
-                // Version requirement is already parsed in DependencySpec
-                // If it parsed successfully, it's valid
-                // But we can check for common mistakes
-                if spec.requirement.to_string() == "*" && !quiet {
-                    warn_msgs.push(format!("Dependency '{}' uses wildcard version (*) - consider pinning to a specific version", spec.name));
-                }
-            }
-
-            println!("{}", "".green());
-
-            // 8. Workspace Structure Check
-            print!("\n  {} Checking workspace structure... ", "".cyan());
-            let base_dir = horus_yaml_path.parent().unwrap_or_else(|| Path::new("."));
-            let horus_dir = base_dir.join(".horus");
-
-            if horus_dir.exists() && horus_dir.is_dir() {
-                println!("{}", "".green());
-            } else {
-                println!("{}", "".yellow());
-                if !quiet {
-                    warn_msgs.push(
-                        "No .horus/ workspace directory found - will be created on first run"
-                            .to_string(),
-                    );
-                }
-            }
-
-            // 9. Dependency Installation Check
-            print!("  {} Checking installed dependencies... ", "".cyan());
-            if horus_dir.exists() {
-                let packages_dir = horus_dir.join("packages");
-                if packages_dir.exists() {
-                    let mut missing_deps = Vec::new();
-
-                    for spec in &dep_specs {
-                        match &spec.source {
-                            DependencySource::Registry => {
-                                let package_dir = packages_dir.join(&spec.name);
-                                if !package_dir.exists() {
-                                    missing_deps.push(spec.name.clone());
-                                }
-                            }
-                            DependencySource::Path(_) => {
-                                // Path deps checked separately
-                            }
-                            DependencySource::Git { .. } => {
-                                // Git deps are cloned by horus run, skip here
-                            }
-                        }
-                    }
-
-                    if missing_deps.is_empty() {
-                        println!("{}", "".green());
-                    } else {
-                        println!("{}", "".yellow());
-                        if !missing_deps.is_empty() && !quiet {
-                            warn_msgs.push(format!(
-                                "Missing dependencies: {} (run 'horus run' to install)",
-                                missing_deps.join(", ")
-                            ));
-                        }
-                    }
-                } else {
-                    println!("{}", "".yellow());
-                    if !quiet {
-                        warn_msgs.push(
-                            "No packages directory - dependencies not installed yet".to_string(),
-                        );
-                    }
-                }
-            } else {
-                println!("{}", "⊘".dimmed());
-            }
-
-            // 10. Toolchain Check
-            print!("  {} Checking toolchain... ", "".cyan());
-            if let Some(ref yaml) = yaml_value {
-                if let Some(language) = yaml.get("language").and_then(|l| l.as_str()) {
-                    let toolchain_available = match language {
-                        "rust" => std::process::Command::new("rustc")
-                            .arg("--version")
-                            .output()
-                            .map(|o| o.status.success())
-                            .unwrap_or(false),
-                        "python" => std::process::Command::new("python3")
-                            .arg("--version")
-                            .output()
-                            .map(|o| o.status.success())
-                            .unwrap_or(false),
-                        _ => false,
-                    };
-
-                    if toolchain_available {
-                        println!("{}", "".green());
-                    } else {
-                        println!("{}", "".red());
-                        errors.push(format!(
-                            "Required toolchain for '{}' not found in PATH",
-                            language
-                        ));
-                    }
-                } else {
-                    println!("{}", "⊘".dimmed());
-                }
-            } else {
-                println!("{}", "⊘".dimmed());
-            }
-
-            // 11. Code Validation (optional, can be slow)
-            print!("  {} Validating code syntax... ", "".cyan());
-            if let Some(ref yaml) = yaml_value {
-                if let Some(language) = yaml.get("language").and_then(|l| l.as_str()) {
-                    match language {
-                        "rust" => {
-                            // Check for Cargo.toml or main.rs
-                            let has_cargo = base_dir.join("Cargo.toml").exists();
-                            let has_main = base_dir.join("main.rs").exists()
-                                || base_dir.join("src/main.rs").exists();
-
-                            if has_cargo || has_main {
-                                let check_result = std::process::Command::new("cargo")
-                                    .arg("build")
-                                    .arg("--quiet")
-                                    .current_dir(base_dir)
-                                    .output();
-
-                                match check_result {
-                                    Ok(output) if output.status.success() => {
-                                        println!("{}", "".green());
-                                    }
-                                    Ok(_) => {
-                                        println!("{}", "".red());
-                                        errors.push("Rust code has compilation errors (run 'cargo build' for details)".to_string());
-                                    }
-                                    Err(_) => {
-                                        println!("{}", "".yellow());
-                                        if !quiet {
-                                            warn_msgs.push("Could not run 'cargo build' - skipping code validation".to_string());
-                                        }
-                                    }
-                                }
-                            } else {
-                                println!("{}", "⊘".dimmed());
-                            }
-                        }
-                        "python" => {
-                            // Check main.py syntax
-                            let main_py = base_dir.join("main.py");
-                            if main_py.exists() {
-                                let check_result = std::process::Command::new("python3")
-                                    .arg("-m")
-                                    .arg("py_compile")
-                                    .arg(&main_py)
-                                    .output();
-
-                                match check_result {
-                                    Ok(output) if output.status.success() => {
-                                        println!("{}", "".green());
-                                    }
-                                    Ok(_) => {
-                                        println!("{}", "".red());
-                                        errors.push("Python code has syntax errors".to_string());
-                                    }
-                                    Err(_) => {
-                                        println!("{}", "".yellow());
-                                        if !quiet {
-                                            warn_msgs.push(
-                                                "Could not validate Python syntax".to_string(),
-                                            );
-                                        }
-                                    }
-                                }
-                            } else {
-                                println!("{}", "⊘".dimmed());
-                            }
-                        }
-                        _ => {
-                            println!("{}", "⊘".dimmed());
-                        }
-                    }
-                } else {
-                    println!("{}", "⊘".dimmed());
-                }
-            } else {
-                println!("{}", "⊘".dimmed());
-            }
+            clean,
+            quiet,
+            drivers,
+            enable,
+            args,
+            record,
+            container,
+            image,
+            launch_args,
+            namespace,
+            dev,
+        } => {
+            // Set quiet mode for progress indicators
+            horus_manager::progress::set_quiet(quiet);
 
-            // 12. HORUS System Check
-            print!("\n  {} Checking HORUS installation... ", "".cyan());
-            let horus_version = env!("CARGO_PKG_VERSION");
-            println!("v{}", horus_version.dimmed());
-
-            // 13. Registry Connectivity
-            print!("  {} Checking registry connectivity... ", "".cyan());
-            // Simple connectivity check - try to connect to registry
-            let registry_available = std::process::Command::new("ping")
-                .arg("-c")
-                .arg("1")
-                .arg("-W")
-                .arg("1")
-                .arg("registry.horus.rs")
-                .output()
-                .map(|o| o.status.success())
-                .unwrap_or(false);
-
-            if registry_available {
-                println!("{}", "".green());
-            } else {
-                println!("{}", "⊘".dimmed());
-                if !quiet {
-                    warn_msgs
-                        .push("Registry not reachable - package installation may fail".to_string());
-                }
+            // Store drivers override in environment variable for later use
+            if let Some(ref driver_list) = drivers {
+                std::env::set_var("HORUS_DRIVERS", driver_list.join(","));
             }
 
-            // 14. System Requirements Check
-            print!("  {} Checking system requirements... ", "".cyan());
-            let mut sys_issues = Vec::new();
-
-            // Check shared memory directory availability (cross-platform)
-            #[allow(unused_variables)] // Used in non-Linux cfg block
-            let shm_path = shm_base_dir();
-            // On Linux, check /dev/shm permissions; on other platforms, just check the base dir
-            if has_native_shm() {
-                #[cfg(target_os = "linux")]
-                {
-                    let dev_shm = std::path::Path::new("/dev/shm");
-                    if !dev_shm.exists() {
-                        sys_issues.push("/dev/shm not available");
-                    } else if let Ok(metadata) = std::fs::metadata(dev_shm) {
-                        use std::os::unix::fs::PermissionsExt;
-                        let mode = metadata.permissions().mode();
-                        if mode & 0o777 != 0o777 {
-                            sys_issues.push("/dev/shm permissions restrictive");
-                        }
-                    }
-                }
+            // Store enable capabilities in environment variable for later use
+            if let Some(ref enable_list) = enable {
+                std::env::set_var("HORUS_ENABLE", enable_list.join(","));
             }
-            // For non-Linux platforms, ensure the shared memory directory can be created
-            #[cfg(not(target_os = "linux"))]
-            {
-                if let Err(_) = std::fs::create_dir_all(&shm_path) {
-                    sys_issues.push("Cannot create shared memory directory");
-                }
+
+            // Namespace for all topics and frames of the program
+            if let Some(ref ns) = namespace {
+                horus_core::communication::namespace::validate_namespace(ns)?;
+                std::env::set_var(horus_core::communication::namespace::NAMESPACE_ENV, ns);
             }
 
-            if sys_issues.is_empty() {
-                println!("{}", "".green());
-            } else {
-                println!("{}", "".yellow());
-                for issue in sys_issues {
-                    if !quiet {
-                        warn_msgs.push(format!("System issue: {}", issue));
-                    }
-                }
+            // If recording enabled, set environment variable for nodes to pick up
+            if let Some(ref session_name) = record {
+                std::env::set_var("HORUS_RECORD_SESSION", session_name);
+                println!(
+                    "{} Recording enabled: session '{}'",
+                    "[RECORD]".yellow().bold(),
+                    session_name
+                );
             }
 
-            // Disk Space Check
-            print!("  {} Checking available disk space... ", "".cyan());
-            #[cfg(target_os = "linux")]
-            {
-                use std::process::Command;
-
-                // Check available space in current directory (where .horus will be created)
-                if let Ok(output) = Command::new("df").arg("-BM").arg(base_dir).output() {
-                    if output.status.success() {
-                        let output_str = String::from_utf8_lossy(&output.stdout);
-                        // Parse df output: Filesystem  1M-blocks  Used Available Use% Mounted
-                        if let Some(line) = output_str.lines().nth(1) {
-                            let parts: Vec<&str> = line.split_whitespace().collect();
-                            if parts.len() >= 4 {
-                                if let Some(available) = parts[3].strip_suffix('M') {
-                                    if let Ok(available_mb) = available.parse::<u64>() {
-                                        // Warn if less than 500MB available
-                                        if available_mb < 500 {
-                                            println!("{} ({}MB free)", "".yellow(), available_mb);
-                                            if !quiet {
-                                                warn_msgs.push(format!(
-                                                    "Low disk space: only {}MB available (recommended: 500MB+)",
-                                                    available_mb
-                                                ));
-                                            }
-                                        } else if available_mb < 100 {
-                                            println!("{} ({}MB free)", "".red(), available_mb);
-                                            errors.push(format!(
-                                                "Critically low disk space: only {}MB available",
-                                                available_mb
-                                            ));
-                                        } else {
-                                            println!("{} ({}MB free)", "".green(), available_mb);
-                                        }
-                                    } else {
-                                        println!("{}", "⊘".dimmed());
-                                    }
-                                } else {
-                                    println!("{}", "⊘".dimmed());
-                                }
-                            } else {
-                                println!("{}", "⊘".dimmed());
-                            }
-                        } else {
-                            println!("{}", "⊘".dimmed());
-                        }
-                    } else {
-                        println!("{}", "⊘".dimmed());
-                    }
-                } else {
-                    println!("{}", "⊘".dimmed());
-                }
+            // Python nodes watch their source files and reload changed code
+            if dev {
+                std::env::set_var("HORUS_DEV", "1");
+                println!(
+                    "{} Hot reload enabled for Python nodes",
+                    "[DEV]".cyan().bold()
+                );
             }
-            #[cfg(not(target_os = "linux"))]
-            {
-                println!("{}", "⊘".dimmed());
+
+            // Build and run inside a container (settings above are forwarded)
+            if container {
+                return commands::container::run_in_container(
+                    files,
+                    args,
+                    release,
+                    clean,
+                    image,
+                    launch_args,
+                )
+                .map_err(|e| HorusError::Config(e.to_string()));
             }
 
-            // 15. API Usage Check (basic pattern matching)
-            print!("  {} Checking API usage... ", "".cyan());
-            if let Some(ref yaml) = yaml_value {
-                if let Some(language) = yaml.get("language").and_then(|l| l.as_str()) {
-                    match language {
-                        "rust" => {
-                            // Check if using HORUS dependencies
-                            let uses_horus = dep_specs
-                                .iter()
-                                .any(|spec| spec.name == "horus" || spec.name == "horus_macros");
-
-                            if uses_horus {
-                                // Quick check for common patterns in main.rs or src/main.rs
-                                let main_paths =
-                                    vec![base_dir.join("main.rs"), base_dir.join("src/main.rs")];
-
-                                let mut has_scheduler = false;
-                                for main_path in main_paths {
-                                    if main_path.exists() {
-                                        if let Ok(content) = std::fs::read_to_string(&main_path) {
-                                            has_scheduler = content.contains("Scheduler::new")
-                                                || content.contains("scheduler.register");
-                                            if has_scheduler {
-                                                break;
-                                            }
-                                        }
-                                    }
-                                }
+            // Build and run
+            commands::run::execute_run(files, args, release, clean, launch_args)
+                .map_err(|e| HorusError::Config(e.to_string()))
+        }
 
-                                if has_scheduler {
-                                    println!("{}", "".green());
-                                } else {
-                                    println!("{}", "".yellow());
-                                    if !quiet {
-                                        warn_msgs.push("HORUS dependency found but no Scheduler usage detected".to_string());
-                                    }
-                                }
-                            } else {
-                                println!("{}", "⊘".dimmed());
-                            }
-                        }
-                        "python" => {
-                            let uses_horus = dep_specs.iter().any(|spec| spec.name == "horus_py");
-
-                            if uses_horus {
-                                let main_py = base_dir.join("main.py");
-                                if main_py.exists() {
-                                    if let Ok(content) = std::fs::read_to_string(&main_py) {
-                                        if content.contains("import horus")
-                                            || content.contains("from horus")
-                                        {
-                                            println!("{}", "".green());
-                                        } else {
-                                            println!("{}", "".yellow());
-                                            if !quiet {
-                                                warn_msgs.push("horus_py dependency but no 'import horus' found".to_string());
-                                            }
-                                        }
-                                    } else {
-                                        println!("{}", "⊘".dimmed());
-                                    }
-                                } else {
-                                    println!("{}", "⊘".dimmed());
-                                }
-                            } else {
-                                println!("{}", "⊘".dimmed());
-                            }
+        Commands::Build {
+            files,
+            release,
+            clean,
+            quiet,
+            drivers,
+            enable,
+        } => {
+            // Set quiet mode for progress indicators
+            horus_manager::progress::set_quiet(quiet);
 
-                            // Check external Python dependencies
-                            print!("  {} Checking Python external dependencies... ", "".cyan());
-                            let main_py = base_dir.join("main.py");
-                            if main_py.exists() {
-                                match parse_python_imports(&main_py) {
-                                    Ok(imports) if !imports.is_empty() => {
-                                        let mut missing_packages = Vec::new();
-                                        for package in &imports {
-                                            if !check_system_package_exists(package) {
-                                                missing_packages.push(package.clone());
-                                            }
-                                        }
+            // Store drivers override in environment variable for later use
+            if let Some(ref driver_list) = drivers {
+                std::env::set_var("HORUS_DRIVERS", driver_list.join(","));
+            }
 
-                                        if missing_packages.is_empty() {
-                                            println!("{} ({})", "".green(), imports.len());
-                                        } else {
-                                            println!("{}", "".red());
-                                            errors.push(format!(
-                                                "Missing Python packages: {} (install with: pip install {})",
-                                                missing_packages.join(", "),
-                                                missing_packages.join(" ")
-                                            ));
-                                        }
-                                    }
-                                    Ok(_) => {
-                                        println!("{}", "⊘".dimmed());
-                                    }
-                                    Err(e) => {
-                                        println!("{}", "".yellow());
-                                        if !quiet {
-                                            warn_msgs.push(format!(
-                                                "Could not parse Python imports: {}",
-                                                e
-                                            ));
-                                        }
-                                    }
-                                }
-                            } else {
-                                println!("{}", "⊘".dimmed());
-                            }
-                        }
-                        _ => {
-                            println!("{}", "⊘".dimmed());
-                        }
-                    }
-                } else {
-                    println!("{}", "⊘".dimmed());
-                }
-            } else {
-                println!("{}", "⊘".dimmed());
+            // Store enable capabilities in environment variable for later use
+            if let Some(ref enable_list) = enable {
+                std::env::set_var("HORUS_ENABLE", enable_list.join(","));
             }
 
-            // Print Summary
-            println!();
-            if !quiet {
-                if warn_msgs.is_empty() {
-                    println!("{} No warnings detected.", "".green());
-                } else {
-                    println!("{} {} warning(s):", "[WARNING]".yellow(), warn_msgs.len());
-                    for warn in &warn_msgs {
-                        println!("  - {}", warn);
-                    }
+            // Build only - compile but don't execute
+            commands::run::execute_build_only(files, release, clean)
+                .map_err(|e| HorusError::Config(e.to_string()))
+        }
+
+        Commands::Check {
+            path,
+            quiet,
+            format,
+            sarif: sarif_path,
+        } => {
+            use horus_manager::commands::check::{run_check, CheckFormat};
+
+            let target_path = path.unwrap_or_else(|| PathBuf::from("."));
+            let report = run_check(&target_path, quiet, format)
+                .map_err(|e| HorusError::Config(e.to_string()))?;
+
+            if let Some(sarif_path) = &sarif_path {
+                report.to_sarif().write(sarif_path)?;
+                if format == CheckFormat::Text {
+                    println!("  SARIF report: {}", sarif_path.display());
                 }
-                println!();
+            }
+            match format {
+                CheckFormat::Text => println!(),
+                CheckFormat::Json => println!(
+                    "{}",
+                    serde_json::to_string_pretty(&report.to_json())
+                        .map_err(|e| HorusError::Config(e.to_string()))?
+                ),
             }
 
-            if errors.is_empty() {
-                println!("{} All checks passed!", "".green().bold());
-                Ok(())
-            } else {
-                println!(
-                    "{} {} error(s) found:\n",
-                    "[FAIL]".red().bold(),
-                    errors.len()
-                );
-                for (i, err) in errors.iter().enumerate() {
-                    println!("  {}. {}", i + 1, err);
-                }
-                println!();
-                Err(HorusError::Config("Validation failed".to_string()))
+            if report.errors() > 0 {
+                return Err(HorusError::Config(format!(
+                    "{} error(s) found",
+                    report.errors()
+                )));
             }
+            Ok(())
         }
 
         Commands::Test {
@@ -2762,6 +2008,7 @@ except ImportError as e:
             test_threads,
             parallel,
             simulation,
+            no_parity,
             integration,
             no_build,
             no_cleanup,
@@ -2790,6 +2037,7 @@ except ImportError as e:
                 no_build,
                 no_cleanup,
                 verbose,
+                no_parity,
             )
             .map_err(|e| HorusError::Config(e.to_string()))?;
             Ok(())
@@ -2799,7 +2047,56 @@ except ImportError as e:
             port,
             tui,
             reset_password,
+            agent,
+            bind,
+            tls_cert,
+            tls_key,
+            insecure,
+            remote,
+            create_token,
+            revoke_token,
+            list_tokens,
         } => {
+            // API token management, no monitor is started
+            if create_token.is_some() || revoke_token.is_some() || list_tokens {
+                let store = security::api_tokens::ApiTokenStore::open_default()
+                    .map_err(|e| HorusError::Config(e.to_string()))?;
+                if let Some(name) = create_token {
+                    let token = store
+                        .create(&name)
+                        .map_err(|e| HorusError::Config(e.to_string()))?;
+                    println!("{} Created API token '{}':", "".green(), name);
+                    println!("\n  {}\n", token.bold());
+                    println!("  {} Store it now, it cannot be shown again", "".yellow());
+                }
+                if let Some(name) = revoke_token {
+                    let revoked = store
+                        .revoke(&name)
+                        .map_err(|e| HorusError::Config(e.to_string()))?;
+                    if !revoked {
+                        return Err(HorusError::Config(format!(
+                            "API token '{}' not found",
+                            name
+                        )));
+                    }
+                    println!("{} Revoked API token '{}'", "".green(), name);
+                }
+                if list_tokens {
+                    let tokens = store
+                        .list()
+                        .map_err(|e| HorusError::Config(e.to_string()))?;
+                    if tokens.is_empty() {
+                        println!(
+                            "No API tokens. Create one with 'horus monitor --create-token <NAME>'"
+                        );
+                    }
+                    for token in tokens {
+                        println!("  {}  {}", token.name.bold(), token.created_at.dimmed());
+                    }
+                }
+                return Ok(());
+            }
+
             // Reset password if requested
             if reset_password {
                 security::auth::reset_password().map_err(|e| HorusError::Config(e.to_string()))?;
@@ -2810,36 +2107,57 @@ except ImportError as e:
                 // Launch TUI monitor
                 monitor_tui::TuiDashboard::run().map_err(|e| HorusError::Config(e.to_string()))
             } else {
-                // Default: Launch web monitor and auto-open browser
-                println!(
-                    "{} Starting HORUS monitor on http://localhost:{}...",
-                    "".cyan(),
-                    port
-                );
-                println!("  {} Password-protected access", "".dimmed());
-                println!("  {} Opening browser...", "".dimmed());
-                println!(
-                    "  {} Use 'horus monitor -t' for Terminal UI",
-                    "Tip:".dimmed()
-                );
+                let runtime = tokio::runtime::Runtime::new().unwrap();
+                let result = if agent {
+                    // Headless agent on the robot
+                    println!(
+                        "{} Starting HORUS monitor agent on {}:{}...",
+                        "".cyan(),
+                        bind,
+                        port
+                    );
+                    let tls = tls_cert
+                        .zip(tls_key)
+                        .map(|(cert, key)| monitor_remote::AgentTls { cert, key });
+                    runtime.block_on(monitor_remote::run_agent(port, bind, tls, insecure))
+                } else if let Some(host) = remote {
+                    // Local dashboard for a remote agent
+                    println!(
+                        "{} Connecting to HORUS monitor agent at {}...",
+                        "".cyan(),
+                        host
+                    );
+                    runtime.block_on(monitor_remote::run_remote(&host, port))
+                } else {
+                    // Default: Launch web monitor and auto-open browser
+                    println!(
+                        "{} Starting HORUS monitor on http://localhost:{}...",
+                        "".cyan(),
+                        port
+                    );
+                    println!("  {} Password-protected access", "".dimmed());
+                    println!("  {} Opening browser...", "".dimmed());
+                    println!(
+                        "  {} Use 'horus monitor -t' for Terminal UI",
+                        "Tip:".dimmed()
+                    );
+                    runtime.block_on(monitor::run(port))
+                };
 
-                tokio::runtime::Runtime::new()
-                    .unwrap()
-                    .block_on(monitor::run(port))
-                    .map_err(|e| {
-                        let err_str = e.to_string();
-                        if err_str.contains("Address already in use") || err_str.contains("os error 98") {
-                            HorusError::Config(format!(
-                                "Port {} is already in use.\n  {} Try a different port: horus monitor <PORT>\n  {} Example: horus monitor {}",
-                                port,
-                                "".cyan(),
-                                "".cyan(),
-                                port + 1
-                            ))
-                        } else {
-                            HorusError::Config(err_str)
-                        }
-                    })
+                result.map_err(|e| {
+                    let err_str = e.to_string();
+                    if err_str.contains("Address already in use") || err_str.contains("os error 98") {
+                        HorusError::Config(format!(
+                            "Port {} is already in use.\n  {} Try a different port: horus monitor <PORT>\n  {} Example: horus monitor {}",
+                            port,
+                            "".cyan(),
+                            "".cyan(),
+                            port + 1
+                        ))
+                    } else {
+                        HorusError::Config(err_str)
+                    }
+                })
             }
         }
 
@@ -2869,6 +2187,25 @@ except ImportError as e:
             NodeCommands::Restart { name } => commands::node::restart_node(&name),
             NodeCommands::Pause { name } => commands::node::pause_node(&name),
             NodeCommands::Resume { name } => commands::node::resume_node(&name),
+            NodeCommands::Create {
+                name,
+                publishers,
+                subscribers,
+                use_macro,
+                rate,
+                priority,
+                path,
+            } => commands::node_create::create_node(
+                &commands::node_create::NodeSpec {
+                    name,
+                    publishers,
+                    subscribers,
+                    use_macro,
+                    rate,
+                    priority,
+                },
+                path,
+            ),
         },
 
         Commands::Param { command } => match command {
@@ -2997,7 +2334,7 @@ except ImportError as e:
             }
         },
 
-        Commands::Doctor { verbose } => commands::doctor::run_doctor(verbose),
+        Commands::Doctor { verbose, fix } => commands::doctor::run_doctor(verbose, fix),
 
         Commands::Clean { shm, all, dry_run } => commands::clean::run_clean(shm, all, dry_run),
 
@@ -3006,20 +2343,47 @@ except ImportError as e:
             dry_run,
             namespace,
             list,
+            launch_args,
         } => {
             if list {
-                commands::launch::list_launch_nodes(&file)
+                commands::launch::list_launch_nodes(&file, &launch_args)
             } else {
-                commands::launch::run_launch(&file, dry_run, namespace)
+                commands::launch::run_launch(&file, dry_run, namespace, &launch_args)
             }
         }
 
+        Commands::Agent { command } => match command {
+            AgentCommands::Start {
+                bind,
+                port,
+                token,
+                workdir,
+            } => commands::agent::run_agent(&bind, port, token, workdir),
+            AgentCommands::Status { address } => commands::agent::print_agent_status(&address),
+        },
+
+        Commands::Ci { command } => match command {
+            CiCommands::Init {
+                provider,
+                path,
+                force,
+            } => commands::ci::init(provider, &path, force),
+            CiCommands::BenchGate {
+                baseline,
+                current,
+                threshold,
+            } => commands::ci::bench_gate(&baseline, &current, threshold),
+        },
+
         Commands::Msg { command } => match command {
             MsgCommands::List { verbose, filter } => {
                 commands::msg::list_messages(verbose, filter.as_deref())
             }
             MsgCommands::Show { name } => commands::msg::show_message(&name),
             MsgCommands::Md5 { name } => commands::msg::message_hash(&name),
+            MsgCommands::GenPython { inputs, output } => {
+                commands::msg_python::generate_python(&inputs, output.as_deref())
+            }
         },
 
         Commands::Log {
@@ -3044,6 +2408,19 @@ except ImportError as e:
             }
         }
 
+        Commands::Blackbox { command } => match command {
+            BlackboxCommands::Dump {
+                file,
+                since,
+                count,
+                anomalies,
+                json,
+            } => {
+                commands::blackbox::dump(file.as_deref(), since.as_deref(), count, anomalies, json)
+            }
+            BlackboxCommands::List => commands::blackbox::list_rings(),
+        },
+
         Commands::Pkg { command } => {
             match command {
                 PkgCommands::Install {
@@ -3051,6 +2428,7 @@ except ImportError as e:
                     ver,
                     global,
                     target,
+                    allow_unsigned,
                 } => {
                     use horus_manager::yaml_utils::{
                         add_path_dependency_to_horus_yaml, is_path_like,
@@ -3163,7 +2541,7 @@ except ImportError as e:
                                 .map_err(|e| HorusError::Config(e.to_string()))?
                         };
 
-                        let client = registry::RegistryClient::new();
+                        let client = registry::RegistryClient::new().allow_unsigned(allow_unsigned);
                         client
                             .install_to_target(&package, ver.as_deref(), install_target.clone())
                             .map_err(|e| HorusError::Config(e.to_string()))?;
@@ -3700,6 +3078,74 @@ except ImportError as e:
                     Ok(())
                 }
 
+                PkgCommands::Audit {
+                    policy,
+                    offline,
+                    json,
+                } => commands::audit::run_audit(policy, offline, json),
+
+                PkgCommands::Update { package } => registry::RegistryClient::new()
+                    .update_lockfile(Path::new("."), package.as_deref())
+                    .map_err(|e| HorusError::Config(e.to_string())),
+
+                PkgCommands::Trust { command } => {
+                    use security::signing::TrustStore;
+                    let store = TrustStore::open_default()
+                        .map_err(|e| HorusError::Config(e.to_string()))?;
+                    match command {
+                        TrustCommands::List => {
+                            let keys = store
+                                .list()
+                                .map_err(|e| HorusError::Config(e.to_string()))?;
+                            if keys.is_empty() {
+                                println!("No trusted publisher keys. Add one with 'horus pkg trust add <NAME> <KEY>'");
+                            }
+                            for key in keys {
+                                println!(
+                                    "  {}  {}  {}",
+                                    key.name.bold(),
+                                    key.public_key,
+                                    key.added_at.dimmed()
+                                );
+                            }
+                        }
+                        TrustCommands::Add { name, public_key } => {
+                            store
+                                .add(&name, &public_key)
+                                .map_err(|e| HorusError::Config(e.to_string()))?;
+                            println!("{} Trusted publisher key '{}'", "".green(), name);
+                        }
+                        TrustCommands::Remove { key } => {
+                            let removed = store
+                                .remove(&key)
+                                .map_err(|e| HorusError::Config(e.to_string()))?;
+                            if !removed {
+                                return Err(HorusError::Config(format!(
+                                    "No trusted key '{}'",
+                                    key
+                                )));
+                            }
+                            println!("{} Removed trusted key '{}'", "".green(), key);
+                        }
+                    }
+                    Ok(())
+                }
+
+                PkgCommands::SigningKey => {
+                    use security::signing;
+                    let path = signing::signing_key_path()
+                        .map_err(|e| HorusError::Config(e.to_string()))?;
+                    let (key, _) = signing::load_or_create_signing_key(&path)
+                        .map_err(|e| HorusError::Config(e.to_string()))?;
+                    println!("{}", signing::public_key_hex(&key));
+                    println!(
+                        "\n  {} Users trust your packages with 'horus pkg trust add <NAME> <KEY>'",
+                        "".dimmed()
+                    );
+                    println!("  {} Private key: {}", "".dimmed(), path.display());
+                    Ok(())
+                }
+
                 PkgCommands::Publish { freeze } => {
                     let client = registry::RegistryClient::new();
                     client
@@ -3871,6 +3317,15 @@ except ImportError as e:
 
                         println!(" Found {} packages to restore", manifest.packages.len());
 
+                        // Download registry packages concurrently up front
+                        let registry_packages: Vec<(String, Option<String>)> = manifest
+                            .packages
+                            .iter()
+                            .filter(|pkg| pkg.source == registry::PackageSource::Registry)
+                            .map(|pkg| (pkg.name.clone(), Some(pkg.version.clone())))
+                            .collect();
+                        client.prefetch(&registry_packages);
+
                         // Get workspace path for horus.yaml updates
                         let workspace_path = workspace::find_workspace_root();
 
@@ -4004,6 +3459,15 @@ except ImportError as e:
 
                         println!(" Found {} packages to restore", manifest.packages.len());
 
+                        // Download registry packages concurrently up front
+                        let registry_packages: Vec<(String, Option<String>)> = manifest
+                            .packages
+                            .iter()
+                            .filter(|pkg| pkg.source == registry::PackageSource::Registry)
+                            .map(|pkg| (pkg.name.clone(), Some(pkg.version.clone())))
+                            .collect();
+                        client.prefetch(&registry_packages);
+
                         // Get workspace path for horus.yaml updates
                         let workspace_path = workspace::find_workspace_root();
 
@@ -4144,6 +3608,8 @@ except ImportError as e:
             articulated,
             preset,
             gravity,
+            lockstep,
+            use_sim_time,
         } => {
             use std::env;
             use std::process::Command;
@@ -4163,6 +3629,12 @@ except ImportError as e:
             if gravity {
                 println!("  Gravity: enabled");
             }
+            if lockstep {
+                println!("  Lockstep: enabled");
+            }
+            if use_sim_time {
+                println!("  Sim time: enabled");
+            }
             if let Some(ref world_path) = world {
                 println!("  World: {}", world_path.display());
             }
@@ -4222,6 +3694,12 @@ except ImportError as e:
                 if gravity {
                     binary_cmd.arg("--gravity");
                 }
+                if lockstep {
+                    binary_cmd.arg("--lockstep");
+                }
+                if use_sim_time {
+                    binary_cmd.arg("--use-sim-time");
+                }
 
                 binary_cmd
                     .status()
@@ -4268,6 +3746,12 @@ except ImportError as e:
                 if gravity {
                     cmd.arg("--gravity");
                 }
+                if lockstep {
+                    cmd.arg("--lockstep");
+                }
+                if use_sim_time {
+                    cmd.arg("--use-sim-time");
+                }
 
                 cmd.status()
                     .map_err(|e| HorusError::Config(format!("Failed to run sim2d: {}. Try running manually: cd {} && cargo run --release", e, sim2d_path)))?
@@ -4289,6 +3773,8 @@ except ImportError as e:
             robot,
             world,
             robot_name,
+            lockstep,
+            use_sim_time,
         } => {
             use std::env;
             use std::process::Command;
@@ -4309,6 +3795,12 @@ except ImportError as e:
                 println!("  World: {}", world_path.display());
             }
             println!("  HORUS robot name: {}", robot_name);
+            if lockstep {
+                println!("  Lockstep: enabled");
+            }
+            if use_sim_time {
+                println!("  Sim time: enabled");
+            }
             println!();
 
             // Convert relative paths to absolute paths before changing directory
@@ -4356,6 +3848,12 @@ except ImportError as e:
                     binary_cmd.arg("--world").arg(w);
                 }
                 binary_cmd.arg("--robot-name").arg(&robot_name);
+                if lockstep {
+                    binary_cmd.arg("--lockstep");
+                }
+                if use_sim_time {
+                    binary_cmd.arg("--use-sim-time");
+                }
 
                 binary_cmd
                     .status()
@@ -4391,6 +3889,12 @@ except ImportError as e:
                     cmd.arg("--world").arg(w);
                 }
                 cmd.arg("--robot-name").arg(&robot_name);
+                if lockstep {
+                    cmd.arg("--lockstep");
+                }
+                if use_sim_time {
+                    cmd.arg("--use-sim-time");
+                }
 
                 cmd.status()
                     .map_err(|e| HorusError::Config(format!("Failed to run sim3d: {}. Try running manually: cd {} && cargo run --release", e, sim3d_path)))?
@@ -4408,8 +3912,13 @@ except ImportError as e:
 
         Commands::Deploy {
             target,
+            host,
             remote_dir,
             arch,
+            builder,
+            service,
+            health_url,
+            keep,
             run_after,
             debug,
             port,
@@ -4419,11 +3928,21 @@ except ImportError as e:
         } => {
             if list {
                 commands::deploy::list_targets()
-            } else if let Some(target) = target {
-                commands::deploy::run_deploy(
-                    &target, remote_dir, arch, run_after, !debug, // release = !debug
-                    port, identity, dry_run,
-                )
+            } else if let Some(target) = target.or(host) {
+                commands::deploy::run_deploy(commands::deploy::DeployOptions {
+                    target,
+                    remote_dir,
+                    arch,
+                    builder,
+                    service,
+                    health_url,
+                    run_after,
+                    release: !debug,
+                    port,
+                    identity,
+                    keep_releases: keep,
+                    dry_run,
+                })
             } else {
                 Err(HorusError::Config(
                     "Target is required for deploy".to_string(),
@@ -4431,6 +3950,36 @@ except ImportError as e:
             }
         }
 
+        Commands::Service { command } => match command {
+            ServiceCommands::Install {
+                name,
+                user,
+                exec,
+                env,
+                env_file,
+                rt_priority,
+                no_rt,
+                watchdog,
+                start,
+                no_enable,
+                print,
+            } => commands::service::install(commands::service::InstallOptions {
+                name,
+                user,
+                exec,
+                env,
+                env_file,
+                rt_priority,
+                no_rt,
+                watchdog,
+                start,
+                no_enable,
+                print,
+            }),
+            ServiceCommands::Uninstall { name, user } => commands::service::uninstall(name, user),
+            ServiceCommands::Status { name, user } => commands::service::status(name, user),
+        },
+
         Commands::Driver { command } => {
             // Driver alias resolver (local implementation)
             fn resolve_driver_alias_local(alias: &str) -> Option<Vec<&'static str>> {
@@ -5420,7 +4969,7 @@ except ImportError as e:
         }
 
         Commands::Record { command } => {
-            use horus_core::scheduling::{diff_recordings, RecordingManager};
+            use horus_core::scheduling::{diff_recording_files, RecordingManager};
 
             let manager = RecordingManager::new();
 
@@ -5683,12 +5232,8 @@ except ImportError as e:
                                 .unwrap_or("");
 
                             if name1 == name2 && !name1.is_empty() && name1 != "scheduler" {
-                                // Load and compare
-                                if let (Ok(rec1), Ok(rec2)) = (
-                                    horus_core::scheduling::NodeRecording::load(path1),
-                                    horus_core::scheduling::NodeRecording::load(path2),
-                                ) {
-                                    let diffs = diff_recordings(&rec1, &rec2);
+                                // Compare tick by tick without loading either recording
+                                if let Ok(diffs) = diff_recording_files(path1, path2) {
                                     if !diffs.is_empty() {
                                         println!(
                                             "  {} Node '{}': {} differences",
@@ -6094,99 +5639,6 @@ fn check_system_package_exists(package_name: &str) -> bool {
     false
 }
 
-/// Parse Python imports from a file
-fn parse_python_imports(python_file: &Path) -> Result<Vec<String>, std::io::Error> {
-    let content = std::fs::read_to_string(python_file)?;
-    let mut imports = Vec::new();
-
-    for line in content.lines() {
-        let trimmed = line.trim();
-
-        // Skip comments and empty lines
-        if trimmed.starts_with('#') || trimmed.is_empty() {
-            continue;
-        }
-
-        // Parse "import X" or "import X as Y"
-        if let Some(rest) = trimmed.strip_prefix("import ") {
-            let module = rest
-                .split_whitespace()
-                .next()
-                .unwrap_or("")
-                .split('.')
-                .next()
-                .unwrap_or("")
-                .split(',')
-                .next()
-                .unwrap_or("")
-                .trim();
-
-            if !module.is_empty() && !imports.contains(&module.to_string()) {
-                imports.push(module.to_string());
-            }
-        }
-
-        // Parse "from X import Y"
-        if let Some(rest) = trimmed.strip_prefix("from ") {
-            if let Some(module) = rest.split_whitespace().next() {
-                let base_module = module.split('.').next().unwrap_or("");
-                if !base_module.is_empty() && !imports.contains(&base_module.to_string()) {
-                    imports.push(base_module.to_string());
-                }
-            }
-        }
-    }
-
-    // Filter out standard library and relative imports
-    let stdlib_modules = vec![
-        "os",
-        "sys",
-        "re",
-        "json",
-        "math",
-        "time",
-        "datetime",
-        "collections",
-        "itertools",
-        "functools",
-        "pathlib",
-        "typing",
-        "abc",
-        "io",
-        "logging",
-        "argparse",
-        "subprocess",
-        "threading",
-        "multiprocessing",
-        "queue",
-        "socket",
-        "http",
-        "urllib",
-        "email",
-        "xml",
-        "html",
-        "random",
-        "string",
-        "unittest",
-        "pytest",
-        "asyncio",
-        "concurrent",
-        "pickle",
-        "copy",
-        "enum",
-        "dataclasses",
-        "contextlib",
-        "warnings",
-        "traceback",
-        "pdb",
-        "timeit",
-    ];
-
-    imports.retain(|module| !stdlib_modules.contains(&module.as_str()) && module != "horus");
-
-    Ok(imports)
-}
-
 fn prompt_missing_system_package(package_name: &str) -> Result<MissingSystemChoice, HorusError> {
     use std::io::{self, Write};
 
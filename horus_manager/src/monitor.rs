@@ -107,7 +107,7 @@ fn display_qr_code(url: &str) {
 }
 
 /// Session validation middleware for monitor using AppState
-async fn monitor_session_middleware(
+pub(crate) async fn monitor_session_middleware(
     State(state): State<Arc<AppState>>,
     req: axum::http::Request<axum::body::Body>,
     next: axum::middleware::Next,
@@ -228,6 +228,15 @@ pub async fn run(port: u16) -> anyhow::Result<()> {
             "/api/debug/sessions/:id/watches/values",
             get(debug_watches_values_handler),
         )
+        .route("/api/teleop", get(teleop_status_handler))
+        .route("/api/teleop/config", post(teleop_config_handler))
+        .route("/api/teleop/cmd", post(teleop_command_handler))
+        .route("/api/teleop/stop", post(teleop_stop_handler))
+        .route("/api/plot/topics", get(plot_topics_handler))
+        .route("/api/plot/subscribe", post(plot_subscribe_handler))
+        .route("/api/plot/unsubscribe", post(plot_unsubscribe_handler))
+        .route("/api/plot/data", get(plot_data_handler))
+        .route("/api/plot/csv", get(plot_csv_handler))
         .route("/api/ws", get(websocket_handler))
         .route("/api/logout", post(logout_handler));
 
@@ -248,6 +257,7 @@ pub async fn run(port: u16) -> anyhow::Result<()> {
     let app = Router::new()
         .merge(api_routes)
         .merge(public_routes)
+        .merge(crate::monitor_api::api_v1_routes(state.clone()))
         .with_state(state.clone())
         .layer(middleware::from_fn(security_headers_middleware))
         .layer(
@@ -269,6 +279,7 @@ pub async fn run(port: u16) -> anyhow::Result<()> {
                 .allow_methods([
                     axum::http::Method::GET,
                     axum::http::Method::POST,
+                    axum::http::Method::PUT,
                     axum::http::Method::DELETE,
                 ])
                 .allow_headers([
@@ -612,6 +623,11 @@ pub async fn nodes_handler() -> impl IntoResponse {
                 "tick_count": n.tick_count,
                 "error_count": n.error_count,
                 "tick_rate": n.actual_rate_hz,
+                "deadline_misses": n.timing.deadline_misses,
+                "jitter_p95_us": n.timing.jitter_p95_us,
+                "jitter_p99_us": n.timing.jitter_p99_us,
+                "tick_p95_us": n.timing.tick_p95_us,
+                "worst_tick_us": n.timing.worst_tick_us,
                 "scheduler_name": n.scheduler_name,
             })
         })
@@ -658,14 +674,29 @@ pub async fn topics_handler() -> impl IntoResponse {
         .into_response()
 }
 
-pub async fn graph_handler() -> impl IntoResponse {
-    // Use graph module to get nodes and edges
+/// Graph of nodes and topics with message rates, topic staleness and
+/// warnings for topics that only have publishers or only subscribers
+fn graph_json() -> serde_json::Value {
     let (nodes, edges) = crate::graph::discover_graph_data();
+    let topics: std::collections::HashMap<String, crate::discovery::SharedMemoryInfo> =
+        crate::discovery::discover_shared_memory()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|t| (t.topic_name.clone(), t))
+            .collect();
+    let dangling = crate::graph::find_dangling_topics(&nodes, &edges);
+
+    // Topic name of each topic node ID
+    let topic_names: std::collections::HashMap<&str, &str> = nodes
+        .iter()
+        .filter(|n| n.node_type == crate::graph::NodeType::Topic)
+        .map(|n| (n.id.as_str(), n.label.as_str()))
+        .collect();
 
     let graph_nodes = nodes
-        .into_iter()
+        .iter()
         .map(|n| {
-            serde_json::json!({
+            let mut node = serde_json::json!({
                 "id": n.id,
                 "label": n.label,
                 "type": match n.node_type {
@@ -674,13 +705,36 @@ pub async fn graph_handler() -> impl IntoResponse {
                 },
                 "pid": n.pid,
                 "active": n.active,
-            })
+            });
+            if n.node_type == crate::graph::NodeType::Topic {
+                if let Some(topic) = topics.get(&n.label) {
+                    node["rate_hz"] = serde_json::json!(topic.message_rate_hz);
+                    node["status"] = serde_json::json!(topic.status.description());
+                    node["message_type"] = serde_json::json!(topic.message_type);
+                }
+                if let Some(d) = dangling.iter().find(|d| d.topic == n.label) {
+                    node["dangling"] = serde_json::json!(if d.published {
+                        "no_subscribers"
+                    } else {
+                        "no_publishers"
+                    });
+                    node["similar"] = serde_json::json!(d.similar);
+                }
+            }
+            node
         })
         .collect::<Vec<_>>();
 
     let graph_edges = edges
-        .into_iter()
+        .iter()
         .map(|e| {
+            let topic_id = match e.edge_type {
+                crate::graph::EdgeType::Publish => &e.to,
+                crate::graph::EdgeType::Subscribe => &e.from,
+            };
+            let topic = topic_names
+                .get(topic_id.as_str())
+                .and_then(|name| topics.get(*name));
             serde_json::json!({
                 "from": e.from,
                 "to": e.to,
@@ -689,18 +743,36 @@ pub async fn graph_handler() -> impl IntoResponse {
                     crate::graph::EdgeType::Subscribe => "subscribe",
                 },
                 "active": e.active,
+                "rate_hz": topic.map(|t| t.message_rate_hz),
+                "stale": topic.is_some_and(|t| t.status == crate::discovery::TopicStatus::Stale),
             })
         })
         .collect::<Vec<_>>();
 
-    (
-        StatusCode::OK,
-        Json(serde_json::json!({
-            "nodes": graph_nodes,
-            "edges": graph_edges
-        })),
-    )
-        .into_response()
+    let warnings = dangling
+        .iter()
+        .map(|d| {
+            let problem = if d.published {
+                format!("'{}' is published but has no subscribers", d.topic)
+            } else {
+                format!("'{}' is subscribed but nobody publishes it", d.topic)
+            };
+            match &d.similar {
+                Some(similar) => format!("{} - did you mean '{}'?", problem, similar),
+                None => problem,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    serde_json::json!({
+        "nodes": graph_nodes,
+        "edges": graph_edges,
+        "warnings": warnings
+    })
+}
+
+pub async fn graph_handler() -> impl IntoResponse {
+    (StatusCode::OK, Json(graph_json())).into_response()
 }
 
 pub async fn network_handler() -> impl IntoResponse {
@@ -2045,7 +2117,7 @@ pub async fn debug_session_get_handler(Path(session_id): Path<String>) -> impl I
                     "recording_path": data.state.recording_path.to_string_lossy(),
                     "state": format!("{:?}", data.debugger.state()),
                     "current_tick": data.debugger.current_tick(),
-                    "total_ticks": recording.snapshots.len(),
+                    "total_ticks": data.debugger.replayer().total_ticks(),
                     "first_tick": recording.first_tick,
                     "last_tick": recording.last_tick,
                     "node_name": recording.node_name,
@@ -2582,8 +2654,519 @@ pub async fn debug_watches_values_handler(Path(session_id): Path<String>) -> imp
     }
 }
 
+// ============================================================================
+// Teleop API Handlers
+// ============================================================================
+
+use horus_library::CmdVel;
+
+/// Hard limit on the teleop linear velocity in m/s (default 1.0)
+pub const TELEOP_LINEAR_LIMIT_ENV: &str = "HORUS_TELEOP_LINEAR_LIMIT";
+/// Hard limit on the teleop angular velocity in rad/s (default 2.0)
+pub const TELEOP_ANGULAR_LIMIT_ENV: &str = "HORUS_TELEOP_ANGULAR_LIMIT";
+
+/// Velocity limit from the environment, `default` if unset or invalid
+fn teleop_limit(env: &str, default: f32) -> f32 {
+    std::env::var(env)
+        .ok()
+        .and_then(|value| value.trim().parse::<f32>().ok())
+        .filter(|limit| limit.is_finite() && *limit >= 0.0)
+        .unwrap_or(default)
+}
+
+/// Browser teleop state, shared by the handlers and the publisher thread
+struct TeleopState {
+    /// Topic the CmdVel commands are published on
+    topic: String,
+    /// Publish rate in Hz, independent of how often the browser sends
+    rate_hz: f64,
+    /// Linear velocity at full deflection (m/s)
+    max_linear: f32,
+    /// Angular velocity at full deflection (rad/s)
+    max_angular: f32,
+    /// Largest `max_linear` the browser may configure (m/s)
+    linear_limit: f32,
+    /// Largest `max_angular` the browser may configure (rad/s)
+    angular_limit: f32,
+    /// Commands older than this stop the robot (deadman released, tab closed, link lost)
+    deadman_timeout: Duration,
+    /// Latest browser command as fractions of the maximum, with its arrival time
+    command: Option<(f32, f32, Instant)>,
+    /// A command was published since the last stop
+    active: bool,
+    published: u64,
+    last_error: Option<String>,
+    publisher_started: bool,
+}
+
+impl Default for TeleopState {
+    fn default() -> Self {
+        let linear_limit = teleop_limit(TELEOP_LINEAR_LIMIT_ENV, 1.0);
+        let angular_limit = teleop_limit(TELEOP_ANGULAR_LIMIT_ENV, 2.0);
+        Self {
+            topic: "cmd_vel".to_string(),
+            rate_hz: 20.0,
+            max_linear: linear_limit.min(0.5),
+            max_angular: angular_limit.min(1.0),
+            linear_limit,
+            angular_limit,
+            deadman_timeout: Duration::from_millis(300),
+            command: None,
+            active: false,
+            published: 0,
+            last_error: None,
+            publisher_started: false,
+        }
+    }
+}
+
+impl TeleopState {
+    /// Velocity to publish now; `None` once the robot has been stopped
+    fn next_command(&mut self, now: Instant) -> Option<(f32, f32)> {
+        match self.command {
+            Some((linear, angular, received))
+                if now.saturating_duration_since(received) <= self.deadman_timeout =>
+            {
+                self.active = true;
+                Some((
+                    linear * self.max_linear.min(self.linear_limit),
+                    angular * self.max_angular.min(self.angular_limit),
+                ))
+            }
+            _ => {
+                self.command = None;
+                if self.active {
+                    // One zero command, then silence so other sources can take over
+                    self.active = false;
+                    Some((0.0, 0.0))
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    fn status_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "topic": self.topic,
+            "rate_hz": self.rate_hz,
+            "max_linear": self.max_linear,
+            "max_angular": self.max_angular,
+            "linear_limit": self.linear_limit,
+            "angular_limit": self.angular_limit,
+            "deadman_timeout_ms": self.deadman_timeout.as_millis() as u64,
+            "active": self.active,
+            "published": self.published,
+            "last_error": self.last_error,
+        })
+    }
+}
+
+lazy_static::lazy_static! {
+    /// Global browser teleop state
+    static ref TELEOP: Mutex<TeleopState> = Mutex::new(TeleopState::default());
+}
+
+/// Publish teleop commands at the configured rate (runs for the monitor's lifetime)
+fn teleop_publisher_loop() {
+    let mut hub: Option<(String, horus_core::Hub<CmdVel>)> = None;
+    loop {
+        let (topic, command, interval) = {
+            let mut state = TELEOP.lock().unwrap();
+            let command = state.next_command(Instant::now());
+            (
+                state.topic.clone(),
+                command,
+                Duration::from_secs_f64(1.0 / state.rate_hz),
+            )
+        };
+
+        if let Some((linear, angular)) = command {
+            if hub.as_ref().is_none_or(|(name, _)| *name != topic) {
+                hub = match horus_core::Hub::new(topic.as_str()) {
+                    Ok(new_hub) => Some((topic.clone(), new_hub)),
+                    Err(e) => {
+                        TELEOP.lock().unwrap().last_error =
+                            Some(format!("Failed to open topic '{}': {}", topic, e));
+                        None
+                    }
+                };
+            }
+            if let Some((_, hub)) = &hub {
+                let sent = hub.send(CmdVel::new(linear, angular), &mut None).is_ok();
+                let mut state = TELEOP.lock().unwrap();
+                if sent {
+                    state.published += 1;
+                    state.last_error = None;
+                } else {
+                    state.last_error = Some(format!("Failed to publish on '{}'", topic));
+                }
+            }
+        }
+
+        std::thread::sleep(interval);
+    }
+}
+
+/// Request body for configuring teleop
+#[derive(Debug, Deserialize)]
+pub struct TeleopConfigRequest {
+    topic: Option<String>,
+    rate_hz: Option<f64>,
+    max_linear: Option<f32>,
+    max_angular: Option<f32>,
+    deadman_timeout_ms: Option<u64>,
+}
+
+/// Request body for a teleop command
+#[derive(Debug, Deserialize)]
+pub struct TeleopCommandRequest {
+    /// Forward command as a fraction of `max_linear` (-1.0 to 1.0)
+    linear: f32,
+    /// Turn command as a fraction of `max_angular` (-1.0 to 1.0, positive is left)
+    angular: f32,
+}
+
+/// Teleop drives the robot, so it is refused unless the monitor has a password
+fn teleop_forbidden(app: &AppState) -> Option<(StatusCode, Json<serde_json::Value>)> {
+    app.auth_disabled.then(|| {
+        (
+            StatusCode::FORBIDDEN,
+            Json(serde_json::json!({
+                "success": false,
+                "error": "Teleop requires a monitor password. Set one with 'horus monitor -r'."
+            })),
+        )
+    })
+}
+
+/// Get teleop configuration and status
+pub async fn teleop_status_handler(State(app): State<Arc<AppState>>) -> impl IntoResponse {
+    let state = TELEOP.lock().unwrap();
+    let mut status = state.status_json();
+    status["enabled"] = serde_json::json!(!app.auth_disabled);
+    (StatusCode::OK, Json(status))
+}
+
+/// Update teleop configuration
+pub async fn teleop_config_handler(
+    State(app): State<Arc<AppState>>,
+    Json(req): Json<TeleopConfigRequest>,
+) -> impl IntoResponse {
+    if let Some(forbidden) = teleop_forbidden(&app) {
+        return forbidden;
+    }
+    let invalid = |error: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "success": false, "error": error })),
+        )
+    };
+
+    let mut state = TELEOP.lock().unwrap();
+    if let Some(topic) = &req.topic {
+        let topic = topic.trim();
+        if topic.is_empty() {
+            return invalid("Topic must not be empty".to_string());
+        }
+        if topic != state.topic && (state.active || state.command.is_some()) {
+            return invalid("Release the deadman before changing the topic".to_string());
+        }
+    }
+    if let Some(rate_hz) = req.rate_hz {
+        if !(1.0..=100.0).contains(&rate_hz) {
+            return invalid("Rate must be between 1 and 100 Hz".to_string());
+        }
+    }
+    for (name, value, limit) in [
+        ("max_linear", req.max_linear, state.linear_limit),
+        ("max_angular", req.max_angular, state.angular_limit),
+    ] {
+        if value.is_some_and(|value| !value.is_finite() || !(0.0..=limit).contains(&value)) {
+            return invalid(format!("{} must be between 0 and {}", name, limit));
+        }
+    }
+    if let Some(timeout) = req.deadman_timeout_ms {
+        if !(50..=2000).contains(&timeout) {
+            return invalid("Deadman timeout must be between 50 and 2000 ms".to_string());
+        }
+    }
+
+    if let Some(topic) = req.topic {
+        state.topic = topic.trim().to_string();
+    }
+    if let Some(rate_hz) = req.rate_hz {
+        state.rate_hz = rate_hz;
+    }
+    if let Some(max_linear) = req.max_linear {
+        state.max_linear = max_linear;
+    }
+    if let Some(max_angular) = req.max_angular {
+        state.max_angular = max_angular;
+    }
+    if let Some(timeout) = req.deadman_timeout_ms {
+        state.deadman_timeout = Duration::from_millis(timeout);
+    }
+
+    let mut response = state.status_json();
+    response["success"] = serde_json::json!(true);
+    (StatusCode::OK, Json(response))
+}
+
+/// Drive command from the browser, sent repeatedly while the deadman is held
+pub async fn teleop_command_handler(
+    State(app): State<Arc<AppState>>,
+    Json(req): Json<TeleopCommandRequest>,
+) -> impl IntoResponse {
+    if let Some(forbidden) = teleop_forbidden(&app) {
+        return forbidden;
+    }
+    if !req.linear.is_finite() || !req.angular.is_finite() {
+        return (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "success": false, "error": "Command must be finite" })),
+        );
+    }
+
+    let mut state = TELEOP.lock().unwrap();
+    if !state.publisher_started {
+        match std::thread::Builder::new()
+            .name("monitor_teleop".to_string())
+            .spawn(teleop_publisher_loop)
+        {
+            Ok(_) => state.publisher_started = true,
+            Err(e) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(serde_json::json!({
+                        "success": false,
+                        "error": format!("Failed to start teleop publisher: {}", e)
+                    })),
+                )
+            }
+        }
+    }
+    state.command = Some((
+        req.linear.clamp(-1.0, 1.0),
+        req.angular.clamp(-1.0, 1.0),
+        Instant::now(),
+    ));
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "success": true,
+            "published": state.published,
+            "last_error": state.last_error
+        })),
+    )
+}
+
+/// Stop immediately (deadman released)
+pub async fn teleop_stop_handler() -> impl IntoResponse {
+    let mut state = TELEOP.lock().unwrap();
+    // The publisher sends the zero command on its next tick
+    state.command = None;
+    (StatusCode::OK, Json(serde_json::json!({ "success": true })))
+}
+
+// ============================================================================
+// Plot API Handlers
+// ============================================================================
+
+use crate::monitor_plot::TopicPlotter;
+
+lazy_static::lazy_static! {
+    /// Global topic plotter, sampled by a background thread
+    static ref PLOTTER: Mutex<TopicPlotter> = Mutex::new(TopicPlotter::new());
+}
+
+static PLOT_SAMPLER: std::sync::Once = std::sync::Once::new();
+
+/// Sample the plotted topics at 100 Hz (runs for the monitor's lifetime)
+fn start_plot_sampler() {
+    PLOT_SAMPLER.call_once(|| {
+        let _ = std::thread::Builder::new()
+            .name("monitor_plot".to_string())
+            .spawn(|| loop {
+                PLOTTER.lock().unwrap().poll();
+                std::thread::sleep(Duration::from_millis(10));
+            });
+    });
+}
+
+/// Request body for subscribing to a topic for plotting
+#[derive(Debug, Deserialize)]
+pub struct PlotSubscribeRequest {
+    topic: String,
+    /// Message type, taken from the topic registry when omitted
+    message_type: Option<String>,
+}
+
+/// Query of plot data and CSV export
+#[derive(Debug, Deserialize)]
+pub struct PlotDataQuery {
+    topic: String,
+    /// Comma-separated field paths
+    #[serde(default)]
+    fields: String,
+    /// Return samples newer than this (seconds, from the previous `latest`)
+    since: Option<f64>,
+    /// CSV export window in seconds
+    window: Option<f64>,
+}
+
+impl PlotDataQuery {
+    fn field_list(&self) -> Vec<String> {
+        self.fields
+            .split(',')
+            .map(str::trim)
+            .filter(|field| !field.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// List topics with their message types and whether they can be plotted
+pub async fn plot_topics_handler() -> impl IntoResponse {
+    let subscribed = PLOTTER.lock().unwrap().topics();
+    let topics: Vec<serde_json::Value> = crate::discovery::discover_shared_memory()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|t| {
+            let plottable = t
+                .message_type
+                .as_deref()
+                .is_some_and(crate::monitor_plot::is_plottable);
+            serde_json::json!({
+                "name": t.topic_name,
+                "message_type": t.message_type,
+                "plottable": plottable,
+                "subscribed": subscribed.contains(&t.topic_name),
+                "active": t.active,
+            })
+        })
+        .collect();
+
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "topics": topics,
+            "supported_types": crate::monitor_plot::PLOTTABLE_TYPES
+        })),
+    )
+}
+
+/// Start sampling a topic
+pub async fn plot_subscribe_handler(Json(req): Json<PlotSubscribeRequest>) -> impl IntoResponse {
+    let message_type = req.message_type.clone().or_else(|| {
+        crate::discovery::discover_shared_memory()
+            .unwrap_or_default()
+            .into_iter()
+            .find(|t| t.topic_name == req.topic)
+            .and_then(|t| t.message_type)
+    });
+    let Some(message_type) = message_type else {
+        return (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "success": false,
+                "error": format!("Message type of topic '{}' is unknown", req.topic)
+            })),
+        );
+    };
+
+    let result = PLOTTER.lock().unwrap().subscribe(&req.topic, &message_type);
+    match result {
+        Ok(()) => {
+            start_plot_sampler();
+            (
+                StatusCode::OK,
+                Json(serde_json::json!({
+                    "success": true,
+                    "topic": req.topic,
+                    "message_type": message_type
+                })),
+            )
+        }
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "success": false, "error": e.to_string() })),
+        ),
+    }
+}
+
+/// Stop sampling a topic
+pub async fn plot_unsubscribe_handler(Json(req): Json<PlotSubscribeRequest>) -> impl IntoResponse {
+    let removed = PLOTTER.lock().unwrap().unsubscribe(&req.topic);
+    (
+        StatusCode::OK,
+        Json(serde_json::json!({ "success": removed })),
+    )
+}
+
+/// New samples of the selected fields of a topic
+pub async fn plot_data_handler(Query(query): Query<PlotDataQuery>) -> impl IntoResponse {
+    let fields = query.field_list();
+    let data = PLOTTER.lock().unwrap().data(
+        &query.topic,
+        &fields,
+        query.since.unwrap_or(f64::NEG_INFINITY),
+    );
+    match data {
+        Some(data) => (StatusCode::OK, Json(serde_json::json!(data))),
+        None => (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": format!("Topic '{}' is not plotted", query.topic)
+            })),
+        ),
+    }
+}
+
+/// Export the selected fields of a topic as CSV
+pub async fn plot_csv_handler(Query(query): Query<PlotDataQuery>) -> Response {
+    let fields = query.field_list();
+    let window = query.window.unwrap_or(crate::monitor_plot::MAX_WINDOW_SECS);
+    let csv = PLOTTER
+        .lock()
+        .unwrap()
+        .to_csv(&query.topic, &fields, window);
+    match csv {
+        Some(csv) => {
+            let filename = format!(
+                "{}.csv",
+                query.topic.replace(
+                    |c: char| !c.is_ascii_alphanumeric() && c != '.' && c != '_',
+                    "_"
+                )
+            );
+            (
+                StatusCode::OK,
+                [
+                    (
+                        axum::http::header::CONTENT_TYPE,
+                        "text/csv; charset=utf-8".to_string(),
+                    ),
+                    (
+                        axum::http::header::CONTENT_DISPOSITION,
+                        format!("attachment; filename=\"{}\"", filename),
+                    ),
+                ],
+                csv,
+            )
+                .into_response()
+        }
+        None => (
+            StatusCode::NOT_FOUND,
+            format!("Topic '{}' is not plotted", query.topic),
+        )
+            .into_response(),
+    }
+}
+
 // WebSocket handler for real-time updates
-async fn websocket_handler(ws: WebSocketUpgrade) -> impl IntoResponse {
+pub async fn websocket_handler(ws: WebSocketUpgrade) -> impl IntoResponse {
     ws.on_upgrade(handle_websocket)
 }
 
@@ -2612,6 +3195,11 @@ async fn handle_websocket(socket: WebSocket) {
                             "health_color": n.health.color(),
                             "cpu": format!("{:.1}%", n.cpu_usage),
                             "memory": format!("{} MB", n.memory_usage / 1024 / 1024),
+                            "deadline_misses": n.timing.deadline_misses,
+                            "jitter_p95_us": n.timing.jitter_p95_us,
+                            "jitter_p99_us": n.timing.jitter_p99_us,
+                            "tick_p95_us": n.timing.tick_p95_us,
+                            "worst_tick_us": n.timing.worst_tick_us,
                             "scheduler_name": n.scheduler_name,
                         })
                     })
@@ -2631,48 +3219,13 @@ async fn handle_websocket(socket: WebSocket) {
                     })
                     .collect::<Vec<_>>()
             }),
-            tokio::task::spawn_blocking(|| {
-                let (nodes, edges) = crate::graph::discover_graph_data();
-                (nodes, edges)
-            })
+            tokio::task::spawn_blocking(graph_json)
         );
 
         // Unwrap results
         let nodes = nodes_result.unwrap_or_default();
         let topics = topics_result.unwrap_or_default();
-        let (graph_nodes, graph_edges) = graph_result.unwrap_or_default();
-
-        // Convert graph data
-        let graph_nodes_json = graph_nodes
-            .into_iter()
-            .map(|n| {
-                serde_json::json!({
-                    "id": n.id,
-                    "label": n.label,
-                    "type": match n.node_type {
-                        crate::graph::NodeType::Process => "process",
-                        crate::graph::NodeType::Topic => "topic",
-                    },
-                    "pid": n.pid,
-                    "active": n.active,
-                })
-            })
-            .collect::<Vec<_>>();
-
-        let graph_edges_json = graph_edges
-            .into_iter()
-            .map(|e| {
-                serde_json::json!({
-                    "from": e.from,
-                    "to": e.to,
-                    "type": match e.edge_type {
-                        crate::graph::EdgeType::Publish => "publish",
-                        crate::graph::EdgeType::Subscribe => "subscribe",
-                    },
-                    "active": e.active,
-                })
-            })
-            .collect::<Vec<_>>();
+        let graph = graph_result.unwrap_or_default();
 
         // Build update message
         let update = serde_json::json!({
@@ -2681,10 +3234,7 @@ async fn handle_websocket(socket: WebSocket) {
             "data": {
                 "nodes": nodes,
                 "topics": topics,
-                "graph": {
-                    "nodes": graph_nodes_json,
-                    "edges": graph_edges_json
-                }
+                "graph": graph
             }
         });
 
@@ -3032,7 +3582,7 @@ fn generate_login_html() -> String {
 </html>"#.to_string()
 }
 
-fn generate_html(port: u16) -> String {
+pub(crate) fn generate_html(port: u16) -> String {
     format!(
         r#"<!DOCTYPE html>
 <html lang="en">
@@ -4375,6 +4925,8 @@ fn generate_html(port: u16) -> String {
                 <li><button class="nav-item active" onclick="switchTab('monitor')">Monitor</button></li>
                 <li><button class="nav-item" onclick="switchTab('params')">Parameters</button></li>
                 <li><button class="nav-item" onclick="switchTab('packages')">Packages</button></li>
+                <li><button class="nav-item" onclick="switchTab('plot')">Plot</button></li>
+                <li><button class="nav-item" onclick="switchTab('teleop')">Teleop</button></li>
             </ul>
         </nav>
 
@@ -4443,6 +4995,7 @@ fn generate_html(port: u16) -> String {
                 <div class="card graph-card">
                     <h2>System Graph</h2>
                     <canvas id="graph-canvas" width="1200" height="500" style="width: 100%; height: 100%; background: var(--dark-bg); border-radius: 4px; border: 1px solid var(--border);"></canvas>
+                    <div id="graph-warnings" style="flex-shrink: 0; margin-top: 0.75rem; font-family: 'JetBrains Mono', monospace; font-size: 0.85rem; color: rgb(255, 165, 0);"></div>
                 </div>
             </div>
         </div>
@@ -4610,6 +5163,93 @@ fn generate_html(port: u16) -> String {
             </div>
         </div>
 
+        <!-- Plot Tab -->
+        <div id="tab-plot" class="tab-content">
+            <div class="card">
+                <h2>Topic Plot</h2>
+
+                <!-- Actions Bar -->
+                <div style="display: flex; gap: 1rem; margin-bottom: 1.5rem; flex-wrap: wrap; align-items: center;">
+                    <select id="plot-topic-select" style="flex: 1; min-width: 220px; padding: 0.5rem; border: 1px solid var(--border); border-radius: 8px; background: var(--surface); color: var(--text-primary); font-family: 'JetBrains Mono', monospace;">
+                        <option value="">Select a topic...</option>
+                    </select>
+                    <button onclick="addPlotTopic()" style="padding: 0.5rem 1.5rem; background: var(--accent); color: white; border: none; border-radius: 8px; cursor: pointer; font-weight: 600;">
+                        Add Topic
+                    </button>
+                    <select id="plot-window" onchange="setPlotWindow(this.value)" style="padding: 0.5rem; border: 1px solid var(--border); border-radius: 8px; background: var(--surface); color: var(--text-primary);">
+                        <option value="10">10 s</option>
+                        <option value="30" selected>30 s</option>
+                        <option value="60">1 min</option>
+                        <option value="120">2 min</option>
+                        <option value="300">5 min</option>
+                    </select>
+                    <button id="plot-pause" onclick="togglePlotPause()" style="padding: 0.5rem 1.5rem; background: var(--warning); color: white; border: none; border-radius: 8px; cursor: pointer; font-weight: 600;">
+                        Pause
+                    </button>
+                    <button onclick="exportPlotCsv()" style="padding: 0.5rem 1.5rem; background: var(--success); color: white; border: none; border-radius: 8px; cursor: pointer; font-weight: 600;">
+                        Export CSV
+                    </button>
+                </div>
+
+                <div style="display: flex; gap: 1.5rem; flex-wrap: wrap;">
+                    <!-- Field Selection -->
+                    <div style="width: 280px; max-height: 420px; overflow-y: auto; font-family: 'JetBrains Mono', monospace; font-size: 0.85rem;">
+                        <input type="text" id="plot-field-filter" placeholder="Filter fields..." oninput="renderPlotFields()" style="width: 100%; margin-bottom: 0.75rem; padding: 0.4rem; border: 1px solid var(--border); border-radius: 8px; background: var(--surface); color: var(--text-primary); font-family: 'JetBrains Mono', monospace;" />
+                        <div id="plot-fields">
+                            <p style="color: var(--text-tertiary);">Add a topic to choose fields</p>
+                        </div>
+                    </div>
+
+                    <!-- Chart -->
+                    <div style="flex: 1; min-width: 320px;">
+                        <canvas id="plot-canvas" width="1000" height="420" style="width: 100%; height: 420px; background: var(--dark-bg); border-radius: 4px; border: 1px solid var(--border);"></canvas>
+                        <div id="plot-legend" style="display: flex; gap: 1rem; flex-wrap: wrap; margin-top: 0.5rem; font-family: 'JetBrains Mono', monospace; font-size: 0.85rem;"></div>
+                    </div>
+                </div>
+            </div>
+        </div>
+
+        <!-- Teleop Tab -->
+        <div id="tab-teleop" class="tab-content">
+            <div class="card">
+                <h2>Teleop</h2>
+
+                <!-- Settings Bar -->
+                <div style="display: flex; gap: 1rem; margin-bottom: 1.5rem; flex-wrap: wrap; align-items: flex-end;">
+                    <label style="flex: 1; min-width: 180px; color: var(--text-secondary); font-weight: 600;">Topic
+                        <input type="text" id="teleop-topic" value="cmd_vel" style="width: 100%; margin-top: 0.25rem; padding: 0.5rem; border: 1px solid var(--border); border-radius: 8px; background: var(--surface); color: var(--text-primary); font-family: 'JetBrains Mono', monospace;" />
+                    </label>
+                    <label style="width: 130px; color: var(--text-secondary); font-weight: 600;">Max linear (m/s)
+                        <input type="number" id="teleop-max-linear" value="0.5" min="0" step="0.1" style="width: 100%; margin-top: 0.25rem; padding: 0.5rem; border: 1px solid var(--border); border-radius: 8px; background: var(--surface); color: var(--text-primary); font-family: 'JetBrains Mono', monospace;" />
+                    </label>
+                    <label style="width: 130px; color: var(--text-secondary); font-weight: 600;">Max angular (rad/s)
+                        <input type="number" id="teleop-max-angular" value="1.0" min="0" step="0.1" style="width: 100%; margin-top: 0.25rem; padding: 0.5rem; border: 1px solid var(--border); border-radius: 8px; background: var(--surface); color: var(--text-primary); font-family: 'JetBrains Mono', monospace;" />
+                    </label>
+                    <label style="width: 100px; color: var(--text-secondary); font-weight: 600;">Rate (Hz)
+                        <input type="number" id="teleop-rate" value="20" min="1" max="100" step="1" style="width: 100%; margin-top: 0.25rem; padding: 0.5rem; border: 1px solid var(--border); border-radius: 8px; background: var(--surface); color: var(--text-primary); font-family: 'JetBrains Mono', monospace;" />
+                    </label>
+                    <button onclick="applyTeleopConfig()" style="padding: 0.5rem 1.5rem; background: var(--accent); color: white; border: none; border-radius: 8px; cursor: pointer; font-weight: 600;">
+                        Apply
+                    </button>
+                </div>
+
+                <div style="display: flex; gap: 2rem; flex-wrap: wrap; align-items: flex-start;">
+                    <!-- Virtual Joystick -->
+                    <div id="teleop-pad" style="position: relative; width: 240px; height: 240px; border-radius: 50%; background: var(--dark-bg); border: 2px solid var(--border); touch-action: none; cursor: grab; user-select: none;">
+                        <div id="teleop-knob" style="position: absolute; left: 85px; top: 85px; width: 70px; height: 70px; border-radius: 50%; background: var(--accent); pointer-events: none;"></div>
+                    </div>
+
+                    <div style="flex: 1; min-width: 240px; font-family: 'JetBrains Mono', monospace; color: var(--text-secondary); line-height: 1.8;">
+                        <p>Press and drag the pad to drive; releasing it stops the robot.</p>
+                        <p>Keyboard: hold <strong>Space</strong> (deadman) and use <strong>W A S D</strong> or the arrow keys.</p>
+                        <div>State: <span id="teleop-state" style="color: var(--text-primary);">Idle</span></div>
+                        <div>Command: <span id="teleop-command" style="color: var(--text-primary);">0.00 m/s, 0.00 rad/s</span></div>
+                        <div>Published: <span id="teleop-published" style="color: var(--text-primary);">0</span></div>
+                        <div id="teleop-error" style="color: var(--error);"></div>
+                    </div>
+                </div>
+            </div>
+        </div>
 
         </div> <!-- end main-content -->
     </div> <!-- end container -->
@@ -4637,6 +5277,17 @@ fn generate_html(port: u16) -> String {
             if (tabName === 'packages') {{
                 onPackagesTabActivate();
             }}
+
+            if (tabName === 'plot') {{
+                loadPlotTopics();
+            }}
+
+            // Never keep driving from a hidden tab
+            if (tabName === 'teleop') {{
+                loadTeleopConfig();
+            }} else if (teleop.driving) {{
+                teleopStop();
+            }}
         }}
 
         // Switch monitor view (list/graph)
@@ -4764,6 +5415,9 @@ fn generate_html(port: u16) -> String {
                                 <span>PID: ${{node.pid}}</span>
                                 <span>CPU: ${{node.cpu}}</span>
                                 <span>Memory: ${{node.memory}}</span>
+                                <span>Misses: ${{node.deadline_misses || 0}}</span>
+                                <span>Jitter p99: ${{Math.round(node.jitter_p99_us || 0)}}µs</span>
+                                <span>Worst: ${{Math.round(node.worst_tick_us || 0)}}µs</span>
                             </div>
                         </div>
                     `).join('');
@@ -5067,9 +5721,14 @@ fn generate_html(port: u16) -> String {
                 const cp2x = to.x - controlDistance;
                 const cp2y = to.y;
 
-                // Edge styling based on type
-                const edgeColor = edge.type === 'publish' ? 'rgba(0, 212, 255, 0.8)' : 'rgba(0, 255, 136, 0.8)';
-                const arrowColor = edge.type === 'publish' ? 'rgba(0, 212, 255, 1.0)' : 'rgba(0, 255, 136, 1.0)';
+                // Edge styling based on type; stale topics and dead processes are dimmed
+                const dimmed = edge.stale || !edge.active;
+                const edgeColor = dimmed
+                    ? 'rgba(110, 110, 120, 0.6)'
+                    : edge.type === 'publish' ? 'rgba(0, 212, 255, 0.8)' : 'rgba(0, 255, 136, 0.8)';
+                const arrowColor = dimmed
+                    ? 'rgba(110, 110, 120, 0.9)'
+                    : edge.type === 'publish' ? 'rgba(0, 212, 255, 1.0)' : 'rgba(0, 255, 136, 1.0)';
 
                 // Draw smooth Bézier curve
                 ctx.beginPath();
@@ -5077,7 +5736,24 @@ fn generate_html(port: u16) -> String {
                 ctx.bezierCurveTo(cp1x, cp1y, cp2x, cp2y, to.x, to.y);
                 ctx.strokeStyle = edgeColor;
                 ctx.lineWidth = 2;
+                ctx.setLineDash(dimmed ? [6, 4] : []);
                 ctx.stroke();
+                ctx.setLineDash([]);
+
+                // Message rate at the middle of the curve (t = 0.5)
+                if (edge.rate_hz > 0) {{
+                    const midX = (from.x + 3 * cp1x + 3 * cp2x + to.x) / 8;
+                    const midY = (from.y + 3 * cp1y + 3 * cp2y + to.y) / 8;
+                    const rateText = edge.rate_hz >= 10 ? `${{edge.rate_hz.toFixed(0)}} Hz` : `${{edge.rate_hz.toFixed(1)}} Hz`;
+                    ctx.font = '400 10px JetBrains Mono, monospace';
+                    ctx.textAlign = 'center';
+                    ctx.textBaseline = 'middle';
+                    const labelWidth = ctx.measureText(rateText).width + 8;
+                    ctx.fillStyle = 'rgba(10, 11, 13, 0.85)';
+                    ctx.fillRect(midX - labelWidth / 2, midY - 7, labelWidth, 14);
+                    ctx.fillStyle = edgeColor;
+                    ctx.fillText(rateText, midX, midY);
+                }}
 
                 // Calculate arrow position and angle at the end of the curve
                 // We need to find the tangent angle at t=1 (end of curve)
@@ -5118,9 +5794,14 @@ fn generate_html(port: u16) -> String {
                 const nodeSize = 15;
 
                 // Different colors for processes vs topics
-                const color = node.type === 'process'
+                let color = node.type === 'process'
                     ? {{ r: 0, g: 255, b: 136 }}     // Green for processes
                     : {{ r: 255, g: 20, b: 147 }};   // Pink for topics
+                if (node.type === 'topic' && node.dangling) {{
+                    color = {{ r: 255, g: 165, b: 0 }};     // Orange: no publishers or no subscribers
+                }} else if (node.type === 'topic' && node.status === 'stale') {{
+                    color = {{ r: 110, g: 110, b: 120 }};   // Gray: no recent writes
+                }}
 
                 if (node.type === 'process') {{
                     // PROCESSES: Draw as circles (no glow, no shadows)
@@ -5154,6 +5835,14 @@ fn generate_html(port: u16) -> String {
                     ctx.textAlign = 'center';
                     ctx.textBaseline = 'middle';
                     ctx.fillText(topicName, pos.x, pos.y);
+
+                    // Hint for unmatched topics below the rectangle
+                    if (node.dangling) {{
+                        ctx.fillStyle = `rgb(${{color.r}}, ${{color.g}}, ${{color.b}})`;
+                        ctx.textBaseline = 'top';
+                        const hint = node.dangling === 'no_subscribers' ? 'no subscribers' : 'no publishers';
+                        ctx.fillText(node.similar ? `${{hint}} (${{node.similar}}?)` : hint, pos.x, pos.y + rectHeight / 2 + 4);
+                    }}
                 }}
 
                 // Draw label for processes only (below the circle, no shadows)
@@ -5168,6 +5857,18 @@ fn generate_html(port: u16) -> String {
             }});
         }}
 
+        // List topics that look disconnected (often a typo in a topic name)
+        function renderGraphWarnings(warnings) {{
+            const container = document.getElementById('graph-warnings');
+            if (!container) return;
+            container.innerHTML = '';
+            warnings.forEach(warning => {{
+                const line = document.createElement('div');
+                line.textContent = '[!] ' + warning;
+                container.appendChild(line);
+            }});
+        }}
+
         // Initialize interaction once
         initGraphInteraction();
 
@@ -5211,6 +5912,7 @@ fn generate_html(port: u16) -> String {
                 if (graphData.nodes.length > 0) {{
                     renderGraph(graphData.nodes, graphData.edges);
                 }}
+                renderGraphWarnings(graphData.warnings || []);
             }} catch (error) {{
                 console.error('Failed to fetch graph:', error);
             }}
@@ -5453,6 +6155,9 @@ fn generate_html(port: u16) -> String {
                                                 <span>PID: ${{node.pid}}</span>
                                                 <span>CPU: ${{node.cpu}}</span>
                                                 <span>Memory: ${{node.memory}}</span>
+                                                <span>Misses: ${{node.deadline_misses || 0}}</span>
+                                                <span>Jitter p99: ${{Math.round(node.jitter_p99_us || 0)}}µs</span>
+                                                <span>Worst: ${{Math.round(node.worst_tick_us || 0)}}µs</span>
                                             </div>
                                         </div>
                                     `).join('');
@@ -5469,6 +6174,9 @@ fn generate_html(port: u16) -> String {
                                                 <span>PID: ${{node.pid}}</span>
                                                 <span>CPU: ${{node.cpu}}</span>
                                                 <span>Memory: ${{node.memory}}</span>
+                                                <span>Misses: ${{node.deadline_misses || 0}}</span>
+                                                <span>Jitter p99: ${{Math.round(node.jitter_p99_us || 0)}}µs</span>
+                                                <span>Worst: ${{Math.round(node.worst_tick_us || 0)}}µs</span>
                                             `;
                                         }}
 
@@ -5533,6 +6241,7 @@ fn generate_html(port: u16) -> String {
                             if (graphData.nodes.length > 0) {{
                                 renderGraph(graphData.nodes, graphData.edges);
                             }}
+                            renderGraphWarnings(graphData.warnings || []);
                         }}
 
                         // Update status bar and tooltips
@@ -6458,6 +7167,560 @@ fn generate_html(port: u16) -> String {
                 attributeFilter: ['class']
             }});
         }}
+
+        // Topic plot: the server samples subscribed topics, the page polls
+        // new samples of the checked fields and draws them on one time axis.
+        const PLOT_POLL_INTERVAL_MS = 200;
+        const PLOT_MAX_WINDOW_S = 300;
+        const PLOT_COLORS = ['#00d4ff', '#ff6b6b', '#51cf66', '#fcc419', '#cc5de8', '#ff922b', '#22b8cf', '#f06595'];
+        const plot = {{
+            topics: {{}},       // topic -> {{ type, fields: [], latest }}
+            series: [],       // {{ topic, field, color, t: [], v: [] }}
+            windowSecs: 30,
+            paused: false,
+            now: 0,
+            timer: null,
+        }};
+
+        async function loadPlotTopics() {{
+            try {{
+                const response = await fetch('/api/plot/topics');
+                const data = await response.json();
+                const select = document.getElementById('plot-topic-select');
+                const selected = select.value;
+                select.innerHTML = '<option value="">Select a topic...</option>';
+                data.topics
+                    .filter((topic) => topic.plottable)
+                    .forEach((topic) => {{
+                        const option = document.createElement('option');
+                        option.value = topic.name;
+                        option.textContent = `${{topic.name}} (${{topic.message_type.split('::').pop()}})`;
+                        select.appendChild(option);
+                    }});
+                select.value = selected;
+            }} catch (error) {{
+                console.error('Failed to load plot topics:', error);
+            }}
+        }}
+
+        async function addPlotTopic() {{
+            const topic = document.getElementById('plot-topic-select').value;
+            if (!topic || plot.topics[topic]) {{
+                return;
+            }}
+            try {{
+                const response = await fetch('/api/plot/subscribe', {{
+                    method: 'POST',
+                    headers: {{ 'Content-Type': 'application/json' }},
+                    body: JSON.stringify({{ topic }})
+                }});
+                const data = await response.json();
+                if (!data.success) {{
+                    alert('Error: ' + data.error);
+                    return;
+                }}
+                plot.topics[topic] = {{ type: data.message_type, fields: [], latest: null }};
+                renderPlotFields();
+                startPlotPolling();
+            }} catch (error) {{
+                alert('Failed to add topic: ' + error.message);
+            }}
+        }}
+
+        async function removePlotTopic(topic) {{
+            delete plot.topics[topic];
+            plot.series = plot.series.filter((series) => series.topic !== topic);
+            renderPlotFields();
+            fetch('/api/plot/unsubscribe', {{
+                method: 'POST',
+                headers: {{ 'Content-Type': 'application/json' }},
+                body: JSON.stringify({{ topic }})
+            }}).catch(() => {{}});
+        }}
+
+        function findPlotSeries(topic, field) {{
+            return plot.series.find((series) => series.topic === topic && series.field === field);
+        }}
+
+        function togglePlotField(topic, field, checked) {{
+            if (!checked) {{
+                plot.series = plot.series.filter((series) => series.topic !== topic || series.field !== field);
+            }} else if (!findPlotSeries(topic, field)) {{
+                const used = plot.series.map((series) => series.color);
+                const color = PLOT_COLORS.find((c) => !used.includes(c)) || PLOT_COLORS[plot.series.length % PLOT_COLORS.length];
+                plot.series.push({{ topic, field, color, t: [], v: [] }});
+                // The server keeps the full window; fetch it for the new field
+                if (plot.topics[topic].latest !== null) {{
+                    fetchPlotHistory(topic, field);
+                }}
+            }}
+            renderPlotFields();
+        }}
+
+        async function fetchPlotHistory(topic, field) {{
+            try {{
+                const params = new URLSearchParams({{ topic, fields: field }});
+                const response = await fetch('/api/plot/data?' + params);
+                const data = await response.json();
+                const series = findPlotSeries(topic, field);
+                const state = plot.topics[topic];
+                if (!series || !state || !data.t) {{
+                    return;
+                }}
+                // Polling appends samples after `latest`, the history fills in before
+                const known = series.t.length ? series.t[0] : Infinity;
+                const t = [];
+                const v = [];
+                data.t.forEach((time, i) => {{
+                    if (time <= state.latest && time < known && data.values[0][i] !== null) {{
+                        t.push(time);
+                        v.push(data.values[0][i]);
+                    }}
+                }});
+                series.t = t.concat(series.t);
+                series.v = v.concat(series.v);
+            }} catch (error) {{
+                console.error('Failed to fetch plot history:', error);
+            }}
+        }}
+
+        function renderPlotFields() {{
+            const container = document.getElementById('plot-fields');
+            const filter = document.getElementById('plot-field-filter').value.toLowerCase();
+            const topics = Object.keys(plot.topics);
+            if (topics.length === 0) {{
+                container.innerHTML = '<p style="color: var(--text-tertiary);">Add a topic to choose fields</p>';
+                return;
+            }}
+            container.innerHTML = '';
+            topics.forEach((topic) => {{
+                const header = document.createElement('div');
+                header.style.cssText = 'display: flex; justify-content: space-between; margin: 0.5rem 0; color: var(--text-primary); font-weight: 600;';
+                header.textContent = topic;
+                const remove = document.createElement('button');
+                remove.textContent = '[X]';
+                remove.style.cssText = 'background: none; border: none; color: var(--error); cursor: pointer;';
+                remove.onclick = () => removePlotTopic(topic);
+                header.appendChild(remove);
+                container.appendChild(header);
+
+                const fields = plot.topics[topic].fields.filter((field) => field.toLowerCase().includes(filter));
+                if (fields.length === 0) {{
+                    const empty = document.createElement('div');
+                    empty.style.color = 'var(--text-tertiary)';
+                    empty.textContent = plot.topics[topic].fields.length ? 'No matching fields' : 'Waiting for messages...';
+                    container.appendChild(empty);
+                }}
+                fields.forEach((field) => {{
+                    const series = findPlotSeries(topic, field);
+                    const label = document.createElement('label');
+                    label.style.cssText = 'display: block; padding: 0.15rem 0; color: ' + (series ? series.color : 'var(--text-secondary)') + '; cursor: pointer;';
+                    const checkbox = document.createElement('input');
+                    checkbox.type = 'checkbox';
+                    checkbox.checked = !!series;
+                    checkbox.onchange = () => togglePlotField(topic, field, checkbox.checked);
+                    label.appendChild(checkbox);
+                    label.appendChild(document.createTextNode(' ' + field));
+                    container.appendChild(label);
+                }});
+            }});
+        }}
+
+        async function pollPlotData() {{
+            for (const topic of Object.keys(plot.topics)) {{
+                const state = plot.topics[topic];
+                const series = plot.series.filter((s) => s.topic === topic);
+                const params = new URLSearchParams({{ topic, fields: series.map((s) => s.field).join(',') }});
+                if (state.latest !== null) {{
+                    params.set('since', state.latest);
+                }}
+                try {{
+                    const response = await fetch('/api/plot/data?' + params);
+                    if (!response.ok) {{
+                        continue;
+                    }}
+                    const data = await response.json();
+                    if (!plot.topics[topic]) {{
+                        continue; // Removed while waiting
+                    }}
+                    state.latest = data.latest;
+                    plot.now = Math.max(plot.now, data.latest);
+                    if (data.fields.length !== state.fields.length) {{
+                        state.fields = data.fields;
+                        renderPlotFields();
+                    }}
+                    series.forEach((s, column) => {{
+                        data.t.forEach((time, i) => {{
+                            const value = data.values[column][i];
+                            if (value !== null) {{
+                                s.t.push(time);
+                                s.v.push(value);
+                            }}
+                        }});
+                        // Keep the longest selectable window
+                        const cutoff = plot.now - PLOT_MAX_WINDOW_S;
+                        let drop = 0;
+                        while (drop < s.t.length && s.t[drop] < cutoff) {{
+                            drop++;
+                        }}
+                        s.t.splice(0, drop);
+                        s.v.splice(0, drop);
+                    }});
+                }} catch (error) {{
+                    console.error('Failed to poll plot data:', error);
+                }}
+            }}
+            if (!plot.paused) {{
+                drawPlot();
+            }}
+        }}
+
+        function startPlotPolling() {{
+            if (!plot.timer) {{
+                plot.timer = setInterval(pollPlotData, PLOT_POLL_INTERVAL_MS);
+            }}
+        }}
+
+        function setPlotWindow(seconds) {{
+            plot.windowSecs = parseFloat(seconds);
+            drawPlot();
+        }}
+
+        function togglePlotPause() {{
+            plot.paused = !plot.paused;
+            document.getElementById('plot-pause').textContent = plot.paused ? 'Resume' : 'Pause';
+            if (!plot.paused) {{
+                drawPlot();
+            }}
+        }}
+
+        function drawPlot() {{
+            const canvas = document.getElementById('plot-canvas');
+            if (!canvas) {{
+                return;
+            }}
+            const ctx = canvas.getContext('2d');
+            const width = canvas.width;
+            const height = canvas.height;
+            const margin = {{ left: 70, right: 20, top: 15, bottom: 30 }};
+            const plotWidth = width - margin.left - margin.right;
+            const plotHeight = height - margin.top - margin.bottom;
+            const end = plot.now;
+            const start = end - plot.windowSecs;
+            const styles = getComputedStyle(document.body);
+            const gridColor = styles.getPropertyValue('--border') || '#333';
+            const textColor = styles.getPropertyValue('--text-secondary') || '#aaa';
+
+            ctx.clearRect(0, 0, width, height);
+
+            // Value range of the visible samples
+            let min = Infinity;
+            let max = -Infinity;
+            plot.series.forEach((s) => {{
+                s.t.forEach((time, i) => {{
+                    if (time >= start) {{
+                        min = Math.min(min, s.v[i]);
+                        max = Math.max(max, s.v[i]);
+                    }}
+                }});
+            }});
+            if (!isFinite(min)) {{
+                min = -1;
+                max = 1;
+            }} else if (max - min < 1e-9) {{
+                min -= 1;
+                max += 1;
+            }} else {{
+                const pad = (max - min) * 0.05;
+                min -= pad;
+                max += pad;
+            }}
+            const x = (time) => margin.left + ((time - start) / plot.windowSecs) * plotWidth;
+            const y = (value) => margin.top + (1 - (value - min) / (max - min)) * plotHeight;
+
+            // Grid and labels
+            ctx.strokeStyle = gridColor;
+            ctx.fillStyle = textColor;
+            ctx.lineWidth = 1;
+            ctx.font = '12px JetBrains Mono, monospace';
+            for (let i = 0; i <= 5; i++) {{
+                const value = min + ((max - min) * i) / 5;
+                const py = y(value);
+                ctx.beginPath();
+                ctx.moveTo(margin.left, py);
+                ctx.lineTo(width - margin.right, py);
+                ctx.stroke();
+                ctx.textAlign = 'right';
+                ctx.fillText(value.toPrecision(4), margin.left - 6, py + 4);
+            }}
+            for (let i = 0; i <= 6; i++) {{
+                const time = start + (plot.windowSecs * i) / 6;
+                const px = x(time);
+                ctx.beginPath();
+                ctx.moveTo(px, margin.top);
+                ctx.lineTo(px, height - margin.bottom);
+                ctx.stroke();
+                ctx.textAlign = 'center';
+                ctx.fillText(`${{(time - end).toFixed(1)}}s`, px, height - 10);
+            }}
+
+            // Series
+            ctx.lineWidth = 2;
+            plot.series.forEach((s) => {{
+                ctx.strokeStyle = s.color;
+                ctx.beginPath();
+                let started = false;
+                s.t.forEach((time, i) => {{
+                    if (time < start) {{
+                        return;
+                    }}
+                    if (started) {{
+                        ctx.lineTo(x(time), y(s.v[i]));
+                    }} else {{
+                        ctx.moveTo(x(time), y(s.v[i]));
+                        started = true;
+                    }}
+                }});
+                ctx.stroke();
+            }});
+
+            // Legend with the latest values
+            const legend = document.getElementById('plot-legend');
+            legend.innerHTML = '';
+            plot.series.forEach((s) => {{
+                const item = document.createElement('span');
+                item.style.color = s.color;
+                const latest = s.v.length ? s.v[s.v.length - 1].toPrecision(6) : '-';
+                item.textContent = `${{s.topic}}/${{s.field}}: ${{latest}}`;
+                legend.appendChild(item);
+            }});
+        }}
+
+        function exportPlotCsv() {{
+            const topics = [...new Set(plot.series.map((s) => s.topic))];
+            if (topics.length === 0) {{
+                alert('Select at least one field to export');
+                return;
+            }}
+            // One file per topic, rows are the topic's messages
+            topics.forEach((topic) => {{
+                const fields = plot.series.filter((s) => s.topic === topic).map((s) => s.field);
+                const params = new URLSearchParams({{ topic, fields: fields.join(','), window: plot.windowSecs }});
+                const link = document.createElement('a');
+                link.href = '/api/plot/csv?' + params;
+                link.download = topic + '.csv';
+                document.body.appendChild(link);
+                link.click();
+                link.remove();
+            }});
+        }}
+
+        // Teleop: commands are sent while the pad is held or Space is down.
+        // The server stops the robot when they stop arriving.
+        const TELEOP_SEND_INTERVAL_MS = 50;
+        const TELEOP_KEYS = {{
+            ' ': 'deadman',
+            'w': 'forward', 'ArrowUp': 'forward',
+            's': 'backward', 'ArrowDown': 'backward',
+            'a': 'left', 'ArrowLeft': 'left',
+            'd': 'right', 'ArrowRight': 'right',
+        }};
+        const teleop = {{
+            pad: {{ active: false, linear: 0, angular: 0 }},
+            keys: new Set(),
+            driving: false,
+            inFlight: false,
+            config: {{ max_linear: 0.5, max_angular: 1.0 }},
+        }};
+
+        function showTeleopStatus(data) {{
+            if (data.max_linear !== undefined) {{
+                teleop.config = data;
+            }}
+            if (data.published !== undefined) {{
+                document.getElementById('teleop-published').textContent = data.published;
+            }}
+            document.getElementById('teleop-error').textContent = data.last_error || '';
+        }}
+
+        async function loadTeleopConfig() {{
+            try {{
+                const response = await fetch('/api/teleop');
+                const data = await response.json();
+                document.getElementById('teleop-topic').value = data.topic;
+                document.getElementById('teleop-max-linear').value = data.max_linear;
+                document.getElementById('teleop-max-linear').max = data.linear_limit;
+                document.getElementById('teleop-max-angular').value = data.max_angular;
+                document.getElementById('teleop-max-angular').max = data.angular_limit;
+                if (data.enabled === false) {{
+                    document.getElementById('teleop-error').textContent =
+                        'Teleop is disabled: set a monitor password with horus monitor -r';
+                    return;
+                }}
+                document.getElementById('teleop-rate').value = data.rate_hz;
+                showTeleopStatus(data);
+            }} catch (error) {{
+                console.error('Failed to load teleop config:', error);
+            }}
+        }}
+
+        async function applyTeleopConfig() {{
+            try {{
+                const response = await fetch('/api/teleop/config', {{
+                    method: 'POST',
+                    headers: {{ 'Content-Type': 'application/json' }},
+                    body: JSON.stringify({{
+                        topic: document.getElementById('teleop-topic').value,
+                        max_linear: parseFloat(document.getElementById('teleop-max-linear').value),
+                        max_angular: parseFloat(document.getElementById('teleop-max-angular').value),
+                        rate_hz: parseFloat(document.getElementById('teleop-rate').value)
+                    }})
+                }});
+                const data = await response.json();
+                if (data.success) {{
+                    showTeleopStatus(data);
+                }} else {{
+                    alert('Error: ' + data.error);
+                }}
+            }} catch (error) {{
+                alert('Failed to apply teleop settings: ' + error.message);
+            }}
+        }}
+
+        // Command as fractions of the maximum velocities, null while the deadman is released
+        function teleopCommand() {{
+            if (teleop.pad.active) {{
+                return {{ linear: teleop.pad.linear, angular: teleop.pad.angular }};
+            }}
+            if (!teleop.keys.has('deadman')) {{
+                return null;
+            }}
+            const held = (key) => teleop.keys.has(key) ? 1 : 0;
+            return {{
+                linear: held('forward') - held('backward'),
+                angular: held('left') - held('right')
+            }};
+        }}
+
+        async function teleopTick() {{
+            const command = teleopCommand();
+            if (!command) {{
+                if (teleop.driving) {{
+                    teleopStop();
+                }}
+                return;
+            }}
+            teleop.driving = true;
+            document.getElementById('teleop-state').textContent = 'Driving';
+            document.getElementById('teleop-command').textContent =
+                `${{(command.linear * teleop.config.max_linear).toFixed(2)}} m/s, ` +
+                `${{(command.angular * teleop.config.max_angular).toFixed(2)}} rad/s`;
+
+            // Skip a tick rather than queue requests on a slow link
+            if (teleop.inFlight) {{
+                return;
+            }}
+            teleop.inFlight = true;
+            try {{
+                const response = await fetch('/api/teleop/cmd', {{
+                    method: 'POST',
+                    headers: {{ 'Content-Type': 'application/json' }},
+                    body: JSON.stringify(command)
+                }});
+                showTeleopStatus(await response.json());
+            }} catch (error) {{
+                document.getElementById('teleop-error').textContent = 'Connection lost';
+            }} finally {{
+                teleop.inFlight = false;
+            }}
+        }}
+
+        function teleopStop() {{
+            const wasDriving = teleop.driving;
+            teleop.driving = false;
+            teleop.pad.active = false;
+            teleop.keys.clear();
+            document.getElementById('teleop-knob').style.transform = '';
+            document.getElementById('teleop-state').textContent = 'Idle';
+            document.getElementById('teleop-command').textContent = '0.00 m/s, 0.00 rad/s';
+            if (wasDriving) {{
+                fetch('/api/teleop/stop', {{ method: 'POST' }}).catch(() => {{}});
+            }}
+        }}
+
+        function initTeleop() {{
+            const pad = document.getElementById('teleop-pad');
+            const knob = document.getElementById('teleop-knob');
+            if (!pad) {{
+                return;
+            }}
+
+            const movePad = (event) => {{
+                const rect = pad.getBoundingClientRect();
+                const radius = rect.width / 2;
+                let dx = (event.clientX - rect.left - radius) / radius;
+                let dy = (event.clientY - rect.top - radius) / radius;
+                const length = Math.hypot(dx, dy);
+                if (length > 1) {{
+                    dx /= length;
+                    dy /= length;
+                }}
+                // Up is forward, left is a positive (counter-clockwise) turn
+                teleop.pad.linear = -dy;
+                teleop.pad.angular = -dx;
+                const travel = radius - knob.offsetWidth / 2;
+                knob.style.transform = `translate(${{dx * travel}}px, ${{dy * travel}}px)`;
+            }};
+            pad.addEventListener('pointerdown', (event) => {{
+                pad.setPointerCapture(event.pointerId);
+                teleop.pad.active = true;
+                movePad(event);
+            }});
+            pad.addEventListener('pointermove', (event) => {{
+                if (teleop.pad.active) {{
+                    movePad(event);
+                }}
+            }});
+            ['pointerup', 'pointercancel'].forEach((type) => {{
+                pad.addEventListener(type, () => {{
+                    teleop.pad.active = false;
+                    knob.style.transform = '';
+                }});
+            }});
+
+            const teleopKey = (event) => {{
+                const tab = document.getElementById('tab-teleop');
+                if (!tab.classList.contains('active') || event.target.tagName === 'INPUT') {{
+                    return null;
+                }}
+                return TELEOP_KEYS[event.key.length === 1 ? event.key.toLowerCase() : event.key];
+            }};
+            document.addEventListener('keydown', (event) => {{
+                const key = teleopKey(event);
+                if (key) {{
+                    event.preventDefault();
+                    teleop.keys.add(key);
+                }}
+            }});
+            document.addEventListener('keyup', (event) => {{
+                const key = teleopKey(event);
+                if (key) {{
+                    teleop.keys.delete(key);
+                }}
+            }});
+
+            // Key-up events are lost when the window loses focus
+            window.addEventListener('blur', teleopStop);
+            document.addEventListener('visibilitychange', () => {{
+                if (document.hidden) {{
+                    teleopStop();
+                }}
+            }});
+
+            setInterval(teleopTick, TELEOP_SEND_INTERVAL_MS);
+        }}
+
+        initTeleop();
     </script>
 
     <!-- Install Dialog -->
@@ -0,0 +1,465 @@
+//! Versioned monitor API for external dashboards and fleet managers
+//!
+//! The dashboard endpoints under `/api` follow the web UI and change with it.
+//! The endpoints under `/api/v1` have a stable, documented schema (see the
+//! "Monitor API" section of the README) and accept API tokens created with
+//! `horus monitor --create-token <NAME>`:
+//!
+//! | Endpoint | Description |
+//! |----------|-------------|
+//! | `GET /api/v1` | API and HORUS version |
+//! | `GET /api/v1/nodes` | All nodes ([`NodeInfo`]) |
+//! | `GET /api/v1/nodes/:name` | One node |
+//! | `GET /api/v1/topics` | All topics ([`TopicInfo`]) |
+//! | `GET /api/v1/metrics` | System totals ([`SystemMetrics`]) |
+//! | `GET /api/v1/params` | All runtime parameters |
+//! | `GET`/`PUT /api/v1/params/:key` | One runtime parameter |
+//! | `GET /api/v1/stream` | WebSocket of periodic [`Snapshot`]s |
+//!
+//! Requests authenticate with `Authorization: Bearer <token>`, where the token
+//! is an API token or a dashboard session token. WebSocket clients that cannot
+//! set headers pass `?access_token=<token>`. A monitor running without a
+//! password serves the API without authentication, like the dashboard.
+
+use crate::monitor::AppState;
+use crate::security::api_tokens::ApiTokenStore;
+use axum::{
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
+    },
+    http::{Request, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use futures::{sink::SinkExt, stream::StreamExt};
+use horus_core::core::HealthStatus;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Version of the `/api/v1` schema
+pub const API_VERSION: u32 = 1;
+
+/// Default and allowed range of the stream interval in milliseconds
+const STREAM_INTERVAL_MS: (u64, u64, u64) = (1000, 100, 60_000);
+
+/// A running node
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NodeInfo {
+    pub name: String,
+    /// Process state, e.g. `Running`
+    pub status: String,
+    /// `Healthy`, `Warning`, `Error`, `Critical` or `Unknown`
+    pub health: String,
+    pub pid: u32,
+    pub priority: u32,
+    pub cpu_percent: f32,
+    /// Resident memory of the process
+    pub memory_bytes: u64,
+    pub tick_count: u64,
+    pub tick_rate_hz: u32,
+    pub error_count: u32,
+    pub deadline_misses: u64,
+    pub jitter_p95_us: f64,
+    pub jitter_p99_us: f64,
+    pub tick_p95_us: f64,
+    pub worst_tick_us: f64,
+    /// Topics the node publishes
+    pub publishes: Vec<String>,
+    /// Topics the node subscribes to
+    pub subscribes: Vec<String>,
+}
+
+impl From<&crate::discovery::NodeStatus> for NodeInfo {
+    fn from(node: &crate::discovery::NodeStatus) -> Self {
+        Self {
+            name: node.name.clone(),
+            status: node.status.clone(),
+            health: node.health.as_str().to_string(),
+            pid: node.process_id,
+            priority: node.priority,
+            cpu_percent: node.cpu_usage,
+            memory_bytes: node.memory_usage,
+            tick_count: node.tick_count,
+            tick_rate_hz: node.actual_rate_hz,
+            error_count: node.error_count,
+            deadline_misses: node.timing.deadline_misses,
+            jitter_p95_us: node.timing.jitter_p95_us,
+            jitter_p99_us: node.timing.jitter_p99_us,
+            tick_p95_us: node.timing.tick_p95_us,
+            worst_tick_us: node.timing.worst_tick_us,
+            publishes: node.publishers.iter().map(|t| t.topic.clone()).collect(),
+            subscribes: node.subscribers.iter().map(|t| t.topic.clone()).collect(),
+        }
+    }
+}
+
+/// A shared memory topic
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopicInfo {
+    pub name: String,
+    /// Rust type name of the message, if known
+    pub message_type: Option<String>,
+    /// `active`, `idle` or `stale`
+    pub status: String,
+    pub rate_hz: f32,
+    pub size_bytes: u64,
+    /// Names of publishing nodes
+    pub publishers: Vec<String>,
+    /// Names of subscribing nodes
+    pub subscribers: Vec<String>,
+}
+
+impl From<&crate::discovery::SharedMemoryInfo> for TopicInfo {
+    fn from(topic: &crate::discovery::SharedMemoryInfo) -> Self {
+        Self {
+            name: topic.topic_name.clone(),
+            message_type: topic.message_type.clone(),
+            status: topic.status.description().to_string(),
+            rate_hz: topic.message_rate_hz,
+            size_bytes: topic.size_bytes,
+            publishers: topic.publishers.clone(),
+            subscribers: topic.subscribers.clone(),
+        }
+    }
+}
+
+/// Totals over all nodes and topics
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SystemMetrics {
+    pub node_count: usize,
+    pub topic_count: usize,
+    /// Number of nodes per health status
+    pub health: BTreeMap<String, usize>,
+    /// Worst node health, `Idle` without nodes
+    pub overall_health: String,
+    pub cpu_percent: f32,
+    pub memory_bytes: u64,
+    pub deadline_misses: u64,
+    /// Sum of all topic rates
+    pub message_rate_hz: f32,
+}
+
+impl SystemMetrics {
+    pub fn from_parts(nodes: &[NodeInfo], topics: &[TopicInfo]) -> Self {
+        let mut health = BTreeMap::new();
+        for node in nodes {
+            *health.entry(node.health.clone()).or_insert(0) += 1;
+        }
+        let overall_health = [
+            HealthStatus::Critical,
+            HealthStatus::Error,
+            HealthStatus::Warning,
+            HealthStatus::Healthy,
+            HealthStatus::Unknown,
+        ]
+        .iter()
+        .map(|status| status.as_str())
+        .find(|status| health.contains_key(*status))
+        .unwrap_or("Idle")
+        .to_string();
+
+        Self {
+            node_count: nodes.len(),
+            topic_count: topics.len(),
+            health,
+            overall_health,
+            cpu_percent: nodes.iter().map(|n| n.cpu_percent).sum(),
+            memory_bytes: nodes.iter().map(|n| n.memory_bytes).sum(),
+            deadline_misses: nodes.iter().map(|n| n.deadline_misses).sum(),
+            message_rate_hz: topics.iter().map(|t| t.rate_hz).sum(),
+        }
+    }
+}
+
+/// Everything the stream sends per interval
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// RFC 3339 time of the snapshot
+    pub timestamp: String,
+    pub nodes: Vec<NodeInfo>,
+    pub topics: Vec<TopicInfo>,
+    pub metrics: SystemMetrics,
+}
+
+fn collect_nodes() -> Vec<NodeInfo> {
+    crate::discovery::discover_nodes()
+        .unwrap_or_default()
+        .iter()
+        .map(NodeInfo::from)
+        .collect()
+}
+
+fn collect_topics() -> Vec<TopicInfo> {
+    crate::discovery::discover_shared_memory()
+        .unwrap_or_default()
+        .iter()
+        .map(TopicInfo::from)
+        .collect()
+}
+
+fn collect_snapshot() -> Snapshot {
+    let nodes = collect_nodes();
+    let topics = collect_topics();
+    Snapshot {
+        timestamp: chrono::Utc::now().to_rfc3339(),
+        metrics: SystemMetrics::from_parts(&nodes, &topics),
+        nodes,
+        topics,
+    }
+}
+
+/// Error response with an `{"error": ...}` body
+fn api_error(status: StatusCode, message: impl Into<String>) -> Response {
+    (status, Json(serde_json::json!({ "error": message.into() }))).into_response()
+}
+
+/// Run blocking discovery off the async runtime
+async fn blocking<T: Send + 'static>(
+    f: impl FnOnce() -> T + Send + 'static,
+) -> Result<T, Response> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| api_error(StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+// ============================================================================
+// Authentication
+// ============================================================================
+
+#[derive(Deserialize)]
+struct AccessTokenQuery {
+    access_token: Option<String>,
+}
+
+/// Accept an API token or a dashboard session
+async fn api_auth_middleware(
+    State(state): State<Arc<AppState>>,
+    req: Request<axum::body::Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    if state.auth_disabled {
+        return Ok(next.run(req).await);
+    }
+
+    let query_token = Query::<AccessTokenQuery>::try_from_uri(req.uri())
+        .ok()
+        .and_then(|Query(query)| query.access_token);
+    let token = crate::security::middleware::extract_session_token(&req)
+        .map(String::from)
+        .or(query_token)
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+
+    let authorized = state.auth_service.validate_session(&token)
+        || ApiTokenStore::open_default()
+            .ok()
+            .and_then(|store| store.verify(&token))
+            .is_some();
+    if !authorized {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    Ok(next.run(req).await)
+}
+
+/// Routes of the `/api/v1` API
+pub fn api_v1_routes(state: Arc<AppState>) -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/api/v1", get(api_index_handler))
+        .route("/api/v1/nodes", get(api_nodes_handler))
+        .route("/api/v1/nodes/:name", get(api_node_handler))
+        .route("/api/v1/topics", get(api_topics_handler))
+        .route("/api/v1/metrics", get(api_metrics_handler))
+        .route("/api/v1/params", get(api_params_handler))
+        .route(
+            "/api/v1/params/:key",
+            get(api_param_get_handler).put(api_param_put_handler),
+        )
+        .route("/api/v1/stream", get(api_stream_handler))
+        .layer(middleware::from_fn_with_state(state, api_auth_middleware))
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+pub async fn api_index_handler() -> impl IntoResponse {
+    Json(serde_json::json!({
+        "api_version": API_VERSION,
+        "horus_version": env!("CARGO_PKG_VERSION"),
+    }))
+}
+
+pub async fn api_nodes_handler() -> Response {
+    match blocking(collect_nodes).await {
+        Ok(nodes) => Json(serde_json::json!({ "nodes": nodes })).into_response(),
+        Err(response) => response,
+    }
+}
+
+pub async fn api_node_handler(Path(name): Path<String>) -> Response {
+    match blocking(collect_nodes).await {
+        Ok(nodes) => match nodes.into_iter().find(|n| n.name == name) {
+            Some(node) => Json(node).into_response(),
+            None => api_error(StatusCode::NOT_FOUND, format!("Node '{}' not found", name)),
+        },
+        Err(response) => response,
+    }
+}
+
+pub async fn api_topics_handler() -> Response {
+    match blocking(collect_topics).await {
+        Ok(topics) => Json(serde_json::json!({ "topics": topics })).into_response(),
+        Err(response) => response,
+    }
+}
+
+pub async fn api_metrics_handler() -> Response {
+    match blocking(collect_snapshot).await {
+        Ok(snapshot) => Json(snapshot.metrics).into_response(),
+        Err(response) => response,
+    }
+}
+
+pub async fn api_params_handler(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    Json(serde_json::json!({ "params": state.params.get_all() }))
+}
+
+pub async fn api_param_get_handler(
+    State(state): State<Arc<AppState>>,
+    Path(key): Path<String>,
+) -> Response {
+    match state.params.get::<serde_json::Value>(&key) {
+        Some(value) => Json(serde_json::json!({
+            "key": key,
+            "value": value,
+            "version": state.params.get_version(&key),
+        }))
+        .into_response(),
+        None => api_error(
+            StatusCode::NOT_FOUND,
+            format!("Parameter '{}' not found", key),
+        ),
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiSetParamRequest {
+    pub value: serde_json::Value,
+    /// Only write if the parameter still has this version
+    pub version: Option<u64>,
+}
+
+pub async fn api_param_put_handler(
+    State(state): State<Arc<AppState>>,
+    Path(key): Path<String>,
+    Json(req): Json<ApiSetParamRequest>,
+) -> Response {
+    let result = match req.version {
+        Some(version) => state.params.set_with_version(&key, &req.value, version),
+        None => state.params.set(&key, &req.value),
+    };
+    if let Err(e) = result {
+        let message = e.to_string();
+        let status = if message.contains("Version mismatch") {
+            StatusCode::CONFLICT
+        } else {
+            StatusCode::BAD_REQUEST
+        };
+        return api_error(status, message);
+    }
+    let _ = state.params.save_to_disk();
+
+    Json(serde_json::json!({
+        "key": key,
+        "value": req.value,
+        "version": state.params.get_version(&key),
+    }))
+    .into_response()
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StreamQuery {
+    /// Milliseconds between snapshots
+    pub interval_ms: Option<u64>,
+}
+
+pub async fn api_stream_handler(
+    Query(query): Query<StreamQuery>,
+    ws: WebSocketUpgrade,
+) -> impl IntoResponse {
+    let (default, min, max) = STREAM_INTERVAL_MS;
+    let interval = Duration::from_millis(query.interval_ms.unwrap_or(default).clamp(min, max));
+    ws.on_upgrade(move |socket| stream_snapshots(socket, interval))
+}
+
+/// Send a snapshot every interval until the client disconnects
+async fn stream_snapshots(socket: WebSocket, interval: Duration) {
+    let (mut sender, mut receiver) = socket.split();
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {
+                let Ok(snapshot) = tokio::task::spawn_blocking(collect_snapshot).await else {
+                    break;
+                };
+                let message = serde_json::json!({ "type": "snapshot", "data": snapshot });
+                if sender.send(Message::Text(message.to_string())).await.is_err() {
+                    break;
+                }
+            }
+            message = receiver.next() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                _ => {}
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn node(name: &str, health: &str, cpu_percent: f32) -> NodeInfo {
+        NodeInfo {
+            name: name.to_string(),
+            status: "Running".to_string(),
+            health: health.to_string(),
+            pid: 1,
+            priority: 0,
+            cpu_percent,
+            memory_bytes: 1024,
+            tick_count: 0,
+            tick_rate_hz: 0,
+            error_count: 0,
+            deadline_misses: 2,
+            jitter_p95_us: 0.0,
+            jitter_p99_us: 0.0,
+            tick_p95_us: 0.0,
+            worst_tick_us: 0.0,
+            publishes: vec![],
+            subscribes: vec![],
+        }
+    }
+
+    #[test]
+    fn test_system_metrics() {
+        let nodes = [
+            node("planner", "Healthy", 10.0),
+            node("camera", "Warning", 20.0),
+        ];
+        let metrics = SystemMetrics::from_parts(&nodes, &[]);
+        assert_eq!(metrics.node_count, 2);
+        assert_eq!(metrics.overall_health, "Warning");
+        assert_eq!(metrics.health["Healthy"], 1);
+        assert_eq!(metrics.cpu_percent, 30.0);
+        assert_eq!(metrics.memory_bytes, 2048);
+        assert_eq!(metrics.deadline_misses, 4);
+
+        assert_eq!(SystemMetrics::from_parts(&[], &[]).overall_health, "Idle");
+    }
+}
@@ -0,0 +1,429 @@
+//! Live topic plotting for the web monitor
+//!
+//! Subscribes to topics of known message types, flattens every message into
+//! numeric fields named by their path (`angular_velocity[2]`, `voltage`) and
+//! keeps a time window of samples per topic. The web UI polls new samples of
+//! the fields it charts and exports the window as CSV.
+
+use horus_core::error::{HorusError, HorusResult};
+use horus_core::Hub;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write as _;
+use std::time::{Duration, Instant};
+
+/// Longest time window kept per topic
+pub const MAX_WINDOW_SECS: f64 = 300.0;
+
+/// Sample limit per topic, bounds memory for fast topics
+const MAX_SAMPLES: usize = 30_000;
+
+/// Longer arrays (scans, images) are not flattened
+const MAX_ARRAY_ELEMENTS: usize = 64;
+
+/// Field limit per message
+const MAX_FIELDS: usize = 256;
+
+/// Messages read per topic and poll, the rest is read on the next poll
+const MAX_MESSAGES_PER_POLL: usize = 1000;
+
+/// Subscriptions without data requests for this long are closed
+const IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Reads the next message of a topic as JSON
+type Decoder = Box<dyn FnMut() -> Option<Value> + Send>;
+
+macro_rules! plottable_types {
+    ($($name:literal => $ty:ty),* $(,)?) => {
+        /// Message types that can be plotted (short type names)
+        pub const PLOTTABLE_TYPES: &[&str] = &[$($name),*];
+
+        fn decoder_for(type_name: &str, topic: &str) -> HorusResult<Decoder> {
+            match short_type_name(type_name) {
+                $($name => {
+                    let hub = Hub::<$ty>::new(topic)?;
+                    Ok(Box::new(move || {
+                        hub.recv(&mut None)
+                            .and_then(|msg| serde_json::to_value(msg).ok())
+                    }))
+                })*
+                _ => Err(HorusError::Unsupported(format!(
+                    "Messages of type '{}' cannot be plotted",
+                    type_name
+                ))),
+            }
+        }
+    };
+}
+
+plottable_types! {
+    "f32" => f32,
+    "f64" => f64,
+    "i32" => i32,
+    "i64" => i64,
+    "u32" => u32,
+    "u64" => u64,
+    "bool" => bool,
+    "CmdVel" => horus_library::CmdVel,
+    "Twist" => horus_library::Twist,
+    "Pose2D" => horus_library::Pose2D,
+    "Transform" => horus_library::Transform,
+    "Vector3" => horus_library::Vector3,
+    "Point3" => horus_library::Point3,
+    "Quaternion" => horus_library::Quaternion,
+    "Imu" => horus_library::Imu,
+    "Odometry" => horus_library::Odometry,
+    "LaserScan" => horus_library::LaserScan,
+    "BatteryState" => horus_library::BatteryState,
+    "NavSatFix" => horus_library::NavSatFix,
+    "Range" => horus_library::Range,
+    "MotorCommand" => horus_library::MotorCommand,
+    "DifferentialDriveCommand" => horus_library::DifferentialDriveCommand,
+    "ServoCommand" => horus_library::ServoCommand,
+    "JointCommand" => horus_library::JointCommand,
+    "PwmCommand" => horus_library::PwmCommand,
+    "WrenchStamped" => horus_library::WrenchStamped,
+    "ResourceUsage" => horus_library::ResourceUsage,
+    "JoystickInput" => horus_library::JoystickInput,
+}
+
+/// `horus_library::messages::sensor::Imu` -> `Imu`
+pub fn short_type_name(type_name: &str) -> &str {
+    let base = type_name.split('<').next().unwrap_or(type_name);
+    base.rsplit("::").next().unwrap_or(base).trim()
+}
+
+/// Check if messages of a type can be plotted
+pub fn is_plottable(type_name: &str) -> bool {
+    PLOTTABLE_TYPES.contains(&short_type_name(type_name))
+}
+
+/// Numeric fields of a message as `(path, value)`; booleans are 0 or 1
+///
+/// Arrays longer than 64 elements and fixed-size text buffers (arrays of 16
+/// or more bytes, e.g. `frame_id`) are skipped.
+pub fn flatten_numeric(value: &Value) -> Vec<(String, f64)> {
+    let mut fields = Vec::new();
+    flatten_into(value, String::new(), &mut fields);
+    fields
+}
+
+fn flatten_into(value: &Value, path: String, fields: &mut Vec<(String, f64)>) {
+    if fields.len() >= MAX_FIELDS {
+        return;
+    }
+    match value {
+        Value::Number(number) => {
+            if let Some(number) = number.as_f64() {
+                fields.push((path, number));
+            }
+        }
+        Value::Bool(flag) => fields.push((path, if *flag { 1.0 } else { 0.0 })),
+        Value::Array(items) => {
+            let is_text = items.len() >= 16
+                && items
+                    .iter()
+                    .all(|item| item.as_u64().is_some_and(|byte| byte <= 255));
+            if items.len() > MAX_ARRAY_ELEMENTS || is_text {
+                return;
+            }
+            for (index, item) in items.iter().enumerate() {
+                flatten_into(item, format!("{}[{}]", path, index), fields);
+            }
+        }
+        Value::Object(map) => {
+            for (key, item) in map {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                flatten_into(item, path, fields);
+            }
+        }
+        Value::String(_) | Value::Null => {}
+    }
+}
+
+/// One received message
+#[derive(Debug, Clone)]
+struct Sample {
+    /// Seconds since the plotter started
+    t: f64,
+    values: HashMap<String, f64>,
+}
+
+/// Samples of one subscribed topic
+struct TopicSeries {
+    type_name: String,
+    decoder: Decoder,
+    /// Fields seen so far
+    fields: Vec<String>,
+    samples: VecDeque<Sample>,
+    last_request: Instant,
+}
+
+impl TopicSeries {
+    fn push(&mut self, t: f64, message: &Value) {
+        let values = flatten_numeric(message);
+        for (field, _) in &values {
+            if !self.fields.contains(field) {
+                self.fields.push(field.clone());
+            }
+        }
+        self.samples.push_back(Sample {
+            t,
+            values: values.into_iter().collect(),
+        });
+    }
+
+    fn trim(&mut self, now: f64) {
+        while self.samples.len() > MAX_SAMPLES
+            || self
+                .samples
+                .front()
+                .is_some_and(|sample| now - sample.t > MAX_WINDOW_SECS)
+        {
+            self.samples.pop_front();
+        }
+    }
+
+    fn window(&self, since: f64) -> impl Iterator<Item = &Sample> {
+        // Samples are in time order
+        let start = self.samples.partition_point(|sample| sample.t <= since);
+        self.samples.range(start..)
+    }
+}
+
+/// New samples of the requested fields
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PlotData {
+    pub type_name: String,
+    /// All numeric fields of the topic
+    pub fields: Vec<String>,
+    /// Sample times in seconds
+    pub t: Vec<f64>,
+    /// One column per requested field, `None` where a message lacked it
+    pub values: Vec<Vec<Option<f64>>>,
+    /// Latest sample time, pass as `since` of the next request
+    pub latest: f64,
+}
+
+/// Time series of all plotted topics
+pub struct TopicPlotter {
+    start: Instant,
+    series: HashMap<String, TopicSeries>,
+}
+
+impl Default for TopicPlotter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TopicPlotter {
+    pub fn new() -> Self {
+        Self {
+            start: Instant::now(),
+            series: HashMap::new(),
+        }
+    }
+
+    /// Seconds since the plotter started
+    pub fn now(&self) -> f64 {
+        self.start.elapsed().as_secs_f64()
+    }
+
+    /// Start sampling a topic; does nothing if it is already sampled
+    pub fn subscribe(&mut self, topic: &str, type_name: &str) -> HorusResult<()> {
+        if let Some(series) = self.series.get_mut(topic) {
+            series.last_request = Instant::now();
+            return Ok(());
+        }
+        let decoder = decoder_for(type_name, topic)?;
+        self.subscribe_with(topic, type_name, decoder);
+        Ok(())
+    }
+
+    fn subscribe_with(&mut self, topic: &str, type_name: &str, decoder: Decoder) {
+        self.series.insert(
+            topic.to_string(),
+            TopicSeries {
+                type_name: short_type_name(type_name).to_string(),
+                decoder,
+                fields: Vec::new(),
+                samples: VecDeque::new(),
+                last_request: Instant::now(),
+            },
+        );
+    }
+
+    /// Stop sampling a topic and drop its samples
+    pub fn unsubscribe(&mut self, topic: &str) -> bool {
+        self.series.remove(topic).is_some()
+    }
+
+    /// Subscribed topics
+    pub fn topics(&self) -> Vec<String> {
+        let mut topics: Vec<String> = self.series.keys().cloned().collect();
+        topics.sort();
+        topics
+    }
+
+    /// Read new messages of all topics and drop samples outside the window
+    pub fn poll(&mut self) {
+        let t = self.now();
+        self.series
+            .retain(|_, series| series.last_request.elapsed() < IDLE_TIMEOUT);
+        for series in self.series.values_mut() {
+            for _ in 0..MAX_MESSAGES_PER_POLL {
+                match (series.decoder)() {
+                    Some(message) => series.push(t, &message),
+                    None => break,
+                }
+            }
+            series.trim(t);
+        }
+    }
+
+    /// Samples of `fields` newer than `since` seconds
+    pub fn data(&mut self, topic: &str, fields: &[String], since: f64) -> Option<PlotData> {
+        let series = self.series.get_mut(topic)?;
+        series.last_request = Instant::now();
+
+        let mut t = Vec::new();
+        let mut values = vec![Vec::new(); fields.len()];
+        for sample in series.window(since) {
+            t.push(sample.t);
+            for (column, field) in values.iter_mut().zip(fields) {
+                column.push(sample.values.get(field).copied());
+            }
+        }
+        Some(PlotData {
+            type_name: series.type_name.clone(),
+            fields: series.fields.clone(),
+            latest: t.last().copied().unwrap_or(since),
+            t,
+            values,
+        })
+    }
+
+    /// CSV of `fields` over the last `window_secs` seconds
+    ///
+    /// The first column is the time in seconds relative to the first row.
+    pub fn to_csv(&self, topic: &str, fields: &[String], window_secs: f64) -> Option<String> {
+        let series = self.series.get(topic)?;
+        let since = self.now() - window_secs.clamp(0.0, MAX_WINDOW_SECS);
+
+        let mut csv = String::from("time");
+        for field in fields {
+            csv.push(',');
+            csv.push_str(&csv_escape(field));
+        }
+        csv.push('\n');
+
+        let mut first = None;
+        for sample in series.window(since) {
+            let start = *first.get_or_insert(sample.t);
+            let _ = write!(csv, "{:.6}", sample.t - start);
+            for field in fields {
+                csv.push(',');
+                if let Some(value) = sample.values.get(field) {
+                    let _ = write!(csv, "{}", value);
+                }
+            }
+            csv.push('\n');
+        }
+        Some(csv)
+    }
+}
+
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_flatten_numeric() {
+        let imu = json!({
+            "angular_velocity": [0.1, 0.2, 0.3],
+            "frame_id": [105, 109, 117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            "ranges": vec![1.0; 100],
+            "pose": { "x": 1.5, "valid": true },
+            "label": "imu",
+        });
+        let fields = flatten_numeric(&imu);
+        let names: Vec<&str> = fields.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "angular_velocity[0]",
+                "angular_velocity[1]",
+                "angular_velocity[2]",
+                "pose.valid",
+                "pose.x",
+            ]
+        );
+        assert_eq!(fields[2].1, 0.3);
+        assert_eq!(fields[3].1, 1.0);
+
+        assert_eq!(flatten_numeric(&json!(12.5)), [(String::new(), 12.5)]);
+    }
+
+    #[test]
+    fn test_type_names() {
+        assert_eq!(
+            short_type_name("horus_library::messages::sensor::Imu"),
+            "Imu"
+        );
+        assert!(is_plottable(
+            "horus_library::messages::sensor::BatteryState"
+        ));
+        assert!(is_plottable("f64"));
+        assert!(!is_plottable("horus_library::messages::vision::Image"));
+    }
+
+    #[test]
+    fn test_series_and_csv() {
+        let mut messages: VecDeque<Value> = VecDeque::from([
+            json!({ "voltage": 12.4, "current": 1.0 }),
+            json!({ "voltage": 12.3 }),
+        ]);
+        let mut plotter = TopicPlotter::new();
+        plotter.subscribe_with(
+            "battery",
+            "horus_library::messages::sensor::BatteryState",
+            Box::new(move || messages.pop_front()),
+        );
+        plotter.poll();
+
+        let fields = vec!["voltage".to_string(), "current".to_string()];
+        let data = plotter.data("battery", &fields, -1.0).unwrap();
+        assert_eq!(data.type_name, "BatteryState");
+        assert_eq!(data.fields, ["current", "voltage"]);
+        assert_eq!(data.t.len(), 2);
+        assert_eq!(data.values[0], [Some(12.4), Some(12.3)]);
+        assert_eq!(data.values[1], [Some(1.0), None]);
+
+        // Nothing new since the latest sample
+        let newer = plotter.data("battery", &fields, data.latest).unwrap();
+        assert!(newer.t.is_empty());
+
+        let csv = plotter.to_csv("battery", &fields, 60.0).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "time,voltage,current");
+        assert_eq!(lines[1], "0.000000,12.4,1");
+        assert_eq!(lines[2], "0.000000,12.3,");
+
+        assert!(plotter.unsubscribe("battery"));
+        assert!(plotter.data("battery", &fields, 0.0).is_none());
+    }
+}
@@ -0,0 +1,577 @@
+//! Remote monitoring: a headless agent on the robot, the dashboard on a workstation
+//!
+//! `horus monitor --agent` runs only the password-protected monitoring API of
+//! the web monitor on the robot: status, nodes, topics, graph, logs,
+//! parameters, plotting, teleop and the `/api/ws` update stream. It serves no
+//! dashboard page and no package management. It listens on loopback unless
+//! `--bind` is given; other addresses require TLS (`--tls-cert`/`--tls-key`)
+//! or an explicit `--insecure`.
+//!
+//! `horus monitor --remote <host>` logs in to an agent and serves the regular
+//! dashboard on the workstation's localhost. `/api` requests are forwarded to
+//! the agent with the session token and the update WebSocket is bridged, so
+//! the dashboard renders the robot's data unchanged. An expired session is
+//! renewed with the password given at startup.
+
+use crate::monitor::{self, AppState};
+use crate::security::{security_headers_middleware, AuthService};
+use anyhow::Context;
+use axum::{
+    body::Body,
+    extract::{
+        ws::{Message, WebSocket, WebSocketUpgrade},
+        State,
+    },
+    http::{header, HeaderValue, Request, StatusCode},
+    middleware,
+    response::{Html, IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use futures::{sink::SinkExt, stream::StreamExt};
+use std::net::{IpAddr, SocketAddr};
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::{self, client::IntoClientRequest};
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+/// Default port of the agent (same as the web monitor)
+pub const DEFAULT_AGENT_PORT: u16 = 3000;
+
+/// Largest request body forwarded to the agent
+const MAX_FORWARD_BODY: usize = 16 * 1024 * 1024;
+
+// ============================================================================
+// Agent (robot side)
+// ============================================================================
+
+/// Routes served by the agent; everything but login requires a session
+pub fn agent_router(state: Arc<AppState>) -> Router {
+    let api_routes = Router::new()
+        .route("/api/status", get(monitor::status_handler))
+        .route("/api/nodes", get(monitor::nodes_handler))
+        .route("/api/topics", get(monitor::topics_handler))
+        .route("/api/graph", get(monitor::graph_handler))
+        .route("/api/network", get(monitor::network_handler))
+        .route("/api/logs/all", get(monitor::logs_all_handler))
+        .route("/api/logs/node/:name", get(monitor::logs_node_handler))
+        .route("/api/logs/topic/:name", get(monitor::logs_topic_handler))
+        .route("/api/params", get(monitor::params_list_handler))
+        .route("/api/params/:key", get(monitor::params_get_handler))
+        .route("/api/params/:key", post(monitor::params_set_handler))
+        .route("/api/teleop", get(monitor::teleop_status_handler))
+        .route("/api/teleop/config", post(monitor::teleop_config_handler))
+        .route("/api/teleop/cmd", post(monitor::teleop_command_handler))
+        .route("/api/teleop/stop", post(monitor::teleop_stop_handler))
+        .route("/api/plot/topics", get(monitor::plot_topics_handler))
+        .route("/api/plot/subscribe", post(monitor::plot_subscribe_handler))
+        .route(
+            "/api/plot/unsubscribe",
+            post(monitor::plot_unsubscribe_handler),
+        )
+        .route("/api/plot/data", get(monitor::plot_data_handler))
+        .route("/api/plot/csv", get(monitor::plot_csv_handler))
+        .route("/api/ws", get(monitor::websocket_handler))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            monitor::monitor_session_middleware,
+        ));
+
+    Router::new()
+        .merge(api_routes)
+        .merge(crate::monitor_api::api_v1_routes(state.clone()))
+        .route("/api/login", post(monitor::login_handler))
+        .with_state(state)
+        .layer(middleware::from_fn(security_headers_middleware))
+}
+
+/// Certificate and private key (PEM files) the agent serves HTTPS with
+pub struct AgentTls {
+    pub cert: PathBuf,
+    pub key: PathBuf,
+}
+
+/// Refuse to expose the agent beyond this host over plain HTTP
+///
+/// A loopback agent is reached through an SSH tunnel; any other address
+/// needs TLS or an explicit `--insecure`.
+fn check_agent_bind(bind: IpAddr, tls: bool, insecure: bool) -> anyhow::Result<()> {
+    if bind.is_loopback() || tls || insecure {
+        return Ok(());
+    }
+    anyhow::bail!(
+        "Refusing to serve the monitor agent on {} without encryption. \
+         Pass --tls-cert and --tls-key, or --insecure on a trusted network \
+         (or keep the default loopback address and use an SSH tunnel).",
+        bind
+    )
+}
+
+/// Run the monitoring agent on the robot
+pub async fn run_agent(
+    port: u16,
+    bind: IpAddr,
+    tls: Option<AgentTls>,
+    insecure: bool,
+) -> anyhow::Result<()> {
+    use colored::Colorize;
+
+    check_agent_bind(bind, tls.is_some(), insecure)?;
+
+    let password_hash = if !crate::security::auth::is_password_configured() {
+        crate::security::auth::prompt_for_password_setup()?
+    } else {
+        crate::security::auth::load_password_hash()?
+    };
+    if password_hash.is_empty() {
+        anyhow::bail!(
+            "The monitor agent requires a password. Set one with 'horus monitor -r --agent'."
+        );
+    }
+
+    let state = Arc::new(AppState {
+        port,
+        params: Arc::new(
+            horus_core::RuntimeParams::init()
+                .unwrap_or_else(|_| horus_core::RuntimeParams::default()),
+        ),
+        auth_service: Arc::new(AuthService::new(password_hash)?),
+        current_workspace: crate::workspace::find_workspace_root(),
+        auth_disabled: false,
+    });
+
+    let addr = SocketAddr::new(bind, port);
+    let tls_config = match tls {
+        Some(tls) => Some(
+            axum_server::tls_rustls::RustlsConfig::from_pem_file(&tls.cert, &tls.key)
+                .await
+                .with_context(|| {
+                    format!(
+                        "cannot load TLS certificate {} / key {}",
+                        tls.cert.display(),
+                        tls.key.display()
+                    )
+                })?,
+        ),
+        None => None,
+    };
+    let scheme = if tls_config.is_some() {
+        "https"
+    } else {
+        "http"
+    };
+
+    println!("{}", "HORUS Monitor Agent is running!".green().bold());
+    println!("\n{}:", "Connect from your workstation".cyan().bold());
+    if bind.is_loopback() {
+        println!(
+            "   • {}",
+            format!("ssh -L {0}:localhost:{0} <robot>", port).bright_blue()
+        );
+        println!(
+            "   • {}",
+            format!("horus monitor --remote {}://localhost:{}", scheme, port).bright_blue()
+        );
+    } else {
+        let host = hostname::get()
+            .ok()
+            .and_then(|h| h.into_string().ok())
+            .unwrap_or_else(|| "<robot>".to_string());
+        println!(
+            "   • {}",
+            format!("horus monitor --remote {}://{}:{}", scheme, host, port).bright_blue()
+        );
+    }
+    println!("\n{}:", "Security".cyan().bold());
+    println!("   • Password-based authentication with session management");
+    if tls_config.is_some() {
+        println!("   • TLS encryption enabled");
+    } else if !bind.is_loopback() {
+        println!(
+            "   {}",
+            "[WARNING] Traffic is not encrypted (--insecure) - use a trusted network or VPN"
+                .yellow()
+        );
+    }
+    println!("\n   Press {} to stop", "Ctrl+C".bright_red());
+
+    let app = agent_router(state);
+    match tls_config {
+        Some(config) => {
+            axum_server::bind_rustls(addr, config)
+                .serve(app.into_make_service())
+                .await?
+        }
+        None => {
+            let listener = tokio::net::TcpListener::bind(addr).await?;
+            axum::serve(listener, app).await?
+        }
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Remote dashboard (workstation side)
+// ============================================================================
+
+type AgentStream = WebSocketStream<MaybeTlsStream<TcpStream>>;
+
+/// Logged-in session with a monitor agent
+struct AgentClient {
+    /// `http://host:port`
+    base_url: String,
+    /// `ws://host:port/api/ws`
+    ws_url: String,
+    password: String,
+    token: tokio::sync::Mutex<String>,
+    http: reqwest::Client,
+}
+
+impl AgentClient {
+    async fn connect(host: &str, password: String) -> anyhow::Result<Self> {
+        let address = agent_address(host);
+        let (http_scheme, ws_scheme) = if agent_uses_tls(host) {
+            ("https", "wss")
+        } else {
+            ("http", "ws")
+        };
+        let mut client = Self {
+            base_url: format!("{}://{}", http_scheme, address),
+            ws_url: format!("{}://{}/api/ws", ws_scheme, address),
+            password,
+            token: tokio::sync::Mutex::new(String::new()),
+            http: reqwest::Client::builder()
+                .timeout(std::time::Duration::from_secs(30))
+                .build()?,
+        };
+        *client.token.get_mut() = client.login().await?;
+        Ok(client)
+    }
+
+    /// Log in with the password and return the new session token
+    async fn login(&self) -> anyhow::Result<String> {
+        let response = self
+            .http
+            .post(format!("{}/api/login", self.base_url))
+            .json(&serde_json::json!({ "password": self.password }))
+            .send()
+            .await
+            .with_context(|| format!("cannot reach monitor agent at {}", self.base_url))?;
+
+        let status = response.status();
+        let body: serde_json::Value = response.json().await.unwrap_or_default();
+        match body["session_token"].as_str() {
+            Some(token) if status.is_success() => Ok(token.to_string()),
+            _ => anyhow::bail!(
+                "login to monitor agent at {} failed: {}",
+                self.base_url,
+                body["error"].as_str().unwrap_or(status.as_str())
+            ),
+        }
+    }
+
+    /// Replace a rejected session token, unless another request already did
+    async fn renew_session(&self, rejected: &str) -> anyhow::Result<()> {
+        let mut token = self.token.lock().await;
+        if *token == rejected {
+            *token = self.login().await?;
+        }
+        Ok(())
+    }
+
+    async fn send(
+        &self,
+        method: &reqwest::Method,
+        path: &str,
+        content_type: Option<&str>,
+        body: &[u8],
+        token: &str,
+    ) -> reqwest::Result<reqwest::Response> {
+        let mut request = self
+            .http
+            .request(method.clone(), format!("{}{}", self.base_url, path))
+            .bearer_auth(token)
+            .body(body.to_vec());
+        if let Some(content_type) = content_type {
+            request = request.header(reqwest::header::CONTENT_TYPE, content_type);
+        }
+        request.send().await
+    }
+
+    /// Forward an API request, logging in again once if the session expired
+    async fn forward(
+        &self,
+        method: &str,
+        path: &str,
+        content_type: Option<&str>,
+        body: &[u8],
+    ) -> anyhow::Result<reqwest::Response> {
+        let method = reqwest::Method::from_bytes(method.as_bytes())?;
+        let token = self.token.lock().await.clone();
+        let response = self.send(&method, path, content_type, body, &token).await?;
+        if response.status() != reqwest::StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+
+        self.renew_session(&token).await?;
+        let token = self.token.lock().await.clone();
+        Ok(self.send(&method, path, content_type, body, &token).await?)
+    }
+
+    async fn try_open_stream(&self, token: &str) -> anyhow::Result<AgentStream> {
+        let mut request = self.ws_url.as_str().into_client_request()?;
+        request.headers_mut().insert(
+            header::AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {}", token))?,
+        );
+        let (stream, _) = tokio_tungstenite::connect_async(request).await?;
+        Ok(stream)
+    }
+
+    /// Open the agent's update stream, logging in again once if the session expired
+    async fn open_stream(&self) -> anyhow::Result<AgentStream> {
+        let token = self.token.lock().await.clone();
+        match self.try_open_stream(&token).await {
+            Err(e) if is_unauthorized(&e) => {
+                self.renew_session(&token).await?;
+                let token = self.token.lock().await.clone();
+                self.try_open_stream(&token).await
+            }
+            result => result,
+        }
+    }
+}
+
+/// Whether a WebSocket handshake was rejected for lack of a valid session
+fn is_unauthorized(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<tungstenite::Error>(),
+        Some(tungstenite::Error::Http(response)) if response.status() == StatusCode::UNAUTHORIZED
+    )
+}
+
+/// `host:port` of an agent; the port defaults to [`DEFAULT_AGENT_PORT`]
+fn agent_address(host: &str) -> String {
+    let host = host
+        .trim_start_matches("https://")
+        .trim_start_matches("http://")
+        .trim_start_matches("wss://")
+        .trim_start_matches("ws://")
+        .trim_end_matches('/');
+
+    match host.rfind(']') {
+        // Bracketed IPv6 address, with or without port
+        Some(end) if host[end..].contains(':') => host.to_string(),
+        Some(_) => format!("{}:{}", host, DEFAULT_AGENT_PORT),
+        None => match host.matches(':').count() {
+            0 => format!("{}:{}", host, DEFAULT_AGENT_PORT),
+            1 => host.to_string(),
+            // Bare IPv6 address
+            _ => format!("[{}]:{}", host, DEFAULT_AGENT_PORT),
+        },
+    }
+}
+
+/// Whether `--remote` names an agent serving TLS (`https://` or `wss://`)
+fn agent_uses_tls(host: &str) -> bool {
+    host.starts_with("https://") || host.starts_with("wss://")
+}
+
+struct RemoteState {
+    agent: AgentClient,
+    port: u16,
+}
+
+async fn remote_index_handler(State(state): State<Arc<RemoteState>>) -> impl IntoResponse {
+    Html(monitor::generate_html(state.port))
+}
+
+/// Forward an `/api` request to the agent and relay its response
+async fn remote_proxy_handler(
+    State(state): State<Arc<RemoteState>>,
+    req: Request<Body>,
+) -> Response {
+    let path = req
+        .uri()
+        .path_and_query()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_default();
+    if !path.starts_with("/api/") {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    let method = req.method().as_str().to_string();
+    let content_type = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map(String::from);
+    let body = match axum::body::to_bytes(req.into_body(), MAX_FORWARD_BODY).await {
+        Ok(body) => body,
+        Err(_) => return StatusCode::PAYLOAD_TOO_LARGE.into_response(),
+    };
+
+    let response = match state
+        .agent
+        .forward(&method, &path, content_type.as_deref(), &body)
+        .await
+    {
+        Ok(response) => response,
+        Err(e) => {
+            return (
+                StatusCode::BAD_GATEWAY,
+                Json(serde_json::json!({
+                    "success": false,
+                    "error": format!("Monitor agent unreachable: {}", e)
+                })),
+            )
+                .into_response()
+        }
+    };
+
+    let status =
+        StatusCode::from_u16(response.status().as_u16()).unwrap_or(StatusCode::BAD_GATEWAY);
+    // Keep the headers the dashboard relies on (JSON vs CSV downloads)
+    let relayed: Vec<(header::HeaderName, HeaderValue)> = [
+        (header::CONTENT_TYPE, reqwest::header::CONTENT_TYPE),
+        (
+            header::CONTENT_DISPOSITION,
+            reqwest::header::CONTENT_DISPOSITION,
+        ),
+    ]
+    .into_iter()
+    .filter_map(|(name, upstream)| {
+        let value = response.headers().get(upstream)?;
+        Some((name, HeaderValue::from_bytes(value.as_bytes()).ok()?))
+    })
+    .collect();
+
+    match response.bytes().await {
+        Ok(bytes) => {
+            let mut relayed_response = (status, bytes.to_vec()).into_response();
+            relayed_response.headers_mut().extend(relayed);
+            relayed_response
+        }
+        Err(_) => StatusCode::BAD_GATEWAY.into_response(),
+    }
+}
+
+async fn remote_websocket_handler(
+    State(state): State<Arc<RemoteState>>,
+    ws: WebSocketUpgrade,
+) -> impl IntoResponse {
+    ws.on_upgrade(move |socket| bridge_websocket(socket, state))
+}
+
+/// Relay messages between the browser and the agent's update stream
+async fn bridge_websocket(socket: WebSocket, state: Arc<RemoteState>) {
+    let upstream = match state.agent.open_stream().await {
+        Ok(stream) => stream,
+        Err(e) => {
+            log::warn!("Cannot open monitor agent stream: {}", e);
+            return;
+        }
+    };
+
+    let (mut browser_tx, mut browser_rx) = socket.split();
+    let (mut agent_tx, mut agent_rx) = upstream.split();
+
+    let to_browser = async {
+        while let Some(Ok(message)) = agent_rx.next().await {
+            let message = match message {
+                tungstenite::Message::Text(text) => Message::Text(text),
+                tungstenite::Message::Binary(data) => Message::Binary(data),
+                tungstenite::Message::Close(_) => break,
+                _ => continue,
+            };
+            if browser_tx.send(message).await.is_err() {
+                break;
+            }
+        }
+    };
+    let to_agent = async {
+        while let Some(Ok(message)) = browser_rx.next().await {
+            let message = match message {
+                Message::Text(text) => tungstenite::Message::Text(text),
+                Message::Binary(data) => tungstenite::Message::Binary(data),
+                Message::Close(_) => break,
+                _ => continue,
+            };
+            if agent_tx.send(message).await.is_err() {
+                break;
+            }
+        }
+    };
+
+    // Either side closing ends the bridge; the dashboard reconnects on its own
+    tokio::select! {
+        _ = to_browser => {}
+        _ = to_agent => {}
+    }
+}
+
+/// Serve the dashboard for a remote agent on localhost
+pub async fn run_remote(host: &str, port: u16) -> anyhow::Result<()> {
+    use colored::Colorize;
+
+    let password = crate::security::auth::prompt_for_password()?;
+    let agent = AgentClient::connect(host, password).await?;
+    let agent_url = agent.base_url.clone();
+
+    let state = Arc::new(RemoteState { agent, port });
+    let app = Router::new()
+        .route("/", get(remote_index_handler))
+        .route("/api/ws", get(remote_websocket_handler))
+        .fallback(remote_proxy_handler)
+        .with_state(state)
+        .layer(middleware::from_fn(security_headers_middleware));
+
+    // The session with the agent is already authenticated: only serve locally
+    let addr = format!("127.0.0.1:{}", port);
+    let listener = tokio::net::TcpListener::bind(&addr).await?;
+
+    println!("{}", "HORUS Remote Monitor is running!".green().bold());
+    println!("\n{}:", "Access URLs".cyan().bold());
+    println!(
+        "   • Local:    {}",
+        format!("http://localhost:{}", port).bright_blue()
+    );
+    println!("   • Agent:    {}", agent_url.bright_blue());
+    println!("\n   Press {} to stop", "Ctrl+C".bright_red());
+
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_agent_address() {
+        assert_eq!(agent_address("robot.local"), "robot.local:3000");
+        assert_eq!(agent_address("192.168.1.20:3100"), "192.168.1.20:3100");
+        assert_eq!(
+            agent_address("http://robot.local:3100/"),
+            "robot.local:3100"
+        );
+        assert_eq!(agent_address("[fe80::1]:3100"), "[fe80::1]:3100");
+        assert_eq!(agent_address("[fe80::1]"), "[fe80::1]:3000");
+        assert_eq!(agent_address("fe80::1"), "[fe80::1]:3000");
+        assert_eq!(agent_address("https://robot.local"), "robot.local:3000");
+        assert!(agent_uses_tls("https://robot.local"));
+        assert!(!agent_uses_tls("robot.local:3100"));
+    }
+
+    #[test]
+    fn test_agent_bind_requires_tls_or_insecure() {
+        let loopback: IpAddr = "127.0.0.1".parse().unwrap();
+        let any: IpAddr = "0.0.0.0".parse().unwrap();
+        assert!(check_agent_bind(loopback, false, false).is_ok());
+        assert!(check_agent_bind("::1".parse().unwrap(), false, false).is_ok());
+        assert!(check_agent_bind(any, false, false).is_err());
+        assert!(check_agent_bind(any, true, false).is_ok());
+        assert!(check_agent_bind(any, false, true).is_ok());
+    }
+}
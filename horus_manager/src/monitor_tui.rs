@@ -29,6 +29,10 @@ pub struct NodeStatus {
     pub memory_usage: u64,
     pub publishers: Vec<String>,  // Topic names this node publishes to
     pub subscribers: Vec<String>, // Topic names this node subscribes from
+    pub deadline_misses: u64,
+    pub jitter_p99_us: f64,
+    pub tick_p95_us: f64,
+    pub worst_tick_us: f64,
 }
 
 #[derive(Clone)]
@@ -66,6 +70,12 @@ pub struct TuiDashboard {
     // Overview panel focus
     overview_panel_focus: OverviewPanelFocus,
 
+    // Nodes tab sorting and filtering
+    node_sort: NodeSortKey,
+    node_sort_descending: bool,
+    node_filter: String,
+    node_filter_editing: bool,
+
     // Workspace caching (to avoid repeated filesystem operations)
     workspace_cache: Vec<WorkspaceData>,
     workspace_cache_time: Instant,
@@ -159,6 +169,38 @@ enum OverviewPanelFocus {
     Topics, // Focused on topics panel
 }
 
+/// Column the Nodes tab is sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeSortKey {
+    Name,
+    Cpu,
+    Memory,
+    TickP95,
+    DeadlineMisses,
+}
+
+impl NodeSortKey {
+    fn as_str(&self) -> &'static str {
+        match self {
+            NodeSortKey::Name => "Name",
+            NodeSortKey::Cpu => "CPU",
+            NodeSortKey::Memory => "RSS",
+            NodeSortKey::TickP95 => "Tick p95",
+            NodeSortKey::DeadlineMisses => "Misses",
+        }
+    }
+
+    fn next(&self) -> Self {
+        match self {
+            NodeSortKey::Name => NodeSortKey::Cpu,
+            NodeSortKey::Cpu => NodeSortKey::Memory,
+            NodeSortKey::Memory => NodeSortKey::TickP95,
+            NodeSortKey::TickP95 => NodeSortKey::DeadlineMisses,
+            NodeSortKey::DeadlineMisses => NodeSortKey::Name,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 struct WorkspaceData {
     name: String,
@@ -294,6 +336,11 @@ impl TuiDashboard {
 
             overview_panel_focus: OverviewPanelFocus::Nodes,
 
+            node_sort: NodeSortKey::Name,
+            node_sort_descending: false,
+            node_filter: String::new(),
+            node_filter_editing: false,
+
             // Initialize workspace cache as empty (will load on first access)
             workspace_cache: Vec::new(),
             workspace_cache_time: Instant::now() - Duration::from_secs(10), // Force initial load
@@ -369,6 +416,24 @@ impl TuiDashboard {
                         continue;
                     }
 
+                    // While typing a node filter, keys edit the filter text
+                    if self.node_filter_editing {
+                        match key.code {
+                            KeyCode::Enter => self.node_filter_editing = false,
+                            KeyCode::Esc => {
+                                self.node_filter_editing = false;
+                                self.node_filter.clear();
+                            }
+                            KeyCode::Backspace => {
+                                self.node_filter.pop();
+                            }
+                            KeyCode::Char(c) => self.node_filter.push(c),
+                            _ => {}
+                        }
+                        self.selected_index = 0;
+                        continue;
+                    }
+
                     // Check if Shift is pressed
                     let shift_pressed = key.modifiers.contains(KeyModifiers::SHIFT);
 
@@ -398,6 +463,11 @@ impl TuiDashboard {
                                 self.show_log_panel = false;
                                 self.panel_target = None;
                                 self.panel_scroll_offset = 0;
+                            } else if self.active_tab == Tab::Nodes && !self.node_filter.is_empty()
+                            {
+                                // Clear node filter
+                                self.node_filter.clear();
+                                self.selected_index = 0;
                             }
                         }
 
@@ -464,6 +534,24 @@ impl TuiDashboard {
                             }
                         }
 
+                        // Node sorting and filtering (only in Nodes tab)
+                        KeyCode::Char('s') | KeyCode::Char('S')
+                            if self.active_tab == Tab::Nodes =>
+                        {
+                            self.cycle_node_sort();
+                        }
+                        KeyCode::Char('r') | KeyCode::Char('R')
+                            if self.active_tab == Tab::Nodes =>
+                        {
+                            self.node_sort_descending = !self.node_sort_descending;
+                            self.selected_index = 0;
+                        }
+                        KeyCode::Char('/')
+                            if self.active_tab == Tab::Nodes && !self.show_log_panel =>
+                        {
+                            self.node_filter_editing = true;
+                        }
+
                         // Parameter operations (only in Parameters tab)
                         KeyCode::Char('r') | KeyCode::Char('R')
                             if self.active_tab == Tab::Parameters
@@ -2412,8 +2500,8 @@ impl TuiDashboard {
 
     fn draw_nodes_simple(&self, f: &mut Frame, area: Rect) {
         // Simplified view showing only node names
-        let rows: Vec<Row> = self
-            .nodes
+        let nodes = self.visible_nodes();
+        let rows: Vec<Row> = nodes
             .iter()
             .map(|node| {
                 let is_running = node.status == "active";
@@ -2443,8 +2531,8 @@ impl TuiDashboard {
 
         // Create table state with current selection
         let mut table_state = TableState::default();
-        if !self.nodes.is_empty() {
-            let selected = self.selected_index.min(self.nodes.len() - 1);
+        if !nodes.is_empty() {
+            let selected = self.selected_index.min(nodes.len() - 1);
             table_state.select(Some(selected));
         }
 
@@ -2452,8 +2540,8 @@ impl TuiDashboard {
     }
 
     fn draw_nodes(&self, f: &mut Frame, area: Rect) {
-        let rows: Vec<Row> = self
-            .nodes
+        let nodes = self.visible_nodes();
+        let rows: Vec<Row> = nodes
             .iter()
             .map(|node| {
                 let is_running = node.status == "active";
@@ -2473,12 +2561,31 @@ impl TuiDashboard {
                     node.subscribers.join(", ")
                 };
 
+                let misses_color = if node.deadline_misses > 0 {
+                    Color::Yellow
+                } else {
+                    Color::White
+                };
+                let cpu_color = if node.cpu_usage > 80.0 {
+                    Color::Red
+                } else if node.cpu_usage > 50.0 {
+                    Color::Yellow
+                } else {
+                    Color::White
+                };
+
                 Row::new(vec![
                     Cell::from(node.name.clone()),
                     Cell::from(node.process_id.to_string()),
-                    Cell::from(format!("{:.1}%", node.cpu_usage)),
-                    Cell::from(format!("{} MB", node.memory_usage / 1024 / 1024)),
+                    Cell::from(format!("{:.1}%", node.cpu_usage))
+                        .style(Style::default().fg(cpu_color)),
+                    Cell::from(format_bytes(node.memory_usage)),
                     Cell::from(status).style(Style::default().fg(status_color)),
+                    Cell::from(format!("{:.0}us", node.tick_p95_us)),
+                    Cell::from(format!("{:.0}us", node.worst_tick_us)),
+                    Cell::from(node.deadline_misses.to_string())
+                        .style(Style::default().fg(misses_color)),
+                    Cell::from(format!("{:.0}us", node.jitter_p99_us)),
                     Cell::from(pubs).style(Style::default().fg(Color::Green)),
                     Cell::from(subs).style(Style::default().fg(Color::Blue)),
                 ])
@@ -2489,29 +2596,59 @@ impl TuiDashboard {
             Constraint::Percentage(15),
             Constraint::Length(8),
             Constraint::Length(8),
+            Constraint::Length(9),
+            Constraint::Length(9),
             Constraint::Length(10),
-            Constraint::Length(10),
-            Constraint::Percentage(24),
-            Constraint::Percentage(25),
+            Constraint::Length(9),
+            Constraint::Length(8),
+            Constraint::Length(11),
+            Constraint::Percentage(15),
+            Constraint::Percentage(15),
         ];
+
+        // Mark the sorted column with its direction
+        let arrow = if self.node_sort_descending {
+            "▼"
+        } else {
+            "▲"
+        };
+        let header = |key: NodeSortKey| {
+            if self.node_sort == key {
+                format!("{} {}", key.as_str(), arrow)
+            } else {
+                key.as_str().to_string()
+            }
+        };
+
+        let mut title = format!(
+            "Node Details - sorted by {} {}",
+            self.node_sort.as_str(),
+            arrow
+        );
+        if self.node_filter_editing {
+            title.push_str(&format!(" | filter: {}_", self.node_filter));
+        } else if !self.node_filter.is_empty() {
+            title.push_str(&format!(" | filter: {}", self.node_filter));
+        }
+
         let table = Table::new(rows, widths)
             .header(
                 Row::new(vec![
-                    "Name",
-                    "PID",
-                    "CPU",
-                    "Memory",
-                    "Status",
-                    "Publishes",
-                    "Subscribes",
+                    header(NodeSortKey::Name),
+                    "PID".to_string(),
+                    header(NodeSortKey::Cpu),
+                    header(NodeSortKey::Memory),
+                    "Status".to_string(),
+                    header(NodeSortKey::TickP95),
+                    "Worst".to_string(),
+                    header(NodeSortKey::DeadlineMisses),
+                    "Jitter p99".to_string(),
+                    "Publishes".to_string(),
+                    "Subscribes".to_string(),
                 ])
                 .style(Style::default().add_modifier(Modifier::BOLD)),
             )
-            .block(
-                Block::default()
-                    .title("Node Details - Use  to select, Enter to view logs")
-                    .borders(Borders::ALL),
-            )
+            .block(Block::default().title(title).borders(Borders::ALL))
             .row_highlight_style(
                 Style::default()
                     .bg(Color::DarkGray)
@@ -2521,9 +2658,9 @@ impl TuiDashboard {
 
         // Create table state with current selection
         let mut table_state = TableState::default();
-        if !self.nodes.is_empty() {
+        if !nodes.is_empty() {
             // Clamp selected_index to valid range
-            let selected = self.selected_index.min(self.nodes.len() - 1);
+            let selected = self.selected_index.min(nodes.len() - 1);
             table_state.select(Some(selected));
         }
 
@@ -2565,6 +2702,14 @@ impl TuiDashboard {
             Line::from("  ESC        - Close log panel"),
             Line::from("  Shift+↑↓   - Switch between nodes/topics while log panel is open"),
             Line::from(""),
+            Line::from(vec![Span::styled(
+                "Nodes Tab:",
+                Style::default().fg(Color::Cyan),
+            )]),
+            Line::from("  s          - Cycle sort column (Name, CPU, RSS, Tick p95, Misses)"),
+            Line::from("  r          - Reverse sort order"),
+            Line::from("  /          - Filter nodes by name (Enter to apply, ESC to clear)"),
+            Line::from(""),
             Line::from(vec![Span::styled(
                 "Packages Tab:",
                 Style::default().fg(Color::Cyan),
@@ -2753,6 +2898,8 @@ impl TuiDashboard {
     fn draw_footer(&self, f: &mut Frame, area: Rect) {
         let footer_text = if self.show_help {
             "Press any key to close help"
+        } else if self.node_filter_editing {
+            "Type to filter nodes | [ENTER] Apply | [ESC] Clear"
         } else if self.show_log_panel {
             "[ESC] Close | [] Scroll Logs | [Shift+] Switch Node/Topic | [Q] Quit"
         } else if self.active_tab == Tab::Parameters && self.param_edit_mode == ParamEditMode::None
@@ -2768,7 +2915,9 @@ impl TuiDashboard {
             && self.package_view_mode == PackageViewMode::WorkspaceDetails
         {
             "[ESC] Back to Workspaces | [↑↓] Navigate | [TAB] Switch Tab | [?] Help | [Q] Quit"
-        } else if self.active_tab == Tab::Nodes || self.active_tab == Tab::Topics {
+        } else if self.active_tab == Tab::Nodes {
+            "[ENTER] View Logs | [S] Sort | [R] Reverse | [/] Filter | [↑↓] Navigate | [TAB] Switch Tab | [?] Help | [Q] Quit"
+        } else if self.active_tab == Tab::Topics {
             "[ENTER] View Logs | [↑↓] Navigate | [TAB] Switch Tab | [P] Pause | [?] Help | [Q] Quit"
         } else {
             "[TAB] Switch Tab | [↑↓] Navigate | [P] Pause | [?] Help | [Q] Quit"
@@ -2830,7 +2979,7 @@ impl TuiDashboard {
                 OverviewPanelFocus::Nodes => self.nodes.len().saturating_sub(1),
                 OverviewPanelFocus::Topics => self.topics.len().saturating_sub(1),
             },
-            Tab::Nodes => self.nodes.len().saturating_sub(1),
+            Tab::Nodes => self.visible_nodes().len().saturating_sub(1),
             Tab::Topics => self.topics.len().saturating_sub(1),
             Tab::Parameters => {
                 let params_map = self.params.get_all();
@@ -2875,11 +3024,10 @@ impl TuiDashboard {
         match self.active_tab {
             Tab::Nodes => {
                 // Open panel for selected node
-                if self.selected_index < self.nodes.len() {
-                    let node = &self.nodes[self.selected_index];
+                if let Some(name) = self.selected_node_name() {
                     // Don't open panel for placeholder entries
-                    if !node.name.contains("No HORUS nodes") {
-                        self.panel_target = Some(LogPanelTarget::Node(node.name.clone()));
+                    if !name.contains("No HORUS nodes") {
+                        self.panel_target = Some(LogPanelTarget::Node(name));
                         self.show_log_panel = true;
                         self.panel_scroll_offset = 0;
                     }
@@ -2908,11 +3056,10 @@ impl TuiDashboard {
         // This is called when using Shift+Up/Down to navigate while panel is open
         match self.active_tab {
             Tab::Nodes => {
-                if self.selected_index < self.nodes.len() {
-                    let node = &self.nodes[self.selected_index];
+                if let Some(name) = self.selected_node_name() {
                     // Don't update for placeholder entries
-                    if !node.name.contains("No HORUS nodes") {
-                        self.panel_target = Some(LogPanelTarget::Node(node.name.clone()));
+                    if !name.contains("No HORUS nodes") {
+                        self.panel_target = Some(LogPanelTarget::Node(name));
                         self.panel_scroll_offset = 0; // Reset scroll when switching
                     }
                 }
@@ -2931,6 +3078,46 @@ impl TuiDashboard {
         }
     }
 
+    /// Nodes shown in the Nodes tab, filtered by name and sorted by the selected column
+    fn visible_nodes(&self) -> Vec<&NodeStatus> {
+        let filter = self.node_filter.to_lowercase();
+        let mut nodes: Vec<&NodeStatus> = self
+            .nodes
+            .iter()
+            .filter(|n| filter.is_empty() || n.name.to_lowercase().contains(&filter))
+            .collect();
+
+        nodes.sort_by(|a, b| {
+            let ordering = match self.node_sort {
+                NodeSortKey::Name => a.name.cmp(&b.name),
+                NodeSortKey::Cpu => a.cpu_usage.total_cmp(&b.cpu_usage),
+                NodeSortKey::Memory => a.memory_usage.cmp(&b.memory_usage),
+                NodeSortKey::TickP95 => a.tick_p95_us.total_cmp(&b.tick_p95_us),
+                NodeSortKey::DeadlineMisses => a.deadline_misses.cmp(&b.deadline_misses),
+            };
+            if self.node_sort_descending {
+                ordering.reverse()
+            } else {
+                ordering
+            }
+        });
+        nodes
+    }
+
+    /// Name of the selected node in the Nodes tab
+    fn selected_node_name(&self) -> Option<String> {
+        self.visible_nodes()
+            .get(self.selected_index)
+            .map(|node| node.name.clone())
+    }
+
+    /// Sort by the next column; numeric columns start with the highest value
+    fn cycle_node_sort(&mut self) {
+        self.node_sort = self.node_sort.next();
+        self.node_sort_descending = self.node_sort != NodeSortKey::Name;
+        self.selected_index = 0;
+    }
+
     /// Get the count of active nodes, excluding placeholder entries
     fn get_active_node_count(&self) -> usize {
         if self.nodes.len() == 1 && self.nodes[0].name.contains("No HORUS nodes") {
@@ -3386,6 +3573,10 @@ fn get_active_nodes() -> Result<Vec<NodeStatus>> {
             priority: 0,
             publishers: Vec::new(),
             subscribers: Vec::new(),
+            deadline_misses: 0,
+            jitter_p99_us: 0.0,
+            tick_p95_us: 0.0,
+            worst_tick_us: 0.0,
         }])
     } else {
         Ok(discovered_nodes
@@ -3403,6 +3594,10 @@ fn get_active_nodes() -> Result<Vec<NodeStatus>> {
                 priority: n.priority,
                 publishers: n.publishers.iter().map(|p| p.topic.clone()).collect(),
                 subscribers: n.subscribers.iter().map(|s| s.topic.clone()).collect(),
+                deadline_misses: n.timing.deadline_misses,
+                jitter_p99_us: n.timing.jitter_p99_us,
+                tick_p95_us: n.timing.tick_p95_us,
+                worst_tick_us: n.timing.worst_tick_us,
             })
             .collect())
     }
@@ -3912,6 +4107,10 @@ mod tests {
                 memory_usage: 1024,
                 publishers: vec![],
                 subscribers: vec![],
+                deadline_misses: 0,
+                jitter_p99_us: 0.0,
+                tick_p95_us: 0.0,
+                worst_tick_us: 0.0,
             },
             NodeStatus {
                 name: "node2".to_string(),
@@ -3922,6 +4121,10 @@ mod tests {
                 memory_usage: 2048,
                 publishers: vec![],
                 subscribers: vec![],
+                deadline_misses: 0,
+                jitter_p99_us: 0.0,
+                tick_p95_us: 0.0,
+                worst_tick_us: 0.0,
             },
         ];
 
@@ -3944,6 +4147,10 @@ mod tests {
                 memory_usage: 1024,
                 publishers: vec![],
                 subscribers: vec![],
+                deadline_misses: 0,
+                jitter_p99_us: 0.0,
+                tick_p95_us: 0.0,
+                worst_tick_us: 0.0,
             },
             NodeStatus {
                 name: "node2".to_string(),
@@ -3954,6 +4161,10 @@ mod tests {
                 memory_usage: 2048,
                 publishers: vec![],
                 subscribers: vec![],
+                deadline_misses: 0,
+                jitter_p99_us: 0.0,
+                tick_p95_us: 0.0,
+                worst_tick_us: 0.0,
             },
             NodeStatus {
                 name: "node3".to_string(),
@@ -3964,6 +4175,10 @@ mod tests {
                 memory_usage: 3072,
                 publishers: vec![],
                 subscribers: vec![],
+                deadline_misses: 0,
+                jitter_p99_us: 0.0,
+                tick_p95_us: 0.0,
+                worst_tick_us: 0.0,
             },
         ];
 
@@ -3973,6 +4188,72 @@ mod tests {
         assert_eq!(dashboard.selected_index, 0);
     }
 
+    #[test]
+    fn test_node_sort_and_filter() {
+        let node = |name: &str, cpu_usage: f32, tick_p95_us: f64| NodeStatus {
+            name: name.to_string(),
+            status: "active".to_string(),
+            priority: 0,
+            process_id: 0,
+            cpu_usage,
+            memory_usage: 0,
+            publishers: vec![],
+            subscribers: vec![],
+            deadline_misses: 0,
+            jitter_p99_us: 0.0,
+            tick_p95_us,
+            worst_tick_us: 0.0,
+        };
+        let names = |dashboard: &TuiDashboard| {
+            dashboard
+                .visible_nodes()
+                .iter()
+                .map(|n| n.name.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let mut dashboard = TuiDashboard::new();
+        dashboard.active_tab = Tab::Nodes;
+        dashboard.nodes = vec![
+            node("planner", 5.0, 900.0),
+            node("camera_front", 40.0, 300.0),
+            node("camera_rear", 20.0, 1200.0),
+        ];
+        assert_eq!(
+            names(&dashboard),
+            ["camera_front", "camera_rear", "planner"]
+        );
+
+        // Numeric columns sort highest first
+        dashboard.cycle_node_sort();
+        assert_eq!(dashboard.node_sort, NodeSortKey::Cpu);
+        assert_eq!(
+            names(&dashboard),
+            ["camera_front", "camera_rear", "planner"]
+        );
+        dashboard.node_sort = NodeSortKey::TickP95;
+        assert_eq!(
+            names(&dashboard),
+            ["camera_rear", "planner", "camera_front"]
+        );
+        dashboard.node_sort_descending = false;
+        assert_eq!(
+            names(&dashboard),
+            ["camera_front", "planner", "camera_rear"]
+        );
+
+        // Filter is a case-insensitive substring match and drives selection
+        dashboard.node_filter = "CAMERA".to_string();
+        assert_eq!(names(&dashboard), ["camera_front", "camera_rear"]);
+        dashboard.select_next();
+        dashboard.select_next();
+        assert_eq!(dashboard.selected_index, 1);
+        assert_eq!(
+            dashboard.selected_node_name().as_deref(),
+            Some("camera_rear")
+        );
+    }
+
     // ========================================================================
     // Pause Toggle Tests
     // ========================================================================
@@ -4121,6 +4402,10 @@ mod tests {
             memory_usage: 1024 * 1024,
             publishers: vec!["topic1".to_string(), "topic2".to_string()],
             subscribers: vec!["topic3".to_string()],
+            deadline_misses: 0,
+            jitter_p99_us: 0.0,
+            tick_p95_us: 0.0,
+            worst_tick_us: 0.0,
         };
 
         assert_eq!(node.name, "test_node");
@@ -258,6 +258,22 @@ impl HorusMultiProgress {
         let pb = progress_bar(total, message);
         self.mp.add(pb)
     }
+
+    /// Add a single-line byte download bar (length can be set once known)
+    pub fn add_download_bar(&self, message: &str) -> ProgressBar {
+        if self.quiet {
+            return ProgressBar::hidden();
+        }
+        let pb = ProgressBar::new(0);
+        pb.set_style(
+            ProgressStyle::default_bar()
+                .template("{msg:30} [{bar:30.green/black}] {bytes}/{total_bytes} ({bytes_per_sec})")
+                .unwrap()
+                .progress_chars("█░-"),
+        );
+        pb.set_message(message.to_string());
+        self.mp.add(pb)
+    }
 }
 
 /// Helper to create a spinner that respects quiet mode
@@ -0,0 +1,202 @@
+//! Per-workspace Python virtualenv (`.horus/venv`)
+//!
+//! `horus run` creates the virtualenv the first time a Python project runs,
+//! installs the pip dependencies of horus.yaml into it and executes nodes with
+//! its interpreter. The venv sees the system site-packages, so a system-wide
+//! `horus` Python package stays importable, but anything horus.yaml asks for
+//! is installed into the venv.
+//!
+//! Installs are incremental: requirements already installed by an earlier
+//! run are recorded in the venv and not passed to pip again. The venv is
+//! recreated when its interpreter no longer starts (e.g. after a system
+//! Python upgrade).
+
+use anyhow::{bail, Context, Result};
+use colored::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Virtualenv location, relative to the workspace
+pub const VENV_DIR: &str = ".horus/venv";
+
+/// Requirements installed by horus, inside the venv
+const STATE_FILE: &str = "horus-requirements.json";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct VenvState {
+    #[serde(default)]
+    requirements: BTreeSet<String>,
+}
+
+/// The virtualenv of a workspace
+#[derive(Debug, Clone)]
+pub struct PythonEnv {
+    dir: PathBuf,
+}
+
+impl PythonEnv {
+    /// Virtualenv of the workspace containing the current directory
+    pub fn current() -> Result<Self> {
+        let root = match crate::workspace::find_workspace_root() {
+            Some(root) => root,
+            None => std::env::current_dir()?,
+        };
+        Ok(Self::at(&root))
+    }
+
+    /// Virtualenv of the workspace at `root` (may not exist yet)
+    pub fn at(root: &Path) -> Self {
+        Self {
+            dir: root.join(VENV_DIR),
+        }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Interpreter of the venv
+    pub fn python(&self) -> PathBuf {
+        if cfg!(windows) {
+            self.dir.join("Scripts").join("python.exe")
+        } else {
+            self.dir.join("bin").join("python")
+        }
+    }
+
+    /// Whether the venv exists and its interpreter starts
+    pub fn is_usable(&self) -> bool {
+        Command::new(self.python())
+            .arg("--version")
+            .output()
+            .is_ok_and(|o| o.status.success())
+    }
+
+    /// Create the venv if it is missing or broken
+    pub fn ensure(&self) -> Result<&Self> {
+        if self.is_usable() {
+            return Ok(self);
+        }
+        if self.dir.exists() {
+            eprintln!(
+                "  {} {} is broken, recreating it",
+                "[!]".yellow(),
+                self.dir.display()
+            );
+            fs::remove_dir_all(&self.dir)
+                .with_context(|| format!("Failed to remove {}", self.dir.display()))?;
+        }
+        if let Some(parent) = self.dir.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        eprintln!(
+            "  {} Creating Python virtualenv {}",
+            "".cyan(),
+            self.dir.display()
+        );
+        let system_python = system_python()?;
+        let output = Command::new(&system_python)
+            .args(["-m", "venv", "--system-site-packages"])
+            .arg(&self.dir)
+            .output()
+            .with_context(|| format!("Failed to run {} -m venv", system_python))?;
+        if !output.status.success() {
+            bail!(
+                "Failed to create virtualenv {}: {}\n  Hint: install the venv module (e.g. 'sudo apt install python3-venv')",
+                self.dir.display(),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+        Ok(self)
+    }
+
+    /// Install requirements (`numpy>=1.24`) not installed by an earlier run
+    ///
+    /// Returns the requirements passed to pip.
+    pub fn install(&self, requirements: &[String]) -> Result<Vec<String>> {
+        let mut state = self.load_state();
+        let missing: Vec<String> = requirements
+            .iter()
+            .filter(|r| !state.requirements.contains(*r))
+            .cloned()
+            .collect();
+        if missing.is_empty() {
+            return Ok(missing);
+        }
+
+        let output = Command::new(self.python())
+            .args(["-m", "pip", "install", "--disable-pip-version-check"])
+            .args(&missing)
+            .output()
+            .context("Failed to run pip install")?;
+        if !output.status.success() {
+            bail!(
+                "pip install failed for {}: {}",
+                missing.join(" "),
+                String::from_utf8_lossy(&output.stderr).trim()
+            );
+        }
+
+        state.requirements.extend(missing.iter().cloned());
+        fs::write(
+            self.dir.join(STATE_FILE),
+            serde_json::to_string_pretty(&state)?,
+        )?;
+        Ok(missing)
+    }
+
+    /// Whether a distribution is installed in the venv (or the system site-packages)
+    pub fn has_package(&self, name: &str) -> bool {
+        Command::new(self.python())
+            .args(["-m", "pip", "show", "--disable-pip-version-check", name])
+            .output()
+            .is_ok_and(|o| o.status.success())
+    }
+
+    fn load_state(&self) -> VenvState {
+        fs::read_to_string(self.dir.join(STATE_FILE))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// System interpreter used to create venvs
+pub fn system_python() -> Result<String> {
+    for cmd in ["python3", "python"] {
+        if Command::new(cmd).arg("--version").output().is_ok() {
+            return Ok(cmd.to_string());
+        }
+    }
+    bail!("No Python interpreter found. Install Python 3.7+ and ensure it's in PATH.");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_paths_and_state() {
+        let root = tempfile::tempdir().unwrap();
+        let env = PythonEnv::at(root.path());
+        assert_eq!(env.dir(), root.path().join(".horus/venv"));
+        assert!(env.python().starts_with(env.dir()));
+        assert!(!env.is_usable());
+
+        // Recorded requirements are not installed again
+        fs::create_dir_all(env.dir()).unwrap();
+        fs::write(
+            env.dir().join(STATE_FILE),
+            r#"{"requirements": ["numpy>=1.24"]}"#,
+        )
+        .unwrap();
+        assert!(env
+            .install(&["numpy>=1.24".to_string()])
+            .unwrap()
+            .is_empty());
+    }
+}
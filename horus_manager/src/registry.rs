@@ -2,7 +2,9 @@
 // Keeps complexity low - just HTTP calls to registry
 
 use crate::dependency_resolver::{DependencySpec, PackageProvider};
+use crate::fetch::{self, DownloadRequest, RetryPolicy};
 use crate::progress::{self, finish_error, finish_success};
+use crate::security::signing::{self, PackageSignature, TrustStore, Verification};
 use anyhow::{anyhow, bail, Result};
 use chrono::{DateTime, Utc};
 use colored::*;
@@ -30,6 +32,9 @@ pub struct PackageMetadata {
     pub name: String,
     pub version: String,
     pub checksum: Option<String>,
+    /// Trusted key the archive was signed with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signed_by: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -140,6 +145,8 @@ pub fn package_name_to_path(name: &str) -> String {
 pub struct RegistryClient {
     client: Client,
     base_url: String,
+    /// Install unsigned and untrusted packages even when signing is required
+    allow_unsigned: bool,
 }
 
 impl Default for RegistryClient {
@@ -154,11 +161,21 @@ impl RegistryClient {
             .unwrap_or_else(|_| "https://horus-marketplace-api.onrender.com".to_string());
 
         Self {
-            client: Client::new(),
+            client: fetch::blocking_client(),
             base_url,
+            allow_unsigned: false,
         }
     }
 
+    /// Install unsigned packages and packages from untrusted signers
+    ///
+    /// By default they are refused once the trust store holds a key (see
+    /// [`signing::require_signed`]). Tampered archives are always refused.
+    pub fn allow_unsigned(mut self, allow: bool) -> Self {
+        self.allow_unsigned = allow;
+        self
+    }
+
     /// Get a reference to the HTTP client
     pub fn http_client(&self) -> &Client {
         &self.client
@@ -194,6 +211,110 @@ impl RegistryClient {
             .ok_or_else(|| anyhow!("No driver metadata for '{}'", package_name))
     }
 
+    /// Fetch the publisher signature of a package archive, None if unsigned
+    pub fn fetch_signature(
+        &self,
+        package_name: &str,
+        version: Option<&str>,
+    ) -> Result<Option<PackageSignature>> {
+        let url = format!(
+            "{}/api/packages/{}/{}/signature",
+            self.base_url,
+            url_encode_package_name(package_name),
+            version.unwrap_or("latest")
+        );
+        let response = fetch::get_with_retry(&self.client, &url)
+            .map_err(|e| anyhow!("Failed to fetch package signature: {}", e))?;
+        if response.status() == reqwest::StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        if !response.status().is_success() {
+            bail!(
+                "Failed to fetch signature of {}: {}",
+                package_name,
+                response.status()
+            );
+        }
+        response
+            .json()
+            .map(Some)
+            .map_err(|e| anyhow!("Invalid signature of {}: {}", package_name, e))
+    }
+
+    /// Check a downloaded archive against its publisher signature
+    ///
+    /// Returns the trusted signer. Tampered archives and invalid signatures
+    /// are errors. Unsigned and untrusted packages are refused whenever the
+    /// trust store holds a key or `HORUS_REQUIRE_SIGNED` is set, unless the
+    /// client allows unsigned packages.
+    fn verify_download(
+        &self,
+        package_name: &str,
+        version: &str,
+        checksum: &str,
+    ) -> Result<Option<String>> {
+        let signature = self.fetch_signature(package_name, Some(version))?;
+        let trust = TrustStore::open_default()?;
+        let verification =
+            signing::verify_package(signature.as_ref(), package_name, version, checksum, &trust)?;
+        let require = !self.allow_unsigned && signing::require_signed(&trust)?;
+        match verification {
+            Verification::Trusted { signer } => Ok(Some(signer)),
+            Verification::Untrusted { public_key } => {
+                if require {
+                    bail!(
+                        "{} is signed by an untrusted key {}. Trust it with 'horus pkg trust add <NAME> {}' or install with --allow-unsigned",
+                        package_name,
+                        public_key,
+                        public_key
+                    );
+                }
+                println!(
+                    "  {} {} is signed by an untrusted key {}",
+                    "[WARN]".yellow(),
+                    package_name,
+                    public_key
+                );
+                Ok(None)
+            }
+            Verification::Unsigned => {
+                if require {
+                    bail!(
+                        "{} is not signed by its publisher. Install with --allow-unsigned to accept it",
+                        package_name
+                    );
+                }
+                println!(
+                    "  {} {} is not signed by its publisher",
+                    "[WARN]".yellow(),
+                    package_name
+                );
+                Ok(None)
+            }
+        }
+    }
+
+    /// Current version of a package according to the registry metadata
+    fn fetch_latest_version(&self, package_name: &str) -> Result<String> {
+        let url = format!(
+            "{}/api/packages/{}",
+            self.base_url,
+            url_encode_package_name(package_name)
+        );
+        let response = fetch::get_with_retry(&self.client, &url)
+            .map_err(|e| anyhow!("Failed to fetch package info: {}", e))?;
+        if !response.status().is_success() {
+            bail!("Package '{}' not found in registry", package_name);
+        }
+        let info: serde_json::Value = response
+            .json()
+            .map_err(|e| anyhow!("Failed to parse package info: {}", e))?;
+        info.get("version")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("Registry did not report a version for '{}'", package_name))
+    }
+
     /// Fetch driver metadata, returning Option (for backwards compatibility)
     pub fn fetch_driver_metadata_opt(&self, package_name: &str) -> Option<DriverMetadata> {
         self.fetch_driver_metadata(package_name).ok()
@@ -238,7 +359,7 @@ impl RegistryClient {
 
         // Try searching drivers by name
         let url = format!("{}/api/drivers?search={}", self.base_url, driver_name);
-        if let Ok(response) = self.client.get(&url).send() {
+        if let Ok(response) = fetch::get_with_retry(&self.client, &url) {
             if response.status().is_success() {
                 if let Ok(list) = response.json::<DriverListResponse>() {
                     // Find exact or best match
@@ -349,7 +470,9 @@ impl RegistryClient {
         let source = self.detect_package_source(package_name)?;
 
         match source {
-            PackageSource::Registry => self.install_from_registry(package_name, version, target),
+            PackageSource::Registry => {
+                self.install_from_registry(package_name, version, None, target)
+            }
             PackageSource::PyPI => self.install_from_pypi(package_name, version, target),
             PackageSource::CratesIO => self.install_from_cratesio(package_name, version, target),
             PackageSource::System => Err(anyhow!(
@@ -362,6 +485,29 @@ impl RegistryClient {
         }
     }
 
+    /// Install a package at the version pinned in `horus.lock`
+    ///
+    /// Locked packages come from the HORUS registry. The archive must match
+    /// the locked checksum before anything is unpacked or linked, and an
+    /// entry without a checksum is refused.
+    pub fn install_locked(
+        &self,
+        package_name: &str,
+        locked: &crate::lockfile::LockedDependency,
+        target: crate::workspace::InstallTarget,
+    ) -> Result<String> {
+        let Some(checksum) = locked.checksum.as_deref() else {
+            bail!(
+                "{} v{} has no checksum in {}; run 'horus pkg update {}' to lock it again",
+                package_name,
+                locked.version,
+                crate::lockfile::LOCKFILE_NAME,
+                package_name
+            );
+        };
+        self.install_from_registry(package_name, Some(&locked.version), Some(checksum), target)
+    }
+
     fn detect_package_source(&self, package_name: &str) -> Result<PackageSource> {
         // Scoped packages (@org/name) are always from HORUS registry
         if package_name.starts_with('@') {
@@ -376,7 +522,7 @@ impl RegistryClient {
         // Try HORUS registry first (URL encode for safety)
         let encoded_name = url_encode_package_name(package_name);
         let url = format!("{}/api/packages/{}", self.base_url, encoded_name);
-        if let Ok(response) = self.client.get(&url).send() {
+        if let Ok(response) = fetch::get_with_retry(&self.client, &url) {
             if response.status().is_success() {
                 return Ok(PackageSource::Registry);
             }
@@ -478,7 +624,7 @@ impl RegistryClient {
                 } else {
                     None
                 };
-                self.install_from_registry(&spec.name, version_str.as_deref(), target)
+                self.install_from_registry(&spec.name, version_str.as_deref(), None, target)
                     .map(|_| ()) // Ignore version for dependency spec
             }
             DependencySource::Path(path) => {
@@ -511,42 +657,108 @@ impl RegistryClient {
         }
     }
 
+    /// Download request for a registry package archive
+    fn download_request(&self, package_name: &str, version: Option<&str>) -> DownloadRequest {
+        // URL-encode scoped package names for API calls
+        let encoded_name = url_encode_package_name(package_name);
+        DownloadRequest {
+            name: package_name.to_string(),
+            version: version.map(str::to_string),
+            url: format!(
+                "{}/api/packages/{}/{}/download",
+                self.base_url,
+                encoded_name,
+                version.unwrap_or("latest")
+            ),
+        }
+    }
+
+    /// Download registry packages concurrently ahead of installation.
+    ///
+    /// Installing afterwards picks the archives up from the download cache, so
+    /// only unpacking and linking remain sequential. Failures are reported and
+    /// left for the install step to retry.
+    pub fn prefetch(&self, packages: &[(String, Option<String>)]) {
+        if packages.len() < 2 {
+            return;
+        }
+        let requests = packages
+            .iter()
+            .map(|(name, version)| self.download_request(name, version.as_deref()))
+            .collect();
+        let results =
+            fetch::download_packages(requests, fetch::download_jobs(), RetryPolicy::default());
+        for ((name, _), result) in packages.iter().zip(results) {
+            if let Err(e) = result {
+                println!("  {} Failed to download {}: {}", "[WARN]".yellow(), name, e);
+            }
+        }
+    }
+
     fn install_from_registry(
         &self,
         package_name: &str,
         version: Option<&str>,
+        expected_checksum: Option<&str>,
         target: crate::workspace::InstallTarget,
     ) -> Result<String> {
-        let spinner = progress::robot_download_spinner(&format!(
-            "Downloading {} from HORUS registry...",
-            package_name
-        ));
-
         let version_str = version.unwrap_or("latest");
-        // URL-encode scoped package names for API calls
-        let encoded_name = url_encode_package_name(package_name);
-        let url = format!(
-            "{}/api/packages/{}/{}/download",
-            self.base_url, encoded_name, version_str
-        );
 
-        // Download package
-        let response = self.client.get(&url).send()?;
-
-        if !response.status().is_success() {
-            return Err(anyhow!("Package not found: {}", package_name));
+        // Download package (reuses an archive left by `prefetch`)
+        let downloaded = fetch::download_package(self.download_request(package_name, version))?;
+        let checksum = downloaded.checksum;
+
+        // A locked package must be the exact archive recorded in horus.lock
+        if let Some(expected) = expected_checksum {
+            if !expected.eq_ignore_ascii_case(&checksum) {
+                let _ = fs::remove_file(&downloaded.path);
+                bail!(
+                    "Checksum of {} v{} differs from {} ({} != {}). The registry archive changed; \
+                     run 'horus pkg update {}' if this is expected",
+                    package_name,
+                    version_str,
+                    crate::lockfile::LOCKFILE_NAME,
+                    checksum,
+                    expected,
+                    package_name
+                );
+            }
         }
-
-        let bytes = response.bytes()?;
-
-        // Calculate checksum
-        let mut hasher = Sha256::new();
-        hasher.update(&bytes);
-        let checksum = format!("{:x}", hasher.finalize());
+        let bytes = fs::read(&downloaded.path)?;
 
         // Convert scoped package name to safe path (e.g., @org/pkg -> org--pkg)
         let safe_pkg_name = package_name_to_path(package_name);
 
+        // The version comes from the registry, not from the archive being
+        // verified, and the signature must be for that version, so an older
+        // signed release can't be passed off as the latest
+        let actual_version = if version_str == "latest" {
+            self.fetch_latest_version(package_name)?
+        } else {
+            version_str.to_string()
+        };
+
+        // Verify the archive bytes before anything is unpacked
+        let signed_by = match self.verify_download(package_name, &actual_version, &checksum) {
+            Ok(signed_by) => signed_by,
+            Err(e) => {
+                // Don't reuse a rejected archive
+                let _ = fs::remove_file(&downloaded.path);
+                return Err(e);
+            }
+        };
+
+        let tar = GzDecoder::new(&bytes[..]);
+        let mut archive = Archive::new(tar);
+        let temp_dir = std::env::temp_dir().join(format!("horus_pkg_{}", safe_pkg_name));
+        fs::create_dir_all(&temp_dir)?;
+        archive.unpack(&temp_dir)?;
+
+        let spinner = progress::robot_download_spinner(&format!(
+            "Installing {} from HORUS registry...",
+            package_name
+        ));
+
         // Determine installation directory based on target
         use crate::workspace::InstallTarget;
         let home = dirs::home_dir().ok_or_else(|| anyhow!("Could not find home directory"))?;
@@ -578,22 +790,6 @@ impl RegistryClient {
             }
         };
 
-        // Create package directory with version
-        let tar = GzDecoder::new(&bytes[..]);
-        let mut archive = Archive::new(tar);
-
-        // Extract to temporary location first to detect version (use safe name)
-        let temp_dir = std::env::temp_dir().join(format!("horus_pkg_{}", safe_pkg_name));
-        fs::create_dir_all(&temp_dir)?;
-        archive.unpack(&temp_dir)?;
-
-        // Get actual version from package info (for "latest" downloads)
-        let actual_version = if version_str == "latest" {
-            detect_package_version(&temp_dir).unwrap_or_else(|| version_str.to_string())
-        } else {
-            version_str.to_string()
-        };
-
         // Move to final location with version info (use safe path name)
         let package_dir = if install_type == "global" {
             install_dir.join(format!("{}@{}", safe_pkg_name, actual_version))
@@ -610,12 +806,14 @@ impl RegistryClient {
         // Move from temp to final location
         copy_dir_all(&temp_dir, &package_dir)?;
         fs::remove_dir_all(&temp_dir)?;
+        let _ = fs::remove_file(&downloaded.path);
 
         // Create metadata.json for tracking
         let metadata = PackageMetadata {
             name: package_name.to_string(),
             version: actual_version.clone(),
             checksum: Some(checksum),
+            signed_by,
         };
 
         let metadata_path = package_dir.join("metadata.json");
@@ -854,21 +1052,9 @@ impl RegistryClient {
             }
         };
 
-        // Create temp venv for pip operations
-        let temp_venv = PathBuf::from(".horus/venv");
-        if !temp_venv.exists() {
-            fs::create_dir_all(&temp_venv)?;
-            let python_cmd = if Command::new("python3").arg("--version").output().is_ok() {
-                "python3"
-            } else {
-                "python"
-            };
-            Command::new(python_cmd)
-                .args(["-m", "venv", temp_venv.to_str().unwrap()])
-                .status()?;
-        }
-
-        let pip_path = temp_venv.join("bin/pip");
+        // pip of the workspace venv
+        let venv = crate::python_env::PythonEnv::at(Path::new("."));
+        venv.ensure()?;
 
         // Build version string
         let version_str = version.unwrap_or("latest");
@@ -891,8 +1077,14 @@ impl RegistryClient {
         fs::create_dir_all(&pkg_dir)?;
 
         spinner.set_message(format!("Installing {} with pip...", package_name));
-        let output = Command::new(&pip_path)
-            .args(["install", "--target", pkg_dir.to_str().unwrap()])
+        let output = Command::new(venv.python())
+            .args([
+                "-m",
+                "pip",
+                "install",
+                "--target",
+                pkg_dir.to_str().unwrap(),
+            ])
             .arg(&requirement)
             .output()?;
 
@@ -1155,8 +1347,18 @@ impl RegistryClient {
 
         println!("  {} Resolving dependency versions...", "".cyan());
 
-        // Create resolver with this registry client as provider
-        let mut resolver = DependencyResolver::new(self);
+        // Create resolver with this registry client as provider, keeping
+        // versions locked by the project
+        let project_dir = match target {
+            crate::workspace::InstallTarget::Local(workspace_path) => workspace_path.clone(),
+            crate::workspace::InstallTarget::Global => PathBuf::from("."),
+        };
+        let locked = crate::lockfile::Lockfile::load(&project_dir)
+            .ok()
+            .flatten()
+            .map(|lockfile| lockfile.versions())
+            .unwrap_or_default();
+        let mut resolver = DependencyResolver::new(self).with_locked(locked);
 
         // Resolve all dependencies with version constraints
         let resolved: Vec<ResolvedDependency> = match resolver.resolve(dependencies.to_vec()) {
@@ -1196,6 +1398,23 @@ impl RegistryClient {
             }
         };
 
+        // Download missing packages concurrently before installing them in order
+        let home = dirs::home_dir().ok_or_else(|| anyhow!("Could not find home directory"))?;
+        let global_cache = home.join(".horus/cache");
+        let missing: Vec<(String, Option<String>)> = resolved
+            .iter()
+            .filter(|dep| !check_global_versions(&global_cache, &dep.name).unwrap_or(false))
+            .filter(|dep| match target {
+                crate::workspace::InstallTarget::Global => true,
+                crate::workspace::InstallTarget::Local(workspace_path) => !workspace_path
+                    .join(".horus/packages")
+                    .join(&dep.name)
+                    .exists(),
+            })
+            .map(|dep| (dep.name.clone(), Some(dep.version.to_string())))
+            .collect();
+        self.prefetch(&missing);
+
         // Install resolved versions
         for resolved_dep in resolved {
             let version_str = resolved_dep.version.to_string();
@@ -1276,6 +1495,80 @@ impl RegistryClient {
         Ok(())
     }
 
+    /// Re-resolve the registry dependencies of a project and rewrite `horus.lock`
+    ///
+    /// With `package`, only that package moves to its newest compatible
+    /// version; the others keep their locked versions where possible.
+    pub fn update_lockfile(&self, project_dir: &Path, package: Option<&str>) -> Result<()> {
+        use crate::dependency_resolver::{DependencyResolver, DependencySource};
+        use crate::lockfile::{Lockfile, LOCKFILE_NAME};
+
+        let yaml_path = project_dir.join("horus.yaml");
+        if !yaml_path.exists() {
+            bail!("No horus.yaml in {}", project_dir.display());
+        }
+        let specs: Vec<DependencySpec> =
+            crate::commands::run::parse_horus_yaml_dependencies_v2(&yaml_path.to_string_lossy())?
+                .into_iter()
+                .filter(|spec| spec.source == DependencySource::Registry)
+                .filter(|spec| !spec.name.contains(':')) // pip:, cargo:
+                .collect();
+
+        let previous = Lockfile::load(project_dir)?.unwrap_or_default();
+        let mut pinned = previous.clone();
+        match package {
+            Some(package) => {
+                if !pinned.remove(package) {
+                    bail!("{} is not in {}", package, LOCKFILE_NAME);
+                }
+            }
+            None => pinned = Lockfile::default(),
+        }
+
+        let resolved = DependencyResolver::new(self)
+            .with_locked(pinned.versions())
+            .resolve(specs)?;
+
+        let packages_dir = project_dir.join(".horus/packages");
+        let target = crate::workspace::InstallTarget::Local(project_dir.to_path_buf());
+        for dep in &resolved {
+            let version = dep.version.to_string();
+            let installed = crate::lockfile::installed_metadata(&packages_dir, &dep.name);
+            if installed.map(|m| m.version) != Some(version.clone()) {
+                // Packages kept at their locked version must match the lockfile
+                match pinned
+                    .get(&dep.name)
+                    .filter(|locked| locked.version == version)
+                {
+                    Some(locked) => self.install_locked(&dep.name, locked, target.clone())?,
+                    None => {
+                        self.install_from_registry(&dep.name, Some(&version), None, target.clone())?
+                    }
+                };
+            }
+            match previous.get(&dep.name) {
+                Some(old) if old.version != version => println!(
+                    "  {} Updated {} v{} -> v{}",
+                    "".green(),
+                    dep.name,
+                    old.version,
+                    version
+                ),
+                None => println!("  {} Locked {} v{}", "".green(), dep.name, version),
+                _ => {}
+            }
+        }
+
+        Lockfile::from_resolved(&resolved, &packages_dir).save(project_dir)?;
+        println!(
+            "{} Wrote {} ({} packages)",
+            "".green(),
+            LOCKFILE_NAME,
+            resolved.len()
+        );
+        Ok(())
+    }
+
     // Publish a package to registry
     pub fn publish(&self, path: Option<&Path>) -> Result<()> {
         let current_dir = path.unwrap_or_else(|| Path::new("."));
@@ -1358,6 +1651,19 @@ impl RegistryClient {
         let package_data = fs::read(&tar_path)?;
         fs::remove_file(&tar_path)?; // Clean up temp file
 
+        // Sign the archive so installs can detect tampering
+        let checksum = format!("{:x}", Sha256::digest(&package_data));
+        let key_path = signing::signing_key_path()?;
+        let (signing_key, created) = signing::load_or_create_signing_key(&key_path)?;
+        if created {
+            println!(
+                "  {} Created package signing key {}",
+                "".cyan(),
+                key_path.display()
+            );
+        }
+        let signature = PackageSignature::sign(&signing_key, &name, &version, &checksum);
+
         // Simple multipart form - just like the original
         let form = reqwest::blocking::multipart::Form::new()
             .text("name", name.clone())
@@ -1367,6 +1673,8 @@ impl RegistryClient {
                 "license",
                 license.unwrap_or_else(|| "Apache-2.0".to_string()),
             )
+            .text("checksum", checksum)
+            .text("signature", serde_json::to_string(&signature)?)
             .part(
                 "package",
                 reqwest::blocking::multipart::Part::bytes(package_data)
@@ -1443,6 +1751,10 @@ impl RegistryClient {
         }
 
         println!(" Published {} v{} successfully!", name, version);
+        println!(
+            "   Signed with key {} (users trust it with 'horus pkg trust add <NAME> {}')",
+            signature.public_key, signature.public_key
+        );
 
         // Show verification status if available
         if let Some(verification) = response_json.get("verification") {
@@ -1614,7 +1926,7 @@ impl RegistryClient {
     pub fn search(&self, query: &str) -> Result<Vec<Package>> {
         let url = format!("{}/api/packages/search?q={}", self.base_url, query);
 
-        let response = self.client.get(&url).send()?;
+        let response = fetch::get_with_retry(&self.client, &url)?;
 
         if !response.status().is_success() {
             return Err(anyhow!("Search failed"));
@@ -1661,7 +1973,7 @@ impl RegistryClient {
             self.base_url, import_name, language
         );
 
-        let response = self.client.get(&url).send()?;
+        let response = fetch::get_with_retry(&self.client, &url)?;
 
         if !response.status().is_success() {
             return Ok(None);
@@ -1860,7 +2172,7 @@ impl RegistryClient {
 
         // Fetch environment manifest from registry
         let url = format!("{}/api/environments/{}", self.base_url, horus_id);
-        let response = self.client.get(&url).send()?;
+        let response = fetch::get_with_retry(&self.client, &url)?;
 
         if !response.status().is_success() {
             return Err(anyhow!("Environment not found: {}", horus_id));
@@ -1868,6 +2180,14 @@ impl RegistryClient {
 
         let manifest: EnvironmentManifest = response.json()?;
 
+        let registry_packages: Vec<(String, Option<String>)> = manifest
+            .packages
+            .iter()
+            .filter(|package| package.source == PackageSource::Registry)
+            .map(|package| (package.name.clone(), Some(package.version.clone())))
+            .collect();
+        self.prefetch(&registry_packages);
+
         // Install each package
         for package in &manifest.packages {
             println!("  Installing {} v{}...", package.name, package.version);
@@ -3056,7 +3376,7 @@ impl PackageProvider for RegistryClient {
         // Query registry for available versions
         let url = format!("{}/api/packages/{}/versions", self.base_url, package);
 
-        let response = self.client.get(&url).send();
+        let response = fetch::get_with_retry(&self.client, &url);
 
         match response {
             Ok(resp) if resp.status().is_success() => {
@@ -3146,7 +3466,7 @@ impl PackageProvider for RegistryClient {
             self.base_url, package, version
         );
 
-        let response = self.client.get(&url).send();
+        let response = fetch::get_with_retry(&self.client, &url);
 
         match response {
             Ok(resp) if resp.status().is_success() => {
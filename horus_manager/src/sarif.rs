@@ -0,0 +1,270 @@
+//! SARIF output for `horus check`
+//!
+//! SARIF 2.1.0 is the static analysis exchange format read by GitHub code
+//! scanning and most CI systems, so `horus check --sarif` findings show up as
+//! annotations on the offending lines.
+
+use crate::commands::check::{Diagnostic, Severity};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::Path;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Kind of finding reported by `horus check`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rule {
+    Manifest,
+    Dependency,
+    CargoCheck,
+    RustSyntax,
+    PythonSyntax,
+    PythonImport,
+    TopicUnregistered,
+    TopicTypeMismatch,
+    /// Topic is subscribed but never published
+    TopicNoPublisher,
+    /// Topic is published but never subscribed
+    TopicNoSubscriber,
+    /// Endpoints of a topic disagree on its type
+    TopicTypeConflict,
+    /// Scheduler or horus import missing from a HORUS project
+    ApiUsage,
+    /// Registry, shared memory or disk space
+    Environment,
+    /// Sleep in a node's tick()
+    TickSleep,
+    /// Blocking I/O in a node's tick()
+    TickBlockingIo,
+    /// Loop without exit in a node's tick()
+    TickUnboundedLoop,
+    /// Large allocation in a node's tick()
+    TickLargeAllocation,
+    /// A checker could not run (cargo or python3 missing)
+    Tooling,
+}
+
+impl Rule {
+    const ALL: [Rule; 18] = [
+        Rule::Manifest,
+        Rule::Dependency,
+        Rule::CargoCheck,
+        Rule::RustSyntax,
+        Rule::PythonSyntax,
+        Rule::PythonImport,
+        Rule::TopicUnregistered,
+        Rule::TopicTypeMismatch,
+        Rule::TopicNoPublisher,
+        Rule::TopicNoSubscriber,
+        Rule::TopicTypeConflict,
+        Rule::ApiUsage,
+        Rule::Environment,
+        Rule::TickSleep,
+        Rule::TickBlockingIo,
+        Rule::TickUnboundedLoop,
+        Rule::TickLargeAllocation,
+        Rule::Tooling,
+    ];
+
+    pub fn id(self) -> &'static str {
+        match self {
+            Rule::Manifest => "horus/manifest",
+            Rule::Dependency => "horus/dependency",
+            Rule::RustSyntax => "horus/rust-syntax",
+            Rule::ApiUsage => "horus/api-usage",
+            Rule::Environment => "horus/environment",
+            Rule::CargoCheck => "horus/cargo-check",
+            Rule::PythonSyntax => "horus/python-syntax",
+            Rule::PythonImport => "horus/python-import",
+            Rule::TopicUnregistered => "horus/topic-unregistered",
+            Rule::TopicTypeMismatch => "horus/topic-type-mismatch",
+            Rule::TopicNoPublisher => "horus/topic-no-publisher",
+            Rule::TopicNoSubscriber => "horus/topic-no-subscriber",
+            Rule::TopicTypeConflict => "horus/topic-type-conflict",
+            Rule::TickSleep => "horus/tick-sleep",
+            Rule::TickBlockingIo => "horus/tick-blocking-io",
+            Rule::TickUnboundedLoop => "horus/tick-unbounded-loop",
+            Rule::TickLargeAllocation => "horus/tick-large-allocation",
+            Rule::Tooling => "horus/tooling",
+        }
+    }
+
+    fn description(self) -> &'static str {
+        match self {
+            Rule::Manifest => "horus.yaml manifest is invalid",
+            Rule::Dependency => "Dependency is missing, duplicated or circular",
+            Rule::RustSyntax => "Rust file has a syntax error",
+            Rule::ApiUsage => "HORUS project does not use the HORUS API",
+            Rule::Environment => "Environment is not ready to run HORUS",
+            Rule::CargoCheck => "Rust code does not compile",
+            Rule::PythonSyntax => "Python file has a syntax error",
+            Rule::PythonImport => "Python import cannot be resolved",
+            Rule::TopicUnregistered => "Topic is not declared in the topics! registry",
+            Rule::TopicTypeMismatch => "Topic is used with another type than registered",
+            Rule::TopicNoPublisher => "Topic is subscribed but never published",
+            Rule::TopicNoSubscriber => "Topic is published but never subscribed",
+            Rule::TopicTypeConflict => "Publishers and subscribers of a topic use different types",
+            Rule::TickSleep => "tick() sleeps",
+            Rule::TickBlockingIo => "tick() blocks on I/O",
+            Rule::TickUnboundedLoop => "tick() contains a loop without exit",
+            Rule::TickLargeAllocation => "tick() allocates a large buffer",
+            Rule::Tooling => "A checker could not run",
+        }
+    }
+}
+
+/// Diagnostics of a check, written as a SARIF log
+#[derive(Debug)]
+pub struct SarifReport<'a> {
+    /// Workspace root, file locations are relative to it
+    root: &'a Path,
+    diagnostics: &'a [Diagnostic],
+}
+
+impl<'a> SarifReport<'a> {
+    pub fn new(root: &'a Path, diagnostics: &'a [Diagnostic]) -> Self {
+        Self { root, diagnostics }
+    }
+
+    pub fn to_json(&self) -> Value {
+        let rules: Vec<Value> = Rule::ALL
+            .iter()
+            .map(|rule| {
+                json!({
+                    "id": rule.id(),
+                    "shortDescription": { "text": rule.description() },
+                })
+            })
+            .collect();
+
+        let results: Vec<Value> = self
+            .diagnostics
+            .iter()
+            .map(|diagnostic| {
+                let mut location = json!({
+                    "artifactLocation": { "uri": relative_uri(self.root, &diagnostic.file) },
+                });
+                if let Some(span) = diagnostic.span {
+                    location["region"] = json!({ "startLine": span.line.max(1) });
+                    if let Some(column) = span.column {
+                        location["region"]["startColumn"] = json!(column.max(1));
+                    }
+                }
+                json!({
+                    "ruleId": diagnostic.rule.id(),
+                    "level": match diagnostic.severity {
+                        Severity::Error => "error",
+                        Severity::Warning => "warning",
+                    },
+                    "message": { "text": diagnostic.message },
+                    "locations": [{ "physicalLocation": location }],
+                })
+            })
+            .collect();
+
+        json!({
+            "$schema": SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "horus check",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    }
+                },
+                "results": results,
+            }],
+        })
+    }
+
+    pub fn write(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(&self.to_json())?;
+        fs::write(path, json)
+    }
+}
+
+/// Path relative to the workspace root with `/` separators
+pub(crate) fn relative_uri(root: &Path, file: &Path) -> String {
+    let relative = file.strip_prefix(root).unwrap_or(file);
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::check::{cargo_diagnostic, Span};
+
+    fn diagnostic(
+        rule: Rule,
+        severity: Severity,
+        message: &str,
+        file: &Path,
+        span: Option<Span>,
+    ) -> Diagnostic {
+        Diagnostic {
+            file: file.to_path_buf(),
+            severity,
+            rule,
+            message: message.to_string(),
+            span,
+        }
+    }
+
+    #[test]
+    fn test_sarif_report() {
+        let root = Path::new("/work/robot");
+        let diagnostics = vec![
+            diagnostic(
+                Rule::Manifest,
+                Severity::Error,
+                "missing 'name' field",
+                &root.join("horus.yaml"),
+                None,
+            ),
+            cargo_diagnostic(
+                &root.join("controller"),
+                "src/main.rs:3:5: error[E0425]: cannot find value `x` in this scope",
+            ),
+            diagnostic(
+                Rule::TopicUnregistered,
+                Severity::Warning,
+                "'scan' is not in the topic registry",
+                &root.join("nodes/lidar.py"),
+                Some(Span::line(12)),
+            ),
+        ];
+
+        let sarif = SarifReport::new(root, &diagnostics).to_json();
+        assert_eq!(sarif["version"], "2.1.0");
+        let results = sarif["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+
+        assert_eq!(results[0]["level"], "error");
+        assert!(results[0]["locations"][0]["physicalLocation"]
+            .get("region")
+            .is_none());
+
+        let cargo = &results[1]["locations"][0]["physicalLocation"];
+        assert_eq!(cargo["artifactLocation"]["uri"], "controller/src/main.rs");
+        assert_eq!(cargo["region"]["startLine"], 3);
+        assert_eq!(cargo["region"]["startColumn"], 5);
+        assert_eq!(
+            results[1]["message"]["text"],
+            "error[E0425]: cannot find value `x` in this scope"
+        );
+
+        assert_eq!(results[2]["ruleId"], "horus/topic-unregistered");
+        assert_eq!(results[2]["level"], "warning");
+        assert!(results[2]["locations"][0]["physicalLocation"]["region"]
+            .get("startColumn")
+            .is_none());
+    }
+}
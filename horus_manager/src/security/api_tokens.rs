@@ -0,0 +1,147 @@
+//! API tokens for programmatic access to the monitor API
+//!
+//! Tokens are long-lived bearer credentials for external dashboards and fleet
+//! managers. Only the SHA-256 hash of a token is stored (in
+//! `~/.horus/api_tokens.json`); the token itself is shown once on creation.
+//! The file is re-read on every check, so tokens created or revoked with the
+//! CLI take effect on a running monitor.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// Prefix of every API token, makes them recognizable in configs and logs
+pub const TOKEN_PREFIX: &str = "horus_";
+
+/// Stored API token (without the secret)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiTokenInfo {
+    pub name: String,
+    /// Hex SHA-256 of the token
+    pub hash: String,
+    /// RFC 3339 creation time
+    pub created_at: String,
+}
+
+/// API tokens stored in a JSON file
+pub struct ApiTokenStore {
+    path: PathBuf,
+}
+
+impl ApiTokenStore {
+    /// Token store in `~/.horus/api_tokens.json`
+    pub fn open_default() -> Result<Self> {
+        let horus_dir = dirs::home_dir()
+            .context("Could not find home directory")?
+            .join(".horus");
+        std::fs::create_dir_all(&horus_dir).context("Failed to create .horus directory")?;
+        Ok(Self::at(horus_dir.join("api_tokens.json")))
+    }
+
+    /// Token store in a specific file
+    pub fn at<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// All stored tokens (empty if the file does not exist)
+    pub fn list(&self) -> Result<Vec<ApiTokenInfo>> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(json) => serde_json::from_str(&json)
+                .with_context(|| format!("Invalid API token file {}", self.path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e)
+                .with_context(|| format!("Failed to read API token file {}", self.path.display())),
+        }
+    }
+
+    fn save(&self, tokens: &[ApiTokenInfo]) -> Result<()> {
+        std::fs::write(&self.path, serde_json::to_string_pretty(tokens)?)
+            .context("Failed to write API token file")?;
+        // Hashes are not secrets, but nobody else needs to read them
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&self.path, std::fs::Permissions::from_mode(0o600))?;
+        }
+        Ok(())
+    }
+
+    /// Create a token; returns the secret, which is not stored
+    pub fn create(&self, name: &str) -> Result<String> {
+        let name = name.trim();
+        if name.is_empty() {
+            anyhow::bail!("API token name must not be empty");
+        }
+        let mut tokens = self.list()?;
+        if tokens.iter().any(|t| t.name == name) {
+            anyhow::bail!("API token '{}' already exists", name);
+        }
+
+        let token = format!("{}{}", TOKEN_PREFIX, super::auth::generate_session_token());
+        tokens.push(ApiTokenInfo {
+            name: name.to_string(),
+            hash: hash_token(&token),
+            created_at: chrono::Utc::now().to_rfc3339(),
+        });
+        self.save(&tokens)?;
+        Ok(token)
+    }
+
+    /// Delete a token; returns false if there was none with this name
+    pub fn revoke(&self, name: &str) -> Result<bool> {
+        let mut tokens = self.list()?;
+        let count = tokens.len();
+        tokens.retain(|t| t.name != name);
+        if tokens.len() == count {
+            return Ok(false);
+        }
+        self.save(&tokens)?;
+        Ok(true)
+    }
+
+    /// Name of the token, if it is valid
+    pub fn verify(&self, token: &str) -> Option<String> {
+        if !token.starts_with(TOKEN_PREFIX) {
+            return None;
+        }
+        let hash = hash_token(token);
+        self.list()
+            .ok()?
+            .into_iter()
+            .find(|t| t.hash == hash)
+            .map(|t| t.name)
+    }
+}
+
+fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_verify_revoke() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ApiTokenStore::at(dir.path().join("api_tokens.json"));
+        assert!(store.list().unwrap().is_empty());
+
+        let token = store.create("fleet-manager").unwrap();
+        assert!(token.starts_with(TOKEN_PREFIX));
+        assert_eq!(store.verify(&token).as_deref(), Some("fleet-manager"));
+        assert_eq!(store.verify("horus_invalid"), None);
+        assert!(store.create("fleet-manager").is_err());
+
+        // The secret itself is never written to disk
+        let file = std::fs::read_to_string(dir.path().join("api_tokens.json")).unwrap();
+        assert!(!file.contains(&token));
+
+        assert!(store.revoke("fleet-manager").unwrap());
+        assert!(!store.revoke("fleet-manager").unwrap());
+        assert_eq!(store.verify(&token), None);
+    }
+}
@@ -149,7 +149,7 @@ impl RateLimiter {
 }
 
 /// Generate a cryptographically secure session token
-fn generate_session_token() -> String {
+pub(crate) fn generate_session_token() -> String {
     let mut rng = rand::thread_rng();
     let random_bytes: Vec<u8> = (0..32).map(|_| rng.gen()).collect();
 
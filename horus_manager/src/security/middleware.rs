@@ -28,7 +28,7 @@ pub async fn session_middleware(
 }
 
 /// Extract session token from request (checks both Cookie and Authorization headers)
-fn extract_session_token(req: &Request<Body>) -> Option<&str> {
+pub(crate) fn extract_session_token(req: &Request<Body>) -> Option<&str> {
     // Try Cookie header first
     if let Some(cookie_header) = req.headers().get(header::COOKIE) {
         if let Ok(cookie_str) = cookie_header.to_str() {
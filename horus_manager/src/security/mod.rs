@@ -1,9 +1,12 @@
-//! Security module for HORUS monitor
+//! Security module for HORUS monitor and registry
 //!
-//! Provides password-based authentication and security middleware.
+//! Provides password-based authentication, API tokens, security middleware
+//! and package signing.
 
+pub mod api_tokens;
 pub mod auth;
 pub mod middleware;
+pub mod signing;
 
 pub use auth::AuthService;
 pub use middleware::{security_headers_middleware, session_middleware};
@@ -0,0 +1,396 @@
+//! Signing and verification of registry packages
+//!
+//! `horus pkg publish` signs the archive with the publisher's ed25519 key
+//! (`~/.horus/keys/package_signing.key`, created on first publish) and
+//! uploads the signature with it. The signature covers the package name,
+//! version and the SHA-256 of the archive.
+//!
+//! On install the signature served by the registry is checked against the
+//! downloaded archive, and the signer is looked up in the trust store
+//! (`~/.horus/trusted_keys.json`, managed with `horus pkg trust`). A checksum
+//! mismatch or an invalid signature always aborts the install. Unsigned
+//! packages and unknown signers are refused as soon as any key is trusted
+//! (or [`REQUIRE_SIGNED_ENV`] is set), unless the install passes
+//! `--allow-unsigned`; with an empty trust store they only warn. The archive
+//! is verified before it is unpacked.
+
+use anyhow::{anyhow, bail, Context, Result};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// Refuse unsigned packages and packages from untrusted signers
+pub const REQUIRE_SIGNED_ENV: &str = "HORUS_REQUIRE_SIGNED";
+
+/// Domain separation of the signed message
+const SIGNATURE_CONTEXT: &str = "horus-package-v1";
+
+/// Signature of a published package archive
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PackageSignature {
+    pub name: String,
+    pub version: String,
+    /// Hex SHA-256 of the archive
+    pub checksum: String,
+    /// Hex ed25519 public key of the publisher
+    pub public_key: String,
+    /// Hex ed25519 signature
+    pub signature: String,
+}
+
+impl PackageSignature {
+    /// Sign an archive
+    pub fn sign(key: &SigningKey, name: &str, version: &str, checksum: &str) -> Self {
+        let signature = key.sign(&signed_message(name, version, checksum));
+        Self {
+            name: name.to_string(),
+            version: version.to_string(),
+            checksum: checksum.to_string(),
+            public_key: to_hex(key.verifying_key().as_bytes()),
+            signature: to_hex(&signature.to_bytes()),
+        }
+    }
+
+    /// Check the signature over its own name, version and checksum
+    pub fn verify(&self) -> Result<()> {
+        let public_key = parse_public_key(&self.public_key)?;
+        let bytes: [u8; 64] = from_hex(&self.signature)
+            .and_then(|b| b.try_into().ok())
+            .ok_or_else(|| anyhow!("Malformed package signature"))?;
+        public_key
+            .verify_strict(
+                &signed_message(&self.name, &self.version, &self.checksum),
+                &Signature::from_bytes(&bytes),
+            )
+            .map_err(|_| anyhow!("Invalid signature on {} v{}", self.name, self.version))
+    }
+}
+
+fn signed_message(name: &str, version: &str, checksum: &str) -> Vec<u8> {
+    format!("{}\n{}\n{}\n{}", SIGNATURE_CONTEXT, name, version, checksum).into_bytes()
+}
+
+/// Outcome of verifying a downloaded archive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verification {
+    /// Signed by a key in the trust store
+    Trusted { signer: String },
+    /// Validly signed by a key that is not trusted
+    Untrusted { public_key: String },
+    /// The registry has no signature for the package
+    Unsigned,
+}
+
+/// Verify a downloaded archive against its registry signature
+///
+/// Errors if the archive doesn't match the signature or the signature is
+/// invalid. `version` is the version being installed: the requested one, or
+/// the one the archive declares when installing the latest. A signature for
+/// any other version is rejected, so an older signed release can't be served
+/// in place of the latest.
+pub fn verify_package(
+    signature: Option<&PackageSignature>,
+    name: &str,
+    version: &str,
+    checksum: &str,
+    trust: &TrustStore,
+) -> Result<Verification> {
+    let Some(signature) = signature else {
+        return Ok(Verification::Unsigned);
+    };
+    if signature.name != name {
+        bail!(
+            "Signature is for package '{}', not '{}'",
+            signature.name,
+            name
+        );
+    }
+    if signature.version != version {
+        bail!(
+            "Signature is for {} v{}, not v{}",
+            name,
+            signature.version,
+            version
+        );
+    }
+    if !signature.checksum.eq_ignore_ascii_case(checksum) {
+        bail!(
+            "Checksum mismatch for {}: archive is {}, publisher signed {}. The package may have been tampered with",
+            name,
+            checksum,
+            signature.checksum
+        );
+    }
+    signature.verify()?;
+
+    Ok(match trust.find(&signature.public_key)? {
+        Some(key) => Verification::Trusted { signer: key.name },
+        None => Verification::Untrusted {
+            public_key: signature.public_key.clone(),
+        },
+    })
+}
+
+/// Whether unsigned and untrusted packages are refused
+///
+/// True once the trust store holds a key, or with `HORUS_REQUIRE_SIGNED`.
+pub fn require_signed(trust: &TrustStore) -> Result<bool> {
+    let forced = std::env::var(REQUIRE_SIGNED_ENV)
+        .map(|v| matches!(v.as_str(), "1" | "true" | "yes"))
+        .unwrap_or(false);
+    Ok(forced || !trust.list()?.is_empty())
+}
+
+/// Signing key in `~/.horus/keys/package_signing.key`
+pub fn signing_key_path() -> Result<PathBuf> {
+    Ok(dirs::home_dir()
+        .context("Could not find home directory")?
+        .join(".horus/keys/package_signing.key"))
+}
+
+/// Load the signing key, creating it if missing; true if it was created
+pub fn load_or_create_signing_key(path: &Path) -> Result<(SigningKey, bool)> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => {
+            let seed: [u8; 32] = from_hex(contents.trim())
+                .and_then(|b| b.try_into().ok())
+                .ok_or_else(|| anyhow!("Invalid signing key {}", path.display()))?;
+            Ok((SigningKey::from_bytes(&seed), false))
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            let key = SigningKey::from_bytes(&rand::random::<[u8; 32]>());
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent).context("Failed to create key directory")?;
+            }
+            write_private(path, &to_hex(&key.to_bytes()))
+                .with_context(|| format!("Failed to write signing key {}", path.display()))?;
+            Ok((key, true))
+        }
+        Err(e) => Err(e).with_context(|| format!("Failed to read signing key {}", path.display())),
+    }
+}
+
+/// Hex public key of a signing key, as shared with `horus pkg trust add`
+pub fn public_key_hex(key: &SigningKey) -> String {
+    to_hex(key.verifying_key().as_bytes())
+}
+
+fn write_private(path: &Path, contents: &str) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::io::Write;
+        use std::os::unix::fs::OpenOptionsExt;
+        std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .mode(0o600)
+            .open(path)?
+            .write_all(contents.as_bytes())
+    }
+    #[cfg(not(unix))]
+    {
+        std::fs::write(path, contents)
+    }
+}
+
+/// Publisher key in the trust store
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrustedKey {
+    pub name: String,
+    /// Hex ed25519 public key
+    pub public_key: String,
+    /// RFC 3339 time the key was added
+    pub added_at: String,
+}
+
+/// Publisher keys trusted for installs, stored in a JSON file
+pub struct TrustStore {
+    path: PathBuf,
+}
+
+impl TrustStore {
+    /// Trust store in `~/.horus/trusted_keys.json`
+    pub fn open_default() -> Result<Self> {
+        let horus_dir = dirs::home_dir()
+            .context("Could not find home directory")?
+            .join(".horus");
+        std::fs::create_dir_all(&horus_dir).context("Failed to create .horus directory")?;
+        Ok(Self::at(horus_dir.join("trusted_keys.json")))
+    }
+
+    /// Trust store in a specific file
+    pub fn at<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+        }
+    }
+
+    /// All trusted keys (empty if the file does not exist)
+    pub fn list(&self) -> Result<Vec<TrustedKey>> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(json) => serde_json::from_str(&json)
+                .with_context(|| format!("Invalid trust store {}", self.path.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e)
+                .with_context(|| format!("Failed to read trust store {}", self.path.display())),
+        }
+    }
+
+    fn save(&self, keys: &[TrustedKey]) -> Result<()> {
+        std::fs::write(&self.path, serde_json::to_string_pretty(keys)?)
+            .context("Failed to write trust store")
+    }
+
+    /// Trust a publisher key under a name
+    pub fn add(&self, name: &str, public_key: &str) -> Result<()> {
+        let name = name.trim();
+        if name.is_empty() {
+            bail!("Trusted key name must not be empty");
+        }
+        let public_key = public_key.trim().to_ascii_lowercase();
+        parse_public_key(&public_key)?;
+
+        let mut keys = self.list()?;
+        if let Some(existing) = keys
+            .iter()
+            .find(|k| k.name == name || k.public_key == public_key)
+        {
+            bail!(
+                "Key '{}' ({}) is already trusted",
+                existing.name,
+                existing.public_key
+            );
+        }
+        keys.push(TrustedKey {
+            name: name.to_string(),
+            public_key,
+            added_at: chrono::Utc::now().to_rfc3339(),
+        });
+        self.save(&keys)
+    }
+
+    /// Stop trusting a key, by name or public key; false if none matched
+    pub fn remove(&self, name_or_key: &str) -> Result<bool> {
+        let mut keys = self.list()?;
+        let count = keys.len();
+        keys.retain(|k| k.name != name_or_key && !k.public_key.eq_ignore_ascii_case(name_or_key));
+        if keys.len() == count {
+            return Ok(false);
+        }
+        self.save(&keys)?;
+        Ok(true)
+    }
+
+    /// Trusted key with this public key
+    pub fn find(&self, public_key: &str) -> Result<Option<TrustedKey>> {
+        Ok(self
+            .list()?
+            .into_iter()
+            .find(|k| k.public_key.eq_ignore_ascii_case(public_key)))
+    }
+}
+
+fn parse_public_key(hex: &str) -> Result<VerifyingKey> {
+    let bytes: [u8; 32] = from_hex(hex)
+        .and_then(|b| b.try_into().ok())
+        .ok_or_else(|| anyhow!("Public key must be 64 hex characters"))?;
+    VerifyingKey::from_bytes(&bytes).map_err(|_| anyhow!("Invalid ed25519 public key"))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHECKSUM: &str = "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08";
+
+    #[test]
+    fn test_sign_and_verify() {
+        let dir = tempfile::tempdir().unwrap();
+        let (key, created) = load_or_create_signing_key(&dir.path().join("signing.key")).unwrap();
+        assert!(created);
+        let (reloaded, created) =
+            load_or_create_signing_key(&dir.path().join("signing.key")).unwrap();
+        assert!(!created);
+        assert_eq!(public_key_hex(&key), public_key_hex(&reloaded));
+
+        let trust = TrustStore::at(dir.path().join("trusted_keys.json"));
+        let signature = PackageSignature::sign(&key, "lidar-driver", "1.2.0", CHECKSUM);
+        assert_eq!(
+            verify_package(Some(&signature), "lidar-driver", "1.2.0", CHECKSUM, &trust).unwrap(),
+            Verification::Untrusted {
+                public_key: public_key_hex(&key)
+            }
+        );
+
+        trust.add("acme", &public_key_hex(&key)).unwrap();
+        assert_eq!(
+            verify_package(Some(&signature), "lidar-driver", "1.2.0", CHECKSUM, &trust).unwrap(),
+            Verification::Trusted {
+                signer: "acme".to_string()
+            }
+        );
+        assert_eq!(
+            verify_package(None, "lidar-driver", "1.2.0", CHECKSUM, &trust).unwrap(),
+            Verification::Unsigned
+        );
+    }
+
+    #[test]
+    fn test_rejects_tampering() {
+        let dir = tempfile::tempdir().unwrap();
+        let trust = TrustStore::at(dir.path().join("trusted_keys.json"));
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let signature = PackageSignature::sign(&key, "lidar-driver", "1.2.0", CHECKSUM);
+
+        let other = CHECKSUM.replace('9', "8");
+        assert!(verify_package(Some(&signature), "lidar-driver", "1.2.0", &other, &trust).is_err());
+        assert!(verify_package(Some(&signature), "imu-driver", "1.2.0", CHECKSUM, &trust).is_err());
+        // Signed for a different release than the one being installed
+        assert!(
+            verify_package(Some(&signature), "lidar-driver", "1.3.0", CHECKSUM, &trust).is_err()
+        );
+
+        // Fields changed after signing
+        let mut forged = signature.clone();
+        forged.version = "1.3.0".to_string();
+        assert!(forged.verify().is_err());
+        let mut forged = signature;
+        forged.public_key = public_key_hex(&SigningKey::from_bytes(&[8u8; 32]));
+        assert!(forged.verify().is_err());
+    }
+
+    #[test]
+    fn test_trust_store() {
+        let dir = tempfile::tempdir().unwrap();
+        let trust = TrustStore::at(dir.path().join("trusted_keys.json"));
+        let public_key = public_key_hex(&SigningKey::from_bytes(&[1u8; 32]));
+
+        assert!(trust.add("acme", "not-a-key").is_err());
+        if std::env::var_os(REQUIRE_SIGNED_ENV).is_none() {
+            assert!(!require_signed(&trust).unwrap());
+        }
+        trust.add("acme", &public_key.to_uppercase()).unwrap();
+        // Trusting any key makes signatures mandatory
+        assert!(require_signed(&trust).unwrap());
+        assert!(trust.add("acme", &public_key).is_err());
+        assert_eq!(trust.find(&public_key).unwrap().unwrap().name, "acme");
+
+        assert!(trust.remove("acme").unwrap());
+        assert!(!trust.remove(&public_key).unwrap());
+        assert!(trust.list().unwrap().is_empty());
+    }
+}
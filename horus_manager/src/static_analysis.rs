@@ -3,14 +3,30 @@
 //! This module provides compile-time checks for:
 //! - Multiple producers/consumers on the same Link (SPSC violation)
 //! - Misuse of Link vs Hub
+//! - Topic names and types that disagree with the `topics!` registry
+//! - Topics subscribed but never published (or the reverse) and endpoints
+//!   that disagree on a topic's type
+//! - Sleeps, blocking I/O, unbounded loops and large allocations in `tick()`
 //! - Other potential IPC issues
 
 use anyhow::{Context, Result};
 use colored::*;
-use std::collections::HashMap;
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fs;
-use std::path::Path;
-use syn::{visit::Visit, Expr, ExprCall, ExprMethodCall, ExprPath, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::OnceLock;
+use syn::{
+    parse::{ParseStream, Parser},
+    visit::Visit,
+    BinOp, Block, Expr, ExprCall, ExprClosure, ExprLit, ExprLoop, ExprMethodCall, ExprPath,
+    ExprRepeat, ExprWhile, FieldValue, File, GenericArgument, Ident, ImplItem, ItemImpl, Lit,
+    LitStr, Local, Macro, Member, Pat, PathArguments, Token, Type,
+};
 
 /// Tracks Link usage per topic to detect SPSC violations
 #[derive(Debug, Default)]
@@ -165,3 +181,1424 @@ pub fn check_link_usage(file_path: &Path) -> Result<()> {
 
     Ok(())
 }
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Topic registry and graph checks
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Topic declared in a `topics!` registry or the `topics:` section of horus.yaml
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegisteredTopic {
+    pub name: String,
+    /// Message type as written in the registry
+    pub type_name: String,
+    /// Published or subscribed outside the project (simulator, drivers)
+    pub external: bool,
+    pub file: PathBuf,
+    pub line: usize,
+}
+
+/// Whether a topic usage sends or receives
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopicRole {
+    Publish,
+    Subscribe,
+}
+
+/// Topic written as a string in Rust or Python code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TopicUsage {
+    /// Topic name, without any `@endpoint` suffix
+    pub topic: String,
+    /// Message type, when the code states it
+    pub type_name: Option<String>,
+    /// Publish or subscribe, when the code makes it clear
+    pub role: Option<TopicRole>,
+    /// The topic had an `@endpoint` suffix: the other side may run on another machine
+    pub remote: bool,
+    pub file: PathBuf,
+    pub line: usize,
+}
+
+/// Disagreement between a topic usage and the registry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TopicIssue {
+    /// The topic is not in the registry
+    Unregistered {
+        usage: TopicUsage,
+        /// Closest registered topic, for typos
+        suggestion: Option<String>,
+    },
+    /// The topic is used with another message type than registered
+    TypeMismatch {
+        usage: TopicUsage,
+        registered: String,
+    },
+}
+
+/// Problem in the topic graph built from all publishers and subscribers
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GraphIssue {
+    /// The topic is subscribed but nothing publishes it
+    NoPublisher { subscriber: TopicUsage },
+    /// The topic is published but nothing subscribes to it
+    NoSubscriber { publisher: TopicUsage },
+    /// Two endpoints of an unregistered topic use different message types
+    TypeConflict {
+        first: TopicUsage,
+        other: TopicUsage,
+    },
+}
+
+/// Registry entries and topic usages found in a Rust file
+#[derive(Debug, Default)]
+pub struct RustTopicScan {
+    pub registry: Vec<RegisteredTopic>,
+    pub usages: Vec<TopicUsage>,
+}
+
+/// Find `topics!` registries, `Hub::new("...")` calls and `node!` topics in Rust code
+///
+/// Files that do not parse are skipped, `cargo check` reports them.
+pub fn scan_rust_topics(content: &str, file: &Path) -> RustTopicScan {
+    let Ok(ast) = syn::parse_file(content) else {
+        return RustTopicScan::default();
+    };
+    let mut visitor = TopicVisitor {
+        file,
+        scan: RustTopicScan::default(),
+        let_type: None,
+        binding: None,
+        bound: Vec::new(),
+        sends: HashSet::new(),
+        recvs: HashSet::new(),
+    };
+    visitor.visit_file(&ast);
+
+    // A Hub is a publisher if its binding calls send(), a subscriber if it
+    // calls recv(); one used for both counts as both
+    let mut scan = visitor.scan;
+    for (index, name) in visitor.bound {
+        let sends = visitor.sends.contains(&name);
+        let recvs = visitor.recvs.contains(&name);
+        if sends {
+            scan.usages[index].role = Some(TopicRole::Publish);
+        }
+        if recvs {
+            let mut usage = scan.usages[index].clone();
+            usage.role = Some(TopicRole::Subscribe);
+            if sends {
+                scan.usages.push(usage);
+            } else {
+                scan.usages[index] = usage;
+            }
+        }
+    }
+    scan.usages.sort_by_key(|usage| usage.line);
+    scan
+}
+
+/// Topics of the `topics:` section of a horus.yaml
+///
+/// Entries are `name: Type` or `name: {type: Type, external: true}`.
+pub fn manifest_topics(content: &str, file: &Path) -> Vec<RegisteredTopic> {
+    let Ok(yaml) = serde_yaml::from_str::<serde_yaml::Value>(content) else {
+        return Vec::new();
+    };
+    let Some(topics) = yaml.get("topics").and_then(|t| t.as_mapping()) else {
+        return Vec::new();
+    };
+
+    topics
+        .iter()
+        .filter_map(|(name, entry)| {
+            let name = name.as_str()?;
+            let (type_name, external) = match entry {
+                serde_yaml::Value::String(type_name) => (type_name.as_str(), false),
+                entry => (
+                    entry.get("type")?.as_str()?,
+                    entry
+                        .get("external")
+                        .and_then(|e| e.as_bool())
+                        .unwrap_or(false),
+                ),
+            };
+            let line = content
+                .lines()
+                .position(|line| {
+                    let line = line.trim_start().trim_start_matches(['"', '\'']);
+                    line.strip_prefix(name)
+                        .is_some_and(|rest| rest.trim_start_matches(['"', '\'']).starts_with(':'))
+                })
+                .map_or(0, |index| index + 1);
+            Some(RegisteredTopic {
+                name: name.to_string(),
+                type_name: type_name.to_string(),
+                external,
+                file: file.to_path_buf(),
+                line,
+            })
+        })
+        .collect()
+}
+
+/// Find topic strings in Python code: `Hub("topic")`, `Hub(Type, endpoint="topic")`
+/// and `pubs`/`subs` entries such as `"topic"`, `["a", "b"]` or
+/// `{"topic": {"type": Type}}`
+pub fn python_topic_usages(content: &str, file: &Path) -> Vec<TopicUsage> {
+    static PATTERNS: OnceLock<[Regex; 4]> = OnceLock::new();
+    let [hub, hub_endpoint, typed_entry, section] = PATTERNS.get_or_init(|| {
+        [
+            r#"\bHub\(\s*["'](?P<topic>[^"']+)["']"#,
+            r#"\bHub\(\s*(?P<type>[\w.]+)\s*,[^)]*?\bendpoint\s*=\s*["'](?P<topic>[^"']+)["']"#,
+            r#"["'](?P<topic>[^"']+)["']\s*:\s*\{\s*["']type["']\s*:\s*(?P<type>[\w.]+)"#,
+            r#"\b(?P<section>pubs|subs)\s*=\s*"#,
+        ]
+        .map(|pattern| Regex::new(pattern).expect("valid topic pattern"))
+    });
+
+    let mut usages = Vec::new();
+    let mut add = |topic: &str, type_name: Option<&str>, role: Option<TopicRole>, start: usize| {
+        let (topic, endpoint) = topic.split_once('@').unwrap_or((topic, ""));
+        if topic.is_empty() {
+            return;
+        }
+        usages.push(TopicUsage {
+            topic: topic.to_string(),
+            type_name: type_name.map(|ty| ty.rsplit('.').next().unwrap_or_default().to_string()),
+            role,
+            remote: !endpoint.is_empty(),
+            file: file.to_path_buf(),
+            line: content[..start].matches('\n').count() + 1,
+        });
+    };
+
+    for pattern in [hub, hub_endpoint] {
+        for captures in pattern.captures_iter(content) {
+            let start = captures.get(0).map_or(0, |m| m.start());
+            add(
+                &captures["topic"],
+                captures.name("type").map(|ty| ty.as_str()),
+                None,
+                start,
+            );
+        }
+    }
+
+    // Entries of `pubs=` / `subs=` arguments know their role
+    let mut in_sections = HashSet::new();
+    for captures in section.captures_iter(content) {
+        let role = match &captures["section"] {
+            "pubs" => TopicRole::Publish,
+            _ => TopicRole::Subscribe,
+        };
+        let value = captures.get(0).map_or(0, |m| m.end());
+        for (start, topic) in python_collection_topics(content, value) {
+            let type_name = typed_entry
+                .captures_at(content, start)
+                .filter(|entry| entry.get(0).is_some_and(|m| m.start() == start))
+                .map(|entry| entry["type"].to_string());
+            add(&topic, type_name.as_deref(), Some(role), start);
+            in_sections.insert(start);
+        }
+    }
+
+    // Typed entries of dicts built elsewhere and passed by name
+    for captures in typed_entry.captures_iter(content) {
+        let start = captures.get(0).map_or(0, |m| m.start());
+        if !in_sections.contains(&start) {
+            add(&captures["topic"], Some(&captures["type"]), None, start);
+        }
+    }
+    usages.sort_by_key(|usage| usage.line);
+    usages
+}
+
+/// Topic strings of the Python value starting at `start`: a string, the
+/// strings of a list or tuple, or the keys of a dict, with their offsets
+fn python_collection_topics(content: &str, start: usize) -> Vec<(usize, String)> {
+    let bytes = content.as_bytes();
+    let mut topics = Vec::new();
+    let mut depth = 0usize;
+    let mut dict = false;
+    let mut i = start;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'"' | b'\'') => {
+                let Some(len) = content[i + 1..].find(quote as char) else {
+                    break;
+                };
+                let end = i + 1 + len;
+                let is_key = content[end + 1..].trim_start().starts_with(':');
+                if depth == 0 || (depth == 1 && (!dict || is_key)) {
+                    topics.push((i, content[i + 1..end].to_string()));
+                }
+                if depth == 0 {
+                    break;
+                }
+                i = end;
+            }
+            open @ (b'[' | b'(' | b'{') => {
+                if depth == 0 {
+                    dict = open == b'{';
+                }
+                depth += 1;
+            }
+            b']' | b')' | b'}' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    break;
+                }
+            }
+            b' ' | b'\t' | b'\n' | b'\r' => {}
+            _ if depth == 0 => break,
+            _ => {}
+        }
+        i += 1;
+    }
+    topics
+}
+
+/// Compare topic usages with the registry
+pub fn check_topic_usages(registry: &[RegisteredTopic], usages: &[TopicUsage]) -> Vec<TopicIssue> {
+    let by_name: HashMap<&str, &RegisteredTopic> = registry
+        .iter()
+        .map(|topic| (topic.name.as_str(), topic))
+        .collect();
+
+    usages
+        .iter()
+        .filter_map(|usage| match by_name.get(usage.topic.as_str()) {
+            None => Some(TopicIssue::Unregistered {
+                usage: usage.clone(),
+                suggestion: closest_topic(&usage.topic, registry),
+            }),
+            Some(registered) => {
+                let type_name = usage.type_name.as_deref()?;
+                (normalize_type(type_name) != normalize_type(&registered.type_name)).then(|| {
+                    TopicIssue::TypeMismatch {
+                        usage: usage.clone(),
+                        registered: registered.type_name.clone(),
+                    }
+                })
+            }
+        })
+        .collect()
+}
+
+/// Compare publishers and subscribers of every topic
+///
+/// A topic is only reported as missing a publisher or subscriber when the
+/// role of all its usages is known, none of them names a remote endpoint and
+/// the registry does not mark it external.
+/// Type conflicts are only reported for unregistered topics, registered ones
+/// are covered by [`check_topic_usages`].
+pub fn check_topic_graph(registry: &[RegisteredTopic], usages: &[TopicUsage]) -> Vec<GraphIssue> {
+    let registered: HashSet<&str> = registry.iter().map(|topic| topic.name.as_str()).collect();
+    let external: HashSet<&str> = registry
+        .iter()
+        .filter(|topic| topic.external)
+        .map(|topic| topic.name.as_str())
+        .collect();
+    let mut topics: BTreeMap<&str, Vec<&TopicUsage>> = BTreeMap::new();
+    for usage in usages {
+        topics.entry(usage.topic.as_str()).or_default().push(usage);
+    }
+
+    let mut issues = Vec::new();
+    for (topic, usages) in topics {
+        let publisher = usages
+            .iter()
+            .find(|usage| usage.role == Some(TopicRole::Publish));
+        let subscriber = usages
+            .iter()
+            .find(|usage| usage.role == Some(TopicRole::Subscribe));
+        let complete = !external.contains(topic)
+            && usages
+                .iter()
+                .all(|usage| usage.role.is_some() && !usage.remote);
+        if complete {
+            match (publisher, subscriber) {
+                (None, Some(subscriber)) => issues.push(GraphIssue::NoPublisher {
+                    subscriber: (*subscriber).clone(),
+                }),
+                (Some(publisher), None) => issues.push(GraphIssue::NoSubscriber {
+                    publisher: (*publisher).clone(),
+                }),
+                _ => {}
+            }
+        }
+
+        if !registered.contains(topic) {
+            let mut typed = usages.iter().filter(|usage| usage.type_name.is_some());
+            if let Some(first) = typed.next() {
+                let first_type = normalize_type(first.type_name.as_deref().unwrap_or_default());
+                if let Some(other) = typed.find(|usage| {
+                    normalize_type(usage.type_name.as_deref().unwrap_or_default()) != first_type
+                }) {
+                    issues.push(GraphIssue::TypeConflict {
+                        first: (*first).clone(),
+                        other: (*other).clone(),
+                    });
+                }
+            }
+        }
+    }
+    issues
+}
+
+/// AST visitor that collects registry entries and topic usages
+struct TopicVisitor<'a> {
+    file: &'a Path,
+    scan: RustTopicScan,
+    /// Type of the `let hub: Hub<T> = ...` being visited
+    let_type: Option<String>,
+    /// Variable or field the visited expression is assigned to
+    binding: Option<String>,
+    /// Usage index and binding of each `Hub::new` assigned to a name
+    bound: Vec<(usize, String)>,
+    /// Bindings that call `send()` / `recv()`
+    sends: HashSet<String>,
+    recvs: HashSet<String>,
+}
+
+impl TopicVisitor<'_> {
+    fn add_usage(
+        &mut self,
+        lit: &LitStr,
+        type_name: Option<String>,
+        role: Option<TopicRole>,
+    ) -> Option<usize> {
+        let value = lit.value();
+        let (topic, endpoint) = value.split_once('@').unwrap_or((value.as_str(), ""));
+        if topic.is_empty() {
+            return None;
+        }
+        self.scan.usages.push(TopicUsage {
+            topic: topic.to_string(),
+            type_name,
+            role,
+            remote: !endpoint.is_empty(),
+            file: self.file.to_path_buf(),
+            line: lit.span().start().line,
+        });
+        Some(self.scan.usages.len() - 1)
+    }
+
+    /// Collect the `pub { name: Type -> "topic" }` and `sub { ... }` sections
+    /// of a `node!` invocation
+    fn scan_node_macro(&mut self, tokens: TokenStream) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        for (i, token) in tokens.iter().enumerate() {
+            let TokenTree::Group(group) = token else {
+                continue;
+            };
+            let role = match i.checked_sub(1).map(|prev| &tokens[prev]) {
+                Some(TokenTree::Ident(ident)) if ident == "pub" => Some(TopicRole::Publish),
+                Some(TokenTree::Ident(ident)) if ident == "sub" => Some(TopicRole::Subscribe),
+                _ => None,
+            };
+            if role.is_some() {
+                if let Ok(entries) = parse_node_topics.parse2(group.stream()) {
+                    for (type_name, topic) in entries {
+                        self.add_usage(&topic, Some(type_name), role);
+                    }
+                }
+            } else {
+                self.scan_node_macro(group.stream());
+            }
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for TopicVisitor<'_> {
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        if let Some(last) = node.path.segments.last() {
+            if last.ident == "topics" {
+                if let Ok(entries) = parse_registry.parse2(node.tokens.clone()) {
+                    for (name, type_name) in entries {
+                        self.scan.registry.push(RegisteredTopic {
+                            name: name.value(),
+                            type_name,
+                            external: false,
+                            file: self.file.to_path_buf(),
+                            line: name.span().start().line,
+                        });
+                    }
+                }
+            } else if last.ident == "node" {
+                self.scan_node_macro(node.tokens.clone());
+            }
+        }
+        syn::visit::visit_macro(self, node);
+    }
+
+    fn visit_local(&mut self, node: &'ast Local) {
+        let mut pat = &node.pat;
+        if let Pat::Type(typed) = pat {
+            self.let_type = hub_type_argument(&typed.ty);
+            pat = &typed.pat;
+        }
+        if let Pat::Ident(ident) = pat {
+            self.binding = Some(ident.ident.to_string());
+        }
+        syn::visit::visit_local(self, node);
+        self.let_type = None;
+        self.binding = None;
+    }
+
+    fn visit_field_value(&mut self, node: &'ast FieldValue) {
+        if let Member::Named(name) = &node.member {
+            self.binding = Some(name.to_string());
+        }
+        syn::visit::visit_field_value(self, node);
+        self.binding = None;
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        let receiver = match &*node.receiver {
+            Expr::Path(ExprPath { path, .. }) => path.get_ident().map(Ident::to_string),
+            Expr::Field(field) => match &field.member {
+                Member::Named(name) => Some(name.to_string()),
+                Member::Unnamed(_) => None,
+            },
+            _ => None,
+        };
+        if let Some(receiver) = receiver {
+            if node.method == "send" {
+                self.sends.insert(receiver);
+            } else if node.method == "recv" {
+                self.recvs.insert(receiver);
+            }
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        // Hub::new("topic"), Hub::<T>::new("topic"), Hub::new_with_capacity("topic", n),
+        // Link::producer("topic") or Link::consumer("topic")
+        if let Expr::Path(ExprPath { path, .. }) = &*node.func {
+            let segments: Vec<_> = path.segments.iter().collect();
+            if let [.., channel, constructor] = segments.as_slice() {
+                let role = match (
+                    channel.ident.to_string().as_str(),
+                    constructor.ident.to_string().as_str(),
+                ) {
+                    ("Hub", "new" | "new_with_capacity") => Some(None),
+                    ("Link", "producer") => Some(Some(TopicRole::Publish)),
+                    ("Link", "consumer") => Some(Some(TopicRole::Subscribe)),
+                    _ => None,
+                };
+                if let Some(role) = role {
+                    let type_name =
+                        generic_type_argument(&channel.arguments).or_else(|| self.let_type.take());
+                    let binding = self.binding.take();
+                    if let Some(Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(lit),
+                        ..
+                    })) = node.args.first()
+                    {
+                        let index = self.add_usage(lit, type_name, role);
+                        if let (Some(index), Some(binding), None) = (index, binding, role) {
+                            self.bound.push((index, binding));
+                        }
+                    }
+                }
+            }
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+}
+
+/// Entries of a `topics!` invocation: `"name": Type,`
+fn parse_registry(input: ParseStream) -> syn::Result<Vec<(LitStr, String)>> {
+    let mut entries = Vec::new();
+    while !input.is_empty() {
+        input.call(syn::Attribute::parse_outer)?;
+        let name: LitStr = input.parse()?;
+        input.parse::<Token![:]>()?;
+        entries.push((name, parse_type_text(input)?));
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+    Ok(entries)
+}
+
+/// Topics of a `node!` pub or sub section: `name: Type -> "topic",`
+///
+/// Topics given as expressions other than string literals are skipped.
+fn parse_node_topics(input: ParseStream) -> syn::Result<Vec<(String, LitStr)>> {
+    let mut entries = Vec::new();
+    while !input.is_empty() {
+        input.parse::<Ident>()?;
+        input.parse::<Token![:]>()?;
+        let type_name = parse_type_text(input)?;
+        input.parse::<Token![->]>()?;
+        if let Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(topic),
+            ..
+        }) = input.parse::<Expr>()?
+        {
+            entries.push((type_name, topic));
+        }
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+    Ok(entries)
+}
+
+/// Parse a type and return it as written, without whitespace
+fn parse_type_text(input: ParseStream) -> syn::Result<String> {
+    let mut cursor = input.cursor();
+    input.parse::<Type>()?;
+    let end = input.cursor();
+
+    let mut text = String::new();
+    while cursor != end {
+        let Some((token, next)) = cursor.token_tree() else {
+            break;
+        };
+        text.push_str(&token.to_string());
+        cursor = next;
+    }
+    text.retain(|c| !c.is_whitespace());
+    Ok(text)
+}
+
+/// `T` of a `Hub<T>` type
+fn hub_type_argument(ty: &Type) -> Option<String> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let last = path.path.segments.last()?;
+    if last.ident == "Hub" || last.ident == "Link" {
+        generic_type_argument(&last.arguments)
+    } else {
+        None
+    }
+}
+
+fn generic_type_argument(arguments: &PathArguments) -> Option<String> {
+    match arguments {
+        PathArguments::AngleBracketed(args) => match args.args.first()? {
+            GenericArgument::Type(ty) => type_text(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Text of a path type such as `horus::CmdVel` or `Vec<u8>`; other types
+/// are not compared
+fn type_text(ty: &Type) -> Option<String> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let mut segments = Vec::new();
+    for segment in &path.path.segments {
+        let mut text = segment.ident.to_string();
+        match &segment.arguments {
+            PathArguments::None => {}
+            PathArguments::AngleBracketed(args) => {
+                let args = args
+                    .args
+                    .iter()
+                    .map(|arg| match arg {
+                        GenericArgument::Type(ty) => type_text(ty),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                text.push_str(&format!("<{}>", args.join(",")));
+            }
+            PathArguments::Parenthesized(_) => return None,
+        }
+        segments.push(text);
+    }
+    Some(segments.join("::"))
+}
+
+/// Type without whitespace and module paths: `horus::msgs::CmdVel` → `CmdVel`
+fn normalize_type(type_name: &str) -> String {
+    let compact: String = type_name.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut normalized = String::new();
+    let mut segment = String::new();
+    let mut chars = compact.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ':' && chars.peek() == Some(&':') {
+            chars.next();
+            segment.clear();
+        } else if c.is_alphanumeric() || c == '_' {
+            segment.push(c);
+        } else {
+            normalized.push_str(&segment);
+            normalized.push(c);
+            segment.clear();
+        }
+    }
+    normalized.push_str(&segment);
+    normalized
+}
+
+/// Registered topic within a few edits of `topic`
+fn closest_topic(topic: &str, registry: &[RegisteredTopic]) -> Option<String> {
+    let max_distance = (topic.len() / 3).max(1);
+    registry
+        .iter()
+        .map(|registered| (edit_distance(topic, &registered.name), &registered.name))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, name)| name.clone())
+}
+
+/// Levenshtein distance
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// Real-time checks in tick()
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Allocations of at least this many elements in tick() are reported
+pub const LARGE_ALLOCATION: u64 = 64 * 1024;
+
+/// Code in a node's tick() that breaks its real-time budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TickHazardKind {
+    /// `thread::sleep`, `time.sleep`
+    Sleep,
+    /// File, socket, stdin or subprocess I/O, joining threads
+    BlockingIo,
+    /// `loop` / `while True` without a way out
+    UnboundedLoop,
+    /// Buffers of [`LARGE_ALLOCATION`] elements or more
+    LargeAllocation,
+}
+
+impl TickHazardKind {
+    /// Rule name accepted by `horus:allow(...)` comments
+    pub fn id(self) -> &'static str {
+        match self {
+            TickHazardKind::Sleep => "tick-sleep",
+            TickHazardKind::BlockingIo => "tick-blocking-io",
+            TickHazardKind::UnboundedLoop => "tick-unbounded-loop",
+            TickHazardKind::LargeAllocation => "tick-large-allocation",
+        }
+    }
+
+    fn from_id(id: &str) -> Option<Self> {
+        [
+            TickHazardKind::Sleep,
+            TickHazardKind::BlockingIo,
+            TickHazardKind::UnboundedLoop,
+            TickHazardKind::LargeAllocation,
+        ]
+        .into_iter()
+        .find(|kind| kind.id() == id)
+    }
+}
+
+/// Hazard found in a tick() body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TickHazard {
+    pub kind: TickHazardKind,
+    pub file: PathBuf,
+    pub line: usize,
+    pub message: String,
+}
+
+/// Find sleeps, blocking I/O, unbounded loops and large allocations in the
+/// `tick` of `impl Node for ...` blocks and `node!` invocations
+///
+/// Closures and nested items are not inspected: work handed to a spawned
+/// thread does not run in the tick. A hazard is suppressed by a
+/// `// horus:allow(tick-sleep)` comment on its line, the line above it or
+/// the `fn tick` line.
+pub fn scan_rust_tick_hazards(content: &str, file: &Path) -> Vec<TickHazard> {
+    let Ok(ast) = syn::parse_file(content) else {
+        return Vec::new();
+    };
+    let mut finder = TickFinder::default();
+    finder.visit_file(&ast);
+
+    let mut hazards = Vec::new();
+    for (tick_line, body) in &finder.ticks {
+        let mut visitor = TickHazardVisitor::default();
+        visitor.visit_block(body);
+        for (kind, line, message) in visitor.hazards {
+            if !is_suppressed(content, kind, &[line, *tick_line]) {
+                hazards.push(TickHazard {
+                    kind,
+                    file: file.to_path_buf(),
+                    line,
+                    message,
+                });
+            }
+        }
+    }
+    hazards.sort_by_key(|hazard| hazard.line);
+    hazards
+}
+
+/// Python version of [`scan_rust_tick_hazards`], using the `ast` module
+///
+/// Tick functions are the functions named `tick` and the functions passed as
+/// `tick=` to `Node(...)`. Returns nothing when python3 is not installed or
+/// the file does not parse; the syntax check reports those.
+pub fn python_tick_hazards(content: &str, file: &Path) -> Vec<TickHazard> {
+    #[derive(Deserialize)]
+    struct Found {
+        rule: String,
+        line: usize,
+        tick_line: usize,
+        message: String,
+    }
+
+    let Ok(mut child) = Command::new("python3")
+        .arg("-c")
+        .arg(PYTHON_TICK_SCRIPT)
+        .arg(LARGE_ALLOCATION.to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+    else {
+        return Vec::new();
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        let _ = stdin.write_all(content.as_bytes());
+    }
+    let Ok(output) = child.wait_with_output() else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+
+    let found: Vec<Found> = serde_json::from_slice(&output.stdout).unwrap_or_default();
+    let mut hazards: Vec<TickHazard> = found
+        .into_iter()
+        .filter_map(|found| {
+            let kind = TickHazardKind::from_id(&found.rule)?;
+            (!is_suppressed(content, kind, &[found.line, found.tick_line])).then(|| TickHazard {
+                kind,
+                file: file.to_path_buf(),
+                line: found.line,
+                message: found.message,
+            })
+        })
+        .collect();
+    hazards.sort_by_key(|hazard| hazard.line);
+    hazards
+}
+
+/// Reads Python source on stdin, prints the hazards as JSON
+const PYTHON_TICK_SCRIPT: &str = r#"
+import ast, json, sys
+
+LARGE = int(sys.argv[1])
+tree = ast.parse(sys.stdin.read())
+ticks = {"tick"}
+for node in ast.walk(tree):
+    if isinstance(node, ast.keyword) and node.arg == "tick" and isinstance(node.value, ast.Name):
+        ticks.add(node.value.id)
+
+SCOPES = (ast.FunctionDef, ast.AsyncFunctionDef, ast.Lambda, ast.ClassDef)
+
+def walk(nodes):
+    stack = [n for n in nodes if not isinstance(n, SCOPES)]
+    while stack:
+        node = stack.pop()
+        yield node
+        stack.extend(c for c in ast.iter_child_nodes(node) if not isinstance(c, SCOPES))
+
+def name(func):
+    if isinstance(func, ast.Name):
+        return func.id
+    if isinstance(func, ast.Attribute):
+        base = name(func.value)
+        return base + "." + func.attr if base else func.attr
+    return ""
+
+def size(expr):
+    if isinstance(expr, ast.Constant) and type(expr.value) is int:
+        return expr.value
+    if isinstance(expr, ast.Tuple):
+        total = 1
+        for elt in expr.elts:
+            value = size(elt)
+            if value is None:
+                return None
+            total *= value
+        return total
+    if isinstance(expr, ast.BinOp) and isinstance(expr.op, (ast.Mult, ast.LShift)):
+        left, right = size(expr.left), size(expr.right)
+        if left is None or right is None:
+            return None
+        if isinstance(expr.op, ast.Mult):
+            return left * right
+        return left << right if 0 <= right < 64 else None
+    return None
+
+BLOCKING = {"open", "input", "os.system", "os.popen", "urlopen", "urllib.request.urlopen"}
+BLOCKING_PREFIXES = ("requests.", "subprocess.", "socket.create_connection")
+ARRAYS = {"zeros", "ones", "empty", "full"}
+
+found = []
+def report(rule, node, tick, message):
+    found.append({"rule": rule, "line": node.lineno, "tick_line": tick.lineno, "message": message})
+
+for tick in ast.walk(tree):
+    if not isinstance(tick, (ast.FunctionDef, ast.AsyncFunctionDef)) or tick.name not in ticks:
+        continue
+    for node in walk(tick.body):
+        if isinstance(node, ast.Call):
+            func = name(node.func)
+            last = func.rsplit(".", 1)[-1]
+            if last == "sleep" and not func.startswith("asyncio."):
+                report("tick-sleep", node, tick, "%s() sleeps inside tick() and stalls the scheduler" % func)
+            elif func in BLOCKING or func.startswith(BLOCKING_PREFIXES):
+                report("tick-blocking-io", node, tick, "%s() blocks on I/O inside tick()" % func)
+            elif node.args and (func in ("bytearray", "bytes") or (last in ARRAYS and func.startswith(("np.", "numpy.")))):
+                count = size(node.args[0])
+                if count is not None and count >= LARGE:
+                    report("tick-large-allocation", node, tick, "%s() allocates %d elements inside tick()" % (func, count))
+        elif isinstance(node, ast.BinOp) and isinstance(node.op, ast.Mult) and isinstance(node.left, ast.List):
+            count = size(node.right)
+            if count is not None and count >= LARGE:
+                report("tick-large-allocation", node, tick, "list of %d elements allocated inside tick()" % count)
+        elif isinstance(node, ast.While) and isinstance(node.test, ast.Constant) and node.test.value:
+            if not any(isinstance(n, (ast.Break, ast.Return, ast.Raise)) for n in walk(node.body)):
+                report("tick-unbounded-loop", node, tick, "'while True' without break or return inside tick() never yields to the scheduler")
+
+json.dump(found, sys.stdout)
+"#;
+
+/// Whether a `horus:allow(rule, ...)` comment on one of `lines` or the line
+/// above it suppresses `kind`
+fn is_suppressed(content: &str, kind: TickHazardKind, lines: &[usize]) -> bool {
+    let source: Vec<&str> = content.lines().collect();
+    lines
+        .iter()
+        .flat_map(|&line| [line.saturating_sub(1), line])
+        .filter_map(|line| source.get(line.checked_sub(1)?))
+        .any(|text| {
+            text.split("horus:allow(").skip(1).any(|rest| {
+                rest.split(')')
+                    .next()
+                    .unwrap_or_default()
+                    .split(',')
+                    .any(|rule| {
+                        let rule = rule.trim();
+                        rule.strip_prefix("horus/").unwrap_or(rule) == kind.id()
+                    })
+            })
+        })
+}
+
+/// Collects tick() bodies with the line of their `tick` keyword
+#[derive(Default)]
+struct TickFinder {
+    ticks: Vec<(usize, Block)>,
+}
+
+impl TickFinder {
+    /// `tick { ... }` and `tick(ctx) { ... }` sections of a `node!` invocation
+    fn scan_node_macro(&mut self, tokens: TokenStream) {
+        let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        let mut i = 0;
+        while i < tokens.len() {
+            match &tokens[i] {
+                TokenTree::Ident(ident) if ident == "tick" => {
+                    let mut body = i + 1;
+                    if matches!(tokens.get(body), Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis)
+                    {
+                        body += 1;
+                    }
+                    if let Some(TokenTree::Group(group)) = tokens.get(body) {
+                        if group.delimiter() == Delimiter::Brace {
+                            let stream = TokenStream::from(TokenTree::Group(group.clone()));
+                            if let Ok(block) = syn::parse2::<Block>(stream) {
+                                self.ticks.push((ident.span().start().line, block));
+                            }
+                            i = body;
+                        }
+                    }
+                }
+                TokenTree::Group(group) => self.scan_node_macro(group.stream()),
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for TickFinder {
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        let is_node = node
+            .trait_
+            .as_ref()
+            .and_then(|(_, path, _)| path.segments.last())
+            .is_some_and(|segment| segment.ident == "Node");
+        if is_node {
+            for item in &node.items {
+                if let ImplItem::Fn(method) = item {
+                    if method.sig.ident == "tick" {
+                        self.ticks
+                            .push((method.sig.ident.span().start().line, method.block.clone()));
+                    }
+                }
+            }
+        }
+        syn::visit::visit_item_impl(self, node);
+    }
+
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        if node.path.segments.last().is_some_and(|s| s.ident == "node") {
+            self.scan_node_macro(node.tokens.clone());
+        }
+        syn::visit::visit_macro(self, node);
+    }
+}
+
+/// Reports hazards in one tick() body
+#[derive(Default)]
+struct TickHazardVisitor {
+    hazards: Vec<(TickHazardKind, usize, String)>,
+}
+
+impl TickHazardVisitor {
+    fn report(&mut self, kind: TickHazardKind, line: usize, message: String) {
+        self.hazards.push((kind, line, message));
+    }
+
+    fn check_size(&mut self, what: &str, size: &Expr, line: usize) {
+        if let Some(count) = literal_size(size).filter(|count| *count >= LARGE_ALLOCATION) {
+            self.report(
+                TickHazardKind::LargeAllocation,
+                line,
+                format!(
+                    "{} allocates {} elements inside tick(); allocate it once when the node is created",
+                    what, count
+                ),
+            );
+        }
+    }
+}
+
+impl<'ast> Visit<'ast> for TickHazardVisitor {
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        if let Expr::Path(ExprPath { path, .. }) = &*node.func {
+            let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+            let call = segments.join("::");
+            let line = path
+                .segments
+                .last()
+                .map_or(0, |segment| segment.ident.span().start().line);
+            let names: Vec<&str> = segments.iter().map(String::as_str).collect();
+            match names.as_slice() {
+                [.., "sleep"] => self.report(
+                    TickHazardKind::Sleep,
+                    line,
+                    format!("`{}` sleeps inside tick() and stalls the scheduler", call),
+                ),
+                [.., "fs", _]
+                | [.., "File" | "OpenOptions", "open" | "create"]
+                | [.., "TcpStream" | "UnixStream", "connect"]
+                | [.., "block_on"] => self.report(
+                    TickHazardKind::BlockingIo,
+                    line,
+                    format!("`{}` blocks on I/O inside tick()", call),
+                ),
+                [.., "Vec" | "String", "with_capacity"] => {
+                    if let Some(size) = node.args.first() {
+                        self.check_size(&format!("`{}`", call), size, line);
+                    }
+                }
+                _ => {}
+            }
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        let method = node.method.to_string();
+        let blocking = match method.as_str() {
+            "read_line" | "read_to_string" | "read_to_end" => true,
+            // JoinHandle::join, Child::wait, Barrier::wait
+            "join" | "wait" => node.args.is_empty(),
+            _ => false,
+        };
+        if blocking {
+            self.report(
+                TickHazardKind::BlockingIo,
+                node.method.span().start().line,
+                format!("`.{}()` blocks inside tick()", method),
+            );
+        }
+        syn::visit::visit_expr_method_call(self, node);
+    }
+
+    fn visit_expr_loop(&mut self, node: &'ast ExprLoop) {
+        if !has_exit(&node.body) {
+            self.report(
+                TickHazardKind::UnboundedLoop,
+                node.loop_token.span.start().line,
+                "`loop` without `break` or `return` inside tick() never yields to the scheduler"
+                    .to_string(),
+            );
+        }
+        syn::visit::visit_expr_loop(self, node);
+    }
+
+    fn visit_expr_while(&mut self, node: &'ast ExprWhile) {
+        let always = matches!(
+            &*node.cond,
+            Expr::Lit(ExprLit { lit: Lit::Bool(value), .. }) if value.value
+        );
+        if always && !has_exit(&node.body) {
+            self.report(
+                TickHazardKind::UnboundedLoop,
+                node.while_token.span.start().line,
+                "`while true` without `break` or `return` inside tick() never yields to the scheduler"
+                    .to_string(),
+            );
+        }
+        syn::visit::visit_expr_while(self, node);
+    }
+
+    fn visit_expr_repeat(&mut self, node: &'ast ExprRepeat) {
+        let line = node.bracket_token.span.open().start().line;
+        self.check_size("array", &node.len, line);
+        syn::visit::visit_expr_repeat(self, node);
+    }
+
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        if node.path.segments.last().is_some_and(|s| s.ident == "vec") {
+            let repeat = |input: ParseStream| -> syn::Result<Expr> {
+                input.parse::<Expr>()?;
+                input.parse::<Token![;]>()?;
+                input.parse()
+            };
+            if let Ok(size) = repeat.parse2(node.tokens.clone()) {
+                let line = node.path.segments[0].ident.span().start().line;
+                self.check_size("`vec!`", &size, line);
+            }
+        }
+        syn::visit::visit_macro(self, node);
+    }
+
+    // Closures and nested items do not run as part of the tick
+    fn visit_expr_closure(&mut self, _: &'ast ExprClosure) {}
+
+    fn visit_item(&mut self, _: &'ast syn::Item) {}
+}
+
+/// Whether a loop body contains `break`, `return` or `?`
+fn has_exit(body: &Block) -> bool {
+    #[derive(Default)]
+    struct ExitFinder(bool);
+
+    impl<'ast> Visit<'ast> for ExitFinder {
+        fn visit_expr(&mut self, node: &'ast Expr) {
+            if matches!(node, Expr::Break(_) | Expr::Return(_) | Expr::Try(_)) {
+                self.0 = true;
+            }
+            syn::visit::visit_expr(self, node);
+        }
+
+        fn visit_expr_closure(&mut self, _: &'ast ExprClosure) {}
+    }
+
+    let mut finder = ExitFinder::default();
+    finder.visit_block(body);
+    finder.0
+}
+
+/// Value of an integer literal, or of a product or shift of literals
+fn literal_size(expr: &Expr) -> Option<u64> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(int), ..
+        }) => int.base10_parse().ok(),
+        Expr::Paren(paren) => literal_size(&paren.expr),
+        Expr::Group(group) => literal_size(&group.expr),
+        Expr::Binary(binary) => {
+            let (left, right) = (literal_size(&binary.left)?, literal_size(&binary.right)?);
+            match binary.op {
+                BinOp::Mul(_) => left.checked_mul(right),
+                BinOp::Shl(_) => left.checked_shl(u32::try_from(right).ok()?),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_topic_registry_check() {
+        let rust = r#"
+            topics! {
+                /// Front lidar
+                "sensors.lidar": LaserScan,
+                "motors.cmd_vel": horus::CmdVel,
+                "map": Vec<u8>,
+            }
+
+            fn setup() {
+                let scans: Hub<LaserScan> = Hub::new("sensors.lidar@router").unwrap();
+                let cmd = Hub::<Twist>::new("motors.cmd_vel").unwrap();
+                let typo = Hub::new_with_capacity("sensor.lidar", 64).unwrap();
+            }
+
+            node! {
+                Planner {
+                    pub { cmd: CmdVel -> "motors.cmd_vel" }
+                    sub { map: Vec<u8> -> "map" }
+                    tick {}
+                }
+            }
+        "#;
+        let python = r#"
+hub = Hub(CmdVel, endpoint="motors.cmd_vel")
+node = Node(subs={"sensors.lidar": {"type": horus.Imu}})
+"#;
+
+        let scan = scan_rust_topics(rust, Path::new("main.rs"));
+        assert_eq!(scan.registry.len(), 3);
+        assert_eq!(scan.registry[1].type_name, "horus::CmdVel");
+        assert_eq!(scan.registry[2].type_name, "Vec<u8>");
+        assert_eq!(scan.registry[0].line, 4);
+        assert_eq!(scan.usages.len(), 5);
+
+        let mut usages = scan.usages;
+        usages.extend(python_topic_usages(python, Path::new("main.py")));
+        assert_eq!(usages.len(), 7);
+
+        let issues = check_topic_usages(&scan.registry, &usages);
+        assert_eq!(issues.len(), 3);
+        assert!(matches!(
+            &issues[0],
+            TopicIssue::TypeMismatch { usage, registered }
+                if usage.type_name.as_deref() == Some("Twist") && registered == "horus::CmdVel"
+        ));
+        assert!(matches!(
+            &issues[1],
+            TopicIssue::Unregistered { usage, suggestion }
+                if usage.topic == "sensor.lidar" && usage.line == 12
+                    && suggestion.as_deref() == Some("sensors.lidar")
+        ));
+        assert!(matches!(
+            &issues[2],
+            TopicIssue::TypeMismatch { usage, .. } if usage.file == Path::new("main.py") && usage.line == 3
+        ));
+    }
+
+    #[test]
+    fn test_rust_tick_hazards() {
+        let rust = r#"
+            impl Node for Camera {
+                fn tick(&mut self, _ctx: Option<&mut NodeInfo>) {
+                    std::thread::sleep(Duration::from_millis(5));
+                    let config = fs::read_to_string("camera.toml").unwrap();
+                    let frame = vec![0u8; 1920 * 1080];
+                    let small = [0.0f32; 16];
+                    std::thread::spawn(|| std::thread::sleep(Duration::from_secs(1)));
+                    // horus:allow(tick-blocking-io)
+                    let file = File::open("calibration.bin").unwrap();
+                    loop {
+                        if self.hub.recv(None).is_none() {
+                            break;
+                        }
+                    }
+                }
+
+                fn init(&mut self, _ctx: &mut NodeInfo) -> Result<()> {
+                    std::thread::sleep(Duration::from_secs(1));
+                    Ok(())
+                }
+            }
+
+            node! {
+                Spinner {
+                    tick(ctx) {
+                        loop {
+                            self.count += 1;
+                        }
+                    }
+                }
+            }
+        "#;
+
+        let hazards = scan_rust_tick_hazards(rust, Path::new("main.rs"));
+        let found: Vec<_> = hazards.iter().map(|h| (h.kind, h.line)).collect();
+        assert_eq!(
+            found,
+            [
+                (TickHazardKind::Sleep, 4),
+                (TickHazardKind::BlockingIo, 5),
+                (TickHazardKind::LargeAllocation, 6),
+                (TickHazardKind::UnboundedLoop, 27),
+            ]
+        );
+        assert!(hazards[2].message.contains("2073600"));
+
+        // Suppressing on the tick line covers the whole body
+        let suppressed = rust.replace(
+            "tick(ctx) {",
+            "tick(ctx) { // horus:allow(horus/tick-unbounded-loop)",
+        );
+        assert_eq!(
+            scan_rust_tick_hazards(&suppressed, Path::new("main.rs")).len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_python_tick_hazards() {
+        if Command::new("python3").arg("--version").output().is_err() {
+            return;
+        }
+        let python = r#"
+import time
+import numpy as np
+
+def process(node):
+    time.sleep(0.01)  # horus:allow(tick-sleep)
+    scratch = np.zeros((512, 512))
+    while True:
+        pass
+
+    def later():
+        time.sleep(1)
+
+class Logger(horus.Node):
+    def tick(self, info=None):
+        with open("log.txt", "a") as f:
+            f.write("tick")
+
+node = horus.Node(tick=process, rate=30)
+"#;
+
+        let hazards = python_tick_hazards(python, Path::new("main.py"));
+        let found: Vec<_> = hazards.iter().map(|h| (h.kind, h.line)).collect();
+        assert_eq!(
+            found,
+            [
+                (TickHazardKind::LargeAllocation, 7),
+                (TickHazardKind::UnboundedLoop, 8),
+                (TickHazardKind::BlockingIo, 16),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_topic_graph() {
+        let rust = r#"
+            struct Camera {
+                frames: Hub<Image>,
+                status: Hub<Status>,
+            }
+
+            impl Camera {
+                fn new() -> Result<Self> {
+                    Ok(Self {
+                        frames: Hub::new("camera.frames")?,
+                        status: Hub::new("camera.status@10.0.0.2")?,
+                    })
+                }
+
+                fn tick(&mut self) {
+                    self.frames.send(self.capture(), &mut None).ok();
+                    self.status.send(Status::Ok, &mut None).ok();
+                }
+            }
+
+            fn main() {
+                let odom: Hub<Odometry> = Hub::new("odom").unwrap();
+                while let Some(msg) = odom.recv(&mut None) {}
+            }
+
+            node! {
+                Planner {
+                    pub { cmd: CmdVel -> "cmd_vel" }
+                    sub { frames: Image -> "camera.frames" }
+                    tick {}
+                }
+            }
+        "#;
+        let python = r#"
+node = horus.Node(subs=["cmd_vel", "lidar"], pubs={"camera.frames": {"type": horus.Imu}}, tick=f)
+"#;
+
+        let mut usages = scan_rust_topics(rust, Path::new("main.rs")).usages;
+        let roles: Vec<_> = usages.iter().map(|u| (u.topic.as_str(), u.role)).collect();
+        assert_eq!(
+            roles,
+            [
+                ("camera.frames", Some(TopicRole::Publish)),
+                ("camera.status", Some(TopicRole::Publish)),
+                ("odom", Some(TopicRole::Subscribe)),
+                ("cmd_vel", Some(TopicRole::Publish)),
+                ("camera.frames", Some(TopicRole::Subscribe)),
+            ]
+        );
+        assert!(usages[1].remote);
+        usages.extend(python_topic_usages(python, Path::new("main.py")));
+        assert_eq!(usages.len(), 8);
+
+        let issues = check_topic_graph(&[], &usages);
+        assert_eq!(issues.len(), 3);
+        assert!(matches!(
+            &issues[0],
+            GraphIssue::TypeConflict { first, other }
+                if first.type_name.as_deref() == Some("Image") && other.type_name.as_deref() == Some("Imu")
+        ));
+        assert!(matches!(
+            &issues[1],
+            GraphIssue::NoPublisher { subscriber } if subscriber.topic == "lidar"
+        ));
+        assert!(matches!(
+            &issues[2],
+            GraphIssue::NoPublisher { subscriber } if subscriber.topic == "odom" && subscriber.line == 22
+        ));
+
+        // Topics marked external in horus.yaml come from outside the project
+        let manifest = "name: robot\ntopics:\n  lidar: {type: LaserScan, external: true}\n  \"odom\": Odometry\n";
+        let registry = manifest_topics(manifest, Path::new("horus.yaml"));
+        assert_eq!(registry.len(), 2);
+        assert!(registry[0].external && registry[0].line == 3);
+        assert_eq!(
+            (registry[1].type_name.as_str(), registry[1].line),
+            ("Odometry", 4)
+        );
+        let issues = check_topic_graph(&registry, &usages);
+        assert_eq!(issues.len(), 2);
+        assert!(
+            matches!(&issues[1], GraphIssue::NoPublisher { subscriber } if subscriber.topic == "odom")
+        );
+    }
+
+    #[test]
+    fn test_normalize_type() {
+        assert_eq!(normalize_type("::horus::msgs::CmdVel"), "CmdVel");
+        assert_eq!(normalize_type("std::vec::Vec<horus::Pose>"), "Vec<Pose>");
+        assert_eq!(normalize_type("[f32; 3]"), "[f32;3]");
+    }
+}
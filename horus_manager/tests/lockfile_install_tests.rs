@@ -0,0 +1,191 @@
+// Lockfile install tests against a local mock registry: `horus pkg update`
+// writes archive checksums, and installs from horus.lock reject archives that
+// don't match them
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use horus_manager::lockfile::{LockedDependency, Lockfile};
+use horus_manager::registry::RegistryClient;
+use horus_manager::workspace::InstallTarget;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
+/// Archives served by the mock registry, by package name
+type Archives = Arc<Mutex<HashMap<String, Vec<u8>>>>;
+
+/// Mock registry shared by all tests of this file
+///
+/// HOME and HORUS_REGISTRY_URL are process-wide, so they are set once.
+fn registry() -> &'static Archives {
+    static REGISTRY: OnceLock<Archives> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let home = tempfile::tempdir().unwrap().keep();
+        std::env::set_var("HOME", &home);
+
+        let archives: Archives = Arc::default();
+        let app = Router::new()
+            .route("/api/packages/:name/versions", get(versions_handler))
+            .route(
+                "/api/packages/:name/:version/download",
+                get(download_handler),
+            )
+            .with_state(archives.clone());
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+        std::env::set_var(
+            "HORUS_REGISTRY_URL",
+            format!("http://{}", listener.local_addr().unwrap()),
+        );
+        std::thread::spawn(move || {
+            let runtime = tokio::runtime::Runtime::new().unwrap();
+            runtime.block_on(async {
+                let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+                axum::serve(listener, app).await.unwrap();
+            });
+        });
+        archives
+    })
+}
+
+async fn versions_handler(
+    State(archives): State<Archives>,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    if archives.lock().unwrap().contains_key(&name) {
+        Ok(Json(serde_json::json!({ "versions": ["1.0.0"] })))
+    } else {
+        Err(StatusCode::NOT_FOUND)
+    }
+}
+
+async fn download_handler(
+    State(archives): State<Archives>,
+    Path((name, _version)): Path<(String, String)>,
+) -> impl IntoResponse {
+    archives
+        .lock()
+        .unwrap()
+        .get(&name)
+        .cloned()
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+/// Gzipped tarball holding one README
+fn package_archive(contents: &str) -> Vec<u8> {
+    let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+        Vec::new(),
+        flate2::Compression::default(),
+    ));
+    let mut header = tar::Header::new_gnu();
+    header.set_size(contents.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    builder
+        .append_data(&mut header, "README.md", contents.as_bytes())
+        .unwrap();
+    builder.into_inner().unwrap().finish().unwrap()
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+fn project_with_dependency(name: &str) -> tempfile::TempDir {
+    let project = tempfile::tempdir().unwrap();
+    std::fs::write(
+        project.path().join("horus.yaml"),
+        format!("name: robot\ndependencies:\n  {}: \"1.0.0\"\n", name),
+    )
+    .unwrap();
+    project
+}
+
+#[test]
+fn test_pkg_update_locks_archive_checksum() {
+    let archive = package_archive("lidar driver");
+    registry()
+        .lock()
+        .unwrap()
+        .insert("horus_lock_update".to_string(), archive.clone());
+    let project = project_with_dependency("horus_lock_update");
+
+    RegistryClient::new()
+        .update_lockfile(project.path(), None)
+        .unwrap();
+
+    let lockfile = Lockfile::load(project.path()).unwrap().unwrap();
+    let locked = lockfile.get("horus_lock_update").unwrap();
+    assert_eq!(locked.version, "1.0.0");
+    assert_eq!(
+        locked.checksum.as_deref(),
+        Some(sha256_hex(&archive).as_str())
+    );
+    assert!(project
+        .path()
+        .join(".horus/packages/horus_lock_update/README.md")
+        .exists());
+}
+
+#[test]
+fn test_locked_install_rejects_tampered_archive() {
+    let archive = package_archive("planner");
+    let locked = LockedDependency {
+        name: "horus_lock_tampered".to_string(),
+        version: "1.0.0".to_string(),
+        checksum: Some(sha256_hex(&archive)),
+    };
+    registry().lock().unwrap().insert(
+        "horus_lock_tampered".to_string(),
+        package_archive("planner with a backdoor"),
+    );
+    let project = project_with_dependency("horus_lock_tampered");
+
+    let err = RegistryClient::new()
+        .install_locked(
+            "horus_lock_tampered",
+            &locked,
+            InstallTarget::Local(project.path().to_path_buf()),
+        )
+        .unwrap_err();
+
+    assert!(err.to_string().contains("Checksum"), "{}", err);
+    assert!(!project
+        .path()
+        .join(".horus/packages/horus_lock_tampered")
+        .exists());
+}
+
+#[test]
+fn test_locked_install_requires_checksum() {
+    registry()
+        .lock()
+        .unwrap()
+        .insert("horus_lock_unchecked".to_string(), package_archive("imu"));
+    let project = project_with_dependency("horus_lock_unchecked");
+    let locked = LockedDependency {
+        name: "horus_lock_unchecked".to_string(),
+        version: "1.0.0".to_string(),
+        checksum: None,
+    };
+
+    let err = RegistryClient::new()
+        .install_locked(
+            "horus_lock_unchecked",
+            &locked,
+            InstallTarget::Local(project.path().to_path_buf()),
+        )
+        .unwrap_err();
+
+    assert!(err.to_string().contains("no checksum"), "{}", err);
+    assert!(!project
+        .path()
+        .join(".horus/packages/horus_lock_unchecked")
+        .exists());
+}
@@ -0,0 +1,171 @@
+// Versioned monitor API tests: authentication, schema and parameter updates
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Request, StatusCode},
+    Router,
+};
+use horus_core::params::RuntimeParams;
+use horus_manager::monitor::AppState;
+use horus_manager::monitor_api::{api_v1_routes, API_VERSION};
+use horus_manager::security::auth::{hash_password, AuthService};
+use serde_json::Value;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+fn create_state(auth_disabled: bool) -> Arc<AppState> {
+    let hash = hash_password("robot-secret").unwrap();
+    Arc::new(AppState {
+        port: 0,
+        params: Arc::new(RuntimeParams::default()),
+        auth_service: Arc::new(AuthService::new(hash).unwrap()),
+        current_workspace: None,
+        auth_disabled,
+    })
+}
+
+fn app(state: Arc<AppState>) -> Router {
+    api_v1_routes(state.clone()).with_state(state)
+}
+
+async fn send(app: Router, request: Request<Body>) -> (StatusCode, Value) {
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+fn put_param(key: &str, body: Value) -> Request<Body> {
+    Request::builder()
+        .method("PUT")
+        .uri(format!("/api/v1/params/{}", key))
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_api_requires_token_when_password_set() {
+    let state = create_state(false);
+
+    for uri in [
+        "/api/v1",
+        "/api/v1/nodes",
+        "/api/v1/params",
+        "/api/v1/stream",
+    ] {
+        let (status, _) = send(
+            app(state.clone()),
+            Request::builder().uri(uri).body(Body::empty()).unwrap(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED, "{}", uri);
+    }
+
+    let (status, _) = send(
+        app(state.clone()),
+        Request::builder()
+            .uri("/api/v1")
+            .header(header::AUTHORIZATION, "Bearer horus_not-a-token")
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_api_accepts_session_token() {
+    let state = create_state(false);
+    let token = state
+        .auth_service
+        .login("robot-secret", None)
+        .unwrap()
+        .unwrap();
+
+    let (status, body) = send(
+        app(state.clone()),
+        Request::builder()
+            .uri("/api/v1")
+            .header(header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["api_version"], API_VERSION);
+
+    let (status, _) = send(
+        app(state),
+        Request::builder()
+            .uri(format!("/api/v1/params?access_token={}", token))
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_api_params_versioned_update() {
+    let state = create_state(true);
+
+    let (status, body) = send(
+        app(state.clone()),
+        put_param("max_speed", serde_json::json!({ "value": 1.5 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let version = body["version"].as_u64().unwrap();
+
+    let (status, body) = send(
+        app(state.clone()),
+        Request::builder()
+            .uri("/api/v1/params/max_speed")
+            .body(Body::empty())
+            .unwrap(),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["value"], 1.5);
+
+    // Writing with an outdated version is rejected
+    let (status, body) = send(
+        app(state.clone()),
+        put_param(
+            "max_speed",
+            serde_json::json!({ "value": 2.0, "version": version + 1 }),
+        ),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+    assert!(body["error"].is_string());
+
+    let (status, _) = send(
+        app(state),
+        put_param(
+            "max_speed",
+            serde_json::json!({ "value": 2.0, "version": version }),
+        ),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_api_unknown_param_and_node() {
+    let state = create_state(true);
+
+    for uri in [
+        "/api/v1/params/does_not_exist",
+        "/api/v1/nodes/does_not_exist",
+    ] {
+        let (status, body) = send(
+            app(state.clone()),
+            Request::builder().uri(uri).body(Body::empty()).unwrap(),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND, "{}", uri);
+        assert!(body["error"].is_string());
+    }
+}
@@ -0,0 +1,106 @@
+// Monitor agent tests: the agent only answers authenticated API requests
+
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, Request, StatusCode},
+};
+use horus_core::params::RuntimeParams;
+use horus_manager::monitor::AppState;
+use horus_manager::monitor_remote::agent_router;
+use horus_manager::security::auth::{hash_password, AuthService};
+use serde_json::Value;
+use std::sync::Arc;
+use tower::ServiceExt;
+
+fn create_agent_state() -> Arc<AppState> {
+    let hash = hash_password("robot-secret").unwrap();
+    Arc::new(AppState {
+        port: 0,
+        params: Arc::new(RuntimeParams::default()),
+        auth_service: Arc::new(AuthService::new(hash).unwrap()),
+        current_workspace: None,
+        auth_disabled: false,
+    })
+}
+
+async fn login(state: Arc<AppState>, password: &str) -> (StatusCode, Value) {
+    let response = agent_router(state)
+        .oneshot(
+            Request::builder()
+                .method("POST")
+                .uri("/api/login")
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(
+                    serde_json::json!({ "password": password }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap())
+}
+
+#[tokio::test]
+async fn test_agent_rejects_requests_without_session() {
+    let app = agent_router(create_agent_state());
+
+    for uri in ["/api/nodes", "/api/topics", "/api/ws"] {
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED, "{}", uri);
+    }
+}
+
+#[tokio::test]
+async fn test_agent_login_and_bearer_token() {
+    let state = create_agent_state();
+
+    let (status, body) = login(state.clone(), "wrong").await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+    assert_eq!(body["success"], false);
+
+    let (status, body) = login(state.clone(), "robot-secret").await;
+    assert_eq!(status, StatusCode::OK);
+    let token = body["session_token"].as_str().unwrap().to_string();
+
+    let response = agent_router(state)
+        .oneshot(
+            Request::builder()
+                .uri("/api/status")
+                .header(header::AUTHORIZATION, format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_agent_serves_no_dashboard_or_package_management() {
+    let state = create_agent_state();
+    let (_, body) = login(state.clone(), "robot-secret").await;
+    let token = body["session_token"].as_str().unwrap().to_string();
+    let app = agent_router(state);
+
+    for (method, uri) in [("GET", "/"), ("POST", "/api/packages/install")] {
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .header(header::AUTHORIZATION, format!("Bearer {}", token))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND, "{}", uri);
+    }
+}
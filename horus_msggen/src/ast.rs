@@ -0,0 +1,116 @@
+//! Message definition model shared by the `.msg` and IDL front ends
+
+/// Primitive field types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Primitive {
+    Bool,
+    I8,
+    U8,
+    I16,
+    U16,
+    I32,
+    U32,
+    I64,
+    U64,
+    F32,
+    F64,
+}
+
+impl Primitive {
+    /// Rust spelling of the type
+    pub fn rust_type(self) -> &'static str {
+        match self {
+            Primitive::Bool => "bool",
+            Primitive::I8 => "i8",
+            Primitive::U8 => "u8",
+            Primitive::I16 => "i16",
+            Primitive::U16 => "u16",
+            Primitive::I32 => "i32",
+            Primitive::U32 => "u32",
+            Primitive::I64 => "i64",
+            Primitive::U64 => "u64",
+            Primitive::F32 => "f32",
+            Primitive::F64 => "f64",
+        }
+    }
+
+    /// Plain-old-data primitives (`bool` has invalid bit patterns)
+    pub fn is_pod(self) -> bool {
+        self != Primitive::Bool
+    }
+}
+
+/// Type of a message field
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldType {
+    Primitive(Primitive),
+    /// UTF-8 string, optionally bounded in bytes
+    String {
+        bound: Option<usize>,
+    },
+    /// Fixed-size array `T[N]`
+    Array(Box<FieldType>, usize),
+    /// Variable-length sequence `T[]` / `T[<=N]`
+    Sequence {
+        element: Box<FieldType>,
+        bound: Option<usize>,
+    },
+    /// Another message type (package qualifier stripped)
+    Named(String),
+}
+
+/// A named field of a message
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDef {
+    pub name: String,
+    pub ty: FieldType,
+    /// Default value as written in the source, if any
+    pub default: Option<String>,
+    pub doc: Vec<String>,
+}
+
+/// A constant declared alongside a message
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstantDef {
+    pub name: String,
+    pub ty: FieldType,
+    /// Value as written in the source
+    pub value: String,
+    pub doc: Vec<String>,
+}
+
+/// A parsed message definition
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageDef {
+    pub name: String,
+    /// Package or outermost IDL module, if known
+    pub package: Option<String>,
+    pub doc: Vec<String>,
+    pub fields: Vec<FieldDef>,
+    pub constants: Vec<ConstantDef>,
+}
+
+/// Rust keywords that must be emitted as raw identifiers
+const RUST_KEYWORDS: &[&str] = &[
+    "as", "async", "await", "box", "break", "const", "continue", "do", "dyn", "else", "enum",
+    "extern", "false", "final", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "macro",
+    "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static", "struct",
+    "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual", "where",
+    "while", "yield",
+];
+
+/// True if `name` is a valid identifier in message definitions
+pub fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Spell `name` as a Rust identifier, escaping keywords
+pub fn rust_ident(name: &str) -> String {
+    if RUST_KEYWORDS.contains(&name) {
+        format!("r#{}", name)
+    } else {
+        name.to_string()
+    }
+}
@@ -0,0 +1,347 @@
+//! Rust code generation
+//!
+//! Each message becomes a `message!` definition. With zero-copy output
+//! enabled, messages whose layout is fixed (POD primitives, arrays of at most
+//! 32 elements, bounded strings of at most 255 bytes, other zero-copy
+//! messages) become `zero_copy_message!` definitions instead, with bounded
+//! strings mapped to `fixed_string!` types.
+
+use crate::ast::{rust_ident, FieldType, MessageDef, Primitive};
+use crate::{Error, Result};
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::Write;
+
+/// Largest array `zero_copy_message!` can default-initialize and serde can derive
+const MAX_FIXED_ARRAY: usize = 32;
+
+/// Largest `FixedString` (length is stored in a `u8`)
+const MAX_FIXED_STRING: usize = 255;
+
+/// Code generation options
+#[derive(Debug, Clone, Default)]
+pub struct GenerateOptions {
+    /// Emit `zero_copy_message!` for messages with a fixed layout
+    ///
+    /// Zero-copy messages are meant for recordings and raw byte transport;
+    /// they do not implement `Deserialize` and therefore cannot be used with
+    /// `Hub`. Off by default.
+    pub zero_copy: bool,
+}
+
+/// Generate Rust source for a set of message definitions
+pub fn generate(messages: &[MessageDef], options: &GenerateOptions) -> Result<String> {
+    let mut seen = HashSet::new();
+    for msg in messages {
+        if !seen.insert(msg.name.as_str()) {
+            return Err(Error::Invalid(format!(
+                "message '{}' is defined more than once",
+                msg.name
+            )));
+        }
+    }
+
+    let zero_copy = if options.zero_copy {
+        zero_copy_set(messages)
+    } else {
+        HashSet::new()
+    };
+
+    let mut out = String::new();
+    out.push_str("// @generated by horus_msggen. Do not edit by hand.\n");
+
+    let fixed_strings: BTreeSet<usize> = messages
+        .iter()
+        .filter(|m| zero_copy.contains(m.name.as_str()))
+        .flat_map(|m| m.fields.iter().filter_map(|f| fixed_string_bound(&f.ty)))
+        .collect();
+    for size in &fixed_strings {
+        let _ = write!(out, "\n::horus::fixed_string!({});\n", size);
+    }
+
+    for msg in messages {
+        let is_zero_copy = zero_copy.contains(msg.name.as_str());
+        out.push('\n');
+        generate_message(&mut out, msg, is_zero_copy);
+        generate_constants(&mut out, msg)?;
+    }
+
+    Ok(out)
+}
+
+/// Messages that can be emitted as `zero_copy_message!`
+fn zero_copy_set(messages: &[MessageDef]) -> HashSet<&str> {
+    let mut set: HashSet<&str> = messages
+        .iter()
+        .filter(|m| !m.fields.is_empty())
+        .map(|m| m.name.as_str())
+        .collect();
+
+    // Iterate to a fixed point: a message stays zero-copy only if its fields
+    // are fixed-size, and only if no serde message embeds it (zero-copy types
+    // don't implement Deserialize).
+    loop {
+        let before = set.len();
+        let snapshot = set.clone();
+        set.retain(|name| {
+            let msg = messages.iter().find(|m| m.name == *name);
+            msg.is_some_and(|m| m.fields.iter().all(|f| is_fixed(&f.ty, &snapshot)))
+                && !messages.iter().any(|m| {
+                    !snapshot.contains(m.name.as_str())
+                        && m.fields.iter().any(|f| references(&f.ty, name))
+                })
+        });
+        if set.len() == before {
+            return set;
+        }
+    }
+}
+
+fn is_fixed(ty: &FieldType, zero_copy: &HashSet<&str>) -> bool {
+    match ty {
+        FieldType::Primitive(p) => p.is_pod(),
+        FieldType::String { bound } => bound.is_some_and(|n| n <= MAX_FIXED_STRING),
+        FieldType::Array(element, n) => *n <= MAX_FIXED_ARRAY && is_fixed(element, zero_copy),
+        FieldType::Sequence { .. } => false,
+        FieldType::Named(name) => zero_copy.contains(name.as_str()),
+    }
+}
+
+fn references(ty: &FieldType, name: &str) -> bool {
+    match ty {
+        FieldType::Named(n) => n == name,
+        FieldType::Array(element, _) => references(element, name),
+        FieldType::Sequence { element, .. } => references(element, name),
+        _ => false,
+    }
+}
+
+fn fixed_string_bound(ty: &FieldType) -> Option<usize> {
+    match ty {
+        FieldType::String { bound } => *bound,
+        FieldType::Array(element, _) => fixed_string_bound(element),
+        _ => None,
+    }
+}
+
+/// Rust type of a field
+fn rust_type(ty: &FieldType, zero_copy: bool) -> String {
+    match ty {
+        FieldType::Primitive(p) => p.rust_type().to_string(),
+        FieldType::String { bound: Some(n) } if zero_copy => format!("FixedString{}", n),
+        FieldType::String { .. } => "String".to_string(),
+        // serde only derives arrays up to 32 elements; longer ones become Vec
+        FieldType::Array(element, n) if zero_copy || *n <= MAX_FIXED_ARRAY => {
+            format!("[{}; {}]", rust_type(element, zero_copy), n)
+        }
+        FieldType::Array(element, _) | FieldType::Sequence { element, .. } => {
+            format!("Vec<{}>", rust_type(element, zero_copy))
+        }
+        FieldType::Named(name) => name.clone(),
+    }
+}
+
+/// Notes about constraints the Rust type doesn't express
+fn type_notes(ty: &FieldType, zero_copy: bool) -> Vec<String> {
+    match ty {
+        FieldType::String { bound: Some(n) } if !zero_copy => {
+            vec![format!("At most {} bytes.", n)]
+        }
+        FieldType::Array(_, n) if !zero_copy && *n > MAX_FIXED_ARRAY => {
+            vec![format!("Exactly {} elements.", n)]
+        }
+        FieldType::Sequence { bound: Some(n), .. } => vec![format!("At most {} elements.", n)],
+        _ => Vec::new(),
+    }
+}
+
+fn write_doc(out: &mut String, indent: &str, lines: &[String]) {
+    for line in lines {
+        if line.is_empty() {
+            let _ = writeln!(out, "{}///", indent);
+        } else {
+            let _ = writeln!(out, "{}/// {}", indent, line);
+        }
+    }
+}
+
+fn generate_message(out: &mut String, msg: &MessageDef, zero_copy: bool) {
+    let macro_name = if zero_copy {
+        "zero_copy_message"
+    } else {
+        "message"
+    };
+    let _ = writeln!(out, "::horus::{}! {{", macro_name);
+
+    let mut doc = msg.doc.clone();
+    if let Some(package) = &msg.package {
+        if !doc.is_empty() {
+            doc.push(String::new());
+        }
+        doc.push(format!("Generated from `{}/{}`.", package, msg.name));
+    }
+    write_doc(out, "    ", &doc);
+    let _ = writeln!(out, "    {} {{", msg.name);
+
+    for field in &msg.fields {
+        let mut doc = field.doc.clone();
+        doc.extend(type_notes(&field.ty, zero_copy));
+        if let Some(default) = &field.default {
+            doc.push(format!("Default: `{}`", default));
+        }
+        write_doc(out, "        ", &doc);
+        let _ = writeln!(
+            out,
+            "        {}: {},",
+            rust_ident(&field.name),
+            rust_type(&field.ty, zero_copy)
+        );
+    }
+
+    out.push_str("    }\n}\n");
+}
+
+fn generate_constants(out: &mut String, msg: &MessageDef) -> Result<()> {
+    if msg.constants.is_empty() {
+        return Ok(());
+    }
+
+    let _ = writeln!(out, "\nimpl {} {{", msg.name);
+    for constant in &msg.constants {
+        let (ty, value) = match &constant.ty {
+            FieldType::Primitive(p) => (p.rust_type(), constant_literal(*p, &constant.value)),
+            FieldType::String { .. } => ("&'static str", Some(format!("{:?}", constant.value))),
+            // Both parsers reject constants of other types
+            _ => ("", None),
+        };
+        let value = value.ok_or_else(|| {
+            Error::Invalid(format!(
+                "invalid value '{}' for constant {}::{}",
+                constant.value, msg.name, constant.name
+            ))
+        })?;
+        write_doc(out, "    ", &constant.doc);
+        let _ = writeln!(
+            out,
+            "    pub const {}: {} = {};",
+            rust_ident(&constant.name),
+            ty,
+            value
+        );
+    }
+    out.push_str("}\n");
+    Ok(())
+}
+
+/// Normalize a constant value into a Rust literal of the given type
+fn constant_literal(primitive: Primitive, value: &str) -> Option<String> {
+    let value = value.trim();
+    match primitive {
+        Primitive::Bool => match value.to_ascii_lowercase().as_str() {
+            "true" | "1" => Some("true".into()),
+            "false" | "0" => Some("false".into()),
+            _ => None,
+        },
+        Primitive::F32 | Primitive::F64 => {
+            let parsed: f64 = value.parse().ok()?;
+            if parsed.is_finite() {
+                // Rust rejects integer literals for float constants
+                Some(format!("{:?}", parsed))
+            } else {
+                None
+            }
+        }
+        _ => {
+            // Character literals (`'a'`) become their byte value
+            if let Some(c) = value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')) {
+                let mut chars = c.chars();
+                return match (chars.next(), chars.next()) {
+                    (Some(ch), None) if ch.is_ascii() => Some((ch as u8).to_string()),
+                    _ => None,
+                };
+            }
+            let (negative, digits) = match value.strip_prefix('-') {
+                Some(rest) => (true, rest),
+                None => (false, value),
+            };
+            let magnitude = match digits.strip_prefix("0x").or(digits.strip_prefix("0X")) {
+                Some(hex) => u64::from_str_radix(hex, 16).ok()?,
+                None => digits.parse::<u64>().ok()?,
+            };
+            if negative
+                && !matches!(
+                    primitive,
+                    Primitive::I8 | Primitive::I16 | Primitive::I32 | Primitive::I64
+                )
+            {
+                return None;
+            }
+            Some(format!("{}{}", if negative { "-" } else { "" }, magnitude))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::msg::parse_msg;
+
+    #[test]
+    fn test_generate_message() {
+        let def = parse_msg(
+            "BatteryState",
+            "# Pack status\n\nuint8 STATE_FULL=2\nfloat32 MAX_VOLTAGE=42\nstring type\nfloat64[36] covariance\nfloat32[<=8] cells\n",
+            "BatteryState.msg",
+        )
+        .unwrap();
+        let code = generate(&[def], &GenerateOptions::default()).unwrap();
+
+        assert!(code.contains("::horus::message! {\n    /// Pack status\n    BatteryState {"));
+        assert!(code.contains("        r#type: String,"));
+        assert!(code.contains("        /// Exactly 36 elements.\n        covariance: Vec<f64>,"));
+        assert!(code.contains("        /// At most 8 elements.\n        cells: Vec<f32>,"));
+        assert!(code.contains("    pub const STATE_FULL: u8 = 2;"));
+        assert!(code.contains("    pub const MAX_VOLTAGE: f32 = 42.0;"));
+    }
+
+    #[test]
+    fn test_generate_zero_copy() {
+        let cell = parse_msg("Cell", "float32 voltage\nstring<=16 id\n", "Cell.msg").unwrap();
+        let pack = parse_msg("Pack", "Cell[4] cells\nuint16 count\n", "Pack.msg").unwrap();
+        let log = parse_msg("Log", "Cell[] history\n", "Log.msg").unwrap();
+        let options = GenerateOptions { zero_copy: true };
+
+        // Pack embeds Cell in a fixed array, so both are zero-copy
+        let code = generate(&[cell.clone(), pack.clone()], &options).unwrap();
+        assert!(code.contains("::horus::fixed_string!(16);"));
+        assert!(code.contains("::horus::zero_copy_message! {\n    Cell {"));
+        assert!(code.contains("        id: FixedString16,"));
+        assert!(code.contains("::horus::zero_copy_message! {\n    Pack {"));
+
+        // A serde message embedding Cell forces Cell (and Pack) back to serde
+        let code = generate(&[cell, pack, log], &options).unwrap();
+        assert!(!code.contains("zero_copy_message!"));
+        assert!(code.contains("        id: String,"));
+    }
+
+    #[test]
+    fn test_constant_literals() {
+        assert_eq!(
+            constant_literal(Primitive::Bool, "True").as_deref(),
+            Some("true")
+        );
+        assert_eq!(constant_literal(Primitive::I8, "-5").as_deref(), Some("-5"));
+        assert_eq!(
+            constant_literal(Primitive::U8, "0x1F").as_deref(),
+            Some("31")
+        );
+        assert_eq!(
+            constant_literal(Primitive::U8, "'A'").as_deref(),
+            Some("65")
+        );
+        assert_eq!(
+            constant_literal(Primitive::F64, "-1.5e1").as_deref(),
+            Some("-15.0")
+        );
+        assert_eq!(constant_literal(Primitive::U8, "-1"), None);
+    }
+}
@@ -0,0 +1,673 @@
+//! OMG IDL front end
+//!
+//! Supports the IDL subset used for message definitions (including the files
+//! generated by `rosidl`): nested modules, structs, typedefs, multi-dimensional
+//! arrays, bounded and unbounded `string`/`sequence<T>`, constants placed in a
+//! `<Struct>_Constants` module, and the `@default` and `@verbatim` annotations.
+//! Unions, enums and struct inheritance are rejected.
+
+use crate::ast::{is_identifier, ConstantDef, FieldDef, FieldType, MessageDef, Primitive};
+use crate::{Error, Result};
+use std::collections::HashMap;
+
+/// Parse the contents of an IDL file into its struct definitions
+pub fn parse_idl(source: &str, origin: &str) -> Result<Vec<MessageDef>> {
+    let tokens = tokenize(source).map_err(|(line, message)| Error::Parse {
+        origin: origin.to_string(),
+        line,
+        message,
+    })?;
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        origin,
+        typedefs: HashMap::new(),
+        messages: Vec::new(),
+        constants: Vec::new(),
+        modules: Vec::new(),
+    };
+    parser.parse_definitions(false)?;
+
+    // Attach constants declared in `<Struct>_Constants` modules
+    let Parser {
+        mut messages,
+        constants,
+        ..
+    } = parser;
+    for (owner, line, constant) in constants {
+        let target = owner
+            .strip_suffix("_Constants")
+            .and_then(|name| messages.iter_mut().find(|m| m.name == name))
+            .ok_or_else(|| Error::Parse {
+                origin: origin.to_string(),
+                line,
+                message: format!(
+                    "constant '{}' must be declared in a <Struct>_Constants module",
+                    constant.name
+                ),
+            })?;
+        target.constants.push(constant);
+    }
+
+    Ok(messages)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    /// Numeric or character literal, as written
+    Literal(String),
+    /// String literal, unescaped
+    Str(String),
+    Punct(char),
+}
+
+fn tokenize(source: &str) -> std::result::Result<Vec<(Token, usize)>, (usize, String)> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut at_line_start = true;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\n' {
+            line += 1;
+            at_line_start = true;
+            i += 1;
+            continue;
+        }
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        // Preprocessor directives (#include, #pragma, ...) are skipped
+        if c == '#' && at_line_start {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+        at_line_start = false;
+
+        if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                i += 1;
+            }
+            continue;
+        }
+        if c == '/' && chars.get(i + 1) == Some(&'*') {
+            i += 2;
+            while i < chars.len() && !(chars[i] == '*' && chars.get(i + 1) == Some(&'/')) {
+                if chars[i] == '\n' {
+                    line += 1;
+                }
+                i += 1;
+            }
+            i += 2;
+            continue;
+        }
+
+        if c.is_ascii_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push((Token::Ident(chars[start..i].iter().collect()), line));
+            continue;
+        }
+
+        if c.is_ascii_digit() || (c == '.' && chars.get(i + 1).is_some_and(|d| d.is_ascii_digit()))
+        {
+            let start = i;
+            while i < chars.len()
+                && (chars[i].is_ascii_alphanumeric()
+                    || chars[i] == '.'
+                    || ((chars[i] == '-' || chars[i] == '+')
+                        && matches!(chars[i - 1], 'e' | 'E')
+                        && !chars[start..i].iter().any(|c| *c == 'x' || *c == 'X')))
+            {
+                i += 1;
+            }
+            tokens.push((Token::Literal(chars[start..i].iter().collect()), line));
+            continue;
+        }
+
+        if c == '"' || c == '\'' {
+            let quote = c;
+            let start_line = line;
+            let mut value = String::new();
+            i += 1;
+            loop {
+                match chars.get(i) {
+                    None => return Err((start_line, "unterminated literal".to_string())),
+                    Some(&ch) if ch == quote => break,
+                    Some('\\') => {
+                        let escaped = match chars.get(i + 1) {
+                            Some('n') => '\n',
+                            Some('t') => '\t',
+                            Some(&other) => other,
+                            None => return Err((start_line, "unterminated literal".to_string())),
+                        };
+                        value.push(escaped);
+                        i += 2;
+                        continue;
+                    }
+                    Some(&ch) => {
+                        if ch == '\n' {
+                            line += 1;
+                        }
+                        value.push(ch);
+                    }
+                }
+                i += 1;
+            }
+            i += 1;
+            let token = if quote == '"' {
+                Token::Str(value)
+            } else {
+                Token::Literal(format!("'{}'", value))
+            };
+            tokens.push((token, start_line));
+            continue;
+        }
+
+        if "{}()<>[],;:=@-+".contains(c) {
+            tokens.push((Token::Punct(c), line));
+            i += 1;
+            continue;
+        }
+
+        return Err((line, format!("unexpected character '{}'", c)));
+    }
+
+    Ok(tokens)
+}
+
+/// A parsed `@name(key=value, ...)` annotation
+struct Annotation {
+    name: String,
+    params: Vec<(String, Token)>,
+}
+
+impl Annotation {
+    fn param(&self, key: &str) -> Option<&Token> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == key || (key == "value" && k.is_empty()))
+            .map(|(_, v)| v)
+    }
+}
+
+struct Parser<'a> {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    origin: &'a str,
+    typedefs: HashMap<String, FieldType>,
+    messages: Vec<MessageDef>,
+    /// (enclosing module, line, constant)
+    constants: Vec<(String, usize, ConstantDef)>,
+    modules: Vec<String>,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: impl Into<String>) -> Error {
+        let line = self
+            .tokens
+            .get(self.pos)
+            .or(self.tokens.last())
+            .map(|(_, line)| *line)
+            .unwrap_or(0);
+        Error::Parse {
+            origin: self.origin.to_string(),
+            line,
+            message: message.into(),
+        }
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos).map(|(token, _)| token)
+    }
+
+    fn next(&mut self) -> Result<Token> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .map(|(token, _)| token.clone())
+            .ok_or_else(|| self.error("unexpected end of file"))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn eat_punct(&mut self, c: char) -> bool {
+        if self.peek() == Some(&Token::Punct(c)) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect_punct(&mut self, c: char) -> Result<()> {
+        if self.eat_punct(c) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected '{}'", c)))
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        if matches!(self.peek(), Some(Token::Ident(ident)) if ident == keyword) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn ident(&mut self) -> Result<String> {
+        match self.next()? {
+            Token::Ident(ident) => Ok(ident),
+            other => {
+                self.pos -= 1;
+                Err(self.error(format!("expected identifier, found {:?}", other)))
+            }
+        }
+    }
+
+    /// `a::b::C`, returning the last segment
+    fn scoped_name(&mut self) -> Result<String> {
+        self.eat_punct(':');
+        self.eat_punct(':');
+        let mut name = self.ident()?;
+        while self.peek() == Some(&Token::Punct(':')) {
+            self.expect_punct(':')?;
+            self.expect_punct(':')?;
+            name = self.ident()?;
+        }
+        Ok(name)
+    }
+
+    fn number(&mut self) -> Result<usize> {
+        match self.next()? {
+            Token::Literal(text) => text
+                .parse()
+                .map_err(|_| self.error(format!("expected a size, found '{}'", text))),
+            other => Err(self.error(format!("expected a size, found {:?}", other))),
+        }
+    }
+
+    fn annotations(&mut self) -> Result<Vec<Annotation>> {
+        let mut annotations = Vec::new();
+        while self.eat_punct('@') {
+            let name = self.scoped_name()?;
+            let mut params = Vec::new();
+            if self.eat_punct('(') {
+                while !self.eat_punct(')') {
+                    // `key=value` or a single positional value
+                    let key = match (self.peek(), self.tokens.get(self.pos + 1)) {
+                        (Some(Token::Ident(key)), Some((Token::Punct('='), _))) => {
+                            let key = key.clone();
+                            self.pos += 2;
+                            key
+                        }
+                        _ => String::new(),
+                    };
+                    let value = self.const_value()?;
+                    params.push((key, value));
+                    self.eat_punct(',');
+                }
+            }
+            annotations.push(Annotation { name, params });
+        }
+        Ok(annotations)
+    }
+
+    /// A (possibly signed) literal or identifier value
+    fn const_value(&mut self) -> Result<Token> {
+        let sign = if self.eat_punct('-') {
+            "-"
+        } else {
+            self.eat_punct('+');
+            ""
+        };
+        match self.next()? {
+            Token::Literal(text) => Ok(Token::Literal(format!("{}{}", sign, text))),
+            Token::Ident(ident) if sign.is_empty() => Ok(Token::Ident(ident)),
+            Token::Str(mut text) if sign.is_empty() => {
+                // Adjacent string literals concatenate
+                while let Some(Token::Str(more)) = self.peek() {
+                    text.push_str(more);
+                    self.pos += 1;
+                }
+                Ok(Token::Str(text))
+            }
+            other => Err(self.error(format!("expected a value, found {:?}", other))),
+        }
+    }
+
+    fn parse_definitions(&mut self, nested: bool) -> Result<()> {
+        loop {
+            if nested && self.eat_punct('}') {
+                self.expect_punct(';')?;
+                return Ok(());
+            }
+            if self.peek().is_none() {
+                return if nested {
+                    Err(self.error("unexpected end of file in module"))
+                } else {
+                    Ok(())
+                };
+            }
+
+            let annotations = self.annotations()?;
+            if self.eat_keyword("module") {
+                let name = self.ident()?;
+                self.expect_punct('{')?;
+                self.modules.push(name);
+                self.parse_definitions(true)?;
+                self.modules.pop();
+            } else if self.eat_keyword("struct") {
+                self.parse_struct(&annotations)?;
+            } else if self.eat_keyword("const") {
+                self.parse_const(&annotations)?;
+            } else if self.eat_keyword("typedef") {
+                let ty = self.parse_type()?;
+                let (name, ty) = self.declarator(ty)?;
+                self.expect_punct(';')?;
+                self.typedefs.insert(name, ty);
+            } else if let Some(Token::Ident(keyword)) = self.peek() {
+                return Err(self.error(format!("'{}' definitions are not supported", keyword)));
+            } else {
+                return Err(self.error("expected a definition"));
+            }
+        }
+    }
+
+    fn parse_struct(&mut self, annotations: &[Annotation]) -> Result<()> {
+        let name = self.ident()?;
+        if self.eat_punct(';') {
+            // Forward declaration
+            return Ok(());
+        }
+        if self.peek() == Some(&Token::Punct(':')) {
+            return Err(self.error(format!("struct inheritance is not supported ('{}')", name)));
+        }
+        self.expect_punct('{')?;
+
+        let mut fields: Vec<FieldDef> = Vec::new();
+        while !self.eat_punct('}') {
+            let member_annotations = self.annotations()?;
+            let ty = self.parse_type()?;
+            loop {
+                let (field_name, field_ty) = self.declarator(ty.clone())?;
+                if fields.iter().any(|f| f.name == field_name) {
+                    return Err(self.error(format!("duplicate field '{}'", field_name)));
+                }
+                fields.push(FieldDef {
+                    name: field_name,
+                    ty: field_ty,
+                    default: default_value(&member_annotations),
+                    doc: doc_lines(&member_annotations),
+                });
+                if !self.eat_punct(',') {
+                    break;
+                }
+            }
+            self.expect_punct(';')?;
+        }
+        self.expect_punct(';')?;
+
+        // rosidl emits empty messages with a placeholder member
+        fields.retain(|f| f.name != "structure_needs_at_least_one_member");
+
+        self.messages.push(MessageDef {
+            name,
+            package: self.modules.first().cloned(),
+            doc: doc_lines(annotations),
+            fields,
+            constants: Vec::new(),
+        });
+        Ok(())
+    }
+
+    fn parse_const(&mut self, annotations: &[Annotation]) -> Result<()> {
+        let line = self.tokens.get(self.pos).map(|(_, l)| *l).unwrap_or(0);
+        let ty = self.parse_type()?;
+        let name = self.ident()?;
+        self.expect_punct('=')?;
+        let value = match self.const_value()? {
+            Token::Literal(text) | Token::Ident(text) | Token::Str(text) => text,
+            Token::Punct(_) => unreachable!("const_value never returns punctuation"),
+        };
+        self.expect_punct(';')?;
+
+        if !matches!(ty, FieldType::Primitive(_) | FieldType::String { .. }) {
+            return Err(self.error(format!(
+                "constant '{}' must have a primitive or string type",
+                name
+            )));
+        }
+        let owner = self.modules.last().cloned().unwrap_or_default();
+        self.constants.push((
+            owner,
+            line,
+            ConstantDef {
+                name,
+                ty,
+                value,
+                doc: doc_lines(annotations),
+            },
+        ));
+        Ok(())
+    }
+
+    /// `name` followed by optional array dimensions `[N][M]`
+    fn declarator(&mut self, ty: FieldType) -> Result<(String, FieldType)> {
+        let name = self.ident()?;
+        let mut dims = Vec::new();
+        while self.eat_punct('[') {
+            dims.push(self.number()?);
+            self.expect_punct(']')?;
+        }
+        // `double m[3][4]` is three arrays of four
+        let ty = dims
+            .into_iter()
+            .rev()
+            .fold(ty, |ty, n| FieldType::Array(Box::new(ty), n));
+        Ok((name, ty))
+    }
+
+    fn parse_type(&mut self) -> Result<FieldType> {
+        if self.eat_keyword("sequence") {
+            self.expect_punct('<')?;
+            let element = Box::new(self.parse_type()?);
+            let bound = if self.eat_punct(',') {
+                Some(self.number()?)
+            } else {
+                None
+            };
+            self.expect_punct('>')?;
+            return Ok(FieldType::Sequence { element, bound });
+        }
+        if self.eat_keyword("string") || self.eat_keyword("wstring") {
+            let bound = if self.eat_punct('<') {
+                let bound = self.number()?;
+                self.expect_punct('>')?;
+                Some(bound)
+            } else {
+                None
+            };
+            return Ok(FieldType::String { bound });
+        }
+
+        let unsigned = self.eat_keyword("unsigned");
+        let primitive = if self.eat_keyword("short") {
+            Some(if unsigned {
+                Primitive::U16
+            } else {
+                Primitive::I16
+            })
+        } else if self.eat_keyword("long") {
+            if self.eat_keyword("double") {
+                return Err(self.error("'long double' is not supported"));
+            }
+            let long_long = self.eat_keyword("long");
+            Some(match (unsigned, long_long) {
+                (false, false) => Primitive::I32,
+                (true, false) => Primitive::U32,
+                (false, true) => Primitive::I64,
+                (true, true) => Primitive::U64,
+            })
+        } else if unsigned {
+            return Err(self.error("expected 'short' or 'long' after 'unsigned'"));
+        } else {
+            None
+        };
+        if let Some(primitive) = primitive {
+            return Ok(FieldType::Primitive(primitive));
+        }
+
+        let name = self.scoped_name()?;
+        let primitive = match name.as_str() {
+            "boolean" => Primitive::Bool,
+            "octet" | "char" | "uint8" => Primitive::U8,
+            "wchar" | "uint16" => Primitive::U16,
+            "int8" => Primitive::I8,
+            "int16" => Primitive::I16,
+            "int32" => Primitive::I32,
+            "uint32" => Primitive::U32,
+            "int64" => Primitive::I64,
+            "uint64" => Primitive::U64,
+            "float" => Primitive::F32,
+            "double" => Primitive::F64,
+            _ => {
+                if let Some(alias) = self.typedefs.get(&name) {
+                    return Ok(alias.clone());
+                }
+                if !is_identifier(&name) {
+                    return Err(self.error(format!("unknown type '{}'", name)));
+                }
+                return Ok(FieldType::Named(name));
+            }
+        };
+        Ok(FieldType::Primitive(primitive))
+    }
+}
+
+/// Value of an `@default(value=...)` annotation
+fn default_value(annotations: &[Annotation]) -> Option<String> {
+    annotations
+        .iter()
+        .find(|a| a.name == "default")
+        .and_then(|a| a.param("value"))
+        .map(|value| match value {
+            Token::Str(text) => format!("{:?}", text),
+            Token::Literal(text) | Token::Ident(text) => text.clone(),
+            Token::Punct(c) => c.to_string(),
+        })
+}
+
+/// Documentation carried in `@verbatim(language="comment", text="...")`
+fn doc_lines(annotations: &[Annotation]) -> Vec<String> {
+    annotations
+        .iter()
+        .filter(|a| a.name == "verbatim")
+        .filter(|a| matches!(a.param("language"), Some(Token::Str(lang)) if lang == "comment"))
+        .filter_map(|a| match a.param("text") {
+            Some(Token::Str(text)) => Some(text.clone()),
+            _ => None,
+        })
+        .flat_map(|text| {
+            text.lines()
+                .map(|line| line.trim().to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rosidl_style() {
+        let source = r#"
+// generated from rosidl_adapter
+#include "std_msgs/msg/Header.idl"
+
+module sensor_msgs {
+  module msg {
+    typedef double double__9[9];
+    module BatteryState_Constants {
+      const uint8 POWER_SUPPLY_STATUS_CHARGING = 1;
+      const float NOMINAL = -1.5e1;
+    };
+    @verbatim (language="comment", text=
+      "Battery state" "\n" "of a pack")
+    struct BatteryState {
+      std_msgs::msg::Header header;
+
+      @verbatim (language="comment", text="Volts")
+      float voltage;
+      sequence<float> cell_voltage;
+      double__9 covariance;
+      unsigned long long cycles, errors;
+      @default (value=5)
+      int16 grid[2][3];
+      string<16> serial_number;
+    };
+  };
+};
+"#;
+        let messages = parse_idl(source, "BatteryState.idl").unwrap();
+        assert_eq!(messages.len(), 1);
+        let msg = &messages[0];
+        assert_eq!(msg.name, "BatteryState");
+        assert_eq!(msg.package.as_deref(), Some("sensor_msgs"));
+        assert_eq!(msg.constants.len(), 2);
+        assert_eq!(msg.constants[1].value, "-1.5e1");
+
+        let fields: Vec<_> = msg.fields.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(
+            fields,
+            vec![
+                "header",
+                "voltage",
+                "cell_voltage",
+                "covariance",
+                "cycles",
+                "errors",
+                "grid",
+                "serial_number"
+            ]
+        );
+        assert_eq!(msg.fields[0].ty, FieldType::Named("Header".into()));
+        assert_eq!(msg.fields[1].doc, vec!["Volts"]);
+        assert_eq!(
+            msg.fields[3].ty,
+            FieldType::Array(Box::new(FieldType::Primitive(Primitive::F64)), 9)
+        );
+        assert_eq!(msg.fields[5].ty, FieldType::Primitive(Primitive::U64));
+        assert_eq!(
+            msg.fields[6].ty,
+            FieldType::Array(
+                Box::new(FieldType::Array(
+                    Box::new(FieldType::Primitive(Primitive::I16)),
+                    3
+                )),
+                2
+            )
+        );
+        assert_eq!(msg.fields[6].default.as_deref(), Some("5"));
+        assert_eq!(msg.fields[7].ty, FieldType::String { bound: Some(16) });
+    }
+
+    #[test]
+    fn test_parse_idl_unsupported() {
+        assert!(parse_idl("enum Mode { A, B };", "m.idl").is_err());
+        assert!(parse_idl("struct A : B { long x; };", "m.idl").is_err());
+        assert!(parse_idl("const long X = 1;", "m.idl").is_err());
+    }
+}
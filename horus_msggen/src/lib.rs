@@ -0,0 +1,246 @@
+//! # HORUS Message Generator
+//!
+//! Generates HORUS message types from existing ROS `.msg` and OMG IDL
+//! definitions at build time, so teams don't have to retype them as Rust
+//! structs.
+//!
+//! ## Usage
+//!
+//! ```toml
+//! [build-dependencies]
+//! horus_msggen = { path = "../horus_msggen" }
+//! ```
+//!
+//! ```rust,ignore
+//! // build.rs
+//! fn main() {
+//!     horus_msggen::Builder::new()
+//!         .input("msg")               // every .msg / .idl file in the directory
+//!         .input("idl/Battery.idl")   // or individual files
+//!         .compile()
+//!         .expect("message generation failed");
+//! }
+//! ```
+//!
+//! ```rust,ignore
+//! // src/messages.rs
+//! use horus::prelude::*;
+//!
+//! include!(concat!(env!("OUT_DIR"), "/horus_messages.rs"));
+//! ```
+//!
+//! Every definition becomes a `message!` type; constants become associated
+//! constants. With [`Builder::zero_copy`], fixed-layout messages are emitted
+//! as `zero_copy_message!` instead (the crate then also needs `bytemuck`).
+//!
+//! Nested message types are referenced by name with the package stripped
+//! (`geometry_msgs/Point` becomes `Point`), so they must be generated in the
+//! same batch or be in scope where the output is included.
+
+pub mod ast;
+pub mod codegen;
+pub mod idl;
+pub mod msg;
+
+pub use ast::{ConstantDef, FieldDef, FieldType, MessageDef, Primitive};
+pub use codegen::{generate, GenerateOptions};
+pub use idl::parse_idl;
+pub use msg::parse_msg;
+
+use std::path::{Path, PathBuf};
+
+/// Message generation errors
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("{origin}:{line}: {message}")]
+    Parse {
+        origin: String,
+        line: usize,
+        message: String,
+    },
+
+    #[error("{path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("{0}")]
+    Invalid(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Parse a `.msg` or `.idl` file
+///
+/// For `.msg` files the message name is the file stem, and the package is
+/// taken from the ROS layout `<package>/msg/<Name>.msg` when present.
+pub fn parse_file(path: &Path) -> Result<Vec<MessageDef>> {
+    let source = std::fs::read_to_string(path).map_err(|source| Error::Io {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let origin = path.display().to_string();
+
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("msg") => {
+            let name = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .ok_or_else(|| Error::Invalid(format!("invalid file name: {}", origin)))?;
+            let mut def = parse_msg(name, &source, &origin)?;
+            def.package = ros_package(path);
+            Ok(vec![def])
+        }
+        Some("idl") => parse_idl(&source, &origin),
+        _ => Err(Error::Invalid(format!(
+            "unsupported definition file (expected .msg or .idl): {}",
+            origin
+        ))),
+    }
+}
+
+/// `<package>` of `<package>/msg/<Name>.msg`
+fn ros_package(path: &Path) -> Option<String> {
+    let parent = path.parent()?;
+    if parent.file_name()? != "msg" {
+        return None;
+    }
+    parent.parent()?.file_name()?.to_str().map(str::to_string)
+}
+
+/// Build-script driver: parses definition files and writes the generated Rust
+#[derive(Debug, Clone, Default)]
+pub struct Builder {
+    inputs: Vec<PathBuf>,
+    out_file: Option<PathBuf>,
+    options: GenerateOptions,
+}
+
+impl Builder {
+    /// Create a builder with no inputs
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a definition file, or a directory whose `.msg`/`.idl` files are all used
+    pub fn input(mut self, path: impl AsRef<Path>) -> Self {
+        self.inputs.push(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Emit `zero_copy_message!` for fixed-layout messages (default: false)
+    pub fn zero_copy(mut self, enabled: bool) -> Self {
+        self.options.zero_copy = enabled;
+        self
+    }
+
+    /// Output file (default: `$OUT_DIR/horus_messages.rs`)
+    pub fn out_file(mut self, path: impl AsRef<Path>) -> Self {
+        self.out_file = Some(path.as_ref().to_path_buf());
+        self
+    }
+
+    /// Parse all inputs and write the generated code, returning the output path
+    ///
+    /// Prints `cargo:rerun-if-changed` for every input so the build script
+    /// reruns when definitions change. The output is only rewritten when its
+    /// contents change.
+    pub fn compile(self) -> Result<PathBuf> {
+        let out_file = match self.out_file {
+            Some(path) => path,
+            None => {
+                let out_dir = std::env::var_os("OUT_DIR").ok_or_else(|| {
+                    Error::Invalid("OUT_DIR is not set; call out_file() outside build.rs".into())
+                })?;
+                PathBuf::from(out_dir).join("horus_messages.rs")
+            }
+        };
+
+        let mut messages = Vec::new();
+        for input in &self.inputs {
+            println!("cargo:rerun-if-changed={}", input.display());
+            for file in definition_files(input)? {
+                messages.extend(parse_file(&file)?);
+            }
+        }
+
+        let code = generate(&messages, &self.options)?;
+
+        let unchanged = std::fs::read_to_string(&out_file).is_ok_and(|existing| existing == code);
+        if !unchanged {
+            if let Some(parent) = out_file.parent() {
+                std::fs::create_dir_all(parent).map_err(|source| Error::Io {
+                    path: parent.to_path_buf(),
+                    source,
+                })?;
+            }
+            std::fs::write(&out_file, code).map_err(|source| Error::Io {
+                path: out_file.clone(),
+                source,
+            })?;
+        }
+
+        Ok(out_file)
+    }
+}
+
+/// Definition files for an input path (sorted for deterministic output)
+fn definition_files(input: &Path) -> Result<Vec<PathBuf>> {
+    if !input.is_dir() {
+        return Ok(vec![input.to_path_buf()]);
+    }
+
+    let entries = std::fs::read_dir(input).map_err(|source| Error::Io {
+        path: input.to_path_buf(),
+        source,
+    })?;
+    let mut files: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            matches!(
+                path.extension().and_then(|e| e.to_str()),
+                Some("msg") | Some("idl")
+            )
+        })
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builder_compile() {
+        let dir = tempfile::tempdir().unwrap();
+        let msg_dir = dir.path().join("power_msgs").join("msg");
+        std::fs::create_dir_all(&msg_dir).unwrap();
+        std::fs::write(msg_dir.join("Cell.msg"), "float32 voltage\n").unwrap();
+        std::fs::write(
+            msg_dir.join("Pack.idl"),
+            "module power_msgs { struct Pack { sequence<Cell> cells; }; };",
+        )
+        .unwrap();
+
+        let out = dir.path().join("out").join("messages.rs");
+        Builder::new()
+            .input(&msg_dir)
+            .out_file(&out)
+            .compile()
+            .unwrap();
+
+        let code = std::fs::read_to_string(&out).unwrap();
+        assert!(code.contains("/// Generated from `power_msgs/Cell`."));
+        assert!(code.contains("        cells: Vec<Cell>,"));
+
+        // Duplicate definitions are rejected
+        std::fs::write(msg_dir.join("Pack.msg"), "uint8 count\n").unwrap();
+        assert!(Builder::new()
+            .input(&msg_dir)
+            .out_file(&out)
+            .compile()
+            .is_err());
+    }
+}
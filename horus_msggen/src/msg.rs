@@ -0,0 +1,261 @@
+//! ROS `.msg` front end
+//!
+//! Supports the ROS 1 / ROS 2 message grammar: primitive and string fields,
+//! bounded strings (`string<=N`), fixed arrays (`T[N]`), sequences (`T[]`,
+//! `T[<=N]`), nested message types (`pkg/Type`), constants (`TYPE NAME=value`)
+//! and ROS 2 default values. `time` and `duration` map to `u64` nanoseconds.
+
+use crate::ast::{is_identifier, ConstantDef, FieldDef, FieldType, MessageDef, Primitive};
+use crate::{Error, Result};
+
+/// Parse the contents of a `.msg` file
+///
+/// `name` is the message name (the file stem); `origin` is used in errors.
+pub fn parse_msg(name: &str, source: &str, origin: &str) -> Result<MessageDef> {
+    let err = |line: usize, message: String| Error::Parse {
+        origin: origin.to_string(),
+        line,
+        message,
+    };
+
+    if !is_identifier(name) {
+        return Err(err(0, format!("invalid message name '{}'", name)));
+    }
+
+    let mut def = MessageDef {
+        name: name.to_string(),
+        package: None,
+        doc: Vec::new(),
+        fields: Vec::new(),
+        constants: Vec::new(),
+    };
+    let mut pending_doc: Vec<String> = Vec::new();
+
+    for (index, raw) in source.lines().enumerate() {
+        let line_no = index + 1;
+        let line = raw.trim();
+
+        if line.is_empty() {
+            // A comment block at the top separated by a blank line documents the message
+            if def.fields.is_empty() && def.constants.is_empty() && def.doc.is_empty() {
+                def.doc = std::mem::take(&mut pending_doc);
+            } else {
+                pending_doc.clear();
+            }
+            continue;
+        }
+
+        if let Some(comment) = line.strip_prefix('#') {
+            pending_doc.push(comment.trim().to_string());
+            continue;
+        }
+
+        if line == "---" {
+            return Err(err(
+                line_no,
+                "service/action definitions are not supported; split them into .msg files"
+                    .to_string(),
+            ));
+        }
+
+        let (type_str, rest) = line
+            .split_once(char::is_whitespace)
+            .ok_or_else(|| err(line_no, format!("expected '<type> <name>', got '{}'", line)))?;
+        let ty = parse_type(type_str).map_err(|message| err(line_no, message))?;
+        let rest = rest.trim_start();
+
+        let mut doc = std::mem::take(&mut pending_doc);
+
+        // Constant: `TYPE NAME=value`
+        if let Some((const_name, value)) = rest
+            .split_once('=')
+            .filter(|(name, _)| is_identifier(name.trim()))
+        {
+            let const_name = const_name.trim();
+            let value = match ty {
+                // String constants run to the end of the line, '#' included
+                FieldType::String { .. } => value.trim().to_string(),
+                FieldType::Primitive(_) => {
+                    let (value, comment) = split_comment(value);
+                    doc.extend(comment);
+                    value.trim().to_string()
+                }
+                _ => {
+                    return Err(err(
+                        line_no,
+                        format!(
+                            "constant '{}' must have a primitive or string type",
+                            const_name
+                        ),
+                    ))
+                }
+            };
+            if value.is_empty() {
+                return Err(err(
+                    line_no,
+                    format!("constant '{}' has no value", const_name),
+                ));
+            }
+            def.constants.push(ConstantDef {
+                name: const_name.to_string(),
+                ty,
+                value,
+                doc,
+            });
+            continue;
+        }
+
+        // Field: `type name [default]`
+        let (body, comment) = split_comment(rest);
+        doc.extend(comment);
+        let body = body.trim();
+        let (field_name, default) = match body.split_once(char::is_whitespace) {
+            Some((field_name, default)) => (field_name, Some(default.trim().to_string())),
+            None => (body, None),
+        };
+        if !is_identifier(field_name) {
+            return Err(err(line_no, format!("invalid field name '{}'", field_name)));
+        }
+        if def.fields.iter().any(|f| f.name == field_name) {
+            return Err(err(line_no, format!("duplicate field '{}'", field_name)));
+        }
+        def.fields.push(FieldDef {
+            name: field_name.to_string(),
+            ty,
+            default,
+            doc,
+        });
+    }
+
+    Ok(def)
+}
+
+/// Split a trailing `# comment` off a line
+fn split_comment(text: &str) -> (&str, Option<String>) {
+    match text.split_once('#') {
+        Some((body, comment)) => (body, Some(comment.trim().to_string())),
+        None => (text, None),
+    }
+}
+
+/// Parse a `.msg` type expression such as `float64[<=3]` or `geometry_msgs/Point`
+fn parse_type(text: &str) -> std::result::Result<FieldType, String> {
+    if let Some(open) = text.find('[') {
+        let spec = text[open + 1..]
+            .strip_suffix(']')
+            .ok_or_else(|| format!("malformed array type '{}'", text))?;
+        let element = Box::new(parse_type(&text[..open])?);
+        return if spec.is_empty() {
+            Ok(FieldType::Sequence {
+                element,
+                bound: None,
+            })
+        } else if let Some(bound) = spec.strip_prefix("<=") {
+            Ok(FieldType::Sequence {
+                element,
+                bound: Some(parse_bound(bound, text)?),
+            })
+        } else {
+            Ok(FieldType::Array(element, parse_bound(spec, text)?))
+        };
+    }
+
+    for string_type in ["string", "wstring"] {
+        if text == string_type {
+            return Ok(FieldType::String { bound: None });
+        }
+        if let Some(bound) = text
+            .strip_prefix(string_type)
+            .and_then(|rest| rest.strip_prefix("<="))
+        {
+            return Ok(FieldType::String {
+                bound: Some(parse_bound(bound, text)?),
+            });
+        }
+    }
+
+    let primitive = match text {
+        "bool" => Primitive::Bool,
+        "byte" | "char" | "uint8" => Primitive::U8,
+        "int8" => Primitive::I8,
+        "int16" => Primitive::I16,
+        "uint16" => Primitive::U16,
+        "int32" => Primitive::I32,
+        "uint32" => Primitive::U32,
+        "int64" => Primitive::I64,
+        "uint64" | "time" | "duration" => Primitive::U64,
+        "float32" => Primitive::F32,
+        "float64" => Primitive::F64,
+        _ => {
+            // Nested message, optionally package-qualified
+            let name = text.rsplit('/').next().unwrap_or(text);
+            if !is_identifier(name) {
+                return Err(format!("unknown type '{}'", text));
+            }
+            return Ok(FieldType::Named(name.to_string()));
+        }
+    };
+    Ok(FieldType::Primitive(primitive))
+}
+
+fn parse_bound(text: &str, ty: &str) -> std::result::Result<usize, String> {
+    text.trim()
+        .parse()
+        .map_err(|_| format!("invalid size '{}' in type '{}'", text, ty))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_msg() {
+        let source = "\
+# Battery status reported by the BMS
+
+# Charge state constants
+uint8 STATE_IDLE=0
+uint8 STATE_CHARGING=1 # actively charging
+string LABEL=pack #1
+
+std_msgs/Header header
+float32 voltage      # volts
+float32[<=16] cell_voltages
+float64[9] covariance
+string<=32 serial_number
+uint8 state 0
+";
+        let def = parse_msg("BatteryState", source, "BatteryState.msg").unwrap();
+
+        assert_eq!(def.doc, vec!["Battery status reported by the BMS"]);
+        assert_eq!(def.constants.len(), 3);
+        assert_eq!(def.constants[0].doc, vec!["Charge state constants"]);
+        assert_eq!(def.constants[1].doc, vec!["actively charging"]);
+        assert_eq!(def.constants[2].value, "pack #1");
+
+        let types: Vec<_> = def.fields.iter().map(|f| f.ty.clone()).collect();
+        assert_eq!(
+            types,
+            vec![
+                FieldType::Named("Header".into()),
+                FieldType::Primitive(Primitive::F32),
+                FieldType::Sequence {
+                    element: Box::new(FieldType::Primitive(Primitive::F32)),
+                    bound: Some(16),
+                },
+                FieldType::Array(Box::new(FieldType::Primitive(Primitive::F64)), 9),
+                FieldType::String { bound: Some(32) },
+                FieldType::Primitive(Primitive::U8),
+            ]
+        );
+        assert_eq!(def.fields[1].doc, vec!["volts"]);
+        assert_eq!(def.fields[5].default.as_deref(), Some("0"));
+    }
+
+    #[test]
+    fn test_parse_msg_errors() {
+        assert!(parse_msg("Srv", "int32 a\n---\nint32 b\n", "Srv.srv").is_err());
+        assert!(parse_msg("Bad", "float64 x\nfloat64 x\n", "Bad.msg").is_err());
+        assert!(parse_msg("Bad", "float64[abc] x\n", "Bad.msg").is_err());
+    }
+}
@@ -8,14 +8,22 @@ A user-friendly Python API for the HORUS robotics framework.
 __path__ = __import__('pkgutil').extend_path(__path__, __name__)
 
 from typing import Optional, Any, Dict, List, Callable, Union
+import asyncio
+import inspect
 import pickle
 import json
-from collections import defaultdict
+from collections import defaultdict, deque
 import time
 
+from . import reload as _reload
+
 # Maximum size for logged data representation (to prevent buffer overflows)
 MAX_LOG_DATA_SIZE = 200
 
+# Tensors sent per topic that the sender keeps alive, so a subscriber can
+# retain a shared-memory tensor before its slot is freed
+TENSOR_KEEPALIVE = 8
+
 # Import the Rust extension module
 try:
     from horus._horus import (
@@ -266,11 +274,15 @@ class Node:
         # Phase 2: Message timestamps (topic -> [(msg, timestamp), ...])
         self._msg_timestamps = defaultdict(list)
 
+        # Recently sent TensorHandles (topic -> handles), see TENSOR_KEEPALIVE
+        self._sent_tensors = defaultdict(lambda: deque(maxlen=TENSOR_KEEPALIVE))
+
         # NodeInfo context (set by scheduler)
         self.info = None
 
-        # Create underlying HORUS components if available
-        if _PyNode:
+        # Create underlying HORUS components if available (not for the copies
+        # created while a file is re-executed for hot reload)
+        if _PyNode and not _reload.reloading():
             self._node = _PyNode(name)
             self._setup_hubs()
         else:
@@ -282,49 +294,52 @@ class Node:
         """Setup publish/subscribe hubs with configured capacities and types."""
         self._hubs = {}
 
-        # Create publisher hubs
-        for topic in self.pub_topics:
-            config = self._topic_configs.get(topic, {})
-            capacity = config.get('capacity', self.default_capacity)
-            msg_type = config.get('type', None)
-
-            # If type specified, create typed hub; otherwise generic hub
-            if msg_type is not None:
-                # Temporarily set __topic_name__ so Rust Hub uses correct topic
-                original_topic = getattr(msg_type, '__topic_name__', None)
-                msg_type.__topic_name__ = topic
-                try:
-                    self._hubs[topic] = Hub(msg_type, capacity)
-                finally:
-                    # Restore original or delete
-                    if original_topic is not None:
-                        msg_type.__topic_name__ = original_topic
-                    elif hasattr(msg_type, '__topic_name__'):
-                        delattr(msg_type, '__topic_name__')
-            else:
-                self._hubs[topic] = Hub(topic, capacity)
-
-        # Create subscriber hubs
-        for topic in self.sub_topics:
-            config = self._topic_configs.get(topic, {})
-            capacity = config.get('capacity', self.default_capacity)
-            msg_type = config.get('type', None)
-
-            # If type specified, create typed hub; otherwise generic hub
-            if msg_type is not None:
-                # Temporarily set __topic_name__ so Rust Hub uses correct topic
-                original_topic = getattr(msg_type, '__topic_name__', None)
-                msg_type.__topic_name__ = topic
-                try:
-                    self._hubs[topic] = Hub(msg_type, capacity)
-                finally:
-                    # Restore original or delete
-                    if original_topic is not None:
-                        msg_type.__topic_name__ = original_topic
-                    elif hasattr(msg_type, '__topic_name__'):
-                        delattr(msg_type, '__topic_name__')
-            else:
-                self._hubs[topic] = Hub(topic, capacity)
+        # Create publisher and subscriber hubs
+        for topic in self.pub_topics + self.sub_topics:
+            self._create_hub(topic)
+
+    def _create_hub(self, topic: str) -> None:
+        """Create the hub of one topic from its configured capacity and type."""
+        config = self._topic_configs.get(topic, {})
+        capacity = config.get('capacity', self.default_capacity)
+        msg_type = config.get('type', None)
+
+        # If type specified, create typed hub; otherwise generic hub
+        if msg_type is not None:
+            # Temporarily set __topic_name__ so Rust Hub uses correct topic
+            original_topic = getattr(msg_type, '__topic_name__', None)
+            msg_type.__topic_name__ = topic
+            try:
+                self._hubs[topic] = Hub(msg_type, capacity)
+            finally:
+                # Restore original or delete
+                if original_topic is not None:
+                    msg_type.__topic_name__ = original_topic
+                elif hasattr(msg_type, '__topic_name__'):
+                    delattr(msg_type, '__topic_name__')
+        else:
+            self._hubs[topic] = Hub(topic, capacity)
+
+    def _add_topics(self, other: 'Node') -> List[str]:
+        """
+        Add the topics of `other` that this node does not have yet (hot reload).
+
+        Existing hubs are kept so running connections are not interrupted.
+        Returns the added topic names.
+        """
+        added = []
+        for topics, new_topics in ((self.pub_topics, other.pub_topics),
+                                   (self.sub_topics, other.sub_topics)):
+            for topic in new_topics:
+                if topic in topics:
+                    continue
+                topics.append(topic)
+                if topic in other._topic_configs:
+                    self._topic_configs[topic] = other._topic_configs[topic]
+                if self._node is not None and topic not in self._hubs:
+                    self._create_hub(topic)
+                added.append(topic)
+        return added
 
     def has_msg(self, topic: str) -> bool:
         """
@@ -443,13 +458,46 @@ class Node:
             return (msg, timestamp)
         return None
 
+    async def recv(self, topic: str, timeout: Optional[float] = None,
+                   poll_interval: float = 0.001) -> Any:
+        """
+        Wait for the next message on a topic without blocking the event loop.
+
+        Hubs are shared memory without a file descriptor to wait on, so the
+        topic is polled every `poll_interval` seconds, yielding to other
+        tasks in between. No background thread is used.
+
+        Args:
+            topic: Topic to read from
+            timeout: Seconds to wait before raising asyncio.TimeoutError
+                     (waits forever if None)
+            poll_interval: Seconds between checks (default 1ms)
+
+        Returns:
+            Message data
+
+        Example:
+            async def tick(node):
+                cmd = await node.recv("cmd_vel", timeout=0.5)
+                await motors.apply(cmd)
+        """
+        loop = asyncio.get_running_loop()
+        deadline = None if timeout is None else loop.time() + timeout
+        while not self.has_msg(topic):
+            if deadline is not None and loop.time() >= deadline:
+                raise asyncio.TimeoutError(f"No message on '{topic}' within {timeout}s")
+            await asyncio.sleep(poll_interval)
+        return self.get(topic)
+
     def send(self, topic: str, data: Any) -> bool:
         """
         Send data to a topic.
 
         Args:
             topic: Topic to send to
-            data: Data to send (supports numpy arrays with zero-copy)
+            data: Data to send. Numpy arrays are copied; TensorHandles from a
+                  TensorPool are shared zero-copy and arrive as read-only
+                  TensorHandles
 
         Returns:
             True if sent successfully
@@ -486,7 +534,12 @@ class Node:
             start_ns = time.perf_counter_ns()
 
             # Serialize based on type
-            if isinstance(data, bytes):
+            if TensorHandle is not None and isinstance(data, TensorHandle) and hub.is_generic():
+                # Zero-copy path for shared-memory tensors: only the descriptor
+                # is sent, subscribers map the same memory read-only
+                result = hub.send_with_metadata(data.to_descriptor(), f"tensor:{data.pool_id}", self)
+                self._sent_tensors[topic].append(data)
+            elif isinstance(data, bytes):
                 result = hub.send_bytes(data, self)
             elif isinstance(data, str):
                 result = hub.send_bytes(data.encode('utf-8'), self)
@@ -577,6 +630,11 @@ class Node:
                     elif msg_type == "numpy":
                         # Keep as raw bytes for numpy arrays
                         msg = data_bytes
+                    elif msg_type.startswith("tensor:"):
+                        # Read-only handle on the sender's shared memory;
+                        # handle.numpy() is a zero-copy view
+                        pool_id = int(msg_type[len("tensor:"):])
+                        msg = TensorHandle.from_descriptor(pool_id, data_bytes)
                     else:
                         try:
                             msg = data_bytes.decode('utf-8')
@@ -603,8 +661,8 @@ class Node:
                 self._msg_queues[topic].append(msg)
                 self._msg_timestamps[topic].append(timestamp)
 
-    def _internal_tick(self, info: Optional[Any] = None) -> None:
-        """Internal tick called by scheduler with per-node rate control."""
+    def _tick_due(self) -> bool:
+        """Per-node rate control: True (and the tick recorded) if the node should tick now."""
         import time
 
         # Check if enough time has elapsed for this node's rate
@@ -613,10 +671,40 @@ class Node:
             time_since_last_tick = current_time - self._last_tick_time
             if time_since_last_tick < self._tick_period:
                 # Not time to tick yet - skip this call
-                return
+                return False
 
         # Update last tick time
         self._last_tick_time = current_time
+        return True
+
+    def _handle_tick_error(self, e: Exception) -> None:
+        """Count, log and dispatch a tick failure; re-raises without an on_error handler."""
+        # Increment error count
+        self.error_count += 1
+
+        # Log error if info available
+        if self.info:
+            self.info.log_error(f"Tick failed: {e}")
+            # Transition to error state if too many errors
+            if self.error_count > 10:
+                self.info.transition_to_error(f"Too many errors ({self.error_count})")
+
+        # Call user's error handler if provided
+        if self.on_error_fn:
+            try:
+                self.on_error_fn(self, e)
+            except Exception as handler_error:
+                # Error handler itself failed - just log it
+                if self.info:
+                    self.info.log_error(f"Error handler failed: {handler_error}")
+        else:
+            # No error handler - re-raise
+            raise
+
+    def _internal_tick(self, info: Optional[Any] = None) -> None:
+        """Internal tick called by scheduler with per-node rate control."""
+        if not self._tick_due():
+            return
 
         # DON'T store info - use a context manager approach
         old_info = self.info
@@ -625,27 +713,24 @@ class Node:
             if self.tick_fn:
                 self.tick_fn(self)
         except Exception as e:
-            # Increment error count
-            self.error_count += 1
+            self._handle_tick_error(e)
+        finally:
+            self.info = old_info
 
-            # Log error if info available
-            if self.info:
-                self.info.log_error(f"Tick failed: {e}")
-                # Transition to error state if too many errors
-                if self.error_count > 10:
-                    self.info.transition_to_error(f"Too many errors ({self.error_count})")
-
-            # Call user's error handler if provided
-            if self.on_error_fn:
-                try:
-                    self.on_error_fn(self, e)
-                except Exception as handler_error:
-                    # Error handler itself failed - just log it
-                    if self.info:
-                        self.info.log_error(f"Error handler failed: {handler_error}")
-            else:
-                # No error handler - re-raise
-                raise
+    async def _internal_tick_async(self, info: Optional[Any] = None) -> None:
+        """Tick on an asyncio event loop (see `spin`); awaits `async def` tick functions."""
+        if not self._tick_due():
+            return
+
+        old_info = self.info
+        self.info = info
+        try:
+            if self.tick_fn:
+                result = self.tick_fn(self)
+                if inspect.isawaitable(result):
+                    await result
+        except Exception as e:
+            self._handle_tick_error(e)
         finally:
             self.info = old_info
 
@@ -661,6 +746,22 @@ class Node:
         if self.shutdown_fn:
             self.shutdown_fn(self)
 
+    async def _internal_init_async(self, info: Optional[Any] = None) -> None:
+        """Init on an asyncio event loop; awaits an `async def` init function."""
+        self.info = info
+        if self.init_fn:
+            result = self.init_fn(self)
+            if inspect.isawaitable(result):
+                await result
+
+    async def _internal_shutdown_async(self, info: Optional[Any] = None) -> None:
+        """Shutdown on an asyncio event loop; awaits an `async def` shutdown function."""
+        self.info = info
+        if self.shutdown_fn:
+            result = self.shutdown_fn(self)
+            if inspect.isawaitable(result):
+                await result
+
     # Public methods for Rust bindings to call
     def init(self, info: Optional[Any] = None) -> None:
         """Called by Rust scheduler during initialization."""
@@ -765,6 +866,8 @@ class Scheduler:
         else:
             self._scheduler = None
         self._nodes = []
+        self._node_options = {}  # id(node) -> (priority, logging), for run_async
+        self._stop_event = None  # Set by stop() while run_async is running
 
     @staticmethod
     def from_config(config: 'SchedulerConfig') -> 'Scheduler':
@@ -800,6 +903,7 @@ class Scheduler:
             scheduler.add(node1, 0, True).add(node2, 1, False).run()
         """
         self._nodes.append(node)
+        self._node_options[id(node)] = (priority, logging)
 
         if self._scheduler:
             # Register the Python Node wrapper directly, not the internal _node
@@ -814,7 +918,26 @@ class Scheduler:
 
         Args:
             duration: Optional duration in seconds (runs forever if None)
+
+        With `HORUS_DEV=1` (set by `horus run --dev`), changes to the nodes'
+        source files are reloaded while running (see `horus.reload`).
         """
+        if _reload.reloading():
+            # The file is being re-executed for hot reload; already running
+            return
+
+        reloader = None
+        if _reload.enabled():
+            reloader = _reload.NodeReloader(self._nodes)
+            reloader.start()
+
+        try:
+            self._run(duration)
+        finally:
+            if reloader is not None:
+                reloader.stop()
+
+    def _run(self, duration: Optional[float] = None) -> None:
         if self._scheduler:
             # Initialize all nodes
             for node in self._nodes:
@@ -856,8 +979,34 @@ class Scheduler:
                 for node in self._nodes:
                     node._internal_shutdown()
 
+    async def run_async(self, duration: Optional[float] = None) -> None:
+        """
+        Run the nodes as a task on the running asyncio event loop.
+
+        Nodes tick in priority order on the loop's thread, so `async def`
+        tick/init/shutdown functions can await `node.recv()`, aiohttp calls
+        or async hardware SDKs alongside other tasks. Unlike `run()`, the
+        Rust scheduler (deadlines, watchdogs, per-node stats) is not used.
+
+        Args:
+            duration: Optional duration in seconds (runs until `stop()` if None)
+
+        Example:
+            async def main():
+                scheduler = Scheduler().add(camera, 0, True).add(planner, 1)
+                await asyncio.gather(scheduler.run_async(), web_server())
+        """
+        from .async_node import _spin
+
+        order = sorted(self._nodes, key=lambda node: self._node_options[id(node)][0])
+        self._stop_event = asyncio.Event()
+        await _spin([(node, self._node_options[id(node)][1]) for node in order],
+                    duration, self._stop_event)
+
     def stop(self) -> None:
         """Stop the scheduler."""
+        if self._stop_event is not None:
+            self._stop_event.set()
         if self._scheduler:
             self._scheduler.stop()
 
@@ -1189,6 +1338,7 @@ __all__ = [
     # Simple async API
     "AsyncNode",
     "AsyncHub",
+    "spin",
     "sleep",
     "gather",
     "wait_for",
@@ -1231,7 +1381,7 @@ Odometry = _RustOdometry
 # LaserScan handled below (after nodes import to avoid override)
 
 # Import simple async API
-from .async_node import AsyncNode, AsyncHub, sleep, gather, wait_for
+from .async_node import AsyncNode, AsyncHub, spin, sleep, gather, wait_for
 
 # Import ML utilities
 from .ml_utils import (
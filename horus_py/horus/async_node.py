@@ -6,8 +6,9 @@ Just use 'async def tick()' and 'await' - that's it.
 """
 
 import asyncio
-from typing import Optional, Callable, Any
-from . import Node
+import time
+from typing import Optional, Callable, Any, List, Tuple
+from . import Node, _NodeInfo
 
 
 class AsyncNode(Node):
@@ -38,7 +39,10 @@ class AsyncNode(Node):
     """
 
     def __init__(self):
-        super().__init__()
+        # The lifecycle methods are coroutines; `spin` awaits them
+        super().__init__(tick=lambda node: node.tick(),
+                         init=lambda node: node.setup(),
+                         shutdown=lambda node: node.shutdown())
         self._loop: Optional[asyncio.AbstractEventLoop] = None
         self._task: Optional[asyncio.Task] = None
 
@@ -115,20 +119,23 @@ class AsyncHub:
 
     async def send(self, msg: Any):
         """Send message asynchronously"""
-        # Run in executor to avoid blocking
-        loop = asyncio.get_event_loop()
-        await loop.run_in_executor(None, self._hub.send, msg)
+        # Shared-memory sends never block, so no executor thread is needed
+        self._hub.send(msg)
 
-    async def recv(self) -> Any:
+    async def recv(self, timeout: Optional[float] = None) -> Any:
         """
         Receive message asynchronously.
-        Waits until a message is available.
+        Waits until a message is available, polling every millisecond
+        and yielding to other tasks in between.
         """
-        loop = asyncio.get_event_loop()
+        loop = asyncio.get_running_loop()
+        deadline = None if timeout is None else loop.time() + timeout
         while True:
-            msg = await loop.run_in_executor(None, self._hub.try_recv)
+            msg = self._hub.recv()
             if msg is not None:
                 return msg
+            if deadline is not None and loop.time() >= deadline:
+                raise asyncio.TimeoutError(f"No message on '{self._hub.topic()}' within {timeout}s")
             await asyncio.sleep(0.001)  # Small delay to avoid busy wait
 
     async def try_recv(self) -> Optional[Any]:
@@ -136,8 +143,7 @@ class AsyncHub:
         Try to receive message asynchronously.
         Returns None immediately if no message.
         """
-        loop = asyncio.get_event_loop()
-        return await loop.run_in_executor(None, self._hub.try_recv)
+        return self._hub.recv()
 
     def subscribe(self, callback: Callable):
         """Subscribe with callback (synchronous)"""
@@ -160,6 +166,98 @@ class AsyncHub:
         self._hub.subscribe(wrapper)
 
 
+class _SpinInfo:
+    """
+    NodeInfo for nodes run by `spin`.
+
+    Forwards to a real NodeInfo when logging is on, and turns
+    `node.request_stop()` into stopping the loop.
+    """
+
+    def __init__(self, name: str, logging: bool, stop: asyncio.Event):
+        self._inner = _NodeInfo(name) if logging and _NodeInfo else None
+        self._stop = stop
+        self._ticks = 0
+
+    def request_stop(self) -> None:
+        self._stop.set()
+
+    def tick_count(self) -> int:
+        return self._ticks
+
+    def __getattr__(self, attr):
+        if self._inner is not None:
+            return getattr(self._inner, attr)
+        return lambda *args, **kwargs: None
+
+
+async def _spin(entries: List[Tuple[Node, bool]], duration: Optional[float],
+                stop: asyncio.Event) -> None:
+    """Tick `(node, logging)` entries in order until `stop` is set or `duration` elapses."""
+    infos = [(node, _SpinInfo(node.name, logging, stop)) for node, logging in entries]
+    loop = asyncio.get_running_loop()
+    start = loop.time()
+
+    for node, info in infos:
+        await node._internal_init_async(info)
+
+    try:
+        while not stop.is_set():
+            if duration is not None and loop.time() - start >= duration:
+                break
+
+            for node, info in infos:
+                ticks = node._last_tick_time
+                await node._internal_tick_async(info)
+                if node._last_tick_time != ticks:
+                    info._ticks += 1
+
+            # Sleep until the next node is due, waking early on stop()
+            now = time.time()
+            delay = min((node._last_tick_time + node._tick_period - now for node, _ in infos),
+                        default=0.001)
+            delay = min(max(delay, 0.0), 0.01)
+            if duration is not None:
+                delay = min(delay, max(start + duration - loop.time(), 0.0))
+            try:
+                await asyncio.wait_for(stop.wait(), timeout=delay)
+            except asyncio.TimeoutError:
+                pass
+    finally:
+        for node, info in infos:
+            await node._internal_shutdown_async(info)
+
+
+async def spin(*nodes: Node, duration: Optional[float] = None, logging: bool = True) -> None:
+    """
+    Run nodes on the running asyncio event loop - the async version of `horus.run`.
+
+    Ticks each node at its own rate, awaiting `async def` tick/init/shutdown
+    functions, so nodes can share the loop with aiohttp servers and async
+    hardware SDKs without background threads. Stops after `duration`
+    seconds, when a node calls `node.request_stop()`, or when the task is
+    cancelled; shutdown functions run in every case.
+
+    Example:
+        ```python
+        import asyncio
+        import horus
+
+        async def forward(node):
+            cmd = await node.recv("cmd_vel")
+            await robot_sdk.drive(cmd)
+
+        driver = horus.Node(name="driver", subs="cmd_vel", tick=forward, rate=50)
+
+        async def main():
+            await asyncio.gather(horus.spin(driver), web_app())
+
+        asyncio.run(main())
+        ```
+    """
+    await _spin([(node, logging) for node in nodes], duration, asyncio.Event())
+
+
 # Simple async utilities
 async def sleep(seconds: float):
     """Sleep without blocking - just use await!"""
@@ -179,6 +277,7 @@ async def wait_for(coro, timeout: float):
 __all__ = [
     'AsyncNode',
     'AsyncHub',
+    'spin',
     'sleep',
     'gather',
     'wait_for',
@@ -0,0 +1,236 @@
+"""
+Hot reload of Python nodes for `horus run --dev`
+
+When `HORUS_DEV=1` is set (by `horus run --dev`), the scheduler watches the
+source files of its nodes. After a file changes, its code is executed again
+and the running nodes are switched to the new tick/init/shutdown functions
+(or to the new class, for Node subclasses). The Node objects themselves are
+kept, so their hubs, topic connections and attributes survive the reload and
+the scheduler never restarts.
+
+Rules for finding the new code of a node:
+    1. A Node with the same name created by the reloaded file provides the new
+       functions, rate and any topics added since the last load.
+    2. Otherwise functions and classes are matched by qualified name.
+
+Top-level code of the reloaded file runs again, but Nodes created while
+reloading do not open hubs and `horus.run()` does nothing, so a script
+without an `if __name__ == "__main__":` guard is still safe.
+"""
+
+import importlib
+import os
+import sys
+import threading
+import traceback
+from typing import Any, Callable, Dict, List, Optional
+
+# Environment variable set by `horus run --dev`
+DEV_ENV = "HORUS_DEV"
+
+# Seconds between checks for changed files
+POLL_INTERVAL = 0.5
+
+# Node attributes holding user callbacks
+_CALLBACKS = ("tick_fn", "init_fn", "shutdown_fn", "on_error_fn")
+
+_active = False
+_lock = threading.Lock()
+
+
+def enabled() -> bool:
+    """Whether hot reload was requested for this process."""
+    return os.environ.get(DEV_ENV, "").lower() in ("1", "true", "yes")
+
+
+def reloading() -> bool:
+    """Whether a file is being re-executed for a reload right now."""
+    return _active
+
+
+def _source_file(obj: Any) -> Optional[str]:
+    code = getattr(obj, "__code__", None)
+    if code is not None:
+        path = code.co_filename
+    else:
+        module = sys.modules.get(getattr(obj, "__module__", None) or "")
+        path = getattr(module, "__file__", None)
+    if not path or not os.path.isfile(path):
+        return None
+    return os.path.realpath(path)
+
+
+def _lookup(namespace: Dict[str, Any], qualname: str) -> Any:
+    """Find `Outer.inner` style qualified names in a module namespace."""
+    if "<" in qualname:
+        # Lambdas and nested functions cannot be found by name
+        return None
+    parts = qualname.split(".")
+    obj = namespace.get(parts[0])
+    for part in parts[1:]:
+        obj = getattr(obj, part, None)
+    return obj
+
+
+class NodeReloader:
+    """
+    Watches the source files of nodes and swaps in their new code.
+
+    Example:
+        reloader = NodeReloader(nodes)
+        reloader.start()   # background thread
+        ...
+        reloader.stop()
+
+        # Or check once, e.g. from a test
+        reloader.poll()
+    """
+
+    def __init__(self, nodes: List[Any], log: Callable[[str], None] = print):
+        self.nodes = list(nodes)
+        self.log = log
+        self._mtimes: Dict[str, int] = {}
+        self._stop = threading.Event()
+        self._thread: Optional[threading.Thread] = None
+        for path in self.files():
+            self._mtimes[path] = self._mtime(path)
+
+    def files(self) -> List[str]:
+        """Source files the nodes' code comes from."""
+        files = []
+        for node in self.nodes:
+            for path in self._node_files(node):
+                if path not in files:
+                    files.append(path)
+        return files
+
+    def start(self) -> None:
+        """Start watching in a daemon thread."""
+        self.log(f"[dev] Hot reload on: watching {len(self._mtimes)} file(s)")
+        self._thread = threading.Thread(target=self._watch, name="horus-reload", daemon=True)
+        self._thread.start()
+
+    def stop(self) -> None:
+        self._stop.set()
+        if self._thread is not None:
+            self._thread.join(timeout=2 * POLL_INTERVAL)
+
+    def poll(self) -> List[str]:
+        """Reload every changed file. Returns the names of reloaded nodes."""
+        reloaded = []
+        for path, mtime in list(self._mtimes.items()):
+            current = self._mtime(path)
+            if current == mtime:
+                continue
+            self._mtimes[path] = current
+            reloaded.extend(self.reload(path))
+        return reloaded
+
+    def reload(self, path: str) -> List[str]:
+        """Re-execute `path` and switch its nodes to the new code."""
+        affected = [node for node in self.nodes if path in self._node_files(node)]
+        if not affected:
+            return []
+        try:
+            namespace = self._execute(path, affected)
+        except Exception:
+            # Keep running the old code until the file is fixed
+            self.log(f"[dev] Reload of {os.path.basename(path)} failed, keeping the running code:")
+            self.log(traceback.format_exc().rstrip())
+            return []
+
+        fresh = {
+            value.name: value
+            for value in namespace.values()
+            if hasattr(value, "tick_fn") and hasattr(value, "pub_topics")
+        }
+        names = []
+        for node in affected:
+            if self._swap(node, path, namespace, fresh.get(node.name)):
+                names.append(node.name)
+        if names:
+            self.log(f"[dev] Reloaded {os.path.basename(path)}: {', '.join(names)}")
+        else:
+            self.log(f"[dev] {os.path.basename(path)} changed, but no node code was found to swap")
+        return names
+
+    def _watch(self) -> None:
+        while not self._stop.wait(POLL_INTERVAL):
+            self.poll()
+
+    def _node_files(self, node: Any) -> List[str]:
+        files = []
+        objects = [getattr(node, attr, None) for attr in _CALLBACKS]
+        if not type(node).__module__.startswith("horus"):
+            # Subclass defined in user code
+            objects.append(type(node))
+        for obj in objects:
+            path = _source_file(obj) if obj is not None else None
+            if path and path not in files:
+                files.append(path)
+        return files
+
+    def _execute(self, path: str, nodes: List[Any]) -> Dict[str, Any]:
+        """Run the file again and return its namespace."""
+        global _active
+        module_name = None
+        for node in nodes:
+            for obj in [getattr(node, attr, None) for attr in _CALLBACKS] + [type(node)]:
+                name = getattr(obj, "__module__", None)
+                if name and name != "__main__" and _source_file(obj) == path:
+                    module_name = name
+                    break
+
+        with _lock:
+            _active = True
+            try:
+                if module_name in sys.modules:
+                    return vars(importlib.reload(sys.modules[module_name]))
+                # Scripts run as __main__ are executed in a fresh namespace so
+                # their `if __name__ == "__main__":` block stays untouched
+                with open(path, encoding="utf-8") as source:
+                    code = compile(source.read(), path, "exec")
+                namespace = {"__name__": "__horus_reload__", "__file__": path}
+                exec(code, namespace)
+                return namespace
+            finally:
+                _active = False
+
+    def _swap(self, node: Any, path: str, namespace: Dict[str, Any], fresh: Any) -> bool:
+        swapped = False
+        for attr in _CALLBACKS:
+            old = getattr(node, attr, None)
+            if fresh is not None:
+                new = getattr(fresh, attr, None)
+            elif old is not None and _source_file(old) == path:
+                new = _lookup(namespace, old.__qualname__)
+            else:
+                continue
+            if new is not old and (new is not None or fresh is not None):
+                setattr(node, attr, new)
+                swapped = True
+
+        cls = type(node)
+        if _source_file(cls) == path:
+            new_cls = _lookup(namespace, cls.__qualname__)
+            if isinstance(new_cls, type) and new_cls is not cls:
+                node.__class__ = new_cls
+                swapped = True
+
+        if fresh is not None:
+            if fresh.rate != node.rate:
+                node.rate = fresh.rate
+                node._tick_period = 1.0 / fresh.rate if fresh.rate > 0 else 0.0
+                swapped = True
+            added = node._add_topics(fresh)
+            if added:
+                self.log(f"[dev] {node.name}: new topics {', '.join(added)}")
+                swapped = True
+        return swapped
+
+    @staticmethod
+    def _mtime(path: str) -> int:
+        try:
+            return os.stat(path).st_mtime_ns
+        except OSError:
+            return 0
@@ -178,6 +178,7 @@ impl PyHFrameConfig {
                 history_len,
                 enable_overflow: false,
                 chain_cache_size: 64,
+                max_extrapolation_ns: 0,
             },
         }
     }
@@ -242,6 +242,7 @@ impl PyNodeInfo {
             message: data_repr,
             tick_us,
             ipc_ns,
+            trace_id: None,
         });
 
         Ok(())
@@ -289,6 +290,7 @@ impl PyNodeInfo {
             message: data_repr,
             tick_us,
             ipc_ns,
+            trace_id: None,
         });
 
         Ok(())
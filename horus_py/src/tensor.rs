@@ -1,7 +1,13 @@
 //! Python bindings for HORUS tensor system
 //!
-//! Provides zero-copy tensor access from Python via numpy's `__array_interface__`
-//! and the buffer protocol.
+//! Provides zero-copy tensor access from Python via numpy's `__array_interface__`.
+//! The wheels target the stable ABI from Python 3.9, where extension types
+//! cannot implement the buffer protocol (it joined the limited API in 3.11),
+//! so the array interface is the zero-copy path numpy and torch both accept.
+//!
+//! Handles decoded from a received descriptor are read-only: their numpy
+//! views are not writeable, so a subscriber cannot modify a frame that other
+//! subscribers of the same topic share.
 
 use horus::memory::tensor_pool::{HorusTensor, TensorDevice, TensorDtype};
 use horus::memory::{TensorHandle, TensorPool, TensorPoolConfig};
@@ -66,6 +72,7 @@ impl PyTensorPool {
             pool_size: size_mb * 1024 * 1024,
             max_slots,
             slot_alignment: 64,
+            huge_pages: false,
         };
         let pool = get_or_create_pool(pool_id, Some(config))?;
         Ok(Self { pool })
@@ -90,6 +97,41 @@ impl PyTensorPool {
 
         Ok(PyTensorHandle {
             handle: Some(handle),
+            readonly: false,
+        })
+    }
+
+    /// Allocate a CPU tensor laid out as an image
+    ///
+    /// The shape is (height, width, channels), or (height, width) for
+    /// single-channel encodings, with the dtype of the encoding (uint16 for
+    /// mono16/depth16, float32 for mono32f/rgb32f, uint8 otherwise). Write
+    /// the pixels through `handle.numpy()` and send the handle on a topic;
+    /// subscribers get a read-only view of the same memory.
+    ///
+    /// Args:
+    ///     width: Image width in pixels
+    ///     height: Image height in pixels
+    ///     encoding: "rgb8", "bgr8", "rgba8", "bgra8", "mono8", "mono16",
+    ///               "depth16", "mono32f", "rgb32f", "yuv422" or "bayer_rggb8"
+    ///
+    /// Returns:
+    ///     TensorHandle for the allocated image
+    #[pyo3(signature = (width, height, encoding="rgb8"))]
+    fn alloc_image(&self, width: u64, height: u64, encoding: &str) -> PyResult<PyTensorHandle> {
+        let (channels, dtype) = image_layout(encoding)?;
+        let shape = if channels == 1 {
+            vec![height, width]
+        } else {
+            vec![height, width, channels]
+        };
+
+        let handle = TensorHandle::alloc(Arc::clone(&self.pool), &shape, dtype, TensorDevice::Cpu)
+            .map_err(|e| PyRuntimeError::new_err(format!("Allocation failed: {}", e)))?;
+
+        Ok(PyTensorHandle {
+            handle: Some(handle),
+            readonly: false,
         })
     }
 
@@ -105,6 +147,12 @@ impl PyTensorPool {
             dict.set_item("total_refcount", stats.total_refcount)?;
             dict.set_item("used_bytes", stats.used_bytes)?;
             dict.set_item("free_bytes", stats.free_bytes)?;
+            dict.set_item("total_allocs", stats.total_allocs)?;
+            dict.set_item("failed_allocs", stats.failed_allocs)?;
+            dict.set_item("live_bytes", stats.live_bytes)?;
+            dict.set_item("peak_live_bytes", stats.peak_live_bytes)?;
+            dict.set_item("largest_free_block", stats.largest_free_block)?;
+            dict.set_item("fragmentation", stats.fragmentation)?;
             Ok(dict.into())
         })
     }
@@ -120,15 +168,19 @@ impl PyTensorPool {
 #[pyclass(name = "TensorHandle")]
 pub struct PyTensorHandle {
     handle: Option<TensorHandle>,
+    /// Views are not writeable (tensors received from another node)
+    readonly: bool,
 }
 
 #[pymethods]
 impl PyTensorHandle {
     /// Create a TensorHandle from a raw tensor descriptor
     ///
-    /// This is used when receiving tensors from Hub/Link.
+    /// This is used when receiving tensors from Hub/Link. The handle is
+    /// read-only unless `readonly=False` is passed.
     #[staticmethod]
-    fn from_descriptor(pool_id: u32, descriptor_bytes: &[u8]) -> PyResult<Self> {
+    #[pyo3(signature = (pool_id, descriptor_bytes, readonly=true))]
+    fn from_descriptor(pool_id: u32, descriptor_bytes: &[u8], readonly: bool) -> PyResult<Self> {
         if descriptor_bytes.len() != std::mem::size_of::<HorusTensor>() {
             return Err(PyValueError::new_err(format!(
                 "Invalid descriptor size: expected {}, got {}",
@@ -145,6 +197,7 @@ impl PyTensorHandle {
 
         Ok(Self {
             handle: Some(handle),
+            readonly,
         })
     }
 
@@ -192,9 +245,10 @@ impl PyTensorHandle {
         let typestr = dtype_numpy_typestr(tensor.dtype);
         dict.set_item("typestr", typestr)?;
 
-        // Data pointer and read-only flag
+        // Data pointer and read-only flag; numpy refuses to make a view of
+        // a read-only handle writeable
         let ptr = handle.data_ptr() as usize;
-        dict.set_item("data", (ptr, false))?; // (ptr, readonly=False)
+        dict.set_item("data", (ptr, self.readonly))?;
 
         // Strides
         let strides: Vec<i64> = handle.strides().iter().map(|&x| x as i64).collect();
@@ -237,7 +291,7 @@ impl PyTensorHandle {
         // Data pointer (GPU address from IPC handle)
         // The cuda_ipc_handle stores the GPU pointer in the first 8 bytes (little-endian)
         let gpu_ptr = u64::from_le_bytes(tensor.cuda_ipc_handle[..8].try_into().unwrap());
-        dict.set_item("data", (gpu_ptr as usize, false))?;
+        dict.set_item("data", (gpu_ptr as usize, self.readonly))?;
 
         // Strides
         let strides: Vec<i64> = handle.strides().iter().map(|&x| x as i64).collect();
@@ -262,6 +316,22 @@ impl PyTensorHandle {
         Ok(handle.shape().to_vec())
     }
 
+    /// Whether numpy/torch views of this tensor are read-only
+    #[getter]
+    fn readonly(&self) -> bool {
+        self.readonly
+    }
+
+    /// ID of the pool holding the tensor (needed to decode its descriptor)
+    #[getter]
+    fn pool_id(&self) -> PyResult<u32> {
+        let handle = self
+            .handle
+            .as_ref()
+            .ok_or_else(|| PyRuntimeError::new_err("TensorHandle has been released"))?;
+        Ok(handle.tensor().pool_id)
+    }
+
     /// Get tensor dtype as string
     #[getter]
     fn dtype(&self) -> PyResult<&'static str> {
@@ -343,6 +413,8 @@ impl PyTensorHandle {
     ///
     /// Returns a numpy array that shares memory with this tensor.
     /// Changes to the numpy array will be visible in the tensor and vice versa.
+    /// The array is read-only if the handle is (see `readonly`), and keeps the
+    /// handle alive through its `base`.
     fn numpy<'py>(slf: &Bound<'py, Self>, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
         let inner = slf.borrow();
         let handle = inner
@@ -396,6 +468,7 @@ impl PyTensorHandle {
 
         Ok(Self {
             handle: Some(sliced),
+            readonly: self.readonly,
         })
     }
 
@@ -413,6 +486,7 @@ impl PyTensorHandle {
 
         Ok(Self {
             handle: Some(viewed),
+            readonly: self.readonly,
         })
     }
 
@@ -435,6 +509,7 @@ impl PyTensorHandle {
             // Already on CPU - return clone (increments refcount)
             return Ok(Self {
                 handle: Some(handle.clone()),
+                readonly: self.readonly,
             });
         }
 
@@ -461,8 +536,10 @@ impl PyTensorHandle {
         )
         .map_err(|e| PyRuntimeError::new_err(format!("CUDA memcpy failed: {}", e)))?;
 
+        // A fresh copy belongs to the caller
         Ok(Self {
             handle: Some(cpu_handle),
+            readonly: false,
         })
     }
 
@@ -476,6 +553,7 @@ impl PyTensorHandle {
         if handle.is_cpu() {
             return Ok(Self {
                 handle: Some(handle.clone()),
+                readonly: self.readonly,
             });
         }
 
@@ -511,6 +589,7 @@ impl PyTensorHandle {
             // Already on target CUDA device - return clone
             return Ok(Self {
                 handle: Some(handle.clone()),
+                readonly: self.readonly,
             });
         }
 
@@ -571,6 +650,7 @@ impl PyTensorHandle {
 
         Ok(Self {
             handle: Some(gpu_handle),
+            readonly: false,
         })
     }
 
@@ -587,6 +667,7 @@ impl PyTensorHandle {
         if handle.is_cuda() && handle.device() == target_device {
             return Ok(Self {
                 handle: Some(handle.clone()),
+                readonly: self.readonly,
             });
         }
 
@@ -622,11 +703,12 @@ impl PyTensorHandle {
     fn __repr__(&self) -> String {
         match &self.handle {
             Some(h) => format!(
-                "TensorHandle(shape={:?}, dtype={}, device={}, refcount={})",
+                "TensorHandle(shape={:?}, dtype={}, device={}, refcount={}{})",
                 h.shape(),
                 dtype_to_str(h.dtype()),
                 device_to_string(h.device()),
-                h.refcount()
+                h.refcount(),
+                if self.readonly { ", readonly" } else { "" }
             ),
             None => "TensorHandle(released)".to_string(),
         }
@@ -687,6 +769,23 @@ fn parse_device(s: &str) -> PyResult<TensorDevice> {
     }
 }
 
+/// Channels and element type of an image encoding (`ImageEncoding` names)
+fn image_layout(encoding: &str) -> PyResult<(u64, TensorDtype)> {
+    match encoding.to_lowercase().as_str() {
+        "mono8" | "bayer_rggb8" => Ok((1, TensorDtype::U8)),
+        "mono16" | "depth16" => Ok((1, TensorDtype::U16)),
+        "mono32f" => Ok((1, TensorDtype::F32)),
+        "yuv422" => Ok((2, TensorDtype::U8)),
+        "rgb8" | "bgr8" => Ok((3, TensorDtype::U8)),
+        "rgb32f" => Ok((3, TensorDtype::F32)),
+        "rgba8" | "bgra8" => Ok((4, TensorDtype::U8)),
+        _ => Err(PyValueError::new_err(format!(
+            "Unknown image encoding: {}",
+            encoding
+        ))),
+    }
+}
+
 fn dtype_numpy_typestr(dtype: TensorDtype) -> &'static str {
     match dtype {
         TensorDtype::F32 => "<f4",
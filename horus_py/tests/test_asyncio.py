@@ -0,0 +1,96 @@
+"""
+Test asyncio support: `horus.spin`, `Scheduler.run_async` and `Node.recv`
+
+Verifies that nodes tick on the running event loop alongside other tasks,
+that async tick/init/shutdown functions are awaited, and that stopping and
+timeouts behave.
+"""
+
+import asyncio
+
+import pytest
+
+import horus
+
+
+def test_spin_awaits_async_ticks_alongside_other_tasks():
+    """Async ticks run on the loop while other tasks make progress."""
+    events = []
+
+    async def init(node):
+        await asyncio.sleep(0)
+        events.append("init")
+
+    async def tick(node):
+        await asyncio.sleep(0.001)
+        events.append("tick")
+        if events.count("tick") >= 5:
+            node.request_stop()
+
+    def shutdown(node):
+        events.append("shutdown")
+
+    node = horus.Node(name="async_ticker", tick=tick, init=init, shutdown=shutdown, rate=100)
+    other = []
+
+    async def background():
+        while True:
+            other.append(1)
+            await asyncio.sleep(0.001)
+
+    async def main():
+        task = asyncio.ensure_future(background())
+        await asyncio.wait_for(horus.spin(node, duration=5.0), timeout=5.0)
+        task.cancel()
+
+    asyncio.run(main())
+
+    assert events[0] == "init"
+    assert events.count("tick") == 5
+    assert events[-1] == "shutdown"
+    assert len(other) > 0
+
+
+def test_scheduler_run_async_respects_priority_and_stop():
+    """Nodes tick in priority order and stop() ends run_async."""
+    order = []
+    scheduler = horus.Scheduler()
+    scheduler.add(horus.Node(name="second", tick=lambda n: order.append("second"), rate=0), 1)
+    scheduler.add(horus.Node(name="first", tick=lambda n: order.append("first"), rate=0), 0)
+
+    async def main():
+        run = asyncio.ensure_future(scheduler.run_async())
+        await asyncio.sleep(0.05)
+        scheduler.stop()
+        await asyncio.wait_for(run, timeout=1.0)
+
+    asyncio.run(main())
+
+    assert order[:2] == ["first", "second"]
+
+
+def test_recv_waits_for_message(unique_test_prefix):
+    """node.recv() returns a message sent while it waits."""
+    topic = f"{unique_test_prefix}_async"
+    publisher = horus.Node(name="async_pub", pubs=[topic])
+    subscriber = horus.Node(name="async_sub", subs=[topic])
+
+    async def main():
+        async def send_later():
+            await asyncio.sleep(0.02)
+            publisher.send(topic, {"value": 42})
+
+        sender = asyncio.ensure_future(send_later())
+        msg = await subscriber.recv(topic, timeout=2.0)
+        await sender
+        return msg
+
+    assert asyncio.run(main()) == {"value": 42}
+
+
+def test_recv_timeout():
+    """node.recv() raises asyncio.TimeoutError when nothing arrives."""
+    node = horus.Node(name="async_timeout")
+
+    with pytest.raises(asyncio.TimeoutError):
+        asyncio.run(node.recv("async_timeout_topic", timeout=0.02))
@@ -0,0 +1,102 @@
+"""
+Test hot reload of Python nodes (`horus run --dev`)
+
+Verifies that a changed node file is re-executed and the running Node object
+keeps its identity and hubs while switching to the new code.
+"""
+
+import os
+import sys
+import time
+
+import horus
+from horus.reload import NodeReloader
+
+
+def write_module(path, body):
+    path.write_text(body)
+    # Make sure the modification time changes even on coarse filesystems
+    stamp = time.time() + len(body)
+    os.utime(path, (stamp, stamp))
+
+
+def test_reload_swaps_tick_and_keeps_node(tmp_path, unique_test_prefix):
+    """A module-level tick function is replaced without recreating the node."""
+    topic = f"{unique_test_prefix}_out"
+    module = tmp_path / "reload_target.py"
+    write_module(module, f'''
+import horus
+
+def tick(node):
+    node.value = "old"
+
+node = horus.Node(name="reload_target", pubs=["{topic}"], tick=tick, rate=10)
+''')
+    sys.path.insert(0, str(tmp_path))
+    try:
+        import reload_target
+
+        node = reload_target.node
+        hubs = dict(node._hubs)
+        logs = []
+        reloader = NodeReloader([node], log=logs.append)
+        assert str(module.resolve()) in reloader.files()
+
+        write_module(module, f'''
+import horus
+
+def tick(node):
+    node.value = "new"
+
+node = horus.Node(name="reload_target", pubs=["{topic}", "{topic}_extra"], tick=tick, rate=20)
+''')
+        assert reloader.poll() == ["reload_target"]
+
+        node.tick_fn(node)
+        assert node.value == "new"
+        assert node.rate == 20
+        assert f"{topic}_extra" in node.pub_topics
+        # Existing hubs are kept so connections survive the reload
+        for name, hub in hubs.items():
+            assert node._hubs[name] is hub
+    finally:
+        sys.path.remove(str(tmp_path))
+        sys.modules.pop("reload_target", None)
+
+
+def test_reload_keeps_running_code_on_error(tmp_path):
+    """A file with a syntax error leaves the previous code in place."""
+    module = tmp_path / "reload_broken.py"
+    write_module(module, '''
+import horus
+
+def tick(node):
+    node.value = "old"
+
+node = horus.Node(name="reload_broken", tick=tick)
+''')
+    sys.path.insert(0, str(tmp_path))
+    try:
+        import reload_broken
+
+        node = reload_broken.node
+        logs = []
+        reloader = NodeReloader([node], log=logs.append)
+
+        write_module(module, "def tick(node)\n")
+        assert reloader.poll() == []
+        assert any("failed" in line for line in logs)
+
+        node.tick_fn(node)
+        assert node.value == "old"
+    finally:
+        sys.path.remove(str(tmp_path))
+        sys.modules.pop("reload_broken", None)
+
+
+def test_run_is_skipped_while_reloading(monkeypatch):
+    """horus.run() at the top level of a reloaded script does not start a scheduler."""
+    monkeypatch.setattr(horus.reload, "_active", True)
+    node = horus.Node(name="reload_skip", tick=lambda n: None)
+    # Returns immediately instead of running forever
+    horus.run(node)
@@ -0,0 +1,83 @@
+"""
+Test zero-copy NumPy views of shared-memory tensors
+
+Verifies that views share memory with the tensor, that received tensors are
+read-only, and that images get the layout of their encoding.
+"""
+
+import numpy as np
+import pytest
+
+import horus
+
+
+@pytest.fixture
+def pool():
+    return horus.TensorPool(pool_id=4242, size_mb=16, max_slots=64)
+
+
+def test_numpy_view_shares_memory(pool):
+    """Writes through one view are visible through another."""
+    handle = pool.alloc((4, 4), "float32")
+    assert not handle.readonly
+
+    view = handle.numpy()
+    view[:] = 3.0
+    assert np.all(handle.numpy() == 3.0)
+    assert view.flags.writeable
+
+
+def test_received_tensor_is_readonly(pool):
+    """A handle decoded from a descriptor gives non-writeable views."""
+    handle = pool.alloc((2, 3), "uint8")
+    handle.numpy()[:] = 7
+
+    received = horus.TensorHandle.from_descriptor(handle.pool_id, handle.to_descriptor())
+    assert received.readonly
+    view = received.numpy()
+    assert np.all(view == 7)
+    assert not view.flags.writeable
+    with pytest.raises(ValueError):
+        view[0, 0] = 1
+    with pytest.raises(ValueError):
+        view.setflags(write=True)
+
+    # Zero-copy: the sender's writes show through the read-only view
+    handle.numpy()[0, 0] = 9
+    assert view[0, 0] == 9
+
+
+@pytest.mark.parametrize("encoding,shape,dtype", [
+    ("rgb8", (48, 64, 3), np.uint8),
+    ("mono8", (48, 64), np.uint8),
+    ("depth16", (48, 64), np.uint16),
+    ("rgba8", (48, 64, 4), np.uint8),
+    ("rgb32f", (48, 64, 3), np.float32),
+])
+def test_alloc_image_layout(pool, encoding, shape, dtype):
+    """alloc_image uses (height, width[, channels]) and the encoding's dtype."""
+    image = pool.alloc_image(64, 48, encoding).numpy()
+    assert image.shape == shape
+    assert image.dtype == dtype
+
+
+def test_alloc_image_unknown_encoding(pool):
+    with pytest.raises(ValueError):
+        pool.alloc_image(64, 48, "jpeg")
+
+
+def test_send_tensor_between_nodes(pool, unique_test_prefix):
+    """Nodes exchange tensors by descriptor; the subscriber gets a read-only view."""
+    topic = f"{unique_test_prefix}_image"
+    publisher = horus.Node(name="tensor_pub", pubs=[topic])
+    subscriber = horus.Node(name="tensor_sub", subs=[topic])
+
+    frame = pool.alloc_image(8, 4, "mono8")
+    frame.numpy()[:] = np.arange(32, dtype=np.uint8).reshape(4, 8)
+    assert publisher.send(topic, frame)
+
+    received = subscriber.get(topic)
+    assert isinstance(received, horus.TensorHandle)
+    image = received.numpy()
+    assert np.array_equal(image, frame.numpy())
+    assert not image.flags.writeable
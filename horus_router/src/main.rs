@@ -49,6 +49,11 @@ struct Args {
     #[cfg(feature = "tls")]
     #[arg(long)]
     tls_key: Option<String>,
+
+    /// Only accept clients presenting a certificate signed by this CA (PEM)
+    #[cfg(feature = "tls")]
+    #[arg(long)]
+    tls_client_ca: Option<String>,
 }
 
 /// A client connection
@@ -289,6 +294,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         } else {
             tls_config = tls_config.with_auto_generate();
         }
+        if let Some(ca) = args.tls_client_ca {
+            info!("Requiring client certificates signed by {}", ca);
+            tls_config = tls_config.with_client_ca(ca);
+        }
 
         match tls_config.create_acceptor() {
             Ok(acceptor) => Some(acceptor),